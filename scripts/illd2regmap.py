@@ -0,0 +1,45 @@
+# Generates the built-in register map of a CPU from the iLLD register headers:
+#   python3 illd2regmap.py ./iLLD/TC37A/Tricore/_Reg tc375 ../src/qecu/arch/tricore/regmaps
+# writes <cpu>_reg.h (register addresses and types) and <cpu>_regdef.h (bitfields),
+# then add the CPU to builtin_regmap() in src/qecu/arch/tricore.rs
+import os
+import re
+import sys
+
+re_define = re.compile(r"^#define\s+([A-Z][A-Z0-9_]+)\s+(?:/\*.*?\*/\s*)?(.*0x[0-9A-Fa-f]{8}u?.*)$")
+re_bits = re.compile(r"typedef struct _Ifx_\w+_Bits\s*\{.*?\}\s*Ifx_\w+_Bits;", re.S)
+re_union = re.compile(r"typedef union\s*\{[^{}]*?Ifx_\w+_Bits B;[^{}]*?\}\s*Ifx_\w+;", re.S)
+re_comment = re.compile(r"/\*\*<?.*?\*/", re.S)
+
+
+def headers(path, suffix):
+    return sorted(os.path.join(path, name) for name in os.listdir(path) if name.endswith(suffix))
+
+
+if __name__ == '__main__':
+    if len(sys.argv) != 4:
+        print(f"usage: {sys.argv[0]} <iLLD _Reg directory> <cpu> <output directory>")
+        sys.exit(1)
+    source, cpu, output = sys.argv[1:]
+
+    defines = []
+    for header in headers(source, '_reg.h'):
+        for line in open(header, encoding='latin-1'):
+            match = re_define.match(line.strip())
+            if match:
+                defines.append(f"#define {match.group(1)} /*lint --e(923, 9078)*/ {match.group(2).strip()}")
+
+    bitfields = []
+    for header in headers(source, '_regdef.h'):
+        content = re_comment.sub('', open(header, encoding='latin-1').read())
+        content = re.sub(r"[ \t]+\n", "\n", content)
+        bitfields.extend(block.group(0) for block in re_bits.finditer(content))
+        bitfields.extend(block.group(0) for block in re_union.finditer(content))
+
+    with open(os.path.join(output, f"{cpu}_reg.h"), 'w') as reg:
+        reg.write(f"/* {cpu.upper()} register and module addresses, iLLD IfxXxx_reg.h format. */\n\n")
+        reg.write('\n'.join(defines) + '\n')
+    with open(os.path.join(output, f"{cpu}_regdef.h"), 'w') as regdef:
+        regdef.write(f"/* {cpu.upper()} bitfields, iLLD IfxXxx_regdef.h format. */\n\n")
+        regdef.write('\n\n'.join(bitfields) + '\n')
+    print(f"{len(defines)} registers, {len(bitfields)} types")
@@ -3,7 +3,8 @@ mod utils;
 use std::thread::spawn;
 use std::fs;
 use crate::qecu::emulator::Emulator;
-use crate::qecu::api;
+use crate::qecu::{api, doip, xcp};
+use crate::qecu::fuzz::{self, ExecResult};
 
 #[tokio::main]
 async fn main() -> () {
@@ -13,10 +14,21 @@ async fn main() -> () {
 
     let emulator: Emulator<'static> = Emulator::new(wf);
     let emustart = emulator.clone();
+    let args: Vec<String> = std::env::args().collect();
 
     spawn(move || {
-        emustart.run();
+        match args.get(1).map(|arg| arg.as_str()) {
+            Some("fuzz") => fuzz::fuzz(&emustart),
+            Some("replay") => {
+                let input = args.get(2).expect("usage: qecu replay <input>");
+                let result = fuzz::replay(&emustart, input);
+                std::process::exit(if result == ExecResult::Ok { 0 } else { 1 });
+            }
+            _ => emustart.run()
+        }
     });
     
+    doip::serve(emulator.clone());
+    xcp::serve(emulator.clone());
     api::bootstrap(String::from("127.0.0.1:3000"), emulator.clone()).await;
 }
\ No newline at end of file
@@ -1,4 +1,26 @@
 pub mod emulator;
 pub mod interceptor;
 pub mod api;
-pub mod arch;
\ No newline at end of file
+pub mod arch;
+pub mod symbols;
+pub mod trace;
+pub mod coverage;
+pub mod disas;
+pub mod pcode;
+pub mod taint;
+pub mod snapshot;
+pub mod fuzz;
+pub mod crash;
+pub mod peripheral;
+pub mod clock;
+pub mod serial;
+pub mod can;
+pub mod socketcan;
+pub mod isotp;
+pub mod uds;
+pub mod doip;
+pub mod xcp;
+pub mod a2l;
+pub mod regmap;
+pub mod memory;
+pub mod scheduler;
\ No newline at end of file
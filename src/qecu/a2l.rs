@@ -0,0 +1,569 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::fs;
+
+/// `/begin NAME args... /end NAME`, keyword items (e.g. `ECU_ADDRESS 0x...`)
+/// are kept in `args`.
+#[derive(Clone, Debug, Default)]
+struct Block {
+    name: String,
+    args: Vec<String>,
+    children: Vec<Block>
+}
+
+impl Block {
+    fn child<'a>(&'a self, name: &str) -> impl Iterator<Item = &'a Block> + 'a {
+        let name = name.to_string();
+        self.children.iter().filter(move |child| child.name == name)
+    }
+
+    /// Arguments following the keyword `keyword`.
+    fn keyword(&self, keyword: &str) -> Option<&[String]> {
+        self.args.iter().position(|arg| arg == keyword).map(|index| &self.args[index + 1..])
+    }
+
+    fn keyword_int(&self, keyword: &str) -> Option<u64> {
+        self.keyword(keyword).and_then(|args| args.first()).and_then(|arg| parse_int(arg))
+    }
+}
+
+fn parse_int(token: &str) -> Option<u64> {
+    match token.strip_prefix("0x").or(token.strip_prefix("0X")) {
+        Some(hex) => u64::from_str_radix(hex, 16).ok(),
+        None => token.parse::<u64>().ok()
+    }
+}
+
+fn parse_float(token: &str) -> f64 {
+    parse_int(token).map(|value| value as f64).or(token.parse::<f64>().ok()).unwrap_or(0.0)
+}
+
+fn tokenize(content: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut chars = content.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => {
+                let mut string = String::from("\"");
+                while let Some(c) = chars.next() {
+                    match c {
+                        '\\' => string.extend(chars.next()),
+                        '"' if chars.peek() == Some(&'"') => {
+                            chars.next();
+                            string.push('"');
+                        }
+                        '"' => break,
+                        _ => string.push(c)
+                    }
+                }
+                tokens.push(string);
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                let mut last = ' ';
+                for c in chars.by_ref() {
+                    if last == '*' && c == '/' {
+                        break;
+                    }
+                    last = c;
+                }
+            }
+            '/' if chars.peek() == Some(&'/') => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+            }
+            c if c.is_whitespace() => {}
+            _ => {
+                let mut token = c.to_string();
+                while let Some(c) = chars.peek() {
+                    if c.is_whitespace() || *c == '"' {
+                        break;
+                    }
+                    token.push(*c);
+                    chars.next();
+                }
+                tokens.push(token);
+            }
+        }
+    }
+    return tokens;
+}
+
+fn parse_blocks(tokens: &[String], index: &mut usize, block: &mut Block) {
+    while *index < tokens.len() {
+        let token = &tokens[*index];
+        *index += 1;
+        match token.as_str() {
+            "/begin" => {
+                let mut child = Block { name: tokens.get(*index).cloned().unwrap_or_default(), ..Block::default() };
+                *index += 1;
+                parse_blocks(tokens, index, &mut child);
+                block.children.push(child);
+            }
+            "/end" => {
+                *index += 1;
+                return;
+            }
+            _ => block.args.push(token.trim_start_matches('"').to_string())
+        }
+    }
+}
+
+fn type_size(datatype: &str) -> usize {
+    match datatype {
+        "UBYTE" | "SBYTE" => 1,
+        "UWORD" | "SWORD" | "FLOAT16_IEEE" => 2,
+        "ULONG" | "SLONG" | "FLOAT32_IEEE" => 4,
+        "A_UINT64" | "A_INT64" | "FLOAT64_IEEE" => 8,
+        _ => 1
+    }
+}
+
+fn decode(datatype: &str, bytes: &[u8], big_endian: bool) -> f64 {
+    let mut raw = [0u8; 8];
+    match big_endian {
+        true => bytes.iter().rev().enumerate().for_each(|(index, byte)| raw[index] = *byte),
+        false => raw[..bytes.len()].copy_from_slice(bytes)
+    }
+    let value = u64::from_le_bytes(raw);
+    match datatype {
+        "SBYTE" => value as u8 as i8 as f64,
+        "SWORD" => value as u16 as i16 as f64,
+        "SLONG" => value as u32 as i32 as f64,
+        "A_INT64" => value as i64 as f64,
+        "FLOAT32_IEEE" => f32::from_bits(value as u32) as f64,
+        "FLOAT64_IEEE" => f64::from_bits(value),
+        _ => value as f64
+    }
+}
+
+fn encode(datatype: &str, value: f64, big_endian: bool) -> Vec<u8> {
+    let raw = match datatype {
+        "FLOAT32_IEEE" => (value as f32).to_bits() as u64,
+        "FLOAT64_IEEE" => value.to_bits(),
+        "SBYTE" | "SWORD" | "SLONG" | "A_INT64" => value.round() as i64 as u64,
+        _ => value.round().max(0.0) as u64
+    };
+    let mut bytes = raw.to_le_bytes()[..type_size(datatype)].to_vec();
+    if big_endian {
+        bytes.reverse();
+    }
+    return bytes;
+}
+
+fn number(value: f64) -> Value {
+    match value.fract() == 0.0 && value.abs() < (1u64 << 53) as f64 {
+        true => json!(value as i64),
+        false => json!(value)
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct CompuMethod {
+    kind: String,
+    unit: String,
+    coeffs: Vec<f64>,
+    /// TAB_VERB texts or TAB_INTP/TAB_NOINTP points.
+    table: Vec<(f64, String)>
+}
+
+impl CompuMethod {
+    fn to_physical(&self, raw: f64) -> Value {
+        match self.kind.as_str() {
+            "LINEAR" if self.coeffs.len() >= 2 => number(self.coeffs[0] * raw + self.coeffs[1]),
+            // Internal = (a.p^2 + b.p + c) / (d.p^2 + e.p + f), inverted for a = d = 0.
+            "RAT_FUNC" if self.coeffs.len() >= 6 => {
+                let c = &self.coeffs;
+                let divisor = raw * c[4] - c[1];
+                number(if divisor == 0.0 { raw } else { (c[2] - raw * c[5]) / divisor })
+            }
+            "TAB_VERB" => match self.table.iter().find(|(value, _)| *value == raw) {
+                Some((_, text)) => json!(text),
+                None => number(raw)
+            },
+            "TAB_INTP" | "TAB_NOINTP" => {
+                let points: Vec<(f64, f64)> = self.table.iter().map(|(x, y)| (*x, parse_float(y))).collect();
+                number(interpolate(&points, raw, self.kind == "TAB_INTP"))
+            }
+            _ => number(raw)
+        }
+    }
+
+    fn to_raw(&self, physical: &Value) -> Result<f64, String> {
+        if let Some(text) = physical.as_str() {
+            return self.table.iter().find(|(_, entry)| entry == text).map(|(value, _)| *value)
+                .ok_or(format!("{} is not a value of {}", text, self.kind));
+        }
+        let value = physical.as_f64().ok_or(format!("{} is not a number", physical))?;
+        match self.kind.as_str() {
+            "LINEAR" if self.coeffs.len() >= 2 && self.coeffs[0] != 0.0 => Ok((value - self.coeffs[1]) / self.coeffs[0]),
+            "RAT_FUNC" if self.coeffs.len() >= 6 => {
+                let c = &self.coeffs;
+                Ok((c[0] * value * value + c[1] * value + c[2]) / (c[3] * value * value + c[4] * value + c[5]))
+            }
+            "TAB_INTP" | "TAB_NOINTP" => {
+                let points: Vec<(f64, f64)> = self.table.iter().map(|(x, y)| (parse_float(y), *x)).collect();
+                Ok(interpolate(&points, value, self.kind == "TAB_INTP"))
+            }
+            _ => Ok(value)
+        }
+    }
+}
+
+fn interpolate(points: &[(f64, f64)], x: f64, linear: bool) -> f64 {
+    let mut points = points.to_vec();
+    points.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+    let (first, last) = match (points.first(), points.last()) {
+        (Some(first), Some(last)) => (*first, *last),
+        _ => return x
+    };
+    if x <= first.0 {
+        return first.1;
+    }
+    if x >= last.0 {
+        return last.1;
+    }
+    for pair in points.windows(2) {
+        let ((x0, y0), (x1, y1)) = (pair[0], pair[1]);
+        if x >= x0 && x <= x1 {
+            return match linear {
+                true => y0 + (y1 - y0) * (x - x0) / (x1 - x0),
+                false => if x - x0 <= x1 - x { y0 } else { y1 }
+            };
+        }
+    }
+    return x;
+}
+
+/// Items of a RECORD_LAYOUT, in memory order: (item, datatype).
+#[derive(Clone, Debug, Default)]
+struct RecordLayout {
+    items: Vec<(String, String)>
+}
+
+#[derive(Clone, Debug, Default)]
+struct Axis {
+    conversion: String,
+    max_points: usize,
+    /// FIX_AXIS_PAR(_DIST) points, no data in memory.
+    fixed: Option<Vec<f64>>
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct LabelInfo {
+    pub name: String,
+    pub kind: String,
+    pub address: u64,
+    pub unit: String,
+    pub description: String
+}
+
+#[derive(Clone, Debug, Default)]
+struct Label {
+    info: LabelInfo,
+    datatype: String,
+    conversion: String,
+    layout: String,
+    count: usize,
+    bit_mask: Option<u64>,
+    big_endian: bool,
+    axes: Vec<Axis>
+}
+
+/// Measurements and characteristics of an A2L file, decoded to physical
+/// values through their COMPU_METHOD.
+#[derive(Clone, Debug, Default)]
+pub struct A2l {
+    labels: HashMap<String, Label>,
+    compu_methods: HashMap<String, CompuMethod>,
+    layouts: HashMap<String, RecordLayout>
+}
+
+fn fixed_axis(axis: &Block) -> Option<Vec<f64>> {
+    if let Some(args) = axis.keyword("FIX_AXIS_PAR").or(axis.keyword("FIX_AXIS_PAR_DIST")) {
+        let values: Vec<f64> = args.iter().take(3).map(|arg| parse_float(arg)).collect();
+        if values.len() == 3 {
+            let distance = match axis.keyword("FIX_AXIS_PAR").is_some() {
+                true => 2f64.powf(values[1]),
+                false => values[1]
+            };
+            return Some((0..values[2] as usize).map(|index| values[0] + distance * index as f64).collect());
+        }
+    }
+    return axis.child("FIX_AXIS_PAR_LIST").next().map(|list| list.args.iter().map(|arg| parse_float(arg)).collect());
+}
+
+impl A2l {
+    pub fn new(path: &Option<String>) -> A2l {
+        let path = match path {
+            Some(path) => path,
+            None => return A2l::default()
+        };
+        let content = fs::read(path).expect(format!("[qecu::a2l] Cannot open {}\n", path).as_str());
+        let a2l = A2l::parse(&String::from_utf8_lossy(&content));
+        print!("[qecu::a2l] {} labels loaded from {}\n", a2l.labels.len(), path);
+        return a2l;
+    }
+
+    pub fn parse(content: &str) -> A2l {
+        let tokens = tokenize(content);
+        let mut root = Block::default();
+        parse_blocks(&tokens, &mut 0, &mut root);
+        let mut a2l = A2l::default();
+        let mut big_endian = false;
+        let mut stack: Vec<&Block> = vec![&root];
+        let mut modules = Vec::new();
+        while let Some(block) = stack.pop() {
+            match block.name.as_str() {
+                "MODULE" => modules.push(block),
+                _ => stack.extend(block.children.iter())
+            }
+        }
+        for module in modules {
+            for common in module.child("MOD_COMMON") {
+                big_endian = matches!(common.keyword("BYTE_ORDER").and_then(|args| args.first()).map(|arg| arg.as_str()),
+                    Some("MSB_FIRST") | Some("BIG_ENDIAN"));
+            }
+            let tables: HashMap<String, Vec<(f64, String)>> = module.children.iter()
+                .filter(|child| matches!(child.name.as_str(), "COMPU_VTAB" | "COMPU_TAB"))
+                .map(|table| {
+                    // name, description, conversion type, number of pairs, pairs
+                    let pairs = table.args.get(4..).unwrap_or(&[]).chunks(2)
+                        .filter(|pair| pair.len() == 2)
+                        .map(|pair| (parse_float(&pair[0]), pair[1].clone()))
+                        .collect();
+                    (table.args.first().cloned().unwrap_or_default(), pairs)
+                }).collect();
+            for method in module.child("COMPU_METHOD") {
+                let coeffs = method.keyword("COEFFS_LINEAR").map(|args| &args[..2.min(args.len())])
+                    .or(method.keyword("COEFFS").map(|args| &args[..6.min(args.len())]))
+                    .unwrap_or(&[]).iter().map(|arg| parse_float(arg)).collect();
+                let table = method.keyword("COMPU_TAB_REF").and_then(|args| args.first())
+                    .and_then(|name| tables.get(name)).cloned().unwrap_or_default();
+                a2l.compu_methods.insert(method.args[0].clone(), CompuMethod {
+                    kind: method.args.get(2).cloned().unwrap_or_default(),
+                    unit: method.args.get(4).cloned().unwrap_or_default(),
+                    coeffs: coeffs,
+                    table: table
+                });
+            }
+            for layout in module.child("RECORD_LAYOUT") {
+                let mut items: Vec<(u64, String, String)> = Vec::new();
+                for (index, arg) in layout.args.iter().enumerate() {
+                    if matches!(arg.as_str(), "FNC_VALUES" | "AXIS_PTS_X" | "AXIS_PTS_Y" | "NO_AXIS_PTS_X" | "NO_AXIS_PTS_Y") {
+                        let position = layout.args.get(index + 1).and_then(|arg| parse_int(arg)).unwrap_or(0);
+                        items.push((position, arg.clone(), layout.args.get(index + 2).cloned().unwrap_or_default()));
+                    }
+                }
+                items.sort_by_key(|item| item.0);
+                a2l.layouts.insert(layout.args[0].clone(), RecordLayout {
+                    items: items.into_iter().map(|(_, item, datatype)| (item, datatype)).collect()
+                });
+            }
+            for measurement in module.child("MEASUREMENT") {
+                let args = &measurement.args;
+                if args.len() < 4 {
+                    continue;
+                }
+                let count = measurement.keyword_int("ARRAY_SIZE")
+                    .or(measurement.keyword("MATRIX_DIM").map(|dims| dims.iter().take(3).filter_map(|dim| parse_int(dim)).product()))
+                    .unwrap_or(1) as usize;
+                a2l.labels.insert(args[0].clone(), Label {
+                    info: LabelInfo {
+                        name: args[0].clone(),
+                        kind: String::from("MEASUREMENT"),
+                        address: measurement.keyword_int("ECU_ADDRESS").unwrap_or(0),
+                        unit: String::new(),
+                        description: args[1].clone()
+                    },
+                    datatype: args[2].clone(),
+                    conversion: args[3].clone(),
+                    count: count.max(1),
+                    bit_mask: measurement.keyword_int("BIT_MASK"),
+                    big_endian: measurement.keyword("BYTE_ORDER").and_then(|args| args.first())
+                        .map_or(big_endian, |order| order == "MSB_FIRST" || order == "BIG_ENDIAN"),
+                    ..Label::default()
+                });
+            }
+            for characteristic in module.child("CHARACTERISTIC") {
+                let args = &characteristic.args;
+                if args.len() < 8 {
+                    continue;
+                }
+                let count = characteristic.keyword_int("NUMBER")
+                    .or(characteristic.keyword("MATRIX_DIM").map(|dims| dims.iter().take(3).filter_map(|dim| parse_int(dim)).product()))
+                    .unwrap_or(1) as usize;
+                let axes = characteristic.child("AXIS_DESCR").map(|axis| Axis {
+                    conversion: axis.args.get(2).cloned().unwrap_or_default(),
+                    max_points: axis.args.get(3).and_then(|arg| parse_int(arg)).unwrap_or(0) as usize,
+                    fixed: fixed_axis(axis)
+                }).collect();
+                a2l.labels.insert(args[0].clone(), Label {
+                    info: LabelInfo {
+                        name: args[0].clone(),
+                        kind: args[2].clone(),
+                        address: parse_int(&args[3]).unwrap_or(0),
+                        unit: String::new(),
+                        description: args[1].clone()
+                    },
+                    layout: args[4].clone(),
+                    conversion: args[6].clone(),
+                    count: count.max(1),
+                    big_endian: characteristic.keyword("BYTE_ORDER").and_then(|args| args.first())
+                        .map_or(big_endian, |order| order == "MSB_FIRST" || order == "BIG_ENDIAN"),
+                    axes: axes,
+                    ..Label::default()
+                });
+            }
+        }
+        for label in a2l.labels.values_mut() {
+            if let Some(method) = a2l.compu_methods.get(&label.conversion) {
+                label.info.unit = method.unit.clone();
+            }
+            if label.datatype.is_empty() {
+                label.datatype = a2l.layouts.get(&label.layout)
+                    .and_then(|layout| layout.items.iter().find(|(item, _)| item == "FNC_VALUES"))
+                    .map(|(_, datatype)| datatype.clone())
+                    .unwrap_or(String::from("UBYTE"));
+            }
+        }
+        return a2l;
+    }
+
+    pub fn get_labels(&self) -> Vec<LabelInfo> {
+        let mut labels: Vec<LabelInfo> = self.labels.values().map(|label| label.info.clone()).collect();
+        labels.sort_by(|a, b| a.name.cmp(&b.name));
+        return labels;
+    }
+
+    fn label(&self, name: &str) -> Result<&Label, String> {
+        self.labels.get(name).ok_or(format!("unknown label {}", name))
+    }
+
+    fn method(&self, name: &str) -> CompuMethod {
+        self.compu_methods.get(name).cloned().unwrap_or_default()
+    }
+
+    /// Memory layout of a curve/map: (offset of every item, axis point counts).
+    fn layout(&self, label: &Label, read: &dyn Fn(u64, usize) -> Option<Vec<u8>>) -> Result<(HashMap<String, u64>, Vec<usize>), String> {
+        let layout = self.layouts.get(&label.layout).cloned().unwrap_or_default();
+        let mut points: Vec<usize> = label.axes.iter().map(|axis| axis.fixed.as_ref().map_or(axis.max_points, |fixed| fixed.len())).collect();
+        let mut offsets = HashMap::new();
+        let mut offset = 0u64;
+        for (item, datatype) in &layout.items {
+            offsets.insert(item.clone(), offset);
+            let size = type_size(datatype) as u64;
+            offset += match item.as_str() {
+                "NO_AXIS_PTS_X" | "NO_AXIS_PTS_Y" => {
+                    let axis = if item.ends_with('X') { 0 } else { 1 };
+                    let bytes = read(label.info.address + offset, size as usize).ok_or(format!("cannot read {}", label.info.name))?;
+                    if let Some(count) = points.get_mut(axis) {
+                        *count = (decode(datatype, &bytes, label.big_endian) as usize).min(label.axes[axis].max_points.max(1));
+                    }
+                    size
+                }
+                "AXIS_PTS_X" => size * *points.first().unwrap_or(&0) as u64,
+                "AXIS_PTS_Y" => size * *points.get(1).unwrap_or(&0) as u64,
+                _ => size * points.iter().product::<usize>().max(1) as u64
+            };
+        }
+        return Ok((offsets, points));
+    }
+
+    fn read_values(&self, label: &Label, datatype: &str, address: u64, count: usize, method: &CompuMethod,
+                   read: &dyn Fn(u64, usize) -> Option<Vec<u8>>) -> Result<Vec<Value>, String> {
+        let size = type_size(datatype);
+        let bytes = read(address, size * count).ok_or(format!("cannot read {} at 0x{:x}", label.info.name, address))?;
+        return Ok(bytes.chunks(size).map(|chunk| {
+            let mut raw = decode(datatype, chunk, label.big_endian);
+            if let Some(mask) = label.bit_mask {
+                raw = ((raw as u64 & mask) >> mask.trailing_zeros()) as f64;
+            }
+            method.to_physical(raw)
+        }).collect());
+    }
+
+    /// Physical value of `name`: a number (or text for verbal conversions),
+    /// an array for blocks, an object with axes for curves and maps.
+    pub fn read(&self, name: &str, read: &dyn Fn(u64, usize) -> Option<Vec<u8>>) -> Result<Value, String> {
+        let label = self.label(name)?;
+        let method = self.method(&label.conversion);
+        match label.info.kind.as_str() {
+            "ASCII" => {
+                let bytes = read(label.info.address, label.count).ok_or(format!("cannot read {}", name))?;
+                let text: String = bytes.iter().take_while(|byte| **byte != 0).map(|byte| *byte as char).collect();
+                return Ok(json!(text));
+            }
+            "CURVE" | "MAP" | "CUBOID" => {
+                let (offsets, points) = self.layout(label, read)?;
+                let layout = self.layouts.get(&label.layout).cloned().unwrap_or_default();
+                let datatype_of = |item: &str| layout.items.iter().find(|(name, _)| name == item).map(|(_, datatype)| datatype.clone());
+                let mut axes = Vec::new();
+                for (index, axis) in label.axes.iter().enumerate() {
+                    let item = if index == 0 { "AXIS_PTS_X" } else { "AXIS_PTS_Y" };
+                    let values = match (&axis.fixed, offsets.get(item), datatype_of(item)) {
+                        (Some(fixed), _, _) => fixed.iter().map(|value| self.method(&axis.conversion).to_physical(*value)).collect(),
+                        (None, Some(offset), Some(datatype)) => self.read_values(label, &datatype, label.info.address + offset, points[index], &self.method(&axis.conversion), read)?,
+                        _ => (0..points[index]).map(|index| json!(index)).collect()
+                    };
+                    axes.push(Value::Array(values));
+                }
+                let offset = offsets.get("FNC_VALUES").copied().unwrap_or(0);
+                let values = self.read_values(label, &label.datatype, label.info.address + offset, points.iter().product::<usize>().max(1), &method, read)?;
+                return Ok(json!({ "axes": axes, "values": values }));
+            }
+            _ => {
+                let values = self.read_values(label, &label.datatype, label.info.address, label.count, &method, read)?;
+                return Ok(match values.len() {
+                    1 => values[0].clone(),
+                    _ => Value::Array(values)
+                });
+            }
+        }
+    }
+
+    /// Writes the physical `value` of `name`, curves and maps take their
+    /// function values only.
+    pub fn write(&self, name: &str, value: &Value, read: &dyn Fn(u64, usize) -> Option<Vec<u8>>,
+                 write: &dyn Fn(u64, &[u8]) -> bool) -> Result<(), String> {
+        let label = self.label(name)?;
+        let method = self.method(&label.conversion);
+        let (address, count) = match label.info.kind.as_str() {
+            "ASCII" => {
+                let mut bytes = value.as_str().ok_or(format!("{} expects a string", name))?.as_bytes().to_vec();
+                bytes.resize(label.count, 0);
+                return match write(label.info.address, &bytes) {
+                    true => Ok(()),
+                    false => Err(format!("cannot write {}", name))
+                };
+            }
+            "CURVE" | "MAP" | "CUBOID" => {
+                let (offsets, points) = self.layout(label, read)?;
+                (label.info.address + offsets.get("FNC_VALUES").copied().unwrap_or(0), points.iter().product::<usize>().max(1))
+            }
+            _ => (label.info.address, label.count)
+        };
+        let values = match value {
+            Value::Array(values) => values.clone(),
+            Value::Object(map) => map.get("values").and_then(|values| values.as_array()).cloned().unwrap_or_default(),
+            value => vec![value.clone()]
+        };
+        if values.len() != count {
+            return Err(format!("{} expects {} values", name, count));
+        }
+        if label.bit_mask.is_some() {
+            return Err(format!("{} is a bit field", name));
+        }
+        let mut bytes = Vec::new();
+        for value in &values {
+            bytes.extend(encode(&label.datatype, method.to_raw(value)?, label.big_endian));
+        }
+        return match write(address, &bytes) {
+            true => Ok(()),
+            false => Err(format!("cannot write {}", name))
+        };
+    }
+}
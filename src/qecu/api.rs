@@ -1,11 +1,23 @@
 use axum::{
-    extract::{Path, State}, routing::{get, post}, Json, Router
+    extract::{Path, Query, State}, http::StatusCode, routing::{get, post}, Json, Router
 };
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use tokio::task::spawn_blocking;
 use crate::qecu::emulator::Emulator;
 
-use super::interceptor::{CodeHook, EventCallback};
+use super::interceptor::{CodeHook, EventCallback, MemHook};
+use super::coverage::CoverageBlock;
+use super::disas::Instruction;
+use super::pcode::PcodeOp;
+use super::taint::TaintReport;
+use super::crash::Crash;
+use super::trace::TraceConfig;
+use super::can::{self, CanFrame};
+use super::isotp::IsoTpConfig;
+use super::uds::{ScanConfig, ScanReport, UdsCommand, UdsResponse};
+use super::a2l::LabelInfo;
+use super::regmap::SfrValue;
+use super::scheduler::CoreState;
 
 #[derive(Clone)]
 struct AppState {
@@ -17,6 +29,121 @@ struct EmitEvent {
     msg: String
 }
 
+#[derive(Deserialize)]
+struct TaintMemory {
+    address: u64,
+    size: u64,
+    label: String
+}
+
+#[derive(Deserialize)]
+struct DisasQuery {
+    address: Option<String>,
+    size: Option<u32>,
+    count: Option<u32>
+}
+
+/// `?tos=` of `POST /interrupt/{priority}`, CPU0 by default.
+#[derive(Deserialize)]
+struct InterruptQuery {
+    tos: Option<u8>
+}
+
+/// `?core=` of `POST /trap/{class}/{tin}`, the current core by default.
+#[derive(Deserialize)]
+struct TrapQuery {
+    core: Option<usize>
+}
+
+/// Body of `POST /isotp/{tx_id}/{rx_id}`, `data` is hex encoded.
+#[derive(Deserialize)]
+struct IsoTpRequest {
+    data: String,
+    node: Option<String>,
+    #[serde(default = "IsoTpRequest::default_response")]
+    response: bool,
+    fd: Option<bool>,
+    padding: Option<u8>,
+    block_size: Option<u8>,
+    st_min: Option<u8>,
+    timeout_ms: Option<u64>
+}
+
+impl IsoTpRequest {
+    fn default_response() -> bool {
+        true
+    }
+}
+
+#[derive(Serialize)]
+struct IsoTpResponse {
+    data: Option<String>,
+    error: Option<String>
+}
+
+#[derive(Serialize)]
+struct SfrResponse {
+    #[serde(flatten)]
+    sfr: Option<SfrValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>
+}
+
+#[derive(Serialize, Deserialize, Default)]
+struct LabelValue {
+    #[serde(default)]
+    value: serde_json::Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>
+}
+
+#[derive(Deserialize)]
+struct UdsQuery {
+    node: Option<String>,
+    fd: Option<bool>
+}
+
+impl UdsQuery {
+    fn config(&self, tx_id: &str, rx_id: &str) -> Result<(String, IsoTpConfig), (StatusCode, String)> {
+        let mut config = IsoTpConfig::new(parse_can_id(tx_id)?, parse_can_id(rx_id)?);
+        config.fd = self.fd.unwrap_or(false);
+        return Ok((self.node.clone().unwrap_or(String::from("CAN0_N0")), config));
+    }
+}
+
+/// Parses a decimal or `0x` prefixed address, a malformed one answers 400.
+fn parse_address(address: &str) -> Result<u64, (StatusCode, String)> {
+    let value = match address.strip_prefix("0x") {
+        Some(hex) => u64::from_str_radix(hex, 16),
+        None => address.parse::<u64>()
+    };
+    return value.map_err(|_| (StatusCode::BAD_REQUEST, format!("Invalid address: {}", address)));
+}
+
+fn parse_can_id(id: &str) -> Result<u32, (StatusCode, String)> {
+    let value = parse_address(id)?;
+    return u32::try_from(value).map_err(|_| (StatusCode::BAD_REQUEST, format!("Invalid CAN id: {}", id)));
+}
+
+/// Resolves the address of a disassembly query, defaulting to PC.
+fn query_address(emu: &Emulator<'static>, query: &DisasQuery) -> Result<u64, (StatusCode, String)> {
+    return match &query.address {
+        Some(address) => parse_address(address),
+        None => Ok(emu.read_register(String::from("PC")))
+    };
+}
+
+/// Resolves a disassembly query, defaulting to 16 instructions at PC.
+fn disas_query(emu: &Emulator<'static>, query: DisasQuery) -> Result<Vec<Instruction>, (StatusCode, String)> {
+    let address = query_address(emu, &query)?;
+    let instructions = match (query.size, query.count) {
+        (Some(size), _) => emu.disassemble(address, size),
+        (None, Some(count)) => emu.disassemble_count(address, count),
+        (None, None) => emu.disassemble_count(address, 16)
+    };
+    return Ok(instructions);
+}
+
 async fn emit(Path(event_type): Path<String>, State(state): State<AppState>, Json(payload): Json<EmitEvent>) -> String {
     spawn_blocking(move || {
         let emu = state.emulator;
@@ -45,11 +172,277 @@ async fn interceptor_get_event_hooks(State(state): State<AppState>) -> Json<Vec<
     Json(event_hooks)
 }
 
+async fn interceptor_get_mem_hooks(State(state): State<AppState>) -> Json<Vec<MemHook>> {
+    let mem_hooks = spawn_blocking(move || {
+        let emu: Emulator<'static> = state.emulator;
+        let mut lock = emu.interceptor.lock();
+        let intercept = lock.as_mut().unwrap().as_mut().unwrap();
+        intercept.get_mem_hooks().clone()
+    }).await.unwrap();
+    Json(mem_hooks)
+}
+
+async fn trace_start(State(state): State<AppState>, Json(payload): Json<TraceConfig>) -> String {
+    spawn_blocking(move || {
+        state.emulator.start_trace(payload);
+    }).await.expect("[qecu::api::trace_start] spawn_blocking error.");
+    return String::from("OK");
+}
+
+async fn trace_stop(State(state): State<AppState>) -> String {
+    spawn_blocking(move || {
+        state.emulator.stop_trace();
+    }).await.expect("[qecu::api::trace_stop] spawn_blocking error.");
+    return String::from("OK");
+}
+
+async fn trace_get_raw(State(state): State<AppState>) -> Vec<u8> {
+    spawn_blocking(move || {
+        state.emulator.get_trace()
+    }).await.unwrap()
+}
+
+async fn trace_get_text(State(state): State<AppState>) -> Result<String, (StatusCode, String)> {
+    spawn_blocking(move || {
+        state.emulator.get_trace_text().map_err(|err| (StatusCode::INTERNAL_SERVER_ERROR, err))
+    }).await.unwrap()
+}
+
+async fn coverage_start(State(state): State<AppState>) -> String {
+    spawn_blocking(move || {
+        state.emulator.set_coverage(true);
+    }).await.expect("[qecu::api::coverage_start] spawn_blocking error.");
+    return String::from("OK");
+}
+
+async fn coverage_stop(State(state): State<AppState>) -> String {
+    spawn_blocking(move || {
+        state.emulator.set_coverage(false);
+    }).await.expect("[qecu::api::coverage_stop] spawn_blocking error.");
+    return String::from("OK");
+}
+
+async fn coverage_reset(State(state): State<AppState>) -> String {
+    spawn_blocking(move || {
+        state.emulator.reset_coverage();
+    }).await.expect("[qecu::api::coverage_reset] spawn_blocking error.");
+    return String::from("OK");
+}
+
+async fn coverage_get_blocks(State(state): State<AppState>) -> Json<Vec<CoverageBlock>> {
+    let blocks = spawn_blocking(move || {
+        state.emulator.get_coverage()
+    }).await.unwrap();
+    Json(blocks)
+}
+
+async fn coverage_get_drcov(State(state): State<AppState>) -> Vec<u8> {
+    spawn_blocking(move || {
+        state.emulator.get_coverage_drcov()
+    }).await.unwrap()
+}
+
+async fn coverage_get_lcov(State(state): State<AppState>) -> String {
+    spawn_blocking(move || {
+        state.emulator.get_coverage_lcov()
+    }).await.unwrap()
+}
+
+async fn disas(State(state): State<AppState>, Query(query): Query<DisasQuery>) -> Result<Json<Vec<Instruction>>, (StatusCode, String)> {
+    let instructions = spawn_blocking(move || {
+        disas_query(&state.emulator, query)
+    }).await.unwrap()?;
+    Ok(Json(instructions))
+}
+
+async fn disas_listing(State(state): State<AppState>, Query(query): Query<DisasQuery>) -> Result<String, (StatusCode, String)> {
+    spawn_blocking(move || {
+        let instructions = disas_query(&state.emulator, query)?;
+        Ok(super::disas::to_listing(&instructions, &state.emulator.symbols))
+    }).await.unwrap()
+}
+
+async fn pcode(State(state): State<AppState>, Query(query): Query<DisasQuery>) -> Result<Json<Vec<PcodeOp>>, (StatusCode, String)> {
+    let ops = spawn_blocking(move || {
+        let emu = state.emulator;
+        let address = query_address(&emu, &query)?;
+        Ok(match query.size {
+            Some(size) => emu.lift(address, size),
+            None => emu.lift_block(address)
+        })
+    }).await.unwrap()?;
+    Ok(Json(ops))
+}
+
+async fn taint_memory(State(state): State<AppState>, Json(payload): Json<TaintMemory>) -> String {
+    spawn_blocking(move || {
+        state.emulator.taint_memory(payload.address, payload.size, &payload.label);
+    }).await.expect("[qecu::api::taint_memory] spawn_blocking error.");
+    return String::from("OK");
+}
+
+async fn taint_clear(State(state): State<AppState>) -> String {
+    spawn_blocking(move || {
+        state.emulator.taint.lock().unwrap().clear();
+    }).await.expect("[qecu::api::taint_clear] spawn_blocking error.");
+    return String::from("OK");
+}
+
+async fn taint_get_reports(State(state): State<AppState>) -> Json<Vec<TaintReport>> {
+    let reports = spawn_blocking(move || {
+        state.emulator.get_taint_reports()
+    }).await.unwrap();
+    Json(reports)
+}
+
+async fn get_crashes(State(state): State<AppState>) -> Json<Vec<Crash>> {
+    let crashes = spawn_blocking(move || {
+        state.emulator.get_crashes()
+    }).await.unwrap();
+    Json(crashes)
+}
+
+async fn get_cores(State(state): State<AppState>) -> Json<Vec<CoreState>> {
+    let cores = spawn_blocking(move || {
+        state.emulator.get_cores()
+    }).await.unwrap();
+    Json(cores)
+}
+
+async fn raise_interrupt(Path(priority): Path<u8>, Query(query): Query<InterruptQuery>, State(state): State<AppState>) -> Result<String, (StatusCode, String)> {
+    spawn_blocking(move || {
+        state.emulator.raise_interrupt(query.tos.unwrap_or(0), priority)
+    }).await.expect("[qecu::api::raise_interrupt] spawn_blocking error.")
+        .map_err(|err| (StatusCode::BAD_REQUEST, err))?;
+    return Ok(String::from("OK"));
+}
+
+async fn raise_trap(Path((class, tin)): Path<(u8, u32)>, Query(query): Query<TrapQuery>, State(state): State<AppState>) -> Result<String, (StatusCode, String)> {
+    spawn_blocking(move || {
+        let core = query.core.unwrap_or(state.emulator.current_core());
+        state.emulator.raise_trap(core, class, tin)
+    }).await.expect("[qecu::api::raise_trap] spawn_blocking error.")
+        .map_err(|err| (StatusCode::BAD_REQUEST, err))?;
+    return Ok(String::from("OK"));
+}
+
+async fn can_inject(Path(node): Path<String>, State(state): State<AppState>, Json(frame): Json<CanFrame>) -> String {
+    let injected = spawn_blocking(move || {
+        state.emulator.can_inject(&node, frame)
+    }).await.expect("[qecu::api::can_inject] spawn_blocking error.");
+    return String::from(if injected { "OK" } else { "Unknown node" });
+}
+
+async fn isotp(Path((tx_id, rx_id)): Path<(String, String)>, State(state): State<AppState>, Json(payload): Json<IsoTpRequest>) -> Result<Json<IsoTpResponse>, (StatusCode, String)> {
+    let mut config = IsoTpConfig::new(parse_can_id(&tx_id)?, parse_can_id(&rx_id)?);
+    let result = spawn_blocking(move || {
+        config.fd = payload.fd.unwrap_or(config.fd);
+        config.padding = payload.padding.or(config.padding);
+        config.block_size = payload.block_size.unwrap_or(config.block_size);
+        config.st_min = payload.st_min.unwrap_or(config.st_min);
+        config.timeout_ms = payload.timeout_ms.unwrap_or(config.timeout_ms);
+        let node = payload.node.unwrap_or(String::from("CAN0_N0"));
+        let data = can::parse_hex(&payload.data).ok_or(String::from("invalid hex data"))?;
+        match payload.response {
+            true => state.emulator.isotp_request(&node, &config, &data),
+            false => state.emulator.isotp_send(&node, &config, &data).map(|_| Vec::new())
+        }
+    }).await.expect("[qecu::api::isotp] spawn_blocking error.");
+    let response = match result {
+        Ok(data) => IsoTpResponse { data: Some(data.iter().map(|b| format!("{:02x}", b)).collect()), error: None },
+        Err(err) => IsoTpResponse { data: None, error: Some(err) }
+    };
+    Ok(Json(response))
+}
+
+async fn uds(Path((tx_id, rx_id)): Path<(String, String)>, Query(query): Query<UdsQuery>, State(state): State<AppState>, Json(command): Json<UdsCommand>) -> Result<Json<UdsResponse>, (StatusCode, String)> {
+    let (node, config) = query.config(&tx_id, &rx_id)?;
+    let response = spawn_blocking(move || {
+        state.emulator.uds_command(&node, config, &command)
+    }).await.expect("[qecu::api::uds] spawn_blocking error.");
+    Ok(Json(response))
+}
+
+async fn read_sfr(Path(symbol): Path<String>, State(state): State<AppState>) -> Json<SfrResponse> {
+    let result = spawn_blocking(move || {
+        state.emulator.read_sfr(&symbol)
+    }).await.expect("[qecu::api::read_sfr] spawn_blocking error.");
+    let response = match result {
+        Ok(sfr) => SfrResponse { sfr: Some(sfr), error: None },
+        Err(err) => SfrResponse { sfr: None, error: Some(err) }
+    };
+    Json(response)
+}
+
+async fn get_labels(State(state): State<AppState>) -> Json<Vec<LabelInfo>> {
+    let labels = spawn_blocking(move || {
+        state.emulator.get_labels()
+    }).await.unwrap();
+    Json(labels)
+}
+
+async fn read_label(Path(name): Path<String>, State(state): State<AppState>) -> Json<LabelValue> {
+    let result = spawn_blocking(move || {
+        state.emulator.read_label(&name)
+    }).await.expect("[qecu::api::read_label] spawn_blocking error.");
+    let response = match result {
+        Ok(value) => LabelValue { value: value, error: None },
+        Err(err) => LabelValue { error: Some(err), ..LabelValue::default() }
+    };
+    Json(response)
+}
+
+async fn write_label(Path(name): Path<String>, State(state): State<AppState>, Json(payload): Json<LabelValue>) -> String {
+    let result = spawn_blocking(move || {
+        state.emulator.write_label(&name, &payload.value)
+    }).await.expect("[qecu::api::write_label] spawn_blocking error.");
+    return match result {
+        Ok(()) => String::from("OK"),
+        Err(err) => err
+    };
+}
+
+async fn uds_scan(Path((tx_id, rx_id)): Path<(String, String)>, Query(query): Query<UdsQuery>, State(state): State<AppState>, Json(scan): Json<ScanConfig>) -> Result<Json<ScanReport>, (StatusCode, String)> {
+    let (node, config) = query.config(&tx_id, &rx_id)?;
+    let report = spawn_blocking(move || {
+        state.emulator.uds_scan(&node, config, &scan)
+    }).await.expect("[qecu::api::uds_scan] spawn_blocking error.");
+    Ok(Json(report))
+}
+
 pub async fn bootstrap(bind_addr: String, emulator: Emulator<'static>) {
     let app = Router::new()
                                 .route("/emit/{event_type}", post(emit))
                                 .route("/interceptor/hooks/code", get(interceptor_get_code_hooks))
                                 .route("/interceptor/hooks/events", get(interceptor_get_event_hooks))
+                                .route("/interceptor/hooks/memory", get(interceptor_get_mem_hooks))
+                                .route("/trace/start", post(trace_start))
+                                .route("/trace/stop", post(trace_stop))
+                                .route("/trace/raw", get(trace_get_raw))
+                                .route("/trace/text", get(trace_get_text))
+                                .route("/disas", get(disas))
+                                .route("/disas/listing", get(disas_listing))
+                                .route("/pcode", get(pcode))
+                                .route("/taint/memory", post(taint_memory))
+                                .route("/taint/clear", post(taint_clear))
+                                .route("/taint/reports", get(taint_get_reports))
+                                .route("/crashes", get(get_crashes))
+                                .route("/cores", get(get_cores))
+                                .route("/interrupt/{priority}", post(raise_interrupt))
+                                .route("/trap/{class}/{tin}", post(raise_trap))
+                                .route("/can/{node}", post(can_inject))
+                                .route("/isotp/{tx_id}/{rx_id}", post(isotp))
+                                .route("/uds/{tx_id}/{rx_id}", post(uds))
+                                .route("/uds/{tx_id}/{rx_id}/scan", post(uds_scan))
+                                .route("/sfr/{symbol}", get(read_sfr))
+                                .route("/labels", get(get_labels))
+                                .route("/label/{name}", get(read_label).post(write_label))
+                                .route("/coverage", get(coverage_get_blocks))
+                                .route("/coverage/start", post(coverage_start))
+                                .route("/coverage/stop", post(coverage_stop))
+                                .route("/coverage/reset", post(coverage_reset))
+                                .route("/coverage/drcov", get(coverage_get_drcov))
+                                .route("/coverage/lcov", get(coverage_get_lcov))
                             .with_state(AppState { emulator: emulator});
     let listener = tokio::net::TcpListener::bind(bind_addr).await.unwrap();
     axum::serve(listener, app).await.unwrap();
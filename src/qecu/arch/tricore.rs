@@ -1,12 +1,79 @@
 use symmap::SymMap;
-use tc375::TricoreCpuTc375;
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock, RwLock};
+use crate::qecu::regmap::{FieldValue, RegMap};
 
-mod tc375;
-mod symmap;
+pub mod symmap;
+pub mod derivative;
+pub mod interrupt;
+pub mod asclin;
+pub mod ir;
+pub mod mcmcan;
+pub mod stm;
+pub mod cpu;
+pub mod lift;
 
-pub fn get_cpu_symbol(cpu: String, symbol: String) -> u32 {
-    match cpu.as_str() {
-        "tc375" => Box::new(TricoreCpuTc375 { }).get_symbol(symbol),
-        _ => 0
+/// Register space offsets of the core registers in the TriCore Sleigh spec (tricore.sinc).
+pub const SLEIGH_REGISTERS: [(&str, u64); 32] = [
+    ("D0", 0xFF00), ("D1", 0xFF04), ("D2", 0xFF08), ("D3", 0xFF0C),
+    ("D4", 0xFF10), ("D5", 0xFF14), ("D6", 0xFF18), ("D7", 0xFF1C),
+    ("D8", 0xFF20), ("D9", 0xFF24), ("D10", 0xFF28), ("D11", 0xFF2C),
+    ("D12", 0xFF30), ("D13", 0xFF34), ("D14", 0xFF38), ("D15", 0xFF3C),
+    ("A0", 0xFF80), ("A1", 0xFF84), ("A2", 0xFF88), ("A3", 0xFF8C),
+    ("A4", 0xFF90), ("A5", 0xFF94), ("A6", 0xFF98), ("A7", 0xFF9C),
+    ("A8", 0xFFA0), ("A9", 0xFFA4), ("A10", 0xFFA8), ("A11", 0xFFAC),
+    ("A12", 0xFFB0), ("A13", 0xFFB4), ("A14", 0xFFB8), ("A15", 0xFFBC)
+];
+
+pub fn get_sleigh_register(name: &str) -> Option<u64> {
+    SLEIGH_REGISTERS.iter()
+        .find(|(reg, _)| reg.eq_ignore_ascii_case(name))
+        .map(|(_, offset)| *offset)
+}
+
+/// Register maps per CPU, the built-in ones are parsed on first use.
+static REGMAPS: OnceLock<RwLock<HashMap<String, Arc<RegMap>>>> = OnceLock::new();
+
+/// Built-in `_reg.h` and `_regdef.h` contents of `cpu`.
+fn builtin_regmap(cpu: &str) -> Option<[&'static str; 2]> {
+    match cpu {
+        "tc375" => Some([include_str!("tricore/regmaps/tc375_reg.h"), include_str!("tricore/regmaps/tc375_regdef.h")]),
+        _ => None
+    }
+}
+
+/// Registers (or replaces) the register map of `cpu`.
+pub fn set_cpu_regmap(cpu: &str, map: RegMap) {
+    let regmaps = REGMAPS.get_or_init(|| RwLock::new(HashMap::new()));
+    regmaps.write().unwrap().insert(cpu.to_lowercase(), Arc::new(map));
+}
+
+pub fn get_cpu_regmap(cpu: &str) -> Option<Arc<RegMap>> {
+    let cpu = cpu.to_lowercase();
+    let regmaps = REGMAPS.get_or_init(|| RwLock::new(HashMap::new()));
+    if let Some(map) = regmaps.read().unwrap().get(&cpu) {
+        return Some(map.clone());
+    }
+    let map = Arc::new(RegMap::from_headers(&builtin_regmap(&cpu)?));
+    regmaps.write().unwrap().entry(cpu).or_insert(map.clone());
+    return Some(map);
+}
+
+pub fn get_cpu_symbol(cpu: String, symbol: String) -> Result<u32, String> {
+    match get_cpu_regmap(&cpu) {
+        Some(map) => map.get_symbol(&symbol).map(|address| address as u32),
+        None => Err(format!("unknown CPU {}", cpu))
+    }
+}
+
+/// `MODULE_REG` name of the register at `address`.
+pub fn get_cpu_register_name(cpu: &str, address: u64) -> Option<String> {
+    get_cpu_regmap(cpu).and_then(|map| map.get_name(address))
+}
+
+pub fn decode_cpu_register(cpu: &str, symbol: &str, value: u64) -> Result<Vec<FieldValue>, String> {
+    match get_cpu_regmap(cpu) {
+        Some(map) => map.decode(symbol, value),
+        None => Err(format!("unknown CPU {}", cpu))
     }
 }
\ No newline at end of file
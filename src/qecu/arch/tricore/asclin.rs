@@ -0,0 +1,275 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::qecu::peripheral::{BusContext, Peripheral, PeripheralState};
+use crate::qecu::serial::SerialHost;
+
+// ASCLIN register offsets (TC3xx).
+const ASCLIN_TXFIFOCON: u64 = 0x0C;
+const ASCLIN_RXFIFOCON: u64 = 0x10;
+const ASCLIN_FLAGS: u64 = 0x34;
+const ASCLIN_FLAGSSET: u64 = 0x38;
+const ASCLIN_FLAGSCLEAR: u64 = 0x3C;
+const ASCLIN_FLAGSENABLE: u64 = 0x40;
+const ASCLIN_TXDATA: u64 = 0x44;
+const ASCLIN_RXDATA: u64 = 0x48;
+const ASCLIN_CSR: u64 = 0x4C;
+const ASCLIN_RXDATAD: u64 = 0x50;
+const ASCLIN_SIZE: u64 = 0x100;
+
+const FIFO_SIZE: usize = 16;
+
+// TXFIFOCON / RXFIFOCON fields.
+const FIFOCON_FLUSH: u32 = 1 << 0;
+const FIFOCON_EN: u32 = 1 << 1;
+const FIFOCON_WIDTH_SHIFT: u32 = 6;
+const FIFOCON_INTLEVEL_SHIFT: u32 = 8;
+const FIFOCON_FILL_SHIFT: u32 = 16;
+
+// FLAGS bits, FLAGSSET/FLAGSCLEAR/FLAGSENABLE use the same positions.
+const FLAGS_PE: u32 = 1 << 16;
+const FLAGS_TC: u32 = 1 << 17;
+const FLAGS_FE: u32 = 1 << 18;
+const FLAGS_RFO: u32 = 1 << 26;
+const FLAGS_RFU: u32 = 1 << 27;
+const FLAGS_RFL: u32 = 1 << 28;
+const FLAGS_TFO: u32 = 1 << 30;
+const FLAGS_TFL: u32 = 1 << 31;
+const FLAGS_ERR: u32 = FLAGS_PE | FLAGS_FE | FLAGS_RFO | FLAGS_RFU | FLAGS_TFO;
+
+const CSR_CON: u32 = 1 << 31;
+
+/// Emulated time between two received bytes, 10 bits at 115200 baud.
+const RX_BYTE_TIME: u64 = 86_800;
+
+/// ASCLIN in ASC (UART) mode. Transmission is instantaneous: the TX FIFO is
+/// drained to the host on every TXDATA write.
+pub struct Asclin {
+    name: String,
+    base: u64,
+    src_tx: u64,
+    src_rx: u64,
+    src_err: u64,
+    host: SerialHost,
+    txfifocon: u32,
+    rxfifocon: u32,
+    flags: u32,
+    enable: u32,
+    csr: u32,
+    rx: VecDeque<u8>,
+    next_rx: u64,
+    regs: HashMap<u64, u32>
+}
+
+/// Registers and RX FIFO of an `Asclin`, without its host side.
+#[derive(Clone)]
+struct AsclinState {
+    txfifocon: u32,
+    rxfifocon: u32,
+    flags: u32,
+    enable: u32,
+    csr: u32,
+    rx: VecDeque<u8>,
+    next_rx: u64,
+    regs: HashMap<u64, u32>
+}
+
+fn fifo_width(fifocon: u32) -> usize {
+    match (fifocon >> FIFOCON_WIDTH_SHIFT) & 0x3 {
+        2 => 2,
+        3 => 4,
+        _ => 1
+    }
+}
+
+impl Asclin {
+    /// `src` are the TX, RX and ERR SRC registers.
+    pub fn new(name: &str, base: u64, src: [u64; 3], host: SerialHost) -> Asclin {
+        return Asclin {
+            name: name.to_string(),
+            base: base,
+            src_tx: src[0],
+            src_rx: src[1],
+            src_err: src[2],
+            host: host,
+            txfifocon: 0,
+            rxfifocon: 0,
+            flags: FLAGS_TFL | FLAGS_TC,
+            enable: 0,
+            csr: 0,
+            rx: VecDeque::new(),
+            next_rx: 0,
+            regs: HashMap::new()
+        };
+    }
+
+    /// Requests the service requests of the flags that became active.
+    fn update(&mut self, flags: u32, enable: u32, ctx: &mut BusContext) {
+        let raised = (self.flags & self.enable) & !(flags & enable);
+        if raised & (FLAGS_TFL | FLAGS_TC) != 0 {
+            ctx.request(self.src_tx);
+        }
+        if raised & FLAGS_RFL != 0 {
+            ctx.request(self.src_rx);
+        }
+        if raised & FLAGS_ERR != 0 {
+            ctx.request(self.src_err);
+        }
+    }
+
+    fn rx_level(&self) -> bool {
+        let level = ((self.rxfifocon >> FIFOCON_INTLEVEL_SHIFT) & 0xF).max(1) as usize;
+        return self.rx.len() >= level;
+    }
+
+    fn transmit(&mut self, value: u32, size: usize) {
+        if self.txfifocon & FIFOCON_EN == 0 {
+            return;
+        }
+        let width = fifo_width(self.txfifocon).min(size.max(1));
+        let data = value.to_le_bytes()[..width].to_vec();
+        self.host.send(data);
+    }
+
+    fn receive(&mut self) -> u32 {
+        let width = fifo_width(self.rxfifocon);
+        if self.rx.is_empty() {
+            self.flags |= FLAGS_RFU;
+            return 0;
+        }
+        let mut value = 0u32;
+        for index in 0..width {
+            if let Some(byte) = self.rx.pop_front() {
+                value |= (byte as u32) << (index * 8);
+            }
+        }
+        if !self.rx_level() {
+            self.flags &= !FLAGS_RFL;
+        }
+        return value;
+    }
+}
+
+impl Peripheral for Asclin {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn base(&self) -> u64 {
+        self.base
+    }
+
+    fn size(&self) -> u64 {
+        ASCLIN_SIZE
+    }
+
+    fn read(&mut self, offset: u64, _size: usize, ctx: &mut BusContext) -> u64 {
+        let register = offset & !0x3;
+        let (flags, enable) = (self.flags, self.enable);
+        let value = match register {
+            ASCLIN_TXFIFOCON => self.txfifocon & !FIFOCON_FLUSH,
+            ASCLIN_RXFIFOCON => (self.rxfifocon & !FIFOCON_FLUSH) | ((self.rx.len() as u32) << FIFOCON_FILL_SHIFT),
+            ASCLIN_FLAGS => self.flags,
+            ASCLIN_FLAGSSET | ASCLIN_FLAGSCLEAR => 0,
+            ASCLIN_FLAGSENABLE => self.enable,
+            ASCLIN_RXDATA => self.receive(),
+            ASCLIN_RXDATAD => self.rx.front().copied().unwrap_or(0) as u32,
+            ASCLIN_CSR => match self.csr & 0x1F {
+                0 => self.csr,
+                _ => self.csr | CSR_CON
+            },
+            _ => self.regs.get(&register).copied().unwrap_or(0)
+        };
+        self.update(flags, enable, ctx);
+        return (value >> ((offset & 0x3) * 8)) as u64;
+    }
+
+    fn write(&mut self, offset: u64, size: usize, value: u64, ctx: &mut BusContext) {
+        let register = offset & !0x3;
+        let shift = (offset & 0x3) * 8;
+        let mask = if size >= 4 { 0xFFFFFFFF } else { ((1u32 << (size * 8)) - 1) << shift };
+        let value = (value as u32) << shift;
+        let merge = |current: u32| (current & !mask) | (value & mask);
+        let (flags, enable) = (self.flags, self.enable);
+        match register {
+            ASCLIN_TXFIFOCON => self.txfifocon = merge(self.txfifocon) & 0xFFF,
+            ASCLIN_RXFIFOCON => {
+                self.rxfifocon = merge(self.rxfifocon) & 0x80000FFF;
+                if self.rxfifocon & FIFOCON_FLUSH != 0 {
+                    self.rx.clear();
+                    self.flags &= !FLAGS_RFL;
+                }
+            }
+            ASCLIN_FLAGSSET => self.flags |= value & mask,
+            ASCLIN_FLAGSCLEAR => self.flags &= !(value & mask),
+            ASCLIN_FLAGSENABLE => self.enable = merge(self.enable),
+            ASCLIN_TXDATA => {
+                // The FIFO goes above its level until drained, which raises TFL/TC again.
+                self.flags &= !(FLAGS_TFL | FLAGS_TC);
+                self.update(flags, enable, ctx);
+                let (flags, enable) = (self.flags, self.enable);
+                self.transmit(value >> shift, size);
+                self.flags |= FLAGS_TFL | FLAGS_TC;
+                self.update(flags, enable, ctx);
+                return;
+            }
+            ASCLIN_RXDATA | ASCLIN_RXDATAD | ASCLIN_FLAGS => {}
+            ASCLIN_CSR => self.csr = merge(self.csr) & 0x1F,
+            _ => {
+                let current = self.regs.get(&register).copied().unwrap_or(0);
+                self.regs.insert(register, merge(current));
+            }
+        }
+        self.update(flags, enable, ctx);
+    }
+
+    fn tick(&mut self, now: u64, ctx: &mut BusContext) {
+        // The clock goes back when a snapshot is restored.
+        if self.next_rx > now + RX_BYTE_TIME {
+            self.next_rx = now;
+        }
+        if now < self.next_rx || self.rxfifocon & FIFOCON_EN == 0 {
+            return;
+        }
+        let byte = match self.host.try_recv() {
+            Some(byte) => byte,
+            None => return
+        };
+        self.next_rx = now + RX_BYTE_TIME;
+        let (flags, enable) = (self.flags, self.enable);
+        if self.rx.len() >= FIFO_SIZE {
+            self.flags |= FLAGS_RFO;
+        } else {
+            self.rx.push_back(byte);
+        }
+        if self.rx_level() {
+            self.flags |= FLAGS_RFL;
+        }
+        self.update(flags, enable, ctx);
+    }
+
+    fn save(&self) -> PeripheralState {
+        return Box::new(AsclinState {
+            txfifocon: self.txfifocon,
+            rxfifocon: self.rxfifocon,
+            flags: self.flags,
+            enable: self.enable,
+            csr: self.csr,
+            rx: self.rx.clone(),
+            next_rx: self.next_rx,
+            regs: self.regs.clone()
+        });
+    }
+
+    fn restore(&mut self, state: &PeripheralState) {
+        if let Some(state) = state.downcast_ref::<AsclinState>() {
+            self.txfifocon = state.txfifocon;
+            self.rxfifocon = state.rxfifocon;
+            self.flags = state.flags;
+            self.enable = state.enable;
+            self.csr = state.csr;
+            self.rx = state.rx.clone();
+            self.next_rx = state.next_rx;
+            self.regs = state.regs.clone();
+        }
+    }
+}
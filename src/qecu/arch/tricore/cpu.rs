@@ -0,0 +1,128 @@
+use std::collections::HashMap;
+
+use crate::qecu::peripheral::{BusContext, Peripheral, PeripheralState};
+
+// CSFR offsets relative to DBGSR (TC2xx and TC3xx).
+const CPU_DBGSR: u64 = 0x000;
+const CPU_PC: u64 = 0x108;
+const CPU_SYSCON: u64 = 0x114;
+const CPU_CORE_ID: u64 = 0x11C;
+const CPU_CSFR_SIZE: u64 = 0x200;
+
+// DBGSR.HALT reads 01 when halted, writing 01 halts the core and 10 runs it.
+const DBGSR_HALT_SHIFT: u32 = 1;
+const DBGSR_HALT: u32 = 0x3 << DBGSR_HALT_SHIFT;
+const HALT_HALT: u32 = 0x1;
+const HALT_RUN: u32 = 0x2;
+
+// SYSCON.BHALT is set at reset for the cores other than CPU0, clearing it
+// starts the core (IfxCpu_startCore on the TC3xx).
+const SYSCON_BHALT: u32 = 1 << 24;
+
+/// Debug status, boot halt and PC of a core as seen through its CSFR window,
+/// used by the firmware of CPU0 to start the other cores.
+#[derive(Clone)]
+pub struct CpuControl {
+    core: usize,
+    base: u64,
+    halted: bool,
+    dbgsr: u32,
+    syscon: u32,
+    pc: u32,
+    regs: HashMap<u64, u32>
+}
+
+impl CpuControl {
+    /// `base` is the address of the DBGSR of `core`, only CPU0 runs at reset.
+    pub fn new(core: usize, base: u64) -> CpuControl {
+        return CpuControl {
+            core: core,
+            base: base,
+            halted: core != 0,
+            dbgsr: 0,
+            syscon: if core != 0 { SYSCON_BHALT } else { 0 },
+            pc: 0,
+            regs: HashMap::new()
+        };
+    }
+
+    fn start(&mut self, ctx: &mut BusContext) {
+        if self.halted {
+            self.halted = false;
+            ctx.start_core(self.core, (self.pc & !0x1) as u64);
+        }
+    }
+}
+
+impl Peripheral for CpuControl {
+    fn name(&self) -> String {
+        format!("CPU{}", self.core)
+    }
+
+    fn base(&self) -> u64 {
+        self.base
+    }
+
+    fn size(&self) -> u64 {
+        CPU_CSFR_SIZE
+    }
+
+    fn read(&mut self, offset: u64, _size: usize, _ctx: &mut BusContext) -> u64 {
+        let register = offset & !0x3;
+        let value = match register {
+            CPU_DBGSR => (self.dbgsr & !DBGSR_HALT) | ((self.halted as u32) << DBGSR_HALT_SHIFT),
+            CPU_PC => self.pc,
+            CPU_SYSCON => self.syscon,
+            CPU_CORE_ID => self.core as u32,
+            _ => self.regs.get(&register).copied().unwrap_or(0)
+        };
+        return (value >> ((offset & 0x3) * 8)) as u64;
+    }
+
+    fn write(&mut self, offset: u64, size: usize, value: u64, ctx: &mut BusContext) {
+        let register = offset & !0x3;
+        let shift = (offset & 0x3) * 8;
+        let mask = if size >= 4 { 0xFFFFFFFF } else { ((1u32 << (size * 8)) - 1) << shift };
+        let value = (value as u32) << shift;
+        let merge = |current: u32| (current & !mask) | (value & mask);
+        match register {
+            CPU_DBGSR => {
+                self.dbgsr = merge(self.dbgsr) & !DBGSR_HALT;
+                if mask & DBGSR_HALT == 0 {
+                    return;
+                }
+                match (value & DBGSR_HALT) >> DBGSR_HALT_SHIFT {
+                    HALT_RUN => self.start(ctx),
+                    HALT_HALT if !self.halted => {
+                        self.halted = true;
+                        ctx.halt_core(self.core);
+                    }
+                    _ => {}
+                }
+            }
+            CPU_PC => self.pc = merge(self.pc),
+            CPU_SYSCON => {
+                let bhalt = self.syscon & SYSCON_BHALT;
+                self.syscon = merge(self.syscon);
+                if bhalt != 0 && self.syscon & SYSCON_BHALT == 0 {
+                    self.start(ctx);
+                }
+            }
+            CPU_CORE_ID => {}
+            _ => {
+                let current = self.regs.get(&register).copied().unwrap_or(0);
+                self.regs.insert(register, merge(current));
+            }
+        }
+    }
+
+    fn save(&self) -> PeripheralState {
+        Box::new(self.clone())
+    }
+
+    fn restore(&mut self, state: &PeripheralState) {
+        if let Some(state) = state.downcast_ref::<CpuControl>() {
+            *self = state.clone();
+        }
+    }
+}
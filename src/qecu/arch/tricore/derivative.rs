@@ -0,0 +1,187 @@
+use crate::utils::workflow::WorkflowMemMap;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Family {
+    Tc2xx,
+    Tc3xx
+}
+
+/// PCXI and ICR fields of the core, which moved between TriCore 1.6 (TC2xx)
+/// and TriCore 1.6.2 (TC3xx).
+#[derive(Clone, Copy, Debug)]
+pub struct ContextLayout {
+    pub pcxi_ul: u64,
+    pub pcxi_pie: u64,
+    pub pcxi_pcpn_shift: u64,
+    pub icr_ie: u64
+}
+
+impl Family {
+    pub fn context_layout(&self) -> ContextLayout {
+        match self {
+            Family::Tc2xx => ContextLayout { pcxi_ul: 1 << 22, pcxi_pie: 1 << 23, pcxi_pcpn_shift: 24, icr_ie: 1 << 8 },
+            Family::Tc3xx => ContextLayout { pcxi_ul: 1 << 20, pcxi_pie: 1 << 21, pcxi_pcpn_shift: 22, icr_ie: 1 << 15 }
+        }
+    }
+}
+
+/// An AURIX derivative: memory sizes, cores and modelled peripherals.
+#[derive(Clone, Debug)]
+pub struct Derivative {
+    pub name: &'static str,
+    pub family: Family,
+    pub cores: usize,
+    pub pflash: u64,
+    pub dflash: u64,
+    /// DSPR and PSPR sizes, per core.
+    pub dspr: &'static [u64],
+    pub pspr: &'static [u64],
+    /// LMU and DLMU RAM at 0x90000000.
+    pub lmu: u64,
+    pub stm: usize,
+    pub asclin: usize,
+    /// MCMCAN modules, TC2xx have a MultiCAN which is not modelled.
+    pub can: &'static [&'static str]
+}
+
+pub const PFLASH_CACHED: u64 = 0x80000000;
+pub const PFLASH_UNCACHED: u64 = 0xA0000000;
+pub const DFLASH: u64 = 0xAF000000;
+pub const LMU_CACHED: u64 = 0x90000000;
+pub const LMU_UNCACHED: u64 = 0xB0000000;
+pub const SFR: u64 = 0xF0000000;
+pub const SFR_SIZE: u64 = 0x10000000;
+/// Local view of the DSPR and PSPR of the executing core.
+pub const DSPR_LOCAL: u64 = 0xD0000000;
+pub const PSPR_LOCAL: u64 = 0xC0000000;
+/// Global DSPR of CPU0..CPU5, the PSPR follows at +0x100000.
+const CORE_SEGMENTS: [u64; 6] = [0x70000000, 0x60000000, 0x50000000, 0x40000000, 0x30000000, 0x10000000];
+
+pub const DERIVATIVES: [Derivative; 5] = [
+    Derivative {
+        name: "tc397", family: Family::Tc3xx, cores: 6,
+        pflash: 16 << 20, dflash: 1 << 20,
+        dspr: &[240 << 10, 240 << 10, 96 << 10, 96 << 10, 96 << 10, 96 << 10],
+        pspr: &[64 << 10; 6],
+        lmu: 0x120000, stm: 6, asclin: 12, can: &["CAN0", "CAN1", "CAN2"]
+    },
+    Derivative {
+        name: "tc387", family: Family::Tc3xx, cores: 4,
+        pflash: 10 << 20, dflash: 1 << 20,
+        dspr: &[240 << 10; 4],
+        pspr: &[64 << 10; 4],
+        lmu: 0xC0000, stm: 4, asclin: 12, can: &["CAN0", "CAN1", "CAN2"]
+    },
+    Derivative {
+        name: "tc375", family: Family::Tc3xx, cores: 3,
+        pflash: 6 << 20, dflash: 256 << 10,
+        dspr: &[240 << 10, 240 << 10, 96 << 10],
+        pspr: &[64 << 10; 3],
+        lmu: 0x30000, stm: 3, asclin: 12, can: &["CAN0", "CAN1"]
+    },
+    Derivative {
+        name: "tc277", family: Family::Tc2xx, cores: 3,
+        pflash: 4 << 20, dflash: 384 << 10,
+        dspr: &[112 << 10, 120 << 10, 120 << 10],
+        pspr: &[24 << 10, 32 << 10, 32 << 10],
+        lmu: 32 << 10, stm: 3, asclin: 4, can: &[]
+    },
+    Derivative {
+        name: "tc234", family: Family::Tc2xx, cores: 1,
+        pflash: 2 << 20, dflash: 128 << 10,
+        dspr: &[184 << 10],
+        pspr: &[8 << 10],
+        lmu: 0, stm: 1, asclin: 2, can: &[]
+    }
+];
+
+pub fn get_derivative(name: &str) -> Option<&'static Derivative> {
+    DERIVATIVES.iter().find(|derivative| derivative.name.eq_ignore_ascii_case(name))
+}
+
+impl Derivative {
+    /// Global address of the DSPR of `core`, its PSPR is at +0x100000.
+    pub fn core_segment(&self, core: usize) -> u64 {
+        CORE_SEGMENTS[core]
+    }
+
+    /// Start address of images without an entry point: the uncached PFlash,
+    /// where the boot mode headers point by default.
+    pub fn boot_address(&self) -> u64 {
+        PFLASH_UNCACHED
+    }
+
+    /// TOS value routing a service request to `core`, 1 being the DMA on the TC3xx.
+    pub fn tos(&self, core: usize) -> u8 {
+        match (self.family, core) {
+            (Family::Tc3xx, 0) => 0,
+            (Family::Tc3xx, core) => core as u8 + 1,
+            (Family::Tc2xx, core) => core as u8
+        }
+    }
+
+    /// Core a service request with this TOS is routed to, None for the DMA
+    /// and values without a core.
+    pub fn tos_core(&self, tos: u8) -> Option<usize> {
+        (0..self.cores).find(|core| self.tos(*core) == tos)
+    }
+
+    /// Memory map seen by `core`: PFlash, DFlash, scratchpads, LMU and SFRs.
+    /// The uncached PFlash and LMU and the local scratchpads of `core` are
+    /// aliases.
+    pub fn memory_map(&self, core: usize) -> Vec<WorkflowMemMap> {
+        let region = |from: u64, size: u64, flags: &str| WorkflowMemMap { from: from, size: size as usize, flags: String::from(flags), alias: None };
+        let alias = |from: u64, size: u64, flags: &str, target: u64| WorkflowMemMap { from: from, size: size as usize, flags: String::from(flags), alias: Some(target) };
+        let mut regions = vec![
+            region(PFLASH_CACHED, self.pflash, "RX"),
+            alias(PFLASH_UNCACHED, self.pflash, "RX", PFLASH_CACHED),
+            region(DFLASH, self.dflash, "RW"),
+            alias(DSPR_LOCAL, self.dspr[core], "RWX", self.core_segment(core)),
+            alias(PSPR_LOCAL, self.pspr[core], "RWX", self.core_segment(core) + 0x100000)
+        ];
+        for index in 0..self.cores {
+            regions.push(region(self.core_segment(index), self.dspr[index], "RWX"));
+            regions.push(region(self.core_segment(index) + 0x100000, self.pspr[index], "RWX"));
+        }
+        if self.lmu > 0 {
+            regions.push(region(LMU_CACHED, self.lmu, "RWX"));
+            regions.push(alias(LMU_UNCACHED, self.lmu, "RWX", LMU_CACHED));
+        }
+        regions.push(region(SFR, SFR_SIZE, "RW"));
+        regions.sort_by_key(|region| region.from);
+        return regions;
+    }
+}
+
+/// Adds `regions` to `builtin`, cutting the parts of the built-in regions
+/// they overlap.
+pub fn merge_memory_map(builtin: Vec<WorkflowMemMap>, regions: &[WorkflowMemMap]) -> Vec<WorkflowMemMap> {
+    let mut merged: Vec<WorkflowMemMap> = Vec::new();
+    for map in builtin {
+        let mut parts = vec![(map.from, map.from + map.size as u64)];
+        for other in regions {
+            let (begin, end) = (other.from, other.from + other.size as u64);
+            let mut remaining = Vec::new();
+            for (from, to) in parts {
+                if end <= from || begin >= to {
+                    remaining.push((from, to));
+                    continue;
+                }
+                if from < begin {
+                    remaining.push((from, begin));
+                }
+                if end < to {
+                    remaining.push((end, to));
+                }
+            }
+            parts = remaining;
+        }
+        for (from, to) in parts {
+            let alias = map.alias.map(|target| target + (from - map.from));
+            merged.push(WorkflowMemMap { from: from, size: (to - from) as usize, flags: map.flags.clone(), alias: alias });
+        }
+    }
+    merged.extend(regions.iter().cloned());
+    merged.sort_by_key(|region| region.from);
+    return merged;
+}
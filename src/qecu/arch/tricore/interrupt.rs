@@ -0,0 +1,163 @@
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+use crate::qecu::emulator::Emulator;
+
+// Core register fields common to TriCore 1.6 and 1.6.2, the others come
+// from the ContextLayout of the derivative.
+const PCXI_LINK: u64 = 0xFFFFF;
+const ICR_CCPN: u64 = 0xFF;
+const PSW_IS: u64 = 1 << 9;
+const BIV_VSS: u64 = 1;
+
+/// Traps and interrupts waiting for the next instruction boundary, traps by
+/// core and interrupts by the TOS of the core they are routed to.
+#[derive(Clone, Debug, Default)]
+pub struct InterruptController {
+    traps: BTreeMap<usize, VecDeque<(u8, u32)>>,
+    interrupts: BTreeMap<u8, BTreeSet<u8>>
+}
+
+impl InterruptController {
+    pub fn raise_trap(&mut self, core: usize, class: u8, tin: u32) {
+        self.traps.entry(core).or_default().push_back((class & 0x7, tin));
+    }
+
+    pub fn raise_interrupt(&mut self, tos: u8, priority: u8) {
+        if priority != 0 {
+            self.interrupts.entry(tos).or_default().insert(priority);
+        }
+    }
+
+    pub fn clear_interrupt(&mut self, tos: u8, priority: u8) {
+        if let Some(interrupts) = self.interrupts.get_mut(&tos) {
+            interrupts.remove(&priority);
+        }
+    }
+
+    pub fn has_pending(&self) -> bool {
+        self.traps.values().any(|traps| !traps.is_empty()) || self.interrupts.values().any(|interrupts| !interrupts.is_empty())
+    }
+
+    pub fn get_pending(&self, tos: u8) -> Vec<u8> {
+        self.interrupts.get(&tos).map(|interrupts| interrupts.iter().rev().copied().collect()).unwrap_or_default()
+    }
+
+    fn next_trap(&mut self, core: usize) -> Option<(u8, u32)> {
+        self.traps.get_mut(&core)?.pop_front()
+    }
+
+    fn highest(&self, tos: u8) -> Option<u8> {
+        self.interrupts.get(&tos)?.iter().next_back().copied()
+    }
+}
+
+fn csa_address(link: u64) -> u64 {
+    ((link & 0xF0000) << 12) | ((link & 0xFFFF) << 6)
+}
+
+fn read_word(emu: &Emulator<'static>, address: u64) -> u64 {
+    let data = emu.read_memory(address, 4);
+    return u32::from_le_bytes(data[..4].try_into().unwrap()) as u64;
+}
+
+/// Saves the upper context in the first free CSA and links it in PCXI,
+/// recording the current CCPN/IE as the previous priority state.
+fn save_upper_context(emu: &Emulator<'static>) -> bool {
+    let fcx = emu.read_register(String::from("FCX"));
+    if fcx & PCXI_LINK == 0 {
+        print!("[tricore::interrupt] Free context list depleted (FCX = 0)\n");
+        return false;
+    }
+    let csa = csa_address(fcx);
+    let next = read_word(emu, csa);
+    let mut data: Vec<u8> = Vec::new();
+    for reg in ["PCXI", "PSW", "A10", "A11", "D8", "D9", "D10", "D11",
+                "A12", "A13", "A14", "A15", "D12", "D13", "D14", "D15"] {
+        data.extend_from_slice(&(emu.read_register(String::from(reg)) as u32).to_le_bytes());
+    }
+    emu.write_memory(csa, data);
+
+    let layout = emu.derivative.family.context_layout();
+    let icr = emu.read_register(String::from("ICR"));
+    let mut pcxi = (fcx & PCXI_LINK) | layout.pcxi_ul | ((icr & ICR_CCPN) << layout.pcxi_pcpn_shift);
+    if icr & layout.icr_ie != 0 {
+        pcxi |= layout.pcxi_pie;
+    }
+    emu.write_register(String::from("PCXI"), pcxi);
+    emu.write_register(String::from("FCX"), (fcx & !PCXI_LINK) | (next & PCXI_LINK));
+    return true;
+}
+
+/// Common trap/interrupt entry: supervisor mode on the interrupt stack.
+fn enter_handler(emu: &Emulator<'static>, return_address: u64) {
+    let psw = emu.read_register(String::from("PSW"));
+    if psw & PSW_IS == 0 {
+        let isp = emu.read_register(String::from("ISP"));
+        emu.write_register(String::from("A10"), isp);
+    }
+    // IO = supervisor, IS = 1, CDE = 1, CDC = 0, GW = 0, PRS = 0, S = 0.
+    emu.write_register(String::from("PSW"), (psw & 0xFFFF0000) | (2 << 10) | PSW_IS | (1 << 7));
+    emu.write_register(String::from("A11"), return_address);
+}
+
+pub fn enter_trap(emu: &Emulator<'static>, class: u8, tin: u32) -> Option<u64> {
+    let pc = emu.read_register(String::from("PC"));
+    if !save_upper_context(emu) {
+        return None;
+    }
+    enter_handler(emu, pc);
+    let icr_ie = emu.derivative.family.context_layout().icr_ie;
+    let icr = emu.read_register(String::from("ICR"));
+    emu.write_register(String::from("ICR"), icr & !icr_ie);
+    emu.write_register(String::from("D15"), tin as u64);
+    let vector = (emu.read_register(String::from("BTV")) & !0xFF) | ((class as u64) << 5);
+    emu.set_pc(vector);
+    print!("[tricore::interrupt] Trap class {} TIN {} from {:#010x} to {:#010x}\n", class, tin, pc, vector);
+    return Some(vector);
+}
+
+pub fn enter_interrupt(emu: &Emulator<'static>, priority: u8) -> Option<u64> {
+    let pc = emu.read_register(String::from("PC"));
+    if !save_upper_context(emu) {
+        return None;
+    }
+    enter_handler(emu, pc);
+    let icr_ie = emu.derivative.family.context_layout().icr_ie;
+    let icr = emu.read_register(String::from("ICR"));
+    emu.write_register(String::from("ICR"), (icr & !(icr_ie | ICR_CCPN)) | priority as u64);
+    let biv = emu.read_register(String::from("BIV"));
+    let spacing = if biv & BIV_VSS != 0 { 3 } else { 5 };
+    let vector = (biv & !BIV_VSS) | ((priority as u64) << spacing);
+    emu.set_pc(vector);
+    return Some(vector);
+}
+
+/// Delivers to the current core its pending trap, or the highest interrupt
+/// pending for its TOS (raised directly or routed by the Interrupt Router)
+/// if enabled and above the current CPU priority. Returns the vector entered.
+pub fn deliver(emu: &Emulator<'static>) -> Option<u64> {
+    let core = emu.current_core();
+    let trap = emu.irq.lock().unwrap().next_trap(core);
+    if let Some((class, tin)) = trap {
+        emu.crash.lock().unwrap().expect_trap(emu.read_register(String::from("BTV")) & !0xFF | ((class as u64) << 5));
+        return enter_trap(emu, class, tin);
+    }
+    let tos = emu.derivative.tos(core);
+    let raised = emu.irq.lock().unwrap().highest(tos);
+    let routed = emu.peripherals.lock().unwrap().arbitrate(tos);
+    let priority = match (raised, routed) {
+        (Some(raised), Some((_, routed))) => raised.max(routed),
+        (Some(raised), None) => raised,
+        (None, Some((_, routed))) => routed,
+        (None, None) => return None
+    };
+    let icr = emu.read_register(String::from("ICR"));
+    if icr & emu.derivative.family.context_layout().icr_ie == 0 || priority as u64 <= icr & ICR_CCPN {
+        return None;
+    }
+    match routed {
+        Some((src, routed)) if routed == priority => emu.peripherals.lock().unwrap().acknowledge(src),
+        _ => emu.irq.lock().unwrap().clear_interrupt(tos, priority)
+    }
+    return enter_interrupt(emu, priority);
+}
@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+
+use crate::qecu::peripheral::{BusContext, Peripheral, PeripheralState, ServiceRequestSink};
+
+// SRC register fields (TC3xx).
+const SRC_SRPN: u32 = 0xFF;
+const SRC_SRE: u32 = 1 << 10;
+const SRC_TOS_SHIFT: u32 = 11;
+const SRC_TOS: u32 = 0x7 << SRC_TOS_SHIFT;
+const SRC_ECC: u32 = 0x1F << 16;
+const SRC_SRR: u32 = 1 << 24;
+const SRC_CLRR: u32 = 1 << 25;
+const SRC_SETR: u32 = 1 << 26;
+const SRC_IOV: u32 = 1 << 27;
+const SRC_IOVCLR: u32 = 1 << 28;
+const SRC_SWS: u32 = 1 << 29;
+const SRC_SWSCLR: u32 = 1 << 30;
+
+/// Interrupt Router: one SRC register per service request node.
+#[derive(Clone)]
+pub struct InterruptRouter {
+    base: u64,
+    size: u64,
+    srcs: HashMap<u64, u32>
+}
+
+impl InterruptRouter {
+    pub fn new(base: u64, size: u64) -> InterruptRouter {
+        return InterruptRouter {
+            base: base,
+            size: size,
+            srcs: HashMap::new()
+        };
+    }
+
+    fn get(&self, src: u64) -> u32 {
+        self.srcs.get(&src).copied().unwrap_or(0)
+    }
+
+    fn raise(&mut self, src: u64, software: bool) {
+        let mut value = self.get(src);
+        if value & SRC_SRR != 0 {
+            value |= SRC_IOV;
+        }
+        value |= SRC_SRR;
+        if software {
+            value |= SRC_SWS;
+        }
+        self.srcs.insert(src, value);
+    }
+}
+
+impl Peripheral for InterruptRouter {
+    fn name(&self) -> String {
+        String::from("IR")
+    }
+
+    fn base(&self) -> u64 {
+        self.base
+    }
+
+    fn size(&self) -> u64 {
+        self.size
+    }
+
+    fn read(&mut self, offset: u64, _size: usize, _ctx: &mut BusContext) -> u64 {
+        let src = self.base + (offset & !0x3);
+        return (self.get(src) >> ((offset & 0x3) * 8)) as u64;
+    }
+
+    fn write(&mut self, offset: u64, size: usize, value: u64, _ctx: &mut BusContext) {
+        let src = self.base + (offset & !0x3);
+        let shift = (offset & 0x3) * 8;
+        let mask = if size >= 4 { 0xFFFFFFFF } else { ((1u32 << (size * 8)) - 1) << shift };
+        let value = ((value as u32) << shift) & mask;
+        let config = SRC_SRPN | SRC_SRE | SRC_TOS | SRC_ECC;
+        let mut current = self.get(src);
+        current = (current & !(config & mask)) | (value & config);
+        self.srcs.insert(src, current);
+        if value & SRC_SETR != 0 {
+            self.raise(src, true);
+        }
+        let mut current = self.get(src);
+        if value & SRC_CLRR != 0 {
+            current &= !SRC_SRR;
+        }
+        if value & SRC_IOVCLR != 0 {
+            current &= !SRC_IOV;
+        }
+        if value & SRC_SWSCLR != 0 {
+            current &= !SRC_SWS;
+        }
+        self.srcs.insert(src, current);
+    }
+
+    fn save(&self) -> PeripheralState {
+        Box::new(self.clone())
+    }
+
+    fn restore(&mut self, state: &PeripheralState) {
+        if let Some(state) = state.downcast_ref::<InterruptRouter>() {
+            *self = state.clone();
+        }
+    }
+}
+
+impl ServiceRequestSink for InterruptRouter {
+    fn set_request(&mut self, src: u64) {
+        self.raise(src, false);
+    }
+
+    fn arbitrate(&self, tos: u8) -> Option<(u64, u8)> {
+        self.srcs.iter()
+            .filter(|(_, value)| **value & SRC_SRR != 0 && **value & SRC_SRE != 0)
+            .filter(|(_, value)| ((**value & SRC_TOS) >> SRC_TOS_SHIFT) as u8 == tos)
+            .filter(|(_, value)| **value & SRC_SRPN != 0)
+            .map(|(src, value)| (*src, (*value & SRC_SRPN) as u8))
+            .max_by_key(|(_, priority)| *priority)
+    }
+
+    fn acknowledge(&mut self, src: u64) {
+        let value = self.get(src) & !SRC_SRR;
+        self.srcs.insert(src, value);
+    }
+}
@@ -0,0 +1,265 @@
+use crate::qecu::disas::Instruction;
+use crate::qecu::pcode::{PcodeOp, Varnode};
+use super::get_sleigh_register;
+
+/// Base of the unique space temporaries of an instruction.
+const UNIQUE_BASE: u64 = 0x1000;
+
+/// Operand of the Sleigh disassembly of an instruction.
+enum Operand {
+    Register(Varnode),
+    Constant(u64),
+    /// `[base]offset`, `update` for the post/pre-increment, circular and
+    /// bit-reverse modes which write the base back.
+    Memory { base: Varnode, offset: u64, update: bool, pre: bool }
+}
+
+fn constant(value: u64) -> Varnode {
+    Varnode { space: String::from("const"), offset: value, size: 4 }
+}
+
+fn parse_number(text: &str) -> Option<u64> {
+    let text = text.trim().trim_start_matches('#');
+    let (negative, text) = match text.strip_prefix('-') {
+        Some(text) => (true, text),
+        None => (false, text)
+    };
+    let value = match text.strip_prefix("0x") {
+        Some(hex) => u64::from_str_radix(hex, 16).ok()?,
+        None => text.parse::<u64>().ok()?
+    };
+    return Some(if negative { value.wrapping_neg() & 0xFFFFFFFF } else { value });
+}
+
+/// Dn/An, `sp` for A10, and the En/Pn register pairs.
+fn parse_register(text: &str) -> Option<Varnode> {
+    let name = text.trim().to_uppercase();
+    if name == "SP" {
+        return parse_register("a10");
+    }
+    let (size, name) = match (name.strip_prefix('E'), name.strip_prefix('P')) {
+        (Some(index), _) => (8, format!("D{}", index)),
+        (_, Some(index)) => (8, format!("A{}", index)),
+        _ => (4, name)
+    };
+    let offset = get_sleigh_register(&name)?;
+    return Some(Varnode { space: String::from("register"), offset: offset, size: size });
+}
+
+fn parse_operand(text: &str) -> Option<Operand> {
+    let text = text.trim();
+    if let Some(inner) = text.strip_prefix('[') {
+        let (inner, rest) = inner.split_once(']')?;
+        let pre = inner.starts_with('+');
+        let update = pre || inner.ends_with('+') || inner.ends_with("+c") || inner.ends_with("+r");
+        let name = inner.trim_start_matches('+').trim_end_matches("+c").trim_end_matches("+r").trim_end_matches('+');
+        let mut base = parse_register(name)?;
+        // Circular and bit-reverse modes address through the even register of the pair.
+        base.size = 4;
+        let offset = match rest.trim() {
+            "" => 0,
+            rest => parse_number(rest)?
+        };
+        return Some(Operand::Memory { base: base, offset: offset, update: update, pre: pre });
+    }
+    if let Some(register) = parse_register(text) {
+        return Some(Operand::Register(register));
+    }
+    return parse_number(text).map(Operand::Constant);
+}
+
+/// Builds the ops of one instruction.
+struct Lifter {
+    address: u64,
+    ops: Vec<PcodeOp>,
+    unique: u64
+}
+
+impl Lifter {
+    fn push(&mut self, opcode: &str, output: Option<Varnode>, inputs: Vec<Varnode>) {
+        self.ops.push(PcodeOp {
+            address: self.address,
+            seq: self.ops.len() as u32,
+            opcode: opcode.to_string(),
+            output: output,
+            inputs: inputs
+        });
+    }
+
+    fn temporary(&mut self, size: u32) -> Varnode {
+        let varnode = Varnode { space: String::from("unique"), offset: UNIQUE_BASE + self.unique, size: size };
+        self.unique += 0x10;
+        return varnode;
+    }
+
+    /// Varnode read for a register or constant operand.
+    fn value(&self, operand: &Operand) -> Option<Varnode> {
+        match operand {
+            Operand::Register(register) => Some(register.clone()),
+            Operand::Constant(value) => Some(constant(*value)),
+            Operand::Memory { .. } => None
+        }
+    }
+
+    /// Effective address of a memory operand, an absolute one being a constant.
+    fn pointer(&mut self, operand: &Operand) -> Option<Varnode> {
+        match operand {
+            Operand::Memory { base, offset, update, pre } => {
+                if *update && !*pre {
+                    return Some(base.clone());
+                }
+                let pointer = self.temporary(4);
+                self.push("INT_ADD", Some(pointer.clone()), vec![base.clone(), constant(*offset)]);
+                Some(pointer)
+            }
+            Operand::Constant(address) => Some(constant(*address)),
+            Operand::Register(_) => None
+        }
+    }
+
+    /// Writes the base of a post/pre-increment operand back.
+    fn update(&mut self, operand: &Operand) {
+        if let Operand::Memory { base, offset, update: true, .. } = operand {
+            self.push("INT_ADD", Some(base.clone()), vec![base.clone(), constant(*offset)]);
+        }
+    }
+
+    fn load(&mut self, output: Varnode, operand: &Operand) {
+        if let Some(pointer) = self.pointer(operand) {
+            self.push("LOAD", Some(output), vec![constant(0), pointer]);
+            self.update(operand);
+        }
+    }
+
+    fn store(&mut self, operand: &Operand, value: Varnode) {
+        if let Some(pointer) = self.pointer(operand) {
+            self.push("STORE", None, vec![constant(0), pointer, value]);
+            self.update(operand);
+        }
+    }
+}
+
+/// Comparison of a conditional jump, from its mnemonic.
+fn condition(mnemonic: &str) -> &'static str {
+    let base = mnemonic.split('.').next().unwrap_or("");
+    match base {
+        "jeq" | "jz" => "INT_EQUAL",
+        "jlt" | "jltz" => "INT_SLESS",
+        "jge" | "jgez" => "INT_SLESSEQUAL",
+        "jlez" | "jgtz" => "INT_SLESS",
+        _ => "INT_NOTEQUAL"
+    }
+}
+
+/// Operation of an ALU instruction, from its mnemonic. Only the data flow
+/// matters to the analyses, the other instructions are a CALLOTHER from their
+/// sources to their destination.
+fn operation(mnemonic: &str) -> &'static str {
+    let base = mnemonic.split('.').next().unwrap_or("");
+    match base {
+        "mov" | "movh" | "movz" | "mfcr" => "COPY",
+        "add" | "adds" | "addi" | "addih" | "addsc" | "addx" | "addc" => "INT_ADD",
+        "sub" | "subs" | "subx" | "subc" | "rsub" | "rsubs" => "INT_SUB",
+        "mul" | "muls" | "madd" | "madds" | "msub" | "msubs" => "INT_MULT",
+        "div" => "INT_SDIV",
+        "and" | "andn" => "INT_AND",
+        "or" | "orn" => "INT_OR",
+        "xor" | "xnor" => "INT_XOR",
+        "not" => "INT_NEGATE",
+        "sh" => "INT_LEFT",
+        "sha" | "shas" => "INT_SRIGHT",
+        "eq" => "INT_EQUAL",
+        "ne" => "INT_NOTEQUAL",
+        "lt" => "INT_SLESS",
+        "ge" => "INT_SLESSEQUAL",
+        _ => "CALLOTHER"
+    }
+}
+
+/// Whether the destination of a two operand ALU instruction is not read,
+/// the 16-bit forms of the others accumulate into it.
+fn overwrites(mnemonic: &str) -> bool {
+    ["mov", "mfcr", "abs", "clo", "cls", "clz"].iter().any(|prefix| mnemonic.starts_with(prefix))
+}
+
+/// Lifts `ins` from its Sleigh disassembly to P-code ops modelling its data
+/// flow: register and memory accesses, branches and their conditions.
+pub fn lift(ins: &Instruction) -> Vec<PcodeOp> {
+    let mnemonic = ins.mnemonic.trim().to_lowercase();
+    let operands: Vec<Operand> = match ins.operands.trim() {
+        "" => Vec::new(),
+        text => text.split(',').filter_map(parse_operand).collect()
+    };
+    let mut lifter = Lifter { address: ins.address, ops: Vec::new(), unique: 0 };
+    let return_address = constant(ins.address + ins.length as u64);
+    let a11 = parse_register("a11").unwrap();
+    match (mnemonic.as_str(), operands.as_slice()) {
+        ("ret" | "rfe" | "rfm" | "fret", _) => lifter.push("RETURN", None, vec![a11]),
+        ("j" | "ja", [Operand::Constant(target)]) => lifter.push("BRANCH", None, vec![constant(*target)]),
+        ("call" | "calla" | "fcall" | "fcalla" | "jl" | "jla", [Operand::Constant(target)]) => {
+            lifter.push("COPY", Some(a11), vec![return_address]);
+            lifter.push("CALL", None, vec![constant(*target)]);
+        }
+        ("ji", [Operand::Register(target)]) => lifter.push("BRANCHIND", None, vec![target.clone()]),
+        ("calli" | "fcalli" | "jli", [Operand::Register(target)]) => {
+            let target = target.clone();
+            lifter.push("COPY", Some(a11), vec![return_address]);
+            lifter.push("CALLIND", None, vec![target]);
+        }
+        ("loopu", [.., Operand::Constant(target)]) => lifter.push("BRANCH", None, vec![constant(*target)]),
+        ("loop", [Operand::Register(counter), Operand::Constant(target)]) => {
+            let cond = lifter.temporary(1);
+            lifter.push("INT_NOTEQUAL", Some(cond.clone()), vec![counter.clone(), constant(0)]);
+            lifter.push("INT_SUB", Some(counter.clone()), vec![counter.clone(), constant(1)]);
+            lifter.push("CBRANCH", None, vec![constant(*target), cond]);
+        }
+        (jump, [sources @ .., Operand::Constant(target)]) if jump.starts_with('j') && !sources.is_empty() => {
+            let inputs: Vec<Varnode> = sources.iter().filter_map(|source| lifter.value(source)).collect();
+            let cond = lifter.temporary(1);
+            let opcode = match jump.ends_with(".t") { true => "INT_AND", false => condition(jump) };
+            lifter.push(opcode, Some(cond.clone()), inputs);
+            // JNED/JNEI step their data register after the comparison.
+            if let ("jned" | "jnei", Operand::Register(register)) = (jump, &sources[0]) {
+                let step = if jump == "jned" { "INT_SUB" } else { "INT_ADD" };
+                lifter.push(step, Some(register.clone()), vec![register.clone(), constant(1)]);
+            }
+            lifter.push("CBRANCH", None, vec![constant(*target), cond]);
+        }
+        ("lea", [Operand::Register(output), source]) => {
+            let output = output.clone();
+            if let Some(pointer) = lifter.pointer(source) {
+                lifter.push("COPY", Some(output), vec![pointer]);
+            }
+        }
+        (load, [Operand::Register(output), source]) if load.starts_with("ld.") => lifter.load(output.clone(), source),
+        (store, [target, value]) if store.starts_with("st.") => {
+            if let Some(value) = lifter.value(value) {
+                lifter.store(target, value);
+            }
+        }
+        (store, [target, _, value]) if store.starts_with("st.") => {
+            if let Some(value) = lifter.value(value) {
+                lifter.store(target, value);
+            }
+        }
+        ("swap.w", [target, Operand::Register(register)]) => {
+            let old = lifter.temporary(4);
+            if let Some(pointer) = lifter.pointer(target) {
+                lifter.push("LOAD", Some(old.clone()), vec![constant(0), pointer.clone()]);
+                lifter.push("STORE", None, vec![constant(0), pointer, register.clone()]);
+                lifter.push("COPY", Some(register.clone()), vec![old]);
+                lifter.update(target);
+            }
+        }
+        (alu, [Operand::Register(output), sources @ ..]) => {
+            let mut inputs: Vec<Varnode> = sources.iter().filter_map(|source| lifter.value(source)).collect();
+            if sources.len() <= 1 && !overwrites(alu) {
+                inputs.insert(0, output.clone());
+            }
+            lifter.push(operation(alu), Some(output.clone()), inputs);
+        }
+        // NOP, barriers, context save/restore and CSFR writes: no tracked data flow.
+        _ => {}
+    }
+    return lifter.ops;
+}
@@ -0,0 +1,556 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::qecu::can::{dlc_to_len, len_to_dlc, CanEvent, CanFrame, CanPort};
+use crate::qecu::peripheral::{BusContext, Peripheral, PeripheralState};
+
+// Module layout (TC3xx): message RAM first, then the module and node registers.
+const RAM_SIZE: u64 = 0x8000;
+const NODE_BASE: u64 = 0x8100;
+const NODE_STRIDE: u64 = 0x400;
+
+// Node registers, relative to the node block. The M_CAN core starts at 0x100.
+const NODE_GRINT1: u64 = 0x14;
+const NODE_GRINT2: u64 = 0x18;
+const NODE_CCCR: u64 = 0x118;
+const NODE_IR: u64 = 0x150;
+const NODE_IE: u64 = 0x154;
+const NODE_GFC: u64 = 0x180;
+const NODE_SIDFC: u64 = 0x184;
+const NODE_XIDFC: u64 = 0x188;
+const NODE_XIDAM: u64 = 0x190;
+const NODE_NDAT1: u64 = 0x198;
+const NODE_NDAT2: u64 = 0x19C;
+const NODE_RXF0C: u64 = 0x1A0;
+const NODE_RXF0S: u64 = 0x1A4;
+const NODE_RXF0A: u64 = 0x1A8;
+const NODE_RXBC: u64 = 0x1AC;
+const NODE_RXF1C: u64 = 0x1B0;
+const NODE_RXF1S: u64 = 0x1B4;
+const NODE_RXF1A: u64 = 0x1B8;
+const NODE_RXESC: u64 = 0x1BC;
+const NODE_TXBC: u64 = 0x1C0;
+const NODE_TXFQS: u64 = 0x1C4;
+const NODE_TXESC: u64 = 0x1C8;
+const NODE_TXBRP: u64 = 0x1CC;
+const NODE_TXBAR: u64 = 0x1D0;
+const NODE_TXBCR: u64 = 0x1D4;
+const NODE_TXBTO: u64 = 0x1D8;
+const NODE_TXBCF: u64 = 0x1DC;
+const NODE_TXBTIE: u64 = 0x1E0;
+
+const CCCR_INIT: u32 = 1 << 0;
+
+// IR bits.
+const IR_RF0N: u32 = 1 << 0;
+const IR_RF0W: u32 = 1 << 1;
+const IR_RF0F: u32 = 1 << 2;
+const IR_RF0L: u32 = 1 << 3;
+const IR_TC: u32 = 1 << 9;
+const IR_TCF: u32 = 1 << 10;
+const IR_DRX: u32 = 1 << 19;
+
+/// IR bits of each interrupt group, in IfxCan_InterruptGroup order. Groups 0-7
+/// are routed to an INT line by GRINT1, 8-15 by GRINT2, 4 bits per group.
+const INTERRUPT_GROUPS: [u32; 16] = [
+    0x0000D000, // tefifo: TEFN, TEFF, TEFL
+    0x00000100, // hpe: HPM
+    0x00002022, // wati: RF0W, RF1W, TEFW
+    0x05C70000, // alrt: TSW, MRAF, TOO, BEU, ELO, EP, EW, WDI
+    0x00000088, // moer: RF0L, RF1L
+    0x00100000, // safe: BEC
+    0x02000000, // boff: BO
+    0x38000000, // loi: PEA, PED, ARA
+    0x00000000, // reint
+    0x00000040, // rxf1f: RF1F
+    0x00000004, // rxf0f: RF0F
+    0x00000010, // rxf1n: RF1N
+    0x00000001, // rxf0n: RF0N
+    0x00080000, // reti: DRX
+    0x00000C00, // traq: TCF, TFE
+    0x00000200  // traco: TC
+];
+
+fn element_size(code: u32) -> u64 {
+    let data = match code & 0x7 {
+        0..=4 => 8 + 4 * (code & 0x7) as u64,
+        5 => 32,
+        6 => 48,
+        _ => 64
+    };
+    return 8 + data;
+}
+
+enum Target {
+    Fifo(usize),
+    Buffer(u32),
+    Reject
+}
+
+#[derive(Clone, Default)]
+struct RxFifo {
+    get: u32,
+    put: u32,
+    fill: u32
+}
+
+struct Node {
+    port: CanPort,
+    regs: HashMap<u64, u32>,
+    fifos: [RxFifo; 2],
+    tx_put: u32
+}
+
+/// Registers, FIFOs and pending frames of a `Node`, without its port.
+#[derive(Clone)]
+struct NodeState {
+    regs: HashMap<u64, u32>,
+    fifos: [RxFifo; 2],
+    tx_put: u32,
+    pending: VecDeque<CanFrame>
+}
+
+impl Node {
+    fn get(&self, offset: u64) -> u32 {
+        self.regs.get(&offset).copied().unwrap_or(0)
+    }
+
+    fn set(&mut self, offset: u64, value: u32) {
+        self.regs.insert(offset, value);
+    }
+
+    /// Mask of the INT lines with an enabled pending interrupt.
+    fn lines(&self) -> u16 {
+        let pending = self.get(NODE_IR) & self.get(NODE_IE);
+        let mut lines = 0u16;
+        for (group, mask) in INTERRUPT_GROUPS.iter().enumerate() {
+            if pending & mask != 0 {
+                let grint = if group < 8 { self.get(NODE_GRINT1) } else { self.get(NODE_GRINT2) };
+                lines |= 1 << ((grint >> ((group % 8) * 4)) & 0xF);
+            }
+        }
+        return lines;
+    }
+
+    fn fifo_status(&self, index: usize) -> u32 {
+        let config = if index == 0 { self.get(NODE_RXF0C) } else { self.get(NODE_RXF1C) };
+        let size = (config >> 16) & 0x7F;
+        let fifo = &self.fifos[index];
+        let mut status = fifo.fill | (fifo.get << 8) | (fifo.put << 16);
+        if size != 0 && fifo.fill >= size {
+            status |= 1 << 24;
+        }
+        return status;
+    }
+
+    /// TX FIFO/queue: frames leave immediately, so it is always empty.
+    fn tx_queue_status(&self) -> u32 {
+        let txbc = self.get(NODE_TXBC);
+        let ndtb = (txbc >> 16) & 0x3F;
+        let tfqs = (txbc >> 24) & 0x3F;
+        let put = ndtb + self.tx_put;
+        return tfqs | (put << 8) | (put << 16);
+    }
+}
+
+#[derive(Clone)]
+struct McmcanState {
+    ram: Vec<u8>,
+    regs: HashMap<u64, u32>,
+    nodes: Vec<NodeState>
+}
+
+/// MCMCAN module: message RAM and M_CAN nodes. Frames are transmitted as soon
+/// as they are requested in TXBAR and received from the node `CanPort`.
+pub struct Mcmcan {
+    name: String,
+    base: u64,
+    ram: Vec<u8>,
+    regs: HashMap<u64, u32>,
+    nodes: Vec<Node>,
+    src_int: Vec<u64>
+}
+
+impl Mcmcan {
+    /// `src_int` are the SRC registers of the INT0..INT15 lines.
+    pub fn new(name: &str, base: u64, ports: Vec<CanPort>, src_int: Vec<u64>) -> Mcmcan {
+        let nodes = ports.into_iter().map(|port| {
+            let mut node = Node { port: port, regs: HashMap::new(), fifos: Default::default(), tx_put: 0 };
+            node.set(NODE_CCCR, CCCR_INIT);
+            node
+        }).collect();
+        return Mcmcan {
+            name: name.to_string(),
+            base: base,
+            ram: vec![0; RAM_SIZE as usize],
+            regs: HashMap::new(),
+            nodes: nodes,
+            src_int: src_int
+        };
+    }
+
+    fn ram_read(&self, address: u64) -> u32 {
+        let address = (address as usize) & (RAM_SIZE as usize - 1) & !0x3;
+        return u32::from_le_bytes(self.ram[address..address + 4].try_into().unwrap());
+    }
+
+    fn ram_write(&mut self, address: u64, value: u32) {
+        let address = (address as usize) & (RAM_SIZE as usize - 1) & !0x3;
+        self.ram[address..address + 4].copy_from_slice(&value.to_le_bytes());
+    }
+
+    /// Sets IR bits, requesting the INT lines that became active.
+    fn interrupt(&mut self, node: usize, bits: u32, ctx: &mut BusContext) {
+        let before = self.nodes[node].lines();
+        let ir = self.nodes[node].get(NODE_IR) | bits;
+        self.nodes[node].set(NODE_IR, ir);
+        self.request_lines(before, self.nodes[node].lines(), ctx);
+    }
+
+    fn request_lines(&self, before: u16, after: u16, ctx: &mut BusContext) {
+        let raised = after & !before;
+        for line in 0..16 {
+            if raised & (1 << line) != 0 {
+                if let Some(src) = self.src_int.get(line) {
+                    ctx.request(*src);
+                }
+            }
+        }
+    }
+
+    /// Runs the acceptance filters, returns the target and the filter index.
+    fn filter(&self, node: usize, frame: &CanFrame) -> (Target, Option<u32>) {
+        let node = &self.nodes[node];
+        let gfc = node.get(NODE_GFC);
+        let rrf = if frame.extended { 0x1 } else { 0x2 };
+        if frame.rtr && gfc & rrf != 0 {
+            return (Target::Reject, None);
+        }
+        let (config, element) = match frame.extended {
+            true => (node.get(NODE_XIDFC), 8),
+            false => (node.get(NODE_SIDFC), 4)
+        };
+        let start = (config & 0xFFFC) as u64;
+        let count = if frame.extended { (config >> 16) & 0x7F } else { (config >> 16) & 0xFF };
+        let xidam = node.get(NODE_XIDAM) & 0x1FFFFFFF;
+        for index in 0..count {
+            let address = start + index as u64 * element;
+            let (action, matched) = match frame.extended {
+                false => {
+                    let value = self.ram_read(address);
+                    let (kind, action) = (value >> 30, (value >> 27) & 0x7);
+                    let (id1, id2) = ((value >> 16) & 0x7FF, value & 0x7FF);
+                    let id = frame.id & 0x7FF;
+                    let matched = match (action, kind) {
+                        (7, _) => id == id1,
+                        (_, 0) => id >= id1 && id <= id2,
+                        (_, 1) => id == id1 || id == id2,
+                        (_, 2) => id & id2 == id1 & id2,
+                        _ => false
+                    };
+                    (if action == 7 { 0x80 | (id2 & 0x3F) } else { action }, matched)
+                }
+                true => {
+                    let (f0, f1) = (self.ram_read(address), self.ram_read(address + 4));
+                    let (action, kind) = (f0 >> 29, f1 >> 30);
+                    let (id1, id2) = (f0 & 0x1FFFFFFF, f1 & 0x1FFFFFFF);
+                    let id = frame.id & 0x1FFFFFFF;
+                    let matched = match (action, kind) {
+                        (7, _) => id == id1,
+                        (_, 0) => id & xidam >= id1 && id & xidam <= id2,
+                        (_, 1) => id == id1 || id == id2,
+                        (_, 2) => id & id2 == id1 & id2,
+                        (_, 3) => id >= id1 && id <= id2,
+                        _ => false
+                    };
+                    (if action == 7 { 0x80 | (id2 & 0x3F) } else { action }, matched)
+                }
+            };
+            if !matched {
+                continue;
+            }
+            match action {
+                0 | 4 => continue,
+                1 | 5 => return (Target::Fifo(0), Some(index)),
+                2 | 6 => return (Target::Fifo(1), Some(index)),
+                3 => return (Target::Reject, Some(index)),
+                buffer => return (Target::Buffer(buffer & 0x3F), Some(index))
+            }
+        }
+        // Non matching frames, GFC.ANFS / GFC.ANFE.
+        let anf = if frame.extended { (gfc >> 2) & 0x3 } else { (gfc >> 4) & 0x3 };
+        return match anf {
+            0 => (Target::Fifo(0), None),
+            1 => (Target::Fifo(1), None),
+            _ => (Target::Reject, None)
+        };
+    }
+
+    fn store(&mut self, address: u64, data_size: u64, frame: &CanFrame, filter: Option<u32>) {
+        let id = if frame.extended { frame.id & 0x1FFFFFFF } else { (frame.id & 0x7FF) << 18 };
+        let r0 = id | (frame.rtr as u32) << 29 | (frame.extended as u32) << 30;
+        let mut r1 = (len_to_dlc(frame.data.len()) as u32) << 16 | (frame.brs as u32) << 20 | (frame.fd as u32) << 21;
+        match filter {
+            Some(index) => r1 |= (index & 0x7F) << 24,
+            None => r1 |= 1 << 31
+        }
+        self.ram_write(address, r0);
+        self.ram_write(address + 4, r1);
+        let mut data = frame.data.clone();
+        data.resize(data_size as usize, 0);
+        for (index, word) in data.chunks(4).enumerate() {
+            let mut bytes = [0u8; 4];
+            bytes[..word.len()].copy_from_slice(word);
+            self.ram_write(address + 8 + index as u64 * 4, u32::from_le_bytes(bytes));
+        }
+    }
+
+    fn receive(&mut self, node: usize, frame: CanFrame, ctx: &mut BusContext) {
+        let (target, filter) = self.filter(node, &frame);
+        let rxesc = self.nodes[node].get(NODE_RXESC);
+        match target {
+            Target::Reject => {}
+            Target::Buffer(index) => {
+                let size = element_size(rxesc >> 8);
+                let address = (self.nodes[node].get(NODE_RXBC) & 0xFFFC) as u64 + index as u64 * size;
+                self.store(address, size - 8, &frame, filter);
+                let ndat = if index < 32 { NODE_NDAT1 } else { NODE_NDAT2 };
+                let value = self.nodes[node].get(ndat) | 1 << (index % 32);
+                self.nodes[node].set(ndat, value);
+                self.interrupt(node, IR_DRX, ctx);
+            }
+            Target::Fifo(fifo) => {
+                let config = self.nodes[node].get(if fifo == 0 { NODE_RXF0C } else { NODE_RXF1C });
+                let size = (config >> 16) & 0x7F;
+                let watermark = (config >> 24) & 0x7F;
+                // RFxN, RFxW, RFxF and RFxL of FIFO1 are the FIFO0 ones shifted by 4.
+                let shift = fifo * 4;
+                if size == 0 {
+                    return;
+                }
+                if self.nodes[node].fifos[fifo].fill >= size {
+                    if config & (1 << 31) == 0 {
+                        self.interrupt(node, IR_RF0L << shift, ctx);
+                        return;
+                    }
+                    let state = &mut self.nodes[node].fifos[fifo];
+                    state.get = (state.get + 1) % size;
+                    state.fill -= 1;
+                }
+                let element = element_size(rxesc >> (fifo * 4));
+                let put = self.nodes[node].fifos[fifo].put;
+                let address = (config & 0xFFFC) as u64 + put as u64 * element;
+                self.store(address, element - 8, &frame, filter);
+                let state = &mut self.nodes[node].fifos[fifo];
+                state.put = (state.put + 1) % size;
+                state.fill += 1;
+                let fill = state.fill;
+                let mut bits = IR_RF0N;
+                if watermark != 0 && fill == watermark {
+                    bits |= IR_RF0W;
+                }
+                if fill == size {
+                    bits |= IR_RF0F;
+                }
+                self.interrupt(node, bits << shift, ctx);
+            }
+        }
+    }
+
+    fn acknowledge(&mut self, node: usize, fifo: usize, index: u32) {
+        let config = self.nodes[node].get(if fifo == 0 { NODE_RXF0C } else { NODE_RXF1C });
+        let size = (config >> 16) & 0x7F;
+        if size == 0 {
+            return;
+        }
+        let state = &mut self.nodes[node].fifos[fifo];
+        let get = (index + 1) % size;
+        let mut acked = (get + size - state.get) % size;
+        if acked == 0 && state.fill == size {
+            acked = size;
+        }
+        state.get = get;
+        state.fill -= acked.min(state.fill);
+    }
+
+    fn transmit(&mut self, node: usize, requests: u32, ctx: &mut BusContext) {
+        let txbc = self.nodes[node].get(NODE_TXBC);
+        let ndtb = (txbc >> 16) & 0x3F;
+        let tfqs = (txbc >> 24) & 0x3F;
+        let size = element_size(self.nodes[node].get(NODE_TXESC));
+        let mut bits = 0;
+        for index in 0..32 {
+            if requests & (1 << index) == 0 {
+                continue;
+            }
+            let address = (txbc & 0xFFFC) as u64 + index as u64 * size;
+            let (t0, t1) = (self.ram_read(address), self.ram_read(address + 4));
+            let extended = t0 & (1 << 30) != 0;
+            let fd = t1 & (1 << 21) != 0;
+            let mut len = dlc_to_len(((t1 >> 16) & 0xF) as u8);
+            if !fd {
+                len = len.min(8);
+            }
+            let mut data = Vec::new();
+            for word in 0..(len as u64 + 3) / 4 {
+                data.extend_from_slice(&self.ram_read(address + 8 + word * 4).to_le_bytes());
+            }
+            data.truncate(len);
+            let frame = CanFrame {
+                id: if extended { t0 & 0x1FFFFFFF } else { (t0 >> 18) & 0x7FF },
+                extended: extended,
+                fd: fd,
+                brs: t1 & (1 << 20) != 0,
+                rtr: t0 & (1 << 29) != 0,
+                data: data
+            };
+            let port = &self.nodes[node].port;
+            port.publish(&frame);
+            let event = CanEvent { node: port.name(), frame: frame };
+            ctx.emit("can-tx", serde_json::to_string(&event).unwrap());
+            if tfqs != 0 && index >= ndtb && index < ndtb + tfqs {
+                self.nodes[node].tx_put = (index - ndtb + 1) % tfqs;
+            }
+            if self.nodes[node].get(NODE_TXBTIE) & (1 << index) != 0 {
+                bits |= IR_TC;
+            }
+        }
+        let txbto = self.nodes[node].get(NODE_TXBTO) | requests;
+        self.nodes[node].set(NODE_TXBTO, txbto);
+        if bits != 0 {
+            self.interrupt(node, bits, ctx);
+        }
+    }
+
+    /// Splits a register offset in (node, offset in the node block).
+    fn node_register(&self, offset: u64) -> Option<(usize, u64)> {
+        if offset < NODE_BASE {
+            return None;
+        }
+        let node = ((offset - NODE_BASE) / NODE_STRIDE) as usize;
+        return match node < self.nodes.len() {
+            true => Some((node, (offset - NODE_BASE) % NODE_STRIDE)),
+            false => None
+        };
+    }
+}
+
+impl Peripheral for Mcmcan {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn base(&self) -> u64 {
+        self.base
+    }
+
+    fn size(&self) -> u64 {
+        NODE_BASE + NODE_STRIDE * self.nodes.len() as u64
+    }
+
+    fn read(&mut self, offset: u64, size: usize, _ctx: &mut BusContext) -> u64 {
+        if offset < RAM_SIZE {
+            let mut value = [0u8; 8];
+            let size = size.min(8).min((RAM_SIZE - offset) as usize);
+            value[..size].copy_from_slice(&self.ram[offset as usize..offset as usize + size]);
+            return u64::from_le_bytes(value);
+        }
+        let register = offset & !0x3;
+        let value = match self.node_register(register) {
+            Some((node, NODE_RXF0S)) => self.nodes[node].fifo_status(0),
+            Some((node, NODE_RXF1S)) => self.nodes[node].fifo_status(1),
+            Some((node, NODE_TXFQS)) => self.nodes[node].tx_queue_status(),
+            Some((_, NODE_TXBRP)) | Some((_, NODE_TXBAR)) | Some((_, NODE_TXBCR)) => 0,
+            Some((node, register)) => self.nodes[node].get(register),
+            None => self.regs.get(&register).copied().unwrap_or(0)
+        };
+        return (value >> ((offset & 0x3) * 8)) as u64;
+    }
+
+    fn write(&mut self, offset: u64, size: usize, value: u64, ctx: &mut BusContext) {
+        if offset < RAM_SIZE {
+            let size = size.min(8).min((RAM_SIZE - offset) as usize);
+            self.ram[offset as usize..offset as usize + size].copy_from_slice(&value.to_le_bytes()[..size]);
+            return;
+        }
+        let register = offset & !0x3;
+        let shift = (offset & 0x3) * 8;
+        let mask = if size >= 4 { 0xFFFFFFFF } else { ((1u32 << (size * 8)) - 1) << shift };
+        let value = (value as u32) << shift;
+        let merge = |current: u32| (current & !mask) | (value & mask);
+        let (node, register) = match self.node_register(register) {
+            Some(node_register) => node_register,
+            None => {
+                let current = self.regs.get(&register).copied().unwrap_or(0);
+                self.regs.insert(register, merge(current));
+                return;
+            }
+        };
+        let before = self.nodes[node].lines();
+        match register {
+            // Write 1 to clear.
+            NODE_IR | NODE_NDAT1 | NODE_NDAT2 => {
+                let current = self.nodes[node].get(register);
+                self.nodes[node].set(register, current & !(value & mask));
+            }
+            NODE_RXF0A => self.acknowledge(node, 0, (value & mask) & 0x3F),
+            NODE_RXF1A => self.acknowledge(node, 1, (value & mask) & 0x3F),
+            NODE_TXBAR => self.transmit(node, value & mask, ctx),
+            NODE_TXBCR => {
+                let txbcf = self.nodes[node].get(NODE_TXBCF) | (value & mask);
+                self.nodes[node].set(NODE_TXBCF, txbcf);
+                self.interrupt(node, IR_TCF, ctx);
+            }
+            NODE_RXF0S | NODE_RXF1S | NODE_TXFQS | NODE_TXBRP | NODE_TXBTO | NODE_TXBCF => {}
+            NODE_RXF0C | NODE_RXF1C => {
+                let current = self.nodes[node].get(register);
+                self.nodes[node].set(register, merge(current));
+                self.nodes[node].fifos[if register == NODE_RXF0C { 0 } else { 1 }] = RxFifo::default();
+            }
+            _ => {
+                let current = self.nodes[node].get(register);
+                self.nodes[node].set(register, merge(current));
+            }
+        }
+        // Enabling an interrupt (IE, GRINT) with its flag already set.
+        if register == NODE_IE || register == NODE_GRINT1 || register == NODE_GRINT2 {
+            self.request_lines(before, self.nodes[node].lines(), ctx);
+        }
+    }
+
+    fn tick(&mut self, _now: u64, ctx: &mut BusContext) {
+        for node in 0..self.nodes.len() {
+            if self.nodes[node].get(NODE_CCCR) & CCCR_INIT != 0 {
+                continue;
+            }
+            if let Some(frame) = self.nodes[node].port.pop_received() {
+                self.receive(node, frame, ctx);
+            }
+        }
+    }
+
+    fn save(&self) -> PeripheralState {
+        return Box::new(McmcanState {
+            ram: self.ram.clone(),
+            regs: self.regs.clone(),
+            nodes: self.nodes.iter().map(|node| NodeState {
+                regs: node.regs.clone(),
+                fifos: node.fifos.clone(),
+                tx_put: node.tx_put,
+                pending: node.port.pending()
+            }).collect()
+        });
+    }
+
+    fn restore(&mut self, state: &PeripheralState) {
+        if let Some(state) = state.downcast_ref::<McmcanState>() {
+            self.ram = state.ram.clone();
+            self.regs = state.regs.clone();
+            for (node, saved) in self.nodes.iter_mut().zip(&state.nodes) {
+                node.regs = saved.regs.clone();
+                node.fifos = saved.fifos.clone();
+                node.tx_put = saved.tx_put;
+                node.port.set_pending(saved.pending.clone());
+            }
+        }
+    }
+}
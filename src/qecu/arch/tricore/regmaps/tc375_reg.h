@@ -0,0 +1,21427 @@
+/* TC375 register and module addresses, iLLD IfxXxx_reg.h format. */
+
+#define ASCLIN9_TXFIFOCON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_TXFIFOCON*)0xF0000F0Cu)
+#define ASCLIN9_RXFIFOCON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_RXFIFOCON*)0xF0000F10u)
+#define ASCLIN9_BITCON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_BITCON*)0xF0000F14u)
+#define MODULE_ASCLIN0 (0xF0000600u)
+#define ASCLIN9_FRAMECON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FRAMECON*)0xF0000F18u)
+#define ASCLIN9_DATCON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_DATCON*)0xF0000F1Cu)
+#define ASCLIN9_BRG /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_BRG*)0xF0000F20u)
+#define MODULE_ASCLIN1 (0xF0000700u)
+#define ASCLIN9_BRD (0xF0000F24u)
+#define ASCLIN9_LIN_CON (0xF0000F28u)
+#define MODULE_ASCLIN2 (0xF0000800u)
+#define ASCLIN9_LIN_BTIMER (0xF0000F2Cu)
+#define ASCLIN9_LIN_HTIMER (0xF0000F30u)
+#define MODULE_ASCLIN3 (0xF0000900u)
+#define ASCLIN9_FLAGS /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FLAGS*)0xF0000F34u)
+#define ASCLIN9_FLAGSSET /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FLAGSSET*)0xF0000F38u)
+#define MODULE_ASCLIN4 (0xF0000A00u)
+#define ASCLIN9_FLAGSCLEAR /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FLAGSCLEAR*)0xF0000F3Cu)
+#define ASCLIN9_FLAGSENABLE /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FLAGSENABLE*)0xF0000F40u)
+#define ASCLIN9_TXDATA /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_TXDATA*)0xF0000F44u)
+#define ASCLIN9_RXDATA /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_RXDATA*)0xF0000F48u)
+#define MODULE_ASCLIN5 (0xF0000B00u)
+#define ASCLIN9_CSR /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_CSR*)0xF0000F4Cu)
+#define ASCLIN9_RXDATAD /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_RXDATAD*)0xF0000F50u)
+#define ASCLIN9_OCS (0xF0000FE8u)
+#define MODULE_ASCLIN6 (0xF0000C00u)
+#define ASCLIN9_KRSTCLR (0xF0000FECu)
+#define ASCLIN9_KRST1 (0xF0000FF0u)
+#define ASCLIN9_KRST0 (0xF0000FF4u)
+#define MODULE_ASCLIN7 (0xF0000D00u)
+#define ASCLIN9_ACCEN1 (0xF0000FF8u)
+#define ASCLIN9_ACCEN0 (0xF0000FFCu)
+#define MODULE_ASCLIN8 (0xF0000E00u)
+#define ASCLIN10_CLC /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_CLC*)0xF02C0A00u)
+#define ASCLIN10_IOCR (0xF02C0A04u)
+#define ASCLIN10_ID (0xF02C0A08u)
+#define ASCLIN10_TXFIFOCON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_TXFIFOCON*)0xF02C0A0Cu)
+#define MODULE_ASCLIN9 (0xF0000F00u)
+#define ASCLIN10_RXFIFOCON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_RXFIFOCON*)0xF02C0A10u)
+#define ASCLIN10_BITCON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_BITCON*)0xF02C0A14u)
+#define ASCLIN10_FRAMECON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FRAMECON*)0xF02C0A18u)
+#define MODULE_ASCLIN10 (0xF02C0A00u)
+#define ASCLIN10_DATCON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_DATCON*)0xF02C0A1Cu)
+#define ASCLIN10_BRG /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_BRG*)0xF02C0A20u)
+#define ASCLIN10_BRD (0xF02C0A24u)
+#define MODULE_ASCLIN11 (0xF02C0B00u)
+#define ASCLIN10_LIN_CON (0xF02C0A28u)
+#define ASCLIN10_LIN_BTIMER (0xF02C0A2Cu)
+#define ASCLIN10_LIN_HTIMER (0xF02C0A30u)
+#define ASCLIN10_FLAGS /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FLAGS*)0xF02C0A34u)
+#define ASCLIN10_FLAGSSET /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FLAGSSET*)0xF02C0A38u)
+#define ASCLIN10_FLAGSCLEAR /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FLAGSCLEAR*)0xF02C0A3Cu)
+#define ASCLIN10_FLAGSENABLE /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FLAGSENABLE*)0xF02C0A40u)
+#define ASCLIN10_TXDATA /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_TXDATA*)0xF02C0A44u)
+#define ASCLIN10_RXDATA /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_RXDATA*)0xF02C0A48u)
+#define ASCLIN10_CSR /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_CSR*)0xF02C0A4Cu)
+#define ASCLIN10_RXDATAD /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_RXDATAD*)0xF02C0A50u)
+#define ASCLIN10_OCS (0xF02C0AE8u)
+#define ASCLIN10_KRSTCLR (0xF02C0AECu)
+#define ASCLIN10_KRST1 (0xF02C0AF0u)
+#define ASCLIN10_KRST0 (0xF02C0AF4u)
+#define ASCLIN10_ACCEN1 (0xF02C0AF8u)
+#define ASCLIN10_ACCEN0 (0xF02C0AFCu)
+#define ASCLIN11_CLC /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_CLC*)0xF02C0B00u)
+#define ASCLIN11_IOCR (0xF02C0B04u)
+#define ASCLIN11_ID (0xF02C0B08u)
+#define ASCLIN11_TXFIFOCON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_TXFIFOCON*)0xF02C0B0Cu)
+#define ASCLIN11_RXFIFOCON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_RXFIFOCON*)0xF02C0B10u)
+#define ASCLIN11_BITCON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_BITCON*)0xF02C0B14u)
+#define ASCLIN11_FRAMECON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FRAMECON*)0xF02C0B18u)
+#define ASCLIN11_DATCON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_DATCON*)0xF02C0B1Cu)
+#define ASCLIN0_CLC /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_CLC*)0xF0000600u)
+#define ASCLIN11_BRG /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_BRG*)0xF02C0B20u)
+#define ASCLIN11_BRD (0xF02C0B24u)
+#define ASCLIN11_LIN_CON (0xF02C0B28u)
+#define ASCLIN11_LIN_BTIMER (0xF02C0B2Cu)
+#define ASCLIN11_LIN_HTIMER (0xF02C0B30u)
+#define ASCLIN11_FLAGS /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FLAGS*)0xF02C0B34u)
+#define ASCLIN0_IOCR (0xF0000604u)
+#define ASCLIN11_FLAGSSET /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FLAGSSET*)0xF02C0B38u)
+#define ASCLIN11_FLAGSCLEAR /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FLAGSCLEAR*)0xF02C0B3Cu)
+#define ASCLIN11_FLAGSENABLE /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FLAGSENABLE*)0xF02C0B40u)
+#define ASCLIN11_TXDATA /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_TXDATA*)0xF02C0B44u)
+#define ASCLIN11_RXDATA /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_RXDATA*)0xF02C0B48u)
+#define ASCLIN11_CSR /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_CSR*)0xF02C0B4Cu)
+#define ASCLIN11_RXDATAD /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_RXDATAD*)0xF02C0B50u)
+#define ASCLIN11_OCS (0xF02C0BE8u)
+#define ASCLIN11_KRSTCLR (0xF02C0BECu)
+#define ASCLIN11_KRST1 (0xF02C0BF0u)
+#define ASCLIN0_ID (0xF0000608u)
+#define ASCLIN11_KRST0 (0xF02C0BF4u)
+#define ASCLIN11_ACCEN1 (0xF02C0BF8u)
+#define ASCLIN11_ACCEN0 (0xF02C0BFCu)
+#define ASCLIN0_TXFIFOCON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_TXFIFOCON*)0xF000060Cu)
+#define ASCLIN0_RXFIFOCON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_RXFIFOCON*)0xF0000610u)
+#define ASCLIN0_BITCON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_BITCON*)0xF0000614u)
+#define ASCLIN0_FRAMECON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FRAMECON*)0xF0000618u)
+#define ASCLIN0_DATCON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_DATCON*)0xF000061Cu)
+#define ASCLIN0_BRG /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_BRG*)0xF0000620u)
+#define ASCLIN0_BRD (0xF0000624u)
+#define ASCLIN0_LIN_CON (0xF0000628u)
+#define ASCLIN0_LIN_BTIMER (0xF000062Cu)
+#define ASCLIN0_LIN_HTIMER (0xF0000630u)
+#define ASCLIN0_FLAGS /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FLAGS*)0xF0000634u)
+#define ASCLIN0_FLAGSSET /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FLAGSSET*)0xF0000638u)
+#define ASCLIN0_FLAGSCLEAR /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FLAGSCLEAR*)0xF000063Cu)
+#define ASCLIN0_FLAGSENABLE /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FLAGSENABLE*)0xF0000640u)
+#define ASCLIN0_TXDATA /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_TXDATA*)0xF0000644u)
+#define ASCLIN0_RXDATA /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_RXDATA*)0xF0000648u)
+#define ASCLIN0_CSR /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_CSR*)0xF000064Cu)
+#define ASCLIN0_RXDATAD /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_RXDATAD*)0xF0000650u)
+#define ASCLIN0_OCS (0xF00006E8u)
+#define ASCLIN0_KRSTCLR (0xF00006ECu)
+#define ASCLIN0_KRST1 (0xF00006F0u)
+#define ASCLIN0_KRST0 (0xF00006F4u)
+#define ASCLIN0_ACCEN1 (0xF00006F8u)
+#define ASCLIN0_ACCEN0 (0xF00006FCu)
+#define ASCLIN1_CLC /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_CLC*)0xF0000700u)
+#define ASCLIN1_IOCR (0xF0000704u)
+#define ASCLIN1_ID (0xF0000708u)
+#define ASCLIN1_TXFIFOCON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_TXFIFOCON*)0xF000070Cu)
+#define ASCLIN1_RXFIFOCON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_RXFIFOCON*)0xF0000710u)
+#define ASCLIN1_BITCON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_BITCON*)0xF0000714u)
+#define ASCLIN1_FRAMECON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FRAMECON*)0xF0000718u)
+#define ASCLIN1_DATCON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_DATCON*)0xF000071Cu)
+#define ASCLIN1_BRG /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_BRG*)0xF0000720u)
+#define ASCLIN1_BRD (0xF0000724u)
+#define ASCLIN1_LIN_CON (0xF0000728u)
+#define ASCLIN1_LIN_BTIMER (0xF000072Cu)
+#define ASCLIN1_LIN_HTIMER (0xF0000730u)
+#define ASCLIN1_FLAGS /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FLAGS*)0xF0000734u)
+#define ASCLIN1_FLAGSSET /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FLAGSSET*)0xF0000738u)
+#define ASCLIN1_FLAGSCLEAR /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FLAGSCLEAR*)0xF000073Cu)
+#define ASCLIN1_FLAGSENABLE /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FLAGSENABLE*)0xF0000740u)
+#define ASCLIN1_TXDATA /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_TXDATA*)0xF0000744u)
+#define ASCLIN1_RXDATA /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_RXDATA*)0xF0000748u)
+#define ASCLIN1_CSR /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_CSR*)0xF000074Cu)
+#define ASCLIN1_RXDATAD /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_RXDATAD*)0xF0000750u)
+#define ASCLIN1_OCS (0xF00007E8u)
+#define ASCLIN1_KRSTCLR (0xF00007ECu)
+#define ASCLIN1_KRST1 (0xF00007F0u)
+#define ASCLIN1_KRST0 (0xF00007F4u)
+#define ASCLIN1_ACCEN1 (0xF00007F8u)
+#define ASCLIN1_ACCEN0 (0xF00007FCu)
+#define ASCLIN2_CLC /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_CLC*)0xF0000800u)
+#define ASCLIN2_IOCR (0xF0000804u)
+#define ASCLIN2_ID (0xF0000808u)
+#define ASCLIN2_TXFIFOCON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_TXFIFOCON*)0xF000080Cu)
+#define ASCLIN2_RXFIFOCON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_RXFIFOCON*)0xF0000810u)
+#define ASCLIN2_BITCON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_BITCON*)0xF0000814u)
+#define ASCLIN2_FRAMECON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FRAMECON*)0xF0000818u)
+#define ASCLIN2_DATCON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_DATCON*)0xF000081Cu)
+#define ASCLIN2_BRG /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_BRG*)0xF0000820u)
+#define ASCLIN2_BRD (0xF0000824u)
+#define ASCLIN2_LIN_CON (0xF0000828u)
+#define ASCLIN2_LIN_BTIMER (0xF000082Cu)
+#define ASCLIN2_LIN_HTIMER (0xF0000830u)
+#define ASCLIN2_FLAGS /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FLAGS*)0xF0000834u)
+#define ASCLIN2_FLAGSSET /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FLAGSSET*)0xF0000838u)
+#define ASCLIN2_FLAGSCLEAR /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FLAGSCLEAR*)0xF000083Cu)
+#define ASCLIN2_FLAGSENABLE /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FLAGSENABLE*)0xF0000840u)
+#define ASCLIN2_TXDATA /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_TXDATA*)0xF0000844u)
+#define ASCLIN2_RXDATA /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_RXDATA*)0xF0000848u)
+#define ASCLIN2_CSR /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_CSR*)0xF000084Cu)
+#define ASCLIN2_RXDATAD /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_RXDATAD*)0xF0000850u)
+#define ASCLIN2_OCS (0xF00008E8u)
+#define ASCLIN2_KRSTCLR (0xF00008ECu)
+#define ASCLIN2_KRST1 (0xF00008F0u)
+#define ASCLIN2_KRST0 (0xF00008F4u)
+#define ASCLIN2_ACCEN1 (0xF00008F8u)
+#define ASCLIN2_ACCEN0 (0xF00008FCu)
+#define ASCLIN3_CLC /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_CLC*)0xF0000900u)
+#define ASCLIN3_IOCR (0xF0000904u)
+#define ASCLIN3_ID (0xF0000908u)
+#define ASCLIN3_TXFIFOCON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_TXFIFOCON*)0xF000090Cu)
+#define ASCLIN3_RXFIFOCON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_RXFIFOCON*)0xF0000910u)
+#define ASCLIN3_BITCON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_BITCON*)0xF0000914u)
+#define ASCLIN3_FRAMECON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FRAMECON*)0xF0000918u)
+#define ASCLIN3_DATCON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_DATCON*)0xF000091Cu)
+#define ASCLIN3_BRG /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_BRG*)0xF0000920u)
+#define ASCLIN3_BRD (0xF0000924u)
+#define ASCLIN3_LIN_CON (0xF0000928u)
+#define ASCLIN3_LIN_BTIMER (0xF000092Cu)
+#define ASCLIN3_LIN_HTIMER (0xF0000930u)
+#define ASCLIN3_FLAGS /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FLAGS*)0xF0000934u)
+#define ASCLIN3_FLAGSSET /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FLAGSSET*)0xF0000938u)
+#define ASCLIN3_FLAGSCLEAR /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FLAGSCLEAR*)0xF000093Cu)
+#define ASCLIN3_FLAGSENABLE /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FLAGSENABLE*)0xF0000940u)
+#define ASCLIN3_TXDATA /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_TXDATA*)0xF0000944u)
+#define ASCLIN3_RXDATA /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_RXDATA*)0xF0000948u)
+#define ASCLIN3_CSR /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_CSR*)0xF000094Cu)
+#define ASCLIN3_RXDATAD /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_RXDATAD*)0xF0000950u)
+#define ASCLIN3_OCS (0xF00009E8u)
+#define ASCLIN3_KRSTCLR (0xF00009ECu)
+#define ASCLIN3_KRST1 (0xF00009F0u)
+#define ASCLIN3_KRST0 (0xF00009F4u)
+#define ASCLIN3_ACCEN1 (0xF00009F8u)
+#define ASCLIN3_ACCEN0 (0xF00009FCu)
+#define ASCLIN4_CLC /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_CLC*)0xF0000A00u)
+#define ASCLIN4_IOCR (0xF0000A04u)
+#define ASCLIN4_ID (0xF0000A08u)
+#define ASCLIN4_TXFIFOCON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_TXFIFOCON*)0xF0000A0Cu)
+#define ASCLIN4_RXFIFOCON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_RXFIFOCON*)0xF0000A10u)
+#define ASCLIN4_BITCON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_BITCON*)0xF0000A14u)
+#define ASCLIN4_FRAMECON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FRAMECON*)0xF0000A18u)
+#define ASCLIN4_DATCON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_DATCON*)0xF0000A1Cu)
+#define ASCLIN4_BRG /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_BRG*)0xF0000A20u)
+#define ASCLIN4_BRD (0xF0000A24u)
+#define ASCLIN4_LIN_CON (0xF0000A28u)
+#define ASCLIN4_LIN_BTIMER (0xF0000A2Cu)
+#define ASCLIN4_LIN_HTIMER (0xF0000A30u)
+#define ASCLIN4_FLAGS /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FLAGS*)0xF0000A34u)
+#define ASCLIN4_FLAGSSET /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FLAGSSET*)0xF0000A38u)
+#define ASCLIN4_FLAGSCLEAR /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FLAGSCLEAR*)0xF0000A3Cu)
+#define ASCLIN4_FLAGSENABLE /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FLAGSENABLE*)0xF0000A40u)
+#define ASCLIN4_TXDATA /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_TXDATA*)0xF0000A44u)
+#define ASCLIN4_RXDATA /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_RXDATA*)0xF0000A48u)
+#define ASCLIN4_CSR /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_CSR*)0xF0000A4Cu)
+#define ASCLIN4_RXDATAD /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_RXDATAD*)0xF0000A50u)
+#define ASCLIN4_OCS (0xF0000AE8u)
+#define ASCLIN4_KRSTCLR (0xF0000AECu)
+#define ASCLIN4_KRST1 (0xF0000AF0u)
+#define ASCLIN4_KRST0 (0xF0000AF4u)
+#define ASCLIN4_ACCEN1 (0xF0000AF8u)
+#define ASCLIN4_ACCEN0 (0xF0000AFCu)
+#define ASCLIN5_CLC /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_CLC*)0xF0000B00u)
+#define ASCLIN5_IOCR (0xF0000B04u)
+#define ASCLIN5_ID (0xF0000B08u)
+#define ASCLIN5_TXFIFOCON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_TXFIFOCON*)0xF0000B0Cu)
+#define ASCLIN5_RXFIFOCON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_RXFIFOCON*)0xF0000B10u)
+#define ASCLIN5_BITCON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_BITCON*)0xF0000B14u)
+#define ASCLIN5_FRAMECON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FRAMECON*)0xF0000B18u)
+#define ASCLIN5_DATCON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_DATCON*)0xF0000B1Cu)
+#define ASCLIN5_BRG /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_BRG*)0xF0000B20u)
+#define ASCLIN5_BRD (0xF0000B24u)
+#define ASCLIN5_LIN_CON (0xF0000B28u)
+#define ASCLIN5_LIN_BTIMER (0xF0000B2Cu)
+#define ASCLIN5_LIN_HTIMER (0xF0000B30u)
+#define ASCLIN5_FLAGS /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FLAGS*)0xF0000B34u)
+#define ASCLIN5_FLAGSSET /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FLAGSSET*)0xF0000B38u)
+#define ASCLIN5_FLAGSCLEAR /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FLAGSCLEAR*)0xF0000B3Cu)
+#define ASCLIN5_FLAGSENABLE /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FLAGSENABLE*)0xF0000B40u)
+#define ASCLIN5_TXDATA /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_TXDATA*)0xF0000B44u)
+#define ASCLIN5_RXDATA /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_RXDATA*)0xF0000B48u)
+#define ASCLIN5_CSR /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_CSR*)0xF0000B4Cu)
+#define ASCLIN5_RXDATAD /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_RXDATAD*)0xF0000B50u)
+#define ASCLIN5_OCS (0xF0000BE8u)
+#define ASCLIN5_KRSTCLR (0xF0000BECu)
+#define ASCLIN5_KRST1 (0xF0000BF0u)
+#define ASCLIN5_KRST0 (0xF0000BF4u)
+#define ASCLIN5_ACCEN1 (0xF0000BF8u)
+#define ASCLIN5_ACCEN0 (0xF0000BFCu)
+#define ASCLIN6_CLC /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_CLC*)0xF0000C00u)
+#define ASCLIN6_IOCR (0xF0000C04u)
+#define ASCLIN6_ID (0xF0000C08u)
+#define ASCLIN6_TXFIFOCON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_TXFIFOCON*)0xF0000C0Cu)
+#define ASCLIN6_RXFIFOCON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_RXFIFOCON*)0xF0000C10u)
+#define ASCLIN6_BITCON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_BITCON*)0xF0000C14u)
+#define ASCLIN6_FRAMECON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FRAMECON*)0xF0000C18u)
+#define ASCLIN6_DATCON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_DATCON*)0xF0000C1Cu)
+#define ASCLIN6_BRG /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_BRG*)0xF0000C20u)
+#define ASCLIN6_BRD (0xF0000C24u)
+#define ASCLIN6_LIN_CON (0xF0000C28u)
+#define ASCLIN6_LIN_BTIMER (0xF0000C2Cu)
+#define ASCLIN6_LIN_HTIMER (0xF0000C30u)
+#define ASCLIN6_FLAGS /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FLAGS*)0xF0000C34u)
+#define ASCLIN6_FLAGSSET /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FLAGSSET*)0xF0000C38u)
+#define ASCLIN6_FLAGSCLEAR /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FLAGSCLEAR*)0xF0000C3Cu)
+#define ASCLIN6_FLAGSENABLE /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FLAGSENABLE*)0xF0000C40u)
+#define ASCLIN6_TXDATA /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_TXDATA*)0xF0000C44u)
+#define ASCLIN6_RXDATA /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_RXDATA*)0xF0000C48u)
+#define ASCLIN6_CSR /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_CSR*)0xF0000C4Cu)
+#define ASCLIN6_RXDATAD /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_RXDATAD*)0xF0000C50u)
+#define ASCLIN6_OCS (0xF0000CE8u)
+#define ASCLIN6_KRSTCLR (0xF0000CECu)
+#define ASCLIN6_KRST1 (0xF0000CF0u)
+#define ASCLIN6_KRST0 (0xF0000CF4u)
+#define ASCLIN6_ACCEN1 (0xF0000CF8u)
+#define ASCLIN6_ACCEN0 (0xF0000CFCu)
+#define ASCLIN7_CLC /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_CLC*)0xF0000D00u)
+#define ASCLIN7_IOCR (0xF0000D04u)
+#define ASCLIN7_ID (0xF0000D08u)
+#define ASCLIN7_TXFIFOCON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_TXFIFOCON*)0xF0000D0Cu)
+#define ASCLIN7_RXFIFOCON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_RXFIFOCON*)0xF0000D10u)
+#define ASCLIN7_BITCON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_BITCON*)0xF0000D14u)
+#define ASCLIN7_FRAMECON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FRAMECON*)0xF0000D18u)
+#define ASCLIN7_DATCON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_DATCON*)0xF0000D1Cu)
+#define ASCLIN7_BRG /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_BRG*)0xF0000D20u)
+#define ASCLIN7_BRD (0xF0000D24u)
+#define ASCLIN7_LIN_CON (0xF0000D28u)
+#define ASCLIN7_LIN_BTIMER (0xF0000D2Cu)
+#define ASCLIN7_LIN_HTIMER (0xF0000D30u)
+#define ASCLIN7_FLAGS /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FLAGS*)0xF0000D34u)
+#define ASCLIN7_FLAGSSET /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FLAGSSET*)0xF0000D38u)
+#define ASCLIN7_FLAGSCLEAR /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FLAGSCLEAR*)0xF0000D3Cu)
+#define ASCLIN7_FLAGSENABLE /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FLAGSENABLE*)0xF0000D40u)
+#define ASCLIN7_TXDATA /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_TXDATA*)0xF0000D44u)
+#define ASCLIN7_RXDATA /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_RXDATA*)0xF0000D48u)
+#define ASCLIN7_CSR /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_CSR*)0xF0000D4Cu)
+#define ASCLIN7_RXDATAD /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_RXDATAD*)0xF0000D50u)
+#define ASCLIN7_OCS (0xF0000DE8u)
+#define ASCLIN7_KRSTCLR (0xF0000DECu)
+#define ASCLIN7_KRST1 (0xF0000DF0u)
+#define ASCLIN7_KRST0 (0xF0000DF4u)
+#define ASCLIN7_ACCEN1 (0xF0000DF8u)
+#define ASCLIN7_ACCEN0 (0xF0000DFCu)
+#define ASCLIN8_CLC /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_CLC*)0xF0000E00u)
+#define ASCLIN8_IOCR (0xF0000E04u)
+#define ASCLIN8_ID (0xF0000E08u)
+#define ASCLIN8_TXFIFOCON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_TXFIFOCON*)0xF0000E0Cu)
+#define ASCLIN8_RXFIFOCON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_RXFIFOCON*)0xF0000E10u)
+#define ASCLIN8_BITCON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_BITCON*)0xF0000E14u)
+#define ASCLIN8_FRAMECON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FRAMECON*)0xF0000E18u)
+#define ASCLIN8_DATCON /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_DATCON*)0xF0000E1Cu)
+#define ASCLIN8_BRG /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_BRG*)0xF0000E20u)
+#define ASCLIN8_BRD (0xF0000E24u)
+#define ASCLIN8_LIN_CON (0xF0000E28u)
+#define ASCLIN8_LIN_BTIMER (0xF0000E2Cu)
+#define ASCLIN8_LIN_HTIMER (0xF0000E30u)
+#define ASCLIN8_FLAGS /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FLAGS*)0xF0000E34u)
+#define ASCLIN8_FLAGSSET /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FLAGSSET*)0xF0000E38u)
+#define ASCLIN8_FLAGSCLEAR /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FLAGSCLEAR*)0xF0000E3Cu)
+#define ASCLIN8_FLAGSENABLE /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_FLAGSENABLE*)0xF0000E40u)
+#define ASCLIN8_TXDATA /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_TXDATA*)0xF0000E44u)
+#define ASCLIN8_RXDATA /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_RXDATA*)0xF0000E48u)
+#define ASCLIN8_CSR /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_CSR*)0xF0000E4Cu)
+#define ASCLIN8_RXDATAD /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_RXDATAD*)0xF0000E50u)
+#define ASCLIN8_OCS (0xF0000EE8u)
+#define ASCLIN8_KRSTCLR (0xF0000EECu)
+#define ASCLIN8_KRST1 (0xF0000EF0u)
+#define ASCLIN8_KRST0 (0xF0000EF4u)
+#define ASCLIN8_ACCEN1 (0xF0000EF8u)
+#define ASCLIN8_ACCEN0 (0xF0000EFCu)
+#define ASCLIN9_CLC /*lint --e(923, 9078)*/ (*(volatile Ifx_ASCLIN_CLC*)0xF0000F00u)
+#define ASCLIN9_IOCR (0xF0000F04u)
+#define ASCLIN9_ID (0xF0000F08u)
+#define CAN0_N1_TX_EFC (0xF02086F0u)
+#define CAN0_N1_TX_EFS (0xF02086F4u)
+#define CAN0_N1_TX_EFA (0xF02086F8u)
+#define CAN0_ACCENCTR0 (0xF02080DCu)
+#define CAN0_N2_ACCENNODE0 (0xF0208900u)
+#define CAN0_N2_STARTADR (0xF0208908u)
+#define CAN0_N2_ENDADR (0xF020890Cu)
+#define CAN0_N2_ISREG (0xF0208910u)
+#define CAN0_OCS (0xF02080E8u)
+#define CAN0_N2_GRINT1 (0xF0208914u)
+#define CAN0_N2_GRINT2 (0xF0208918u)
+#define CAN0_N2_NT_CCR (0xF0208920u)
+#define CAN0_N2_NT_ATTR (0xF0208924u)
+#define CAN0_N2_NT_BTTR (0xF0208928u)
+#define CAN0_KRSTCLR (0xF02080ECu)
+#define CAN0_N2_NT_CTTR (0xF020892Cu)
+#define CAN0_N2_NT_RTR (0xF0208930u)
+#define CAN0_N2_NPCR (0xF0208940u)
+#define CAN0_N2_CREL (0xF0208A00u)
+#define CAN0_KRST1 (0xF02080F0u)
+#define CAN0_N2_ENDN (0xF0208A04u)
+#define CAN0_N2_DBTP (0xF0208A0Cu)
+#define CAN0_N2_TEST (0xF0208A10u)
+#define CAN0_N2_RWD (0xF0208A14u)
+#define CAN0_KRST0 (0xF02080F4u)
+#define CAN0_N2_CCCR (0xF0208A18u)
+#define CAN0_N2_NBTP (0xF0208A1Cu)
+#define CAN0_N2_TSCC (0xF0208A20u)
+#define CAN0_N2_TSCV (0xF0208A24u)
+#define CAN0_N2_TOCC (0xF0208A28u)
+#define CAN0_ACCEN0 (0xF02080FCu)
+#define CAN0_N2_TOCV (0xF0208A2Cu)
+#define CAN0_N2_ECR (0xF0208A40u)
+#define CAN0_N2_PSR (0xF0208A44u)
+#define CAN0_N2_TDCR (0xF0208A48u)
+#define CAN0_N0_ACCENNODE0 (0xF0208100u)
+#define CAN0_N2_IR (0xF0208A50u)
+#define CAN0_N2_IE (0xF0208A54u)
+#define CAN0_N2_GFC (0xF0208A80u)
+#define CAN0_N2_SIDFC (0xF0208A84u)
+#define CAN0_N2_XIDFC (0xF0208A88u)
+#define CAN0_N2_XIDAM (0xF0208A90u)
+#define CAN0_N2_HPMS (0xF0208A94u)
+#define CAN0_N2_NDAT1 (0xF0208A98u)
+#define CAN0_N2_NDAT2 (0xF0208A9Cu)
+#define CAN0_N2_RX_F0C (0xF0208AA0u)
+#define CAN0_N0_STARTADR (0xF0208108u)
+#define CAN0_N2_RX_F0S (0xF0208AA4u)
+#define CAN0_N2_RX_F0A (0xF0208AA8u)
+#define CAN0_N2_RX_BC (0xF0208AACu)
+#define CAN0_N2_RX_F1C (0xF0208AB0u)
+#define CAN0_N2_RX_F1S (0xF0208AB4u)
+#define CAN0_N2_RX_F1A (0xF0208AB8u)
+#define CAN0_N2_RX_ESC (0xF0208ABCu)
+#define CAN0_N2_TX_BC (0xF0208AC0u)
+#define CAN0_N2_TX_FQS (0xF0208AC4u)
+#define CAN0_N2_TX_ESC (0xF0208AC8u)
+#define CAN0_N0_ENDADR (0xF020810Cu)
+#define CAN0_N2_TX_BRP (0xF0208ACCu)
+#define CAN0_N2_TX_BAR (0xF0208AD0u)
+#define CAN0_N2_TX_BCR (0xF0208AD4u)
+#define CAN0_N2_TX_BTO (0xF0208AD8u)
+#define CAN0_N2_TX_BCF (0xF0208ADCu)
+#define CAN0_N2_TX_BTIE (0xF0208AE0u)
+#define CAN0_N2_TX_BCIE (0xF0208AE4u)
+#define CAN0_N2_TX_EFC (0xF0208AF0u)
+#define CAN0_N2_TX_EFS (0xF0208AF4u)
+#define CAN0_N2_TX_EFA (0xF0208AF8u)
+#define CAN0_N0_ISREG (0xF0208110u)
+#define CAN0_N3_ACCENNODE0 (0xF0208D00u)
+#define CAN0_N3_STARTADR (0xF0208D08u)
+#define CAN0_N3_ENDADR (0xF0208D0Cu)
+#define CAN0_N3_ISREG (0xF0208D10u)
+#define CAN0_N3_GRINT1 (0xF0208D14u)
+#define CAN0_N3_GRINT2 (0xF0208D18u)
+#define CAN0_N3_NT_CCR (0xF0208D20u)
+#define CAN0_N3_NT_ATTR (0xF0208D24u)
+#define CAN0_N3_NT_BTTR (0xF0208D28u)
+#define CAN0_N3_NT_CTTR (0xF0208D2Cu)
+#define CAN0_N0_GRINT1 (0xF0208114u)
+#define CAN0_N3_NT_RTR (0xF0208D30u)
+#define CAN0_N3_NPCR (0xF0208D40u)
+#define CAN0_N3_CREL (0xF0208E00u)
+#define CAN0_N3_ENDN (0xF0208E04u)
+#define CAN0_N3_DBTP (0xF0208E0Cu)
+#define CAN0_N3_TEST (0xF0208E10u)
+#define CAN0_N3_RWD (0xF0208E14u)
+#define CAN0_N3_CCCR (0xF0208E18u)
+#define CAN0_N3_NBTP (0xF0208E1Cu)
+#define CAN0_N3_TSCC (0xF0208E20u)
+#define CAN0_N0_GRINT2 (0xF0208118u)
+#define CAN0_N3_TSCV (0xF0208E24u)
+#define CAN0_N3_TOCC (0xF0208E28u)
+#define CAN0_N3_TOCV (0xF0208E2Cu)
+#define CAN0_N3_ECR (0xF0208E40u)
+#define CAN0_N3_PSR (0xF0208E44u)
+#define CAN0_N3_TDCR (0xF0208E48u)
+#define CAN0_N3_IR (0xF0208E50u)
+#define CAN0_N3_IE (0xF0208E54u)
+#define CAN0_N3_GFC (0xF0208E80u)
+#define CAN0_N3_SIDFC (0xF0208E84u)
+#define CAN0_N0_NT_CCR (0xF0208120u)
+#define CAN0_N3_XIDFC (0xF0208E88u)
+#define CAN0_N3_XIDAM (0xF0208E90u)
+#define CAN0_N3_HPMS (0xF0208E94u)
+#define CAN0_N3_NDAT1 (0xF0208E98u)
+#define CAN0_N3_NDAT2 (0xF0208E9Cu)
+#define CAN0_N3_RX_F0C (0xF0208EA0u)
+#define CAN0_N3_RX_F0S (0xF0208EA4u)
+#define CAN0_N3_RX_F0A (0xF0208EA8u)
+#define CAN0_N3_RX_BC (0xF0208EACu)
+#define CAN0_N3_RX_F1C (0xF0208EB0u)
+#define CAN0_N0_NT_ATTR (0xF0208124u)
+#define CAN0_N3_RX_F1S (0xF0208EB4u)
+#define CAN0_N3_RX_F1A (0xF0208EB8u)
+#define CAN0_N3_RX_ESC (0xF0208EBCu)
+#define CAN0_N3_TX_BC (0xF0208EC0u)
+#define CAN0_N3_TX_FQS (0xF0208EC4u)
+#define CAN0_N3_TX_ESC (0xF0208EC8u)
+#define CAN0_N3_TX_BRP (0xF0208ECCu)
+#define CAN0_N3_TX_BAR (0xF0208ED0u)
+#define CAN0_N3_TX_BCR (0xF0208ED4u)
+#define CAN0_N3_TX_BTO (0xF0208ED8u)
+#define CAN0_N0_NT_BTTR (0xF0208128u)
+#define CAN0_N3_TX_BCF (0xF0208EDCu)
+#define CAN0_N3_TX_BTIE (0xF0208EE0u)
+#define CAN0_N3_TX_BCIE (0xF0208EE4u)
+#define CAN0_N3_TX_EFC (0xF0208EF0u)
+#define CAN0_N3_TX_EFS (0xF0208EF4u)
+#define CAN0_N3_TX_EFA (0xF0208EF8u)
+#define CAN1_RAM (0xF0210000u)
+#define CAN1_CLC (0xF0218000u)
+#define CAN1_ID (0xF0218008u)
+#define CAN1_MCR (0xF0218030u)
+#define CAN1_ACCENCTR0 (0xF02180DCu)
+#define CAN1_OCS (0xF02180E8u)
+#define CAN1_KRSTCLR (0xF02180ECu)
+#define CAN0_N0_NT_CTTR (0xF020812Cu)
+#define CAN1_KRST1 (0xF02180F0u)
+#define CAN1_KRST0 (0xF02180F4u)
+#define CAN1_ACCEN0 (0xF02180FCu)
+#define CAN1_N0_ACCENNODE0 (0xF0218100u)
+#define CAN1_N0_STARTADR (0xF0218108u)
+#define CAN1_N0_ENDADR (0xF021810Cu)
+#define CAN1_N0_ISREG (0xF0218110u)
+#define CAN1_N0_GRINT1 (0xF0218114u)
+#define CAN1_N0_GRINT2 (0xF0218118u)
+#define CAN1_N0_NT_CCR (0xF0218120u)
+#define CAN1_N0_NT_ATTR (0xF0218124u)
+#define CAN1_N0_NT_BTTR (0xF0218128u)
+#define CAN0_N0_NT_RTR (0xF0208130u)
+#define CAN1_N0_NT_CTTR (0xF021812Cu)
+#define CAN1_N0_NT_RTR (0xF0218130u)
+#define CAN1_N0_NPCR (0xF0218140u)
+#define CAN1_N0_CREL (0xF0218200u)
+#define CAN1_N0_ENDN (0xF0218204u)
+#define CAN1_N0_DBTP (0xF021820Cu)
+#define CAN1_N0_TEST (0xF0218210u)
+#define CAN1_N0_RWD (0xF0218214u)
+#define CAN1_N0_CCCR (0xF0218218u)
+#define CAN1_N0_NBTP (0xF021821Cu)
+#define CAN0_N0_NPCR (0xF0208140u)
+#define CAN1_N0_TSCC (0xF0218220u)
+#define CAN1_N0_TSCV (0xF0218224u)
+#define CAN1_N0_TOCC (0xF0218228u)
+#define CAN1_N0_TOCV (0xF021822Cu)
+#define CAN1_N0_ECR (0xF0218240u)
+#define CAN1_N0_PSR (0xF0218244u)
+#define CAN1_N0_TDCR (0xF0218248u)
+#define CAN1_N0_IR (0xF0218250u)
+#define CAN1_N0_IE (0xF0218254u)
+#define CAN1_N0_GFC (0xF0218280u)
+#define CAN0_N0_TTCR (0xF02081F0u)
+#define CAN1_N0_SIDFC (0xF0218284u)
+#define CAN1_N0_XIDFC (0xF0218288u)
+#define CAN1_N0_XIDAM (0xF0218290u)
+#define CAN1_N0_HPMS (0xF0218294u)
+#define CAN1_N0_NDAT1 (0xF0218298u)
+#define CAN1_N0_NDAT2 (0xF021829Cu)
+#define CAN1_N0_RX_F0C (0xF02182A0u)
+#define CAN1_N0_RX_F0S (0xF02182A4u)
+#define CAN1_N0_RX_F0A (0xF02182A8u)
+#define CAN1_N0_RX_BC (0xF02182ACu)
+#define CAN0_N0_CREL (0xF0208200u)
+#define CAN1_N0_RX_F1C (0xF02182B0u)
+#define CAN1_N0_RX_F1S (0xF02182B4u)
+#define CAN1_N0_RX_F1A (0xF02182B8u)
+#define CAN1_N0_RX_ESC (0xF02182BCu)
+#define CAN1_N0_TX_BC (0xF02182C0u)
+#define CAN1_N0_TX_FQS (0xF02182C4u)
+#define CAN1_N0_TX_ESC (0xF02182C8u)
+#define CAN1_N0_TX_BRP (0xF02182CCu)
+#define CAN1_N0_TX_BAR (0xF02182D0u)
+#define CAN1_N0_TX_BCR (0xF02182D4u)
+#define CAN0_N0_ENDN (0xF0208204u)
+#define CAN1_N0_TX_BTO (0xF02182D8u)
+#define CAN1_N0_TX_BCF (0xF02182DCu)
+#define CAN1_N0_TX_BTIE (0xF02182E0u)
+#define CAN1_N0_TX_BCIE (0xF02182E4u)
+#define CAN1_N0_TX_EFC (0xF02182F0u)
+#define CAN1_N0_TX_EFS (0xF02182F4u)
+#define CAN1_N0_TX_EFA (0xF02182F8u)
+#define CAN1_N1_ACCENNODE0 (0xF0218500u)
+#define CAN1_N1_STARTADR (0xF0218508u)
+#define CAN1_N1_ENDADR (0xF021850Cu)
+#define CAN0_N0_DBTP (0xF020820Cu)
+#define CAN1_N1_ISREG (0xF0218510u)
+#define CAN1_N1_GRINT1 (0xF0218514u)
+#define CAN1_N1_GRINT2 (0xF0218518u)
+#define CAN1_N1_NT_CCR (0xF0218520u)
+#define CAN1_N1_NT_ATTR (0xF0218524u)
+#define CAN1_N1_NT_BTTR (0xF0218528u)
+#define CAN1_N1_NT_CTTR (0xF021852Cu)
+#define CAN1_N1_NT_RTR (0xF0218530u)
+#define CAN1_N1_NPCR (0xF0218540u)
+#define CAN1_N1_CREL (0xF0218600u)
+#define CAN0_N0_TEST (0xF0208210u)
+#define CAN1_N1_ENDN (0xF0218604u)
+#define CAN1_N1_DBTP (0xF021860Cu)
+#define CAN1_N1_TEST (0xF0218610u)
+#define CAN1_N1_RWD (0xF0218614u)
+#define CAN1_N1_CCCR (0xF0218618u)
+#define CAN1_N1_NBTP (0xF021861Cu)
+#define CAN1_N1_TSCC (0xF0218620u)
+#define CAN1_N1_TSCV (0xF0218624u)
+#define CAN1_N1_TOCC (0xF0218628u)
+#define CAN1_N1_TOCV (0xF021862Cu)
+#define CAN0_N0_RWD (0xF0208214u)
+#define CAN1_N1_ECR (0xF0218640u)
+#define CAN1_N1_PSR (0xF0218644u)
+#define CAN1_N1_TDCR (0xF0218648u)
+#define CAN1_N1_IR (0xF0218650u)
+#define CAN1_N1_IE (0xF0218654u)
+#define CAN1_N1_GFC (0xF0218680u)
+#define CAN1_N1_SIDFC (0xF0218684u)
+#define CAN1_N1_XIDFC (0xF0218688u)
+#define CAN1_N1_XIDAM (0xF0218690u)
+#define CAN1_N1_HPMS (0xF0218694u)
+#define CAN0_N0_CCCR (0xF0208218u)
+#define CAN1_N1_NDAT1 (0xF0218698u)
+#define CAN1_N1_NDAT2 (0xF021869Cu)
+#define CAN1_N1_RX_F0C (0xF02186A0u)
+#define CAN1_N1_RX_F0S (0xF02186A4u)
+#define CAN1_N1_RX_F0A (0xF02186A8u)
+#define CAN1_N1_RX_BC (0xF02186ACu)
+#define CAN1_N1_RX_F1C (0xF02186B0u)
+#define CAN1_N1_RX_F1S (0xF02186B4u)
+#define CAN1_N1_RX_F1A (0xF02186B8u)
+#define CAN1_N1_RX_ESC (0xF02186BCu)
+#define CAN0_N0_NBTP (0xF020821Cu)
+#define CAN1_N1_TX_BC (0xF02186C0u)
+#define CAN1_N1_TX_FQS (0xF02186C4u)
+#define CAN1_N1_TX_ESC (0xF02186C8u)
+#define CAN1_N1_TX_BRP (0xF02186CCu)
+#define CAN1_N1_TX_BAR (0xF02186D0u)
+#define CAN1_N1_TX_BCR (0xF02186D4u)
+#define CAN1_N1_TX_BTO (0xF02186D8u)
+#define CAN1_N1_TX_BCF (0xF02186DCu)
+#define CAN1_N1_TX_BTIE (0xF02186E0u)
+#define CAN1_N1_TX_BCIE (0xF02186E4u)
+#define CAN0_N0_TSCC (0xF0208220u)
+#define CAN1_N1_TX_EFC (0xF02186F0u)
+#define CAN1_N1_TX_EFS (0xF02186F4u)
+#define CAN1_N1_TX_EFA (0xF02186F8u)
+#define CAN1_N2_ACCENNODE0 (0xF0218900u)
+#define CAN1_N2_STARTADR (0xF0218908u)
+#define CAN1_N2_ENDADR (0xF021890Cu)
+#define CAN1_N2_ISREG (0xF0218910u)
+#define CAN1_N2_GRINT1 (0xF0218914u)
+#define CAN1_N2_GRINT2 (0xF0218918u)
+#define CAN1_N2_NT_CCR (0xF0218920u)
+#define CAN0_N0_TSCV (0xF0208224u)
+#define CAN1_N2_NT_ATTR (0xF0218924u)
+#define CAN1_N2_NT_BTTR (0xF0218928u)
+#define CAN1_N2_NT_CTTR (0xF021892Cu)
+#define CAN1_N2_NT_RTR (0xF0218930u)
+#define CAN1_N2_NPCR (0xF0218940u)
+#define CAN1_N2_CREL (0xF0218A00u)
+#define CAN1_N2_ENDN (0xF0218A04u)
+#define CAN1_N2_DBTP (0xF0218A0Cu)
+#define CAN1_N2_TEST (0xF0218A10u)
+#define CAN1_N2_RWD (0xF0218A14u)
+#define CAN0_N0_TOCC (0xF0208228u)
+#define CAN1_N2_CCCR (0xF0218A18u)
+#define CAN1_N2_NBTP (0xF0218A1Cu)
+#define CAN1_N2_TSCC (0xF0218A20u)
+#define CAN1_N2_TSCV (0xF0218A24u)
+#define CAN1_N2_TOCC (0xF0218A28u)
+#define CAN1_N2_TOCV (0xF0218A2Cu)
+#define CAN1_N2_ECR (0xF0218A40u)
+#define CAN1_N2_PSR (0xF0218A44u)
+#define CAN1_N2_TDCR (0xF0218A48u)
+#define CAN1_N2_IR (0xF0218A50u)
+#define CAN0_N0_TOCV (0xF020822Cu)
+#define CAN1_N2_IE (0xF0218A54u)
+#define CAN1_N2_GFC (0xF0218A80u)
+#define CAN1_N2_SIDFC (0xF0218A84u)
+#define CAN1_N2_XIDFC (0xF0218A88u)
+#define CAN1_N2_XIDAM (0xF0218A90u)
+#define CAN1_N2_HPMS (0xF0218A94u)
+#define CAN1_N2_NDAT1 (0xF0218A98u)
+#define CAN1_N2_NDAT2 (0xF0218A9Cu)
+#define CAN1_N2_RX_F0C (0xF0218AA0u)
+#define CAN1_N2_RX_F0S (0xF0218AA4u)
+#define CAN0_N0_ECR (0xF0208240u)
+#define CAN1_N2_RX_F0A (0xF0218AA8u)
+#define CAN1_N2_RX_BC (0xF0218AACu)
+#define CAN1_N2_RX_F1C (0xF0218AB0u)
+#define CAN1_N2_RX_F1S (0xF0218AB4u)
+#define CAN1_N2_RX_F1A (0xF0218AB8u)
+#define CAN1_N2_RX_ESC (0xF0218ABCu)
+#define CAN1_N2_TX_BC (0xF0218AC0u)
+#define CAN1_N2_TX_FQS (0xF0218AC4u)
+#define CAN1_N2_TX_ESC (0xF0218AC8u)
+#define CAN1_N2_TX_BRP (0xF0218ACCu)
+#define CAN0_N0_PSR (0xF0208244u)
+#define CAN1_N2_TX_BAR (0xF0218AD0u)
+#define CAN1_N2_TX_BCR (0xF0218AD4u)
+#define CAN1_N2_TX_BTO (0xF0218AD8u)
+#define CAN1_N2_TX_BCF (0xF0218ADCu)
+#define CAN1_N2_TX_BTIE (0xF0218AE0u)
+#define CAN1_N2_TX_BCIE (0xF0218AE4u)
+#define CAN1_N2_TX_EFC (0xF0218AF0u)
+#define CAN1_N2_TX_EFS (0xF0218AF4u)
+#define CAN1_N2_TX_EFA (0xF0218AF8u)
+#define CAN1_N3_ACCENNODE0 (0xF0218D00u)
+#define CAN0_N0_TDCR (0xF0208248u)
+#define CAN1_N3_STARTADR (0xF0218D08u)
+#define CAN1_N3_ENDADR (0xF0218D0Cu)
+#define CAN1_N3_ISREG (0xF0218D10u)
+#define CAN1_N3_GRINT1 (0xF0218D14u)
+#define CAN1_N3_GRINT2 (0xF0218D18u)
+#define CAN1_N3_NT_CCR (0xF0218D20u)
+#define CAN1_N3_NT_ATTR (0xF0218D24u)
+#define CAN1_N3_NT_BTTR (0xF0218D28u)
+#define CAN1_N3_NT_CTTR (0xF0218D2Cu)
+#define CAN1_N3_NT_RTR (0xF0218D30u)
+#define CAN0_N0_IR (0xF0208250u)
+#define CAN1_N3_NPCR (0xF0218D40u)
+#define CAN1_N3_CREL (0xF0218E00u)
+#define CAN1_N3_ENDN (0xF0218E04u)
+#define CAN1_N3_DBTP (0xF0218E0Cu)
+#define CAN1_N3_TEST (0xF0218E10u)
+#define CAN1_N3_RWD (0xF0218E14u)
+#define CAN1_N3_CCCR (0xF0218E18u)
+#define CAN1_N3_NBTP (0xF0218E1Cu)
+#define CAN1_N3_TSCC (0xF0218E20u)
+#define CAN1_N3_TSCV (0xF0218E24u)
+#define CAN0_N0_IE (0xF0208254u)
+#define CAN1_N3_TOCC (0xF0218E28u)
+#define CAN1_N3_TOCV (0xF0218E2Cu)
+#define CAN1_N3_ECR (0xF0218E40u)
+#define CAN1_N3_PSR (0xF0218E44u)
+#define CAN1_N3_TDCR (0xF0218E48u)
+#define CAN1_N3_IR (0xF0218E50u)
+#define CAN1_N3_IE (0xF0218E54u)
+#define CAN1_N3_GFC (0xF0218E80u)
+#define CAN1_N3_SIDFC (0xF0218E84u)
+#define CAN1_N3_XIDFC (0xF0218E88u)
+#define CAN0_N0_GFC (0xF0208280u)
+#define CAN1_N3_XIDAM (0xF0218E90u)
+#define CAN1_N3_HPMS (0xF0218E94u)
+#define CAN1_N3_NDAT1 (0xF0218E98u)
+#define CAN1_N3_NDAT2 (0xF0218E9Cu)
+#define CAN1_N3_RX_F0C (0xF0218EA0u)
+#define CAN1_N3_RX_F0S (0xF0218EA4u)
+#define CAN1_N3_RX_F0A (0xF0218EA8u)
+#define CAN1_N3_RX_BC (0xF0218EACu)
+#define CAN1_N3_RX_F1C (0xF0218EB0u)
+#define CAN1_N3_RX_F1S (0xF0218EB4u)
+#define CAN0_N0_SIDFC (0xF0208284u)
+#define CAN1_N3_RX_F1A (0xF0218EB8u)
+#define CAN1_N3_RX_ESC (0xF0218EBCu)
+#define CAN1_N3_TX_BC (0xF0218EC0u)
+#define CAN1_N3_TX_FQS (0xF0218EC4u)
+#define CAN1_N3_TX_ESC (0xF0218EC8u)
+#define CAN1_N3_TX_BRP (0xF0218ECCu)
+#define CAN1_N3_TX_BAR (0xF0218ED0u)
+#define CAN1_N3_TX_BCR (0xF0218ED4u)
+#define CAN1_N3_TX_BTO (0xF0218ED8u)
+#define CAN1_N3_TX_BCF (0xF0218EDCu)
+#define CAN0_N0_XIDFC (0xF0208288u)
+#define CAN1_N3_TX_BTIE (0xF0218EE0u)
+#define CAN1_N3_TX_BCIE (0xF0218EE4u)
+#define CAN1_N3_TX_EFC (0xF0218EF0u)
+#define CAN1_N3_TX_EFS (0xF0218EF4u)
+#define CAN1_N3_TX_EFA (0xF0218EF8u)
+#define CAN0_N0_XIDAM (0xF0208290u)
+#define CAN0_N0_HPMS (0xF0208294u)
+#define CAN0_N0_NDAT1 (0xF0208298u)
+#define CAN0_N0_NDAT2 (0xF020829Cu)
+#define CAN0_N0_RX_F0C (0xF02082A0u)
+#define CAN0_N0_RX_F0S (0xF02082A4u)
+#define CAN0_N0_RX_F0A (0xF02082A8u)
+#define CAN0_N0_RX_BC (0xF02082ACu)
+#define CAN0_N0_RX_F1C (0xF02082B0u)
+#define CAN0_N0_RX_F1S (0xF02082B4u)
+#define CAN0_N0_RX_F1A (0xF02082B8u)
+#define CAN0_N0_RX_ESC (0xF02082BCu)
+#define CAN0_N0_TX_BC (0xF02082C0u)
+#define CAN0_N0_TX_FQS (0xF02082C4u)
+#define CAN0_N0_TX_ESC (0xF02082C8u)
+#define CAN0_N0_TX_BRP (0xF02082CCu)
+#define CAN0_N0_TX_BAR (0xF02082D0u)
+#define CAN0_N0_TX_BCR (0xF02082D4u)
+#define CAN0_N0_TX_BTO (0xF02082D8u)
+#define CAN0_N0_TX_BCF (0xF02082DCu)
+#define CAN0_N0_TX_BTIE (0xF02082E0u)
+#define CAN0_N0_TX_BCIE (0xF02082E4u)
+#define CAN0_N0_TX_EFC (0xF02082F0u)
+#define CAN0_N0_TX_EFS (0xF02082F4u)
+#define CAN0_N0_TX_EFA (0xF02082F8u)
+#define CAN0_N0_TT_TMC (0xF0208300u)
+#define CAN0_N0_TT_RMC (0xF0208304u)
+#define CAN0_N0_TT_OCF (0xF0208308u)
+#define CAN0_N0_TT_MLM (0xF020830Cu)
+#define CAN0_N0_TT_TURCF (0xF0208310u)
+#define CAN0_N0_TT_OCN (0xF0208314u)
+#define CAN0_N0_TT_GTP (0xF0208318u)
+#define CAN0_N0_TT_TMK (0xF020831Cu)
+#define CAN0_N0_TT_IR (0xF0208320u)
+#define CAN0_N0_TT_IE (0xF0208324u)
+#define CAN0_N0_TT_OST (0xF020832Cu)
+#define CAN0_N0_TT_TURNA (0xF0208330u)
+#define CAN0_N0_TT_LGT (0xF0208334u)
+#define CAN0_N0_TT_CTC (0xF0208338u)
+#define CAN0_N0_TT_CPT (0xF020833Cu)
+#define CAN0_N0_TT_CSM (0xF0208340u)
+#define CAN0_N1_ACCENNODE0 (0xF0208500u)
+#define CAN0_N1_STARTADR (0xF0208508u)
+#define CAN0_N1_ENDADR (0xF020850Cu)
+#define CAN0_N1_ISREG (0xF0208510u)
+#define CAN0_N1_GRINT1 (0xF0208514u)
+#define CAN0_N1_GRINT2 (0xF0208518u)
+#define CAN0_N1_NT_CCR (0xF0208520u)
+#define CAN0_N1_NT_ATTR (0xF0208524u)
+#define CAN0_N1_NT_BTTR (0xF0208528u)
+#define CAN0_N1_NT_CTTR (0xF020852Cu)
+#define CAN0_N1_NT_RTR (0xF0208530u)
+#define CAN0_N1_NPCR (0xF0208540u)
+#define MODULE_CAN0 (0xF0200000u)
+#define CAN0_N1_CREL (0xF0208600u)
+#define MODULE_CAN1 (0xF0210000u)
+#define CAN0_N1_ENDN (0xF0208604u)
+#define CAN0_N1_DBTP (0xF020860Cu)
+#define CAN0_N1_TEST (0xF0208610u)
+#define CAN0_N1_RWD (0xF0208614u)
+#define CAN0_N1_CCCR (0xF0208618u)
+#define CAN0_N1_NBTP (0xF020861Cu)
+#define CAN0_N1_TSCC (0xF0208620u)
+#define CAN0_N1_TSCV (0xF0208624u)
+#define CAN0_N1_TOCC (0xF0208628u)
+#define CAN0_N1_TOCV (0xF020862Cu)
+#define CAN0_N1_ECR (0xF0208640u)
+#define CAN0_N1_PSR (0xF0208644u)
+#define CAN0_N1_TDCR (0xF0208648u)
+#define CAN0_RAM (0xF0200000u)
+#define CAN0_N1_IR (0xF0208650u)
+#define CAN0_N1_IE (0xF0208654u)
+#define CAN0_N1_GFC (0xF0208680u)
+#define CAN0_N1_SIDFC (0xF0208684u)
+#define CAN0_CLC (0xF0208000u)
+#define CAN0_N1_XIDFC (0xF0208688u)
+#define CAN0_N1_XIDAM (0xF0208690u)
+#define CAN0_N1_HPMS (0xF0208694u)
+#define CAN0_N1_NDAT1 (0xF0208698u)
+#define CAN0_N1_NDAT2 (0xF020869Cu)
+#define CAN0_ID (0xF0208008u)
+#define CAN0_N1_RX_F0C (0xF02086A0u)
+#define CAN0_N1_RX_F0S (0xF02086A4u)
+#define CAN0_N1_RX_F0A (0xF02086A8u)
+#define CAN0_N1_RX_BC (0xF02086ACu)
+#define CAN0_MCR (0xF0208030u)
+#define CAN0_N1_RX_F1C (0xF02086B0u)
+#define CAN0_N1_RX_F1S (0xF02086B4u)
+#define CAN0_N1_RX_F1A (0xF02086B8u)
+#define CAN0_N1_RX_ESC (0xF02086BCu)
+#define CAN0_BUFADR (0xF0208034u)
+#define CAN0_N1_TX_BC (0xF02086C0u)
+#define CAN0_N1_TX_FQS (0xF02086C4u)
+#define CAN0_N1_TX_ESC (0xF02086C8u)
+#define CAN0_N1_TX_BRP (0xF02086CCu)
+#define CAN0_N1_TX_BAR (0xF02086D0u)
+#define CAN0_MECR (0xF0208040u)
+#define CAN0_N1_TX_BCR (0xF02086D4u)
+#define CAN0_N1_TX_BTO (0xF02086D8u)
+#define CAN0_N1_TX_BCF (0xF02086DCu)
+#define CAN0_N1_TX_BTIE (0xF02086E0u)
+#define CAN0_MESTAT (0xF0208044u)
+#define CAN0_N1_TX_BCIE (0xF02086E4u)
+#define CBS_TRC2 (0xF0000428u)
+#define CBS_TRC3 (0xF000042Cu)
+#define CBS_TRC4 (0xF0000430u)
+#define CBS_TRC5 (0xF0000434u)
+#define CBS_TRHSM (0xF0000438u)
+#define CBS_TRMC (0xF000043Cu)
+#define CBS_TLCC0 (0xF0000440u)
+#define CBS_TLCC1 (0xF0000444u)
+#define CBS_TLCV0 (0xF0000450u)
+#define CBS_TLCV1 (0xF0000454u)
+#define CBS_TRSS (0xF0000460u)
+#define CBS_JTAGID (0xF0000464u)
+#define CBS_COMDATA (0xF0000468u)
+#define CBS_IOSR (0xF000046Cu)
+#define CBS_TLS (0xF0000470u)
+#define CBS_TCTL (0xF0000474u)
+#define CBS_OEC (0xF0000478u)
+#define CBS_OCNTRL (0xF000047Cu)
+#define CBS_OSTATE (0xF0000480u)
+#define CBS_INTMOD (0xF0000484u)
+#define CBS_ICTSA (0xF0000488u)
+#define CBS_ICTTA (0xF000048Cu)
+#define CBS_TLC (0xF0000490u)
+#define CBS_TL1ST (0xF0000494u)
+#define CBS_TLCHE (0xF0000498u)
+#define CBS_TLCHS (0xF000049Cu)
+#define CBS_TRIGS (0xF00004A0u)
+#define CBS_TRIGC (0xF00004A4u)
+#define CBS_TLT (0xF00004A8u)
+#define CBS_TLTTH (0xF00004ACu)
+#define CBS_TCCB (0xF00004B0u)
+#define CBS_TCCH (0xF00004B4u)
+#define CBS_TCTGB (0xF00004B8u)
+#define CBS_TCM (0xF00004BCu)
+#define CBS_TREC0 (0xF00004C0u)
+#define CBS_TREC1 (0xF00004C4u)
+#define CBS_TREC2 (0xF00004C8u)
+#define CBS_TREC3 (0xF00004CCu)
+#define CBS_TREC4 (0xF00004D0u)
+#define CBS_TREC5 (0xF00004D4u)
+#define CBS_TRMT (0xF00004DCu)
+#define CBS_TRTGB0_L (0xF00004E0u)
+#define CBS_TRTGB0_H (0xF00004E4u)
+#define CBS_TRTGB1_L (0xF00004E8u)
+#define CBS_TRTGB1_H (0xF00004ECu)
+#define CBS_IFSA (0xF00004F0u)
+#define CBS_IFSC (0xF00004F4u)
+#define CBS_TRIG0 (0xF0000500u)
+#define CBS_TRIG1 (0xF0000504u)
+#define CBS_TRIG2 (0xF0000508u)
+#define CBS_TRIG3 (0xF000050Cu)
+#define CBS_TRIG4 (0xF0000510u)
+#define CBS_TRIG5 (0xF0000514u)
+#define CBS_ACCEN1 (0xF00005F8u)
+#define CBS_ACCEN0 (0xF00005FCu)
+#define MODULE_CBS (0xF0000400u)
+#define CBS_JDPID (0xF0000408u)
+#define CBS_OIFM (0xF000040Cu)
+#define CBS_TIPR (0xF0000410u)
+#define CBS_TOPR (0xF0000414u)
+#define CBS_TOPPS (0xF0000418u)
+#define CBS_TCIP (0xF000041Cu)
+#define CBS_TRC0 (0xF0000420u)
+#define CBS_TRC1 (0xF0000424u)
+#define CCU60_T12 (0xF0002A20u)
+#define CCU60_T12PR (0xF0002A24u)
+#define CCU60_T12DTC (0xF0002A28u)
+#define CCU60_CC6R0 (0xF0002A30u)
+#define CCU60_CC6R1 (0xF0002A34u)
+#define CCU60_CC6R2 (0xF0002A38u)
+#define CCU60_CC6SR0 (0xF0002A40u)
+#define CCU60_CC6SR1 (0xF0002A44u)
+#define CCU60_CC6SR2 (0xF0002A48u)
+#define CCU60_T13 (0xF0002A50u)
+#define CCU60_T13PR (0xF0002A54u)
+#define CCU60_CC63R (0xF0002A58u)
+#define CCU60_CC63SR (0xF0002A5Cu)
+#define CCU60_CMPSTAT (0xF0002A60u)
+#define CCU60_CMPMODIF (0xF0002A64u)
+#define CCU60_T12MSEL (0xF0002A68u)
+#define CCU60_TCTR0 (0xF0002A70u)
+#define CCU60_TCTR2 (0xF0002A74u)
+#define CCU60_TCTR4 (0xF0002A78u)
+#define CCU60_MODCTR (0xF0002A80u)
+#define CCU60_TRPCTR (0xF0002A84u)
+#define CCU60_PSLR (0xF0002A88u)
+#define CCU60_MCMOUTS (0xF0002A8Cu)
+#define CCU60_MCMOUT (0xF0002A90u)
+#define CCU60_MCMCTR (0xF0002A94u)
+#define CCU60_IMON (0xF0002A98u)
+#define CCU60_LI (0xF0002A9Cu)
+#define CCU60_IS (0xF0002AA0u)
+#define CCU60_ISS (0xF0002AA4u)
+#define CCU60_ISR (0xF0002AA8u)
+#define CCU60_INP (0xF0002AACu)
+#define CCU60_IEN (0xF0002AB0u)
+#define CCU60_OCS (0xF0002AE8u)
+#define CCU60_KRSTCLR (0xF0002AECu)
+#define CCU60_KRST1 (0xF0002AF0u)
+#define CCU60_KRST0 (0xF0002AF4u)
+#define CCU60_ACCEN0 (0xF0002AFCu)
+#define CCU61_CLC (0xF0002B00u)
+#define CCU61_MCFG (0xF0002B04u)
+#define CCU61_ID (0xF0002B08u)
+#define CCU61_PISEL0 (0xF0002B10u)
+#define CCU61_PISEL2 (0xF0002B14u)
+#define CCU61_KSCSR (0xF0002B1Cu)
+#define CCU61_T12 (0xF0002B20u)
+#define CCU61_T12PR (0xF0002B24u)
+#define CCU61_T12DTC (0xF0002B28u)
+#define CCU61_CC6R0 (0xF0002B30u)
+#define CCU61_CC6R1 (0xF0002B34u)
+#define CCU61_CC6R2 (0xF0002B38u)
+#define CCU61_CC6SR0 (0xF0002B40u)
+#define CCU61_CC6SR1 (0xF0002B44u)
+#define CCU61_CC6SR2 (0xF0002B48u)
+#define CCU61_T13 (0xF0002B50u)
+#define CCU61_T13PR (0xF0002B54u)
+#define CCU61_CC63R (0xF0002B58u)
+#define CCU61_CC63SR (0xF0002B5Cu)
+#define CCU61_CMPSTAT (0xF0002B60u)
+#define CCU61_CMPMODIF (0xF0002B64u)
+#define CCU61_T12MSEL (0xF0002B68u)
+#define CCU61_TCTR0 (0xF0002B70u)
+#define CCU61_TCTR2 (0xF0002B74u)
+#define CCU61_TCTR4 (0xF0002B78u)
+#define CCU61_MODCTR (0xF0002B80u)
+#define CCU61_TRPCTR (0xF0002B84u)
+#define CCU61_PSLR (0xF0002B88u)
+#define CCU61_MCMOUTS (0xF0002B8Cu)
+#define CCU61_MCMOUT (0xF0002B90u)
+#define CCU61_MCMCTR (0xF0002B94u)
+#define CCU61_IMON (0xF0002B98u)
+#define CCU61_LI (0xF0002B9Cu)
+#define CCU61_IS (0xF0002BA0u)
+#define CCU61_ISS (0xF0002BA4u)
+#define CCU61_ISR (0xF0002BA8u)
+#define CCU61_INP (0xF0002BACu)
+#define CCU61_IEN (0xF0002BB0u)
+#define CCU61_OCS (0xF0002BE8u)
+#define CCU61_KRSTCLR (0xF0002BECu)
+#define CCU61_KRST1 (0xF0002BF0u)
+#define CCU61_KRST0 (0xF0002BF4u)
+#define CCU61_ACCEN0 (0xF0002BFCu)
+#define MODULE_CCU60 (0xF0002A00u)
+#define MODULE_CCU61 (0xF0002B00u)
+#define CCU60_CLC (0xF0002A00u)
+#define CCU60_MCFG (0xF0002A04u)
+#define CCU60_ID (0xF0002A08u)
+#define CCU60_MOSEL (0xF0002A0Cu)
+#define CCU60_PISEL0 (0xF0002A10u)
+#define CCU60_PISEL2 (0xF0002A14u)
+#define CCU60_KSCSR (0xF0002A1Cu)
+#define CONVCTRL_PHSCFG (0xF0025080u)
+#define CONVCTRL_PHSSFTY (0xF0025084u)
+#define MODULE_CONVCTRL (0xF0025000u)
+#define CONVCTRL_CLC (0xF0025000u)
+#define CONVCTRL_ID (0xF0025008u)
+#define CONVCTRL_OCS (0xF0025028u)
+#define CONVCTRL_KRSTCLR (0xF002502Cu)
+#define CONVCTRL_KRST1 (0xF0025030u)
+#define CONVCTRL_KRST0 (0xF0025034u)
+#define CONVCTRL_ACCEN0 (0xF002503Cu)
+#define CONVCTRL_CCCTRL (0xF002507Cu)
+#define CPU0_SPR_SPROT_RGNACCENB_R3 (0xF880E0BCu)
+#define CPU0_SPR_SPROT_RGNACCENA_R4 (0xF880E0C8u)
+#define CPU0_SPR_SPROT_RGNACCENB_R4 (0xF880E0CCu)
+#define CPU0_SPR_SPROT_RGNACCENA_R5 (0xF880E0D8u)
+#define CPU0_SPR_SPROT_RGNACCENB_R5 (0xF880E0DCu)
+#define CPU0_SPR_SPROT_RGNACCENA_R6 (0xF880E0E8u)
+#define CPU0_SPR_SPROT_RGNACCENB_R6 (0xF880E0ECu)
+#define CPU0_SPR_SPROT_RGNACCENA_R7 (0xF880E0F8u)
+#define CPU0_SPR_SPROT_RGNACCENB_R7 (0xF880E0FCu)
+#define CPU0_SFR_SPROT_ACCENA_W (0xF880E100u)
+#define CPU0_SFR_SPROT_ACCENB_W (0xF880E104u)
+#define CPU0_LPB_SPROT_ACCENA_R (0xF880E110u)
+#define CPU0_LPB_SPROT_ACCENB_R (0xF880E114u)
+#define CPU0_DLMU_SPROT_RGNLA0 (0xF880E200u)
+#define CPU0_DLMU_SPROT_RGNUA0 (0xF880E204u)
+#define CPU0_DLMU_SPROT_RGNACCENA_W0 (0xF880E208u)
+#define CPU0_DLMU_SPROT_RGNACCENB_W0 (0xF880E20Cu)
+#define CPU0_DLMU_SPROT_RGNLA1 (0xF880E210u)
+#define CPU0_DLMU_SPROT_RGNUA1 (0xF880E214u)
+#define CPU0_DLMU_SPROT_RGNACCENA_W1 (0xF880E218u)
+#define CPU0_DLMU_SPROT_RGNACCENB_W1 (0xF880E21Cu)
+#define CPU0_DLMU_SPROT_RGNLA2 (0xF880E220u)
+#define CPU0_DLMU_SPROT_RGNUA2 (0xF880E224u)
+#define CPU0_DLMU_SPROT_RGNACCENA_W2 (0xF880E228u)
+#define CPU0_DLMU_SPROT_RGNACCENB_W2 (0xF880E22Cu)
+#define CPU0_DLMU_SPROT_RGNLA3 (0xF880E230u)
+#define CPU0_DLMU_SPROT_RGNUA3 (0xF880E234u)
+#define CPU0_DLMU_SPROT_RGNACCENA_W3 (0xF880E238u)
+#define CPU0_DLMU_SPROT_RGNACCENB_W3 (0xF880E23Cu)
+#define CPU0_DLMU_SPROT_RGNLA4 (0xF880E240u)
+#define CPU0_DLMU_SPROT_RGNUA4 (0xF880E244u)
+#define CPU0_DLMU_SPROT_RGNACCENA_W4 (0xF880E248u)
+#define CPU0_DLMU_SPROT_RGNACCENB_W4 (0xF880E24Cu)
+#define CPU0_DLMU_SPROT_RGNLA5 (0xF880E250u)
+#define CPU0_DLMU_SPROT_RGNUA5 (0xF880E254u)
+#define CPU0_DLMU_SPROT_RGNACCENA_W5 (0xF880E258u)
+#define CPU0_DLMU_SPROT_RGNACCENB_W5 (0xF880E25Cu)
+#define CPU0_DLMU_SPROT_RGNLA6 (0xF880E260u)
+#define CPU0_DLMU_SPROT_RGNUA6 (0xF880E264u)
+#define CPU0_DLMU_SPROT_RGNACCENA_W6 (0xF880E268u)
+#define CPU0_DLMU_SPROT_RGNACCENB_W6 (0xF880E26Cu)
+#define CPU0_DLMU_SPROT_RGNLA7 (0xF880E270u)
+#define CPU0_DLMU_SPROT_RGNUA7 (0xF880E274u)
+#define CPU0_DLMU_SPROT_RGNACCENA_W7 (0xF880E278u)
+#define CPU0_DLMU_SPROT_RGNACCENB_W7 (0xF880E27Cu)
+#define CPU0_DLMU_SPROT_RGNACCENA_R0 (0xF880E288u)
+#define CPU0_DLMU_SPROT_RGNACCENB_R0 (0xF880E28Cu)
+#define CPU0_DLMU_SPROT_RGNACCENA_R1 (0xF880E298u)
+#define CPU0_DLMU_SPROT_RGNACCENB_R1 (0xF880E29Cu)
+#define CPU0_DLMU_SPROT_RGNACCENA_R2 (0xF880E2A8u)
+#define CPU0_DLMU_SPROT_RGNACCENB_R2 (0xF880E2ACu)
+#define CPU0_DLMU_SPROT_RGNACCENA_R3 (0xF880E2B8u)
+#define CPU0_DLMU_SPROT_RGNACCENB_R3 (0xF880E2BCu)
+#define CPU0_DLMU_SPROT_RGNACCENA_R4 (0xF880E2C8u)
+#define CPU0_DLMU_SPROT_RGNACCENB_R4 (0xF880E2CCu)
+#define CPU0_DLMU_SPROT_RGNACCENA_R5 (0xF880E2D8u)
+#define CPU0_DLMU_SPROT_RGNACCENB_R5 (0xF880E2DCu)
+#define CPU0_DLMU_SPROT_RGNACCENA_R6 (0xF880E2E8u)
+#define CPU0_DLMU_SPROT_RGNACCENB_R6 (0xF880E2ECu)
+#define CPU0_DLMU_SPROT_RGNACCENA_R7 (0xF880E2F8u)
+#define CPU0_DLMU_SPROT_RGNACCENB_R7 (0xF880E2FCu)
+#define CPU0_OSEL (0xF880FB00u)
+#define CPU0_BLK0_RABR (0xF880FB10u)
+#define CPU0_BLK0_OTAR (0xF880FB14u)
+#define CPU0_BLK0_OMASK (0xF880FB18u)
+#define CPU0_BLK1_RABR (0xF880FB1Cu)
+#define CPU0_BLK1_OTAR (0xF880FB20u)
+#define CPU0_BLK1_OMASK (0xF880FB24u)
+#define CPU0_BLK2_RABR (0xF880FB28u)
+#define CPU0_BLK2_OTAR (0xF880FB2Cu)
+#define CPU0_BLK2_OMASK (0xF880FB30u)
+#define CPU0_BLK3_RABR (0xF880FB34u)
+#define CPU0_BLK3_OTAR (0xF880FB38u)
+#define CPU0_BLK3_OMASK (0xF880FB3Cu)
+#define CPU0_BLK4_RABR (0xF880FB40u)
+#define CPU0_BLK4_OTAR (0xF880FB44u)
+#define CPU0_BLK4_OMASK (0xF880FB48u)
+#define CPU0_BLK5_RABR (0xF880FB4Cu)
+#define CPU0_BLK5_OTAR (0xF880FB50u)
+#define CPU0_BLK5_OMASK (0xF880FB54u)
+#define CPU0_BLK6_RABR (0xF880FB58u)
+#define CPU0_BLK6_OTAR (0xF880FB5Cu)
+#define CPU0_BLK6_OMASK (0xF880FB60u)
+#define CPU0_BLK7_RABR (0xF880FB64u)
+#define CPU0_BLK7_OTAR (0xF880FB68u)
+#define CPU0_BLK7_OMASK (0xF880FB6Cu)
+#define CPU0_BLK8_RABR (0xF880FB70u)
+#define CPU0_BLK8_OTAR (0xF880FB74u)
+#define CPU0_BLK8_OMASK (0xF880FB78u)
+#define CPU0_BLK9_RABR (0xF880FB7Cu)
+#define CPU0_BLK9_OTAR (0xF880FB80u)
+#define CPU0_BLK9_OMASK (0xF880FB84u)
+#define CPU0_BLK10_RABR (0xF880FB88u)
+#define CPU0_BLK10_OTAR (0xF880FB8Cu)
+#define CPU0_BLK10_OMASK (0xF880FB90u)
+#define CPU0_BLK11_RABR (0xF880FB94u)
+#define CPU0_BLK11_OTAR (0xF880FB98u)
+#define CPU0_BLK11_OMASK (0xF880FB9Cu)
+#define CPU0_BLK12_RABR (0xF880FBA0u)
+#define CPU0_BLK12_OTAR (0xF880FBA4u)
+#define CPU0_BLK12_OMASK (0xF880FBA8u)
+#define CPU0_BLK13_RABR (0xF880FBACu)
+#define CPU0_BLK13_OTAR (0xF880FBB0u)
+#define CPU0_BLK13_OMASK (0xF880FBB4u)
+#define CPU0_BLK14_RABR (0xF880FBB8u)
+#define CPU0_BLK14_OTAR (0xF880FBBCu)
+#define CPU0_BLK14_OMASK (0xF880FBC0u)
+#define CPU0_BLK15_RABR (0xF880FBC4u)
+#define CPU0_BLK15_OTAR (0xF880FBC8u)
+#define CPU0_BLK15_OMASK (0xF880FBCCu)
+#define CPU0_BLK16_RABR (0xF880FBD0u)
+#define CPU0_BLK16_OTAR (0xF880FBD4u)
+#define CPU0_BLK16_OMASK (0xF880FBD8u)
+#define CPU0_BLK17_RABR (0xF880FBDCu)
+#define CPU0_BLK17_OTAR (0xF880FBE0u)
+#define CPU0_BLK17_OMASK (0xF880FBE4u)
+#define CPU0_BLK18_RABR (0xF880FBE8u)
+#define CPU0_BLK18_OTAR (0xF880FBECu)
+#define CPU0_BLK18_OMASK (0xF880FBF0u)
+#define CPU0_BLK19_RABR (0xF880FBF4u)
+#define CPU0_BLK19_OTAR (0xF880FBF8u)
+#define CPU0_BLK19_OMASK (0xF880FBFCu)
+#define CPU0_BLK20_RABR (0xF880FC00u)
+#define CPU0_BLK20_OTAR (0xF880FC04u)
+#define CPU0_BLK20_OMASK (0xF880FC08u)
+#define CPU0_BLK21_RABR (0xF880FC0Cu)
+#define CPU0_BLK21_OTAR (0xF880FC10u)
+#define CPU0_BLK21_OMASK (0xF880FC14u)
+#define CPU0_BLK22_RABR (0xF880FC18u)
+#define CPU0_BLK22_OTAR (0xF880FC1Cu)
+#define CPU0_BLK22_OMASK (0xF880FC20u)
+#define CPU0_BLK23_RABR (0xF880FC24u)
+#define CPU0_BLK23_OTAR (0xF880FC28u)
+#define CPU0_BLK23_OMASK (0xF880FC2Cu)
+#define CPU0_BLK24_RABR (0xF880FC30u)
+#define CPU0_BLK24_OTAR (0xF880FC34u)
+#define CPU0_BLK24_OMASK (0xF880FC38u)
+#define CPU0_BLK25_RABR (0xF880FC3Cu)
+#define CPU0_BLK25_OTAR (0xF880FC40u)
+#define CPU0_BLK25_OMASK (0xF880FC44u)
+#define CPU0_BLK26_RABR (0xF880FC48u)
+#define CPU0_BLK26_OTAR (0xF880FC4Cu)
+#define CPU0_BLK26_OMASK (0xF880FC50u)
+#define CPU0_BLK27_RABR (0xF880FC54u)
+#define CPU0_BLK27_OTAR (0xF880FC58u)
+#define CPU0_BLK27_OMASK (0xF880FC5Cu)
+#define CPU0_BLK28_RABR (0xF880FC60u)
+#define CPU0_BLK28_OTAR (0xF880FC64u)
+#define CPU0_BLK28_OMASK (0xF880FC68u)
+#define CPU0_BLK29_RABR (0xF880FC6Cu)
+#define CPU0_BLK29_OTAR (0xF880FC70u)
+#define CPU0_BLK29_OMASK (0xF880FC74u)
+#define CPU0_BLK30_RABR (0xF880FC78u)
+#define CPU0_BLK30_OTAR (0xF880FC7Cu)
+#define CPU0_BLK30_OMASK (0xF880FC80u)
+#define CPU0_BLK31_RABR (0xF880FC84u)
+#define CPU0_BLK31_OTAR (0xF880FC88u)
+#define CPU0_BLK31_OMASK (0xF880FC8Cu)
+#define CPU0_SEGEN (0xF8811030u)
+#define CPU0_TASK_ASI (0xF8818004u)
+#define CPU0_PMA0 (0xF8818100u)
+#define CPU0_PMA1 (0xF8818104u)
+#define CPU0_PMA2 (0xF8818108u)
+#define CPU0_DCON2 (0xF8819000u)
+#define CPU0_SMACON (0xF881900Cu)
+#define CPU0_DSTR (0xF8819010u)
+#define CPU0_DATR (0xF8819018u)
+#define CPU0_DEADD (0xF881901Cu)
+#define CPU0_DIEAR (0xF8819020u)
+#define CPU0_DIETR (0xF8819024u)
+#define CPU0_DCON0 (0xF8819040u)
+#define CPU0_PSTR (0xF8819200u)
+#define CPU0_PCON1 (0xF8819204u)
+#define CPU0_PCON2 (0xF8819208u)
+#define CPU0_PCON0 (0xF881920Cu)
+#define CPU0_PIEAR (0xF8819210u)
+#define CPU0_PIETR (0xF8819214u)
+#define CPU0_COMPAT (0xF8819400u)
+#define CPU0_FPU_TRAP_CON (0xF881A000u)
+#define CPU0_FPU_TRAP_PC (0xF881A004u)
+#define CPU0_FPU_TRAP_OPC (0xF881A008u)
+#define CPU0_FPU_TRAP_SRC1 (0xF881A010u)
+#define CPU0_FPU_TRAP_SRC2 (0xF881A014u)
+#define CPU0_FPU_TRAP_SRC3 (0xF881A018u)
+#define CPU0_DPR0_L (0xF881C000u)
+#define CPU0_DPR0_U (0xF881C004u)
+#define CPU0_DPR1_L (0xF881C008u)
+#define CPU0_DPR1_U (0xF881C00Cu)
+#define CPU0_DPR2_L (0xF881C010u)
+#define CPU0_DPR2_U (0xF881C014u)
+#define CPU0_DPR3_L (0xF881C018u)
+#define CPU0_DPR3_U (0xF881C01Cu)
+#define CPU0_DPR4_L (0xF881C020u)
+#define CPU0_DPR4_U (0xF881C024u)
+#define CPU0_DPR5_L (0xF881C028u)
+#define CPU0_DPR5_U (0xF881C02Cu)
+#define CPU0_DPR6_L (0xF881C030u)
+#define CPU0_DPR6_U (0xF881C034u)
+#define CPU0_DPR7_L (0xF881C038u)
+#define CPU0_DPR7_U (0xF881C03Cu)
+#define CPU0_DPR8_L (0xF881C040u)
+#define CPU0_DPR8_U (0xF881C044u)
+#define CPU0_DPR9_L (0xF881C048u)
+#define CPU0_DPR9_U (0xF881C04Cu)
+#define CPU0_DPR10_L (0xF881C050u)
+#define CPU0_DPR10_U (0xF881C054u)
+#define CPU0_DPR11_L (0xF881C058u)
+#define CPU0_DPR11_U (0xF881C05Cu)
+#define CPU0_DPR12_L (0xF881C060u)
+#define CPU0_DPR12_U (0xF881C064u)
+#define CPU0_DPR13_L (0xF881C068u)
+#define CPU0_DPR13_U (0xF881C06Cu)
+#define CPU0_DPR14_L (0xF881C070u)
+#define CPU0_DPR14_U (0xF881C074u)
+#define CPU0_DPR15_L (0xF881C078u)
+#define CPU0_DPR15_U (0xF881C07Cu)
+#define CPU0_DPR16_L (0xF881C080u)
+#define CPU0_DPR16_U (0xF881C084u)
+#define CPU0_DPR17_L (0xF881C088u)
+#define CPU0_DPR17_U (0xF881C08Cu)
+#define CPU0_CPR0_L (0xF881D000u)
+#define CPU0_CPR0_U (0xF881D004u)
+#define CPU0_CPR1_L (0xF881D008u)
+#define CPU0_CPR1_U (0xF881D00Cu)
+#define CPU0_CPR2_L (0xF881D010u)
+#define CPU0_CPR2_U (0xF881D014u)
+#define CPU0_CPR3_L (0xF881D018u)
+#define CPU0_CPR3_U (0xF881D01Cu)
+#define CPU0_CPR4_L (0xF881D020u)
+#define CPU0_CPR4_U (0xF881D024u)
+#define CPU0_CPR5_L (0xF881D028u)
+#define CPU0_CPR5_U (0xF881D02Cu)
+#define CPU0_CPR6_L (0xF881D030u)
+#define CPU0_CPR6_U (0xF881D034u)
+#define CPU0_CPR7_L (0xF881D038u)
+#define CPU0_CPR7_U (0xF881D03Cu)
+#define CPU0_CPR8_L (0xF881D040u)
+#define CPU0_CPR8_U (0xF881D044u)
+#define CPU0_CPR9_L (0xF881D048u)
+#define CPU0_CPR9_U (0xF881D04Cu)
+#define CPU0_CPXE_0 (0xF881E000u)
+#define CPU0_CPXE_1 (0xF881E004u)
+#define CPU0_CPXE_2 (0xF881E008u)
+#define CPU0_CPXE_3 (0xF881E00Cu)
+#define CPU0_DPRE_0 (0xF881E010u)
+#define CPU0_DPRE_1 (0xF881E014u)
+#define CPU0_DPRE_2 (0xF881E018u)
+#define CPU0_DPRE_3 (0xF881E01Cu)
+#define CPU0_DPWE_0 (0xF881E020u)
+#define CPU0_DPWE_1 (0xF881E024u)
+#define CPU0_DPWE_2 (0xF881E028u)
+#define CPU0_DPWE_3 (0xF881E02Cu)
+#define CPU0_CPXE_4 (0xF881E040u)
+#define CPU0_CPXE_5 (0xF881E044u)
+#define CPU0_DPRE_4 (0xF881E050u)
+#define CPU0_DPRE_5 (0xF881E054u)
+#define CPU0_DPWE_4 (0xF881E060u)
+#define CPU0_DPWE_5 (0xF881E064u)
+#define CPU0_TPS_CON (0xF881E400u)
+#define CPU0_TPS_TIMER0 (0xF881E404u)
+#define CPU0_TPS_TIMER1 (0xF881E408u)
+#define CPU0_TPS_TIMER2 (0xF881E40Cu)
+#define CPU0_TPS_EXTIM_ENTRY_LVAL (0xF881E440u)
+#define CPU0_TPS_EXTIM_ENTRY_CVAL (0xF881E444u)
+#define CPU0_TPS_EXTIM_EXIT_LVAL (0xF881E448u)
+#define CPU0_TPS_EXTIM_EXIT_CVAL (0xF881E44Cu)
+#define CPU0_TPS_EXTIM_CLASS_EN (0xF881E450u)
+#define CPU0_TPS_EXTIM_STAT (0xF881E454u)
+#define CPU0_TPS_EXTIM_FCX (0xF881E458u)
+#define CPU0_TR0_EVT (0xF881F000u)
+#define CPU0_TR0_ADR (0xF881F004u)
+#define CPU0_TR1_EVT (0xF881F008u)
+#define CPU0_TR1_ADR (0xF881F00Cu)
+#define CPU0_TR2_EVT (0xF881F010u)
+#define CPU0_TR2_ADR (0xF881F014u)
+#define CPU0_TR3_EVT (0xF881F018u)
+#define CPU0_TR3_ADR (0xF881F01Cu)
+#define CPU0_TR4_EVT (0xF881F020u)
+#define CPU0_TR4_ADR (0xF881F024u)
+#define CPU0_TR5_EVT (0xF881F028u)
+#define CPU0_TR5_ADR (0xF881F02Cu)
+#define CPU0_TR6_EVT (0xF881F030u)
+#define CPU0_TR6_ADR (0xF881F034u)
+#define CPU0_TR7_EVT (0xF881F038u)
+#define CPU0_TR7_ADR (0xF881F03Cu)
+#define CPU0_CCTRL (0xF881FC00u)
+#define CPU0_CCNT (0xF881FC04u)
+#define CPU0_ICNT (0xF881FC08u)
+#define CPU0_M1CNT (0xF881FC0Cu)
+#define CPU0_M2CNT (0xF881FC10u)
+#define CPU0_M3CNT (0xF881FC14u)
+#define CPU0_DBGSR /*lint --e(923, 9078)*/ (*(volatile Ifx_CPU_DBGSR*)0xF881FD00u)
+#define CPU0_EXEVT (0xF881FD08u)
+#define CPU0_CREVT (0xF881FD0Cu)
+#define CPU0_SWEVT (0xF881FD10u)
+#define CPU0_TRIG_ACC (0xF881FD30u)
+#define CPU0_DMS (0xF881FD40u)
+#define CPU0_DCX (0xF881FD44u)
+#define CPU0_DBGTCR (0xF881FD48u)
+#define CPU0_PCXI (0xF881FE00u)
+#define CPU0_PSW (0xF881FE04u)
+#define CPU0_PC /*lint --e(923, 9078)*/ (*(volatile Ifx_CPU_PC*)0xF881FE08u)
+#define CPU0_SYSCON /*lint --e(923, 9078)*/ (*(volatile Ifx_CPU_SYSCON*)0xF881FE14u)
+#define CPU0_CPU_ID (0xF881FE18u)
+#define CPU0_CORE_ID /*lint --e(923, 9078)*/ (*(volatile Ifx_CPU_CORE_ID*)0xF881FE1Cu)
+#define CPU0_BIV (0xF881FE20u)
+#define CPU0_BTV (0xF881FE24u)
+#define CPU0_ISP (0xF881FE28u)
+#define CPU0_ICR (0xF881FE2Cu)
+#define CPU0_FCX (0xF881FE38u)
+#define CPU0_LCX (0xF881FE3Cu)
+#define CPU0_CUS_ID (0xF881FE50u)
+#define CPU0_D0 (0xF881FF00u)
+#define CPU0_D1 (0xF881FF04u)
+#define CPU0_D2 (0xF881FF08u)
+#define CPU0_D3 (0xF881FF0Cu)
+#define CPU0_D4 (0xF881FF10u)
+#define CPU0_D5 (0xF881FF14u)
+#define CPU0_D6 (0xF881FF18u)
+#define CPU0_D7 (0xF881FF1Cu)
+#define CPU0_D8 (0xF881FF20u)
+#define CPU0_D9 (0xF881FF24u)
+#define CPU0_D10 (0xF881FF28u)
+#define CPU0_D11 (0xF881FF2Cu)
+#define CPU0_D12 (0xF881FF30u)
+#define CPU0_D13 (0xF881FF34u)
+#define CPU0_D14 (0xF881FF38u)
+#define CPU0_D15 (0xF881FF3Cu)
+#define CPU0_A0 (0xF881FF80u)
+#define CPU0_A1 (0xF881FF84u)
+#define CPU0_A2 (0xF881FF88u)
+#define CPU0_A3 (0xF881FF8Cu)
+#define CPU0_A4 (0xF881FF90u)
+#define CPU0_A5 (0xF881FF94u)
+#define CPU0_A6 (0xF881FF98u)
+#define CPU0_A7 (0xF881FF9Cu)
+#define CPU0_A8 (0xF881FFA0u)
+#define CPU0_A9 (0xF881FFA4u)
+#define CPU0_A10 (0xF881FFA8u)
+#define CPU0_A11 (0xF881FFACu)
+#define CPU0_A12 (0xF881FFB0u)
+#define CPU0_A13 (0xF881FFB4u)
+#define CPU0_A14 (0xF881FFB8u)
+#define CPU0_A15 (0xF881FFBCu)
+#define CPU1_FLASHCON0 (0xF8821100u)
+#define CPU1_FLASHCON1 (0xF8821104u)
+#define CPU1_FLASHCON2 (0xF8821108u)
+#define CPU1_FLASHCON3 (0xF882110Cu)
+#define CPU1_FLASHCON4 (0xF8821110u)
+#define CPU1_KRST0 (0xF882D000u)
+#define CPU1_KRST1 (0xF882D004u)
+#define CPU1_KRSTCLR (0xF882D008u)
+#define CPU1_RGN0_LA (0xF882E000u)
+#define CPU1_RGN0_UA (0xF882E004u)
+#define CPU1_RGN0_ACCENA (0xF882E008u)
+#define CPU1_RGN0_ACCENB (0xF882E00Cu)
+#define CPU1_RGN1_LA (0xF882E010u)
+#define CPU1_RGN1_UA (0xF882E014u)
+#define CPU1_RGN1_ACCENA (0xF882E018u)
+#define CPU1_RGN1_ACCENB (0xF882E01Cu)
+#define CPU1_RGN2_LA (0xF882E020u)
+#define CPU1_RGN2_UA (0xF882E024u)
+#define CPU1_RGN2_ACCENA (0xF882E028u)
+#define CPU1_RGN2_ACCENB (0xF882E02Cu)
+#define CPU1_RGN3_LA (0xF882E030u)
+#define CPU1_RGN3_UA (0xF882E034u)
+#define CPU1_RGN3_ACCENA (0xF882E038u)
+#define CPU1_RGN3_ACCENB (0xF882E03Cu)
+#define CPU1_RGN4_LA (0xF882E040u)
+#define CPU1_RGN4_UA (0xF882E044u)
+#define CPU1_RGN4_ACCENA (0xF882E048u)
+#define CPU1_RGN4_ACCENB (0xF882E04Cu)
+#define CPU1_RGN5_LA (0xF882E050u)
+#define CPU1_RGN5_UA (0xF882E054u)
+#define CPU1_RGN5_ACCENA (0xF882E058u)
+#define CPU1_RGN5_ACCENB (0xF882E05Cu)
+#define CPU1_RGN6_LA (0xF882E060u)
+#define CPU1_RGN6_UA (0xF882E064u)
+#define CPU1_RGN6_ACCENA (0xF882E068u)
+#define CPU1_RGN6_ACCENB (0xF882E06Cu)
+#define CPU1_RGN7_LA (0xF882E070u)
+#define CPU1_RGN7_UA (0xF882E074u)
+#define CPU1_RGN7_ACCENA (0xF882E078u)
+#define CPU1_RGN7_ACCENB (0xF882E07Cu)
+#define CPU1_SPR_SPROT_RGNACCENA_R0 (0xF882E088u)
+#define CPU1_SPR_SPROT_RGNACCENB_R0 (0xF882E08Cu)
+#define CPU1_SPR_SPROT_RGNACCENA_R1 (0xF882E098u)
+#define CPU1_SPR_SPROT_RGNACCENB_R1 (0xF882E09Cu)
+#define CPU1_SPR_SPROT_RGNACCENA_R2 (0xF882E0A8u)
+#define CPU1_SPR_SPROT_RGNACCENB_R2 (0xF882E0ACu)
+#define CPU1_SPR_SPROT_RGNACCENA_R3 (0xF882E0B8u)
+#define CPU1_SPR_SPROT_RGNACCENB_R3 (0xF882E0BCu)
+#define CPU1_SPR_SPROT_RGNACCENA_R4 (0xF882E0C8u)
+#define CPU1_SPR_SPROT_RGNACCENB_R4 (0xF882E0CCu)
+#define CPU1_SPR_SPROT_RGNACCENA_R5 (0xF882E0D8u)
+#define CPU1_SPR_SPROT_RGNACCENB_R5 (0xF882E0DCu)
+#define CPU1_SPR_SPROT_RGNACCENA_R6 (0xF882E0E8u)
+#define CPU1_SPR_SPROT_RGNACCENB_R6 (0xF882E0ECu)
+#define CPU1_SPR_SPROT_RGNACCENA_R7 (0xF882E0F8u)
+#define CPU1_SPR_SPROT_RGNACCENB_R7 (0xF882E0FCu)
+#define CPU1_SFR_SPROT_ACCENA_W (0xF882E100u)
+#define CPU1_SFR_SPROT_ACCENB_W (0xF882E104u)
+#define CPU1_LPB_SPROT_ACCENA_R (0xF882E110u)
+#define CPU1_LPB_SPROT_ACCENB_R (0xF882E114u)
+#define CPU1_DLMU_SPROT_RGNLA0 (0xF882E200u)
+#define CPU1_DLMU_SPROT_RGNUA0 (0xF882E204u)
+#define CPU1_DLMU_SPROT_RGNACCENA_W0 (0xF882E208u)
+#define CPU1_DLMU_SPROT_RGNACCENB_W0 (0xF882E20Cu)
+#define CPU1_DLMU_SPROT_RGNLA1 (0xF882E210u)
+#define CPU1_DLMU_SPROT_RGNUA1 (0xF882E214u)
+#define CPU1_DLMU_SPROT_RGNACCENA_W1 (0xF882E218u)
+#define CPU1_DLMU_SPROT_RGNACCENB_W1 (0xF882E21Cu)
+#define CPU1_DLMU_SPROT_RGNLA2 (0xF882E220u)
+#define CPU1_DLMU_SPROT_RGNUA2 (0xF882E224u)
+#define CPU1_DLMU_SPROT_RGNACCENA_W2 (0xF882E228u)
+#define CPU1_DLMU_SPROT_RGNACCENB_W2 (0xF882E22Cu)
+#define CPU1_DLMU_SPROT_RGNLA3 (0xF882E230u)
+#define CPU1_DLMU_SPROT_RGNUA3 (0xF882E234u)
+#define CPU1_DLMU_SPROT_RGNACCENA_W3 (0xF882E238u)
+#define CPU1_DLMU_SPROT_RGNACCENB_W3 (0xF882E23Cu)
+#define CPU1_DLMU_SPROT_RGNLA4 (0xF882E240u)
+#define CPU1_DLMU_SPROT_RGNUA4 (0xF882E244u)
+#define CPU1_DLMU_SPROT_RGNACCENA_W4 (0xF882E248u)
+#define CPU1_DLMU_SPROT_RGNACCENB_W4 (0xF882E24Cu)
+#define CPU1_DLMU_SPROT_RGNLA5 (0xF882E250u)
+#define CPU1_DLMU_SPROT_RGNUA5 (0xF882E254u)
+#define CPU1_DLMU_SPROT_RGNACCENA_W5 (0xF882E258u)
+#define CPU1_DLMU_SPROT_RGNACCENB_W5 (0xF882E25Cu)
+#define CPU1_DLMU_SPROT_RGNLA6 (0xF882E260u)
+#define CPU1_DLMU_SPROT_RGNUA6 (0xF882E264u)
+#define CPU1_DLMU_SPROT_RGNACCENA_W6 (0xF882E268u)
+#define CPU1_DLMU_SPROT_RGNACCENB_W6 (0xF882E26Cu)
+#define CPU1_DLMU_SPROT_RGNLA7 (0xF882E270u)
+#define CPU1_DLMU_SPROT_RGNUA7 (0xF882E274u)
+#define CPU1_DLMU_SPROT_RGNACCENA_W7 (0xF882E278u)
+#define CPU1_DLMU_SPROT_RGNACCENB_W7 (0xF882E27Cu)
+#define CPU1_DLMU_SPROT_RGNACCENA_R0 (0xF882E288u)
+#define CPU1_DLMU_SPROT_RGNACCENB_R0 (0xF882E28Cu)
+#define CPU1_DLMU_SPROT_RGNACCENA_R1 (0xF882E298u)
+#define CPU1_DLMU_SPROT_RGNACCENB_R1 (0xF882E29Cu)
+#define CPU1_DLMU_SPROT_RGNACCENA_R2 (0xF882E2A8u)
+#define CPU1_DLMU_SPROT_RGNACCENB_R2 (0xF882E2ACu)
+#define CPU1_DLMU_SPROT_RGNACCENA_R3 (0xF882E2B8u)
+#define CPU1_DLMU_SPROT_RGNACCENB_R3 (0xF882E2BCu)
+#define CPU1_DLMU_SPROT_RGNACCENA_R4 (0xF882E2C8u)
+#define CPU1_DLMU_SPROT_RGNACCENB_R4 (0xF882E2CCu)
+#define CPU1_DLMU_SPROT_RGNACCENA_R5 (0xF882E2D8u)
+#define CPU1_DLMU_SPROT_RGNACCENB_R5 (0xF882E2DCu)
+#define CPU1_DLMU_SPROT_RGNACCENA_R6 (0xF882E2E8u)
+#define CPU1_DLMU_SPROT_RGNACCENB_R6 (0xF882E2ECu)
+#define CPU1_DLMU_SPROT_RGNACCENA_R7 (0xF882E2F8u)
+#define CPU1_DLMU_SPROT_RGNACCENB_R7 (0xF882E2FCu)
+#define CPU1_OSEL (0xF882FB00u)
+#define CPU1_BLK0_RABR (0xF882FB10u)
+#define CPU1_BLK0_OTAR (0xF882FB14u)
+#define CPU1_BLK0_OMASK (0xF882FB18u)
+#define CPU1_BLK1_RABR (0xF882FB1Cu)
+#define CPU1_BLK1_OTAR (0xF882FB20u)
+#define CPU1_BLK1_OMASK (0xF882FB24u)
+#define CPU1_BLK2_RABR (0xF882FB28u)
+#define CPU1_BLK2_OTAR (0xF882FB2Cu)
+#define CPU1_BLK2_OMASK (0xF882FB30u)
+#define CPU1_BLK3_RABR (0xF882FB34u)
+#define CPU1_BLK3_OTAR (0xF882FB38u)
+#define CPU1_BLK3_OMASK (0xF882FB3Cu)
+#define CPU1_BLK4_RABR (0xF882FB40u)
+#define CPU1_BLK4_OTAR (0xF882FB44u)
+#define CPU1_BLK4_OMASK (0xF882FB48u)
+#define CPU1_BLK5_RABR (0xF882FB4Cu)
+#define CPU1_BLK5_OTAR (0xF882FB50u)
+#define CPU1_BLK5_OMASK (0xF882FB54u)
+#define CPU1_BLK6_RABR (0xF882FB58u)
+#define CPU1_BLK6_OTAR (0xF882FB5Cu)
+#define CPU1_BLK6_OMASK (0xF882FB60u)
+#define CPU1_BLK7_RABR (0xF882FB64u)
+#define CPU1_BLK7_OTAR (0xF882FB68u)
+#define CPU1_BLK7_OMASK (0xF882FB6Cu)
+#define CPU1_BLK8_RABR (0xF882FB70u)
+#define CPU1_BLK8_OTAR (0xF882FB74u)
+#define CPU1_BLK8_OMASK (0xF882FB78u)
+#define CPU1_BLK9_RABR (0xF882FB7Cu)
+#define CPU1_BLK9_OTAR (0xF882FB80u)
+#define CPU1_BLK9_OMASK (0xF882FB84u)
+#define CPU1_BLK10_RABR (0xF882FB88u)
+#define CPU1_BLK10_OTAR (0xF882FB8Cu)
+#define CPU1_BLK10_OMASK (0xF882FB90u)
+#define CPU1_BLK11_RABR (0xF882FB94u)
+#define CPU1_BLK11_OTAR (0xF882FB98u)
+#define CPU1_BLK11_OMASK (0xF882FB9Cu)
+#define CPU1_BLK12_RABR (0xF882FBA0u)
+#define CPU1_BLK12_OTAR (0xF882FBA4u)
+#define CPU1_BLK12_OMASK (0xF882FBA8u)
+#define CPU1_BLK13_RABR (0xF882FBACu)
+#define CPU1_BLK13_OTAR (0xF882FBB0u)
+#define CPU1_BLK13_OMASK (0xF882FBB4u)
+#define CPU1_BLK14_RABR (0xF882FBB8u)
+#define CPU1_BLK14_OTAR (0xF882FBBCu)
+#define CPU1_BLK14_OMASK (0xF882FBC0u)
+#define CPU1_BLK15_RABR (0xF882FBC4u)
+#define CPU1_BLK15_OTAR (0xF882FBC8u)
+#define CPU1_BLK15_OMASK (0xF882FBCCu)
+#define CPU1_BLK16_RABR (0xF882FBD0u)
+#define CPU1_BLK16_OTAR (0xF882FBD4u)
+#define CPU1_BLK16_OMASK (0xF882FBD8u)
+#define CPU1_BLK17_RABR (0xF882FBDCu)
+#define CPU1_BLK17_OTAR (0xF882FBE0u)
+#define CPU1_BLK17_OMASK (0xF882FBE4u)
+#define CPU1_BLK18_RABR (0xF882FBE8u)
+#define CPU1_BLK18_OTAR (0xF882FBECu)
+#define CPU1_BLK18_OMASK (0xF882FBF0u)
+#define CPU1_BLK19_RABR (0xF882FBF4u)
+#define CPU1_BLK19_OTAR (0xF882FBF8u)
+#define CPU1_BLK19_OMASK (0xF882FBFCu)
+#define CPU1_BLK20_RABR (0xF882FC00u)
+#define CPU1_BLK20_OTAR (0xF882FC04u)
+#define CPU1_BLK20_OMASK (0xF882FC08u)
+#define CPU1_BLK21_RABR (0xF882FC0Cu)
+#define CPU1_BLK21_OTAR (0xF882FC10u)
+#define CPU1_BLK21_OMASK (0xF882FC14u)
+#define CPU1_BLK22_RABR (0xF882FC18u)
+#define CPU1_BLK22_OTAR (0xF882FC1Cu)
+#define CPU1_BLK22_OMASK (0xF882FC20u)
+#define CPU1_BLK23_RABR (0xF882FC24u)
+#define CPU1_BLK23_OTAR (0xF882FC28u)
+#define CPU1_BLK23_OMASK (0xF882FC2Cu)
+#define CPU1_BLK24_RABR (0xF882FC30u)
+#define CPU1_BLK24_OTAR (0xF882FC34u)
+#define CPU1_BLK24_OMASK (0xF882FC38u)
+#define CPU1_BLK25_RABR (0xF882FC3Cu)
+#define CPU1_BLK25_OTAR (0xF882FC40u)
+#define CPU1_BLK25_OMASK (0xF882FC44u)
+#define CPU1_BLK26_RABR (0xF882FC48u)
+#define CPU1_BLK26_OTAR (0xF882FC4Cu)
+#define CPU1_BLK26_OMASK (0xF882FC50u)
+#define CPU1_BLK27_RABR (0xF882FC54u)
+#define CPU1_BLK27_OTAR (0xF882FC58u)
+#define CPU1_BLK27_OMASK (0xF882FC5Cu)
+#define CPU1_BLK28_RABR (0xF882FC60u)
+#define CPU1_BLK28_OTAR (0xF882FC64u)
+#define CPU1_BLK28_OMASK (0xF882FC68u)
+#define CPU1_BLK29_RABR (0xF882FC6Cu)
+#define CPU1_BLK29_OTAR (0xF882FC70u)
+#define CPU1_BLK29_OMASK (0xF882FC74u)
+#define CPU1_BLK30_RABR (0xF882FC78u)
+#define CPU1_BLK30_OTAR (0xF882FC7Cu)
+#define CPU1_BLK30_OMASK (0xF882FC80u)
+#define CPU1_BLK31_RABR (0xF882FC84u)
+#define CPU1_BLK31_OTAR (0xF882FC88u)
+#define CPU1_BLK31_OMASK (0xF882FC8Cu)
+#define CPU1_SEGEN (0xF8831030u)
+#define CPU1_TASK_ASI (0xF8838004u)
+#define CPU1_PMA0 (0xF8838100u)
+#define CPU1_PMA1 (0xF8838104u)
+#define CPU1_PMA2 (0xF8838108u)
+#define CPU1_DCON2 (0xF8839000u)
+#define CPU1_SMACON (0xF883900Cu)
+#define CPU1_DSTR (0xF8839010u)
+#define CPU1_DATR (0xF8839018u)
+#define CPU1_DEADD (0xF883901Cu)
+#define CPU1_DIEAR (0xF8839020u)
+#define CPU1_DIETR (0xF8839024u)
+#define CPU1_DCON0 (0xF8839040u)
+#define CPU1_PSTR (0xF8839200u)
+#define CPU1_PCON1 (0xF8839204u)
+#define CPU1_PCON2 (0xF8839208u)
+#define CPU1_PCON0 (0xF883920Cu)
+#define CPU1_PIEAR (0xF8839210u)
+#define CPU1_PIETR (0xF8839214u)
+#define CPU1_COMPAT (0xF8839400u)
+#define CPU1_FPU_TRAP_CON (0xF883A000u)
+#define CPU1_FPU_TRAP_PC (0xF883A004u)
+#define CPU1_FPU_TRAP_OPC (0xF883A008u)
+#define CPU1_FPU_TRAP_SRC1 (0xF883A010u)
+#define CPU1_FPU_TRAP_SRC2 (0xF883A014u)
+#define CPU1_FPU_TRAP_SRC3 (0xF883A018u)
+#define CPU1_DPR0_L (0xF883C000u)
+#define CPU1_DPR0_U (0xF883C004u)
+#define CPU1_DPR1_L (0xF883C008u)
+#define CPU1_DPR1_U (0xF883C00Cu)
+#define CPU1_DPR2_L (0xF883C010u)
+#define CPU1_DPR2_U (0xF883C014u)
+#define CPU1_DPR3_L (0xF883C018u)
+#define CPU1_DPR3_U (0xF883C01Cu)
+#define CPU1_DPR4_L (0xF883C020u)
+#define CPU1_DPR4_U (0xF883C024u)
+#define CPU1_DPR5_L (0xF883C028u)
+#define CPU1_DPR5_U (0xF883C02Cu)
+#define CPU1_DPR6_L (0xF883C030u)
+#define CPU1_DPR6_U (0xF883C034u)
+#define CPU1_DPR7_L (0xF883C038u)
+#define CPU1_DPR7_U (0xF883C03Cu)
+#define CPU1_DPR8_L (0xF883C040u)
+#define CPU1_DPR8_U (0xF883C044u)
+#define CPU1_DPR9_L (0xF883C048u)
+#define CPU1_DPR9_U (0xF883C04Cu)
+#define CPU1_DPR10_L (0xF883C050u)
+#define CPU1_DPR10_U (0xF883C054u)
+#define CPU1_DPR11_L (0xF883C058u)
+#define CPU1_DPR11_U (0xF883C05Cu)
+#define CPU1_DPR12_L (0xF883C060u)
+#define CPU1_DPR12_U (0xF883C064u)
+#define CPU1_DPR13_L (0xF883C068u)
+#define CPU1_DPR13_U (0xF883C06Cu)
+#define CPU1_DPR14_L (0xF883C070u)
+#define CPU1_DPR14_U (0xF883C074u)
+#define CPU1_DPR15_L (0xF883C078u)
+#define CPU1_DPR15_U (0xF883C07Cu)
+#define CPU1_DPR16_L (0xF883C080u)
+#define CPU1_DPR16_U (0xF883C084u)
+#define CPU1_DPR17_L (0xF883C088u)
+#define CPU1_DPR17_U (0xF883C08Cu)
+#define CPU1_CPR0_L (0xF883D000u)
+#define CPU1_CPR0_U (0xF883D004u)
+#define CPU1_CPR1_L (0xF883D008u)
+#define CPU1_CPR1_U (0xF883D00Cu)
+#define CPU1_CPR2_L (0xF883D010u)
+#define CPU1_CPR2_U (0xF883D014u)
+#define CPU1_CPR3_L (0xF883D018u)
+#define CPU1_CPR3_U (0xF883D01Cu)
+#define CPU1_CPR4_L (0xF883D020u)
+#define CPU1_CPR4_U (0xF883D024u)
+#define CPU1_CPR5_L (0xF883D028u)
+#define CPU1_CPR5_U (0xF883D02Cu)
+#define CPU1_CPR6_L (0xF883D030u)
+#define CPU1_CPR6_U (0xF883D034u)
+#define CPU1_CPR7_L (0xF883D038u)
+#define CPU1_CPR7_U (0xF883D03Cu)
+#define CPU1_CPR8_L (0xF883D040u)
+#define CPU1_CPR8_U (0xF883D044u)
+#define CPU1_CPR9_L (0xF883D048u)
+#define CPU1_CPR9_U (0xF883D04Cu)
+#define CPU1_CPXE_0 (0xF883E000u)
+#define CPU1_CPXE_1 (0xF883E004u)
+#define CPU1_CPXE_2 (0xF883E008u)
+#define CPU1_CPXE_3 (0xF883E00Cu)
+#define CPU1_DPRE_0 (0xF883E010u)
+#define CPU1_DPRE_1 (0xF883E014u)
+#define CPU1_DPRE_2 (0xF883E018u)
+#define CPU1_DPRE_3 (0xF883E01Cu)
+#define CPU1_DPWE_0 (0xF883E020u)
+#define CPU1_DPWE_1 (0xF883E024u)
+#define CPU1_DPWE_2 (0xF883E028u)
+#define CPU1_DPWE_3 (0xF883E02Cu)
+#define CPU1_CPXE_4 (0xF883E040u)
+#define CPU1_CPXE_5 (0xF883E044u)
+#define CPU1_DPRE_4 (0xF883E050u)
+#define CPU1_DPRE_5 (0xF883E054u)
+#define CPU1_DPWE_4 (0xF883E060u)
+#define CPU1_DPWE_5 (0xF883E064u)
+#define CPU1_TPS_CON (0xF883E400u)
+#define CPU1_TPS_TIMER0 (0xF883E404u)
+#define CPU1_TPS_TIMER1 (0xF883E408u)
+#define CPU1_TPS_TIMER2 (0xF883E40Cu)
+#define CPU1_TPS_EXTIM_ENTRY_LVAL (0xF883E440u)
+#define CPU1_TPS_EXTIM_ENTRY_CVAL (0xF883E444u)
+#define CPU1_TPS_EXTIM_EXIT_LVAL (0xF883E448u)
+#define CPU1_TPS_EXTIM_EXIT_CVAL (0xF883E44Cu)
+#define CPU1_TPS_EXTIM_CLASS_EN (0xF883E450u)
+#define CPU1_TPS_EXTIM_STAT (0xF883E454u)
+#define CPU1_TPS_EXTIM_FCX (0xF883E458u)
+#define CPU1_TR0_EVT (0xF883F000u)
+#define CPU1_TR0_ADR (0xF883F004u)
+#define CPU1_TR1_EVT (0xF883F008u)
+#define CPU1_TR1_ADR (0xF883F00Cu)
+#define CPU1_TR2_EVT (0xF883F010u)
+#define CPU1_TR2_ADR (0xF883F014u)
+#define CPU1_TR3_EVT (0xF883F018u)
+#define CPU1_TR3_ADR (0xF883F01Cu)
+#define CPU1_TR4_EVT (0xF883F020u)
+#define CPU1_TR4_ADR (0xF883F024u)
+#define CPU1_TR5_EVT (0xF883F028u)
+#define CPU1_TR5_ADR (0xF883F02Cu)
+#define CPU1_TR6_EVT (0xF883F030u)
+#define CPU1_TR6_ADR (0xF883F034u)
+#define CPU1_TR7_EVT (0xF883F038u)
+#define CPU1_TR7_ADR (0xF883F03Cu)
+#define CPU1_CCTRL (0xF883FC00u)
+#define CPU1_CCNT (0xF883FC04u)
+#define CPU1_ICNT (0xF883FC08u)
+#define CPU1_M1CNT (0xF883FC0Cu)
+#define CPU1_M2CNT (0xF883FC10u)
+#define CPU1_M3CNT (0xF883FC14u)
+#define CPU1_DBGSR /*lint --e(923, 9078)*/ (*(volatile Ifx_CPU_DBGSR*)0xF883FD00u)
+#define CPU1_EXEVT (0xF883FD08u)
+#define CPU1_CREVT (0xF883FD0Cu)
+#define CPU1_SWEVT (0xF883FD10u)
+#define CPU1_TRIG_ACC (0xF883FD30u)
+#define CPU1_DMS (0xF883FD40u)
+#define CPU1_DCX (0xF883FD44u)
+#define CPU1_DBGTCR (0xF883FD48u)
+#define CPU1_PCXI (0xF883FE00u)
+#define CPU1_PSW (0xF883FE04u)
+#define CPU1_PC /*lint --e(923, 9078)*/ (*(volatile Ifx_CPU_PC*)0xF883FE08u)
+#define CPU1_SYSCON /*lint --e(923, 9078)*/ (*(volatile Ifx_CPU_SYSCON*)0xF883FE14u)
+#define CPU1_CPU_ID (0xF883FE18u)
+#define CPU1_CORE_ID /*lint --e(923, 9078)*/ (*(volatile Ifx_CPU_CORE_ID*)0xF883FE1Cu)
+#define CPU1_BIV (0xF883FE20u)
+#define CPU1_BTV (0xF883FE24u)
+#define CPU1_ISP (0xF883FE28u)
+#define CPU1_ICR (0xF883FE2Cu)
+#define CPU1_FCX (0xF883FE38u)
+#define CPU1_LCX (0xF883FE3Cu)
+#define CPU1_CUS_ID (0xF883FE50u)
+#define CPU1_D0 (0xF883FF00u)
+#define CPU1_D1 (0xF883FF04u)
+#define CPU1_D2 (0xF883FF08u)
+#define CPU1_D3 (0xF883FF0Cu)
+#define CPU1_D4 (0xF883FF10u)
+#define CPU1_D5 (0xF883FF14u)
+#define CPU1_D6 (0xF883FF18u)
+#define CPU1_D7 (0xF883FF1Cu)
+#define CPU1_D8 (0xF883FF20u)
+#define CPU1_D9 (0xF883FF24u)
+#define CPU1_D10 (0xF883FF28u)
+#define CPU1_D11 (0xF883FF2Cu)
+#define CPU1_D12 (0xF883FF30u)
+#define CPU1_D13 (0xF883FF34u)
+#define CPU1_D14 (0xF883FF38u)
+#define CPU1_D15 (0xF883FF3Cu)
+#define CPU1_A0 (0xF883FF80u)
+#define CPU1_A1 (0xF883FF84u)
+#define CPU1_A2 (0xF883FF88u)
+#define CPU1_A3 (0xF883FF8Cu)
+#define CPU1_A4 (0xF883FF90u)
+#define CPU1_A5 (0xF883FF94u)
+#define CPU1_A6 (0xF883FF98u)
+#define CPU1_A7 (0xF883FF9Cu)
+#define CPU1_A8 (0xF883FFA0u)
+#define CPU1_A9 (0xF883FFA4u)
+#define CPU1_A10 (0xF883FFA8u)
+#define CPU1_A11 (0xF883FFACu)
+#define CPU1_A12 (0xF883FFB0u)
+#define CPU1_A13 (0xF883FFB4u)
+#define CPU1_A14 (0xF883FFB8u)
+#define CPU1_A15 (0xF883FFBCu)
+#define CPU2_FLASHCON0 (0xF8841100u)
+#define CPU2_FLASHCON1 (0xF8841104u)
+#define CPU2_FLASHCON2 (0xF8841108u)
+#define CPU2_FLASHCON3 (0xF884110Cu)
+#define CPU2_FLASHCON4 (0xF8841110u)
+#define CPU2_KRST0 (0xF884D000u)
+#define CPU2_KRST1 (0xF884D004u)
+#define CPU2_KRSTCLR (0xF884D008u)
+#define CPU2_RGN0_LA (0xF884E000u)
+#define CPU2_RGN0_UA (0xF884E004u)
+#define CPU2_RGN0_ACCENA (0xF884E008u)
+#define CPU2_RGN0_ACCENB (0xF884E00Cu)
+#define CPU2_RGN1_LA (0xF884E010u)
+#define CPU2_RGN1_UA (0xF884E014u)
+#define CPU2_RGN1_ACCENA (0xF884E018u)
+#define CPU2_RGN1_ACCENB (0xF884E01Cu)
+#define CPU2_RGN2_LA (0xF884E020u)
+#define CPU2_RGN2_UA (0xF884E024u)
+#define CPU2_RGN2_ACCENA (0xF884E028u)
+#define CPU2_RGN2_ACCENB (0xF884E02Cu)
+#define CPU2_RGN3_LA (0xF884E030u)
+#define CPU2_RGN3_UA (0xF884E034u)
+#define CPU2_RGN3_ACCENA (0xF884E038u)
+#define CPU2_RGN3_ACCENB (0xF884E03Cu)
+#define CPU2_RGN4_LA (0xF884E040u)
+#define CPU2_RGN4_UA (0xF884E044u)
+#define CPU2_RGN4_ACCENA (0xF884E048u)
+#define CPU2_RGN4_ACCENB (0xF884E04Cu)
+#define CPU2_RGN5_LA (0xF884E050u)
+#define CPU2_RGN5_UA (0xF884E054u)
+#define CPU2_RGN5_ACCENA (0xF884E058u)
+#define CPU2_RGN5_ACCENB (0xF884E05Cu)
+#define CPU2_RGN6_LA (0xF884E060u)
+#define CPU2_RGN6_UA (0xF884E064u)
+#define CPU2_RGN6_ACCENA (0xF884E068u)
+#define CPU2_RGN6_ACCENB (0xF884E06Cu)
+#define CPU2_RGN7_LA (0xF884E070u)
+#define CPU2_RGN7_UA (0xF884E074u)
+#define CPU2_RGN7_ACCENA (0xF884E078u)
+#define CPU2_RGN7_ACCENB (0xF884E07Cu)
+#define CPU2_SPR_SPROT_RGNACCENA_R0 (0xF884E088u)
+#define CPU2_SPR_SPROT_RGNACCENB_R0 (0xF884E08Cu)
+#define CPU2_SPR_SPROT_RGNACCENA_R1 (0xF884E098u)
+#define CPU2_SPR_SPROT_RGNACCENB_R1 (0xF884E09Cu)
+#define CPU2_SPR_SPROT_RGNACCENA_R2 (0xF884E0A8u)
+#define CPU2_SPR_SPROT_RGNACCENB_R2 (0xF884E0ACu)
+#define CPU2_SPR_SPROT_RGNACCENA_R3 (0xF884E0B8u)
+#define CPU2_SPR_SPROT_RGNACCENB_R3 (0xF884E0BCu)
+#define CPU2_SPR_SPROT_RGNACCENA_R4 (0xF884E0C8u)
+#define CPU2_SPR_SPROT_RGNACCENB_R4 (0xF884E0CCu)
+#define CPU2_SPR_SPROT_RGNACCENA_R5 (0xF884E0D8u)
+#define CPU2_SPR_SPROT_RGNACCENB_R5 (0xF884E0DCu)
+#define CPU2_SPR_SPROT_RGNACCENA_R6 (0xF884E0E8u)
+#define CPU2_SPR_SPROT_RGNACCENB_R6 (0xF884E0ECu)
+#define CPU2_SPR_SPROT_RGNACCENA_R7 (0xF884E0F8u)
+#define CPU2_SPR_SPROT_RGNACCENB_R7 (0xF884E0FCu)
+#define CPU2_SFR_SPROT_ACCENA_W (0xF884E100u)
+#define CPU2_SFR_SPROT_ACCENB_W (0xF884E104u)
+#define CPU2_LPB_SPROT_ACCENA_R (0xF884E110u)
+#define CPU2_LPB_SPROT_ACCENB_R (0xF884E114u)
+#define CPU2_DLMU_SPROT_RGNLA0 (0xF884E200u)
+#define CPU2_DLMU_SPROT_RGNUA0 (0xF884E204u)
+#define CPU2_DLMU_SPROT_RGNACCENA_W0 (0xF884E208u)
+#define CPU2_DLMU_SPROT_RGNACCENB_W0 (0xF884E20Cu)
+#define CPU2_DLMU_SPROT_RGNLA1 (0xF884E210u)
+#define CPU2_DLMU_SPROT_RGNUA1 (0xF884E214u)
+#define CPU2_DLMU_SPROT_RGNACCENA_W1 (0xF884E218u)
+#define CPU2_DLMU_SPROT_RGNACCENB_W1 (0xF884E21Cu)
+#define CPU2_DLMU_SPROT_RGNLA2 (0xF884E220u)
+#define CPU2_DLMU_SPROT_RGNUA2 (0xF884E224u)
+#define CPU2_DLMU_SPROT_RGNACCENA_W2 (0xF884E228u)
+#define CPU2_DLMU_SPROT_RGNACCENB_W2 (0xF884E22Cu)
+#define CPU2_DLMU_SPROT_RGNLA3 (0xF884E230u)
+#define CPU2_DLMU_SPROT_RGNUA3 (0xF884E234u)
+#define CPU2_DLMU_SPROT_RGNACCENA_W3 (0xF884E238u)
+#define CPU2_DLMU_SPROT_RGNACCENB_W3 (0xF884E23Cu)
+#define CPU2_DLMU_SPROT_RGNLA4 (0xF884E240u)
+#define CPU2_DLMU_SPROT_RGNUA4 (0xF884E244u)
+#define CPU2_DLMU_SPROT_RGNACCENA_W4 (0xF884E248u)
+#define CPU2_DLMU_SPROT_RGNACCENB_W4 (0xF884E24Cu)
+#define CPU2_DLMU_SPROT_RGNLA5 (0xF884E250u)
+#define CPU2_DLMU_SPROT_RGNUA5 (0xF884E254u)
+#define CPU2_DLMU_SPROT_RGNACCENA_W5 (0xF884E258u)
+#define CPU2_DLMU_SPROT_RGNACCENB_W5 (0xF884E25Cu)
+#define CPU2_DLMU_SPROT_RGNLA6 (0xF884E260u)
+#define CPU2_DLMU_SPROT_RGNUA6 (0xF884E264u)
+#define CPU2_DLMU_SPROT_RGNACCENA_W6 (0xF884E268u)
+#define CPU2_DLMU_SPROT_RGNACCENB_W6 (0xF884E26Cu)
+#define CPU2_DLMU_SPROT_RGNLA7 (0xF884E270u)
+#define CPU2_DLMU_SPROT_RGNUA7 (0xF884E274u)
+#define CPU2_DLMU_SPROT_RGNACCENA_W7 (0xF884E278u)
+#define CPU2_DLMU_SPROT_RGNACCENB_W7 (0xF884E27Cu)
+#define CPU2_DLMU_SPROT_RGNACCENA_R0 (0xF884E288u)
+#define CPU2_DLMU_SPROT_RGNACCENB_R0 (0xF884E28Cu)
+#define CPU2_DLMU_SPROT_RGNACCENA_R1 (0xF884E298u)
+#define CPU2_DLMU_SPROT_RGNACCENB_R1 (0xF884E29Cu)
+#define CPU2_DLMU_SPROT_RGNACCENA_R2 (0xF884E2A8u)
+#define CPU2_DLMU_SPROT_RGNACCENB_R2 (0xF884E2ACu)
+#define CPU2_DLMU_SPROT_RGNACCENA_R3 (0xF884E2B8u)
+#define CPU2_DLMU_SPROT_RGNACCENB_R3 (0xF884E2BCu)
+#define CPU2_DLMU_SPROT_RGNACCENA_R4 (0xF884E2C8u)
+#define CPU2_DLMU_SPROT_RGNACCENB_R4 (0xF884E2CCu)
+#define CPU2_DLMU_SPROT_RGNACCENA_R5 (0xF884E2D8u)
+#define CPU2_DLMU_SPROT_RGNACCENB_R5 (0xF884E2DCu)
+#define CPU2_DLMU_SPROT_RGNACCENA_R6 (0xF884E2E8u)
+#define CPU2_DLMU_SPROT_RGNACCENB_R6 (0xF884E2ECu)
+#define CPU2_DLMU_SPROT_RGNACCENA_R7 (0xF884E2F8u)
+#define CPU2_DLMU_SPROT_RGNACCENB_R7 (0xF884E2FCu)
+#define CPU2_OSEL (0xF884FB00u)
+#define CPU2_BLK0_RABR (0xF884FB10u)
+#define CPU2_BLK0_OTAR (0xF884FB14u)
+#define CPU2_BLK0_OMASK (0xF884FB18u)
+#define CPU2_BLK1_RABR (0xF884FB1Cu)
+#define CPU2_BLK1_OTAR (0xF884FB20u)
+#define CPU2_BLK1_OMASK (0xF884FB24u)
+#define CPU2_BLK2_RABR (0xF884FB28u)
+#define CPU2_BLK2_OTAR (0xF884FB2Cu)
+#define CPU2_BLK2_OMASK (0xF884FB30u)
+#define CPU2_BLK3_RABR (0xF884FB34u)
+#define CPU2_BLK3_OTAR (0xF884FB38u)
+#define CPU2_BLK3_OMASK (0xF884FB3Cu)
+#define CPU2_BLK4_RABR (0xF884FB40u)
+#define CPU2_BLK4_OTAR (0xF884FB44u)
+#define CPU2_BLK4_OMASK (0xF884FB48u)
+#define CPU2_BLK5_RABR (0xF884FB4Cu)
+#define CPU2_BLK5_OTAR (0xF884FB50u)
+#define CPU2_BLK5_OMASK (0xF884FB54u)
+#define CPU2_BLK6_RABR (0xF884FB58u)
+#define CPU2_BLK6_OTAR (0xF884FB5Cu)
+#define CPU2_BLK6_OMASK (0xF884FB60u)
+#define CPU2_BLK7_RABR (0xF884FB64u)
+#define CPU2_BLK7_OTAR (0xF884FB68u)
+#define CPU2_BLK7_OMASK (0xF884FB6Cu)
+#define CPU2_BLK8_RABR (0xF884FB70u)
+#define CPU2_BLK8_OTAR (0xF884FB74u)
+#define CPU2_BLK8_OMASK (0xF884FB78u)
+#define CPU2_BLK9_RABR (0xF884FB7Cu)
+#define CPU2_BLK9_OTAR (0xF884FB80u)
+#define CPU2_BLK9_OMASK (0xF884FB84u)
+#define CPU2_BLK10_RABR (0xF884FB88u)
+#define CPU2_BLK10_OTAR (0xF884FB8Cu)
+#define CPU2_BLK10_OMASK (0xF884FB90u)
+#define CPU2_BLK11_RABR (0xF884FB94u)
+#define CPU2_BLK11_OTAR (0xF884FB98u)
+#define CPU2_BLK11_OMASK (0xF884FB9Cu)
+#define CPU2_BLK12_RABR (0xF884FBA0u)
+#define CPU2_BLK12_OTAR (0xF884FBA4u)
+#define CPU2_BLK12_OMASK (0xF884FBA8u)
+#define CPU2_BLK13_RABR (0xF884FBACu)
+#define CPU2_BLK13_OTAR (0xF884FBB0u)
+#define CPU2_BLK13_OMASK (0xF884FBB4u)
+#define CPU2_BLK14_RABR (0xF884FBB8u)
+#define CPU2_BLK14_OTAR (0xF884FBBCu)
+#define CPU2_BLK14_OMASK (0xF884FBC0u)
+#define CPU2_BLK15_RABR (0xF884FBC4u)
+#define CPU2_BLK15_OTAR (0xF884FBC8u)
+#define CPU2_BLK15_OMASK (0xF884FBCCu)
+#define CPU2_BLK16_RABR (0xF884FBD0u)
+#define CPU2_BLK16_OTAR (0xF884FBD4u)
+#define CPU2_BLK16_OMASK (0xF884FBD8u)
+#define CPU2_BLK17_RABR (0xF884FBDCu)
+#define CPU2_BLK17_OTAR (0xF884FBE0u)
+#define CPU2_BLK17_OMASK (0xF884FBE4u)
+#define CPU2_BLK18_RABR (0xF884FBE8u)
+#define CPU2_BLK18_OTAR (0xF884FBECu)
+#define CPU2_BLK18_OMASK (0xF884FBF0u)
+#define CPU2_BLK19_RABR (0xF884FBF4u)
+#define CPU2_BLK19_OTAR (0xF884FBF8u)
+#define CPU2_BLK19_OMASK (0xF884FBFCu)
+#define CPU2_BLK20_RABR (0xF884FC00u)
+#define CPU2_BLK20_OTAR (0xF884FC04u)
+#define CPU2_BLK20_OMASK (0xF884FC08u)
+#define CPU2_BLK21_RABR (0xF884FC0Cu)
+#define CPU2_BLK21_OTAR (0xF884FC10u)
+#define CPU2_BLK21_OMASK (0xF884FC14u)
+#define CPU2_BLK22_RABR (0xF884FC18u)
+#define CPU2_BLK22_OTAR (0xF884FC1Cu)
+#define CPU2_BLK22_OMASK (0xF884FC20u)
+#define CPU2_BLK23_RABR (0xF884FC24u)
+#define CPU2_BLK23_OTAR (0xF884FC28u)
+#define CPU2_BLK23_OMASK (0xF884FC2Cu)
+#define CPU2_BLK24_RABR (0xF884FC30u)
+#define CPU2_BLK24_OTAR (0xF884FC34u)
+#define CPU2_BLK24_OMASK (0xF884FC38u)
+#define CPU2_BLK25_RABR (0xF884FC3Cu)
+#define CPU2_BLK25_OTAR (0xF884FC40u)
+#define CPU2_BLK25_OMASK (0xF884FC44u)
+#define CPU2_BLK26_RABR (0xF884FC48u)
+#define CPU2_BLK26_OTAR (0xF884FC4Cu)
+#define CPU2_BLK26_OMASK (0xF884FC50u)
+#define CPU2_BLK27_RABR (0xF884FC54u)
+#define CPU2_BLK27_OTAR (0xF884FC58u)
+#define CPU2_BLK27_OMASK (0xF884FC5Cu)
+#define CPU2_BLK28_RABR (0xF884FC60u)
+#define CPU2_BLK28_OTAR (0xF884FC64u)
+#define CPU2_BLK28_OMASK (0xF884FC68u)
+#define CPU2_BLK29_RABR (0xF884FC6Cu)
+#define CPU2_BLK29_OTAR (0xF884FC70u)
+#define CPU2_BLK29_OMASK (0xF884FC74u)
+#define CPU2_BLK30_RABR (0xF884FC78u)
+#define CPU2_BLK30_OTAR (0xF884FC7Cu)
+#define CPU2_BLK30_OMASK (0xF884FC80u)
+#define CPU2_BLK31_RABR (0xF884FC84u)
+#define CPU2_BLK31_OTAR (0xF884FC88u)
+#define CPU2_BLK31_OMASK (0xF884FC8Cu)
+#define CPU2_SEGEN (0xF8851030u)
+#define CPU2_TASK_ASI (0xF8858004u)
+#define CPU2_PMA0 (0xF8858100u)
+#define CPU2_PMA1 (0xF8858104u)
+#define CPU2_PMA2 (0xF8858108u)
+#define CPU2_DCON2 (0xF8859000u)
+#define CPU2_SMACON (0xF885900Cu)
+#define CPU2_DSTR (0xF8859010u)
+#define CPU2_DATR (0xF8859018u)
+#define CPU2_DEADD (0xF885901Cu)
+#define CPU2_DIEAR (0xF8859020u)
+#define CPU2_DIETR (0xF8859024u)
+#define CPU2_DCON0 (0xF8859040u)
+#define CPU2_PSTR (0xF8859200u)
+#define CPU2_PCON1 (0xF8859204u)
+#define CPU2_PCON2 (0xF8859208u)
+#define CPU2_PCON0 (0xF885920Cu)
+#define CPU2_PIEAR (0xF8859210u)
+#define CPU2_PIETR (0xF8859214u)
+#define CPU2_COMPAT (0xF8859400u)
+#define CPU2_FPU_TRAP_CON (0xF885A000u)
+#define CPU2_FPU_TRAP_PC (0xF885A004u)
+#define CPU2_FPU_TRAP_OPC (0xF885A008u)
+#define CPU2_FPU_TRAP_SRC1 (0xF885A010u)
+#define CPU2_FPU_TRAP_SRC2 (0xF885A014u)
+#define CPU2_FPU_TRAP_SRC3 (0xF885A018u)
+#define CPU2_DPR0_L (0xF885C000u)
+#define CPU2_DPR0_U (0xF885C004u)
+#define CPU2_DPR1_L (0xF885C008u)
+#define CPU2_DPR1_U (0xF885C00Cu)
+#define CPU2_DPR2_L (0xF885C010u)
+#define CPU2_DPR2_U (0xF885C014u)
+#define CPU2_DPR3_L (0xF885C018u)
+#define CPU2_DPR3_U (0xF885C01Cu)
+#define CPU2_DPR4_L (0xF885C020u)
+#define CPU2_DPR4_U (0xF885C024u)
+#define CPU2_DPR5_L (0xF885C028u)
+#define CPU2_DPR5_U (0xF885C02Cu)
+#define CPU2_DPR6_L (0xF885C030u)
+#define CPU2_DPR6_U (0xF885C034u)
+#define CPU2_DPR7_L (0xF885C038u)
+#define CPU2_DPR7_U (0xF885C03Cu)
+#define CPU2_DPR8_L (0xF885C040u)
+#define CPU2_DPR8_U (0xF885C044u)
+#define CPU2_DPR9_L (0xF885C048u)
+#define CPU2_DPR9_U (0xF885C04Cu)
+#define CPU2_DPR10_L (0xF885C050u)
+#define CPU2_DPR10_U (0xF885C054u)
+#define CPU2_DPR11_L (0xF885C058u)
+#define CPU2_DPR11_U (0xF885C05Cu)
+#define CPU2_DPR12_L (0xF885C060u)
+#define CPU2_DPR12_U (0xF885C064u)
+#define CPU2_DPR13_L (0xF885C068u)
+#define CPU2_DPR13_U (0xF885C06Cu)
+#define CPU2_DPR14_L (0xF885C070u)
+#define CPU2_DPR14_U (0xF885C074u)
+#define CPU2_DPR15_L (0xF885C078u)
+#define CPU2_DPR15_U (0xF885C07Cu)
+#define CPU2_DPR16_L (0xF885C080u)
+#define CPU2_DPR16_U (0xF885C084u)
+#define CPU2_DPR17_L (0xF885C088u)
+#define CPU2_DPR17_U (0xF885C08Cu)
+#define CPU2_CPR0_L (0xF885D000u)
+#define CPU2_CPR0_U (0xF885D004u)
+#define CPU2_CPR1_L (0xF885D008u)
+#define CPU2_CPR1_U (0xF885D00Cu)
+#define CPU2_CPR2_L (0xF885D010u)
+#define CPU2_CPR2_U (0xF885D014u)
+#define CPU2_CPR3_L (0xF885D018u)
+#define CPU2_CPR3_U (0xF885D01Cu)
+#define CPU2_CPR4_L (0xF885D020u)
+#define CPU2_CPR4_U (0xF885D024u)
+#define CPU2_CPR5_L (0xF885D028u)
+#define CPU2_CPR5_U (0xF885D02Cu)
+#define CPU2_CPR6_L (0xF885D030u)
+#define CPU2_CPR6_U (0xF885D034u)
+#define CPU2_CPR7_L (0xF885D038u)
+#define CPU2_CPR7_U (0xF885D03Cu)
+#define CPU2_CPR8_L (0xF885D040u)
+#define CPU2_CPR8_U (0xF885D044u)
+#define CPU2_CPR9_L (0xF885D048u)
+#define CPU2_CPR9_U (0xF885D04Cu)
+#define CPU2_CPXE_0 (0xF885E000u)
+#define CPU2_CPXE_1 (0xF885E004u)
+#define CPU2_CPXE_2 (0xF885E008u)
+#define CPU2_CPXE_3 (0xF885E00Cu)
+#define CPU2_DPRE_0 (0xF885E010u)
+#define CPU2_DPRE_1 (0xF885E014u)
+#define CPU2_DPRE_2 (0xF885E018u)
+#define CPU2_DPRE_3 (0xF885E01Cu)
+#define CPU2_DPWE_0 (0xF885E020u)
+#define CPU2_DPWE_1 (0xF885E024u)
+#define CPU2_DPWE_2 (0xF885E028u)
+#define CPU2_DPWE_3 (0xF885E02Cu)
+#define CPU2_CPXE_4 (0xF885E040u)
+#define CPU2_CPXE_5 (0xF885E044u)
+#define CPU2_DPRE_4 (0xF885E050u)
+#define CPU2_DPRE_5 (0xF885E054u)
+#define CPU2_DPWE_4 (0xF885E060u)
+#define CPU2_DPWE_5 (0xF885E064u)
+#define CPU2_TPS_CON (0xF885E400u)
+#define CPU2_TPS_TIMER0 (0xF885E404u)
+#define CPU2_TPS_TIMER1 (0xF885E408u)
+#define CPU2_TPS_TIMER2 (0xF885E40Cu)
+#define CPU2_TPS_EXTIM_ENTRY_LVAL (0xF885E440u)
+#define CPU2_TPS_EXTIM_ENTRY_CVAL (0xF885E444u)
+#define CPU2_TPS_EXTIM_EXIT_LVAL (0xF885E448u)
+#define CPU2_TPS_EXTIM_EXIT_CVAL (0xF885E44Cu)
+#define CPU2_TPS_EXTIM_CLASS_EN (0xF885E450u)
+#define CPU2_TPS_EXTIM_STAT (0xF885E454u)
+#define CPU2_TPS_EXTIM_FCX (0xF885E458u)
+#define CPU2_TR0_EVT (0xF885F000u)
+#define CPU2_TR0_ADR (0xF885F004u)
+#define CPU2_TR1_EVT (0xF885F008u)
+#define CPU2_TR1_ADR (0xF885F00Cu)
+#define CPU2_TR2_EVT (0xF885F010u)
+#define CPU2_TR2_ADR (0xF885F014u)
+#define CPU2_TR3_EVT (0xF885F018u)
+#define CPU2_TR3_ADR (0xF885F01Cu)
+#define CPU2_TR4_EVT (0xF885F020u)
+#define CPU2_TR4_ADR (0xF885F024u)
+#define CPU2_TR5_EVT (0xF885F028u)
+#define CPU2_TR5_ADR (0xF885F02Cu)
+#define CPU2_TR6_EVT (0xF885F030u)
+#define CPU2_TR6_ADR (0xF885F034u)
+#define CPU2_TR7_EVT (0xF885F038u)
+#define CPU2_TR7_ADR (0xF885F03Cu)
+#define CPU2_CCTRL (0xF885FC00u)
+#define CPU2_CCNT (0xF885FC04u)
+#define CPU2_ICNT (0xF885FC08u)
+#define CPU2_M1CNT (0xF885FC0Cu)
+#define CPU2_M2CNT (0xF885FC10u)
+#define CPU2_M3CNT (0xF885FC14u)
+#define CPU2_DBGSR /*lint --e(923, 9078)*/ (*(volatile Ifx_CPU_DBGSR*)0xF885FD00u)
+#define CPU2_EXEVT (0xF885FD08u)
+#define CPU2_CREVT (0xF885FD0Cu)
+#define CPU2_SWEVT (0xF885FD10u)
+#define CPU2_TRIG_ACC (0xF885FD30u)
+#define CPU2_DMS (0xF885FD40u)
+#define CPU2_DCX (0xF885FD44u)
+#define CPU2_DBGTCR (0xF885FD48u)
+#define CPU2_PCXI (0xF885FE00u)
+#define CPU2_PSW (0xF885FE04u)
+#define CPU2_PC /*lint --e(923, 9078)*/ (*(volatile Ifx_CPU_PC*)0xF885FE08u)
+#define CPU2_SYSCON /*lint --e(923, 9078)*/ (*(volatile Ifx_CPU_SYSCON*)0xF885FE14u)
+#define CPU2_CPU_ID (0xF885FE18u)
+#define CPU2_CORE_ID /*lint --e(923, 9078)*/ (*(volatile Ifx_CPU_CORE_ID*)0xF885FE1Cu)
+#define CPU2_BIV (0xF885FE20u)
+#define CPU2_BTV (0xF885FE24u)
+#define CPU2_ISP (0xF885FE28u)
+#define CPU2_ICR (0xF885FE2Cu)
+#define CPU2_FCX (0xF885FE38u)
+#define CPU2_LCX (0xF885FE3Cu)
+#define CPU2_CUS_ID (0xF885FE50u)
+#define CPU2_D0 (0xF885FF00u)
+#define CPU2_D1 (0xF885FF04u)
+#define CPU2_D2 (0xF885FF08u)
+#define CPU2_D3 (0xF885FF0Cu)
+#define CPU2_D4 (0xF885FF10u)
+#define CPU2_D5 (0xF885FF14u)
+#define CPU2_D6 (0xF885FF18u)
+#define CPU2_D7 (0xF885FF1Cu)
+#define CPU2_D8 (0xF885FF20u)
+#define CPU2_D9 (0xF885FF24u)
+#define CPU2_D10 (0xF885FF28u)
+#define CPU2_D11 (0xF885FF2Cu)
+#define CPU2_D12 (0xF885FF30u)
+#define CPU2_D13 (0xF885FF34u)
+#define CPU2_D14 (0xF885FF38u)
+#define CPU2_D15 (0xF885FF3Cu)
+#define CPU2_A0 (0xF885FF80u)
+#define CPU2_A1 (0xF885FF84u)
+#define CPU2_A2 (0xF885FF88u)
+#define CPU2_A3 (0xF885FF8Cu)
+#define CPU2_A4 (0xF885FF90u)
+#define CPU2_A5 (0xF885FF94u)
+#define CPU2_A6 (0xF885FF98u)
+#define CPU2_A7 (0xF885FF9Cu)
+#define CPU2_A8 (0xF885FFA0u)
+#define CPU2_A9 (0xF885FFA4u)
+#define CPU2_A10 (0xF885FFA8u)
+#define CPU2_A11 (0xF885FFACu)
+#define CPU2_A12 (0xF885FFB0u)
+#define CPU2_A13 (0xF885FFB4u)
+#define CPU2_A14 (0xF885FFB8u)
+#define CPU2_A15 (0xF885FFBCu)
+#define CPU0_FLASHCON0 (0xF8801100u)
+#define CPU0_FLASHCON1 (0xF8801104u)
+#define CPU0_FLASHCON2 (0xF8801108u)
+#define CPU0_FLASHCON3 (0xF880110Cu)
+#define CPU0_FLASHCON4 (0xF8801110u)
+#define CPU0_KRST0 (0xF880D000u)
+#define CPU0_KRST1 (0xF880D004u)
+#define CPU0_KRSTCLR (0xF880D008u)
+#define CPU0_RGN0_LA (0xF880E000u)
+#define CPU0_RGN0_UA (0xF880E004u)
+#define MODULE_CPU0 (0xF8800000u)
+#define CPU0_RGN0_ACCENA (0xF880E008u)
+#define MODULE_CPU1 (0xF8820000u)
+#define CPU0_RGN0_ACCENB (0xF880E00Cu)
+#define CPU0_RGN1_LA (0xF880E010u)
+#define MODULE_CPU2 (0xF8840000u)
+#define CPU0_RGN1_UA (0xF880E014u)
+#define CPU0_RGN1_ACCENA (0xF880E018u)
+#define CPU0_RGN1_ACCENB (0xF880E01Cu)
+#define CPU0_RGN2_LA (0xF880E020u)
+#define CPU0_RGN2_UA (0xF880E024u)
+#define CPU0_RGN2_ACCENA (0xF880E028u)
+#define CPU0_RGN2_ACCENB (0xF880E02Cu)
+#define CPU0_RGN3_LA (0xF880E030u)
+#define CPU0_RGN3_UA (0xF880E034u)
+#define CPU0_RGN3_ACCENA (0xF880E038u)
+#define CPU0_RGN3_ACCENB (0xF880E03Cu)
+#define CPU0_RGN4_LA (0xF880E040u)
+#define CPU0_RGN4_UA (0xF880E044u)
+#define CPU0_RGN4_ACCENA (0xF880E048u)
+#define CPU0_RGN4_ACCENB (0xF880E04Cu)
+#define CPU0_RGN5_LA (0xF880E050u)
+#define CPU0_RGN5_UA (0xF880E054u)
+#define CPU0_RGN5_ACCENA (0xF880E058u)
+#define CPU0_RGN5_ACCENB (0xF880E05Cu)
+#define CPU0_RGN6_LA (0xF880E060u)
+#define CPU0_RGN6_UA (0xF880E064u)
+#define CPU0_RGN6_ACCENA (0xF880E068u)
+#define CPU0_RGN6_ACCENB (0xF880E06Cu)
+#define CPU0_RGN7_LA (0xF880E070u)
+#define CPU0_RGN7_UA (0xF880E074u)
+#define CPU0_RGN7_ACCENA (0xF880E078u)
+#define CPU0_RGN7_ACCENB (0xF880E07Cu)
+#define CPU0_SPR_SPROT_RGNACCENA_R0 (0xF880E088u)
+#define CPU0_SPR_SPROT_RGNACCENB_R0 (0xF880E08Cu)
+#define CPU0_SPR_SPROT_RGNACCENA_R1 (0xF880E098u)
+#define CPU0_SPR_SPROT_RGNACCENB_R1 (0xF880E09Cu)
+#define CPU0_SPR_SPROT_RGNACCENA_R2 (0xF880E0A8u)
+#define CPU0_SPR_SPROT_RGNACCENB_R2 (0xF880E0ACu)
+#define CPU0_SPR_SPROT_RGNACCENA_R3 (0xF880E0B8u)
+#define DAM0_RGN0_ACCENA (0xF8500058u)
+#define DAM0_RGN0_ACCENB (0xF850005Cu)
+#define DAM0_RGN1_LA (0xF8500060u)
+#define DAM0_RGN1_UA (0xF8500064u)
+#define DAM0_RGN1_ACCENA (0xF8500068u)
+#define DAM0_RGN1_ACCENB (0xF850006Cu)
+#define DAM0_RGN2_LA (0xF8500070u)
+#define DAM0_RGN2_UA (0xF8500074u)
+#define DAM0_RGN2_ACCENA (0xF8500078u)
+#define DAM0_RGN2_ACCENB (0xF850007Cu)
+#define DAM0_RGN3_LA (0xF8500080u)
+#define DAM0_RGN3_UA (0xF8500084u)
+#define DAM0_RGN3_ACCENA (0xF8500088u)
+#define DAM0_RGN3_ACCENB (0xF850008Cu)
+#define DAM0_RGN4_LA (0xF8500090u)
+#define DAM0_RGN4_UA (0xF8500094u)
+#define DAM0_RGN4_ACCENA (0xF8500098u)
+#define DAM0_RGN4_ACCENB (0xF850009Cu)
+#define DAM0_RGN5_LA (0xF85000A0u)
+#define DAM0_RGN5_UA (0xF85000A4u)
+#define DAM0_RGN5_ACCENA (0xF85000A8u)
+#define DAM0_RGN5_ACCENB (0xF85000ACu)
+#define DAM0_RGN6_LA (0xF85000B0u)
+#define DAM0_RGN6_UA (0xF85000B4u)
+#define DAM0_RGN6_ACCENA (0xF85000B8u)
+#define DAM0_RGN6_ACCENB (0xF85000BCu)
+#define DAM0_RGN7_LA (0xF85000C0u)
+#define DAM0_RGN7_UA (0xF85000C4u)
+#define DAM0_RGN7_ACCENA (0xF85000C8u)
+#define DAM0_RGN7_ACCENB (0xF85000CCu)
+#define DAM0_RGNACCEN0_RA (0xF85000D8u)
+#define DAM0_RGNACCEN0_RB (0xF85000DCu)
+#define DAM0_RGNACCEN1_RA (0xF85000E8u)
+#define DAM0_RGNACCEN1_RB (0xF85000ECu)
+#define DAM0_RGNACCEN2_RA (0xF85000F8u)
+#define DAM0_RGNACCEN2_RB (0xF85000FCu)
+#define DAM0_RGNACCEN3_RA (0xF8500108u)
+#define DAM0_RGNACCEN3_RB (0xF850010Cu)
+#define DAM0_RGNACCEN4_RA (0xF8500118u)
+#define DAM0_RGNACCEN4_RB (0xF850011Cu)
+#define DAM0_RGNACCEN5_RA (0xF8500128u)
+#define DAM0_RGNACCEN5_RB (0xF850012Cu)
+#define DAM0_RGNACCEN6_RA (0xF8500138u)
+#define DAM0_RGNACCEN6_RB (0xF850013Cu)
+#define DAM0_RGNACCEN7_RA (0xF8500148u)
+#define DAM0_RGNACCEN7_RB (0xF850014Cu)
+#define MODULE_DAM0 (0xF8500000u)
+#define DAM0_CLC (0xF8500000u)
+#define DAM0_MODID (0xF8500008u)
+#define DAM0_ACCEN0 (0xF8500010u)
+#define DAM0_ACCEN1 (0xF8500014u)
+#define DAM0_MEMCON (0xF8500020u)
+#define DAM0_RGN0_LA (0xF8500050u)
+#define DAM0_RGN0_UA (0xF8500054u)
+#define DMA_CH114_ADICR (0xF0012E50u)
+#define DMA_CH114_CHCFGR (0xF0012E54u)
+#define DMA_HRR78 (0xF0011938u)
+#define DMA_CH114_SHADR (0xF0012E58u)
+#define DMA_CH114_CHCSR (0xF0012E5Cu)
+#define DMA_CH115_RDCRCR (0xF0012E60u)
+#define DMA_CH115_SDCRCR (0xF0012E64u)
+#define DMA_CH115_SADR (0xF0012E68u)
+#define DMA_CH115_DADR (0xF0012E6Cu)
+#define DMA_CH115_ADICR (0xF0012E70u)
+#define DMA_CH115_CHCFGR (0xF0012E74u)
+#define DMA_CH115_SHADR (0xF0012E78u)
+#define DMA_CH115_CHCSR (0xF0012E7Cu)
+#define DMA_HRR79 (0xF001193Cu)
+#define DMA_CH116_RDCRCR (0xF0012E80u)
+#define DMA_CH116_SDCRCR (0xF0012E84u)
+#define DMA_CH116_SADR (0xF0012E88u)
+#define DMA_CH116_DADR (0xF0012E8Cu)
+#define DMA_CH116_ADICR (0xF0012E90u)
+#define DMA_CH116_CHCFGR (0xF0012E94u)
+#define DMA_CH116_SHADR (0xF0012E98u)
+#define DMA_CH116_CHCSR (0xF0012E9Cu)
+#define DMA_CH117_RDCRCR (0xF0012EA0u)
+#define DMA_CH117_SDCRCR (0xF0012EA4u)
+#define DMA_HRR80 (0xF0011940u)
+#define DMA_CH117_SADR (0xF0012EA8u)
+#define DMA_CH117_DADR (0xF0012EACu)
+#define DMA_CH117_ADICR (0xF0012EB0u)
+#define DMA_CH117_CHCFGR (0xF0012EB4u)
+#define DMA_CH117_SHADR (0xF0012EB8u)
+#define DMA_CH117_CHCSR (0xF0012EBCu)
+#define DMA_CH118_RDCRCR (0xF0012EC0u)
+#define DMA_CH118_SDCRCR (0xF0012EC4u)
+#define DMA_CH118_SADR (0xF0012EC8u)
+#define DMA_CH118_DADR (0xF0012ECCu)
+#define DMA_HRR81 (0xF0011944u)
+#define DMA_CH118_ADICR (0xF0012ED0u)
+#define DMA_CH118_CHCFGR (0xF0012ED4u)
+#define DMA_CH118_SHADR (0xF0012ED8u)
+#define DMA_CH118_CHCSR (0xF0012EDCu)
+#define DMA_CH119_RDCRCR (0xF0012EE0u)
+#define DMA_CH119_SDCRCR (0xF0012EE4u)
+#define DMA_CH119_SADR (0xF0012EE8u)
+#define DMA_CH119_DADR (0xF0012EECu)
+#define DMA_CH119_ADICR (0xF0012EF0u)
+#define DMA_CH119_CHCFGR (0xF0012EF4u)
+#define DMA_HRR82 (0xF0011948u)
+#define DMA_CH119_SHADR (0xF0012EF8u)
+#define DMA_CH119_CHCSR (0xF0012EFCu)
+#define DMA_CH120_RDCRCR (0xF0012F00u)
+#define DMA_CH120_SDCRCR (0xF0012F04u)
+#define DMA_CH120_SADR (0xF0012F08u)
+#define DMA_CH120_DADR (0xF0012F0Cu)
+#define DMA_CH120_ADICR (0xF0012F10u)
+#define DMA_CH120_CHCFGR (0xF0012F14u)
+#define DMA_CH120_SHADR (0xF0012F18u)
+#define DMA_CH120_CHCSR (0xF0012F1Cu)
+#define DMA_HRR83 (0xF001194Cu)
+#define DMA_CH121_RDCRCR (0xF0012F20u)
+#define DMA_CH121_SDCRCR (0xF0012F24u)
+#define DMA_CH121_SADR (0xF0012F28u)
+#define DMA_CH121_DADR (0xF0012F2Cu)
+#define DMA_CH121_ADICR (0xF0012F30u)
+#define DMA_ACCEN1_ACCENR1 (0xF001004Cu)
+#define DMA_CH121_CHCFGR (0xF0012F34u)
+#define DMA_CH121_SHADR (0xF0012F38u)
+#define DMA_CH121_CHCSR (0xF0012F3Cu)
+#define DMA_CH122_RDCRCR (0xF0012F40u)
+#define DMA_CH122_SDCRCR (0xF0012F44u)
+#define DMA_HRR84 (0xF0011950u)
+#define DMA_CH122_SADR (0xF0012F48u)
+#define DMA_CH122_DADR (0xF0012F4Cu)
+#define DMA_CH122_ADICR (0xF0012F50u)
+#define DMA_CH122_CHCFGR (0xF0012F54u)
+#define DMA_CH122_SHADR (0xF0012F58u)
+#define DMA_CH122_CHCSR (0xF0012F5Cu)
+#define DMA_CH123_RDCRCR (0xF0012F60u)
+#define DMA_CH123_SDCRCR (0xF0012F64u)
+#define DMA_CH123_SADR (0xF0012F68u)
+#define DMA_CH123_DADR (0xF0012F6Cu)
+#define DMA_HRR85 (0xF0011954u)
+#define DMA_CH123_ADICR (0xF0012F70u)
+#define DMA_CH123_CHCFGR (0xF0012F74u)
+#define DMA_CH123_SHADR (0xF0012F78u)
+#define DMA_CH123_CHCSR (0xF0012F7Cu)
+#define DMA_CH124_RDCRCR (0xF0012F80u)
+#define DMA_CH124_SDCRCR (0xF0012F84u)
+#define DMA_CH124_SADR (0xF0012F88u)
+#define DMA_CH124_DADR (0xF0012F8Cu)
+#define DMA_CH124_ADICR (0xF0012F90u)
+#define DMA_CH124_CHCFGR (0xF0012F94u)
+#define DMA_HRR86 (0xF0011958u)
+#define DMA_CH124_SHADR (0xF0012F98u)
+#define DMA_CH124_CHCSR (0xF0012F9Cu)
+#define DMA_CH125_RDCRCR (0xF0012FA0u)
+#define DMA_CH125_SDCRCR (0xF0012FA4u)
+#define DMA_CH125_SADR (0xF0012FA8u)
+#define DMA_CH125_DADR (0xF0012FACu)
+#define DMA_CH125_ADICR (0xF0012FB0u)
+#define DMA_CH125_CHCFGR (0xF0012FB4u)
+#define DMA_CH125_SHADR (0xF0012FB8u)
+#define DMA_CH125_CHCSR (0xF0012FBCu)
+#define DMA_HRR87 (0xF001195Cu)
+#define DMA_CH126_RDCRCR (0xF0012FC0u)
+#define DMA_CH126_SDCRCR (0xF0012FC4u)
+#define DMA_CH126_SADR (0xF0012FC8u)
+#define DMA_CH126_DADR (0xF0012FCCu)
+#define DMA_CH126_ADICR (0xF0012FD0u)
+#define DMA_CH126_CHCFGR (0xF0012FD4u)
+#define DMA_CH126_SHADR (0xF0012FD8u)
+#define DMA_CH126_CHCSR (0xF0012FDCu)
+#define DMA_CH127_RDCRCR (0xF0012FE0u)
+#define DMA_CH127_SDCRCR (0xF0012FE4u)
+#define DMA_HRR88 (0xF0011960u)
+#define DMA_CH127_SADR (0xF0012FE8u)
+#define DMA_CH127_DADR (0xF0012FECu)
+#define DMA_CH127_ADICR (0xF0012FF0u)
+#define DMA_CH127_CHCFGR (0xF0012FF4u)
+#define DMA_CH127_SHADR (0xF0012FF8u)
+#define DMA_CH127_CHCSR (0xF0012FFCu)
+#define DMA_HRR89 (0xF0011964u)
+#define DMA_HRR90 (0xF0011968u)
+#define DMA_HRR91 (0xF001196Cu)
+#define DMA_HRR92 (0xF0011970u)
+#define DMA_HRR93 (0xF0011974u)
+#define DMA_ACCEN2_ACCENR0 (0xF0010050u)
+#define DMA_HRR94 (0xF0011978u)
+#define DMA_HRR95 (0xF001197Cu)
+#define DMA_HRR96 (0xF0011980u)
+#define DMA_HRR97 (0xF0011984u)
+#define DMA_HRR98 (0xF0011988u)
+#define DMA_HRR99 (0xF001198Cu)
+#define DMA_HRR100 (0xF0011990u)
+#define DMA_HRR101 (0xF0011994u)
+#define DMA_HRR102 (0xF0011998u)
+#define DMA_HRR103 (0xF001199Cu)
+#define DMA_HRR104 (0xF00119A0u)
+#define DMA_HRR105 (0xF00119A4u)
+#define DMA_HRR106 (0xF00119A8u)
+#define DMA_HRR107 (0xF00119ACu)
+#define DMA_HRR108 (0xF00119B0u)
+#define DMA_ACCEN2_ACCENR1 (0xF0010054u)
+#define DMA_HRR109 (0xF00119B4u)
+#define DMA_HRR110 (0xF00119B8u)
+#define DMA_HRR111 (0xF00119BCu)
+#define DMA_HRR112 (0xF00119C0u)
+#define DMA_HRR113 (0xF00119C4u)
+#define DMA_HRR114 (0xF00119C8u)
+#define DMA_HRR115 (0xF00119CCu)
+#define DMA_HRR116 (0xF00119D0u)
+#define DMA_HRR117 (0xF00119D4u)
+#define DMA_HRR118 (0xF00119D8u)
+#define DMA_HRR119 (0xF00119DCu)
+#define DMA_HRR120 (0xF00119E0u)
+#define DMA_HRR121 (0xF00119E4u)
+#define DMA_HRR122 (0xF00119E8u)
+#define DMA_HRR123 (0xF00119ECu)
+#define DMA_HRR124 (0xF00119F0u)
+#define DMA_HRR125 (0xF00119F4u)
+#define DMA_HRR126 (0xF00119F8u)
+#define DMA_HRR127 (0xF00119FCu)
+#define DMA_SUSENR0 (0xF0011A00u)
+#define DMA_SUSENR1 (0xF0011A04u)
+#define DMA_ACCEN3_ACCENR0 (0xF0010058u)
+#define DMA_SUSENR2 (0xF0011A08u)
+#define DMA_SUSENR3 (0xF0011A0Cu)
+#define DMA_SUSENR4 (0xF0011A10u)
+#define DMA_SUSENR5 (0xF0011A14u)
+#define DMA_SUSENR6 (0xF0011A18u)
+#define DMA_SUSENR7 (0xF0011A1Cu)
+#define DMA_SUSENR8 (0xF0011A20u)
+#define DMA_SUSENR9 (0xF0011A24u)
+#define DMA_SUSENR10 (0xF0011A28u)
+#define DMA_SUSENR11 (0xF0011A2Cu)
+#define DMA_ACCEN3_ACCENR1 (0xF001005Cu)
+#define DMA_SUSENR12 (0xF0011A30u)
+#define DMA_SUSENR13 (0xF0011A34u)
+#define DMA_SUSENR14 (0xF0011A38u)
+#define DMA_SUSENR15 (0xF0011A3Cu)
+#define DMA_SUSENR16 (0xF0011A40u)
+#define DMA_SUSENR17 (0xF0011A44u)
+#define DMA_SUSENR18 (0xF0011A48u)
+#define DMA_SUSENR19 (0xF0011A4Cu)
+#define DMA_SUSENR20 (0xF0011A50u)
+#define DMA_SUSENR21 (0xF0011A54u)
+#define DMA_ME0_EER (0xF0010120u)
+#define DMA_SUSENR22 (0xF0011A58u)
+#define DMA_SUSENR23 (0xF0011A5Cu)
+#define DMA_SUSENR24 (0xF0011A60u)
+#define DMA_SUSENR25 (0xF0011A64u)
+#define DMA_SUSENR26 (0xF0011A68u)
+#define DMA_SUSENR27 (0xF0011A6Cu)
+#define DMA_SUSENR28 (0xF0011A70u)
+#define DMA_SUSENR29 (0xF0011A74u)
+#define DMA_SUSENR30 (0xF0011A78u)
+#define DMA_SUSENR31 (0xF0011A7Cu)
+#define DMA_ME0_ERRSR (0xF0010124u)
+#define DMA_SUSENR32 (0xF0011A80u)
+#define DMA_SUSENR33 (0xF0011A84u)
+#define DMA_SUSENR34 (0xF0011A88u)
+#define DMA_SUSENR35 (0xF0011A8Cu)
+#define DMA_SUSENR36 (0xF0011A90u)
+#define DMA_SUSENR37 (0xF0011A94u)
+#define DMA_SUSENR38 (0xF0011A98u)
+#define DMA_SUSENR39 (0xF0011A9Cu)
+#define DMA_SUSENR40 (0xF0011AA0u)
+#define DMA_SUSENR41 (0xF0011AA4u)
+#define DMA_ME0_CLRE (0xF0010128u)
+#define DMA_SUSENR42 (0xF0011AA8u)
+#define DMA_SUSENR43 (0xF0011AACu)
+#define DMA_SUSENR44 (0xF0011AB0u)
+#define DMA_SUSENR45 (0xF0011AB4u)
+#define DMA_SUSENR46 (0xF0011AB8u)
+#define DMA_SUSENR47 (0xF0011ABCu)
+#define DMA_SUSENR48 (0xF0011AC0u)
+#define DMA_SUSENR49 (0xF0011AC4u)
+#define DMA_SUSENR50 (0xF0011AC8u)
+#define DMA_SUSENR51 (0xF0011ACCu)
+#define DMA_ME0_SR (0xF0010130u)
+#define DMA_SUSENR52 (0xF0011AD0u)
+#define DMA_SUSENR53 (0xF0011AD4u)
+#define DMA_SUSENR54 (0xF0011AD8u)
+#define DMA_SUSENR55 (0xF0011ADCu)
+#define DMA_SUSENR56 (0xF0011AE0u)
+#define DMA_SUSENR57 (0xF0011AE4u)
+#define DMA_SUSENR58 (0xF0011AE8u)
+#define DMA_SUSENR59 (0xF0011AECu)
+#define DMA_SUSENR60 (0xF0011AF0u)
+#define DMA_SUSENR61 (0xF0011AF4u)
+#define DMA_ME0_R0 (0xF0010140u)
+#define DMA_SUSENR62 (0xF0011AF8u)
+#define DMA_SUSENR63 (0xF0011AFCu)
+#define DMA_SUSENR64 (0xF0011B00u)
+#define DMA_SUSENR65 (0xF0011B04u)
+#define DMA_SUSENR66 (0xF0011B08u)
+#define DMA_SUSENR67 (0xF0011B0Cu)
+#define DMA_SUSENR68 (0xF0011B10u)
+#define DMA_SUSENR69 (0xF0011B14u)
+#define DMA_SUSENR70 (0xF0011B18u)
+#define DMA_SUSENR71 (0xF0011B1Cu)
+#define DMA_ME0_R1 (0xF0010144u)
+#define DMA_SUSENR72 (0xF0011B20u)
+#define DMA_SUSENR73 (0xF0011B24u)
+#define DMA_SUSENR74 (0xF0011B28u)
+#define DMA_SUSENR75 (0xF0011B2Cu)
+#define DMA_SUSENR76 (0xF0011B30u)
+#define DMA_SUSENR77 (0xF0011B34u)
+#define DMA_SUSENR78 (0xF0011B38u)
+#define DMA_SUSENR79 (0xF0011B3Cu)
+#define DMA_SUSENR80 (0xF0011B40u)
+#define DMA_SUSENR81 (0xF0011B44u)
+#define DMA_ME0_R2 (0xF0010148u)
+#define DMA_SUSENR82 (0xF0011B48u)
+#define DMA_SUSENR83 (0xF0011B4Cu)
+#define DMA_SUSENR84 (0xF0011B50u)
+#define DMA_SUSENR85 (0xF0011B54u)
+#define DMA_SUSENR86 (0xF0011B58u)
+#define DMA_SUSENR87 (0xF0011B5Cu)
+#define DMA_SUSENR88 (0xF0011B60u)
+#define DMA_SUSENR89 (0xF0011B64u)
+#define DMA_SUSENR90 (0xF0011B68u)
+#define DMA_SUSENR91 (0xF0011B6Cu)
+#define DMA_ME0_R3 (0xF001014Cu)
+#define DMA_SUSENR92 (0xF0011B70u)
+#define DMA_SUSENR93 (0xF0011B74u)
+#define DMA_SUSENR94 (0xF0011B78u)
+#define DMA_SUSENR95 (0xF0011B7Cu)
+#define DMA_SUSENR96 (0xF0011B80u)
+#define DMA_SUSENR97 (0xF0011B84u)
+#define DMA_SUSENR98 (0xF0011B88u)
+#define DMA_SUSENR99 (0xF0011B8Cu)
+#define DMA_SUSENR100 (0xF0011B90u)
+#define DMA_SUSENR101 (0xF0011B94u)
+#define DMA_SUSENR102 (0xF0011B98u)
+#define DMA_SUSENR103 (0xF0011B9Cu)
+#define DMA_ME0_R4 (0xF0010150u)
+#define DMA_SUSENR104 (0xF0011BA0u)
+#define DMA_SUSENR105 (0xF0011BA4u)
+#define DMA_SUSENR106 (0xF0011BA8u)
+#define DMA_SUSENR107 (0xF0011BACu)
+#define DMA_SUSENR108 (0xF0011BB0u)
+#define DMA_SUSENR109 (0xF0011BB4u)
+#define DMA_SUSENR110 (0xF0011BB8u)
+#define DMA_SUSENR111 (0xF0011BBCu)
+#define DMA_SUSENR112 (0xF0011BC0u)
+#define DMA_SUSENR113 (0xF0011BC4u)
+#define DMA_SUSENR114 (0xF0011BC8u)
+#define DMA_SUSENR115 (0xF0011BCCu)
+#define DMA_SUSENR116 (0xF0011BD0u)
+#define DMA_SUSENR117 (0xF0011BD4u)
+#define DMA_SUSENR118 (0xF0011BD8u)
+#define DMA_SUSENR119 (0xF0011BDCu)
+#define DMA_SUSENR120 (0xF0011BE0u)
+#define DMA_SUSENR121 (0xF0011BE4u)
+#define DMA_SUSENR122 (0xF0011BE8u)
+#define DMA_SUSENR123 (0xF0011BECu)
+#define DMA_SUSENR124 (0xF0011BF0u)
+#define DMA_SUSENR125 (0xF0011BF4u)
+#define DMA_SUSENR126 (0xF0011BF8u)
+#define DMA_SUSENR127 (0xF0011BFCu)
+#define DMA_ME0_R5 (0xF0010154u)
+#define DMA_SUSACR0 (0xF0011C00u)
+#define DMA_SUSACR1 (0xF0011C04u)
+#define DMA_SUSACR2 (0xF0011C08u)
+#define DMA_SUSACR3 (0xF0011C0Cu)
+#define DMA_SUSACR4 (0xF0011C10u)
+#define DMA_SUSACR5 (0xF0011C14u)
+#define DMA_SUSACR6 (0xF0011C18u)
+#define DMA_SUSACR7 (0xF0011C1Cu)
+#define DMA_SUSACR8 (0xF0011C20u)
+#define DMA_SUSACR9 (0xF0011C24u)
+#define DMA_ME0_R6 (0xF0010158u)
+#define DMA_SUSACR10 (0xF0011C28u)
+#define DMA_SUSACR11 (0xF0011C2Cu)
+#define DMA_SUSACR12 (0xF0011C30u)
+#define DMA_SUSACR13 (0xF0011C34u)
+#define DMA_SUSACR14 (0xF0011C38u)
+#define DMA_SUSACR15 (0xF0011C3Cu)
+#define DMA_SUSACR16 (0xF0011C40u)
+#define DMA_SUSACR17 (0xF0011C44u)
+#define DMA_SUSACR18 (0xF0011C48u)
+#define DMA_SUSACR19 (0xF0011C4Cu)
+#define DMA_ME0_R7 (0xF001015Cu)
+#define DMA_SUSACR20 (0xF0011C50u)
+#define DMA_SUSACR21 (0xF0011C54u)
+#define DMA_SUSACR22 (0xF0011C58u)
+#define DMA_SUSACR23 (0xF0011C5Cu)
+#define DMA_SUSACR24 (0xF0011C60u)
+#define DMA_SUSACR25 (0xF0011C64u)
+#define DMA_SUSACR26 (0xF0011C68u)
+#define DMA_SUSACR27 (0xF0011C6Cu)
+#define DMA_SUSACR28 (0xF0011C70u)
+#define DMA_SUSACR29 (0xF0011C74u)
+#define DMA_ME0_RDCRC (0xF0010180u)
+#define DMA_SUSACR30 (0xF0011C78u)
+#define DMA_SUSACR31 (0xF0011C7Cu)
+#define DMA_SUSACR32 (0xF0011C80u)
+#define DMA_SUSACR33 (0xF0011C84u)
+#define DMA_SUSACR34 (0xF0011C88u)
+#define DMA_SUSACR35 (0xF0011C8Cu)
+#define DMA_SUSACR36 (0xF0011C90u)
+#define DMA_SUSACR37 (0xF0011C94u)
+#define DMA_SUSACR38 (0xF0011C98u)
+#define DMA_SUSACR39 (0xF0011C9Cu)
+#define DMA_ME0_SDCRC (0xF0010184u)
+#define DMA_SUSACR40 (0xF0011CA0u)
+#define DMA_SUSACR41 (0xF0011CA4u)
+#define DMA_SUSACR42 (0xF0011CA8u)
+#define DMA_SUSACR43 (0xF0011CACu)
+#define DMA_SUSACR44 (0xF0011CB0u)
+#define DMA_SUSACR45 (0xF0011CB4u)
+#define DMA_SUSACR46 (0xF0011CB8u)
+#define DMA_SUSACR47 (0xF0011CBCu)
+#define DMA_SUSACR48 (0xF0011CC0u)
+#define DMA_SUSACR49 (0xF0011CC4u)
+#define DMA_ME0_SADR (0xF0010188u)
+#define DMA_SUSACR50 (0xF0011CC8u)
+#define DMA_SUSACR51 (0xF0011CCCu)
+#define DMA_SUSACR52 (0xF0011CD0u)
+#define DMA_SUSACR53 (0xF0011CD4u)
+#define DMA_SUSACR54 (0xF0011CD8u)
+#define DMA_SUSACR55 (0xF0011CDCu)
+#define DMA_SUSACR56 (0xF0011CE0u)
+#define DMA_SUSACR57 (0xF0011CE4u)
+#define DMA_SUSACR58 (0xF0011CE8u)
+#define DMA_SUSACR59 (0xF0011CECu)
+#define DMA_ME0_DADR (0xF001018Cu)
+#define DMA_SUSACR60 (0xF0011CF0u)
+#define DMA_SUSACR61 (0xF0011CF4u)
+#define DMA_SUSACR62 (0xF0011CF8u)
+#define DMA_SUSACR63 (0xF0011CFCu)
+#define DMA_SUSACR64 (0xF0011D00u)
+#define DMA_SUSACR65 (0xF0011D04u)
+#define DMA_SUSACR66 (0xF0011D08u)
+#define DMA_SUSACR67 (0xF0011D0Cu)
+#define DMA_SUSACR68 (0xF0011D10u)
+#define DMA_SUSACR69 (0xF0011D14u)
+#define DMA_ME0_ADICR (0xF0010190u)
+#define DMA_SUSACR70 (0xF0011D18u)
+#define DMA_SUSACR71 (0xF0011D1Cu)
+#define DMA_SUSACR72 (0xF0011D20u)
+#define DMA_SUSACR73 (0xF0011D24u)
+#define DMA_SUSACR74 (0xF0011D28u)
+#define DMA_SUSACR75 (0xF0011D2Cu)
+#define DMA_SUSACR76 (0xF0011D30u)
+#define DMA_SUSACR77 (0xF0011D34u)
+#define DMA_SUSACR78 (0xF0011D38u)
+#define DMA_SUSACR79 (0xF0011D3Cu)
+#define DMA_ME0_CHCR (0xF0010194u)
+#define DMA_SUSACR80 (0xF0011D40u)
+#define DMA_SUSACR81 (0xF0011D44u)
+#define DMA_SUSACR82 (0xF0011D48u)
+#define DMA_SUSACR83 (0xF0011D4Cu)
+#define DMA_SUSACR84 (0xF0011D50u)
+#define DMA_SUSACR85 (0xF0011D54u)
+#define DMA_SUSACR86 (0xF0011D58u)
+#define DMA_SUSACR87 (0xF0011D5Cu)
+#define DMA_SUSACR88 (0xF0011D60u)
+#define DMA_SUSACR89 (0xF0011D64u)
+#define DMA_ME0_SHADR (0xF0010198u)
+#define DMA_SUSACR90 (0xF0011D68u)
+#define DMA_SUSACR91 (0xF0011D6Cu)
+#define DMA_SUSACR92 (0xF0011D70u)
+#define DMA_SUSACR93 (0xF0011D74u)
+#define DMA_SUSACR94 (0xF0011D78u)
+#define DMA_SUSACR95 (0xF0011D7Cu)
+#define DMA_SUSACR96 (0xF0011D80u)
+#define DMA_SUSACR97 (0xF0011D84u)
+#define DMA_SUSACR98 (0xF0011D88u)
+#define DMA_SUSACR99 (0xF0011D8Cu)
+#define DMA_ME0_CHSR (0xF001019Cu)
+#define DMA_SUSACR100 (0xF0011D90u)
+#define DMA_SUSACR101 (0xF0011D94u)
+#define DMA_SUSACR102 (0xF0011D98u)
+#define DMA_SUSACR103 (0xF0011D9Cu)
+#define DMA_SUSACR104 (0xF0011DA0u)
+#define DMA_SUSACR105 (0xF0011DA4u)
+#define DMA_SUSACR106 (0xF0011DA8u)
+#define DMA_SUSACR107 (0xF0011DACu)
+#define DMA_SUSACR108 (0xF0011DB0u)
+#define DMA_SUSACR109 (0xF0011DB4u)
+#define DMA_SUSACR110 (0xF0011DB8u)
+#define DMA_SUSACR111 (0xF0011DBCu)
+#define DMA_SUSACR112 (0xF0011DC0u)
+#define DMA_SUSACR113 (0xF0011DC4u)
+#define DMA_SUSACR114 (0xF0011DC8u)
+#define DMA_SUSACR115 (0xF0011DCCu)
+#define DMA_SUSACR116 (0xF0011DD0u)
+#define DMA_SUSACR117 (0xF0011DD4u)
+#define DMA_SUSACR118 (0xF0011DD8u)
+#define DMA_SUSACR119 (0xF0011DDCu)
+#define DMA_SUSACR120 (0xF0011DE0u)
+#define DMA_SUSACR121 (0xF0011DE4u)
+#define DMA_SUSACR122 (0xF0011DE8u)
+#define DMA_ME1_EER (0xF0011120u)
+#define DMA_SUSACR123 (0xF0011DECu)
+#define DMA_SUSACR124 (0xF0011DF0u)
+#define DMA_SUSACR125 (0xF0011DF4u)
+#define DMA_SUSACR126 (0xF0011DF8u)
+#define DMA_SUSACR127 (0xF0011DFCu)
+#define DMA_TSR0 (0xF0011E00u)
+#define DMA_TSR1 (0xF0011E04u)
+#define DMA_TSR2 (0xF0011E08u)
+#define DMA_TSR3 (0xF0011E0Cu)
+#define DMA_TSR4 (0xF0011E10u)
+#define DMA_TSR5 (0xF0011E14u)
+#define DMA_TSR6 (0xF0011E18u)
+#define DMA_TSR7 (0xF0011E1Cu)
+#define DMA_ME1_ERRSR (0xF0011124u)
+#define DMA_TSR8 (0xF0011E20u)
+#define DMA_TSR9 (0xF0011E24u)
+#define DMA_TSR10 (0xF0011E28u)
+#define DMA_TSR11 (0xF0011E2Cu)
+#define DMA_TSR12 (0xF0011E30u)
+#define DMA_TSR13 (0xF0011E34u)
+#define DMA_TSR14 (0xF0011E38u)
+#define DMA_TSR15 (0xF0011E3Cu)
+#define DMA_TSR16 (0xF0011E40u)
+#define DMA_TSR17 (0xF0011E44u)
+#define DMA_ME1_CLRE (0xF0011128u)
+#define DMA_TSR18 (0xF0011E48u)
+#define DMA_TSR19 (0xF0011E4Cu)
+#define DMA_TSR20 (0xF0011E50u)
+#define DMA_TSR21 (0xF0011E54u)
+#define DMA_TSR22 (0xF0011E58u)
+#define DMA_TSR23 (0xF0011E5Cu)
+#define DMA_TSR24 (0xF0011E60u)
+#define DMA_TSR25 (0xF0011E64u)
+#define DMA_TSR26 (0xF0011E68u)
+#define DMA_TSR27 (0xF0011E6Cu)
+#define DMA_ME1_SR (0xF0011130u)
+#define DMA_TSR28 (0xF0011E70u)
+#define DMA_TSR29 (0xF0011E74u)
+#define DMA_TSR30 (0xF0011E78u)
+#define DMA_TSR31 (0xF0011E7Cu)
+#define DMA_TSR32 (0xF0011E80u)
+#define DMA_TSR33 (0xF0011E84u)
+#define DMA_TSR34 (0xF0011E88u)
+#define DMA_TSR35 (0xF0011E8Cu)
+#define DMA_TSR36 (0xF0011E90u)
+#define DMA_TSR37 (0xF0011E94u)
+#define DMA_ME1_R0 (0xF0011140u)
+#define DMA_TSR38 (0xF0011E98u)
+#define DMA_TSR39 (0xF0011E9Cu)
+#define DMA_TSR40 (0xF0011EA0u)
+#define DMA_TSR41 (0xF0011EA4u)
+#define DMA_TSR42 (0xF0011EA8u)
+#define DMA_TSR43 (0xF0011EACu)
+#define DMA_TSR44 (0xF0011EB0u)
+#define DMA_TSR45 (0xF0011EB4u)
+#define DMA_TSR46 (0xF0011EB8u)
+#define DMA_TSR47 (0xF0011EBCu)
+#define DMA_ME1_R1 (0xF0011144u)
+#define DMA_TSR48 (0xF0011EC0u)
+#define DMA_TSR49 (0xF0011EC4u)
+#define DMA_TSR50 (0xF0011EC8u)
+#define DMA_TSR51 (0xF0011ECCu)
+#define DMA_TSR52 (0xF0011ED0u)
+#define DMA_TSR53 (0xF0011ED4u)
+#define DMA_TSR54 (0xF0011ED8u)
+#define DMA_TSR55 (0xF0011EDCu)
+#define DMA_TSR56 (0xF0011EE0u)
+#define DMA_TSR57 (0xF0011EE4u)
+#define DMA_ME1_R2 (0xF0011148u)
+#define DMA_TSR58 (0xF0011EE8u)
+#define DMA_TSR59 (0xF0011EECu)
+#define DMA_TSR60 (0xF0011EF0u)
+#define DMA_TSR61 (0xF0011EF4u)
+#define DMA_TSR62 (0xF0011EF8u)
+#define DMA_TSR63 (0xF0011EFCu)
+#define DMA_TSR64 (0xF0011F00u)
+#define DMA_TSR65 (0xF0011F04u)
+#define DMA_TSR66 (0xF0011F08u)
+#define DMA_TSR67 (0xF0011F0Cu)
+#define DMA_ME1_R3 (0xF001114Cu)
+#define DMA_TSR68 (0xF0011F10u)
+#define DMA_TSR69 (0xF0011F14u)
+#define DMA_TSR70 (0xF0011F18u)
+#define DMA_TSR71 (0xF0011F1Cu)
+#define DMA_TSR72 (0xF0011F20u)
+#define DMA_TSR73 (0xF0011F24u)
+#define DMA_TSR74 (0xF0011F28u)
+#define DMA_TSR75 (0xF0011F2Cu)
+#define DMA_TSR76 (0xF0011F30u)
+#define DMA_TSR77 (0xF0011F34u)
+#define DMA_ME1_R4 (0xF0011150u)
+#define DMA_TSR78 (0xF0011F38u)
+#define DMA_TSR79 (0xF0011F3Cu)
+#define DMA_TSR80 (0xF0011F40u)
+#define DMA_TSR81 (0xF0011F44u)
+#define DMA_TSR82 (0xF0011F48u)
+#define DMA_TSR83 (0xF0011F4Cu)
+#define DMA_TSR84 (0xF0011F50u)
+#define DMA_TSR85 (0xF0011F54u)
+#define DMA_TSR86 (0xF0011F58u)
+#define DMA_TSR87 (0xF0011F5Cu)
+#define DMA_ME1_R5 (0xF0011154u)
+#define DMA_TSR88 (0xF0011F60u)
+#define DMA_TSR89 (0xF0011F64u)
+#define DMA_TSR90 (0xF0011F68u)
+#define DMA_TSR91 (0xF0011F6Cu)
+#define DMA_TSR92 (0xF0011F70u)
+#define DMA_TSR93 (0xF0011F74u)
+#define DMA_TSR94 (0xF0011F78u)
+#define DMA_TSR95 (0xF0011F7Cu)
+#define DMA_TSR96 (0xF0011F80u)
+#define DMA_TSR97 (0xF0011F84u)
+#define DMA_ME1_R6 (0xF0011158u)
+#define DMA_TSR98 (0xF0011F88u)
+#define DMA_TSR99 (0xF0011F8Cu)
+#define DMA_TSR100 (0xF0011F90u)
+#define DMA_TSR101 (0xF0011F94u)
+#define DMA_TSR102 (0xF0011F98u)
+#define DMA_TSR103 (0xF0011F9Cu)
+#define DMA_TSR104 (0xF0011FA0u)
+#define DMA_TSR105 (0xF0011FA4u)
+#define DMA_TSR106 (0xF0011FA8u)
+#define DMA_TSR107 (0xF0011FACu)
+#define DMA_TSR108 (0xF0011FB0u)
+#define DMA_TSR109 (0xF0011FB4u)
+#define DMA_TSR110 (0xF0011FB8u)
+#define DMA_TSR111 (0xF0011FBCu)
+#define DMA_TSR112 (0xF0011FC0u)
+#define DMA_TSR113 (0xF0011FC4u)
+#define DMA_TSR114 (0xF0011FC8u)
+#define DMA_TSR115 (0xF0011FCCu)
+#define DMA_TSR116 (0xF0011FD0u)
+#define DMA_TSR117 (0xF0011FD4u)
+#define DMA_ME1_R7 (0xF001115Cu)
+#define DMA_TSR118 (0xF0011FD8u)
+#define DMA_TSR119 (0xF0011FDCu)
+#define DMA_TSR120 (0xF0011FE0u)
+#define DMA_TSR121 (0xF0011FE4u)
+#define DMA_TSR122 (0xF0011FE8u)
+#define DMA_TSR123 (0xF0011FECu)
+#define DMA_TSR124 (0xF0011FF0u)
+#define DMA_TSR125 (0xF0011FF4u)
+#define DMA_TSR126 (0xF0011FF8u)
+#define DMA_TSR127 (0xF0011FFCu)
+#define DMA_CH0_RDCRCR (0xF0012000u)
+#define DMA_CH0_SDCRCR (0xF0012004u)
+#define DMA_CH0_SADR (0xF0012008u)
+#define DMA_CH0_DADR (0xF001200Cu)
+#define DMA_CH0_ADICR (0xF0012010u)
+#define DMA_CH0_CHCFGR (0xF0012014u)
+#define DMA_ME1_RDCRC (0xF0011180u)
+#define DMA_CH0_SHADR (0xF0012018u)
+#define DMA_CH0_CHCSR (0xF001201Cu)
+#define DMA_CH1_RDCRCR (0xF0012020u)
+#define DMA_CH1_SDCRCR (0xF0012024u)
+#define DMA_CH1_SADR (0xF0012028u)
+#define DMA_CH1_DADR (0xF001202Cu)
+#define DMA_CH1_ADICR (0xF0012030u)
+#define DMA_CH1_CHCFGR (0xF0012034u)
+#define DMA_CH1_SHADR (0xF0012038u)
+#define DMA_CH1_CHCSR (0xF001203Cu)
+#define DMA_ME1_SDCRC (0xF0011184u)
+#define DMA_CH2_RDCRCR (0xF0012040u)
+#define DMA_CH2_SDCRCR (0xF0012044u)
+#define DMA_CH2_SADR (0xF0012048u)
+#define DMA_CH2_DADR (0xF001204Cu)
+#define DMA_CH2_ADICR (0xF0012050u)
+#define DMA_CH2_CHCFGR (0xF0012054u)
+#define DMA_CH2_SHADR (0xF0012058u)
+#define DMA_CH2_CHCSR (0xF001205Cu)
+#define DMA_CH3_RDCRCR (0xF0012060u)
+#define DMA_CH3_SDCRCR (0xF0012064u)
+#define DMA_ME1_SADR (0xF0011188u)
+#define DMA_CH3_SADR (0xF0012068u)
+#define DMA_CH3_DADR (0xF001206Cu)
+#define DMA_CH3_ADICR (0xF0012070u)
+#define DMA_CH3_CHCFGR (0xF0012074u)
+#define DMA_CH3_SHADR (0xF0012078u)
+#define DMA_CH3_CHCSR (0xF001207Cu)
+#define DMA_CH4_RDCRCR (0xF0012080u)
+#define DMA_CH4_SDCRCR (0xF0012084u)
+#define DMA_CH4_SADR (0xF0012088u)
+#define DMA_CH4_DADR (0xF001208Cu)
+#define DMA_ME1_DADR (0xF001118Cu)
+#define DMA_CH4_ADICR (0xF0012090u)
+#define DMA_CH4_CHCFGR (0xF0012094u)
+#define DMA_CH4_SHADR (0xF0012098u)
+#define DMA_CH4_CHCSR (0xF001209Cu)
+#define DMA_CH5_RDCRCR (0xF00120A0u)
+#define DMA_CH5_SDCRCR (0xF00120A4u)
+#define DMA_CH5_SADR (0xF00120A8u)
+#define DMA_CH5_DADR (0xF00120ACu)
+#define DMA_CH5_ADICR (0xF00120B0u)
+#define DMA_CH5_CHCFGR (0xF00120B4u)
+#define DMA_ME1_ADICR (0xF0011190u)
+#define DMA_CH5_SHADR (0xF00120B8u)
+#define DMA_CH5_CHCSR (0xF00120BCu)
+#define DMA_CH6_RDCRCR (0xF00120C0u)
+#define DMA_CH6_SDCRCR (0xF00120C4u)
+#define DMA_CH6_SADR (0xF00120C8u)
+#define DMA_CH6_DADR (0xF00120CCu)
+#define DMA_CH6_ADICR (0xF00120D0u)
+#define DMA_CH6_CHCFGR (0xF00120D4u)
+#define DMA_CH6_SHADR (0xF00120D8u)
+#define DMA_CH6_CHCSR (0xF00120DCu)
+#define DMA_ME1_CHCR (0xF0011194u)
+#define DMA_CH7_RDCRCR (0xF00120E0u)
+#define DMA_CH7_SDCRCR (0xF00120E4u)
+#define DMA_CH7_SADR (0xF00120E8u)
+#define DMA_CH7_DADR (0xF00120ECu)
+#define DMA_CH7_ADICR (0xF00120F0u)
+#define DMA_CH7_CHCFGR (0xF00120F4u)
+#define DMA_CH7_SHADR (0xF00120F8u)
+#define DMA_CH7_CHCSR (0xF00120FCu)
+#define DMA_CH8_RDCRCR (0xF0012100u)
+#define DMA_CH8_SDCRCR (0xF0012104u)
+#define DMA_ME1_SHADR (0xF0011198u)
+#define DMA_CH8_SADR (0xF0012108u)
+#define DMA_CH8_DADR (0xF001210Cu)
+#define DMA_CH8_ADICR (0xF0012110u)
+#define DMA_CH8_CHCFGR (0xF0012114u)
+#define DMA_CH8_SHADR (0xF0012118u)
+#define DMA_CH8_CHCSR (0xF001211Cu)
+#define DMA_CH9_RDCRCR (0xF0012120u)
+#define DMA_CH9_SDCRCR (0xF0012124u)
+#define DMA_CH9_SADR (0xF0012128u)
+#define DMA_CH9_DADR (0xF001212Cu)
+#define DMA_ME1_CHSR (0xF001119Cu)
+#define DMA_CH9_ADICR (0xF0012130u)
+#define DMA_CH9_CHCFGR (0xF0012134u)
+#define DMA_CH9_SHADR (0xF0012138u)
+#define DMA_CH9_CHCSR (0xF001213Cu)
+#define DMA_CH10_RDCRCR (0xF0012140u)
+#define DMA_CH10_SDCRCR (0xF0012144u)
+#define DMA_CH10_SADR (0xF0012148u)
+#define DMA_CH10_DADR (0xF001214Cu)
+#define DMA_CH10_ADICR (0xF0012150u)
+#define DMA_CH10_CHCFGR (0xF0012154u)
+#define DMA_OTSS (0xF0011200u)
+#define DMA_CH10_SHADR (0xF0012158u)
+#define DMA_CH10_CHCSR (0xF001215Cu)
+#define DMA_CH11_RDCRCR (0xF0012160u)
+#define DMA_CH11_SDCRCR (0xF0012164u)
+#define DMA_PRR0 (0xF0011208u)
+#define DMA_CH11_SADR (0xF0012168u)
+#define DMA_CH11_DADR (0xF001216Cu)
+#define DMA_CH11_ADICR (0xF0012170u)
+#define DMA_CH11_CHCFGR (0xF0012174u)
+#define DMA_CH11_SHADR (0xF0012178u)
+#define DMA_PRR1 (0xF001120Cu)
+#define DMA_CH11_CHCSR (0xF001217Cu)
+#define DMA_CH12_RDCRCR (0xF0012180u)
+#define DMA_CH12_SDCRCR (0xF0012184u)
+#define DMA_CH12_SADR (0xF0012188u)
+#define DMA_TIME (0xF0011210u)
+#define DMA_CH12_DADR (0xF001218Cu)
+#define DMA_CH12_ADICR (0xF0012190u)
+#define DMA_CH12_CHCFGR (0xF0012194u)
+#define DMA_CH12_SHADR (0xF0012198u)
+#define DMA_MODE0 (0xF0011300u)
+#define DMA_CH12_CHCSR (0xF001219Cu)
+#define DMA_CH13_RDCRCR (0xF00121A0u)
+#define DMA_CH13_SDCRCR (0xF00121A4u)
+#define DMA_CH13_SADR (0xF00121A8u)
+#define DMA_CH13_DADR (0xF00121ACu)
+#define DMA_MODE1 (0xF0011304u)
+#define DMA_CH13_ADICR (0xF00121B0u)
+#define DMA_CH13_CHCFGR (0xF00121B4u)
+#define DMA_CH13_SHADR (0xF00121B8u)
+#define DMA_CH13_CHCSR (0xF00121BCu)
+#define DMA_MODE2 (0xF0011308u)
+#define DMA_CH14_RDCRCR (0xF00121C0u)
+#define DMA_CH14_SDCRCR (0xF00121C4u)
+#define DMA_CH14_SADR (0xF00121C8u)
+#define DMA_CH14_DADR (0xF00121CCu)
+#define DMA_MODE3 (0xF001130Cu)
+#define DMA_CH14_ADICR (0xF00121D0u)
+#define DMA_CH14_CHCFGR (0xF00121D4u)
+#define DMA_CH14_SHADR (0xF00121D8u)
+#define DMA_CH14_CHCSR (0xF00121DCu)
+#define DMA_ERRINTR0 (0xF0011320u)
+#define DMA_CH15_RDCRCR (0xF00121E0u)
+#define DMA_CH15_SDCRCR (0xF00121E4u)
+#define DMA_CH15_SADR (0xF00121E8u)
+#define DMA_CH15_DADR (0xF00121ECu)
+#define DMA_CH15_ADICR (0xF00121F0u)
+#define DMA_ERRINTR1 (0xF0011324u)
+#define DMA_CH15_CHCFGR (0xF00121F4u)
+#define DMA_CH15_SHADR (0xF00121F8u)
+#define DMA_CH15_CHCSR (0xF00121FCu)
+#define DMA_CH16_RDCRCR (0xF0012200u)
+#define DMA_ERRINTR2 (0xF0011328u)
+#define DMA_CH16_SDCRCR (0xF0012204u)
+#define DMA_CH16_SADR (0xF0012208u)
+#define DMA_CH16_DADR (0xF001220Cu)
+#define DMA_CH16_ADICR (0xF0012210u)
+#define DMA_ERRINTR3 (0xF001132Cu)
+#define DMA_CH16_CHCFGR (0xF0012214u)
+#define DMA_CH16_SHADR (0xF0012218u)
+#define DMA_CH16_CHCSR (0xF001221Cu)
+#define DMA_CH17_RDCRCR (0xF0012220u)
+#define DMA_CH17_SDCRCR (0xF0012224u)
+#define DMA_HRR0 (0xF0011800u)
+#define DMA_CH17_SADR (0xF0012228u)
+#define DMA_CH17_DADR (0xF001222Cu)
+#define DMA_CH17_ADICR (0xF0012230u)
+#define DMA_CH17_CHCFGR (0xF0012234u)
+#define DMA_CH17_SHADR (0xF0012238u)
+#define DMA_CH17_CHCSR (0xF001223Cu)
+#define DMA_CH18_RDCRCR (0xF0012240u)
+#define DMA_CH18_SDCRCR (0xF0012244u)
+#define DMA_CH18_SADR (0xF0012248u)
+#define DMA_CH18_DADR (0xF001224Cu)
+#define DMA_HRR1 (0xF0011804u)
+#define DMA_CH18_ADICR (0xF0012250u)
+#define DMA_CH18_CHCFGR (0xF0012254u)
+#define DMA_CH18_SHADR (0xF0012258u)
+#define DMA_CH18_CHCSR (0xF001225Cu)
+#define DMA_CH19_RDCRCR (0xF0012260u)
+#define DMA_CH19_SDCRCR (0xF0012264u)
+#define DMA_CH19_SADR (0xF0012268u)
+#define DMA_CH19_DADR (0xF001226Cu)
+#define DMA_CH19_ADICR (0xF0012270u)
+#define DMA_CH19_CHCFGR (0xF0012274u)
+#define DMA_HRR2 (0xF0011808u)
+#define DMA_CH19_SHADR (0xF0012278u)
+#define DMA_CH19_CHCSR (0xF001227Cu)
+#define DMA_CH20_RDCRCR (0xF0012280u)
+#define DMA_CH20_SDCRCR (0xF0012284u)
+#define DMA_CH20_SADR (0xF0012288u)
+#define DMA_CH20_DADR (0xF001228Cu)
+#define DMA_CH20_ADICR (0xF0012290u)
+#define DMA_CH20_CHCFGR (0xF0012294u)
+#define DMA_CH20_SHADR (0xF0012298u)
+#define DMA_CH20_CHCSR (0xF001229Cu)
+#define DMA_HRR3 (0xF001180Cu)
+#define DMA_CH21_RDCRCR (0xF00122A0u)
+#define DMA_CH21_SDCRCR (0xF00122A4u)
+#define DMA_CH21_SADR (0xF00122A8u)
+#define DMA_CH21_DADR (0xF00122ACu)
+#define DMA_CH21_ADICR (0xF00122B0u)
+#define DMA_CH21_CHCFGR (0xF00122B4u)
+#define DMA_CH21_SHADR (0xF00122B8u)
+#define DMA_CH21_CHCSR (0xF00122BCu)
+#define DMA_CH22_RDCRCR (0xF00122C0u)
+#define DMA_CH22_SDCRCR (0xF00122C4u)
+#define DMA_HRR4 (0xF0011810u)
+#define DMA_CH22_SADR (0xF00122C8u)
+#define DMA_CH22_DADR (0xF00122CCu)
+#define DMA_CH22_ADICR (0xF00122D0u)
+#define DMA_CH22_CHCFGR (0xF00122D4u)
+#define DMA_CH22_SHADR (0xF00122D8u)
+#define DMA_CH22_CHCSR (0xF00122DCu)
+#define DMA_CH23_RDCRCR (0xF00122E0u)
+#define DMA_CH23_SDCRCR (0xF00122E4u)
+#define DMA_CH23_SADR (0xF00122E8u)
+#define DMA_CH23_DADR (0xF00122ECu)
+#define DMA_HRR5 (0xF0011814u)
+#define DMA_CH23_ADICR (0xF00122F0u)
+#define DMA_CH23_CHCFGR (0xF00122F4u)
+#define DMA_CH23_SHADR (0xF00122F8u)
+#define DMA_CH23_CHCSR (0xF00122FCu)
+#define DMA_CH24_RDCRCR (0xF0012300u)
+#define DMA_CH24_SDCRCR (0xF0012304u)
+#define DMA_CH24_SADR (0xF0012308u)
+#define DMA_CH24_DADR (0xF001230Cu)
+#define DMA_CH24_ADICR (0xF0012310u)
+#define DMA_CH24_CHCFGR (0xF0012314u)
+#define DMA_HRR6 (0xF0011818u)
+#define DMA_CH24_SHADR (0xF0012318u)
+#define DMA_CH24_CHCSR (0xF001231Cu)
+#define DMA_CH25_RDCRCR (0xF0012320u)
+#define DMA_CH25_SDCRCR (0xF0012324u)
+#define DMA_CH25_SADR (0xF0012328u)
+#define DMA_CH25_DADR (0xF001232Cu)
+#define DMA_CH25_ADICR (0xF0012330u)
+#define DMA_CH25_CHCFGR (0xF0012334u)
+#define DMA_CH25_SHADR (0xF0012338u)
+#define DMA_CH25_CHCSR (0xF001233Cu)
+#define DMA_HRR7 (0xF001181Cu)
+#define DMA_CH26_RDCRCR (0xF0012340u)
+#define DMA_CH26_SDCRCR (0xF0012344u)
+#define DMA_CH26_SADR (0xF0012348u)
+#define DMA_CH26_DADR (0xF001234Cu)
+#define DMA_CH26_ADICR (0xF0012350u)
+#define DMA_CH26_CHCFGR (0xF0012354u)
+#define DMA_CH26_SHADR (0xF0012358u)
+#define DMA_CH26_CHCSR (0xF001235Cu)
+#define DMA_CH27_RDCRCR (0xF0012360u)
+#define DMA_CH27_SDCRCR (0xF0012364u)
+#define DMA_HRR8 (0xF0011820u)
+#define DMA_CH27_SADR (0xF0012368u)
+#define DMA_CH27_DADR (0xF001236Cu)
+#define DMA_CH27_ADICR (0xF0012370u)
+#define DMA_CH27_CHCFGR (0xF0012374u)
+#define DMA_CH27_SHADR (0xF0012378u)
+#define DMA_CH27_CHCSR (0xF001237Cu)
+#define DMA_CH28_RDCRCR (0xF0012380u)
+#define DMA_CH28_SDCRCR (0xF0012384u)
+#define DMA_CH28_SADR (0xF0012388u)
+#define DMA_CH28_DADR (0xF001238Cu)
+#define DMA_HRR9 (0xF0011824u)
+#define DMA_CH28_ADICR (0xF0012390u)
+#define DMA_CH28_CHCFGR (0xF0012394u)
+#define DMA_CH28_SHADR (0xF0012398u)
+#define DMA_CH28_CHCSR (0xF001239Cu)
+#define DMA_CH29_RDCRCR (0xF00123A0u)
+#define DMA_CH29_SDCRCR (0xF00123A4u)
+#define DMA_CH29_SADR (0xF00123A8u)
+#define DMA_CH29_DADR (0xF00123ACu)
+#define DMA_CH29_ADICR (0xF00123B0u)
+#define DMA_CH29_CHCFGR (0xF00123B4u)
+#define DMA_HRR10 (0xF0011828u)
+#define DMA_CH29_SHADR (0xF00123B8u)
+#define DMA_CH29_CHCSR (0xF00123BCu)
+#define DMA_CH30_RDCRCR (0xF00123C0u)
+#define DMA_CH30_SDCRCR (0xF00123C4u)
+#define DMA_CH30_SADR (0xF00123C8u)
+#define DMA_CH30_DADR (0xF00123CCu)
+#define DMA_CH30_ADICR (0xF00123D0u)
+#define DMA_CH30_CHCFGR (0xF00123D4u)
+#define DMA_CH30_SHADR (0xF00123D8u)
+#define DMA_CH30_CHCSR (0xF00123DCu)
+#define DMA_HRR11 (0xF001182Cu)
+#define DMA_CH31_RDCRCR (0xF00123E0u)
+#define DMA_CH31_SDCRCR (0xF00123E4u)
+#define DMA_CH31_SADR (0xF00123E8u)
+#define DMA_CH31_DADR (0xF00123ECu)
+#define DMA_CH31_ADICR (0xF00123F0u)
+#define DMA_CH31_CHCFGR (0xF00123F4u)
+#define DMA_CH31_SHADR (0xF00123F8u)
+#define DMA_CH31_CHCSR (0xF00123FCu)
+#define DMA_CH32_RDCRCR (0xF0012400u)
+#define DMA_CH32_SDCRCR (0xF0012404u)
+#define DMA_HRR12 (0xF0011830u)
+#define DMA_CH32_SADR (0xF0012408u)
+#define DMA_CH32_DADR (0xF001240Cu)
+#define DMA_CH32_ADICR (0xF0012410u)
+#define DMA_CH32_CHCFGR (0xF0012414u)
+#define DMA_CH32_SHADR (0xF0012418u)
+#define DMA_CH32_CHCSR (0xF001241Cu)
+#define DMA_CH33_RDCRCR (0xF0012420u)
+#define DMA_CH33_SDCRCR (0xF0012424u)
+#define DMA_CH33_SADR (0xF0012428u)
+#define DMA_CH33_DADR (0xF001242Cu)
+#define DMA_HRR13 (0xF0011834u)
+#define DMA_CH33_ADICR (0xF0012430u)
+#define DMA_CH33_CHCFGR (0xF0012434u)
+#define DMA_CH33_SHADR (0xF0012438u)
+#define DMA_CH33_CHCSR (0xF001243Cu)
+#define DMA_CH34_RDCRCR (0xF0012440u)
+#define DMA_CH34_SDCRCR (0xF0012444u)
+#define DMA_CH34_SADR (0xF0012448u)
+#define DMA_CH34_DADR (0xF001244Cu)
+#define DMA_CH34_ADICR (0xF0012450u)
+#define DMA_CH34_CHCFGR (0xF0012454u)
+#define DMA_HRR14 (0xF0011838u)
+#define DMA_CH34_SHADR (0xF0012458u)
+#define DMA_CH34_CHCSR (0xF001245Cu)
+#define DMA_CH35_RDCRCR (0xF0012460u)
+#define DMA_CH35_SDCRCR (0xF0012464u)
+#define DMA_CH35_SADR (0xF0012468u)
+#define DMA_CH35_DADR (0xF001246Cu)
+#define DMA_CH35_ADICR (0xF0012470u)
+#define DMA_CH35_CHCFGR (0xF0012474u)
+#define DMA_CH35_SHADR (0xF0012478u)
+#define DMA_CH35_CHCSR (0xF001247Cu)
+#define DMA_HRR15 (0xF001183Cu)
+#define DMA_CH36_RDCRCR (0xF0012480u)
+#define DMA_CH36_SDCRCR (0xF0012484u)
+#define DMA_CH36_SADR (0xF0012488u)
+#define DMA_CH36_DADR (0xF001248Cu)
+#define DMA_CH36_ADICR (0xF0012490u)
+#define DMA_CH36_CHCFGR (0xF0012494u)
+#define DMA_CH36_SHADR (0xF0012498u)
+#define DMA_CH36_CHCSR (0xF001249Cu)
+#define DMA_CH37_RDCRCR (0xF00124A0u)
+#define DMA_CH37_SDCRCR (0xF00124A4u)
+#define DMA_HRR16 (0xF0011840u)
+#define DMA_CH37_SADR (0xF00124A8u)
+#define DMA_CH37_DADR (0xF00124ACu)
+#define DMA_CH37_ADICR (0xF00124B0u)
+#define DMA_CH37_CHCFGR (0xF00124B4u)
+#define DMA_CH37_SHADR (0xF00124B8u)
+#define DMA_CH37_CHCSR (0xF00124BCu)
+#define DMA_CH38_RDCRCR (0xF00124C0u)
+#define DMA_CH38_SDCRCR (0xF00124C4u)
+#define DMA_CH38_SADR (0xF00124C8u)
+#define DMA_CH38_DADR (0xF00124CCu)
+#define DMA_HRR17 (0xF0011844u)
+#define DMA_CH38_ADICR (0xF00124D0u)
+#define DMA_CH38_CHCFGR (0xF00124D4u)
+#define DMA_CH38_SHADR (0xF00124D8u)
+#define DMA_CH38_CHCSR (0xF00124DCu)
+#define DMA_CH39_RDCRCR (0xF00124E0u)
+#define DMA_CH39_SDCRCR (0xF00124E4u)
+#define DMA_CH39_SADR (0xF00124E8u)
+#define DMA_CH39_DADR (0xF00124ECu)
+#define DMA_CH39_ADICR (0xF00124F0u)
+#define DMA_CH39_CHCFGR (0xF00124F4u)
+#define DMA_HRR18 (0xF0011848u)
+#define DMA_CH39_SHADR (0xF00124F8u)
+#define DMA_CH39_CHCSR (0xF00124FCu)
+#define DMA_CH40_RDCRCR (0xF0012500u)
+#define DMA_CH40_SDCRCR (0xF0012504u)
+#define DMA_CH40_SADR (0xF0012508u)
+#define DMA_CH40_DADR (0xF001250Cu)
+#define DMA_CH40_ADICR (0xF0012510u)
+#define DMA_CH40_CHCFGR (0xF0012514u)
+#define DMA_CH40_SHADR (0xF0012518u)
+#define DMA_CH40_CHCSR (0xF001251Cu)
+#define DMA_HRR19 (0xF001184Cu)
+#define DMA_CH41_RDCRCR (0xF0012520u)
+#define DMA_CH41_SDCRCR (0xF0012524u)
+#define DMA_CH41_SADR (0xF0012528u)
+#define DMA_CH41_DADR (0xF001252Cu)
+#define DMA_CH41_ADICR (0xF0012530u)
+#define DMA_CH41_CHCFGR (0xF0012534u)
+#define DMA_CH41_SHADR (0xF0012538u)
+#define DMA_CH41_CHCSR (0xF001253Cu)
+#define DMA_CH42_RDCRCR (0xF0012540u)
+#define DMA_CH42_SDCRCR (0xF0012544u)
+#define DMA_HRR20 (0xF0011850u)
+#define DMA_CH42_SADR (0xF0012548u)
+#define DMA_CH42_DADR (0xF001254Cu)
+#define DMA_CH42_ADICR (0xF0012550u)
+#define DMA_CH42_CHCFGR (0xF0012554u)
+#define DMA_CH42_SHADR (0xF0012558u)
+#define DMA_CH42_CHCSR (0xF001255Cu)
+#define DMA_CH43_RDCRCR (0xF0012560u)
+#define DMA_CH43_SDCRCR (0xF0012564u)
+#define DMA_CH43_SADR (0xF0012568u)
+#define DMA_CH43_DADR (0xF001256Cu)
+#define DMA_HRR21 (0xF0011854u)
+#define DMA_CH43_ADICR (0xF0012570u)
+#define DMA_CH43_CHCFGR (0xF0012574u)
+#define DMA_CH43_SHADR (0xF0012578u)
+#define DMA_CH43_CHCSR (0xF001257Cu)
+#define DMA_CH44_RDCRCR (0xF0012580u)
+#define DMA_CH44_SDCRCR (0xF0012584u)
+#define DMA_CH44_SADR (0xF0012588u)
+#define DMA_CH44_DADR (0xF001258Cu)
+#define DMA_CH44_ADICR (0xF0012590u)
+#define DMA_CH44_CHCFGR (0xF0012594u)
+#define DMA_HRR22 (0xF0011858u)
+#define DMA_CH44_SHADR (0xF0012598u)
+#define DMA_CH44_CHCSR (0xF001259Cu)
+#define DMA_CH45_RDCRCR (0xF00125A0u)
+#define DMA_CH45_SDCRCR (0xF00125A4u)
+#define DMA_CH45_SADR (0xF00125A8u)
+#define DMA_CH45_DADR (0xF00125ACu)
+#define DMA_CH45_ADICR (0xF00125B0u)
+#define DMA_CH45_CHCFGR (0xF00125B4u)
+#define DMA_CH45_SHADR (0xF00125B8u)
+#define DMA_CH45_CHCSR (0xF00125BCu)
+#define DMA_HRR23 (0xF001185Cu)
+#define DMA_CH46_RDCRCR (0xF00125C0u)
+#define DMA_CH46_SDCRCR (0xF00125C4u)
+#define DMA_CH46_SADR (0xF00125C8u)
+#define DMA_CH46_DADR (0xF00125CCu)
+#define DMA_CH46_ADICR (0xF00125D0u)
+#define DMA_CH46_CHCFGR (0xF00125D4u)
+#define DMA_CH46_SHADR (0xF00125D8u)
+#define DMA_CH46_CHCSR (0xF00125DCu)
+#define DMA_CH47_RDCRCR (0xF00125E0u)
+#define DMA_CH47_SDCRCR (0xF00125E4u)
+#define DMA_HRR24 (0xF0011860u)
+#define DMA_CH47_SADR (0xF00125E8u)
+#define DMA_CH47_DADR (0xF00125ECu)
+#define DMA_CH47_ADICR (0xF00125F0u)
+#define DMA_CH47_CHCFGR (0xF00125F4u)
+#define DMA_CH47_SHADR (0xF00125F8u)
+#define DMA_CH47_CHCSR (0xF00125FCu)
+#define DMA_CH48_RDCRCR (0xF0012600u)
+#define DMA_CH48_SDCRCR (0xF0012604u)
+#define DMA_CH48_SADR (0xF0012608u)
+#define DMA_CH48_DADR (0xF001260Cu)
+#define DMA_HRR25 (0xF0011864u)
+#define DMA_CH48_ADICR (0xF0012610u)
+#define DMA_CH48_CHCFGR (0xF0012614u)
+#define DMA_CH48_SHADR (0xF0012618u)
+#define DMA_CH48_CHCSR (0xF001261Cu)
+#define DMA_CH49_RDCRCR (0xF0012620u)
+#define DMA_CH49_SDCRCR (0xF0012624u)
+#define DMA_CH49_SADR (0xF0012628u)
+#define DMA_CH49_DADR (0xF001262Cu)
+#define DMA_CH49_ADICR (0xF0012630u)
+#define DMA_CH49_CHCFGR (0xF0012634u)
+#define DMA_HRR26 (0xF0011868u)
+#define DMA_CH49_SHADR (0xF0012638u)
+#define DMA_CH49_CHCSR (0xF001263Cu)
+#define DMA_CH50_RDCRCR (0xF0012640u)
+#define DMA_CH50_SDCRCR (0xF0012644u)
+#define DMA_CH50_SADR (0xF0012648u)
+#define DMA_CH50_DADR (0xF001264Cu)
+#define DMA_CH50_ADICR (0xF0012650u)
+#define DMA_CH50_CHCFGR (0xF0012654u)
+#define DMA_CH50_SHADR (0xF0012658u)
+#define DMA_CH50_CHCSR (0xF001265Cu)
+#define DMA_HRR27 (0xF001186Cu)
+#define DMA_CH51_RDCRCR (0xF0012660u)
+#define DMA_CH51_SDCRCR (0xF0012664u)
+#define DMA_CH51_SADR (0xF0012668u)
+#define DMA_CH51_DADR (0xF001266Cu)
+#define DMA_CH51_ADICR (0xF0012670u)
+#define DMA_CH51_CHCFGR (0xF0012674u)
+#define DMA_CH51_SHADR (0xF0012678u)
+#define DMA_CH51_CHCSR (0xF001267Cu)
+#define DMA_CH52_RDCRCR (0xF0012680u)
+#define DMA_CH52_SDCRCR (0xF0012684u)
+#define DMA_HRR28 (0xF0011870u)
+#define DMA_CH52_SADR (0xF0012688u)
+#define DMA_CH52_DADR (0xF001268Cu)
+#define DMA_CH52_ADICR (0xF0012690u)
+#define DMA_CH52_CHCFGR (0xF0012694u)
+#define DMA_CH52_SHADR (0xF0012698u)
+#define DMA_CH52_CHCSR (0xF001269Cu)
+#define DMA_CH53_RDCRCR (0xF00126A0u)
+#define DMA_CH53_SDCRCR (0xF00126A4u)
+#define DMA_CH53_SADR (0xF00126A8u)
+#define DMA_CH53_DADR (0xF00126ACu)
+#define DMA_HRR29 (0xF0011874u)
+#define DMA_CH53_ADICR (0xF00126B0u)
+#define DMA_CH53_CHCFGR (0xF00126B4u)
+#define DMA_CH53_SHADR (0xF00126B8u)
+#define DMA_CH53_CHCSR (0xF00126BCu)
+#define DMA_CH54_RDCRCR (0xF00126C0u)
+#define DMA_CH54_SDCRCR (0xF00126C4u)
+#define DMA_CH54_SADR (0xF00126C8u)
+#define DMA_CH54_DADR (0xF00126CCu)
+#define DMA_CH54_ADICR (0xF00126D0u)
+#define DMA_CH54_CHCFGR (0xF00126D4u)
+#define DMA_HRR30 (0xF0011878u)
+#define DMA_CH54_SHADR (0xF00126D8u)
+#define DMA_CH54_CHCSR (0xF00126DCu)
+#define DMA_CH55_RDCRCR (0xF00126E0u)
+#define DMA_CH55_SDCRCR (0xF00126E4u)
+#define DMA_CH55_SADR (0xF00126E8u)
+#define DMA_CH55_DADR (0xF00126ECu)
+#define DMA_CH55_ADICR (0xF00126F0u)
+#define DMA_CH55_CHCFGR (0xF00126F4u)
+#define DMA_CH55_SHADR (0xF00126F8u)
+#define DMA_CH55_CHCSR (0xF00126FCu)
+#define DMA_HRR31 (0xF001187Cu)
+#define DMA_CH56_RDCRCR (0xF0012700u)
+#define DMA_CH56_SDCRCR (0xF0012704u)
+#define DMA_CH56_SADR (0xF0012708u)
+#define DMA_CH56_DADR (0xF001270Cu)
+#define DMA_CH56_ADICR (0xF0012710u)
+#define DMA_CH56_CHCFGR (0xF0012714u)
+#define DMA_CH56_SHADR (0xF0012718u)
+#define DMA_CH56_CHCSR (0xF001271Cu)
+#define DMA_CH57_RDCRCR (0xF0012720u)
+#define DMA_CH57_SDCRCR (0xF0012724u)
+#define DMA_HRR32 (0xF0011880u)
+#define DMA_CH57_SADR (0xF0012728u)
+#define MODULE_DMA (0xF0010000u)
+#define DMA_CH57_DADR (0xF001272Cu)
+#define DMA_CH57_ADICR (0xF0012730u)
+#define DMA_CH57_CHCFGR (0xF0012734u)
+#define DMA_CH57_SHADR (0xF0012738u)
+#define DMA_CH57_CHCSR (0xF001273Cu)
+#define DMA_CH58_RDCRCR (0xF0012740u)
+#define DMA_CH58_SDCRCR (0xF0012744u)
+#define DMA_CH58_SADR (0xF0012748u)
+#define DMA_CH58_DADR (0xF001274Cu)
+#define DMA_HRR33 (0xF0011884u)
+#define DMA_CH58_ADICR (0xF0012750u)
+#define DMA_CH58_CHCFGR (0xF0012754u)
+#define DMA_CH58_SHADR (0xF0012758u)
+#define DMA_CH58_CHCSR (0xF001275Cu)
+#define DMA_CH59_RDCRCR (0xF0012760u)
+#define DMA_CH59_SDCRCR (0xF0012764u)
+#define DMA_CH59_SADR (0xF0012768u)
+#define DMA_CH59_DADR (0xF001276Cu)
+#define DMA_CH59_ADICR (0xF0012770u)
+#define DMA_CH59_CHCFGR (0xF0012774u)
+#define DMA_HRR34 (0xF0011888u)
+#define DMA_CH59_SHADR (0xF0012778u)
+#define DMA_CH59_CHCSR (0xF001277Cu)
+#define DMA_CH60_RDCRCR (0xF0012780u)
+#define DMA_CH60_SDCRCR (0xF0012784u)
+#define DMA_CH60_SADR (0xF0012788u)
+#define DMA_CH60_DADR (0xF001278Cu)
+#define DMA_CH60_ADICR (0xF0012790u)
+#define DMA_CH60_CHCFGR (0xF0012794u)
+#define DMA_CH60_SHADR (0xF0012798u)
+#define DMA_CH60_CHCSR (0xF001279Cu)
+#define DMA_HRR35 (0xF001188Cu)
+#define DMA_CH61_RDCRCR (0xF00127A0u)
+#define DMA_CH61_SDCRCR (0xF00127A4u)
+#define DMA_CH61_SADR (0xF00127A8u)
+#define DMA_CH61_DADR (0xF00127ACu)
+#define DMA_CH61_ADICR (0xF00127B0u)
+#define DMA_CH61_CHCFGR (0xF00127B4u)
+#define DMA_CH61_SHADR (0xF00127B8u)
+#define DMA_CH61_CHCSR (0xF00127BCu)
+#define DMA_CH62_RDCRCR (0xF00127C0u)
+#define DMA_CH62_SDCRCR (0xF00127C4u)
+#define DMA_HRR36 (0xF0011890u)
+#define DMA_CH62_SADR (0xF00127C8u)
+#define DMA_CH62_DADR (0xF00127CCu)
+#define DMA_CH62_ADICR (0xF00127D0u)
+#define DMA_CH62_CHCFGR (0xF00127D4u)
+#define DMA_CH62_SHADR (0xF00127D8u)
+#define DMA_CH62_CHCSR (0xF00127DCu)
+#define DMA_CH63_RDCRCR (0xF00127E0u)
+#define DMA_CH63_SDCRCR (0xF00127E4u)
+#define DMA_CH63_SADR (0xF00127E8u)
+#define DMA_CH63_DADR (0xF00127ECu)
+#define DMA_HRR37 (0xF0011894u)
+#define DMA_CH63_ADICR (0xF00127F0u)
+#define DMA_CH63_CHCFGR (0xF00127F4u)
+#define DMA_CH63_SHADR (0xF00127F8u)
+#define DMA_CH63_CHCSR (0xF00127FCu)
+#define DMA_CH64_RDCRCR (0xF0012800u)
+#define DMA_CH64_SDCRCR (0xF0012804u)
+#define DMA_CH64_SADR (0xF0012808u)
+#define DMA_CH64_DADR (0xF001280Cu)
+#define DMA_CH64_ADICR (0xF0012810u)
+#define DMA_CH64_CHCFGR (0xF0012814u)
+#define DMA_HRR38 (0xF0011898u)
+#define DMA_CH64_SHADR (0xF0012818u)
+#define DMA_CH64_CHCSR (0xF001281Cu)
+#define DMA_CH65_RDCRCR (0xF0012820u)
+#define DMA_CH65_SDCRCR (0xF0012824u)
+#define DMA_CH65_SADR (0xF0012828u)
+#define DMA_CH65_DADR (0xF001282Cu)
+#define DMA_CH65_ADICR (0xF0012830u)
+#define DMA_CH65_CHCFGR (0xF0012834u)
+#define DMA_CH65_SHADR (0xF0012838u)
+#define DMA_CH65_CHCSR (0xF001283Cu)
+#define DMA_HRR39 (0xF001189Cu)
+#define DMA_CH66_RDCRCR (0xF0012840u)
+#define DMA_CH66_SDCRCR (0xF0012844u)
+#define DMA_CH66_SADR (0xF0012848u)
+#define DMA_CH66_DADR (0xF001284Cu)
+#define DMA_CH66_ADICR (0xF0012850u)
+#define DMA_CH66_CHCFGR (0xF0012854u)
+#define DMA_CH66_SHADR (0xF0012858u)
+#define DMA_CH66_CHCSR (0xF001285Cu)
+#define DMA_CH67_RDCRCR (0xF0012860u)
+#define DMA_CH67_SDCRCR (0xF0012864u)
+#define DMA_HRR40 (0xF00118A0u)
+#define DMA_CH67_SADR (0xF0012868u)
+#define DMA_CH67_DADR (0xF001286Cu)
+#define DMA_CH67_ADICR (0xF0012870u)
+#define DMA_CH67_CHCFGR (0xF0012874u)
+#define DMA_CH67_SHADR (0xF0012878u)
+#define DMA_CH67_CHCSR (0xF001287Cu)
+#define DMA_CH68_RDCRCR (0xF0012880u)
+#define DMA_CH68_SDCRCR (0xF0012884u)
+#define DMA_CH68_SADR (0xF0012888u)
+#define DMA_CH68_DADR (0xF001288Cu)
+#define DMA_HRR41 (0xF00118A4u)
+#define DMA_CH68_ADICR (0xF0012890u)
+#define DMA_CH68_CHCFGR (0xF0012894u)
+#define DMA_CH68_SHADR (0xF0012898u)
+#define DMA_CH68_CHCSR (0xF001289Cu)
+#define DMA_CH69_RDCRCR (0xF00128A0u)
+#define DMA_CH69_SDCRCR (0xF00128A4u)
+#define DMA_CH69_SADR (0xF00128A8u)
+#define DMA_CH69_DADR (0xF00128ACu)
+#define DMA_CH69_ADICR (0xF00128B0u)
+#define DMA_CH69_CHCFGR (0xF00128B4u)
+#define DMA_HRR42 (0xF00118A8u)
+#define DMA_CH69_SHADR (0xF00128B8u)
+#define DMA_CH69_CHCSR (0xF00128BCu)
+#define DMA_CH70_RDCRCR (0xF00128C0u)
+#define DMA_CH70_SDCRCR (0xF00128C4u)
+#define DMA_CH70_SADR (0xF00128C8u)
+#define DMA_CH70_DADR (0xF00128CCu)
+#define DMA_CH70_ADICR (0xF00128D0u)
+#define DMA_CH70_CHCFGR (0xF00128D4u)
+#define DMA_CH70_SHADR (0xF00128D8u)
+#define DMA_CH70_CHCSR (0xF00128DCu)
+#define DMA_HRR43 (0xF00118ACu)
+#define DMA_CH71_RDCRCR (0xF00128E0u)
+#define DMA_CH71_SDCRCR (0xF00128E4u)
+#define DMA_CH71_SADR (0xF00128E8u)
+#define DMA_CH71_DADR (0xF00128ECu)
+#define DMA_CH71_ADICR (0xF00128F0u)
+#define DMA_CH71_CHCFGR (0xF00128F4u)
+#define DMA_CH71_SHADR (0xF00128F8u)
+#define DMA_CH71_CHCSR (0xF00128FCu)
+#define DMA_CH72_RDCRCR (0xF0012900u)
+#define DMA_CH72_SDCRCR (0xF0012904u)
+#define DMA_HRR44 (0xF00118B0u)
+#define DMA_CH72_SADR (0xF0012908u)
+#define DMA_CH72_DADR (0xF001290Cu)
+#define DMA_CH72_ADICR (0xF0012910u)
+#define DMA_CH72_CHCFGR (0xF0012914u)
+#define DMA_CH72_SHADR (0xF0012918u)
+#define DMA_CH72_CHCSR (0xF001291Cu)
+#define DMA_CH73_RDCRCR (0xF0012920u)
+#define DMA_CH73_SDCRCR (0xF0012924u)
+#define DMA_CH73_SADR (0xF0012928u)
+#define DMA_CH73_DADR (0xF001292Cu)
+#define DMA_HRR45 (0xF00118B4u)
+#define DMA_CLC (0xF0010000u)
+#define DMA_CH73_ADICR (0xF0012930u)
+#define DMA_CH73_CHCFGR (0xF0012934u)
+#define DMA_CH73_SHADR (0xF0012938u)
+#define DMA_CH73_CHCSR (0xF001293Cu)
+#define DMA_CH74_RDCRCR (0xF0012940u)
+#define DMA_CH74_SDCRCR (0xF0012944u)
+#define DMA_CH74_SADR (0xF0012948u)
+#define DMA_CH74_DADR (0xF001294Cu)
+#define DMA_CH74_ADICR (0xF0012950u)
+#define DMA_CH74_CHCFGR (0xF0012954u)
+#define DMA_HRR46 (0xF00118B8u)
+#define DMA_CH74_SHADR (0xF0012958u)
+#define DMA_CH74_CHCSR (0xF001295Cu)
+#define DMA_CH75_RDCRCR (0xF0012960u)
+#define DMA_CH75_SDCRCR (0xF0012964u)
+#define DMA_CH75_SADR (0xF0012968u)
+#define DMA_CH75_DADR (0xF001296Cu)
+#define DMA_CH75_ADICR (0xF0012970u)
+#define DMA_CH75_CHCFGR (0xF0012974u)
+#define DMA_CH75_SHADR (0xF0012978u)
+#define DMA_CH75_CHCSR (0xF001297Cu)
+#define DMA_HRR47 (0xF00118BCu)
+#define DMA_CH76_RDCRCR (0xF0012980u)
+#define DMA_CH76_SDCRCR (0xF0012984u)
+#define DMA_CH76_SADR (0xF0012988u)
+#define DMA_CH76_DADR (0xF001298Cu)
+#define DMA_CH76_ADICR (0xF0012990u)
+#define DMA_CH76_CHCFGR (0xF0012994u)
+#define DMA_CH76_SHADR (0xF0012998u)
+#define DMA_CH76_CHCSR (0xF001299Cu)
+#define DMA_CH77_RDCRCR (0xF00129A0u)
+#define DMA_CH77_SDCRCR (0xF00129A4u)
+#define DMA_HRR48 (0xF00118C0u)
+#define DMA_CH77_SADR (0xF00129A8u)
+#define DMA_CH77_DADR (0xF00129ACu)
+#define DMA_CH77_ADICR (0xF00129B0u)
+#define DMA_CH77_CHCFGR (0xF00129B4u)
+#define DMA_CH77_SHADR (0xF00129B8u)
+#define DMA_CH77_CHCSR (0xF00129BCu)
+#define DMA_CH78_RDCRCR (0xF00129C0u)
+#define DMA_CH78_SDCRCR (0xF00129C4u)
+#define DMA_CH78_SADR (0xF00129C8u)
+#define DMA_CH78_DADR (0xF00129CCu)
+#define DMA_HRR49 (0xF00118C4u)
+#define DMA_CH78_ADICR (0xF00129D0u)
+#define DMA_CH78_CHCFGR (0xF00129D4u)
+#define DMA_ID (0xF0010008u)
+#define DMA_CH78_SHADR (0xF00129D8u)
+#define DMA_CH78_CHCSR (0xF00129DCu)
+#define DMA_CH79_RDCRCR (0xF00129E0u)
+#define DMA_CH79_SDCRCR (0xF00129E4u)
+#define DMA_CH79_SADR (0xF00129E8u)
+#define DMA_CH79_DADR (0xF00129ECu)
+#define DMA_CH79_ADICR (0xF00129F0u)
+#define DMA_CH79_CHCFGR (0xF00129F4u)
+#define DMA_HRR50 (0xF00118C8u)
+#define DMA_CH79_SHADR (0xF00129F8u)
+#define DMA_CH79_CHCSR (0xF00129FCu)
+#define DMA_CH80_RDCRCR (0xF0012A00u)
+#define DMA_CH80_SDCRCR (0xF0012A04u)
+#define DMA_CH80_SADR (0xF0012A08u)
+#define DMA_CH80_DADR (0xF0012A0Cu)
+#define DMA_CH80_ADICR (0xF0012A10u)
+#define DMA_CH80_CHCFGR (0xF0012A14u)
+#define DMA_CH80_SHADR (0xF0012A18u)
+#define DMA_CH80_CHCSR (0xF0012A1Cu)
+#define DMA_HRR51 (0xF00118CCu)
+#define DMA_CH81_RDCRCR (0xF0012A20u)
+#define DMA_CH81_SDCRCR (0xF0012A24u)
+#define DMA_CH81_SADR (0xF0012A28u)
+#define DMA_CH81_DADR (0xF0012A2Cu)
+#define DMA_CH81_ADICR (0xF0012A30u)
+#define DMA_CH81_CHCFGR (0xF0012A34u)
+#define DMA_CH81_SHADR (0xF0012A38u)
+#define DMA_CH81_CHCSR (0xF0012A3Cu)
+#define DMA_CH82_RDCRCR (0xF0012A40u)
+#define DMA_CH82_SDCRCR (0xF0012A44u)
+#define DMA_HRR52 (0xF00118D0u)
+#define DMA_CH82_SADR (0xF0012A48u)
+#define DMA_CH82_DADR (0xF0012A4Cu)
+#define DMA_CH82_ADICR (0xF0012A50u)
+#define DMA_CH82_CHCFGR (0xF0012A54u)
+#define DMA_CH82_SHADR (0xF0012A58u)
+#define DMA_CH82_CHCSR (0xF0012A5Cu)
+#define DMA_CH83_RDCRCR (0xF0012A60u)
+#define DMA_CH83_SDCRCR (0xF0012A64u)
+#define DMA_CH83_SADR (0xF0012A68u)
+#define DMA_CH83_DADR (0xF0012A6Cu)
+#define DMA_HRR53 (0xF00118D4u)
+#define DMA_CH83_ADICR (0xF0012A70u)
+#define DMA_CH83_CHCFGR (0xF0012A74u)
+#define DMA_CH83_SHADR (0xF0012A78u)
+#define DMA_CH83_CHCSR (0xF0012A7Cu)
+#define DMA_CH84_RDCRCR (0xF0012A80u)
+#define DMA_ACCEN0_ACCENR0 (0xF0010040u)
+#define DMA_CH84_SDCRCR (0xF0012A84u)
+#define DMA_CH84_SADR (0xF0012A88u)
+#define DMA_CH84_DADR (0xF0012A8Cu)
+#define DMA_CH84_ADICR (0xF0012A90u)
+#define DMA_CH84_CHCFGR (0xF0012A94u)
+#define DMA_HRR54 (0xF00118D8u)
+#define DMA_CH84_SHADR (0xF0012A98u)
+#define DMA_CH84_CHCSR (0xF0012A9Cu)
+#define DMA_CH85_RDCRCR (0xF0012AA0u)
+#define DMA_CH85_SDCRCR (0xF0012AA4u)
+#define DMA_CH85_SADR (0xF0012AA8u)
+#define DMA_CH85_DADR (0xF0012AACu)
+#define DMA_CH85_ADICR (0xF0012AB0u)
+#define DMA_CH85_CHCFGR (0xF0012AB4u)
+#define DMA_CH85_SHADR (0xF0012AB8u)
+#define DMA_CH85_CHCSR (0xF0012ABCu)
+#define DMA_HRR55 (0xF00118DCu)
+#define DMA_CH86_RDCRCR (0xF0012AC0u)
+#define DMA_CH86_SDCRCR (0xF0012AC4u)
+#define DMA_CH86_SADR (0xF0012AC8u)
+#define DMA_CH86_DADR (0xF0012ACCu)
+#define DMA_CH86_ADICR (0xF0012AD0u)
+#define DMA_CH86_CHCFGR (0xF0012AD4u)
+#define DMA_CH86_SHADR (0xF0012AD8u)
+#define DMA_CH86_CHCSR (0xF0012ADCu)
+#define DMA_CH87_RDCRCR (0xF0012AE0u)
+#define DMA_CH87_SDCRCR (0xF0012AE4u)
+#define DMA_HRR56 (0xF00118E0u)
+#define DMA_CH87_SADR (0xF0012AE8u)
+#define DMA_CH87_DADR (0xF0012AECu)
+#define DMA_CH87_ADICR (0xF0012AF0u)
+#define DMA_CH87_CHCFGR (0xF0012AF4u)
+#define DMA_CH87_SHADR (0xF0012AF8u)
+#define DMA_CH87_CHCSR (0xF0012AFCu)
+#define DMA_CH88_RDCRCR (0xF0012B00u)
+#define DMA_CH88_SDCRCR (0xF0012B04u)
+#define DMA_CH88_SADR (0xF0012B08u)
+#define DMA_CH88_DADR (0xF0012B0Cu)
+#define DMA_HRR57 (0xF00118E4u)
+#define DMA_CH88_ADICR (0xF0012B10u)
+#define DMA_CH88_CHCFGR (0xF0012B14u)
+#define DMA_CH88_SHADR (0xF0012B18u)
+#define DMA_CH88_CHCSR (0xF0012B1Cu)
+#define DMA_CH89_RDCRCR (0xF0012B20u)
+#define DMA_CH89_SDCRCR (0xF0012B24u)
+#define DMA_CH89_SADR (0xF0012B28u)
+#define DMA_CH89_DADR (0xF0012B2Cu)
+#define DMA_CH89_ADICR (0xF0012B30u)
+#define DMA_CH89_CHCFGR (0xF0012B34u)
+#define DMA_HRR58 (0xF00118E8u)
+#define DMA_CH89_SHADR (0xF0012B38u)
+#define DMA_CH89_CHCSR (0xF0012B3Cu)
+#define DMA_CH90_RDCRCR (0xF0012B40u)
+#define DMA_CH90_SDCRCR (0xF0012B44u)
+#define DMA_CH90_SADR (0xF0012B48u)
+#define DMA_CH90_DADR (0xF0012B4Cu)
+#define DMA_CH90_ADICR (0xF0012B50u)
+#define DMA_CH90_CHCFGR (0xF0012B54u)
+#define DMA_CH90_SHADR (0xF0012B58u)
+#define DMA_CH90_CHCSR (0xF0012B5Cu)
+#define DMA_HRR59 (0xF00118ECu)
+#define DMA_CH91_RDCRCR (0xF0012B60u)
+#define DMA_CH91_SDCRCR (0xF0012B64u)
+#define DMA_CH91_SADR (0xF0012B68u)
+#define DMA_CH91_DADR (0xF0012B6Cu)
+#define DMA_CH91_ADICR (0xF0012B70u)
+#define DMA_CH91_CHCFGR (0xF0012B74u)
+#define DMA_CH91_SHADR (0xF0012B78u)
+#define DMA_CH91_CHCSR (0xF0012B7Cu)
+#define DMA_CH92_RDCRCR (0xF0012B80u)
+#define DMA_CH92_SDCRCR (0xF0012B84u)
+#define DMA_HRR60 (0xF00118F0u)
+#define DMA_CH92_SADR (0xF0012B88u)
+#define DMA_CH92_DADR (0xF0012B8Cu)
+#define DMA_CH92_ADICR (0xF0012B90u)
+#define DMA_CH92_CHCFGR (0xF0012B94u)
+#define DMA_CH92_SHADR (0xF0012B98u)
+#define DMA_CH92_CHCSR (0xF0012B9Cu)
+#define DMA_CH93_RDCRCR (0xF0012BA0u)
+#define DMA_CH93_SDCRCR (0xF0012BA4u)
+#define DMA_CH93_SADR (0xF0012BA8u)
+#define DMA_CH93_DADR (0xF0012BACu)
+#define DMA_HRR61 (0xF00118F4u)
+#define DMA_CH93_ADICR (0xF0012BB0u)
+#define DMA_CH93_CHCFGR (0xF0012BB4u)
+#define DMA_CH93_SHADR (0xF0012BB8u)
+#define DMA_CH93_CHCSR (0xF0012BBCu)
+#define DMA_CH94_RDCRCR (0xF0012BC0u)
+#define DMA_CH94_SDCRCR (0xF0012BC4u)
+#define DMA_CH94_SADR (0xF0012BC8u)
+#define DMA_CH94_DADR (0xF0012BCCu)
+#define DMA_CH94_ADICR (0xF0012BD0u)
+#define DMA_CH94_CHCFGR (0xF0012BD4u)
+#define DMA_HRR62 (0xF00118F8u)
+#define DMA_CH94_SHADR (0xF0012BD8u)
+#define DMA_CH94_CHCSR (0xF0012BDCu)
+#define DMA_CH95_RDCRCR (0xF0012BE0u)
+#define DMA_CH95_SDCRCR (0xF0012BE4u)
+#define DMA_CH95_SADR (0xF0012BE8u)
+#define DMA_CH95_DADR (0xF0012BECu)
+#define DMA_CH95_ADICR (0xF0012BF0u)
+#define DMA_CH95_CHCFGR (0xF0012BF4u)
+#define DMA_CH95_SHADR (0xF0012BF8u)
+#define DMA_CH95_CHCSR (0xF0012BFCu)
+#define DMA_HRR63 (0xF00118FCu)
+#define DMA_CH96_RDCRCR (0xF0012C00u)
+#define DMA_CH96_SDCRCR (0xF0012C04u)
+#define DMA_CH96_SADR (0xF0012C08u)
+#define DMA_CH96_DADR (0xF0012C0Cu)
+#define DMA_CH96_ADICR (0xF0012C10u)
+#define DMA_ACCEN0_ACCENR1 (0xF0010044u)
+#define DMA_CH96_CHCFGR (0xF0012C14u)
+#define DMA_CH96_SHADR (0xF0012C18u)
+#define DMA_CH96_CHCSR (0xF0012C1Cu)
+#define DMA_CH97_RDCRCR (0xF0012C20u)
+#define DMA_CH97_SDCRCR (0xF0012C24u)
+#define DMA_HRR64 (0xF0011900u)
+#define DMA_CH97_SADR (0xF0012C28u)
+#define DMA_CH97_DADR (0xF0012C2Cu)
+#define DMA_CH97_ADICR (0xF0012C30u)
+#define DMA_CH97_CHCFGR (0xF0012C34u)
+#define DMA_CH97_SHADR (0xF0012C38u)
+#define DMA_CH97_CHCSR (0xF0012C3Cu)
+#define DMA_CH98_RDCRCR (0xF0012C40u)
+#define DMA_CH98_SDCRCR (0xF0012C44u)
+#define DMA_CH98_SADR (0xF0012C48u)
+#define DMA_CH98_DADR (0xF0012C4Cu)
+#define DMA_HRR65 (0xF0011904u)
+#define DMA_CH98_ADICR (0xF0012C50u)
+#define DMA_CH98_CHCFGR (0xF0012C54u)
+#define DMA_CH98_SHADR (0xF0012C58u)
+#define DMA_CH98_CHCSR (0xF0012C5Cu)
+#define DMA_CH99_RDCRCR (0xF0012C60u)
+#define DMA_CH99_SDCRCR (0xF0012C64u)
+#define DMA_CH99_SADR (0xF0012C68u)
+#define DMA_CH99_DADR (0xF0012C6Cu)
+#define DMA_CH99_ADICR (0xF0012C70u)
+#define DMA_CH99_CHCFGR (0xF0012C74u)
+#define DMA_HRR66 (0xF0011908u)
+#define DMA_CH99_SHADR (0xF0012C78u)
+#define DMA_CH99_CHCSR (0xF0012C7Cu)
+#define DMA_CH100_RDCRCR (0xF0012C80u)
+#define DMA_CH100_SDCRCR (0xF0012C84u)
+#define DMA_CH100_SADR (0xF0012C88u)
+#define DMA_CH100_DADR (0xF0012C8Cu)
+#define DMA_CH100_ADICR (0xF0012C90u)
+#define DMA_CH100_CHCFGR (0xF0012C94u)
+#define DMA_CH100_SHADR (0xF0012C98u)
+#define DMA_CH100_CHCSR (0xF0012C9Cu)
+#define DMA_HRR67 (0xF001190Cu)
+#define DMA_CH101_RDCRCR (0xF0012CA0u)
+#define DMA_CH101_SDCRCR (0xF0012CA4u)
+#define DMA_CH101_SADR (0xF0012CA8u)
+#define DMA_CH101_DADR (0xF0012CACu)
+#define DMA_CH101_ADICR (0xF0012CB0u)
+#define DMA_CH101_CHCFGR (0xF0012CB4u)
+#define DMA_CH101_SHADR (0xF0012CB8u)
+#define DMA_CH101_CHCSR (0xF0012CBCu)
+#define DMA_CH102_RDCRCR (0xF0012CC0u)
+#define DMA_CH102_SDCRCR (0xF0012CC4u)
+#define DMA_HRR68 (0xF0011910u)
+#define DMA_CH102_SADR (0xF0012CC8u)
+#define DMA_CH102_DADR (0xF0012CCCu)
+#define DMA_CH102_ADICR (0xF0012CD0u)
+#define DMA_CH102_CHCFGR (0xF0012CD4u)
+#define DMA_CH102_SHADR (0xF0012CD8u)
+#define DMA_CH102_CHCSR (0xF0012CDCu)
+#define DMA_CH103_RDCRCR (0xF0012CE0u)
+#define DMA_CH103_SDCRCR (0xF0012CE4u)
+#define DMA_CH103_SADR (0xF0012CE8u)
+#define DMA_CH103_DADR (0xF0012CECu)
+#define DMA_HRR69 (0xF0011914u)
+#define DMA_CH103_ADICR (0xF0012CF0u)
+#define DMA_CH103_CHCFGR (0xF0012CF4u)
+#define DMA_CH103_SHADR (0xF0012CF8u)
+#define DMA_CH103_CHCSR (0xF0012CFCu)
+#define DMA_CH104_RDCRCR (0xF0012D00u)
+#define DMA_CH104_SDCRCR (0xF0012D04u)
+#define DMA_CH104_SADR (0xF0012D08u)
+#define DMA_CH104_DADR (0xF0012D0Cu)
+#define DMA_CH104_ADICR (0xF0012D10u)
+#define DMA_CH104_CHCFGR (0xF0012D14u)
+#define DMA_HRR70 (0xF0011918u)
+#define DMA_CH104_SHADR (0xF0012D18u)
+#define DMA_CH104_CHCSR (0xF0012D1Cu)
+#define DMA_CH105_RDCRCR (0xF0012D20u)
+#define DMA_CH105_SDCRCR (0xF0012D24u)
+#define DMA_CH105_SADR (0xF0012D28u)
+#define DMA_CH105_DADR (0xF0012D2Cu)
+#define DMA_CH105_ADICR (0xF0012D30u)
+#define DMA_CH105_CHCFGR (0xF0012D34u)
+#define DMA_CH105_SHADR (0xF0012D38u)
+#define DMA_CH105_CHCSR (0xF0012D3Cu)
+#define DMA_HRR71 (0xF001191Cu)
+#define DMA_CH106_RDCRCR (0xF0012D40u)
+#define DMA_CH106_SDCRCR (0xF0012D44u)
+#define DMA_CH106_SADR (0xF0012D48u)
+#define DMA_CH106_DADR (0xF0012D4Cu)
+#define DMA_CH106_ADICR (0xF0012D50u)
+#define DMA_CH106_CHCFGR (0xF0012D54u)
+#define DMA_CH106_SHADR (0xF0012D58u)
+#define DMA_CH106_CHCSR (0xF0012D5Cu)
+#define DMA_CH107_RDCRCR (0xF0012D60u)
+#define DMA_CH107_SDCRCR (0xF0012D64u)
+#define DMA_HRR72 (0xF0011920u)
+#define DMA_CH107_SADR (0xF0012D68u)
+#define DMA_CH107_DADR (0xF0012D6Cu)
+#define DMA_CH107_ADICR (0xF0012D70u)
+#define DMA_CH107_CHCFGR (0xF0012D74u)
+#define DMA_CH107_SHADR (0xF0012D78u)
+#define DMA_CH107_CHCSR (0xF0012D7Cu)
+#define DMA_CH108_RDCRCR (0xF0012D80u)
+#define DMA_CH108_SDCRCR (0xF0012D84u)
+#define DMA_CH108_SADR (0xF0012D88u)
+#define DMA_CH108_DADR (0xF0012D8Cu)
+#define DMA_HRR73 (0xF0011924u)
+#define DMA_CH108_ADICR (0xF0012D90u)
+#define DMA_CH108_CHCFGR (0xF0012D94u)
+#define DMA_CH108_SHADR (0xF0012D98u)
+#define DMA_CH108_CHCSR (0xF0012D9Cu)
+#define DMA_CH109_RDCRCR (0xF0012DA0u)
+#define DMA_ACCEN1_ACCENR0 (0xF0010048u)
+#define DMA_CH109_SDCRCR (0xF0012DA4u)
+#define DMA_CH109_SADR (0xF0012DA8u)
+#define DMA_CH109_DADR (0xF0012DACu)
+#define DMA_CH109_ADICR (0xF0012DB0u)
+#define DMA_CH109_CHCFGR (0xF0012DB4u)
+#define DMA_HRR74 (0xF0011928u)
+#define DMA_CH109_SHADR (0xF0012DB8u)
+#define DMA_CH109_CHCSR (0xF0012DBCu)
+#define DMA_CH110_RDCRCR (0xF0012DC0u)
+#define DMA_CH110_SDCRCR (0xF0012DC4u)
+#define DMA_CH110_SADR (0xF0012DC8u)
+#define DMA_CH110_DADR (0xF0012DCCu)
+#define DMA_CH110_ADICR (0xF0012DD0u)
+#define DMA_CH110_CHCFGR (0xF0012DD4u)
+#define DMA_CH110_SHADR (0xF0012DD8u)
+#define DMA_CH110_CHCSR (0xF0012DDCu)
+#define DMA_HRR75 (0xF001192Cu)
+#define DMA_CH111_RDCRCR (0xF0012DE0u)
+#define DMA_CH111_SDCRCR (0xF0012DE4u)
+#define DMA_CH111_SADR (0xF0012DE8u)
+#define DMA_CH111_DADR (0xF0012DECu)
+#define DMA_CH111_ADICR (0xF0012DF0u)
+#define DMA_CH111_CHCFGR (0xF0012DF4u)
+#define DMA_CH111_SHADR (0xF0012DF8u)
+#define DMA_CH111_CHCSR (0xF0012DFCu)
+#define DMA_CH112_RDCRCR (0xF0012E00u)
+#define DMA_CH112_SDCRCR (0xF0012E04u)
+#define DMA_HRR76 (0xF0011930u)
+#define DMA_CH112_SADR (0xF0012E08u)
+#define DMA_CH112_DADR (0xF0012E0Cu)
+#define DMA_CH112_ADICR (0xF0012E10u)
+#define DMA_CH112_CHCFGR (0xF0012E14u)
+#define DMA_CH112_SHADR (0xF0012E18u)
+#define DMA_CH112_CHCSR (0xF0012E1Cu)
+#define DMA_CH113_RDCRCR (0xF0012E20u)
+#define DMA_CH113_SDCRCR (0xF0012E24u)
+#define DMA_CH113_SADR (0xF0012E28u)
+#define DMA_CH113_DADR (0xF0012E2Cu)
+#define DMA_HRR77 (0xF0011934u)
+#define DMA_CH113_ADICR (0xF0012E30u)
+#define DMA_CH113_CHCFGR (0xF0012E34u)
+#define DMA_CH113_SHADR (0xF0012E38u)
+#define DMA_CH113_CHCSR (0xF0012E3Cu)
+#define DMA_CH114_RDCRCR (0xF0012E40u)
+#define DMA_CH114_SDCRCR (0xF0012E44u)
+#define DMA_CH114_SADR (0xF0012E48u)
+#define DMA_CH114_DADR (0xF0012E4Cu)
+#define DMU_HF_EER (0xF8040030u)
+#define DMU_HF_ERRSR (0xF8040034u)
+#define DMU_HF_CLRE (0xF8040038u)
+#define DMU_HF_ECCR (0xF8040040u)
+#define DMU_HF_ECCS (0xF8040044u)
+#define DMU_HF_ECCC (0xF8040048u)
+#define DMU_HF_ECCW (0xF804004Cu)
+#define DMU_HF_CCONTROL (0xF8040050u)
+#define DMU_HF_PSTATUS (0xF8040060u)
+#define DMU_HF_PCONTROL (0xF8040064u)
+#define DMU_HF_PWAIT (0xF8040068u)
+#define DMU_HF_DWAIT (0xF804006Cu)
+#define DMU_HF_PROCONUSR (0xF8040074u)
+#define DMU_HF_PROCONPF (0xF8040080u)
+#define DMU_HF_PROCONTP (0xF8040084u)
+#define DMU_HF_PROCONDF (0xF8040088u)
+#define DMU_HF_PROCONRAM (0xF804008Cu)
+#define DMU_HF_PROCONDBG (0xF8040090u)
+#define DMU_HF_SUSPEND (0xF80400F0u)
+#define DMU_HF_MARGIN (0xF80400F4u)
+#define DMU_HF_ACCEN1 (0xF80400F8u)
+#define DMU_HF_ACCEN0 (0xF80400FCu)
+#define DMU_HP0_PROCON_P0 (0xF8050000u)
+#define DMU_HP0_PROCON_P1 (0xF8050004u)
+#define DMU_HP0_PROCON_P2 (0xF8050008u)
+#define DMU_HP0_PROCON_P3 (0xF805000Cu)
+#define DMU_HP0_PROCON_P4 (0xF8050010u)
+#define DMU_HP0_PROCON_P5 (0xF8050014u)
+#define DMU_HP0_PROCON_OTP0 (0xF8050040u)
+#define DMU_HP0_PROCON_OTP1 (0xF8050044u)
+#define DMU_HP0_PROCON_OTP2 (0xF8050048u)
+#define DMU_HP0_PROCON_OTP3 (0xF805004Cu)
+#define DMU_HP0_PROCON_OTP4 (0xF8050050u)
+#define DMU_HP0_PROCON_OTP5 (0xF8050054u)
+#define DMU_HP0_PROCON_WOP0 (0xF8050080u)
+#define DMU_HP0_PROCON_WOP1 (0xF8050084u)
+#define DMU_HP0_PROCON_WOP2 (0xF8050088u)
+#define DMU_HP0_PROCON_WOP3 (0xF805008Cu)
+#define DMU_HP0_PROCON_WOP4 (0xF8050090u)
+#define DMU_HP0_PROCON_WOP5 (0xF8050094u)
+#define DMU_HP0_ECPRIO_P0 (0xF80500A0u)
+#define DMU_HP0_ECPRIO_P1 (0xF80500A4u)
+#define DMU_HP0_ECPRIO_P2 (0xF80500A8u)
+#define DMU_HP0_ECPRIO_P3 (0xF80500ACu)
+#define DMU_HP0_ECPRIO_P4 (0xF80500B0u)
+#define DMU_HP0_ECPRIO_P5 (0xF80500B4u)
+#define DMU_HP1_PROCON_P0 (0xF8050100u)
+#define DMU_HP1_PROCON_P1 (0xF8050104u)
+#define DMU_HP1_PROCON_P2 (0xF8050108u)
+#define DMU_HP1_PROCON_P3 (0xF805010Cu)
+#define DMU_HP1_PROCON_P4 (0xF8050110u)
+#define DMU_HP1_PROCON_P5 (0xF8050114u)
+#define DMU_HP1_PROCON_OTP0 (0xF8050140u)
+#define DMU_HP1_PROCON_OTP1 (0xF8050144u)
+#define DMU_HP1_PROCON_OTP2 (0xF8050148u)
+#define DMU_HP1_PROCON_OTP3 (0xF805014Cu)
+#define DMU_HP1_PROCON_OTP4 (0xF8050150u)
+#define DMU_HP1_PROCON_OTP5 (0xF8050154u)
+#define DMU_HP1_PROCON_WOP0 (0xF8050180u)
+#define DMU_HP1_PROCON_WOP1 (0xF8050184u)
+#define DMU_HP1_PROCON_WOP2 (0xF8050188u)
+#define DMU_HP1_PROCON_WOP3 (0xF805018Cu)
+#define DMU_HP1_PROCON_WOP4 (0xF8050190u)
+#define DMU_HP1_PROCON_WOP5 (0xF8050194u)
+#define DMU_HP1_ECPRIO_P0 (0xF80501A0u)
+#define DMU_HP1_ECPRIO_P1 (0xF80501A4u)
+#define DMU_HP1_ECPRIO_P2 (0xF80501A8u)
+#define DMU_HP1_ECPRIO_P3 (0xF80501ACu)
+#define DMU_HP1_ECPRIO_P4 (0xF80501B0u)
+#define DMU_HP1_ECPRIO_P5 (0xF80501B4u)
+#define DMU_SF_STATUS (0xF8060010u)
+#define DMU_SF_CONTROL (0xF8060014u)
+#define DMU_SF_OPERATION (0xF8060018u)
+#define DMU_SF_EER (0xF8060030u)
+#define DMU_SF_ERRSR (0xF8060034u)
+#define DMU_SF_CLRE (0xF8060038u)
+#define DMU_SF_ECCR (0xF8060040u)
+#define DMU_SF_ECCS (0xF8060044u)
+#define DMU_SF_ECCC (0xF8060048u)
+#define DMU_SF_ECCW (0xF806004Cu)
+#define DMU_SF_PROCONUSR (0xF8060074u)
+#define DMU_SF_SUSPEND (0xF80600E8u)
+#define DMU_SF_MARGIN (0xF80600ECu)
+#define DMU_SP_PROCONHSMCFG (0xF8070000u)
+#define DMU_SP_PROCONHSMCBS (0xF8070004u)
+#define DMU_SP_PROCONHSMCX0 (0xF8070008u)
+#define DMU_SP_PROCONHSMCX1 (0xF807000Cu)
+#define DMU_SP_PROCONHSMCOTP0 (0xF8070010u)
+#define DMU_SP_PROCONHSMCOTP1 (0xF8070014u)
+#define DMU_SP_PROCONHSM (0xF8070040u)
+#define MODULE_DMU (0xF8040000u)
+#define DMU_HF_ID (0xF8040008u)
+#define DMU_HF_STATUS (0xF8040010u)
+#define DMU_HF_CONTROL (0xF8040014u)
+#define DMU_HF_OPERATION (0xF8040018u)
+#define DMU_HF_PROTECT (0xF804001Cu)
+#define DMU_HF_CONFIRM0 (0xF8040020u)
+#define DMU_HF_CONFIRM1 (0xF8040024u)
+#define DMU_HF_CONFIRM2 (0xF8040028u)
+#define DOM0_SCICTRL1_PECON (0xF8700020u)
+#define DOM0_SCICTRL1_PRIORITY (0xF8700028u)
+#define DOM0_SCICTRL1_ERRADDR (0xF8700030u)
+#define DOM0_SCICTRL1_ERR (0xF8700038u)
+#define DOM0_SCICTRL2_PECON (0xF8700040u)
+#define DOM0_SCICTRL2_PRIORITY (0xF8700048u)
+#define DOM0_SCICTRL2_ERRADDR (0xF8700050u)
+#define DOM0_SCICTRL2_ERR (0xF8700058u)
+#define DOM0_SCICTRL3_PECON (0xF8700060u)
+#define DOM0_SCICTRL3_PRIORITY (0xF8700068u)
+#define DOM0_SCICTRL3_ERRADDR (0xF8700070u)
+#define DOM0_SCICTRL3_ERR (0xF8700078u)
+#define DOM0_SCICTRL4_PECON (0xF8700080u)
+#define DOM0_SCICTRL4_PRIORITY (0xF8700088u)
+#define DOM0_SCICTRL4_ERRADDR (0xF8700090u)
+#define DOM0_SCICTRL4_ERR (0xF8700098u)
+#define DOM0_SCICTRL5_PECON (0xF87000A0u)
+#define DOM0_SCICTRL5_PRIORITY (0xF87000A8u)
+#define DOM0_SCICTRL5_ERRADDR (0xF87000B0u)
+#define DOM0_SCICTRL5_ERR (0xF87000B8u)
+#define DOM0_SCICTRL6_PECON (0xF87000C0u)
+#define DOM0_SCICTRL6_PRIORITY (0xF87000C8u)
+#define DOM0_SCICTRL6_ERRADDR (0xF87000D0u)
+#define DOM0_SCICTRL6_ERR (0xF87000D8u)
+#define DOM0_SCICTRL7_PECON (0xF87000E0u)
+#define DOM0_SCICTRL7_PRIORITY (0xF87000E8u)
+#define DOM0_SCICTRL7_ERRADDR (0xF87000F0u)
+#define DOM0_SCICTRL7_ERR (0xF87000F8u)
+#define DOM0_SCICTRL8_PECON (0xF8700100u)
+#define DOM0_SCICTRL8_PRIORITY (0xF8700108u)
+#define DOM0_SCICTRL8_ERRADDR (0xF8700110u)
+#define DOM0_SCICTRL8_ERR (0xF8700118u)
+#define DOM0_SCICTRL9_PECON (0xF8700120u)
+#define DOM0_SCICTRL9_PRIORITY (0xF8700128u)
+#define DOM0_SCICTRL9_ERRADDR (0xF8700130u)
+#define DOM0_SCICTRL9_ERR (0xF8700138u)
+#define DOM0_SCICTRL10_PECON (0xF8700140u)
+#define DOM0_SCICTRL10_PRIORITY (0xF8700148u)
+#define DOM0_SCICTRL10_ERRADDR (0xF8700150u)
+#define DOM0_SCICTRL10_ERR (0xF8700158u)
+#define DOM0_SCICTRL11_PECON (0xF8700160u)
+#define DOM0_SCICTRL11_PRIORITY (0xF8700168u)
+#define DOM0_SCICTRL11_ERRADDR (0xF8700170u)
+#define DOM0_SCICTRL11_ERR (0xF8700178u)
+#define DOM0_SCICTRL12_PECON (0xF8700180u)
+#define DOM0_SCICTRL12_PRIORITY (0xF8700188u)
+#define DOM0_SCICTRL12_ERRADDR (0xF8700190u)
+#define DOM0_SCICTRL12_ERR (0xF8700198u)
+#define DOM0_SCICTRL13_PECON (0xF87001A0u)
+#define DOM0_SCICTRL13_PRIORITY (0xF87001A8u)
+#define DOM0_SCICTRL13_ERRADDR (0xF87001B0u)
+#define DOM0_SCICTRL13_ERR (0xF87001B8u)
+#define DOM0_SCICTRL14_PECON (0xF87001C0u)
+#define DOM0_SCICTRL14_PRIORITY (0xF87001C8u)
+#define DOM0_SCICTRL14_ERRADDR (0xF87001D0u)
+#define DOM0_SCICTRL14_ERR (0xF87001D8u)
+#define DOM0_SCICTRL15_PECON (0xF87001E0u)
+#define DOM0_SCICTRL15_PRIORITY (0xF87001E8u)
+#define DOM0_SCICTRL15_ERRADDR (0xF87001F0u)
+#define DOM0_SCICTRL15_ERR (0xF87001F8u)
+#define DOM0_ID (0xF8700408u)
+#define DOM0_PESTAT (0xF8700410u)
+#define DOM0_TIDSTAT (0xF8700418u)
+#define DOM0_TIDEN (0xF8700420u)
+#define DOM0_BRCON (0xF8700430u)
+#define DOM0_ACCEN0 (0xF87004F0u)
+#define DOM0_ACCEN1 (0xF87004F8u)
+#define MODULE_DOM0 (0xF8700000u)
+#define DOM0_SCICTRL0_PECON (0xF8700000u)
+#define DOM0_SCICTRL0_PRIORITY (0xF8700008u)
+#define DOM0_SCICTRL0_ERRADDR (0xF8700010u)
+#define DOM0_SCICTRL0_ERR (0xF8700018u)
+#define EDSADC_CH5_GAINCAL (0xF002463Cu)
+#define EDSADC_GLOBRC (0xF0024088u)
+#define EDSADC_CH5_GAINCTR (0xF0024640u)
+#define EDSADC_CH5_GAINCORR (0xF0024644u)
+#define EDSADC_CH5_TSTMP (0xF0024650u)
+#define EDSADC_CH5_TSCNT (0xF0024654u)
+#define EDSADC_CH5_FCFGA (0xF0024670u)
+#define EDSADC_ACCPROT (0xF0024090u)
+#define EDSADC_CH5_BOUNDSEL (0xF0024678u)
+#define EDSADC_CH5_RESA (0xF0024680u)
+#define EDSADC_CH5_CGSYNC (0xF00246A0u)
+#define EDSADC_CH5_RECTCFG (0xF00246A8u)
+#define EDSADC_CGCFG (0xF00240A0u)
+#define EDSADC_CH5_VCM (0xF00246B0u)
+#define EDSADC_EVFLAG (0xF00240E0u)
+#define EDSADC_EVFLAGCLR (0xF00240E4u)
+#define EDSADC_CH0_MODCFG (0xF0024100u)
+#define EDSADC_CH0_DICFG (0xF0024108u)
+#define EDSADC_CH0_FCFGM (0xF0024110u)
+#define EDSADC_CH0_FCFGC (0xF0024114u)
+#define EDSADC_CH0_FCNTC (0xF0024118u)
+#define EDSADC_CH0_OVSCFG (0xF002411Cu)
+#define EDSADC_CH0_IWCTR (0xF0024120u)
+#define EDSADC_CH0_IIVAL (0xF0024124u)
+#define EDSADC_CH0_ISTAT (0xF0024128u)
+#define EDSADC_CH0_RFC (0xF002412Cu)
+#define EDSADC_CH0_RESM (0xF0024130u)
+#define EDSADC_CH0_OFFCOMP (0xF0024138u)
+#define EDSADC_CH0_GAINCAL (0xF002413Cu)
+#define EDSADC_CH0_GAINCTR (0xF0024140u)
+#define EDSADC_CH0_GAINCORR (0xF0024144u)
+#define EDSADC_CH0_TSTMP (0xF0024150u)
+#define EDSADC_CH0_TSCNT (0xF0024154u)
+#define EDSADC_CH0_FCFGA (0xF0024170u)
+#define EDSADC_CH0_BOUNDSEL (0xF0024178u)
+#define EDSADC_CH0_RESA (0xF0024180u)
+#define EDSADC_CH0_CGSYNC (0xF00241A0u)
+#define EDSADC_CH0_RECTCFG (0xF00241A8u)
+#define EDSADC_CH0_VCM (0xF00241B0u)
+#define EDSADC_CH1_MODCFG (0xF0024200u)
+#define EDSADC_CH1_DICFG (0xF0024208u)
+#define EDSADC_CH1_FCFGM (0xF0024210u)
+#define EDSADC_CH1_FCFGC (0xF0024214u)
+#define EDSADC_CH1_FCNTC (0xF0024218u)
+#define EDSADC_CH1_OVSCFG (0xF002421Cu)
+#define EDSADC_CH1_IWCTR (0xF0024220u)
+#define EDSADC_CH1_IIVAL (0xF0024224u)
+#define EDSADC_CH1_ISTAT (0xF0024228u)
+#define EDSADC_CH1_RFC (0xF002422Cu)
+#define EDSADC_CH1_RESM (0xF0024230u)
+#define EDSADC_CH1_OFFCOMP (0xF0024238u)
+#define EDSADC_CH1_GAINCAL (0xF002423Cu)
+#define EDSADC_CH1_GAINCTR (0xF0024240u)
+#define EDSADC_CH1_GAINCORR (0xF0024244u)
+#define EDSADC_CH1_TSTMP (0xF0024250u)
+#define EDSADC_CH1_TSCNT (0xF0024254u)
+#define EDSADC_CH1_FCFGA (0xF0024270u)
+#define EDSADC_CH1_BOUNDSEL (0xF0024278u)
+#define EDSADC_CH1_RESA (0xF0024280u)
+#define EDSADC_CH1_CGSYNC (0xF00242A0u)
+#define EDSADC_CH1_RECTCFG (0xF00242A8u)
+#define EDSADC_CH1_VCM (0xF00242B0u)
+#define EDSADC_CH2_MODCFG (0xF0024300u)
+#define EDSADC_CH2_DICFG (0xF0024308u)
+#define EDSADC_CH2_FCFGM (0xF0024310u)
+#define EDSADC_CH2_FCFGC (0xF0024314u)
+#define EDSADC_CH2_FCNTC (0xF0024318u)
+#define EDSADC_CH2_OVSCFG (0xF002431Cu)
+#define EDSADC_CH2_IWCTR (0xF0024320u)
+#define EDSADC_CH2_IIVAL (0xF0024324u)
+#define EDSADC_CH2_ISTAT (0xF0024328u)
+#define EDSADC_CH2_RFC (0xF002432Cu)
+#define EDSADC_CH2_RESM (0xF0024330u)
+#define EDSADC_CH2_OFFCOMP (0xF0024338u)
+#define EDSADC_CH2_GAINCAL (0xF002433Cu)
+#define EDSADC_CH2_GAINCTR (0xF0024340u)
+#define EDSADC_CH2_GAINCORR (0xF0024344u)
+#define EDSADC_CH2_TSTMP (0xF0024350u)
+#define EDSADC_CH2_TSCNT (0xF0024354u)
+#define EDSADC_CH2_FCFGA (0xF0024370u)
+#define EDSADC_CH2_BOUNDSEL (0xF0024378u)
+#define EDSADC_CH2_RESA (0xF0024380u)
+#define EDSADC_CH2_CGSYNC (0xF00243A0u)
+#define EDSADC_CH2_RECTCFG (0xF00243A8u)
+#define EDSADC_CH2_VCM (0xF00243B0u)
+#define EDSADC_CH3_MODCFG (0xF0024400u)
+#define EDSADC_CH3_DICFG (0xF0024408u)
+#define EDSADC_CH3_FCFGM (0xF0024410u)
+#define EDSADC_CH3_FCFGC (0xF0024414u)
+#define EDSADC_CH3_FCNTC (0xF0024418u)
+#define EDSADC_CH3_OVSCFG (0xF002441Cu)
+#define EDSADC_CH3_IWCTR (0xF0024420u)
+#define EDSADC_CH3_IIVAL (0xF0024424u)
+#define EDSADC_CH3_ISTAT (0xF0024428u)
+#define EDSADC_CH3_RFC (0xF002442Cu)
+#define EDSADC_CH3_RESM (0xF0024430u)
+#define EDSADC_CH3_OFFCOMP (0xF0024438u)
+#define MODULE_EDSADC (0xF0024000u)
+#define EDSADC_CH3_GAINCAL (0xF002443Cu)
+#define EDSADC_CH3_GAINCTR (0xF0024440u)
+#define EDSADC_CH3_GAINCORR (0xF0024444u)
+#define EDSADC_CH3_TSTMP (0xF0024450u)
+#define EDSADC_CH3_TSCNT (0xF0024454u)
+#define EDSADC_CH3_FCFGA (0xF0024470u)
+#define EDSADC_CH3_BOUNDSEL (0xF0024478u)
+#define EDSADC_CH3_RESA (0xF0024480u)
+#define EDSADC_CH3_CGSYNC (0xF00244A0u)
+#define EDSADC_CH3_RECTCFG (0xF00244A8u)
+#define EDSADC_CH3_VCM (0xF00244B0u)
+#define EDSADC_CH4_MODCFG (0xF0024500u)
+#define EDSADC_CH4_DICFG (0xF0024508u)
+#define EDSADC_CLC (0xF0024000u)
+#define EDSADC_CH4_FCFGM (0xF0024510u)
+#define EDSADC_CH4_FCFGC (0xF0024514u)
+#define EDSADC_CH4_FCNTC (0xF0024518u)
+#define EDSADC_CH4_OVSCFG (0xF002451Cu)
+#define EDSADC_ID (0xF0024008u)
+#define EDSADC_CH4_IWCTR (0xF0024520u)
+#define EDSADC_CH4_IIVAL (0xF0024524u)
+#define EDSADC_CH4_ISTAT (0xF0024528u)
+#define EDSADC_CH4_RFC (0xF002452Cu)
+#define EDSADC_CH4_RESM (0xF0024530u)
+#define EDSADC_OCS (0xF0024028u)
+#define EDSADC_CH4_OFFCOMP (0xF0024538u)
+#define EDSADC_CH4_GAINCAL (0xF002453Cu)
+#define EDSADC_CH4_GAINCTR (0xF0024540u)
+#define EDSADC_CH4_GAINCORR (0xF0024544u)
+#define EDSADC_KRSTCLR (0xF002402Cu)
+#define EDSADC_CH4_TSTMP (0xF0024550u)
+#define EDSADC_CH4_TSCNT (0xF0024554u)
+#define EDSADC_CH4_FCFGA (0xF0024570u)
+#define EDSADC_CH4_BOUNDSEL (0xF0024578u)
+#define EDSADC_KRST1 (0xF0024030u)
+#define EDSADC_CH4_RESA (0xF0024580u)
+#define EDSADC_CH4_CGSYNC (0xF00245A0u)
+#define EDSADC_CH4_RECTCFG (0xF00245A8u)
+#define EDSADC_CH4_VCM (0xF00245B0u)
+#define EDSADC_KRST0 (0xF0024034u)
+#define EDSADC_CH5_MODCFG (0xF0024600u)
+#define EDSADC_CH5_DICFG (0xF0024608u)
+#define EDSADC_CH5_FCFGM (0xF0024610u)
+#define EDSADC_CH5_FCFGC (0xF0024614u)
+#define EDSADC_CH5_FCNTC (0xF0024618u)
+#define EDSADC_ACCEN0 (0xF002403Cu)
+#define EDSADC_CH5_OVSCFG (0xF002461Cu)
+#define EDSADC_CH5_IWCTR (0xF0024620u)
+#define EDSADC_CH5_IIVAL (0xF0024624u)
+#define EDSADC_CH5_ISTAT (0xF0024628u)
+#define EDSADC_GLOBCFG (0xF0024080u)
+#define EDSADC_CH5_RFC (0xF002462Cu)
+#define EDSADC_CH5_RESM (0xF0024630u)
+#define EDSADC_CH5_OFFCOMP (0xF0024638u)
+#define ERAY0_RDDS_1S0 (0xF001C600u)
+#define ERAY0_RDDS_1S1 (0xF001C604u)
+#define ERAY0_SIR (0xF001C024u)
+#define ERAY0_RDDS_1S2 (0xF001C608u)
+#define ERAY0_RDDS_1S3 (0xF001C60Cu)
+#define ERAY0_RDDS_1S4 (0xF001C610u)
+#define ERAY0_RDDS_1S5 (0xF001C614u)
+#define ERAY0_EILS (0xF001C028u)
+#define ERAY0_RDDS_1S6 (0xF001C618u)
+#define ERAY0_RDDS_1S7 (0xF001C61Cu)
+#define ERAY0_RDDS_1S8 (0xF001C620u)
+#define ERAY0_RDDS_1S9 (0xF001C624u)
+#define ERAY0_SILS (0xF001C02Cu)
+#define ERAY0_RDDS_1S10 (0xF001C628u)
+#define ERAY0_RDDS_1S11 (0xF001C62Cu)
+#define ERAY0_RDDS_1S12 (0xF001C630u)
+#define ERAY0_RDDS_1S13 (0xF001C634u)
+#define ERAY0_RDDS_1S14 (0xF001C638u)
+#define ERAY0_EIES (0xF001C030u)
+#define ERAY0_RDDS_1S15 (0xF001C63Cu)
+#define ERAY0_RDDS_1S16 (0xF001C640u)
+#define ERAY0_RDDS_1S17 (0xF001C644u)
+#define ERAY0_RDDS_1S18 (0xF001C648u)
+#define ERAY0_EIER (0xF001C034u)
+#define ERAY0_RDDS_1S19 (0xF001C64Cu)
+#define ERAY0_RDDS_1S20 (0xF001C650u)
+#define ERAY0_RDDS_1S21 (0xF001C654u)
+#define ERAY0_RDDS_1S22 (0xF001C658u)
+#define ERAY0_SIES (0xF001C038u)
+#define ERAY0_RDDS_1S23 (0xF001C65Cu)
+#define ERAY0_RDDS_1S24 (0xF001C660u)
+#define ERAY0_RDDS_1S25 (0xF001C664u)
+#define ERAY0_RDDS_1S26 (0xF001C668u)
+#define ERAY0_SIER (0xF001C03Cu)
+#define ERAY0_RDDS_1S27 (0xF001C66Cu)
+#define ERAY0_RDDS_1S28 (0xF001C670u)
+#define ERAY0_RDDS_1S29 (0xF001C674u)
+#define ERAY0_RDDS_1S30 (0xF001C678u)
+#define ERAY0_RDDS_1S31 (0xF001C67Cu)
+#define ERAY0_ILE (0xF001C040u)
+#define ERAY0_RDDS_1S32 (0xF001C680u)
+#define ERAY0_RDDS_1S33 (0xF001C684u)
+#define ERAY0_RDDS_1S34 (0xF001C688u)
+#define ERAY0_RDDS_1S35 (0xF001C68Cu)
+#define ERAY0_T0C (0xF001C044u)
+#define ERAY0_RDDS_1S36 (0xF001C690u)
+#define ERAY0_RDDS_1S37 (0xF001C694u)
+#define ERAY0_RDDS_1S38 (0xF001C698u)
+#define ERAY0_RDDS_1S39 (0xF001C69Cu)
+#define ERAY0_T1C (0xF001C048u)
+#define ERAY0_RDDS_1S40 (0xF001C6A0u)
+#define ERAY0_RDDS_1S41 (0xF001C6A4u)
+#define ERAY0_RDDS_1S42 (0xF001C6A8u)
+#define ERAY0_RDDS_1S43 (0xF001C6ACu)
+#define ERAY0_RDDS_1S44 (0xF001C6B0u)
+#define ERAY0_STPW1 (0xF001C04Cu)
+#define ERAY0_RDDS_1S45 (0xF001C6B4u)
+#define ERAY0_RDDS_1S46 (0xF001C6B8u)
+#define ERAY0_RDDS_1S47 (0xF001C6BCu)
+#define ERAY0_RDDS_1S48 (0xF001C6C0u)
+#define ERAY0_STPW2 (0xF001C050u)
+#define ERAY0_RDDS_1S49 (0xF001C6C4u)
+#define ERAY0_RDDS_1S50 (0xF001C6C8u)
+#define ERAY0_RDDS_1S51 (0xF001C6CCu)
+#define ERAY0_RDDS_1S52 (0xF001C6D0u)
+#define ERAY0_SUCC1 (0xF001C080u)
+#define ERAY0_RDDS_1S53 (0xF001C6D4u)
+#define ERAY0_RDDS_1S54 (0xF001C6D8u)
+#define ERAY0_RDDS_1S55 (0xF001C6DCu)
+#define ERAY0_RDDS_1S56 (0xF001C6E0u)
+#define ERAY0_SUCC2 (0xF001C084u)
+#define ERAY0_RDDS_1S57 (0xF001C6E4u)
+#define ERAY0_RDDS_1S58 (0xF001C6E8u)
+#define ERAY0_RDDS_1S59 (0xF001C6ECu)
+#define ERAY0_RDDS_1S60 (0xF001C6F0u)
+#define ERAY0_RDDS_1S61 (0xF001C6F4u)
+#define ERAY0_SUCC3 (0xF001C088u)
+#define ERAY0_RDDS_1S62 (0xF001C6F8u)
+#define ERAY0_RDDS_1S63 (0xF001C6FCu)
+#define ERAY0_RDHS1 (0xF001C700u)
+#define ERAY0_RDHS2 (0xF001C704u)
+#define ERAY0_RDHS3 (0xF001C708u)
+#define ERAY0_MBS (0xF001C70Cu)
+#define ERAY0_NEMC (0xF001C08Cu)
+#define ERAY0_OBCM (0xF001C710u)
+#define ERAY0_OBCR (0xF001C714u)
+#define ERAY0_OTSS (0xF001C870u)
+#define ERAY0_OCS (0xF001C8E8u)
+#define ERAY0_KRSTCLR (0xF001C8ECu)
+#define ERAY0_KRST1 (0xF001C8F0u)
+#define ERAY0_KRST0 (0xF001C8F4u)
+#define ERAY0_ACCEN0 (0xF001C8FCu)
+#define ERAY0_PRTC1 (0xF001C090u)
+#define ERAY0_PRTC2 (0xF001C094u)
+#define ERAY0_MHDC (0xF001C098u)
+#define ERAY0_GTUC01 (0xF001C0A0u)
+#define ERAY0_GTUC02 (0xF001C0A4u)
+#define ERAY0_GTUC03 (0xF001C0A8u)
+#define ERAY0_GTUC04 (0xF001C0ACu)
+#define ERAY0_GTUC05 (0xF001C0B0u)
+#define ERAY0_GTUC06 (0xF001C0B4u)
+#define ERAY0_GTUC07 (0xF001C0B8u)
+#define ERAY0_GTUC08 (0xF001C0BCu)
+#define ERAY0_GTUC09 (0xF001C0C0u)
+#define ERAY0_GTUC10 (0xF001C0C4u)
+#define ERAY0_GTUC11 (0xF001C0C8u)
+#define ERAY0_CCSV (0xF001C100u)
+#define ERAY0_CCEV (0xF001C104u)
+#define ERAY0_SCV (0xF001C110u)
+#define ERAY0_MTCCV (0xF001C114u)
+#define ERAY0_RCV (0xF001C118u)
+#define ERAY0_OCV (0xF001C11Cu)
+#define ERAY0_SFS (0xF001C120u)
+#define ERAY0_SWNIT (0xF001C124u)
+#define ERAY0_ACS (0xF001C128u)
+#define ERAY0_ESID_1S0 (0xF001C130u)
+#define ERAY0_ESID_1S1 (0xF001C134u)
+#define ERAY0_ESID_1S2 (0xF001C138u)
+#define ERAY0_ESID_1S3 (0xF001C13Cu)
+#define ERAY0_ESID_1S4 (0xF001C140u)
+#define ERAY0_ESID_1S5 (0xF001C144u)
+#define ERAY0_ESID_1S6 (0xF001C148u)
+#define ERAY0_ESID_1S7 (0xF001C14Cu)
+#define ERAY0_ESID_1S8 (0xF001C150u)
+#define ERAY0_ESID_1S9 (0xF001C154u)
+#define ERAY0_ESID_1S10 (0xF001C158u)
+#define ERAY0_ESID_1S11 (0xF001C15Cu)
+#define ERAY0_ESID_1S12 (0xF001C160u)
+#define ERAY0_ESID_1S13 (0xF001C164u)
+#define ERAY0_ESID_1S14 (0xF001C168u)
+#define ERAY0_OSID_1S0 (0xF001C170u)
+#define ERAY0_OSID_1S1 (0xF001C174u)
+#define ERAY0_OSID_1S2 (0xF001C178u)
+#define ERAY0_OSID_1S3 (0xF001C17Cu)
+#define ERAY0_OSID_1S4 (0xF001C180u)
+#define ERAY0_OSID_1S5 (0xF001C184u)
+#define ERAY0_OSID_1S6 (0xF001C188u)
+#define ERAY0_OSID_1S7 (0xF001C18Cu)
+#define ERAY0_OSID_1S8 (0xF001C190u)
+#define ERAY0_OSID_1S9 (0xF001C194u)
+#define ERAY0_OSID_1S10 (0xF001C198u)
+#define ERAY0_OSID_1S11 (0xF001C19Cu)
+#define ERAY0_OSID_1S12 (0xF001C1A0u)
+#define ERAY0_OSID_1S13 (0xF001C1A4u)
+#define ERAY0_OSID_1S14 (0xF001C1A8u)
+#define ERAY0_NMV_1S0 (0xF001C1B0u)
+#define ERAY0_NMV_1S1 (0xF001C1B4u)
+#define ERAY0_NMV_1S2 (0xF001C1B8u)
+#define ERAY0_MRC (0xF001C300u)
+#define ERAY0_FRF (0xF001C304u)
+#define ERAY0_FRFM (0xF001C308u)
+#define ERAY0_FCL (0xF001C30Cu)
+#define ERAY0_MHDS (0xF001C310u)
+#define ERAY0_LDTS (0xF001C314u)
+#define ERAY0_FSR (0xF001C318u)
+#define ERAY0_MHDF (0xF001C31Cu)
+#define ERAY0_TXRQ1 (0xF001C320u)
+#define ERAY0_TXRQ2 (0xF001C324u)
+#define ERAY0_TXRQ3 (0xF001C328u)
+#define ERAY0_TXRQ4 (0xF001C32Cu)
+#define ERAY0_NDAT1 (0xF001C330u)
+#define ERAY0_NDAT2 (0xF001C334u)
+#define ERAY0_NDAT3 (0xF001C338u)
+#define ERAY0_NDAT4 (0xF001C33Cu)
+#define ERAY0_MBSC1 (0xF001C340u)
+#define ERAY0_MBSC2 (0xF001C344u)
+#define ERAY0_MBSC3 (0xF001C348u)
+#define ERAY0_MBSC4 (0xF001C34Cu)
+#define ERAY0_NDIC1 (0xF001C3A8u)
+#define ERAY0_NDIC2 (0xF001C3ACu)
+#define ERAY0_NDIC3 (0xF001C3B0u)
+#define ERAY0_NDIC4 (0xF001C3B4u)
+#define ERAY0_MSIC1 (0xF001C3B8u)
+#define ERAY0_MSIC2 (0xF001C3BCu)
+#define ERAY0_MSIC3 (0xF001C3C0u)
+#define ERAY0_MSIC4 (0xF001C3C4u)
+#define ERAY0_CREL (0xF001C3F0u)
+#define ERAY0_ENDN (0xF001C3F4u)
+#define ERAY0_WRDS_1S0 (0xF001C400u)
+#define ERAY0_WRDS_1S1 (0xF001C404u)
+#define ERAY0_WRDS_1S2 (0xF001C408u)
+#define ERAY0_WRDS_1S3 (0xF001C40Cu)
+#define ERAY0_WRDS_1S4 (0xF001C410u)
+#define ERAY0_WRDS_1S5 (0xF001C414u)
+#define ERAY0_WRDS_1S6 (0xF001C418u)
+#define ERAY0_WRDS_1S7 (0xF001C41Cu)
+#define ERAY0_WRDS_1S8 (0xF001C420u)
+#define ERAY0_WRDS_1S9 (0xF001C424u)
+#define ERAY0_WRDS_1S10 (0xF001C428u)
+#define ERAY0_WRDS_1S11 (0xF001C42Cu)
+#define ERAY0_WRDS_1S12 (0xF001C430u)
+#define ERAY0_WRDS_1S13 (0xF001C434u)
+#define ERAY0_WRDS_1S14 (0xF001C438u)
+#define ERAY0_WRDS_1S15 (0xF001C43Cu)
+#define ERAY0_WRDS_1S16 (0xF001C440u)
+#define ERAY0_WRDS_1S17 (0xF001C444u)
+#define ERAY0_WRDS_1S18 (0xF001C448u)
+#define ERAY0_WRDS_1S19 (0xF001C44Cu)
+#define ERAY0_WRDS_1S20 (0xF001C450u)
+#define MODULE_ERAY0 (0xF001C000u)
+#define ERAY0_WRDS_1S21 (0xF001C454u)
+#define ERAY0_WRDS_1S22 (0xF001C458u)
+#define ERAY0_WRDS_1S23 (0xF001C45Cu)
+#define ERAY0_WRDS_1S24 (0xF001C460u)
+#define ERAY0_WRDS_1S25 (0xF001C464u)
+#define ERAY0_WRDS_1S26 (0xF001C468u)
+#define ERAY0_WRDS_1S27 (0xF001C46Cu)
+#define ERAY0_WRDS_1S28 (0xF001C470u)
+#define ERAY0_WRDS_1S29 (0xF001C474u)
+#define ERAY0_WRDS_1S30 (0xF001C478u)
+#define ERAY0_WRDS_1S31 (0xF001C47Cu)
+#define ERAY0_WRDS_1S32 (0xF001C480u)
+#define ERAY0_WRDS_1S33 (0xF001C484u)
+#define ERAY0_CLC (0xF001C000u)
+#define ERAY0_WRDS_1S34 (0xF001C488u)
+#define ERAY0_WRDS_1S35 (0xF001C48Cu)
+#define ERAY0_WRDS_1S36 (0xF001C490u)
+#define ERAY0_WRDS_1S37 (0xF001C494u)
+#define ERAY0_CUST1 (0xF001C004u)
+#define ERAY0_WRDS_1S38 (0xF001C498u)
+#define ERAY0_WRDS_1S39 (0xF001C49Cu)
+#define ERAY0_WRDS_1S40 (0xF001C4A0u)
+#define ERAY0_WRDS_1S41 (0xF001C4A4u)
+#define ERAY0_ID (0xF001C008u)
+#define ERAY0_WRDS_1S42 (0xF001C4A8u)
+#define ERAY0_WRDS_1S43 (0xF001C4ACu)
+#define ERAY0_WRDS_1S44 (0xF001C4B0u)
+#define ERAY0_WRDS_1S45 (0xF001C4B4u)
+#define ERAY0_CUST3 (0xF001C00Cu)
+#define ERAY0_WRDS_1S46 (0xF001C4B8u)
+#define ERAY0_WRDS_1S47 (0xF001C4BCu)
+#define ERAY0_WRDS_1S48 (0xF001C4C0u)
+#define ERAY0_WRDS_1S49 (0xF001C4C4u)
+#define ERAY0_WRDS_1S50 (0xF001C4C8u)
+#define ERAY0_TEST1 (0xF001C010u)
+#define ERAY0_WRDS_1S51 (0xF001C4CCu)
+#define ERAY0_WRDS_1S52 (0xF001C4D0u)
+#define ERAY0_WRDS_1S53 (0xF001C4D4u)
+#define ERAY0_WRDS_1S54 (0xF001C4D8u)
+#define ERAY0_TEST2 (0xF001C014u)
+#define ERAY0_WRDS_1S55 (0xF001C4DCu)
+#define ERAY0_WRDS_1S56 (0xF001C4E0u)
+#define ERAY0_WRDS_1S57 (0xF001C4E4u)
+#define ERAY0_WRDS_1S58 (0xF001C4E8u)
+#define ERAY0_LCK (0xF001C01Cu)
+#define ERAY0_WRDS_1S59 (0xF001C4ECu)
+#define ERAY0_WRDS_1S60 (0xF001C4F0u)
+#define ERAY0_WRDS_1S61 (0xF001C4F4u)
+#define ERAY0_WRDS_1S62 (0xF001C4F8u)
+#define ERAY0_WRDS_1S63 (0xF001C4FCu)
+#define ERAY0_EIR (0xF001C020u)
+#define ERAY0_WRHS1 (0xF001C500u)
+#define ERAY0_WRHS2 (0xF001C504u)
+#define ERAY0_WRHS3 (0xF001C508u)
+#define ERAY0_IBCM (0xF001C510u)
+#define ERAY0_IBCR (0xF001C514u)
+#define EVADC_G1_Q0_QINR (0xF0020910u)
+#define EVADC_G1_Q0_QBUR (0xF0020914u)
+#define EVADC_ACCPROT0 (0xF0020088u)
+#define EVADC_G1_Q0_REQTM (0xF0020918u)
+#define EVADC_G1_Q0_REQTS (0xF002091Cu)
+#define EVADC_G1_Q1_QCTRL (0xF0020920u)
+#define EVADC_G1_Q1_QMR (0xF0020924u)
+#define EVADC_ACCPROT1 (0xF002008Cu)
+#define EVADC_G1_Q1_QSR (0xF0020928u)
+#define EVADC_G1_Q1_Q0R (0xF002092Cu)
+#define EVADC_G1_Q1_QINR (0xF0020930u)
+#define EVADC_G1_Q1_QBUR (0xF0020934u)
+#define EVADC_ACCPROT2 (0xF0020090u)
+#define EVADC_G1_Q1_REQTM (0xF0020938u)
+#define EVADC_G1_Q1_REQTS (0xF002093Cu)
+#define EVADC_G1_Q2_QCTRL (0xF0020940u)
+#define EVADC_G1_Q2_QMR (0xF0020944u)
+#define EVADC_G1_Q2_QSR (0xF0020948u)
+#define EVADC_GLOB_ICLASS0 (0xF00200A0u)
+#define EVADC_G1_Q2_Q0R (0xF002094Cu)
+#define EVADC_G1_Q2_QINR (0xF0020950u)
+#define EVADC_G1_Q2_QBUR (0xF0020954u)
+#define EVADC_G1_Q2_REQTM (0xF0020958u)
+#define EVADC_G1_Q2_REQTS (0xF002095Cu)
+#define EVADC_G1_CEFLAG (0xF0020980u)
+#define EVADC_G1_REFLAG (0xF0020984u)
+#define EVADC_G1_SEFLAG (0xF0020988u)
+#define EVADC_G1_CEFCLR (0xF0020990u)
+#define EVADC_G1_REFCLR (0xF0020994u)
+#define EVADC_GLOB_ICLASS1 (0xF00200A4u)
+#define EVADC_G1_SEFCLR (0xF0020998u)
+#define EVADC_G1_CEVNP0 (0xF00209A0u)
+#define EVADC_G1_CEVNP1 (0xF00209A4u)
+#define EVADC_G1_REVNP0 (0xF00209B0u)
+#define EVADC_G1_REVNP1 (0xF00209B4u)
+#define EVADC_G1_SEVNP (0xF00209C0u)
+#define EVADC_G1_SRACT (0xF00209C8u)
+#define EVADC_G1_EMUXCTR (0xF00209F0u)
+#define EVADC_G1_EMUXCS (0xF00209F4u)
+#define EVADC_G1_VFR (0xF00209F8u)
+#define EVADC_GLOB_BOUND (0xF00200B8u)
+#define EVADC_G1_CHCTR0 (0xF0020A00u)
+#define EVADC_G1_CHCTR1 (0xF0020A04u)
+#define EVADC_G1_CHCTR2 (0xF0020A08u)
+#define EVADC_G1_CHCTR3 (0xF0020A0Cu)
+#define EVADC_G1_CHCTR4 (0xF0020A10u)
+#define EVADC_G1_CHCTR5 (0xF0020A14u)
+#define EVADC_G1_CHCTR6 (0xF0020A18u)
+#define EVADC_G1_CHCTR7 (0xF0020A1Cu)
+#define EVADC_G1_RCR0 (0xF0020A80u)
+#define EVADC_G1_RCR1 (0xF0020A84u)
+#define EVADC_GLOB_EFLAG (0xF00200E0u)
+#define EVADC_G1_RCR2 (0xF0020A88u)
+#define EVADC_G1_RCR3 (0xF0020A8Cu)
+#define EVADC_G1_RCR4 (0xF0020A90u)
+#define EVADC_G1_RCR5 (0xF0020A94u)
+#define EVADC_G1_RCR6 (0xF0020A98u)
+#define EVADC_G1_RCR7 (0xF0020A9Cu)
+#define EVADC_G1_RCR8 (0xF0020AA0u)
+#define EVADC_G1_RCR9 (0xF0020AA4u)
+#define EVADC_G1_RCR10 (0xF0020AA8u)
+#define EVADC_G1_RCR11 (0xF0020AACu)
+#define EVADC_GLOB_EVNP (0xF0020140u)
+#define EVADC_G1_RCR12 (0xF0020AB0u)
+#define EVADC_G1_RCR13 (0xF0020AB4u)
+#define EVADC_G1_RCR14 (0xF0020AB8u)
+#define EVADC_G1_RCR15 (0xF0020ABCu)
+#define EVADC_G1_RES0 (0xF0020B00u)
+#define EVADC_G1_RES1 (0xF0020B04u)
+#define EVADC_G1_RES2 (0xF0020B08u)
+#define EVADC_G1_RES3 (0xF0020B0Cu)
+#define EVADC_G1_RES4 (0xF0020B10u)
+#define EVADC_G1_RES5 (0xF0020B14u)
+#define EVADC_GLOB_TF (0xF0020160u)
+#define EVADC_G1_RES6 (0xF0020B18u)
+#define EVADC_G1_RES7 (0xF0020B1Cu)
+#define EVADC_G1_RES8 (0xF0020B20u)
+#define EVADC_G1_RES9 (0xF0020B24u)
+#define EVADC_G1_RES10 (0xF0020B28u)
+#define EVADC_G1_RES11 (0xF0020B2Cu)
+#define EVADC_G1_RES12 (0xF0020B30u)
+#define EVADC_G1_RES13 (0xF0020B34u)
+#define EVADC_G1_RES14 (0xF0020B38u)
+#define EVADC_G1_RES15 (0xF0020B3Cu)
+#define EVADC_GLOB_TE (0xF0020164u)
+#define EVADC_G1_RESD0 (0xF0020B80u)
+#define EVADC_G1_RESD1 (0xF0020B84u)
+#define EVADC_G1_RESD2 (0xF0020B88u)
+#define EVADC_G1_RESD3 (0xF0020B8Cu)
+#define EVADC_G1_RESD4 (0xF0020B90u)
+#define EVADC_G1_RESD5 (0xF0020B94u)
+#define EVADC_G1_RESD6 (0xF0020B98u)
+#define EVADC_G1_RESD7 (0xF0020B9Cu)
+#define EVADC_G1_RESD8 (0xF0020BA0u)
+#define EVADC_G1_RESD9 (0xF0020BA4u)
+#define EVADC_GLOB_RCR (0xF0020280u)
+#define EVADC_G1_RESD10 (0xF0020BA8u)
+#define EVADC_G1_RESD11 (0xF0020BACu)
+#define EVADC_G1_RESD12 (0xF0020BB0u)
+#define EVADC_G1_RESD13 (0xF0020BB4u)
+#define EVADC_G1_RESD14 (0xF0020BB8u)
+#define EVADC_G1_RESD15 (0xF0020BBCu)
+#define EVADC_G2_TRCTR (0xF0020C10u)
+#define EVADC_G2_ARBCFG (0xF0020C80u)
+#define EVADC_G2_ARBPR (0xF0020C84u)
+#define EVADC_G2_ANCFG (0xF0020C88u)
+#define EVADC_GLOB_RES (0xF0020300u)
+#define EVADC_G2_ICLASS0 (0xF0020CA0u)
+#define EVADC_G2_ICLASS1 (0xF0020CA4u)
+#define EVADC_G2_ALIAS (0xF0020CB0u)
+#define EVADC_G2_BOUND (0xF0020CB8u)
+#define EVADC_G2_SYNCTR (0xF0020CC0u)
+#define EVADC_G2_Q0_QCTRL (0xF0020D00u)
+#define EVADC_G2_Q0_QMR (0xF0020D04u)
+#define EVADC_G2_Q0_QSR (0xF0020D08u)
+#define EVADC_G2_Q0_Q0R (0xF0020D0Cu)
+#define EVADC_G2_Q0_QINR (0xF0020D10u)
+#define EVADC_GLOB_RESD (0xF0020380u)
+#define EVADC_G2_Q0_QBUR (0xF0020D14u)
+#define EVADC_G2_Q0_REQTM (0xF0020D18u)
+#define EVADC_G2_Q0_REQTS (0xF0020D1Cu)
+#define EVADC_G2_Q1_QCTRL (0xF0020D20u)
+#define EVADC_G2_Q1_QMR (0xF0020D24u)
+#define EVADC_G2_Q1_QSR (0xF0020D28u)
+#define EVADC_G2_Q1_Q0R (0xF0020D2Cu)
+#define EVADC_G2_Q1_QINR (0xF0020D30u)
+#define EVADC_G2_Q1_QBUR (0xF0020D34u)
+#define EVADC_G2_Q1_REQTM (0xF0020D38u)
+#define EVADC_EMUXSEL (0xF00203F0u)
+#define EVADC_G2_Q1_REQTS (0xF0020D3Cu)
+#define EVADC_G2_Q2_QCTRL (0xF0020D40u)
+#define EVADC_G2_Q2_QMR (0xF0020D44u)
+#define EVADC_G2_Q2_QSR (0xF0020D48u)
+#define EVADC_G0_TRCTR (0xF0020410u)
+#define EVADC_G2_Q2_Q0R (0xF0020D4Cu)
+#define EVADC_G2_Q2_QINR (0xF0020D50u)
+#define EVADC_G2_Q2_QBUR (0xF0020D54u)
+#define EVADC_G2_Q2_REQTM (0xF0020D58u)
+#define EVADC_G2_Q2_REQTS (0xF0020D5Cu)
+#define EVADC_G2_CEFLAG (0xF0020D80u)
+#define EVADC_G2_REFLAG (0xF0020D84u)
+#define EVADC_G2_SEFLAG (0xF0020D88u)
+#define EVADC_G2_CEFCLR (0xF0020D90u)
+#define EVADC_G2_REFCLR (0xF0020D94u)
+#define EVADC_G0_ARBCFG (0xF0020480u)
+#define EVADC_G2_SEFCLR (0xF0020D98u)
+#define EVADC_G2_CEVNP0 (0xF0020DA0u)
+#define EVADC_G2_CEVNP1 (0xF0020DA4u)
+#define EVADC_G2_REVNP0 (0xF0020DB0u)
+#define EVADC_G2_REVNP1 (0xF0020DB4u)
+#define EVADC_G2_SEVNP (0xF0020DC0u)
+#define EVADC_G2_SRACT (0xF0020DC8u)
+#define EVADC_G2_EMUXCTR (0xF0020DF0u)
+#define EVADC_G2_EMUXCS (0xF0020DF4u)
+#define EVADC_G2_VFR (0xF0020DF8u)
+#define EVADC_G0_ARBPR (0xF0020484u)
+#define EVADC_G2_CHCTR0 (0xF0020E00u)
+#define EVADC_G2_CHCTR1 (0xF0020E04u)
+#define EVADC_G2_CHCTR2 (0xF0020E08u)
+#define EVADC_G2_CHCTR3 (0xF0020E0Cu)
+#define EVADC_G2_CHCTR4 (0xF0020E10u)
+#define EVADC_G2_CHCTR5 (0xF0020E14u)
+#define EVADC_G2_CHCTR6 (0xF0020E18u)
+#define EVADC_G2_CHCTR7 (0xF0020E1Cu)
+#define EVADC_G2_RCR0 (0xF0020E80u)
+#define EVADC_G2_RCR1 (0xF0020E84u)
+#define EVADC_G0_ANCFG (0xF0020488u)
+#define EVADC_G2_RCR2 (0xF0020E88u)
+#define EVADC_G2_RCR3 (0xF0020E8Cu)
+#define EVADC_G2_RCR4 (0xF0020E90u)
+#define EVADC_G2_RCR5 (0xF0020E94u)
+#define EVADC_G2_RCR6 (0xF0020E98u)
+#define EVADC_G2_RCR7 (0xF0020E9Cu)
+#define EVADC_G2_RCR8 (0xF0020EA0u)
+#define EVADC_G2_RCR9 (0xF0020EA4u)
+#define EVADC_G2_RCR10 (0xF0020EA8u)
+#define EVADC_G2_RCR11 (0xF0020EACu)
+#define EVADC_G0_ICLASS0 (0xF00204A0u)
+#define EVADC_G2_RCR12 (0xF0020EB0u)
+#define EVADC_G2_RCR13 (0xF0020EB4u)
+#define EVADC_G2_RCR14 (0xF0020EB8u)
+#define EVADC_G2_RCR15 (0xF0020EBCu)
+#define EVADC_G2_RES0 (0xF0020F00u)
+#define EVADC_G2_RES1 (0xF0020F04u)
+#define EVADC_G2_RES2 (0xF0020F08u)
+#define EVADC_G2_RES3 (0xF0020F0Cu)
+#define EVADC_G2_RES4 (0xF0020F10u)
+#define EVADC_G2_RES5 (0xF0020F14u)
+#define EVADC_G0_ICLASS1 (0xF00204A4u)
+#define EVADC_G2_RES6 (0xF0020F18u)
+#define EVADC_G2_RES7 (0xF0020F1Cu)
+#define EVADC_G2_RES8 (0xF0020F20u)
+#define EVADC_G2_RES9 (0xF0020F24u)
+#define EVADC_G2_RES10 (0xF0020F28u)
+#define EVADC_G2_RES11 (0xF0020F2Cu)
+#define EVADC_G2_RES12 (0xF0020F30u)
+#define EVADC_G2_RES13 (0xF0020F34u)
+#define EVADC_G2_RES14 (0xF0020F38u)
+#define EVADC_G2_RES15 (0xF0020F3Cu)
+#define EVADC_G0_ALIAS (0xF00204B0u)
+#define EVADC_G2_RESD0 (0xF0020F80u)
+#define EVADC_G2_RESD1 (0xF0020F84u)
+#define EVADC_G2_RESD2 (0xF0020F88u)
+#define EVADC_G2_RESD3 (0xF0020F8Cu)
+#define EVADC_G2_RESD4 (0xF0020F90u)
+#define EVADC_G2_RESD5 (0xF0020F94u)
+#define EVADC_G2_RESD6 (0xF0020F98u)
+#define EVADC_G2_RESD7 (0xF0020F9Cu)
+#define EVADC_G2_RESD8 (0xF0020FA0u)
+#define EVADC_G2_RESD9 (0xF0020FA4u)
+#define EVADC_G0_BOUND (0xF00204B8u)
+#define EVADC_G2_RESD10 (0xF0020FA8u)
+#define EVADC_G2_RESD11 (0xF0020FACu)
+#define EVADC_G2_RESD12 (0xF0020FB0u)
+#define EVADC_G2_RESD13 (0xF0020FB4u)
+#define EVADC_G2_RESD14 (0xF0020FB8u)
+#define EVADC_G2_RESD15 (0xF0020FBCu)
+#define EVADC_G3_TRCTR (0xF0021010u)
+#define EVADC_G3_ARBCFG (0xF0021080u)
+#define EVADC_G3_ARBPR (0xF0021084u)
+#define EVADC_G3_ANCFG (0xF0021088u)
+#define EVADC_G0_SYNCTR (0xF00204C0u)
+#define EVADC_G3_ICLASS0 (0xF00210A0u)
+#define EVADC_G3_ICLASS1 (0xF00210A4u)
+#define EVADC_G3_ALIAS (0xF00210B0u)
+#define EVADC_G3_BOUND (0xF00210B8u)
+#define EVADC_G3_SYNCTR (0xF00210C0u)
+#define EVADC_G3_Q0_QCTRL (0xF0021100u)
+#define EVADC_G3_Q0_QMR (0xF0021104u)
+#define EVADC_G3_Q0_QSR (0xF0021108u)
+#define EVADC_G3_Q0_Q0R (0xF002110Cu)
+#define EVADC_G3_Q0_QINR (0xF0021110u)
+#define EVADC_G0_Q0_QCTRL (0xF0020500u)
+#define EVADC_G3_Q0_QBUR (0xF0021114u)
+#define EVADC_G3_Q0_REQTM (0xF0021118u)
+#define EVADC_G3_Q0_REQTS (0xF002111Cu)
+#define EVADC_G3_Q1_QCTRL (0xF0021120u)
+#define EVADC_G3_Q1_QMR (0xF0021124u)
+#define EVADC_G3_Q1_QSR (0xF0021128u)
+#define EVADC_G3_Q1_Q0R (0xF002112Cu)
+#define EVADC_G3_Q1_QINR (0xF0021130u)
+#define EVADC_G3_Q1_QBUR (0xF0021134u)
+#define EVADC_G3_Q1_REQTM (0xF0021138u)
+#define EVADC_G0_Q0_QMR (0xF0020504u)
+#define EVADC_G3_Q1_REQTS (0xF002113Cu)
+#define EVADC_G3_Q2_QCTRL (0xF0021140u)
+#define EVADC_G3_Q2_QMR (0xF0021144u)
+#define EVADC_G3_Q2_QSR (0xF0021148u)
+#define EVADC_G3_Q2_Q0R (0xF002114Cu)
+#define EVADC_G3_Q2_QINR (0xF0021150u)
+#define EVADC_G3_Q2_QBUR (0xF0021154u)
+#define EVADC_G3_Q2_REQTM (0xF0021158u)
+#define EVADC_G3_Q2_REQTS (0xF002115Cu)
+#define EVADC_G3_CEFLAG (0xF0021180u)
+#define EVADC_G0_Q0_QSR (0xF0020508u)
+#define EVADC_G3_REFLAG (0xF0021184u)
+#define EVADC_G3_SEFLAG (0xF0021188u)
+#define EVADC_G3_CEFCLR (0xF0021190u)
+#define EVADC_G3_REFCLR (0xF0021194u)
+#define EVADC_G3_SEFCLR (0xF0021198u)
+#define EVADC_G3_CEVNP0 (0xF00211A0u)
+#define EVADC_G3_CEVNP1 (0xF00211A4u)
+#define EVADC_G3_REVNP0 (0xF00211B0u)
+#define EVADC_G3_REVNP1 (0xF00211B4u)
+#define EVADC_G3_SEVNP (0xF00211C0u)
+#define EVADC_G0_Q0_Q0R (0xF002050Cu)
+#define EVADC_G3_SRACT (0xF00211C8u)
+#define EVADC_G3_EMUXCTR (0xF00211F0u)
+#define EVADC_G3_EMUXCS (0xF00211F4u)
+#define EVADC_G3_VFR (0xF00211F8u)
+#define EVADC_G3_CHCTR0 (0xF0021200u)
+#define EVADC_G3_CHCTR1 (0xF0021204u)
+#define EVADC_G3_CHCTR2 (0xF0021208u)
+#define EVADC_G3_CHCTR3 (0xF002120Cu)
+#define EVADC_G3_CHCTR4 (0xF0021210u)
+#define EVADC_G3_CHCTR5 (0xF0021214u)
+#define EVADC_G0_Q0_QINR (0xF0020510u)
+#define EVADC_G3_CHCTR6 (0xF0021218u)
+#define EVADC_G3_CHCTR7 (0xF002121Cu)
+#define EVADC_G3_RCR0 (0xF0021280u)
+#define EVADC_G3_RCR1 (0xF0021284u)
+#define EVADC_G3_RCR2 (0xF0021288u)
+#define EVADC_G3_RCR3 (0xF002128Cu)
+#define EVADC_G3_RCR4 (0xF0021290u)
+#define EVADC_G3_RCR5 (0xF0021294u)
+#define EVADC_G3_RCR6 (0xF0021298u)
+#define EVADC_G3_RCR7 (0xF002129Cu)
+#define EVADC_G0_Q0_QBUR (0xF0020514u)
+#define EVADC_G3_RCR8 (0xF00212A0u)
+#define EVADC_G3_RCR9 (0xF00212A4u)
+#define EVADC_G3_RCR10 (0xF00212A8u)
+#define EVADC_G3_RCR11 (0xF00212ACu)
+#define EVADC_G3_RCR12 (0xF00212B0u)
+#define EVADC_G3_RCR13 (0xF00212B4u)
+#define EVADC_G3_RCR14 (0xF00212B8u)
+#define EVADC_G3_RCR15 (0xF00212BCu)
+#define EVADC_G3_RES0 (0xF0021300u)
+#define EVADC_G3_RES1 (0xF0021304u)
+#define EVADC_G0_Q0_REQTM (0xF0020518u)
+#define EVADC_G3_RES2 (0xF0021308u)
+#define EVADC_G3_RES3 (0xF002130Cu)
+#define EVADC_G3_RES4 (0xF0021310u)
+#define EVADC_G3_RES5 (0xF0021314u)
+#define EVADC_G3_RES6 (0xF0021318u)
+#define EVADC_G3_RES7 (0xF002131Cu)
+#define EVADC_G3_RES8 (0xF0021320u)
+#define EVADC_G3_RES9 (0xF0021324u)
+#define EVADC_G3_RES10 (0xF0021328u)
+#define EVADC_G3_RES11 (0xF002132Cu)
+#define EVADC_G0_Q0_REQTS (0xF002051Cu)
+#define EVADC_G3_RES12 (0xF0021330u)
+#define EVADC_G3_RES13 (0xF0021334u)
+#define EVADC_G3_RES14 (0xF0021338u)
+#define EVADC_G3_RES15 (0xF002133Cu)
+#define EVADC_G3_RESD0 (0xF0021380u)
+#define EVADC_G3_RESD1 (0xF0021384u)
+#define EVADC_G3_RESD2 (0xF0021388u)
+#define EVADC_G3_RESD3 (0xF002138Cu)
+#define EVADC_G3_RESD4 (0xF0021390u)
+#define EVADC_G3_RESD5 (0xF0021394u)
+#define EVADC_G0_Q1_QCTRL (0xF0020520u)
+#define EVADC_G3_RESD6 (0xF0021398u)
+#define EVADC_G3_RESD7 (0xF002139Cu)
+#define EVADC_G3_RESD8 (0xF00213A0u)
+#define EVADC_G3_RESD9 (0xF00213A4u)
+#define EVADC_G3_RESD10 (0xF00213A8u)
+#define EVADC_G3_RESD11 (0xF00213ACu)
+#define EVADC_G3_RESD12 (0xF00213B0u)
+#define EVADC_G3_RESD13 (0xF00213B4u)
+#define EVADC_G3_RESD14 (0xF00213B8u)
+#define EVADC_G3_RESD15 (0xF00213BCu)
+#define EVADC_G0_Q1_QMR (0xF0020524u)
+#define EVADC_G4_CEVNP1 (0xF00215A4u)
+#define EVADC_G5_CEVNP1 (0xF00219A4u)
+#define EVADC_G6_CEVNP1 (0xF0021DA4u)
+#define EVADC_G7_CEVNP1 (0xF00221A4u)
+#define EVADC_G8_TRCTR (0xF0022410u)
+#define EVADC_G8_ARBCFG (0xF0022480u)
+#define EVADC_G8_ARBPR (0xF0022484u)
+#define EVADC_G8_ANCFG (0xF0022488u)
+#define EVADC_G8_ICLASS0 (0xF00224A0u)
+#define EVADC_G8_ICLASS1 (0xF00224A4u)
+#define EVADC_G0_Q1_QSR (0xF0020528u)
+#define EVADC_G8_ALIAS (0xF00224B0u)
+#define EVADC_G8_BOUND (0xF00224B8u)
+#define EVADC_G8_SYNCTR (0xF00224C0u)
+#define EVADC_G8_Q0_QCTRL (0xF0022500u)
+#define EVADC_G8_Q0_QMR (0xF0022504u)
+#define EVADC_G8_Q0_QSR (0xF0022508u)
+#define EVADC_G8_Q0_Q0R (0xF002250Cu)
+#define EVADC_G8_Q0_QINR (0xF0022510u)
+#define EVADC_G8_Q0_QBUR (0xF0022514u)
+#define EVADC_G8_Q0_REQTM (0xF0022518u)
+#define EVADC_G0_Q1_Q0R (0xF002052Cu)
+#define EVADC_G8_Q0_REQTS (0xF002251Cu)
+#define EVADC_G8_Q1_QCTRL (0xF0022520u)
+#define EVADC_G8_Q1_QMR (0xF0022524u)
+#define EVADC_G8_Q1_QSR (0xF0022528u)
+#define EVADC_G8_Q1_Q0R (0xF002252Cu)
+#define EVADC_G8_Q1_QINR (0xF0022530u)
+#define EVADC_G8_Q1_QBUR (0xF0022534u)
+#define EVADC_G8_Q1_REQTM (0xF0022538u)
+#define EVADC_G8_Q1_REQTS (0xF002253Cu)
+#define EVADC_G8_Q2_QCTRL (0xF0022540u)
+#define EVADC_G0_Q1_QINR (0xF0020530u)
+#define EVADC_G8_Q2_QMR (0xF0022544u)
+#define EVADC_G8_Q2_QSR (0xF0022548u)
+#define EVADC_G8_Q2_Q0R (0xF002254Cu)
+#define EVADC_G8_Q2_QINR (0xF0022550u)
+#define EVADC_G8_Q2_QBUR (0xF0022554u)
+#define EVADC_G8_Q2_REQTM (0xF0022558u)
+#define EVADC_G8_Q2_REQTS (0xF002255Cu)
+#define EVADC_G8_CEFLAG (0xF0022580u)
+#define EVADC_G8_REFLAG (0xF0022584u)
+#define EVADC_G8_SEFLAG (0xF0022588u)
+#define EVADC_G0_Q1_QBUR (0xF0020534u)
+#define EVADC_G8_CEFCLR (0xF0022590u)
+#define EVADC_G8_REFCLR (0xF0022594u)
+#define EVADC_G8_SEFCLR (0xF0022598u)
+#define EVADC_G8_CEVNP0 (0xF00225A0u)
+#define EVADC_G8_CEVNP1 (0xF00225A4u)
+#define EVADC_G8_REVNP0 (0xF00225B0u)
+#define EVADC_G8_REVNP1 (0xF00225B4u)
+#define EVADC_G8_SEVNP (0xF00225C0u)
+#define EVADC_G8_SRACT (0xF00225C8u)
+#define EVADC_G8_EMUXCTR (0xF00225F0u)
+#define EVADC_G0_Q1_REQTM (0xF0020538u)
+#define EVADC_G8_EMUXCS (0xF00225F4u)
+#define EVADC_G8_VFR (0xF00225F8u)
+#define EVADC_G8_CHCTR0 (0xF0022600u)
+#define EVADC_G8_CHCTR1 (0xF0022604u)
+#define EVADC_G8_CHCTR2 (0xF0022608u)
+#define EVADC_G8_CHCTR3 (0xF002260Cu)
+#define EVADC_G8_CHCTR4 (0xF0022610u)
+#define EVADC_G8_CHCTR5 (0xF0022614u)
+#define EVADC_G8_CHCTR6 (0xF0022618u)
+#define EVADC_G8_CHCTR7 (0xF002261Cu)
+#define EVADC_G0_Q1_REQTS (0xF002053Cu)
+#define EVADC_G8_CHCTR8 (0xF0022620u)
+#define EVADC_G8_CHCTR9 (0xF0022624u)
+#define EVADC_G8_CHCTR10 (0xF0022628u)
+#define EVADC_G8_CHCTR11 (0xF002262Cu)
+#define EVADC_G8_CHCTR12 (0xF0022630u)
+#define EVADC_G8_CHCTR13 (0xF0022634u)
+#define EVADC_G8_CHCTR14 (0xF0022638u)
+#define EVADC_G8_CHCTR15 (0xF002263Cu)
+#define EVADC_G8_RCR0 (0xF0022680u)
+#define EVADC_G8_RCR1 (0xF0022684u)
+#define EVADC_G0_Q2_QCTRL (0xF0020540u)
+#define EVADC_G8_RCR2 (0xF0022688u)
+#define EVADC_G8_RCR3 (0xF002268Cu)
+#define EVADC_G8_RCR4 (0xF0022690u)
+#define EVADC_G8_RCR5 (0xF0022694u)
+#define EVADC_G8_RCR6 (0xF0022698u)
+#define EVADC_G8_RCR7 (0xF002269Cu)
+#define EVADC_G8_RCR8 (0xF00226A0u)
+#define EVADC_G8_RCR9 (0xF00226A4u)
+#define EVADC_G8_RCR10 (0xF00226A8u)
+#define EVADC_G8_RCR11 (0xF00226ACu)
+#define EVADC_G0_Q2_QMR (0xF0020544u)
+#define EVADC_G8_RCR12 (0xF00226B0u)
+#define EVADC_G8_RCR13 (0xF00226B4u)
+#define EVADC_G8_RCR14 (0xF00226B8u)
+#define EVADC_G8_RCR15 (0xF00226BCu)
+#define EVADC_G8_RES0 (0xF0022700u)
+#define EVADC_G8_RES1 (0xF0022704u)
+#define EVADC_G8_RES2 (0xF0022708u)
+#define EVADC_G8_RES3 (0xF002270Cu)
+#define EVADC_G8_RES4 (0xF0022710u)
+#define EVADC_G8_RES5 (0xF0022714u)
+#define EVADC_G0_Q2_QSR (0xF0020548u)
+#define EVADC_G8_RES6 (0xF0022718u)
+#define EVADC_G8_RES7 (0xF002271Cu)
+#define EVADC_G8_RES8 (0xF0022720u)
+#define EVADC_G8_RES9 (0xF0022724u)
+#define EVADC_G8_RES10 (0xF0022728u)
+#define EVADC_G8_RES11 (0xF002272Cu)
+#define EVADC_G8_RES12 (0xF0022730u)
+#define EVADC_G8_RES13 (0xF0022734u)
+#define EVADC_G8_RES14 (0xF0022738u)
+#define EVADC_G8_RES15 (0xF002273Cu)
+#define EVADC_G0_Q2_Q0R (0xF002054Cu)
+#define EVADC_G8_RESD0 (0xF0022780u)
+#define EVADC_G8_RESD1 (0xF0022784u)
+#define EVADC_G8_RESD2 (0xF0022788u)
+#define EVADC_G8_RESD3 (0xF002278Cu)
+#define EVADC_G8_RESD4 (0xF0022790u)
+#define EVADC_G8_RESD5 (0xF0022794u)
+#define EVADC_G8_RESD6 (0xF0022798u)
+#define EVADC_G8_RESD7 (0xF002279Cu)
+#define EVADC_G8_RESD8 (0xF00227A0u)
+#define EVADC_G8_RESD9 (0xF00227A4u)
+#define EVADC_G0_Q2_QINR (0xF0020550u)
+#define EVADC_G8_RESD10 (0xF00227A8u)
+#define EVADC_G8_RESD11 (0xF00227ACu)
+#define EVADC_G8_RESD12 (0xF00227B0u)
+#define EVADC_G8_RESD13 (0xF00227B4u)
+#define EVADC_G8_RESD14 (0xF00227B8u)
+#define EVADC_G8_RESD15 (0xF00227BCu)
+#define EVADC_G9_TRCTR (0xF0022810u)
+#define EVADC_G9_ARBCFG (0xF0022880u)
+#define EVADC_G9_ARBPR (0xF0022884u)
+#define EVADC_G9_ANCFG (0xF0022888u)
+#define EVADC_G0_Q2_QBUR (0xF0020554u)
+#define EVADC_G9_ICLASS0 (0xF00228A0u)
+#define EVADC_G9_ICLASS1 (0xF00228A4u)
+#define EVADC_G9_ALIAS (0xF00228B0u)
+#define EVADC_G9_BOUND (0xF00228B8u)
+#define EVADC_G9_SYNCTR (0xF00228C0u)
+#define EVADC_G9_Q0_QCTRL (0xF0022900u)
+#define EVADC_G9_Q0_QMR (0xF0022904u)
+#define EVADC_G9_Q0_QSR (0xF0022908u)
+#define EVADC_G9_Q0_Q0R (0xF002290Cu)
+#define EVADC_G9_Q0_QINR (0xF0022910u)
+#define EVADC_G0_Q2_REQTM (0xF0020558u)
+#define EVADC_G9_Q0_QBUR (0xF0022914u)
+#define EVADC_G9_Q0_REQTM (0xF0022918u)
+#define EVADC_G9_Q0_REQTS (0xF002291Cu)
+#define EVADC_G9_Q1_QCTRL (0xF0022920u)
+#define EVADC_G9_Q1_QMR (0xF0022924u)
+#define EVADC_G9_Q1_QSR (0xF0022928u)
+#define EVADC_G9_Q1_Q0R (0xF002292Cu)
+#define EVADC_G9_Q1_QINR (0xF0022930u)
+#define EVADC_G9_Q1_QBUR (0xF0022934u)
+#define EVADC_G9_Q1_REQTM (0xF0022938u)
+#define EVADC_G0_Q2_REQTS (0xF002055Cu)
+#define EVADC_G9_Q1_REQTS (0xF002293Cu)
+#define EVADC_G9_Q2_QCTRL (0xF0022940u)
+#define EVADC_G9_Q2_QMR (0xF0022944u)
+#define EVADC_G9_Q2_QSR (0xF0022948u)
+#define EVADC_G9_Q2_Q0R (0xF002294Cu)
+#define EVADC_G9_Q2_QINR (0xF0022950u)
+#define EVADC_G9_Q2_QBUR (0xF0022954u)
+#define EVADC_G9_Q2_REQTM (0xF0022958u)
+#define EVADC_G9_Q2_REQTS (0xF002295Cu)
+#define EVADC_G9_CEFLAG (0xF0022980u)
+#define EVADC_G0_CEFLAG (0xF0020580u)
+#define EVADC_G9_REFLAG (0xF0022984u)
+#define EVADC_G9_SEFLAG (0xF0022988u)
+#define EVADC_G9_CEFCLR (0xF0022990u)
+#define EVADC_G9_REFCLR (0xF0022994u)
+#define EVADC_G9_SEFCLR (0xF0022998u)
+#define EVADC_G9_CEVNP0 (0xF00229A0u)
+#define EVADC_G9_CEVNP1 (0xF00229A4u)
+#define EVADC_G9_REVNP0 (0xF00229B0u)
+#define EVADC_G9_REVNP1 (0xF00229B4u)
+#define EVADC_G9_SEVNP (0xF00229C0u)
+#define EVADC_G0_REFLAG (0xF0020584u)
+#define EVADC_G9_SRACT (0xF00229C8u)
+#define EVADC_G9_EMUXCTR (0xF00229F0u)
+#define EVADC_G9_EMUXCS (0xF00229F4u)
+#define EVADC_G9_VFR (0xF00229F8u)
+#define EVADC_G9_CHCTR0 (0xF0022A00u)
+#define EVADC_G9_CHCTR1 (0xF0022A04u)
+#define EVADC_G9_CHCTR2 (0xF0022A08u)
+#define EVADC_G9_CHCTR3 (0xF0022A0Cu)
+#define EVADC_G9_CHCTR4 (0xF0022A10u)
+#define EVADC_G9_CHCTR5 (0xF0022A14u)
+#define EVADC_G0_SEFLAG (0xF0020588u)
+#define EVADC_G9_CHCTR6 (0xF0022A18u)
+#define EVADC_G9_CHCTR7 (0xF0022A1Cu)
+#define EVADC_G9_CHCTR8 (0xF0022A20u)
+#define EVADC_G9_CHCTR9 (0xF0022A24u)
+#define EVADC_G9_CHCTR10 (0xF0022A28u)
+#define EVADC_G9_CHCTR11 (0xF0022A2Cu)
+#define EVADC_G9_CHCTR12 (0xF0022A30u)
+#define EVADC_G9_CHCTR13 (0xF0022A34u)
+#define EVADC_G9_CHCTR14 (0xF0022A38u)
+#define EVADC_G9_CHCTR15 (0xF0022A3Cu)
+#define EVADC_G0_CEFCLR (0xF0020590u)
+#define EVADC_G9_RCR0 (0xF0022A80u)
+#define EVADC_G9_RCR1 (0xF0022A84u)
+#define EVADC_G9_RCR2 (0xF0022A88u)
+#define EVADC_G9_RCR3 (0xF0022A8Cu)
+#define EVADC_G9_RCR4 (0xF0022A90u)
+#define EVADC_G9_RCR5 (0xF0022A94u)
+#define EVADC_G9_RCR6 (0xF0022A98u)
+#define EVADC_G9_RCR7 (0xF0022A9Cu)
+#define EVADC_G9_RCR8 (0xF0022AA0u)
+#define EVADC_G9_RCR9 (0xF0022AA4u)
+#define EVADC_G0_REFCLR (0xF0020594u)
+#define EVADC_G9_RCR10 (0xF0022AA8u)
+#define EVADC_G9_RCR11 (0xF0022AACu)
+#define EVADC_G9_RCR12 (0xF0022AB0u)
+#define EVADC_G9_RCR13 (0xF0022AB4u)
+#define EVADC_G9_RCR14 (0xF0022AB8u)
+#define EVADC_G9_RCR15 (0xF0022ABCu)
+#define EVADC_G9_RES0 (0xF0022B00u)
+#define EVADC_G9_RES1 (0xF0022B04u)
+#define EVADC_G9_RES2 (0xF0022B08u)
+#define EVADC_G9_RES3 (0xF0022B0Cu)
+#define EVADC_G0_SEFCLR (0xF0020598u)
+#define EVADC_G9_RES4 (0xF0022B10u)
+#define EVADC_G9_RES5 (0xF0022B14u)
+#define EVADC_G9_RES6 (0xF0022B18u)
+#define EVADC_G9_RES7 (0xF0022B1Cu)
+#define EVADC_G9_RES8 (0xF0022B20u)
+#define EVADC_G9_RES9 (0xF0022B24u)
+#define EVADC_G9_RES10 (0xF0022B28u)
+#define EVADC_G9_RES11 (0xF0022B2Cu)
+#define EVADC_G9_RES12 (0xF0022B30u)
+#define EVADC_G9_RES13 (0xF0022B34u)
+#define EVADC_G0_CEVNP0 (0xF00205A0u)
+#define EVADC_G9_RES14 (0xF0022B38u)
+#define EVADC_G9_RES15 (0xF0022B3Cu)
+#define EVADC_G9_RESD0 (0xF0022B80u)
+#define EVADC_G9_RESD1 (0xF0022B84u)
+#define EVADC_G9_RESD2 (0xF0022B88u)
+#define EVADC_G9_RESD3 (0xF0022B8Cu)
+#define EVADC_G9_RESD4 (0xF0022B90u)
+#define EVADC_G9_RESD5 (0xF0022B94u)
+#define EVADC_G9_RESD6 (0xF0022B98u)
+#define EVADC_G9_RESD7 (0xF0022B9Cu)
+#define EVADC_G0_CEVNP1 (0xF00205A4u)
+#define EVADC_G9_RESD8 (0xF0022BA0u)
+#define EVADC_G9_RESD9 (0xF0022BA4u)
+#define EVADC_G9_RESD10 (0xF0022BA8u)
+#define EVADC_G9_RESD11 (0xF0022BACu)
+#define EVADC_G9_RESD12 (0xF0022BB0u)
+#define EVADC_G9_RESD13 (0xF0022BB4u)
+#define EVADC_G9_RESD14 (0xF0022BB8u)
+#define EVADC_G9_RESD15 (0xF0022BBCu)
+#define EVADC_G10_TRCTR (0xF0022C10u)
+#define EVADC_G10_ARBCFG (0xF0022C80u)
+#define EVADC_G0_REVNP0 (0xF00205B0u)
+#define EVADC_G10_ARBPR (0xF0022C84u)
+#define EVADC_G10_ANCFG (0xF0022C88u)
+#define EVADC_G10_ICLASS0 (0xF0022CA0u)
+#define EVADC_G10_ICLASS1 (0xF0022CA4u)
+#define EVADC_G10_ALIAS (0xF0022CB0u)
+#define EVADC_G10_BOUND (0xF0022CB8u)
+#define EVADC_G10_SYNCTR (0xF0022CC0u)
+#define EVADC_G10_Q0_QCTRL (0xF0022D00u)
+#define EVADC_G10_Q0_QMR (0xF0022D04u)
+#define EVADC_G10_Q0_QSR (0xF0022D08u)
+#define EVADC_G0_REVNP1 (0xF00205B4u)
+#define EVADC_G10_Q0_Q0R (0xF0022D0Cu)
+#define EVADC_G10_Q0_QINR (0xF0022D10u)
+#define EVADC_G10_Q0_QBUR (0xF0022D14u)
+#define EVADC_G10_Q0_REQTM (0xF0022D18u)
+#define EVADC_G10_Q0_REQTS (0xF0022D1Cu)
+#define EVADC_G10_Q1_QCTRL (0xF0022D20u)
+#define EVADC_G10_Q1_QMR (0xF0022D24u)
+#define EVADC_G10_Q1_QSR (0xF0022D28u)
+#define EVADC_G10_Q1_Q0R (0xF0022D2Cu)
+#define EVADC_G10_Q1_QINR (0xF0022D30u)
+#define EVADC_G0_SEVNP (0xF00205C0u)
+#define EVADC_G10_Q1_QBUR (0xF0022D34u)
+#define EVADC_G10_Q1_REQTM (0xF0022D38u)
+#define EVADC_G10_Q1_REQTS (0xF0022D3Cu)
+#define EVADC_G10_Q2_QCTRL (0xF0022D40u)
+#define EVADC_G10_Q2_QMR (0xF0022D44u)
+#define EVADC_G10_Q2_QSR (0xF0022D48u)
+#define EVADC_G10_Q2_Q0R (0xF0022D4Cu)
+#define EVADC_G10_Q2_QINR (0xF0022D50u)
+#define EVADC_G10_Q2_QBUR (0xF0022D54u)
+#define EVADC_G10_Q2_REQTM (0xF0022D58u)
+#define EVADC_G0_SRACT (0xF00205C8u)
+#define EVADC_G10_Q2_REQTS (0xF0022D5Cu)
+#define EVADC_G10_CEFLAG (0xF0022D80u)
+#define EVADC_G10_REFLAG (0xF0022D84u)
+#define EVADC_G10_SEFLAG (0xF0022D88u)
+#define EVADC_G10_CEFCLR (0xF0022D90u)
+#define EVADC_G10_REFCLR (0xF0022D94u)
+#define EVADC_G10_SEFCLR (0xF0022D98u)
+#define EVADC_G10_CEVNP0 (0xF0022DA0u)
+#define EVADC_G10_CEVNP1 (0xF0022DA4u)
+#define EVADC_G10_REVNP0 (0xF0022DB0u)
+#define EVADC_G0_EMUXCTR (0xF00205F0u)
+#define EVADC_G10_REVNP1 (0xF0022DB4u)
+#define EVADC_G10_SEVNP (0xF0022DC0u)
+#define EVADC_G10_SRACT (0xF0022DC8u)
+#define EVADC_G10_EMUXCTR (0xF0022DF0u)
+#define EVADC_G10_EMUXCS (0xF0022DF4u)
+#define EVADC_G10_VFR (0xF0022DF8u)
+#define EVADC_G10_CHCTR0 (0xF0022E00u)
+#define EVADC_G10_CHCTR1 (0xF0022E04u)
+#define EVADC_G10_CHCTR2 (0xF0022E08u)
+#define EVADC_G10_CHCTR3 (0xF0022E0Cu)
+#define EVADC_G0_EMUXCS (0xF00205F4u)
+#define EVADC_G10_CHCTR4 (0xF0022E10u)
+#define EVADC_G10_CHCTR5 (0xF0022E14u)
+#define EVADC_G10_CHCTR6 (0xF0022E18u)
+#define EVADC_G10_CHCTR7 (0xF0022E1Cu)
+#define EVADC_G10_CHCTR8 (0xF0022E20u)
+#define EVADC_G10_CHCTR9 (0xF0022E24u)
+#define EVADC_G10_CHCTR10 (0xF0022E28u)
+#define EVADC_G10_CHCTR11 (0xF0022E2Cu)
+#define EVADC_G10_CHCTR12 (0xF0022E30u)
+#define EVADC_G10_CHCTR13 (0xF0022E34u)
+#define EVADC_G0_VFR (0xF00205F8u)
+#define EVADC_G10_CHCTR14 (0xF0022E38u)
+#define EVADC_G10_CHCTR15 (0xF0022E3Cu)
+#define EVADC_G10_RCR0 (0xF0022E80u)
+#define EVADC_G10_RCR1 (0xF0022E84u)
+#define EVADC_G10_RCR2 (0xF0022E88u)
+#define EVADC_G10_RCR3 (0xF0022E8Cu)
+#define EVADC_G10_RCR4 (0xF0022E90u)
+#define EVADC_G10_RCR5 (0xF0022E94u)
+#define EVADC_G10_RCR6 (0xF0022E98u)
+#define EVADC_G10_RCR7 (0xF0022E9Cu)
+#define EVADC_G0_CHCTR0 (0xF0020600u)
+#define EVADC_G10_RCR8 (0xF0022EA0u)
+#define EVADC_G10_RCR9 (0xF0022EA4u)
+#define EVADC_G10_RCR10 (0xF0022EA8u)
+#define EVADC_G10_RCR11 (0xF0022EACu)
+#define EVADC_G10_RCR12 (0xF0022EB0u)
+#define EVADC_G10_RCR13 (0xF0022EB4u)
+#define EVADC_G10_RCR14 (0xF0022EB8u)
+#define EVADC_G10_RCR15 (0xF0022EBCu)
+#define EVADC_G10_RES0 (0xF0022F00u)
+#define EVADC_G10_RES1 (0xF0022F04u)
+#define EVADC_G0_CHCTR1 (0xF0020604u)
+#define EVADC_G10_RES2 (0xF0022F08u)
+#define EVADC_G10_RES3 (0xF0022F0Cu)
+#define EVADC_G10_RES4 (0xF0022F10u)
+#define EVADC_G10_RES5 (0xF0022F14u)
+#define EVADC_G10_RES6 (0xF0022F18u)
+#define EVADC_G10_RES7 (0xF0022F1Cu)
+#define EVADC_G10_RES8 (0xF0022F20u)
+#define EVADC_G10_RES9 (0xF0022F24u)
+#define EVADC_G10_RES10 (0xF0022F28u)
+#define EVADC_G10_RES11 (0xF0022F2Cu)
+#define EVADC_G0_CHCTR2 (0xF0020608u)
+#define EVADC_G10_RES12 (0xF0022F30u)
+#define EVADC_G10_RES13 (0xF0022F34u)
+#define EVADC_G10_RES14 (0xF0022F38u)
+#define EVADC_G10_RES15 (0xF0022F3Cu)
+#define EVADC_G10_RESD0 (0xF0022F80u)
+#define EVADC_G10_RESD1 (0xF0022F84u)
+#define EVADC_G10_RESD2 (0xF0022F88u)
+#define EVADC_G10_RESD3 (0xF0022F8Cu)
+#define EVADC_G10_RESD4 (0xF0022F90u)
+#define EVADC_G10_RESD5 (0xF0022F94u)
+#define EVADC_G0_CHCTR3 (0xF002060Cu)
+#define EVADC_G10_RESD6 (0xF0022F98u)
+#define EVADC_G10_RESD7 (0xF0022F9Cu)
+#define EVADC_G10_RESD8 (0xF0022FA0u)
+#define EVADC_G10_RESD9 (0xF0022FA4u)
+#define EVADC_G10_RESD10 (0xF0022FA8u)
+#define EVADC_G10_RESD11 (0xF0022FACu)
+#define EVADC_G10_RESD12 (0xF0022FB0u)
+#define EVADC_G10_RESD13 (0xF0022FB4u)
+#define EVADC_G10_RESD14 (0xF0022FB8u)
+#define EVADC_G10_RESD15 (0xF0022FBCu)
+#define EVADC_G0_CHCTR4 (0xF0020610u)
+#define EVADC_G11_TRCTR (0xF0023010u)
+#define EVADC_G11_ARBCFG (0xF0023080u)
+#define EVADC_G11_ARBPR (0xF0023084u)
+#define EVADC_G11_ANCFG (0xF0023088u)
+#define EVADC_G11_ICLASS0 (0xF00230A0u)
+#define EVADC_G11_ICLASS1 (0xF00230A4u)
+#define EVADC_G11_ALIAS (0xF00230B0u)
+#define EVADC_G11_BOUND (0xF00230B8u)
+#define EVADC_G11_SYNCTR (0xF00230C0u)
+#define EVADC_G11_Q0_QCTRL (0xF0023100u)
+#define EVADC_G0_CHCTR5 (0xF0020614u)
+#define EVADC_G11_Q0_QMR (0xF0023104u)
+#define EVADC_G11_Q0_QSR (0xF0023108u)
+#define EVADC_G11_Q0_Q0R (0xF002310Cu)
+#define EVADC_G11_Q0_QINR (0xF0023110u)
+#define EVADC_G11_Q0_QBUR (0xF0023114u)
+#define EVADC_G11_Q0_REQTM (0xF0023118u)
+#define EVADC_G11_Q0_REQTS (0xF002311Cu)
+#define EVADC_G11_Q1_QCTRL (0xF0023120u)
+#define EVADC_G11_Q1_QMR (0xF0023124u)
+#define EVADC_G11_Q1_QSR (0xF0023128u)
+#define EVADC_G0_CHCTR6 (0xF0020618u)
+#define EVADC_G11_Q1_Q0R (0xF002312Cu)
+#define EVADC_G11_Q1_QINR (0xF0023130u)
+#define EVADC_G11_Q1_QBUR (0xF0023134u)
+#define EVADC_G11_Q1_REQTM (0xF0023138u)
+#define EVADC_G11_Q1_REQTS (0xF002313Cu)
+#define EVADC_G11_Q2_QCTRL (0xF0023140u)
+#define EVADC_G11_Q2_QMR (0xF0023144u)
+#define EVADC_G11_Q2_QSR (0xF0023148u)
+#define EVADC_G11_Q2_Q0R (0xF002314Cu)
+#define EVADC_G11_Q2_QINR (0xF0023150u)
+#define EVADC_G0_CHCTR7 (0xF002061Cu)
+#define EVADC_G11_Q2_QBUR (0xF0023154u)
+#define EVADC_G11_Q2_REQTM (0xF0023158u)
+#define EVADC_G11_Q2_REQTS (0xF002315Cu)
+#define EVADC_G11_CEFLAG (0xF0023180u)
+#define EVADC_G11_REFLAG (0xF0023184u)
+#define EVADC_G11_SEFLAG (0xF0023188u)
+#define EVADC_G11_CEFCLR (0xF0023190u)
+#define EVADC_G11_REFCLR (0xF0023194u)
+#define EVADC_G11_SEFCLR (0xF0023198u)
+#define EVADC_G11_CEVNP0 (0xF00231A0u)
+#define EVADC_G0_RCR0 (0xF0020680u)
+#define EVADC_G11_CEVNP1 (0xF00231A4u)
+#define EVADC_G11_REVNP0 (0xF00231B0u)
+#define EVADC_G11_REVNP1 (0xF00231B4u)
+#define EVADC_G11_SEVNP (0xF00231C0u)
+#define EVADC_G11_SRACT (0xF00231C8u)
+#define EVADC_G11_EMUXCTR (0xF00231F0u)
+#define EVADC_G11_EMUXCS (0xF00231F4u)
+#define EVADC_G11_VFR (0xF00231F8u)
+#define EVADC_G11_CHCTR0 (0xF0023200u)
+#define EVADC_G11_CHCTR1 (0xF0023204u)
+#define EVADC_G0_RCR1 (0xF0020684u)
+#define EVADC_G11_CHCTR2 (0xF0023208u)
+#define EVADC_G11_CHCTR3 (0xF002320Cu)
+#define EVADC_G11_CHCTR4 (0xF0023210u)
+#define EVADC_G11_CHCTR5 (0xF0023214u)
+#define EVADC_G11_CHCTR6 (0xF0023218u)
+#define EVADC_G11_CHCTR7 (0xF002321Cu)
+#define EVADC_G11_CHCTR8 (0xF0023220u)
+#define EVADC_G11_CHCTR9 (0xF0023224u)
+#define EVADC_G11_CHCTR10 (0xF0023228u)
+#define EVADC_G11_CHCTR11 (0xF002322Cu)
+#define EVADC_G0_RCR2 (0xF0020688u)
+#define EVADC_G11_CHCTR12 (0xF0023230u)
+#define EVADC_G11_CHCTR13 (0xF0023234u)
+#define EVADC_G11_CHCTR14 (0xF0023238u)
+#define EVADC_G11_CHCTR15 (0xF002323Cu)
+#define EVADC_G11_RCR0 (0xF0023280u)
+#define EVADC_G11_RCR1 (0xF0023284u)
+#define EVADC_G11_RCR2 (0xF0023288u)
+#define EVADC_G11_RCR3 (0xF002328Cu)
+#define EVADC_G11_RCR4 (0xF0023290u)
+#define EVADC_G11_RCR5 (0xF0023294u)
+#define EVADC_G0_RCR3 (0xF002068Cu)
+#define EVADC_G11_RCR6 (0xF0023298u)
+#define EVADC_G11_RCR7 (0xF002329Cu)
+#define EVADC_G11_RCR8 (0xF00232A0u)
+#define EVADC_G11_RCR9 (0xF00232A4u)
+#define EVADC_G11_RCR10 (0xF00232A8u)
+#define EVADC_G11_RCR11 (0xF00232ACu)
+#define EVADC_G11_RCR12 (0xF00232B0u)
+#define EVADC_G11_RCR13 (0xF00232B4u)
+#define EVADC_G11_RCR14 (0xF00232B8u)
+#define EVADC_G11_RCR15 (0xF00232BCu)
+#define EVADC_G0_RCR4 (0xF0020690u)
+#define EVADC_G11_RES0 (0xF0023300u)
+#define EVADC_G11_RES1 (0xF0023304u)
+#define EVADC_G11_RES2 (0xF0023308u)
+#define EVADC_G11_RES3 (0xF002330Cu)
+#define EVADC_G11_RES4 (0xF0023310u)
+#define EVADC_G11_RES5 (0xF0023314u)
+#define EVADC_G11_RES6 (0xF0023318u)
+#define EVADC_G11_RES7 (0xF002331Cu)
+#define EVADC_G11_RES8 (0xF0023320u)
+#define EVADC_G11_RES9 (0xF0023324u)
+#define EVADC_G0_RCR5 (0xF0020694u)
+#define EVADC_G11_RES10 (0xF0023328u)
+#define EVADC_G11_RES11 (0xF002332Cu)
+#define EVADC_G11_RES12 (0xF0023330u)
+#define EVADC_G11_RES13 (0xF0023334u)
+#define EVADC_G11_RES14 (0xF0023338u)
+#define EVADC_G11_RES15 (0xF002333Cu)
+#define EVADC_G11_RESD0 (0xF0023380u)
+#define EVADC_G11_RESD1 (0xF0023384u)
+#define EVADC_G11_RESD2 (0xF0023388u)
+#define EVADC_G11_RESD3 (0xF002338Cu)
+#define EVADC_G0_RCR6 (0xF0020698u)
+#define EVADC_G11_RESD4 (0xF0023390u)
+#define EVADC_G11_RESD5 (0xF0023394u)
+#define EVADC_G11_RESD6 (0xF0023398u)
+#define EVADC_G11_RESD7 (0xF002339Cu)
+#define EVADC_G11_RESD8 (0xF00233A0u)
+#define EVADC_G11_RESD9 (0xF00233A4u)
+#define EVADC_G11_RESD10 (0xF00233A8u)
+#define EVADC_G11_RESD11 (0xF00233ACu)
+#define EVADC_G11_RESD12 (0xF00233B0u)
+#define EVADC_G11_RESD13 (0xF00233B4u)
+#define EVADC_G0_RCR7 (0xF002069Cu)
+#define EVADC_G11_RESD14 (0xF00233B8u)
+#define EVADC_G11_RESD15 (0xF00233BCu)
+#define EVADC_FC0_FCCTRL (0xF0023400u)
+#define EVADC_FC0_FCM (0xF0023404u)
+#define EVADC_FC0_FCRAMP0 (0xF0023408u)
+#define EVADC_FC0_FCRAMP1 (0xF002340Cu)
+#define EVADC_FC0_FCBFL (0xF0023420u)
+#define EVADC_FC0_FCHYST (0xF0023424u)
+#define EVADC_FC1_FCCTRL (0xF0023500u)
+#define EVADC_FC1_FCM (0xF0023504u)
+#define EVADC_G0_RCR8 (0xF00206A0u)
+#define EVADC_FC1_FCRAMP0 (0xF0023508u)
+#define EVADC_FC1_FCRAMP1 (0xF002350Cu)
+#define EVADC_FC1_FCBFL (0xF0023520u)
+#define EVADC_FC1_FCHYST (0xF0023524u)
+#define EVADC_FC2_FCCTRL (0xF0023600u)
+#define EVADC_FC2_FCM (0xF0023604u)
+#define EVADC_FC2_FCRAMP0 (0xF0023608u)
+#define EVADC_FC2_FCRAMP1 (0xF002360Cu)
+#define EVADC_FC2_FCBFL (0xF0023620u)
+#define EVADC_FC2_FCHYST (0xF0023624u)
+#define EVADC_G0_RCR9 (0xF00206A4u)
+#define EVADC_FC3_FCCTRL (0xF0023700u)
+#define EVADC_FC3_FCM (0xF0023704u)
+#define EVADC_FC3_FCRAMP0 (0xF0023708u)
+#define EVADC_FC3_FCRAMP1 (0xF002370Cu)
+#define EVADC_FC3_FCBFL (0xF0023720u)
+#define EVADC_FC3_FCHYST (0xF0023724u)
+#define EVADC_G0_RCR10 (0xF00206A8u)
+#define EVADC_G0_RCR11 (0xF00206ACu)
+#define EVADC_G0_RCR12 (0xF00206B0u)
+#define EVADC_G0_RCR13 (0xF00206B4u)
+#define EVADC_G0_RCR14 (0xF00206B8u)
+#define EVADC_G0_RCR15 (0xF00206BCu)
+#define MODULE_EVADC (0xF0020000u)
+#define EVADC_G0_RES0 (0xF0020700u)
+#define EVADC_G0_RES1 (0xF0020704u)
+#define EVADC_G0_RES2 (0xF0020708u)
+#define EVADC_G0_RES3 (0xF002070Cu)
+#define EVADC_G0_RES4 (0xF0020710u)
+#define EVADC_G0_RES5 (0xF0020714u)
+#define EVADC_G0_RES6 (0xF0020718u)
+#define EVADC_G0_RES7 (0xF002071Cu)
+#define EVADC_G0_RES8 (0xF0020720u)
+#define EVADC_G0_RES9 (0xF0020724u)
+#define EVADC_G0_RES10 (0xF0020728u)
+#define EVADC_G0_RES11 (0xF002072Cu)
+#define EVADC_CLC (0xF0020000u)
+#define EVADC_G0_RES12 (0xF0020730u)
+#define EVADC_G0_RES13 (0xF0020734u)
+#define EVADC_G0_RES14 (0xF0020738u)
+#define EVADC_G0_RES15 (0xF002073Cu)
+#define EVADC_G0_RESD0 (0xF0020780u)
+#define EVADC_ID (0xF0020008u)
+#define EVADC_G0_RESD1 (0xF0020784u)
+#define EVADC_G0_RESD2 (0xF0020788u)
+#define EVADC_G0_RESD3 (0xF002078Cu)
+#define EVADC_G0_RESD4 (0xF0020790u)
+#define EVADC_OCS (0xF0020028u)
+#define EVADC_G0_RESD5 (0xF0020794u)
+#define EVADC_G0_RESD6 (0xF0020798u)
+#define EVADC_G0_RESD7 (0xF002079Cu)
+#define EVADC_G0_RESD8 (0xF00207A0u)
+#define EVADC_KRSTCLR (0xF002002Cu)
+#define EVADC_G0_RESD9 (0xF00207A4u)
+#define EVADC_G0_RESD10 (0xF00207A8u)
+#define EVADC_G0_RESD11 (0xF00207ACu)
+#define EVADC_G0_RESD12 (0xF00207B0u)
+#define EVADC_G0_RESD13 (0xF00207B4u)
+#define EVADC_KRST1 (0xF0020030u)
+#define EVADC_G0_RESD14 (0xF00207B8u)
+#define EVADC_G0_RESD15 (0xF00207BCu)
+#define EVADC_G1_TRCTR (0xF0020810u)
+#define EVADC_G1_ARBCFG (0xF0020880u)
+#define EVADC_KRST0 (0xF0020034u)
+#define EVADC_G1_ARBPR (0xF0020884u)
+#define EVADC_G1_ANCFG (0xF0020888u)
+#define EVADC_G1_ICLASS0 (0xF00208A0u)
+#define EVADC_G1_ICLASS1 (0xF00208A4u)
+#define EVADC_ACCEN0 (0xF002003Cu)
+#define EVADC_G1_ALIAS (0xF00208B0u)
+#define EVADC_G1_BOUND (0xF00208B8u)
+#define EVADC_G1_SYNCTR (0xF00208C0u)
+#define EVADC_G1_Q0_QCTRL (0xF0020900u)
+#define EVADC_GLOBCFG (0xF0020080u)
+#define EVADC_G1_Q0_QMR (0xF0020904u)
+#define EVADC_G1_Q0_QSR (0xF0020908u)
+#define EVADC_G1_Q0_Q0R (0xF002090Cu)
+#define FCE_IN0_IR (0xF0000100u)
+#define FCE_IN0_RES (0xF0000104u)
+#define FCE_IN0_CFG (0xF0000108u)
+#define FCE_IN0_STS (0xF000010Cu)
+#define FCE_IN0_LENGTH (0xF0000110u)
+#define FCE_IN0_CHECK (0xF0000114u)
+#define FCE_IN0_CRC (0xF0000118u)
+#define FCE_IN0_CTR (0xF000011Cu)
+#define FCE_IN1_IR (0xF0000120u)
+#define FCE_IN1_RES (0xF0000124u)
+#define FCE_IN1_CFG (0xF0000128u)
+#define FCE_IN1_STS (0xF000012Cu)
+#define FCE_IN1_LENGTH (0xF0000130u)
+#define FCE_IN1_CHECK (0xF0000134u)
+#define FCE_IN1_CRC (0xF0000138u)
+#define FCE_IN1_CTR (0xF000013Cu)
+#define FCE_IN2_IR (0xF0000140u)
+#define FCE_IN2_RES (0xF0000144u)
+#define FCE_IN2_CFG (0xF0000148u)
+#define FCE_IN2_STS (0xF000014Cu)
+#define FCE_IN2_LENGTH (0xF0000150u)
+#define FCE_IN2_CHECK (0xF0000154u)
+#define FCE_IN2_CRC (0xF0000158u)
+#define FCE_IN2_CTR (0xF000015Cu)
+#define FCE_IN3_IR (0xF0000160u)
+#define FCE_IN3_RES (0xF0000164u)
+#define FCE_IN3_CFG (0xF0000168u)
+#define FCE_IN3_STS (0xF000016Cu)
+#define FCE_IN3_LENGTH (0xF0000170u)
+#define FCE_IN3_CHECK (0xF0000174u)
+#define FCE_IN3_CRC (0xF0000178u)
+#define FCE_IN3_CTR (0xF000017Cu)
+#define FCE_IN4_IR (0xF0000180u)
+#define FCE_IN4_RES (0xF0000184u)
+#define FCE_IN4_CFG (0xF0000188u)
+#define FCE_IN4_STS (0xF000018Cu)
+#define FCE_IN4_LENGTH (0xF0000190u)
+#define FCE_IN4_CHECK (0xF0000194u)
+#define FCE_IN4_CRC (0xF0000198u)
+#define FCE_IN4_CTR (0xF000019Cu)
+#define FCE_IN5_IR (0xF00001A0u)
+#define FCE_IN5_RES (0xF00001A4u)
+#define FCE_IN5_CFG (0xF00001A8u)
+#define FCE_IN5_STS (0xF00001ACu)
+#define FCE_IN5_LENGTH (0xF00001B0u)
+#define FCE_IN5_CHECK (0xF00001B4u)
+#define FCE_IN5_CRC (0xF00001B8u)
+#define FCE_IN5_CTR (0xF00001BCu)
+#define FCE_IN6_IR (0xF00001C0u)
+#define FCE_IN6_RES (0xF00001C4u)
+#define FCE_IN6_CFG (0xF00001C8u)
+#define FCE_IN6_STS (0xF00001CCu)
+#define FCE_IN6_LENGTH (0xF00001D0u)
+#define FCE_IN6_CHECK (0xF00001D4u)
+#define FCE_IN6_CRC (0xF00001D8u)
+#define FCE_IN6_CTR (0xF00001DCu)
+#define FCE_IN7_IR (0xF00001E0u)
+#define FCE_IN7_RES (0xF00001E4u)
+#define FCE_IN7_CFG (0xF00001E8u)
+#define FCE_IN7_STS (0xF00001ECu)
+#define FCE_IN7_LENGTH (0xF00001F0u)
+#define FCE_IN7_CHECK (0xF00001F4u)
+#define FCE_IN7_CRC (0xF00001F8u)
+#define FCE_IN7_CTR (0xF00001FCu)
+#define MODULE_FCE (0xF0000000u)
+#define FCE_CLC (0xF0000000u)
+#define FCE_ID (0xF0000008u)
+#define FCE_CHSTS (0xF0000020u)
+#define FCE_KRSTCLR (0xF00000ECu)
+#define FCE_KRST1 (0xF00000F0u)
+#define FCE_KRST0 (0xF00000F4u)
+#define FCE_ACCEN1 (0xF00000F8u)
+#define FCE_ACCEN0 (0xF00000FCu)
+#define MODULE_FSI (0xF8030000u)
+#define FSI_COMM_1 (0xF8030004u)
+#define FSI_COMM_2 (0xF8030005u)
+#define FSI_HSMCOMM_1 (0xF8030006u)
+#define FSI_HSMCOMM_2 (0xF8030007u)
+#define GETH_MAC_TIMESTAMP_INGRESS_CORR_NANOSECOND (0xF001DB58u)
+#define GETH_MAC_TIMESTAMP_EGRESS_CORR_NANOSECOND (0xF001DB5Cu)
+#define GETH_MAC_TIMESTAMP_INGRESS_CORR_SUBNANOSEC (0xF001DB60u)
+#define GETH_MAC_TIMESTAMP_EGRESS_CORR_SUBNANOSEC (0xF001DB64u)
+#define GETH_MAC_PPS_CONTROL (0xF001DB70u)
+#define GETH_MAC_PPS0_TARGET_TIME_SECONDS (0xF001DB80u)
+#define GETH_MAC_PPS0_TARGET_TIME_NANOSECONDS (0xF001DB84u)
+#define GETH_MAC_PPS0_INTERVAL (0xF001DB88u)
+#define GETH_MAC_PPS0_WIDTH (0xF001DB8Cu)
+#define GETH_MTL_OPERATION_MODE (0xF001DC00u)
+#define GETH_MTL_INTERRUPT_STATUS (0xF001DC20u)
+#define GETH_MTL_RXQ_DMA_MAP0 (0xF001DC30u)
+#define GETH_MTL_TXQ0_OPERATION_MODE (0xF001DD00u)
+#define GETH_MTL_TXQ0_UNDERFLOW (0xF001DD04u)
+#define GETH_MTL_TXQ0_DEBUG (0xF001DD08u)
+#define GETH_MTL_TXQ0_ETS_STATUS (0xF001DD14u)
+#define GETH_MTL_TXQ0_QUANTUM_WEIGHT (0xF001DD18u)
+#define GETH_MTL_Q0_INTERRUPT_CONTROL_STATUS (0xF001DD2Cu)
+#define GETH_MTL_RXQ0_OPERATION_MODE (0xF001DD30u)
+#define GETH_MTL_RXQ0_MISSED_PACKET_OVERFLOW_CNT (0xF001DD34u)
+#define GETH_MTL_RXQ0_DEBUG (0xF001DD38u)
+#define GETH_MTL_RXQ0_CONTROL (0xF001DD3Cu)
+#define GETH_MTL_TXQ1_OPERATION_MODE (0xF001DD40u)
+#define GETH_MTL_TXQ1_UNDERFLOW (0xF001DD44u)
+#define GETH_MTL_TXQ1_DEBUG (0xF001DD48u)
+#define GETH_MTL_TXQ1_ETS_CONTROL (0xF001DD50u)
+#define GETH_MTL_TXQ1_ETS_STATUS (0xF001DD54u)
+#define GETH_MTL_TXQ1_QUANTUM_WEIGHT (0xF001DD58u)
+#define GETH_MTL_TXQ1_SENDSLOPECREDIT (0xF001DD5Cu)
+#define GETH_MTL_TXQ1_HICREDIT (0xF001DD60u)
+#define GETH_MTL_TXQ1_LOCREDIT (0xF001DD64u)
+#define GETH_MTL_Q1_INTERRUPT_CONTROL_STATUS (0xF001DD6Cu)
+#define GETH_MTL_RXQ1_OPERATION_MODE (0xF001DD70u)
+#define GETH_MAC_VLAN_HASH_TABLE (0xF001D058u)
+#define GETH_MTL_RXQ1_MISSED_PACKET_OVERFLOW_CNT (0xF001DD74u)
+#define GETH_MTL_RXQ1_DEBUG (0xF001DD78u)
+#define GETH_MTL_RXQ1_CONTROL (0xF001DD7Cu)
+#define GETH_MTL_TXQ2_OPERATION_MODE (0xF001DD80u)
+#define GETH_MTL_TXQ2_UNDERFLOW (0xF001DD84u)
+#define GETH_MTL_TXQ2_DEBUG (0xF001DD88u)
+#define GETH_MTL_TXQ2_ETS_CONTROL (0xF001DD90u)
+#define GETH_MTL_TXQ2_ETS_STATUS (0xF001DD94u)
+#define GETH_MTL_TXQ2_QUANTUM_WEIGHT (0xF001DD98u)
+#define GETH_MTL_TXQ2_SENDSLOPECREDIT (0xF001DD9Cu)
+#define GETH_MAC_VLAN_INCL (0xF001D060u)
+#define GETH_MTL_TXQ2_HICREDIT (0xF001DDA0u)
+#define GETH_MTL_TXQ2_LOCREDIT (0xF001DDA4u)
+#define GETH_MTL_Q2_INTERRUPT_CONTROL_STATUS (0xF001DDACu)
+#define GETH_MTL_RXQ2_OPERATION_MODE (0xF001DDB0u)
+#define GETH_MTL_RXQ2_MISSED_PACKET_OVERFLOW_CNT (0xF001DDB4u)
+#define GETH_MTL_RXQ2_DEBUG (0xF001DDB8u)
+#define GETH_MTL_RXQ2_CONTROL (0xF001DDBCu)
+#define GETH_MTL_TXQ3_OPERATION_MODE (0xF001DDC0u)
+#define GETH_MTL_TXQ3_UNDERFLOW (0xF001DDC4u)
+#define GETH_MTL_TXQ3_DEBUG (0xF001DDC8u)
+#define GETH_MAC_VLAN_INCL_Q (0xF001D060u)
+#define GETH_MTL_TXQ3_ETS_CONTROL (0xF001DDD0u)
+#define GETH_MTL_TXQ3_ETS_STATUS (0xF001DDD4u)
+#define GETH_MTL_TXQ3_QUANTUM_WEIGHT (0xF001DDD8u)
+#define GETH_MTL_TXQ3_SENDSLOPECREDIT (0xF001DDDCu)
+#define GETH_MTL_TXQ3_HICREDIT (0xF001DDE0u)
+#define GETH_MTL_TXQ3_LOCREDIT (0xF001DDE4u)
+#define GETH_MTL_Q3_INTERRUPT_CONTROL_STATUS (0xF001DDECu)
+#define GETH_MTL_RXQ3_OPERATION_MODE (0xF001DDF0u)
+#define GETH_MTL_RXQ3_MISSED_PACKET_OVERFLOW_CNT (0xF001DDF4u)
+#define GETH_MTL_RXQ3_DEBUG (0xF001DDF8u)
+#define GETH_MTL_RXQ3_CONTROL (0xF001DDFCu)
+#define GETH_DMA_MODE (0xF001E000u)
+#define GETH_DMA_SYSBUS_MODE (0xF001E004u)
+#define GETH_DMA_INTERRUPT_STATUS (0xF001E008u)
+#define GETH_DMA_DEBUG_STATUS0 (0xF001E00Cu)
+#define GETH_DMA_DEBUG_STATUS1 (0xF001E010u)
+#define GETH_DMA_CH0_CONTROL (0xF001E100u)
+#define GETH_DMA_CH0_TX_CONTROL (0xF001E104u)
+#define GETH_DMA_CH0_RX_CONTROL (0xF001E108u)
+#define GETH_DMA_CH0_TXDESC_LIST_ADDRESS (0xF001E114u)
+#define GETH_DMA_CH0_RXDESC_LIST_ADDRESS (0xF001E11Cu)
+#define GETH_DMA_CH0_TXDESC_TAIL_POINTER (0xF001E120u)
+#define GETH_DMA_CH0_RXDESC_TAIL_POINTER (0xF001E128u)
+#define GETH_DMA_CH0_TXDESC_RING_LENGTH (0xF001E12Cu)
+#define GETH_DMA_CH0_RXDESC_RING_LENGTH (0xF001E130u)
+#define GETH_DMA_CH0_INTERRUPT_ENABLE (0xF001E134u)
+#define GETH_DMA_CH0_RX_INTERRUPT_WATCHDOG_TIMER (0xF001E138u)
+#define GETH_DMA_CH0_SLOT_FUNCTION_CONTROL_STATUS (0xF001E13Cu)
+#define GETH_DMA_CH0_CURRENT_APP_TXDESC (0xF001E144u)
+#define GETH_DMA_CH0_CURRENT_APP_RXDESC (0xF001E14Cu)
+#define GETH_DMA_CH0_CURRENT_APP_TXBUFFER (0xF001E154u)
+#define GETH_DMA_CH0_CURRENT_APP_RXBUFFER (0xF001E15Cu)
+#define GETH_DMA_CH0_STATUS (0xF001E160u)
+#define GETH_DMA_CH0_MISS_FRAME_CNT (0xF001E164u)
+#define GETH_DMA_CH1_CONTROL (0xF001E180u)
+#define GETH_DMA_CH1_TX_CONTROL (0xF001E184u)
+#define GETH_DMA_CH1_RX_CONTROL (0xF001E188u)
+#define GETH_MAC_INNER_VLAN_INCL (0xF001D064u)
+#define GETH_DMA_CH1_TXDESC_LIST_ADDRESS (0xF001E194u)
+#define GETH_DMA_CH1_RXDESC_LIST_ADDRESS (0xF001E19Cu)
+#define GETH_DMA_CH1_TXDESC_TAIL_POINTER (0xF001E1A0u)
+#define GETH_DMA_CH1_RXDESC_TAIL_POINTER (0xF001E1A8u)
+#define GETH_DMA_CH1_TXDESC_RING_LENGTH (0xF001E1ACu)
+#define GETH_DMA_CH1_RXDESC_RING_LENGTH (0xF001E1B0u)
+#define GETH_DMA_CH1_INTERRUPT_ENABLE (0xF001E1B4u)
+#define GETH_DMA_CH1_RX_INTERRUPT_WATCHDOG_TIMER (0xF001E1B8u)
+#define GETH_DMA_CH1_SLOT_FUNCTION_CONTROL_STATUS (0xF001E1BCu)
+#define GETH_DMA_CH1_CURRENT_APP_TXDESC (0xF001E1C4u)
+#define GETH_DMA_CH1_CURRENT_APP_RXDESC (0xF001E1CCu)
+#define GETH_DMA_CH1_CURRENT_APP_TXBUFFER (0xF001E1D4u)
+#define GETH_DMA_CH1_CURRENT_APP_RXBUFFER (0xF001E1DCu)
+#define GETH_DMA_CH1_STATUS (0xF001E1E0u)
+#define GETH_DMA_CH1_MISS_FRAME_CNT (0xF001E1E4u)
+#define GETH_DMA_CH2_CONTROL (0xF001E200u)
+#define GETH_DMA_CH2_TX_CONTROL (0xF001E204u)
+#define GETH_DMA_CH2_RX_CONTROL (0xF001E208u)
+#define GETH_DMA_CH2_TXDESC_LIST_ADDRESS (0xF001E214u)
+#define GETH_DMA_CH2_RXDESC_LIST_ADDRESS (0xF001E21Cu)
+#define GETH_DMA_CH2_TXDESC_TAIL_POINTER (0xF001E220u)
+#define GETH_DMA_CH2_RXDESC_TAIL_POINTER (0xF001E228u)
+#define GETH_DMA_CH2_TXDESC_RING_LENGTH (0xF001E22Cu)
+#define GETH_DMA_CH2_RXDESC_RING_LENGTH (0xF001E230u)
+#define GETH_DMA_CH2_INTERRUPT_ENABLE (0xF001E234u)
+#define GETH_DMA_CH2_RX_INTERRUPT_WATCHDOG_TIMER (0xF001E238u)
+#define GETH_DMA_CH2_SLOT_FUNCTION_CONTROL_STATUS (0xF001E23Cu)
+#define GETH_DMA_CH2_CURRENT_APP_TXDESC (0xF001E244u)
+#define GETH_DMA_CH2_CURRENT_APP_RXDESC (0xF001E24Cu)
+#define GETH_DMA_CH2_CURRENT_APP_TXBUFFER (0xF001E254u)
+#define GETH_DMA_CH2_CURRENT_APP_RXBUFFER (0xF001E25Cu)
+#define GETH_DMA_CH2_STATUS (0xF001E260u)
+#define GETH_DMA_CH2_MISS_FRAME_CNT (0xF001E264u)
+#define GETH_DMA_CH3_CONTROL (0xF001E280u)
+#define GETH_DMA_CH3_TX_CONTROL (0xF001E284u)
+#define GETH_DMA_CH3_RX_CONTROL (0xF001E288u)
+#define GETH_DMA_CH3_TXDESC_LIST_ADDRESS (0xF001E294u)
+#define GETH_MAC_Q0_TX_FLOW_CTRL (0xF001D070u)
+#define GETH_DMA_CH3_RXDESC_LIST_ADDRESS (0xF001E29Cu)
+#define GETH_DMA_CH3_TXDESC_TAIL_POINTER (0xF001E2A0u)
+#define GETH_DMA_CH3_RXDESC_TAIL_POINTER (0xF001E2A8u)
+#define GETH_DMA_CH3_TXDESC_RING_LENGTH (0xF001E2ACu)
+#define GETH_DMA_CH3_RXDESC_RING_LENGTH (0xF001E2B0u)
+#define GETH_DMA_CH3_INTERRUPT_ENABLE (0xF001E2B4u)
+#define GETH_DMA_CH3_RX_INTERRUPT_WATCHDOG_TIMER (0xF001E2B8u)
+#define GETH_DMA_CH3_SLOT_FUNCTION_CONTROL_STATUS (0xF001E2BCu)
+#define GETH_DMA_CH3_CURRENT_APP_TXDESC (0xF001E2C4u)
+#define GETH_DMA_CH3_CURRENT_APP_RXDESC (0xF001E2CCu)
+#define GETH_MAC_RX_FLOW_CTRL (0xF001D090u)
+#define GETH_DMA_CH3_CURRENT_APP_TXBUFFER (0xF001E2D4u)
+#define GETH_DMA_CH3_CURRENT_APP_RXBUFFER (0xF001E2DCu)
+#define GETH_DMA_CH3_STATUS (0xF001E2E0u)
+#define GETH_DMA_CH3_MISS_FRAME_CNT (0xF001E2E4u)
+#define GETH_CLC (0xF001F000u)
+#define GETH_ID (0xF001F004u)
+#define GETH_GPCTL (0xF001F008u)
+#define GETH_ACCEN0 (0xF001F00Cu)
+#define GETH_ACCEN1 (0xF001F010u)
+#define GETH_KRST0 (0xF001F014u)
+#define GETH_MAC_RXQ_CTRL4 (0xF001D094u)
+#define GETH_KRST1 (0xF001F018u)
+#define GETH_KRSTCLR (0xF001F01Cu)
+#define GETH_ACCEND0_ACCEN0D (0xF001F020u)
+#define GETH_ACCEND0_ACCEN1D (0xF001F024u)
+#define GETH_ACCEND1_ACCEN0D (0xF001F028u)
+#define GETH_ACCEND1_ACCEN1D (0xF001F02Cu)
+#define GETH_MAC_RXQ_CTRL0 (0xF001D0A0u)
+#define GETH_ACCEND2_ACCEN0D (0xF001F030u)
+#define GETH_ACCEND2_ACCEN1D (0xF001F034u)
+#define GETH_ACCEND3_ACCEN0D (0xF001F038u)
+#define GETH_ACCEND3_ACCEN1D (0xF001F03Cu)
+#define GETH_MAC_RXQ_CTRL1 (0xF001D0A4u)
+#define GETH_SKEWCTL (0xF001F040u)
+#define GETH_MAC_RXQ_CTRL2 (0xF001D0A8u)
+#define GETH_MAC_INTERRUPT_STATUS (0xF001D0B0u)
+#define GETH_MAC_INTERRUPT_ENABLE (0xF001D0B4u)
+#define GETH_MAC_RX_TX_STATUS (0xF001D0B8u)
+#define GETH_MAC_PMT_CONTROL_STATUS (0xF001D0C0u)
+#define GETH_MAC_RWK_PACKET_FILTER (0xF001D0C4u)
+#define GETH_RWK_FILTER_COMMAND_0 (0xF001D0C4u)
+#define GETH_RWK_FILTER_OFFSET_0 (0xF001D0C4u)
+#define GETH_RWK_FILTER_CRC (0xF001D0C4u)
+#define GETH_RWK_FILTER_BYTE_MASK (0xF001D0C4u)
+#define GETH_MAC_LPI_CONTROL_STATUS (0xF001D0D0u)
+#define GETH_MAC_LPI_TIMERS_CONTROL (0xF001D0D4u)
+#define GETH_MAC_LPI_ENTRY_TIMER (0xF001D0D8u)
+#define GETH_MAC_1US_TIC_COUNTER (0xF001D0DCu)
+#define GETH_MAC_PHYIF_CONTROL_STATUS (0xF001D0F8u)
+#define GETH_MAC_VERSION (0xF001D110u)
+#define GETH_MAC_DEBUG (0xF001D114u)
+#define GETH_MAC_HW_FEATURE0 (0xF001D11Cu)
+#define GETH_MAC_HW_FEATURE1 (0xF001D120u)
+#define GETH_MAC_HW_FEATURE2 (0xF001D124u)
+#define GETH_MAC_HW_FEATURE3 (0xF001D128u)
+#define GETH_MAC_MDIO_ADDRESS (0xF001D200u)
+#define GETH_MAC_MDIO_DATA (0xF001D204u)
+#define GETH_MAC_CSR_SW_CTRL (0xF001D230u)
+#define GETH_MAC_EXT_CFG1 (0xF001D238u)
+#define GETH_MAC_ADDRESS_HIGH0 (0xF001D300u)
+#define GETH_MAC_ADDRESS_LOW0 (0xF001D304u)
+#define GETH_MAC_ADDRESS_HIGH1 (0xF001D308u)
+#define GETH_MAC_ADDRESS_LOW1 (0xF001D30Cu)
+#define GETH_MAC_ADDRESS_HIGH2 (0xF001D310u)
+#define GETH_MAC_ADDRESS_LOW2 (0xF001D314u)
+#define GETH_MAC_ADDRESS_HIGH3 (0xF001D318u)
+#define GETH_MAC_ADDRESS_LOW3 (0xF001D31Cu)
+#define GETH_MAC_ADDRESS_HIGH4 (0xF001D320u)
+#define GETH_MAC_ADDRESS_LOW4 (0xF001D324u)
+#define GETH_MAC_ADDRESS_HIGH5 (0xF001D328u)
+#define GETH_MAC_ADDRESS_LOW5 (0xF001D32Cu)
+#define GETH_MAC_ADDRESS_HIGH6 (0xF001D330u)
+#define GETH_MAC_ADDRESS_LOW6 (0xF001D334u)
+#define GETH_MAC_ADDRESS_HIGH7 (0xF001D338u)
+#define GETH_MAC_ADDRESS_LOW7 (0xF001D33Cu)
+#define GETH_MAC_ADDRESS_HIGH8 (0xF001D340u)
+#define GETH_MAC_ADDRESS_LOW8 (0xF001D344u)
+#define GETH_MAC_ADDRESS_HIGH9 (0xF001D348u)
+#define GETH_MAC_ADDRESS_LOW9 (0xF001D34Cu)
+#define GETH_MAC_ADDRESS_HIGH10 (0xF001D350u)
+#define GETH_MAC_ADDRESS_LOW10 (0xF001D354u)
+#define GETH_MAC_ADDRESS_HIGH11 (0xF001D358u)
+#define GETH_MAC_ADDRESS_LOW11 (0xF001D35Cu)
+#define GETH_MAC_ADDRESS_HIGH12 (0xF001D360u)
+#define GETH_MAC_ADDRESS_LOW12 (0xF001D364u)
+#define GETH_MAC_ADDRESS_HIGH13 (0xF001D368u)
+#define GETH_MAC_ADDRESS_LOW13 (0xF001D36Cu)
+#define GETH_MAC_ADDRESS_HIGH14 (0xF001D370u)
+#define GETH_MAC_ADDRESS_LOW14 (0xF001D374u)
+#define GETH_MAC_ADDRESS_HIGH15 (0xF001D378u)
+#define GETH_MAC_ADDRESS_LOW15 (0xF001D37Cu)
+#define GETH_MAC_ADDRESS_HIGH16 (0xF001D380u)
+#define GETH_MAC_ADDRESS_LOW16 (0xF001D384u)
+#define GETH_MAC_ADDRESS_HIGH17 (0xF001D388u)
+#define GETH_MAC_ADDRESS_LOW17 (0xF001D38Cu)
+#define GETH_MAC_ADDRESS_HIGH18 (0xF001D390u)
+#define GETH_MAC_ADDRESS_LOW18 (0xF001D394u)
+#define GETH_MAC_ADDRESS_HIGH19 (0xF001D398u)
+#define GETH_MAC_ADDRESS_LOW19 (0xF001D39Cu)
+#define GETH_MAC_ADDRESS_HIGH20 (0xF001D3A0u)
+#define GETH_MAC_ADDRESS_LOW20 (0xF001D3A4u)
+#define GETH_MAC_ADDRESS_HIGH21 (0xF001D3A8u)
+#define GETH_MAC_ADDRESS_LOW21 (0xF001D3ACu)
+#define GETH_MAC_ADDRESS_HIGH22 (0xF001D3B0u)
+#define GETH_MAC_ADDRESS_LOW22 (0xF001D3B4u)
+#define GETH_MAC_ADDRESS_HIGH23 (0xF001D3B8u)
+#define GETH_MAC_ADDRESS_LOW23 (0xF001D3BCu)
+#define GETH_MAC_ADDRESS_HIGH24 (0xF001D3C0u)
+#define GETH_MAC_ADDRESS_LOW24 (0xF001D3C4u)
+#define GETH_MAC_ADDRESS_HIGH25 (0xF001D3C8u)
+#define GETH_MAC_ADDRESS_LOW25 (0xF001D3CCu)
+#define GETH_MAC_ADDRESS_HIGH26 (0xF001D3D0u)
+#define GETH_MAC_ADDRESS_LOW26 (0xF001D3D4u)
+#define GETH_MAC_ADDRESS_HIGH27 (0xF001D3D8u)
+#define GETH_MAC_ADDRESS_LOW27 (0xF001D3DCu)
+#define GETH_MAC_ADDRESS_HIGH28 (0xF001D3E0u)
+#define GETH_MAC_ADDRESS_LOW28 (0xF001D3E4u)
+#define GETH_MAC_ADDRESS_HIGH29 (0xF001D3E8u)
+#define GETH_MAC_ADDRESS_LOW29 (0xF001D3ECu)
+#define GETH_MAC_ADDRESS_HIGH30 (0xF001D3F0u)
+#define GETH_MAC_ADDRESS_LOW30 (0xF001D3F4u)
+#define GETH_MAC_ADDRESS_HIGH31 (0xF001D3F8u)
+#define MODULE_GETH (0xF001D000u)
+#define GETH_MAC_ADDRESS_LOW31 (0xF001D3FCu)
+#define GETH_MMC_CONTROL (0xF001D700u)
+#define GETH_MMC_RX_INTERRUPT (0xF001D704u)
+#define GETH_MMC_TX_INTERRUPT (0xF001D708u)
+#define GETH_MMC_RX_INTERRUPT_MASK (0xF001D70Cu)
+#define GETH_MMC_TX_INTERRUPT_MASK (0xF001D710u)
+#define GETH_TX_OCTET_COUNT_GOOD_BAD (0xF001D714u)
+#define GETH_TX_PACKET_COUNT_GOOD_BAD (0xF001D718u)
+#define GETH_TX_BROADCAST_PACKETS_GOOD (0xF001D71Cu)
+#define GETH_TX_MULTICAST_PACKETS_GOOD (0xF001D720u)
+#define GETH_TX_64OCTETS_PACKETS_GOOD_BAD (0xF001D724u)
+#define GETH_TX_65TO127OCTETS_PACKETS_GOOD_BAD (0xF001D728u)
+#define GETH_TX_128TO255OCTETS_PACKETS_GOOD_BAD (0xF001D72Cu)
+#define GETH_TX_256TO511OCTETS_PACKETS_GOOD_BAD (0xF001D730u)
+#define GETH_TX_512TO1023OCTETS_PACKETS_GOOD_BAD (0xF001D734u)
+#define GETH_TX_1024TOMAXOCTETS_PACKETS_GOOD_BAD (0xF001D738u)
+#define GETH_TX_UNICAST_PACKETS_GOOD_BAD (0xF001D73Cu)
+#define GETH_TX_MULTICAST_PACKETS_GOOD_BAD (0xF001D740u)
+#define GETH_TX_BROADCAST_PACKETS_GOOD_BAD (0xF001D744u)
+#define GETH_TX_UNDERFLOW_ERROR_PACKETS (0xF001D748u)
+#define GETH_TX_SINGLE_COLLISION_GOOD_PACKETS (0xF001D74Cu)
+#define GETH_TX_MULTIPLE_COLLISION_GOOD_PACKETS (0xF001D750u)
+#define GETH_TX_DEFERRED_PACKETS (0xF001D754u)
+#define GETH_TX_LATE_COLLISION_PACKETS (0xF001D758u)
+#define GETH_TX_EXCESSIVE_COLLISION_PACKETS (0xF001D75Cu)
+#define GETH_TX_CARRIER_ERROR_PACKETS (0xF001D760u)
+#define GETH_TX_OCTET_COUNT_GOOD (0xF001D764u)
+#define GETH_TX_PACKET_COUNT_GOOD (0xF001D768u)
+#define GETH_MAC_CONFIGURATION (0xF001D000u)
+#define GETH_TX_EXCESSIVE_DEFERRAL_ERROR (0xF001D76Cu)
+#define GETH_TX_PAUSE_PACKETS (0xF001D770u)
+#define GETH_TX_VLAN_PACKETS_GOOD (0xF001D774u)
+#define GETH_TX_OSIZE_PACKETS_GOOD (0xF001D778u)
+#define GETH_RX_PACKETS_COUNT_GOOD_BAD (0xF001D780u)
+#define GETH_RX_OCTET_COUNT_GOOD_BAD (0xF001D784u)
+#define GETH_RX_OCTET_COUNT_GOOD (0xF001D788u)
+#define GETH_RX_BROADCAST_PACKETS_GOOD (0xF001D78Cu)
+#define GETH_RX_MULTICAST_PACKETS_GOOD (0xF001D790u)
+#define GETH_RX_CRC_ERROR_PACKETS (0xF001D794u)
+#define GETH_MAC_EXT_CONFIGURATION (0xF001D004u)
+#define GETH_RX_ALIGNMENT_ERROR_PACKETS (0xF001D798u)
+#define GETH_RX_RUNT_ERROR_PACKETS (0xF001D79Cu)
+#define GETH_RX_JABBER_ERROR_PACKETS (0xF001D7A0u)
+#define GETH_RX_UNDERSIZE_PACKETS_GOOD (0xF001D7A4u)
+#define GETH_RX_OVERSIZE_PACKETS_GOOD (0xF001D7A8u)
+#define GETH_RX_64OCTETS_PACKETS_GOOD_BAD (0xF001D7ACu)
+#define GETH_RX_65TO127OCTETS_PACKETS_GOOD_BAD (0xF001D7B0u)
+#define GETH_RX_128TO255OCTETS_PACKETS_GOOD_BAD (0xF001D7B4u)
+#define GETH_RX_256TO511OCTETS_PACKETS_GOOD_BAD (0xF001D7B8u)
+#define GETH_RX_512TO1023OCTETS_PACKETS_GOOD_BAD (0xF001D7BCu)
+#define GETH_MAC_PACKET_FILTER (0xF001D008u)
+#define GETH_RX_1024TOMAXOCTETS_PACKETS_GOOD_BAD (0xF001D7C0u)
+#define GETH_RX_UNICAST_PACKETS_GOOD (0xF001D7C4u)
+#define GETH_RX_LENGTH_ERROR_PACKETS (0xF001D7C8u)
+#define GETH_RX_OUT_OF_RANGE_TYPE_PACKETS (0xF001D7CCu)
+#define GETH_RX_PAUSE_PACKETS (0xF001D7D0u)
+#define GETH_RX_FIFO_OVERFLOW_PACKETS (0xF001D7D4u)
+#define GETH_RX_VLAN_PACKETS_GOOD_BAD (0xF001D7D8u)
+#define GETH_RX_WATCHDOG_ERROR_PACKETS (0xF001D7DCu)
+#define GETH_RX_RECEIVE_ERROR_PACKETS (0xF001D7E0u)
+#define GETH_RX_CONTROL_PACKETS_GOOD (0xF001D7E4u)
+#define GETH_MAC_WATCHDOG_TIMEOUT (0xF001D00Cu)
+#define GETH_TX_LPI_USEC_CNTR (0xF001D7ECu)
+#define GETH_TX_LPI_TRAN_CNTR (0xF001D7F0u)
+#define GETH_RX_LPI_USEC_CNTR (0xF001D7F4u)
+#define GETH_RX_LPI_TRAN_CNTR (0xF001D7F8u)
+#define GETH_MMC_IPC_RX_INTERRUPT_MASK (0xF001D800u)
+#define GETH_MMC_IPC_RX_INTERRUPT (0xF001D808u)
+#define GETH_RXIPV4_GOOD_PACKETS (0xF001D810u)
+#define GETH_RXIPV4_HEADER_ERROR_PACKETS (0xF001D814u)
+#define GETH_RXIPV4_NO_PAYLOAD_PACKETS (0xF001D818u)
+#define GETH_RXIPV4_FRAGMENTED_PACKETS (0xF001D81Cu)
+#define GETH_MAC_VLAN_TAG_CTRL (0xF001D050u)
+#define GETH_RXIPV4_UDP_CHECKSUM_DISABLED_PACKETS (0xF001D820u)
+#define GETH_RXIPV6_GOOD_PACKETS (0xF001D824u)
+#define GETH_RXIPV6_HEADER_ERROR_PACKETS (0xF001D828u)
+#define GETH_RXIPV6_NO_PAYLOAD_PACKETS (0xF001D82Cu)
+#define GETH_RXUDP_GOOD_PACKETS (0xF001D830u)
+#define GETH_RXUDP_ERROR_PACKETS (0xF001D834u)
+#define GETH_RXTCP_GOOD_PACKETS (0xF001D838u)
+#define GETH_RXTCP_ERROR_PACKETS (0xF001D83Cu)
+#define GETH_RXICMP_GOOD_PACKETS (0xF001D840u)
+#define GETH_RXICMP_ERROR_PACKETS (0xF001D844u)
+#define GETH_MAC_VLAN_TAG_DATA (0xF001D054u)
+#define GETH_RXIPV4_GOOD_OCTETS (0xF001D850u)
+#define GETH_RXIPV4_HEADER_ERROR_OCTETS (0xF001D854u)
+#define GETH_RXIPV4_NO_PAYLOAD_OCTETS (0xF001D858u)
+#define GETH_RXIPV4_FRAGMENTED_OCTETS (0xF001D85Cu)
+#define GETH_RXIPV4_UDP_CHECKSUM_DISABLE_OCTETS (0xF001D860u)
+#define GETH_RXIPV6_GOOD_OCTETS (0xF001D864u)
+#define GETH_RXIPV6_HEADER_ERROR_OCTETS (0xF001D868u)
+#define GETH_RXIPV6_NO_PAYLOAD_OCTETS (0xF001D86Cu)
+#define GETH_RXUDP_GOOD_OCTETS (0xF001D870u)
+#define GETH_RXUDP_ERROR_OCTETS (0xF001D874u)
+#define GETH_MAC_VLAN_TAG_FILTER (0xF001D054u)
+#define GETH_RXTCP_GOOD_OCTETS (0xF001D878u)
+#define GETH_RXTCP_ERROR_OCTETS (0xF001D87Cu)
+#define GETH_RXICMP_GOOD_OCTETS (0xF001D880u)
+#define GETH_RXICMP_ERROR_OCTETS (0xF001D884u)
+#define GETH_MAC_TIMESTAMP_CONTROL (0xF001DB00u)
+#define GETH_MAC_SUB_SECOND_INCREMENT (0xF001DB04u)
+#define GETH_MAC_SYSTEM_TIME_SECONDS (0xF001DB08u)
+#define GETH_MAC_SYSTEM_TIME_NANOSECONDS (0xF001DB0Cu)
+#define GETH_MAC_SYSTEM_TIME_SECONDS_UPDATE (0xF001DB10u)
+#define GETH_MAC_SYSTEM_TIME_NANOSECONDS_UPDATE (0xF001DB14u)
+#define GETH_MAC_TIMESTAMP_ADDEND (0xF001DB18u)
+#define GETH_MAC_SYSTEM_TIME_HIGHER_WORD_SECONDS (0xF001DB1Cu)
+#define GETH_MAC_TIMESTAMP_STATUS (0xF001DB20u)
+#define GETH_MAC_TX_TIMESTAMP_STATUS_NANOSECONDS (0xF001DB30u)
+#define GETH_MAC_TX_TIMESTAMP_STATUS_SECONDS (0xF001DB34u)
+#define GETH_MAC_TIMESTAMP_INGRESS_ASYM_CORR (0xF001DB50u)
+#define GETH_MAC_TIMESTAMP_EGRESS_ASYM_CORR (0xF001DB54u)
+#define GPT120_CAPREL (0xF0001830u)
+#define GPT120_T2 (0xF0001834u)
+#define GPT120_T3 (0xF0001838u)
+#define GPT120_T4 (0xF000183Cu)
+#define GPT120_T5 (0xF0001840u)
+#define GPT120_T6 (0xF0001844u)
+#define GPT120_OCS (0xF00018E8u)
+#define GPT120_KRSTCLR (0xF00018ECu)
+#define GPT120_KRST1 (0xF00018F0u)
+#define GPT120_KRST0 (0xF00018F4u)
+#define GPT120_ACCEN0 (0xF00018FCu)
+#define MODULE_GPT120 (0xF0001800u)
+#define GPT120_CLC (0xF0001800u)
+#define GPT120_PISEL (0xF0001804u)
+#define GPT120_ID (0xF0001808u)
+#define GPT120_T2CON (0xF0001810u)
+#define GPT120_T3CON (0xF0001814u)
+#define GPT120_T4CON (0xF0001818u)
+#define GPT120_T5CON (0xF000181Cu)
+#define GPT120_T6CON (0xF0001820u)
+#define GTM_ATOM0_CH1_CM0 (0xF01E8090u)
+#define GTM_ATOM0_CH1_CM1 (0xF01E8094u)
+#define GTM_ATOM0_CH1_CN0 (0xF01E8098u)
+#define GTM_ATOM0_CH1_STAT (0xF01E809Cu)
+#define GTM_ATOM0_CH1_IRQ_NOTIFY (0xF01E80A0u)
+#define GTM_ATOM0_CH1_IRQ_EN (0xF01E80A4u)
+#define GTM_ATOM0_CH1_IRQ_FORCINT (0xF01E80A8u)
+#define GTM_ATOM0_CH1_IRQ_MODE (0xF01E80ACu)
+#define GTM_ATOM0_CH2_RDADDR (0xF01E8100u)
+#define GTM_ATOM0_CH2_CTRL (0xF01E8104u)
+#define GTM_ATOM0_CH2_SOMB (0xF01E8104u)
+#define GTM_ATOM0_CH2_SOMC (0xF01E8104u)
+#define GTM_ATOM0_CH2_SOMI (0xF01E8104u)
+#define GTM_ATOM0_CH2_SOMP (0xF01E8104u)
+#define GTM_ATOM0_CH2_SOMS (0xF01E8104u)
+#define GTM_ATOM0_CH2_SR0 (0xF01E8108u)
+#define GTM_ATOM0_CH2_SR1 (0xF01E810Cu)
+#define GTM_ATOM0_CH2_CM0 (0xF01E8110u)
+#define GTM_ATOM0_CH2_CM1 (0xF01E8114u)
+#define GTM_ATOM0_CH2_CN0 (0xF01E8118u)
+#define GTM_ATOM0_CH2_STAT (0xF01E811Cu)
+#define GTM_ATOM0_CH2_IRQ_NOTIFY (0xF01E8120u)
+#define GTM_ATOM0_CH2_IRQ_EN (0xF01E8124u)
+#define GTM_ATOM0_CH2_IRQ_FORCINT (0xF01E8128u)
+#define GTM_ICM_IRQG_CI_ATOM1 (0xF0100794u)
+#define GTM_ATOM0_CH2_IRQ_MODE (0xF01E812Cu)
+#define GTM_ATOM0_CH3_RDADDR (0xF01E8180u)
+#define GTM_ATOM0_CH3_CTRL (0xF01E8184u)
+#define GTM_ATOM0_CH3_SOMB (0xF01E8184u)
+#define GTM_ATOM0_CH3_SOMC (0xF01E8184u)
+#define GTM_ATOM0_CH3_SOMI (0xF01E8184u)
+#define GTM_ATOM0_CH3_SOMP (0xF01E8184u)
+#define GTM_ATOM0_CH3_SOMS (0xF01E8184u)
+#define GTM_ATOM0_CH3_SR0 (0xF01E8188u)
+#define GTM_ATOM0_CH3_SR1 (0xF01E818Cu)
+#define GTM_MCS3_MEM (0xF0150000u)
+#define GTM_ATOM0_CH3_CM0 (0xF01E8190u)
+#define GTM_ATOM0_CH3_CM1 (0xF01E8194u)
+#define GTM_ATOM0_CH3_CN0 (0xF01E8198u)
+#define GTM_ATOM0_CH3_STAT (0xF01E819Cu)
+#define GTM_ATOM0_CH3_IRQ_NOTIFY (0xF01E81A0u)
+#define GTM_ATOM0_CH3_IRQ_EN (0xF01E81A4u)
+#define GTM_ATOM0_CH3_IRQ_FORCINT (0xF01E81A8u)
+#define GTM_ATOM0_CH3_IRQ_MODE (0xF01E81ACu)
+#define GTM_ATOM0_CH4_RDADDR (0xF01E8200u)
+#define GTM_ATOM0_CH4_CTRL (0xF01E8204u)
+#define GTM_ATOM0_CH4_SOMB (0xF01E8204u)
+#define GTM_ATOM0_CH4_SOMC (0xF01E8204u)
+#define GTM_ATOM0_CH4_SOMI (0xF01E8204u)
+#define GTM_ICM_IRQG_CI_TOM0 (0xF01007A0u)
+#define GTM_ATOM0_CH4_SOMP (0xF01E8204u)
+#define GTM_ATOM0_CH4_SOMS (0xF01E8204u)
+#define GTM_ATOM0_CH4_SR0 (0xF01E8208u)
+#define GTM_ATOM0_CH4_SR1 (0xF01E820Cu)
+#define GTM_ATOM0_CH4_CM0 (0xF01E8210u)
+#define GTM_ATOM0_CH4_CM1 (0xF01E8214u)
+#define GTM_ATOM0_CH4_CN0 (0xF01E8218u)
+#define GTM_ATOM0_CH4_STAT (0xF01E821Cu)
+#define GTM_ATOM0_CH4_IRQ_NOTIFY (0xF01E8220u)
+#define GTM_ATOM0_CH4_IRQ_EN (0xF01E8224u)
+#define GTM_ATOM0_CH4_IRQ_FORCINT (0xF01E8228u)
+#define GTM_ATOM0_CH4_IRQ_MODE (0xF01E822Cu)
+#define GTM_ATOM0_CH5_RDADDR (0xF01E8280u)
+#define GTM_ATOM0_CH5_CTRL (0xF01E8284u)
+#define GTM_ATOM0_CH5_SOMB (0xF01E8284u)
+#define GTM_ATOM0_CH5_SOMC (0xF01E8284u)
+#define GTM_ATOM0_CH5_SOMI (0xF01E8284u)
+#define GTM_ATOM0_CH5_SOMP (0xF01E8284u)
+#define GTM_ATOM0_CH5_SOMS (0xF01E8284u)
+#define GTM_ATOM0_CH5_SR0 (0xF01E8288u)
+#define GTM_ATOM0_CH5_SR1 (0xF01E828Cu)
+#define GTM_ATOM0_CH5_CM0 (0xF01E8290u)
+#define GTM_ATOM0_CH5_CM1 (0xF01E8294u)
+#define GTM_ICM_IRQG_CI_TOM1 (0xF01007A4u)
+#define GTM_ATOM0_CH5_CN0 (0xF01E8298u)
+#define GTM_ATOM0_CH5_STAT (0xF01E829Cu)
+#define GTM_ATOM0_CH5_IRQ_NOTIFY (0xF01E82A0u)
+#define GTM_ATOM0_CH5_IRQ_EN (0xF01E82A4u)
+#define GTM_ATOM0_CH5_IRQ_FORCINT (0xF01E82A8u)
+#define GTM_ATOM0_CH5_IRQ_MODE (0xF01E82ACu)
+#define GTM_ATOM0_CH6_RDADDR (0xF01E8300u)
+#define GTM_ATOM0_CH6_CTRL (0xF01E8304u)
+#define GTM_ATOM0_CH6_SOMB (0xF01E8304u)
+#define GTM_ATOM0_CH6_SOMC (0xF01E8304u)
+#define GTM_ATOM0_CH6_SOMI (0xF01E8304u)
+#define GTM_ATOM0_CH6_SOMP (0xF01E8304u)
+#define GTM_ATOM0_CH6_SOMS (0xF01E8304u)
+#define GTM_ATOM0_CH6_SR0 (0xF01E8308u)
+#define GTM_ATOM0_CH6_SR1 (0xF01E830Cu)
+#define GTM_ATOM0_CH6_CM0 (0xF01E8310u)
+#define GTM_ATOM0_CH6_CM1 (0xF01E8314u)
+#define GTM_ATOM0_CH6_CN0 (0xF01E8318u)
+#define GTM_ATOM0_CH6_STAT (0xF01E831Cu)
+#define GTM_ATOM0_CH6_IRQ_NOTIFY (0xF01E8320u)
+#define GTM_ATOM0_CH6_IRQ_EN (0xF01E8324u)
+#define GTM_ATOM0_CH6_IRQ_FORCINT (0xF01E8328u)
+#define GTM_ATOM0_CH6_IRQ_MODE (0xF01E832Cu)
+#define GTM_ATOM0_CH7_RDADDR (0xF01E8380u)
+#define GTM_SPE0_CTRL_STAT (0xF0100800u)
+#define GTM_ATOM0_CH7_CTRL (0xF01E8384u)
+#define GTM_ATOM0_CH7_SOMB (0xF01E8384u)
+#define GTM_ATOM0_CH7_SOMC (0xF01E8384u)
+#define GTM_ATOM0_CH7_SOMI (0xF01E8384u)
+#define GTM_ATOM0_CH7_SOMP (0xF01E8384u)
+#define GTM_ATOM0_CH7_SOMS (0xF01E8384u)
+#define GTM_ATOM0_CH7_SR0 (0xF01E8388u)
+#define GTM_ATOM0_CH7_SR1 (0xF01E838Cu)
+#define GTM_ATOM0_CH7_CM0 (0xF01E8390u)
+#define GTM_ATOM0_CH7_CM1 (0xF01E8394u)
+#define GTM_SPE0_PAT (0xF0100804u)
+#define GTM_ATOM0_CH7_CN0 (0xF01E8398u)
+#define GTM_ATOM0_CH7_STAT (0xF01E839Cu)
+#define GTM_ATOM0_CH7_IRQ_NOTIFY (0xF01E83A0u)
+#define GTM_ATOM0_CH7_IRQ_EN (0xF01E83A4u)
+#define GTM_ATOM0_CH7_IRQ_FORCINT (0xF01E83A8u)
+#define GTM_ATOM0_CH7_IRQ_MODE (0xF01E83ACu)
+#define GTM_ATOM1_CH0_RDADDR (0xF01E8800u)
+#define GTM_ATOM1_CH0_CTRL (0xF01E8804u)
+#define GTM_ATOM1_CH0_SOMB (0xF01E8804u)
+#define GTM_ATOM1_CH0_SOMC (0xF01E8804u)
+#define GTM_SPE0_OUT_PAT0 (0xF0100808u)
+#define GTM_ATOM1_CH0_SOMI (0xF01E8804u)
+#define GTM_ATOM1_CH0_SOMP (0xF01E8804u)
+#define GTM_ATOM1_CH0_SOMS (0xF01E8804u)
+#define GTM_ATOM1_CH0_SR0 (0xF01E8808u)
+#define GTM_ATOM1_CH0_SR1 (0xF01E880Cu)
+#define GTM_ATOM1_CH0_CM0 (0xF01E8810u)
+#define GTM_ATOM1_CH0_CM1 (0xF01E8814u)
+#define GTM_ATOM1_CH0_CN0 (0xF01E8818u)
+#define GTM_ATOM1_CH0_STAT (0xF01E881Cu)
+#define GTM_ATOM1_CH0_IRQ_NOTIFY (0xF01E8820u)
+#define GTM_SPE0_OUT_PAT1 (0xF010080Cu)
+#define GTM_ATOM1_CH0_IRQ_EN (0xF01E8824u)
+#define GTM_ATOM1_CH0_IRQ_FORCINT (0xF01E8828u)
+#define GTM_ATOM1_CH0_IRQ_MODE (0xF01E882Cu)
+#define GTM_ATOM1_AGC_GLB_CTRL (0xF01E8840u)
+#define GTM_ATOM1_AGC_ENDIS_CTRL (0xF01E8844u)
+#define GTM_ATOM1_AGC_ENDIS_STAT (0xF01E8848u)
+#define GTM_ATOM1_AGC_ACT_TB (0xF01E884Cu)
+#define GTM_ATOM1_AGC_OUTEN_CTRL (0xF01E8850u)
+#define GTM_ATOM1_AGC_OUTEN_STAT (0xF01E8854u)
+#define GTM_ATOM1_AGC_FUPD_CTRL (0xF01E8858u)
+#define GTM_SPE0_OUT_PAT2 (0xF0100810u)
+#define GTM_MCS3_MEM1 (0xF0152000u)
+#define GTM_ATOM1_AGC_INT_TRIG (0xF01E885Cu)
+#define GTM_ATOM1_CH1_RDADDR (0xF01E8880u)
+#define GTM_ATOM1_CH1_CTRL (0xF01E8884u)
+#define GTM_ATOM1_CH1_SOMB (0xF01E8884u)
+#define GTM_ATOM1_CH1_SOMC (0xF01E8884u)
+#define GTM_ATOM1_CH1_SOMI (0xF01E8884u)
+#define GTM_ATOM1_CH1_SOMP (0xF01E8884u)
+#define GTM_ATOM1_CH1_SOMS (0xF01E8884u)
+#define GTM_ATOM1_CH1_SR0 (0xF01E8888u)
+#define GTM_ATOM1_CH1_SR1 (0xF01E888Cu)
+#define GTM_SPE0_OUT_PAT3 (0xF0100814u)
+#define GTM_ATOM1_CH1_CM0 (0xF01E8890u)
+#define GTM_ATOM1_CH1_CM1 (0xF01E8894u)
+#define GTM_ATOM1_CH1_CN0 (0xF01E8898u)
+#define GTM_ATOM1_CH1_STAT (0xF01E889Cu)
+#define GTM_ATOM1_CH1_IRQ_NOTIFY (0xF01E88A0u)
+#define GTM_ATOM1_CH1_IRQ_EN (0xF01E88A4u)
+#define GTM_ATOM1_CH1_IRQ_FORCINT (0xF01E88A8u)
+#define GTM_ATOM1_CH1_IRQ_MODE (0xF01E88ACu)
+#define GTM_ATOM1_CH2_RDADDR (0xF01E8900u)
+#define GTM_ATOM1_CH2_CTRL (0xF01E8904u)
+#define GTM_SPE0_OUT_PAT4 (0xF0100818u)
+#define GTM_ATOM1_CH2_SOMB (0xF01E8904u)
+#define GTM_ATOM1_CH2_SOMC (0xF01E8904u)
+#define GTM_ATOM1_CH2_SOMI (0xF01E8904u)
+#define GTM_ATOM1_CH2_SOMP (0xF01E8904u)
+#define GTM_ATOM1_CH2_SOMS (0xF01E8904u)
+#define GTM_ATOM1_CH2_SR0 (0xF01E8908u)
+#define GTM_ATOM1_CH2_SR1 (0xF01E890Cu)
+#define GTM_ATOM1_CH2_CM0 (0xF01E8910u)
+#define GTM_ATOM1_CH2_CM1 (0xF01E8914u)
+#define GTM_ATOM1_CH2_CN0 (0xF01E8918u)
+#define GTM_SPE0_OUT_PAT5 (0xF010081Cu)
+#define GTM_ATOM1_CH2_STAT (0xF01E891Cu)
+#define GTM_ATOM1_CH2_IRQ_NOTIFY (0xF01E8920u)
+#define GTM_ATOM1_CH2_IRQ_EN (0xF01E8924u)
+#define GTM_ATOM1_CH2_IRQ_FORCINT (0xF01E8928u)
+#define GTM_ATOM1_CH2_IRQ_MODE (0xF01E892Cu)
+#define GTM_ATOM1_CH3_RDADDR (0xF01E8980u)
+#define GTM_ATOM1_CH3_CTRL (0xF01E8984u)
+#define GTM_ATOM1_CH3_SOMB (0xF01E8984u)
+#define GTM_ATOM1_CH3_SOMC (0xF01E8984u)
+#define GTM_ATOM1_CH3_SOMI (0xF01E8984u)
+#define GTM_SPE0_OUT_PAT6 (0xF0100820u)
+#define GTM_ATOM1_CH3_SOMP (0xF01E8984u)
+#define GTM_ATOM1_CH3_SOMS (0xF01E8984u)
+#define GTM_ATOM1_CH3_SR0 (0xF01E8988u)
+#define GTM_ATOM1_CH3_SR1 (0xF01E898Cu)
+#define GTM_ATOM1_CH3_CM0 (0xF01E8990u)
+#define GTM_ATOM1_CH3_CM1 (0xF01E8994u)
+#define GTM_ATOM1_CH3_CN0 (0xF01E8998u)
+#define GTM_ATOM1_CH3_STAT (0xF01E899Cu)
+#define GTM_ATOM1_CH3_IRQ_NOTIFY (0xF01E89A0u)
+#define GTM_ATOM1_CH3_IRQ_EN (0xF01E89A4u)
+#define GTM_SPE0_OUT_PAT7 (0xF0100824u)
+#define GTM_ATOM1_CH3_IRQ_FORCINT (0xF01E89A8u)
+#define GTM_ATOM1_CH3_IRQ_MODE (0xF01E89ACu)
+#define GTM_ATOM1_CH4_RDADDR (0xF01E8A00u)
+#define GTM_ATOM1_CH4_CTRL (0xF01E8A04u)
+#define GTM_ATOM1_CH4_SOMB (0xF01E8A04u)
+#define GTM_ATOM1_CH4_SOMC (0xF01E8A04u)
+#define GTM_ATOM1_CH4_SOMI (0xF01E8A04u)
+#define GTM_ATOM1_CH4_SOMP (0xF01E8A04u)
+#define GTM_ATOM1_CH4_SOMS (0xF01E8A04u)
+#define GTM_ATOM1_CH4_SR0 (0xF01E8A08u)
+#define GTM_SPE0_OUT_CTRL (0xF0100828u)
+#define GTM_ATOM1_CH4_SR1 (0xF01E8A0Cu)
+#define GTM_ATOM1_CH4_CM0 (0xF01E8A10u)
+#define GTM_ATOM1_CH4_CM1 (0xF01E8A14u)
+#define GTM_ATOM1_CH4_CN0 (0xF01E8A18u)
+#define GTM_ATOM1_CH4_STAT (0xF01E8A1Cu)
+#define GTM_ATOM1_CH4_IRQ_NOTIFY (0xF01E8A20u)
+#define GTM_ATOM1_CH4_IRQ_EN (0xF01E8A24u)
+#define GTM_ATOM1_CH4_IRQ_FORCINT (0xF01E8A28u)
+#define GTM_ATOM1_CH4_IRQ_MODE (0xF01E8A2Cu)
+#define GTM_ATOM1_CH5_RDADDR (0xF01E8A80u)
+#define GTM_SPE0_IRQ_NOTIFY (0xF010082Cu)
+#define GTM_ATOM1_CH5_CTRL (0xF01E8A84u)
+#define GTM_ATOM1_CH5_SOMB (0xF01E8A84u)
+#define GTM_ATOM1_CH5_SOMC (0xF01E8A84u)
+#define GTM_ATOM1_CH5_SOMI (0xF01E8A84u)
+#define GTM_ATOM1_CH5_SOMP (0xF01E8A84u)
+#define GTM_ATOM1_CH5_SOMS (0xF01E8A84u)
+#define GTM_ATOM1_CH5_SR0 (0xF01E8A88u)
+#define GTM_ATOM1_CH5_SR1 (0xF01E8A8Cu)
+#define GTM_ATOM1_CH5_CM0 (0xF01E8A90u)
+#define GTM_ATOM1_CH5_CM1 (0xF01E8A94u)
+#define GTM_SPE0_IRQ_EN (0xF0100830u)
+#define GTM_ATOM1_CH5_CN0 (0xF01E8A98u)
+#define GTM_ATOM1_CH5_STAT (0xF01E8A9Cu)
+#define GTM_ATOM1_CH5_IRQ_NOTIFY (0xF01E8AA0u)
+#define GTM_ATOM1_CH5_IRQ_EN (0xF01E8AA4u)
+#define GTM_ATOM1_CH5_IRQ_FORCINT (0xF01E8AA8u)
+#define GTM_ATOM1_CH5_IRQ_MODE (0xF01E8AACu)
+#define GTM_ATOM1_CH6_RDADDR (0xF01E8B00u)
+#define GTM_ATOM1_CH6_CTRL (0xF01E8B04u)
+#define GTM_ATOM1_CH6_SOMB (0xF01E8B04u)
+#define GTM_ATOM1_CH6_SOMC (0xF01E8B04u)
+#define GTM_SPE0_IRQ_FORCINT (0xF0100834u)
+#define GTM_ATOM1_CH6_SOMI (0xF01E8B04u)
+#define GTM_ATOM1_CH6_SOMP (0xF01E8B04u)
+#define GTM_ATOM1_CH6_SOMS (0xF01E8B04u)
+#define GTM_ATOM1_CH6_SR0 (0xF01E8B08u)
+#define GTM_ATOM1_CH6_SR1 (0xF01E8B0Cu)
+#define GTM_ATOM1_CH6_CM0 (0xF01E8B10u)
+#define GTM_ATOM1_CH6_CM1 (0xF01E8B14u)
+#define GTM_ATOM1_CH6_CN0 (0xF01E8B18u)
+#define GTM_ATOM1_CH6_STAT (0xF01E8B1Cu)
+#define GTM_ATOM1_CH6_IRQ_NOTIFY (0xF01E8B20u)
+#define GTM_SPE0_IRQ_MODE (0xF0100838u)
+#define GTM_MCS4_MEM (0xF0158000u)
+#define GTM_ATOM1_CH6_IRQ_EN (0xF01E8B24u)
+#define GTM_ATOM1_CH6_IRQ_FORCINT (0xF01E8B28u)
+#define GTM_ATOM1_CH6_IRQ_MODE (0xF01E8B2Cu)
+#define GTM_ATOM1_CH7_RDADDR (0xF01E8B80u)
+#define GTM_ATOM1_CH7_CTRL (0xF01E8B84u)
+#define GTM_ATOM1_CH7_SOMB (0xF01E8B84u)
+#define GTM_ATOM1_CH7_SOMC (0xF01E8B84u)
+#define GTM_ATOM1_CH7_SOMI (0xF01E8B84u)
+#define GTM_ATOM1_CH7_SOMP (0xF01E8B84u)
+#define GTM_ATOM1_CH7_SOMS (0xF01E8B84u)
+#define GTM_SPE0_EIRQ_EN (0xF010083Cu)
+#define GTM_ATOM1_CH7_SR0 (0xF01E8B88u)
+#define GTM_ATOM1_CH7_SR1 (0xF01E8B8Cu)
+#define GTM_ATOM1_CH7_CM0 (0xF01E8B90u)
+#define GTM_ATOM1_CH7_CM1 (0xF01E8B94u)
+#define GTM_ATOM1_CH7_CN0 (0xF01E8B98u)
+#define GTM_ATOM1_CH7_STAT (0xF01E8B9Cu)
+#define GTM_ATOM1_CH7_IRQ_NOTIFY (0xF01E8BA0u)
+#define GTM_ATOM1_CH7_IRQ_EN (0xF01E8BA4u)
+#define GTM_ATOM1_CH7_IRQ_FORCINT (0xF01E8BA8u)
+#define GTM_ATOM1_CH7_IRQ_MODE (0xF01E8BACu)
+#define GTM_SPE0_REV_CNT (0xF0100840u)
+#define GTM_ATOM2_CH0_RDADDR (0xF01E9000u)
+#define GTM_ATOM2_CH0_CTRL (0xF01E9004u)
+#define GTM_ATOM2_CH0_SOMB (0xF01E9004u)
+#define GTM_ATOM2_CH0_SOMC (0xF01E9004u)
+#define GTM_ATOM2_CH0_SOMI (0xF01E9004u)
+#define GTM_ATOM2_CH0_SOMP (0xF01E9004u)
+#define GTM_ATOM2_CH0_SOMS (0xF01E9004u)
+#define GTM_ATOM2_CH0_SR0 (0xF01E9008u)
+#define GTM_ATOM2_CH0_SR1 (0xF01E900Cu)
+#define GTM_ATOM2_CH0_CM0 (0xF01E9010u)
+#define GTM_SPE0_REV_CMP (0xF0100844u)
+#define GTM_ATOM2_CH0_CM1 (0xF01E9014u)
+#define GTM_ATOM2_CH0_CN0 (0xF01E9018u)
+#define GTM_ATOM2_CH0_STAT (0xF01E901Cu)
+#define GTM_ATOM2_CH0_IRQ_NOTIFY (0xF01E9020u)
+#define GTM_ATOM2_CH0_IRQ_EN (0xF01E9024u)
+#define GTM_ATOM2_CH0_IRQ_FORCINT (0xF01E9028u)
+#define GTM_ATOM2_CH0_IRQ_MODE (0xF01E902Cu)
+#define GTM_ATOM2_AGC_GLB_CTRL (0xF01E9040u)
+#define GTM_ATOM2_AGC_ENDIS_CTRL (0xF01E9044u)
+#define GTM_ATOM2_AGC_ENDIS_STAT (0xF01E9048u)
+#define GTM_SPE0_CTRL_STAT2 (0xF0100848u)
+#define GTM_ATOM2_AGC_ACT_TB (0xF01E904Cu)
+#define GTM_ATOM2_AGC_OUTEN_CTRL (0xF01E9050u)
+#define GTM_ATOM2_AGC_OUTEN_STAT (0xF01E9054u)
+#define GTM_ATOM2_AGC_FUPD_CTRL (0xF01E9058u)
+#define GTM_ATOM2_AGC_INT_TRIG (0xF01E905Cu)
+#define GTM_ATOM2_CH1_RDADDR (0xF01E9080u)
+#define GTM_ATOM2_CH1_CTRL (0xF01E9084u)
+#define GTM_ATOM2_CH1_SOMB (0xF01E9084u)
+#define GTM_ATOM2_CH1_SOMC (0xF01E9084u)
+#define GTM_ATOM2_CH1_SOMI (0xF01E9084u)
+#define GTM_SPE0_CMD (0xF010084Cu)
+#define GTM_ATOM2_CH1_SOMP (0xF01E9084u)
+#define GTM_ATOM2_CH1_SOMS (0xF01E9084u)
+#define GTM_ATOM2_CH1_SR0 (0xF01E9088u)
+#define GTM_ATOM2_CH1_SR1 (0xF01E908Cu)
+#define GTM_ATOM2_CH1_CM0 (0xF01E9090u)
+#define GTM_ATOM2_CH1_CM1 (0xF01E9094u)
+#define GTM_ATOM2_CH1_CN0 (0xF01E9098u)
+#define GTM_ATOM2_CH1_STAT (0xF01E909Cu)
+#define GTM_ATOM2_CH1_IRQ_NOTIFY (0xF01E90A0u)
+#define GTM_ATOM2_CH1_IRQ_EN (0xF01E90A4u)
+#define GTM_SPE1_CTRL_STAT (0xF0100880u)
+#define GTM_ATOM2_CH1_IRQ_FORCINT (0xF01E90A8u)
+#define GTM_ATOM2_CH1_IRQ_MODE (0xF01E90ACu)
+#define GTM_ATOM2_CH2_RDADDR (0xF01E9100u)
+#define GTM_ATOM2_CH2_CTRL (0xF01E9104u)
+#define GTM_ATOM2_CH2_SOMB (0xF01E9104u)
+#define GTM_ATOM2_CH2_SOMC (0xF01E9104u)
+#define GTM_ATOM2_CH2_SOMI (0xF01E9104u)
+#define GTM_ATOM2_CH2_SOMP (0xF01E9104u)
+#define GTM_ATOM2_CH2_SOMS (0xF01E9104u)
+#define GTM_ATOM2_CH2_SR0 (0xF01E9108u)
+#define GTM_SPE1_PAT (0xF0100884u)
+#define GTM_ATOM2_CH2_SR1 (0xF01E910Cu)
+#define GTM_ATOM2_CH2_CM0 (0xF01E9110u)
+#define GTM_ATOM2_CH2_CM1 (0xF01E9114u)
+#define GTM_ATOM2_CH2_CN0 (0xF01E9118u)
+#define GTM_ATOM2_CH2_STAT (0xF01E911Cu)
+#define GTM_ATOM2_CH2_IRQ_NOTIFY (0xF01E9120u)
+#define GTM_ATOM2_CH2_IRQ_EN (0xF01E9124u)
+#define GTM_ATOM2_CH2_IRQ_FORCINT (0xF01E9128u)
+#define GTM_ATOM2_CH2_IRQ_MODE (0xF01E912Cu)
+#define GTM_ATOM2_CH3_RDADDR (0xF01E9180u)
+#define GTM_SPE1_OUT_PAT0 (0xF0100888u)
+#define GTM_ATOM2_CH3_CTRL (0xF01E9184u)
+#define GTM_ATOM2_CH3_SOMB (0xF01E9184u)
+#define GTM_ATOM2_CH3_SOMC (0xF01E9184u)
+#define GTM_ATOM2_CH3_SOMI (0xF01E9184u)
+#define GTM_ATOM2_CH3_SOMP (0xF01E9184u)
+#define GTM_ATOM2_CH3_SOMS (0xF01E9184u)
+#define GTM_ATOM2_CH3_SR0 (0xF01E9188u)
+#define GTM_ATOM2_CH3_SR1 (0xF01E918Cu)
+#define GTM_ATOM2_CH3_CM0 (0xF01E9190u)
+#define GTM_ATOM2_CH3_CM1 (0xF01E9194u)
+#define GTM_SPE1_OUT_PAT1 (0xF010088Cu)
+#define GTM_ATOM2_CH3_CN0 (0xF01E9198u)
+#define GTM_ATOM2_CH3_STAT (0xF01E919Cu)
+#define GTM_ATOM2_CH3_IRQ_NOTIFY (0xF01E91A0u)
+#define GTM_ATOM2_CH3_IRQ_EN (0xF01E91A4u)
+#define GTM_ATOM2_CH3_IRQ_FORCINT (0xF01E91A8u)
+#define GTM_ATOM2_CH3_IRQ_MODE (0xF01E91ACu)
+#define GTM_ATOM2_CH4_RDADDR (0xF01E9200u)
+#define GTM_ATOM2_CH4_CTRL (0xF01E9204u)
+#define GTM_ATOM2_CH4_SOMB (0xF01E9204u)
+#define GTM_ATOM2_CH4_SOMC (0xF01E9204u)
+#define GTM_SPE1_OUT_PAT2 (0xF0100890u)
+#define GTM_MCS4_MEM1 (0xF015A000u)
+#define GTM_ATOM2_CH4_SOMI (0xF01E9204u)
+#define GTM_ATOM2_CH4_SOMP (0xF01E9204u)
+#define GTM_ATOM2_CH4_SOMS (0xF01E9204u)
+#define GTM_ATOM2_CH4_SR0 (0xF01E9208u)
+#define GTM_ATOM2_CH4_SR1 (0xF01E920Cu)
+#define GTM_ATOM2_CH4_CM0 (0xF01E9210u)
+#define GTM_ATOM2_CH4_CM1 (0xF01E9214u)
+#define GTM_ATOM2_CH4_CN0 (0xF01E9218u)
+#define GTM_ATOM2_CH4_STAT (0xF01E921Cu)
+#define GTM_ATOM2_CH4_IRQ_NOTIFY (0xF01E9220u)
+#define GTM_SPE1_OUT_PAT3 (0xF0100894u)
+#define GTM_ATOM2_CH4_IRQ_EN (0xF01E9224u)
+#define GTM_ATOM2_CH4_IRQ_FORCINT (0xF01E9228u)
+#define GTM_ATOM2_CH4_IRQ_MODE (0xF01E922Cu)
+#define GTM_ATOM2_CH5_RDADDR (0xF01E9280u)
+#define GTM_ATOM2_CH5_CTRL (0xF01E9284u)
+#define GTM_ATOM2_CH5_SOMB (0xF01E9284u)
+#define GTM_ATOM2_CH5_SOMC (0xF01E9284u)
+#define GTM_ATOM2_CH5_SOMI (0xF01E9284u)
+#define GTM_ATOM2_CH5_SOMP (0xF01E9284u)
+#define GTM_ATOM2_CH5_SOMS (0xF01E9284u)
+#define GTM_SPE1_OUT_PAT4 (0xF0100898u)
+#define GTM_ATOM2_CH5_SR0 (0xF01E9288u)
+#define GTM_ATOM2_CH5_SR1 (0xF01E928Cu)
+#define GTM_ATOM2_CH5_CM0 (0xF01E9290u)
+#define GTM_ATOM2_CH5_CM1 (0xF01E9294u)
+#define GTM_ATOM2_CH5_CN0 (0xF01E9298u)
+#define GTM_ATOM2_CH5_STAT (0xF01E929Cu)
+#define GTM_ATOM2_CH5_IRQ_NOTIFY (0xF01E92A0u)
+#define GTM_ATOM2_CH5_IRQ_EN (0xF01E92A4u)
+#define GTM_ATOM2_CH5_IRQ_FORCINT (0xF01E92A8u)
+#define GTM_ATOM2_CH5_IRQ_MODE (0xF01E92ACu)
+#define GTM_SPE1_OUT_PAT5 (0xF010089Cu)
+#define GTM_ATOM2_CH6_RDADDR (0xF01E9300u)
+#define GTM_ATOM2_CH6_CTRL (0xF01E9304u)
+#define GTM_ATOM2_CH6_SOMB (0xF01E9304u)
+#define GTM_ATOM2_CH6_SOMC (0xF01E9304u)
+#define GTM_ATOM2_CH6_SOMI (0xF01E9304u)
+#define GTM_ATOM2_CH6_SOMP (0xF01E9304u)
+#define GTM_ATOM2_CH6_SOMS (0xF01E9304u)
+#define GTM_ATOM2_CH6_SR0 (0xF01E9308u)
+#define GTM_ATOM2_CH6_SR1 (0xF01E930Cu)
+#define GTM_ATOM2_CH6_CM0 (0xF01E9310u)
+#define GTM_SPE1_OUT_PAT6 (0xF01008A0u)
+#define GTM_ATOM2_CH6_CM1 (0xF01E9314u)
+#define GTM_ATOM2_CH6_CN0 (0xF01E9318u)
+#define GTM_ATOM2_CH6_STAT (0xF01E931Cu)
+#define GTM_ATOM2_CH6_IRQ_NOTIFY (0xF01E9320u)
+#define GTM_ATOM2_CH6_IRQ_EN (0xF01E9324u)
+#define GTM_ATOM2_CH6_IRQ_FORCINT (0xF01E9328u)
+#define GTM_ATOM2_CH6_IRQ_MODE (0xF01E932Cu)
+#define GTM_ATOM2_CH7_RDADDR (0xF01E9380u)
+#define GTM_ATOM2_CH7_CTRL (0xF01E9384u)
+#define GTM_ATOM2_CH7_SOMB (0xF01E9384u)
+#define GTM_SPE1_OUT_PAT7 (0xF01008A4u)
+#define GTM_ATOM2_CH7_SOMC (0xF01E9384u)
+#define GTM_ATOM2_CH7_SOMI (0xF01E9384u)
+#define GTM_ATOM2_CH7_SOMP (0xF01E9384u)
+#define GTM_ATOM2_CH7_SOMS (0xF01E9384u)
+#define GTM_ATOM2_CH7_SR0 (0xF01E9388u)
+#define GTM_ATOM2_CH7_SR1 (0xF01E938Cu)
+#define GTM_ATOM2_CH7_CM0 (0xF01E9390u)
+#define GTM_ATOM2_CH7_CM1 (0xF01E9394u)
+#define GTM_ATOM2_CH7_CN0 (0xF01E9398u)
+#define GTM_ATOM2_CH7_STAT (0xF01E939Cu)
+#define GTM_SPE1_OUT_CTRL (0xF01008A8u)
+#define GTM_ATOM2_CH7_IRQ_NOTIFY (0xF01E93A0u)
+#define GTM_ATOM2_CH7_IRQ_EN (0xF01E93A4u)
+#define GTM_ATOM2_CH7_IRQ_FORCINT (0xF01E93A8u)
+#define GTM_ATOM2_CH7_IRQ_MODE (0xF01E93ACu)
+#define GTM_ATOM3_CH0_RDADDR (0xF01E9800u)
+#define GTM_ATOM3_CH0_CTRL (0xF01E9804u)
+#define GTM_ATOM3_CH0_SOMB (0xF01E9804u)
+#define GTM_ATOM3_CH0_SOMC (0xF01E9804u)
+#define GTM_ATOM3_CH0_SOMI (0xF01E9804u)
+#define GTM_ATOM3_CH0_SOMP (0xF01E9804u)
+#define GTM_SPE1_IRQ_NOTIFY (0xF01008ACu)
+#define GTM_ATOM3_CH0_SOMS (0xF01E9804u)
+#define GTM_ATOM3_CH0_SR0 (0xF01E9808u)
+#define GTM_ATOM3_CH0_SR1 (0xF01E980Cu)
+#define GTM_ATOM3_CH0_CM0 (0xF01E9810u)
+#define GTM_ATOM3_CH0_CM1 (0xF01E9814u)
+#define GTM_ATOM3_CH0_CN0 (0xF01E9818u)
+#define GTM_ATOM3_CH0_STAT (0xF01E981Cu)
+#define GTM_ATOM3_CH0_IRQ_NOTIFY (0xF01E9820u)
+#define GTM_ATOM3_CH0_IRQ_EN (0xF01E9824u)
+#define GTM_ATOM3_CH0_IRQ_FORCINT (0xF01E9828u)
+#define GTM_SPE1_IRQ_EN (0xF01008B0u)
+#define GTM_ATOM3_CH0_IRQ_MODE (0xF01E982Cu)
+#define GTM_ATOM3_AGC_GLB_CTRL (0xF01E9840u)
+#define GTM_ATOM3_AGC_ENDIS_CTRL (0xF01E9844u)
+#define GTM_ATOM3_AGC_ENDIS_STAT (0xF01E9848u)
+#define GTM_ATOM3_AGC_ACT_TB (0xF01E984Cu)
+#define GTM_ATOM3_AGC_OUTEN_CTRL (0xF01E9850u)
+#define GTM_ATOM3_AGC_OUTEN_STAT (0xF01E9854u)
+#define GTM_ATOM3_AGC_FUPD_CTRL (0xF01E9858u)
+#define GTM_ATOM3_AGC_INT_TRIG (0xF01E985Cu)
+#define GTM_ATOM3_CH1_RDADDR (0xF01E9880u)
+#define GTM_SPE1_IRQ_FORCINT (0xF01008B4u)
+#define GTM_ATOM3_CH1_CTRL (0xF01E9884u)
+#define GTM_ATOM3_CH1_SOMB (0xF01E9884u)
+#define GTM_ATOM3_CH1_SOMC (0xF01E9884u)
+#define GTM_ATOM3_CH1_SOMI (0xF01E9884u)
+#define GTM_ATOM3_CH1_SOMP (0xF01E9884u)
+#define GTM_ATOM3_CH1_SOMS (0xF01E9884u)
+#define GTM_ATOM3_CH1_SR0 (0xF01E9888u)
+#define GTM_ATOM3_CH1_SR1 (0xF01E988Cu)
+#define GTM_ATOM3_CH1_CM0 (0xF01E9890u)
+#define GTM_ATOM3_CH1_CM1 (0xF01E9894u)
+#define GTM_SPE1_IRQ_MODE (0xF01008B8u)
+#define GTM_REV (0xF0100000u)
+#define GTM_ATOM3_CH1_CN0 (0xF01E9898u)
+#define GTM_ATOM3_CH1_STAT (0xF01E989Cu)
+#define GTM_ATOM3_CH1_IRQ_NOTIFY (0xF01E98A0u)
+#define GTM_ATOM3_CH1_IRQ_EN (0xF01E98A4u)
+#define GTM_ATOM3_CH1_IRQ_FORCINT (0xF01E98A8u)
+#define GTM_ATOM3_CH1_IRQ_MODE (0xF01E98ACu)
+#define GTM_ATOM3_CH2_RDADDR (0xF01E9900u)
+#define GTM_ATOM3_CH2_CTRL (0xF01E9904u)
+#define GTM_ATOM3_CH2_SOMB (0xF01E9904u)
+#define GTM_ATOM3_CH2_SOMC (0xF01E9904u)
+#define GTM_SPE1_EIRQ_EN (0xF01008BCu)
+#define GTM_ATOM3_CH2_SOMI (0xF01E9904u)
+#define GTM_ATOM3_CH2_SOMP (0xF01E9904u)
+#define GTM_ATOM3_CH2_SOMS (0xF01E9904u)
+#define GTM_ATOM3_CH2_SR0 (0xF01E9908u)
+#define GTM_ATOM3_CH2_SR1 (0xF01E990Cu)
+#define GTM_ATOM3_CH2_CM0 (0xF01E9910u)
+#define GTM_ATOM3_CH2_CM1 (0xF01E9914u)
+#define GTM_ATOM3_CH2_CN0 (0xF01E9918u)
+#define GTM_ATOM3_CH2_STAT (0xF01E991Cu)
+#define GTM_ATOM3_CH2_IRQ_NOTIFY (0xF01E9920u)
+#define GTM_SPE1_REV_CNT (0xF01008C0u)
+#define GTM_ATOM3_CH2_IRQ_EN (0xF01E9924u)
+#define GTM_ATOM3_CH2_IRQ_FORCINT (0xF01E9928u)
+#define GTM_ATOM3_CH2_IRQ_MODE (0xF01E992Cu)
+#define GTM_ATOM3_CH3_RDADDR (0xF01E9980u)
+#define GTM_ATOM3_CH3_CTRL (0xF01E9984u)
+#define GTM_ATOM3_CH3_SOMB (0xF01E9984u)
+#define GTM_ATOM3_CH3_SOMC (0xF01E9984u)
+#define GTM_ATOM3_CH3_SOMI (0xF01E9984u)
+#define GTM_ATOM3_CH3_SOMP (0xF01E9984u)
+#define GTM_ATOM3_CH3_SOMS (0xF01E9984u)
+#define GTM_SPE1_REV_CMP (0xF01008C4u)
+#define GTM_ATOM3_CH3_SR0 (0xF01E9988u)
+#define GTM_ATOM3_CH3_SR1 (0xF01E998Cu)
+#define GTM_ATOM3_CH3_CM0 (0xF01E9990u)
+#define GTM_ATOM3_CH3_CM1 (0xF01E9994u)
+#define GTM_ATOM3_CH3_CN0 (0xF01E9998u)
+#define GTM_ATOM3_CH3_STAT (0xF01E999Cu)
+#define GTM_ATOM3_CH3_IRQ_NOTIFY (0xF01E99A0u)
+#define GTM_ATOM3_CH3_IRQ_EN (0xF01E99A4u)
+#define GTM_ATOM3_CH3_IRQ_FORCINT (0xF01E99A8u)
+#define GTM_ATOM3_CH3_IRQ_MODE (0xF01E99ACu)
+#define GTM_SPE1_CTRL_STAT2 (0xF01008C8u)
+#define GTM_ATOM3_CH4_RDADDR (0xF01E9A00u)
+#define GTM_ATOM3_CH4_CTRL (0xF01E9A04u)
+#define GTM_ATOM3_CH4_SOMB (0xF01E9A04u)
+#define GTM_ATOM3_CH4_SOMC (0xF01E9A04u)
+#define GTM_ATOM3_CH4_SOMI (0xF01E9A04u)
+#define GTM_ATOM3_CH4_SOMP (0xF01E9A04u)
+#define GTM_ATOM3_CH4_SOMS (0xF01E9A04u)
+#define GTM_ATOM3_CH4_SR0 (0xF01E9A08u)
+#define GTM_ATOM3_CH4_SR1 (0xF01E9A0Cu)
+#define GTM_ATOM3_CH4_CM0 (0xF01E9A10u)
+#define GTM_SPE1_CMD (0xF01008CCu)
+#define GTM_ATOM3_CH4_CM1 (0xF01E9A14u)
+#define GTM_ATOM3_CH4_CN0 (0xF01E9A18u)
+#define GTM_ATOM3_CH4_STAT (0xF01E9A1Cu)
+#define GTM_ATOM3_CH4_IRQ_NOTIFY (0xF01E9A20u)
+#define GTM_ATOM3_CH4_IRQ_EN (0xF01E9A24u)
+#define GTM_ATOM3_CH4_IRQ_FORCINT (0xF01E9A28u)
+#define GTM_ATOM3_CH4_IRQ_MODE (0xF01E9A2Cu)
+#define GTM_ATOM3_CH5_RDADDR (0xF01E9A80u)
+#define GTM_ATOM3_CH5_CTRL (0xF01E9A84u)
+#define GTM_ATOM3_CH5_SOMB (0xF01E9A84u)
+#define GTM_MAP_CTRL (0xF0100F00u)
+#define GTM_ATOM3_CH5_SOMC (0xF01E9A84u)
+#define GTM_ATOM3_CH5_SOMI (0xF01E9A84u)
+#define GTM_ATOM3_CH5_SOMP (0xF01E9A84u)
+#define GTM_ATOM3_CH5_SOMS (0xF01E9A84u)
+#define GTM_ATOM3_CH5_SR0 (0xF01E9A88u)
+#define GTM_ATOM3_CH5_SR1 (0xF01E9A8Cu)
+#define GTM_ATOM3_CH5_CM0 (0xF01E9A90u)
+#define GTM_ATOM3_CH5_CM1 (0xF01E9A94u)
+#define GTM_ATOM3_CH5_CN0 (0xF01E9A98u)
+#define GTM_ATOM3_CH5_STAT (0xF01E9A9Cu)
+#define GTM_MCFG_CTRL (0xF0100F40u)
+#define GTM_ATOM3_CH5_IRQ_NOTIFY (0xF01E9AA0u)
+#define GTM_ATOM3_CH5_IRQ_EN (0xF01E9AA4u)
+#define GTM_ATOM3_CH5_IRQ_FORCINT (0xF01E9AA8u)
+#define GTM_ATOM3_CH5_IRQ_MODE (0xF01E9AACu)
+#define GTM_ATOM3_CH6_RDADDR (0xF01E9B00u)
+#define GTM_ATOM3_CH6_CTRL (0xF01E9B04u)
+#define GTM_ATOM3_CH6_SOMB (0xF01E9B04u)
+#define GTM_ATOM3_CH6_SOMC (0xF01E9B04u)
+#define GTM_ATOM3_CH6_SOMI (0xF01E9B04u)
+#define GTM_ATOM3_CH6_SOMP (0xF01E9B04u)
+#define GTM_TIM0_CH0_GPR0 (0xF0101000u)
+#define GTM_ATOM3_CH6_SOMS (0xF01E9B04u)
+#define GTM_ATOM3_CH6_SR0 (0xF01E9B08u)
+#define GTM_ATOM3_CH6_SR1 (0xF01E9B0Cu)
+#define GTM_ATOM3_CH6_CM0 (0xF01E9B10u)
+#define GTM_ATOM3_CH6_CM1 (0xF01E9B14u)
+#define GTM_ATOM3_CH6_CN0 (0xF01E9B18u)
+#define GTM_ATOM3_CH6_STAT (0xF01E9B1Cu)
+#define GTM_ATOM3_CH6_IRQ_NOTIFY (0xF01E9B20u)
+#define GTM_ATOM3_CH6_IRQ_EN (0xF01E9B24u)
+#define GTM_ATOM3_CH6_IRQ_FORCINT (0xF01E9B28u)
+#define GTM_TIM0_CH0_GPR1 (0xF0101004u)
+#define GTM_ATOM3_CH6_IRQ_MODE (0xF01E9B2Cu)
+#define GTM_ATOM3_CH7_RDADDR (0xF01E9B80u)
+#define GTM_ATOM3_CH7_CTRL (0xF01E9B84u)
+#define GTM_ATOM3_CH7_SOMB (0xF01E9B84u)
+#define GTM_ATOM3_CH7_SOMC (0xF01E9B84u)
+#define GTM_ATOM3_CH7_SOMI (0xF01E9B84u)
+#define GTM_ATOM3_CH7_SOMP (0xF01E9B84u)
+#define GTM_ATOM3_CH7_SOMS (0xF01E9B84u)
+#define GTM_ATOM3_CH7_SR0 (0xF01E9B88u)
+#define GTM_ATOM3_CH7_SR1 (0xF01E9B8Cu)
+#define GTM_TIM0_CH0_CNT (0xF0101008u)
+#define GTM_RST (0xF0100004u)
+#define GTM_ATOM3_CH7_CM0 (0xF01E9B90u)
+#define GTM_ATOM3_CH7_CM1 (0xF01E9B94u)
+#define GTM_ATOM3_CH7_CN0 (0xF01E9B98u)
+#define GTM_ATOM3_CH7_STAT (0xF01E9B9Cu)
+#define GTM_ATOM3_CH7_IRQ_NOTIFY (0xF01E9BA0u)
+#define GTM_ATOM3_CH7_IRQ_EN (0xF01E9BA4u)
+#define GTM_ATOM3_CH7_IRQ_FORCINT (0xF01E9BA8u)
+#define GTM_ATOM3_CH7_IRQ_MODE (0xF01E9BACu)
+#define GTM_ATOM4_CH0_RDADDR (0xF01EA000u)
+#define GTM_ATOM4_CH0_CTRL (0xF01EA004u)
+#define GTM_TIM0_CH0_ECNT (0xF010100Cu)
+#define GTM_ATOM4_CH0_SOMB (0xF01EA004u)
+#define GTM_ATOM4_CH0_SOMC (0xF01EA004u)
+#define GTM_ATOM4_CH0_SOMI (0xF01EA004u)
+#define GTM_ATOM4_CH0_SOMP (0xF01EA004u)
+#define GTM_ATOM4_CH0_SOMS (0xF01EA004u)
+#define GTM_ATOM4_CH0_SR0 (0xF01EA008u)
+#define GTM_ATOM4_CH0_SR1 (0xF01EA00Cu)
+#define GTM_ATOM4_CH0_CM0 (0xF01EA010u)
+#define GTM_ATOM4_CH0_CM1 (0xF01EA014u)
+#define GTM_ATOM4_CH0_CN0 (0xF01EA018u)
+#define GTM_TIM0_CH0_CNTS (0xF0101010u)
+#define GTM_ATOM4_CH0_STAT (0xF01EA01Cu)
+#define GTM_ATOM4_CH0_IRQ_NOTIFY (0xF01EA020u)
+#define GTM_ATOM4_CH0_IRQ_EN (0xF01EA024u)
+#define GTM_ATOM4_CH0_IRQ_FORCINT (0xF01EA028u)
+#define GTM_ATOM4_CH0_IRQ_MODE (0xF01EA02Cu)
+#define GTM_ATOM4_AGC_GLB_CTRL (0xF01EA040u)
+#define GTM_ATOM4_AGC_ENDIS_CTRL (0xF01EA044u)
+#define GTM_ATOM4_AGC_ENDIS_STAT (0xF01EA048u)
+#define GTM_ATOM4_AGC_ACT_TB (0xF01EA04Cu)
+#define GTM_ATOM4_AGC_OUTEN_CTRL (0xF01EA050u)
+#define GTM_TIM0_CH0_TDUC (0xF0101014u)
+#define GTM_ATOM4_AGC_OUTEN_STAT (0xF01EA054u)
+#define GTM_ATOM4_AGC_FUPD_CTRL (0xF01EA058u)
+#define GTM_ATOM4_AGC_INT_TRIG (0xF01EA05Cu)
+#define GTM_ATOM4_CH1_RDADDR (0xF01EA080u)
+#define GTM_ATOM4_CH1_CTRL (0xF01EA084u)
+#define GTM_ATOM4_CH1_SOMB (0xF01EA084u)
+#define GTM_ATOM4_CH1_SOMC (0xF01EA084u)
+#define GTM_ATOM4_CH1_SOMI (0xF01EA084u)
+#define GTM_ATOM4_CH1_SOMP (0xF01EA084u)
+#define GTM_ATOM4_CH1_SOMS (0xF01EA084u)
+#define GTM_TIM0_CH0_TDUV (0xF0101018u)
+#define GTM_ATOM4_CH1_SR0 (0xF01EA088u)
+#define GTM_ATOM4_CH1_SR1 (0xF01EA08Cu)
+#define GTM_ATOM4_CH1_CM0 (0xF01EA090u)
+#define GTM_ATOM4_CH1_CM1 (0xF01EA094u)
+#define GTM_ATOM4_CH1_CN0 (0xF01EA098u)
+#define GTM_ATOM4_CH1_STAT (0xF01EA09Cu)
+#define GTM_ATOM4_CH1_IRQ_NOTIFY (0xF01EA0A0u)
+#define GTM_ATOM4_CH1_IRQ_EN (0xF01EA0A4u)
+#define GTM_ATOM4_CH1_IRQ_FORCINT (0xF01EA0A8u)
+#define GTM_ATOM4_CH1_IRQ_MODE (0xF01EA0ACu)
+#define GTM_TIM0_CH0_FLT_RE (0xF010101Cu)
+#define GTM_ATOM4_CH2_RDADDR (0xF01EA100u)
+#define GTM_ATOM4_CH2_CTRL (0xF01EA104u)
+#define GTM_ATOM4_CH2_SOMB (0xF01EA104u)
+#define GTM_ATOM4_CH2_SOMC (0xF01EA104u)
+#define GTM_ATOM4_CH2_SOMI (0xF01EA104u)
+#define GTM_ATOM4_CH2_SOMP (0xF01EA104u)
+#define GTM_ATOM4_CH2_SOMS (0xF01EA104u)
+#define GTM_ATOM4_CH2_SR0 (0xF01EA108u)
+#define GTM_ATOM4_CH2_SR1 (0xF01EA10Cu)
+#define GTM_ATOM4_CH2_CM0 (0xF01EA110u)
+#define GTM_TIM0_CH0_FLT_FE (0xF0101020u)
+#define GTM_ATOM4_CH2_CM1 (0xF01EA114u)
+#define GTM_ATOM4_CH2_CN0 (0xF01EA118u)
+#define GTM_ATOM4_CH2_STAT (0xF01EA11Cu)
+#define GTM_ATOM4_CH2_IRQ_NOTIFY (0xF01EA120u)
+#define GTM_ATOM4_CH2_IRQ_EN (0xF01EA124u)
+#define GTM_ATOM4_CH2_IRQ_FORCINT (0xF01EA128u)
+#define GTM_ATOM4_CH2_IRQ_MODE (0xF01EA12Cu)
+#define GTM_ATOM4_CH3_RDADDR (0xF01EA180u)
+#define GTM_ATOM4_CH3_CTRL (0xF01EA184u)
+#define GTM_ATOM4_CH3_SOMB (0xF01EA184u)
+#define GTM_TIM0_CH0_CTRL (0xF0101024u)
+#define GTM_ATOM4_CH3_SOMC (0xF01EA184u)
+#define GTM_ATOM4_CH3_SOMI (0xF01EA184u)
+#define GTM_ATOM4_CH3_SOMP (0xF01EA184u)
+#define GTM_ATOM4_CH3_SOMS (0xF01EA184u)
+#define GTM_ATOM4_CH3_SR0 (0xF01EA188u)
+#define GTM_ATOM4_CH3_SR1 (0xF01EA18Cu)
+#define GTM_ATOM4_CH3_CM0 (0xF01EA190u)
+#define GTM_ATOM4_CH3_CM1 (0xF01EA194u)
+#define GTM_ATOM4_CH3_CN0 (0xF01EA198u)
+#define GTM_ATOM4_CH3_STAT (0xF01EA19Cu)
+#define GTM_TIM0_CH0_ECTRL (0xF0101028u)
+#define GTM_ATOM4_CH3_IRQ_NOTIFY (0xF01EA1A0u)
+#define GTM_ATOM4_CH3_IRQ_EN (0xF01EA1A4u)
+#define GTM_ATOM4_CH3_IRQ_FORCINT (0xF01EA1A8u)
+#define GTM_ATOM4_CH3_IRQ_MODE (0xF01EA1ACu)
+#define GTM_ATOM4_CH4_RDADDR (0xF01EA200u)
+#define GTM_ATOM4_CH4_CTRL (0xF01EA204u)
+#define GTM_ATOM4_CH4_SOMB (0xF01EA204u)
+#define GTM_ATOM4_CH4_SOMC (0xF01EA204u)
+#define GTM_ATOM4_CH4_SOMI (0xF01EA204u)
+#define GTM_ATOM4_CH4_SOMP (0xF01EA204u)
+#define GTM_TIM0_CH0_IRQ_NOTIFY (0xF010102Cu)
+#define GTM_ATOM4_CH4_SOMS (0xF01EA204u)
+#define GTM_ATOM4_CH4_SR0 (0xF01EA208u)
+#define GTM_ATOM4_CH4_SR1 (0xF01EA20Cu)
+#define GTM_ATOM4_CH4_CM0 (0xF01EA210u)
+#define GTM_ATOM4_CH4_CM1 (0xF01EA214u)
+#define GTM_ATOM4_CH4_CN0 (0xF01EA218u)
+#define GTM_ATOM4_CH4_STAT (0xF01EA21Cu)
+#define GTM_ATOM4_CH4_IRQ_NOTIFY (0xF01EA220u)
+#define GTM_ATOM4_CH4_IRQ_EN (0xF01EA224u)
+#define GTM_ATOM4_CH4_IRQ_FORCINT (0xF01EA228u)
+#define GTM_TIM0_CH0_IRQ_EN (0xF0101030u)
+#define GTM_CTRL (0xF0100008u)
+#define GTM_ATOM4_CH4_IRQ_MODE (0xF01EA22Cu)
+#define GTM_ATOM4_CH5_RDADDR (0xF01EA280u)
+#define GTM_ATOM4_CH5_CTRL (0xF01EA284u)
+#define GTM_ATOM4_CH5_SOMB (0xF01EA284u)
+#define GTM_ATOM4_CH5_SOMC (0xF01EA284u)
+#define GTM_ATOM4_CH5_SOMI (0xF01EA284u)
+#define GTM_ATOM4_CH5_SOMP (0xF01EA284u)
+#define GTM_ATOM4_CH5_SOMS (0xF01EA284u)
+#define GTM_ATOM4_CH5_SR0 (0xF01EA288u)
+#define GTM_ATOM4_CH5_SR1 (0xF01EA28Cu)
+#define GTM_TIM0_CH0_IRQ_FORCINT (0xF0101034u)
+#define GTM_ATOM4_CH5_CM0 (0xF01EA290u)
+#define GTM_ATOM4_CH5_CM1 (0xF01EA294u)
+#define GTM_ATOM4_CH5_CN0 (0xF01EA298u)
+#define GTM_ATOM4_CH5_STAT (0xF01EA29Cu)
+#define GTM_ATOM4_CH5_IRQ_NOTIFY (0xF01EA2A0u)
+#define GTM_ATOM4_CH5_IRQ_EN (0xF01EA2A4u)
+#define GTM_ATOM4_CH5_IRQ_FORCINT (0xF01EA2A8u)
+#define GTM_ATOM4_CH5_IRQ_MODE (0xF01EA2ACu)
+#define GTM_ATOM4_CH6_RDADDR (0xF01EA300u)
+#define GTM_ATOM4_CH6_CTRL (0xF01EA304u)
+#define GTM_TIM0_CH0_IRQ_MODE (0xF0101038u)
+#define GTM_ATOM4_CH6_SOMB (0xF01EA304u)
+#define GTM_ATOM4_CH6_SOMC (0xF01EA304u)
+#define GTM_ATOM4_CH6_SOMI (0xF01EA304u)
+#define GTM_ATOM4_CH6_SOMP (0xF01EA304u)
+#define GTM_ATOM4_CH6_SOMS (0xF01EA304u)
+#define GTM_ATOM4_CH6_SR0 (0xF01EA308u)
+#define GTM_ATOM4_CH6_SR1 (0xF01EA30Cu)
+#define GTM_ATOM4_CH6_CM0 (0xF01EA310u)
+#define GTM_ATOM4_CH6_CM1 (0xF01EA314u)
+#define GTM_ATOM4_CH6_CN0 (0xF01EA318u)
+#define GTM_TIM0_CH0_EIRQ_EN (0xF010103Cu)
+#define GTM_ATOM4_CH6_STAT (0xF01EA31Cu)
+#define GTM_ATOM4_CH6_IRQ_NOTIFY (0xF01EA320u)
+#define GTM_ATOM4_CH6_IRQ_EN (0xF01EA324u)
+#define GTM_ATOM4_CH6_IRQ_FORCINT (0xF01EA328u)
+#define GTM_ATOM4_CH6_IRQ_MODE (0xF01EA32Cu)
+#define GTM_ATOM4_CH7_RDADDR (0xF01EA380u)
+#define GTM_ATOM4_CH7_CTRL (0xF01EA384u)
+#define GTM_ATOM4_CH7_SOMB (0xF01EA384u)
+#define GTM_ATOM4_CH7_SOMC (0xF01EA384u)
+#define GTM_ATOM4_CH7_SOMI (0xF01EA384u)
+#define GTM_TIM0_INP_VAL (0xF0101074u)
+#define GTM_ATOM4_CH7_SOMP (0xF01EA384u)
+#define GTM_ATOM4_CH7_SOMS (0xF01EA384u)
+#define GTM_ATOM4_CH7_SR0 (0xF01EA388u)
+#define GTM_ATOM4_CH7_SR1 (0xF01EA38Cu)
+#define GTM_ATOM4_CH7_CM0 (0xF01EA390u)
+#define GTM_ATOM4_CH7_CM1 (0xF01EA394u)
+#define GTM_ATOM4_CH7_CN0 (0xF01EA398u)
+#define GTM_ATOM4_CH7_STAT (0xF01EA39Cu)
+#define GTM_ATOM4_CH7_IRQ_NOTIFY (0xF01EA3A0u)
+#define GTM_ATOM4_CH7_IRQ_EN (0xF01EA3A4u)
+#define GTM_TIM0_IN_SRC (0xF0101078u)
+#define GTM_ATOM4_CH7_IRQ_FORCINT (0xF01EA3A8u)
+#define GTM_ATOM4_CH7_IRQ_MODE (0xF01EA3ACu)
+#define GTM_ATOM5_CH0_RDADDR (0xF01EA800u)
+#define GTM_ATOM5_CH0_CTRL (0xF01EA804u)
+#define GTM_ATOM5_CH0_SOMB (0xF01EA804u)
+#define GTM_ATOM5_CH0_SOMC (0xF01EA804u)
+#define GTM_ATOM5_CH0_SOMI (0xF01EA804u)
+#define GTM_ATOM5_CH0_SOMP (0xF01EA804u)
+#define GTM_ATOM5_CH0_SOMS (0xF01EA804u)
+#define GTM_ATOM5_CH0_SR0 (0xF01EA808u)
+#define GTM_TIM0_RST (0xF010107Cu)
+#define GTM_ATOM5_CH0_SR1 (0xF01EA80Cu)
+#define GTM_ATOM5_CH0_CM0 (0xF01EA810u)
+#define GTM_ATOM5_CH0_CM1 (0xF01EA814u)
+#define GTM_ATOM5_CH0_CN0 (0xF01EA818u)
+#define GTM_ATOM5_CH0_STAT (0xF01EA81Cu)
+#define GTM_ATOM5_CH0_IRQ_NOTIFY (0xF01EA820u)
+#define GTM_ATOM5_CH0_IRQ_EN (0xF01EA824u)
+#define GTM_ATOM5_CH0_IRQ_FORCINT (0xF01EA828u)
+#define GTM_ATOM5_CH0_IRQ_MODE (0xF01EA82Cu)
+#define GTM_ATOM5_AGC_GLB_CTRL (0xF01EA840u)
+#define GTM_TIM0_CH1_GPR0 (0xF0101080u)
+#define GTM_ATOM5_AGC_ENDIS_CTRL (0xF01EA844u)
+#define GTM_ATOM5_AGC_ENDIS_STAT (0xF01EA848u)
+#define GTM_ATOM5_AGC_ACT_TB (0xF01EA84Cu)
+#define GTM_ATOM5_AGC_OUTEN_CTRL (0xF01EA850u)
+#define GTM_ATOM5_AGC_OUTEN_STAT (0xF01EA854u)
+#define GTM_ATOM5_AGC_FUPD_CTRL (0xF01EA858u)
+#define GTM_ATOM5_AGC_INT_TRIG (0xF01EA85Cu)
+#define GTM_ATOM5_CH1_RDADDR (0xF01EA880u)
+#define GTM_ATOM5_CH1_CTRL (0xF01EA884u)
+#define GTM_ATOM5_CH1_SOMB (0xF01EA884u)
+#define GTM_TIM0_CH1_GPR1 (0xF0101084u)
+#define GTM_ATOM5_CH1_SOMC (0xF01EA884u)
+#define GTM_ATOM5_CH1_SOMI (0xF01EA884u)
+#define GTM_ATOM5_CH1_SOMP (0xF01EA884u)
+#define GTM_ATOM5_CH1_SOMS (0xF01EA884u)
+#define GTM_ATOM5_CH1_SR0 (0xF01EA888u)
+#define GTM_ATOM5_CH1_SR1 (0xF01EA88Cu)
+#define GTM_ATOM5_CH1_CM0 (0xF01EA890u)
+#define GTM_ATOM5_CH1_CM1 (0xF01EA894u)
+#define GTM_ATOM5_CH1_CN0 (0xF01EA898u)
+#define GTM_ATOM5_CH1_STAT (0xF01EA89Cu)
+#define GTM_TIM0_CH1_CNT (0xF0101088u)
+#define GTM_ATOM5_CH1_IRQ_NOTIFY (0xF01EA8A0u)
+#define GTM_ATOM5_CH1_IRQ_EN (0xF01EA8A4u)
+#define GTM_ATOM5_CH1_IRQ_FORCINT (0xF01EA8A8u)
+#define GTM_ATOM5_CH1_IRQ_MODE (0xF01EA8ACu)
+#define GTM_ATOM5_CH2_RDADDR (0xF01EA900u)
+#define GTM_ATOM5_CH2_CTRL (0xF01EA904u)
+#define GTM_ATOM5_CH2_SOMB (0xF01EA904u)
+#define GTM_ATOM5_CH2_SOMC (0xF01EA904u)
+#define GTM_ATOM5_CH2_SOMI (0xF01EA904u)
+#define GTM_ATOM5_CH2_SOMP (0xF01EA904u)
+#define GTM_TIM0_CH1_ECNT (0xF010108Cu)
+#define GTM_AEI_ADDR_XPT (0xF010000Cu)
+#define GTM_ATOM5_CH2_SOMS (0xF01EA904u)
+#define GTM_ATOM5_CH2_SR0 (0xF01EA908u)
+#define GTM_ATOM5_CH2_SR1 (0xF01EA90Cu)
+#define GTM_ATOM5_CH2_CM0 (0xF01EA910u)
+#define GTM_ATOM5_CH2_CM1 (0xF01EA914u)
+#define GTM_ATOM5_CH2_CN0 (0xF01EA918u)
+#define GTM_ATOM5_CH2_STAT (0xF01EA91Cu)
+#define GTM_ATOM5_CH2_IRQ_NOTIFY (0xF01EA920u)
+#define GTM_ATOM5_CH2_IRQ_EN (0xF01EA924u)
+#define GTM_ATOM5_CH2_IRQ_FORCINT (0xF01EA928u)
+#define GTM_TIM0_CH1_CNTS (0xF0101090u)
+#define GTM_ATOM5_CH2_IRQ_MODE (0xF01EA92Cu)
+#define GTM_ATOM5_CH3_RDADDR (0xF01EA980u)
+#define GTM_ATOM5_CH3_CTRL (0xF01EA984u)
+#define GTM_ATOM5_CH3_SOMB (0xF01EA984u)
+#define GTM_ATOM5_CH3_SOMC (0xF01EA984u)
+#define GTM_ATOM5_CH3_SOMI (0xF01EA984u)
+#define GTM_ATOM5_CH3_SOMP (0xF01EA984u)
+#define GTM_ATOM5_CH3_SOMS (0xF01EA984u)
+#define GTM_ATOM5_CH3_SR0 (0xF01EA988u)
+#define GTM_ATOM5_CH3_SR1 (0xF01EA98Cu)
+#define GTM_TIM0_CH1_TDUC (0xF0101094u)
+#define GTM_ATOM5_CH3_CM0 (0xF01EA990u)
+#define GTM_ATOM5_CH3_CM1 (0xF01EA994u)
+#define GTM_ATOM5_CH3_CN0 (0xF01EA998u)
+#define GTM_ATOM5_CH3_STAT (0xF01EA99Cu)
+#define GTM_ATOM5_CH3_IRQ_NOTIFY (0xF01EA9A0u)
+#define GTM_ATOM5_CH3_IRQ_EN (0xF01EA9A4u)
+#define GTM_ATOM5_CH3_IRQ_FORCINT (0xF01EA9A8u)
+#define GTM_ATOM5_CH3_IRQ_MODE (0xF01EA9ACu)
+#define GTM_ATOM5_CH4_RDADDR (0xF01EAA00u)
+#define GTM_ATOM5_CH4_CTRL (0xF01EAA04u)
+#define GTM_TIM0_CH1_TDUV (0xF0101098u)
+#define GTM_ATOM5_CH4_SOMB (0xF01EAA04u)
+#define GTM_ATOM5_CH4_SOMC (0xF01EAA04u)
+#define GTM_ATOM5_CH4_SOMI (0xF01EAA04u)
+#define GTM_ATOM5_CH4_SOMP (0xF01EAA04u)
+#define GTM_ATOM5_CH4_SOMS (0xF01EAA04u)
+#define GTM_ATOM5_CH4_SR0 (0xF01EAA08u)
+#define GTM_ATOM5_CH4_SR1 (0xF01EAA0Cu)
+#define GTM_ATOM5_CH4_CM0 (0xF01EAA10u)
+#define GTM_ATOM5_CH4_CM1 (0xF01EAA14u)
+#define GTM_ATOM5_CH4_CN0 (0xF01EAA18u)
+#define GTM_TIM0_CH1_FLT_RE (0xF010109Cu)
+#define GTM_ATOM5_CH4_STAT (0xF01EAA1Cu)
+#define GTM_ATOM5_CH4_IRQ_NOTIFY (0xF01EAA20u)
+#define GTM_ATOM5_CH4_IRQ_EN (0xF01EAA24u)
+#define GTM_ATOM5_CH4_IRQ_FORCINT (0xF01EAA28u)
+#define GTM_ATOM5_CH4_IRQ_MODE (0xF01EAA2Cu)
+#define GTM_ATOM5_CH5_RDADDR (0xF01EAA80u)
+#define GTM_ATOM5_CH5_CTRL (0xF01EAA84u)
+#define GTM_ATOM5_CH5_SOMB (0xF01EAA84u)
+#define GTM_ATOM5_CH5_SOMC (0xF01EAA84u)
+#define GTM_ATOM5_CH5_SOMI (0xF01EAA84u)
+#define GTM_TIM0_CH1_FLT_FE (0xF01010A0u)
+#define GTM_ATOM5_CH5_SOMP (0xF01EAA84u)
+#define GTM_ATOM5_CH5_SOMS (0xF01EAA84u)
+#define GTM_ATOM5_CH5_SR0 (0xF01EAA88u)
+#define GTM_ATOM5_CH5_SR1 (0xF01EAA8Cu)
+#define GTM_ATOM5_CH5_CM0 (0xF01EAA90u)
+#define GTM_ATOM5_CH5_CM1 (0xF01EAA94u)
+#define GTM_ATOM5_CH5_CN0 (0xF01EAA98u)
+#define GTM_ATOM5_CH5_STAT (0xF01EAA9Cu)
+#define GTM_ATOM5_CH5_IRQ_NOTIFY (0xF01EAAA0u)
+#define GTM_ATOM5_CH5_IRQ_EN (0xF01EAAA4u)
+#define GTM_TIM0_CH1_CTRL (0xF01010A4u)
+#define GTM_ATOM5_CH5_IRQ_FORCINT (0xF01EAAA8u)
+#define GTM_ATOM5_CH5_IRQ_MODE (0xF01EAAACu)
+#define GTM_ATOM5_CH6_RDADDR (0xF01EAB00u)
+#define GTM_ATOM5_CH6_CTRL (0xF01EAB04u)
+#define GTM_ATOM5_CH6_SOMB (0xF01EAB04u)
+#define GTM_ATOM5_CH6_SOMC (0xF01EAB04u)
+#define GTM_ATOM5_CH6_SOMI (0xF01EAB04u)
+#define GTM_ATOM5_CH6_SOMP (0xF01EAB04u)
+#define GTM_ATOM5_CH6_SOMS (0xF01EAB04u)
+#define GTM_ATOM5_CH6_SR0 (0xF01EAB08u)
+#define GTM_TIM0_CH1_ECTRL (0xF01010A8u)
+#define GTM_ATOM5_CH6_SR1 (0xF01EAB0Cu)
+#define GTM_ATOM5_CH6_CM0 (0xF01EAB10u)
+#define GTM_ATOM5_CH6_CM1 (0xF01EAB14u)
+#define GTM_ATOM5_CH6_CN0 (0xF01EAB18u)
+#define GTM_ATOM5_CH6_STAT (0xF01EAB1Cu)
+#define GTM_ATOM5_CH6_IRQ_NOTIFY (0xF01EAB20u)
+#define GTM_ATOM5_CH6_IRQ_EN (0xF01EAB24u)
+#define GTM_ATOM5_CH6_IRQ_FORCINT (0xF01EAB28u)
+#define GTM_ATOM5_CH6_IRQ_MODE (0xF01EAB2Cu)
+#define GTM_ATOM5_CH7_RDADDR (0xF01EAB80u)
+#define GTM_TIM0_CH1_IRQ_NOTIFY (0xF01010ACu)
+#define GTM_ATOM5_CH7_CTRL (0xF01EAB84u)
+#define GTM_ATOM5_CH7_SOMB (0xF01EAB84u)
+#define GTM_ATOM5_CH7_SOMC (0xF01EAB84u)
+#define GTM_ATOM5_CH7_SOMI (0xF01EAB84u)
+#define GTM_ATOM5_CH7_SOMP (0xF01EAB84u)
+#define GTM_ATOM5_CH7_SOMS (0xF01EAB84u)
+#define GTM_ATOM5_CH7_SR0 (0xF01EAB88u)
+#define GTM_ATOM5_CH7_SR1 (0xF01EAB8Cu)
+#define GTM_ATOM5_CH7_CM0 (0xF01EAB90u)
+#define GTM_ATOM5_CH7_CM1 (0xF01EAB94u)
+#define GTM_TIM0_CH1_IRQ_EN (0xF01010B0u)
+#define GTM_ATOM5_CH7_CN0 (0xF01EAB98u)
+#define GTM_ATOM5_CH7_STAT (0xF01EAB9Cu)
+#define GTM_ATOM5_CH7_IRQ_NOTIFY (0xF01EABA0u)
+#define GTM_ATOM5_CH7_IRQ_EN (0xF01EABA4u)
+#define GTM_ATOM5_CH7_IRQ_FORCINT (0xF01EABA8u)
+#define GTM_ATOM5_CH7_IRQ_MODE (0xF01EABACu)
+#define GTM_MCS0_CH0_R0 (0xF01F0000u)
+#define GTM_MCS0_CH0_R1 (0xF01F0004u)
+#define GTM_MCS0_CH0_R2 (0xF01F0008u)
+#define GTM_MCS0_CH0_R3 (0xF01F000Cu)
+#define GTM_TIM0_CH1_IRQ_FORCINT (0xF01010B4u)
+#define GTM_IRQ_NOTIFY (0xF0100010u)
+#define GTM_MCS0_CH0_R4 (0xF01F0010u)
+#define GTM_MCS0_CH0_R5 (0xF01F0014u)
+#define GTM_MCS0_CH0_R6 (0xF01F0018u)
+#define GTM_MCS0_CH0_R7 (0xF01F001Cu)
+#define GTM_MCS0_CH0_CTRL (0xF01F0020u)
+#define GTM_MCS0_CH0_ACB (0xF01F0024u)
+#define GTM_MCS0_CH0_CTRG (0xF01F0028u)
+#define GTM_MCS0_CH0_STRG (0xF01F002Cu)
+#define GTM_TIM0_CH1_IRQ_MODE (0xF01010B8u)
+#define GTM_MCS0_CH0_MHB (0xF01F003Cu)
+#define GTM_MCS0_CH0_PC (0xF01F0040u)
+#define GTM_MCS0_CH0_IRQ_NOTIFY (0xF01F0044u)
+#define GTM_MCS0_CH0_IRQ_EN (0xF01F0048u)
+#define GTM_MCS0_CH0_IRQ_FORCINT (0xF01F004Cu)
+#define GTM_MCS0_CH0_IRQ_MODE (0xF01F0050u)
+#define GTM_MCS0_CH0_EIRQ_EN (0xF01F0054u)
+#define GTM_MCS0_REG_PROT (0xF01F0060u)
+#define GTM_MCS0_CTRL_STAT (0xF01F0064u)
+#define GTM_TIM0_CH1_EIRQ_EN (0xF01010BCu)
+#define GTM_MCS0_RESET (0xF01F0068u)
+#define GTM_MCS0_CAT (0xF01F006Cu)
+#define GTM_MCS0_CWT (0xF01F0070u)
+#define GTM_MCS0_ERR (0xF01F007Cu)
+#define GTM_MCS0_CH1_R0 (0xF01F0080u)
+#define GTM_MCS0_CH1_R1 (0xF01F0084u)
+#define GTM_MCS0_CH1_R2 (0xF01F0088u)
+#define GTM_MCS0_CH1_R3 (0xF01F008Cu)
+#define GTM_MCS0_CH1_R4 (0xF01F0090u)
+#define GTM_MCS0_CH1_R5 (0xF01F0094u)
+#define GTM_TIM0_CH2_GPR0 (0xF0101100u)
+#define GTM_MCS0_CH1_R6 (0xF01F0098u)
+#define GTM_MCS0_CH1_R7 (0xF01F009Cu)
+#define GTM_MCS0_CH1_CTRL (0xF01F00A0u)
+#define GTM_MCS0_CH1_ACB (0xF01F00A4u)
+#define GTM_MCS0_CH1_MHB (0xF01F00BCu)
+#define GTM_MCS0_CH1_PC (0xF01F00C0u)
+#define GTM_MCS0_CH1_IRQ_NOTIFY (0xF01F00C4u)
+#define GTM_MCS0_CH1_IRQ_EN (0xF01F00C8u)
+#define GTM_MCS0_CH1_IRQ_FORCINT (0xF01F00CCu)
+#define GTM_MCS0_CH1_IRQ_MODE (0xF01F00D0u)
+#define GTM_TIM0_CH2_GPR1 (0xF0101104u)
+#define GTM_MCS0_CH1_EIRQ_EN (0xF01F00D4u)
+#define GTM_MCS0_CH2_R0 (0xF01F0100u)
+#define GTM_MCS0_CH2_R1 (0xF01F0104u)
+#define GTM_MCS0_CH2_R2 (0xF01F0108u)
+#define GTM_MCS0_CH2_R3 (0xF01F010Cu)
+#define GTM_MCS0_CH2_R4 (0xF01F0110u)
+#define GTM_MCS0_CH2_R5 (0xF01F0114u)
+#define GTM_MCS0_CH2_R6 (0xF01F0118u)
+#define GTM_MCS0_CH2_R7 (0xF01F011Cu)
+#define GTM_MCS0_CH2_CTRL (0xF01F0120u)
+#define GTM_TIM0_CH2_CNT (0xF0101108u)
+#define GTM_MCS0_CH2_ACB (0xF01F0124u)
+#define GTM_MCS0_CH2_MHB (0xF01F013Cu)
+#define GTM_MCS0_CH2_PC (0xF01F0140u)
+#define GTM_MCS0_CH2_IRQ_NOTIFY (0xF01F0144u)
+#define GTM_MCS0_CH2_IRQ_EN (0xF01F0148u)
+#define GTM_MCS0_CH2_IRQ_FORCINT (0xF01F014Cu)
+#define GTM_MCS0_CH2_IRQ_MODE (0xF01F0150u)
+#define GTM_MCS0_CH2_EIRQ_EN (0xF01F0154u)
+#define GTM_MCS0_CH3_R0 (0xF01F0180u)
+#define GTM_MCS0_CH3_R1 (0xF01F0184u)
+#define GTM_TIM0_CH2_ECNT (0xF010110Cu)
+#define GTM_MCS0_CH3_R2 (0xF01F0188u)
+#define GTM_MCS0_CH3_R3 (0xF01F018Cu)
+#define GTM_MCS0_CH3_R4 (0xF01F0190u)
+#define GTM_MCS0_CH3_R5 (0xF01F0194u)
+#define GTM_MCS0_CH3_R6 (0xF01F0198u)
+#define GTM_MCS0_CH3_R7 (0xF01F019Cu)
+#define GTM_MCS0_CH3_CTRL (0xF01F01A0u)
+#define GTM_MCS0_CH3_ACB (0xF01F01A4u)
+#define GTM_MCS0_CH3_MHB (0xF01F01BCu)
+#define GTM_MCS0_CH3_PC (0xF01F01C0u)
+#define GTM_TIM0_CH2_CNTS (0xF0101110u)
+#define GTM_MCS0_CH3_IRQ_NOTIFY (0xF01F01C4u)
+#define GTM_MCS0_CH3_IRQ_EN (0xF01F01C8u)
+#define GTM_MCS0_CH3_IRQ_FORCINT (0xF01F01CCu)
+#define GTM_MCS0_CH3_IRQ_MODE (0xF01F01D0u)
+#define GTM_MCS0_CH3_EIRQ_EN (0xF01F01D4u)
+#define GTM_MCS0_CH4_R0 (0xF01F0200u)
+#define GTM_MCS0_CH4_R1 (0xF01F0204u)
+#define GTM_MCS0_CH4_R2 (0xF01F0208u)
+#define GTM_MCS0_CH4_R3 (0xF01F020Cu)
+#define GTM_MCS0_CH4_R4 (0xF01F0210u)
+#define GTM_TIM0_CH2_TDUC (0xF0101114u)
+#define GTM_MCS0_CH4_R5 (0xF01F0214u)
+#define GTM_MCS0_CH4_R6 (0xF01F0218u)
+#define GTM_MCS0_CH4_R7 (0xF01F021Cu)
+#define GTM_MCS0_CH4_CTRL (0xF01F0220u)
+#define GTM_MCS0_CH4_ACB (0xF01F0224u)
+#define GTM_MCS0_CH4_MHB (0xF01F023Cu)
+#define GTM_MCS0_CH4_PC (0xF01F0240u)
+#define GTM_MCS0_CH4_IRQ_NOTIFY (0xF01F0244u)
+#define GTM_MCS0_CH4_IRQ_EN (0xF01F0248u)
+#define GTM_MCS0_CH4_IRQ_FORCINT (0xF01F024Cu)
+#define GTM_TIM0_CH2_TDUV (0xF0101118u)
+#define GTM_MCS0_CH4_IRQ_MODE (0xF01F0250u)
+#define GTM_MCS0_CH4_EIRQ_EN (0xF01F0254u)
+#define GTM_MCS0_CH5_R0 (0xF01F0280u)
+#define GTM_MCS0_CH5_R1 (0xF01F0284u)
+#define GTM_MCS0_CH5_R2 (0xF01F0288u)
+#define GTM_MCS0_CH5_R3 (0xF01F028Cu)
+#define GTM_MCS0_CH5_R4 (0xF01F0290u)
+#define GTM_MCS0_CH5_R5 (0xF01F0294u)
+#define GTM_MCS0_CH5_R6 (0xF01F0298u)
+#define GTM_MCS0_CH5_R7 (0xF01F029Cu)
+#define GTM_TIM0_CH2_FLT_RE (0xF010111Cu)
+#define GTM_IRQ_EN (0xF0100014u)
+#define GTM_MCS0_CH5_CTRL (0xF01F02A0u)
+#define GTM_MCS0_CH5_ACB (0xF01F02A4u)
+#define GTM_MCS0_CH5_MHB (0xF01F02BCu)
+#define GTM_MCS0_CH5_PC (0xF01F02C0u)
+#define GTM_MCS0_CH5_IRQ_NOTIFY (0xF01F02C4u)
+#define GTM_MCS0_CH5_IRQ_EN (0xF01F02C8u)
+#define GTM_MCS0_CH5_IRQ_FORCINT (0xF01F02CCu)
+#define GTM_MCS0_CH5_IRQ_MODE (0xF01F02D0u)
+#define GTM_MCS0_CH5_EIRQ_EN (0xF01F02D4u)
+#define GTM_MCS0_CH6_R0 (0xF01F0300u)
+#define GTM_TIM0_CH2_FLT_FE (0xF0101120u)
+#define GTM_MCS0_CH6_R1 (0xF01F0304u)
+#define GTM_MCS0_CH6_R2 (0xF01F0308u)
+#define GTM_MCS0_CH6_R3 (0xF01F030Cu)
+#define GTM_MCS0_CH6_R4 (0xF01F0310u)
+#define GTM_MCS0_CH6_R5 (0xF01F0314u)
+#define GTM_MCS0_CH6_R6 (0xF01F0318u)
+#define GTM_MCS0_CH6_R7 (0xF01F031Cu)
+#define GTM_MCS0_CH6_CTRL (0xF01F0320u)
+#define GTM_MCS0_CH6_ACB (0xF01F0324u)
+#define GTM_MCS0_CH6_MHB (0xF01F033Cu)
+#define GTM_TIM0_CH2_CTRL (0xF0101124u)
+#define GTM_MCS0_CH6_PC (0xF01F0340u)
+#define GTM_MCS0_CH6_IRQ_NOTIFY (0xF01F0344u)
+#define GTM_MCS0_CH6_IRQ_EN (0xF01F0348u)
+#define GTM_MCS0_CH6_IRQ_FORCINT (0xF01F034Cu)
+#define GTM_MCS0_CH6_IRQ_MODE (0xF01F0350u)
+#define GTM_MCS0_CH6_EIRQ_EN (0xF01F0354u)
+#define GTM_MCS0_CH7_R0 (0xF01F0380u)
+#define GTM_MCS0_CH7_R1 (0xF01F0384u)
+#define GTM_MCS0_CH7_R2 (0xF01F0388u)
+#define GTM_MCS0_CH7_R3 (0xF01F038Cu)
+#define GTM_TIM0_CH2_ECTRL (0xF0101128u)
+#define GTM_MCS0_CH7_R4 (0xF01F0390u)
+#define GTM_MCS0_CH7_R5 (0xF01F0394u)
+#define GTM_MCS0_CH7_R6 (0xF01F0398u)
+#define GTM_MCS0_CH7_R7 (0xF01F039Cu)
+#define GTM_MCS0_CH7_CTRL (0xF01F03A0u)
+#define GTM_MCS0_CH7_ACB (0xF01F03A4u)
+#define GTM_MCS0_CH7_MHB (0xF01F03BCu)
+#define GTM_MCS0_CH7_PC (0xF01F03C0u)
+#define GTM_MCS0_CH7_IRQ_NOTIFY (0xF01F03C4u)
+#define GTM_MCS0_CH7_IRQ_EN (0xF01F03C8u)
+#define GTM_TIM0_CH2_IRQ_NOTIFY (0xF010112Cu)
+#define GTM_MCS0_CH7_IRQ_FORCINT (0xF01F03CCu)
+#define GTM_MCS0_CH7_IRQ_MODE (0xF01F03D0u)
+#define GTM_MCS0_CH7_EIRQ_EN (0xF01F03D4u)
+#define GTM_MCS1_CH0_R0 (0xF01F1000u)
+#define GTM_MCS1_CH0_R1 (0xF01F1004u)
+#define GTM_MCS1_CH0_R2 (0xF01F1008u)
+#define GTM_MCS1_CH0_R3 (0xF01F100Cu)
+#define GTM_MCS1_CH0_R4 (0xF01F1010u)
+#define GTM_MCS1_CH0_R5 (0xF01F1014u)
+#define GTM_MCS1_CH0_R6 (0xF01F1018u)
+#define GTM_TIM0_CH2_IRQ_EN (0xF0101130u)
+#define GTM_MCS1_CH0_R7 (0xF01F101Cu)
+#define GTM_MCS1_CH0_CTRL (0xF01F1020u)
+#define GTM_MCS1_CH0_ACB (0xF01F1024u)
+#define GTM_MCS1_CH0_CTRG (0xF01F1028u)
+#define GTM_MCS1_CH0_STRG (0xF01F102Cu)
+#define GTM_MCS1_CH0_MHB (0xF01F103Cu)
+#define GTM_MCS1_CH0_PC (0xF01F1040u)
+#define GTM_TIM0_CH2_IRQ_FORCINT (0xF0101134u)
+#define GTM_MCS1_CH0_IRQ_NOTIFY (0xF01F1044u)
+#define GTM_MCS1_CH0_IRQ_EN (0xF01F1048u)
+#define GTM_MCS1_CH0_IRQ_FORCINT (0xF01F104Cu)
+#define GTM_MCS1_CH0_IRQ_MODE (0xF01F1050u)
+#define GTM_MCS1_CH0_EIRQ_EN (0xF01F1054u)
+#define GTM_MCS1_REG_PROT (0xF01F1060u)
+#define GTM_MCS1_CTRL_STAT (0xF01F1064u)
+#define GTM_MCS1_RESET (0xF01F1068u)
+#define GTM_MCS1_CAT (0xF01F106Cu)
+#define GTM_MCS1_CWT (0xF01F1070u)
+#define GTM_TIM0_CH2_IRQ_MODE (0xF0101138u)
+#define GTM_MCS1_ERR (0xF01F107Cu)
+#define GTM_MCS1_CH1_R0 (0xF01F1080u)
+#define GTM_MCS1_CH1_R1 (0xF01F1084u)
+#define GTM_MCS1_CH1_R2 (0xF01F1088u)
+#define GTM_MCS1_CH1_R3 (0xF01F108Cu)
+#define GTM_MCS1_CH1_R4 (0xF01F1090u)
+#define GTM_MCS1_CH1_R5 (0xF01F1094u)
+#define GTM_MCS1_CH1_R6 (0xF01F1098u)
+#define GTM_MCS1_CH1_R7 (0xF01F109Cu)
+#define GTM_MCS1_CH1_CTRL (0xF01F10A0u)
+#define GTM_TIM0_CH2_EIRQ_EN (0xF010113Cu)
+#define GTM_MCS1_CH1_ACB (0xF01F10A4u)
+#define GTM_MCS1_CH1_MHB (0xF01F10BCu)
+#define GTM_MCS1_CH1_PC (0xF01F10C0u)
+#define GTM_MCS1_CH1_IRQ_NOTIFY (0xF01F10C4u)
+#define GTM_MCS1_CH1_IRQ_EN (0xF01F10C8u)
+#define GTM_MCS1_CH1_IRQ_FORCINT (0xF01F10CCu)
+#define GTM_MCS1_CH1_IRQ_MODE (0xF01F10D0u)
+#define GTM_MCS1_CH1_EIRQ_EN (0xF01F10D4u)
+#define GTM_MCS1_CH2_R0 (0xF01F1100u)
+#define GTM_MCS1_CH2_R1 (0xF01F1104u)
+#define GTM_TIM0_CH3_GPR0 (0xF0101180u)
+#define GTM_MCS1_CH2_R2 (0xF01F1108u)
+#define GTM_MCS1_CH2_R3 (0xF01F110Cu)
+#define GTM_MCS1_CH2_R4 (0xF01F1110u)
+#define GTM_MCS1_CH2_R5 (0xF01F1114u)
+#define GTM_MCS1_CH2_R6 (0xF01F1118u)
+#define GTM_MCS1_CH2_R7 (0xF01F111Cu)
+#define GTM_MCS1_CH2_CTRL (0xF01F1120u)
+#define GTM_MCS1_CH2_ACB (0xF01F1124u)
+#define GTM_MCS1_CH2_MHB (0xF01F113Cu)
+#define GTM_MCS1_CH2_PC (0xF01F1140u)
+#define GTM_TIM0_CH3_GPR1 (0xF0101184u)
+#define GTM_IRQ_FORCINT (0xF0100018u)
+#define GTM_MCS1_CH2_IRQ_NOTIFY (0xF01F1144u)
+#define GTM_MCS1_CH2_IRQ_EN (0xF01F1148u)
+#define GTM_MCS1_CH2_IRQ_FORCINT (0xF01F114Cu)
+#define GTM_MCS1_CH2_IRQ_MODE (0xF01F1150u)
+#define GTM_MCS1_CH2_EIRQ_EN (0xF01F1154u)
+#define GTM_MCS1_CH3_R0 (0xF01F1180u)
+#define GTM_MCS1_CH3_R1 (0xF01F1184u)
+#define GTM_MCS1_CH3_R2 (0xF01F1188u)
+#define GTM_MCS1_CH3_R3 (0xF01F118Cu)
+#define GTM_MCS1_CH3_R4 (0xF01F1190u)
+#define GTM_TIM0_CH3_CNT (0xF0101188u)
+#define GTM_MCS1_CH3_R5 (0xF01F1194u)
+#define GTM_MCS1_CH3_R6 (0xF01F1198u)
+#define GTM_MCS1_CH3_R7 (0xF01F119Cu)
+#define GTM_MCS1_CH3_CTRL (0xF01F11A0u)
+#define GTM_MCS1_CH3_ACB (0xF01F11A4u)
+#define GTM_MCS1_CH3_MHB (0xF01F11BCu)
+#define GTM_MCS1_CH3_PC (0xF01F11C0u)
+#define GTM_MCS1_CH3_IRQ_NOTIFY (0xF01F11C4u)
+#define GTM_MCS1_CH3_IRQ_EN (0xF01F11C8u)
+#define GTM_MCS1_CH3_IRQ_FORCINT (0xF01F11CCu)
+#define GTM_TIM0_CH3_ECNT (0xF010118Cu)
+#define GTM_MCS1_CH3_IRQ_MODE (0xF01F11D0u)
+#define GTM_MCS1_CH3_EIRQ_EN (0xF01F11D4u)
+#define GTM_MCS1_CH4_R0 (0xF01F1200u)
+#define GTM_MCS1_CH4_R1 (0xF01F1204u)
+#define GTM_MCS1_CH4_R2 (0xF01F1208u)
+#define GTM_MCS1_CH4_R3 (0xF01F120Cu)
+#define GTM_MCS1_CH4_R4 (0xF01F1210u)
+#define GTM_MCS1_CH4_R5 (0xF01F1214u)
+#define GTM_MCS1_CH4_R6 (0xF01F1218u)
+#define GTM_MCS1_CH4_R7 (0xF01F121Cu)
+#define GTM_TIM0_CH3_CNTS (0xF0101190u)
+#define GTM_MCS1_CH4_CTRL (0xF01F1220u)
+#define GTM_MCS1_CH4_ACB (0xF01F1224u)
+#define GTM_MCS1_CH4_MHB (0xF01F123Cu)
+#define GTM_MCS1_CH4_PC (0xF01F1240u)
+#define GTM_MCS1_CH4_IRQ_NOTIFY (0xF01F1244u)
+#define GTM_MCS1_CH4_IRQ_EN (0xF01F1248u)
+#define GTM_MCS1_CH4_IRQ_FORCINT (0xF01F124Cu)
+#define GTM_MCS1_CH4_IRQ_MODE (0xF01F1250u)
+#define GTM_MCS1_CH4_EIRQ_EN (0xF01F1254u)
+#define GTM_MCS1_CH5_R0 (0xF01F1280u)
+#define GTM_TIM0_CH3_TDUC (0xF0101194u)
+#define GTM_MCS1_CH5_R1 (0xF01F1284u)
+#define GTM_MCS1_CH5_R2 (0xF01F1288u)
+#define GTM_MCS1_CH5_R3 (0xF01F128Cu)
+#define GTM_MCS1_CH5_R4 (0xF01F1290u)
+#define GTM_MCS1_CH5_R5 (0xF01F1294u)
+#define GTM_MCS1_CH5_R6 (0xF01F1298u)
+#define GTM_MCS1_CH5_R7 (0xF01F129Cu)
+#define GTM_MCS1_CH5_CTRL (0xF01F12A0u)
+#define GTM_MCS1_CH5_ACB (0xF01F12A4u)
+#define GTM_MCS1_CH5_MHB (0xF01F12BCu)
+#define GTM_TIM0_CH3_TDUV (0xF0101198u)
+#define GTM_MCS1_CH5_PC (0xF01F12C0u)
+#define GTM_MCS1_CH5_IRQ_NOTIFY (0xF01F12C4u)
+#define GTM_MCS1_CH5_IRQ_EN (0xF01F12C8u)
+#define GTM_MCS1_CH5_IRQ_FORCINT (0xF01F12CCu)
+#define GTM_MCS1_CH5_IRQ_MODE (0xF01F12D0u)
+#define GTM_MCS1_CH5_EIRQ_EN (0xF01F12D4u)
+#define GTM_MCS1_CH6_R0 (0xF01F1300u)
+#define GTM_MCS1_CH6_R1 (0xF01F1304u)
+#define GTM_MCS1_CH6_R2 (0xF01F1308u)
+#define GTM_MCS1_CH6_R3 (0xF01F130Cu)
+#define GTM_TIM0_CH3_FLT_RE (0xF010119Cu)
+#define GTM_MCS1_CH6_R4 (0xF01F1310u)
+#define GTM_MCS1_CH6_R5 (0xF01F1314u)
+#define GTM_MCS1_CH6_R6 (0xF01F1318u)
+#define GTM_MCS1_CH6_R7 (0xF01F131Cu)
+#define GTM_MCS1_CH6_CTRL (0xF01F1320u)
+#define GTM_MCS1_CH6_ACB (0xF01F1324u)
+#define GTM_MCS1_CH6_MHB (0xF01F133Cu)
+#define GTM_MCS1_CH6_PC (0xF01F1340u)
+#define GTM_MCS1_CH6_IRQ_NOTIFY (0xF01F1344u)
+#define GTM_MCS1_CH6_IRQ_EN (0xF01F1348u)
+#define GTM_TIM0_CH3_FLT_FE (0xF01011A0u)
+#define GTM_MCS1_CH6_IRQ_FORCINT (0xF01F134Cu)
+#define GTM_MCS1_CH6_IRQ_MODE (0xF01F1350u)
+#define GTM_MCS1_CH6_EIRQ_EN (0xF01F1354u)
+#define GTM_MCS1_CH7_R0 (0xF01F1380u)
+#define GTM_MCS1_CH7_R1 (0xF01F1384u)
+#define GTM_MCS1_CH7_R2 (0xF01F1388u)
+#define GTM_MCS1_CH7_R3 (0xF01F138Cu)
+#define GTM_MCS1_CH7_R4 (0xF01F1390u)
+#define GTM_MCS1_CH7_R5 (0xF01F1394u)
+#define GTM_MCS1_CH7_R6 (0xF01F1398u)
+#define GTM_TIM0_CH3_CTRL (0xF01011A4u)
+#define GTM_MCS1_CH7_R7 (0xF01F139Cu)
+#define GTM_MCS1_CH7_CTRL (0xF01F13A0u)
+#define GTM_MCS1_CH7_ACB (0xF01F13A4u)
+#define GTM_MCS1_CH7_MHB (0xF01F13BCu)
+#define GTM_MCS1_CH7_PC (0xF01F13C0u)
+#define GTM_MCS1_CH7_IRQ_NOTIFY (0xF01F13C4u)
+#define GTM_MCS1_CH7_IRQ_EN (0xF01F13C8u)
+#define GTM_MCS1_CH7_IRQ_FORCINT (0xF01F13CCu)
+#define GTM_MCS1_CH7_IRQ_MODE (0xF01F13D0u)
+#define GTM_MCS1_CH7_EIRQ_EN (0xF01F13D4u)
+#define GTM_TIM0_CH3_ECTRL (0xF01011A8u)
+#define GTM_MCS2_CH0_R0 (0xF01F2000u)
+#define GTM_MCS2_CH0_R1 (0xF01F2004u)
+#define GTM_MCS2_CH0_R2 (0xF01F2008u)
+#define GTM_MCS2_CH0_R3 (0xF01F200Cu)
+#define GTM_MCS2_CH0_R4 (0xF01F2010u)
+#define GTM_MCS2_CH0_R5 (0xF01F2014u)
+#define GTM_MCS2_CH0_R6 (0xF01F2018u)
+#define GTM_MCS2_CH0_R7 (0xF01F201Cu)
+#define GTM_MCS2_CH0_CTRL (0xF01F2020u)
+#define GTM_MCS2_CH0_ACB (0xF01F2024u)
+#define GTM_TIM0_CH3_IRQ_NOTIFY (0xF01011ACu)
+#define GTM_IRQ_MODE (0xF010001Cu)
+#define GTM_MCS2_CH0_CTRG (0xF01F2028u)
+#define GTM_MCS2_CH0_STRG (0xF01F202Cu)
+#define GTM_MCS2_CH0_MHB (0xF01F203Cu)
+#define GTM_MCS2_CH0_PC (0xF01F2040u)
+#define GTM_MCS2_CH0_IRQ_NOTIFY (0xF01F2044u)
+#define GTM_MCS2_CH0_IRQ_EN (0xF01F2048u)
+#define GTM_MCS2_CH0_IRQ_FORCINT (0xF01F204Cu)
+#define GTM_MCS2_CH0_IRQ_MODE (0xF01F2050u)
+#define GTM_TIM0_CH3_IRQ_EN (0xF01011B0u)
+#define GTM_MCS2_CH0_EIRQ_EN (0xF01F2054u)
+#define GTM_MCS2_REG_PROT (0xF01F2060u)
+#define GTM_MCS2_CTRL_STAT (0xF01F2064u)
+#define GTM_MCS2_RESET (0xF01F2068u)
+#define GTM_MCS2_CAT (0xF01F206Cu)
+#define GTM_MCS2_CWT (0xF01F2070u)
+#define GTM_MCS2_ERR (0xF01F207Cu)
+#define GTM_MCS2_CH1_R0 (0xF01F2080u)
+#define GTM_MCS2_CH1_R1 (0xF01F2084u)
+#define GTM_MCS2_CH1_R2 (0xF01F2088u)
+#define GTM_TIM0_CH3_IRQ_FORCINT (0xF01011B4u)
+#define GTM_MCS2_CH1_R3 (0xF01F208Cu)
+#define GTM_MCS2_CH1_R4 (0xF01F2090u)
+#define GTM_MCS2_CH1_R5 (0xF01F2094u)
+#define GTM_MCS2_CH1_R6 (0xF01F2098u)
+#define GTM_MCS2_CH1_R7 (0xF01F209Cu)
+#define GTM_MCS2_CH1_CTRL (0xF01F20A0u)
+#define GTM_MCS2_CH1_ACB (0xF01F20A4u)
+#define GTM_MCS2_CH1_MHB (0xF01F20BCu)
+#define GTM_MCS2_CH1_PC (0xF01F20C0u)
+#define GTM_MCS2_CH1_IRQ_NOTIFY (0xF01F20C4u)
+#define GTM_TIM0_CH3_IRQ_MODE (0xF01011B8u)
+#define GTM_MCS2_CH1_IRQ_EN (0xF01F20C8u)
+#define GTM_MCS2_CH1_IRQ_FORCINT (0xF01F20CCu)
+#define GTM_MCS2_CH1_IRQ_MODE (0xF01F20D0u)
+#define GTM_MCS2_CH1_EIRQ_EN (0xF01F20D4u)
+#define GTM_MCS2_CH2_R0 (0xF01F2100u)
+#define GTM_MCS2_CH2_R1 (0xF01F2104u)
+#define GTM_MCS2_CH2_R2 (0xF01F2108u)
+#define GTM_MCS2_CH2_R3 (0xF01F210Cu)
+#define GTM_MCS2_CH2_R4 (0xF01F2110u)
+#define GTM_MCS2_CH2_R5 (0xF01F2114u)
+#define GTM_TIM0_CH3_EIRQ_EN (0xF01011BCu)
+#define GTM_MCS2_CH2_R6 (0xF01F2118u)
+#define GTM_MCS2_CH2_R7 (0xF01F211Cu)
+#define GTM_MCS2_CH2_CTRL (0xF01F2120u)
+#define GTM_MCS2_CH2_ACB (0xF01F2124u)
+#define GTM_MCS2_CH2_MHB (0xF01F213Cu)
+#define GTM_MCS2_CH2_PC (0xF01F2140u)
+#define GTM_MCS2_CH2_IRQ_NOTIFY (0xF01F2144u)
+#define GTM_MCS2_CH2_IRQ_EN (0xF01F2148u)
+#define GTM_MCS2_CH2_IRQ_FORCINT (0xF01F214Cu)
+#define GTM_MCS2_CH2_IRQ_MODE (0xF01F2150u)
+#define GTM_TIM0_CH4_GPR0 (0xF0101200u)
+#define GTM_MCS2_CH2_EIRQ_EN (0xF01F2154u)
+#define GTM_MCS2_CH3_R0 (0xF01F2180u)
+#define GTM_MCS2_CH3_R1 (0xF01F2184u)
+#define GTM_MCS2_CH3_R2 (0xF01F2188u)
+#define GTM_MCS2_CH3_R3 (0xF01F218Cu)
+#define GTM_MCS2_CH3_R4 (0xF01F2190u)
+#define GTM_MCS2_CH3_R5 (0xF01F2194u)
+#define GTM_MCS2_CH3_R6 (0xF01F2198u)
+#define GTM_MCS2_CH3_R7 (0xF01F219Cu)
+#define GTM_MCS2_CH3_CTRL (0xF01F21A0u)
+#define GTM_TIM0_CH4_GPR1 (0xF0101204u)
+#define GTM_MCS2_CH3_ACB (0xF01F21A4u)
+#define GTM_MCS2_CH3_MHB (0xF01F21BCu)
+#define GTM_MCS2_CH3_PC (0xF01F21C0u)
+#define GTM_MCS2_CH3_IRQ_NOTIFY (0xF01F21C4u)
+#define GTM_MCS2_CH3_IRQ_EN (0xF01F21C8u)
+#define GTM_MCS2_CH3_IRQ_FORCINT (0xF01F21CCu)
+#define GTM_MCS2_CH3_IRQ_MODE (0xF01F21D0u)
+#define GTM_MCS2_CH3_EIRQ_EN (0xF01F21D4u)
+#define GTM_MCS2_CH4_R0 (0xF01F2200u)
+#define GTM_MCS2_CH4_R1 (0xF01F2204u)
+#define GTM_TIM0_CH4_CNT (0xF0101208u)
+#define GTM_MCS2_CH4_R2 (0xF01F2208u)
+#define GTM_MCS2_CH4_R3 (0xF01F220Cu)
+#define GTM_MCS2_CH4_R4 (0xF01F2210u)
+#define GTM_MCS2_CH4_R5 (0xF01F2214u)
+#define GTM_MCS2_CH4_R6 (0xF01F2218u)
+#define GTM_MCS2_CH4_R7 (0xF01F221Cu)
+#define GTM_MCS2_CH4_CTRL (0xF01F2220u)
+#define GTM_MCS2_CH4_ACB (0xF01F2224u)
+#define GTM_MCS2_CH4_MHB (0xF01F223Cu)
+#define GTM_MCS2_CH4_PC (0xF01F2240u)
+#define GTM_TIM0_CH4_ECNT (0xF010120Cu)
+#define GTM_MCS2_CH4_IRQ_NOTIFY (0xF01F2244u)
+#define GTM_MCS2_CH4_IRQ_EN (0xF01F2248u)
+#define GTM_MCS2_CH4_IRQ_FORCINT (0xF01F224Cu)
+#define GTM_MCS2_CH4_IRQ_MODE (0xF01F2250u)
+#define GTM_MCS2_CH4_EIRQ_EN (0xF01F2254u)
+#define GTM_MCS2_CH5_R0 (0xF01F2280u)
+#define GTM_MCS2_CH5_R1 (0xF01F2284u)
+#define GTM_MCS2_CH5_R2 (0xF01F2288u)
+#define GTM_MCS2_CH5_R3 (0xF01F228Cu)
+#define GTM_MCS2_CH5_R4 (0xF01F2290u)
+#define GTM_TIM0_CH4_CNTS (0xF0101210u)
+#define GTM_MCS2_CH5_R5 (0xF01F2294u)
+#define GTM_MCS2_CH5_R6 (0xF01F2298u)
+#define GTM_MCS2_CH5_R7 (0xF01F229Cu)
+#define GTM_MCS2_CH5_CTRL (0xF01F22A0u)
+#define GTM_MCS2_CH5_ACB (0xF01F22A4u)
+#define GTM_MCS2_CH5_MHB (0xF01F22BCu)
+#define GTM_MCS2_CH5_PC (0xF01F22C0u)
+#define GTM_MCS2_CH5_IRQ_NOTIFY (0xF01F22C4u)
+#define GTM_MCS2_CH5_IRQ_EN (0xF01F22C8u)
+#define GTM_MCS2_CH5_IRQ_FORCINT (0xF01F22CCu)
+#define GTM_TIM0_CH4_TDUC (0xF0101214u)
+#define GTM_EIRQ_EN (0xF0100020u)
+#define GTM_MCS2_CH5_IRQ_MODE (0xF01F22D0u)
+#define GTM_MCS2_CH5_EIRQ_EN (0xF01F22D4u)
+#define GTM_MCS2_CH6_R0 (0xF01F2300u)
+#define GTM_MCS2_CH6_R1 (0xF01F2304u)
+#define GTM_MCS2_CH6_R2 (0xF01F2308u)
+#define GTM_MCS2_CH6_R3 (0xF01F230Cu)
+#define GTM_MCS2_CH6_R4 (0xF01F2310u)
+#define GTM_MCS2_CH6_R5 (0xF01F2314u)
+#define GTM_MCS2_CH6_R6 (0xF01F2318u)
+#define GTM_MCS2_CH6_R7 (0xF01F231Cu)
+#define GTM_TIM0_CH4_TDUV (0xF0101218u)
+#define GTM_MCS2_CH6_CTRL (0xF01F2320u)
+#define GTM_MCS2_CH6_ACB (0xF01F2324u)
+#define GTM_MCS2_CH6_MHB (0xF01F233Cu)
+#define GTM_MCS2_CH6_PC (0xF01F2340u)
+#define GTM_MCS2_CH6_IRQ_NOTIFY (0xF01F2344u)
+#define GTM_MCS2_CH6_IRQ_EN (0xF01F2348u)
+#define GTM_MCS2_CH6_IRQ_FORCINT (0xF01F234Cu)
+#define GTM_MCS2_CH6_IRQ_MODE (0xF01F2350u)
+#define GTM_MCS2_CH6_EIRQ_EN (0xF01F2354u)
+#define GTM_MCS2_CH7_R0 (0xF01F2380u)
+#define GTM_TIM0_CH4_FLT_RE (0xF010121Cu)
+#define GTM_MCS2_CH7_R1 (0xF01F2384u)
+#define GTM_MCS2_CH7_R2 (0xF01F2388u)
+#define GTM_MCS2_CH7_R3 (0xF01F238Cu)
+#define GTM_MCS2_CH7_R4 (0xF01F2390u)
+#define GTM_MCS2_CH7_R5 (0xF01F2394u)
+#define GTM_MCS2_CH7_R6 (0xF01F2398u)
+#define GTM_MCS2_CH7_R7 (0xF01F239Cu)
+#define GTM_MCS2_CH7_CTRL (0xF01F23A0u)
+#define GTM_MCS2_CH7_ACB (0xF01F23A4u)
+#define GTM_MCS2_CH7_MHB (0xF01F23BCu)
+#define GTM_TIM0_CH4_FLT_FE (0xF0101220u)
+#define GTM_MCS2_CH7_PC (0xF01F23C0u)
+#define GTM_MCS2_CH7_IRQ_NOTIFY (0xF01F23C4u)
+#define GTM_MCS2_CH7_IRQ_EN (0xF01F23C8u)
+#define GTM_MCS2_CH7_IRQ_FORCINT (0xF01F23CCu)
+#define GTM_MCS2_CH7_IRQ_MODE (0xF01F23D0u)
+#define GTM_MCS2_CH7_EIRQ_EN (0xF01F23D4u)
+#define GTM_MCS3_CH0_R0 (0xF01F3000u)
+#define GTM_MCS3_CH0_R1 (0xF01F3004u)
+#define GTM_MCS3_CH0_R2 (0xF01F3008u)
+#define GTM_MCS3_CH0_R3 (0xF01F300Cu)
+#define GTM_TIM0_CH4_CTRL (0xF0101224u)
+#define GTM_MCS3_CH0_R4 (0xF01F3010u)
+#define GTM_MCS3_CH0_R5 (0xF01F3014u)
+#define GTM_MCS3_CH0_R6 (0xF01F3018u)
+#define GTM_MCS3_CH0_R7 (0xF01F301Cu)
+#define GTM_MCS3_CH0_CTRL (0xF01F3020u)
+#define GTM_MCS3_CH0_ACB (0xF01F3024u)
+#define GTM_MCS3_CH0_CTRG (0xF01F3028u)
+#define GTM_MCS3_CH0_STRG (0xF01F302Cu)
+#define GTM_TIM0_CH4_ECTRL (0xF0101228u)
+#define GTM_MCS3_CH0_MHB (0xF01F303Cu)
+#define GTM_MCS3_CH0_PC (0xF01F3040u)
+#define GTM_MCS3_CH0_IRQ_NOTIFY (0xF01F3044u)
+#define GTM_MCS3_CH0_IRQ_EN (0xF01F3048u)
+#define GTM_MCS3_CH0_IRQ_FORCINT (0xF01F304Cu)
+#define GTM_MCS3_CH0_IRQ_MODE (0xF01F3050u)
+#define GTM_MCS3_CH0_EIRQ_EN (0xF01F3054u)
+#define GTM_MCS3_REG_PROT (0xF01F3060u)
+#define GTM_MCS3_CTRL_STAT (0xF01F3064u)
+#define GTM_TIM0_CH4_IRQ_NOTIFY (0xF010122Cu)
+#define GTM_MCS3_RESET (0xF01F3068u)
+#define GTM_MCS3_CAT (0xF01F306Cu)
+#define GTM_MCS3_CWT (0xF01F3070u)
+#define GTM_MCS3_ERR (0xF01F307Cu)
+#define GTM_MCS3_CH1_R0 (0xF01F3080u)
+#define GTM_MCS3_CH1_R1 (0xF01F3084u)
+#define GTM_MCS3_CH1_R2 (0xF01F3088u)
+#define GTM_MCS3_CH1_R3 (0xF01F308Cu)
+#define GTM_MCS3_CH1_R4 (0xF01F3090u)
+#define GTM_MCS3_CH1_R5 (0xF01F3094u)
+#define GTM_TIM0_CH4_IRQ_EN (0xF0101230u)
+#define GTM_MCS3_CH1_R6 (0xF01F3098u)
+#define GTM_MCS3_CH1_R7 (0xF01F309Cu)
+#define GTM_MCS3_CH1_CTRL (0xF01F30A0u)
+#define GTM_MCS3_CH1_ACB (0xF01F30A4u)
+#define GTM_MCS3_CH1_MHB (0xF01F30BCu)
+#define GTM_MCS3_CH1_PC (0xF01F30C0u)
+#define GTM_MCS3_CH1_IRQ_NOTIFY (0xF01F30C4u)
+#define GTM_MCS3_CH1_IRQ_EN (0xF01F30C8u)
+#define GTM_MCS3_CH1_IRQ_FORCINT (0xF01F30CCu)
+#define GTM_MCS3_CH1_IRQ_MODE (0xF01F30D0u)
+#define GTM_TIM0_CH4_IRQ_FORCINT (0xF0101234u)
+#define GTM_MCS3_CH1_EIRQ_EN (0xF01F30D4u)
+#define GTM_MCS3_CH2_R0 (0xF01F3100u)
+#define GTM_MCS3_CH2_R1 (0xF01F3104u)
+#define GTM_MCS3_CH2_R2 (0xF01F3108u)
+#define GTM_MCS3_CH2_R3 (0xF01F310Cu)
+#define GTM_MCS3_CH2_R4 (0xF01F3110u)
+#define GTM_MCS3_CH2_R5 (0xF01F3114u)
+#define GTM_MCS3_CH2_R6 (0xF01F3118u)
+#define GTM_MCS3_CH2_R7 (0xF01F311Cu)
+#define GTM_MCS3_CH2_CTRL (0xF01F3120u)
+#define GTM_TIM0_CH4_IRQ_MODE (0xF0101238u)
+#define GTM_MCS3_CH2_ACB (0xF01F3124u)
+#define GTM_MCS3_CH2_MHB (0xF01F313Cu)
+#define GTM_MCS3_CH2_PC (0xF01F3140u)
+#define GTM_MCS3_CH2_IRQ_NOTIFY (0xF01F3144u)
+#define GTM_MCS3_CH2_IRQ_EN (0xF01F3148u)
+#define GTM_MCS3_CH2_IRQ_FORCINT (0xF01F314Cu)
+#define GTM_MCS3_CH2_IRQ_MODE (0xF01F3150u)
+#define GTM_MCS3_CH2_EIRQ_EN (0xF01F3154u)
+#define GTM_MCS3_CH3_R0 (0xF01F3180u)
+#define GTM_MCS3_CH3_R1 (0xF01F3184u)
+#define GTM_TIM0_CH4_EIRQ_EN (0xF010123Cu)
+#define GTM_HW_CONF (0xF0100024u)
+#define GTM_MCS3_CH3_R2 (0xF01F3188u)
+#define GTM_MCS3_CH3_R3 (0xF01F318Cu)
+#define GTM_MCS3_CH3_R4 (0xF01F3190u)
+#define GTM_MCS3_CH3_R5 (0xF01F3194u)
+#define GTM_MCS3_CH3_R6 (0xF01F3198u)
+#define GTM_MCS3_CH3_R7 (0xF01F319Cu)
+#define GTM_MCS3_CH3_CTRL (0xF01F31A0u)
+#define GTM_MCS3_CH3_ACB (0xF01F31A4u)
+#define GTM_MCS3_CH3_MHB (0xF01F31BCu)
+#define GTM_MCS3_CH3_PC (0xF01F31C0u)
+#define GTM_TIM0_CH5_GPR0 (0xF0101280u)
+#define GTM_MCS3_CH3_IRQ_NOTIFY (0xF01F31C4u)
+#define GTM_MCS3_CH3_IRQ_EN (0xF01F31C8u)
+#define GTM_MCS3_CH3_IRQ_FORCINT (0xF01F31CCu)
+#define GTM_MCS3_CH3_IRQ_MODE (0xF01F31D0u)
+#define GTM_MCS3_CH3_EIRQ_EN (0xF01F31D4u)
+#define GTM_MCS3_CH4_R0 (0xF01F3200u)
+#define GTM_MCS3_CH4_R1 (0xF01F3204u)
+#define GTM_MCS3_CH4_R2 (0xF01F3208u)
+#define GTM_MCS3_CH4_R3 (0xF01F320Cu)
+#define GTM_MCS3_CH4_R4 (0xF01F3210u)
+#define GTM_TIM0_CH5_GPR1 (0xF0101284u)
+#define GTM_MCS3_CH4_R5 (0xF01F3214u)
+#define GTM_MCS3_CH4_R6 (0xF01F3218u)
+#define GTM_MCS3_CH4_R7 (0xF01F321Cu)
+#define GTM_MCS3_CH4_CTRL (0xF01F3220u)
+#define GTM_MCS3_CH4_ACB (0xF01F3224u)
+#define GTM_MCS3_CH4_MHB (0xF01F323Cu)
+#define GTM_MCS3_CH4_PC (0xF01F3240u)
+#define GTM_MCS3_CH4_IRQ_NOTIFY (0xF01F3244u)
+#define GTM_MCS3_CH4_IRQ_EN (0xF01F3248u)
+#define GTM_MCS3_CH4_IRQ_FORCINT (0xF01F324Cu)
+#define GTM_TIM0_CH5_CNT (0xF0101288u)
+#define GTM_MCS3_CH4_IRQ_MODE (0xF01F3250u)
+#define GTM_MCS3_CH4_EIRQ_EN (0xF01F3254u)
+#define GTM_MCS3_CH5_R0 (0xF01F3280u)
+#define GTM_MCS3_CH5_R1 (0xF01F3284u)
+#define GTM_MCS3_CH5_R2 (0xF01F3288u)
+#define GTM_MCS3_CH5_R3 (0xF01F328Cu)
+#define GTM_MCS3_CH5_R4 (0xF01F3290u)
+#define GTM_MCS3_CH5_R5 (0xF01F3294u)
+#define GTM_MCS3_CH5_R6 (0xF01F3298u)
+#define GTM_MCS3_CH5_R7 (0xF01F329Cu)
+#define GTM_TIM0_CH5_ECNT (0xF010128Cu)
+#define GTM_MCS3_CH5_CTRL (0xF01F32A0u)
+#define GTM_MCS3_CH5_ACB (0xF01F32A4u)
+#define GTM_MCS3_CH5_MHB (0xF01F32BCu)
+#define GTM_MCS3_CH5_PC (0xF01F32C0u)
+#define GTM_MCS3_CH5_IRQ_NOTIFY (0xF01F32C4u)
+#define GTM_MCS3_CH5_IRQ_EN (0xF01F32C8u)
+#define GTM_MCS3_CH5_IRQ_FORCINT (0xF01F32CCu)
+#define GTM_MCS3_CH5_IRQ_MODE (0xF01F32D0u)
+#define GTM_MCS3_CH5_EIRQ_EN (0xF01F32D4u)
+#define GTM_MCS3_CH6_R0 (0xF01F3300u)
+#define GTM_TIM0_CH5_CNTS (0xF0101290u)
+#define GTM_MCS3_CH6_R1 (0xF01F3304u)
+#define GTM_MCS3_CH6_R2 (0xF01F3308u)
+#define GTM_MCS3_CH6_R3 (0xF01F330Cu)
+#define GTM_MCS3_CH6_R4 (0xF01F3310u)
+#define GTM_MCS3_CH6_R5 (0xF01F3314u)
+#define GTM_MCS3_CH6_R6 (0xF01F3318u)
+#define GTM_MCS3_CH6_R7 (0xF01F331Cu)
+#define GTM_MCS3_CH6_CTRL (0xF01F3320u)
+#define GTM_MCS3_CH6_ACB (0xF01F3324u)
+#define GTM_MCS3_CH6_MHB (0xF01F333Cu)
+#define GTM_TIM0_CH5_TDUC (0xF0101294u)
+#define GTM_MCS3_CH6_PC (0xF01F3340u)
+#define GTM_MCS3_CH6_IRQ_NOTIFY (0xF01F3344u)
+#define GTM_MCS3_CH6_IRQ_EN (0xF01F3348u)
+#define GTM_MCS3_CH6_IRQ_FORCINT (0xF01F334Cu)
+#define GTM_MCS3_CH6_IRQ_MODE (0xF01F3350u)
+#define GTM_MCS3_CH6_EIRQ_EN (0xF01F3354u)
+#define GTM_MCS3_CH7_R0 (0xF01F3380u)
+#define GTM_MCS3_CH7_R1 (0xF01F3384u)
+#define GTM_MCS3_CH7_R2 (0xF01F3388u)
+#define GTM_MCS3_CH7_R3 (0xF01F338Cu)
+#define GTM_TIM0_CH5_TDUV (0xF0101298u)
+#define GTM_MCS3_CH7_R4 (0xF01F3390u)
+#define GTM_MCS3_CH7_R5 (0xF01F3394u)
+#define GTM_MCS3_CH7_R6 (0xF01F3398u)
+#define GTM_MCS3_CH7_R7 (0xF01F339Cu)
+#define GTM_MCS3_CH7_CTRL (0xF01F33A0u)
+#define GTM_MCS3_CH7_ACB (0xF01F33A4u)
+#define GTM_MCS3_CH7_MHB (0xF01F33BCu)
+#define GTM_MCS3_CH7_PC (0xF01F33C0u)
+#define GTM_MCS3_CH7_IRQ_NOTIFY (0xF01F33C4u)
+#define GTM_MCS3_CH7_IRQ_EN (0xF01F33C8u)
+#define GTM_TIM0_CH5_FLT_RE (0xF010129Cu)
+#define GTM_MCS3_CH7_IRQ_FORCINT (0xF01F33CCu)
+#define GTM_MCS3_CH7_IRQ_MODE (0xF01F33D0u)
+#define GTM_MCS3_CH7_EIRQ_EN (0xF01F33D4u)
+#define GTM_MCS4_CH0_R0 (0xF01F4000u)
+#define GTM_MCS4_CH0_R1 (0xF01F4004u)
+#define GTM_MCS4_CH0_R2 (0xF01F4008u)
+#define GTM_MCS4_CH0_R3 (0xF01F400Cu)
+#define GTM_MCS4_CH0_R4 (0xF01F4010u)
+#define GTM_MCS4_CH0_R5 (0xF01F4014u)
+#define GTM_MCS4_CH0_R6 (0xF01F4018u)
+#define GTM_TIM0_CH5_FLT_FE (0xF01012A0u)
+#define GTM_MCS4_CH0_R7 (0xF01F401Cu)
+#define GTM_MCS4_CH0_CTRL (0xF01F4020u)
+#define GTM_MCS4_CH0_ACB (0xF01F4024u)
+#define GTM_MCS4_CH0_CTRG (0xF01F4028u)
+#define GTM_MCS4_CH0_STRG (0xF01F402Cu)
+#define GTM_MCS4_CH0_MHB (0xF01F403Cu)
+#define GTM_MCS4_CH0_PC (0xF01F4040u)
+#define GTM_TIM0_CH5_CTRL (0xF01012A4u)
+#define GTM_CFG (0xF0100028u)
+#define GTM_MCS4_CH0_IRQ_NOTIFY (0xF01F4044u)
+#define GTM_MCS4_CH0_IRQ_EN (0xF01F4048u)
+#define GTM_MCS4_CH0_IRQ_FORCINT (0xF01F404Cu)
+#define GTM_MCS4_CH0_IRQ_MODE (0xF01F4050u)
+#define GTM_MCS4_CH0_EIRQ_EN (0xF01F4054u)
+#define GTM_MCS4_REG_PROT (0xF01F4060u)
+#define GTM_MCS4_CTRL_STAT (0xF01F4064u)
+#define GTM_MCS4_RESET (0xF01F4068u)
+#define GTM_MCS4_CAT (0xF01F406Cu)
+#define GTM_MCS4_CWT (0xF01F4070u)
+#define GTM_TIM0_CH5_ECTRL (0xF01012A8u)
+#define GTM_MCS4_ERR (0xF01F407Cu)
+#define GTM_MCS4_CH1_R0 (0xF01F4080u)
+#define GTM_MCS4_CH1_R1 (0xF01F4084u)
+#define GTM_MCS4_CH1_R2 (0xF01F4088u)
+#define GTM_MCS4_CH1_R3 (0xF01F408Cu)
+#define GTM_MCS4_CH1_R4 (0xF01F4090u)
+#define GTM_MCS4_CH1_R5 (0xF01F4094u)
+#define GTM_MCS4_CH1_R6 (0xF01F4098u)
+#define GTM_MCS4_CH1_R7 (0xF01F409Cu)
+#define GTM_MCS4_CH1_CTRL (0xF01F40A0u)
+#define GTM_TIM0_CH5_IRQ_NOTIFY (0xF01012ACu)
+#define GTM_MCS4_CH1_ACB (0xF01F40A4u)
+#define GTM_MCS4_CH1_MHB (0xF01F40BCu)
+#define GTM_MCS4_CH1_PC (0xF01F40C0u)
+#define GTM_MCS4_CH1_IRQ_NOTIFY (0xF01F40C4u)
+#define GTM_MCS4_CH1_IRQ_EN (0xF01F40C8u)
+#define GTM_MCS4_CH1_IRQ_FORCINT (0xF01F40CCu)
+#define GTM_MCS4_CH1_IRQ_MODE (0xF01F40D0u)
+#define GTM_MCS4_CH1_EIRQ_EN (0xF01F40D4u)
+#define GTM_MCS4_CH2_R0 (0xF01F4100u)
+#define GTM_MCS4_CH2_R1 (0xF01F4104u)
+#define GTM_TIM0_CH5_IRQ_EN (0xF01012B0u)
+#define GTM_MCS4_CH2_R2 (0xF01F4108u)
+#define GTM_MCS4_CH2_R3 (0xF01F410Cu)
+#define GTM_MCS4_CH2_R4 (0xF01F4110u)
+#define GTM_MCS4_CH2_R5 (0xF01F4114u)
+#define GTM_MCS4_CH2_R6 (0xF01F4118u)
+#define GTM_MCS4_CH2_R7 (0xF01F411Cu)
+#define GTM_MCS4_CH2_CTRL (0xF01F4120u)
+#define GTM_MCS4_CH2_ACB (0xF01F4124u)
+#define GTM_MCS4_CH2_MHB (0xF01F413Cu)
+#define GTM_MCS4_CH2_PC (0xF01F4140u)
+#define GTM_TIM0_CH5_IRQ_FORCINT (0xF01012B4u)
+#define GTM_MCS4_CH2_IRQ_NOTIFY (0xF01F4144u)
+#define GTM_MCS4_CH2_IRQ_EN (0xF01F4148u)
+#define GTM_MCS4_CH2_IRQ_FORCINT (0xF01F414Cu)
+#define GTM_MCS4_CH2_IRQ_MODE (0xF01F4150u)
+#define GTM_MCS4_CH2_EIRQ_EN (0xF01F4154u)
+#define GTM_MCS4_CH3_R0 (0xF01F4180u)
+#define GTM_MCS4_CH3_R1 (0xF01F4184u)
+#define GTM_MCS4_CH3_R2 (0xF01F4188u)
+#define GTM_MCS4_CH3_R3 (0xF01F418Cu)
+#define GTM_MCS4_CH3_R4 (0xF01F4190u)
+#define GTM_TIM0_CH5_IRQ_MODE (0xF01012B8u)
+#define GTM_MCS4_CH3_R5 (0xF01F4194u)
+#define GTM_MCS4_CH3_R6 (0xF01F4198u)
+#define GTM_MCS4_CH3_R7 (0xF01F419Cu)
+#define GTM_MCS4_CH3_CTRL (0xF01F41A0u)
+#define GTM_MCS4_CH3_ACB (0xF01F41A4u)
+#define GTM_MCS4_CH3_MHB (0xF01F41BCu)
+#define GTM_MCS4_CH3_PC (0xF01F41C0u)
+#define GTM_MCS4_CH3_IRQ_NOTIFY (0xF01F41C4u)
+#define GTM_MCS4_CH3_IRQ_EN (0xF01F41C8u)
+#define GTM_MCS4_CH3_IRQ_FORCINT (0xF01F41CCu)
+#define GTM_TIM0_CH5_EIRQ_EN (0xF01012BCu)
+#define GTM_MCS4_CH3_IRQ_MODE (0xF01F41D0u)
+#define GTM_MCS4_CH3_EIRQ_EN (0xF01F41D4u)
+#define GTM_MCS4_CH4_R0 (0xF01F4200u)
+#define GTM_MCS4_CH4_R1 (0xF01F4204u)
+#define GTM_MCS4_CH4_R2 (0xF01F4208u)
+#define GTM_MCS4_CH4_R3 (0xF01F420Cu)
+#define GTM_MCS4_CH4_R4 (0xF01F4210u)
+#define GTM_MCS4_CH4_R5 (0xF01F4214u)
+#define GTM_MCS4_CH4_R6 (0xF01F4218u)
+#define GTM_MCS4_CH4_R7 (0xF01F421Cu)
+#define GTM_TIM0_CH6_GPR0 (0xF0101300u)
+#define GTM_MCS4_CH4_CTRL (0xF01F4220u)
+#define GTM_MCS4_CH4_ACB (0xF01F4224u)
+#define GTM_MCS4_CH4_MHB (0xF01F423Cu)
+#define GTM_MCS4_CH4_PC (0xF01F4240u)
+#define GTM_MCS4_CH4_IRQ_NOTIFY (0xF01F4244u)
+#define GTM_MCS4_CH4_IRQ_EN (0xF01F4248u)
+#define GTM_MCS4_CH4_IRQ_FORCINT (0xF01F424Cu)
+#define GTM_MCS4_CH4_IRQ_MODE (0xF01F4250u)
+#define GTM_MCS4_CH4_EIRQ_EN (0xF01F4254u)
+#define GTM_MCS4_CH5_R0 (0xF01F4280u)
+#define GTM_TIM0_CH6_GPR1 (0xF0101304u)
+#define GTM_MCS4_CH5_R1 (0xF01F4284u)
+#define GTM_MCS4_CH5_R2 (0xF01F4288u)
+#define GTM_MCS4_CH5_R3 (0xF01F428Cu)
+#define GTM_MCS4_CH5_R4 (0xF01F4290u)
+#define GTM_MCS4_CH5_R5 (0xF01F4294u)
+#define GTM_MCS4_CH5_R6 (0xF01F4298u)
+#define GTM_MCS4_CH5_R7 (0xF01F429Cu)
+#define GTM_MCS4_CH5_CTRL (0xF01F42A0u)
+#define GTM_MCS4_CH5_ACB (0xF01F42A4u)
+#define GTM_MCS4_CH5_MHB (0xF01F42BCu)
+#define GTM_TIM0_CH6_CNT (0xF0101308u)
+#define GTM_MCS4_CH5_PC (0xF01F42C0u)
+#define GTM_MCS4_CH5_IRQ_NOTIFY (0xF01F42C4u)
+#define GTM_MCS4_CH5_IRQ_EN (0xF01F42C8u)
+#define GTM_MCS4_CH5_IRQ_FORCINT (0xF01F42CCu)
+#define GTM_MCS4_CH5_IRQ_MODE (0xF01F42D0u)
+#define GTM_MCS4_CH5_EIRQ_EN (0xF01F42D4u)
+#define GTM_MCS4_CH6_R0 (0xF01F4300u)
+#define GTM_MCS4_CH6_R1 (0xF01F4304u)
+#define GTM_MCS4_CH6_R2 (0xF01F4308u)
+#define GTM_MCS4_CH6_R3 (0xF01F430Cu)
+#define GTM_TIM0_CH6_ECNT (0xF010130Cu)
+#define GTM_AEI_STA_XPT (0xF010002Cu)
+#define GTM_MCS4_CH6_R4 (0xF01F4310u)
+#define GTM_MCS4_CH6_R5 (0xF01F4314u)
+#define GTM_MCS4_CH6_R6 (0xF01F4318u)
+#define GTM_MCS4_CH6_R7 (0xF01F431Cu)
+#define GTM_MCS4_CH6_CTRL (0xF01F4320u)
+#define GTM_MCS4_CH6_ACB (0xF01F4324u)
+#define GTM_MCS4_CH6_MHB (0xF01F433Cu)
+#define GTM_MCS4_CH6_PC (0xF01F4340u)
+#define GTM_MCS4_CH6_IRQ_NOTIFY (0xF01F4344u)
+#define GTM_MCS4_CH6_IRQ_EN (0xF01F4348u)
+#define GTM_TIM0_CH6_CNTS (0xF0101310u)
+#define GTM_MCS4_CH6_IRQ_FORCINT (0xF01F434Cu)
+#define GTM_MCS4_CH6_IRQ_MODE (0xF01F4350u)
+#define GTM_MCS4_CH6_EIRQ_EN (0xF01F4354u)
+#define GTM_MCS4_CH7_R0 (0xF01F4380u)
+#define GTM_MCS4_CH7_R1 (0xF01F4384u)
+#define GTM_MCS4_CH7_R2 (0xF01F4388u)
+#define GTM_MCS4_CH7_R3 (0xF01F438Cu)
+#define GTM_MCS4_CH7_R4 (0xF01F4390u)
+#define GTM_MCS4_CH7_R5 (0xF01F4394u)
+#define GTM_MCS4_CH7_R6 (0xF01F4398u)
+#define GTM_TIM0_CH6_TDUC (0xF0101314u)
+#define GTM_MCS4_CH7_R7 (0xF01F439Cu)
+#define GTM_MCS4_CH7_CTRL (0xF01F43A0u)
+#define GTM_MCS4_CH7_ACB (0xF01F43A4u)
+#define GTM_MCS4_CH7_MHB (0xF01F43BCu)
+#define GTM_MCS4_CH7_PC (0xF01F43C0u)
+#define GTM_MCS4_CH7_IRQ_NOTIFY (0xF01F43C4u)
+#define GTM_MCS4_CH7_IRQ_EN (0xF01F43C8u)
+#define GTM_MCS4_CH7_IRQ_FORCINT (0xF01F43CCu)
+#define GTM_MCS4_CH7_IRQ_MODE (0xF01F43D0u)
+#define GTM_MCS4_CH7_EIRQ_EN (0xF01F43D4u)
+#define GTM_TIM0_CH6_TDUV (0xF0101318u)
+#define GTM_TIM0_CH6_FLT_RE (0xF010131Cu)
+#define GTM_TIM0_CH6_FLT_FE (0xF0101320u)
+#define GTM_TIM0_CH6_CTRL (0xF0101324u)
+#define GTM_TIM0_CH6_ECTRL (0xF0101328u)
+#define GTM_TIM0_CH6_IRQ_NOTIFY (0xF010132Cu)
+#define GTM_TIM0_CH6_IRQ_EN (0xF0101330u)
+#define GTM_TIM0_CH6_IRQ_FORCINT (0xF0101334u)
+#define GTM_BRIDGE_MODE (0xF0100030u)
+#define GTM_TIM0_CH6_IRQ_MODE (0xF0101338u)
+#define GTM_TIM0_CH6_EIRQ_EN (0xF010133Cu)
+#define GTM_TIM0_CH7_GPR0 (0xF0101380u)
+#define GTM_TIM0_CH7_GPR1 (0xF0101384u)
+#define GTM_TIM0_CH7_CNT (0xF0101388u)
+#define GTM_TIM0_CH7_ECNT (0xF010138Cu)
+#define GTM_TIM0_CH7_CNTS (0xF0101390u)
+#define GTM_TIM0_CH7_TDUC (0xF0101394u)
+#define GTM_TIM0_CH7_TDUV (0xF0101398u)
+#define GTM_TIM0_CH7_FLT_RE (0xF010139Cu)
+#define GTM_BRIDGE_PTR1 (0xF0100034u)
+#define GTM_TIM0_CH7_FLT_FE (0xF01013A0u)
+#define GTM_TIM0_CH7_CTRL (0xF01013A4u)
+#define GTM_TIM0_CH7_ECTRL (0xF01013A8u)
+#define GTM_TIM0_CH7_IRQ_NOTIFY (0xF01013ACu)
+#define GTM_TIM0_CH7_IRQ_EN (0xF01013B0u)
+#define GTM_TIM0_CH7_IRQ_FORCINT (0xF01013B4u)
+#define GTM_TIM0_CH7_IRQ_MODE (0xF01013B8u)
+#define GTM_TIM0_CH7_EIRQ_EN (0xF01013BCu)
+#define GTM_TIM1_CH0_GPR0 (0xF0101800u)
+#define GTM_TIM1_CH0_GPR1 (0xF0101804u)
+#define GTM_BRIDGE_PTR2 (0xF0100038u)
+#define GTM_TIM1_CH0_CNT (0xF0101808u)
+#define GTM_TIM1_CH0_ECNT (0xF010180Cu)
+#define GTM_TIM1_CH0_CNTS (0xF0101810u)
+#define GTM_TIM1_CH0_TDUC (0xF0101814u)
+#define GTM_TIM1_CH0_TDUV (0xF0101818u)
+#define GTM_TIM1_CH0_FLT_RE (0xF010181Cu)
+#define GTM_TIM1_CH0_FLT_FE (0xF0101820u)
+#define GTM_TIM1_CH0_CTRL (0xF0101824u)
+#define GTM_TIM1_CH0_ECTRL (0xF0101828u)
+#define GTM_TIM1_CH0_IRQ_NOTIFY (0xF010182Cu)
+#define GTM_MCS_AEM_DIS (0xF010003Cu)
+#define GTM_TIM1_CH0_IRQ_EN (0xF0101830u)
+#define GTM_TIM1_CH0_IRQ_FORCINT (0xF0101834u)
+#define GTM_TIM1_CH0_IRQ_MODE (0xF0101838u)
+#define GTM_TIM1_CH0_EIRQ_EN (0xF010183Cu)
+#define GTM_TIM1_INP_VAL (0xF0101874u)
+#define GTM_TIM1_IN_SRC (0xF0101878u)
+#define GTM_TIM1_RST (0xF010187Cu)
+#define GTM_TIM1_CH1_GPR0 (0xF0101880u)
+#define GTM_TIM1_CH1_GPR1 (0xF0101884u)
+#define GTM_TIM1_CH1_CNT (0xF0101888u)
+#define GTM_AUX_IN_SRC_TIM0 (0xF0100040u)
+#define GTM_TIM1_CH1_ECNT (0xF010188Cu)
+#define GTM_TIM1_CH1_CNTS (0xF0101890u)
+#define GTM_TIM1_CH1_TDUC (0xF0101894u)
+#define GTM_TIM1_CH1_TDUV (0xF0101898u)
+#define GTM_TIM1_CH1_FLT_RE (0xF010189Cu)
+#define GTM_TIM1_CH1_FLT_FE (0xF01018A0u)
+#define GTM_TIM1_CH1_CTRL (0xF01018A4u)
+#define GTM_TIM1_CH1_ECTRL (0xF01018A8u)
+#define GTM_TIM1_CH1_IRQ_NOTIFY (0xF01018ACu)
+#define GTM_TIM1_CH1_IRQ_EN (0xF01018B0u)
+#define GTM_TIM1_CH1_IRQ_FORCINT (0xF01018B4u)
+#define GTM_TIM1_CH1_IRQ_MODE (0xF01018B8u)
+#define GTM_TIM1_CH1_EIRQ_EN (0xF01018BCu)
+#define GTM_TIM1_CH2_GPR0 (0xF0101900u)
+#define GTM_TIM1_CH2_GPR1 (0xF0101904u)
+#define GTM_TIM1_CH2_CNT (0xF0101908u)
+#define GTM_TIM1_CH2_ECNT (0xF010190Cu)
+#define GTM_TIM1_CH2_CNTS (0xF0101910u)
+#define GTM_TIM1_CH2_TDUC (0xF0101914u)
+#define GTM_TIM1_CH2_TDUV (0xF0101918u)
+#define GTM_TIM1_CH2_FLT_RE (0xF010191Cu)
+#define GTM_TIM1_CH2_FLT_FE (0xF0101920u)
+#define GTM_TIM1_CH2_CTRL (0xF0101924u)
+#define GTM_AUX_IN_SRC_TIM1 (0xF0100044u)
+#define GTM_TIM1_CH2_ECTRL (0xF0101928u)
+#define GTM_TIM1_CH2_IRQ_NOTIFY (0xF010192Cu)
+#define GTM_TIM1_CH2_IRQ_EN (0xF0101930u)
+#define GTM_TIM1_CH2_IRQ_FORCINT (0xF0101934u)
+#define GTM_TIM1_CH2_IRQ_MODE (0xF0101938u)
+#define GTM_TIM1_CH2_EIRQ_EN (0xF010193Cu)
+#define GTM_TIM1_CH3_GPR0 (0xF0101980u)
+#define GTM_TIM1_CH3_GPR1 (0xF0101984u)
+#define GTM_TIM1_CH3_CNT (0xF0101988u)
+#define GTM_TIM1_CH3_ECNT (0xF010198Cu)
+#define GTM_TIM1_CH3_CNTS (0xF0101990u)
+#define GTM_TIM1_CH3_TDUC (0xF0101994u)
+#define GTM_TIM1_CH3_TDUV (0xF0101998u)
+#define GTM_TIM1_CH3_FLT_RE (0xF010199Cu)
+#define GTM_TIM1_CH3_FLT_FE (0xF01019A0u)
+#define GTM_TIM1_CH3_CTRL (0xF01019A4u)
+#define GTM_TIM1_CH3_ECTRL (0xF01019A8u)
+#define GTM_TIM1_CH3_IRQ_NOTIFY (0xF01019ACu)
+#define GTM_TIM1_CH3_IRQ_EN (0xF01019B0u)
+#define GTM_TIM1_CH3_IRQ_FORCINT (0xF01019B4u)
+#define GTM_TIM1_CH3_IRQ_MODE (0xF01019B8u)
+#define GTM_TIM1_CH3_EIRQ_EN (0xF01019BCu)
+#define GTM_TIM1_CH4_GPR0 (0xF0101A00u)
+#define GTM_AUX_IN_SRC_TIM2 (0xF0100048u)
+#define GTM_TIM1_CH4_GPR1 (0xF0101A04u)
+#define GTM_TIM1_CH4_CNT (0xF0101A08u)
+#define GTM_TIM1_CH4_ECNT (0xF0101A0Cu)
+#define GTM_TIM1_CH4_CNTS (0xF0101A10u)
+#define GTM_TIM1_CH4_TDUC (0xF0101A14u)
+#define GTM_TIM1_CH4_TDUV (0xF0101A18u)
+#define GTM_TIM1_CH4_FLT_RE (0xF0101A1Cu)
+#define GTM_TIM1_CH4_FLT_FE (0xF0101A20u)
+#define GTM_TIM1_CH4_CTRL (0xF0101A24u)
+#define GTM_TIM1_CH4_ECTRL (0xF0101A28u)
+#define GTM_TIM1_CH4_IRQ_NOTIFY (0xF0101A2Cu)
+#define GTM_TIM1_CH4_IRQ_EN (0xF0101A30u)
+#define GTM_TIM1_CH4_IRQ_FORCINT (0xF0101A34u)
+#define GTM_TIM1_CH4_IRQ_MODE (0xF0101A38u)
+#define GTM_TIM1_CH4_EIRQ_EN (0xF0101A3Cu)
+#define GTM_TIM1_CH5_GPR0 (0xF0101A80u)
+#define GTM_TIM1_CH5_GPR1 (0xF0101A84u)
+#define GTM_TIM1_CH5_CNT (0xF0101A88u)
+#define GTM_TIM1_CH5_ECNT (0xF0101A8Cu)
+#define GTM_TIM1_CH5_CNTS (0xF0101A90u)
+#define GTM_TIM1_CH5_TDUC (0xF0101A94u)
+#define GTM_TIM1_CH5_TDUV (0xF0101A98u)
+#define GTM_TIM1_CH5_FLT_RE (0xF0101A9Cu)
+#define GTM_TIM1_CH5_FLT_FE (0xF0101AA0u)
+#define GTM_AUX_IN_SRC_TIM3 (0xF010004Cu)
+#define GTM_TIM1_CH5_CTRL (0xF0101AA4u)
+#define GTM_TIM1_CH5_ECTRL (0xF0101AA8u)
+#define GTM_TIM1_CH5_IRQ_NOTIFY (0xF0101AACu)
+#define GTM_TIM1_CH5_IRQ_EN (0xF0101AB0u)
+#define GTM_TIM1_CH5_IRQ_FORCINT (0xF0101AB4u)
+#define GTM_TIM1_CH5_IRQ_MODE (0xF0101AB8u)
+#define GTM_TIM1_CH5_EIRQ_EN (0xF0101ABCu)
+#define GTM_TIM1_CH6_GPR0 (0xF0101B00u)
+#define GTM_TIM1_CH6_GPR1 (0xF0101B04u)
+#define GTM_TIM1_CH6_CNT (0xF0101B08u)
+#define GTM_TIM1_CH6_ECNT (0xF0101B0Cu)
+#define GTM_TIM1_CH6_CNTS (0xF0101B10u)
+#define GTM_TIM1_CH6_TDUC (0xF0101B14u)
+#define GTM_TIM1_CH6_TDUV (0xF0101B18u)
+#define GTM_TIM1_CH6_FLT_RE (0xF0101B1Cu)
+#define GTM_TIM1_CH6_FLT_FE (0xF0101B20u)
+#define GTM_TIM1_CH6_CTRL (0xF0101B24u)
+#define GTM_TIM1_CH6_ECTRL (0xF0101B28u)
+#define GTM_TIM1_CH6_IRQ_NOTIFY (0xF0101B2Cu)
+#define GTM_TIM1_CH6_IRQ_EN (0xF0101B30u)
+#define GTM_TIM1_CH6_IRQ_FORCINT (0xF0101B34u)
+#define GTM_TIM1_CH6_IRQ_MODE (0xF0101B38u)
+#define GTM_TIM1_CH6_EIRQ_EN (0xF0101B3Cu)
+#define GTM_AUX_IN_SRC_TIM4 (0xF0100050u)
+#define GTM_TIM1_CH7_GPR0 (0xF0101B80u)
+#define GTM_TIM1_CH7_GPR1 (0xF0101B84u)
+#define GTM_TIM1_CH7_CNT (0xF0101B88u)
+#define GTM_TIM1_CH7_ECNT (0xF0101B8Cu)
+#define GTM_TIM1_CH7_CNTS (0xF0101B90u)
+#define GTM_TIM1_CH7_TDUC (0xF0101B94u)
+#define GTM_TIM1_CH7_TDUV (0xF0101B98u)
+#define GTM_TIM1_CH7_FLT_RE (0xF0101B9Cu)
+#define GTM_TIM1_CH7_FLT_FE (0xF0101BA0u)
+#define GTM_TIM1_CH7_CTRL (0xF0101BA4u)
+#define GTM_TIM1_CH7_ECTRL (0xF0101BA8u)
+#define GTM_TIM1_CH7_IRQ_NOTIFY (0xF0101BACu)
+#define GTM_TIM1_CH7_IRQ_EN (0xF0101BB0u)
+#define GTM_TIM1_CH7_IRQ_FORCINT (0xF0101BB4u)
+#define GTM_TIM1_CH7_IRQ_MODE (0xF0101BB8u)
+#define GTM_TIM1_CH7_EIRQ_EN (0xF0101BBCu)
+#define GTM_TIM2_CH0_GPR0 (0xF0102000u)
+#define GTM_TIM2_CH0_GPR1 (0xF0102004u)
+#define GTM_TIM2_CH0_CNT (0xF0102008u)
+#define GTM_TIM2_CH0_ECNT (0xF010200Cu)
+#define GTM_TIM2_CH0_CNTS (0xF0102010u)
+#define GTM_TIM2_CH0_TDUC (0xF0102014u)
+#define GTM_TIM2_CH0_TDUV (0xF0102018u)
+#define GTM_AUX_IN_SRC_TIM5 (0xF0100054u)
+#define GTM_TIM2_CH0_FLT_RE (0xF010201Cu)
+#define GTM_TIM2_CH0_FLT_FE (0xF0102020u)
+#define GTM_TIM2_CH0_CTRL (0xF0102024u)
+#define GTM_TIM2_CH0_ECTRL (0xF0102028u)
+#define GTM_TIM2_CH0_IRQ_NOTIFY (0xF010202Cu)
+#define GTM_TIM2_CH0_IRQ_EN (0xF0102030u)
+#define GTM_TIM2_CH0_IRQ_FORCINT (0xF0102034u)
+#define GTM_TIM2_CH0_IRQ_MODE (0xF0102038u)
+#define GTM_TIM2_CH0_EIRQ_EN (0xF010203Cu)
+#define GTM_TIM2_INP_VAL (0xF0102074u)
+#define GTM_TIM2_IN_SRC (0xF0102078u)
+#define GTM_TIM2_RST (0xF010207Cu)
+#define GTM_TIM2_CH1_GPR0 (0xF0102080u)
+#define GTM_TIM2_CH1_GPR1 (0xF0102084u)
+#define GTM_TIM2_CH1_CNT (0xF0102088u)
+#define GTM_TIM2_CH1_ECNT (0xF010208Cu)
+#define GTM_TIM2_CH1_CNTS (0xF0102090u)
+#define GTM_TIM2_CH1_TDUC (0xF0102094u)
+#define GTM_TIM2_CH1_TDUV (0xF0102098u)
+#define GTM_TIM2_CH1_FLT_RE (0xF010209Cu)
+#define GTM_TIM2_CH1_FLT_FE (0xF01020A0u)
+#define GTM_TIM2_CH1_CTRL (0xF01020A4u)
+#define GTM_TIM2_CH1_ECTRL (0xF01020A8u)
+#define GTM_TIM2_CH1_IRQ_NOTIFY (0xF01020ACu)
+#define GTM_EXT_CAP_EN0 (0xF010005Cu)
+#define GTM_TIM2_CH1_IRQ_EN (0xF01020B0u)
+#define GTM_TIM2_CH1_IRQ_FORCINT (0xF01020B4u)
+#define GTM_TIM2_CH1_IRQ_MODE (0xF01020B8u)
+#define GTM_TIM2_CH1_EIRQ_EN (0xF01020BCu)
+#define GTM_TIM2_CH2_GPR0 (0xF0102100u)
+#define GTM_TIM2_CH2_GPR1 (0xF0102104u)
+#define GTM_TIM2_CH2_CNT (0xF0102108u)
+#define GTM_TIM2_CH2_ECNT (0xF010210Cu)
+#define GTM_TIM2_CH2_CNTS (0xF0102110u)
+#define GTM_TIM2_CH2_TDUC (0xF0102114u)
+#define GTM_TIM2_CH2_TDUV (0xF0102118u)
+#define GTM_TIM2_CH2_FLT_RE (0xF010211Cu)
+#define GTM_TIM2_CH2_FLT_FE (0xF0102120u)
+#define GTM_TIM2_CH2_CTRL (0xF0102124u)
+#define GTM_TIM2_CH2_ECTRL (0xF0102128u)
+#define GTM_TIM2_CH2_IRQ_NOTIFY (0xF010212Cu)
+#define GTM_TIM2_CH2_IRQ_EN (0xF0102130u)
+#define GTM_TIM2_CH2_IRQ_FORCINT (0xF0102134u)
+#define GTM_TIM2_CH2_IRQ_MODE (0xF0102138u)
+#define GTM_TIM2_CH2_EIRQ_EN (0xF010213Cu)
+#define GTM_TIM2_CH3_GPR0 (0xF0102180u)
+#define GTM_TIM2_CH3_GPR1 (0xF0102184u)
+#define GTM_TIM2_CH3_CNT (0xF0102188u)
+#define GTM_EXT_CAP_EN1 (0xF0100060u)
+#define GTM_TIM2_CH3_ECNT (0xF010218Cu)
+#define GTM_TIM2_CH3_CNTS (0xF0102190u)
+#define GTM_TIM2_CH3_TDUC (0xF0102194u)
+#define GTM_TIM2_CH3_TDUV (0xF0102198u)
+#define GTM_TIM2_CH3_FLT_RE (0xF010219Cu)
+#define GTM_TIM2_CH3_FLT_FE (0xF01021A0u)
+#define GTM_TIM2_CH3_CTRL (0xF01021A4u)
+#define GTM_TIM2_CH3_ECTRL (0xF01021A8u)
+#define GTM_TIM2_CH3_IRQ_NOTIFY (0xF01021ACu)
+#define GTM_TIM2_CH3_IRQ_EN (0xF01021B0u)
+#define GTM_TIM2_CH3_IRQ_FORCINT (0xF01021B4u)
+#define GTM_TIM2_CH3_IRQ_MODE (0xF01021B8u)
+#define GTM_TIM2_CH3_EIRQ_EN (0xF01021BCu)
+#define GTM_TIM2_CH4_GPR0 (0xF0102200u)
+#define GTM_TIM2_CH4_GPR1 (0xF0102204u)
+#define GTM_TIM2_CH4_CNT (0xF0102208u)
+#define GTM_TIM2_CH4_ECNT (0xF010220Cu)
+#define GTM_TIM2_CH4_CNTS (0xF0102210u)
+#define GTM_TIM2_CH4_TDUC (0xF0102214u)
+#define GTM_TIM2_CH4_TDUV (0xF0102218u)
+#define GTM_TIM2_CH4_FLT_RE (0xF010221Cu)
+#define GTM_TIM2_CH4_FLT_FE (0xF0102220u)
+#define GTM_TIM2_CH4_CTRL (0xF0102224u)
+#define GTM_EXT_CAP_EN2 (0xF0100064u)
+#define GTM_TIM2_CH4_ECTRL (0xF0102228u)
+#define GTM_TIM2_CH4_IRQ_NOTIFY (0xF010222Cu)
+#define GTM_TIM2_CH4_IRQ_EN (0xF0102230u)
+#define GTM_TIM2_CH4_IRQ_FORCINT (0xF0102234u)
+#define GTM_TIM2_CH4_IRQ_MODE (0xF0102238u)
+#define GTM_TIM2_CH4_EIRQ_EN (0xF010223Cu)
+#define GTM_TIM2_CH5_GPR0 (0xF0102280u)
+#define GTM_TIM2_CH5_GPR1 (0xF0102284u)
+#define GTM_TIM2_CH5_CNT (0xF0102288u)
+#define GTM_TIM2_CH5_ECNT (0xF010228Cu)
+#define GTM_TIM2_CH5_CNTS (0xF0102290u)
+#define GTM_TIM2_CH5_TDUC (0xF0102294u)
+#define GTM_TIM2_CH5_TDUV (0xF0102298u)
+#define GTM_TIM2_CH5_FLT_RE (0xF010229Cu)
+#define GTM_TIM2_CH5_FLT_FE (0xF01022A0u)
+#define GTM_TIM2_CH5_CTRL (0xF01022A4u)
+#define GTM_TIM2_CH5_ECTRL (0xF01022A8u)
+#define GTM_TIM2_CH5_IRQ_NOTIFY (0xF01022ACu)
+#define GTM_TIM2_CH5_IRQ_EN (0xF01022B0u)
+#define GTM_TIM2_CH5_IRQ_FORCINT (0xF01022B4u)
+#define GTM_TIM2_CH5_IRQ_MODE (0xF01022B8u)
+#define GTM_TIM2_CH5_EIRQ_EN (0xF01022BCu)
+#define GTM_TIM2_CH6_GPR0 (0xF0102300u)
+#define GTM_TIM2_CH6_GPR1 (0xF0102304u)
+#define GTM_EXT_CAP_EN3 (0xF0100068u)
+#define GTM_TIM2_CH6_CNT (0xF0102308u)
+#define GTM_TIM2_CH6_ECNT (0xF010230Cu)
+#define GTM_TIM2_CH6_CNTS (0xF0102310u)
+#define GTM_TIM2_CH6_TDUC (0xF0102314u)
+#define GTM_TIM2_CH6_TDUV (0xF0102318u)
+#define GTM_TIM2_CH6_FLT_RE (0xF010231Cu)
+#define GTM_TIM2_CH6_FLT_FE (0xF0102320u)
+#define GTM_TIM2_CH6_CTRL (0xF0102324u)
+#define GTM_TIM2_CH6_ECTRL (0xF0102328u)
+#define GTM_TIM2_CH6_IRQ_NOTIFY (0xF010232Cu)
+#define GTM_TIM2_CH6_IRQ_EN (0xF0102330u)
+#define GTM_TIM2_CH6_IRQ_FORCINT (0xF0102334u)
+#define GTM_TIM2_CH6_IRQ_MODE (0xF0102338u)
+#define GTM_TIM2_CH6_EIRQ_EN (0xF010233Cu)
+#define GTM_TIM2_CH7_GPR0 (0xF0102380u)
+#define GTM_TIM2_CH7_GPR1 (0xF0102384u)
+#define GTM_TIM2_CH7_CNT (0xF0102388u)
+#define GTM_TIM2_CH7_ECNT (0xF010238Cu)
+#define GTM_TIM2_CH7_CNTS (0xF0102390u)
+#define GTM_TIM2_CH7_TDUC (0xF0102394u)
+#define GTM_TIM2_CH7_TDUV (0xF0102398u)
+#define GTM_TIM2_CH7_FLT_RE (0xF010239Cu)
+#define GTM_TIM2_CH7_FLT_FE (0xF01023A0u)
+#define GTM_EXT_CAP_EN4 (0xF010006Cu)
+#define GTM_TIM2_CH7_CTRL (0xF01023A4u)
+#define GTM_TIM2_CH7_ECTRL (0xF01023A8u)
+#define GTM_TIM2_CH7_IRQ_NOTIFY (0xF01023ACu)
+#define GTM_TIM2_CH7_IRQ_EN (0xF01023B0u)
+#define GTM_TIM2_CH7_IRQ_FORCINT (0xF01023B4u)
+#define GTM_TIM2_CH7_IRQ_MODE (0xF01023B8u)
+#define GTM_TIM2_CH7_EIRQ_EN (0xF01023BCu)
+#define GTM_TIM3_CH0_GPR0 (0xF0102800u)
+#define GTM_TIM3_CH0_GPR1 (0xF0102804u)
+#define GTM_TIM3_CH0_CNT (0xF0102808u)
+#define GTM_TIM3_CH0_ECNT (0xF010280Cu)
+#define GTM_TIM3_CH0_CNTS (0xF0102810u)
+#define GTM_TIM3_CH0_TDUC (0xF0102814u)
+#define GTM_TIM3_CH0_TDUV (0xF0102818u)
+#define GTM_TIM3_CH0_FLT_RE (0xF010281Cu)
+#define GTM_TIM3_CH0_FLT_FE (0xF0102820u)
+#define GTM_TIM3_CH0_CTRL (0xF0102824u)
+#define GTM_TIM3_CH0_ECTRL (0xF0102828u)
+#define GTM_TIM3_CH0_IRQ_NOTIFY (0xF010282Cu)
+#define GTM_TIM3_CH0_IRQ_EN (0xF0102830u)
+#define GTM_TIM3_CH0_IRQ_FORCINT (0xF0102834u)
+#define GTM_TIM3_CH0_IRQ_MODE (0xF0102838u)
+#define GTM_TIM3_CH0_EIRQ_EN (0xF010283Cu)
+#define GTM_OUT_TOM0 (0xF0100080u)
+#define GTM_TIM3_INP_VAL (0xF0102874u)
+#define GTM_TIM3_IN_SRC (0xF0102878u)
+#define GTM_TIM3_RST (0xF010287Cu)
+#define GTM_TIM3_CH1_GPR0 (0xF0102880u)
+#define GTM_TIM3_CH1_GPR1 (0xF0102884u)
+#define GTM_TIM3_CH1_CNT (0xF0102888u)
+#define GTM_TIM3_CH1_ECNT (0xF010288Cu)
+#define GTM_TIM3_CH1_CNTS (0xF0102890u)
+#define GTM_TIM3_CH1_TDUC (0xF0102894u)
+#define GTM_TIM3_CH1_TDUV (0xF0102898u)
+#define GTM_TIM3_CH1_FLT_RE (0xF010289Cu)
+#define GTM_TIM3_CH1_FLT_FE (0xF01028A0u)
+#define GTM_TIM3_CH1_CTRL (0xF01028A4u)
+#define GTM_TIM3_CH1_ECTRL (0xF01028A8u)
+#define GTM_TIM3_CH1_IRQ_NOTIFY (0xF01028ACu)
+#define GTM_TIM3_CH1_IRQ_EN (0xF01028B0u)
+#define GTM_TIM3_CH1_IRQ_FORCINT (0xF01028B4u)
+#define GTM_TIM3_CH1_IRQ_MODE (0xF01028B8u)
+#define GTM_TIM3_CH1_EIRQ_EN (0xF01028BCu)
+#define GTM_TIM3_CH2_GPR0 (0xF0102900u)
+#define GTM_TIM3_CH2_GPR1 (0xF0102904u)
+#define GTM_TIM3_CH2_CNT (0xF0102908u)
+#define GTM_TIM3_CH2_ECNT (0xF010290Cu)
+#define GTM_TIM3_CH2_CNTS (0xF0102910u)
+#define GTM_OUT_TOM1 (0xF0100084u)
+#define GTM_TIM3_CH2_TDUC (0xF0102914u)
+#define GTM_TIM3_CH2_TDUV (0xF0102918u)
+#define GTM_TIM3_CH2_FLT_RE (0xF010291Cu)
+#define GTM_TIM3_CH2_FLT_FE (0xF0102920u)
+#define GTM_TIM3_CH2_CTRL (0xF0102924u)
+#define GTM_TIM3_CH2_ECTRL (0xF0102928u)
+#define GTM_TIM3_CH2_IRQ_NOTIFY (0xF010292Cu)
+#define GTM_TIM3_CH2_IRQ_EN (0xF0102930u)
+#define GTM_TIM3_CH2_IRQ_FORCINT (0xF0102934u)
+#define GTM_TIM3_CH2_IRQ_MODE (0xF0102938u)
+#define GTM_TIM3_CH2_EIRQ_EN (0xF010293Cu)
+#define GTM_TIM3_CH3_GPR0 (0xF0102980u)
+#define GTM_TIM3_CH3_GPR1 (0xF0102984u)
+#define GTM_TIM3_CH3_CNT (0xF0102988u)
+#define GTM_TIM3_CH3_ECNT (0xF010298Cu)
+#define GTM_TIM3_CH3_CNTS (0xF0102990u)
+#define GTM_TIM3_CH3_TDUC (0xF0102994u)
+#define GTM_TIM3_CH3_TDUV (0xF0102998u)
+#define GTM_TIM3_CH3_FLT_RE (0xF010299Cu)
+#define GTM_TIM3_CH3_FLT_FE (0xF01029A0u)
+#define GTM_TIM3_CH3_CTRL (0xF01029A4u)
+#define GTM_TIM3_CH3_ECTRL (0xF01029A8u)
+#define GTM_TIM3_CH3_IRQ_NOTIFY (0xF01029ACu)
+#define GTM_OUT_TOM2 (0xF0100088u)
+#define GTM_TIM3_CH3_IRQ_EN (0xF01029B0u)
+#define GTM_TIM3_CH3_IRQ_FORCINT (0xF01029B4u)
+#define GTM_TIM3_CH3_IRQ_MODE (0xF01029B8u)
+#define GTM_TIM3_CH3_EIRQ_EN (0xF01029BCu)
+#define GTM_TIM3_CH4_GPR0 (0xF0102A00u)
+#define GTM_TIM3_CH4_GPR1 (0xF0102A04u)
+#define GTM_TIM3_CH4_CNT (0xF0102A08u)
+#define GTM_TIM3_CH4_ECNT (0xF0102A0Cu)
+#define GTM_TIM3_CH4_CNTS (0xF0102A10u)
+#define GTM_TIM3_CH4_TDUC (0xF0102A14u)
+#define GTM_TIM3_CH4_TDUV (0xF0102A18u)
+#define GTM_TIM3_CH4_FLT_RE (0xF0102A1Cu)
+#define GTM_TIM3_CH4_FLT_FE (0xF0102A20u)
+#define GTM_TIM3_CH4_CTRL (0xF0102A24u)
+#define GTM_TIM3_CH4_ECTRL (0xF0102A28u)
+#define GTM_TIM3_CH4_IRQ_NOTIFY (0xF0102A2Cu)
+#define GTM_TIM3_CH4_IRQ_EN (0xF0102A30u)
+#define GTM_TIM3_CH4_IRQ_FORCINT (0xF0102A34u)
+#define GTM_TIM3_CH4_IRQ_MODE (0xF0102A38u)
+#define GTM_TIM3_CH4_EIRQ_EN (0xF0102A3Cu)
+#define GTM_TIM3_CH5_GPR0 (0xF0102A80u)
+#define GTM_TIM3_CH5_GPR1 (0xF0102A84u)
+#define GTM_TIM3_CH5_CNT (0xF0102A88u)
+#define GTM_OUT_ATOM0 (0xF0100098u)
+#define GTM_TIM3_CH5_ECNT (0xF0102A8Cu)
+#define GTM_TIM3_CH5_CNTS (0xF0102A90u)
+#define GTM_TIM3_CH5_TDUC (0xF0102A94u)
+#define GTM_TIM3_CH5_TDUV (0xF0102A98u)
+#define GTM_TIM3_CH5_FLT_RE (0xF0102A9Cu)
+#define GTM_TIM3_CH5_FLT_FE (0xF0102AA0u)
+#define GTM_TIM3_CH5_CTRL (0xF0102AA4u)
+#define GTM_TIM3_CH5_ECTRL (0xF0102AA8u)
+#define GTM_TIM3_CH5_IRQ_NOTIFY (0xF0102AACu)
+#define GTM_TIM3_CH5_IRQ_EN (0xF0102AB0u)
+#define GTM_TIM3_CH5_IRQ_FORCINT (0xF0102AB4u)
+#define GTM_TIM3_CH5_IRQ_MODE (0xF0102AB8u)
+#define GTM_TIM3_CH5_EIRQ_EN (0xF0102ABCu)
+#define GTM_TIM3_CH6_GPR0 (0xF0102B00u)
+#define GTM_TIM3_CH6_GPR1 (0xF0102B04u)
+#define GTM_TIM3_CH6_CNT (0xF0102B08u)
+#define GTM_TIM3_CH6_ECNT (0xF0102B0Cu)
+#define GTM_TIM3_CH6_CNTS (0xF0102B10u)
+#define GTM_TIM3_CH6_TDUC (0xF0102B14u)
+#define GTM_TIM3_CH6_TDUV (0xF0102B18u)
+#define GTM_TIM3_CH6_FLT_RE (0xF0102B1Cu)
+#define GTM_TIM3_CH6_FLT_FE (0xF0102B20u)
+#define GTM_TIM3_CH6_CTRL (0xF0102B24u)
+#define GTM_TIM3_CH6_ECTRL (0xF0102B28u)
+#define GTM_OUT_ATOM2 (0xF010009Cu)
+#define GTM_TIM3_CH6_IRQ_NOTIFY (0xF0102B2Cu)
+#define GTM_TIM3_CH6_IRQ_EN (0xF0102B30u)
+#define GTM_TIM3_CH6_IRQ_FORCINT (0xF0102B34u)
+#define GTM_TIM3_CH6_IRQ_MODE (0xF0102B38u)
+#define GTM_TIM3_CH6_EIRQ_EN (0xF0102B3Cu)
+#define GTM_TIM3_CH7_GPR0 (0xF0102B80u)
+#define GTM_TIM3_CH7_GPR1 (0xF0102B84u)
+#define GTM_TIM3_CH7_CNT (0xF0102B88u)
+#define GTM_TIM3_CH7_ECNT (0xF0102B8Cu)
+#define GTM_TIM3_CH7_CNTS (0xF0102B90u)
+#define GTM_TIM3_CH7_TDUC (0xF0102B94u)
+#define GTM_TIM3_CH7_TDUV (0xF0102B98u)
+#define GTM_TIM3_CH7_FLT_RE (0xF0102B9Cu)
+#define GTM_TIM3_CH7_FLT_FE (0xF0102BA0u)
+#define GTM_TIM3_CH7_CTRL (0xF0102BA4u)
+#define GTM_TIM3_CH7_ECTRL (0xF0102BA8u)
+#define GTM_TIM3_CH7_IRQ_NOTIFY (0xF0102BACu)
+#define GTM_TIM3_CH7_IRQ_EN (0xF0102BB0u)
+#define GTM_TIM3_CH7_IRQ_FORCINT (0xF0102BB4u)
+#define GTM_TIM3_CH7_IRQ_MODE (0xF0102BB8u)
+#define GTM_TIM3_CH7_EIRQ_EN (0xF0102BBCu)
+#define GTM_TIM4_CH0_GPR0 (0xF0103000u)
+#define GTM_TIM4_CH0_GPR1 (0xF0103004u)
+#define GTM_OUT_ATOM4 (0xF01000A0u)
+#define GTM_TIM4_CH0_CNT (0xF0103008u)
+#define GTM_TIM4_CH0_ECNT (0xF010300Cu)
+#define GTM_TIM4_CH0_CNTS (0xF0103010u)
+#define GTM_TIM4_CH0_TDUC (0xF0103014u)
+#define GTM_TIM4_CH0_TDUV (0xF0103018u)
+#define GTM_TIM4_CH0_FLT_RE (0xF010301Cu)
+#define GTM_TIM4_CH0_FLT_FE (0xF0103020u)
+#define GTM_TIM4_CH0_CTRL (0xF0103024u)
+#define GTM_TIM4_CH0_ECTRL (0xF0103028u)
+#define GTM_TIM4_CH0_IRQ_NOTIFY (0xF010302Cu)
+#define GTM_TIM4_CH0_IRQ_EN (0xF0103030u)
+#define GTM_TIM4_CH0_IRQ_FORCINT (0xF0103034u)
+#define GTM_TIM4_CH0_IRQ_MODE (0xF0103038u)
+#define GTM_TIM4_CH0_EIRQ_EN (0xF010303Cu)
+#define GTM_TIM4_INP_VAL (0xF0103074u)
+#define GTM_TIM4_IN_SRC (0xF0103078u)
+#define GTM_TIM4_RST (0xF010307Cu)
+#define GTM_TIM4_CH1_GPR0 (0xF0103080u)
+#define GTM_TIM4_CH1_GPR1 (0xF0103084u)
+#define GTM_TIM4_CH1_CNT (0xF0103088u)
+#define GTM_TIM4_CH1_ECNT (0xF010308Cu)
+#define GTM_TIM4_CH1_CNTS (0xF0103090u)
+#define GTM_TIM4_CH1_TDUC (0xF0103094u)
+#define GTM_CLS_CLK_CFG (0xF01000B0u)
+#define GTM_TIM4_CH1_TDUV (0xF0103098u)
+#define GTM_TIM4_CH1_FLT_RE (0xF010309Cu)
+#define GTM_TIM4_CH1_FLT_FE (0xF01030A0u)
+#define GTM_TIM4_CH1_CTRL (0xF01030A4u)
+#define GTM_TIM4_CH1_ECTRL (0xF01030A8u)
+#define GTM_TIM4_CH1_IRQ_NOTIFY (0xF01030ACu)
+#define GTM_TIM4_CH1_IRQ_EN (0xF01030B0u)
+#define GTM_TIM4_CH1_IRQ_FORCINT (0xF01030B4u)
+#define GTM_TIM4_CH1_IRQ_MODE (0xF01030B8u)
+#define GTM_TIM4_CH1_EIRQ_EN (0xF01030BCu)
+#define GTM_TBU_CHEN (0xF0100100u)
+#define GTM_TIM4_CH2_GPR0 (0xF0103100u)
+#define GTM_TIM4_CH2_GPR1 (0xF0103104u)
+#define GTM_TIM4_CH2_CNT (0xF0103108u)
+#define GTM_TIM4_CH2_ECNT (0xF010310Cu)
+#define GTM_TIM4_CH2_CNTS (0xF0103110u)
+#define GTM_TIM4_CH2_TDUC (0xF0103114u)
+#define GTM_TIM4_CH2_TDUV (0xF0103118u)
+#define GTM_TIM4_CH2_FLT_RE (0xF010311Cu)
+#define GTM_TIM4_CH2_FLT_FE (0xF0103120u)
+#define GTM_TIM4_CH2_CTRL (0xF0103124u)
+#define GTM_TBU_CH0_CTRL (0xF0100104u)
+#define GTM_TIM4_CH2_ECTRL (0xF0103128u)
+#define GTM_TIM4_CH2_IRQ_NOTIFY (0xF010312Cu)
+#define GTM_TIM4_CH2_IRQ_EN (0xF0103130u)
+#define GTM_TIM4_CH2_IRQ_FORCINT (0xF0103134u)
+#define GTM_TIM4_CH2_IRQ_MODE (0xF0103138u)
+#define GTM_TIM4_CH2_EIRQ_EN (0xF010313Cu)
+#define GTM_TIM4_CH3_GPR0 (0xF0103180u)
+#define GTM_TIM4_CH3_GPR1 (0xF0103184u)
+#define GTM_TIM4_CH3_CNT (0xF0103188u)
+#define GTM_TIM4_CH3_ECNT (0xF010318Cu)
+#define GTM_TBU_CH0_BASE (0xF0100108u)
+#define GTM_TIM4_CH3_CNTS (0xF0103190u)
+#define GTM_TIM4_CH3_TDUC (0xF0103194u)
+#define GTM_TIM4_CH3_TDUV (0xF0103198u)
+#define GTM_TIM4_CH3_FLT_RE (0xF010319Cu)
+#define GTM_TIM4_CH3_FLT_FE (0xF01031A0u)
+#define GTM_TIM4_CH3_CTRL (0xF01031A4u)
+#define GTM_TIM4_CH3_ECTRL (0xF01031A8u)
+#define GTM_TIM4_CH3_IRQ_NOTIFY (0xF01031ACu)
+#define GTM_TIM4_CH3_IRQ_EN (0xF01031B0u)
+#define GTM_TIM4_CH3_IRQ_FORCINT (0xF01031B4u)
+#define GTM_TBU_CH1_CTRL (0xF010010Cu)
+#define GTM_TIM4_CH3_IRQ_MODE (0xF01031B8u)
+#define GTM_TIM4_CH3_EIRQ_EN (0xF01031BCu)
+#define GTM_TIM4_CH4_GPR0 (0xF0103200u)
+#define GTM_TIM4_CH4_GPR1 (0xF0103204u)
+#define GTM_TIM4_CH4_CNT (0xF0103208u)
+#define GTM_TIM4_CH4_ECNT (0xF010320Cu)
+#define GTM_TIM4_CH4_CNTS (0xF0103210u)
+#define GTM_TIM4_CH4_TDUC (0xF0103214u)
+#define GTM_TIM4_CH4_TDUV (0xF0103218u)
+#define GTM_TIM4_CH4_FLT_RE (0xF010321Cu)
+#define GTM_TBU_CH1_BASE (0xF0100110u)
+#define GTM_TIM4_CH4_FLT_FE (0xF0103220u)
+#define GTM_TIM4_CH4_CTRL (0xF0103224u)
+#define GTM_TIM4_CH4_ECTRL (0xF0103228u)
+#define GTM_TIM4_CH4_IRQ_NOTIFY (0xF010322Cu)
+#define GTM_TIM4_CH4_IRQ_EN (0xF0103230u)
+#define GTM_TIM4_CH4_IRQ_FORCINT (0xF0103234u)
+#define GTM_TIM4_CH4_IRQ_MODE (0xF0103238u)
+#define GTM_TIM4_CH4_EIRQ_EN (0xF010323Cu)
+#define GTM_TIM4_CH5_GPR0 (0xF0103280u)
+#define GTM_TIM4_CH5_GPR1 (0xF0103284u)
+#define GTM_TBU_CH2_CTRL (0xF0100114u)
+#define GTM_TIM4_CH5_CNT (0xF0103288u)
+#define GTM_TIM4_CH5_ECNT (0xF010328Cu)
+#define GTM_TIM4_CH5_CNTS (0xF0103290u)
+#define GTM_TIM4_CH5_TDUC (0xF0103294u)
+#define GTM_TIM4_CH5_TDUV (0xF0103298u)
+#define GTM_TIM4_CH5_FLT_RE (0xF010329Cu)
+#define GTM_TIM4_CH5_FLT_FE (0xF01032A0u)
+#define GTM_TIM4_CH5_CTRL (0xF01032A4u)
+#define GTM_TIM4_CH5_ECTRL (0xF01032A8u)
+#define GTM_TIM4_CH5_IRQ_NOTIFY (0xF01032ACu)
+#define GTM_TBU_CH2_BASE (0xF0100118u)
+#define GTM_TIM4_CH5_IRQ_EN (0xF01032B0u)
+#define GTM_TIM4_CH5_IRQ_FORCINT (0xF01032B4u)
+#define GTM_TIM4_CH5_IRQ_MODE (0xF01032B8u)
+#define GTM_TIM4_CH5_EIRQ_EN (0xF01032BCu)
+#define GTM_TIM4_CH6_GPR0 (0xF0103300u)
+#define GTM_TIM4_CH6_GPR1 (0xF0103304u)
+#define GTM_TIM4_CH6_CNT (0xF0103308u)
+#define GTM_TIM4_CH6_ECNT (0xF010330Cu)
+#define GTM_TIM4_CH6_CNTS (0xF0103310u)
+#define GTM_TIM4_CH6_TDUC (0xF0103314u)
+#define GTM_TBU_CH3_CTRL (0xF010011Cu)
+#define GTM_TIM4_CH6_TDUV (0xF0103318u)
+#define GTM_TIM4_CH6_FLT_RE (0xF010331Cu)
+#define GTM_TIM4_CH6_FLT_FE (0xF0103320u)
+#define GTM_TIM4_CH6_CTRL (0xF0103324u)
+#define GTM_TIM4_CH6_ECTRL (0xF0103328u)
+#define GTM_TIM4_CH6_IRQ_NOTIFY (0xF010332Cu)
+#define GTM_TIM4_CH6_IRQ_EN (0xF0103330u)
+#define GTM_TIM4_CH6_IRQ_FORCINT (0xF0103334u)
+#define GTM_TIM4_CH6_IRQ_MODE (0xF0103338u)
+#define GTM_TIM4_CH6_EIRQ_EN (0xF010333Cu)
+#define GTM_TBU_CH3_BASE (0xF0100120u)
+#define GTM_TIM4_CH7_GPR0 (0xF0103380u)
+#define GTM_TIM4_CH7_GPR1 (0xF0103384u)
+#define GTM_TIM4_CH7_CNT (0xF0103388u)
+#define GTM_TIM4_CH7_ECNT (0xF010338Cu)
+#define GTM_TIM4_CH7_CNTS (0xF0103390u)
+#define GTM_TIM4_CH7_TDUC (0xF0103394u)
+#define GTM_TIM4_CH7_TDUV (0xF0103398u)
+#define GTM_TIM4_CH7_FLT_RE (0xF010339Cu)
+#define GTM_TIM4_CH7_FLT_FE (0xF01033A0u)
+#define GTM_TIM4_CH7_CTRL (0xF01033A4u)
+#define GTM_TBU_CH3_BASE_MARK (0xF0100124u)
+#define GTM_TIM4_CH7_ECTRL (0xF01033A8u)
+#define GTM_TIM4_CH7_IRQ_NOTIFY (0xF01033ACu)
+#define GTM_TIM4_CH7_IRQ_EN (0xF01033B0u)
+#define GTM_TIM4_CH7_IRQ_FORCINT (0xF01033B4u)
+#define GTM_TIM4_CH7_IRQ_MODE (0xF01033B8u)
+#define GTM_TIM4_CH7_EIRQ_EN (0xF01033BCu)
+#define GTM_TIM5_CH0_GPR0 (0xF0103800u)
+#define GTM_TIM5_CH0_GPR1 (0xF0103804u)
+#define GTM_TIM5_CH0_CNT (0xF0103808u)
+#define GTM_TIM5_CH0_ECNT (0xF010380Cu)
+#define GTM_TBU_CH3_BASE_CAPTURE (0xF0100128u)
+#define GTM_TIM5_CH0_CNTS (0xF0103810u)
+#define GTM_TIM5_CH0_TDUC (0xF0103814u)
+#define GTM_TIM5_CH0_TDUV (0xF0103818u)
+#define GTM_TIM5_CH0_FLT_RE (0xF010381Cu)
+#define GTM_TIM5_CH0_FLT_FE (0xF0103820u)
+#define GTM_TIM5_CH0_CTRL (0xF0103824u)
+#define GTM_TIM5_CH0_ECTRL (0xF0103828u)
+#define GTM_TIM5_CH0_IRQ_NOTIFY (0xF010382Cu)
+#define GTM_TIM5_CH0_IRQ_EN (0xF0103830u)
+#define GTM_TIM5_CH0_IRQ_FORCINT (0xF0103834u)
+#define GTM_MON_STATUS (0xF0100180u)
+#define GTM_TIM5_CH0_IRQ_MODE (0xF0103838u)
+#define GTM_TIM5_CH0_EIRQ_EN (0xF010383Cu)
+#define GTM_TIM5_INP_VAL (0xF0103874u)
+#define GTM_TIM5_IN_SRC (0xF0103878u)
+#define GTM_TIM5_RST (0xF010387Cu)
+#define GTM_TIM5_CH1_GPR0 (0xF0103880u)
+#define GTM_TIM5_CH1_GPR1 (0xF0103884u)
+#define GTM_TIM5_CH1_CNT (0xF0103888u)
+#define GTM_TIM5_CH1_ECNT (0xF010388Cu)
+#define GTM_TIM5_CH1_CNTS (0xF0103890u)
+#define GTM_MON_ACTIVITY_R0 (0xF0100184u)
+#define GTM_TIM5_CH1_TDUC (0xF0103894u)
+#define GTM_TIM5_CH1_TDUV (0xF0103898u)
+#define GTM_TIM5_CH1_FLT_RE (0xF010389Cu)
+#define GTM_TIM5_CH1_FLT_FE (0xF01038A0u)
+#define GTM_TIM5_CH1_CTRL (0xF01038A4u)
+#define GTM_TIM5_CH1_ECTRL (0xF01038A8u)
+#define GTM_TIM5_CH1_IRQ_NOTIFY (0xF01038ACu)
+#define GTM_TIM5_CH1_IRQ_EN (0xF01038B0u)
+#define GTM_TIM5_CH1_IRQ_FORCINT (0xF01038B4u)
+#define GTM_TIM5_CH1_IRQ_MODE (0xF01038B8u)
+#define GTM_TIM5_CH1_EIRQ_EN (0xF01038BCu)
+#define GTM_TIM5_CH2_GPR0 (0xF0103900u)
+#define GTM_TIM5_CH2_GPR1 (0xF0103904u)
+#define GTM_TIM5_CH2_CNT (0xF0103908u)
+#define GTM_TIM5_CH2_ECNT (0xF010390Cu)
+#define GTM_TIM5_CH2_CNTS (0xF0103910u)
+#define GTM_TIM5_CH2_TDUC (0xF0103914u)
+#define GTM_TIM5_CH2_TDUV (0xF0103918u)
+#define GTM_TIM5_CH2_FLT_RE (0xF010391Cu)
+#define GTM_TIM5_CH2_FLT_FE (0xF0103920u)
+#define GTM_TIM5_CH2_CTRL (0xF0103924u)
+#define GTM_TIM5_CH2_ECTRL (0xF0103928u)
+#define GTM_TIM5_CH2_IRQ_NOTIFY (0xF010392Cu)
+#define GTM_TIM5_CH2_IRQ_EN (0xF0103930u)
+#define GTM_MON_ACTIVITY_R1 (0xF0100188u)
+#define GTM_TIM5_CH2_IRQ_FORCINT (0xF0103934u)
+#define GTM_TIM5_CH2_IRQ_MODE (0xF0103938u)
+#define GTM_TIM5_CH2_EIRQ_EN (0xF010393Cu)
+#define GTM_TIM5_CH3_GPR0 (0xF0103980u)
+#define GTM_TIM5_CH3_GPR1 (0xF0103984u)
+#define GTM_TIM5_CH3_CNT (0xF0103988u)
+#define GTM_TIM5_CH3_ECNT (0xF010398Cu)
+#define GTM_TIM5_CH3_CNTS (0xF0103990u)
+#define GTM_TIM5_CH3_TDUC (0xF0103994u)
+#define GTM_TIM5_CH3_TDUV (0xF0103998u)
+#define GTM_TIM5_CH3_FLT_RE (0xF010399Cu)
+#define GTM_TIM5_CH3_FLT_FE (0xF01039A0u)
+#define GTM_TIM5_CH3_CTRL (0xF01039A4u)
+#define GTM_TIM5_CH3_ECTRL (0xF01039A8u)
+#define GTM_TIM5_CH3_IRQ_NOTIFY (0xF01039ACu)
+#define GTM_TIM5_CH3_IRQ_EN (0xF01039B0u)
+#define GTM_TIM5_CH3_IRQ_FORCINT (0xF01039B4u)
+#define GTM_TIM5_CH3_IRQ_MODE (0xF01039B8u)
+#define GTM_TIM5_CH3_EIRQ_EN (0xF01039BCu)
+#define GTM_TIM5_CH4_GPR0 (0xF0103A00u)
+#define GTM_TIM5_CH4_GPR1 (0xF0103A04u)
+#define GTM_TIM5_CH4_CNT (0xF0103A08u)
+#define GTM_TIM5_CH4_ECNT (0xF0103A0Cu)
+#define GTM_MON_ACTIVITY_MCS0 (0xF010018Cu)
+#define GTM_TIM5_CH4_CNTS (0xF0103A10u)
+#define GTM_TIM5_CH4_TDUC (0xF0103A14u)
+#define GTM_TIM5_CH4_TDUV (0xF0103A18u)
+#define GTM_TIM5_CH4_FLT_RE (0xF0103A1Cu)
+#define GTM_TIM5_CH4_FLT_FE (0xF0103A20u)
+#define GTM_TIM5_CH4_CTRL (0xF0103A24u)
+#define GTM_TIM5_CH4_ECTRL (0xF0103A28u)
+#define GTM_TIM5_CH4_IRQ_NOTIFY (0xF0103A2Cu)
+#define GTM_TIM5_CH4_IRQ_EN (0xF0103A30u)
+#define GTM_TIM5_CH4_IRQ_FORCINT (0xF0103A34u)
+#define GTM_MON_ACTIVITY_MCS1 (0xF0100190u)
+#define GTM_TIM5_CH4_IRQ_MODE (0xF0103A38u)
+#define GTM_TIM5_CH4_EIRQ_EN (0xF0103A3Cu)
+#define GTM_TIM5_CH5_GPR0 (0xF0103A80u)
+#define GTM_TIM5_CH5_GPR1 (0xF0103A84u)
+#define GTM_TIM5_CH5_CNT (0xF0103A88u)
+#define GTM_TIM5_CH5_ECNT (0xF0103A8Cu)
+#define GTM_TIM5_CH5_CNTS (0xF0103A90u)
+#define GTM_TIM5_CH5_TDUC (0xF0103A94u)
+#define GTM_TIM5_CH5_TDUV (0xF0103A98u)
+#define GTM_TIM5_CH5_FLT_RE (0xF0103A9Cu)
+#define GTM_MON_ACTIVITY_MCS2 (0xF0100194u)
+#define GTM_TIM5_CH5_FLT_FE (0xF0103AA0u)
+#define GTM_TIM5_CH5_CTRL (0xF0103AA4u)
+#define GTM_TIM5_CH5_ECTRL (0xF0103AA8u)
+#define GTM_TIM5_CH5_IRQ_NOTIFY (0xF0103AACu)
+#define GTM_TIM5_CH5_IRQ_EN (0xF0103AB0u)
+#define GTM_TIM5_CH5_IRQ_FORCINT (0xF0103AB4u)
+#define GTM_TIM5_CH5_IRQ_MODE (0xF0103AB8u)
+#define GTM_TIM5_CH5_EIRQ_EN (0xF0103ABCu)
+#define GTM_TIM5_CH6_GPR0 (0xF0103B00u)
+#define GTM_TIM5_CH6_GPR1 (0xF0103B04u)
+#define GTM_MON_ACTIVITY_MCS3 (0xF0100198u)
+#define GTM_TIM5_CH6_CNT (0xF0103B08u)
+#define GTM_TIM5_CH6_ECNT (0xF0103B0Cu)
+#define GTM_TIM5_CH6_CNTS (0xF0103B10u)
+#define GTM_TIM5_CH6_TDUC (0xF0103B14u)
+#define GTM_TIM5_CH6_TDUV (0xF0103B18u)
+#define GTM_TIM5_CH6_FLT_RE (0xF0103B1Cu)
+#define GTM_TIM5_CH6_FLT_FE (0xF0103B20u)
+#define GTM_TIM5_CH6_CTRL (0xF0103B24u)
+#define GTM_TIM5_CH6_ECTRL (0xF0103B28u)
+#define GTM_TIM5_CH6_IRQ_NOTIFY (0xF0103B2Cu)
+#define GTM_MON_ACTIVITY_MCS4 (0xF010019Cu)
+#define GTM_TIM5_CH6_IRQ_EN (0xF0103B30u)
+#define GTM_TIM5_CH6_IRQ_FORCINT (0xF0103B34u)
+#define GTM_TIM5_CH6_IRQ_MODE (0xF0103B38u)
+#define GTM_TIM5_CH6_EIRQ_EN (0xF0103B3Cu)
+#define GTM_TIM5_CH7_GPR0 (0xF0103B80u)
+#define GTM_TIM5_CH7_GPR1 (0xF0103B84u)
+#define GTM_TIM5_CH7_CNT (0xF0103B88u)
+#define GTM_TIM5_CH7_ECNT (0xF0103B8Cu)
+#define GTM_TIM5_CH7_CNTS (0xF0103B90u)
+#define GTM_TIM5_CH7_TDUC (0xF0103B94u)
+#define GTM_CMP_EN (0xF0100200u)
+#define GTM_TIM5_CH7_TDUV (0xF0103B98u)
+#define GTM_TIM5_CH7_FLT_RE (0xF0103B9Cu)
+#define GTM_TIM5_CH7_FLT_FE (0xF0103BA0u)
+#define GTM_TIM5_CH7_CTRL (0xF0103BA4u)
+#define GTM_TIM5_CH7_ECTRL (0xF0103BA8u)
+#define GTM_TIM5_CH7_IRQ_NOTIFY (0xF0103BACu)
+#define GTM_TIM5_CH7_IRQ_EN (0xF0103BB0u)
+#define GTM_TIM5_CH7_IRQ_FORCINT (0xF0103BB4u)
+#define GTM_TIM5_CH7_IRQ_MODE (0xF0103BB8u)
+#define GTM_TIM5_CH7_EIRQ_EN (0xF0103BBCu)
+#define GTM_CMP_IRQ_NOTIFY (0xF0100204u)
+#define GTM_TOM0_CH0_CTRL (0xF0108000u)
+#define GTM_TOM0_CH0_SR0 (0xF0108004u)
+#define GTM_TOM0_CH0_SR1 (0xF0108008u)
+#define GTM_TOM0_CH0_CM0 (0xF010800Cu)
+#define GTM_TOM0_CH0_CM1 (0xF0108010u)
+#define GTM_TOM0_CH0_CN0 (0xF0108014u)
+#define GTM_TOM0_CH0_STAT (0xF0108018u)
+#define GTM_TOM0_CH0_IRQ_NOTIFY (0xF010801Cu)
+#define GTM_TOM0_CH0_IRQ_EN (0xF0108020u)
+#define GTM_TOM0_CH0_IRQ_FORCINT (0xF0108024u)
+#define GTM_CMP_IRQ_EN (0xF0100208u)
+#define GTM_TOM0_CH0_IRQ_MODE (0xF0108028u)
+#define GTM_TOM0_TGC0_GLB_CTRL (0xF0108030u)
+#define GTM_TOM0_TGC0_ACT_TB (0xF0108034u)
+#define GTM_TOM0_TGC0_FUPD_CTRL (0xF0108038u)
+#define GTM_TOM0_TGC0_INT_TRIG (0xF010803Cu)
+#define GTM_TOM0_CH1_CTRL (0xF0108040u)
+#define GTM_TOM0_CH1_SR0 (0xF0108044u)
+#define GTM_TOM0_CH1_SR1 (0xF0108048u)
+#define GTM_TOM0_CH1_CM0 (0xF010804Cu)
+#define GTM_TOM0_CH1_CM1 (0xF0108050u)
+#define GTM_CMP_IRQ_FORCINT (0xF010020Cu)
+#define GTM_TOM0_CH1_CN0 (0xF0108054u)
+#define GTM_TOM0_CH1_STAT (0xF0108058u)
+#define GTM_TOM0_CH1_IRQ_NOTIFY (0xF010805Cu)
+#define GTM_TOM0_CH1_IRQ_EN (0xF0108060u)
+#define GTM_TOM0_CH1_IRQ_FORCINT (0xF0108064u)
+#define GTM_TOM0_CH1_IRQ_MODE (0xF0108068u)
+#define GTM_TOM0_TGC0_ENDIS_CTRL (0xF0108070u)
+#define GTM_TOM0_TGC0_ENDIS_STAT (0xF0108074u)
+#define GTM_TOM0_TGC0_OUTEN_CTRL (0xF0108078u)
+#define GTM_TOM0_TGC0_OUTEN_STAT (0xF010807Cu)
+#define GTM_CMP_IRQ_MODE (0xF0100210u)
+#define GTM_TOM0_CH2_CTRL (0xF0108080u)
+#define GTM_TOM0_CH2_SR0 (0xF0108084u)
+#define GTM_TOM0_CH2_SR1 (0xF0108088u)
+#define GTM_TOM0_CH2_CM0 (0xF010808Cu)
+#define GTM_TOM0_CH2_CM1 (0xF0108090u)
+#define GTM_TOM0_CH2_CN0 (0xF0108094u)
+#define GTM_TOM0_CH2_STAT (0xF0108098u)
+#define GTM_TOM0_CH2_IRQ_NOTIFY (0xF010809Cu)
+#define GTM_TOM0_CH2_IRQ_EN (0xF01080A0u)
+#define GTM_TOM0_CH2_IRQ_FORCINT (0xF01080A4u)
+#define GTM_CMP_EIRQ_EN (0xF0100214u)
+#define GTM_TOM0_CH2_IRQ_MODE (0xF01080A8u)
+#define GTM_TOM0_CH3_CTRL (0xF01080C0u)
+#define GTM_TOM0_CH3_SR0 (0xF01080C4u)
+#define GTM_TOM0_CH3_SR1 (0xF01080C8u)
+#define GTM_TOM0_CH3_CM0 (0xF01080CCu)
+#define GTM_TOM0_CH3_CM1 (0xF01080D0u)
+#define GTM_TOM0_CH3_CN0 (0xF01080D4u)
+#define GTM_TOM0_CH3_STAT (0xF01080D8u)
+#define GTM_TOM0_CH3_IRQ_NOTIFY (0xF01080DCu)
+#define GTM_TOM0_CH3_IRQ_EN (0xF01080E0u)
+#define GTM_ARU_ACCESS (0xF0100280u)
+#define GTM_TOM0_CH3_IRQ_FORCINT (0xF01080E4u)
+#define GTM_TOM0_CH3_IRQ_MODE (0xF01080E8u)
+#define GTM_TOM0_CH4_CTRL (0xF0108100u)
+#define GTM_TOM0_CH4_SR0 (0xF0108104u)
+#define GTM_TOM0_CH4_SR1 (0xF0108108u)
+#define GTM_TOM0_CH4_CM0 (0xF010810Cu)
+#define GTM_TOM0_CH4_CM1 (0xF0108110u)
+#define GTM_TOM0_CH4_CN0 (0xF0108114u)
+#define GTM_TOM0_CH4_STAT (0xF0108118u)
+#define GTM_TOM0_CH4_IRQ_NOTIFY (0xF010811Cu)
+#define GTM_ARU_DATA_H (0xF0100284u)
+#define GTM_TOM0_CH4_IRQ_EN (0xF0108120u)
+#define GTM_TOM0_CH4_IRQ_FORCINT (0xF0108124u)
+#define GTM_TOM0_CH4_IRQ_MODE (0xF0108128u)
+#define GTM_TOM0_CH5_CTRL (0xF0108140u)
+#define GTM_TOM0_CH5_SR0 (0xF0108144u)
+#define GTM_TOM0_CH5_SR1 (0xF0108148u)
+#define GTM_TOM0_CH5_CM0 (0xF010814Cu)
+#define GTM_TOM0_CH5_CM1 (0xF0108150u)
+#define GTM_TOM0_CH5_CN0 (0xF0108154u)
+#define GTM_TOM0_CH5_STAT (0xF0108158u)
+#define GTM_ARU_DATA_L (0xF0100288u)
+#define GTM_TOM0_CH5_IRQ_NOTIFY (0xF010815Cu)
+#define GTM_TOM0_CH5_IRQ_EN (0xF0108160u)
+#define GTM_TOM0_CH5_IRQ_FORCINT (0xF0108164u)
+#define GTM_TOM0_CH5_IRQ_MODE (0xF0108168u)
+#define GTM_TOM0_CH6_CTRL (0xF0108180u)
+#define GTM_TOM0_CH6_SR0 (0xF0108184u)
+#define GTM_TOM0_CH6_SR1 (0xF0108188u)
+#define GTM_TOM0_CH6_CM0 (0xF010818Cu)
+#define GTM_TOM0_CH6_CM1 (0xF0108190u)
+#define GTM_TOM0_CH6_CN0 (0xF0108194u)
+#define GTM_ARU_DBG_ACCESS0 (0xF010028Cu)
+#define GTM_TOM0_CH6_STAT (0xF0108198u)
+#define GTM_TOM0_CH6_IRQ_NOTIFY (0xF010819Cu)
+#define GTM_TOM0_CH6_IRQ_EN (0xF01081A0u)
+#define GTM_TOM0_CH6_IRQ_FORCINT (0xF01081A4u)
+#define GTM_TOM0_CH6_IRQ_MODE (0xF01081A8u)
+#define GTM_TOM0_CH7_CTRL (0xF01081C0u)
+#define GTM_TOM0_CH7_SR0 (0xF01081C4u)
+#define GTM_TOM0_CH7_SR1 (0xF01081C8u)
+#define GTM_TOM0_CH7_CM0 (0xF01081CCu)
+#define GTM_TOM0_CH7_CM1 (0xF01081D0u)
+#define GTM_ARU_DBG_DATA0_H (0xF0100290u)
+#define GTM_TOM0_CH7_CN0 (0xF01081D4u)
+#define GTM_TOM0_CH7_STAT (0xF01081D8u)
+#define GTM_TOM0_CH7_IRQ_NOTIFY (0xF01081DCu)
+#define GTM_TOM0_CH7_IRQ_EN (0xF01081E0u)
+#define GTM_TOM0_CH7_IRQ_FORCINT (0xF01081E4u)
+#define GTM_TOM0_CH7_IRQ_MODE (0xF01081E8u)
+#define GTM_TOM0_CH8_CTRL (0xF0108200u)
+#define GTM_TOM0_CH8_SR0 (0xF0108204u)
+#define GTM_TOM0_CH8_SR1 (0xF0108208u)
+#define GTM_TOM0_CH8_CM0 (0xF010820Cu)
+#define GTM_ARU_DBG_DATA0_L (0xF0100294u)
+#define GTM_TOM0_CH8_CM1 (0xF0108210u)
+#define GTM_TOM0_CH8_CN0 (0xF0108214u)
+#define GTM_TOM0_CH8_STAT (0xF0108218u)
+#define GTM_TOM0_CH8_IRQ_NOTIFY (0xF010821Cu)
+#define GTM_TOM0_CH8_IRQ_EN (0xF0108220u)
+#define GTM_TOM0_CH8_IRQ_FORCINT (0xF0108224u)
+#define GTM_TOM0_CH8_IRQ_MODE (0xF0108228u)
+#define GTM_TOM0_TGC1_GLB_CTRL (0xF0108230u)
+#define GTM_TOM0_TGC1_ACT_TB (0xF0108234u)
+#define GTM_TOM0_TGC1_FUPD_CTRL (0xF0108238u)
+#define GTM_ARU_DBG_ACCESS1 (0xF0100298u)
+#define GTM_TOM0_TGC1_INT_TRIG (0xF010823Cu)
+#define GTM_TOM0_CH9_CTRL (0xF0108240u)
+#define GTM_TOM0_CH9_SR0 (0xF0108244u)
+#define GTM_TOM0_CH9_SR1 (0xF0108248u)
+#define GTM_TOM0_CH9_CM0 (0xF010824Cu)
+#define GTM_TOM0_CH9_CM1 (0xF0108250u)
+#define GTM_TOM0_CH9_CN0 (0xF0108254u)
+#define GTM_TOM0_CH9_STAT (0xF0108258u)
+#define GTM_TOM0_CH9_IRQ_NOTIFY (0xF010825Cu)
+#define GTM_TOM0_CH9_IRQ_EN (0xF0108260u)
+#define GTM_ARU_DBG_DATA1_H (0xF010029Cu)
+#define GTM_TOM0_CH9_IRQ_FORCINT (0xF0108264u)
+#define GTM_TOM0_CH9_IRQ_MODE (0xF0108268u)
+#define GTM_TOM0_TGC1_ENDIS_CTRL (0xF0108270u)
+#define GTM_TOM0_TGC1_ENDIS_STAT (0xF0108274u)
+#define GTM_TOM0_TGC1_OUTEN_CTRL (0xF0108278u)
+#define GTM_TOM0_TGC1_OUTEN_STAT (0xF010827Cu)
+#define GTM_TOM0_CH10_CTRL (0xF0108280u)
+#define GTM_TOM0_CH10_SR0 (0xF0108284u)
+#define GTM_TOM0_CH10_SR1 (0xF0108288u)
+#define GTM_TOM0_CH10_CM0 (0xF010828Cu)
+#define GTM_ARU_DBG_DATA1_L (0xF01002A0u)
+#define GTM_TOM0_CH10_CM1 (0xF0108290u)
+#define GTM_TOM0_CH10_CN0 (0xF0108294u)
+#define GTM_TOM0_CH10_STAT (0xF0108298u)
+#define GTM_TOM0_CH10_IRQ_NOTIFY (0xF010829Cu)
+#define GTM_TOM0_CH10_IRQ_EN (0xF01082A0u)
+#define GTM_TOM0_CH10_IRQ_FORCINT (0xF01082A4u)
+#define GTM_TOM0_CH10_IRQ_MODE (0xF01082A8u)
+#define GTM_TOM0_CH11_CTRL (0xF01082C0u)
+#define GTM_TOM0_CH11_SR0 (0xF01082C4u)
+#define GTM_TOM0_CH11_SR1 (0xF01082C8u)
+#define GTM_ARU_IRQ_NOTIFY (0xF01002A4u)
+#define GTM_TOM0_CH11_CM0 (0xF01082CCu)
+#define GTM_TOM0_CH11_CM1 (0xF01082D0u)
+#define GTM_TOM0_CH11_CN0 (0xF01082D4u)
+#define GTM_TOM0_CH11_STAT (0xF01082D8u)
+#define GTM_TOM0_CH11_IRQ_NOTIFY (0xF01082DCu)
+#define GTM_TOM0_CH11_IRQ_EN (0xF01082E0u)
+#define GTM_TOM0_CH11_IRQ_FORCINT (0xF01082E4u)
+#define GTM_TOM0_CH11_IRQ_MODE (0xF01082E8u)
+#define GTM_TOM0_CH12_CTRL (0xF0108300u)
+#define GTM_TOM0_CH12_SR0 (0xF0108304u)
+#define GTM_ARU_IRQ_EN (0xF01002A8u)
+#define GTM_TOM0_CH12_SR1 (0xF0108308u)
+#define GTM_TOM0_CH12_CM0 (0xF010830Cu)
+#define GTM_TOM0_CH12_CM1 (0xF0108310u)
+#define GTM_TOM0_CH12_CN0 (0xF0108314u)
+#define GTM_TOM0_CH12_STAT (0xF0108318u)
+#define GTM_TOM0_CH12_IRQ_NOTIFY (0xF010831Cu)
+#define GTM_TOM0_CH12_IRQ_EN (0xF0108320u)
+#define GTM_TOM0_CH12_IRQ_FORCINT (0xF0108324u)
+#define GTM_TOM0_CH12_IRQ_MODE (0xF0108328u)
+#define GTM_TOM0_CH13_CTRL (0xF0108340u)
+#define GTM_ARU_IRQ_FORCINT (0xF01002ACu)
+#define GTM_TOM0_CH13_SR0 (0xF0108344u)
+#define GTM_TOM0_CH13_SR1 (0xF0108348u)
+#define GTM_TOM0_CH13_CM0 (0xF010834Cu)
+#define GTM_TOM0_CH13_CM1 (0xF0108350u)
+#define GTM_TOM0_CH13_CN0 (0xF0108354u)
+#define GTM_TOM0_CH13_STAT (0xF0108358u)
+#define GTM_TOM0_CH13_IRQ_NOTIFY (0xF010835Cu)
+#define GTM_TOM0_CH13_IRQ_EN (0xF0108360u)
+#define GTM_TOM0_CH13_IRQ_FORCINT (0xF0108364u)
+#define GTM_TOM0_CH13_IRQ_MODE (0xF0108368u)
+#define GTM_ARU_IRQ_MODE (0xF01002B0u)
+#define GTM_TOM0_CH14_CTRL (0xF0108380u)
+#define GTM_TOM0_CH14_SR0 (0xF0108384u)
+#define GTM_TOM0_CH14_SR1 (0xF0108388u)
+#define GTM_TOM0_CH14_CM0 (0xF010838Cu)
+#define GTM_TOM0_CH14_CM1 (0xF0108390u)
+#define GTM_TOM0_CH14_CN0 (0xF0108394u)
+#define GTM_TOM0_CH14_STAT (0xF0108398u)
+#define GTM_TOM0_CH14_IRQ_NOTIFY (0xF010839Cu)
+#define GTM_TOM0_CH14_IRQ_EN (0xF01083A0u)
+#define GTM_TOM0_CH14_IRQ_FORCINT (0xF01083A4u)
+#define GTM_ARU_CADDR_END (0xF01002B4u)
+#define GTM_TOM0_CH14_IRQ_MODE (0xF01083A8u)
+#define GTM_TOM0_CH15_CTRL (0xF01083C0u)
+#define GTM_TOM0_CH15_SR0 (0xF01083C4u)
+#define GTM_TOM0_CH15_SR1 (0xF01083C8u)
+#define GTM_TOM0_CH15_CM0 (0xF01083CCu)
+#define GTM_TOM0_CH15_CM1 (0xF01083D0u)
+#define GTM_TOM0_CH15_CN0 (0xF01083D4u)
+#define GTM_TOM0_CH15_STAT (0xF01083D8u)
+#define GTM_TOM0_CH15_IRQ_NOTIFY (0xF01083DCu)
+#define GTM_TOM0_CH15_IRQ_EN (0xF01083E0u)
+#define GTM_ARU_CTRL (0xF01002BCu)
+#define GTM_TOM0_CH15_IRQ_FORCINT (0xF01083E4u)
+#define GTM_TOM0_CH15_IRQ_MODE (0xF01083E8u)
+#define GTM_TOM1_CH0_CTRL (0xF0108800u)
+#define GTM_TOM1_CH0_SR0 (0xF0108804u)
+#define GTM_TOM1_CH0_SR1 (0xF0108808u)
+#define GTM_TOM1_CH0_CM0 (0xF010880Cu)
+#define GTM_TOM1_CH0_CM1 (0xF0108810u)
+#define GTM_TOM1_CH0_CN0 (0xF0108814u)
+#define GTM_TOM1_CH0_STAT (0xF0108818u)
+#define GTM_TOM1_CH0_IRQ_NOTIFY (0xF010881Cu)
+#define GTM_ARU_DYN_CTRL0 (0xF01002C0u)
+#define GTM_TOM1_CH0_IRQ_EN (0xF0108820u)
+#define GTM_TOM1_CH0_IRQ_FORCINT (0xF0108824u)
+#define GTM_TOM1_CH0_IRQ_MODE (0xF0108828u)
+#define GTM_TOM1_TGC0_GLB_CTRL (0xF0108830u)
+#define GTM_TOM1_TGC0_ACT_TB (0xF0108834u)
+#define GTM_TOM1_TGC0_FUPD_CTRL (0xF0108838u)
+#define GTM_TOM1_TGC0_INT_TRIG (0xF010883Cu)
+#define GTM_TOM1_CH1_CTRL (0xF0108840u)
+#define GTM_TOM1_CH1_SR0 (0xF0108844u)
+#define GTM_TOM1_CH1_SR1 (0xF0108848u)
+#define GTM_TOM1_CH1_CM0 (0xF010884Cu)
+#define GTM_TOM1_CH1_CM1 (0xF0108850u)
+#define GTM_TOM1_CH1_CN0 (0xF0108854u)
+#define GTM_TOM1_CH1_STAT (0xF0108858u)
+#define GTM_TOM1_CH1_IRQ_NOTIFY (0xF010885Cu)
+#define GTM_TOM1_CH1_IRQ_EN (0xF0108860u)
+#define GTM_TOM1_CH1_IRQ_FORCINT (0xF0108864u)
+#define GTM_TOM1_CH1_IRQ_MODE (0xF0108868u)
+#define GTM_TOM1_TGC0_ENDIS_CTRL (0xF0108870u)
+#define GTM_TOM1_TGC0_ENDIS_STAT (0xF0108874u)
+#define GTM_TOM1_TGC0_OUTEN_CTRL (0xF0108878u)
+#define GTM_TOM1_TGC0_OUTEN_STAT (0xF010887Cu)
+#define GTM_TOM1_CH2_CTRL (0xF0108880u)
+#define GTM_ARU_DYN_CTRL1 (0xF01002C4u)
+#define GTM_TOM1_CH2_SR0 (0xF0108884u)
+#define GTM_TOM1_CH2_SR1 (0xF0108888u)
+#define GTM_TOM1_CH2_CM0 (0xF010888Cu)
+#define GTM_TOM1_CH2_CM1 (0xF0108890u)
+#define GTM_TOM1_CH2_CN0 (0xF0108894u)
+#define GTM_TOM1_CH2_STAT (0xF0108898u)
+#define GTM_TOM1_CH2_IRQ_NOTIFY (0xF010889Cu)
+#define GTM_TOM1_CH2_IRQ_EN (0xF01088A0u)
+#define GTM_TOM1_CH2_IRQ_FORCINT (0xF01088A4u)
+#define GTM_TOM1_CH2_IRQ_MODE (0xF01088A8u)
+#define GTM_TOM1_CH3_CTRL (0xF01088C0u)
+#define GTM_TOM1_CH3_SR0 (0xF01088C4u)
+#define GTM_TOM1_CH3_SR1 (0xF01088C8u)
+#define GTM_TOM1_CH3_CM0 (0xF01088CCu)
+#define GTM_TOM1_CH3_CM1 (0xF01088D0u)
+#define GTM_TOM1_CH3_CN0 (0xF01088D4u)
+#define GTM_TOM1_CH3_STAT (0xF01088D8u)
+#define GTM_TOM1_CH3_IRQ_NOTIFY (0xF01088DCu)
+#define GTM_TOM1_CH3_IRQ_EN (0xF01088E0u)
+#define GTM_TOM1_CH3_IRQ_FORCINT (0xF01088E4u)
+#define GTM_TOM1_CH3_IRQ_MODE (0xF01088E8u)
+#define GTM_TOM1_CH4_CTRL (0xF0108900u)
+#define GTM_TOM1_CH4_SR0 (0xF0108904u)
+#define GTM_TOM1_CH4_SR1 (0xF0108908u)
+#define GTM_ARU_DYN_ROUTE_LOW0 (0xF01002C8u)
+#define GTM_TOM1_CH4_CM0 (0xF010890Cu)
+#define GTM_TOM1_CH4_CM1 (0xF0108910u)
+#define GTM_TOM1_CH4_CN0 (0xF0108914u)
+#define GTM_TOM1_CH4_STAT (0xF0108918u)
+#define GTM_TOM1_CH4_IRQ_NOTIFY (0xF010891Cu)
+#define GTM_TOM1_CH4_IRQ_EN (0xF0108920u)
+#define GTM_TOM1_CH4_IRQ_FORCINT (0xF0108924u)
+#define GTM_TOM1_CH4_IRQ_MODE (0xF0108928u)
+#define GTM_TOM1_CH5_CTRL (0xF0108940u)
+#define GTM_TOM1_CH5_SR0 (0xF0108944u)
+#define GTM_TOM1_CH5_SR1 (0xF0108948u)
+#define GTM_TOM1_CH5_CM0 (0xF010894Cu)
+#define GTM_TOM1_CH5_CM1 (0xF0108950u)
+#define GTM_TOM1_CH5_CN0 (0xF0108954u)
+#define GTM_TOM1_CH5_STAT (0xF0108958u)
+#define GTM_TOM1_CH5_IRQ_NOTIFY (0xF010895Cu)
+#define GTM_TOM1_CH5_IRQ_EN (0xF0108960u)
+#define GTM_TOM1_CH5_IRQ_FORCINT (0xF0108964u)
+#define GTM_TOM1_CH5_IRQ_MODE (0xF0108968u)
+#define GTM_TOM1_CH6_CTRL (0xF0108980u)
+#define GTM_TOM1_CH6_SR0 (0xF0108984u)
+#define GTM_TOM1_CH6_SR1 (0xF0108988u)
+#define GTM_TOM1_CH6_CM0 (0xF010898Cu)
+#define GTM_ARU_DYN_ROUTE_LOW1 (0xF01002CCu)
+#define GTM_TOM1_CH6_CM1 (0xF0108990u)
+#define GTM_TOM1_CH6_CN0 (0xF0108994u)
+#define GTM_TOM1_CH6_STAT (0xF0108998u)
+#define GTM_TOM1_CH6_IRQ_NOTIFY (0xF010899Cu)
+#define GTM_TOM1_CH6_IRQ_EN (0xF01089A0u)
+#define GTM_TOM1_CH6_IRQ_FORCINT (0xF01089A4u)
+#define GTM_TOM1_CH6_IRQ_MODE (0xF01089A8u)
+#define GTM_TOM1_CH7_CTRL (0xF01089C0u)
+#define GTM_TOM1_CH7_SR0 (0xF01089C4u)
+#define GTM_TOM1_CH7_SR1 (0xF01089C8u)
+#define GTM_TOM1_CH7_CM0 (0xF01089CCu)
+#define GTM_TOM1_CH7_CM1 (0xF01089D0u)
+#define GTM_TOM1_CH7_CN0 (0xF01089D4u)
+#define GTM_TOM1_CH7_STAT (0xF01089D8u)
+#define GTM_TOM1_CH7_IRQ_NOTIFY (0xF01089DCu)
+#define GTM_TOM1_CH7_IRQ_EN (0xF01089E0u)
+#define GTM_TOM1_CH7_IRQ_FORCINT (0xF01089E4u)
+#define GTM_TOM1_CH7_IRQ_MODE (0xF01089E8u)
+#define GTM_TOM1_CH8_CTRL (0xF0108A00u)
+#define GTM_TOM1_CH8_SR0 (0xF0108A04u)
+#define GTM_TOM1_CH8_SR1 (0xF0108A08u)
+#define GTM_TOM1_CH8_CM0 (0xF0108A0Cu)
+#define GTM_TOM1_CH8_CM1 (0xF0108A10u)
+#define GTM_ARU_DYN_ROUTE_HIGH0 (0xF01002D0u)
+#define GTM_TOM1_CH8_CN0 (0xF0108A14u)
+#define GTM_TOM1_CH8_STAT (0xF0108A18u)
+#define GTM_TOM1_CH8_IRQ_NOTIFY (0xF0108A1Cu)
+#define GTM_TOM1_CH8_IRQ_EN (0xF0108A20u)
+#define GTM_TOM1_CH8_IRQ_FORCINT (0xF0108A24u)
+#define GTM_TOM1_CH8_IRQ_MODE (0xF0108A28u)
+#define GTM_TOM1_TGC1_GLB_CTRL (0xF0108A30u)
+#define GTM_TOM1_TGC1_ACT_TB (0xF0108A34u)
+#define GTM_TOM1_TGC1_FUPD_CTRL (0xF0108A38u)
+#define GTM_TOM1_TGC1_INT_TRIG (0xF0108A3Cu)
+#define GTM_TOM1_CH9_CTRL (0xF0108A40u)
+#define GTM_TOM1_CH9_SR0 (0xF0108A44u)
+#define GTM_TOM1_CH9_SR1 (0xF0108A48u)
+#define GTM_TOM1_CH9_CM0 (0xF0108A4Cu)
+#define GTM_TOM1_CH9_CM1 (0xF0108A50u)
+#define GTM_TOM1_CH9_CN0 (0xF0108A54u)
+#define GTM_TOM1_CH9_STAT (0xF0108A58u)
+#define GTM_TOM1_CH9_IRQ_NOTIFY (0xF0108A5Cu)
+#define GTM_TOM1_CH9_IRQ_EN (0xF0108A60u)
+#define GTM_TOM1_CH9_IRQ_FORCINT (0xF0108A64u)
+#define GTM_TOM1_CH9_IRQ_MODE (0xF0108A68u)
+#define GTM_TOM1_TGC1_ENDIS_CTRL (0xF0108A70u)
+#define GTM_TOM1_TGC1_ENDIS_STAT (0xF0108A74u)
+#define GTM_TOM1_TGC1_OUTEN_CTRL (0xF0108A78u)
+#define GTM_ARU_DYN_ROUTE_HIGH1 (0xF01002D4u)
+#define GTM_TOM1_TGC1_OUTEN_STAT (0xF0108A7Cu)
+#define GTM_TOM1_CH10_CTRL (0xF0108A80u)
+#define GTM_TOM1_CH10_SR0 (0xF0108A84u)
+#define GTM_TOM1_CH10_SR1 (0xF0108A88u)
+#define GTM_TOM1_CH10_CM0 (0xF0108A8Cu)
+#define GTM_TOM1_CH10_CM1 (0xF0108A90u)
+#define GTM_TOM1_CH10_CN0 (0xF0108A94u)
+#define GTM_TOM1_CH10_STAT (0xF0108A98u)
+#define GTM_TOM1_CH10_IRQ_NOTIFY (0xF0108A9Cu)
+#define GTM_TOM1_CH10_IRQ_EN (0xF0108AA0u)
+#define GTM_TOM1_CH10_IRQ_FORCINT (0xF0108AA4u)
+#define GTM_TOM1_CH10_IRQ_MODE (0xF0108AA8u)
+#define GTM_TOM1_CH11_CTRL (0xF0108AC0u)
+#define GTM_TOM1_CH11_SR0 (0xF0108AC4u)
+#define GTM_TOM1_CH11_SR1 (0xF0108AC8u)
+#define GTM_TOM1_CH11_CM0 (0xF0108ACCu)
+#define GTM_TOM1_CH11_CM1 (0xF0108AD0u)
+#define GTM_TOM1_CH11_CN0 (0xF0108AD4u)
+#define GTM_TOM1_CH11_STAT (0xF0108AD8u)
+#define GTM_TOM1_CH11_IRQ_NOTIFY (0xF0108ADCu)
+#define GTM_TOM1_CH11_IRQ_EN (0xF0108AE0u)
+#define GTM_TOM1_CH11_IRQ_FORCINT (0xF0108AE4u)
+#define GTM_TOM1_CH11_IRQ_MODE (0xF0108AE8u)
+#define GTM_ARU_DYN_ROUTE_SR_LOW0 (0xF01002D8u)
+#define GTM_TOM1_CH12_CTRL (0xF0108B00u)
+#define GTM_TOM1_CH12_SR0 (0xF0108B04u)
+#define GTM_TOM1_CH12_SR1 (0xF0108B08u)
+#define GTM_TOM1_CH12_CM0 (0xF0108B0Cu)
+#define GTM_TOM1_CH12_CM1 (0xF0108B10u)
+#define GTM_TOM1_CH12_CN0 (0xF0108B14u)
+#define GTM_TOM1_CH12_STAT (0xF0108B18u)
+#define GTM_TOM1_CH12_IRQ_NOTIFY (0xF0108B1Cu)
+#define GTM_TOM1_CH12_IRQ_EN (0xF0108B20u)
+#define GTM_TOM1_CH12_IRQ_FORCINT (0xF0108B24u)
+#define GTM_TOM1_CH12_IRQ_MODE (0xF0108B28u)
+#define GTM_TOM1_CH13_CTRL (0xF0108B40u)
+#define GTM_TOM1_CH13_SR0 (0xF0108B44u)
+#define GTM_TOM1_CH13_SR1 (0xF0108B48u)
+#define GTM_TOM1_CH13_CM0 (0xF0108B4Cu)
+#define GTM_TOM1_CH13_CM1 (0xF0108B50u)
+#define GTM_TOM1_CH13_CN0 (0xF0108B54u)
+#define GTM_TOM1_CH13_STAT (0xF0108B58u)
+#define GTM_TOM1_CH13_IRQ_NOTIFY (0xF0108B5Cu)
+#define GTM_TOM1_CH13_IRQ_EN (0xF0108B60u)
+#define GTM_TOM1_CH13_IRQ_FORCINT (0xF0108B64u)
+#define GTM_TOM1_CH13_IRQ_MODE (0xF0108B68u)
+#define GTM_TOM1_CH14_CTRL (0xF0108B80u)
+#define GTM_ARU_DYN_ROUTE_SR_LOW1 (0xF01002DCu)
+#define GTM_TOM1_CH14_SR0 (0xF0108B84u)
+#define GTM_TOM1_CH14_SR1 (0xF0108B88u)
+#define GTM_TOM1_CH14_CM0 (0xF0108B8Cu)
+#define GTM_TOM1_CH14_CM1 (0xF0108B90u)
+#define GTM_TOM1_CH14_CN0 (0xF0108B94u)
+#define GTM_TOM1_CH14_STAT (0xF0108B98u)
+#define GTM_TOM1_CH14_IRQ_NOTIFY (0xF0108B9Cu)
+#define GTM_TOM1_CH14_IRQ_EN (0xF0108BA0u)
+#define GTM_TOM1_CH14_IRQ_FORCINT (0xF0108BA4u)
+#define GTM_TOM1_CH14_IRQ_MODE (0xF0108BA8u)
+#define GTM_TOM1_CH15_CTRL (0xF0108BC0u)
+#define GTM_TOM1_CH15_SR0 (0xF0108BC4u)
+#define GTM_TOM1_CH15_SR1 (0xF0108BC8u)
+#define GTM_TOM1_CH15_CM0 (0xF0108BCCu)
+#define GTM_TOM1_CH15_CM1 (0xF0108BD0u)
+#define GTM_TOM1_CH15_CN0 (0xF0108BD4u)
+#define GTM_TOM1_CH15_STAT (0xF0108BD8u)
+#define GTM_TOM1_CH15_IRQ_NOTIFY (0xF0108BDCu)
+#define GTM_TOM1_CH15_IRQ_EN (0xF0108BE0u)
+#define GTM_TOM1_CH15_IRQ_FORCINT (0xF0108BE4u)
+#define GTM_TOM1_CH15_IRQ_MODE (0xF0108BE8u)
+#define GTM_TOM2_CH0_CTRL (0xF0109000u)
+#define GTM_TOM2_CH0_SR0 (0xF0109004u)
+#define GTM_TOM2_CH0_SR1 (0xF0109008u)
+#define GTM_ARU_DYN_ROUTE_SR_HIGH0 (0xF01002E0u)
+#define GTM_TOM2_CH0_CM0 (0xF010900Cu)
+#define GTM_TOM2_CH0_CM1 (0xF0109010u)
+#define GTM_TOM2_CH0_CN0 (0xF0109014u)
+#define GTM_TOM2_CH0_STAT (0xF0109018u)
+#define GTM_TOM2_CH0_IRQ_NOTIFY (0xF010901Cu)
+#define GTM_TOM2_CH0_IRQ_EN (0xF0109020u)
+#define GTM_TOM2_CH0_IRQ_FORCINT (0xF0109024u)
+#define GTM_TOM2_CH0_IRQ_MODE (0xF0109028u)
+#define GTM_TOM2_TGC0_GLB_CTRL (0xF0109030u)
+#define GTM_TOM2_TGC0_ACT_TB (0xF0109034u)
+#define GTM_TOM2_TGC0_FUPD_CTRL (0xF0109038u)
+#define GTM_TOM2_TGC0_INT_TRIG (0xF010903Cu)
+#define GTM_TOM2_CH1_CTRL (0xF0109040u)
+#define GTM_TOM2_CH1_SR0 (0xF0109044u)
+#define GTM_TOM2_CH1_SR1 (0xF0109048u)
+#define GTM_TOM2_CH1_CM0 (0xF010904Cu)
+#define GTM_TOM2_CH1_CM1 (0xF0109050u)
+#define GTM_TOM2_CH1_CN0 (0xF0109054u)
+#define GTM_TOM2_CH1_STAT (0xF0109058u)
+#define GTM_TOM2_CH1_IRQ_NOTIFY (0xF010905Cu)
+#define GTM_TOM2_CH1_IRQ_EN (0xF0109060u)
+#define GTM_TOM2_CH1_IRQ_FORCINT (0xF0109064u)
+#define GTM_TOM2_CH1_IRQ_MODE (0xF0109068u)
+#define GTM_ARU_DYN_ROUTE_SR_HIGH1 (0xF01002E4u)
+#define GTM_TOM2_TGC0_ENDIS_CTRL (0xF0109070u)
+#define GTM_TOM2_TGC0_ENDIS_STAT (0xF0109074u)
+#define GTM_TOM2_TGC0_OUTEN_CTRL (0xF0109078u)
+#define GTM_TOM2_TGC0_OUTEN_STAT (0xF010907Cu)
+#define GTM_TOM2_CH2_CTRL (0xF0109080u)
+#define GTM_TOM2_CH2_SR0 (0xF0109084u)
+#define GTM_TOM2_CH2_SR1 (0xF0109088u)
+#define GTM_TOM2_CH2_CM0 (0xF010908Cu)
+#define GTM_TOM2_CH2_CM1 (0xF0109090u)
+#define GTM_TOM2_CH2_CN0 (0xF0109094u)
+#define GTM_TOM2_CH2_STAT (0xF0109098u)
+#define GTM_TOM2_CH2_IRQ_NOTIFY (0xF010909Cu)
+#define GTM_TOM2_CH2_IRQ_EN (0xF01090A0u)
+#define GTM_TOM2_CH2_IRQ_FORCINT (0xF01090A4u)
+#define GTM_TOM2_CH2_IRQ_MODE (0xF01090A8u)
+#define GTM_TOM2_CH3_CTRL (0xF01090C0u)
+#define GTM_TOM2_CH3_SR0 (0xF01090C4u)
+#define GTM_TOM2_CH3_SR1 (0xF01090C8u)
+#define GTM_TOM2_CH3_CM0 (0xF01090CCu)
+#define GTM_TOM2_CH3_CM1 (0xF01090D0u)
+#define GTM_TOM2_CH3_CN0 (0xF01090D4u)
+#define GTM_TOM2_CH3_STAT (0xF01090D8u)
+#define GTM_TOM2_CH3_IRQ_NOTIFY (0xF01090DCu)
+#define GTM_ARU_DYN_RDADDR0 (0xF01002E8u)
+#define GTM_TOM2_CH3_IRQ_EN (0xF01090E0u)
+#define GTM_TOM2_CH3_IRQ_FORCINT (0xF01090E4u)
+#define GTM_TOM2_CH3_IRQ_MODE (0xF01090E8u)
+#define GTM_TOM2_CH4_CTRL (0xF0109100u)
+#define GTM_TOM2_CH4_SR0 (0xF0109104u)
+#define GTM_TOM2_CH4_SR1 (0xF0109108u)
+#define GTM_TOM2_CH4_CM0 (0xF010910Cu)
+#define GTM_TOM2_CH4_CM1 (0xF0109110u)
+#define GTM_TOM2_CH4_CN0 (0xF0109114u)
+#define GTM_TOM2_CH4_STAT (0xF0109118u)
+#define GTM_TOM2_CH4_IRQ_NOTIFY (0xF010911Cu)
+#define GTM_TOM2_CH4_IRQ_EN (0xF0109120u)
+#define GTM_TOM2_CH4_IRQ_FORCINT (0xF0109124u)
+#define GTM_TOM2_CH4_IRQ_MODE (0xF0109128u)
+#define GTM_TOM2_CH5_CTRL (0xF0109140u)
+#define GTM_TOM2_CH5_SR0 (0xF0109144u)
+#define GTM_TOM2_CH5_SR1 (0xF0109148u)
+#define GTM_TOM2_CH5_CM0 (0xF010914Cu)
+#define GTM_TOM2_CH5_CM1 (0xF0109150u)
+#define GTM_TOM2_CH5_CN0 (0xF0109154u)
+#define GTM_TOM2_CH5_STAT (0xF0109158u)
+#define GTM_TOM2_CH5_IRQ_NOTIFY (0xF010915Cu)
+#define GTM_TOM2_CH5_IRQ_EN (0xF0109160u)
+#define GTM_TOM2_CH5_IRQ_FORCINT (0xF0109164u)
+#define GTM_ARU_DYN_RDADDR1 (0xF01002ECu)
+#define GTM_TOM2_CH5_IRQ_MODE (0xF0109168u)
+#define GTM_TOM2_CH6_CTRL (0xF0109180u)
+#define GTM_TOM2_CH6_SR0 (0xF0109184u)
+#define GTM_TOM2_CH6_SR1 (0xF0109188u)
+#define GTM_TOM2_CH6_CM0 (0xF010918Cu)
+#define GTM_TOM2_CH6_CM1 (0xF0109190u)
+#define GTM_TOM2_CH6_CN0 (0xF0109194u)
+#define GTM_TOM2_CH6_STAT (0xF0109198u)
+#define GTM_TOM2_CH6_IRQ_NOTIFY (0xF010919Cu)
+#define GTM_TOM2_CH6_IRQ_EN (0xF01091A0u)
+#define GTM_TOM2_CH6_IRQ_FORCINT (0xF01091A4u)
+#define GTM_TOM2_CH6_IRQ_MODE (0xF01091A8u)
+#define GTM_TOM2_CH7_CTRL (0xF01091C0u)
+#define GTM_TOM2_CH7_SR0 (0xF01091C4u)
+#define GTM_TOM2_CH7_SR1 (0xF01091C8u)
+#define GTM_TOM2_CH7_CM0 (0xF01091CCu)
+#define GTM_TOM2_CH7_CM1 (0xF01091D0u)
+#define GTM_TOM2_CH7_CN0 (0xF01091D4u)
+#define GTM_TOM2_CH7_STAT (0xF01091D8u)
+#define GTM_TOM2_CH7_IRQ_NOTIFY (0xF01091DCu)
+#define GTM_TOM2_CH7_IRQ_EN (0xF01091E0u)
+#define GTM_TOM2_CH7_IRQ_FORCINT (0xF01091E4u)
+#define GTM_TOM2_CH7_IRQ_MODE (0xF01091E8u)
+#define GTM_ARU_CADDR (0xF01002FCu)
+#define GTM_TOM2_CH8_CTRL (0xF0109200u)
+#define GTM_TOM2_CH8_SR0 (0xF0109204u)
+#define GTM_TOM2_CH8_SR1 (0xF0109208u)
+#define GTM_TOM2_CH8_CM0 (0xF010920Cu)
+#define GTM_TOM2_CH8_CM1 (0xF0109210u)
+#define GTM_TOM2_CH8_CN0 (0xF0109214u)
+#define GTM_TOM2_CH8_STAT (0xF0109218u)
+#define GTM_TOM2_CH8_IRQ_NOTIFY (0xF010921Cu)
+#define GTM_TOM2_CH8_IRQ_EN (0xF0109220u)
+#define GTM_TOM2_CH8_IRQ_FORCINT (0xF0109224u)
+#define GTM_CMU_CLK_EN (0xF0100300u)
+#define GTM_TOM2_CH8_IRQ_MODE (0xF0109228u)
+#define GTM_TOM2_TGC1_GLB_CTRL (0xF0109230u)
+#define GTM_TOM2_TGC1_ACT_TB (0xF0109234u)
+#define GTM_TOM2_TGC1_FUPD_CTRL (0xF0109238u)
+#define GTM_TOM2_TGC1_INT_TRIG (0xF010923Cu)
+#define GTM_TOM2_CH9_CTRL (0xF0109240u)
+#define GTM_TOM2_CH9_SR0 (0xF0109244u)
+#define GTM_TOM2_CH9_SR1 (0xF0109248u)
+#define GTM_TOM2_CH9_CM0 (0xF010924Cu)
+#define GTM_TOM2_CH9_CM1 (0xF0109250u)
+#define GTM_CMU_GCLK_NUM (0xF0100304u)
+#define GTM_TOM2_CH9_CN0 (0xF0109254u)
+#define GTM_TOM2_CH9_STAT (0xF0109258u)
+#define GTM_TOM2_CH9_IRQ_NOTIFY (0xF010925Cu)
+#define GTM_TOM2_CH9_IRQ_EN (0xF0109260u)
+#define GTM_TOM2_CH9_IRQ_FORCINT (0xF0109264u)
+#define GTM_TOM2_CH9_IRQ_MODE (0xF0109268u)
+#define GTM_TOM2_TGC1_ENDIS_CTRL (0xF0109270u)
+#define GTM_TOM2_TGC1_ENDIS_STAT (0xF0109274u)
+#define GTM_TOM2_TGC1_OUTEN_CTRL (0xF0109278u)
+#define GTM_TOM2_TGC1_OUTEN_STAT (0xF010927Cu)
+#define GTM_CMU_GCLK_DEN (0xF0100308u)
+#define GTM_TOM2_CH10_CTRL (0xF0109280u)
+#define GTM_TOM2_CH10_SR0 (0xF0109284u)
+#define GTM_TOM2_CH10_SR1 (0xF0109288u)
+#define GTM_TOM2_CH10_CM0 (0xF010928Cu)
+#define GTM_TOM2_CH10_CM1 (0xF0109290u)
+#define GTM_TOM2_CH10_CN0 (0xF0109294u)
+#define GTM_TOM2_CH10_STAT (0xF0109298u)
+#define GTM_TOM2_CH10_IRQ_NOTIFY (0xF010929Cu)
+#define GTM_TOM2_CH10_IRQ_EN (0xF01092A0u)
+#define GTM_TOM2_CH10_IRQ_FORCINT (0xF01092A4u)
+#define GTM_CMU_CLK0_CTRL (0xF010030Cu)
+#define GTM_TOM2_CH10_IRQ_MODE (0xF01092A8u)
+#define GTM_TOM2_CH11_CTRL (0xF01092C0u)
+#define GTM_TOM2_CH11_SR0 (0xF01092C4u)
+#define GTM_TOM2_CH11_SR1 (0xF01092C8u)
+#define GTM_TOM2_CH11_CM0 (0xF01092CCu)
+#define GTM_TOM2_CH11_CM1 (0xF01092D0u)
+#define GTM_TOM2_CH11_CN0 (0xF01092D4u)
+#define GTM_TOM2_CH11_STAT (0xF01092D8u)
+#define GTM_TOM2_CH11_IRQ_NOTIFY (0xF01092DCu)
+#define GTM_TOM2_CH11_IRQ_EN (0xF01092E0u)
+#define GTM_TOM2_CH11_IRQ_FORCINT (0xF01092E4u)
+#define GTM_TOM2_CH11_IRQ_MODE (0xF01092E8u)
+#define GTM_TOM2_CH12_CTRL (0xF0109300u)
+#define GTM_TOM2_CH12_SR0 (0xF0109304u)
+#define GTM_TOM2_CH12_SR1 (0xF0109308u)
+#define GTM_TOM2_CH12_CM0 (0xF010930Cu)
+#define GTM_TOM2_CH12_CM1 (0xF0109310u)
+#define GTM_TOM2_CH12_CN0 (0xF0109314u)
+#define GTM_TOM2_CH12_STAT (0xF0109318u)
+#define GTM_TOM2_CH12_IRQ_NOTIFY (0xF010931Cu)
+#define GTM_TOM2_CH12_IRQ_EN (0xF0109320u)
+#define GTM_TOM2_CH12_IRQ_FORCINT (0xF0109324u)
+#define GTM_TOM2_CH12_IRQ_MODE (0xF0109328u)
+#define GTM_CMU_CLK1_CTRL (0xF0100310u)
+#define GTM_TOM2_CH13_CTRL (0xF0109340u)
+#define GTM_TOM2_CH13_SR0 (0xF0109344u)
+#define GTM_TOM2_CH13_SR1 (0xF0109348u)
+#define GTM_TOM2_CH13_CM0 (0xF010934Cu)
+#define GTM_TOM2_CH13_CM1 (0xF0109350u)
+#define GTM_TOM2_CH13_CN0 (0xF0109354u)
+#define GTM_TOM2_CH13_STAT (0xF0109358u)
+#define GTM_TOM2_CH13_IRQ_NOTIFY (0xF010935Cu)
+#define GTM_TOM2_CH13_IRQ_EN (0xF0109360u)
+#define GTM_TOM2_CH13_IRQ_FORCINT (0xF0109364u)
+#define GTM_TOM2_CH13_IRQ_MODE (0xF0109368u)
+#define GTM_TOM2_CH14_CTRL (0xF0109380u)
+#define GTM_TOM2_CH14_SR0 (0xF0109384u)
+#define GTM_TOM2_CH14_SR1 (0xF0109388u)
+#define GTM_TOM2_CH14_CM0 (0xF010938Cu)
+#define GTM_TOM2_CH14_CM1 (0xF0109390u)
+#define GTM_TOM2_CH14_CN0 (0xF0109394u)
+#define GTM_TOM2_CH14_STAT (0xF0109398u)
+#define GTM_TOM2_CH14_IRQ_NOTIFY (0xF010939Cu)
+#define GTM_TOM2_CH14_IRQ_EN (0xF01093A0u)
+#define GTM_TOM2_CH14_IRQ_FORCINT (0xF01093A4u)
+#define GTM_TOM2_CH14_IRQ_MODE (0xF01093A8u)
+#define GTM_TOM2_CH15_CTRL (0xF01093C0u)
+#define GTM_TOM2_CH15_SR0 (0xF01093C4u)
+#define GTM_CMU_CLK2_CTRL (0xF0100314u)
+#define GTM_TOM2_CH15_SR1 (0xF01093C8u)
+#define GTM_TOM2_CH15_CM0 (0xF01093CCu)
+#define GTM_TOM2_CH15_CM1 (0xF01093D0u)
+#define GTM_TOM2_CH15_CN0 (0xF01093D4u)
+#define GTM_TOM2_CH15_STAT (0xF01093D8u)
+#define GTM_TOM2_CH15_IRQ_NOTIFY (0xF01093DCu)
+#define GTM_TOM2_CH15_IRQ_EN (0xF01093E0u)
+#define GTM_TOM2_CH15_IRQ_FORCINT (0xF01093E4u)
+#define GTM_TOM2_CH15_IRQ_MODE (0xF01093E8u)
+#define GTM_PSM0_F2A_RD_CH0_ARU_RD_FIFO (0xF0118000u)
+#define GTM_PSM0_F2A_RD_CH1_ARU_RD_FIFO (0xF0118004u)
+#define GTM_PSM0_F2A_RD_CH2_ARU_RD_FIFO (0xF0118008u)
+#define GTM_PSM0_F2A_RD_CH3_ARU_RD_FIFO (0xF011800Cu)
+#define GTM_PSM0_F2A_RD_CH4_ARU_RD_FIFO (0xF0118010u)
+#define GTM_PSM0_F2A_RD_CH5_ARU_RD_FIFO (0xF0118014u)
+#define GTM_CMU_CLK3_CTRL (0xF0100318u)
+#define GTM_PSM0_F2A_RD_CH6_ARU_RD_FIFO (0xF0118018u)
+#define GTM_PSM0_F2A_RD_CH7_ARU_RD_FIFO (0xF011801Cu)
+#define GTM_PSM0_F2A_STR_CH0_STR_CFG (0xF0118020u)
+#define GTM_PSM0_F2A_STR_CH1_STR_CFG (0xF0118024u)
+#define GTM_PSM0_F2A_STR_CH2_STR_CFG (0xF0118028u)
+#define GTM_PSM0_F2A_STR_CH3_STR_CFG (0xF011802Cu)
+#define GTM_PSM0_F2A_STR_CH4_STR_CFG (0xF0118030u)
+#define GTM_PSM0_F2A_STR_CH5_STR_CFG (0xF0118034u)
+#define GTM_PSM0_F2A_STR_CH6_STR_CFG (0xF0118038u)
+#define GTM_PSM0_F2A_STR_CH7_STR_CFG (0xF011803Cu)
+#define GTM_CMU_CLK4_CTRL (0xF010031Cu)
+#define GTM_PSM0_F2A_ENABLE (0xF0118040u)
+#define GTM_PSM0_F2A_F2A_CTRL (0xF0118044u)
+#define GTM_PSM0_AFD_CH0_BUF_ACC (0xF0118080u)
+#define GTM_PSM0_AFD_CH1_BUF_ACC (0xF0118090u)
+#define GTM_PSM0_AFD_CH2_BUF_ACC (0xF01180A0u)
+#define GTM_PSM0_AFD_CH3_BUF_ACC (0xF01180B0u)
+#define GTM_PSM0_AFD_CH4_BUF_ACC (0xF01180C0u)
+#define GTM_PSM0_AFD_CH5_BUF_ACC (0xF01180D0u)
+#define GTM_PSM0_AFD_CH6_BUF_ACC (0xF01180E0u)
+#define GTM_PSM0_AFD_CH7_BUF_ACC (0xF01180F0u)
+#define GTM_CMU_CLK5_CTRL (0xF0100320u)
+#define GTM_PSM0_FIFO_CH0_CTRL (0xF0118400u)
+#define GTM_PSM0_FIFO_CH0_END_ADDR (0xF0118404u)
+#define GTM_PSM0_FIFO_CH0_START_ADDR (0xF0118408u)
+#define GTM_PSM0_FIFO_CH0_UPPER_WM (0xF011840Cu)
+#define GTM_PSM0_FIFO_CH0_LOWER_WM (0xF0118410u)
+#define GTM_PSM0_FIFO_CH0_STATUS (0xF0118414u)
+#define GTM_PSM0_FIFO_CH0_FILL_LEVEL (0xF0118418u)
+#define GTM_PSM0_FIFO_CH0_WR_PTR (0xF011841Cu)
+#define GTM_PSM0_FIFO_CH0_RD_PTR (0xF0118420u)
+#define GTM_PSM0_FIFO_CH0_IRQ_NOTIFY (0xF0118424u)
+#define GTM_CMU_CLK6_CTRL (0xF0100324u)
+#define GTM_PSM0_FIFO_CH0_IRQ_EN (0xF0118428u)
+#define GTM_PSM0_FIFO_CH0_IRQ_FORCINT (0xF011842Cu)
+#define GTM_PSM0_FIFO_CH0_IRQ_MODE (0xF0118430u)
+#define GTM_PSM0_FIFO_CH0_EIRQ_EN (0xF0118434u)
+#define GTM_PSM0_FIFO_CH1_CTRL (0xF0118440u)
+#define GTM_PSM0_FIFO_CH1_END_ADDR (0xF0118444u)
+#define GTM_PSM0_FIFO_CH1_START_ADDR (0xF0118448u)
+#define GTM_PSM0_FIFO_CH1_UPPER_WM (0xF011844Cu)
+#define GTM_PSM0_FIFO_CH1_LOWER_WM (0xF0118450u)
+#define GTM_PSM0_FIFO_CH1_STATUS (0xF0118454u)
+#define GTM_CMU_CLK7_CTRL (0xF0100328u)
+#define GTM_PSM0_FIFO_CH1_FILL_LEVEL (0xF0118458u)
+#define GTM_PSM0_FIFO_CH1_WR_PTR (0xF011845Cu)
+#define GTM_PSM0_FIFO_CH1_RD_PTR (0xF0118460u)
+#define GTM_PSM0_FIFO_CH1_IRQ_NOTIFY (0xF0118464u)
+#define GTM_PSM0_FIFO_CH1_IRQ_EN (0xF0118468u)
+#define GTM_PSM0_FIFO_CH1_IRQ_FORCINT (0xF011846Cu)
+#define GTM_PSM0_FIFO_CH1_IRQ_MODE (0xF0118470u)
+#define GTM_PSM0_FIFO_CH1_EIRQ_EN (0xF0118474u)
+#define GTM_PSM0_FIFO_CH2_CTRL (0xF0118480u)
+#define GTM_PSM0_FIFO_CH2_END_ADDR (0xF0118484u)
+#define GTM_CMU_ECLK0_NUM (0xF010032Cu)
+#define GTM_PSM0_FIFO_CH2_START_ADDR (0xF0118488u)
+#define GTM_PSM0_FIFO_CH2_UPPER_WM (0xF011848Cu)
+#define GTM_PSM0_FIFO_CH2_LOWER_WM (0xF0118490u)
+#define GTM_PSM0_FIFO_CH2_STATUS (0xF0118494u)
+#define GTM_PSM0_FIFO_CH2_FILL_LEVEL (0xF0118498u)
+#define GTM_PSM0_FIFO_CH2_WR_PTR (0xF011849Cu)
+#define GTM_PSM0_FIFO_CH2_RD_PTR (0xF01184A0u)
+#define GTM_PSM0_FIFO_CH2_IRQ_NOTIFY (0xF01184A4u)
+#define GTM_PSM0_FIFO_CH2_IRQ_EN (0xF01184A8u)
+#define GTM_PSM0_FIFO_CH2_IRQ_FORCINT (0xF01184ACu)
+#define GTM_CMU_ECLK0_DEN (0xF0100330u)
+#define GTM_PSM0_FIFO_CH2_IRQ_MODE (0xF01184B0u)
+#define GTM_PSM0_FIFO_CH2_EIRQ_EN (0xF01184B4u)
+#define GTM_PSM0_FIFO_CH3_CTRL (0xF01184C0u)
+#define GTM_PSM0_FIFO_CH3_END_ADDR (0xF01184C4u)
+#define GTM_PSM0_FIFO_CH3_START_ADDR (0xF01184C8u)
+#define GTM_PSM0_FIFO_CH3_UPPER_WM (0xF01184CCu)
+#define GTM_PSM0_FIFO_CH3_LOWER_WM (0xF01184D0u)
+#define GTM_PSM0_FIFO_CH3_STATUS (0xF01184D4u)
+#define GTM_PSM0_FIFO_CH3_FILL_LEVEL (0xF01184D8u)
+#define GTM_PSM0_FIFO_CH3_WR_PTR (0xF01184DCu)
+#define GTM_CMU_ECLK1_NUM (0xF0100334u)
+#define GTM_PSM0_FIFO_CH3_RD_PTR (0xF01184E0u)
+#define GTM_PSM0_FIFO_CH3_IRQ_NOTIFY (0xF01184E4u)
+#define GTM_PSM0_FIFO_CH3_IRQ_EN (0xF01184E8u)
+#define GTM_PSM0_FIFO_CH3_IRQ_FORCINT (0xF01184ECu)
+#define GTM_PSM0_FIFO_CH3_IRQ_MODE (0xF01184F0u)
+#define GTM_PSM0_FIFO_CH3_EIRQ_EN (0xF01184F4u)
+#define GTM_PSM0_FIFO_CH4_CTRL (0xF0118500u)
+#define GTM_PSM0_FIFO_CH4_END_ADDR (0xF0118504u)
+#define GTM_PSM0_FIFO_CH4_START_ADDR (0xF0118508u)
+#define GTM_PSM0_FIFO_CH4_UPPER_WM (0xF011850Cu)
+#define GTM_CMU_ECLK1_DEN (0xF0100338u)
+#define GTM_PSM0_FIFO_CH4_LOWER_WM (0xF0118510u)
+#define GTM_PSM0_FIFO_CH4_STATUS (0xF0118514u)
+#define GTM_PSM0_FIFO_CH4_FILL_LEVEL (0xF0118518u)
+#define GTM_PSM0_FIFO_CH4_WR_PTR (0xF011851Cu)
+#define GTM_PSM0_FIFO_CH4_RD_PTR (0xF0118520u)
+#define GTM_PSM0_FIFO_CH4_IRQ_NOTIFY (0xF0118524u)
+#define GTM_PSM0_FIFO_CH4_IRQ_EN (0xF0118528u)
+#define GTM_PSM0_FIFO_CH4_IRQ_FORCINT (0xF011852Cu)
+#define GTM_PSM0_FIFO_CH4_IRQ_MODE (0xF0118530u)
+#define GTM_PSM0_FIFO_CH4_EIRQ_EN (0xF0118534u)
+#define GTM_CMU_ECLK2_NUM (0xF010033Cu)
+#define GTM_PSM0_FIFO_CH5_CTRL (0xF0118540u)
+#define GTM_PSM0_FIFO_CH5_END_ADDR (0xF0118544u)
+#define GTM_PSM0_FIFO_CH5_START_ADDR (0xF0118548u)
+#define GTM_PSM0_FIFO_CH5_UPPER_WM (0xF011854Cu)
+#define GTM_PSM0_FIFO_CH5_LOWER_WM (0xF0118550u)
+#define GTM_PSM0_FIFO_CH5_STATUS (0xF0118554u)
+#define GTM_PSM0_FIFO_CH5_FILL_LEVEL (0xF0118558u)
+#define GTM_PSM0_FIFO_CH5_WR_PTR (0xF011855Cu)
+#define GTM_PSM0_FIFO_CH5_RD_PTR (0xF0118560u)
+#define GTM_PSM0_FIFO_CH5_IRQ_NOTIFY (0xF0118564u)
+#define GTM_CMU_ECLK2_DEN (0xF0100340u)
+#define GTM_PSM0_FIFO_CH5_IRQ_EN (0xF0118568u)
+#define GTM_PSM0_FIFO_CH5_IRQ_FORCINT (0xF011856Cu)
+#define GTM_PSM0_FIFO_CH5_IRQ_MODE (0xF0118570u)
+#define GTM_PSM0_FIFO_CH5_EIRQ_EN (0xF0118574u)
+#define GTM_PSM0_FIFO_CH6_CTRL (0xF0118580u)
+#define GTM_PSM0_FIFO_CH6_END_ADDR (0xF0118584u)
+#define GTM_PSM0_FIFO_CH6_START_ADDR (0xF0118588u)
+#define GTM_PSM0_FIFO_CH6_UPPER_WM (0xF011858Cu)
+#define GTM_PSM0_FIFO_CH6_LOWER_WM (0xF0118590u)
+#define GTM_PSM0_FIFO_CH6_STATUS (0xF0118594u)
+#define GTM_CMU_FXCLK_CTRL (0xF0100344u)
+#define GTM_PSM0_FIFO_CH6_FILL_LEVEL (0xF0118598u)
+#define GTM_PSM0_FIFO_CH6_WR_PTR (0xF011859Cu)
+#define GTM_PSM0_FIFO_CH6_RD_PTR (0xF01185A0u)
+#define GTM_PSM0_FIFO_CH6_IRQ_NOTIFY (0xF01185A4u)
+#define GTM_PSM0_FIFO_CH6_IRQ_EN (0xF01185A8u)
+#define GTM_CMU_GLB_CTRL (0xF0100348u)
+#define GTM_PSM0_FIFO_CH6_IRQ_FORCINT (0xF01185ACu)
+#define GTM_PSM0_FIFO_CH6_IRQ_MODE (0xF01185B0u)
+#define GTM_PSM0_FIFO_CH6_EIRQ_EN (0xF01185B4u)
+#define GTM_PSM0_FIFO_CH7_CTRL (0xF01185C0u)
+#define GTM_CMU_CLK_CTRL (0xF010034Cu)
+#define GTM_PSM0_FIFO_CH7_END_ADDR (0xF01185C4u)
+#define GTM_PSM0_FIFO_CH7_START_ADDR (0xF01185C8u)
+#define GTM_PSM0_FIFO_CH7_UPPER_WM (0xF01185CCu)
+#define GTM_PSM0_FIFO_CH7_LOWER_WM (0xF01185D0u)
+#define GTM_BRC_SRC0_ADDR (0xF0100400u)
+#define GTM_PSM0_FIFO_CH7_STATUS (0xF01185D4u)
+#define GTM_PSM0_FIFO_CH7_FILL_LEVEL (0xF01185D8u)
+#define GTM_PSM0_FIFO_CH7_WR_PTR (0xF01185DCu)
+#define GTM_PSM0_FIFO_CH7_RD_PTR (0xF01185E0u)
+#define GTM_PSM0_FIFO_CH7_IRQ_NOTIFY (0xF01185E4u)
+#define GTM_PSM0_FIFO_CH7_IRQ_EN (0xF01185E8u)
+#define GTM_PSM0_FIFO_CH7_IRQ_FORCINT (0xF01185ECu)
+#define GTM_PSM0_FIFO_CH7_IRQ_MODE (0xF01185F0u)
+#define GTM_PSM0_FIFO_CH7_EIRQ_EN (0xF01185F4u)
+#define GTM_DPLL_CTRL_0 (0xF0128000u)
+#define GTM_DPLL_CTRL_1 (0xF0128004u)
+#define GTM_BRC_SRC0_DEST (0xF0100404u)
+#define GTM_DPLL_CTRL_2 (0xF0128008u)
+#define GTM_DPLL_CTRL_3 (0xF012800Cu)
+#define GTM_DPLL_CTRL_4 (0xF0128010u)
+#define GTM_DPLL_CTRL_5 (0xF0128014u)
+#define GTM_DPLL_ACT_STA (0xF0128018u)
+#define GTM_DPLL_OSW (0xF012801Cu)
+#define GTM_DPLL_AOSV_2 (0xF0128020u)
+#define GTM_DPLL_APT (0xF0128024u)
+#define GTM_DPLL_APS (0xF0128028u)
+#define GTM_DPLL_APT_2C (0xF012802Cu)
+#define GTM_DPLL_APS_1C3 (0xF0128030u)
+#define GTM_DPLL_NUTC (0xF0128034u)
+#define GTM_DPLL_NUSC (0xF0128038u)
+#define GTM_DPLL_NTI_CNT (0xF012803Cu)
+#define GTM_DPLL_IRQ_NOTIFY (0xF0128040u)
+#define GTM_DPLL_IRQ_EN (0xF0128044u)
+#define GTM_DPLL_IRQ_FORCINT (0xF0128048u)
+#define GTM_DPLL_IRQ_MODE (0xF012804Cu)
+#define GTM_DPLL_EIRQ_EN (0xF0128050u)
+#define GTM_DPLL_INC_CNT1 (0xF01280B0u)
+#define GTM_DPLL_INC_CNT2 (0xF01280B4u)
+#define GTM_DPLL_APT_SYNC (0xF01280B8u)
+#define GTM_DPLL_APS_SYNC (0xF01280BCu)
+#define GTM_BRC_SRC1_ADDR (0xF0100408u)
+#define GTM_DPLL_TBU_TS0_T (0xF01280C0u)
+#define GTM_DPLL_TBU_TS0_S (0xF01280C4u)
+#define GTM_DPLL_ADD_IN_LD1 (0xF01280C8u)
+#define GTM_DPLL_ADD_IN_LD2 (0xF01280CCu)
+#define GTM_DPLL_STATUS (0xF01280FCu)
+#define GTM_DPLL_ID_PMTR0 (0xF0128100u)
+#define GTM_DPLL_ID_PMTR1 (0xF0128104u)
+#define GTM_DPLL_ID_PMTR2 (0xF0128108u)
+#define GTM_DPLL_ID_PMTR3 (0xF012810Cu)
+#define GTM_DPLL_ID_PMTR4 (0xF0128110u)
+#define GTM_DPLL_ID_PMTR5 (0xF0128114u)
+#define GTM_DPLL_ID_PMTR6 (0xF0128118u)
+#define GTM_DPLL_ID_PMTR7 (0xF012811Cu)
+#define GTM_BRC_SRC1_DEST (0xF010040Cu)
+#define GTM_DPLL_ID_PMTR8 (0xF0128120u)
+#define GTM_DPLL_ID_PMTR9 (0xF0128124u)
+#define GTM_DPLL_ID_PMTR10 (0xF0128128u)
+#define GTM_DPLL_ID_PMTR11 (0xF012812Cu)
+#define GTM_DPLL_ID_PMTR12 (0xF0128130u)
+#define GTM_DPLL_ID_PMTR13 (0xF0128134u)
+#define GTM_DPLL_ID_PMTR14 (0xF0128138u)
+#define GTM_DPLL_ID_PMTR15 (0xF012813Cu)
+#define GTM_DPLL_ID_PMTR16 (0xF0128140u)
+#define GTM_DPLL_ID_PMTR17 (0xF0128144u)
+#define GTM_BRC_SRC2_ADDR (0xF0100410u)
+#define GTM_DPLL_ID_PMTR18 (0xF0128148u)
+#define GTM_DPLL_ID_PMTR19 (0xF012814Cu)
+#define GTM_DPLL_ID_PMTR20 (0xF0128150u)
+#define GTM_DPLL_ID_PMTR21 (0xF0128154u)
+#define GTM_DPLL_ID_PMTR22 (0xF0128158u)
+#define GTM_DPLL_ID_PMTR23 (0xF012815Cu)
+#define GTM_DPLL_ID_PMTR24 (0xF0128160u)
+#define GTM_DPLL_ID_PMTR25 (0xF0128164u)
+#define GTM_DPLL_ID_PMTR26 (0xF0128168u)
+#define GTM_DPLL_ID_PMTR27 (0xF012816Cu)
+#define GTM_BRC_SRC2_DEST (0xF0100414u)
+#define GTM_DPLL_ID_PMTR28 (0xF0128170u)
+#define GTM_DPLL_ID_PMTR29 (0xF0128174u)
+#define GTM_DPLL_ID_PMTR30 (0xF0128178u)
+#define GTM_DPLL_ID_PMTR31 (0xF012817Cu)
+#define GTM_DPLL_CTRL_0_SHADOW_TRIGGER (0xF01281E0u)
+#define GTM_DPLL_CTRL_0_SHADOW_STATE (0xF01281E4u)
+#define GTM_DPLL_CTRL_1_SHADOW_TRIGGER (0xF01281E8u)
+#define GTM_DPLL_CTRL_1_SHADOW_STATE (0xF01281ECu)
+#define GTM_DPLL_RAM_INI (0xF01281FCu)
+#define GTM_DPLL_PSA0 (0xF0128200u)
+#define GTM_DPLL_PSA1 (0xF0128204u)
+#define GTM_DPLL_PSA2 (0xF0128208u)
+#define GTM_DPLL_PSA3 (0xF012820Cu)
+#define GTM_DPLL_PSA4 (0xF0128210u)
+#define GTM_DPLL_PSA5 (0xF0128214u)
+#define GTM_DPLL_PSA6 (0xF0128218u)
+#define GTM_DPLL_PSA7 (0xF012821Cu)
+#define GTM_DPLL_PSA8 (0xF0128220u)
+#define GTM_BRC_SRC3_ADDR (0xF0100418u)
+#define GTM_DPLL_PSA9 (0xF0128224u)
+#define GTM_DPLL_PSA10 (0xF0128228u)
+#define GTM_DPLL_PSA11 (0xF012822Cu)
+#define GTM_DPLL_PSA12 (0xF0128230u)
+#define GTM_DPLL_PSA13 (0xF0128234u)
+#define GTM_DPLL_PSA14 (0xF0128238u)
+#define GTM_DPLL_PSA15 (0xF012823Cu)
+#define GTM_DPLL_PSA16 (0xF0128240u)
+#define GTM_DPLL_PSA17 (0xF0128244u)
+#define GTM_DPLL_PSA18 (0xF0128248u)
+#define GTM_DPLL_PSA19 (0xF012824Cu)
+#define GTM_DPLL_PSA20 (0xF0128250u)
+#define GTM_DPLL_PSA21 (0xF0128254u)
+#define GTM_DPLL_PSA22 (0xF0128258u)
+#define GTM_DPLL_PSA23 (0xF012825Cu)
+#define GTM_DPLL_PSA24 (0xF0128260u)
+#define GTM_DPLL_PSA25 (0xF0128264u)
+#define GTM_DPLL_PSA26 (0xF0128268u)
+#define GTM_DPLL_PSA27 (0xF012826Cu)
+#define GTM_DPLL_PSA28 (0xF0128270u)
+#define GTM_DPLL_PSA29 (0xF0128274u)
+#define GTM_DPLL_PSA30 (0xF0128278u)
+#define GTM_DPLL_PSA31 (0xF012827Cu)
+#define GTM_BRC_SRC3_DEST (0xF010041Cu)
+#define GTM_DPLL_DLA0 (0xF0128280u)
+#define GTM_DPLL_DLA1 (0xF0128284u)
+#define GTM_DPLL_DLA2 (0xF0128288u)
+#define GTM_DPLL_DLA3 (0xF012828Cu)
+#define GTM_DPLL_DLA4 (0xF0128290u)
+#define GTM_DPLL_DLA5 (0xF0128294u)
+#define GTM_DPLL_DLA6 (0xF0128298u)
+#define GTM_DPLL_DLA7 (0xF012829Cu)
+#define GTM_DPLL_DLA8 (0xF01282A0u)
+#define GTM_DPLL_DLA9 (0xF01282A4u)
+#define GTM_DPLL_DLA10 (0xF01282A8u)
+#define GTM_DPLL_DLA11 (0xF01282ACu)
+#define GTM_DPLL_DLA12 (0xF01282B0u)
+#define GTM_DPLL_DLA13 (0xF01282B4u)
+#define GTM_DPLL_DLA14 (0xF01282B8u)
+#define GTM_DPLL_DLA15 (0xF01282BCu)
+#define GTM_DPLL_DLA16 (0xF01282C0u)
+#define GTM_DPLL_DLA17 (0xF01282C4u)
+#define GTM_DPLL_DLA18 (0xF01282C8u)
+#define GTM_DPLL_DLA19 (0xF01282CCu)
+#define GTM_DPLL_DLA20 (0xF01282D0u)
+#define GTM_DPLL_DLA21 (0xF01282D4u)
+#define GTM_DPLL_DLA22 (0xF01282D8u)
+#define GTM_DPLL_DLA23 (0xF01282DCu)
+#define GTM_BRC_SRC4_ADDR (0xF0100420u)
+#define GTM_DPLL_DLA24 (0xF01282E0u)
+#define GTM_DPLL_DLA25 (0xF01282E4u)
+#define GTM_DPLL_DLA26 (0xF01282E8u)
+#define GTM_DPLL_DLA27 (0xF01282ECu)
+#define GTM_DPLL_DLA28 (0xF01282F0u)
+#define GTM_DPLL_DLA29 (0xF01282F4u)
+#define GTM_DPLL_DLA30 (0xF01282F8u)
+#define GTM_DPLL_DLA31 (0xF01282FCu)
+#define GTM_DPLL_NA0 (0xF0128300u)
+#define GTM_DPLL_NA1 (0xF0128304u)
+#define GTM_DPLL_NA2 (0xF0128308u)
+#define GTM_DPLL_NA3 (0xF012830Cu)
+#define GTM_DPLL_NA4 (0xF0128310u)
+#define GTM_DPLL_NA5 (0xF0128314u)
+#define GTM_DPLL_NA6 (0xF0128318u)
+#define GTM_DPLL_NA7 (0xF012831Cu)
+#define GTM_DPLL_NA8 (0xF0128320u)
+#define GTM_DPLL_NA9 (0xF0128324u)
+#define GTM_DPLL_NA10 (0xF0128328u)
+#define GTM_DPLL_NA11 (0xF012832Cu)
+#define GTM_DPLL_NA12 (0xF0128330u)
+#define GTM_DPLL_NA13 (0xF0128334u)
+#define GTM_DPLL_NA14 (0xF0128338u)
+#define GTM_BRC_SRC4_DEST (0xF0100424u)
+#define GTM_DPLL_NA15 (0xF012833Cu)
+#define GTM_DPLL_NA16 (0xF0128340u)
+#define GTM_DPLL_NA17 (0xF0128344u)
+#define GTM_DPLL_NA18 (0xF0128348u)
+#define GTM_DPLL_NA19 (0xF012834Cu)
+#define GTM_DPLL_NA20 (0xF0128350u)
+#define GTM_DPLL_NA21 (0xF0128354u)
+#define GTM_DPLL_NA22 (0xF0128358u)
+#define GTM_DPLL_NA23 (0xF012835Cu)
+#define GTM_DPLL_NA24 (0xF0128360u)
+#define MODULE_GTM (0xF0100000u)
+#define GTM_DPLL_NA25 (0xF0128364u)
+#define GTM_DPLL_NA26 (0xF0128368u)
+#define GTM_DPLL_NA27 (0xF012836Cu)
+#define GTM_DPLL_NA28 (0xF0128370u)
+#define GTM_DPLL_NA29 (0xF0128374u)
+#define GTM_DPLL_NA30 (0xF0128378u)
+#define GTM_DPLL_NA31 (0xF012837Cu)
+#define GTM_DPLL_DTA0 (0xF0128380u)
+#define GTM_DPLL_DTA1 (0xF0128384u)
+#define GTM_DPLL_DTA2 (0xF0128388u)
+#define GTM_DPLL_DTA3 (0xF012838Cu)
+#define GTM_DPLL_DTA4 (0xF0128390u)
+#define GTM_DPLL_DTA5 (0xF0128394u)
+#define GTM_BRC_SRC5_ADDR (0xF0100428u)
+#define GTM_DPLL_DTA6 (0xF0128398u)
+#define GTM_DPLL_DTA7 (0xF012839Cu)
+#define GTM_DPLL_DTA8 (0xF01283A0u)
+#define GTM_DPLL_DTA9 (0xF01283A4u)
+#define GTM_DPLL_DTA10 (0xF01283A8u)
+#define GTM_DPLL_DTA11 (0xF01283ACu)
+#define GTM_DPLL_DTA12 (0xF01283B0u)
+#define GTM_DPLL_DTA13 (0xF01283B4u)
+#define GTM_DPLL_DTA14 (0xF01283B8u)
+#define GTM_DPLL_DTA15 (0xF01283BCu)
+#define GTM_DPLL_DTA16 (0xF01283C0u)
+#define GTM_DPLL_DTA17 (0xF01283C4u)
+#define GTM_DPLL_DTA18 (0xF01283C8u)
+#define GTM_DPLL_DTA19 (0xF01283CCu)
+#define GTM_DPLL_DTA20 (0xF01283D0u)
+#define GTM_DPLL_DTA21 (0xF01283D4u)
+#define GTM_DPLL_DTA22 (0xF01283D8u)
+#define GTM_DPLL_DTA23 (0xF01283DCu)
+#define GTM_DPLL_DTA24 (0xF01283E0u)
+#define GTM_DPLL_DTA25 (0xF01283E4u)
+#define GTM_DPLL_DTA26 (0xF01283E8u)
+#define GTM_DPLL_DTA27 (0xF01283ECu)
+#define GTM_DPLL_DTA28 (0xF01283F0u)
+#define GTM_DPLL_DTA29 (0xF01283F4u)
+#define GTM_BRC_SRC5_DEST (0xF010042Cu)
+#define GTM_DPLL_DTA30 (0xF01283F8u)
+#define GTM_DPLL_DTA31 (0xF01283FCu)
+#define GTM_DPLL_TS_T (0xF0128400u)
+#define GTM_DPLL_TS_T_OLD (0xF0128404u)
+#define GTM_DPLL_FTV_T (0xF0128408u)
+#define GTM_DPLL_TS_S (0xF0128410u)
+#define GTM_DPLL_TS_S_OLD (0xF0128414u)
+#define GTM_DPLL_FTV_S (0xF0128418u)
+#define GTM_DPLL_THMI (0xF0128420u)
+#define GTM_DPLL_THMA (0xF0128424u)
+#define GTM_DPLL_THVAL (0xF0128428u)
+#define GTM_DPLL_TOV (0xF0128430u)
+#define GTM_DPLL_TOV_S (0xF0128434u)
+#define GTM_DPLL_ADD_IN_CAL1 (0xF0128438u)
+#define GTM_DPLL_ADD_IN_CAL2 (0xF012843Cu)
+#define GTM_DPLL_MPVAL1 (0xF0128440u)
+#define GTM_DPLL_MPVAL2 (0xF0128444u)
+#define GTM_DPLL_NMB_T_TAR (0xF0128448u)
+#define GTM_DPLL_NMB_T_TAR_OLD (0xF012844Cu)
+#define GTM_DPLL_NMB_S_TAR (0xF0128450u)
+#define GTM_DPLL_NMB_S_TAR_OLD (0xF0128454u)
+#define GTM_DPLL_RCDT_TX (0xF0128460u)
+#define GTM_DPLL_RCDT_SX (0xF0128464u)
+#define GTM_BRC_SRC6_ADDR (0xF0100430u)
+#define GTM_DPLL_RCDT_TX_NOM (0xF0128468u)
+#define GTM_DPLL_RCDT_SX_NOM (0xF012846Cu)
+#define GTM_DPLL_RDT_T_ACT (0xF0128470u)
+#define GTM_DPLL_RDT_S_ACT (0xF0128474u)
+#define GTM_DPLL_DT_T_ACT (0xF0128478u)
+#define GTM_DPLL_DT_S_ACT (0xF012847Cu)
+#define GTM_DPLL_EDT_T (0xF0128480u)
+#define GTM_DPLL_MEDT_T (0xF0128484u)
+#define GTM_DPLL_EDT_S (0xF0128488u)
+#define GTM_DPLL_MEDT_S (0xF012848Cu)
+#define GTM_DPLL_CDT_TX (0xF0128490u)
+#define GTM_DPLL_CDT_SX (0xF0128494u)
+#define GTM_DPLL_CDT_TX_NOM (0xF0128498u)
+#define GTM_DPLL_CDT_SX_NOM (0xF012849Cu)
+#define GTM_DPLL_TLR (0xF01284A0u)
+#define GTM_DPLL_SLR (0xF01284A4u)
+#define GTM_DPLL_PDT0 (0xF0128500u)
+#define GTM_DPLL_PDT1 (0xF0128504u)
+#define GTM_DPLL_PDT2 (0xF0128508u)
+#define GTM_BRC_SRC6_DEST (0xF0100434u)
+#define GTM_DPLL_PDT3 (0xF012850Cu)
+#define GTM_DPLL_PDT4 (0xF0128510u)
+#define GTM_DPLL_PDT5 (0xF0128514u)
+#define GTM_DPLL_PDT6 (0xF0128518u)
+#define GTM_DPLL_PDT7 (0xF012851Cu)
+#define GTM_DPLL_PDT8 (0xF0128520u)
+#define GTM_DPLL_PDT9 (0xF0128524u)
+#define GTM_DPLL_PDT10 (0xF0128528u)
+#define GTM_DPLL_PDT11 (0xF012852Cu)
+#define GTM_DPLL_PDT12 (0xF0128530u)
+#define GTM_BRC_SRC7_ADDR (0xF0100438u)
+#define GTM_DPLL_PDT13 (0xF0128534u)
+#define GTM_DPLL_PDT14 (0xF0128538u)
+#define GTM_DPLL_PDT15 (0xF012853Cu)
+#define GTM_DPLL_PDT16 (0xF0128540u)
+#define GTM_DPLL_PDT17 (0xF0128544u)
+#define GTM_DPLL_PDT18 (0xF0128548u)
+#define GTM_DPLL_PDT19 (0xF012854Cu)
+#define GTM_DPLL_PDT20 (0xF0128550u)
+#define GTM_DPLL_PDT21 (0xF0128554u)
+#define GTM_DPLL_PDT22 (0xF0128558u)
+#define GTM_BRC_SRC7_DEST (0xF010043Cu)
+#define GTM_DPLL_PDT23 (0xF012855Cu)
+#define GTM_DPLL_PDT24 (0xF0128560u)
+#define GTM_DPLL_PDT25 (0xF0128564u)
+#define GTM_DPLL_PDT26 (0xF0128568u)
+#define GTM_DPLL_PDT27 (0xF012856Cu)
+#define GTM_DPLL_PDT28 (0xF0128570u)
+#define GTM_DPLL_PDT29 (0xF0128574u)
+#define GTM_DPLL_PDT30 (0xF0128578u)
+#define GTM_DPLL_PDT31 (0xF012857Cu)
+#define GTM_DPLL_MLS1 (0xF01285C0u)
+#define GTM_DPLL_MLS2 (0xF01285C4u)
+#define GTM_DPLL_CNT_NUM_1 (0xF01285C8u)
+#define GTM_BRC_SRC8_ADDR (0xF0100440u)
+#define GTM_DPLL_CNT_NUM_2 (0xF01285CCu)
+#define GTM_DPLL_PVT (0xF01285D0u)
+#define GTM_DPLL_PSTC (0xF01285E0u)
+#define GTM_DPLL_PSSC (0xF01285E4u)
+#define GTM_DPLL_PSTM (0xF01285E8u)
+#define GTM_DPLL_PSTM_OLD (0xF01285ECu)
+#define GTM_DPLL_PSSM (0xF01285F0u)
+#define GTM_DPLL_PSSM_OLD (0xF01285F4u)
+#define GTM_DPLL_NMB_T (0xF01285F8u)
+#define GTM_DPLL_NMB_S (0xF01285FCu)
+#define GTM_DPLL_RDT_S0 (0xF0128600u)
+#define GTM_DPLL_RDT_S1 (0xF0128604u)
+#define GTM_DPLL_RDT_S2 (0xF0128608u)
+#define GTM_DPLL_RDT_S3 (0xF012860Cu)
+#define GTM_DPLL_RDT_S4 (0xF0128610u)
+#define GTM_DPLL_RDT_S5 (0xF0128614u)
+#define GTM_DPLL_RDT_S6 (0xF0128618u)
+#define GTM_DPLL_RDT_S7 (0xF012861Cu)
+#define GTM_DPLL_RDT_S8 (0xF0128620u)
+#define GTM_DPLL_RDT_S9 (0xF0128624u)
+#define GTM_DPLL_RDT_S10 (0xF0128628u)
+#define GTM_DPLL_RDT_S11 (0xF012862Cu)
+#define GTM_DPLL_RDT_S12 (0xF0128630u)
+#define GTM_BRC_SRC8_DEST (0xF0100444u)
+#define GTM_DPLL_RDT_S13 (0xF0128634u)
+#define GTM_DPLL_RDT_S14 (0xF0128638u)
+#define GTM_DPLL_RDT_S15 (0xF012863Cu)
+#define GTM_DPLL_RDT_S16 (0xF0128640u)
+#define GTM_DPLL_RDT_S17 (0xF0128644u)
+#define GTM_DPLL_RDT_S18 (0xF0128648u)
+#define GTM_DPLL_RDT_S19 (0xF012864Cu)
+#define GTM_DPLL_RDT_S20 (0xF0128650u)
+#define GTM_DPLL_RDT_S21 (0xF0128654u)
+#define GTM_DPLL_RDT_S22 (0xF0128658u)
+#define GTM_DPLL_RDT_S23 (0xF012865Cu)
+#define GTM_DPLL_RDT_S24 (0xF0128660u)
+#define GTM_DPLL_RDT_S25 (0xF0128664u)
+#define GTM_DPLL_RDT_S26 (0xF0128668u)
+#define GTM_DPLL_RDT_S27 (0xF012866Cu)
+#define GTM_DPLL_RDT_S28 (0xF0128670u)
+#define GTM_DPLL_RDT_S29 (0xF0128674u)
+#define GTM_DPLL_RDT_S30 (0xF0128678u)
+#define GTM_DPLL_RDT_S31 (0xF012867Cu)
+#define GTM_DPLL_RDT_S32 (0xF0128680u)
+#define GTM_DPLL_RDT_S33 (0xF0128684u)
+#define GTM_DPLL_RDT_S34 (0xF0128688u)
+#define GTM_DPLL_RDT_S35 (0xF012868Cu)
+#define GTM_BRC_SRC9_ADDR (0xF0100448u)
+#define GTM_DPLL_RDT_S36 (0xF0128690u)
+#define GTM_DPLL_RDT_S37 (0xF0128694u)
+#define GTM_DPLL_RDT_S38 (0xF0128698u)
+#define GTM_DPLL_RDT_S39 (0xF012869Cu)
+#define GTM_DPLL_RDT_S40 (0xF01286A0u)
+#define GTM_DPLL_RDT_S41 (0xF01286A4u)
+#define GTM_DPLL_RDT_S42 (0xF01286A8u)
+#define GTM_DPLL_RDT_S43 (0xF01286ACu)
+#define GTM_DPLL_RDT_S44 (0xF01286B0u)
+#define GTM_DPLL_RDT_S45 (0xF01286B4u)
+#define GTM_DPLL_RDT_S46 (0xF01286B8u)
+#define GTM_DPLL_RDT_S47 (0xF01286BCu)
+#define GTM_DPLL_RDT_S48 (0xF01286C0u)
+#define GTM_DPLL_RDT_S49 (0xF01286C4u)
+#define GTM_DPLL_RDT_S50 (0xF01286C8u)
+#define GTM_DPLL_RDT_S51 (0xF01286CCu)
+#define GTM_DPLL_RDT_S52 (0xF01286D0u)
+#define GTM_DPLL_RDT_S53 (0xF01286D4u)
+#define GTM_DPLL_RDT_S54 (0xF01286D8u)
+#define GTM_DPLL_RDT_S55 (0xF01286DCu)
+#define GTM_DPLL_RDT_S56 (0xF01286E0u)
+#define GTM_DPLL_RDT_S57 (0xF01286E4u)
+#define GTM_DPLL_RDT_S58 (0xF01286E8u)
+#define GTM_DPLL_RDT_S59 (0xF01286ECu)
+#define GTM_BRC_SRC9_DEST (0xF010044Cu)
+#define GTM_DPLL_RDT_S60 (0xF01286F0u)
+#define GTM_DPLL_RDT_S61 (0xF01286F4u)
+#define GTM_DPLL_RDT_S62 (0xF01286F8u)
+#define GTM_DPLL_RDT_S63 (0xF01286FCu)
+#define GTM_DPLL_TSF_S0 (0xF0128700u)
+#define GTM_DPLL_TSF_S1 (0xF0128704u)
+#define GTM_DPLL_TSF_S2 (0xF0128708u)
+#define GTM_DPLL_TSF_S3 (0xF012870Cu)
+#define GTM_DPLL_TSF_S4 (0xF0128710u)
+#define GTM_DPLL_TSF_S5 (0xF0128714u)
+#define GTM_DPLL_TSF_S6 (0xF0128718u)
+#define GTM_DPLL_TSF_S7 (0xF012871Cu)
+#define GTM_DPLL_TSF_S8 (0xF0128720u)
+#define GTM_DPLL_TSF_S9 (0xF0128724u)
+#define GTM_DPLL_TSF_S10 (0xF0128728u)
+#define GTM_DPLL_TSF_S11 (0xF012872Cu)
+#define GTM_DPLL_TSF_S12 (0xF0128730u)
+#define GTM_DPLL_TSF_S13 (0xF0128734u)
+#define GTM_DPLL_TSF_S14 (0xF0128738u)
+#define GTM_DPLL_TSF_S15 (0xF012873Cu)
+#define GTM_DPLL_TSF_S16 (0xF0128740u)
+#define GTM_DPLL_TSF_S17 (0xF0128744u)
+#define GTM_DPLL_TSF_S18 (0xF0128748u)
+#define GTM_BRC_SRC10_ADDR (0xF0100450u)
+#define GTM_DPLL_TSF_S19 (0xF012874Cu)
+#define GTM_DPLL_TSF_S20 (0xF0128750u)
+#define GTM_DPLL_TSF_S21 (0xF0128754u)
+#define GTM_DPLL_TSF_S22 (0xF0128758u)
+#define GTM_DPLL_TSF_S23 (0xF012875Cu)
+#define GTM_DPLL_TSF_S24 (0xF0128760u)
+#define GTM_DPLL_TSF_S25 (0xF0128764u)
+#define GTM_DPLL_TSF_S26 (0xF0128768u)
+#define GTM_DPLL_TSF_S27 (0xF012876Cu)
+#define GTM_DPLL_TSF_S28 (0xF0128770u)
+#define GTM_DPLL_TSF_S29 (0xF0128774u)
+#define GTM_DPLL_TSF_S30 (0xF0128778u)
+#define GTM_DPLL_TSF_S31 (0xF012877Cu)
+#define GTM_DPLL_TSF_S32 (0xF0128780u)
+#define GTM_DPLL_TSF_S33 (0xF0128784u)
+#define GTM_DPLL_TSF_S34 (0xF0128788u)
+#define GTM_DPLL_TSF_S35 (0xF012878Cu)
+#define GTM_DPLL_TSF_S36 (0xF0128790u)
+#define GTM_DPLL_TSF_S37 (0xF0128794u)
+#define GTM_DPLL_TSF_S38 (0xF0128798u)
+#define GTM_DPLL_TSF_S39 (0xF012879Cu)
+#define GTM_DPLL_TSF_S40 (0xF01287A0u)
+#define GTM_DPLL_TSF_S41 (0xF01287A4u)
+#define GTM_BRC_SRC10_DEST (0xF0100454u)
+#define GTM_DPLL_TSF_S42 (0xF01287A8u)
+#define GTM_DPLL_TSF_S43 (0xF01287ACu)
+#define GTM_DPLL_TSF_S44 (0xF01287B0u)
+#define GTM_DPLL_TSF_S45 (0xF01287B4u)
+#define GTM_DPLL_TSF_S46 (0xF01287B8u)
+#define GTM_DPLL_TSF_S47 (0xF01287BCu)
+#define GTM_DPLL_TSF_S48 (0xF01287C0u)
+#define GTM_DPLL_TSF_S49 (0xF01287C4u)
+#define GTM_DPLL_TSF_S50 (0xF01287C8u)
+#define GTM_DPLL_TSF_S51 (0xF01287CCu)
+#define GTM_DPLL_TSF_S52 (0xF01287D0u)
+#define GTM_DPLL_TSF_S53 (0xF01287D4u)
+#define GTM_DPLL_TSF_S54 (0xF01287D8u)
+#define GTM_DPLL_TSF_S55 (0xF01287DCu)
+#define GTM_DPLL_TSF_S56 (0xF01287E0u)
+#define GTM_DPLL_TSF_S57 (0xF01287E4u)
+#define GTM_DPLL_TSF_S58 (0xF01287E8u)
+#define GTM_DPLL_TSF_S59 (0xF01287ECu)
+#define GTM_DPLL_TSF_S60 (0xF01287F0u)
+#define GTM_DPLL_TSF_S61 (0xF01287F4u)
+#define GTM_DPLL_TSF_S62 (0xF01287F8u)
+#define GTM_DPLL_TSF_S63 (0xF01287FCu)
+#define GTM_DPLL_ADT_S0 (0xF0128800u)
+#define GTM_DPLL_ADT_S1 (0xF0128804u)
+#define GTM_BRC_SRC11_ADDR (0xF0100458u)
+#define GTM_DPLL_ADT_S2 (0xF0128808u)
+#define GTM_DPLL_ADT_S3 (0xF012880Cu)
+#define GTM_DPLL_ADT_S4 (0xF0128810u)
+#define GTM_DPLL_ADT_S5 (0xF0128814u)
+#define GTM_DPLL_ADT_S6 (0xF0128818u)
+#define GTM_DPLL_ADT_S7 (0xF012881Cu)
+#define GTM_PSM_FIFO0_MEMORY (0xF0119000u)
+#define GTM_DPLL_ADT_S8 (0xF0128820u)
+#define GTM_DPLL_ADT_S9 (0xF0128824u)
+#define GTM_DPLL_ADT_S10 (0xF0128828u)
+#define GTM_DPLL_ADT_S11 (0xF012882Cu)
+#define GTM_DPLL_ADT_S12 (0xF0128830u)
+#define GTM_DPLL_ADT_S13 (0xF0128834u)
+#define GTM_DPLL_ADT_S14 (0xF0128838u)
+#define GTM_DPLL_ADT_S15 (0xF012883Cu)
+#define GTM_DPLL_ADT_S16 (0xF0128840u)
+#define GTM_DPLL_ADT_S17 (0xF0128844u)
+#define GTM_DPLL_ADT_S18 (0xF0128848u)
+#define GTM_DPLL_ADT_S19 (0xF012884Cu)
+#define GTM_DPLL_ADT_S20 (0xF0128850u)
+#define GTM_DPLL_ADT_S21 (0xF0128854u)
+#define GTM_DPLL_ADT_S22 (0xF0128858u)
+#define GTM_DPLL_ADT_S23 (0xF012885Cu)
+#define GTM_DPLL_ADT_S24 (0xF0128860u)
+#define GTM_BRC_SRC11_DEST (0xF010045Cu)
+#define GTM_DPLL_ADT_S25 (0xF0128864u)
+#define GTM_DPLL_ADT_S26 (0xF0128868u)
+#define GTM_DPLL_ADT_S27 (0xF012886Cu)
+#define GTM_DPLL_ADT_S28 (0xF0128870u)
+#define GTM_DPLL_ADT_S29 (0xF0128874u)
+#define GTM_DPLL_ADT_S30 (0xF0128878u)
+#define GTM_DPLL_ADT_S31 (0xF012887Cu)
+#define GTM_DPLL_ADT_S32 (0xF0128880u)
+#define GTM_DPLL_ADT_S33 (0xF0128884u)
+#define GTM_DPLL_ADT_S34 (0xF0128888u)
+#define GTM_DPLL_ADT_S35 (0xF012888Cu)
+#define GTM_DPLL_ADT_S36 (0xF0128890u)
+#define GTM_DPLL_ADT_S37 (0xF0128894u)
+#define GTM_DPLL_ADT_S38 (0xF0128898u)
+#define GTM_DPLL_ADT_S39 (0xF012889Cu)
+#define GTM_DPLL_ADT_S40 (0xF01288A0u)
+#define GTM_DPLL_ADT_S41 (0xF01288A4u)
+#define GTM_DPLL_ADT_S42 (0xF01288A8u)
+#define GTM_DPLL_ADT_S43 (0xF01288ACu)
+#define GTM_DPLL_ADT_S44 (0xF01288B0u)
+#define GTM_DPLL_ADT_S45 (0xF01288B4u)
+#define GTM_DPLL_ADT_S46 (0xF01288B8u)
+#define GTM_DPLL_ADT_S47 (0xF01288BCu)
+#define GTM_BRC_IRQ_NOTIFY (0xF0100460u)
+#define GTM_DPLL_ADT_S48 (0xF01288C0u)
+#define GTM_DPLL_ADT_S49 (0xF01288C4u)
+#define GTM_DPLL_ADT_S50 (0xF01288C8u)
+#define GTM_DPLL_ADT_S51 (0xF01288CCu)
+#define GTM_DPLL_ADT_S52 (0xF01288D0u)
+#define GTM_DPLL_ADT_S53 (0xF01288D4u)
+#define GTM_DPLL_ADT_S54 (0xF01288D8u)
+#define GTM_DPLL_ADT_S55 (0xF01288DCu)
+#define GTM_DPLL_ADT_S56 (0xF01288E0u)
+#define GTM_DPLL_ADT_S57 (0xF01288E4u)
+#define GTM_BRC_IRQ_EN (0xF0100464u)
+#define GTM_DPLL_ADT_S58 (0xF01288E8u)
+#define GTM_DPLL_ADT_S59 (0xF01288ECu)
+#define GTM_DPLL_ADT_S60 (0xF01288F0u)
+#define GTM_DPLL_ADT_S61 (0xF01288F4u)
+#define GTM_DPLL_ADT_S62 (0xF01288F8u)
+#define GTM_DPLL_ADT_S63 (0xF01288FCu)
+#define GTM_DPLL_DT_S0 (0xF0128900u)
+#define GTM_DPLL_DT_S1 (0xF0128904u)
+#define GTM_DPLL_DT_S2 (0xF0128908u)
+#define GTM_DPLL_DT_S3 (0xF012890Cu)
+#define GTM_BRC_IRQ_FORCINT (0xF0100468u)
+#define GTM_DPLL_DT_S4 (0xF0128910u)
+#define GTM_DPLL_DT_S5 (0xF0128914u)
+#define GTM_DPLL_DT_S6 (0xF0128918u)
+#define GTM_DPLL_DT_S7 (0xF012891Cu)
+#define GTM_DPLL_DT_S8 (0xF0128920u)
+#define GTM_DPLL_DT_S9 (0xF0128924u)
+#define GTM_DPLL_DT_S10 (0xF0128928u)
+#define GTM_DPLL_DT_S11 (0xF012892Cu)
+#define GTM_DPLL_DT_S12 (0xF0128930u)
+#define GTM_DPLL_DT_S13 (0xF0128934u)
+#define GTM_BRC_IRQ_MODE (0xF010046Cu)
+#define GTM_DPLL_DT_S14 (0xF0128938u)
+#define GTM_DPLL_DT_S15 (0xF012893Cu)
+#define GTM_DPLL_DT_S16 (0xF0128940u)
+#define GTM_DPLL_DT_S17 (0xF0128944u)
+#define GTM_DPLL_DT_S18 (0xF0128948u)
+#define GTM_DPLL_DT_S19 (0xF012894Cu)
+#define GTM_DPLL_DT_S20 (0xF0128950u)
+#define GTM_DPLL_DT_S21 (0xF0128954u)
+#define GTM_DPLL_DT_S22 (0xF0128958u)
+#define GTM_DPLL_DT_S23 (0xF012895Cu)
+#define GTM_BRC_RST (0xF0100470u)
+#define GTM_DPLL_DT_S24 (0xF0128960u)
+#define GTM_DPLL_DT_S25 (0xF0128964u)
+#define GTM_DPLL_DT_S26 (0xF0128968u)
+#define GTM_DPLL_DT_S27 (0xF012896Cu)
+#define GTM_DPLL_DT_S28 (0xF0128970u)
+#define GTM_DPLL_DT_S29 (0xF0128974u)
+#define GTM_DPLL_DT_S30 (0xF0128978u)
+#define GTM_DPLL_DT_S31 (0xF012897Cu)
+#define GTM_DPLL_DT_S32 (0xF0128980u)
+#define GTM_DPLL_DT_S33 (0xF0128984u)
+#define GTM_BRC_EIRQ_EN (0xF0100474u)
+#define GTM_DPLL_DT_S34 (0xF0128988u)
+#define GTM_DPLL_DT_S35 (0xF012898Cu)
+#define GTM_DPLL_DT_S36 (0xF0128990u)
+#define GTM_DPLL_DT_S37 (0xF0128994u)
+#define GTM_DPLL_DT_S38 (0xF0128998u)
+#define GTM_DPLL_DT_S39 (0xF012899Cu)
+#define GTM_DPLL_DT_S40 (0xF01289A0u)
+#define GTM_DPLL_DT_S41 (0xF01289A4u)
+#define GTM_DPLL_DT_S42 (0xF01289A8u)
+#define GTM_DPLL_DT_S43 (0xF01289ACu)
+#define GTM_ICM_IRQG_R0 (0xF0100600u)
+#define GTM_DPLL_RR2 (0xF012C000u)
+#define GTM_DPLL_DT_S44 (0xF01289B0u)
+#define GTM_DPLL_DT_S45 (0xF01289B4u)
+#define GTM_DPLL_DT_S46 (0xF01289B8u)
+#define GTM_DPLL_DT_S47 (0xF01289BCu)
+#define GTM_DPLL_DT_S48 (0xF01289C0u)
+#define GTM_DPLL_DT_S49 (0xF01289C4u)
+#define GTM_DPLL_DT_S50 (0xF01289C8u)
+#define GTM_DPLL_DT_S51 (0xF01289CCu)
+#define GTM_DPLL_DT_S52 (0xF01289D0u)
+#define GTM_DPLL_DT_S53 (0xF01289D4u)
+#define GTM_DPLL_DT_S54 (0xF01289D8u)
+#define GTM_DPLL_DT_S55 (0xF01289DCu)
+#define GTM_DPLL_DT_S56 (0xF01289E0u)
+#define GTM_DPLL_DT_S57 (0xF01289E4u)
+#define GTM_DPLL_DT_S58 (0xF01289E8u)
+#define GTM_DPLL_DT_S59 (0xF01289ECu)
+#define GTM_DPLL_DT_S60 (0xF01289F0u)
+#define GTM_DPLL_DT_S61 (0xF01289F4u)
+#define GTM_DPLL_DT_S62 (0xF01289F8u)
+#define GTM_DPLL_DT_S63 (0xF01289FCu)
+#define GTM_DPLL_TSAC0 (0xF0128E00u)
+#define GTM_DPLL_TSAC1 (0xF0128E04u)
+#define GTM_DPLL_TSAC2 (0xF0128E08u)
+#define GTM_DPLL_TSAC3 (0xF0128E0Cu)
+#define GTM_ICM_IRQG_R1 (0xF0100604u)
+#define GTM_DPLL_TSAC4 (0xF0128E10u)
+#define GTM_DPLL_TSAC5 (0xF0128E14u)
+#define GTM_DPLL_TSAC6 (0xF0128E18u)
+#define GTM_DPLL_TSAC7 (0xF0128E1Cu)
+#define GTM_DPLL_TSAC8 (0xF0128E20u)
+#define GTM_DPLL_TSAC9 (0xF0128E24u)
+#define GTM_DPLL_TSAC10 (0xF0128E28u)
+#define GTM_DPLL_TSAC11 (0xF0128E2Cu)
+#define GTM_DPLL_TSAC12 (0xF0128E30u)
+#define GTM_DPLL_TSAC13 (0xF0128E34u)
+#define GTM_DPLL_TSAC14 (0xF0128E38u)
+#define GTM_DPLL_TSAC15 (0xF0128E3Cu)
+#define GTM_DPLL_TSAC16 (0xF0128E40u)
+#define GTM_DPLL_TSAC17 (0xF0128E44u)
+#define GTM_DPLL_TSAC18 (0xF0128E48u)
+#define GTM_DPLL_TSAC19 (0xF0128E4Cu)
+#define GTM_DPLL_TSAC20 (0xF0128E50u)
+#define GTM_DPLL_TSAC21 (0xF0128E54u)
+#define GTM_DPLL_TSAC22 (0xF0128E58u)
+#define GTM_DPLL_TSAC23 (0xF0128E5Cu)
+#define GTM_DPLL_TSAC24 (0xF0128E60u)
+#define GTM_DPLL_TSAC25 (0xF0128E64u)
+#define GTM_DPLL_TSAC26 (0xF0128E68u)
+#define GTM_ICM_IRQG_R2 (0xF0100608u)
+#define GTM_DPLL_TSAC27 (0xF0128E6Cu)
+#define GTM_DPLL_TSAC28 (0xF0128E70u)
+#define GTM_DPLL_TSAC29 (0xF0128E74u)
+#define GTM_DPLL_TSAC30 (0xF0128E78u)
+#define GTM_DPLL_TSAC31 (0xF0128E7Cu)
+#define GTM_DPLL_PSAC0 (0xF0128E80u)
+#define GTM_DPLL_PSAC1 (0xF0128E84u)
+#define GTM_DPLL_PSAC2 (0xF0128E88u)
+#define GTM_DPLL_PSAC3 (0xF0128E8Cu)
+#define GTM_DPLL_PSAC4 (0xF0128E90u)
+#define GTM_DPLL_PSAC5 (0xF0128E94u)
+#define GTM_DPLL_PSAC6 (0xF0128E98u)
+#define GTM_DPLL_PSAC7 (0xF0128E9Cu)
+#define GTM_DPLL_PSAC8 (0xF0128EA0u)
+#define GTM_DPLL_PSAC9 (0xF0128EA4u)
+#define GTM_DPLL_PSAC10 (0xF0128EA8u)
+#define GTM_DPLL_PSAC11 (0xF0128EACu)
+#define GTM_DPLL_PSAC12 (0xF0128EB0u)
+#define GTM_DPLL_PSAC13 (0xF0128EB4u)
+#define GTM_DPLL_PSAC14 (0xF0128EB8u)
+#define GTM_DPLL_PSAC15 (0xF0128EBCu)
+#define GTM_DPLL_PSAC16 (0xF0128EC0u)
+#define GTM_DPLL_PSAC17 (0xF0128EC4u)
+#define GTM_ICM_IRQG_R3 (0xF010060Cu)
+#define GTM_DPLL_PSAC18 (0xF0128EC8u)
+#define GTM_DPLL_PSAC19 (0xF0128ECCu)
+#define GTM_DPLL_PSAC20 (0xF0128ED0u)
+#define GTM_DPLL_PSAC21 (0xF0128ED4u)
+#define GTM_DPLL_PSAC22 (0xF0128ED8u)
+#define GTM_DPLL_PSAC23 (0xF0128EDCu)
+#define GTM_DPLL_PSAC24 (0xF0128EE0u)
+#define GTM_DPLL_PSAC25 (0xF0128EE4u)
+#define GTM_DPLL_PSAC26 (0xF0128EE8u)
+#define GTM_DPLL_PSAC27 (0xF0128EECu)
+#define GTM_DPLL_PSAC28 (0xF0128EF0u)
+#define GTM_DPLL_PSAC29 (0xF0128EF4u)
+#define GTM_DPLL_PSAC30 (0xF0128EF8u)
+#define GTM_DPLL_PSAC31 (0xF0128EFCu)
+#define GTM_DPLL_ACB0 (0xF0128F00u)
+#define GTM_DPLL_ACB1 (0xF0128F04u)
+#define GTM_DPLL_ACB2 (0xF0128F08u)
+#define GTM_DPLL_ACB3 (0xF0128F0Cu)
+#define GTM_ICM_IRQG_R4 (0xF0100610u)
+#define GTM_DPLL_ACB4 (0xF0128F10u)
+#define GTM_DPLL_ACB5 (0xF0128F14u)
+#define GTM_DPLL_ACB6 (0xF0128F18u)
+#define GTM_MCS0_MEM (0xF0138000u)
+#define GTM_DPLL_ACB7 (0xF0128F1Cu)
+#define GTM_DPLL_CTRL_11 (0xF0128F20u)
+#define GTM_DPLL_THVAL2 (0xF0128F24u)
+#define GTM_DPLL_TIDEL (0xF0128F28u)
+#define GTM_DPLL_SIDEL (0xF0128F2Cu)
+#define GTM_DPLL_APS_SYNC_EXT (0xF0128F30u)
+#define GTM_DPLL_CTRL_EXT (0xF0128F34u)
+#define GTM_DPLL_APS_EXT (0xF0128F38u)
+#define GTM_DPLL_APS_1C3_EXT (0xF0128F3Cu)
+#define GTM_DPLL_STA (0xF0128F40u)
+#define GTM_DPLL_INCF1_OFFSET (0xF0128F44u)
+#define GTM_DPLL_INCF2_OFFSET (0xF0128F48u)
+#define GTM_DPLL_DT_T_START (0xF0128F4Cu)
+#define GTM_DPLL_DT_S_START (0xF0128F50u)
+#define GTM_DPLL_STA_MASK (0xF0128F54u)
+#define GTM_ICM_IRQG_R5 (0xF0100614u)
+#define GTM_DPLL_STA_FLAG (0xF0128F58u)
+#define GTM_DPLL_INC_CNT1_MASK (0xF0128F5Cu)
+#define GTM_DPLL_INC_CNT2_MASK (0xF0128F60u)
+#define GTM_DPLL_NUSC_EXT1 (0xF0128F64u)
+#define GTM_DPLL_NUSC_EXT2 (0xF0128F68u)
+#define GTM_DPLL_CTN_MIN (0xF0128F6Cu)
+#define GTM_DPLL_CTN_MAX (0xF0128F70u)
+#define GTM_DPLL_CSN_MIN (0xF0128F74u)
+#define GTM_DPLL_CSN_MAX (0xF0128F78u)
+#define GTM_CLC (0xF019FD00u)
+#define GTM_RESET_CLR (0xF019FD04u)
+#define GTM_RESET1 (0xF019FD08u)
+#define GTM_RESET2 (0xF019FD0Cu)
+#define GTM_ACCEN0 (0xF019FD10u)
+#define GTM_ACCEN1 (0xF019FD14u)
+#define GTM_OCDS_OTBU0T (0xF019FD18u)
+#define GTM_OCDS_OTBU1T (0xF019FD1Cu)
+#define GTM_OCDS_OTBU2T (0xF019FD20u)
+#define GTM_OCDS_OTBU3T (0xF019FD24u)
+#define GTM_ICM_IRQG_R6 (0xF0100618u)
+#define GTM_OCDS_OTSS (0xF019FD28u)
+#define GTM_OCDS_OTSC0 (0xF019FD2Cu)
+#define GTM_OCDS_OTSC1 (0xF019FD30u)
+#define GTM_OCDS_ODA (0xF019FD34u)
+#define GTM_OCDS_OCS (0xF019FD38u)
+#define GTM_TIMINSEL0 (0xF019FD40u)
+#define GTM_TIMINSEL1 (0xF019FD44u)
+#define GTM_TIMINSEL2 (0xF019FD48u)
+#define GTM_TIMINSEL3 (0xF019FD4Cu)
+#define GTM_TIMINSEL4 (0xF019FD50u)
+#define GTM_ICM_IRQG_R7 (0xF010061Cu)
+#define GTM_TIMINSEL5 (0xF019FD54u)
+#define GTM_TOUTSEL0 (0xF019FD60u)
+#define GTM_TOUTSEL1 (0xF019FD64u)
+#define GTM_TOUTSEL2 (0xF019FD68u)
+#define GTM_TOUTSEL3 (0xF019FD6Cu)
+#define GTM_TOUTSEL4 (0xF019FD70u)
+#define GTM_TOUTSEL5 (0xF019FD74u)
+#define GTM_TOUTSEL6 (0xF019FD78u)
+#define GTM_TOUTSEL7 (0xF019FD7Cu)
+#define GTM_TOUTSEL8 (0xF019FD80u)
+#define GTM_TOUTSEL9 (0xF019FD84u)
+#define GTM_TOUTSEL10 (0xF019FD88u)
+#define GTM_TOUTSEL11 (0xF019FD8Cu)
+#define GTM_TOUTSEL12 (0xF019FD90u)
+#define GTM_TOUTSEL13 (0xF019FD94u)
+#define GTM_TOUTSEL14 (0xF019FD98u)
+#define GTM_TOUTSEL15 (0xF019FD9Cu)
+#define GTM_TOUTSEL16 (0xF019FDA0u)
+#define GTM_TOUTSEL17 (0xF019FDA4u)
+#define GTM_TOUTSEL18 (0xF019FDA8u)
+#define GTM_DSADCINSEL0 (0xF019FE00u)
+#define GTM_ICM_IRQG_R9 (0xF0100624u)
+#define GTM_DSADCINSEL1 (0xF019FE04u)
+#define GTM_DSADCINSEL2 (0xF019FE08u)
+#define GTM_DSADCINSEL3 (0xF019FE0Cu)
+#define GTM_DSADCINSEL4 (0xF019FE10u)
+#define GTM_DSADCINSEL5 (0xF019FE14u)
+#define GTM_DSADC0_OUTSEL0 (0xF019FE20u)
+#define GTM_DSADC1_OUTSEL0 (0xF019FE28u)
+#define GTM_DSADC2_OUTSEL0 (0xF019FE30u)
+#define GTM_DSADC3_OUTSEL0 (0xF019FE38u)
+#define GTM_MCS0_MEM1 (0xF013A000u)
+#define GTM_ADCTRIG0_OUT0 (0xF019FE40u)
+#define GTM_ADCTRIG0_OUT1 (0xF019FE44u)
+#define GTM_ADCTRIG1_OUT0 (0xF019FE48u)
+#define GTM_ADCTRIG1_OUT1 (0xF019FE4Cu)
+#define GTM_ICM_IRQG_R10 (0xF0100628u)
+#define GTM_ADCTRIG2_OUT0 (0xF019FE50u)
+#define GTM_ADCTRIG2_OUT1 (0xF019FE54u)
+#define GTM_ADCTRIG3_OUT0 (0xF019FE58u)
+#define GTM_ADCTRIG3_OUT1 (0xF019FE5Cu)
+#define GTM_ADCTRIG4_OUT0 (0xF019FE60u)
+#define GTM_ADCTRIG4_OUT1 (0xF019FE64u)
+#define GTM_DXOUTCON (0xF019FE70u)
+#define GTM_TRIGOUT0 (0xF019FE74u)
+#define GTM_TRIGOUT1 (0xF019FE78u)
+#define GTM_TRIGOUT2 (0xF019FE7Cu)
+#define GTM_TRIGOUT3 (0xF019FE80u)
+#define GTM_TRIGOUT4 (0xF019FE84u)
+#define GTM_INTOUT0 (0xF019FE9Cu)
+#define GTM_INTOUT1 (0xF019FEA0u)
+#define GTM_INTOUT2 (0xF019FEA4u)
+#define GTM_ICM_IRQG_MEI (0xF0100630u)
+#define GTM_INTOUT3 (0xF019FEA8u)
+#define GTM_INTOUT4 (0xF019FEACu)
+#define GTM_MCSTRIGOUTSEL (0xF019FEC4u)
+#define GTM_MCSINTSTAT (0xF019FEC8u)
+#define GTM_MCSINTCLR (0xF019FECCu)
+#define GTM_DXINCON (0xF019FED0u)
+#define GTM_DATAIN0 (0xF019FED4u)
+#define GTM_DATAIN1 (0xF019FED8u)
+#define GTM_DATAIN2 (0xF019FEDCu)
+#define GTM_DATAIN3 (0xF019FEE0u)
+#define GTM_ICM_IRQG_CEI0 (0xF0100634u)
+#define GTM_DATAIN4 (0xF019FEE4u)
+#define GTM_MSC_SET0_CON0 (0xF019FF00u)
+#define GTM_MSC_SET0_CON1 (0xF019FF04u)
+#define GTM_MSC_SET0_CON2 (0xF019FF08u)
+#define GTM_MSC_SET0_CON3 (0xF019FF0Cu)
+#define GTM_ICM_IRQG_CEI1 (0xF0100638u)
+#define GTM_MSC_SET1_CON0 (0xF019FF10u)
+#define GTM_MSC_SET1_CON1 (0xF019FF14u)
+#define GTM_MSC_SET1_CON2 (0xF019FF18u)
+#define GTM_MSC_SET1_CON3 (0xF019FF1Cu)
+#define GTM_MSC_SET2_CON0 (0xF019FF20u)
+#define GTM_ICM_IRQG_CEI2 (0xF010063Cu)
+#define GTM_MSC_SET2_CON1 (0xF019FF24u)
+#define GTM_MSC_SET2_CON2 (0xF019FF28u)
+#define GTM_MSC_SET2_CON3 (0xF019FF2Cu)
+#define GTM_MSC_SET3_CON0 (0xF019FF30u)
+#define GTM_ICM_IRQG_CEI3 (0xF0100640u)
+#define GTM_MSC_SET3_CON1 (0xF019FF34u)
+#define GTM_MSC_SET3_CON2 (0xF019FF38u)
+#define GTM_MSC_SET3_CON3 (0xF019FF3Cu)
+#define GTM_MSC_MSCQ0_INLCON (0xF019FF90u)
+#define GTM_ICM_IRQG_CEI4 (0xF0100644u)
+#define GTM_MSC_MSCQ0_INHCON (0xF019FF94u)
+#define GTM_MSC_MSCQ0_INLEXTCON (0xF019FF98u)
+#define GTM_MSC_MSCQ1_INLCON (0xF019FF9Cu)
+#define GTM_MSC_MSCQ1_INHCON (0xF019FFA0u)
+#define GTM_ICM_IRQG_CEI_MCS0 (0xF0100664u)
+#define GTM_MSC_MSCQ1_INLEXTCON (0xF019FFA4u)
+#define GTM_PSI5OUTSEL (0xF019FFCCu)
+#define GTM_PSI5SOUTSEL (0xF019FFD0u)
+#define GTM_LCDCDCOUTSEL (0xF019FFD4u)
+#define GTM_DTMAUXINSEL (0xF019FFD8u)
+#define GTM_CANOUTSEL0 (0xF019FFDCu)
+#define GTM_MCS1_MEM (0xF0140000u)
+#define GTM_CANOUTSEL1 (0xF019FFE0u)
+#define GTM_CCM0_ARP0_CTRL (0xF01E2000u)
+#define GTM_CCM0_ARP0_PROT (0xF01E2004u)
+#define GTM_CCM0_ARP1_CTRL (0xF01E2008u)
+#define GTM_CCM0_ARP1_PROT (0xF01E200Cu)
+#define GTM_CCM0_ARP2_CTRL (0xF01E2010u)
+#define GTM_CCM0_ARP2_PROT (0xF01E2014u)
+#define GTM_CCM0_ARP3_CTRL (0xF01E2018u)
+#define GTM_CCM0_ARP3_PROT (0xF01E201Cu)
+#define GTM_CCM0_ARP4_CTRL (0xF01E2020u)
+#define GTM_CCM0_ARP4_PROT (0xF01E2024u)
+#define GTM_CCM0_ARP5_CTRL (0xF01E2028u)
+#define GTM_CCM0_ARP5_PROT (0xF01E202Cu)
+#define GTM_CCM0_ARP6_CTRL (0xF01E2030u)
+#define GTM_CCM0_ARP6_PROT (0xF01E2034u)
+#define GTM_CCM0_ARP7_CTRL (0xF01E2038u)
+#define GTM_ICM_IRQG_CEI_MCS1 (0xF0100668u)
+#define GTM_CCM0_ARP7_PROT (0xF01E203Cu)
+#define GTM_CCM0_ARP8_CTRL (0xF01E2040u)
+#define GTM_CCM0_ARP8_PROT (0xF01E2044u)
+#define GTM_CCM0_ARP9_CTRL (0xF01E2048u)
+#define GTM_CCM0_ARP9_PROT (0xF01E204Cu)
+#define GTM_CCM0_AEIM_STA (0xF01E21D8u)
+#define GTM_CCM0_HW_CONF (0xF01E21DCu)
+#define GTM_CCM0_TIM_AUX_IN_SRC (0xF01E21E0u)
+#define GTM_CCM0_EXT_CAP_EN (0xF01E21E4u)
+#define GTM_CCM0_TOM_OUT (0xF01E21E8u)
+#define GTM_CCM0_ATOM_OUT (0xF01E21ECu)
+#define GTM_CCM0_CMU_CLK_CFG (0xF01E21F0u)
+#define GTM_CCM0_CMU_FXCLK_CFG (0xF01E21F4u)
+#define GTM_CCM0_CFG (0xF01E21F8u)
+#define GTM_CCM0_PROT (0xF01E21FCu)
+#define GTM_CCM1_ARP0_CTRL (0xF01E2200u)
+#define GTM_CCM1_ARP0_PROT (0xF01E2204u)
+#define GTM_CCM1_ARP1_CTRL (0xF01E2208u)
+#define GTM_CCM1_ARP1_PROT (0xF01E220Cu)
+#define GTM_CCM1_ARP2_CTRL (0xF01E2210u)
+#define GTM_CCM1_ARP2_PROT (0xF01E2214u)
+#define GTM_CCM1_ARP3_CTRL (0xF01E2218u)
+#define GTM_CCM1_ARP3_PROT (0xF01E221Cu)
+#define GTM_CCM1_ARP4_CTRL (0xF01E2220u)
+#define GTM_ICM_IRQG_CEI_MCS2 (0xF010066Cu)
+#define GTM_CCM1_ARP4_PROT (0xF01E2224u)
+#define GTM_CCM1_ARP5_CTRL (0xF01E2228u)
+#define GTM_CCM1_ARP5_PROT (0xF01E222Cu)
+#define GTM_CCM1_ARP6_CTRL (0xF01E2230u)
+#define GTM_CCM1_ARP6_PROT (0xF01E2234u)
+#define GTM_CCM1_ARP7_CTRL (0xF01E2238u)
+#define GTM_CCM1_ARP7_PROT (0xF01E223Cu)
+#define GTM_CCM1_ARP8_CTRL (0xF01E2240u)
+#define GTM_CCM1_ARP8_PROT (0xF01E2244u)
+#define GTM_CCM1_ARP9_CTRL (0xF01E2248u)
+#define GTM_CCM1_ARP9_PROT (0xF01E224Cu)
+#define GTM_CCM1_AEIM_STA (0xF01E23D8u)
+#define GTM_CCM1_HW_CONF (0xF01E23DCu)
+#define GTM_CCM1_TIM_AUX_IN_SRC (0xF01E23E0u)
+#define GTM_CCM1_EXT_CAP_EN (0xF01E23E4u)
+#define GTM_CCM1_TOM_OUT (0xF01E23E8u)
+#define GTM_CCM1_ATOM_OUT (0xF01E23ECu)
+#define GTM_CCM1_CMU_CLK_CFG (0xF01E23F0u)
+#define GTM_CCM1_CMU_FXCLK_CFG (0xF01E23F4u)
+#define GTM_CCM1_CFG (0xF01E23F8u)
+#define GTM_CCM1_PROT (0xF01E23FCu)
+#define GTM_CCM2_ARP0_CTRL (0xF01E2400u)
+#define GTM_CCM2_ARP0_PROT (0xF01E2404u)
+#define GTM_ICM_IRQG_CEI_MCS3 (0xF0100670u)
+#define GTM_CCM2_ARP1_CTRL (0xF01E2408u)
+#define GTM_CCM2_ARP1_PROT (0xF01E240Cu)
+#define GTM_CCM2_ARP2_CTRL (0xF01E2410u)
+#define GTM_CCM2_ARP2_PROT (0xF01E2414u)
+#define GTM_CCM2_ARP3_CTRL (0xF01E2418u)
+#define GTM_CCM2_ARP3_PROT (0xF01E241Cu)
+#define GTM_CCM2_ARP4_CTRL (0xF01E2420u)
+#define GTM_CCM2_ARP4_PROT (0xF01E2424u)
+#define GTM_CCM2_ARP5_CTRL (0xF01E2428u)
+#define GTM_CCM2_ARP5_PROT (0xF01E242Cu)
+#define GTM_CCM2_ARP6_CTRL (0xF01E2430u)
+#define GTM_CCM2_ARP6_PROT (0xF01E2434u)
+#define GTM_CCM2_ARP7_CTRL (0xF01E2438u)
+#define GTM_CCM2_ARP7_PROT (0xF01E243Cu)
+#define GTM_CCM2_ARP8_CTRL (0xF01E2440u)
+#define GTM_CCM2_ARP8_PROT (0xF01E2444u)
+#define GTM_CCM2_ARP9_CTRL (0xF01E2448u)
+#define GTM_CCM2_ARP9_PROT (0xF01E244Cu)
+#define GTM_CCM2_AEIM_STA (0xF01E25D8u)
+#define GTM_CCM2_HW_CONF (0xF01E25DCu)
+#define GTM_CCM2_TIM_AUX_IN_SRC (0xF01E25E0u)
+#define GTM_CCM2_EXT_CAP_EN (0xF01E25E4u)
+#define GTM_CCM2_TOM_OUT (0xF01E25E8u)
+#define GTM_ICM_IRQG_CEI_MCS4 (0xF0100674u)
+#define GTM_CCM2_ATOM_OUT (0xF01E25ECu)
+#define GTM_CCM2_CMU_CLK_CFG (0xF01E25F0u)
+#define GTM_CCM2_CMU_FXCLK_CFG (0xF01E25F4u)
+#define GTM_CCM2_CFG (0xF01E25F8u)
+#define GTM_CCM2_PROT (0xF01E25FCu)
+#define GTM_CCM3_ARP0_CTRL (0xF01E2600u)
+#define GTM_CCM3_ARP0_PROT (0xF01E2604u)
+#define GTM_CCM3_ARP1_CTRL (0xF01E2608u)
+#define GTM_CCM3_ARP1_PROT (0xF01E260Cu)
+#define GTM_CCM3_ARP2_CTRL (0xF01E2610u)
+#define GTM_CCM3_ARP2_PROT (0xF01E2614u)
+#define GTM_CCM3_ARP3_CTRL (0xF01E2618u)
+#define GTM_CCM3_ARP3_PROT (0xF01E261Cu)
+#define GTM_CCM3_ARP4_CTRL (0xF01E2620u)
+#define GTM_MCS1_MEM1 (0xF0142000u)
+#define GTM_CCM3_ARP4_PROT (0xF01E2624u)
+#define GTM_CCM3_ARP5_CTRL (0xF01E2628u)
+#define GTM_CCM3_ARP5_PROT (0xF01E262Cu)
+#define GTM_CCM3_ARP6_CTRL (0xF01E2630u)
+#define GTM_CCM3_ARP6_PROT (0xF01E2634u)
+#define GTM_CCM3_ARP7_CTRL (0xF01E2638u)
+#define GTM_CCM3_ARP7_PROT (0xF01E263Cu)
+#define GTM_CCM3_ARP8_CTRL (0xF01E2640u)
+#define GTM_CCM3_ARP8_PROT (0xF01E2644u)
+#define GTM_CCM3_ARP9_CTRL (0xF01E2648u)
+#define GTM_ICM_IRQG_CEI_PSM0 (0xF01006A4u)
+#define GTM_CCM3_ARP9_PROT (0xF01E264Cu)
+#define GTM_CCM3_AEIM_STA (0xF01E27D8u)
+#define GTM_CCM3_HW_CONF (0xF01E27DCu)
+#define GTM_CCM3_TIM_AUX_IN_SRC (0xF01E27E0u)
+#define GTM_CCM3_EXT_CAP_EN (0xF01E27E4u)
+#define GTM_CCM3_ATOM_OUT (0xF01E27ECu)
+#define GTM_CCM3_CMU_CLK_CFG (0xF01E27F0u)
+#define GTM_CCM3_CMU_FXCLK_CFG (0xF01E27F4u)
+#define GTM_CCM3_CFG (0xF01E27F8u)
+#define GTM_CCM3_PROT (0xF01E27FCu)
+#define GTM_CCM4_ARP0_CTRL (0xF01E2800u)
+#define GTM_CCM4_ARP0_PROT (0xF01E2804u)
+#define GTM_CCM4_ARP1_CTRL (0xF01E2808u)
+#define GTM_CCM4_ARP1_PROT (0xF01E280Cu)
+#define GTM_CCM4_ARP2_CTRL (0xF01E2810u)
+#define GTM_CCM4_ARP2_PROT (0xF01E2814u)
+#define GTM_CCM4_ARP3_CTRL (0xF01E2818u)
+#define GTM_CCM4_ARP3_PROT (0xF01E281Cu)
+#define GTM_CCM4_ARP4_CTRL (0xF01E2820u)
+#define GTM_CCM4_ARP4_PROT (0xF01E2824u)
+#define GTM_CCM4_ARP5_CTRL (0xF01E2828u)
+#define GTM_CCM4_ARP5_PROT (0xF01E282Cu)
+#define GTM_CCM4_ARP6_CTRL (0xF01E2830u)
+#define GTM_ICM_IRQG_CEI_SPE (0xF01006B4u)
+#define GTM_CCM4_ARP6_PROT (0xF01E2834u)
+#define GTM_CCM4_ARP7_CTRL (0xF01E2838u)
+#define GTM_CCM4_ARP7_PROT (0xF01E283Cu)
+#define GTM_CCM4_ARP8_CTRL (0xF01E2840u)
+#define GTM_CCM4_ARP8_PROT (0xF01E2844u)
+#define GTM_CCM4_ARP9_CTRL (0xF01E2848u)
+#define GTM_CCM4_ARP9_PROT (0xF01E284Cu)
+#define GTM_CCM4_AEIM_STA (0xF01E29D8u)
+#define GTM_CCM4_HW_CONF (0xF01E29DCu)
+#define GTM_CCM4_TIM_AUX_IN_SRC (0xF01E29E0u)
+#define GTM_CCM4_EXT_CAP_EN (0xF01E29E4u)
+#define GTM_CCM4_ATOM_OUT (0xF01E29ECu)
+#define GTM_CCM4_CMU_CLK_CFG (0xF01E29F0u)
+#define GTM_CCM4_CMU_FXCLK_CFG (0xF01E29F4u)
+#define GTM_CCM4_CFG (0xF01E29F8u)
+#define GTM_CCM4_PROT (0xF01E29FCu)
+#define GTM_CCM5_HW_CONF (0xF01E2BDCu)
+#define GTM_CCM5_TIM_AUX_IN_SRC (0xF01E2BE0u)
+#define GTM_CCM5_ATOM_OUT (0xF01E2BECu)
+#define GTM_CCM5_CMU_CLK_CFG (0xF01E2BF0u)
+#define GTM_CCM5_CMU_FXCLK_CFG (0xF01E2BF4u)
+#define GTM_CCM5_CFG (0xF01E2BF8u)
+#define GTM_CCM5_PROT (0xF01E2BFCu)
+#define GTM_ICM_IRQG_MEI_CLS0 (0xF0100710u)
+#define GTM_CDTM0_DTM0_CTRL (0xF01E4000u)
+#define GTM_CDTM0_DTM0_CH_CTRL1 (0xF01E4004u)
+#define GTM_CDTM0_DTM0_CH_CTRL2 (0xF01E4008u)
+#define GTM_CDTM0_DTM0_CH_CTRL2_SR (0xF01E400Cu)
+#define GTM_CDTM0_DTM0_PS_CTRL (0xF01E4010u)
+#define GTM_CDTM0_DTM0_CH0_DTV (0xF01E4014u)
+#define GTM_CDTM0_DTM0_CH1_DTV (0xF01E4018u)
+#define GTM_CDTM0_DTM0_CH2_DTV (0xF01E401Cu)
+#define GTM_CDTM0_DTM0_CH3_DTV (0xF01E4020u)
+#define GTM_CDTM0_DTM0_CH_SR (0xF01E4024u)
+#define GTM_CDTM0_DTM0_CH_CTRL3 (0xF01E4028u)
+#define GTM_CDTM0_DTM1_CTRL (0xF01E4040u)
+#define GTM_CDTM0_DTM1_CH_CTRL1 (0xF01E4044u)
+#define GTM_CDTM0_DTM1_CH_CTRL2 (0xF01E4048u)
+#define GTM_CDTM0_DTM1_CH_CTRL2_SR (0xF01E404Cu)
+#define GTM_CDTM0_DTM1_PS_CTRL (0xF01E4050u)
+#define GTM_CDTM0_DTM1_CH0_DTV (0xF01E4054u)
+#define GTM_CDTM0_DTM1_CH1_DTV (0xF01E4058u)
+#define GTM_CDTM0_DTM1_CH2_DTV (0xF01E405Cu)
+#define GTM_CDTM0_DTM1_CH3_DTV (0xF01E4060u)
+#define GTM_CDTM0_DTM1_CH_SR (0xF01E4064u)
+#define GTM_CDTM0_DTM1_CH_CTRL3 (0xF01E4068u)
+#define GTM_CDTM0_DTM4_CTRL (0xF01E4100u)
+#define GTM_CDTM0_DTM4_CH_CTRL1 (0xF01E4104u)
+#define GTM_ICM_IRQG_MEI_CLS1 (0xF0100714u)
+#define GTM_CDTM0_DTM4_CH_CTRL2 (0xF01E4108u)
+#define GTM_CDTM0_DTM4_CH_CTRL2_SR (0xF01E410Cu)
+#define GTM_CDTM0_DTM4_PS_CTRL (0xF01E4110u)
+#define GTM_CDTM0_DTM4_CH0_DTV (0xF01E4114u)
+#define GTM_CDTM0_DTM4_CH1_DTV (0xF01E4118u)
+#define GTM_CDTM0_DTM4_CH2_DTV (0xF01E411Cu)
+#define GTM_CDTM0_DTM4_CH3_DTV (0xF01E4120u)
+#define GTM_CDTM0_DTM4_CH_SR (0xF01E4124u)
+#define GTM_CDTM0_DTM4_CH_CTRL3 (0xF01E4128u)
+#define GTM_CDTM0_DTM5_CTRL (0xF01E4140u)
+#define GTM_CDTM0_DTM5_CH_CTRL1 (0xF01E4144u)
+#define GTM_CDTM0_DTM5_CH_CTRL2 (0xF01E4148u)
+#define GTM_CDTM0_DTM5_CH_CTRL2_SR (0xF01E414Cu)
+#define GTM_CDTM0_DTM5_PS_CTRL (0xF01E4150u)
+#define GTM_CDTM0_DTM5_CH0_DTV (0xF01E4154u)
+#define GTM_CDTM0_DTM5_CH1_DTV (0xF01E4158u)
+#define GTM_CDTM0_DTM5_CH2_DTV (0xF01E415Cu)
+#define GTM_CDTM0_DTM5_CH3_DTV (0xF01E4160u)
+#define GTM_CDTM0_DTM5_CH_SR (0xF01E4164u)
+#define GTM_CDTM0_DTM5_CH_CTRL3 (0xF01E4168u)
+#define GTM_MCS2_MEM (0xF0148000u)
+#define GTM_CDTM1_DTM0_CTRL (0xF01E4400u)
+#define GTM_CDTM1_DTM0_CH_CTRL1 (0xF01E4404u)
+#define GTM_CDTM1_DTM0_CH_CTRL2 (0xF01E4408u)
+#define GTM_ICM_IRQG_CI_MCS0 (0xF0100720u)
+#define GTM_CDTM1_DTM0_CH_CTRL2_SR (0xF01E440Cu)
+#define GTM_CDTM1_DTM0_PS_CTRL (0xF01E4410u)
+#define GTM_CDTM1_DTM0_CH0_DTV (0xF01E4414u)
+#define GTM_CDTM1_DTM0_CH1_DTV (0xF01E4418u)
+#define GTM_CDTM1_DTM0_CH2_DTV (0xF01E441Cu)
+#define GTM_CDTM1_DTM0_CH3_DTV (0xF01E4420u)
+#define GTM_CDTM1_DTM0_CH_SR (0xF01E4424u)
+#define GTM_CDTM1_DTM0_CH_CTRL3 (0xF01E4428u)
+#define GTM_CDTM1_DTM1_CTRL (0xF01E4440u)
+#define GTM_CDTM1_DTM1_CH_CTRL1 (0xF01E4444u)
+#define GTM_CDTM1_DTM1_CH_CTRL2 (0xF01E4448u)
+#define GTM_CDTM1_DTM1_CH_CTRL2_SR (0xF01E444Cu)
+#define GTM_CDTM1_DTM1_PS_CTRL (0xF01E4450u)
+#define GTM_CDTM1_DTM1_CH0_DTV (0xF01E4454u)
+#define GTM_CDTM1_DTM1_CH1_DTV (0xF01E4458u)
+#define GTM_CDTM1_DTM1_CH2_DTV (0xF01E445Cu)
+#define GTM_CDTM1_DTM1_CH3_DTV (0xF01E4460u)
+#define GTM_CDTM1_DTM1_CH_SR (0xF01E4464u)
+#define GTM_CDTM1_DTM1_CH_CTRL3 (0xF01E4468u)
+#define GTM_CDTM1_DTM4_CTRL (0xF01E4500u)
+#define GTM_CDTM1_DTM4_CH_CTRL1 (0xF01E4504u)
+#define GTM_CDTM1_DTM4_CH_CTRL2 (0xF01E4508u)
+#define GTM_CDTM1_DTM4_CH_CTRL2_SR (0xF01E450Cu)
+#define GTM_ICM_IRQG_CI_MCS1 (0xF0100724u)
+#define GTM_CDTM1_DTM4_PS_CTRL (0xF01E4510u)
+#define GTM_CDTM1_DTM4_CH0_DTV (0xF01E4514u)
+#define GTM_CDTM1_DTM4_CH1_DTV (0xF01E4518u)
+#define GTM_CDTM1_DTM4_CH2_DTV (0xF01E451Cu)
+#define GTM_CDTM1_DTM4_CH3_DTV (0xF01E4520u)
+#define GTM_CDTM1_DTM4_CH_SR (0xF01E4524u)
+#define GTM_CDTM1_DTM4_CH_CTRL3 (0xF01E4528u)
+#define GTM_CDTM1_DTM5_CTRL (0xF01E4540u)
+#define GTM_CDTM1_DTM5_CH_CTRL1 (0xF01E4544u)
+#define GTM_CDTM1_DTM5_CH_CTRL2 (0xF01E4548u)
+#define GTM_CDTM1_DTM5_CH_CTRL2_SR (0xF01E454Cu)
+#define GTM_CDTM1_DTM5_PS_CTRL (0xF01E4550u)
+#define GTM_CDTM1_DTM5_CH0_DTV (0xF01E4554u)
+#define GTM_CDTM1_DTM5_CH1_DTV (0xF01E4558u)
+#define GTM_CDTM1_DTM5_CH2_DTV (0xF01E455Cu)
+#define GTM_CDTM1_DTM5_CH3_DTV (0xF01E4560u)
+#define GTM_CDTM1_DTM5_CH_SR (0xF01E4564u)
+#define GTM_CDTM1_DTM5_CH_CTRL3 (0xF01E4568u)
+#define GTM_CDTM2_DTM0_CTRL (0xF01E4800u)
+#define GTM_CDTM2_DTM0_CH_CTRL1 (0xF01E4804u)
+#define GTM_CDTM2_DTM0_CH_CTRL2 (0xF01E4808u)
+#define GTM_CDTM2_DTM0_CH_CTRL2_SR (0xF01E480Cu)
+#define GTM_CDTM2_DTM0_PS_CTRL (0xF01E4810u)
+#define GTM_CDTM2_DTM0_CH0_DTV (0xF01E4814u)
+#define GTM_ICM_IRQG_CI_MCS2 (0xF0100728u)
+#define GTM_CDTM2_DTM0_CH1_DTV (0xF01E4818u)
+#define GTM_CDTM2_DTM0_CH2_DTV (0xF01E481Cu)
+#define GTM_CDTM2_DTM0_CH3_DTV (0xF01E4820u)
+#define GTM_CDTM2_DTM0_CH_SR (0xF01E4824u)
+#define GTM_CDTM2_DTM0_CH_CTRL3 (0xF01E4828u)
+#define GTM_CDTM2_DTM1_CTRL (0xF01E4840u)
+#define GTM_CDTM2_DTM1_CH_CTRL1 (0xF01E4844u)
+#define GTM_CDTM2_DTM1_CH_CTRL2 (0xF01E4848u)
+#define GTM_CDTM2_DTM1_CH_CTRL2_SR (0xF01E484Cu)
+#define GTM_CDTM2_DTM1_PS_CTRL (0xF01E4850u)
+#define GTM_CDTM2_DTM1_CH0_DTV (0xF01E4854u)
+#define GTM_CDTM2_DTM1_CH1_DTV (0xF01E4858u)
+#define GTM_CDTM2_DTM1_CH2_DTV (0xF01E485Cu)
+#define GTM_CDTM2_DTM1_CH3_DTV (0xF01E4860u)
+#define GTM_CDTM2_DTM1_CH_SR (0xF01E4864u)
+#define GTM_CDTM2_DTM1_CH_CTRL3 (0xF01E4868u)
+#define GTM_CDTM2_DTM4_CTRL (0xF01E4900u)
+#define GTM_CDTM2_DTM4_CH_CTRL1 (0xF01E4904u)
+#define GTM_CDTM2_DTM4_CH_CTRL2 (0xF01E4908u)
+#define GTM_CDTM2_DTM4_CH_CTRL2_SR (0xF01E490Cu)
+#define GTM_CDTM2_DTM4_PS_CTRL (0xF01E4910u)
+#define GTM_CDTM2_DTM4_CH0_DTV (0xF01E4914u)
+#define GTM_CDTM2_DTM4_CH1_DTV (0xF01E4918u)
+#define GTM_ICM_IRQG_CI_MCS3 (0xF010072Cu)
+#define GTM_CDTM2_DTM4_CH2_DTV (0xF01E491Cu)
+#define GTM_CDTM2_DTM4_CH3_DTV (0xF01E4920u)
+#define GTM_CDTM2_DTM4_CH_SR (0xF01E4924u)
+#define GTM_CDTM2_DTM4_CH_CTRL3 (0xF01E4928u)
+#define GTM_CDTM2_DTM5_CTRL (0xF01E4940u)
+#define GTM_CDTM2_DTM5_CH_CTRL1 (0xF01E4944u)
+#define GTM_CDTM2_DTM5_CH_CTRL2 (0xF01E4948u)
+#define GTM_CDTM2_DTM5_CH_CTRL2_SR (0xF01E494Cu)
+#define GTM_CDTM2_DTM5_PS_CTRL (0xF01E4950u)
+#define GTM_CDTM2_DTM5_CH0_DTV (0xF01E4954u)
+#define GTM_CDTM2_DTM5_CH1_DTV (0xF01E4958u)
+#define GTM_CDTM2_DTM5_CH2_DTV (0xF01E495Cu)
+#define GTM_CDTM2_DTM5_CH3_DTV (0xF01E4960u)
+#define GTM_CDTM2_DTM5_CH_SR (0xF01E4964u)
+#define GTM_CDTM2_DTM5_CH_CTRL3 (0xF01E4968u)
+#define GTM_CDTM3_DTM4_CTRL (0xF01E4D00u)
+#define GTM_CDTM3_DTM4_CH_CTRL1 (0xF01E4D04u)
+#define GTM_CDTM3_DTM4_CH_CTRL2 (0xF01E4D08u)
+#define GTM_CDTM3_DTM4_CH_CTRL2_SR (0xF01E4D0Cu)
+#define GTM_CDTM3_DTM4_PS_CTRL (0xF01E4D10u)
+#define GTM_CDTM3_DTM4_CH0_DTV (0xF01E4D14u)
+#define GTM_CDTM3_DTM4_CH1_DTV (0xF01E4D18u)
+#define GTM_CDTM3_DTM4_CH2_DTV (0xF01E4D1Cu)
+#define GTM_ICM_IRQG_CI_MCS4 (0xF0100730u)
+#define GTM_CDTM3_DTM4_CH3_DTV (0xF01E4D20u)
+#define GTM_CDTM3_DTM4_CH_SR (0xF01E4D24u)
+#define GTM_CDTM3_DTM4_CH_CTRL3 (0xF01E4D28u)
+#define GTM_CDTM3_DTM5_CTRL (0xF01E4D40u)
+#define GTM_MCS2_MEM1 (0xF014A000u)
+#define GTM_CDTM3_DTM5_CH_CTRL1 (0xF01E4D44u)
+#define GTM_CDTM3_DTM5_CH_CTRL2 (0xF01E4D48u)
+#define GTM_CDTM3_DTM5_CH_CTRL2_SR (0xF01E4D4Cu)
+#define GTM_CDTM3_DTM5_PS_CTRL (0xF01E4D50u)
+#define GTM_CDTM3_DTM5_CH0_DTV (0xF01E4D54u)
+#define GTM_CDTM3_DTM5_CH1_DTV (0xF01E4D58u)
+#define GTM_CDTM3_DTM5_CH2_DTV (0xF01E4D5Cu)
+#define GTM_CDTM3_DTM5_CH3_DTV (0xF01E4D60u)
+#define GTM_CDTM3_DTM5_CH_SR (0xF01E4D64u)
+#define GTM_CDTM3_DTM5_CH_CTRL3 (0xF01E4D68u)
+#define GTM_CDTM4_DTM4_CTRL (0xF01E5100u)
+#define GTM_CDTM4_DTM4_CH_CTRL1 (0xF01E5104u)
+#define GTM_CDTM4_DTM4_CH_CTRL2 (0xF01E5108u)
+#define GTM_CDTM4_DTM4_CH_CTRL2_SR (0xF01E510Cu)
+#define GTM_CDTM4_DTM4_PS_CTRL (0xF01E5110u)
+#define GTM_CDTM4_DTM4_CH0_DTV (0xF01E5114u)
+#define GTM_CDTM4_DTM4_CH1_DTV (0xF01E5118u)
+#define GTM_CDTM4_DTM4_CH2_DTV (0xF01E511Cu)
+#define GTM_CDTM4_DTM4_CH3_DTV (0xF01E5120u)
+#define GTM_CDTM4_DTM4_CH_SR (0xF01E5124u)
+#define GTM_ICM_IRQG_CI_PSM0 (0xF0100760u)
+#define GTM_CDTM4_DTM4_CH_CTRL3 (0xF01E5128u)
+#define GTM_CDTM4_DTM5_CTRL (0xF01E5140u)
+#define GTM_CDTM4_DTM5_CH_CTRL1 (0xF01E5144u)
+#define GTM_CDTM4_DTM5_CH_CTRL2 (0xF01E5148u)
+#define GTM_CDTM4_DTM5_CH_CTRL2_SR (0xF01E514Cu)
+#define GTM_CDTM4_DTM5_PS_CTRL (0xF01E5150u)
+#define GTM_CDTM4_DTM5_CH0_DTV (0xF01E5154u)
+#define GTM_CDTM4_DTM5_CH1_DTV (0xF01E5158u)
+#define GTM_CDTM4_DTM5_CH2_DTV (0xF01E515Cu)
+#define GTM_CDTM4_DTM5_CH3_DTV (0xF01E5160u)
+#define GTM_CDTM4_DTM5_CH_SR (0xF01E5164u)
+#define GTM_CDTM4_DTM5_CH_CTRL3 (0xF01E5168u)
+#define GTM_ATOM0_CH0_RDADDR (0xF01E8000u)
+#define GTM_ATOM0_CH0_CTRL (0xF01E8004u)
+#define GTM_ATOM0_CH0_SOMB (0xF01E8004u)
+#define GTM_ATOM0_CH0_SOMC (0xF01E8004u)
+#define GTM_ATOM0_CH0_SOMI (0xF01E8004u)
+#define GTM_ATOM0_CH0_SOMP (0xF01E8004u)
+#define GTM_ATOM0_CH0_SOMS (0xF01E8004u)
+#define GTM_ATOM0_CH0_SR0 (0xF01E8008u)
+#define GTM_ATOM0_CH0_SR1 (0xF01E800Cu)
+#define GTM_ATOM0_CH0_CM0 (0xF01E8010u)
+#define GTM_ATOM0_CH0_CM1 (0xF01E8014u)
+#define GTM_ICM_IRQG_CI_SPE (0xF0100770u)
+#define GTM_ATOM0_CH0_CN0 (0xF01E8018u)
+#define GTM_ATOM0_CH0_STAT (0xF01E801Cu)
+#define GTM_ATOM0_CH0_IRQ_NOTIFY (0xF01E8020u)
+#define GTM_ATOM0_CH0_IRQ_EN (0xF01E8024u)
+#define GTM_ATOM0_CH0_IRQ_FORCINT (0xF01E8028u)
+#define GTM_ATOM0_CH0_IRQ_MODE (0xF01E802Cu)
+#define GTM_ATOM0_AGC_GLB_CTRL (0xF01E8040u)
+#define GTM_ATOM0_AGC_ENDIS_CTRL (0xF01E8044u)
+#define GTM_ATOM0_AGC_ENDIS_STAT (0xF01E8048u)
+#define GTM_ATOM0_AGC_ACT_TB (0xF01E804Cu)
+#define GTM_ATOM0_AGC_OUTEN_CTRL (0xF01E8050u)
+#define GTM_ATOM0_AGC_OUTEN_STAT (0xF01E8054u)
+#define GTM_ATOM0_AGC_FUPD_CTRL (0xF01E8058u)
+#define GTM_ATOM0_AGC_INT_TRIG (0xF01E805Cu)
+#define GTM_ATOM0_CH1_RDADDR (0xF01E8080u)
+#define GTM_ATOM0_CH1_CTRL (0xF01E8084u)
+#define GTM_ATOM0_CH1_SOMB (0xF01E8084u)
+#define GTM_ATOM0_CH1_SOMC (0xF01E8084u)
+#define GTM_ATOM0_CH1_SOMI (0xF01E8084u)
+#define GTM_ATOM0_CH1_SOMP (0xF01E8084u)
+#define GTM_ATOM0_CH1_SOMS (0xF01E8084u)
+#define GTM_ATOM0_CH1_SR0 (0xF01E8088u)
+#define GTM_ATOM0_CH1_SR1 (0xF01E808Cu)
+#define GTM_ICM_IRQG_CI_ATOM0 (0xF0100790u)
+#define HSCT0_IFSTAT (0xF0090028u)
+#define HSCT0_CONFIGPHY (0xF0090030u)
+#define HSCT0_STATPHY (0xF0090034u)
+#define HSCT0_IRQ (0xF0090040u)
+#define HSCT0_IRQEN (0xF0090044u)
+#define HSCT0_IRQCLR (0xF0090048u)
+#define HSCT0_USMR (0xF0090050u)
+#define HSCT0_USMS (0xF0090054u)
+#define HSCT0_TESTCTRL (0xF0090060u)
+#define HSCT0_OCS (0xF009FFE8u)
+#define HSCT0_KRSTCLR (0xF009FFECu)
+#define HSCT0_KRST1 (0xF009FFF0u)
+#define HSCT0_KRST0 (0xF009FFF4u)
+#define HSCT0_ACCEN1 (0xF009FFF8u)
+#define HSCT0_ACCEN0 (0xF009FFFCu)
+#define MODULE_HSCT0 (0xF0090000u)
+#define HSCT0_CLC (0xF0090000u)
+#define HSCT0_ID (0xF0090008u)
+#define HSCT0_INIT (0xF0090010u)
+#define HSCT0_IFCTRL (0xF0090014u)
+#define HSCT0_SLEEPCTRL (0xF0090018u)
+#define HSCT0_CTSCTRL (0xF009001Cu)
+#define HSCT0_DISABLE (0xF0090020u)
+#define HSCT0_STAT (0xF0090024u)
+#define HSSL0_MFLAGSEN (0xF0080024u)
+#define HSSL0_SFSFLAGS (0xF0080028u)
+#define HSSL0_I0_IWD (0xF0080030u)
+#define HSSL0_I0_ICON (0xF0080034u)
+#define HSSL0_I0_IRWA (0xF0080038u)
+#define HSSL0_I0_IRD (0xF008003Cu)
+#define HSSL0_I1_IWD (0xF0080040u)
+#define HSSL0_I1_ICON (0xF0080044u)
+#define HSSL0_I1_IRWA (0xF0080048u)
+#define HSSL0_I1_IRD (0xF008004Cu)
+#define HSSL0_I2_IWD (0xF0080050u)
+#define HSSL0_I2_ICON (0xF0080054u)
+#define HSSL0_I2_IRWA (0xF0080058u)
+#define HSSL0_I2_IRD (0xF008005Cu)
+#define HSSL0_I3_IWD (0xF0080060u)
+#define HSSL0_I3_ICON (0xF0080064u)
+#define HSSL0_I3_IRWA (0xF0080068u)
+#define HSSL0_I3_IRD (0xF008006Cu)
+#define HSSL0_T0_TCD (0xF0080070u)
+#define HSSL0_T0_TCA (0xF0080074u)
+#define HSSL0_T1_TCD (0xF0080078u)
+#define HSSL0_T1_TCA (0xF008007Cu)
+#define HSSL0_T2_TCD (0xF0080080u)
+#define HSSL0_T2_TCA (0xF0080084u)
+#define HSSL0_T3_TCD (0xF0080088u)
+#define HSSL0_T3_TCA (0xF008008Cu)
+#define HSSL0_TSTAT (0xF0080090u)
+#define HSSL0_TIDADD (0xF0080094u)
+#define HSSL0_SEC (0xF0080098u)
+#define HSSL0_MSCR (0xF008009Cu)
+#define HSSL0_IS_SA0 (0xF00800A0u)
+#define HSSL0_IS_SA1 (0xF00800A4u)
+#define HSSL0_IS_CA (0xF00800A8u)
+#define HSSL0_IS_FC (0xF00800ACu)
+#define HSSL0_TS_SA0 (0xF00800B0u)
+#define HSSL0_TS_SA1 (0xF00800B4u)
+#define HSSL0_TS_CA (0xF00800B8u)
+#define HSSL0_TS_FC (0xF00800BCu)
+#define HSSL0_AW0_AWSTART (0xF00800C0u)
+#define HSSL0_AW0_AWEND (0xF00800C4u)
+#define HSSL0_AW1_AWSTART (0xF00800C8u)
+#define HSSL0_AW1_AWEND (0xF00800CCu)
+#define HSSL0_AW2_AWSTART (0xF00800D0u)
+#define HSSL0_AW2_AWEND (0xF00800D4u)
+#define HSSL0_AW3_AWSTART (0xF00800D8u)
+#define HSSL0_AW3_AWEND (0xF00800DCu)
+#define HSSL0_AR (0xF00800E0u)
+#define HSSL0_OCS (0xF00800E8u)
+#define HSSL0_KRSTCLR (0xF00800ECu)
+#define HSSL0_KRST1 (0xF00800F0u)
+#define HSSL0_KRST0 (0xF00800F4u)
+#define HSSL0_ACCEN1 (0xF00800F8u)
+#define HSSL0_ACCEN0 (0xF00800FCu)
+#define MODULE_HSSL0 (0xF0080000u)
+#define HSSL0_CLC (0xF0080000u)
+#define HSSL0_ID (0xF0080008u)
+#define HSSL0_CRC (0xF008000Cu)
+#define HSSL0_CFG (0xF0080010u)
+#define HSSL0_QFLAGS (0xF0080014u)
+#define HSSL0_MFLAGS (0xF0080018u)
+#define HSSL0_MFLAGSSET (0xF008001Cu)
+#define HSSL0_MFLAGSCL (0xF0080020u)
+#define I2C0_FIFOCFG (0xF00C0028u)
+#define I2C0_MRPSCTRL (0xF00C002Cu)
+#define I2C0_RPSSTAT (0xF00C0030u)
+#define I2C0_TPSCTRL (0xF00C0034u)
+#define I2C0_FFSSTAT (0xF00C0038u)
+#define I2C0_TIMCFG (0xF00C0040u)
+#define I2C0_ERRIRQSM (0xF00C0060u)
+#define I2C0_ERRIRQSS (0xF00C0064u)
+#define I2C0_ERRIRQSC (0xF00C0068u)
+#define I2C0_PIRQSM (0xF00C0070u)
+#define I2C0_PIRQSS (0xF00C0074u)
+#define I2C0_PIRQSC (0xF00C0078u)
+#define I2C0_RIS (0xF00C0080u)
+#define I2C0_IMSC (0xF00C0084u)
+#define I2C0_MIS (0xF00C0088u)
+#define I2C0_ICR (0xF00C008Cu)
+#define I2C0_ISR (0xF00C0090u)
+#define I2C0_TXD (0xF00C8000u)
+#define I2C0_RXD (0xF00CC000u)
+#define I2C0_CLC (0xF00D0000u)
+#define I2C0_MODID (0xF00D0004u)
+#define I2C0_GPCTL (0xF00D0008u)
+#define I2C0_ACCEN0 (0xF00D000Cu)
+#define I2C0_ACCEN1 (0xF00D0010u)
+#define I2C0_KRST0 (0xF00D0014u)
+#define I2C0_KRST1 (0xF00D0018u)
+#define I2C0_KRSTCLR (0xF00D001Cu)
+#define MODULE_I2C0 (0xF00C0000u)
+#define I2C0_CLC1 (0xF00C0000u)
+#define I2C0_ID (0xF00C0008u)
+#define I2C0_RUNCTRL (0xF00C0010u)
+#define I2C0_ENDDCTRL (0xF00C0014u)
+#define I2C0_FDIVCFG (0xF00C0018u)
+#define I2C0_FDIVHIGHCFG (0xF00C001Cu)
+#define I2C0_ADDRCFG (0xF00C0020u)
+#define I2C0_BUSSTAT (0xF00C0024u)
+#define INT_OIXS0 (0xF0037090u)
+#define INT_OIXS1 (0xF0037094u)
+#define INT_OIT (0xF00370A0u)
+#define INT_OMISP (0xF00370A4u)
+#define INT_OMISN (0xF00370A8u)
+#define INT_ACCEN_CONFIG0 (0xF00370F0u)
+#define INT_ACCEN_CONFIG1 (0xF00370F4u)
+#define INT_ACCEN0_SRB0 (0xF0037100u)
+#define INT_ACCEN0_SRB1 (0xF0037104u)
+#define INT_ACCEN1_SRB0 (0xF0037108u)
+#define INT_ACCEN1_SRB1 (0xF003710Cu)
+#define INT_ACCEN2_SRB0 (0xF0037110u)
+#define INT_ACCEN2_SRB1 (0xF0037114u)
+#define INT_ACCEN_SRC0_TOS0 (0xF0037180u)
+#define INT_ACCEN_SRC0_TOS1 (0xF0037184u)
+#define INT_ACCEN_SRC1_TOS0 (0xF0037188u)
+#define INT_ACCEN_SRC1_TOS1 (0xF003718Cu)
+#define INT_ACCEN_SRC2_TOS0 (0xF0037190u)
+#define INT_ACCEN_SRC2_TOS1 (0xF0037194u)
+#define INT_ACCEN_SRC3_TOS0 (0xF0037198u)
+#define INT_ACCEN_SRC3_TOS1 (0xF003719Cu)
+#define INT_CH0_LWSR (0xF0037200u)
+#define INT_CH0_LASR (0xF0037204u)
+#define INT_CH0_ECR (0xF0037208u)
+#define INT_CH1_LWSR (0xF0037210u)
+#define INT_CH1_LASR (0xF0037214u)
+#define INT_CH1_ECR (0xF0037218u)
+#define INT_CH2_LWSR (0xF0037220u)
+#define INT_CH2_LASR (0xF0037224u)
+#define INT_CH2_ECR (0xF0037228u)
+#define INT_CH3_LWSR (0xF0037230u)
+#define INT_CH3_LASR (0xF0037234u)
+#define INT_CH3_ECR (0xF0037238u)
+#define MODULE_INT (0xF0037000u)
+#define INT_ID (0xF0037008u)
+#define INT_SRB0 (0xF0037010u)
+#define INT_SRB1 (0xF0037014u)
+#define INT_SRB2 (0xF0037018u)
+#define INT_OOBS (0xF0037080u)
+#define INT_OSSIC (0xF0037084u)
+#define INT_OIXTS (0xF0037088u)
+#define INT_OIXMS (0xF003708Cu)
+#define IOM_ECMSELR (0xF0035034u)
+#define IOM_ECMETH0 (0xF0035038u)
+#define IOM_ECMETH1 (0xF003503Cu)
+#define IOM_GTMEXR (0xF0035040u)
+#define IOM_FPCESR (0xF0035078u)
+#define IOM_FPCCTR0 (0xF0035080u)
+#define IOM_FPCCTR1 (0xF0035084u)
+#define IOM_FPCCTR2 (0xF0035088u)
+#define IOM_FPCCTR3 (0xF003508Cu)
+#define IOM_FPCCTR4 (0xF0035090u)
+#define IOM_FPCCTR5 (0xF0035094u)
+#define IOM_FPCCTR6 (0xF0035098u)
+#define IOM_FPCCTR7 (0xF003509Cu)
+#define IOM_FPCCTR8 (0xF00350A0u)
+#define IOM_FPCCTR9 (0xF00350A4u)
+#define IOM_FPCCTR10 (0xF00350A8u)
+#define IOM_FPCCTR11 (0xF00350ACu)
+#define IOM_FPCCTR12 (0xF00350B0u)
+#define IOM_FPCCTR13 (0xF00350B4u)
+#define IOM_FPCCTR14 (0xF00350B8u)
+#define IOM_FPCCTR15 (0xF00350BCu)
+#define IOM_FPCTIM0 (0xF00350C0u)
+#define IOM_FPCTIM1 (0xF00350C4u)
+#define IOM_FPCTIM2 (0xF00350C8u)
+#define IOM_FPCTIM3 (0xF00350CCu)
+#define IOM_FPCTIM4 (0xF00350D0u)
+#define IOM_FPCTIM5 (0xF00350D4u)
+#define IOM_FPCTIM6 (0xF00350D8u)
+#define IOM_FPCTIM7 (0xF00350DCu)
+#define IOM_FPCTIM8 (0xF00350E0u)
+#define IOM_FPCTIM9 (0xF00350E4u)
+#define IOM_FPCTIM10 (0xF00350E8u)
+#define IOM_FPCTIM11 (0xF00350ECu)
+#define IOM_FPCTIM12 (0xF00350F0u)
+#define IOM_FPCTIM13 (0xF00350F4u)
+#define IOM_FPCTIM14 (0xF00350F8u)
+#define IOM_FPCTIM15 (0xF00350FCu)
+#define IOM_LAMEWC0 (0xF0035100u)
+#define IOM_LAMEWC1 (0xF0035104u)
+#define IOM_LAMEWC2 (0xF0035108u)
+#define IOM_LAMEWC3 (0xF003510Cu)
+#define IOM_LAMEWC4 (0xF0035110u)
+#define IOM_LAMEWC5 (0xF0035114u)
+#define IOM_LAMEWC6 (0xF0035118u)
+#define IOM_LAMEWC7 (0xF003511Cu)
+#define IOM_LAMEWC8 (0xF0035120u)
+#define IOM_LAMEWC9 (0xF0035124u)
+#define IOM_LAMEWC10 (0xF0035128u)
+#define IOM_LAMEWC11 (0xF003512Cu)
+#define IOM_LAMEWC12 (0xF0035130u)
+#define IOM_LAMEWC13 (0xF0035134u)
+#define IOM_LAMEWC14 (0xF0035138u)
+#define IOM_LAMEWC15 (0xF003513Cu)
+#define IOM_LAMCFG0 (0xF0035180u)
+#define IOM_LAMCFG1 (0xF0035184u)
+#define IOM_LAMCFG2 (0xF0035188u)
+#define IOM_LAMCFG3 (0xF003518Cu)
+#define IOM_LAMCFG4 (0xF0035190u)
+#define IOM_LAMCFG5 (0xF0035194u)
+#define IOM_LAMCFG6 (0xF0035198u)
+#define IOM_LAMCFG7 (0xF003519Cu)
+#define IOM_LAMCFG8 (0xF00351A0u)
+#define IOM_LAMCFG9 (0xF00351A4u)
+#define IOM_LAMCFG10 (0xF00351A8u)
+#define IOM_LAMCFG11 (0xF00351ACu)
+#define IOM_LAMCFG12 (0xF00351B0u)
+#define IOM_LAMCFG13 (0xF00351B4u)
+#define IOM_LAMCFG14 (0xF00351B8u)
+#define IOM_LAMCFG15 (0xF00351BCu)
+#define IOM_LAMEWS0 (0xF00351C0u)
+#define IOM_LAMEWS1 (0xF00351C4u)
+#define IOM_LAMEWS2 (0xF00351C8u)
+#define IOM_LAMEWS3 (0xF00351CCu)
+#define IOM_LAMEWS4 (0xF00351D0u)
+#define IOM_LAMEWS5 (0xF00351D4u)
+#define IOM_LAMEWS6 (0xF00351D8u)
+#define IOM_LAMEWS7 (0xF00351DCu)
+#define IOM_LAMEWS8 (0xF00351E0u)
+#define IOM_LAMEWS9 (0xF00351E4u)
+#define IOM_LAMEWS10 (0xF00351E8u)
+#define IOM_LAMEWS11 (0xF00351ECu)
+#define IOM_LAMEWS12 (0xF00351F0u)
+#define IOM_LAMEWS13 (0xF00351F4u)
+#define IOM_LAMEWS14 (0xF00351F8u)
+#define IOM_LAMEWS15 (0xF00351FCu)
+#define MODULE_IOM (0xF0035000u)
+#define IOM_CLC (0xF0035000u)
+#define IOM_ID (0xF0035008u)
+#define IOM_KRSTCLR (0xF003501Cu)
+#define IOM_KRST1 (0xF0035020u)
+#define IOM_KRST0 (0xF0035024u)
+#define IOM_ACCEN1 (0xF0035028u)
+#define IOM_ACCEN0 (0xF003502Cu)
+#define IOM_ECMCCFG (0xF0035030u)
+#define MINIMCDS_FIFOPRE (0xFB718208u)
+#define MINIMCDS_FIFOTOP (0xFB71820Cu)
+#define MINIMCDS_FIFOCTL (0xFB718210u)
+#define MINIMCDS_FIFOWARN0 (0xFB718214u)
+#define MINIMCDS_FIFOWARN1 (0xFB718218u)
+#define MINIMCDS_FIFOOVRCNT (0xFB71821Cu)
+#define MINIMCDS_TSUREFCNT (0xFB718400u)
+#define MINIMCDS_TSUPRSCL (0xFB718404u)
+#define MINIMCDS_TSUEMUCNT (0xFB718408u)
+#define MINIMCDS_MCX_EVT0 (0xFB718800u)
+#define MINIMCDS_MCX_EVT1 (0xFB718804u)
+#define MINIMCDS_MCX_EVT2 (0xFB718808u)
+#define MINIMCDS_MCX_EVT3 (0xFB71880Cu)
+#define MINIMCDS_MCX_EVT4 (0xFB718810u)
+#define MINIMCDS_MCX_EVT5 (0xFB718814u)
+#define MINIMCDS_MCX_EVT6 (0xFB718818u)
+#define MINIMCDS_MCX_EVT7 (0xFB71881Cu)
+#define MINIMCDS_MCX_EVT8 (0xFB718820u)
+#define MINIMCDS_MCX_EVT9 (0xFB718824u)
+#define MINIMCDS_MCX_EVT10 (0xFB718828u)
+#define MINIMCDS_MCX_EVT11 (0xFB71882Cu)
+#define MINIMCDS_MCX_EVT12 (0xFB718830u)
+#define MINIMCDS_MCX_EVT13 (0xFB718834u)
+#define MINIMCDS_MCX_EVT14 (0xFB718838u)
+#define MINIMCDS_MCX_EVT15 (0xFB71883Cu)
+#define MINIMCDS_MCX_ACT0 (0xFB718880u)
+#define MINIMCDS_MCX_ACT1 (0xFB718884u)
+#define MINIMCDS_MCX_ACT2 (0xFB718888u)
+#define MINIMCDS_MCX_ACT3 (0xFB71888Cu)
+#define MINIMCDS_MCX_ACT4 (0xFB718890u)
+#define MINIMCDS_MCX_ACT5 (0xFB718894u)
+#define MINIMCDS_MCX_ACT6 (0xFB718898u)
+#define MINIMCDS_MCX_ACT7 (0xFB71889Cu)
+#define MINIMCDS_MCX_ACT8 (0xFB7188A0u)
+#define MINIMCDS_MCX_ACT9 (0xFB7188A4u)
+#define MINIMCDS_MCX_ACT10 (0xFB7188A8u)
+#define MINIMCDS_MCX_ACT11 (0xFB7188ACu)
+#define MINIMCDS_MCX_ACT12 (0xFB7188B0u)
+#define MINIMCDS_MCX_ACT13 (0xFB7188B4u)
+#define MINIMCDS_MCX_ACT14 (0xFB7188B8u)
+#define MINIMCDS_MCX_ACT15 (0xFB7188BCu)
+#define MINIMCDS_MCX_ACT16 (0xFB7188C0u)
+#define MINIMCDS_MCX_ACT17 (0xFB7188C4u)
+#define MINIMCDS_MCX_ACT18 (0xFB7188C8u)
+#define MINIMCDS_MCX_ACT19 (0xFB7188CCu)
+#define MINIMCDS_MCX_ACT20 (0xFB7188D0u)
+#define MINIMCDS_MCX_ACT21 (0xFB7188D4u)
+#define MINIMCDS_MCX_ACT22 (0xFB7188D8u)
+#define MINIMCDS_MCX_ACT23 (0xFB7188DCu)
+#define MINIMCDS_MCX_ACT24 (0xFB7188E0u)
+#define MINIMCDS_MCX_ACT25 (0xFB7188E4u)
+#define MINIMCDS_MCX_ACT26 (0xFB7188E8u)
+#define MINIMCDS_MCX_ACT27 (0xFB7188ECu)
+#define MINIMCDS_MCX_ACT28 (0xFB7188F0u)
+#define MINIMCDS_MCX_ACT29 (0xFB7188F4u)
+#define MINIMCDS_MCX_ACT30 (0xFB7188F8u)
+#define MINIMCDS_MCX_ACT31 (0xFB7188FCu)
+#define MINIMCDS_MCX_ACT32 (0xFB718900u)
+#define MINIMCDS_MCX_ACT33 (0xFB718904u)
+#define MINIMCDS_MCX_ACT34 (0xFB718908u)
+#define MINIMCDS_MCX_ACT35 (0xFB71890Cu)
+#define MINIMCDS_MCX_ACT36 (0xFB718910u)
+#define MINIMCDS_MCX_ACT37 (0xFB718914u)
+#define MINIMCDS_MCX_ACT38 (0xFB718918u)
+#define MINIMCDS_MCX_ACT39 (0xFB71891Cu)
+#define MINIMCDS_MCX_ACT40 (0xFB718920u)
+#define MINIMCDS_MCX_ACT41 (0xFB718924u)
+#define MINIMCDS_MCX_CNT0_CCL (0xFB718A00u)
+#define MINIMCDS_MCX_CNT0_LMT (0xFB718A04u)
+#define MINIMCDS_MCX_CNT0_CNT (0xFB718A08u)
+#define MINIMCDS_MCX_CNT1_CCL (0xFB718A10u)
+#define MINIMCDS_MCX_CNT1_LMT (0xFB718A14u)
+#define MINIMCDS_MCX_CNT1_CNT (0xFB718A18u)
+#define MINIMCDS_MCX_CNT2_CCL (0xFB718A20u)
+#define MINIMCDS_MCX_CNT2_LMT (0xFB718A24u)
+#define MINIMCDS_MCX_CNT2_CNT (0xFB718A28u)
+#define MINIMCDS_MCX_CNT3_CCL (0xFB718A30u)
+#define MINIMCDS_MCX_CNT3_LMT (0xFB718A34u)
+#define MINIMCDS_MCX_CNT3_CNT (0xFB718A38u)
+#define MINIMCDS_MCX_CNT4_CCL (0xFB718A40u)
+#define MINIMCDS_MCX_CNT4_LMT (0xFB718A44u)
+#define MINIMCDS_MCX_CNT4_CNT (0xFB718A48u)
+#define MINIMCDS_MCX_CNT5_CCL (0xFB718A50u)
+#define MINIMCDS_MCX_CNT5_LMT (0xFB718A54u)
+#define MINIMCDS_MCX_CNT5_CNT (0xFB718A58u)
+#define MINIMCDS_MCX_CNT6_CCL (0xFB718A60u)
+#define MINIMCDS_MCX_CNT6_LMT (0xFB718A64u)
+#define MINIMCDS_MCX_CNT6_CNT (0xFB718A68u)
+#define MODULE_MINIMCDS (0xFB718000u)
+#define MINIMCDS_MCX_CNT7_CCL (0xFB718A70u)
+#define MINIMCDS_MCX_CNT7_LMT (0xFB718A74u)
+#define MINIMCDS_MCX_CNT7_CNT (0xFB718A78u)
+#define MINIMCDS_TCX_DCSTS (0xFB71A000u)
+#define MINIMCDS_TCX_CIP (0xFB71A008u)
+#define MINIMCDS_TCX_CFT (0xFB71A00Cu)
+#define MINIMCDS_TCX_EA0_BND (0xFB71A400u)
+#define MINIMCDS_TCX_EA0_RNG (0xFB71A404u)
+#define MINIMCDS_TCX_EA1_BND (0xFB71A410u)
+#define MINIMCDS_TCX_EA1_RNG (0xFB71A414u)
+#define MINIMCDS_TCX_WD0_BND (0xFB71A480u)
+#define MINIMCDS_TCX_WD0_RNG (0xFB71A488u)
+#define MINIMCDS_TCX_WD0_MSK (0xFB71A490u)
+#define MINIMCDS_CLC (0xFB718000u)
+#define MINIMCDS_TCX_WD0_SGN (0xFB71A49Cu)
+#define MINIMCDS_TCX_WD1_BND (0xFB71A4A0u)
+#define MINIMCDS_TCX_WD1_RNG (0xFB71A4A8u)
+#define MINIMCDS_TCX_WD1_MSK (0xFB71A4B0u)
+#define MINIMCDS_TCX_WD1_SGN (0xFB71A4BCu)
+#define MINIMCDS_OCS (0xFB718004u)
+#define MINIMCDS_TCX_AC0_BND (0xFB71A500u)
+#define MINIMCDS_TCX_AC0_RNG (0xFB71A504u)
+#define MINIMCDS_TCX_AC0_MSK (0xFB71A508u)
+#define MINIMCDS_TCX_AC1_BND (0xFB71A518u)
+#define MINIMCDS_ID (0xFB718008u)
+#define MINIMCDS_TCX_AC1_RNG (0xFB71A51Cu)
+#define MINIMCDS_TCX_AC1_MSK (0xFB71A520u)
+#define MINIMCDS_TCX_IP0_BND (0xFB71B000u)
+#define MINIMCDS_TCX_IP0_RNG (0xFB71B004u)
+#define MINIMCDS_CT (0xFB718010u)
+#define MINIMCDS_TCX_IP1_BND (0xFB71B010u)
+#define MINIMCDS_TCX_IP1_RNG (0xFB71B014u)
+#define MINIMCDS_MUX (0xFB718014u)
+#define MINIMCDS_MUX_TC_RC (0xFB718020u)
+#define MINIMCDS_FIFONOW (0xFB718200u)
+#define MINIMCDS_FIFOBOT (0xFB718204u)
+#define MSC0_DC (0xF0002620u)
+#define MSC0_DSDSL (0xF0002624u)
+#define MSC0_DSDSH (0xF0002628u)
+#define MSC0_ESR (0xF000262Cu)
+#define MSC0_UD0 (0xF0002630u)
+#define MSC0_UD1 (0xF0002634u)
+#define MSC0_UD2 (0xF0002638u)
+#define MSC0_UD3 (0xF000263Cu)
+#define MSC0_ICR (0xF0002640u)
+#define MSC0_ISR (0xF0002644u)
+#define MSC0_ISC (0xF0002648u)
+#define MSC0_OCR (0xF000264Cu)
+#define MSC0_DSCE (0xF0002658u)
+#define MSC0_USCE (0xF000265Cu)
+#define MSC0_DSDSLE (0xF0002660u)
+#define MSC0_DSDSHE (0xF0002664u)
+#define MSC0_ESRE (0xF0002668u)
+#define MSC0_DSTE (0xF000266Cu)
+#define MSC0_DDM (0xF0002670u)
+#define MSC0_DDE (0xF0002674u)
+#define MSC0_DCM (0xF0002678u)
+#define MSC0_DCE (0xF000267Cu)
+#define MSC0_ABC (0xF0002680u)
+#define MSC0_OCS (0xF00026E8u)
+#define MSC0_KRSTCLR (0xF00026ECu)
+#define MSC0_KRST1 (0xF00026F0u)
+#define MSC0_KRST0 (0xF00026F4u)
+#define MSC0_ACCEN1 (0xF00026F8u)
+#define MSC0_ACCEN0 (0xF00026FCu)
+#define MSC1_CLC (0xF0002700u)
+#define MSC1_ID (0xF0002708u)
+#define MSC1_FDR (0xF000270Cu)
+#define MSC1_USR (0xF0002710u)
+#define MSC1_DSC (0xF0002714u)
+#define MSC1_DSS (0xF0002718u)
+#define MSC1_DD (0xF000271Cu)
+#define MSC1_DC (0xF0002720u)
+#define MSC1_DSDSL (0xF0002724u)
+#define MSC1_DSDSH (0xF0002728u)
+#define MSC1_ESR (0xF000272Cu)
+#define MSC1_UD0 (0xF0002730u)
+#define MSC1_UD1 (0xF0002734u)
+#define MSC1_UD2 (0xF0002738u)
+#define MSC1_UD3 (0xF000273Cu)
+#define MSC1_ICR (0xF0002740u)
+#define MSC1_ISR (0xF0002744u)
+#define MSC1_ISC (0xF0002748u)
+#define MSC1_OCR (0xF000274Cu)
+#define MSC1_DSCE (0xF0002758u)
+#define MSC1_USCE (0xF000275Cu)
+#define MSC1_DSDSLE (0xF0002760u)
+#define MSC1_DSDSHE (0xF0002764u)
+#define MSC1_ESRE (0xF0002768u)
+#define MSC1_DSTE (0xF000276Cu)
+#define MSC1_DDM (0xF0002770u)
+#define MSC1_DDE (0xF0002774u)
+#define MSC1_DCM (0xF0002778u)
+#define MSC1_DCE (0xF000277Cu)
+#define MSC1_ABC (0xF0002780u)
+#define MSC1_OCS (0xF00027E8u)
+#define MSC1_KRSTCLR (0xF00027ECu)
+#define MSC1_KRST1 (0xF00027F0u)
+#define MSC1_KRST0 (0xF00027F4u)
+#define MSC1_ACCEN1 (0xF00027F8u)
+#define MSC1_ACCEN0 (0xF00027FCu)
+#define MODULE_MSC0 (0xF0002600u)
+#define MODULE_MSC1 (0xF0002700u)
+#define MSC0_CLC (0xF0002600u)
+#define MSC0_ID (0xF0002608u)
+#define MSC0_FDR (0xF000260Cu)
+#define MSC0_USR (0xF0002610u)
+#define MSC0_DSC (0xF0002614u)
+#define MSC0_DSS (0xF0002618u)
+#define MSC0_DD (0xF000261Cu)
+#define MTU_MC37_RDBFL59 (0xF00635D6u)
+#define MTU_MC37_RDBFL60 (0xF00635D8u)
+#define MTU_MC37_RDBFL61 (0xF00635DAu)
+#define MTU_MC37_RDBFL62 (0xF00635DCu)
+#define MTU_MC3_RDBFL17 (0xF0061382u)
+#define MTU_MC37_RDBFL63 (0xF00635DEu)
+#define MTU_MC37_RDBFL64 (0xF00635E0u)
+#define MTU_MC37_RDBFL65 (0xF00635E2u)
+#define MTU_MC37_RDBFL66 (0xF00635E4u)
+#define MTU_MC37_ALMSRCS (0xF00635EEu)
+#define MTU_MC37_FAULTSTS (0xF00635F0u)
+#define MTU_MC37_ERRINFO0 (0xF00635F2u)
+#define MTU_MC37_ERRINFO1 (0xF00635F4u)
+#define MTU_MC37_ERRINFO2 (0xF00635F6u)
+#define MTU_MC37_ERRINFO3 (0xF00635F8u)
+#define MTU_MC3_RDBFL18 (0xF0061384u)
+#define MTU_MC37_ERRINFO4 (0xF00635FAu)
+#define MTU_MC38_CONFIG0 (0xF0063600u)
+#define MTU_MC38_CONFIG1 (0xF0063602u)
+#define MTU_MC38_MCONTROL (0xF0063604u)
+#define MTU_MC38_MSTATUS (0xF0063606u)
+#define MTU_MC38_RANGE (0xF0063608u)
+#define MTU_MC38_REVID (0xF006360Cu)
+#define MTU_MC38_ECCS (0xF006360Eu)
+#define MTU_MC38_ECCD (0xF0063610u)
+#define MTU_MC38_ETRR0 (0xF0063612u)
+#define MTU_MC3_RDBFL19 (0xF0061386u)
+#define MTU_MC38_ETRR1 (0xF0063614u)
+#define MTU_MC38_ETRR2 (0xF0063616u)
+#define MTU_MC38_ETRR3 (0xF0063618u)
+#define MTU_MC38_ETRR4 (0xF006361Au)
+#define MTU_MC38_RDBFL0 (0xF0063660u)
+#define MTU_MC38_RDBFL1 (0xF0063662u)
+#define MTU_MC38_RDBFL2 (0xF0063664u)
+#define MTU_MC38_RDBFL3 (0xF0063666u)
+#define MTU_MC38_RDBFL4 (0xF0063668u)
+#define MTU_MC38_RDBFL5 (0xF006366Au)
+#define MTU_MC3_RDBFL20 (0xF0061388u)
+#define MTU_MEMSTAT2 (0xF0060040u)
+#define MTU_MC38_RDBFL6 (0xF006366Cu)
+#define MTU_MC38_RDBFL7 (0xF006366Eu)
+#define MTU_MC38_RDBFL8 (0xF0063670u)
+#define MTU_MC38_RDBFL9 (0xF0063672u)
+#define MTU_MC38_RDBFL10 (0xF0063674u)
+#define MTU_MC38_RDBFL11 (0xF0063676u)
+#define MTU_MC38_RDBFL12 (0xF0063678u)
+#define MTU_MC38_RDBFL13 (0xF006367Au)
+#define MTU_MC38_RDBFL14 (0xF006367Cu)
+#define MTU_MC38_RDBFL15 (0xF006367Eu)
+#define MTU_MC3_RDBFL21 (0xF006138Au)
+#define MTU_MC38_RDBFL16 (0xF0063680u)
+#define MTU_MC38_RDBFL17 (0xF0063682u)
+#define MTU_MC38_RDBFL18 (0xF0063684u)
+#define MTU_MC38_RDBFL19 (0xF0063686u)
+#define MTU_MC38_RDBFL20 (0xF0063688u)
+#define MTU_MC38_RDBFL21 (0xF006368Au)
+#define MTU_MC38_RDBFL22 (0xF006368Cu)
+#define MTU_MC38_RDBFL23 (0xF006368Eu)
+#define MTU_MC38_RDBFL24 (0xF0063690u)
+#define MTU_MC38_RDBFL25 (0xF0063692u)
+#define MTU_MC3_RDBFL22 (0xF006138Cu)
+#define MTU_MC38_RDBFL26 (0xF0063694u)
+#define MTU_MC38_RDBFL27 (0xF0063696u)
+#define MTU_MC38_RDBFL28 (0xF0063698u)
+#define MTU_MC38_RDBFL29 (0xF006369Au)
+#define MTU_MC38_RDBFL30 (0xF006369Cu)
+#define MTU_MC38_RDBFL31 (0xF006369Eu)
+#define MTU_MC38_RDBFL32 (0xF00636A0u)
+#define MTU_MC38_RDBFL33 (0xF00636A2u)
+#define MTU_MC38_RDBFL34 (0xF00636A4u)
+#define MTU_MC38_RDBFL35 (0xF00636A6u)
+#define MTU_MC3_RDBFL23 (0xF006138Eu)
+#define MTU_MC38_RDBFL36 (0xF00636A8u)
+#define MTU_MC38_RDBFL37 (0xF00636AAu)
+#define MTU_MC38_RDBFL38 (0xF00636ACu)
+#define MTU_MC38_RDBFL39 (0xF00636AEu)
+#define MTU_MC38_RDBFL40 (0xF00636B0u)
+#define MTU_MC38_RDBFL41 (0xF00636B2u)
+#define MTU_MC38_RDBFL42 (0xF00636B4u)
+#define MTU_MC38_RDBFL43 (0xF00636B6u)
+#define MTU_MC38_RDBFL44 (0xF00636B8u)
+#define MTU_MC38_RDBFL45 (0xF00636BAu)
+#define MTU_MC3_RDBFL24 (0xF0061390u)
+#define MTU_MC38_RDBFL46 (0xF00636BCu)
+#define MTU_MC38_RDBFL47 (0xF00636BEu)
+#define MTU_MC38_RDBFL48 (0xF00636C0u)
+#define MTU_MC38_RDBFL49 (0xF00636C2u)
+#define MTU_MC38_RDBFL50 (0xF00636C4u)
+#define MTU_MC38_RDBFL51 (0xF00636C6u)
+#define MTU_MC38_RDBFL52 (0xF00636C8u)
+#define MTU_MC38_RDBFL53 (0xF00636CAu)
+#define MTU_MC38_RDBFL54 (0xF00636CCu)
+#define MTU_MC38_RDBFL55 (0xF00636CEu)
+#define MTU_MC3_RDBFL25 (0xF0061392u)
+#define MTU_MC38_RDBFL56 (0xF00636D0u)
+#define MTU_MC38_RDBFL57 (0xF00636D2u)
+#define MTU_MC38_RDBFL58 (0xF00636D4u)
+#define MTU_MC38_RDBFL59 (0xF00636D6u)
+#define MTU_MC38_RDBFL60 (0xF00636D8u)
+#define MTU_MC38_RDBFL61 (0xF00636DAu)
+#define MTU_MC38_RDBFL62 (0xF00636DCu)
+#define MTU_MC38_RDBFL63 (0xF00636DEu)
+#define MTU_MC38_RDBFL64 (0xF00636E0u)
+#define MTU_MC38_RDBFL65 (0xF00636E2u)
+#define MTU_MC3_RDBFL26 (0xF0061394u)
+#define MTU_MC38_RDBFL66 (0xF00636E4u)
+#define MTU_MC38_ALMSRCS (0xF00636EEu)
+#define MTU_MC38_FAULTSTS (0xF00636F0u)
+#define MTU_MC38_ERRINFO0 (0xF00636F2u)
+#define MTU_MC38_ERRINFO1 (0xF00636F4u)
+#define MTU_MC38_ERRINFO2 (0xF00636F6u)
+#define MTU_MC38_ERRINFO3 (0xF00636F8u)
+#define MTU_MC38_ERRINFO4 (0xF00636FAu)
+#define MTU_MC39_CONFIG0 (0xF0063700u)
+#define MTU_MC39_CONFIG1 (0xF0063702u)
+#define MTU_MC3_RDBFL27 (0xF0061396u)
+#define MTU_MC39_MCONTROL (0xF0063704u)
+#define MTU_MC39_MSTATUS (0xF0063706u)
+#define MTU_MC39_RANGE (0xF0063708u)
+#define MTU_MC39_REVID (0xF006370Cu)
+#define MTU_MC39_ECCS (0xF006370Eu)
+#define MTU_MC39_ECCD (0xF0063710u)
+#define MTU_MC39_ETRR0 (0xF0063712u)
+#define MTU_MC39_ETRR1 (0xF0063714u)
+#define MTU_MC39_ETRR2 (0xF0063716u)
+#define MTU_MC39_ETRR3 (0xF0063718u)
+#define MTU_MC3_RDBFL28 (0xF0061398u)
+#define MTU_MC39_ETRR4 (0xF006371Au)
+#define MTU_MC39_RDBFL0 (0xF0063760u)
+#define MTU_MC39_RDBFL1 (0xF0063762u)
+#define MTU_MC39_RDBFL2 (0xF0063764u)
+#define MTU_MC39_RDBFL3 (0xF0063766u)
+#define MTU_MC39_RDBFL4 (0xF0063768u)
+#define MTU_MC39_RDBFL5 (0xF006376Au)
+#define MTU_MC39_RDBFL6 (0xF006376Cu)
+#define MTU_MC39_RDBFL7 (0xF006376Eu)
+#define MTU_MC39_RDBFL8 (0xF0063770u)
+#define MTU_MC3_RDBFL29 (0xF006139Au)
+#define MTU_MC39_RDBFL9 (0xF0063772u)
+#define MTU_MC39_RDBFL10 (0xF0063774u)
+#define MTU_MC39_RDBFL11 (0xF0063776u)
+#define MTU_MC39_RDBFL12 (0xF0063778u)
+#define MTU_MC39_RDBFL13 (0xF006377Au)
+#define MTU_MC39_RDBFL14 (0xF006377Cu)
+#define MTU_MC39_RDBFL15 (0xF006377Eu)
+#define MTU_MC39_RDBFL16 (0xF0063780u)
+#define MTU_MC39_RDBFL17 (0xF0063782u)
+#define MTU_MC39_RDBFL18 (0xF0063784u)
+#define MTU_MC3_RDBFL30 (0xF006139Cu)
+#define MTU_MEMDONE0 (0xF0060050u)
+#define MTU_MC39_RDBFL19 (0xF0063786u)
+#define MTU_MC39_RDBFL20 (0xF0063788u)
+#define MTU_MC39_RDBFL21 (0xF006378Au)
+#define MTU_MC39_RDBFL22 (0xF006378Cu)
+#define MTU_MC39_RDBFL23 (0xF006378Eu)
+#define MTU_MC39_RDBFL24 (0xF0063790u)
+#define MTU_MC39_RDBFL25 (0xF0063792u)
+#define MTU_MC39_RDBFL26 (0xF0063794u)
+#define MTU_MC39_RDBFL27 (0xF0063796u)
+#define MTU_MC39_RDBFL28 (0xF0063798u)
+#define MTU_MC3_RDBFL31 (0xF006139Eu)
+#define MTU_MC39_RDBFL29 (0xF006379Au)
+#define MTU_MC39_RDBFL30 (0xF006379Cu)
+#define MTU_MC39_RDBFL31 (0xF006379Eu)
+#define MTU_MC39_RDBFL32 (0xF00637A0u)
+#define MTU_MC39_RDBFL33 (0xF00637A2u)
+#define MTU_MC39_RDBFL34 (0xF00637A4u)
+#define MTU_MC39_RDBFL35 (0xF00637A6u)
+#define MTU_MC39_RDBFL36 (0xF00637A8u)
+#define MTU_MC39_RDBFL37 (0xF00637AAu)
+#define MTU_MC39_RDBFL38 (0xF00637ACu)
+#define MTU_MC3_RDBFL32 (0xF00613A0u)
+#define MTU_MC39_RDBFL39 (0xF00637AEu)
+#define MTU_MC39_RDBFL40 (0xF00637B0u)
+#define MTU_MC39_RDBFL41 (0xF00637B2u)
+#define MTU_MC39_RDBFL42 (0xF00637B4u)
+#define MTU_MC39_RDBFL43 (0xF00637B6u)
+#define MTU_MC39_RDBFL44 (0xF00637B8u)
+#define MTU_MC39_RDBFL45 (0xF00637BAu)
+#define MTU_MC39_RDBFL46 (0xF00637BCu)
+#define MTU_MC39_RDBFL47 (0xF00637BEu)
+#define MTU_MC39_RDBFL48 (0xF00637C0u)
+#define MTU_MC3_RDBFL33 (0xF00613A2u)
+#define MTU_MC39_RDBFL49 (0xF00637C2u)
+#define MTU_MC39_RDBFL50 (0xF00637C4u)
+#define MTU_MC39_RDBFL51 (0xF00637C6u)
+#define MTU_MC39_RDBFL52 (0xF00637C8u)
+#define MTU_MC39_RDBFL53 (0xF00637CAu)
+#define MTU_MC39_RDBFL54 (0xF00637CCu)
+#define MTU_MC39_RDBFL55 (0xF00637CEu)
+#define MTU_MC39_RDBFL56 (0xF00637D0u)
+#define MTU_MC39_RDBFL57 (0xF00637D2u)
+#define MTU_MC39_RDBFL58 (0xF00637D4u)
+#define MTU_MC3_RDBFL34 (0xF00613A4u)
+#define MTU_MC39_RDBFL59 (0xF00637D6u)
+#define MTU_MC39_RDBFL60 (0xF00637D8u)
+#define MTU_MC39_RDBFL61 (0xF00637DAu)
+#define MTU_MC39_RDBFL62 (0xF00637DCu)
+#define MTU_MC39_RDBFL63 (0xF00637DEu)
+#define MTU_MC39_RDBFL64 (0xF00637E0u)
+#define MTU_MC39_RDBFL65 (0xF00637E2u)
+#define MTU_MC39_RDBFL66 (0xF00637E4u)
+#define MTU_MC39_ALMSRCS (0xF00637EEu)
+#define MTU_MC39_FAULTSTS (0xF00637F0u)
+#define MTU_MC3_RDBFL35 (0xF00613A6u)
+#define MTU_MC39_ERRINFO0 (0xF00637F2u)
+#define MTU_MC39_ERRINFO1 (0xF00637F4u)
+#define MTU_MC39_ERRINFO2 (0xF00637F6u)
+#define MTU_MC39_ERRINFO3 (0xF00637F8u)
+#define MTU_MC39_ERRINFO4 (0xF00637FAu)
+#define MTU_MC40_CONFIG0 (0xF0063800u)
+#define MTU_MC40_CONFIG1 (0xF0063802u)
+#define MTU_MC40_MCONTROL (0xF0063804u)
+#define MTU_MC40_MSTATUS (0xF0063806u)
+#define MTU_MC40_RANGE (0xF0063808u)
+#define MTU_MC3_RDBFL36 (0xF00613A8u)
+#define MTU_MC40_REVID (0xF006380Cu)
+#define MTU_MC40_ECCS (0xF006380Eu)
+#define MTU_MC40_ECCD (0xF0063810u)
+#define MTU_MC40_ETRR0 (0xF0063812u)
+#define MTU_MC40_ETRR1 (0xF0063814u)
+#define MTU_MC40_ETRR2 (0xF0063816u)
+#define MTU_MC40_ETRR3 (0xF0063818u)
+#define MTU_MC40_ETRR4 (0xF006381Au)
+#define MTU_MC40_RDBFL0 (0xF0063860u)
+#define MTU_MC40_RDBFL1 (0xF0063862u)
+#define MTU_MC3_RDBFL37 (0xF00613AAu)
+#define MTU_MC40_RDBFL2 (0xF0063864u)
+#define MTU_MC40_RDBFL3 (0xF0063866u)
+#define MTU_MC40_RDBFL4 (0xF0063868u)
+#define MTU_MC40_RDBFL5 (0xF006386Au)
+#define MTU_MC40_RDBFL6 (0xF006386Cu)
+#define MTU_MC40_RDBFL7 (0xF006386Eu)
+#define MTU_MC40_RDBFL8 (0xF0063870u)
+#define MTU_MC40_RDBFL9 (0xF0063872u)
+#define MTU_MC40_RDBFL10 (0xF0063874u)
+#define MTU_MC40_RDBFL11 (0xF0063876u)
+#define MTU_MC3_RDBFL38 (0xF00613ACu)
+#define MTU_MC40_RDBFL12 (0xF0063878u)
+#define MTU_MC40_RDBFL13 (0xF006387Au)
+#define MTU_MC40_RDBFL14 (0xF006387Cu)
+#define MTU_MC40_RDBFL15 (0xF006387Eu)
+#define MTU_MC40_RDBFL16 (0xF0063880u)
+#define MTU_MC40_RDBFL17 (0xF0063882u)
+#define MTU_MC40_RDBFL18 (0xF0063884u)
+#define MTU_MC40_RDBFL19 (0xF0063886u)
+#define MTU_MC40_RDBFL20 (0xF0063888u)
+#define MTU_MC40_RDBFL21 (0xF006388Au)
+#define MTU_MC3_RDBFL39 (0xF00613AEu)
+#define MTU_MC40_RDBFL22 (0xF006388Cu)
+#define MTU_MC40_RDBFL23 (0xF006388Eu)
+#define MTU_MC40_RDBFL24 (0xF0063890u)
+#define MTU_MC40_RDBFL25 (0xF0063892u)
+#define MTU_MC40_RDBFL26 (0xF0063894u)
+#define MTU_MC40_RDBFL27 (0xF0063896u)
+#define MTU_MC40_RDBFL28 (0xF0063898u)
+#define MTU_MC40_RDBFL29 (0xF006389Au)
+#define MTU_MC40_RDBFL30 (0xF006389Cu)
+#define MTU_MC40_RDBFL31 (0xF006389Eu)
+#define MTU_MC3_RDBFL40 (0xF00613B0u)
+#define MTU_MEMDONE1 (0xF0060054u)
+#define MTU_MC40_RDBFL32 (0xF00638A0u)
+#define MTU_MC40_RDBFL33 (0xF00638A2u)
+#define MTU_MC40_RDBFL34 (0xF00638A4u)
+#define MTU_MC40_RDBFL35 (0xF00638A6u)
+#define MTU_MC40_RDBFL36 (0xF00638A8u)
+#define MTU_MC40_RDBFL37 (0xF00638AAu)
+#define MTU_MC40_RDBFL38 (0xF00638ACu)
+#define MTU_MC40_RDBFL39 (0xF00638AEu)
+#define MTU_MC40_RDBFL40 (0xF00638B0u)
+#define MTU_MC40_RDBFL41 (0xF00638B2u)
+#define MTU_MC3_RDBFL41 (0xF00613B2u)
+#define MTU_MC40_RDBFL42 (0xF00638B4u)
+#define MTU_MC40_RDBFL43 (0xF00638B6u)
+#define MTU_MC40_RDBFL44 (0xF00638B8u)
+#define MTU_MC40_RDBFL45 (0xF00638BAu)
+#define MTU_MC40_RDBFL46 (0xF00638BCu)
+#define MTU_MC40_RDBFL47 (0xF00638BEu)
+#define MTU_MC40_RDBFL48 (0xF00638C0u)
+#define MTU_MC40_RDBFL49 (0xF00638C2u)
+#define MTU_MC40_RDBFL50 (0xF00638C4u)
+#define MTU_MC40_RDBFL51 (0xF00638C6u)
+#define MTU_MC3_RDBFL42 (0xF00613B4u)
+#define MTU_MC40_RDBFL52 (0xF00638C8u)
+#define MTU_MC40_RDBFL53 (0xF00638CAu)
+#define MTU_MC40_RDBFL54 (0xF00638CCu)
+#define MTU_MC40_RDBFL55 (0xF00638CEu)
+#define MTU_MC40_RDBFL56 (0xF00638D0u)
+#define MTU_MC40_RDBFL57 (0xF00638D2u)
+#define MTU_MC40_RDBFL58 (0xF00638D4u)
+#define MTU_MC40_RDBFL59 (0xF00638D6u)
+#define MTU_MC40_RDBFL60 (0xF00638D8u)
+#define MTU_MC40_RDBFL61 (0xF00638DAu)
+#define MTU_MC3_RDBFL43 (0xF00613B6u)
+#define MTU_MC40_RDBFL62 (0xF00638DCu)
+#define MTU_MC40_RDBFL63 (0xF00638DEu)
+#define MTU_MC40_RDBFL64 (0xF00638E0u)
+#define MTU_MC40_RDBFL65 (0xF00638E2u)
+#define MTU_MC40_RDBFL66 (0xF00638E4u)
+#define MTU_MC40_ALMSRCS (0xF00638EEu)
+#define MTU_MC40_FAULTSTS (0xF00638F0u)
+#define MTU_MC40_ERRINFO0 (0xF00638F2u)
+#define MTU_MC40_ERRINFO1 (0xF00638F4u)
+#define MTU_MC40_ERRINFO2 (0xF00638F6u)
+#define MTU_MC3_RDBFL44 (0xF00613B8u)
+#define MTU_MC40_ERRINFO3 (0xF00638F8u)
+#define MTU_MC40_ERRINFO4 (0xF00638FAu)
+#define MTU_MC41_CONFIG0 (0xF0063900u)
+#define MTU_MC41_CONFIG1 (0xF0063902u)
+#define MTU_MC41_MCONTROL (0xF0063904u)
+#define MTU_MC41_MSTATUS (0xF0063906u)
+#define MTU_MC41_RANGE (0xF0063908u)
+#define MTU_MC41_REVID (0xF006390Cu)
+#define MTU_MC41_ECCS (0xF006390Eu)
+#define MTU_MC41_ECCD (0xF0063910u)
+#define MTU_MC3_RDBFL45 (0xF00613BAu)
+#define MTU_MC41_ETRR0 (0xF0063912u)
+#define MTU_MC41_ETRR1 (0xF0063914u)
+#define MTU_MC41_ETRR2 (0xF0063916u)
+#define MTU_MC41_ETRR3 (0xF0063918u)
+#define MTU_MC41_ETRR4 (0xF006391Au)
+#define MTU_MC41_RDBFL0 (0xF0063960u)
+#define MTU_MC41_RDBFL1 (0xF0063962u)
+#define MTU_MC41_RDBFL2 (0xF0063964u)
+#define MTU_MC41_RDBFL3 (0xF0063966u)
+#define MTU_MC41_RDBFL4 (0xF0063968u)
+#define MTU_MC3_RDBFL46 (0xF00613BCu)
+#define MTU_MC41_RDBFL5 (0xF006396Au)
+#define MTU_MC41_RDBFL6 (0xF006396Cu)
+#define MTU_MC41_RDBFL7 (0xF006396Eu)
+#define MTU_MC41_RDBFL8 (0xF0063970u)
+#define MTU_MC41_RDBFL9 (0xF0063972u)
+#define MTU_MC41_RDBFL10 (0xF0063974u)
+#define MTU_MC41_RDBFL11 (0xF0063976u)
+#define MTU_MC41_RDBFL12 (0xF0063978u)
+#define MTU_MC41_RDBFL13 (0xF006397Au)
+#define MTU_MC41_RDBFL14 (0xF006397Cu)
+#define MTU_MC3_RDBFL47 (0xF00613BEu)
+#define MTU_MC41_RDBFL15 (0xF006397Eu)
+#define MTU_MC41_RDBFL16 (0xF0063980u)
+#define MTU_MC41_RDBFL17 (0xF0063982u)
+#define MTU_MC41_RDBFL18 (0xF0063984u)
+#define MTU_MC41_RDBFL19 (0xF0063986u)
+#define MTU_MC41_RDBFL20 (0xF0063988u)
+#define MTU_MC41_RDBFL21 (0xF006398Au)
+#define MTU_MC41_RDBFL22 (0xF006398Cu)
+#define MTU_MC41_RDBFL23 (0xF006398Eu)
+#define MTU_MC41_RDBFL24 (0xF0063990u)
+#define MTU_MC3_RDBFL48 (0xF00613C0u)
+#define MTU_MC41_RDBFL25 (0xF0063992u)
+#define MTU_MC41_RDBFL26 (0xF0063994u)
+#define MTU_MC41_RDBFL27 (0xF0063996u)
+#define MTU_MC41_RDBFL28 (0xF0063998u)
+#define MTU_MC41_RDBFL29 (0xF006399Au)
+#define MTU_MC41_RDBFL30 (0xF006399Cu)
+#define MTU_MC41_RDBFL31 (0xF006399Eu)
+#define MTU_MC41_RDBFL32 (0xF00639A0u)
+#define MTU_MC41_RDBFL33 (0xF00639A2u)
+#define MTU_MC41_RDBFL34 (0xF00639A4u)
+#define MTU_MC3_RDBFL49 (0xF00613C2u)
+#define MTU_MC41_RDBFL35 (0xF00639A6u)
+#define MTU_MC41_RDBFL36 (0xF00639A8u)
+#define MTU_MC41_RDBFL37 (0xF00639AAu)
+#define MTU_MC41_RDBFL38 (0xF00639ACu)
+#define MTU_MC41_RDBFL39 (0xF00639AEu)
+#define MTU_MC41_RDBFL40 (0xF00639B0u)
+#define MTU_MC41_RDBFL41 (0xF00639B2u)
+#define MTU_MC41_RDBFL42 (0xF00639B4u)
+#define MTU_MC41_RDBFL43 (0xF00639B6u)
+#define MTU_MC41_RDBFL44 (0xF00639B8u)
+#define MTU_MC3_RDBFL50 (0xF00613C4u)
+#define MTU_MEMDONE2 (0xF0060058u)
+#define MTU_MC41_RDBFL45 (0xF00639BAu)
+#define MTU_MC41_RDBFL46 (0xF00639BCu)
+#define MTU_MC41_RDBFL47 (0xF00639BEu)
+#define MTU_MC41_RDBFL48 (0xF00639C0u)
+#define MTU_MC41_RDBFL49 (0xF00639C2u)
+#define MTU_MC41_RDBFL50 (0xF00639C4u)
+#define MTU_MC41_RDBFL51 (0xF00639C6u)
+#define MTU_MC41_RDBFL52 (0xF00639C8u)
+#define MTU_MC41_RDBFL53 (0xF00639CAu)
+#define MTU_MC41_RDBFL54 (0xF00639CCu)
+#define MTU_MC3_RDBFL51 (0xF00613C6u)
+#define MTU_MC41_RDBFL55 (0xF00639CEu)
+#define MTU_MC41_RDBFL56 (0xF00639D0u)
+#define MTU_MC41_RDBFL57 (0xF00639D2u)
+#define MTU_MC41_RDBFL58 (0xF00639D4u)
+#define MTU_MC41_RDBFL59 (0xF00639D6u)
+#define MTU_MC41_RDBFL60 (0xF00639D8u)
+#define MTU_MC41_RDBFL61 (0xF00639DAu)
+#define MTU_MC41_RDBFL62 (0xF00639DCu)
+#define MTU_MC41_RDBFL63 (0xF00639DEu)
+#define MTU_MC41_RDBFL64 (0xF00639E0u)
+#define MTU_MC3_RDBFL52 (0xF00613C8u)
+#define MTU_MC41_RDBFL65 (0xF00639E2u)
+#define MTU_MC41_RDBFL66 (0xF00639E4u)
+#define MTU_MC41_ALMSRCS (0xF00639EEu)
+#define MTU_MC41_FAULTSTS (0xF00639F0u)
+#define MTU_MC41_ERRINFO0 (0xF00639F2u)
+#define MTU_MC41_ERRINFO1 (0xF00639F4u)
+#define MTU_MC41_ERRINFO2 (0xF00639F6u)
+#define MTU_MC41_ERRINFO3 (0xF00639F8u)
+#define MTU_MC41_ERRINFO4 (0xF00639FAu)
+#define MTU_MC42_CONFIG0 (0xF0063A00u)
+#define MTU_MC3_RDBFL53 (0xF00613CAu)
+#define MTU_MC42_CONFIG1 (0xF0063A02u)
+#define MTU_MC42_MCONTROL (0xF0063A04u)
+#define MTU_MC42_MSTATUS (0xF0063A06u)
+#define MTU_MC42_RANGE (0xF0063A08u)
+#define MTU_MC42_REVID (0xF0063A0Cu)
+#define MTU_MC42_ECCS (0xF0063A0Eu)
+#define MTU_MC42_ECCD (0xF0063A10u)
+#define MTU_MC42_ETRR0 (0xF0063A12u)
+#define MTU_MC42_ETRR1 (0xF0063A14u)
+#define MTU_MC42_ETRR2 (0xF0063A16u)
+#define MTU_MC3_RDBFL54 (0xF00613CCu)
+#define MTU_MC42_ETRR3 (0xF0063A18u)
+#define MTU_MC42_ETRR4 (0xF0063A1Au)
+#define MTU_MC42_RDBFL0 (0xF0063A60u)
+#define MTU_MC42_RDBFL1 (0xF0063A62u)
+#define MTU_MC42_RDBFL2 (0xF0063A64u)
+#define MTU_MC42_RDBFL3 (0xF0063A66u)
+#define MTU_MC42_RDBFL4 (0xF0063A68u)
+#define MTU_MC42_RDBFL5 (0xF0063A6Au)
+#define MTU_MC42_RDBFL6 (0xF0063A6Cu)
+#define MTU_MC42_RDBFL7 (0xF0063A6Eu)
+#define MTU_MC3_RDBFL55 (0xF00613CEu)
+#define MTU_MC42_RDBFL8 (0xF0063A70u)
+#define MTU_MC42_RDBFL9 (0xF0063A72u)
+#define MTU_MC42_RDBFL10 (0xF0063A74u)
+#define MTU_MC42_RDBFL11 (0xF0063A76u)
+#define MTU_MC42_RDBFL12 (0xF0063A78u)
+#define MTU_MC42_RDBFL13 (0xF0063A7Au)
+#define MTU_MC42_RDBFL14 (0xF0063A7Cu)
+#define MTU_MC42_RDBFL15 (0xF0063A7Eu)
+#define MTU_MC42_RDBFL16 (0xF0063A80u)
+#define MTU_MC42_RDBFL17 (0xF0063A82u)
+#define MTU_MC3_RDBFL56 (0xF00613D0u)
+#define MTU_MC42_RDBFL18 (0xF0063A84u)
+#define MTU_MC42_RDBFL19 (0xF0063A86u)
+#define MTU_MC42_RDBFL20 (0xF0063A88u)
+#define MTU_MC42_RDBFL21 (0xF0063A8Au)
+#define MTU_MC42_RDBFL22 (0xF0063A8Cu)
+#define MTU_MC42_RDBFL23 (0xF0063A8Eu)
+#define MTU_MC42_RDBFL24 (0xF0063A90u)
+#define MTU_MC42_RDBFL25 (0xF0063A92u)
+#define MTU_MC42_RDBFL26 (0xF0063A94u)
+#define MTU_MC42_RDBFL27 (0xF0063A96u)
+#define MTU_MC3_RDBFL57 (0xF00613D2u)
+#define MTU_MC42_RDBFL28 (0xF0063A98u)
+#define MTU_MC42_RDBFL29 (0xF0063A9Au)
+#define MTU_MC42_RDBFL30 (0xF0063A9Cu)
+#define MTU_MC42_RDBFL31 (0xF0063A9Eu)
+#define MTU_MC42_RDBFL32 (0xF0063AA0u)
+#define MTU_MC42_RDBFL33 (0xF0063AA2u)
+#define MTU_MC42_RDBFL34 (0xF0063AA4u)
+#define MTU_MC42_RDBFL35 (0xF0063AA6u)
+#define MTU_MC42_RDBFL36 (0xF0063AA8u)
+#define MTU_MC42_RDBFL37 (0xF0063AAAu)
+#define MTU_MC3_RDBFL58 (0xF00613D4u)
+#define MTU_MC42_RDBFL38 (0xF0063AACu)
+#define MTU_MC42_RDBFL39 (0xF0063AAEu)
+#define MTU_MC42_RDBFL40 (0xF0063AB0u)
+#define MTU_MC42_RDBFL41 (0xF0063AB2u)
+#define MTU_MC42_RDBFL42 (0xF0063AB4u)
+#define MTU_MC42_RDBFL43 (0xF0063AB6u)
+#define MTU_MC42_RDBFL44 (0xF0063AB8u)
+#define MTU_MC42_RDBFL45 (0xF0063ABAu)
+#define MTU_MC42_RDBFL46 (0xF0063ABCu)
+#define MTU_MC42_RDBFL47 (0xF0063ABEu)
+#define MTU_MC3_RDBFL59 (0xF00613D6u)
+#define MTU_MC42_RDBFL48 (0xF0063AC0u)
+#define MTU_MC42_RDBFL49 (0xF0063AC2u)
+#define MTU_MC42_RDBFL50 (0xF0063AC4u)
+#define MTU_MC42_RDBFL51 (0xF0063AC6u)
+#define MTU_MC42_RDBFL52 (0xF0063AC8u)
+#define MTU_MC42_RDBFL53 (0xF0063ACAu)
+#define MTU_MC42_RDBFL54 (0xF0063ACCu)
+#define MTU_MC42_RDBFL55 (0xF0063ACEu)
+#define MTU_MC42_RDBFL56 (0xF0063AD0u)
+#define MTU_MC42_RDBFL57 (0xF0063AD2u)
+#define MTU_MC3_RDBFL60 (0xF00613D8u)
+#define MTU_MEMFDA0 (0xF0060060u)
+#define MTU_MC42_RDBFL58 (0xF0063AD4u)
+#define MTU_MC42_RDBFL59 (0xF0063AD6u)
+#define MTU_MC42_RDBFL60 (0xF0063AD8u)
+#define MTU_MC42_RDBFL61 (0xF0063ADAu)
+#define MTU_MC42_RDBFL62 (0xF0063ADCu)
+#define MTU_MC42_RDBFL63 (0xF0063ADEu)
+#define MTU_MC42_RDBFL64 (0xF0063AE0u)
+#define MTU_MC42_RDBFL65 (0xF0063AE2u)
+#define MTU_MC42_RDBFL66 (0xF0063AE4u)
+#define MTU_MC42_ALMSRCS (0xF0063AEEu)
+#define MTU_MC3_RDBFL61 (0xF00613DAu)
+#define MTU_MC42_FAULTSTS (0xF0063AF0u)
+#define MTU_MC42_ERRINFO0 (0xF0063AF2u)
+#define MTU_MC42_ERRINFO1 (0xF0063AF4u)
+#define MTU_MC42_ERRINFO2 (0xF0063AF6u)
+#define MTU_MC42_ERRINFO3 (0xF0063AF8u)
+#define MTU_MC42_ERRINFO4 (0xF0063AFAu)
+#define MTU_MC43_CONFIG0 (0xF0063B00u)
+#define MTU_MC43_CONFIG1 (0xF0063B02u)
+#define MTU_MC43_MCONTROL (0xF0063B04u)
+#define MTU_MC43_MSTATUS (0xF0063B06u)
+#define MTU_MC3_RDBFL62 (0xF00613DCu)
+#define MTU_MC43_RANGE (0xF0063B08u)
+#define MTU_MC43_REVID (0xF0063B0Cu)
+#define MTU_MC43_ECCS (0xF0063B0Eu)
+#define MTU_MC43_ECCD (0xF0063B10u)
+#define MTU_MC43_ETRR0 (0xF0063B12u)
+#define MTU_MC43_ETRR1 (0xF0063B14u)
+#define MTU_MC43_ETRR2 (0xF0063B16u)
+#define MTU_MC43_ETRR3 (0xF0063B18u)
+#define MTU_MC43_ETRR4 (0xF0063B1Au)
+#define MTU_MC43_RDBFL0 (0xF0063B60u)
+#define MTU_MC3_RDBFL63 (0xF00613DEu)
+#define MTU_MC43_RDBFL1 (0xF0063B62u)
+#define MTU_MC43_RDBFL2 (0xF0063B64u)
+#define MTU_MC43_RDBFL3 (0xF0063B66u)
+#define MTU_MC43_RDBFL4 (0xF0063B68u)
+#define MTU_MC43_RDBFL5 (0xF0063B6Au)
+#define MTU_MC43_RDBFL6 (0xF0063B6Cu)
+#define MTU_MC43_RDBFL7 (0xF0063B6Eu)
+#define MTU_MC43_RDBFL8 (0xF0063B70u)
+#define MTU_MC43_RDBFL9 (0xF0063B72u)
+#define MTU_MC43_RDBFL10 (0xF0063B74u)
+#define MTU_MC3_RDBFL64 (0xF00613E0u)
+#define MTU_MC43_RDBFL11 (0xF0063B76u)
+#define MTU_MC43_RDBFL12 (0xF0063B78u)
+#define MTU_MC43_RDBFL13 (0xF0063B7Au)
+#define MTU_MC43_RDBFL14 (0xF0063B7Cu)
+#define MTU_MC43_RDBFL15 (0xF0063B7Eu)
+#define MTU_MC43_RDBFL16 (0xF0063B80u)
+#define MTU_MC43_RDBFL17 (0xF0063B82u)
+#define MTU_MC43_RDBFL18 (0xF0063B84u)
+#define MTU_MC43_RDBFL19 (0xF0063B86u)
+#define MTU_MC43_RDBFL20 (0xF0063B88u)
+#define MTU_MC3_RDBFL65 (0xF00613E2u)
+#define MTU_MC43_RDBFL21 (0xF0063B8Au)
+#define MTU_MC43_RDBFL22 (0xF0063B8Cu)
+#define MTU_MC43_RDBFL23 (0xF0063B8Eu)
+#define MTU_MC43_RDBFL24 (0xF0063B90u)
+#define MTU_MC43_RDBFL25 (0xF0063B92u)
+#define MTU_MC43_RDBFL26 (0xF0063B94u)
+#define MTU_MC43_RDBFL27 (0xF0063B96u)
+#define MTU_MC43_RDBFL28 (0xF0063B98u)
+#define MTU_MC43_RDBFL29 (0xF0063B9Au)
+#define MTU_MC43_RDBFL30 (0xF0063B9Cu)
+#define MTU_MC3_RDBFL66 (0xF00613E4u)
+#define MTU_MC43_RDBFL31 (0xF0063B9Eu)
+#define MTU_MC43_RDBFL32 (0xF0063BA0u)
+#define MTU_MC43_RDBFL33 (0xF0063BA2u)
+#define MTU_MC43_RDBFL34 (0xF0063BA4u)
+#define MTU_MC43_RDBFL35 (0xF0063BA6u)
+#define MTU_MC43_RDBFL36 (0xF0063BA8u)
+#define MTU_MC43_RDBFL37 (0xF0063BAAu)
+#define MTU_MC43_RDBFL38 (0xF0063BACu)
+#define MTU_MC43_RDBFL39 (0xF0063BAEu)
+#define MTU_MC43_RDBFL40 (0xF0063BB0u)
+#define MTU_MC3_ALMSRCS (0xF00613EEu)
+#define MTU_MC43_RDBFL41 (0xF0063BB2u)
+#define MTU_MC43_RDBFL42 (0xF0063BB4u)
+#define MTU_MC43_RDBFL43 (0xF0063BB6u)
+#define MTU_MC43_RDBFL44 (0xF0063BB8u)
+#define MTU_MC43_RDBFL45 (0xF0063BBAu)
+#define MTU_MC43_RDBFL46 (0xF0063BBCu)
+#define MTU_MC43_RDBFL47 (0xF0063BBEu)
+#define MTU_MC43_RDBFL48 (0xF0063BC0u)
+#define MTU_MC43_RDBFL49 (0xF0063BC2u)
+#define MTU_MC43_RDBFL50 (0xF0063BC4u)
+#define MTU_MC3_FAULTSTS (0xF00613F0u)
+#define MTU_MC43_RDBFL51 (0xF0063BC6u)
+#define MTU_MC43_RDBFL52 (0xF0063BC8u)
+#define MTU_MC43_RDBFL53 (0xF0063BCAu)
+#define MTU_MC43_RDBFL54 (0xF0063BCCu)
+#define MTU_MC43_RDBFL55 (0xF0063BCEu)
+#define MTU_MC43_RDBFL56 (0xF0063BD0u)
+#define MTU_MC43_RDBFL57 (0xF0063BD2u)
+#define MTU_MC43_RDBFL58 (0xF0063BD4u)
+#define MTU_MC43_RDBFL59 (0xF0063BD6u)
+#define MTU_MC43_RDBFL60 (0xF0063BD8u)
+#define MTU_MC3_ERRINFO0 (0xF00613F2u)
+#define MTU_MC43_RDBFL61 (0xF0063BDAu)
+#define MTU_MC43_RDBFL62 (0xF0063BDCu)
+#define MTU_MC43_RDBFL63 (0xF0063BDEu)
+#define MTU_MC43_RDBFL64 (0xF0063BE0u)
+#define MTU_MC43_RDBFL65 (0xF0063BE2u)
+#define MTU_MC43_RDBFL66 (0xF0063BE4u)
+#define MTU_MC43_ALMSRCS (0xF0063BEEu)
+#define MTU_MC43_FAULTSTS (0xF0063BF0u)
+#define MTU_MC43_ERRINFO0 (0xF0063BF2u)
+#define MTU_MC43_ERRINFO1 (0xF0063BF4u)
+#define MTU_MC3_ERRINFO1 (0xF00613F4u)
+#define MTU_MEMFDA1 (0xF0060064u)
+#define MTU_MC43_ERRINFO2 (0xF0063BF6u)
+#define MTU_MC43_ERRINFO3 (0xF0063BF8u)
+#define MTU_MC43_ERRINFO4 (0xF0063BFAu)
+#define MTU_MC44_CONFIG0 (0xF0063C00u)
+#define MTU_MC44_CONFIG1 (0xF0063C02u)
+#define MTU_MC44_MCONTROL (0xF0063C04u)
+#define MTU_MC44_MSTATUS (0xF0063C06u)
+#define MTU_MC44_RANGE (0xF0063C08u)
+#define MTU_MC44_REVID (0xF0063C0Cu)
+#define MTU_MC44_ECCS (0xF0063C0Eu)
+#define MTU_MC3_ERRINFO2 (0xF00613F6u)
+#define MTU_MC44_ECCD (0xF0063C10u)
+#define MTU_MC44_ETRR0 (0xF0063C12u)
+#define MTU_MC44_ETRR1 (0xF0063C14u)
+#define MTU_MC44_ETRR2 (0xF0063C16u)
+#define MTU_MC44_ETRR3 (0xF0063C18u)
+#define MTU_MC44_ETRR4 (0xF0063C1Au)
+#define MTU_MC44_RDBFL0 (0xF0063C60u)
+#define MTU_MC44_RDBFL1 (0xF0063C62u)
+#define MTU_MC44_RDBFL2 (0xF0063C64u)
+#define MTU_MC44_RDBFL3 (0xF0063C66u)
+#define MTU_MC3_ERRINFO3 (0xF00613F8u)
+#define MTU_MC44_RDBFL4 (0xF0063C68u)
+#define MTU_MC44_RDBFL5 (0xF0063C6Au)
+#define MTU_MC44_RDBFL6 (0xF0063C6Cu)
+#define MTU_MC44_RDBFL7 (0xF0063C6Eu)
+#define MTU_MC44_RDBFL8 (0xF0063C70u)
+#define MTU_MC44_RDBFL9 (0xF0063C72u)
+#define MTU_MC44_RDBFL10 (0xF0063C74u)
+#define MTU_MC44_RDBFL11 (0xF0063C76u)
+#define MTU_MC44_RDBFL12 (0xF0063C78u)
+#define MTU_MC44_RDBFL13 (0xF0063C7Au)
+#define MTU_MC3_ERRINFO4 (0xF00613FAu)
+#define MTU_MC44_RDBFL14 (0xF0063C7Cu)
+#define MTU_MC44_RDBFL15 (0xF0063C7Eu)
+#define MTU_MC44_RDBFL16 (0xF0063C80u)
+#define MTU_MC44_RDBFL17 (0xF0063C82u)
+#define MTU_MC44_RDBFL18 (0xF0063C84u)
+#define MTU_MC44_RDBFL19 (0xF0063C86u)
+#define MTU_MC44_RDBFL20 (0xF0063C88u)
+#define MTU_MC44_RDBFL21 (0xF0063C8Au)
+#define MTU_MC44_RDBFL22 (0xF0063C8Cu)
+#define MTU_MC44_RDBFL23 (0xF0063C8Eu)
+#define MTU_MC4_CONFIG0 (0xF0061400u)
+#define MTU_MC44_RDBFL24 (0xF0063C90u)
+#define MTU_MC44_RDBFL25 (0xF0063C92u)
+#define MTU_MC44_RDBFL26 (0xF0063C94u)
+#define MTU_MC44_RDBFL27 (0xF0063C96u)
+#define MTU_MC44_RDBFL28 (0xF0063C98u)
+#define MTU_MC44_RDBFL29 (0xF0063C9Au)
+#define MTU_MC44_RDBFL30 (0xF0063C9Cu)
+#define MTU_MC44_RDBFL31 (0xF0063C9Eu)
+#define MTU_MC44_RDBFL32 (0xF0063CA0u)
+#define MTU_MC44_RDBFL33 (0xF0063CA2u)
+#define MTU_MC4_CONFIG1 (0xF0061402u)
+#define MTU_MC44_RDBFL34 (0xF0063CA4u)
+#define MTU_MC44_RDBFL35 (0xF0063CA6u)
+#define MTU_MC44_RDBFL36 (0xF0063CA8u)
+#define MTU_MC44_RDBFL37 (0xF0063CAAu)
+#define MTU_MC44_RDBFL38 (0xF0063CACu)
+#define MTU_MC44_RDBFL39 (0xF0063CAEu)
+#define MTU_MC44_RDBFL40 (0xF0063CB0u)
+#define MTU_MC44_RDBFL41 (0xF0063CB2u)
+#define MTU_MC44_RDBFL42 (0xF0063CB4u)
+#define MTU_MC44_RDBFL43 (0xF0063CB6u)
+#define MTU_MC4_MCONTROL (0xF0061404u)
+#define MTU_MC44_RDBFL44 (0xF0063CB8u)
+#define MTU_MC44_RDBFL45 (0xF0063CBAu)
+#define MTU_MC44_RDBFL46 (0xF0063CBCu)
+#define MTU_MC44_RDBFL47 (0xF0063CBEu)
+#define MTU_MC44_RDBFL48 (0xF0063CC0u)
+#define MTU_MC44_RDBFL49 (0xF0063CC2u)
+#define MTU_MC44_RDBFL50 (0xF0063CC4u)
+#define MTU_MC44_RDBFL51 (0xF0063CC6u)
+#define MTU_MC44_RDBFL52 (0xF0063CC8u)
+#define MTU_MC44_RDBFL53 (0xF0063CCAu)
+#define MTU_MC4_MSTATUS (0xF0061406u)
+#define MTU_MC44_RDBFL54 (0xF0063CCCu)
+#define MTU_MC44_RDBFL55 (0xF0063CCEu)
+#define MTU_MC44_RDBFL56 (0xF0063CD0u)
+#define MTU_MC44_RDBFL57 (0xF0063CD2u)
+#define MTU_MC44_RDBFL58 (0xF0063CD4u)
+#define MTU_MC44_RDBFL59 (0xF0063CD6u)
+#define MTU_MC44_RDBFL60 (0xF0063CD8u)
+#define MTU_MC44_RDBFL61 (0xF0063CDAu)
+#define MTU_MC44_RDBFL62 (0xF0063CDCu)
+#define MTU_MC44_RDBFL63 (0xF0063CDEu)
+#define MTU_MC4_RANGE (0xF0061408u)
+#define MTU_MC44_RDBFL64 (0xF0063CE0u)
+#define MTU_MC44_RDBFL65 (0xF0063CE2u)
+#define MTU_MC44_RDBFL66 (0xF0063CE4u)
+#define MTU_MC44_ALMSRCS (0xF0063CEEu)
+#define MTU_MC44_FAULTSTS (0xF0063CF0u)
+#define MTU_MC44_ERRINFO0 (0xF0063CF2u)
+#define MTU_MC44_ERRINFO1 (0xF0063CF4u)
+#define MTU_MC44_ERRINFO2 (0xF0063CF6u)
+#define MTU_MC44_ERRINFO3 (0xF0063CF8u)
+#define MTU_MC44_ERRINFO4 (0xF0063CFAu)
+#define MTU_MC4_REVID (0xF006140Cu)
+#define MTU_MC45_CONFIG0 (0xF0063D00u)
+#define MTU_MC45_CONFIG1 (0xF0063D02u)
+#define MTU_MC45_MCONTROL (0xF0063D04u)
+#define MTU_MC45_MSTATUS (0xF0063D06u)
+#define MTU_MC45_RANGE (0xF0063D08u)
+#define MTU_MC45_REVID (0xF0063D0Cu)
+#define MTU_MC45_ECCS (0xF0063D0Eu)
+#define MTU_MC45_ECCD (0xF0063D10u)
+#define MTU_MC45_ETRR0 (0xF0063D12u)
+#define MTU_MC45_ETRR1 (0xF0063D14u)
+#define MTU_MC4_ECCS (0xF006140Eu)
+#define MTU_MEMFDA2 (0xF0060068u)
+#define MTU_MC45_ETRR2 (0xF0063D16u)
+#define MTU_MC45_ETRR3 (0xF0063D18u)
+#define MTU_MC45_ETRR4 (0xF0063D1Au)
+#define MTU_MC45_RDBFL0 (0xF0063D60u)
+#define MTU_MC45_RDBFL1 (0xF0063D62u)
+#define MTU_MC45_RDBFL2 (0xF0063D64u)
+#define MTU_MC45_RDBFL3 (0xF0063D66u)
+#define MTU_MC45_RDBFL4 (0xF0063D68u)
+#define MTU_MC45_RDBFL5 (0xF0063D6Au)
+#define MTU_MC45_RDBFL6 (0xF0063D6Cu)
+#define MTU_MC4_ECCD (0xF0061410u)
+#define MTU_MC45_RDBFL7 (0xF0063D6Eu)
+#define MTU_MC45_RDBFL8 (0xF0063D70u)
+#define MTU_MC45_RDBFL9 (0xF0063D72u)
+#define MTU_MC45_RDBFL10 (0xF0063D74u)
+#define MTU_MC45_RDBFL11 (0xF0063D76u)
+#define MTU_MC45_RDBFL12 (0xF0063D78u)
+#define MTU_MC45_RDBFL13 (0xF0063D7Au)
+#define MTU_MC45_RDBFL14 (0xF0063D7Cu)
+#define MTU_MC45_RDBFL15 (0xF0063D7Eu)
+#define MTU_MC45_RDBFL16 (0xF0063D80u)
+#define MTU_MC4_ETRR0 (0xF0061412u)
+#define MTU_MC45_RDBFL17 (0xF0063D82u)
+#define MTU_MC45_RDBFL18 (0xF0063D84u)
+#define MTU_MC45_RDBFL19 (0xF0063D86u)
+#define MTU_MC45_RDBFL20 (0xF0063D88u)
+#define MTU_MC45_RDBFL21 (0xF0063D8Au)
+#define MTU_MC45_RDBFL22 (0xF0063D8Cu)
+#define MTU_MC45_RDBFL23 (0xF0063D8Eu)
+#define MTU_MC45_RDBFL24 (0xF0063D90u)
+#define MTU_MC45_RDBFL25 (0xF0063D92u)
+#define MTU_MC45_RDBFL26 (0xF0063D94u)
+#define MTU_MC4_ETRR1 (0xF0061414u)
+#define MTU_MC45_RDBFL27 (0xF0063D96u)
+#define MTU_MC45_RDBFL28 (0xF0063D98u)
+#define MTU_MC45_RDBFL29 (0xF0063D9Au)
+#define MTU_MC45_RDBFL30 (0xF0063D9Cu)
+#define MTU_MC45_RDBFL31 (0xF0063D9Eu)
+#define MTU_MC45_RDBFL32 (0xF0063DA0u)
+#define MTU_MC45_RDBFL33 (0xF0063DA2u)
+#define MTU_MC45_RDBFL34 (0xF0063DA4u)
+#define MTU_MC45_RDBFL35 (0xF0063DA6u)
+#define MTU_MC45_RDBFL36 (0xF0063DA8u)
+#define MTU_MC4_ETRR2 (0xF0061416u)
+#define MTU_MC45_RDBFL37 (0xF0063DAAu)
+#define MTU_MC45_RDBFL38 (0xF0063DACu)
+#define MTU_MC45_RDBFL39 (0xF0063DAEu)
+#define MTU_MC45_RDBFL40 (0xF0063DB0u)
+#define MTU_MC45_RDBFL41 (0xF0063DB2u)
+#define MTU_MC45_RDBFL42 (0xF0063DB4u)
+#define MTU_MC45_RDBFL43 (0xF0063DB6u)
+#define MTU_MC45_RDBFL44 (0xF0063DB8u)
+#define MTU_MC45_RDBFL45 (0xF0063DBAu)
+#define MTU_MC45_RDBFL46 (0xF0063DBCu)
+#define MTU_MC4_ETRR3 (0xF0061418u)
+#define MTU_MC45_RDBFL47 (0xF0063DBEu)
+#define MTU_MC45_RDBFL48 (0xF0063DC0u)
+#define MTU_MC45_RDBFL49 (0xF0063DC2u)
+#define MTU_MC45_RDBFL50 (0xF0063DC4u)
+#define MTU_MC45_RDBFL51 (0xF0063DC6u)
+#define MTU_MC45_RDBFL52 (0xF0063DC8u)
+#define MTU_MC45_RDBFL53 (0xF0063DCAu)
+#define MTU_MC45_RDBFL54 (0xF0063DCCu)
+#define MTU_MC45_RDBFL55 (0xF0063DCEu)
+#define MTU_MC45_RDBFL56 (0xF0063DD0u)
+#define MTU_MC4_ETRR4 (0xF006141Au)
+#define MTU_MC45_RDBFL57 (0xF0063DD2u)
+#define MTU_MC45_RDBFL58 (0xF0063DD4u)
+#define MTU_MC45_RDBFL59 (0xF0063DD6u)
+#define MTU_MC45_RDBFL60 (0xF0063DD8u)
+#define MTU_MC45_RDBFL61 (0xF0063DDAu)
+#define MTU_MC45_RDBFL62 (0xF0063DDCu)
+#define MTU_MC45_RDBFL63 (0xF0063DDEu)
+#define MTU_MC45_RDBFL64 (0xF0063DE0u)
+#define MTU_MC45_RDBFL65 (0xF0063DE2u)
+#define MTU_MC45_RDBFL66 (0xF0063DE4u)
+#define MTU_MC4_RDBFL0 (0xF0061460u)
+#define MTU_MC45_ALMSRCS (0xF0063DEEu)
+#define MTU_MC45_FAULTSTS (0xF0063DF0u)
+#define MTU_MC45_ERRINFO0 (0xF0063DF2u)
+#define MTU_MC45_ERRINFO1 (0xF0063DF4u)
+#define MTU_MC45_ERRINFO2 (0xF0063DF6u)
+#define MTU_MC45_ERRINFO3 (0xF0063DF8u)
+#define MTU_MC45_ERRINFO4 (0xF0063DFAu)
+#define MTU_MC46_CONFIG0 (0xF0063E00u)
+#define MTU_MC46_CONFIG1 (0xF0063E02u)
+#define MTU_MC46_MCONTROL (0xF0063E04u)
+#define MTU_MC4_RDBFL1 (0xF0061462u)
+#define MTU_MC46_MSTATUS (0xF0063E06u)
+#define MTU_MC46_RANGE (0xF0063E08u)
+#define MTU_MC46_REVID (0xF0063E0Cu)
+#define MTU_MC46_ECCS (0xF0063E0Eu)
+#define MTU_MC46_ECCD (0xF0063E10u)
+#define MTU_MC46_ETRR0 (0xF0063E12u)
+#define MTU_MC46_ETRR1 (0xF0063E14u)
+#define MTU_MC46_ETRR2 (0xF0063E16u)
+#define MTU_MC46_ETRR3 (0xF0063E18u)
+#define MTU_MC46_ETRR4 (0xF0063E1Au)
+#define MTU_MC4_RDBFL2 (0xF0061464u)
+#define MTU_MC46_RDBFL0 (0xF0063E60u)
+#define MTU_MC46_RDBFL1 (0xF0063E62u)
+#define MTU_MC46_RDBFL2 (0xF0063E64u)
+#define MTU_MC46_RDBFL3 (0xF0063E66u)
+#define MTU_MC46_RDBFL4 (0xF0063E68u)
+#define MTU_MC46_RDBFL5 (0xF0063E6Au)
+#define MTU_MC46_RDBFL6 (0xF0063E6Cu)
+#define MTU_MC46_RDBFL7 (0xF0063E6Eu)
+#define MTU_MC46_RDBFL8 (0xF0063E70u)
+#define MTU_MC46_RDBFL9 (0xF0063E72u)
+#define MTU_MC4_RDBFL3 (0xF0061466u)
+#define MTU_ACCEN1 (0xF00600F8u)
+#define MTU_MC46_RDBFL10 (0xF0063E74u)
+#define MTU_MC46_RDBFL11 (0xF0063E76u)
+#define MTU_MC46_RDBFL12 (0xF0063E78u)
+#define MTU_MC46_RDBFL13 (0xF0063E7Au)
+#define MTU_MC46_RDBFL14 (0xF0063E7Cu)
+#define MTU_MC46_RDBFL15 (0xF0063E7Eu)
+#define MTU_MC46_RDBFL16 (0xF0063E80u)
+#define MTU_MC46_RDBFL17 (0xF0063E82u)
+#define MTU_MC46_RDBFL18 (0xF0063E84u)
+#define MTU_MC46_RDBFL19 (0xF0063E86u)
+#define MTU_MC4_RDBFL4 (0xF0061468u)
+#define MTU_MC46_RDBFL20 (0xF0063E88u)
+#define MTU_MC46_RDBFL21 (0xF0063E8Au)
+#define MTU_MC46_RDBFL22 (0xF0063E8Cu)
+#define MTU_MC46_RDBFL23 (0xF0063E8Eu)
+#define MTU_MC46_RDBFL24 (0xF0063E90u)
+#define MTU_MC46_RDBFL25 (0xF0063E92u)
+#define MTU_MC46_RDBFL26 (0xF0063E94u)
+#define MTU_MC46_RDBFL27 (0xF0063E96u)
+#define MTU_MC46_RDBFL28 (0xF0063E98u)
+#define MTU_MC46_RDBFL29 (0xF0063E9Au)
+#define MTU_MC4_RDBFL5 (0xF006146Au)
+#define MTU_MC46_RDBFL30 (0xF0063E9Cu)
+#define MTU_MC46_RDBFL31 (0xF0063E9Eu)
+#define MTU_MC46_RDBFL32 (0xF0063EA0u)
+#define MTU_MC46_RDBFL33 (0xF0063EA2u)
+#define MTU_MC46_RDBFL34 (0xF0063EA4u)
+#define MTU_MC46_RDBFL35 (0xF0063EA6u)
+#define MTU_MC46_RDBFL36 (0xF0063EA8u)
+#define MTU_MC46_RDBFL37 (0xF0063EAAu)
+#define MTU_MC46_RDBFL38 (0xF0063EACu)
+#define MTU_MC46_RDBFL39 (0xF0063EAEu)
+#define MTU_MC4_RDBFL6 (0xF006146Cu)
+#define MTU_MC46_RDBFL40 (0xF0063EB0u)
+#define MTU_MC46_RDBFL41 (0xF0063EB2u)
+#define MTU_MC46_RDBFL42 (0xF0063EB4u)
+#define MTU_MC46_RDBFL43 (0xF0063EB6u)
+#define MTU_MC46_RDBFL44 (0xF0063EB8u)
+#define MTU_MC46_RDBFL45 (0xF0063EBAu)
+#define MTU_MC46_RDBFL46 (0xF0063EBCu)
+#define MTU_MC46_RDBFL47 (0xF0063EBEu)
+#define MTU_MC46_RDBFL48 (0xF0063EC0u)
+#define MTU_MC46_RDBFL49 (0xF0063EC2u)
+#define MTU_MC4_RDBFL7 (0xF006146Eu)
+#define MTU_MC46_RDBFL50 (0xF0063EC4u)
+#define MTU_MC46_RDBFL51 (0xF0063EC6u)
+#define MTU_MC46_RDBFL52 (0xF0063EC8u)
+#define MTU_MC46_RDBFL53 (0xF0063ECAu)
+#define MTU_MC46_RDBFL54 (0xF0063ECCu)
+#define MTU_MC46_RDBFL55 (0xF0063ECEu)
+#define MTU_MC46_RDBFL56 (0xF0063ED0u)
+#define MTU_MC46_RDBFL57 (0xF0063ED2u)
+#define MTU_MC46_RDBFL58 (0xF0063ED4u)
+#define MTU_MC46_RDBFL59 (0xF0063ED6u)
+#define MTU_MC4_RDBFL8 (0xF0061470u)
+#define MTU_MC46_RDBFL60 (0xF0063ED8u)
+#define MTU_MC46_RDBFL61 (0xF0063EDAu)
+#define MTU_MC46_RDBFL62 (0xF0063EDCu)
+#define MTU_MC46_RDBFL63 (0xF0063EDEu)
+#define MTU_MC46_RDBFL64 (0xF0063EE0u)
+#define MTU_MC46_RDBFL65 (0xF0063EE2u)
+#define MTU_MC46_RDBFL66 (0xF0063EE4u)
+#define MTU_MC46_ALMSRCS (0xF0063EEEu)
+#define MTU_MC46_FAULTSTS (0xF0063EF0u)
+#define MTU_MC46_ERRINFO0 (0xF0063EF2u)
+#define MTU_MC4_RDBFL9 (0xF0061472u)
+#define MTU_MC46_ERRINFO1 (0xF0063EF4u)
+#define MTU_MC46_ERRINFO2 (0xF0063EF6u)
+#define MTU_MC46_ERRINFO3 (0xF0063EF8u)
+#define MTU_MC46_ERRINFO4 (0xF0063EFAu)
+#define MTU_MC47_CONFIG0 (0xF0063F00u)
+#define MTU_MC47_CONFIG1 (0xF0063F02u)
+#define MTU_MC47_MCONTROL (0xF0063F04u)
+#define MTU_MC47_MSTATUS (0xF0063F06u)
+#define MTU_MC47_RANGE (0xF0063F08u)
+#define MTU_MC47_REVID (0xF0063F0Cu)
+#define MTU_MC4_RDBFL10 (0xF0061474u)
+#define MTU_MC47_ECCS (0xF0063F0Eu)
+#define MTU_MC47_ECCD (0xF0063F10u)
+#define MTU_MC47_ETRR0 (0xF0063F12u)
+#define MTU_MC47_ETRR1 (0xF0063F14u)
+#define MTU_MC47_ETRR2 (0xF0063F16u)
+#define MTU_MC47_ETRR3 (0xF0063F18u)
+#define MTU_MC47_ETRR4 (0xF0063F1Au)
+#define MTU_MC47_RDBFL0 (0xF0063F60u)
+#define MTU_MC47_RDBFL1 (0xF0063F62u)
+#define MTU_MC47_RDBFL2 (0xF0063F64u)
+#define MTU_MC4_RDBFL11 (0xF0061476u)
+#define MTU_MC47_RDBFL3 (0xF0063F66u)
+#define MTU_MC47_RDBFL4 (0xF0063F68u)
+#define MTU_MC47_RDBFL5 (0xF0063F6Au)
+#define MTU_MC47_RDBFL6 (0xF0063F6Cu)
+#define MTU_MC47_RDBFL7 (0xF0063F6Eu)
+#define MTU_MC47_RDBFL8 (0xF0063F70u)
+#define MTU_MC47_RDBFL9 (0xF0063F72u)
+#define MTU_MC47_RDBFL10 (0xF0063F74u)
+#define MTU_MC47_RDBFL11 (0xF0063F76u)
+#define MTU_MC47_RDBFL12 (0xF0063F78u)
+#define MTU_MC4_RDBFL12 (0xF0061478u)
+#define MTU_MC47_RDBFL13 (0xF0063F7Au)
+#define MTU_MC47_RDBFL14 (0xF0063F7Cu)
+#define MTU_MC47_RDBFL15 (0xF0063F7Eu)
+#define MTU_MC47_RDBFL16 (0xF0063F80u)
+#define MTU_MC47_RDBFL17 (0xF0063F82u)
+#define MTU_MC47_RDBFL18 (0xF0063F84u)
+#define MTU_MC47_RDBFL19 (0xF0063F86u)
+#define MTU_MC47_RDBFL20 (0xF0063F88u)
+#define MTU_MC47_RDBFL21 (0xF0063F8Au)
+#define MTU_MC47_RDBFL22 (0xF0063F8Cu)
+#define MTU_MC4_RDBFL13 (0xF006147Au)
+#define MTU_ACCEN0 (0xF00600FCu)
+#define MTU_MC47_RDBFL23 (0xF0063F8Eu)
+#define MTU_MC47_RDBFL24 (0xF0063F90u)
+#define MTU_MC47_RDBFL25 (0xF0063F92u)
+#define MTU_MC47_RDBFL26 (0xF0063F94u)
+#define MTU_MC47_RDBFL27 (0xF0063F96u)
+#define MTU_MC47_RDBFL28 (0xF0063F98u)
+#define MTU_MC47_RDBFL29 (0xF0063F9Au)
+#define MTU_MC47_RDBFL30 (0xF0063F9Cu)
+#define MTU_MC47_RDBFL31 (0xF0063F9Eu)
+#define MTU_MC47_RDBFL32 (0xF0063FA0u)
+#define MTU_MC4_RDBFL14 (0xF006147Cu)
+#define MTU_MC47_RDBFL33 (0xF0063FA2u)
+#define MTU_MC47_RDBFL34 (0xF0063FA4u)
+#define MTU_MC47_RDBFL35 (0xF0063FA6u)
+#define MTU_MC47_RDBFL36 (0xF0063FA8u)
+#define MTU_MC47_RDBFL37 (0xF0063FAAu)
+#define MTU_MC47_RDBFL38 (0xF0063FACu)
+#define MTU_MC47_RDBFL39 (0xF0063FAEu)
+#define MTU_MC47_RDBFL40 (0xF0063FB0u)
+#define MTU_MC47_RDBFL41 (0xF0063FB2u)
+#define MTU_MC47_RDBFL42 (0xF0063FB4u)
+#define MTU_MC4_RDBFL15 (0xF006147Eu)
+#define MTU_MC47_RDBFL43 (0xF0063FB6u)
+#define MTU_MC47_RDBFL44 (0xF0063FB8u)
+#define MTU_MC47_RDBFL45 (0xF0063FBAu)
+#define MTU_MC47_RDBFL46 (0xF0063FBCu)
+#define MTU_MC47_RDBFL47 (0xF0063FBEu)
+#define MTU_MC47_RDBFL48 (0xF0063FC0u)
+#define MTU_MC47_RDBFL49 (0xF0063FC2u)
+#define MTU_MC47_RDBFL50 (0xF0063FC4u)
+#define MTU_MC47_RDBFL51 (0xF0063FC6u)
+#define MTU_MC47_RDBFL52 (0xF0063FC8u)
+#define MTU_MC4_RDBFL16 (0xF0061480u)
+#define MTU_MC47_RDBFL53 (0xF0063FCAu)
+#define MTU_MC47_RDBFL54 (0xF0063FCCu)
+#define MTU_MC47_RDBFL55 (0xF0063FCEu)
+#define MTU_MC47_RDBFL56 (0xF0063FD0u)
+#define MTU_MC47_RDBFL57 (0xF0063FD2u)
+#define MTU_MC47_RDBFL58 (0xF0063FD4u)
+#define MTU_MC47_RDBFL59 (0xF0063FD6u)
+#define MTU_MC47_RDBFL60 (0xF0063FD8u)
+#define MTU_MC47_RDBFL61 (0xF0063FDAu)
+#define MTU_MC47_RDBFL62 (0xF0063FDCu)
+#define MTU_MC4_RDBFL17 (0xF0061482u)
+#define MTU_MC47_RDBFL63 (0xF0063FDEu)
+#define MTU_MC47_RDBFL64 (0xF0063FE0u)
+#define MTU_MC47_RDBFL65 (0xF0063FE2u)
+#define MTU_MC47_RDBFL66 (0xF0063FE4u)
+#define MTU_MC47_ALMSRCS (0xF0063FEEu)
+#define MTU_MC47_FAULTSTS (0xF0063FF0u)
+#define MTU_MC47_ERRINFO0 (0xF0063FF2u)
+#define MTU_MC47_ERRINFO1 (0xF0063FF4u)
+#define MTU_MC47_ERRINFO2 (0xF0063FF6u)
+#define MTU_MC47_ERRINFO3 (0xF0063FF8u)
+#define MTU_MC4_RDBFL18 (0xF0061484u)
+#define MTU_MC47_ERRINFO4 (0xF0063FFAu)
+#define MTU_MC48_CONFIG0 (0xF0064000u)
+#define MTU_MC48_CONFIG1 (0xF0064002u)
+#define MTU_MC48_MCONTROL (0xF0064004u)
+#define MTU_MC48_MSTATUS (0xF0064006u)
+#define MTU_MC48_RANGE (0xF0064008u)
+#define MTU_MC48_REVID (0xF006400Cu)
+#define MTU_MC48_ECCS (0xF006400Eu)
+#define MTU_MC48_ECCD (0xF0064010u)
+#define MTU_MC48_ETRR0 (0xF0064012u)
+#define MTU_MC4_RDBFL19 (0xF0061486u)
+#define MTU_MC48_ETRR1 (0xF0064014u)
+#define MTU_MC48_ETRR2 (0xF0064016u)
+#define MTU_MC48_ETRR3 (0xF0064018u)
+#define MTU_MC48_ETRR4 (0xF006401Au)
+#define MTU_MC48_RDBFL0 (0xF0064060u)
+#define MTU_MC48_RDBFL1 (0xF0064062u)
+#define MTU_MC48_RDBFL2 (0xF0064064u)
+#define MTU_MC48_RDBFL3 (0xF0064066u)
+#define MTU_MC48_RDBFL4 (0xF0064068u)
+#define MTU_MC48_RDBFL5 (0xF006406Au)
+#define MTU_MC4_RDBFL20 (0xF0061488u)
+#define MTU_MC48_RDBFL6 (0xF006406Cu)
+#define MTU_MC48_RDBFL7 (0xF006406Eu)
+#define MTU_MC48_RDBFL8 (0xF0064070u)
+#define MTU_MC48_RDBFL9 (0xF0064072u)
+#define MTU_MC48_RDBFL10 (0xF0064074u)
+#define MTU_MC48_RDBFL11 (0xF0064076u)
+#define MTU_MC48_RDBFL12 (0xF0064078u)
+#define MTU_MC48_RDBFL13 (0xF006407Au)
+#define MTU_MC48_RDBFL14 (0xF006407Cu)
+#define MTU_MC48_RDBFL15 (0xF006407Eu)
+#define MTU_MC4_RDBFL21 (0xF006148Au)
+#define MTU_MC48_RDBFL16 (0xF0064080u)
+#define MTU_MC48_RDBFL17 (0xF0064082u)
+#define MTU_MC48_RDBFL18 (0xF0064084u)
+#define MTU_MC48_RDBFL19 (0xF0064086u)
+#define MTU_MC48_RDBFL20 (0xF0064088u)
+#define MTU_MC48_RDBFL21 (0xF006408Au)
+#define MTU_MC48_RDBFL22 (0xF006408Cu)
+#define MTU_MC48_RDBFL23 (0xF006408Eu)
+#define MTU_MC48_RDBFL24 (0xF0064090u)
+#define MTU_MC48_RDBFL25 (0xF0064092u)
+#define MTU_MC4_RDBFL22 (0xF006148Cu)
+#define MTU_MC48_RDBFL26 (0xF0064094u)
+#define MTU_MC48_RDBFL27 (0xF0064096u)
+#define MTU_MC48_RDBFL28 (0xF0064098u)
+#define MTU_MC48_RDBFL29 (0xF006409Au)
+#define MTU_MC48_RDBFL30 (0xF006409Cu)
+#define MTU_MC48_RDBFL31 (0xF006409Eu)
+#define MTU_MC48_RDBFL32 (0xF00640A0u)
+#define MTU_MC48_RDBFL33 (0xF00640A2u)
+#define MTU_MC48_RDBFL34 (0xF00640A4u)
+#define MTU_MC48_RDBFL35 (0xF00640A6u)
+#define MTU_MC4_RDBFL23 (0xF006148Eu)
+#define MTU_MC0_CONFIG0 (0xF0061000u)
+#define MTU_MC48_RDBFL36 (0xF00640A8u)
+#define MTU_MC48_RDBFL37 (0xF00640AAu)
+#define MTU_MC48_RDBFL38 (0xF00640ACu)
+#define MTU_MC48_RDBFL39 (0xF00640AEu)
+#define MTU_MC48_RDBFL40 (0xF00640B0u)
+#define MTU_MC48_RDBFL41 (0xF00640B2u)
+#define MTU_MC48_RDBFL42 (0xF00640B4u)
+#define MTU_MC48_RDBFL43 (0xF00640B6u)
+#define MTU_MC48_RDBFL44 (0xF00640B8u)
+#define MTU_MC48_RDBFL45 (0xF00640BAu)
+#define MTU_MC4_RDBFL24 (0xF0061490u)
+#define MTU_MC48_RDBFL46 (0xF00640BCu)
+#define MTU_MC48_RDBFL47 (0xF00640BEu)
+#define MTU_MC48_RDBFL48 (0xF00640C0u)
+#define MTU_MC48_RDBFL49 (0xF00640C2u)
+#define MTU_MC48_RDBFL50 (0xF00640C4u)
+#define MTU_MC48_RDBFL51 (0xF00640C6u)
+#define MTU_MC48_RDBFL52 (0xF00640C8u)
+#define MTU_MC48_RDBFL53 (0xF00640CAu)
+#define MTU_MC48_RDBFL54 (0xF00640CCu)
+#define MTU_MC48_RDBFL55 (0xF00640CEu)
+#define MTU_MC4_RDBFL25 (0xF0061492u)
+#define MTU_MC48_RDBFL56 (0xF00640D0u)
+#define MTU_MC48_RDBFL57 (0xF00640D2u)
+#define MTU_MC48_RDBFL58 (0xF00640D4u)
+#define MTU_MC48_RDBFL59 (0xF00640D6u)
+#define MTU_MC48_RDBFL60 (0xF00640D8u)
+#define MTU_MC48_RDBFL61 (0xF00640DAu)
+#define MTU_MC48_RDBFL62 (0xF00640DCu)
+#define MTU_MC48_RDBFL63 (0xF00640DEu)
+#define MTU_MC48_RDBFL64 (0xF00640E0u)
+#define MTU_MC48_RDBFL65 (0xF00640E2u)
+#define MTU_MC4_RDBFL26 (0xF0061494u)
+#define MTU_MC48_RDBFL66 (0xF00640E4u)
+#define MTU_MC48_ALMSRCS (0xF00640EEu)
+#define MTU_MC48_FAULTSTS (0xF00640F0u)
+#define MTU_MC48_ERRINFO0 (0xF00640F2u)
+#define MTU_MC48_ERRINFO1 (0xF00640F4u)
+#define MTU_MC48_ERRINFO2 (0xF00640F6u)
+#define MTU_MC48_ERRINFO3 (0xF00640F8u)
+#define MTU_MC48_ERRINFO4 (0xF00640FAu)
+#define MTU_MC49_CONFIG0 (0xF0064100u)
+#define MTU_MC49_CONFIG1 (0xF0064102u)
+#define MTU_MC4_RDBFL27 (0xF0061496u)
+#define MTU_MC49_MCONTROL (0xF0064104u)
+#define MTU_MC49_MSTATUS (0xF0064106u)
+#define MTU_MC49_RANGE (0xF0064108u)
+#define MTU_MC49_REVID (0xF006410Cu)
+#define MTU_MC49_ECCS (0xF006410Eu)
+#define MTU_MC49_ECCD (0xF0064110u)
+#define MTU_MC49_ETRR0 (0xF0064112u)
+#define MTU_MC49_ETRR1 (0xF0064114u)
+#define MTU_MC49_ETRR2 (0xF0064116u)
+#define MTU_MC49_ETRR3 (0xF0064118u)
+#define MTU_MC4_RDBFL28 (0xF0061498u)
+#define MTU_MC49_ETRR4 (0xF006411Au)
+#define MTU_MC49_RDBFL0 (0xF0064160u)
+#define MTU_MC49_RDBFL1 (0xF0064162u)
+#define MTU_MC49_RDBFL2 (0xF0064164u)
+#define MTU_MC49_RDBFL3 (0xF0064166u)
+#define MTU_MC49_RDBFL4 (0xF0064168u)
+#define MTU_MC49_RDBFL5 (0xF006416Au)
+#define MTU_MC49_RDBFL6 (0xF006416Cu)
+#define MTU_MC49_RDBFL7 (0xF006416Eu)
+#define MTU_MC49_RDBFL8 (0xF0064170u)
+#define MTU_MC4_RDBFL29 (0xF006149Au)
+#define MTU_MC49_RDBFL9 (0xF0064172u)
+#define MTU_MC49_RDBFL10 (0xF0064174u)
+#define MTU_MC49_RDBFL11 (0xF0064176u)
+#define MTU_MC49_RDBFL12 (0xF0064178u)
+#define MTU_MC49_RDBFL13 (0xF006417Au)
+#define MTU_MC49_RDBFL14 (0xF006417Cu)
+#define MTU_MC49_RDBFL15 (0xF006417Eu)
+#define MTU_MC49_RDBFL16 (0xF0064180u)
+#define MTU_MC49_RDBFL17 (0xF0064182u)
+#define MTU_MC49_RDBFL18 (0xF0064184u)
+#define MTU_MC4_RDBFL30 (0xF006149Cu)
+#define MTU_MC49_RDBFL19 (0xF0064186u)
+#define MTU_MC49_RDBFL20 (0xF0064188u)
+#define MTU_MC49_RDBFL21 (0xF006418Au)
+#define MTU_MC49_RDBFL22 (0xF006418Cu)
+#define MTU_MC49_RDBFL23 (0xF006418Eu)
+#define MTU_MC49_RDBFL24 (0xF0064190u)
+#define MTU_MC49_RDBFL25 (0xF0064192u)
+#define MTU_MC49_RDBFL26 (0xF0064194u)
+#define MTU_MC49_RDBFL27 (0xF0064196u)
+#define MTU_MC49_RDBFL28 (0xF0064198u)
+#define MTU_MC4_RDBFL31 (0xF006149Eu)
+#define MTU_MC49_RDBFL29 (0xF006419Au)
+#define MTU_MC49_RDBFL30 (0xF006419Cu)
+#define MTU_MC49_RDBFL31 (0xF006419Eu)
+#define MTU_MC49_RDBFL32 (0xF00641A0u)
+#define MTU_MC49_RDBFL33 (0xF00641A2u)
+#define MTU_MC49_RDBFL34 (0xF00641A4u)
+#define MTU_MC49_RDBFL35 (0xF00641A6u)
+#define MTU_MC49_RDBFL36 (0xF00641A8u)
+#define MTU_MC49_RDBFL37 (0xF00641AAu)
+#define MTU_MC49_RDBFL38 (0xF00641ACu)
+#define MTU_MC4_RDBFL32 (0xF00614A0u)
+#define MTU_MC49_RDBFL39 (0xF00641AEu)
+#define MTU_MC49_RDBFL40 (0xF00641B0u)
+#define MTU_MC49_RDBFL41 (0xF00641B2u)
+#define MTU_MC49_RDBFL42 (0xF00641B4u)
+#define MTU_MC49_RDBFL43 (0xF00641B6u)
+#define MTU_MC49_RDBFL44 (0xF00641B8u)
+#define MTU_MC49_RDBFL45 (0xF00641BAu)
+#define MTU_MC49_RDBFL46 (0xF00641BCu)
+#define MTU_MC49_RDBFL47 (0xF00641BEu)
+#define MTU_MC49_RDBFL48 (0xF00641C0u)
+#define MTU_MC4_RDBFL33 (0xF00614A2u)
+#define MTU_MC0_CONFIG1 (0xF0061002u)
+#define MTU_MC49_RDBFL49 (0xF00641C2u)
+#define MTU_MC49_RDBFL50 (0xF00641C4u)
+#define MTU_MC49_RDBFL51 (0xF00641C6u)
+#define MTU_MC49_RDBFL52 (0xF00641C8u)
+#define MTU_MC49_RDBFL53 (0xF00641CAu)
+#define MTU_MC49_RDBFL54 (0xF00641CCu)
+#define MTU_MC49_RDBFL55 (0xF00641CEu)
+#define MTU_MC49_RDBFL56 (0xF00641D0u)
+#define MTU_MC49_RDBFL57 (0xF00641D2u)
+#define MTU_MC49_RDBFL58 (0xF00641D4u)
+#define MTU_MC4_RDBFL34 (0xF00614A4u)
+#define MTU_MC49_RDBFL59 (0xF00641D6u)
+#define MTU_MC49_RDBFL60 (0xF00641D8u)
+#define MTU_MC49_RDBFL61 (0xF00641DAu)
+#define MTU_MC49_RDBFL62 (0xF00641DCu)
+#define MTU_MC49_RDBFL63 (0xF00641DEu)
+#define MTU_MC49_RDBFL64 (0xF00641E0u)
+#define MTU_MC49_RDBFL65 (0xF00641E2u)
+#define MTU_MC49_RDBFL66 (0xF00641E4u)
+#define MTU_MC49_ALMSRCS (0xF00641EEu)
+#define MTU_MC49_FAULTSTS (0xF00641F0u)
+#define MTU_MC4_RDBFL35 (0xF00614A6u)
+#define MTU_MC49_ERRINFO0 (0xF00641F2u)
+#define MTU_MC49_ERRINFO1 (0xF00641F4u)
+#define MTU_MC49_ERRINFO2 (0xF00641F6u)
+#define MTU_MC49_ERRINFO3 (0xF00641F8u)
+#define MTU_MC49_ERRINFO4 (0xF00641FAu)
+#define MTU_MC50_CONFIG0 (0xF0064200u)
+#define MTU_MC50_CONFIG1 (0xF0064202u)
+#define MTU_MC50_MCONTROL (0xF0064204u)
+#define MTU_MC50_MSTATUS (0xF0064206u)
+#define MTU_MC50_RANGE (0xF0064208u)
+#define MTU_MC4_RDBFL36 (0xF00614A8u)
+#define MTU_MC50_REVID (0xF006420Cu)
+#define MTU_MC50_ECCS (0xF006420Eu)
+#define MTU_MC50_ECCD (0xF0064210u)
+#define MTU_MC50_ETRR0 (0xF0064212u)
+#define MTU_MC50_ETRR1 (0xF0064214u)
+#define MTU_MC50_ETRR2 (0xF0064216u)
+#define MTU_MC50_ETRR3 (0xF0064218u)
+#define MTU_MC50_ETRR4 (0xF006421Au)
+#define MTU_MC50_RDBFL0 (0xF0064260u)
+#define MTU_MC50_RDBFL1 (0xF0064262u)
+#define MTU_MC4_RDBFL37 (0xF00614AAu)
+#define MTU_MC50_RDBFL2 (0xF0064264u)
+#define MTU_MC50_RDBFL3 (0xF0064266u)
+#define MTU_MC50_RDBFL4 (0xF0064268u)
+#define MTU_MC50_RDBFL5 (0xF006426Au)
+#define MTU_MC50_RDBFL6 (0xF006426Cu)
+#define MTU_MC50_RDBFL7 (0xF006426Eu)
+#define MTU_MC50_RDBFL8 (0xF0064270u)
+#define MTU_MC50_RDBFL9 (0xF0064272u)
+#define MTU_MC50_RDBFL10 (0xF0064274u)
+#define MTU_MC50_RDBFL11 (0xF0064276u)
+#define MTU_MC4_RDBFL38 (0xF00614ACu)
+#define MTU_MC50_RDBFL12 (0xF0064278u)
+#define MTU_MC50_RDBFL13 (0xF006427Au)
+#define MTU_MC50_RDBFL14 (0xF006427Cu)
+#define MTU_MC50_RDBFL15 (0xF006427Eu)
+#define MTU_MC50_RDBFL16 (0xF0064280u)
+#define MTU_MC50_RDBFL17 (0xF0064282u)
+#define MTU_MC50_RDBFL18 (0xF0064284u)
+#define MTU_MC50_RDBFL19 (0xF0064286u)
+#define MTU_MC50_RDBFL20 (0xF0064288u)
+#define MTU_MC50_RDBFL21 (0xF006428Au)
+#define MTU_MC4_RDBFL39 (0xF00614AEu)
+#define MTU_MC50_RDBFL22 (0xF006428Cu)
+#define MTU_MC50_RDBFL23 (0xF006428Eu)
+#define MTU_MC50_RDBFL24 (0xF0064290u)
+#define MTU_MC50_RDBFL25 (0xF0064292u)
+#define MTU_MC50_RDBFL26 (0xF0064294u)
+#define MTU_MC50_RDBFL27 (0xF0064296u)
+#define MTU_MC50_RDBFL28 (0xF0064298u)
+#define MTU_MC50_RDBFL29 (0xF006429Au)
+#define MTU_MC50_RDBFL30 (0xF006429Cu)
+#define MTU_MC50_RDBFL31 (0xF006429Eu)
+#define MTU_MC4_RDBFL40 (0xF00614B0u)
+#define MTU_MC50_RDBFL32 (0xF00642A0u)
+#define MTU_MC50_RDBFL33 (0xF00642A2u)
+#define MTU_MC50_RDBFL34 (0xF00642A4u)
+#define MTU_MC50_RDBFL35 (0xF00642A6u)
+#define MTU_MC50_RDBFL36 (0xF00642A8u)
+#define MTU_MC50_RDBFL37 (0xF00642AAu)
+#define MTU_MC50_RDBFL38 (0xF00642ACu)
+#define MTU_MC50_RDBFL39 (0xF00642AEu)
+#define MTU_MC50_RDBFL40 (0xF00642B0u)
+#define MTU_MC50_RDBFL41 (0xF00642B2u)
+#define MTU_MC4_RDBFL41 (0xF00614B2u)
+#define MTU_MC50_RDBFL42 (0xF00642B4u)
+#define MTU_MC50_RDBFL43 (0xF00642B6u)
+#define MTU_MC50_RDBFL44 (0xF00642B8u)
+#define MTU_MC50_RDBFL45 (0xF00642BAu)
+#define MTU_MC50_RDBFL46 (0xF00642BCu)
+#define MTU_MC50_RDBFL47 (0xF00642BEu)
+#define MTU_MC50_RDBFL48 (0xF00642C0u)
+#define MTU_MC50_RDBFL49 (0xF00642C2u)
+#define MTU_MC50_RDBFL50 (0xF00642C4u)
+#define MTU_MC50_RDBFL51 (0xF00642C6u)
+#define MTU_MC4_RDBFL42 (0xF00614B4u)
+#define MTU_MC50_RDBFL52 (0xF00642C8u)
+#define MTU_MC50_RDBFL53 (0xF00642CAu)
+#define MTU_MC50_RDBFL54 (0xF00642CCu)
+#define MTU_MC50_RDBFL55 (0xF00642CEu)
+#define MTU_MC50_RDBFL56 (0xF00642D0u)
+#define MTU_MC50_RDBFL57 (0xF00642D2u)
+#define MTU_MC50_RDBFL58 (0xF00642D4u)
+#define MTU_MC50_RDBFL59 (0xF00642D6u)
+#define MTU_MC50_RDBFL60 (0xF00642D8u)
+#define MTU_MC50_RDBFL61 (0xF00642DAu)
+#define MTU_MC4_RDBFL43 (0xF00614B6u)
+#define MTU_MC0_MCONTROL (0xF0061004u)
+#define MTU_MC50_RDBFL62 (0xF00642DCu)
+#define MTU_MC50_RDBFL63 (0xF00642DEu)
+#define MTU_MC50_RDBFL64 (0xF00642E0u)
+#define MTU_MC50_RDBFL65 (0xF00642E2u)
+#define MTU_MC50_RDBFL66 (0xF00642E4u)
+#define MTU_MC50_ALMSRCS (0xF00642EEu)
+#define MTU_MC50_FAULTSTS (0xF00642F0u)
+#define MTU_MC50_ERRINFO0 (0xF00642F2u)
+#define MTU_MC50_ERRINFO1 (0xF00642F4u)
+#define MTU_MC50_ERRINFO2 (0xF00642F6u)
+#define MTU_MC4_RDBFL44 (0xF00614B8u)
+#define MTU_MC50_ERRINFO3 (0xF00642F8u)
+#define MTU_MC50_ERRINFO4 (0xF00642FAu)
+#define MTU_MC51_CONFIG0 (0xF0064300u)
+#define MTU_MC51_CONFIG1 (0xF0064302u)
+#define MTU_MC51_MCONTROL (0xF0064304u)
+#define MTU_MC51_MSTATUS (0xF0064306u)
+#define MTU_MC51_RANGE (0xF0064308u)
+#define MTU_MC51_REVID (0xF006430Cu)
+#define MTU_MC51_ECCS (0xF006430Eu)
+#define MTU_MC51_ECCD (0xF0064310u)
+#define MTU_MC4_RDBFL45 (0xF00614BAu)
+#define MTU_MC51_ETRR0 (0xF0064312u)
+#define MTU_MC51_ETRR1 (0xF0064314u)
+#define MTU_MC51_ETRR2 (0xF0064316u)
+#define MTU_MC51_ETRR3 (0xF0064318u)
+#define MTU_MC51_ETRR4 (0xF006431Au)
+#define MTU_MC51_RDBFL0 (0xF0064360u)
+#define MTU_MC51_RDBFL1 (0xF0064362u)
+#define MTU_MC51_RDBFL2 (0xF0064364u)
+#define MTU_MC51_RDBFL3 (0xF0064366u)
+#define MTU_MC51_RDBFL4 (0xF0064368u)
+#define MTU_MC4_RDBFL46 (0xF00614BCu)
+#define MTU_MC51_RDBFL5 (0xF006436Au)
+#define MTU_MC51_RDBFL6 (0xF006436Cu)
+#define MTU_MC51_RDBFL7 (0xF006436Eu)
+#define MTU_MC51_RDBFL8 (0xF0064370u)
+#define MTU_MC51_RDBFL9 (0xF0064372u)
+#define MTU_MC51_RDBFL10 (0xF0064374u)
+#define MTU_MC51_RDBFL11 (0xF0064376u)
+#define MTU_MC51_RDBFL12 (0xF0064378u)
+#define MTU_MC51_RDBFL13 (0xF006437Au)
+#define MTU_MC51_RDBFL14 (0xF006437Cu)
+#define MTU_MC4_RDBFL47 (0xF00614BEu)
+#define MTU_MC51_RDBFL15 (0xF006437Eu)
+#define MTU_MC51_RDBFL16 (0xF0064380u)
+#define MTU_MC51_RDBFL17 (0xF0064382u)
+#define MTU_MC51_RDBFL18 (0xF0064384u)
+#define MTU_MC51_RDBFL19 (0xF0064386u)
+#define MTU_MC51_RDBFL20 (0xF0064388u)
+#define MTU_MC51_RDBFL21 (0xF006438Au)
+#define MTU_MC51_RDBFL22 (0xF006438Cu)
+#define MTU_MC51_RDBFL23 (0xF006438Eu)
+#define MTU_MC51_RDBFL24 (0xF0064390u)
+#define MTU_MC4_RDBFL48 (0xF00614C0u)
+#define MTU_MC51_RDBFL25 (0xF0064392u)
+#define MTU_MC51_RDBFL26 (0xF0064394u)
+#define MTU_MC51_RDBFL27 (0xF0064396u)
+#define MTU_MC51_RDBFL28 (0xF0064398u)
+#define MTU_MC51_RDBFL29 (0xF006439Au)
+#define MTU_MC51_RDBFL30 (0xF006439Cu)
+#define MTU_MC51_RDBFL31 (0xF006439Eu)
+#define MTU_MC51_RDBFL32 (0xF00643A0u)
+#define MTU_MC51_RDBFL33 (0xF00643A2u)
+#define MTU_MC51_RDBFL34 (0xF00643A4u)
+#define MTU_MC4_RDBFL49 (0xF00614C2u)
+#define MTU_MC51_RDBFL35 (0xF00643A6u)
+#define MTU_MC51_RDBFL36 (0xF00643A8u)
+#define MTU_MC51_RDBFL37 (0xF00643AAu)
+#define MTU_MC51_RDBFL38 (0xF00643ACu)
+#define MTU_MC51_RDBFL39 (0xF00643AEu)
+#define MTU_MC51_RDBFL40 (0xF00643B0u)
+#define MTU_MC51_RDBFL41 (0xF00643B2u)
+#define MTU_MC51_RDBFL42 (0xF00643B4u)
+#define MTU_MC51_RDBFL43 (0xF00643B6u)
+#define MTU_MC51_RDBFL44 (0xF00643B8u)
+#define MTU_MC4_RDBFL50 (0xF00614C4u)
+#define MTU_MC51_RDBFL45 (0xF00643BAu)
+#define MTU_MC51_RDBFL46 (0xF00643BCu)
+#define MTU_MC51_RDBFL47 (0xF00643BEu)
+#define MTU_MC51_RDBFL48 (0xF00643C0u)
+#define MTU_MC51_RDBFL49 (0xF00643C2u)
+#define MTU_MC51_RDBFL50 (0xF00643C4u)
+#define MTU_MC51_RDBFL51 (0xF00643C6u)
+#define MTU_MC51_RDBFL52 (0xF00643C8u)
+#define MTU_MC51_RDBFL53 (0xF00643CAu)
+#define MTU_MC51_RDBFL54 (0xF00643CCu)
+#define MTU_MC4_RDBFL51 (0xF00614C6u)
+#define MTU_MC51_RDBFL55 (0xF00643CEu)
+#define MTU_MC51_RDBFL56 (0xF00643D0u)
+#define MTU_MC51_RDBFL57 (0xF00643D2u)
+#define MTU_MC51_RDBFL58 (0xF00643D4u)
+#define MTU_MC51_RDBFL59 (0xF00643D6u)
+#define MTU_MC51_RDBFL60 (0xF00643D8u)
+#define MTU_MC51_RDBFL61 (0xF00643DAu)
+#define MTU_MC51_RDBFL62 (0xF00643DCu)
+#define MTU_MC51_RDBFL63 (0xF00643DEu)
+#define MTU_MC51_RDBFL64 (0xF00643E0u)
+#define MTU_MC4_RDBFL52 (0xF00614C8u)
+#define MTU_MC51_RDBFL65 (0xF00643E2u)
+#define MTU_MC51_RDBFL66 (0xF00643E4u)
+#define MTU_MC51_ALMSRCS (0xF00643EEu)
+#define MTU_MC51_FAULTSTS (0xF00643F0u)
+#define MTU_MC51_ERRINFO0 (0xF00643F2u)
+#define MTU_MC51_ERRINFO1 (0xF00643F4u)
+#define MTU_MC51_ERRINFO2 (0xF00643F6u)
+#define MTU_MC51_ERRINFO3 (0xF00643F8u)
+#define MTU_MC51_ERRINFO4 (0xF00643FAu)
+#define MTU_MC52_CONFIG0 (0xF0064400u)
+#define MTU_MC4_RDBFL53 (0xF00614CAu)
+#define MTU_MC0_MSTATUS (0xF0061006u)
+#define MTU_MC52_CONFIG1 (0xF0064402u)
+#define MTU_MC52_MCONTROL (0xF0064404u)
+#define MTU_MC52_MSTATUS (0xF0064406u)
+#define MTU_MC52_RANGE (0xF0064408u)
+#define MTU_MC52_REVID (0xF006440Cu)
+#define MTU_MC52_ECCS (0xF006440Eu)
+#define MTU_MC52_ECCD (0xF0064410u)
+#define MTU_MC52_ETRR0 (0xF0064412u)
+#define MTU_MC52_ETRR1 (0xF0064414u)
+#define MTU_MC52_ETRR2 (0xF0064416u)
+#define MTU_MC4_RDBFL54 (0xF00614CCu)
+#define MTU_MC52_ETRR3 (0xF0064418u)
+#define MTU_MC52_ETRR4 (0xF006441Au)
+#define MTU_MC52_RDBFL0 (0xF0064460u)
+#define MTU_MC52_RDBFL1 (0xF0064462u)
+#define MTU_MC52_RDBFL2 (0xF0064464u)
+#define MTU_MC52_RDBFL3 (0xF0064466u)
+#define MTU_MC52_RDBFL4 (0xF0064468u)
+#define MTU_MC52_RDBFL5 (0xF006446Au)
+#define MTU_MC52_RDBFL6 (0xF006446Cu)
+#define MTU_MC52_RDBFL7 (0xF006446Eu)
+#define MTU_MC4_RDBFL55 (0xF00614CEu)
+#define MTU_MC52_RDBFL8 (0xF0064470u)
+#define MTU_MC52_RDBFL9 (0xF0064472u)
+#define MTU_MC52_RDBFL10 (0xF0064474u)
+#define MTU_MC52_RDBFL11 (0xF0064476u)
+#define MTU_MC52_RDBFL12 (0xF0064478u)
+#define MTU_MC52_RDBFL13 (0xF006447Au)
+#define MTU_MC52_RDBFL14 (0xF006447Cu)
+#define MTU_MC52_RDBFL15 (0xF006447Eu)
+#define MTU_MC52_RDBFL16 (0xF0064480u)
+#define MTU_MC52_RDBFL17 (0xF0064482u)
+#define MTU_MC4_RDBFL56 (0xF00614D0u)
+#define MTU_MC52_RDBFL18 (0xF0064484u)
+#define MTU_MC52_RDBFL19 (0xF0064486u)
+#define MTU_MC52_RDBFL20 (0xF0064488u)
+#define MTU_MC52_RDBFL21 (0xF006448Au)
+#define MTU_MC52_RDBFL22 (0xF006448Cu)
+#define MTU_MC52_RDBFL23 (0xF006448Eu)
+#define MTU_MC52_RDBFL24 (0xF0064490u)
+#define MTU_MC52_RDBFL25 (0xF0064492u)
+#define MTU_MC52_RDBFL26 (0xF0064494u)
+#define MTU_MC52_RDBFL27 (0xF0064496u)
+#define MTU_MC4_RDBFL57 (0xF00614D2u)
+#define MTU_MC52_RDBFL28 (0xF0064498u)
+#define MTU_MC52_RDBFL29 (0xF006449Au)
+#define MTU_MC52_RDBFL30 (0xF006449Cu)
+#define MTU_MC52_RDBFL31 (0xF006449Eu)
+#define MTU_MC52_RDBFL32 (0xF00644A0u)
+#define MTU_MC52_RDBFL33 (0xF00644A2u)
+#define MTU_MC52_RDBFL34 (0xF00644A4u)
+#define MTU_MC52_RDBFL35 (0xF00644A6u)
+#define MTU_MC52_RDBFL36 (0xF00644A8u)
+#define MTU_MC52_RDBFL37 (0xF00644AAu)
+#define MTU_MC4_RDBFL58 (0xF00614D4u)
+#define MTU_MC52_RDBFL38 (0xF00644ACu)
+#define MTU_MC52_RDBFL39 (0xF00644AEu)
+#define MTU_MC52_RDBFL40 (0xF00644B0u)
+#define MTU_MC52_RDBFL41 (0xF00644B2u)
+#define MTU_MC52_RDBFL42 (0xF00644B4u)
+#define MTU_MC52_RDBFL43 (0xF00644B6u)
+#define MTU_MC52_RDBFL44 (0xF00644B8u)
+#define MTU_MC52_RDBFL45 (0xF00644BAu)
+#define MTU_MC52_RDBFL46 (0xF00644BCu)
+#define MTU_MC52_RDBFL47 (0xF00644BEu)
+#define MTU_MC4_RDBFL59 (0xF00614D6u)
+#define MTU_MC52_RDBFL48 (0xF00644C0u)
+#define MTU_MC52_RDBFL49 (0xF00644C2u)
+#define MTU_MC52_RDBFL50 (0xF00644C4u)
+#define MTU_MC52_RDBFL51 (0xF00644C6u)
+#define MTU_MC52_RDBFL52 (0xF00644C8u)
+#define MTU_MC52_RDBFL53 (0xF00644CAu)
+#define MTU_MC52_RDBFL54 (0xF00644CCu)
+#define MTU_MC52_RDBFL55 (0xF00644CEu)
+#define MTU_MC52_RDBFL56 (0xF00644D0u)
+#define MTU_MC52_RDBFL57 (0xF00644D2u)
+#define MTU_MC4_RDBFL60 (0xF00614D8u)
+#define MTU_MC52_RDBFL58 (0xF00644D4u)
+#define MTU_MC52_RDBFL59 (0xF00644D6u)
+#define MTU_MC52_RDBFL60 (0xF00644D8u)
+#define MTU_MC52_RDBFL61 (0xF00644DAu)
+#define MTU_MC52_RDBFL62 (0xF00644DCu)
+#define MTU_MC52_RDBFL63 (0xF00644DEu)
+#define MTU_MC52_RDBFL64 (0xF00644E0u)
+#define MTU_MC52_RDBFL65 (0xF00644E2u)
+#define MTU_MC52_RDBFL66 (0xF00644E4u)
+#define MTU_MC52_ALMSRCS (0xF00644EEu)
+#define MTU_MC4_RDBFL61 (0xF00614DAu)
+#define MTU_MC52_FAULTSTS (0xF00644F0u)
+#define MTU_MC52_ERRINFO0 (0xF00644F2u)
+#define MTU_MC52_ERRINFO1 (0xF00644F4u)
+#define MTU_MC52_ERRINFO2 (0xF00644F6u)
+#define MTU_MC52_ERRINFO3 (0xF00644F8u)
+#define MTU_MC52_ERRINFO4 (0xF00644FAu)
+#define MTU_MC53_CONFIG0 (0xF0064500u)
+#define MTU_MC53_CONFIG1 (0xF0064502u)
+#define MTU_MC53_MCONTROL (0xF0064504u)
+#define MTU_MC53_MSTATUS (0xF0064506u)
+#define MTU_MC4_RDBFL62 (0xF00614DCu)
+#define MTU_MC53_RANGE (0xF0064508u)
+#define MTU_MC53_REVID (0xF006450Cu)
+#define MTU_MC53_ECCS (0xF006450Eu)
+#define MTU_MC53_ECCD (0xF0064510u)
+#define MTU_MC53_ETRR0 (0xF0064512u)
+#define MTU_MC53_ETRR1 (0xF0064514u)
+#define MTU_MC53_ETRR2 (0xF0064516u)
+#define MTU_MC53_ETRR3 (0xF0064518u)
+#define MTU_MC53_ETRR4 (0xF006451Au)
+#define MTU_MC53_RDBFL0 (0xF0064560u)
+#define MTU_MC4_RDBFL63 (0xF00614DEu)
+#define MTU_MC0_RANGE (0xF0061008u)
+#define MTU_MC53_RDBFL1 (0xF0064562u)
+#define MTU_MC53_RDBFL2 (0xF0064564u)
+#define MTU_MC53_RDBFL3 (0xF0064566u)
+#define MTU_MC53_RDBFL4 (0xF0064568u)
+#define MTU_MC53_RDBFL5 (0xF006456Au)
+#define MTU_MC53_RDBFL6 (0xF006456Cu)
+#define MTU_MC53_RDBFL7 (0xF006456Eu)
+#define MTU_MC53_RDBFL8 (0xF0064570u)
+#define MTU_MC53_RDBFL9 (0xF0064572u)
+#define MTU_MC53_RDBFL10 (0xF0064574u)
+#define MTU_MC4_RDBFL64 (0xF00614E0u)
+#define MTU_MC53_RDBFL11 (0xF0064576u)
+#define MTU_MC53_RDBFL12 (0xF0064578u)
+#define MTU_MC53_RDBFL13 (0xF006457Au)
+#define MTU_MC53_RDBFL14 (0xF006457Cu)
+#define MTU_MC53_RDBFL15 (0xF006457Eu)
+#define MTU_MC53_RDBFL16 (0xF0064580u)
+#define MTU_MC53_RDBFL17 (0xF0064582u)
+#define MTU_MC53_RDBFL18 (0xF0064584u)
+#define MTU_MC53_RDBFL19 (0xF0064586u)
+#define MTU_MC53_RDBFL20 (0xF0064588u)
+#define MTU_MC4_RDBFL65 (0xF00614E2u)
+#define MTU_MC53_RDBFL21 (0xF006458Au)
+#define MTU_MC53_RDBFL22 (0xF006458Cu)
+#define MTU_MC53_RDBFL23 (0xF006458Eu)
+#define MTU_MC53_RDBFL24 (0xF0064590u)
+#define MTU_MC53_RDBFL25 (0xF0064592u)
+#define MTU_MC53_RDBFL26 (0xF0064594u)
+#define MTU_MC53_RDBFL27 (0xF0064596u)
+#define MTU_MC53_RDBFL28 (0xF0064598u)
+#define MTU_MC53_RDBFL29 (0xF006459Au)
+#define MTU_MC53_RDBFL30 (0xF006459Cu)
+#define MTU_MC4_RDBFL66 (0xF00614E4u)
+#define MTU_MC53_RDBFL31 (0xF006459Eu)
+#define MTU_MC53_RDBFL32 (0xF00645A0u)
+#define MTU_MC53_RDBFL33 (0xF00645A2u)
+#define MTU_MC53_RDBFL34 (0xF00645A4u)
+#define MTU_MC53_RDBFL35 (0xF00645A6u)
+#define MTU_MC53_RDBFL36 (0xF00645A8u)
+#define MTU_MC53_RDBFL37 (0xF00645AAu)
+#define MTU_MC53_RDBFL38 (0xF00645ACu)
+#define MTU_MC53_RDBFL39 (0xF00645AEu)
+#define MTU_MC53_RDBFL40 (0xF00645B0u)
+#define MTU_MC4_ALMSRCS (0xF00614EEu)
+#define MTU_MC53_RDBFL41 (0xF00645B2u)
+#define MTU_MC53_RDBFL42 (0xF00645B4u)
+#define MTU_MC53_RDBFL43 (0xF00645B6u)
+#define MTU_MC53_RDBFL44 (0xF00645B8u)
+#define MTU_MC53_RDBFL45 (0xF00645BAu)
+#define MTU_MC53_RDBFL46 (0xF00645BCu)
+#define MTU_MC53_RDBFL47 (0xF00645BEu)
+#define MTU_MC53_RDBFL48 (0xF00645C0u)
+#define MTU_MC53_RDBFL49 (0xF00645C2u)
+#define MTU_MC53_RDBFL50 (0xF00645C4u)
+#define MTU_MC4_FAULTSTS (0xF00614F0u)
+#define MTU_MC53_RDBFL51 (0xF00645C6u)
+#define MTU_MC53_RDBFL52 (0xF00645C8u)
+#define MTU_MC53_RDBFL53 (0xF00645CAu)
+#define MTU_MC53_RDBFL54 (0xF00645CCu)
+#define MTU_MC53_RDBFL55 (0xF00645CEu)
+#define MTU_MC53_RDBFL56 (0xF00645D0u)
+#define MTU_MC53_RDBFL57 (0xF00645D2u)
+#define MTU_MC53_RDBFL58 (0xF00645D4u)
+#define MTU_MC53_RDBFL59 (0xF00645D6u)
+#define MTU_MC53_RDBFL60 (0xF00645D8u)
+#define MTU_MC4_ERRINFO0 (0xF00614F2u)
+#define MTU_MC53_RDBFL61 (0xF00645DAu)
+#define MTU_MC53_RDBFL62 (0xF00645DCu)
+#define MTU_MC53_RDBFL63 (0xF00645DEu)
+#define MTU_MC53_RDBFL64 (0xF00645E0u)
+#define MTU_MC53_RDBFL65 (0xF00645E2u)
+#define MTU_MC53_RDBFL66 (0xF00645E4u)
+#define MTU_MC53_ALMSRCS (0xF00645EEu)
+#define MTU_MC53_FAULTSTS (0xF00645F0u)
+#define MTU_MC53_ERRINFO0 (0xF00645F2u)
+#define MTU_MC53_ERRINFO1 (0xF00645F4u)
+#define MTU_MC4_ERRINFO1 (0xF00614F4u)
+#define MTU_MC53_ERRINFO2 (0xF00645F6u)
+#define MTU_MC53_ERRINFO3 (0xF00645F8u)
+#define MTU_MC53_ERRINFO4 (0xF00645FAu)
+#define MTU_MC54_CONFIG0 (0xF0064600u)
+#define MTU_MC54_CONFIG1 (0xF0064602u)
+#define MTU_MC54_MCONTROL (0xF0064604u)
+#define MTU_MC54_MSTATUS (0xF0064606u)
+#define MTU_MC54_RANGE (0xF0064608u)
+#define MTU_MC54_REVID (0xF006460Cu)
+#define MTU_MC54_ECCS (0xF006460Eu)
+#define MTU_MC4_ERRINFO2 (0xF00614F6u)
+#define MTU_MC54_ECCD (0xF0064610u)
+#define MTU_MC54_ETRR0 (0xF0064612u)
+#define MTU_MC54_ETRR1 (0xF0064614u)
+#define MTU_MC54_ETRR2 (0xF0064616u)
+#define MTU_MC54_ETRR3 (0xF0064618u)
+#define MTU_MC54_ETRR4 (0xF006461Au)
+#define MTU_MC54_RDBFL0 (0xF0064660u)
+#define MTU_MC54_RDBFL1 (0xF0064662u)
+#define MTU_MC54_RDBFL2 (0xF0064664u)
+#define MTU_MC54_RDBFL3 (0xF0064666u)
+#define MTU_MC4_ERRINFO3 (0xF00614F8u)
+#define MTU_MC54_RDBFL4 (0xF0064668u)
+#define MTU_MC54_RDBFL5 (0xF006466Au)
+#define MTU_MC54_RDBFL6 (0xF006466Cu)
+#define MTU_MC54_RDBFL7 (0xF006466Eu)
+#define MTU_MC54_RDBFL8 (0xF0064670u)
+#define MTU_MC54_RDBFL9 (0xF0064672u)
+#define MTU_MC54_RDBFL10 (0xF0064674u)
+#define MTU_MC54_RDBFL11 (0xF0064676u)
+#define MTU_MC54_RDBFL12 (0xF0064678u)
+#define MTU_MC54_RDBFL13 (0xF006467Au)
+#define MTU_MC4_ERRINFO4 (0xF00614FAu)
+#define MTU_MC0_REVID (0xF006100Cu)
+#define MTU_MC54_RDBFL14 (0xF006467Cu)
+#define MTU_MC54_RDBFL15 (0xF006467Eu)
+#define MTU_MC54_RDBFL16 (0xF0064680u)
+#define MTU_MC54_RDBFL17 (0xF0064682u)
+#define MTU_MC54_RDBFL18 (0xF0064684u)
+#define MTU_MC54_RDBFL19 (0xF0064686u)
+#define MTU_MC54_RDBFL20 (0xF0064688u)
+#define MTU_MC54_RDBFL21 (0xF006468Au)
+#define MTU_MC54_RDBFL22 (0xF006468Cu)
+#define MTU_MC54_RDBFL23 (0xF006468Eu)
+#define MTU_MC5_CONFIG0 (0xF0061500u)
+#define MTU_MC54_RDBFL24 (0xF0064690u)
+#define MTU_MC54_RDBFL25 (0xF0064692u)
+#define MTU_MC54_RDBFL26 (0xF0064694u)
+#define MTU_MC54_RDBFL27 (0xF0064696u)
+#define MTU_MC54_RDBFL28 (0xF0064698u)
+#define MTU_MC54_RDBFL29 (0xF006469Au)
+#define MTU_MC54_RDBFL30 (0xF006469Cu)
+#define MTU_MC54_RDBFL31 (0xF006469Eu)
+#define MTU_MC54_RDBFL32 (0xF00646A0u)
+#define MTU_MC54_RDBFL33 (0xF00646A2u)
+#define MTU_MC5_CONFIG1 (0xF0061502u)
+#define MTU_MC54_RDBFL34 (0xF00646A4u)
+#define MTU_MC54_RDBFL35 (0xF00646A6u)
+#define MTU_MC54_RDBFL36 (0xF00646A8u)
+#define MTU_MC54_RDBFL37 (0xF00646AAu)
+#define MTU_MC54_RDBFL38 (0xF00646ACu)
+#define MTU_MC54_RDBFL39 (0xF00646AEu)
+#define MTU_MC54_RDBFL40 (0xF00646B0u)
+#define MTU_MC54_RDBFL41 (0xF00646B2u)
+#define MTU_MC54_RDBFL42 (0xF00646B4u)
+#define MTU_MC54_RDBFL43 (0xF00646B6u)
+#define MTU_MC5_MCONTROL (0xF0061504u)
+#define MTU_MC54_RDBFL44 (0xF00646B8u)
+#define MTU_MC54_RDBFL45 (0xF00646BAu)
+#define MTU_MC54_RDBFL46 (0xF00646BCu)
+#define MTU_MC54_RDBFL47 (0xF00646BEu)
+#define MTU_MC54_RDBFL48 (0xF00646C0u)
+#define MTU_MC54_RDBFL49 (0xF00646C2u)
+#define MTU_MC54_RDBFL50 (0xF00646C4u)
+#define MTU_MC54_RDBFL51 (0xF00646C6u)
+#define MTU_MC54_RDBFL52 (0xF00646C8u)
+#define MTU_MC54_RDBFL53 (0xF00646CAu)
+#define MTU_MC5_MSTATUS (0xF0061506u)
+#define MTU_MC54_RDBFL54 (0xF00646CCu)
+#define MTU_MC54_RDBFL55 (0xF00646CEu)
+#define MTU_MC54_RDBFL56 (0xF00646D0u)
+#define MTU_MC54_RDBFL57 (0xF00646D2u)
+#define MTU_MC54_RDBFL58 (0xF00646D4u)
+#define MTU_MC54_RDBFL59 (0xF00646D6u)
+#define MTU_MC54_RDBFL60 (0xF00646D8u)
+#define MTU_MC54_RDBFL61 (0xF00646DAu)
+#define MTU_MC54_RDBFL62 (0xF00646DCu)
+#define MTU_MC54_RDBFL63 (0xF00646DEu)
+#define MTU_MC5_RANGE (0xF0061508u)
+#define MTU_MC54_RDBFL64 (0xF00646E0u)
+#define MTU_MC54_RDBFL65 (0xF00646E2u)
+#define MTU_MC54_RDBFL66 (0xF00646E4u)
+#define MTU_MC54_ALMSRCS (0xF00646EEu)
+#define MTU_MC54_FAULTSTS (0xF00646F0u)
+#define MTU_MC54_ERRINFO0 (0xF00646F2u)
+#define MTU_MC54_ERRINFO1 (0xF00646F4u)
+#define MTU_MC54_ERRINFO2 (0xF00646F6u)
+#define MTU_MC54_ERRINFO3 (0xF00646F8u)
+#define MTU_MC54_ERRINFO4 (0xF00646FAu)
+#define MTU_MC5_REVID (0xF006150Cu)
+#define MTU_MC55_CONFIG0 (0xF0064700u)
+#define MTU_MC55_CONFIG1 (0xF0064702u)
+#define MTU_MC55_MCONTROL (0xF0064704u)
+#define MTU_MC55_MSTATUS (0xF0064706u)
+#define MTU_MC55_RANGE (0xF0064708u)
+#define MTU_MC55_REVID (0xF006470Cu)
+#define MTU_MC55_ECCS (0xF006470Eu)
+#define MTU_MC55_ECCD (0xF0064710u)
+#define MTU_MC55_ETRR0 (0xF0064712u)
+#define MTU_MC55_ETRR1 (0xF0064714u)
+#define MTU_MC5_ECCS (0xF006150Eu)
+#define MTU_MC55_ETRR2 (0xF0064716u)
+#define MTU_MC55_ETRR3 (0xF0064718u)
+#define MTU_MC55_ETRR4 (0xF006471Au)
+#define MTU_MC55_RDBFL0 (0xF0064760u)
+#define MTU_MC55_RDBFL1 (0xF0064762u)
+#define MTU_MC55_RDBFL2 (0xF0064764u)
+#define MTU_MC55_RDBFL3 (0xF0064766u)
+#define MTU_MC55_RDBFL4 (0xF0064768u)
+#define MTU_MC55_RDBFL5 (0xF006476Au)
+#define MTU_MC55_RDBFL6 (0xF006476Cu)
+#define MTU_MC5_ECCD (0xF0061510u)
+#define MTU_MC55_RDBFL7 (0xF006476Eu)
+#define MTU_MC55_RDBFL8 (0xF0064770u)
+#define MTU_MC55_RDBFL9 (0xF0064772u)
+#define MTU_MC55_RDBFL10 (0xF0064774u)
+#define MTU_MC55_RDBFL11 (0xF0064776u)
+#define MTU_MC55_RDBFL12 (0xF0064778u)
+#define MTU_MC55_RDBFL13 (0xF006477Au)
+#define MTU_MC55_RDBFL14 (0xF006477Cu)
+#define MTU_MC55_RDBFL15 (0xF006477Eu)
+#define MTU_MC55_RDBFL16 (0xF0064780u)
+#define MTU_MC5_ETRR0 (0xF0061512u)
+#define MTU_MC55_RDBFL17 (0xF0064782u)
+#define MTU_MC55_RDBFL18 (0xF0064784u)
+#define MTU_MC55_RDBFL19 (0xF0064786u)
+#define MTU_MC55_RDBFL20 (0xF0064788u)
+#define MTU_MC55_RDBFL21 (0xF006478Au)
+#define MTU_MC55_RDBFL22 (0xF006478Cu)
+#define MTU_MC55_RDBFL23 (0xF006478Eu)
+#define MTU_MC55_RDBFL24 (0xF0064790u)
+#define MTU_MC55_RDBFL25 (0xF0064792u)
+#define MTU_MC55_RDBFL26 (0xF0064794u)
+#define MTU_MC5_ETRR1 (0xF0061514u)
+#define MTU_MC0_ECCS (0xF006100Eu)
+#define MTU_MC55_RDBFL27 (0xF0064796u)
+#define MTU_MC55_RDBFL28 (0xF0064798u)
+#define MTU_MC55_RDBFL29 (0xF006479Au)
+#define MTU_MC55_RDBFL30 (0xF006479Cu)
+#define MTU_MC55_RDBFL31 (0xF006479Eu)
+#define MTU_MC55_RDBFL32 (0xF00647A0u)
+#define MTU_MC55_RDBFL33 (0xF00647A2u)
+#define MTU_MC55_RDBFL34 (0xF00647A4u)
+#define MTU_MC55_RDBFL35 (0xF00647A6u)
+#define MTU_MC55_RDBFL36 (0xF00647A8u)
+#define MTU_MC5_ETRR2 (0xF0061516u)
+#define MTU_MC55_RDBFL37 (0xF00647AAu)
+#define MTU_MC55_RDBFL38 (0xF00647ACu)
+#define MTU_MC55_RDBFL39 (0xF00647AEu)
+#define MTU_MC55_RDBFL40 (0xF00647B0u)
+#define MTU_MC55_RDBFL41 (0xF00647B2u)
+#define MTU_MC55_RDBFL42 (0xF00647B4u)
+#define MTU_MC55_RDBFL43 (0xF00647B6u)
+#define MTU_MC55_RDBFL44 (0xF00647B8u)
+#define MTU_MC55_RDBFL45 (0xF00647BAu)
+#define MTU_MC55_RDBFL46 (0xF00647BCu)
+#define MTU_MC5_ETRR3 (0xF0061518u)
+#define MTU_MC55_RDBFL47 (0xF00647BEu)
+#define MTU_MC55_RDBFL48 (0xF00647C0u)
+#define MTU_MC55_RDBFL49 (0xF00647C2u)
+#define MTU_MC55_RDBFL50 (0xF00647C4u)
+#define MTU_MC55_RDBFL51 (0xF00647C6u)
+#define MTU_MC55_RDBFL52 (0xF00647C8u)
+#define MTU_MC55_RDBFL53 (0xF00647CAu)
+#define MTU_MC55_RDBFL54 (0xF00647CCu)
+#define MTU_MC55_RDBFL55 (0xF00647CEu)
+#define MTU_MC55_RDBFL56 (0xF00647D0u)
+#define MTU_MC5_ETRR4 (0xF006151Au)
+#define MTU_MC55_RDBFL57 (0xF00647D2u)
+#define MTU_MC55_RDBFL58 (0xF00647D4u)
+#define MTU_MC55_RDBFL59 (0xF00647D6u)
+#define MTU_MC55_RDBFL60 (0xF00647D8u)
+#define MTU_MC55_RDBFL61 (0xF00647DAu)
+#define MTU_MC55_RDBFL62 (0xF00647DCu)
+#define MTU_MC55_RDBFL63 (0xF00647DEu)
+#define MTU_MC55_RDBFL64 (0xF00647E0u)
+#define MTU_MC55_RDBFL65 (0xF00647E2u)
+#define MTU_MC55_RDBFL66 (0xF00647E4u)
+#define MTU_MC5_RDBFL0 (0xF0061560u)
+#define MTU_MC55_ALMSRCS (0xF00647EEu)
+#define MTU_MC55_FAULTSTS (0xF00647F0u)
+#define MTU_MC55_ERRINFO0 (0xF00647F2u)
+#define MTU_MC55_ERRINFO1 (0xF00647F4u)
+#define MTU_MC55_ERRINFO2 (0xF00647F6u)
+#define MTU_MC55_ERRINFO3 (0xF00647F8u)
+#define MTU_MC55_ERRINFO4 (0xF00647FAu)
+#define MTU_MC56_CONFIG0 (0xF0064800u)
+#define MTU_MC56_CONFIG1 (0xF0064802u)
+#define MTU_MC56_MCONTROL (0xF0064804u)
+#define MTU_MC5_RDBFL1 (0xF0061562u)
+#define MTU_MC56_MSTATUS (0xF0064806u)
+#define MTU_MC56_RANGE (0xF0064808u)
+#define MTU_MC56_REVID (0xF006480Cu)
+#define MTU_MC56_ECCS (0xF006480Eu)
+#define MTU_MC56_ECCD (0xF0064810u)
+#define MTU_MC56_ETRR0 (0xF0064812u)
+#define MTU_MC56_ETRR1 (0xF0064814u)
+#define MTU_MC56_ETRR2 (0xF0064816u)
+#define MTU_MC56_ETRR3 (0xF0064818u)
+#define MTU_MC56_ETRR4 (0xF006481Au)
+#define MTU_MC5_RDBFL2 (0xF0061564u)
+#define MTU_MC56_RDBFL0 (0xF0064860u)
+#define MTU_MC56_RDBFL1 (0xF0064862u)
+#define MTU_MC56_RDBFL2 (0xF0064864u)
+#define MTU_MC56_RDBFL3 (0xF0064866u)
+#define MTU_MC56_RDBFL4 (0xF0064868u)
+#define MTU_MC56_RDBFL5 (0xF006486Au)
+#define MTU_MC56_RDBFL6 (0xF006486Cu)
+#define MTU_MC56_RDBFL7 (0xF006486Eu)
+#define MTU_MC56_RDBFL8 (0xF0064870u)
+#define MTU_MC56_RDBFL9 (0xF0064872u)
+#define MTU_MC5_RDBFL3 (0xF0061566u)
+#define MTU_MC56_RDBFL10 (0xF0064874u)
+#define MTU_MC56_RDBFL11 (0xF0064876u)
+#define MTU_MC56_RDBFL12 (0xF0064878u)
+#define MTU_MC56_RDBFL13 (0xF006487Au)
+#define MTU_MC56_RDBFL14 (0xF006487Cu)
+#define MTU_MC56_RDBFL15 (0xF006487Eu)
+#define MTU_MC56_RDBFL16 (0xF0064880u)
+#define MTU_MC56_RDBFL17 (0xF0064882u)
+#define MTU_MC56_RDBFL18 (0xF0064884u)
+#define MTU_MC56_RDBFL19 (0xF0064886u)
+#define MTU_MC5_RDBFL4 (0xF0061568u)
+#define MTU_MC56_RDBFL20 (0xF0064888u)
+#define MTU_MC56_RDBFL21 (0xF006488Au)
+#define MTU_MC56_RDBFL22 (0xF006488Cu)
+#define MTU_MC56_RDBFL23 (0xF006488Eu)
+#define MTU_MC56_RDBFL24 (0xF0064890u)
+#define MTU_MC56_RDBFL25 (0xF0064892u)
+#define MTU_MC56_RDBFL26 (0xF0064894u)
+#define MTU_MC56_RDBFL27 (0xF0064896u)
+#define MTU_MC56_RDBFL28 (0xF0064898u)
+#define MTU_MC56_RDBFL29 (0xF006489Au)
+#define MTU_MC5_RDBFL5 (0xF006156Au)
+#define MTU_MC56_RDBFL30 (0xF006489Cu)
+#define MTU_MC56_RDBFL31 (0xF006489Eu)
+#define MTU_MC56_RDBFL32 (0xF00648A0u)
+#define MTU_MC56_RDBFL33 (0xF00648A2u)
+#define MTU_MC56_RDBFL34 (0xF00648A4u)
+#define MTU_MC56_RDBFL35 (0xF00648A6u)
+#define MTU_MC56_RDBFL36 (0xF00648A8u)
+#define MTU_MC56_RDBFL37 (0xF00648AAu)
+#define MTU_MC56_RDBFL38 (0xF00648ACu)
+#define MTU_MC56_RDBFL39 (0xF00648AEu)
+#define MTU_MC5_RDBFL6 (0xF006156Cu)
+#define MTU_MC0_ECCD (0xF0061010u)
+#define MTU_MC56_RDBFL40 (0xF00648B0u)
+#define MTU_MC56_RDBFL41 (0xF00648B2u)
+#define MTU_MC56_RDBFL42 (0xF00648B4u)
+#define MTU_MC56_RDBFL43 (0xF00648B6u)
+#define MTU_MC56_RDBFL44 (0xF00648B8u)
+#define MTU_MC56_RDBFL45 (0xF00648BAu)
+#define MTU_MC56_RDBFL46 (0xF00648BCu)
+#define MTU_MC56_RDBFL47 (0xF00648BEu)
+#define MTU_MC56_RDBFL48 (0xF00648C0u)
+#define MTU_MC56_RDBFL49 (0xF00648C2u)
+#define MTU_MC5_RDBFL7 (0xF006156Eu)
+#define MTU_MC56_RDBFL50 (0xF00648C4u)
+#define MTU_MC56_RDBFL51 (0xF00648C6u)
+#define MTU_MC56_RDBFL52 (0xF00648C8u)
+#define MTU_MC56_RDBFL53 (0xF00648CAu)
+#define MTU_MC56_RDBFL54 (0xF00648CCu)
+#define MTU_MC56_RDBFL55 (0xF00648CEu)
+#define MTU_MC56_RDBFL56 (0xF00648D0u)
+#define MTU_MC56_RDBFL57 (0xF00648D2u)
+#define MTU_MC56_RDBFL58 (0xF00648D4u)
+#define MTU_MC56_RDBFL59 (0xF00648D6u)
+#define MTU_MC5_RDBFL8 (0xF0061570u)
+#define MTU_MC56_RDBFL60 (0xF00648D8u)
+#define MTU_MC56_RDBFL61 (0xF00648DAu)
+#define MTU_MC56_RDBFL62 (0xF00648DCu)
+#define MTU_MC56_RDBFL63 (0xF00648DEu)
+#define MTU_MC56_RDBFL64 (0xF00648E0u)
+#define MTU_MC56_RDBFL65 (0xF00648E2u)
+#define MTU_MC56_RDBFL66 (0xF00648E4u)
+#define MTU_MC56_ALMSRCS (0xF00648EEu)
+#define MTU_MC56_FAULTSTS (0xF00648F0u)
+#define MTU_MC56_ERRINFO0 (0xF00648F2u)
+#define MTU_MC5_RDBFL9 (0xF0061572u)
+#define MTU_MC56_ERRINFO1 (0xF00648F4u)
+#define MTU_MC56_ERRINFO2 (0xF00648F6u)
+#define MTU_MC56_ERRINFO3 (0xF00648F8u)
+#define MTU_MC56_ERRINFO4 (0xF00648FAu)
+#define MTU_MC57_CONFIG0 (0xF0064900u)
+#define MTU_MC57_CONFIG1 (0xF0064902u)
+#define MTU_MC57_MCONTROL (0xF0064904u)
+#define MTU_MC57_MSTATUS (0xF0064906u)
+#define MTU_MC57_RANGE (0xF0064908u)
+#define MTU_MC57_REVID (0xF006490Cu)
+#define MTU_MC5_RDBFL10 (0xF0061574u)
+#define MTU_MC57_ECCS (0xF006490Eu)
+#define MTU_MC57_ECCD (0xF0064910u)
+#define MTU_MC57_ETRR0 (0xF0064912u)
+#define MTU_MC57_ETRR1 (0xF0064914u)
+#define MTU_MC57_ETRR2 (0xF0064916u)
+#define MTU_MC57_ETRR3 (0xF0064918u)
+#define MTU_MC57_ETRR4 (0xF006491Au)
+#define MTU_MC57_RDBFL0 (0xF0064960u)
+#define MTU_MC57_RDBFL1 (0xF0064962u)
+#define MTU_MC57_RDBFL2 (0xF0064964u)
+#define MTU_MC5_RDBFL11 (0xF0061576u)
+#define MTU_MC57_RDBFL3 (0xF0064966u)
+#define MTU_MC57_RDBFL4 (0xF0064968u)
+#define MTU_MC57_RDBFL5 (0xF006496Au)
+#define MTU_MC57_RDBFL6 (0xF006496Cu)
+#define MTU_MC57_RDBFL7 (0xF006496Eu)
+#define MTU_MC57_RDBFL8 (0xF0064970u)
+#define MTU_MC57_RDBFL9 (0xF0064972u)
+#define MTU_MC57_RDBFL10 (0xF0064974u)
+#define MTU_MC57_RDBFL11 (0xF0064976u)
+#define MTU_MC57_RDBFL12 (0xF0064978u)
+#define MTU_MC5_RDBFL12 (0xF0061578u)
+#define MTU_MC57_RDBFL13 (0xF006497Au)
+#define MTU_MC57_RDBFL14 (0xF006497Cu)
+#define MTU_MC57_RDBFL15 (0xF006497Eu)
+#define MTU_MC57_RDBFL16 (0xF0064980u)
+#define MTU_MC57_RDBFL17 (0xF0064982u)
+#define MTU_MC57_RDBFL18 (0xF0064984u)
+#define MTU_MC57_RDBFL19 (0xF0064986u)
+#define MTU_MC57_RDBFL20 (0xF0064988u)
+#define MTU_MC57_RDBFL21 (0xF006498Au)
+#define MTU_MC57_RDBFL22 (0xF006498Cu)
+#define MTU_MC5_RDBFL13 (0xF006157Au)
+#define MTU_MC57_RDBFL23 (0xF006498Eu)
+#define MTU_MC57_RDBFL24 (0xF0064990u)
+#define MTU_MC57_RDBFL25 (0xF0064992u)
+#define MTU_MC57_RDBFL26 (0xF0064994u)
+#define MTU_MC57_RDBFL27 (0xF0064996u)
+#define MTU_MC57_RDBFL28 (0xF0064998u)
+#define MTU_MC57_RDBFL29 (0xF006499Au)
+#define MTU_MC57_RDBFL30 (0xF006499Cu)
+#define MTU_MC57_RDBFL31 (0xF006499Eu)
+#define MTU_MC57_RDBFL32 (0xF00649A0u)
+#define MTU_MC5_RDBFL14 (0xF006157Cu)
+#define MTU_MC57_RDBFL33 (0xF00649A2u)
+#define MTU_MC57_RDBFL34 (0xF00649A4u)
+#define MTU_MC57_RDBFL35 (0xF00649A6u)
+#define MTU_MC57_RDBFL36 (0xF00649A8u)
+#define MTU_MC57_RDBFL37 (0xF00649AAu)
+#define MTU_MC57_RDBFL38 (0xF00649ACu)
+#define MTU_MC57_RDBFL39 (0xF00649AEu)
+#define MTU_MC57_RDBFL40 (0xF00649B0u)
+#define MTU_MC57_RDBFL41 (0xF00649B2u)
+#define MTU_MC57_RDBFL42 (0xF00649B4u)
+#define MTU_MC5_RDBFL15 (0xF006157Eu)
+#define MTU_MC57_RDBFL43 (0xF00649B6u)
+#define MTU_MC57_RDBFL44 (0xF00649B8u)
+#define MTU_MC57_RDBFL45 (0xF00649BAu)
+#define MTU_MC57_RDBFL46 (0xF00649BCu)
+#define MTU_MC57_RDBFL47 (0xF00649BEu)
+#define MTU_MC57_RDBFL48 (0xF00649C0u)
+#define MTU_MC57_RDBFL49 (0xF00649C2u)
+#define MTU_MC57_RDBFL50 (0xF00649C4u)
+#define MTU_MC57_RDBFL51 (0xF00649C6u)
+#define MTU_MC57_RDBFL52 (0xF00649C8u)
+#define MTU_MC5_RDBFL16 (0xF0061580u)
+#define MTU_MC0_ETRR0 (0xF0061012u)
+#define MTU_MC57_RDBFL53 (0xF00649CAu)
+#define MTU_MC57_RDBFL54 (0xF00649CCu)
+#define MTU_MC57_RDBFL55 (0xF00649CEu)
+#define MTU_MC57_RDBFL56 (0xF00649D0u)
+#define MTU_MC57_RDBFL57 (0xF00649D2u)
+#define MTU_MC57_RDBFL58 (0xF00649D4u)
+#define MTU_MC57_RDBFL59 (0xF00649D6u)
+#define MTU_MC57_RDBFL60 (0xF00649D8u)
+#define MTU_MC57_RDBFL61 (0xF00649DAu)
+#define MTU_MC57_RDBFL62 (0xF00649DCu)
+#define MTU_MC5_RDBFL17 (0xF0061582u)
+#define MTU_MC57_RDBFL63 (0xF00649DEu)
+#define MTU_MC57_RDBFL64 (0xF00649E0u)
+#define MTU_MC57_RDBFL65 (0xF00649E2u)
+#define MTU_MC57_RDBFL66 (0xF00649E4u)
+#define MTU_MC57_ALMSRCS (0xF00649EEu)
+#define MTU_MC57_FAULTSTS (0xF00649F0u)
+#define MTU_MC57_ERRINFO0 (0xF00649F2u)
+#define MTU_MC57_ERRINFO1 (0xF00649F4u)
+#define MTU_MC57_ERRINFO2 (0xF00649F6u)
+#define MTU_MC57_ERRINFO3 (0xF00649F8u)
+#define MTU_MC5_RDBFL18 (0xF0061584u)
+#define MTU_MC57_ERRINFO4 (0xF00649FAu)
+#define MTU_MC58_CONFIG0 (0xF0064A00u)
+#define MTU_MC58_CONFIG1 (0xF0064A02u)
+#define MTU_MC58_MCONTROL (0xF0064A04u)
+#define MTU_MC58_MSTATUS (0xF0064A06u)
+#define MTU_MC58_RANGE (0xF0064A08u)
+#define MTU_MC58_REVID (0xF0064A0Cu)
+#define MTU_MC58_ECCS (0xF0064A0Eu)
+#define MTU_MC58_ECCD (0xF0064A10u)
+#define MTU_MC58_ETRR0 (0xF0064A12u)
+#define MTU_MC5_RDBFL19 (0xF0061586u)
+#define MTU_MC58_ETRR1 (0xF0064A14u)
+#define MTU_MC58_ETRR2 (0xF0064A16u)
+#define MTU_MC58_ETRR3 (0xF0064A18u)
+#define MTU_MC58_ETRR4 (0xF0064A1Au)
+#define MTU_MC58_RDBFL0 (0xF0064A60u)
+#define MTU_MC58_RDBFL1 (0xF0064A62u)
+#define MTU_MC58_RDBFL2 (0xF0064A64u)
+#define MTU_MC58_RDBFL3 (0xF0064A66u)
+#define MTU_MC58_RDBFL4 (0xF0064A68u)
+#define MTU_MC58_RDBFL5 (0xF0064A6Au)
+#define MTU_MC5_RDBFL20 (0xF0061588u)
+#define MTU_MC58_RDBFL6 (0xF0064A6Cu)
+#define MTU_MC58_RDBFL7 (0xF0064A6Eu)
+#define MTU_MC58_RDBFL8 (0xF0064A70u)
+#define MTU_MC58_RDBFL9 (0xF0064A72u)
+#define MTU_MC58_RDBFL10 (0xF0064A74u)
+#define MTU_MC58_RDBFL11 (0xF0064A76u)
+#define MTU_MC58_RDBFL12 (0xF0064A78u)
+#define MTU_MC58_RDBFL13 (0xF0064A7Au)
+#define MTU_MC58_RDBFL14 (0xF0064A7Cu)
+#define MTU_MC58_RDBFL15 (0xF0064A7Eu)
+#define MTU_MC5_RDBFL21 (0xF006158Au)
+#define MTU_MC58_RDBFL16 (0xF0064A80u)
+#define MTU_MC58_RDBFL17 (0xF0064A82u)
+#define MTU_MC58_RDBFL18 (0xF0064A84u)
+#define MTU_MC58_RDBFL19 (0xF0064A86u)
+#define MTU_MC58_RDBFL20 (0xF0064A88u)
+#define MTU_MC58_RDBFL21 (0xF0064A8Au)
+#define MTU_MC58_RDBFL22 (0xF0064A8Cu)
+#define MTU_MC58_RDBFL23 (0xF0064A8Eu)
+#define MTU_MC58_RDBFL24 (0xF0064A90u)
+#define MTU_MC58_RDBFL25 (0xF0064A92u)
+#define MTU_MC5_RDBFL22 (0xF006158Cu)
+#define MTU_MC58_RDBFL26 (0xF0064A94u)
+#define MTU_MC58_RDBFL27 (0xF0064A96u)
+#define MTU_MC58_RDBFL28 (0xF0064A98u)
+#define MTU_MC58_RDBFL29 (0xF0064A9Au)
+#define MTU_MC58_RDBFL30 (0xF0064A9Cu)
+#define MTU_MC58_RDBFL31 (0xF0064A9Eu)
+#define MTU_MC58_RDBFL32 (0xF0064AA0u)
+#define MTU_MC58_RDBFL33 (0xF0064AA2u)
+#define MTU_MC58_RDBFL34 (0xF0064AA4u)
+#define MTU_MC58_RDBFL35 (0xF0064AA6u)
+#define MTU_MC5_RDBFL23 (0xF006158Eu)
+#define MTU_MC58_RDBFL36 (0xF0064AA8u)
+#define MTU_MC58_RDBFL37 (0xF0064AAAu)
+#define MTU_MC58_RDBFL38 (0xF0064AACu)
+#define MTU_MC58_RDBFL39 (0xF0064AAEu)
+#define MTU_MC58_RDBFL40 (0xF0064AB0u)
+#define MTU_MC58_RDBFL41 (0xF0064AB2u)
+#define MTU_MC58_RDBFL42 (0xF0064AB4u)
+#define MTU_MC58_RDBFL43 (0xF0064AB6u)
+#define MTU_MC58_RDBFL44 (0xF0064AB8u)
+#define MTU_MC58_RDBFL45 (0xF0064ABAu)
+#define MTU_MC5_RDBFL24 (0xF0061590u)
+#define MTU_MC58_RDBFL46 (0xF0064ABCu)
+#define MTU_MC58_RDBFL47 (0xF0064ABEu)
+#define MTU_MC58_RDBFL48 (0xF0064AC0u)
+#define MTU_MC58_RDBFL49 (0xF0064AC2u)
+#define MTU_MC58_RDBFL50 (0xF0064AC4u)
+#define MTU_MC58_RDBFL51 (0xF0064AC6u)
+#define MTU_MC58_RDBFL52 (0xF0064AC8u)
+#define MTU_MC58_RDBFL53 (0xF0064ACAu)
+#define MTU_MC58_RDBFL54 (0xF0064ACCu)
+#define MTU_MC58_RDBFL55 (0xF0064ACEu)
+#define MTU_MC5_RDBFL25 (0xF0061592u)
+#define MTU_MC58_RDBFL56 (0xF0064AD0u)
+#define MTU_MC58_RDBFL57 (0xF0064AD2u)
+#define MTU_MC58_RDBFL58 (0xF0064AD4u)
+#define MTU_MC58_RDBFL59 (0xF0064AD6u)
+#define MTU_MC58_RDBFL60 (0xF0064AD8u)
+#define MTU_MC58_RDBFL61 (0xF0064ADAu)
+#define MTU_MC58_RDBFL62 (0xF0064ADCu)
+#define MTU_MC58_RDBFL63 (0xF0064ADEu)
+#define MTU_MC58_RDBFL64 (0xF0064AE0u)
+#define MTU_MC58_RDBFL65 (0xF0064AE2u)
+#define MTU_MC5_RDBFL26 (0xF0061594u)
+#define MTU_MC0_ETRR1 (0xF0061014u)
+#define MTU_MC58_RDBFL66 (0xF0064AE4u)
+#define MTU_MC58_ALMSRCS (0xF0064AEEu)
+#define MTU_MC58_FAULTSTS (0xF0064AF0u)
+#define MTU_MC58_ERRINFO0 (0xF0064AF2u)
+#define MTU_MC58_ERRINFO1 (0xF0064AF4u)
+#define MTU_MC58_ERRINFO2 (0xF0064AF6u)
+#define MTU_MC58_ERRINFO3 (0xF0064AF8u)
+#define MTU_MC58_ERRINFO4 (0xF0064AFAu)
+#define MTU_MC59_CONFIG0 (0xF0064B00u)
+#define MTU_MC59_CONFIG1 (0xF0064B02u)
+#define MTU_MC5_RDBFL27 (0xF0061596u)
+#define MTU_MC59_MCONTROL (0xF0064B04u)
+#define MTU_MC59_MSTATUS (0xF0064B06u)
+#define MTU_MC59_RANGE (0xF0064B08u)
+#define MTU_MC59_REVID (0xF0064B0Cu)
+#define MTU_MC59_ECCS (0xF0064B0Eu)
+#define MTU_MC59_ECCD (0xF0064B10u)
+#define MTU_MC59_ETRR0 (0xF0064B12u)
+#define MTU_MC59_ETRR1 (0xF0064B14u)
+#define MTU_MC59_ETRR2 (0xF0064B16u)
+#define MTU_MC59_ETRR3 (0xF0064B18u)
+#define MTU_MC5_RDBFL28 (0xF0061598u)
+#define MTU_MC59_ETRR4 (0xF0064B1Au)
+#define MTU_MC59_RDBFL0 (0xF0064B60u)
+#define MTU_MC59_RDBFL1 (0xF0064B62u)
+#define MTU_MC59_RDBFL2 (0xF0064B64u)
+#define MTU_MC59_RDBFL3 (0xF0064B66u)
+#define MTU_MC59_RDBFL4 (0xF0064B68u)
+#define MTU_MC59_RDBFL5 (0xF0064B6Au)
+#define MTU_MC59_RDBFL6 (0xF0064B6Cu)
+#define MTU_MC59_RDBFL7 (0xF0064B6Eu)
+#define MTU_MC59_RDBFL8 (0xF0064B70u)
+#define MTU_MC5_RDBFL29 (0xF006159Au)
+#define MTU_MC59_RDBFL9 (0xF0064B72u)
+#define MTU_MC59_RDBFL10 (0xF0064B74u)
+#define MTU_MC59_RDBFL11 (0xF0064B76u)
+#define MTU_MC59_RDBFL12 (0xF0064B78u)
+#define MTU_MC59_RDBFL13 (0xF0064B7Au)
+#define MTU_MC59_RDBFL14 (0xF0064B7Cu)
+#define MTU_MC59_RDBFL15 (0xF0064B7Eu)
+#define MTU_MC59_RDBFL16 (0xF0064B80u)
+#define MTU_MC59_RDBFL17 (0xF0064B82u)
+#define MTU_MC59_RDBFL18 (0xF0064B84u)
+#define MTU_MC5_RDBFL30 (0xF006159Cu)
+#define MTU_MC59_RDBFL19 (0xF0064B86u)
+#define MTU_MC59_RDBFL20 (0xF0064B88u)
+#define MTU_MC59_RDBFL21 (0xF0064B8Au)
+#define MTU_MC59_RDBFL22 (0xF0064B8Cu)
+#define MTU_MC59_RDBFL23 (0xF0064B8Eu)
+#define MTU_MC59_RDBFL24 (0xF0064B90u)
+#define MTU_MC59_RDBFL25 (0xF0064B92u)
+#define MTU_MC59_RDBFL26 (0xF0064B94u)
+#define MTU_MC59_RDBFL27 (0xF0064B96u)
+#define MTU_MC59_RDBFL28 (0xF0064B98u)
+#define MTU_MC5_RDBFL31 (0xF006159Eu)
+#define MTU_MC59_RDBFL29 (0xF0064B9Au)
+#define MTU_MC59_RDBFL30 (0xF0064B9Cu)
+#define MTU_MC59_RDBFL31 (0xF0064B9Eu)
+#define MTU_MC59_RDBFL32 (0xF0064BA0u)
+#define MTU_MC59_RDBFL33 (0xF0064BA2u)
+#define MTU_MC59_RDBFL34 (0xF0064BA4u)
+#define MTU_MC59_RDBFL35 (0xF0064BA6u)
+#define MTU_MC59_RDBFL36 (0xF0064BA8u)
+#define MTU_MC59_RDBFL37 (0xF0064BAAu)
+#define MTU_MC59_RDBFL38 (0xF0064BACu)
+#define MTU_MC5_RDBFL32 (0xF00615A0u)
+#define MTU_MC59_RDBFL39 (0xF0064BAEu)
+#define MTU_MC59_RDBFL40 (0xF0064BB0u)
+#define MTU_MC59_RDBFL41 (0xF0064BB2u)
+#define MTU_MC59_RDBFL42 (0xF0064BB4u)
+#define MTU_MC59_RDBFL43 (0xF0064BB6u)
+#define MTU_MC59_RDBFL44 (0xF0064BB8u)
+#define MTU_MC59_RDBFL45 (0xF0064BBAu)
+#define MTU_MC59_RDBFL46 (0xF0064BBCu)
+#define MTU_MC59_RDBFL47 (0xF0064BBEu)
+#define MTU_MC59_RDBFL48 (0xF0064BC0u)
+#define MTU_MC5_RDBFL33 (0xF00615A2u)
+#define MTU_MC59_RDBFL49 (0xF0064BC2u)
+#define MTU_MC59_RDBFL50 (0xF0064BC4u)
+#define MTU_MC59_RDBFL51 (0xF0064BC6u)
+#define MTU_MC59_RDBFL52 (0xF0064BC8u)
+#define MTU_MC59_RDBFL53 (0xF0064BCAu)
+#define MTU_MC59_RDBFL54 (0xF0064BCCu)
+#define MTU_MC59_RDBFL55 (0xF0064BCEu)
+#define MTU_MC59_RDBFL56 (0xF0064BD0u)
+#define MTU_MC59_RDBFL57 (0xF0064BD2u)
+#define MTU_MC59_RDBFL58 (0xF0064BD4u)
+#define MTU_MC5_RDBFL34 (0xF00615A4u)
+#define MTU_MC59_RDBFL59 (0xF0064BD6u)
+#define MTU_MC59_RDBFL60 (0xF0064BD8u)
+#define MTU_MC59_RDBFL61 (0xF0064BDAu)
+#define MTU_MC59_RDBFL62 (0xF0064BDCu)
+#define MTU_MC59_RDBFL63 (0xF0064BDEu)
+#define MTU_MC59_RDBFL64 (0xF0064BE0u)
+#define MTU_MC59_RDBFL65 (0xF0064BE2u)
+#define MTU_MC59_RDBFL66 (0xF0064BE4u)
+#define MTU_MC59_ALMSRCS (0xF0064BEEu)
+#define MTU_MC59_FAULTSTS (0xF0064BF0u)
+#define MTU_MC5_RDBFL35 (0xF00615A6u)
+#define MTU_MC59_ERRINFO0 (0xF0064BF2u)
+#define MTU_MC59_ERRINFO1 (0xF0064BF4u)
+#define MTU_MC59_ERRINFO2 (0xF0064BF6u)
+#define MTU_MC59_ERRINFO3 (0xF0064BF8u)
+#define MTU_MC59_ERRINFO4 (0xF0064BFAu)
+#define MTU_MC60_CONFIG0 (0xF0064C00u)
+#define MTU_MC60_CONFIG1 (0xF0064C02u)
+#define MTU_MC60_MCONTROL (0xF0064C04u)
+#define MTU_MC60_MSTATUS (0xF0064C06u)
+#define MTU_MC60_RANGE (0xF0064C08u)
+#define MTU_MC5_RDBFL36 (0xF00615A8u)
+#define MTU_MC0_ETRR2 (0xF0061016u)
+#define MTU_MC60_REVID (0xF0064C0Cu)
+#define MTU_MC60_ECCS (0xF0064C0Eu)
+#define MTU_MC60_ECCD (0xF0064C10u)
+#define MTU_MC60_ETRR0 (0xF0064C12u)
+#define MTU_MC60_ETRR1 (0xF0064C14u)
+#define MTU_MC60_ETRR2 (0xF0064C16u)
+#define MTU_MC60_ETRR3 (0xF0064C18u)
+#define MTU_MC60_ETRR4 (0xF0064C1Au)
+#define MTU_MC60_RDBFL0 (0xF0064C60u)
+#define MTU_MC60_RDBFL1 (0xF0064C62u)
+#define MTU_MC5_RDBFL37 (0xF00615AAu)
+#define MTU_MC60_RDBFL2 (0xF0064C64u)
+#define MTU_MC60_RDBFL3 (0xF0064C66u)
+#define MTU_MC60_RDBFL4 (0xF0064C68u)
+#define MTU_MC60_RDBFL5 (0xF0064C6Au)
+#define MTU_MC60_RDBFL6 (0xF0064C6Cu)
+#define MTU_MC60_RDBFL7 (0xF0064C6Eu)
+#define MTU_MC60_RDBFL8 (0xF0064C70u)
+#define MTU_MC60_RDBFL9 (0xF0064C72u)
+#define MTU_MC60_RDBFL10 (0xF0064C74u)
+#define MTU_MC60_RDBFL11 (0xF0064C76u)
+#define MTU_MC5_RDBFL38 (0xF00615ACu)
+#define MTU_MC60_RDBFL12 (0xF0064C78u)
+#define MTU_MC60_RDBFL13 (0xF0064C7Au)
+#define MTU_MC60_RDBFL14 (0xF0064C7Cu)
+#define MTU_MC60_RDBFL15 (0xF0064C7Eu)
+#define MTU_MC60_RDBFL16 (0xF0064C80u)
+#define MTU_MC60_RDBFL17 (0xF0064C82u)
+#define MTU_MC60_RDBFL18 (0xF0064C84u)
+#define MTU_MC60_RDBFL19 (0xF0064C86u)
+#define MTU_MC60_RDBFL20 (0xF0064C88u)
+#define MTU_MC60_RDBFL21 (0xF0064C8Au)
+#define MTU_MC5_RDBFL39 (0xF00615AEu)
+#define MTU_MC60_RDBFL22 (0xF0064C8Cu)
+#define MTU_MC60_RDBFL23 (0xF0064C8Eu)
+#define MTU_MC60_RDBFL24 (0xF0064C90u)
+#define MTU_MC60_RDBFL25 (0xF0064C92u)
+#define MTU_MC60_RDBFL26 (0xF0064C94u)
+#define MTU_MC60_RDBFL27 (0xF0064C96u)
+#define MTU_MC60_RDBFL28 (0xF0064C98u)
+#define MTU_MC60_RDBFL29 (0xF0064C9Au)
+#define MTU_MC60_RDBFL30 (0xF0064C9Cu)
+#define MTU_MC60_RDBFL31 (0xF0064C9Eu)
+#define MTU_MC5_RDBFL40 (0xF00615B0u)
+#define MTU_MC60_RDBFL32 (0xF0064CA0u)
+#define MTU_MC60_RDBFL33 (0xF0064CA2u)
+#define MTU_MC60_RDBFL34 (0xF0064CA4u)
+#define MTU_MC60_RDBFL35 (0xF0064CA6u)
+#define MTU_MC60_RDBFL36 (0xF0064CA8u)
+#define MTU_MC60_RDBFL37 (0xF0064CAAu)
+#define MTU_MC60_RDBFL38 (0xF0064CACu)
+#define MTU_MC60_RDBFL39 (0xF0064CAEu)
+#define MTU_MC60_RDBFL40 (0xF0064CB0u)
+#define MTU_MC60_RDBFL41 (0xF0064CB2u)
+#define MTU_MC5_RDBFL41 (0xF00615B2u)
+#define MTU_MC60_RDBFL42 (0xF0064CB4u)
+#define MTU_MC60_RDBFL43 (0xF0064CB6u)
+#define MTU_MC60_RDBFL44 (0xF0064CB8u)
+#define MTU_MC60_RDBFL45 (0xF0064CBAu)
+#define MTU_MC60_RDBFL46 (0xF0064CBCu)
+#define MTU_MC60_RDBFL47 (0xF0064CBEu)
+#define MTU_MC60_RDBFL48 (0xF0064CC0u)
+#define MTU_MC60_RDBFL49 (0xF0064CC2u)
+#define MTU_MC60_RDBFL50 (0xF0064CC4u)
+#define MTU_MC60_RDBFL51 (0xF0064CC6u)
+#define MTU_MC5_RDBFL42 (0xF00615B4u)
+#define MTU_MC60_RDBFL52 (0xF0064CC8u)
+#define MTU_MC60_RDBFL53 (0xF0064CCAu)
+#define MTU_MC60_RDBFL54 (0xF0064CCCu)
+#define MTU_MC60_RDBFL55 (0xF0064CCEu)
+#define MTU_MC60_RDBFL56 (0xF0064CD0u)
+#define MTU_MC60_RDBFL57 (0xF0064CD2u)
+#define MTU_MC60_RDBFL58 (0xF0064CD4u)
+#define MTU_MC60_RDBFL59 (0xF0064CD6u)
+#define MTU_MC60_RDBFL60 (0xF0064CD8u)
+#define MTU_MC60_RDBFL61 (0xF0064CDAu)
+#define MTU_MC5_RDBFL43 (0xF00615B6u)
+#define MTU_MC60_RDBFL62 (0xF0064CDCu)
+#define MTU_MC60_RDBFL63 (0xF0064CDEu)
+#define MTU_MC60_RDBFL64 (0xF0064CE0u)
+#define MTU_MC60_RDBFL65 (0xF0064CE2u)
+#define MTU_MC60_RDBFL66 (0xF0064CE4u)
+#define MTU_MC60_ALMSRCS (0xF0064CEEu)
+#define MTU_MC60_FAULTSTS (0xF0064CF0u)
+#define MTU_MC60_ERRINFO0 (0xF0064CF2u)
+#define MTU_MC60_ERRINFO1 (0xF0064CF4u)
+#define MTU_MC60_ERRINFO2 (0xF0064CF6u)
+#define MTU_MC5_RDBFL44 (0xF00615B8u)
+#define MTU_MC60_ERRINFO3 (0xF0064CF8u)
+#define MTU_MC60_ERRINFO4 (0xF0064CFAu)
+#define MTU_MC61_CONFIG0 (0xF0064D00u)
+#define MTU_MC61_CONFIG1 (0xF0064D02u)
+#define MTU_MC61_MCONTROL (0xF0064D04u)
+#define MTU_MC61_MSTATUS (0xF0064D06u)
+#define MTU_MC61_RANGE (0xF0064D08u)
+#define MTU_MC61_REVID (0xF0064D0Cu)
+#define MTU_MC61_ECCS (0xF0064D0Eu)
+#define MTU_MC61_ECCD (0xF0064D10u)
+#define MTU_MC5_RDBFL45 (0xF00615BAu)
+#define MTU_MC61_ETRR0 (0xF0064D12u)
+#define MTU_MC61_ETRR1 (0xF0064D14u)
+#define MTU_MC61_ETRR2 (0xF0064D16u)
+#define MTU_MC61_ETRR3 (0xF0064D18u)
+#define MTU_MC61_ETRR4 (0xF0064D1Au)
+#define MTU_MC61_RDBFL0 (0xF0064D60u)
+#define MTU_MC61_RDBFL1 (0xF0064D62u)
+#define MTU_MC61_RDBFL2 (0xF0064D64u)
+#define MTU_MC61_RDBFL3 (0xF0064D66u)
+#define MTU_MC61_RDBFL4 (0xF0064D68u)
+#define MTU_MC5_RDBFL46 (0xF00615BCu)
+#define MTU_MC0_ETRR3 (0xF0061018u)
+#define MTU_MC61_RDBFL5 (0xF0064D6Au)
+#define MTU_MC61_RDBFL6 (0xF0064D6Cu)
+#define MTU_MC61_RDBFL7 (0xF0064D6Eu)
+#define MTU_MC61_RDBFL8 (0xF0064D70u)
+#define MTU_MC61_RDBFL9 (0xF0064D72u)
+#define MTU_MC61_RDBFL10 (0xF0064D74u)
+#define MTU_MC61_RDBFL11 (0xF0064D76u)
+#define MTU_MC61_RDBFL12 (0xF0064D78u)
+#define MTU_MC61_RDBFL13 (0xF0064D7Au)
+#define MTU_MC61_RDBFL14 (0xF0064D7Cu)
+#define MTU_MC5_RDBFL47 (0xF00615BEu)
+#define MTU_MC61_RDBFL15 (0xF0064D7Eu)
+#define MTU_MC61_RDBFL16 (0xF0064D80u)
+#define MTU_MC61_RDBFL17 (0xF0064D82u)
+#define MTU_MC61_RDBFL18 (0xF0064D84u)
+#define MTU_MC61_RDBFL19 (0xF0064D86u)
+#define MTU_MC61_RDBFL20 (0xF0064D88u)
+#define MTU_MC61_RDBFL21 (0xF0064D8Au)
+#define MTU_MC61_RDBFL22 (0xF0064D8Cu)
+#define MTU_MC61_RDBFL23 (0xF0064D8Eu)
+#define MTU_MC61_RDBFL24 (0xF0064D90u)
+#define MTU_MC5_RDBFL48 (0xF00615C0u)
+#define MTU_MC61_RDBFL25 (0xF0064D92u)
+#define MTU_MC61_RDBFL26 (0xF0064D94u)
+#define MTU_MC61_RDBFL27 (0xF0064D96u)
+#define MTU_MC61_RDBFL28 (0xF0064D98u)
+#define MTU_MC61_RDBFL29 (0xF0064D9Au)
+#define MTU_MC61_RDBFL30 (0xF0064D9Cu)
+#define MTU_MC61_RDBFL31 (0xF0064D9Eu)
+#define MTU_MC61_RDBFL32 (0xF0064DA0u)
+#define MTU_MC61_RDBFL33 (0xF0064DA2u)
+#define MTU_MC61_RDBFL34 (0xF0064DA4u)
+#define MTU_MC5_RDBFL49 (0xF00615C2u)
+#define MTU_MC61_RDBFL35 (0xF0064DA6u)
+#define MTU_MC61_RDBFL36 (0xF0064DA8u)
+#define MTU_MC61_RDBFL37 (0xF0064DAAu)
+#define MTU_MC61_RDBFL38 (0xF0064DACu)
+#define MTU_MC61_RDBFL39 (0xF0064DAEu)
+#define MTU_MC61_RDBFL40 (0xF0064DB0u)
+#define MTU_MC61_RDBFL41 (0xF0064DB2u)
+#define MTU_MC61_RDBFL42 (0xF0064DB4u)
+#define MTU_MC61_RDBFL43 (0xF0064DB6u)
+#define MTU_MC61_RDBFL44 (0xF0064DB8u)
+#define MTU_MC5_RDBFL50 (0xF00615C4u)
+#define MTU_MC61_RDBFL45 (0xF0064DBAu)
+#define MTU_MC61_RDBFL46 (0xF0064DBCu)
+#define MTU_MC61_RDBFL47 (0xF0064DBEu)
+#define MTU_MC61_RDBFL48 (0xF0064DC0u)
+#define MTU_MC61_RDBFL49 (0xF0064DC2u)
+#define MTU_MC61_RDBFL50 (0xF0064DC4u)
+#define MTU_MC61_RDBFL51 (0xF0064DC6u)
+#define MTU_MC61_RDBFL52 (0xF0064DC8u)
+#define MTU_MC61_RDBFL53 (0xF0064DCAu)
+#define MTU_MC61_RDBFL54 (0xF0064DCCu)
+#define MTU_MC5_RDBFL51 (0xF00615C6u)
+#define MTU_MC61_RDBFL55 (0xF0064DCEu)
+#define MTU_MC61_RDBFL56 (0xF0064DD0u)
+#define MTU_MC61_RDBFL57 (0xF0064DD2u)
+#define MTU_MC61_RDBFL58 (0xF0064DD4u)
+#define MTU_MC61_RDBFL59 (0xF0064DD6u)
+#define MTU_MC61_RDBFL60 (0xF0064DD8u)
+#define MTU_MC61_RDBFL61 (0xF0064DDAu)
+#define MTU_MC61_RDBFL62 (0xF0064DDCu)
+#define MTU_MC61_RDBFL63 (0xF0064DDEu)
+#define MTU_MC61_RDBFL64 (0xF0064DE0u)
+#define MTU_MC5_RDBFL52 (0xF00615C8u)
+#define MTU_MC61_RDBFL65 (0xF0064DE2u)
+#define MTU_MC61_RDBFL66 (0xF0064DE4u)
+#define MTU_MC61_ALMSRCS (0xF0064DEEu)
+#define MTU_MC61_FAULTSTS (0xF0064DF0u)
+#define MTU_MC61_ERRINFO0 (0xF0064DF2u)
+#define MTU_MC61_ERRINFO1 (0xF0064DF4u)
+#define MTU_MC61_ERRINFO2 (0xF0064DF6u)
+#define MTU_MC61_ERRINFO3 (0xF0064DF8u)
+#define MTU_MC61_ERRINFO4 (0xF0064DFAu)
+#define MTU_MC62_CONFIG0 (0xF0064E00u)
+#define MTU_MC5_RDBFL53 (0xF00615CAu)
+#define MTU_MC62_CONFIG1 (0xF0064E02u)
+#define MTU_MC62_MCONTROL (0xF0064E04u)
+#define MTU_MC62_MSTATUS (0xF0064E06u)
+#define MTU_MC62_RANGE (0xF0064E08u)
+#define MTU_MC62_REVID (0xF0064E0Cu)
+#define MTU_MC62_ECCS (0xF0064E0Eu)
+#define MTU_MC62_ECCD (0xF0064E10u)
+#define MTU_MC62_ETRR0 (0xF0064E12u)
+#define MTU_MC62_ETRR1 (0xF0064E14u)
+#define MTU_MC62_ETRR2 (0xF0064E16u)
+#define MTU_MC5_RDBFL54 (0xF00615CCu)
+#define MTU_MC62_ETRR3 (0xF0064E18u)
+#define MTU_MC62_ETRR4 (0xF0064E1Au)
+#define MTU_MC62_RDBFL0 (0xF0064E60u)
+#define MTU_MC62_RDBFL1 (0xF0064E62u)
+#define MTU_MC62_RDBFL2 (0xF0064E64u)
+#define MTU_MC62_RDBFL3 (0xF0064E66u)
+#define MTU_MC62_RDBFL4 (0xF0064E68u)
+#define MTU_MC62_RDBFL5 (0xF0064E6Au)
+#define MTU_MC62_RDBFL6 (0xF0064E6Cu)
+#define MTU_MC62_RDBFL7 (0xF0064E6Eu)
+#define MTU_MC5_RDBFL55 (0xF00615CEu)
+#define MTU_MC62_RDBFL8 (0xF0064E70u)
+#define MTU_MC62_RDBFL9 (0xF0064E72u)
+#define MTU_MC62_RDBFL10 (0xF0064E74u)
+#define MTU_MC62_RDBFL11 (0xF0064E76u)
+#define MTU_MC62_RDBFL12 (0xF0064E78u)
+#define MTU_MC62_RDBFL13 (0xF0064E7Au)
+#define MTU_MC62_RDBFL14 (0xF0064E7Cu)
+#define MTU_MC62_RDBFL15 (0xF0064E7Eu)
+#define MTU_MC62_RDBFL16 (0xF0064E80u)
+#define MTU_MC62_RDBFL17 (0xF0064E82u)
+#define MTU_MC5_RDBFL56 (0xF00615D0u)
+#define MTU_MC0_ETRR4 (0xF006101Au)
+#define MTU_MC62_RDBFL18 (0xF0064E84u)
+#define MTU_MC62_RDBFL19 (0xF0064E86u)
+#define MTU_MC62_RDBFL20 (0xF0064E88u)
+#define MTU_MC62_RDBFL21 (0xF0064E8Au)
+#define MTU_MC62_RDBFL22 (0xF0064E8Cu)
+#define MTU_MC62_RDBFL23 (0xF0064E8Eu)
+#define MTU_MC62_RDBFL24 (0xF0064E90u)
+#define MTU_MC62_RDBFL25 (0xF0064E92u)
+#define MTU_MC62_RDBFL26 (0xF0064E94u)
+#define MTU_MC62_RDBFL27 (0xF0064E96u)
+#define MTU_MC5_RDBFL57 (0xF00615D2u)
+#define MTU_MC62_RDBFL28 (0xF0064E98u)
+#define MTU_MC62_RDBFL29 (0xF0064E9Au)
+#define MTU_MC62_RDBFL30 (0xF0064E9Cu)
+#define MTU_MC62_RDBFL31 (0xF0064E9Eu)
+#define MTU_MC62_RDBFL32 (0xF0064EA0u)
+#define MTU_MC62_RDBFL33 (0xF0064EA2u)
+#define MTU_MC62_RDBFL34 (0xF0064EA4u)
+#define MTU_MC62_RDBFL35 (0xF0064EA6u)
+#define MTU_MC62_RDBFL36 (0xF0064EA8u)
+#define MTU_MC62_RDBFL37 (0xF0064EAAu)
+#define MTU_MC5_RDBFL58 (0xF00615D4u)
+#define MTU_MC62_RDBFL38 (0xF0064EACu)
+#define MTU_MC62_RDBFL39 (0xF0064EAEu)
+#define MTU_MC62_RDBFL40 (0xF0064EB0u)
+#define MTU_MC62_RDBFL41 (0xF0064EB2u)
+#define MTU_MC62_RDBFL42 (0xF0064EB4u)
+#define MTU_MC62_RDBFL43 (0xF0064EB6u)
+#define MTU_MC62_RDBFL44 (0xF0064EB8u)
+#define MTU_MC62_RDBFL45 (0xF0064EBAu)
+#define MTU_MC62_RDBFL46 (0xF0064EBCu)
+#define MTU_MC62_RDBFL47 (0xF0064EBEu)
+#define MTU_MC5_RDBFL59 (0xF00615D6u)
+#define MTU_MC62_RDBFL48 (0xF0064EC0u)
+#define MTU_MC62_RDBFL49 (0xF0064EC2u)
+#define MTU_MC62_RDBFL50 (0xF0064EC4u)
+#define MTU_MC62_RDBFL51 (0xF0064EC6u)
+#define MTU_MC62_RDBFL52 (0xF0064EC8u)
+#define MTU_MC62_RDBFL53 (0xF0064ECAu)
+#define MTU_MC62_RDBFL54 (0xF0064ECCu)
+#define MTU_MC62_RDBFL55 (0xF0064ECEu)
+#define MTU_MC62_RDBFL56 (0xF0064ED0u)
+#define MTU_MC62_RDBFL57 (0xF0064ED2u)
+#define MTU_MC5_RDBFL60 (0xF00615D8u)
+#define MTU_MC62_RDBFL58 (0xF0064ED4u)
+#define MTU_MC62_RDBFL59 (0xF0064ED6u)
+#define MTU_MC62_RDBFL60 (0xF0064ED8u)
+#define MTU_MC62_RDBFL61 (0xF0064EDAu)
+#define MTU_MC62_RDBFL62 (0xF0064EDCu)
+#define MTU_MC62_RDBFL63 (0xF0064EDEu)
+#define MTU_MC62_RDBFL64 (0xF0064EE0u)
+#define MTU_MC62_RDBFL65 (0xF0064EE2u)
+#define MTU_MC62_RDBFL66 (0xF0064EE4u)
+#define MTU_MC62_ALMSRCS (0xF0064EEEu)
+#define MTU_MC5_RDBFL61 (0xF00615DAu)
+#define MTU_MC62_FAULTSTS (0xF0064EF0u)
+#define MTU_MC62_ERRINFO0 (0xF0064EF2u)
+#define MTU_MC62_ERRINFO1 (0xF0064EF4u)
+#define MTU_MC62_ERRINFO2 (0xF0064EF6u)
+#define MTU_MC62_ERRINFO3 (0xF0064EF8u)
+#define MTU_MC62_ERRINFO4 (0xF0064EFAu)
+#define MTU_MC63_CONFIG0 (0xF0064F00u)
+#define MTU_MC63_CONFIG1 (0xF0064F02u)
+#define MTU_MC63_MCONTROL (0xF0064F04u)
+#define MTU_MC63_MSTATUS (0xF0064F06u)
+#define MTU_MC5_RDBFL62 (0xF00615DCu)
+#define MTU_MC63_RANGE (0xF0064F08u)
+#define MTU_MC63_REVID (0xF0064F0Cu)
+#define MTU_MC63_ECCS (0xF0064F0Eu)
+#define MTU_MC63_ECCD (0xF0064F10u)
+#define MTU_MC63_ETRR0 (0xF0064F12u)
+#define MTU_MC63_ETRR1 (0xF0064F14u)
+#define MTU_MC63_ETRR2 (0xF0064F16u)
+#define MTU_MC63_ETRR3 (0xF0064F18u)
+#define MTU_MC63_ETRR4 (0xF0064F1Au)
+#define MTU_MC63_RDBFL0 (0xF0064F60u)
+#define MTU_MC5_RDBFL63 (0xF00615DEu)
+#define MTU_MC63_RDBFL1 (0xF0064F62u)
+#define MTU_MC63_RDBFL2 (0xF0064F64u)
+#define MTU_MC63_RDBFL3 (0xF0064F66u)
+#define MTU_MC63_RDBFL4 (0xF0064F68u)
+#define MTU_MC63_RDBFL5 (0xF0064F6Au)
+#define MTU_MC63_RDBFL6 (0xF0064F6Cu)
+#define MTU_MC63_RDBFL7 (0xF0064F6Eu)
+#define MTU_MC63_RDBFL8 (0xF0064F70u)
+#define MTU_MC63_RDBFL9 (0xF0064F72u)
+#define MTU_MC63_RDBFL10 (0xF0064F74u)
+#define MTU_MC5_RDBFL64 (0xF00615E0u)
+#define MTU_MC63_RDBFL11 (0xF0064F76u)
+#define MTU_MC63_RDBFL12 (0xF0064F78u)
+#define MTU_MC63_RDBFL13 (0xF0064F7Au)
+#define MTU_MC63_RDBFL14 (0xF0064F7Cu)
+#define MTU_MC63_RDBFL15 (0xF0064F7Eu)
+#define MTU_MC63_RDBFL16 (0xF0064F80u)
+#define MTU_MC63_RDBFL17 (0xF0064F82u)
+#define MTU_MC63_RDBFL18 (0xF0064F84u)
+#define MTU_MC63_RDBFL19 (0xF0064F86u)
+#define MTU_MC63_RDBFL20 (0xF0064F88u)
+#define MTU_MC5_RDBFL65 (0xF00615E2u)
+#define MTU_MC63_RDBFL21 (0xF0064F8Au)
+#define MTU_MC63_RDBFL22 (0xF0064F8Cu)
+#define MTU_MC63_RDBFL23 (0xF0064F8Eu)
+#define MTU_MC63_RDBFL24 (0xF0064F90u)
+#define MTU_MC63_RDBFL25 (0xF0064F92u)
+#define MTU_MC63_RDBFL26 (0xF0064F94u)
+#define MTU_MC63_RDBFL27 (0xF0064F96u)
+#define MTU_MC63_RDBFL28 (0xF0064F98u)
+#define MTU_MC63_RDBFL29 (0xF0064F9Au)
+#define MTU_MC63_RDBFL30 (0xF0064F9Cu)
+#define MTU_MC5_RDBFL66 (0xF00615E4u)
+#define MTU_MC0_RDBFL0 (0xF0061060u)
+#define MTU_MC63_RDBFL31 (0xF0064F9Eu)
+#define MTU_MC63_RDBFL32 (0xF0064FA0u)
+#define MTU_MC63_RDBFL33 (0xF0064FA2u)
+#define MTU_MC63_RDBFL34 (0xF0064FA4u)
+#define MTU_MC63_RDBFL35 (0xF0064FA6u)
+#define MTU_MC63_RDBFL36 (0xF0064FA8u)
+#define MTU_MC63_RDBFL37 (0xF0064FAAu)
+#define MTU_MC63_RDBFL38 (0xF0064FACu)
+#define MTU_MC63_RDBFL39 (0xF0064FAEu)
+#define MTU_MC63_RDBFL40 (0xF0064FB0u)
+#define MTU_MC5_ALMSRCS (0xF00615EEu)
+#define MTU_MC63_RDBFL41 (0xF0064FB2u)
+#define MTU_MC63_RDBFL42 (0xF0064FB4u)
+#define MTU_MC63_RDBFL43 (0xF0064FB6u)
+#define MTU_MC63_RDBFL44 (0xF0064FB8u)
+#define MTU_MC63_RDBFL45 (0xF0064FBAu)
+#define MTU_MC63_RDBFL46 (0xF0064FBCu)
+#define MTU_MC63_RDBFL47 (0xF0064FBEu)
+#define MTU_MC63_RDBFL48 (0xF0064FC0u)
+#define MTU_MC63_RDBFL49 (0xF0064FC2u)
+#define MTU_MC63_RDBFL50 (0xF0064FC4u)
+#define MTU_MC5_FAULTSTS (0xF00615F0u)
+#define MTU_MC63_RDBFL51 (0xF0064FC6u)
+#define MTU_MC63_RDBFL52 (0xF0064FC8u)
+#define MTU_MC63_RDBFL53 (0xF0064FCAu)
+#define MTU_MC63_RDBFL54 (0xF0064FCCu)
+#define MTU_MC63_RDBFL55 (0xF0064FCEu)
+#define MTU_MC63_RDBFL56 (0xF0064FD0u)
+#define MTU_MC63_RDBFL57 (0xF0064FD2u)
+#define MTU_MC63_RDBFL58 (0xF0064FD4u)
+#define MTU_MC63_RDBFL59 (0xF0064FD6u)
+#define MTU_MC63_RDBFL60 (0xF0064FD8u)
+#define MTU_MC5_ERRINFO0 (0xF00615F2u)
+#define MTU_MC63_RDBFL61 (0xF0064FDAu)
+#define MTU_MC63_RDBFL62 (0xF0064FDCu)
+#define MTU_MC63_RDBFL63 (0xF0064FDEu)
+#define MTU_MC63_RDBFL64 (0xF0064FE0u)
+#define MTU_MC63_RDBFL65 (0xF0064FE2u)
+#define MTU_MC63_RDBFL66 (0xF0064FE4u)
+#define MTU_MC63_ALMSRCS (0xF0064FEEu)
+#define MTU_MC63_FAULTSTS (0xF0064FF0u)
+#define MTU_MC63_ERRINFO0 (0xF0064FF2u)
+#define MTU_MC63_ERRINFO1 (0xF0064FF4u)
+#define MTU_MC5_ERRINFO1 (0xF00615F4u)
+#define MTU_MC63_ERRINFO2 (0xF0064FF6u)
+#define MTU_MC63_ERRINFO3 (0xF0064FF8u)
+#define MTU_MC63_ERRINFO4 (0xF0064FFAu)
+#define MTU_MC64_CONFIG0 (0xF0065000u)
+#define MTU_MC64_CONFIG1 (0xF0065002u)
+#define MTU_MC64_MCONTROL (0xF0065004u)
+#define MTU_MC64_MSTATUS (0xF0065006u)
+#define MTU_MC64_RANGE (0xF0065008u)
+#define MTU_MC64_REVID (0xF006500Cu)
+#define MTU_MC64_ECCS (0xF006500Eu)
+#define MTU_MC5_ERRINFO2 (0xF00615F6u)
+#define MTU_MC64_ECCD (0xF0065010u)
+#define MTU_MC64_ETRR0 (0xF0065012u)
+#define MTU_MC64_ETRR1 (0xF0065014u)
+#define MTU_MC64_ETRR2 (0xF0065016u)
+#define MTU_MC64_ETRR3 (0xF0065018u)
+#define MTU_MC64_ETRR4 (0xF006501Au)
+#define MTU_MC64_RDBFL0 (0xF0065060u)
+#define MTU_MC64_RDBFL1 (0xF0065062u)
+#define MTU_MC64_RDBFL2 (0xF0065064u)
+#define MTU_MC64_RDBFL3 (0xF0065066u)
+#define MTU_MC5_ERRINFO3 (0xF00615F8u)
+#define MTU_MC64_RDBFL4 (0xF0065068u)
+#define MTU_MC64_RDBFL5 (0xF006506Au)
+#define MTU_MC64_RDBFL6 (0xF006506Cu)
+#define MTU_MC64_RDBFL7 (0xF006506Eu)
+#define MTU_MC64_RDBFL8 (0xF0065070u)
+#define MTU_MC64_RDBFL9 (0xF0065072u)
+#define MTU_MC64_RDBFL10 (0xF0065074u)
+#define MTU_MC64_RDBFL11 (0xF0065076u)
+#define MTU_MC64_RDBFL12 (0xF0065078u)
+#define MTU_MC64_RDBFL13 (0xF006507Au)
+#define MTU_MC5_ERRINFO4 (0xF00615FAu)
+#define MTU_MC64_RDBFL14 (0xF006507Cu)
+#define MTU_MC64_RDBFL15 (0xF006507Eu)
+#define MTU_MC64_RDBFL16 (0xF0065080u)
+#define MTU_MC64_RDBFL17 (0xF0065082u)
+#define MTU_MC64_RDBFL18 (0xF0065084u)
+#define MTU_MC64_RDBFL19 (0xF0065086u)
+#define MTU_MC64_RDBFL20 (0xF0065088u)
+#define MTU_MC64_RDBFL21 (0xF006508Au)
+#define MTU_MC64_RDBFL22 (0xF006508Cu)
+#define MTU_MC64_RDBFL23 (0xF006508Eu)
+#define MTU_MC6_CONFIG0 (0xF0061600u)
+#define MTU_MC64_RDBFL24 (0xF0065090u)
+#define MTU_MC64_RDBFL25 (0xF0065092u)
+#define MTU_MC64_RDBFL26 (0xF0065094u)
+#define MTU_MC64_RDBFL27 (0xF0065096u)
+#define MTU_MC64_RDBFL28 (0xF0065098u)
+#define MTU_MC64_RDBFL29 (0xF006509Au)
+#define MTU_MC64_RDBFL30 (0xF006509Cu)
+#define MTU_MC64_RDBFL31 (0xF006509Eu)
+#define MTU_MC64_RDBFL32 (0xF00650A0u)
+#define MTU_MC64_RDBFL33 (0xF00650A2u)
+#define MTU_MC6_CONFIG1 (0xF0061602u)
+#define MTU_MC64_RDBFL34 (0xF00650A4u)
+#define MTU_MC64_RDBFL35 (0xF00650A6u)
+#define MTU_MC64_RDBFL36 (0xF00650A8u)
+#define MTU_MC64_RDBFL37 (0xF00650AAu)
+#define MTU_MC64_RDBFL38 (0xF00650ACu)
+#define MTU_MC64_RDBFL39 (0xF00650AEu)
+#define MTU_MC64_RDBFL40 (0xF00650B0u)
+#define MTU_MC64_RDBFL41 (0xF00650B2u)
+#define MTU_MC64_RDBFL42 (0xF00650B4u)
+#define MTU_MC64_RDBFL43 (0xF00650B6u)
+#define MTU_MC6_MCONTROL (0xF0061604u)
+#define MTU_MC0_RDBFL1 (0xF0061062u)
+#define MTU_MC64_RDBFL44 (0xF00650B8u)
+#define MTU_MC64_RDBFL45 (0xF00650BAu)
+#define MTU_MC64_RDBFL46 (0xF00650BCu)
+#define MTU_MC64_RDBFL47 (0xF00650BEu)
+#define MTU_MC64_RDBFL48 (0xF00650C0u)
+#define MTU_MC64_RDBFL49 (0xF00650C2u)
+#define MTU_MC64_RDBFL50 (0xF00650C4u)
+#define MTU_MC64_RDBFL51 (0xF00650C6u)
+#define MTU_MC64_RDBFL52 (0xF00650C8u)
+#define MTU_MC64_RDBFL53 (0xF00650CAu)
+#define MTU_MC6_MSTATUS (0xF0061606u)
+#define MTU_MC64_RDBFL54 (0xF00650CCu)
+#define MTU_MC64_RDBFL55 (0xF00650CEu)
+#define MTU_MC64_RDBFL56 (0xF00650D0u)
+#define MTU_MC64_RDBFL57 (0xF00650D2u)
+#define MTU_MC64_RDBFL58 (0xF00650D4u)
+#define MTU_MC64_RDBFL59 (0xF00650D6u)
+#define MTU_MC64_RDBFL60 (0xF00650D8u)
+#define MTU_MC64_RDBFL61 (0xF00650DAu)
+#define MTU_MC64_RDBFL62 (0xF00650DCu)
+#define MTU_MC64_RDBFL63 (0xF00650DEu)
+#define MTU_MC6_RANGE (0xF0061608u)
+#define MTU_MC64_RDBFL64 (0xF00650E0u)
+#define MTU_MC64_RDBFL65 (0xF00650E2u)
+#define MTU_MC64_RDBFL66 (0xF00650E4u)
+#define MTU_MC64_ALMSRCS (0xF00650EEu)
+#define MTU_MC64_FAULTSTS (0xF00650F0u)
+#define MTU_MC64_ERRINFO0 (0xF00650F2u)
+#define MTU_MC64_ERRINFO1 (0xF00650F4u)
+#define MTU_MC64_ERRINFO2 (0xF00650F6u)
+#define MTU_MC64_ERRINFO3 (0xF00650F8u)
+#define MTU_MC64_ERRINFO4 (0xF00650FAu)
+#define MTU_MC6_REVID (0xF006160Cu)
+#define MTU_MC65_CONFIG0 (0xF0065100u)
+#define MTU_MC65_CONFIG1 (0xF0065102u)
+#define MTU_MC65_MCONTROL (0xF0065104u)
+#define MTU_MC65_MSTATUS (0xF0065106u)
+#define MTU_MC65_RANGE (0xF0065108u)
+#define MTU_MC65_REVID (0xF006510Cu)
+#define MTU_MC65_ECCS (0xF006510Eu)
+#define MTU_MC65_ECCD (0xF0065110u)
+#define MTU_MC65_ETRR0 (0xF0065112u)
+#define MTU_MC65_ETRR1 (0xF0065114u)
+#define MTU_MC6_ECCS (0xF006160Eu)
+#define MTU_MC65_ETRR2 (0xF0065116u)
+#define MTU_MC65_ETRR3 (0xF0065118u)
+#define MTU_MC65_ETRR4 (0xF006511Au)
+#define MTU_MC65_RDBFL0 (0xF0065160u)
+#define MTU_MC65_RDBFL1 (0xF0065162u)
+#define MTU_MC65_RDBFL2 (0xF0065164u)
+#define MTU_MC65_RDBFL3 (0xF0065166u)
+#define MTU_MC65_RDBFL4 (0xF0065168u)
+#define MTU_MC65_RDBFL5 (0xF006516Au)
+#define MTU_MC65_RDBFL6 (0xF006516Cu)
+#define MTU_MC6_ECCD (0xF0061610u)
+#define MTU_MC65_RDBFL7 (0xF006516Eu)
+#define MTU_MC65_RDBFL8 (0xF0065170u)
+#define MTU_MC65_RDBFL9 (0xF0065172u)
+#define MTU_MC65_RDBFL10 (0xF0065174u)
+#define MTU_MC65_RDBFL11 (0xF0065176u)
+#define MTU_MC65_RDBFL12 (0xF0065178u)
+#define MTU_MC65_RDBFL13 (0xF006517Au)
+#define MTU_MC65_RDBFL14 (0xF006517Cu)
+#define MTU_MC65_RDBFL15 (0xF006517Eu)
+#define MTU_MC65_RDBFL16 (0xF0065180u)
+#define MTU_MC6_ETRR0 (0xF0061612u)
+#define MTU_MC65_RDBFL17 (0xF0065182u)
+#define MTU_MC65_RDBFL18 (0xF0065184u)
+#define MTU_MC65_RDBFL19 (0xF0065186u)
+#define MTU_MC65_RDBFL20 (0xF0065188u)
+#define MTU_MC65_RDBFL21 (0xF006518Au)
+#define MTU_MC65_RDBFL22 (0xF006518Cu)
+#define MTU_MC65_RDBFL23 (0xF006518Eu)
+#define MTU_MC65_RDBFL24 (0xF0065190u)
+#define MTU_MC65_RDBFL25 (0xF0065192u)
+#define MTU_MC65_RDBFL26 (0xF0065194u)
+#define MTU_MC6_ETRR1 (0xF0061614u)
+#define MTU_MC65_RDBFL27 (0xF0065196u)
+#define MTU_MC65_RDBFL28 (0xF0065198u)
+#define MTU_MC65_RDBFL29 (0xF006519Au)
+#define MTU_MC65_RDBFL30 (0xF006519Cu)
+#define MTU_MC65_RDBFL31 (0xF006519Eu)
+#define MTU_MC65_RDBFL32 (0xF00651A0u)
+#define MTU_MC65_RDBFL33 (0xF00651A2u)
+#define MTU_MC65_RDBFL34 (0xF00651A4u)
+#define MTU_MC65_RDBFL35 (0xF00651A6u)
+#define MTU_MC65_RDBFL36 (0xF00651A8u)
+#define MTU_MC6_ETRR2 (0xF0061616u)
+#define MTU_MC65_RDBFL37 (0xF00651AAu)
+#define MTU_MC65_RDBFL38 (0xF00651ACu)
+#define MTU_MC65_RDBFL39 (0xF00651AEu)
+#define MTU_MC65_RDBFL40 (0xF00651B0u)
+#define MTU_MC65_RDBFL41 (0xF00651B2u)
+#define MTU_MC65_RDBFL42 (0xF00651B4u)
+#define MTU_MC65_RDBFL43 (0xF00651B6u)
+#define MTU_MC65_RDBFL44 (0xF00651B8u)
+#define MTU_MC65_RDBFL45 (0xF00651BAu)
+#define MTU_MC65_RDBFL46 (0xF00651BCu)
+#define MTU_MC6_ETRR3 (0xF0061618u)
+#define MTU_MC65_RDBFL47 (0xF00651BEu)
+#define MTU_MC65_RDBFL48 (0xF00651C0u)
+#define MTU_MC65_RDBFL49 (0xF00651C2u)
+#define MTU_MC65_RDBFL50 (0xF00651C4u)
+#define MTU_MC65_RDBFL51 (0xF00651C6u)
+#define MTU_MC65_RDBFL52 (0xF00651C8u)
+#define MTU_MC65_RDBFL53 (0xF00651CAu)
+#define MTU_MC65_RDBFL54 (0xF00651CCu)
+#define MTU_MC65_RDBFL55 (0xF00651CEu)
+#define MTU_MC65_RDBFL56 (0xF00651D0u)
+#define MTU_MC6_ETRR4 (0xF006161Au)
+#define MTU_MC0_RDBFL2 (0xF0061064u)
+#define MTU_MC65_RDBFL57 (0xF00651D2u)
+#define MTU_MC65_RDBFL58 (0xF00651D4u)
+#define MTU_MC65_RDBFL59 (0xF00651D6u)
+#define MTU_MC65_RDBFL60 (0xF00651D8u)
+#define MTU_MC65_RDBFL61 (0xF00651DAu)
+#define MTU_MC65_RDBFL62 (0xF00651DCu)
+#define MTU_MC65_RDBFL63 (0xF00651DEu)
+#define MTU_MC65_RDBFL64 (0xF00651E0u)
+#define MTU_MC65_RDBFL65 (0xF00651E2u)
+#define MTU_MC65_RDBFL66 (0xF00651E4u)
+#define MTU_MC6_RDBFL0 (0xF0061660u)
+#define MTU_MC65_ALMSRCS (0xF00651EEu)
+#define MTU_MC65_FAULTSTS (0xF00651F0u)
+#define MTU_MC65_ERRINFO0 (0xF00651F2u)
+#define MTU_MC65_ERRINFO1 (0xF00651F4u)
+#define MTU_MC65_ERRINFO2 (0xF00651F6u)
+#define MTU_MC65_ERRINFO3 (0xF00651F8u)
+#define MTU_MC65_ERRINFO4 (0xF00651FAu)
+#define MTU_MC66_CONFIG0 (0xF0065200u)
+#define MTU_MC66_CONFIG1 (0xF0065202u)
+#define MTU_MC66_MCONTROL (0xF0065204u)
+#define MTU_MC6_RDBFL1 (0xF0061662u)
+#define MTU_MC66_MSTATUS (0xF0065206u)
+#define MTU_MC66_RANGE (0xF0065208u)
+#define MTU_MC66_REVID (0xF006520Cu)
+#define MTU_MC66_ECCS (0xF006520Eu)
+#define MTU_MC66_ECCD (0xF0065210u)
+#define MTU_MC66_ETRR0 (0xF0065212u)
+#define MTU_MC66_ETRR1 (0xF0065214u)
+#define MTU_MC66_ETRR2 (0xF0065216u)
+#define MTU_MC66_ETRR3 (0xF0065218u)
+#define MTU_MC66_ETRR4 (0xF006521Au)
+#define MTU_MC6_RDBFL2 (0xF0061664u)
+#define MTU_MC66_RDBFL0 (0xF0065260u)
+#define MTU_MC66_RDBFL1 (0xF0065262u)
+#define MTU_MC66_RDBFL2 (0xF0065264u)
+#define MTU_MC66_RDBFL3 (0xF0065266u)
+#define MTU_MC66_RDBFL4 (0xF0065268u)
+#define MTU_MC66_RDBFL5 (0xF006526Au)
+#define MTU_MC66_RDBFL6 (0xF006526Cu)
+#define MTU_MC66_RDBFL7 (0xF006526Eu)
+#define MTU_MC66_RDBFL8 (0xF0065270u)
+#define MTU_MC66_RDBFL9 (0xF0065272u)
+#define MTU_MC6_RDBFL3 (0xF0061666u)
+#define MTU_MC66_RDBFL10 (0xF0065274u)
+#define MTU_MC66_RDBFL11 (0xF0065276u)
+#define MTU_MC66_RDBFL12 (0xF0065278u)
+#define MTU_MC66_RDBFL13 (0xF006527Au)
+#define MTU_MC66_RDBFL14 (0xF006527Cu)
+#define MTU_MC66_RDBFL15 (0xF006527Eu)
+#define MTU_MC66_RDBFL16 (0xF0065280u)
+#define MTU_MC66_RDBFL17 (0xF0065282u)
+#define MTU_MC66_RDBFL18 (0xF0065284u)
+#define MTU_MC66_RDBFL19 (0xF0065286u)
+#define MTU_MC6_RDBFL4 (0xF0061668u)
+#define MTU_MC66_RDBFL20 (0xF0065288u)
+#define MTU_MC66_RDBFL21 (0xF006528Au)
+#define MTU_MC66_RDBFL22 (0xF006528Cu)
+#define MTU_MC66_RDBFL23 (0xF006528Eu)
+#define MTU_MC66_RDBFL24 (0xF0065290u)
+#define MTU_MC66_RDBFL25 (0xF0065292u)
+#define MTU_MC66_RDBFL26 (0xF0065294u)
+#define MTU_MC66_RDBFL27 (0xF0065296u)
+#define MTU_MC66_RDBFL28 (0xF0065298u)
+#define MTU_MC66_RDBFL29 (0xF006529Au)
+#define MTU_MC6_RDBFL5 (0xF006166Au)
+#define MTU_MC66_RDBFL30 (0xF006529Cu)
+#define MTU_MC66_RDBFL31 (0xF006529Eu)
+#define MTU_MC66_RDBFL32 (0xF00652A0u)
+#define MTU_MC66_RDBFL33 (0xF00652A2u)
+#define MTU_MC66_RDBFL34 (0xF00652A4u)
+#define MTU_MC66_RDBFL35 (0xF00652A6u)
+#define MTU_MC66_RDBFL36 (0xF00652A8u)
+#define MTU_MC66_RDBFL37 (0xF00652AAu)
+#define MTU_MC66_RDBFL38 (0xF00652ACu)
+#define MTU_MC66_RDBFL39 (0xF00652AEu)
+#define MTU_MC6_RDBFL6 (0xF006166Cu)
+#define MTU_MC66_RDBFL40 (0xF00652B0u)
+#define MTU_MC66_RDBFL41 (0xF00652B2u)
+#define MTU_MC66_RDBFL42 (0xF00652B4u)
+#define MTU_MC66_RDBFL43 (0xF00652B6u)
+#define MTU_MC66_RDBFL44 (0xF00652B8u)
+#define MTU_MC66_RDBFL45 (0xF00652BAu)
+#define MTU_MC66_RDBFL46 (0xF00652BCu)
+#define MTU_MC66_RDBFL47 (0xF00652BEu)
+#define MTU_MC66_RDBFL48 (0xF00652C0u)
+#define MTU_MC66_RDBFL49 (0xF00652C2u)
+#define MTU_MC6_RDBFL7 (0xF006166Eu)
+#define MTU_MC66_RDBFL50 (0xF00652C4u)
+#define MTU_MC66_RDBFL51 (0xF00652C6u)
+#define MTU_MC66_RDBFL52 (0xF00652C8u)
+#define MTU_MC66_RDBFL53 (0xF00652CAu)
+#define MTU_MC66_RDBFL54 (0xF00652CCu)
+#define MTU_MC66_RDBFL55 (0xF00652CEu)
+#define MTU_MC66_RDBFL56 (0xF00652D0u)
+#define MTU_MC66_RDBFL57 (0xF00652D2u)
+#define MTU_MC66_RDBFL58 (0xF00652D4u)
+#define MTU_MC66_RDBFL59 (0xF00652D6u)
+#define MTU_MC6_RDBFL8 (0xF0061670u)
+#define MTU_MC66_RDBFL60 (0xF00652D8u)
+#define MTU_MC66_RDBFL61 (0xF00652DAu)
+#define MTU_MC66_RDBFL62 (0xF00652DCu)
+#define MTU_MC66_RDBFL63 (0xF00652DEu)
+#define MTU_MC66_RDBFL64 (0xF00652E0u)
+#define MTU_MC66_RDBFL65 (0xF00652E2u)
+#define MTU_MC66_RDBFL66 (0xF00652E4u)
+#define MTU_MC66_ALMSRCS (0xF00652EEu)
+#define MTU_MC66_FAULTSTS (0xF00652F0u)
+#define MTU_MC66_ERRINFO0 (0xF00652F2u)
+#define MTU_MC6_RDBFL9 (0xF0061672u)
+#define MTU_MC0_RDBFL3 (0xF0061066u)
+#define MTU_MC66_ERRINFO1 (0xF00652F4u)
+#define MTU_MC66_ERRINFO2 (0xF00652F6u)
+#define MTU_MC66_ERRINFO3 (0xF00652F8u)
+#define MTU_MC66_ERRINFO4 (0xF00652FAu)
+#define MTU_MC67_CONFIG0 (0xF0065300u)
+#define MTU_MC67_CONFIG1 (0xF0065302u)
+#define MTU_MC67_MCONTROL (0xF0065304u)
+#define MTU_MC67_MSTATUS (0xF0065306u)
+#define MTU_MC67_RANGE (0xF0065308u)
+#define MTU_MC67_REVID (0xF006530Cu)
+#define MTU_MC6_RDBFL10 (0xF0061674u)
+#define MTU_MC67_ECCS (0xF006530Eu)
+#define MTU_MC67_ECCD (0xF0065310u)
+#define MTU_MC67_ETRR0 (0xF0065312u)
+#define MTU_MC67_ETRR1 (0xF0065314u)
+#define MTU_MC67_ETRR2 (0xF0065316u)
+#define MTU_MC67_ETRR3 (0xF0065318u)
+#define MTU_MC67_ETRR4 (0xF006531Au)
+#define MTU_MC67_RDBFL0 (0xF0065360u)
+#define MTU_MC67_RDBFL1 (0xF0065362u)
+#define MTU_MC67_RDBFL2 (0xF0065364u)
+#define MTU_MC6_RDBFL11 (0xF0061676u)
+#define MTU_MC67_RDBFL3 (0xF0065366u)
+#define MTU_MC67_RDBFL4 (0xF0065368u)
+#define MTU_MC67_RDBFL5 (0xF006536Au)
+#define MTU_MC67_RDBFL6 (0xF006536Cu)
+#define MTU_MC67_RDBFL7 (0xF006536Eu)
+#define MTU_MC67_RDBFL8 (0xF0065370u)
+#define MTU_MC67_RDBFL9 (0xF0065372u)
+#define MTU_MC67_RDBFL10 (0xF0065374u)
+#define MTU_MC67_RDBFL11 (0xF0065376u)
+#define MTU_MC67_RDBFL12 (0xF0065378u)
+#define MTU_MC6_RDBFL12 (0xF0061678u)
+#define MTU_MC67_RDBFL13 (0xF006537Au)
+#define MTU_MC67_RDBFL14 (0xF006537Cu)
+#define MTU_MC67_RDBFL15 (0xF006537Eu)
+#define MTU_MC67_RDBFL16 (0xF0065380u)
+#define MTU_MC67_RDBFL17 (0xF0065382u)
+#define MTU_MC67_RDBFL18 (0xF0065384u)
+#define MTU_MC67_RDBFL19 (0xF0065386u)
+#define MTU_MC67_RDBFL20 (0xF0065388u)
+#define MTU_MC67_RDBFL21 (0xF006538Au)
+#define MTU_MC67_RDBFL22 (0xF006538Cu)
+#define MTU_MC6_RDBFL13 (0xF006167Au)
+#define MTU_MC67_RDBFL23 (0xF006538Eu)
+#define MTU_MC67_RDBFL24 (0xF0065390u)
+#define MTU_MC67_RDBFL25 (0xF0065392u)
+#define MTU_MC67_RDBFL26 (0xF0065394u)
+#define MTU_MC67_RDBFL27 (0xF0065396u)
+#define MTU_MC67_RDBFL28 (0xF0065398u)
+#define MTU_MC67_RDBFL29 (0xF006539Au)
+#define MTU_MC67_RDBFL30 (0xF006539Cu)
+#define MTU_MC67_RDBFL31 (0xF006539Eu)
+#define MTU_MC67_RDBFL32 (0xF00653A0u)
+#define MTU_MC6_RDBFL14 (0xF006167Cu)
+#define MTU_MC67_RDBFL33 (0xF00653A2u)
+#define MTU_MC67_RDBFL34 (0xF00653A4u)
+#define MTU_MC67_RDBFL35 (0xF00653A6u)
+#define MTU_MC67_RDBFL36 (0xF00653A8u)
+#define MTU_MC67_RDBFL37 (0xF00653AAu)
+#define MTU_MC67_RDBFL38 (0xF00653ACu)
+#define MTU_MC67_RDBFL39 (0xF00653AEu)
+#define MTU_MC67_RDBFL40 (0xF00653B0u)
+#define MTU_MC67_RDBFL41 (0xF00653B2u)
+#define MTU_MC67_RDBFL42 (0xF00653B4u)
+#define MTU_MC6_RDBFL15 (0xF006167Eu)
+#define MTU_MC67_RDBFL43 (0xF00653B6u)
+#define MTU_MC67_RDBFL44 (0xF00653B8u)
+#define MTU_MC67_RDBFL45 (0xF00653BAu)
+#define MTU_MC67_RDBFL46 (0xF00653BCu)
+#define MTU_MC67_RDBFL47 (0xF00653BEu)
+#define MTU_MC67_RDBFL48 (0xF00653C0u)
+#define MTU_MC67_RDBFL49 (0xF00653C2u)
+#define MTU_MC67_RDBFL50 (0xF00653C4u)
+#define MTU_MC67_RDBFL51 (0xF00653C6u)
+#define MTU_MC67_RDBFL52 (0xF00653C8u)
+#define MTU_MC6_RDBFL16 (0xF0061680u)
+#define MTU_MC67_RDBFL53 (0xF00653CAu)
+#define MTU_MC67_RDBFL54 (0xF00653CCu)
+#define MTU_MC67_RDBFL55 (0xF00653CEu)
+#define MTU_MC67_RDBFL56 (0xF00653D0u)
+#define MTU_MC67_RDBFL57 (0xF00653D2u)
+#define MTU_MC67_RDBFL58 (0xF00653D4u)
+#define MTU_MC67_RDBFL59 (0xF00653D6u)
+#define MTU_MC67_RDBFL60 (0xF00653D8u)
+#define MTU_MC67_RDBFL61 (0xF00653DAu)
+#define MTU_MC67_RDBFL62 (0xF00653DCu)
+#define MTU_MC6_RDBFL17 (0xF0061682u)
+#define MTU_MC67_RDBFL63 (0xF00653DEu)
+#define MTU_MC67_RDBFL64 (0xF00653E0u)
+#define MTU_MC67_RDBFL65 (0xF00653E2u)
+#define MTU_MC67_RDBFL66 (0xF00653E4u)
+#define MTU_MC67_ALMSRCS (0xF00653EEu)
+#define MTU_MC67_FAULTSTS (0xF00653F0u)
+#define MTU_MC67_ERRINFO0 (0xF00653F2u)
+#define MTU_MC67_ERRINFO1 (0xF00653F4u)
+#define MTU_MC67_ERRINFO2 (0xF00653F6u)
+#define MTU_MC67_ERRINFO3 (0xF00653F8u)
+#define MTU_MC6_RDBFL18 (0xF0061684u)
+#define MTU_MC67_ERRINFO4 (0xF00653FAu)
+#define MTU_MC68_CONFIG0 (0xF0065400u)
+#define MTU_MC68_CONFIG1 (0xF0065402u)
+#define MTU_MC68_MCONTROL (0xF0065404u)
+#define MTU_MC68_MSTATUS (0xF0065406u)
+#define MTU_MC68_RANGE (0xF0065408u)
+#define MTU_MC68_REVID (0xF006540Cu)
+#define MTU_MC68_ECCS (0xF006540Eu)
+#define MTU_MC68_ECCD (0xF0065410u)
+#define MTU_MC68_ETRR0 (0xF0065412u)
+#define MTU_MC6_RDBFL19 (0xF0061686u)
+#define MTU_MC0_RDBFL4 (0xF0061068u)
+#define MTU_MC68_ETRR1 (0xF0065414u)
+#define MTU_MC68_ETRR2 (0xF0065416u)
+#define MTU_MC68_ETRR3 (0xF0065418u)
+#define MTU_MC68_ETRR4 (0xF006541Au)
+#define MTU_MC68_RDBFL0 (0xF0065460u)
+#define MTU_MC68_RDBFL1 (0xF0065462u)
+#define MTU_MC68_RDBFL2 (0xF0065464u)
+#define MTU_MC68_RDBFL3 (0xF0065466u)
+#define MTU_MC68_RDBFL4 (0xF0065468u)
+#define MTU_MC68_RDBFL5 (0xF006546Au)
+#define MTU_MC6_RDBFL20 (0xF0061688u)
+#define MTU_MC68_RDBFL6 (0xF006546Cu)
+#define MTU_MC68_RDBFL7 (0xF006546Eu)
+#define MTU_MC68_RDBFL8 (0xF0065470u)
+#define MTU_MC68_RDBFL9 (0xF0065472u)
+#define MTU_MC68_RDBFL10 (0xF0065474u)
+#define MTU_MC68_RDBFL11 (0xF0065476u)
+#define MTU_MC68_RDBFL12 (0xF0065478u)
+#define MTU_MC68_RDBFL13 (0xF006547Au)
+#define MTU_MC68_RDBFL14 (0xF006547Cu)
+#define MTU_MC68_RDBFL15 (0xF006547Eu)
+#define MTU_MC6_RDBFL21 (0xF006168Au)
+#define MTU_MC68_RDBFL16 (0xF0065480u)
+#define MTU_MC68_RDBFL17 (0xF0065482u)
+#define MTU_MC68_RDBFL18 (0xF0065484u)
+#define MTU_MC68_RDBFL19 (0xF0065486u)
+#define MTU_MC68_RDBFL20 (0xF0065488u)
+#define MTU_MC68_RDBFL21 (0xF006548Au)
+#define MTU_MC68_RDBFL22 (0xF006548Cu)
+#define MTU_MC68_RDBFL23 (0xF006548Eu)
+#define MTU_MC68_RDBFL24 (0xF0065490u)
+#define MTU_MC68_RDBFL25 (0xF0065492u)
+#define MTU_MC6_RDBFL22 (0xF006168Cu)
+#define MTU_MC68_RDBFL26 (0xF0065494u)
+#define MTU_MC68_RDBFL27 (0xF0065496u)
+#define MTU_MC68_RDBFL28 (0xF0065498u)
+#define MTU_MC68_RDBFL29 (0xF006549Au)
+#define MTU_MC68_RDBFL30 (0xF006549Cu)
+#define MTU_MC68_RDBFL31 (0xF006549Eu)
+#define MTU_MC68_RDBFL32 (0xF00654A0u)
+#define MTU_MC68_RDBFL33 (0xF00654A2u)
+#define MTU_MC68_RDBFL34 (0xF00654A4u)
+#define MTU_MC68_RDBFL35 (0xF00654A6u)
+#define MTU_MC6_RDBFL23 (0xF006168Eu)
+#define MTU_MC68_RDBFL36 (0xF00654A8u)
+#define MTU_MC68_RDBFL37 (0xF00654AAu)
+#define MTU_MC68_RDBFL38 (0xF00654ACu)
+#define MTU_MC68_RDBFL39 (0xF00654AEu)
+#define MTU_MC68_RDBFL40 (0xF00654B0u)
+#define MTU_MC68_RDBFL41 (0xF00654B2u)
+#define MTU_MC68_RDBFL42 (0xF00654B4u)
+#define MTU_MC68_RDBFL43 (0xF00654B6u)
+#define MTU_MC68_RDBFL44 (0xF00654B8u)
+#define MTU_MC68_RDBFL45 (0xF00654BAu)
+#define MTU_MC6_RDBFL24 (0xF0061690u)
+#define MTU_MC68_RDBFL46 (0xF00654BCu)
+#define MTU_MC68_RDBFL47 (0xF00654BEu)
+#define MTU_MC68_RDBFL48 (0xF00654C0u)
+#define MTU_MC68_RDBFL49 (0xF00654C2u)
+#define MTU_MC68_RDBFL50 (0xF00654C4u)
+#define MTU_MC68_RDBFL51 (0xF00654C6u)
+#define MTU_MC68_RDBFL52 (0xF00654C8u)
+#define MTU_MC68_RDBFL53 (0xF00654CAu)
+#define MTU_MC68_RDBFL54 (0xF00654CCu)
+#define MTU_MC68_RDBFL55 (0xF00654CEu)
+#define MTU_MC6_RDBFL25 (0xF0061692u)
+#define MTU_MC68_RDBFL56 (0xF00654D0u)
+#define MTU_MC68_RDBFL57 (0xF00654D2u)
+#define MTU_MC68_RDBFL58 (0xF00654D4u)
+#define MTU_MC68_RDBFL59 (0xF00654D6u)
+#define MTU_MC68_RDBFL60 (0xF00654D8u)
+#define MTU_MC68_RDBFL61 (0xF00654DAu)
+#define MTU_MC68_RDBFL62 (0xF00654DCu)
+#define MTU_MC68_RDBFL63 (0xF00654DEu)
+#define MTU_MC68_RDBFL64 (0xF00654E0u)
+#define MTU_MC68_RDBFL65 (0xF00654E2u)
+#define MTU_MC6_RDBFL26 (0xF0061694u)
+#define MTU_MC68_RDBFL66 (0xF00654E4u)
+#define MTU_MC68_ALMSRCS (0xF00654EEu)
+#define MTU_MC68_FAULTSTS (0xF00654F0u)
+#define MTU_MC68_ERRINFO0 (0xF00654F2u)
+#define MTU_MC68_ERRINFO1 (0xF00654F4u)
+#define MTU_MC68_ERRINFO2 (0xF00654F6u)
+#define MTU_MC68_ERRINFO3 (0xF00654F8u)
+#define MTU_MC68_ERRINFO4 (0xF00654FAu)
+#define MTU_MC69_CONFIG0 (0xF0065500u)
+#define MTU_MC69_CONFIG1 (0xF0065502u)
+#define MTU_MC6_RDBFL27 (0xF0061696u)
+#define MTU_MC69_MCONTROL (0xF0065504u)
+#define MTU_MC69_MSTATUS (0xF0065506u)
+#define MTU_MC69_RANGE (0xF0065508u)
+#define MTU_MC69_REVID (0xF006550Cu)
+#define MTU_MC69_ECCS (0xF006550Eu)
+#define MTU_MC69_ECCD (0xF0065510u)
+#define MTU_MC69_ETRR0 (0xF0065512u)
+#define MTU_MC69_ETRR1 (0xF0065514u)
+#define MTU_MC69_ETRR2 (0xF0065516u)
+#define MTU_MC69_ETRR3 (0xF0065518u)
+#define MTU_MC6_RDBFL28 (0xF0061698u)
+#define MTU_MC69_ETRR4 (0xF006551Au)
+#define MTU_MC69_RDBFL0 (0xF0065560u)
+#define MTU_MC69_RDBFL1 (0xF0065562u)
+#define MTU_MC69_RDBFL2 (0xF0065564u)
+#define MTU_MC69_RDBFL3 (0xF0065566u)
+#define MTU_MC69_RDBFL4 (0xF0065568u)
+#define MTU_MC69_RDBFL5 (0xF006556Au)
+#define MTU_MC69_RDBFL6 (0xF006556Cu)
+#define MTU_MC69_RDBFL7 (0xF006556Eu)
+#define MTU_MC69_RDBFL8 (0xF0065570u)
+#define MTU_MC6_RDBFL29 (0xF006169Au)
+#define MTU_MC0_RDBFL5 (0xF006106Au)
+#define MTU_MC69_RDBFL9 (0xF0065572u)
+#define MTU_MC69_RDBFL10 (0xF0065574u)
+#define MTU_MC69_RDBFL11 (0xF0065576u)
+#define MTU_MC69_RDBFL12 (0xF0065578u)
+#define MTU_MC69_RDBFL13 (0xF006557Au)
+#define MTU_MC69_RDBFL14 (0xF006557Cu)
+#define MTU_MC69_RDBFL15 (0xF006557Eu)
+#define MTU_MC69_RDBFL16 (0xF0065580u)
+#define MTU_MC69_RDBFL17 (0xF0065582u)
+#define MTU_MC69_RDBFL18 (0xF0065584u)
+#define MTU_MC6_RDBFL30 (0xF006169Cu)
+#define MTU_MC69_RDBFL19 (0xF0065586u)
+#define MTU_MC69_RDBFL20 (0xF0065588u)
+#define MTU_MC69_RDBFL21 (0xF006558Au)
+#define MTU_MC69_RDBFL22 (0xF006558Cu)
+#define MTU_MC69_RDBFL23 (0xF006558Eu)
+#define MTU_MC69_RDBFL24 (0xF0065590u)
+#define MTU_MC69_RDBFL25 (0xF0065592u)
+#define MTU_MC69_RDBFL26 (0xF0065594u)
+#define MTU_MC69_RDBFL27 (0xF0065596u)
+#define MTU_MC69_RDBFL28 (0xF0065598u)
+#define MTU_MC6_RDBFL31 (0xF006169Eu)
+#define MTU_MC69_RDBFL29 (0xF006559Au)
+#define MTU_MC69_RDBFL30 (0xF006559Cu)
+#define MTU_MC69_RDBFL31 (0xF006559Eu)
+#define MTU_MC69_RDBFL32 (0xF00655A0u)
+#define MTU_MC69_RDBFL33 (0xF00655A2u)
+#define MTU_MC69_RDBFL34 (0xF00655A4u)
+#define MTU_MC69_RDBFL35 (0xF00655A6u)
+#define MTU_MC69_RDBFL36 (0xF00655A8u)
+#define MTU_MC69_RDBFL37 (0xF00655AAu)
+#define MTU_MC69_RDBFL38 (0xF00655ACu)
+#define MTU_MC6_RDBFL32 (0xF00616A0u)
+#define MTU_MC69_RDBFL39 (0xF00655AEu)
+#define MTU_MC69_RDBFL40 (0xF00655B0u)
+#define MTU_MC69_RDBFL41 (0xF00655B2u)
+#define MTU_MC69_RDBFL42 (0xF00655B4u)
+#define MTU_MC69_RDBFL43 (0xF00655B6u)
+#define MTU_MC69_RDBFL44 (0xF00655B8u)
+#define MTU_MC69_RDBFL45 (0xF00655BAu)
+#define MTU_MC69_RDBFL46 (0xF00655BCu)
+#define MTU_MC69_RDBFL47 (0xF00655BEu)
+#define MTU_MC69_RDBFL48 (0xF00655C0u)
+#define MTU_MC6_RDBFL33 (0xF00616A2u)
+#define MTU_MC69_RDBFL49 (0xF00655C2u)
+#define MTU_MC69_RDBFL50 (0xF00655C4u)
+#define MTU_MC69_RDBFL51 (0xF00655C6u)
+#define MTU_MC69_RDBFL52 (0xF00655C8u)
+#define MTU_MC69_RDBFL53 (0xF00655CAu)
+#define MTU_MC69_RDBFL54 (0xF00655CCu)
+#define MTU_MC69_RDBFL55 (0xF00655CEu)
+#define MTU_MC69_RDBFL56 (0xF00655D0u)
+#define MTU_MC69_RDBFL57 (0xF00655D2u)
+#define MTU_MC69_RDBFL58 (0xF00655D4u)
+#define MTU_MC6_RDBFL34 (0xF00616A4u)
+#define MTU_MC69_RDBFL59 (0xF00655D6u)
+#define MTU_MC69_RDBFL60 (0xF00655D8u)
+#define MTU_MC69_RDBFL61 (0xF00655DAu)
+#define MTU_MC69_RDBFL62 (0xF00655DCu)
+#define MTU_MC69_RDBFL63 (0xF00655DEu)
+#define MTU_MC69_RDBFL64 (0xF00655E0u)
+#define MTU_MC69_RDBFL65 (0xF00655E2u)
+#define MTU_MC69_RDBFL66 (0xF00655E4u)
+#define MTU_MC69_ALMSRCS (0xF00655EEu)
+#define MTU_MC69_FAULTSTS (0xF00655F0u)
+#define MTU_MC6_RDBFL35 (0xF00616A6u)
+#define MTU_MC69_ERRINFO0 (0xF00655F2u)
+#define MTU_MC69_ERRINFO1 (0xF00655F4u)
+#define MTU_MC69_ERRINFO2 (0xF00655F6u)
+#define MTU_MC69_ERRINFO3 (0xF00655F8u)
+#define MTU_MC69_ERRINFO4 (0xF00655FAu)
+#define MTU_MC70_CONFIG0 (0xF0065600u)
+#define MTU_MC70_CONFIG1 (0xF0065602u)
+#define MTU_MC70_MCONTROL (0xF0065604u)
+#define MTU_MC70_MSTATUS (0xF0065606u)
+#define MTU_MC70_RANGE (0xF0065608u)
+#define MTU_MC6_RDBFL36 (0xF00616A8u)
+#define MTU_MC70_REVID (0xF006560Cu)
+#define MTU_MC70_ECCS (0xF006560Eu)
+#define MTU_MC70_ECCD (0xF0065610u)
+#define MTU_MC70_ETRR0 (0xF0065612u)
+#define MTU_MC70_ETRR1 (0xF0065614u)
+#define MTU_MC70_ETRR2 (0xF0065616u)
+#define MTU_MC70_ETRR3 (0xF0065618u)
+#define MTU_MC70_ETRR4 (0xF006561Au)
+#define MTU_MC70_RDBFL0 (0xF0065660u)
+#define MTU_MC70_RDBFL1 (0xF0065662u)
+#define MTU_MC6_RDBFL37 (0xF00616AAu)
+#define MTU_MC70_RDBFL2 (0xF0065664u)
+#define MTU_MC70_RDBFL3 (0xF0065666u)
+#define MTU_MC70_RDBFL4 (0xF0065668u)
+#define MTU_MC70_RDBFL5 (0xF006566Au)
+#define MTU_MC70_RDBFL6 (0xF006566Cu)
+#define MTU_MC70_RDBFL7 (0xF006566Eu)
+#define MTU_MC70_RDBFL8 (0xF0065670u)
+#define MTU_MC70_RDBFL9 (0xF0065672u)
+#define MTU_MC70_RDBFL10 (0xF0065674u)
+#define MTU_MC70_RDBFL11 (0xF0065676u)
+#define MTU_MC6_RDBFL38 (0xF00616ACu)
+#define MTU_MC70_RDBFL12 (0xF0065678u)
+#define MTU_MC70_RDBFL13 (0xF006567Au)
+#define MTU_MC70_RDBFL14 (0xF006567Cu)
+#define MTU_MC70_RDBFL15 (0xF006567Eu)
+#define MTU_MC70_RDBFL16 (0xF0065680u)
+#define MTU_MC70_RDBFL17 (0xF0065682u)
+#define MTU_MC70_RDBFL18 (0xF0065684u)
+#define MTU_MC70_RDBFL19 (0xF0065686u)
+#define MTU_MC70_RDBFL20 (0xF0065688u)
+#define MTU_MC70_RDBFL21 (0xF006568Au)
+#define MTU_MC6_RDBFL39 (0xF00616AEu)
+#define MTU_MC0_RDBFL6 (0xF006106Cu)
+#define MTU_MC70_RDBFL22 (0xF006568Cu)
+#define MTU_MC70_RDBFL23 (0xF006568Eu)
+#define MTU_MC70_RDBFL24 (0xF0065690u)
+#define MTU_MC70_RDBFL25 (0xF0065692u)
+#define MTU_MC70_RDBFL26 (0xF0065694u)
+#define MTU_MC70_RDBFL27 (0xF0065696u)
+#define MTU_MC70_RDBFL28 (0xF0065698u)
+#define MTU_MC70_RDBFL29 (0xF006569Au)
+#define MTU_MC70_RDBFL30 (0xF006569Cu)
+#define MTU_MC70_RDBFL31 (0xF006569Eu)
+#define MTU_MC6_RDBFL40 (0xF00616B0u)
+#define MTU_MC70_RDBFL32 (0xF00656A0u)
+#define MTU_MC70_RDBFL33 (0xF00656A2u)
+#define MTU_MC70_RDBFL34 (0xF00656A4u)
+#define MTU_MC70_RDBFL35 (0xF00656A6u)
+#define MTU_MC70_RDBFL36 (0xF00656A8u)
+#define MTU_MC70_RDBFL37 (0xF00656AAu)
+#define MTU_MC70_RDBFL38 (0xF00656ACu)
+#define MTU_MC70_RDBFL39 (0xF00656AEu)
+#define MTU_MC70_RDBFL40 (0xF00656B0u)
+#define MTU_MC70_RDBFL41 (0xF00656B2u)
+#define MTU_MC6_RDBFL41 (0xF00616B2u)
+#define MTU_MC70_RDBFL42 (0xF00656B4u)
+#define MTU_MC70_RDBFL43 (0xF00656B6u)
+#define MTU_MC70_RDBFL44 (0xF00656B8u)
+#define MTU_MC70_RDBFL45 (0xF00656BAu)
+#define MTU_MC70_RDBFL46 (0xF00656BCu)
+#define MTU_MC70_RDBFL47 (0xF00656BEu)
+#define MTU_MC70_RDBFL48 (0xF00656C0u)
+#define MTU_MC70_RDBFL49 (0xF00656C2u)
+#define MTU_MC70_RDBFL50 (0xF00656C4u)
+#define MTU_MC70_RDBFL51 (0xF00656C6u)
+#define MTU_MC6_RDBFL42 (0xF00616B4u)
+#define MTU_MC70_RDBFL52 (0xF00656C8u)
+#define MTU_MC70_RDBFL53 (0xF00656CAu)
+#define MTU_MC70_RDBFL54 (0xF00656CCu)
+#define MTU_MC70_RDBFL55 (0xF00656CEu)
+#define MTU_MC70_RDBFL56 (0xF00656D0u)
+#define MTU_MC70_RDBFL57 (0xF00656D2u)
+#define MTU_MC70_RDBFL58 (0xF00656D4u)
+#define MTU_MC70_RDBFL59 (0xF00656D6u)
+#define MTU_MC70_RDBFL60 (0xF00656D8u)
+#define MTU_MC70_RDBFL61 (0xF00656DAu)
+#define MTU_MC6_RDBFL43 (0xF00616B6u)
+#define MTU_MC70_RDBFL62 (0xF00656DCu)
+#define MTU_MC70_RDBFL63 (0xF00656DEu)
+#define MTU_MC70_RDBFL64 (0xF00656E0u)
+#define MTU_MC70_RDBFL65 (0xF00656E2u)
+#define MTU_MC70_RDBFL66 (0xF00656E4u)
+#define MTU_MC70_ALMSRCS (0xF00656EEu)
+#define MTU_MC70_FAULTSTS (0xF00656F0u)
+#define MTU_MC70_ERRINFO0 (0xF00656F2u)
+#define MTU_MC70_ERRINFO1 (0xF00656F4u)
+#define MTU_MC70_ERRINFO2 (0xF00656F6u)
+#define MTU_MC6_RDBFL44 (0xF00616B8u)
+#define MTU_MC70_ERRINFO3 (0xF00656F8u)
+#define MTU_MC70_ERRINFO4 (0xF00656FAu)
+#define MTU_MC71_CONFIG0 (0xF0065700u)
+#define MTU_MC71_CONFIG1 (0xF0065702u)
+#define MTU_MC71_MCONTROL (0xF0065704u)
+#define MTU_MC71_MSTATUS (0xF0065706u)
+#define MTU_MC71_RANGE (0xF0065708u)
+#define MTU_MC71_REVID (0xF006570Cu)
+#define MTU_MC71_ECCS (0xF006570Eu)
+#define MTU_MC71_ECCD (0xF0065710u)
+#define MTU_MC6_RDBFL45 (0xF00616BAu)
+#define MTU_MC71_ETRR0 (0xF0065712u)
+#define MTU_MC71_ETRR1 (0xF0065714u)
+#define MTU_MC71_ETRR2 (0xF0065716u)
+#define MTU_MC71_ETRR3 (0xF0065718u)
+#define MTU_MC71_ETRR4 (0xF006571Au)
+#define MTU_MC71_RDBFL0 (0xF0065760u)
+#define MTU_MC71_RDBFL1 (0xF0065762u)
+#define MTU_MC71_RDBFL2 (0xF0065764u)
+#define MTU_MC71_RDBFL3 (0xF0065766u)
+#define MTU_MC71_RDBFL4 (0xF0065768u)
+#define MTU_MC6_RDBFL46 (0xF00616BCu)
+#define MTU_MC71_RDBFL5 (0xF006576Au)
+#define MTU_MC71_RDBFL6 (0xF006576Cu)
+#define MTU_MC71_RDBFL7 (0xF006576Eu)
+#define MTU_MC71_RDBFL8 (0xF0065770u)
+#define MTU_MC71_RDBFL9 (0xF0065772u)
+#define MTU_MC71_RDBFL10 (0xF0065774u)
+#define MTU_MC71_RDBFL11 (0xF0065776u)
+#define MTU_MC71_RDBFL12 (0xF0065778u)
+#define MTU_MC71_RDBFL13 (0xF006577Au)
+#define MTU_MC71_RDBFL14 (0xF006577Cu)
+#define MTU_MC6_RDBFL47 (0xF00616BEu)
+#define MTU_MC71_RDBFL15 (0xF006577Eu)
+#define MTU_MC71_RDBFL16 (0xF0065780u)
+#define MTU_MC71_RDBFL17 (0xF0065782u)
+#define MTU_MC71_RDBFL18 (0xF0065784u)
+#define MTU_MC71_RDBFL19 (0xF0065786u)
+#define MTU_MC71_RDBFL20 (0xF0065788u)
+#define MTU_MC71_RDBFL21 (0xF006578Au)
+#define MTU_MC71_RDBFL22 (0xF006578Cu)
+#define MTU_MC71_RDBFL23 (0xF006578Eu)
+#define MTU_MC71_RDBFL24 (0xF0065790u)
+#define MTU_MC6_RDBFL48 (0xF00616C0u)
+#define MTU_MC71_RDBFL25 (0xF0065792u)
+#define MTU_MC71_RDBFL26 (0xF0065794u)
+#define MTU_MC71_RDBFL27 (0xF0065796u)
+#define MTU_MC71_RDBFL28 (0xF0065798u)
+#define MTU_MC71_RDBFL29 (0xF006579Au)
+#define MTU_MC71_RDBFL30 (0xF006579Cu)
+#define MTU_MC71_RDBFL31 (0xF006579Eu)
+#define MTU_MC71_RDBFL32 (0xF00657A0u)
+#define MTU_MC71_RDBFL33 (0xF00657A2u)
+#define MTU_MC71_RDBFL34 (0xF00657A4u)
+#define MTU_MC6_RDBFL49 (0xF00616C2u)
+#define MTU_MC0_RDBFL7 (0xF006106Eu)
+#define MTU_MC71_RDBFL35 (0xF00657A6u)
+#define MTU_MC71_RDBFL36 (0xF00657A8u)
+#define MTU_MC71_RDBFL37 (0xF00657AAu)
+#define MTU_MC71_RDBFL38 (0xF00657ACu)
+#define MTU_MC71_RDBFL39 (0xF00657AEu)
+#define MTU_MC71_RDBFL40 (0xF00657B0u)
+#define MTU_MC71_RDBFL41 (0xF00657B2u)
+#define MTU_MC71_RDBFL42 (0xF00657B4u)
+#define MTU_MC71_RDBFL43 (0xF00657B6u)
+#define MTU_MC71_RDBFL44 (0xF00657B8u)
+#define MTU_MC6_RDBFL50 (0xF00616C4u)
+#define MTU_MC71_RDBFL45 (0xF00657BAu)
+#define MTU_MC71_RDBFL46 (0xF00657BCu)
+#define MTU_MC71_RDBFL47 (0xF00657BEu)
+#define MTU_MC71_RDBFL48 (0xF00657C0u)
+#define MTU_MC71_RDBFL49 (0xF00657C2u)
+#define MTU_MC71_RDBFL50 (0xF00657C4u)
+#define MTU_MC71_RDBFL51 (0xF00657C6u)
+#define MTU_MC71_RDBFL52 (0xF00657C8u)
+#define MTU_MC71_RDBFL53 (0xF00657CAu)
+#define MTU_MC71_RDBFL54 (0xF00657CCu)
+#define MTU_MC6_RDBFL51 (0xF00616C6u)
+#define MTU_MC71_RDBFL55 (0xF00657CEu)
+#define MTU_MC71_RDBFL56 (0xF00657D0u)
+#define MTU_MC71_RDBFL57 (0xF00657D2u)
+#define MTU_MC71_RDBFL58 (0xF00657D4u)
+#define MTU_MC71_RDBFL59 (0xF00657D6u)
+#define MTU_MC71_RDBFL60 (0xF00657D8u)
+#define MTU_MC71_RDBFL61 (0xF00657DAu)
+#define MTU_MC71_RDBFL62 (0xF00657DCu)
+#define MTU_MC71_RDBFL63 (0xF00657DEu)
+#define MTU_MC71_RDBFL64 (0xF00657E0u)
+#define MTU_MC6_RDBFL52 (0xF00616C8u)
+#define MTU_MC71_RDBFL65 (0xF00657E2u)
+#define MTU_MC71_RDBFL66 (0xF00657E4u)
+#define MTU_MC71_ALMSRCS (0xF00657EEu)
+#define MTU_MC71_FAULTSTS (0xF00657F0u)
+#define MTU_MC71_ERRINFO0 (0xF00657F2u)
+#define MTU_MC71_ERRINFO1 (0xF00657F4u)
+#define MTU_MC71_ERRINFO2 (0xF00657F6u)
+#define MTU_MC71_ERRINFO3 (0xF00657F8u)
+#define MTU_MC71_ERRINFO4 (0xF00657FAu)
+#define MTU_MC72_CONFIG0 (0xF0065800u)
+#define MTU_MC6_RDBFL53 (0xF00616CAu)
+#define MTU_MC72_CONFIG1 (0xF0065802u)
+#define MTU_MC72_MCONTROL (0xF0065804u)
+#define MTU_MC72_MSTATUS (0xF0065806u)
+#define MTU_MC72_RANGE (0xF0065808u)
+#define MTU_MC72_REVID (0xF006580Cu)
+#define MTU_MC72_ECCS (0xF006580Eu)
+#define MTU_MC72_ECCD (0xF0065810u)
+#define MTU_MC72_ETRR0 (0xF0065812u)
+#define MTU_MC72_ETRR1 (0xF0065814u)
+#define MTU_MC72_ETRR2 (0xF0065816u)
+#define MTU_MC6_RDBFL54 (0xF00616CCu)
+#define MTU_MC72_ETRR3 (0xF0065818u)
+#define MTU_MC72_ETRR4 (0xF006581Au)
+#define MTU_MC72_RDBFL0 (0xF0065860u)
+#define MTU_MC72_RDBFL1 (0xF0065862u)
+#define MTU_MC72_RDBFL2 (0xF0065864u)
+#define MTU_MC72_RDBFL3 (0xF0065866u)
+#define MTU_MC72_RDBFL4 (0xF0065868u)
+#define MTU_MC72_RDBFL5 (0xF006586Au)
+#define MTU_MC72_RDBFL6 (0xF006586Cu)
+#define MTU_MC72_RDBFL7 (0xF006586Eu)
+#define MTU_MC6_RDBFL55 (0xF00616CEu)
+#define MTU_MC72_RDBFL8 (0xF0065870u)
+#define MTU_MC72_RDBFL9 (0xF0065872u)
+#define MTU_MC72_RDBFL10 (0xF0065874u)
+#define MTU_MC72_RDBFL11 (0xF0065876u)
+#define MTU_MC72_RDBFL12 (0xF0065878u)
+#define MTU_MC72_RDBFL13 (0xF006587Au)
+#define MTU_MC72_RDBFL14 (0xF006587Cu)
+#define MTU_MC72_RDBFL15 (0xF006587Eu)
+#define MTU_MC72_RDBFL16 (0xF0065880u)
+#define MTU_MC72_RDBFL17 (0xF0065882u)
+#define MTU_MC6_RDBFL56 (0xF00616D0u)
+#define MTU_MC72_RDBFL18 (0xF0065884u)
+#define MTU_MC72_RDBFL19 (0xF0065886u)
+#define MTU_MC72_RDBFL20 (0xF0065888u)
+#define MTU_MC72_RDBFL21 (0xF006588Au)
+#define MTU_MC72_RDBFL22 (0xF006588Cu)
+#define MTU_MC72_RDBFL23 (0xF006588Eu)
+#define MTU_MC72_RDBFL24 (0xF0065890u)
+#define MTU_MC72_RDBFL25 (0xF0065892u)
+#define MTU_MC72_RDBFL26 (0xF0065894u)
+#define MTU_MC72_RDBFL27 (0xF0065896u)
+#define MTU_MC6_RDBFL57 (0xF00616D2u)
+#define MTU_MC72_RDBFL28 (0xF0065898u)
+#define MTU_MC72_RDBFL29 (0xF006589Au)
+#define MTU_MC72_RDBFL30 (0xF006589Cu)
+#define MTU_MC72_RDBFL31 (0xF006589Eu)
+#define MTU_MC72_RDBFL32 (0xF00658A0u)
+#define MTU_MC72_RDBFL33 (0xF00658A2u)
+#define MTU_MC72_RDBFL34 (0xF00658A4u)
+#define MTU_MC72_RDBFL35 (0xF00658A6u)
+#define MTU_MC72_RDBFL36 (0xF00658A8u)
+#define MTU_MC72_RDBFL37 (0xF00658AAu)
+#define MTU_MC6_RDBFL58 (0xF00616D4u)
+#define MTU_MC72_RDBFL38 (0xF00658ACu)
+#define MTU_MC72_RDBFL39 (0xF00658AEu)
+#define MTU_MC72_RDBFL40 (0xF00658B0u)
+#define MTU_MC72_RDBFL41 (0xF00658B2u)
+#define MTU_MC72_RDBFL42 (0xF00658B4u)
+#define MTU_MC72_RDBFL43 (0xF00658B6u)
+#define MTU_MC72_RDBFL44 (0xF00658B8u)
+#define MTU_MC72_RDBFL45 (0xF00658BAu)
+#define MTU_MC72_RDBFL46 (0xF00658BCu)
+#define MTU_MC72_RDBFL47 (0xF00658BEu)
+#define MTU_MC6_RDBFL59 (0xF00616D6u)
+#define MTU_MC0_RDBFL8 (0xF0061070u)
+#define MTU_MC72_RDBFL48 (0xF00658C0u)
+#define MTU_MC72_RDBFL49 (0xF00658C2u)
+#define MTU_MC72_RDBFL50 (0xF00658C4u)
+#define MTU_MC72_RDBFL51 (0xF00658C6u)
+#define MTU_MC72_RDBFL52 (0xF00658C8u)
+#define MTU_MC72_RDBFL53 (0xF00658CAu)
+#define MTU_MC72_RDBFL54 (0xF00658CCu)
+#define MTU_MC72_RDBFL55 (0xF00658CEu)
+#define MTU_MC72_RDBFL56 (0xF00658D0u)
+#define MTU_MC72_RDBFL57 (0xF00658D2u)
+#define MTU_MC6_RDBFL60 (0xF00616D8u)
+#define MTU_MC72_RDBFL58 (0xF00658D4u)
+#define MTU_MC72_RDBFL59 (0xF00658D6u)
+#define MTU_MC72_RDBFL60 (0xF00658D8u)
+#define MTU_MC72_RDBFL61 (0xF00658DAu)
+#define MTU_MC72_RDBFL62 (0xF00658DCu)
+#define MTU_MC72_RDBFL63 (0xF00658DEu)
+#define MTU_MC72_RDBFL64 (0xF00658E0u)
+#define MTU_MC72_RDBFL65 (0xF00658E2u)
+#define MTU_MC72_RDBFL66 (0xF00658E4u)
+#define MTU_MC72_ALMSRCS (0xF00658EEu)
+#define MTU_MC6_RDBFL61 (0xF00616DAu)
+#define MTU_MC72_FAULTSTS (0xF00658F0u)
+#define MTU_MC72_ERRINFO0 (0xF00658F2u)
+#define MTU_MC72_ERRINFO1 (0xF00658F4u)
+#define MTU_MC72_ERRINFO2 (0xF00658F6u)
+#define MTU_MC72_ERRINFO3 (0xF00658F8u)
+#define MTU_MC72_ERRINFO4 (0xF00658FAu)
+#define MTU_MC73_CONFIG0 (0xF0065900u)
+#define MTU_MC73_CONFIG1 (0xF0065902u)
+#define MTU_MC73_MCONTROL (0xF0065904u)
+#define MTU_MC73_MSTATUS (0xF0065906u)
+#define MTU_MC6_RDBFL62 (0xF00616DCu)
+#define MTU_MC73_RANGE (0xF0065908u)
+#define MTU_MC73_REVID (0xF006590Cu)
+#define MTU_MC73_ECCS (0xF006590Eu)
+#define MTU_MC73_ECCD (0xF0065910u)
+#define MTU_MC73_ETRR0 (0xF0065912u)
+#define MTU_MC73_ETRR1 (0xF0065914u)
+#define MTU_MC73_ETRR2 (0xF0065916u)
+#define MTU_MC73_ETRR3 (0xF0065918u)
+#define MTU_MC73_ETRR4 (0xF006591Au)
+#define MTU_MC73_RDBFL0 (0xF0065960u)
+#define MTU_MC6_RDBFL63 (0xF00616DEu)
+#define MTU_MC73_RDBFL1 (0xF0065962u)
+#define MTU_MC73_RDBFL2 (0xF0065964u)
+#define MTU_MC73_RDBFL3 (0xF0065966u)
+#define MTU_MC73_RDBFL4 (0xF0065968u)
+#define MTU_MC73_RDBFL5 (0xF006596Au)
+#define MTU_MC73_RDBFL6 (0xF006596Cu)
+#define MTU_MC73_RDBFL7 (0xF006596Eu)
+#define MTU_MC73_RDBFL8 (0xF0065970u)
+#define MTU_MC73_RDBFL9 (0xF0065972u)
+#define MTU_MC73_RDBFL10 (0xF0065974u)
+#define MTU_MC6_RDBFL64 (0xF00616E0u)
+#define MTU_MC73_RDBFL11 (0xF0065976u)
+#define MTU_MC73_RDBFL12 (0xF0065978u)
+#define MTU_MC73_RDBFL13 (0xF006597Au)
+#define MTU_MC73_RDBFL14 (0xF006597Cu)
+#define MTU_MC73_RDBFL15 (0xF006597Eu)
+#define MTU_MC73_RDBFL16 (0xF0065980u)
+#define MTU_MC73_RDBFL17 (0xF0065982u)
+#define MTU_MC73_RDBFL18 (0xF0065984u)
+#define MTU_MC73_RDBFL19 (0xF0065986u)
+#define MTU_MC73_RDBFL20 (0xF0065988u)
+#define MTU_MC6_RDBFL65 (0xF00616E2u)
+#define MTU_MC73_RDBFL21 (0xF006598Au)
+#define MTU_MC73_RDBFL22 (0xF006598Cu)
+#define MTU_MC73_RDBFL23 (0xF006598Eu)
+#define MTU_MC73_RDBFL24 (0xF0065990u)
+#define MTU_MC73_RDBFL25 (0xF0065992u)
+#define MTU_MC73_RDBFL26 (0xF0065994u)
+#define MTU_MC73_RDBFL27 (0xF0065996u)
+#define MTU_MC73_RDBFL28 (0xF0065998u)
+#define MTU_MC73_RDBFL29 (0xF006599Au)
+#define MTU_MC73_RDBFL30 (0xF006599Cu)
+#define MTU_MC6_RDBFL66 (0xF00616E4u)
+#define MTU_MC73_RDBFL31 (0xF006599Eu)
+#define MTU_MC73_RDBFL32 (0xF00659A0u)
+#define MTU_MC73_RDBFL33 (0xF00659A2u)
+#define MTU_MC73_RDBFL34 (0xF00659A4u)
+#define MTU_MC73_RDBFL35 (0xF00659A6u)
+#define MTU_MC73_RDBFL36 (0xF00659A8u)
+#define MTU_MC73_RDBFL37 (0xF00659AAu)
+#define MTU_MC73_RDBFL38 (0xF00659ACu)
+#define MTU_MC73_RDBFL39 (0xF00659AEu)
+#define MTU_MC73_RDBFL40 (0xF00659B0u)
+#define MTU_MC6_ALMSRCS (0xF00616EEu)
+#define MTU_MC73_RDBFL41 (0xF00659B2u)
+#define MTU_MC73_RDBFL42 (0xF00659B4u)
+#define MTU_MC73_RDBFL43 (0xF00659B6u)
+#define MTU_MC73_RDBFL44 (0xF00659B8u)
+#define MTU_MC73_RDBFL45 (0xF00659BAu)
+#define MTU_MC73_RDBFL46 (0xF00659BCu)
+#define MTU_MC73_RDBFL47 (0xF00659BEu)
+#define MTU_MC73_RDBFL48 (0xF00659C0u)
+#define MTU_MC73_RDBFL49 (0xF00659C2u)
+#define MTU_MC73_RDBFL50 (0xF00659C4u)
+#define MTU_MC6_FAULTSTS (0xF00616F0u)
+#define MTU_MC73_RDBFL51 (0xF00659C6u)
+#define MTU_MC73_RDBFL52 (0xF00659C8u)
+#define MTU_MC73_RDBFL53 (0xF00659CAu)
+#define MTU_MC73_RDBFL54 (0xF00659CCu)
+#define MTU_MC73_RDBFL55 (0xF00659CEu)
+#define MTU_MC73_RDBFL56 (0xF00659D0u)
+#define MTU_MC73_RDBFL57 (0xF00659D2u)
+#define MTU_MC73_RDBFL58 (0xF00659D4u)
+#define MTU_MC73_RDBFL59 (0xF00659D6u)
+#define MTU_MC73_RDBFL60 (0xF00659D8u)
+#define MTU_MC6_ERRINFO0 (0xF00616F2u)
+#define MTU_MC0_RDBFL9 (0xF0061072u)
+#define MTU_MC73_RDBFL61 (0xF00659DAu)
+#define MTU_MC73_RDBFL62 (0xF00659DCu)
+#define MTU_MC73_RDBFL63 (0xF00659DEu)
+#define MTU_MC73_RDBFL64 (0xF00659E0u)
+#define MTU_MC73_RDBFL65 (0xF00659E2u)
+#define MTU_MC73_RDBFL66 (0xF00659E4u)
+#define MTU_MC73_ALMSRCS (0xF00659EEu)
+#define MTU_MC73_FAULTSTS (0xF00659F0u)
+#define MTU_MC73_ERRINFO0 (0xF00659F2u)
+#define MTU_MC73_ERRINFO1 (0xF00659F4u)
+#define MTU_MC6_ERRINFO1 (0xF00616F4u)
+#define MTU_MC73_ERRINFO2 (0xF00659F6u)
+#define MTU_MC73_ERRINFO3 (0xF00659F8u)
+#define MTU_MC73_ERRINFO4 (0xF00659FAu)
+#define MTU_MC74_CONFIG0 (0xF0065A00u)
+#define MTU_MC74_CONFIG1 (0xF0065A02u)
+#define MTU_MC74_MCONTROL (0xF0065A04u)
+#define MTU_MC74_MSTATUS (0xF0065A06u)
+#define MTU_MC74_RANGE (0xF0065A08u)
+#define MTU_MC74_REVID (0xF0065A0Cu)
+#define MTU_MC74_ECCS (0xF0065A0Eu)
+#define MTU_MC6_ERRINFO2 (0xF00616F6u)
+#define MTU_MC74_ECCD (0xF0065A10u)
+#define MTU_MC74_ETRR0 (0xF0065A12u)
+#define MTU_MC74_ETRR1 (0xF0065A14u)
+#define MTU_MC74_ETRR2 (0xF0065A16u)
+#define MTU_MC74_ETRR3 (0xF0065A18u)
+#define MTU_MC74_ETRR4 (0xF0065A1Au)
+#define MTU_MC74_RDBFL0 (0xF0065A60u)
+#define MTU_MC74_RDBFL1 (0xF0065A62u)
+#define MTU_MC74_RDBFL2 (0xF0065A64u)
+#define MTU_MC74_RDBFL3 (0xF0065A66u)
+#define MTU_MC6_ERRINFO3 (0xF00616F8u)
+#define MTU_MC74_RDBFL4 (0xF0065A68u)
+#define MTU_MC74_RDBFL5 (0xF0065A6Au)
+#define MTU_MC74_RDBFL6 (0xF0065A6Cu)
+#define MTU_MC74_RDBFL7 (0xF0065A6Eu)
+#define MTU_MC74_RDBFL8 (0xF0065A70u)
+#define MTU_MC74_RDBFL9 (0xF0065A72u)
+#define MTU_MC74_RDBFL10 (0xF0065A74u)
+#define MTU_MC74_RDBFL11 (0xF0065A76u)
+#define MTU_MC74_RDBFL12 (0xF0065A78u)
+#define MTU_MC74_RDBFL13 (0xF0065A7Au)
+#define MTU_MC6_ERRINFO4 (0xF00616FAu)
+#define MTU_MC74_RDBFL14 (0xF0065A7Cu)
+#define MTU_MC74_RDBFL15 (0xF0065A7Eu)
+#define MTU_MC74_RDBFL16 (0xF0065A80u)
+#define MTU_MC74_RDBFL17 (0xF0065A82u)
+#define MTU_MC74_RDBFL18 (0xF0065A84u)
+#define MTU_MC74_RDBFL19 (0xF0065A86u)
+#define MTU_MC74_RDBFL20 (0xF0065A88u)
+#define MTU_MC74_RDBFL21 (0xF0065A8Au)
+#define MTU_MC74_RDBFL22 (0xF0065A8Cu)
+#define MTU_MC74_RDBFL23 (0xF0065A8Eu)
+#define MTU_MC7_CONFIG0 (0xF0061700u)
+#define MTU_MC74_RDBFL24 (0xF0065A90u)
+#define MTU_MC74_RDBFL25 (0xF0065A92u)
+#define MTU_MC74_RDBFL26 (0xF0065A94u)
+#define MTU_MC74_RDBFL27 (0xF0065A96u)
+#define MTU_MC74_RDBFL28 (0xF0065A98u)
+#define MTU_MC74_RDBFL29 (0xF0065A9Au)
+#define MTU_MC74_RDBFL30 (0xF0065A9Cu)
+#define MTU_MC74_RDBFL31 (0xF0065A9Eu)
+#define MTU_MC74_RDBFL32 (0xF0065AA0u)
+#define MTU_MC74_RDBFL33 (0xF0065AA2u)
+#define MTU_MC7_CONFIG1 (0xF0061702u)
+#define MTU_MC74_RDBFL34 (0xF0065AA4u)
+#define MTU_MC74_RDBFL35 (0xF0065AA6u)
+#define MTU_MC74_RDBFL36 (0xF0065AA8u)
+#define MTU_MC74_RDBFL37 (0xF0065AAAu)
+#define MTU_MC74_RDBFL38 (0xF0065AACu)
+#define MTU_MC74_RDBFL39 (0xF0065AAEu)
+#define MTU_MC74_RDBFL40 (0xF0065AB0u)
+#define MTU_MC74_RDBFL41 (0xF0065AB2u)
+#define MTU_MC74_RDBFL42 (0xF0065AB4u)
+#define MTU_MC74_RDBFL43 (0xF0065AB6u)
+#define MTU_MC7_MCONTROL (0xF0061704u)
+#define MTU_MC74_RDBFL44 (0xF0065AB8u)
+#define MTU_MC74_RDBFL45 (0xF0065ABAu)
+#define MTU_MC74_RDBFL46 (0xF0065ABCu)
+#define MTU_MC74_RDBFL47 (0xF0065ABEu)
+#define MTU_MC74_RDBFL48 (0xF0065AC0u)
+#define MTU_MC74_RDBFL49 (0xF0065AC2u)
+#define MTU_MC74_RDBFL50 (0xF0065AC4u)
+#define MTU_MC74_RDBFL51 (0xF0065AC6u)
+#define MTU_MC74_RDBFL52 (0xF0065AC8u)
+#define MTU_MC74_RDBFL53 (0xF0065ACAu)
+#define MTU_MC7_MSTATUS (0xF0061706u)
+#define MTU_MC74_RDBFL54 (0xF0065ACCu)
+#define MTU_MC74_RDBFL55 (0xF0065ACEu)
+#define MTU_MC74_RDBFL56 (0xF0065AD0u)
+#define MTU_MC74_RDBFL57 (0xF0065AD2u)
+#define MTU_MC74_RDBFL58 (0xF0065AD4u)
+#define MTU_MC74_RDBFL59 (0xF0065AD6u)
+#define MTU_MC74_RDBFL60 (0xF0065AD8u)
+#define MTU_MC74_RDBFL61 (0xF0065ADAu)
+#define MTU_MC74_RDBFL62 (0xF0065ADCu)
+#define MTU_MC74_RDBFL63 (0xF0065ADEu)
+#define MTU_MC7_RANGE (0xF0061708u)
+#define MTU_MC74_RDBFL64 (0xF0065AE0u)
+#define MTU_MC74_RDBFL65 (0xF0065AE2u)
+#define MTU_MC74_RDBFL66 (0xF0065AE4u)
+#define MTU_MC74_ALMSRCS (0xF0065AEEu)
+#define MTU_MC74_FAULTSTS (0xF0065AF0u)
+#define MTU_MC74_ERRINFO0 (0xF0065AF2u)
+#define MTU_MC74_ERRINFO1 (0xF0065AF4u)
+#define MTU_MC74_ERRINFO2 (0xF0065AF6u)
+#define MTU_MC74_ERRINFO3 (0xF0065AF8u)
+#define MTU_MC74_ERRINFO4 (0xF0065AFAu)
+#define MTU_MC7_REVID (0xF006170Cu)
+#define MTU_MC0_RDBFL10 (0xF0061074u)
+#define MTU_MC75_CONFIG0 (0xF0065B00u)
+#define MTU_MC75_CONFIG1 (0xF0065B02u)
+#define MTU_MC75_MCONTROL (0xF0065B04u)
+#define MTU_MC75_MSTATUS (0xF0065B06u)
+#define MTU_MC75_RANGE (0xF0065B08u)
+#define MTU_MC75_REVID (0xF0065B0Cu)
+#define MTU_MC75_ECCS (0xF0065B0Eu)
+#define MTU_MC75_ECCD (0xF0065B10u)
+#define MTU_MC75_ETRR0 (0xF0065B12u)
+#define MTU_MC75_ETRR1 (0xF0065B14u)
+#define MTU_MC7_ECCS (0xF006170Eu)
+#define MTU_MC75_ETRR2 (0xF0065B16u)
+#define MTU_MC75_ETRR3 (0xF0065B18u)
+#define MTU_MC75_ETRR4 (0xF0065B1Au)
+#define MTU_MC75_RDBFL0 (0xF0065B60u)
+#define MTU_MC75_RDBFL1 (0xF0065B62u)
+#define MTU_MC75_RDBFL2 (0xF0065B64u)
+#define MTU_MC75_RDBFL3 (0xF0065B66u)
+#define MTU_MC75_RDBFL4 (0xF0065B68u)
+#define MTU_MC75_RDBFL5 (0xF0065B6Au)
+#define MTU_MC75_RDBFL6 (0xF0065B6Cu)
+#define MTU_MC7_ECCD (0xF0061710u)
+#define MTU_MC75_RDBFL7 (0xF0065B6Eu)
+#define MTU_MC75_RDBFL8 (0xF0065B70u)
+#define MTU_MC75_RDBFL9 (0xF0065B72u)
+#define MTU_MC75_RDBFL10 (0xF0065B74u)
+#define MTU_MC75_RDBFL11 (0xF0065B76u)
+#define MTU_MC75_RDBFL12 (0xF0065B78u)
+#define MTU_MC75_RDBFL13 (0xF0065B7Au)
+#define MTU_MC75_RDBFL14 (0xF0065B7Cu)
+#define MTU_MC75_RDBFL15 (0xF0065B7Eu)
+#define MTU_MC75_RDBFL16 (0xF0065B80u)
+#define MTU_MC7_ETRR0 (0xF0061712u)
+#define MTU_MC75_RDBFL17 (0xF0065B82u)
+#define MTU_MC75_RDBFL18 (0xF0065B84u)
+#define MTU_MC75_RDBFL19 (0xF0065B86u)
+#define MTU_MC75_RDBFL20 (0xF0065B88u)
+#define MTU_MC75_RDBFL21 (0xF0065B8Au)
+#define MTU_MC75_RDBFL22 (0xF0065B8Cu)
+#define MTU_MC75_RDBFL23 (0xF0065B8Eu)
+#define MTU_MC75_RDBFL24 (0xF0065B90u)
+#define MTU_MC75_RDBFL25 (0xF0065B92u)
+#define MTU_MC75_RDBFL26 (0xF0065B94u)
+#define MTU_MC7_ETRR1 (0xF0061714u)
+#define MTU_MC75_RDBFL27 (0xF0065B96u)
+#define MTU_MC75_RDBFL28 (0xF0065B98u)
+#define MTU_MC75_RDBFL29 (0xF0065B9Au)
+#define MTU_MC75_RDBFL30 (0xF0065B9Cu)
+#define MTU_MC75_RDBFL31 (0xF0065B9Eu)
+#define MTU_MC75_RDBFL32 (0xF0065BA0u)
+#define MTU_MC75_RDBFL33 (0xF0065BA2u)
+#define MTU_MC75_RDBFL34 (0xF0065BA4u)
+#define MTU_MC75_RDBFL35 (0xF0065BA6u)
+#define MTU_MC75_RDBFL36 (0xF0065BA8u)
+#define MTU_MC7_ETRR2 (0xF0061716u)
+#define MTU_MC75_RDBFL37 (0xF0065BAAu)
+#define MTU_MC75_RDBFL38 (0xF0065BACu)
+#define MTU_MC75_RDBFL39 (0xF0065BAEu)
+#define MTU_MC75_RDBFL40 (0xF0065BB0u)
+#define MTU_MC75_RDBFL41 (0xF0065BB2u)
+#define MTU_MC75_RDBFL42 (0xF0065BB4u)
+#define MTU_MC75_RDBFL43 (0xF0065BB6u)
+#define MTU_MC75_RDBFL44 (0xF0065BB8u)
+#define MTU_MC75_RDBFL45 (0xF0065BBAu)
+#define MTU_MC75_RDBFL46 (0xF0065BBCu)
+#define MTU_MC7_ETRR3 (0xF0061718u)
+#define MTU_MC75_RDBFL47 (0xF0065BBEu)
+#define MTU_MC75_RDBFL48 (0xF0065BC0u)
+#define MTU_MC75_RDBFL49 (0xF0065BC2u)
+#define MTU_MC75_RDBFL50 (0xF0065BC4u)
+#define MTU_MC75_RDBFL51 (0xF0065BC6u)
+#define MTU_MC75_RDBFL52 (0xF0065BC8u)
+#define MTU_MC75_RDBFL53 (0xF0065BCAu)
+#define MTU_MC75_RDBFL54 (0xF0065BCCu)
+#define MTU_MC75_RDBFL55 (0xF0065BCEu)
+#define MTU_MC75_RDBFL56 (0xF0065BD0u)
+#define MTU_MC7_ETRR4 (0xF006171Au)
+#define MTU_MC75_RDBFL57 (0xF0065BD2u)
+#define MTU_MC75_RDBFL58 (0xF0065BD4u)
+#define MTU_MC75_RDBFL59 (0xF0065BD6u)
+#define MTU_MC75_RDBFL60 (0xF0065BD8u)
+#define MTU_MC75_RDBFL61 (0xF0065BDAu)
+#define MTU_MC75_RDBFL62 (0xF0065BDCu)
+#define MTU_MC75_RDBFL63 (0xF0065BDEu)
+#define MTU_MC75_RDBFL64 (0xF0065BE0u)
+#define MTU_MC75_RDBFL65 (0xF0065BE2u)
+#define MTU_MC75_RDBFL66 (0xF0065BE4u)
+#define MTU_MC7_RDBFL0 (0xF0061760u)
+#define MTU_MC75_ALMSRCS (0xF0065BEEu)
+#define MTU_MC75_FAULTSTS (0xF0065BF0u)
+#define MTU_MC75_ERRINFO0 (0xF0065BF2u)
+#define MTU_MC75_ERRINFO1 (0xF0065BF4u)
+#define MTU_MC75_ERRINFO2 (0xF0065BF6u)
+#define MTU_MC75_ERRINFO3 (0xF0065BF8u)
+#define MTU_MC75_ERRINFO4 (0xF0065BFAu)
+#define MTU_MC76_CONFIG0 (0xF0065C00u)
+#define MTU_MC76_CONFIG1 (0xF0065C02u)
+#define MTU_MC76_MCONTROL (0xF0065C04u)
+#define MTU_MC7_RDBFL1 (0xF0061762u)
+#define MTU_MC76_MSTATUS (0xF0065C06u)
+#define MTU_MC76_RANGE (0xF0065C08u)
+#define MTU_MC76_REVID (0xF0065C0Cu)
+#define MTU_MC76_ECCS (0xF0065C0Eu)
+#define MTU_MC76_ECCD (0xF0065C10u)
+#define MTU_MC76_ETRR0 (0xF0065C12u)
+#define MTU_MC76_ETRR1 (0xF0065C14u)
+#define MTU_MC76_ETRR2 (0xF0065C16u)
+#define MTU_MC76_ETRR3 (0xF0065C18u)
+#define MTU_MC76_ETRR4 (0xF0065C1Au)
+#define MTU_MC7_RDBFL2 (0xF0061764u)
+#define MTU_MC0_RDBFL11 (0xF0061076u)
+#define MTU_MC76_RDBFL0 (0xF0065C60u)
+#define MTU_MC76_RDBFL1 (0xF0065C62u)
+#define MTU_MC76_RDBFL2 (0xF0065C64u)
+#define MTU_MC76_RDBFL3 (0xF0065C66u)
+#define MTU_MC76_RDBFL4 (0xF0065C68u)
+#define MTU_MC76_RDBFL5 (0xF0065C6Au)
+#define MTU_MC76_RDBFL6 (0xF0065C6Cu)
+#define MTU_MC76_RDBFL7 (0xF0065C6Eu)
+#define MTU_MC76_RDBFL8 (0xF0065C70u)
+#define MTU_MC76_RDBFL9 (0xF0065C72u)
+#define MTU_MC7_RDBFL3 (0xF0061766u)
+#define MTU_MC76_RDBFL10 (0xF0065C74u)
+#define MTU_MC76_RDBFL11 (0xF0065C76u)
+#define MTU_MC76_RDBFL12 (0xF0065C78u)
+#define MTU_MC76_RDBFL13 (0xF0065C7Au)
+#define MTU_MC76_RDBFL14 (0xF0065C7Cu)
+#define MTU_MC76_RDBFL15 (0xF0065C7Eu)
+#define MTU_MC76_RDBFL16 (0xF0065C80u)
+#define MTU_MC76_RDBFL17 (0xF0065C82u)
+#define MTU_MC76_RDBFL18 (0xF0065C84u)
+#define MTU_MC76_RDBFL19 (0xF0065C86u)
+#define MTU_MC7_RDBFL4 (0xF0061768u)
+#define MTU_MC76_RDBFL20 (0xF0065C88u)
+#define MTU_MC76_RDBFL21 (0xF0065C8Au)
+#define MTU_MC76_RDBFL22 (0xF0065C8Cu)
+#define MTU_MC76_RDBFL23 (0xF0065C8Eu)
+#define MTU_MC76_RDBFL24 (0xF0065C90u)
+#define MTU_MC76_RDBFL25 (0xF0065C92u)
+#define MTU_MC76_RDBFL26 (0xF0065C94u)
+#define MTU_MC76_RDBFL27 (0xF0065C96u)
+#define MTU_MC76_RDBFL28 (0xF0065C98u)
+#define MTU_MC76_RDBFL29 (0xF0065C9Au)
+#define MTU_MC7_RDBFL5 (0xF006176Au)
+#define MTU_MC76_RDBFL30 (0xF0065C9Cu)
+#define MTU_MC76_RDBFL31 (0xF0065C9Eu)
+#define MTU_MC76_RDBFL32 (0xF0065CA0u)
+#define MTU_MC76_RDBFL33 (0xF0065CA2u)
+#define MTU_MC76_RDBFL34 (0xF0065CA4u)
+#define MTU_MC76_RDBFL35 (0xF0065CA6u)
+#define MTU_MC76_RDBFL36 (0xF0065CA8u)
+#define MTU_MC76_RDBFL37 (0xF0065CAAu)
+#define MTU_MC76_RDBFL38 (0xF0065CACu)
+#define MTU_MC76_RDBFL39 (0xF0065CAEu)
+#define MTU_MC7_RDBFL6 (0xF006176Cu)
+#define MTU_MC76_RDBFL40 (0xF0065CB0u)
+#define MTU_MC76_RDBFL41 (0xF0065CB2u)
+#define MTU_MC76_RDBFL42 (0xF0065CB4u)
+#define MTU_MC76_RDBFL43 (0xF0065CB6u)
+#define MTU_MC76_RDBFL44 (0xF0065CB8u)
+#define MTU_MC76_RDBFL45 (0xF0065CBAu)
+#define MTU_MC76_RDBFL46 (0xF0065CBCu)
+#define MTU_MC76_RDBFL47 (0xF0065CBEu)
+#define MTU_MC76_RDBFL48 (0xF0065CC0u)
+#define MTU_MC76_RDBFL49 (0xF0065CC2u)
+#define MTU_MC7_RDBFL7 (0xF006176Eu)
+#define MTU_MC76_RDBFL50 (0xF0065CC4u)
+#define MTU_MC76_RDBFL51 (0xF0065CC6u)
+#define MTU_MC76_RDBFL52 (0xF0065CC8u)
+#define MTU_MC76_RDBFL53 (0xF0065CCAu)
+#define MTU_MC76_RDBFL54 (0xF0065CCCu)
+#define MTU_MC76_RDBFL55 (0xF0065CCEu)
+#define MTU_MC76_RDBFL56 (0xF0065CD0u)
+#define MTU_MC76_RDBFL57 (0xF0065CD2u)
+#define MTU_MC76_RDBFL58 (0xF0065CD4u)
+#define MTU_MC76_RDBFL59 (0xF0065CD6u)
+#define MTU_MC7_RDBFL8 (0xF0061770u)
+#define MTU_MC76_RDBFL60 (0xF0065CD8u)
+#define MTU_MC76_RDBFL61 (0xF0065CDAu)
+#define MTU_MC76_RDBFL62 (0xF0065CDCu)
+#define MTU_MC76_RDBFL63 (0xF0065CDEu)
+#define MTU_MC76_RDBFL64 (0xF0065CE0u)
+#define MTU_MC76_RDBFL65 (0xF0065CE2u)
+#define MTU_MC76_RDBFL66 (0xF0065CE4u)
+#define MTU_MC76_ALMSRCS (0xF0065CEEu)
+#define MTU_MC76_FAULTSTS (0xF0065CF0u)
+#define MTU_MC76_ERRINFO0 (0xF0065CF2u)
+#define MTU_MC7_RDBFL9 (0xF0061772u)
+#define MTU_MC76_ERRINFO1 (0xF0065CF4u)
+#define MTU_MC76_ERRINFO2 (0xF0065CF6u)
+#define MTU_MC76_ERRINFO3 (0xF0065CF8u)
+#define MTU_MC76_ERRINFO4 (0xF0065CFAu)
+#define MTU_MC77_CONFIG0 (0xF0065D00u)
+#define MTU_MC77_CONFIG1 (0xF0065D02u)
+#define MTU_MC77_MCONTROL (0xF0065D04u)
+#define MTU_MC77_MSTATUS (0xF0065D06u)
+#define MTU_MC77_RANGE (0xF0065D08u)
+#define MTU_MC77_REVID (0xF0065D0Cu)
+#define MTU_MC7_RDBFL10 (0xF0061774u)
+#define MTU_MC77_ECCS (0xF0065D0Eu)
+#define MTU_MC77_ECCD (0xF0065D10u)
+#define MTU_MC77_ETRR0 (0xF0065D12u)
+#define MTU_MC77_ETRR1 (0xF0065D14u)
+#define MTU_MC77_ETRR2 (0xF0065D16u)
+#define MTU_MC77_ETRR3 (0xF0065D18u)
+#define MTU_MC77_ETRR4 (0xF0065D1Au)
+#define MTU_MC77_RDBFL0 (0xF0065D60u)
+#define MTU_MC77_RDBFL1 (0xF0065D62u)
+#define MTU_MC77_RDBFL2 (0xF0065D64u)
+#define MTU_MC7_RDBFL11 (0xF0061776u)
+#define MTU_MC77_RDBFL3 (0xF0065D66u)
+#define MTU_MC77_RDBFL4 (0xF0065D68u)
+#define MTU_MC77_RDBFL5 (0xF0065D6Au)
+#define MTU_MC77_RDBFL6 (0xF0065D6Cu)
+#define MTU_MC77_RDBFL7 (0xF0065D6Eu)
+#define MTU_MC77_RDBFL8 (0xF0065D70u)
+#define MTU_MC77_RDBFL9 (0xF0065D72u)
+#define MTU_MC77_RDBFL10 (0xF0065D74u)
+#define MTU_MC77_RDBFL11 (0xF0065D76u)
+#define MTU_MC77_RDBFL12 (0xF0065D78u)
+#define MTU_MC7_RDBFL12 (0xF0061778u)
+#define MTU_MC0_RDBFL12 (0xF0061078u)
+#define MTU_MC77_RDBFL13 (0xF0065D7Au)
+#define MTU_MC77_RDBFL14 (0xF0065D7Cu)
+#define MTU_MC77_RDBFL15 (0xF0065D7Eu)
+#define MTU_MC77_RDBFL16 (0xF0065D80u)
+#define MTU_MC77_RDBFL17 (0xF0065D82u)
+#define MTU_MC77_RDBFL18 (0xF0065D84u)
+#define MTU_MC77_RDBFL19 (0xF0065D86u)
+#define MTU_MC77_RDBFL20 (0xF0065D88u)
+#define MTU_MC77_RDBFL21 (0xF0065D8Au)
+#define MTU_MC77_RDBFL22 (0xF0065D8Cu)
+#define MTU_MC7_RDBFL13 (0xF006177Au)
+#define MTU_MC77_RDBFL23 (0xF0065D8Eu)
+#define MTU_MC77_RDBFL24 (0xF0065D90u)
+#define MTU_MC77_RDBFL25 (0xF0065D92u)
+#define MTU_MC77_RDBFL26 (0xF0065D94u)
+#define MTU_MC77_RDBFL27 (0xF0065D96u)
+#define MTU_MC77_RDBFL28 (0xF0065D98u)
+#define MTU_MC77_RDBFL29 (0xF0065D9Au)
+#define MTU_MC77_RDBFL30 (0xF0065D9Cu)
+#define MTU_MC77_RDBFL31 (0xF0065D9Eu)
+#define MTU_MC77_RDBFL32 (0xF0065DA0u)
+#define MTU_MC7_RDBFL14 (0xF006177Cu)
+#define MTU_MC77_RDBFL33 (0xF0065DA2u)
+#define MTU_MC77_RDBFL34 (0xF0065DA4u)
+#define MTU_MC77_RDBFL35 (0xF0065DA6u)
+#define MTU_MC77_RDBFL36 (0xF0065DA8u)
+#define MTU_MC77_RDBFL37 (0xF0065DAAu)
+#define MTU_MC77_RDBFL38 (0xF0065DACu)
+#define MTU_MC77_RDBFL39 (0xF0065DAEu)
+#define MTU_MC77_RDBFL40 (0xF0065DB0u)
+#define MTU_MC77_RDBFL41 (0xF0065DB2u)
+#define MTU_MC77_RDBFL42 (0xF0065DB4u)
+#define MTU_MC7_RDBFL15 (0xF006177Eu)
+#define MTU_MC77_RDBFL43 (0xF0065DB6u)
+#define MTU_MC77_RDBFL44 (0xF0065DB8u)
+#define MTU_MC77_RDBFL45 (0xF0065DBAu)
+#define MTU_MC77_RDBFL46 (0xF0065DBCu)
+#define MTU_MC77_RDBFL47 (0xF0065DBEu)
+#define MTU_MC77_RDBFL48 (0xF0065DC0u)
+#define MTU_MC77_RDBFL49 (0xF0065DC2u)
+#define MTU_MC77_RDBFL50 (0xF0065DC4u)
+#define MTU_MC77_RDBFL51 (0xF0065DC6u)
+#define MTU_MC77_RDBFL52 (0xF0065DC8u)
+#define MTU_MC7_RDBFL16 (0xF0061780u)
+#define MTU_MC77_RDBFL53 (0xF0065DCAu)
+#define MTU_MC77_RDBFL54 (0xF0065DCCu)
+#define MTU_MC77_RDBFL55 (0xF0065DCEu)
+#define MTU_MC77_RDBFL56 (0xF0065DD0u)
+#define MTU_MC77_RDBFL57 (0xF0065DD2u)
+#define MTU_MC77_RDBFL58 (0xF0065DD4u)
+#define MTU_MC77_RDBFL59 (0xF0065DD6u)
+#define MTU_MC77_RDBFL60 (0xF0065DD8u)
+#define MTU_MC77_RDBFL61 (0xF0065DDAu)
+#define MTU_MC77_RDBFL62 (0xF0065DDCu)
+#define MTU_MC7_RDBFL17 (0xF0061782u)
+#define MTU_MC77_RDBFL63 (0xF0065DDEu)
+#define MTU_MC77_RDBFL64 (0xF0065DE0u)
+#define MTU_MC77_RDBFL65 (0xF0065DE2u)
+#define MTU_MC77_RDBFL66 (0xF0065DE4u)
+#define MTU_MC77_ALMSRCS (0xF0065DEEu)
+#define MTU_MC77_FAULTSTS (0xF0065DF0u)
+#define MTU_MC77_ERRINFO0 (0xF0065DF2u)
+#define MTU_MC77_ERRINFO1 (0xF0065DF4u)
+#define MTU_MC77_ERRINFO2 (0xF0065DF6u)
+#define MTU_MC77_ERRINFO3 (0xF0065DF8u)
+#define MTU_MC7_RDBFL18 (0xF0061784u)
+#define MTU_MC77_ERRINFO4 (0xF0065DFAu)
+#define MTU_MC78_CONFIG0 (0xF0065E00u)
+#define MTU_MC78_CONFIG1 (0xF0065E02u)
+#define MTU_MC78_MCONTROL (0xF0065E04u)
+#define MTU_MC78_MSTATUS (0xF0065E06u)
+#define MTU_MC78_RANGE (0xF0065E08u)
+#define MTU_MC78_REVID (0xF0065E0Cu)
+#define MTU_MC78_ECCS (0xF0065E0Eu)
+#define MTU_MC78_ECCD (0xF0065E10u)
+#define MTU_MC78_ETRR0 (0xF0065E12u)
+#define MTU_MC7_RDBFL19 (0xF0061786u)
+#define MTU_MC78_ETRR1 (0xF0065E14u)
+#define MTU_MC78_ETRR2 (0xF0065E16u)
+#define MTU_MC78_ETRR3 (0xF0065E18u)
+#define MTU_MC78_ETRR4 (0xF0065E1Au)
+#define MTU_MC78_RDBFL0 (0xF0065E60u)
+#define MTU_MC78_RDBFL1 (0xF0065E62u)
+#define MTU_MC78_RDBFL2 (0xF0065E64u)
+#define MTU_MC78_RDBFL3 (0xF0065E66u)
+#define MTU_MC78_RDBFL4 (0xF0065E68u)
+#define MTU_MC78_RDBFL5 (0xF0065E6Au)
+#define MTU_MC7_RDBFL20 (0xF0061788u)
+#define MTU_MC78_RDBFL6 (0xF0065E6Cu)
+#define MTU_MC78_RDBFL7 (0xF0065E6Eu)
+#define MTU_MC78_RDBFL8 (0xF0065E70u)
+#define MTU_MC78_RDBFL9 (0xF0065E72u)
+#define MTU_MC78_RDBFL10 (0xF0065E74u)
+#define MTU_MC78_RDBFL11 (0xF0065E76u)
+#define MTU_MC78_RDBFL12 (0xF0065E78u)
+#define MTU_MC78_RDBFL13 (0xF0065E7Au)
+#define MTU_MC78_RDBFL14 (0xF0065E7Cu)
+#define MTU_MC78_RDBFL15 (0xF0065E7Eu)
+#define MTU_MC7_RDBFL21 (0xF006178Au)
+#define MTU_MC78_RDBFL16 (0xF0065E80u)
+#define MTU_MC78_RDBFL17 (0xF0065E82u)
+#define MTU_MC78_RDBFL18 (0xF0065E84u)
+#define MTU_MC78_RDBFL19 (0xF0065E86u)
+#define MTU_MC78_RDBFL20 (0xF0065E88u)
+#define MTU_MC78_RDBFL21 (0xF0065E8Au)
+#define MTU_MC78_RDBFL22 (0xF0065E8Cu)
+#define MTU_MC78_RDBFL23 (0xF0065E8Eu)
+#define MTU_MC78_RDBFL24 (0xF0065E90u)
+#define MTU_MC78_RDBFL25 (0xF0065E92u)
+#define MTU_MC7_RDBFL22 (0xF006178Cu)
+#define MTU_MC0_RDBFL13 (0xF006107Au)
+#define MTU_MC78_RDBFL26 (0xF0065E94u)
+#define MTU_MC78_RDBFL27 (0xF0065E96u)
+#define MTU_MC78_RDBFL28 (0xF0065E98u)
+#define MTU_MC78_RDBFL29 (0xF0065E9Au)
+#define MTU_MC78_RDBFL30 (0xF0065E9Cu)
+#define MTU_MC78_RDBFL31 (0xF0065E9Eu)
+#define MTU_MC78_RDBFL32 (0xF0065EA0u)
+#define MTU_MC78_RDBFL33 (0xF0065EA2u)
+#define MTU_MC78_RDBFL34 (0xF0065EA4u)
+#define MTU_MC78_RDBFL35 (0xF0065EA6u)
+#define MTU_MC7_RDBFL23 (0xF006178Eu)
+#define MTU_MC78_RDBFL36 (0xF0065EA8u)
+#define MTU_MC78_RDBFL37 (0xF0065EAAu)
+#define MTU_MC78_RDBFL38 (0xF0065EACu)
+#define MTU_MC78_RDBFL39 (0xF0065EAEu)
+#define MTU_MC78_RDBFL40 (0xF0065EB0u)
+#define MTU_MC78_RDBFL41 (0xF0065EB2u)
+#define MTU_MC78_RDBFL42 (0xF0065EB4u)
+#define MTU_MC78_RDBFL43 (0xF0065EB6u)
+#define MTU_MC78_RDBFL44 (0xF0065EB8u)
+#define MTU_MC78_RDBFL45 (0xF0065EBAu)
+#define MTU_MC7_RDBFL24 (0xF0061790u)
+#define MTU_MC78_RDBFL46 (0xF0065EBCu)
+#define MTU_MC78_RDBFL47 (0xF0065EBEu)
+#define MTU_MC78_RDBFL48 (0xF0065EC0u)
+#define MTU_MC78_RDBFL49 (0xF0065EC2u)
+#define MTU_MC78_RDBFL50 (0xF0065EC4u)
+#define MTU_MC78_RDBFL51 (0xF0065EC6u)
+#define MTU_MC78_RDBFL52 (0xF0065EC8u)
+#define MTU_MC78_RDBFL53 (0xF0065ECAu)
+#define MTU_MC78_RDBFL54 (0xF0065ECCu)
+#define MTU_MC78_RDBFL55 (0xF0065ECEu)
+#define MTU_MC7_RDBFL25 (0xF0061792u)
+#define MTU_MC78_RDBFL56 (0xF0065ED0u)
+#define MTU_MC78_RDBFL57 (0xF0065ED2u)
+#define MTU_MC78_RDBFL58 (0xF0065ED4u)
+#define MTU_MC78_RDBFL59 (0xF0065ED6u)
+#define MTU_MC78_RDBFL60 (0xF0065ED8u)
+#define MTU_MC78_RDBFL61 (0xF0065EDAu)
+#define MTU_MC78_RDBFL62 (0xF0065EDCu)
+#define MTU_MC78_RDBFL63 (0xF0065EDEu)
+#define MTU_MC78_RDBFL64 (0xF0065EE0u)
+#define MTU_MC78_RDBFL65 (0xF0065EE2u)
+#define MTU_MC7_RDBFL26 (0xF0061794u)
+#define MTU_MC78_RDBFL66 (0xF0065EE4u)
+#define MTU_MC78_ALMSRCS (0xF0065EEEu)
+#define MTU_MC78_FAULTSTS (0xF0065EF0u)
+#define MTU_MC78_ERRINFO0 (0xF0065EF2u)
+#define MTU_MC78_ERRINFO1 (0xF0065EF4u)
+#define MTU_MC78_ERRINFO2 (0xF0065EF6u)
+#define MTU_MC78_ERRINFO3 (0xF0065EF8u)
+#define MTU_MC78_ERRINFO4 (0xF0065EFAu)
+#define MTU_MC79_CONFIG0 (0xF0065F00u)
+#define MTU_MC79_CONFIG1 (0xF0065F02u)
+#define MTU_MC7_RDBFL27 (0xF0061796u)
+#define MTU_MC79_MCONTROL (0xF0065F04u)
+#define MTU_MC79_MSTATUS (0xF0065F06u)
+#define MTU_MC79_RANGE (0xF0065F08u)
+#define MTU_MC79_REVID (0xF0065F0Cu)
+#define MTU_MC79_ECCS (0xF0065F0Eu)
+#define MTU_MC79_ECCD (0xF0065F10u)
+#define MTU_MC79_ETRR0 (0xF0065F12u)
+#define MTU_MC79_ETRR1 (0xF0065F14u)
+#define MTU_MC79_ETRR2 (0xF0065F16u)
+#define MTU_MC79_ETRR3 (0xF0065F18u)
+#define MTU_MC7_RDBFL28 (0xF0061798u)
+#define MTU_MC79_ETRR4 (0xF0065F1Au)
+#define MTU_MC79_RDBFL0 (0xF0065F60u)
+#define MTU_MC79_RDBFL1 (0xF0065F62u)
+#define MTU_MC79_RDBFL2 (0xF0065F64u)
+#define MTU_MC79_RDBFL3 (0xF0065F66u)
+#define MTU_MC79_RDBFL4 (0xF0065F68u)
+#define MTU_MC79_RDBFL5 (0xF0065F6Au)
+#define MTU_MC79_RDBFL6 (0xF0065F6Cu)
+#define MTU_MC79_RDBFL7 (0xF0065F6Eu)
+#define MTU_MC79_RDBFL8 (0xF0065F70u)
+#define MTU_MC7_RDBFL29 (0xF006179Au)
+#define MTU_MC79_RDBFL9 (0xF0065F72u)
+#define MTU_MC79_RDBFL10 (0xF0065F74u)
+#define MTU_MC79_RDBFL11 (0xF0065F76u)
+#define MTU_MC79_RDBFL12 (0xF0065F78u)
+#define MTU_MC79_RDBFL13 (0xF0065F7Au)
+#define MTU_MC79_RDBFL14 (0xF0065F7Cu)
+#define MTU_MC79_RDBFL15 (0xF0065F7Eu)
+#define MTU_MC79_RDBFL16 (0xF0065F80u)
+#define MTU_MC79_RDBFL17 (0xF0065F82u)
+#define MTU_MC79_RDBFL18 (0xF0065F84u)
+#define MTU_MC7_RDBFL30 (0xF006179Cu)
+#define MTU_MC79_RDBFL19 (0xF0065F86u)
+#define MTU_MC79_RDBFL20 (0xF0065F88u)
+#define MTU_MC79_RDBFL21 (0xF0065F8Au)
+#define MTU_MC79_RDBFL22 (0xF0065F8Cu)
+#define MTU_MC79_RDBFL23 (0xF0065F8Eu)
+#define MTU_MC79_RDBFL24 (0xF0065F90u)
+#define MTU_MC79_RDBFL25 (0xF0065F92u)
+#define MTU_MC79_RDBFL26 (0xF0065F94u)
+#define MTU_MC79_RDBFL27 (0xF0065F96u)
+#define MTU_MC79_RDBFL28 (0xF0065F98u)
+#define MTU_MC7_RDBFL31 (0xF006179Eu)
+#define MTU_MC79_RDBFL29 (0xF0065F9Au)
+#define MTU_MC79_RDBFL30 (0xF0065F9Cu)
+#define MTU_MC79_RDBFL31 (0xF0065F9Eu)
+#define MTU_MC79_RDBFL32 (0xF0065FA0u)
+#define MTU_MC79_RDBFL33 (0xF0065FA2u)
+#define MTU_MC79_RDBFL34 (0xF0065FA4u)
+#define MTU_MC79_RDBFL35 (0xF0065FA6u)
+#define MTU_MC79_RDBFL36 (0xF0065FA8u)
+#define MTU_MC79_RDBFL37 (0xF0065FAAu)
+#define MTU_MC79_RDBFL38 (0xF0065FACu)
+#define MTU_MC7_RDBFL32 (0xF00617A0u)
+#define MTU_MC0_RDBFL14 (0xF006107Cu)
+#define MTU_MC79_RDBFL39 (0xF0065FAEu)
+#define MTU_MC79_RDBFL40 (0xF0065FB0u)
+#define MTU_MC79_RDBFL41 (0xF0065FB2u)
+#define MTU_MC79_RDBFL42 (0xF0065FB4u)
+#define MTU_MC79_RDBFL43 (0xF0065FB6u)
+#define MTU_MC79_RDBFL44 (0xF0065FB8u)
+#define MTU_MC79_RDBFL45 (0xF0065FBAu)
+#define MTU_MC79_RDBFL46 (0xF0065FBCu)
+#define MTU_MC79_RDBFL47 (0xF0065FBEu)
+#define MTU_MC79_RDBFL48 (0xF0065FC0u)
+#define MTU_MC7_RDBFL33 (0xF00617A2u)
+#define MTU_MC79_RDBFL49 (0xF0065FC2u)
+#define MTU_MC79_RDBFL50 (0xF0065FC4u)
+#define MTU_MC79_RDBFL51 (0xF0065FC6u)
+#define MTU_MC79_RDBFL52 (0xF0065FC8u)
+#define MTU_MC79_RDBFL53 (0xF0065FCAu)
+#define MTU_MC79_RDBFL54 (0xF0065FCCu)
+#define MTU_MC79_RDBFL55 (0xF0065FCEu)
+#define MTU_MC79_RDBFL56 (0xF0065FD0u)
+#define MTU_MC79_RDBFL57 (0xF0065FD2u)
+#define MTU_MC79_RDBFL58 (0xF0065FD4u)
+#define MTU_MC7_RDBFL34 (0xF00617A4u)
+#define MTU_MC79_RDBFL59 (0xF0065FD6u)
+#define MTU_MC79_RDBFL60 (0xF0065FD8u)
+#define MTU_MC79_RDBFL61 (0xF0065FDAu)
+#define MTU_MC79_RDBFL62 (0xF0065FDCu)
+#define MTU_MC79_RDBFL63 (0xF0065FDEu)
+#define MTU_MC79_RDBFL64 (0xF0065FE0u)
+#define MTU_MC79_RDBFL65 (0xF0065FE2u)
+#define MTU_MC79_RDBFL66 (0xF0065FE4u)
+#define MTU_MC79_ALMSRCS (0xF0065FEEu)
+#define MTU_MC79_FAULTSTS (0xF0065FF0u)
+#define MTU_MC7_RDBFL35 (0xF00617A6u)
+#define MTU_MC79_ERRINFO0 (0xF0065FF2u)
+#define MTU_MC79_ERRINFO1 (0xF0065FF4u)
+#define MTU_MC79_ERRINFO2 (0xF0065FF6u)
+#define MTU_MC79_ERRINFO3 (0xF0065FF8u)
+#define MTU_MC79_ERRINFO4 (0xF0065FFAu)
+#define MTU_MC80_CONFIG0 (0xF0066000u)
+#define MTU_MC80_CONFIG1 (0xF0066002u)
+#define MTU_MC80_MCONTROL (0xF0066004u)
+#define MTU_MC80_MSTATUS (0xF0066006u)
+#define MTU_MC80_RANGE (0xF0066008u)
+#define MTU_MC7_RDBFL36 (0xF00617A8u)
+#define MTU_MC80_REVID (0xF006600Cu)
+#define MTU_MC80_ECCS (0xF006600Eu)
+#define MTU_MC80_ECCD (0xF0066010u)
+#define MTU_MC80_ETRR0 (0xF0066012u)
+#define MTU_MC80_ETRR1 (0xF0066014u)
+#define MTU_MC80_ETRR2 (0xF0066016u)
+#define MTU_MC80_ETRR3 (0xF0066018u)
+#define MTU_MC80_ETRR4 (0xF006601Au)
+#define MTU_MC80_RDBFL0 (0xF0066060u)
+#define MTU_MC80_RDBFL1 (0xF0066062u)
+#define MTU_MC7_RDBFL37 (0xF00617AAu)
+#define MTU_MC80_RDBFL2 (0xF0066064u)
+#define MTU_MC80_RDBFL3 (0xF0066066u)
+#define MTU_MC80_RDBFL4 (0xF0066068u)
+#define MTU_MC80_RDBFL5 (0xF006606Au)
+#define MTU_MC80_RDBFL6 (0xF006606Cu)
+#define MTU_MC80_RDBFL7 (0xF006606Eu)
+#define MTU_MC80_RDBFL8 (0xF0066070u)
+#define MTU_MC80_RDBFL9 (0xF0066072u)
+#define MTU_MC80_RDBFL10 (0xF0066074u)
+#define MTU_MC80_RDBFL11 (0xF0066076u)
+#define MTU_MC7_RDBFL38 (0xF00617ACu)
+#define MTU_MC80_RDBFL12 (0xF0066078u)
+#define MTU_MC80_RDBFL13 (0xF006607Au)
+#define MTU_MC80_RDBFL14 (0xF006607Cu)
+#define MTU_MC80_RDBFL15 (0xF006607Eu)
+#define MTU_MC80_RDBFL16 (0xF0066080u)
+#define MTU_MC80_RDBFL17 (0xF0066082u)
+#define MTU_MC80_RDBFL18 (0xF0066084u)
+#define MTU_MC80_RDBFL19 (0xF0066086u)
+#define MTU_MC80_RDBFL20 (0xF0066088u)
+#define MTU_MC80_RDBFL21 (0xF006608Au)
+#define MTU_MC7_RDBFL39 (0xF00617AEu)
+#define MTU_MC80_RDBFL22 (0xF006608Cu)
+#define MTU_MC80_RDBFL23 (0xF006608Eu)
+#define MTU_MC80_RDBFL24 (0xF0066090u)
+#define MTU_MC80_RDBFL25 (0xF0066092u)
+#define MTU_MC80_RDBFL26 (0xF0066094u)
+#define MTU_MC80_RDBFL27 (0xF0066096u)
+#define MTU_MC80_RDBFL28 (0xF0066098u)
+#define MTU_MC80_RDBFL29 (0xF006609Au)
+#define MTU_MC80_RDBFL30 (0xF006609Cu)
+#define MTU_MC80_RDBFL31 (0xF006609Eu)
+#define MTU_MC7_RDBFL40 (0xF00617B0u)
+#define MTU_MC80_RDBFL32 (0xF00660A0u)
+#define MTU_MC80_RDBFL33 (0xF00660A2u)
+#define MTU_MC80_RDBFL34 (0xF00660A4u)
+#define MTU_MC80_RDBFL35 (0xF00660A6u)
+#define MTU_MC80_RDBFL36 (0xF00660A8u)
+#define MTU_MC80_RDBFL37 (0xF00660AAu)
+#define MTU_MC80_RDBFL38 (0xF00660ACu)
+#define MTU_MC80_RDBFL39 (0xF00660AEu)
+#define MTU_MC80_RDBFL40 (0xF00660B0u)
+#define MTU_MC80_RDBFL41 (0xF00660B2u)
+#define MTU_MC7_RDBFL41 (0xF00617B2u)
+#define MTU_MC80_RDBFL42 (0xF00660B4u)
+#define MTU_MC80_RDBFL43 (0xF00660B6u)
+#define MTU_MC80_RDBFL44 (0xF00660B8u)
+#define MTU_MC80_RDBFL45 (0xF00660BAu)
+#define MTU_MC80_RDBFL46 (0xF00660BCu)
+#define MTU_MC80_RDBFL47 (0xF00660BEu)
+#define MTU_MC80_RDBFL48 (0xF00660C0u)
+#define MTU_MC80_RDBFL49 (0xF00660C2u)
+#define MTU_MC80_RDBFL50 (0xF00660C4u)
+#define MTU_MC80_RDBFL51 (0xF00660C6u)
+#define MTU_MC7_RDBFL42 (0xF00617B4u)
+#define MTU_MC0_RDBFL15 (0xF006107Eu)
+#define MTU_MC80_RDBFL52 (0xF00660C8u)
+#define MTU_MC80_RDBFL53 (0xF00660CAu)
+#define MTU_MC80_RDBFL54 (0xF00660CCu)
+#define MTU_MC80_RDBFL55 (0xF00660CEu)
+#define MTU_MC80_RDBFL56 (0xF00660D0u)
+#define MTU_MC80_RDBFL57 (0xF00660D2u)
+#define MTU_MC80_RDBFL58 (0xF00660D4u)
+#define MTU_MC80_RDBFL59 (0xF00660D6u)
+#define MTU_MC80_RDBFL60 (0xF00660D8u)
+#define MTU_MC80_RDBFL61 (0xF00660DAu)
+#define MTU_MC7_RDBFL43 (0xF00617B6u)
+#define MTU_MC80_RDBFL62 (0xF00660DCu)
+#define MTU_MC80_RDBFL63 (0xF00660DEu)
+#define MTU_MC80_RDBFL64 (0xF00660E0u)
+#define MTU_MC80_RDBFL65 (0xF00660E2u)
+#define MTU_MC80_RDBFL66 (0xF00660E4u)
+#define MTU_MC80_ALMSRCS (0xF00660EEu)
+#define MTU_MC80_FAULTSTS (0xF00660F0u)
+#define MTU_MC80_ERRINFO0 (0xF00660F2u)
+#define MTU_MC80_ERRINFO1 (0xF00660F4u)
+#define MTU_MC80_ERRINFO2 (0xF00660F6u)
+#define MTU_MC7_RDBFL44 (0xF00617B8u)
+#define MTU_MC80_ERRINFO3 (0xF00660F8u)
+#define MTU_MC80_ERRINFO4 (0xF00660FAu)
+#define MTU_MC81_CONFIG0 (0xF0066100u)
+#define MTU_MC81_CONFIG1 (0xF0066102u)
+#define MTU_MC81_MCONTROL (0xF0066104u)
+#define MTU_MC81_MSTATUS (0xF0066106u)
+#define MTU_MC81_RANGE (0xF0066108u)
+#define MTU_MC81_REVID (0xF006610Cu)
+#define MTU_MC81_ECCS (0xF006610Eu)
+#define MTU_MC81_ECCD (0xF0066110u)
+#define MTU_MC7_RDBFL45 (0xF00617BAu)
+#define MTU_MC81_ETRR0 (0xF0066112u)
+#define MTU_MC81_ETRR1 (0xF0066114u)
+#define MTU_MC81_ETRR2 (0xF0066116u)
+#define MTU_MC81_ETRR3 (0xF0066118u)
+#define MTU_MC81_ETRR4 (0xF006611Au)
+#define MTU_MC81_RDBFL0 (0xF0066160u)
+#define MTU_MC81_RDBFL1 (0xF0066162u)
+#define MTU_MC81_RDBFL2 (0xF0066164u)
+#define MTU_MC81_RDBFL3 (0xF0066166u)
+#define MTU_MC81_RDBFL4 (0xF0066168u)
+#define MTU_MC7_RDBFL46 (0xF00617BCu)
+#define MTU_MC81_RDBFL5 (0xF006616Au)
+#define MTU_MC81_RDBFL6 (0xF006616Cu)
+#define MTU_MC81_RDBFL7 (0xF006616Eu)
+#define MTU_MC81_RDBFL8 (0xF0066170u)
+#define MTU_MC81_RDBFL9 (0xF0066172u)
+#define MTU_MC81_RDBFL10 (0xF0066174u)
+#define MTU_MC81_RDBFL11 (0xF0066176u)
+#define MTU_MC81_RDBFL12 (0xF0066178u)
+#define MTU_MC81_RDBFL13 (0xF006617Au)
+#define MTU_MC81_RDBFL14 (0xF006617Cu)
+#define MTU_MC7_RDBFL47 (0xF00617BEu)
+#define MTU_MC81_RDBFL15 (0xF006617Eu)
+#define MTU_MC81_RDBFL16 (0xF0066180u)
+#define MTU_MC81_RDBFL17 (0xF0066182u)
+#define MTU_MC81_RDBFL18 (0xF0066184u)
+#define MTU_MC81_RDBFL19 (0xF0066186u)
+#define MTU_MC81_RDBFL20 (0xF0066188u)
+#define MTU_MC81_RDBFL21 (0xF006618Au)
+#define MTU_MC81_RDBFL22 (0xF006618Cu)
+#define MTU_MC81_RDBFL23 (0xF006618Eu)
+#define MTU_MC81_RDBFL24 (0xF0066190u)
+#define MTU_MC7_RDBFL48 (0xF00617C0u)
+#define MTU_MC81_RDBFL25 (0xF0066192u)
+#define MTU_MC81_RDBFL26 (0xF0066194u)
+#define MTU_MC81_RDBFL27 (0xF0066196u)
+#define MTU_MC81_RDBFL28 (0xF0066198u)
+#define MTU_MC81_RDBFL29 (0xF006619Au)
+#define MTU_MC81_RDBFL30 (0xF006619Cu)
+#define MTU_MC81_RDBFL31 (0xF006619Eu)
+#define MTU_MC81_RDBFL32 (0xF00661A0u)
+#define MTU_MC81_RDBFL33 (0xF00661A2u)
+#define MTU_MC81_RDBFL34 (0xF00661A4u)
+#define MTU_MC7_RDBFL49 (0xF00617C2u)
+#define MTU_MC81_RDBFL35 (0xF00661A6u)
+#define MTU_MC81_RDBFL36 (0xF00661A8u)
+#define MTU_MC81_RDBFL37 (0xF00661AAu)
+#define MTU_MC81_RDBFL38 (0xF00661ACu)
+#define MTU_MC81_RDBFL39 (0xF00661AEu)
+#define MTU_MC81_RDBFL40 (0xF00661B0u)
+#define MTU_MC81_RDBFL41 (0xF00661B2u)
+#define MTU_MC81_RDBFL42 (0xF00661B4u)
+#define MTU_MC81_RDBFL43 (0xF00661B6u)
+#define MTU_MC81_RDBFL44 (0xF00661B8u)
+#define MTU_MC7_RDBFL50 (0xF00617C4u)
+#define MTU_MC81_RDBFL45 (0xF00661BAu)
+#define MTU_MC81_RDBFL46 (0xF00661BCu)
+#define MTU_MC81_RDBFL47 (0xF00661BEu)
+#define MTU_MC81_RDBFL48 (0xF00661C0u)
+#define MTU_MC81_RDBFL49 (0xF00661C2u)
+#define MTU_MC81_RDBFL50 (0xF00661C4u)
+#define MTU_MC81_RDBFL51 (0xF00661C6u)
+#define MTU_MC81_RDBFL52 (0xF00661C8u)
+#define MTU_MC81_RDBFL53 (0xF00661CAu)
+#define MTU_MC81_RDBFL54 (0xF00661CCu)
+#define MTU_MC7_RDBFL51 (0xF00617C6u)
+#define MTU_MC81_RDBFL55 (0xF00661CEu)
+#define MTU_MC81_RDBFL56 (0xF00661D0u)
+#define MTU_MC81_RDBFL57 (0xF00661D2u)
+#define MTU_MC81_RDBFL58 (0xF00661D4u)
+#define MTU_MC81_RDBFL59 (0xF00661D6u)
+#define MTU_MC81_RDBFL60 (0xF00661D8u)
+#define MTU_MC81_RDBFL61 (0xF00661DAu)
+#define MTU_MC81_RDBFL62 (0xF00661DCu)
+#define MTU_MC81_RDBFL63 (0xF00661DEu)
+#define MTU_MC81_RDBFL64 (0xF00661E0u)
+#define MTU_MC7_RDBFL52 (0xF00617C8u)
+#define MTU_MC0_RDBFL16 (0xF0061080u)
+#define MTU_MC81_RDBFL65 (0xF00661E2u)
+#define MTU_MC81_RDBFL66 (0xF00661E4u)
+#define MTU_MC81_ALMSRCS (0xF00661EEu)
+#define MTU_MC81_FAULTSTS (0xF00661F0u)
+#define MTU_MC81_ERRINFO0 (0xF00661F2u)
+#define MTU_MC81_ERRINFO1 (0xF00661F4u)
+#define MTU_MC81_ERRINFO2 (0xF00661F6u)
+#define MTU_MC81_ERRINFO3 (0xF00661F8u)
+#define MTU_MC81_ERRINFO4 (0xF00661FAu)
+#define MTU_MC82_CONFIG0 (0xF0066200u)
+#define MTU_MC7_RDBFL53 (0xF00617CAu)
+#define MTU_MC82_CONFIG1 (0xF0066202u)
+#define MTU_MC82_MCONTROL (0xF0066204u)
+#define MTU_MC82_MSTATUS (0xF0066206u)
+#define MTU_MC82_RANGE (0xF0066208u)
+#define MTU_MC82_REVID (0xF006620Cu)
+#define MTU_MC82_ECCS (0xF006620Eu)
+#define MTU_MC82_ECCD (0xF0066210u)
+#define MTU_MC82_ETRR0 (0xF0066212u)
+#define MTU_MC82_ETRR1 (0xF0066214u)
+#define MTU_MC82_ETRR2 (0xF0066216u)
+#define MTU_MC7_RDBFL54 (0xF00617CCu)
+#define MTU_MC82_ETRR3 (0xF0066218u)
+#define MTU_MC82_ETRR4 (0xF006621Au)
+#define MTU_MC82_RDBFL0 (0xF0066260u)
+#define MTU_MC82_RDBFL1 (0xF0066262u)
+#define MTU_MC82_RDBFL2 (0xF0066264u)
+#define MTU_MC82_RDBFL3 (0xF0066266u)
+#define MTU_MC82_RDBFL4 (0xF0066268u)
+#define MTU_MC82_RDBFL5 (0xF006626Au)
+#define MTU_MC82_RDBFL6 (0xF006626Cu)
+#define MTU_MC82_RDBFL7 (0xF006626Eu)
+#define MTU_MC7_RDBFL55 (0xF00617CEu)
+#define MTU_MC82_RDBFL8 (0xF0066270u)
+#define MTU_MC82_RDBFL9 (0xF0066272u)
+#define MTU_MC82_RDBFL10 (0xF0066274u)
+#define MTU_MC82_RDBFL11 (0xF0066276u)
+#define MTU_MC82_RDBFL12 (0xF0066278u)
+#define MTU_MC82_RDBFL13 (0xF006627Au)
+#define MTU_MC82_RDBFL14 (0xF006627Cu)
+#define MTU_MC82_RDBFL15 (0xF006627Eu)
+#define MTU_MC82_RDBFL16 (0xF0066280u)
+#define MTU_MC82_RDBFL17 (0xF0066282u)
+#define MTU_MC7_RDBFL56 (0xF00617D0u)
+#define MTU_MC82_RDBFL18 (0xF0066284u)
+#define MTU_MC82_RDBFL19 (0xF0066286u)
+#define MTU_MC82_RDBFL20 (0xF0066288u)
+#define MTU_MC82_RDBFL21 (0xF006628Au)
+#define MTU_MC82_RDBFL22 (0xF006628Cu)
+#define MTU_MC82_RDBFL23 (0xF006628Eu)
+#define MTU_MC82_RDBFL24 (0xF0066290u)
+#define MTU_MC82_RDBFL25 (0xF0066292u)
+#define MTU_MC82_RDBFL26 (0xF0066294u)
+#define MTU_MC82_RDBFL27 (0xF0066296u)
+#define MTU_MC7_RDBFL57 (0xF00617D2u)
+#define MTU_MC82_RDBFL28 (0xF0066298u)
+#define MTU_MC82_RDBFL29 (0xF006629Au)
+#define MTU_MC82_RDBFL30 (0xF006629Cu)
+#define MTU_MC82_RDBFL31 (0xF006629Eu)
+#define MTU_MC82_RDBFL32 (0xF00662A0u)
+#define MTU_MC82_RDBFL33 (0xF00662A2u)
+#define MTU_MC82_RDBFL34 (0xF00662A4u)
+#define MTU_MC82_RDBFL35 (0xF00662A6u)
+#define MTU_MC82_RDBFL36 (0xF00662A8u)
+#define MTU_MC82_RDBFL37 (0xF00662AAu)
+#define MTU_MC7_RDBFL58 (0xF00617D4u)
+#define MTU_MC82_RDBFL38 (0xF00662ACu)
+#define MTU_MC82_RDBFL39 (0xF00662AEu)
+#define MTU_MC82_RDBFL40 (0xF00662B0u)
+#define MTU_MC82_RDBFL41 (0xF00662B2u)
+#define MTU_MC82_RDBFL42 (0xF00662B4u)
+#define MTU_MC82_RDBFL43 (0xF00662B6u)
+#define MTU_MC82_RDBFL44 (0xF00662B8u)
+#define MTU_MC82_RDBFL45 (0xF00662BAu)
+#define MTU_MC82_RDBFL46 (0xF00662BCu)
+#define MTU_MC82_RDBFL47 (0xF00662BEu)
+#define MTU_MC7_RDBFL59 (0xF00617D6u)
+#define MTU_MC82_RDBFL48 (0xF00662C0u)
+#define MTU_MC82_RDBFL49 (0xF00662C2u)
+#define MTU_MC82_RDBFL50 (0xF00662C4u)
+#define MTU_MC82_RDBFL51 (0xF00662C6u)
+#define MTU_MC82_RDBFL52 (0xF00662C8u)
+#define MTU_MC82_RDBFL53 (0xF00662CAu)
+#define MTU_MC82_RDBFL54 (0xF00662CCu)
+#define MTU_MC82_RDBFL55 (0xF00662CEu)
+#define MTU_MC82_RDBFL56 (0xF00662D0u)
+#define MTU_MC82_RDBFL57 (0xF00662D2u)
+#define MTU_MC7_RDBFL60 (0xF00617D8u)
+#define MTU_MC82_RDBFL58 (0xF00662D4u)
+#define MTU_MC82_RDBFL59 (0xF00662D6u)
+#define MTU_MC82_RDBFL60 (0xF00662D8u)
+#define MTU_MC82_RDBFL61 (0xF00662DAu)
+#define MTU_MC82_RDBFL62 (0xF00662DCu)
+#define MTU_MC82_RDBFL63 (0xF00662DEu)
+#define MTU_MC82_RDBFL64 (0xF00662E0u)
+#define MTU_MC82_RDBFL65 (0xF00662E2u)
+#define MTU_MC82_RDBFL66 (0xF00662E4u)
+#define MTU_MC82_ALMSRCS (0xF00662EEu)
+#define MTU_MC7_RDBFL61 (0xF00617DAu)
+#define MTU_MC82_FAULTSTS (0xF00662F0u)
+#define MTU_MC82_ERRINFO0 (0xF00662F2u)
+#define MTU_MC82_ERRINFO1 (0xF00662F4u)
+#define MTU_MC82_ERRINFO2 (0xF00662F6u)
+#define MTU_MC82_ERRINFO3 (0xF00662F8u)
+#define MTU_MC82_ERRINFO4 (0xF00662FAu)
+#define MTU_MC83_CONFIG0 (0xF0066300u)
+#define MTU_MC83_CONFIG1 (0xF0066302u)
+#define MTU_MC83_MCONTROL (0xF0066304u)
+#define MTU_MC83_MSTATUS (0xF0066306u)
+#define MTU_MC7_RDBFL62 (0xF00617DCu)
+#define MTU_MC0_RDBFL17 (0xF0061082u)
+#define MTU_MC83_RANGE (0xF0066308u)
+#define MTU_MC83_REVID (0xF006630Cu)
+#define MTU_MC83_ECCS (0xF006630Eu)
+#define MTU_MC83_ECCD (0xF0066310u)
+#define MTU_MC83_ETRR0 (0xF0066312u)
+#define MTU_MC83_ETRR1 (0xF0066314u)
+#define MTU_MC83_ETRR2 (0xF0066316u)
+#define MTU_MC83_ETRR3 (0xF0066318u)
+#define MTU_MC83_ETRR4 (0xF006631Au)
+#define MTU_MC83_RDBFL0 (0xF0066360u)
+#define MTU_MC7_RDBFL63 (0xF00617DEu)
+#define MTU_MC83_RDBFL1 (0xF0066362u)
+#define MTU_MC83_RDBFL2 (0xF0066364u)
+#define MTU_MC83_RDBFL3 (0xF0066366u)
+#define MTU_MC83_RDBFL4 (0xF0066368u)
+#define MTU_MC83_RDBFL5 (0xF006636Au)
+#define MTU_MC83_RDBFL6 (0xF006636Cu)
+#define MTU_MC83_RDBFL7 (0xF006636Eu)
+#define MTU_MC83_RDBFL8 (0xF0066370u)
+#define MTU_MC83_RDBFL9 (0xF0066372u)
+#define MTU_MC83_RDBFL10 (0xF0066374u)
+#define MTU_MC7_RDBFL64 (0xF00617E0u)
+#define MTU_MC83_RDBFL11 (0xF0066376u)
+#define MTU_MC83_RDBFL12 (0xF0066378u)
+#define MTU_MC83_RDBFL13 (0xF006637Au)
+#define MTU_MC83_RDBFL14 (0xF006637Cu)
+#define MTU_MC83_RDBFL15 (0xF006637Eu)
+#define MTU_MC83_RDBFL16 (0xF0066380u)
+#define MTU_MC83_RDBFL17 (0xF0066382u)
+#define MTU_MC83_RDBFL18 (0xF0066384u)
+#define MTU_MC83_RDBFL19 (0xF0066386u)
+#define MTU_MC83_RDBFL20 (0xF0066388u)
+#define MTU_MC7_RDBFL65 (0xF00617E2u)
+#define MTU_MC83_RDBFL21 (0xF006638Au)
+#define MTU_MC83_RDBFL22 (0xF006638Cu)
+#define MTU_MC83_RDBFL23 (0xF006638Eu)
+#define MTU_MC83_RDBFL24 (0xF0066390u)
+#define MTU_MC83_RDBFL25 (0xF0066392u)
+#define MTU_MC83_RDBFL26 (0xF0066394u)
+#define MTU_MC83_RDBFL27 (0xF0066396u)
+#define MTU_MC83_RDBFL28 (0xF0066398u)
+#define MTU_MC83_RDBFL29 (0xF006639Au)
+#define MTU_MC83_RDBFL30 (0xF006639Cu)
+#define MTU_MC7_RDBFL66 (0xF00617E4u)
+#define MTU_MC83_RDBFL31 (0xF006639Eu)
+#define MTU_MC83_RDBFL32 (0xF00663A0u)
+#define MTU_MC83_RDBFL33 (0xF00663A2u)
+#define MTU_MC83_RDBFL34 (0xF00663A4u)
+#define MTU_MC83_RDBFL35 (0xF00663A6u)
+#define MTU_MC83_RDBFL36 (0xF00663A8u)
+#define MTU_MC83_RDBFL37 (0xF00663AAu)
+#define MTU_MC83_RDBFL38 (0xF00663ACu)
+#define MTU_MC83_RDBFL39 (0xF00663AEu)
+#define MTU_MC83_RDBFL40 (0xF00663B0u)
+#define MTU_MC7_ALMSRCS (0xF00617EEu)
+#define MTU_MC83_RDBFL41 (0xF00663B2u)
+#define MTU_MC83_RDBFL42 (0xF00663B4u)
+#define MTU_MC83_RDBFL43 (0xF00663B6u)
+#define MTU_MC83_RDBFL44 (0xF00663B8u)
+#define MTU_MC83_RDBFL45 (0xF00663BAu)
+#define MTU_MC83_RDBFL46 (0xF00663BCu)
+#define MTU_MC83_RDBFL47 (0xF00663BEu)
+#define MTU_MC83_RDBFL48 (0xF00663C0u)
+#define MTU_MC83_RDBFL49 (0xF00663C2u)
+#define MTU_MC83_RDBFL50 (0xF00663C4u)
+#define MTU_MC7_FAULTSTS (0xF00617F0u)
+#define MTU_MC83_RDBFL51 (0xF00663C6u)
+#define MTU_MC83_RDBFL52 (0xF00663C8u)
+#define MTU_MC83_RDBFL53 (0xF00663CAu)
+#define MTU_MC83_RDBFL54 (0xF00663CCu)
+#define MTU_MC83_RDBFL55 (0xF00663CEu)
+#define MTU_MC83_RDBFL56 (0xF00663D0u)
+#define MTU_MC83_RDBFL57 (0xF00663D2u)
+#define MTU_MC83_RDBFL58 (0xF00663D4u)
+#define MTU_MC83_RDBFL59 (0xF00663D6u)
+#define MTU_MC83_RDBFL60 (0xF00663D8u)
+#define MTU_MC7_ERRINFO0 (0xF00617F2u)
+#define MTU_MC83_RDBFL61 (0xF00663DAu)
+#define MTU_MC83_RDBFL62 (0xF00663DCu)
+#define MTU_MC83_RDBFL63 (0xF00663DEu)
+#define MTU_MC83_RDBFL64 (0xF00663E0u)
+#define MTU_MC83_RDBFL65 (0xF00663E2u)
+#define MTU_MC83_RDBFL66 (0xF00663E4u)
+#define MTU_MC83_ALMSRCS (0xF00663EEu)
+#define MTU_MC83_FAULTSTS (0xF00663F0u)
+#define MTU_MC83_ERRINFO0 (0xF00663F2u)
+#define MTU_MC83_ERRINFO1 (0xF00663F4u)
+#define MTU_MC7_ERRINFO1 (0xF00617F4u)
+#define MTU_MC83_ERRINFO2 (0xF00663F6u)
+#define MTU_MC83_ERRINFO3 (0xF00663F8u)
+#define MTU_MC83_ERRINFO4 (0xF00663FAu)
+#define MTU_MC84_CONFIG0 (0xF0066400u)
+#define MTU_MC84_CONFIG1 (0xF0066402u)
+#define MTU_MC84_MCONTROL (0xF0066404u)
+#define MTU_MC84_MSTATUS (0xF0066406u)
+#define MTU_MC84_RANGE (0xF0066408u)
+#define MTU_MC84_REVID (0xF006640Cu)
+#define MTU_MC84_ECCS (0xF006640Eu)
+#define MTU_MC7_ERRINFO2 (0xF00617F6u)
+#define MTU_MC84_ECCD (0xF0066410u)
+#define MTU_MC84_ETRR0 (0xF0066412u)
+#define MTU_MC84_ETRR1 (0xF0066414u)
+#define MTU_MC84_ETRR2 (0xF0066416u)
+#define MTU_MC84_ETRR3 (0xF0066418u)
+#define MTU_MC84_ETRR4 (0xF006641Au)
+#define MTU_MC84_RDBFL0 (0xF0066460u)
+#define MTU_MC84_RDBFL1 (0xF0066462u)
+#define MTU_MC84_RDBFL2 (0xF0066464u)
+#define MTU_MC84_RDBFL3 (0xF0066466u)
+#define MTU_MC7_ERRINFO3 (0xF00617F8u)
+#define MTU_MC0_RDBFL18 (0xF0061084u)
+#define MTU_MC84_RDBFL4 (0xF0066468u)
+#define MTU_MC84_RDBFL5 (0xF006646Au)
+#define MTU_MC84_RDBFL6 (0xF006646Cu)
+#define MTU_MC84_RDBFL7 (0xF006646Eu)
+#define MTU_MC84_RDBFL8 (0xF0066470u)
+#define MTU_MC84_RDBFL9 (0xF0066472u)
+#define MTU_MC84_RDBFL10 (0xF0066474u)
+#define MTU_MC84_RDBFL11 (0xF0066476u)
+#define MTU_MC84_RDBFL12 (0xF0066478u)
+#define MTU_MC84_RDBFL13 (0xF006647Au)
+#define MTU_MC7_ERRINFO4 (0xF00617FAu)
+#define MTU_MC84_RDBFL14 (0xF006647Cu)
+#define MTU_MC84_RDBFL15 (0xF006647Eu)
+#define MTU_MC84_RDBFL16 (0xF0066480u)
+#define MTU_MC84_RDBFL17 (0xF0066482u)
+#define MTU_MC84_RDBFL18 (0xF0066484u)
+#define MTU_MC84_RDBFL19 (0xF0066486u)
+#define MTU_MC84_RDBFL20 (0xF0066488u)
+#define MTU_MC84_RDBFL21 (0xF006648Au)
+#define MTU_MC84_RDBFL22 (0xF006648Cu)
+#define MTU_MC84_RDBFL23 (0xF006648Eu)
+#define MTU_MC8_CONFIG0 (0xF0061800u)
+#define MTU_MC84_RDBFL24 (0xF0066490u)
+#define MTU_MC84_RDBFL25 (0xF0066492u)
+#define MTU_MC84_RDBFL26 (0xF0066494u)
+#define MTU_MC84_RDBFL27 (0xF0066496u)
+#define MTU_MC84_RDBFL28 (0xF0066498u)
+#define MTU_MC84_RDBFL29 (0xF006649Au)
+#define MTU_MC84_RDBFL30 (0xF006649Cu)
+#define MTU_MC84_RDBFL31 (0xF006649Eu)
+#define MTU_MC84_RDBFL32 (0xF00664A0u)
+#define MTU_MC84_RDBFL33 (0xF00664A2u)
+#define MTU_MC8_CONFIG1 (0xF0061802u)
+#define MTU_MC84_RDBFL34 (0xF00664A4u)
+#define MTU_MC84_RDBFL35 (0xF00664A6u)
+#define MTU_MC84_RDBFL36 (0xF00664A8u)
+#define MTU_MC84_RDBFL37 (0xF00664AAu)
+#define MTU_MC84_RDBFL38 (0xF00664ACu)
+#define MTU_MC84_RDBFL39 (0xF00664AEu)
+#define MTU_MC84_RDBFL40 (0xF00664B0u)
+#define MTU_MC84_RDBFL41 (0xF00664B2u)
+#define MTU_MC84_RDBFL42 (0xF00664B4u)
+#define MTU_MC84_RDBFL43 (0xF00664B6u)
+#define MTU_MC8_MCONTROL (0xF0061804u)
+#define MTU_MC84_RDBFL44 (0xF00664B8u)
+#define MTU_MC84_RDBFL45 (0xF00664BAu)
+#define MTU_MC84_RDBFL46 (0xF00664BCu)
+#define MTU_MC84_RDBFL47 (0xF00664BEu)
+#define MTU_MC84_RDBFL48 (0xF00664C0u)
+#define MTU_MC84_RDBFL49 (0xF00664C2u)
+#define MTU_MC84_RDBFL50 (0xF00664C4u)
+#define MTU_MC84_RDBFL51 (0xF00664C6u)
+#define MTU_MC84_RDBFL52 (0xF00664C8u)
+#define MTU_MC84_RDBFL53 (0xF00664CAu)
+#define MTU_MC8_MSTATUS (0xF0061806u)
+#define MTU_MC84_RDBFL54 (0xF00664CCu)
+#define MTU_MC84_RDBFL55 (0xF00664CEu)
+#define MTU_MC84_RDBFL56 (0xF00664D0u)
+#define MTU_MC84_RDBFL57 (0xF00664D2u)
+#define MTU_MC84_RDBFL58 (0xF00664D4u)
+#define MTU_MC84_RDBFL59 (0xF00664D6u)
+#define MTU_MC84_RDBFL60 (0xF00664D8u)
+#define MTU_MC84_RDBFL61 (0xF00664DAu)
+#define MTU_MC84_RDBFL62 (0xF00664DCu)
+#define MTU_MC84_RDBFL63 (0xF00664DEu)
+#define MTU_MC8_RANGE (0xF0061808u)
+#define MTU_MC84_RDBFL64 (0xF00664E0u)
+#define MTU_MC84_RDBFL65 (0xF00664E2u)
+#define MTU_MC84_RDBFL66 (0xF00664E4u)
+#define MTU_MC84_ALMSRCS (0xF00664EEu)
+#define MTU_MC84_FAULTSTS (0xF00664F0u)
+#define MTU_MC84_ERRINFO0 (0xF00664F2u)
+#define MTU_MC84_ERRINFO1 (0xF00664F4u)
+#define MTU_MC84_ERRINFO2 (0xF00664F6u)
+#define MTU_MC84_ERRINFO3 (0xF00664F8u)
+#define MTU_MC84_ERRINFO4 (0xF00664FAu)
+#define MTU_MC8_REVID (0xF006180Cu)
+#define MTU_MC85_CONFIG0 (0xF0066500u)
+#define MTU_MC85_CONFIG1 (0xF0066502u)
+#define MTU_MC85_MCONTROL (0xF0066504u)
+#define MTU_MC85_MSTATUS (0xF0066506u)
+#define MTU_MC85_RANGE (0xF0066508u)
+#define MTU_MC85_REVID (0xF006650Cu)
+#define MTU_MC85_ECCS (0xF006650Eu)
+#define MTU_MC85_ECCD (0xF0066510u)
+#define MTU_MC85_ETRR0 (0xF0066512u)
+#define MTU_MC85_ETRR1 (0xF0066514u)
+#define MTU_MC8_ECCS (0xF006180Eu)
+#define MTU_MC85_ETRR2 (0xF0066516u)
+#define MTU_MC85_ETRR3 (0xF0066518u)
+#define MTU_MC85_ETRR4 (0xF006651Au)
+#define MTU_MC85_RDBFL0 (0xF0066560u)
+#define MTU_MC85_RDBFL1 (0xF0066562u)
+#define MTU_MC85_RDBFL2 (0xF0066564u)
+#define MTU_MC85_RDBFL3 (0xF0066566u)
+#define MTU_MC85_RDBFL4 (0xF0066568u)
+#define MTU_MC85_RDBFL5 (0xF006656Au)
+#define MTU_MC85_RDBFL6 (0xF006656Cu)
+#define MTU_MC8_ECCD (0xF0061810u)
+#define MTU_MC85_RDBFL7 (0xF006656Eu)
+#define MTU_MC85_RDBFL8 (0xF0066570u)
+#define MTU_MC85_RDBFL9 (0xF0066572u)
+#define MTU_MC85_RDBFL10 (0xF0066574u)
+#define MTU_MC85_RDBFL11 (0xF0066576u)
+#define MTU_MC85_RDBFL12 (0xF0066578u)
+#define MTU_MC85_RDBFL13 (0xF006657Au)
+#define MTU_MC85_RDBFL14 (0xF006657Cu)
+#define MTU_MC85_RDBFL15 (0xF006657Eu)
+#define MTU_MC85_RDBFL16 (0xF0066580u)
+#define MTU_MC8_ETRR0 (0xF0061812u)
+#define MTU_MC0_RDBFL19 (0xF0061086u)
+#define MTU_MC85_RDBFL17 (0xF0066582u)
+#define MTU_MC85_RDBFL18 (0xF0066584u)
+#define MTU_MC85_RDBFL19 (0xF0066586u)
+#define MTU_MC85_RDBFL20 (0xF0066588u)
+#define MTU_MC85_RDBFL21 (0xF006658Au)
+#define MTU_MC85_RDBFL22 (0xF006658Cu)
+#define MTU_MC85_RDBFL23 (0xF006658Eu)
+#define MTU_MC85_RDBFL24 (0xF0066590u)
+#define MTU_MC85_RDBFL25 (0xF0066592u)
+#define MTU_MC85_RDBFL26 (0xF0066594u)
+#define MTU_MC8_ETRR1 (0xF0061814u)
+#define MTU_MC85_RDBFL27 (0xF0066596u)
+#define MTU_MC85_RDBFL28 (0xF0066598u)
+#define MTU_MC85_RDBFL29 (0xF006659Au)
+#define MTU_MC85_RDBFL30 (0xF006659Cu)
+#define MTU_MC85_RDBFL31 (0xF006659Eu)
+#define MTU_MC85_RDBFL32 (0xF00665A0u)
+#define MTU_MC85_RDBFL33 (0xF00665A2u)
+#define MTU_MC85_RDBFL34 (0xF00665A4u)
+#define MTU_MC85_RDBFL35 (0xF00665A6u)
+#define MTU_MC85_RDBFL36 (0xF00665A8u)
+#define MTU_MC8_ETRR2 (0xF0061816u)
+#define MTU_MC85_RDBFL37 (0xF00665AAu)
+#define MTU_MC85_RDBFL38 (0xF00665ACu)
+#define MTU_MC85_RDBFL39 (0xF00665AEu)
+#define MTU_MC85_RDBFL40 (0xF00665B0u)
+#define MTU_MC85_RDBFL41 (0xF00665B2u)
+#define MTU_MC85_RDBFL42 (0xF00665B4u)
+#define MTU_MC85_RDBFL43 (0xF00665B6u)
+#define MTU_MC85_RDBFL44 (0xF00665B8u)
+#define MTU_MC85_RDBFL45 (0xF00665BAu)
+#define MTU_MC85_RDBFL46 (0xF00665BCu)
+#define MTU_MC8_ETRR3 (0xF0061818u)
+#define MTU_MC85_RDBFL47 (0xF00665BEu)
+#define MTU_MC85_RDBFL48 (0xF00665C0u)
+#define MTU_MC85_RDBFL49 (0xF00665C2u)
+#define MTU_MC85_RDBFL50 (0xF00665C4u)
+#define MTU_MC85_RDBFL51 (0xF00665C6u)
+#define MTU_MC85_RDBFL52 (0xF00665C8u)
+#define MTU_MC85_RDBFL53 (0xF00665CAu)
+#define MTU_MC85_RDBFL54 (0xF00665CCu)
+#define MTU_MC85_RDBFL55 (0xF00665CEu)
+#define MTU_MC85_RDBFL56 (0xF00665D0u)
+#define MTU_MC8_ETRR4 (0xF006181Au)
+#define MTU_MC85_RDBFL57 (0xF00665D2u)
+#define MTU_MC85_RDBFL58 (0xF00665D4u)
+#define MTU_MC85_RDBFL59 (0xF00665D6u)
+#define MTU_MC85_RDBFL60 (0xF00665D8u)
+#define MTU_MC85_RDBFL61 (0xF00665DAu)
+#define MTU_MC85_RDBFL62 (0xF00665DCu)
+#define MTU_MC85_RDBFL63 (0xF00665DEu)
+#define MTU_MC85_RDBFL64 (0xF00665E0u)
+#define MTU_MC85_RDBFL65 (0xF00665E2u)
+#define MTU_MC85_RDBFL66 (0xF00665E4u)
+#define MTU_MC8_RDBFL0 (0xF0061860u)
+#define MTU_MC85_ALMSRCS (0xF00665EEu)
+#define MTU_MC85_FAULTSTS (0xF00665F0u)
+#define MTU_MC85_ERRINFO0 (0xF00665F2u)
+#define MTU_MC85_ERRINFO1 (0xF00665F4u)
+#define MTU_MC85_ERRINFO2 (0xF00665F6u)
+#define MTU_MC85_ERRINFO3 (0xF00665F8u)
+#define MTU_MC85_ERRINFO4 (0xF00665FAu)
+#define MTU_MC86_CONFIG0 (0xF0066600u)
+#define MTU_MC86_CONFIG1 (0xF0066602u)
+#define MTU_MC86_MCONTROL (0xF0066604u)
+#define MTU_MC8_RDBFL1 (0xF0061862u)
+#define MTU_MC86_MSTATUS (0xF0066606u)
+#define MTU_MC86_RANGE (0xF0066608u)
+#define MTU_MC86_REVID (0xF006660Cu)
+#define MTU_MC86_ECCS (0xF006660Eu)
+#define MTU_MC86_ECCD (0xF0066610u)
+#define MTU_MC86_ETRR0 (0xF0066612u)
+#define MTU_MC86_ETRR1 (0xF0066614u)
+#define MTU_MC86_ETRR2 (0xF0066616u)
+#define MTU_MC86_ETRR3 (0xF0066618u)
+#define MTU_MC86_ETRR4 (0xF006661Au)
+#define MTU_MC8_RDBFL2 (0xF0061864u)
+#define MTU_MC86_RDBFL0 (0xF0066660u)
+#define MTU_MC86_RDBFL1 (0xF0066662u)
+#define MTU_MC86_RDBFL2 (0xF0066664u)
+#define MTU_MC86_RDBFL3 (0xF0066666u)
+#define MTU_MC86_RDBFL4 (0xF0066668u)
+#define MTU_MC86_RDBFL5 (0xF006666Au)
+#define MTU_MC86_RDBFL6 (0xF006666Cu)
+#define MTU_MC86_RDBFL7 (0xF006666Eu)
+#define MTU_MC86_RDBFL8 (0xF0066670u)
+#define MTU_MC86_RDBFL9 (0xF0066672u)
+#define MTU_MC8_RDBFL3 (0xF0061866u)
+#define MTU_MC86_RDBFL10 (0xF0066674u)
+#define MTU_MC86_RDBFL11 (0xF0066676u)
+#define MTU_MC86_RDBFL12 (0xF0066678u)
+#define MTU_MC86_RDBFL13 (0xF006667Au)
+#define MTU_MC86_RDBFL14 (0xF006667Cu)
+#define MTU_MC86_RDBFL15 (0xF006667Eu)
+#define MTU_MC86_RDBFL16 (0xF0066680u)
+#define MTU_MC86_RDBFL17 (0xF0066682u)
+#define MTU_MC86_RDBFL18 (0xF0066684u)
+#define MTU_MC86_RDBFL19 (0xF0066686u)
+#define MTU_MC8_RDBFL4 (0xF0061868u)
+#define MTU_MC86_RDBFL20 (0xF0066688u)
+#define MTU_MC86_RDBFL21 (0xF006668Au)
+#define MTU_MC86_RDBFL22 (0xF006668Cu)
+#define MTU_MC86_RDBFL23 (0xF006668Eu)
+#define MTU_MC86_RDBFL24 (0xF0066690u)
+#define MTU_MC86_RDBFL25 (0xF0066692u)
+#define MTU_MC86_RDBFL26 (0xF0066694u)
+#define MTU_MC86_RDBFL27 (0xF0066696u)
+#define MTU_MC86_RDBFL28 (0xF0066698u)
+#define MTU_MC86_RDBFL29 (0xF006669Au)
+#define MTU_MC8_RDBFL5 (0xF006186Au)
+#define MTU_MC0_RDBFL20 (0xF0061088u)
+#define MTU_MC86_RDBFL30 (0xF006669Cu)
+#define MTU_MC86_RDBFL31 (0xF006669Eu)
+#define MTU_MC86_RDBFL32 (0xF00666A0u)
+#define MTU_MC86_RDBFL33 (0xF00666A2u)
+#define MTU_MC86_RDBFL34 (0xF00666A4u)
+#define MTU_MC86_RDBFL35 (0xF00666A6u)
+#define MTU_MC86_RDBFL36 (0xF00666A8u)
+#define MTU_MC86_RDBFL37 (0xF00666AAu)
+#define MTU_MC86_RDBFL38 (0xF00666ACu)
+#define MTU_MC86_RDBFL39 (0xF00666AEu)
+#define MTU_MC8_RDBFL6 (0xF006186Cu)
+#define MTU_MC86_RDBFL40 (0xF00666B0u)
+#define MTU_MC86_RDBFL41 (0xF00666B2u)
+#define MTU_MC86_RDBFL42 (0xF00666B4u)
+#define MTU_MC86_RDBFL43 (0xF00666B6u)
+#define MTU_MC86_RDBFL44 (0xF00666B8u)
+#define MTU_MC86_RDBFL45 (0xF00666BAu)
+#define MTU_MC86_RDBFL46 (0xF00666BCu)
+#define MTU_MC86_RDBFL47 (0xF00666BEu)
+#define MTU_MC86_RDBFL48 (0xF00666C0u)
+#define MTU_MC86_RDBFL49 (0xF00666C2u)
+#define MTU_MC8_RDBFL7 (0xF006186Eu)
+#define MTU_MC86_RDBFL50 (0xF00666C4u)
+#define MTU_MC86_RDBFL51 (0xF00666C6u)
+#define MTU_MC86_RDBFL52 (0xF00666C8u)
+#define MTU_MC86_RDBFL53 (0xF00666CAu)
+#define MTU_MC86_RDBFL54 (0xF00666CCu)
+#define MTU_MC86_RDBFL55 (0xF00666CEu)
+#define MTU_MC86_RDBFL56 (0xF00666D0u)
+#define MTU_MC86_RDBFL57 (0xF00666D2u)
+#define MTU_MC86_RDBFL58 (0xF00666D4u)
+#define MTU_MC86_RDBFL59 (0xF00666D6u)
+#define MTU_MC8_RDBFL8 (0xF0061870u)
+#define MTU_MC86_RDBFL60 (0xF00666D8u)
+#define MTU_MC86_RDBFL61 (0xF00666DAu)
+#define MTU_MC86_RDBFL62 (0xF00666DCu)
+#define MTU_MC86_RDBFL63 (0xF00666DEu)
+#define MTU_MC86_RDBFL64 (0xF00666E0u)
+#define MTU_MC86_RDBFL65 (0xF00666E2u)
+#define MTU_MC86_RDBFL66 (0xF00666E4u)
+#define MTU_MC86_ALMSRCS (0xF00666EEu)
+#define MTU_MC86_FAULTSTS (0xF00666F0u)
+#define MTU_MC86_ERRINFO0 (0xF00666F2u)
+#define MTU_MC8_RDBFL9 (0xF0061872u)
+#define MTU_MC86_ERRINFO1 (0xF00666F4u)
+#define MTU_MC86_ERRINFO2 (0xF00666F6u)
+#define MTU_MC86_ERRINFO3 (0xF00666F8u)
+#define MTU_MC86_ERRINFO4 (0xF00666FAu)
+#define MTU_MC87_CONFIG0 (0xF0066700u)
+#define MTU_MC87_CONFIG1 (0xF0066702u)
+#define MTU_MC87_MCONTROL (0xF0066704u)
+#define MTU_MC87_MSTATUS (0xF0066706u)
+#define MTU_MC87_RANGE (0xF0066708u)
+#define MTU_MC87_REVID (0xF006670Cu)
+#define MTU_MC8_RDBFL10 (0xF0061874u)
+#define MTU_MC87_ECCS (0xF006670Eu)
+#define MTU_MC87_ECCD (0xF0066710u)
+#define MTU_MC87_ETRR0 (0xF0066712u)
+#define MTU_MC87_ETRR1 (0xF0066714u)
+#define MTU_MC87_ETRR2 (0xF0066716u)
+#define MTU_MC87_ETRR3 (0xF0066718u)
+#define MTU_MC87_ETRR4 (0xF006671Au)
+#define MTU_MC87_RDBFL0 (0xF0066760u)
+#define MTU_MC87_RDBFL1 (0xF0066762u)
+#define MTU_MC87_RDBFL2 (0xF0066764u)
+#define MTU_MC8_RDBFL11 (0xF0061876u)
+#define MTU_MC87_RDBFL3 (0xF0066766u)
+#define MTU_MC87_RDBFL4 (0xF0066768u)
+#define MTU_MC87_RDBFL5 (0xF006676Au)
+#define MTU_MC87_RDBFL6 (0xF006676Cu)
+#define MTU_MC87_RDBFL7 (0xF006676Eu)
+#define MTU_MC87_RDBFL8 (0xF0066770u)
+#define MTU_MC87_RDBFL9 (0xF0066772u)
+#define MTU_MC87_RDBFL10 (0xF0066774u)
+#define MTU_MC87_RDBFL11 (0xF0066776u)
+#define MTU_MC87_RDBFL12 (0xF0066778u)
+#define MTU_MC8_RDBFL12 (0xF0061878u)
+#define MTU_MC87_RDBFL13 (0xF006677Au)
+#define MTU_MC87_RDBFL14 (0xF006677Cu)
+#define MTU_MC87_RDBFL15 (0xF006677Eu)
+#define MTU_MC87_RDBFL16 (0xF0066780u)
+#define MTU_MC87_RDBFL17 (0xF0066782u)
+#define MTU_MC87_RDBFL18 (0xF0066784u)
+#define MTU_MC87_RDBFL19 (0xF0066786u)
+#define MTU_MC87_RDBFL20 (0xF0066788u)
+#define MTU_MC87_RDBFL21 (0xF006678Au)
+#define MTU_MC87_RDBFL22 (0xF006678Cu)
+#define MTU_MC8_RDBFL13 (0xF006187Au)
+#define MTU_MC87_RDBFL23 (0xF006678Eu)
+#define MTU_MC87_RDBFL24 (0xF0066790u)
+#define MTU_MC87_RDBFL25 (0xF0066792u)
+#define MTU_MC87_RDBFL26 (0xF0066794u)
+#define MTU_MC87_RDBFL27 (0xF0066796u)
+#define MTU_MC87_RDBFL28 (0xF0066798u)
+#define MTU_MC87_RDBFL29 (0xF006679Au)
+#define MTU_MC87_RDBFL30 (0xF006679Cu)
+#define MTU_MC87_RDBFL31 (0xF006679Eu)
+#define MTU_MC87_RDBFL32 (0xF00667A0u)
+#define MTU_MC8_RDBFL14 (0xF006187Cu)
+#define MTU_MC87_RDBFL33 (0xF00667A2u)
+#define MTU_MC87_RDBFL34 (0xF00667A4u)
+#define MTU_MC87_RDBFL35 (0xF00667A6u)
+#define MTU_MC87_RDBFL36 (0xF00667A8u)
+#define MTU_MC87_RDBFL37 (0xF00667AAu)
+#define MTU_MC87_RDBFL38 (0xF00667ACu)
+#define MTU_MC87_RDBFL39 (0xF00667AEu)
+#define MTU_MC87_RDBFL40 (0xF00667B0u)
+#define MTU_MC87_RDBFL41 (0xF00667B2u)
+#define MTU_MC87_RDBFL42 (0xF00667B4u)
+#define MTU_MC8_RDBFL15 (0xF006187Eu)
+#define MTU_MC0_RDBFL21 (0xF006108Au)
+#define MTU_MC87_RDBFL43 (0xF00667B6u)
+#define MTU_MC87_RDBFL44 (0xF00667B8u)
+#define MTU_MC87_RDBFL45 (0xF00667BAu)
+#define MTU_MC87_RDBFL46 (0xF00667BCu)
+#define MTU_MC87_RDBFL47 (0xF00667BEu)
+#define MTU_MC87_RDBFL48 (0xF00667C0u)
+#define MTU_MC87_RDBFL49 (0xF00667C2u)
+#define MTU_MC87_RDBFL50 (0xF00667C4u)
+#define MTU_MC87_RDBFL51 (0xF00667C6u)
+#define MTU_MC87_RDBFL52 (0xF00667C8u)
+#define MTU_MC8_RDBFL16 (0xF0061880u)
+#define MTU_MC87_RDBFL53 (0xF00667CAu)
+#define MTU_MC87_RDBFL54 (0xF00667CCu)
+#define MTU_MC87_RDBFL55 (0xF00667CEu)
+#define MTU_MC87_RDBFL56 (0xF00667D0u)
+#define MTU_MC87_RDBFL57 (0xF00667D2u)
+#define MTU_MC87_RDBFL58 (0xF00667D4u)
+#define MTU_MC87_RDBFL59 (0xF00667D6u)
+#define MTU_MC87_RDBFL60 (0xF00667D8u)
+#define MTU_MC87_RDBFL61 (0xF00667DAu)
+#define MTU_MC87_RDBFL62 (0xF00667DCu)
+#define MTU_MC8_RDBFL17 (0xF0061882u)
+#define MTU_MC87_RDBFL63 (0xF00667DEu)
+#define MTU_MC87_RDBFL64 (0xF00667E0u)
+#define MTU_MC87_RDBFL65 (0xF00667E2u)
+#define MTU_MC87_RDBFL66 (0xF00667E4u)
+#define MTU_MC87_ALMSRCS (0xF00667EEu)
+#define MTU_MC87_FAULTSTS (0xF00667F0u)
+#define MTU_MC87_ERRINFO0 (0xF00667F2u)
+#define MTU_MC87_ERRINFO1 (0xF00667F4u)
+#define MTU_MC87_ERRINFO2 (0xF00667F6u)
+#define MTU_MC87_ERRINFO3 (0xF00667F8u)
+#define MTU_MC8_RDBFL18 (0xF0061884u)
+#define MTU_MC87_ERRINFO4 (0xF00667FAu)
+#define MTU_MC88_CONFIG0 (0xF0066800u)
+#define MTU_MC88_CONFIG1 (0xF0066802u)
+#define MTU_MC88_MCONTROL (0xF0066804u)
+#define MTU_MC88_MSTATUS (0xF0066806u)
+#define MTU_MC88_RANGE (0xF0066808u)
+#define MTU_MC88_REVID (0xF006680Cu)
+#define MTU_MC88_ECCS (0xF006680Eu)
+#define MTU_MC88_ECCD (0xF0066810u)
+#define MTU_MC88_ETRR0 (0xF0066812u)
+#define MTU_MC8_RDBFL19 (0xF0061886u)
+#define MTU_MC88_ETRR1 (0xF0066814u)
+#define MTU_MC88_ETRR2 (0xF0066816u)
+#define MTU_MC88_ETRR3 (0xF0066818u)
+#define MTU_MC88_ETRR4 (0xF006681Au)
+#define MTU_MC88_RDBFL0 (0xF0066860u)
+#define MTU_MC88_RDBFL1 (0xF0066862u)
+#define MTU_MC88_RDBFL2 (0xF0066864u)
+#define MTU_MC88_RDBFL3 (0xF0066866u)
+#define MTU_MC88_RDBFL4 (0xF0066868u)
+#define MTU_MC88_RDBFL5 (0xF006686Au)
+#define MTU_MC8_RDBFL20 (0xF0061888u)
+#define MTU_MC88_RDBFL6 (0xF006686Cu)
+#define MTU_MC88_RDBFL7 (0xF006686Eu)
+#define MTU_MC88_RDBFL8 (0xF0066870u)
+#define MTU_MC88_RDBFL9 (0xF0066872u)
+#define MTU_MC88_RDBFL10 (0xF0066874u)
+#define MTU_MC88_RDBFL11 (0xF0066876u)
+#define MTU_MC88_RDBFL12 (0xF0066878u)
+#define MTU_MC88_RDBFL13 (0xF006687Au)
+#define MTU_MC88_RDBFL14 (0xF006687Cu)
+#define MTU_MC88_RDBFL15 (0xF006687Eu)
+#define MTU_MC8_RDBFL21 (0xF006188Au)
+#define MTU_MC88_RDBFL16 (0xF0066880u)
+#define MTU_MC88_RDBFL17 (0xF0066882u)
+#define MTU_MC88_RDBFL18 (0xF0066884u)
+#define MTU_MC88_RDBFL19 (0xF0066886u)
+#define MTU_MC88_RDBFL20 (0xF0066888u)
+#define MTU_MC88_RDBFL21 (0xF006688Au)
+#define MTU_MC88_RDBFL22 (0xF006688Cu)
+#define MTU_MC88_RDBFL23 (0xF006688Eu)
+#define MTU_MC88_RDBFL24 (0xF0066890u)
+#define MTU_MC88_RDBFL25 (0xF0066892u)
+#define MTU_MC8_RDBFL22 (0xF006188Cu)
+#define MTU_MC88_RDBFL26 (0xF0066894u)
+#define MTU_MC88_RDBFL27 (0xF0066896u)
+#define MTU_MC88_RDBFL28 (0xF0066898u)
+#define MTU_MC88_RDBFL29 (0xF006689Au)
+#define MTU_MC88_RDBFL30 (0xF006689Cu)
+#define MTU_MC88_RDBFL31 (0xF006689Eu)
+#define MTU_MC88_RDBFL32 (0xF00668A0u)
+#define MTU_MC88_RDBFL33 (0xF00668A2u)
+#define MTU_MC88_RDBFL34 (0xF00668A4u)
+#define MTU_MC88_RDBFL35 (0xF00668A6u)
+#define MTU_MC8_RDBFL23 (0xF006188Eu)
+#define MTU_MC88_RDBFL36 (0xF00668A8u)
+#define MTU_MC88_RDBFL37 (0xF00668AAu)
+#define MTU_MC88_RDBFL38 (0xF00668ACu)
+#define MTU_MC88_RDBFL39 (0xF00668AEu)
+#define MTU_MC88_RDBFL40 (0xF00668B0u)
+#define MTU_MC88_RDBFL41 (0xF00668B2u)
+#define MTU_MC88_RDBFL42 (0xF00668B4u)
+#define MTU_MC88_RDBFL43 (0xF00668B6u)
+#define MTU_MC88_RDBFL44 (0xF00668B8u)
+#define MTU_MC88_RDBFL45 (0xF00668BAu)
+#define MTU_MC8_RDBFL24 (0xF0061890u)
+#define MTU_MC88_RDBFL46 (0xF00668BCu)
+#define MTU_MC88_RDBFL47 (0xF00668BEu)
+#define MTU_MC88_RDBFL48 (0xF00668C0u)
+#define MTU_MC88_RDBFL49 (0xF00668C2u)
+#define MTU_MC88_RDBFL50 (0xF00668C4u)
+#define MTU_MC88_RDBFL51 (0xF00668C6u)
+#define MTU_MC88_RDBFL52 (0xF00668C8u)
+#define MTU_MC88_RDBFL53 (0xF00668CAu)
+#define MTU_MC88_RDBFL54 (0xF00668CCu)
+#define MTU_MC88_RDBFL55 (0xF00668CEu)
+#define MTU_MC8_RDBFL25 (0xF0061892u)
+#define MTU_MC0_RDBFL22 (0xF006108Cu)
+#define MTU_MC88_RDBFL56 (0xF00668D0u)
+#define MTU_MC88_RDBFL57 (0xF00668D2u)
+#define MTU_MC88_RDBFL58 (0xF00668D4u)
+#define MTU_MC88_RDBFL59 (0xF00668D6u)
+#define MTU_MC88_RDBFL60 (0xF00668D8u)
+#define MTU_MC88_RDBFL61 (0xF00668DAu)
+#define MTU_MC88_RDBFL62 (0xF00668DCu)
+#define MTU_MC88_RDBFL63 (0xF00668DEu)
+#define MTU_MC88_RDBFL64 (0xF00668E0u)
+#define MTU_MC88_RDBFL65 (0xF00668E2u)
+#define MTU_MC8_RDBFL26 (0xF0061894u)
+#define MTU_MC88_RDBFL66 (0xF00668E4u)
+#define MTU_MC88_ALMSRCS (0xF00668EEu)
+#define MTU_MC88_FAULTSTS (0xF00668F0u)
+#define MTU_MC88_ERRINFO0 (0xF00668F2u)
+#define MTU_MC88_ERRINFO1 (0xF00668F4u)
+#define MTU_MC88_ERRINFO2 (0xF00668F6u)
+#define MTU_MC88_ERRINFO3 (0xF00668F8u)
+#define MTU_MC88_ERRINFO4 (0xF00668FAu)
+#define MTU_MC89_CONFIG0 (0xF0066900u)
+#define MTU_MC89_CONFIG1 (0xF0066902u)
+#define MTU_MC8_RDBFL27 (0xF0061896u)
+#define MTU_MC89_MCONTROL (0xF0066904u)
+#define MTU_MC89_MSTATUS (0xF0066906u)
+#define MTU_MC89_RANGE (0xF0066908u)
+#define MTU_MC89_REVID (0xF006690Cu)
+#define MTU_MC89_ECCS (0xF006690Eu)
+#define MTU_MC89_ECCD (0xF0066910u)
+#define MTU_MC89_ETRR0 (0xF0066912u)
+#define MTU_MC89_ETRR1 (0xF0066914u)
+#define MTU_MC89_ETRR2 (0xF0066916u)
+#define MTU_MC89_ETRR3 (0xF0066918u)
+#define MTU_MC8_RDBFL28 (0xF0061898u)
+#define MTU_MC89_ETRR4 (0xF006691Au)
+#define MTU_MC89_RDBFL0 (0xF0066960u)
+#define MTU_MC89_RDBFL1 (0xF0066962u)
+#define MTU_MC89_RDBFL2 (0xF0066964u)
+#define MTU_MC89_RDBFL3 (0xF0066966u)
+#define MTU_MC89_RDBFL4 (0xF0066968u)
+#define MTU_MC89_RDBFL5 (0xF006696Au)
+#define MTU_MC89_RDBFL6 (0xF006696Cu)
+#define MTU_MC89_RDBFL7 (0xF006696Eu)
+#define MTU_MC89_RDBFL8 (0xF0066970u)
+#define MTU_MC8_RDBFL29 (0xF006189Au)
+#define MTU_MC89_RDBFL9 (0xF0066972u)
+#define MTU_MC89_RDBFL10 (0xF0066974u)
+#define MTU_MC89_RDBFL11 (0xF0066976u)
+#define MTU_MC89_RDBFL12 (0xF0066978u)
+#define MTU_MC89_RDBFL13 (0xF006697Au)
+#define MTU_MC89_RDBFL14 (0xF006697Cu)
+#define MTU_MC89_RDBFL15 (0xF006697Eu)
+#define MTU_MC89_RDBFL16 (0xF0066980u)
+#define MTU_MC89_RDBFL17 (0xF0066982u)
+#define MTU_MC89_RDBFL18 (0xF0066984u)
+#define MTU_MC8_RDBFL30 (0xF006189Cu)
+#define MTU_MC89_RDBFL19 (0xF0066986u)
+#define MTU_MC89_RDBFL20 (0xF0066988u)
+#define MTU_MC89_RDBFL21 (0xF006698Au)
+#define MTU_MC89_RDBFL22 (0xF006698Cu)
+#define MTU_MC89_RDBFL23 (0xF006698Eu)
+#define MTU_MC89_RDBFL24 (0xF0066990u)
+#define MTU_MC89_RDBFL25 (0xF0066992u)
+#define MTU_MC89_RDBFL26 (0xF0066994u)
+#define MTU_MC89_RDBFL27 (0xF0066996u)
+#define MTU_MC89_RDBFL28 (0xF0066998u)
+#define MTU_MC8_RDBFL31 (0xF006189Eu)
+#define MTU_MC89_RDBFL29 (0xF006699Au)
+#define MTU_MC89_RDBFL30 (0xF006699Cu)
+#define MTU_MC89_RDBFL31 (0xF006699Eu)
+#define MTU_MC89_RDBFL32 (0xF00669A0u)
+#define MTU_MC89_RDBFL33 (0xF00669A2u)
+#define MTU_MC89_RDBFL34 (0xF00669A4u)
+#define MTU_MC89_RDBFL35 (0xF00669A6u)
+#define MTU_MC89_RDBFL36 (0xF00669A8u)
+#define MTU_MC89_RDBFL37 (0xF00669AAu)
+#define MTU_MC89_RDBFL38 (0xF00669ACu)
+#define MTU_MC8_RDBFL32 (0xF00618A0u)
+#define MTU_MC89_RDBFL39 (0xF00669AEu)
+#define MTU_MC89_RDBFL40 (0xF00669B0u)
+#define MTU_MC89_RDBFL41 (0xF00669B2u)
+#define MTU_MC89_RDBFL42 (0xF00669B4u)
+#define MTU_MC89_RDBFL43 (0xF00669B6u)
+#define MTU_MC89_RDBFL44 (0xF00669B8u)
+#define MTU_MC89_RDBFL45 (0xF00669BAu)
+#define MTU_MC89_RDBFL46 (0xF00669BCu)
+#define MTU_MC89_RDBFL47 (0xF00669BEu)
+#define MTU_MC89_RDBFL48 (0xF00669C0u)
+#define MTU_MC8_RDBFL33 (0xF00618A2u)
+#define MTU_MC89_RDBFL49 (0xF00669C2u)
+#define MTU_MC89_RDBFL50 (0xF00669C4u)
+#define MTU_MC89_RDBFL51 (0xF00669C6u)
+#define MTU_MC89_RDBFL52 (0xF00669C8u)
+#define MTU_MC89_RDBFL53 (0xF00669CAu)
+#define MTU_MC89_RDBFL54 (0xF00669CCu)
+#define MTU_MC89_RDBFL55 (0xF00669CEu)
+#define MTU_MC89_RDBFL56 (0xF00669D0u)
+#define MTU_MC89_RDBFL57 (0xF00669D2u)
+#define MTU_MC89_RDBFL58 (0xF00669D4u)
+#define MTU_MC8_RDBFL34 (0xF00618A4u)
+#define MTU_MC89_RDBFL59 (0xF00669D6u)
+#define MTU_MC89_RDBFL60 (0xF00669D8u)
+#define MTU_MC89_RDBFL61 (0xF00669DAu)
+#define MTU_MC89_RDBFL62 (0xF00669DCu)
+#define MTU_MC89_RDBFL63 (0xF00669DEu)
+#define MTU_MC89_RDBFL64 (0xF00669E0u)
+#define MTU_MC89_RDBFL65 (0xF00669E2u)
+#define MTU_MC89_RDBFL66 (0xF00669E4u)
+#define MTU_MC89_ALMSRCS (0xF00669EEu)
+#define MTU_MC89_FAULTSTS (0xF00669F0u)
+#define MTU_MC8_RDBFL35 (0xF00618A6u)
+#define MTU_MC0_RDBFL23 (0xF006108Eu)
+#define MTU_MC89_ERRINFO0 (0xF00669F2u)
+#define MTU_MC89_ERRINFO1 (0xF00669F4u)
+#define MTU_MC89_ERRINFO2 (0xF00669F6u)
+#define MTU_MC89_ERRINFO3 (0xF00669F8u)
+#define MTU_MC89_ERRINFO4 (0xF00669FAu)
+#define MTU_MC90_CONFIG0 (0xF0066A00u)
+#define MTU_MC90_CONFIG1 (0xF0066A02u)
+#define MTU_MC90_MCONTROL (0xF0066A04u)
+#define MTU_MC90_MSTATUS (0xF0066A06u)
+#define MTU_MC90_RANGE (0xF0066A08u)
+#define MTU_MC8_RDBFL36 (0xF00618A8u)
+#define MTU_MC90_REVID (0xF0066A0Cu)
+#define MTU_MC90_ECCS (0xF0066A0Eu)
+#define MTU_MC90_ECCD (0xF0066A10u)
+#define MTU_MC90_ETRR0 (0xF0066A12u)
+#define MTU_MC90_ETRR1 (0xF0066A14u)
+#define MTU_MC90_ETRR2 (0xF0066A16u)
+#define MTU_MC90_ETRR3 (0xF0066A18u)
+#define MTU_MC90_ETRR4 (0xF0066A1Au)
+#define MTU_MC90_RDBFL0 (0xF0066A60u)
+#define MTU_MC90_RDBFL1 (0xF0066A62u)
+#define MTU_MC8_RDBFL37 (0xF00618AAu)
+#define MTU_MC90_RDBFL2 (0xF0066A64u)
+#define MTU_MC90_RDBFL3 (0xF0066A66u)
+#define MTU_MC90_RDBFL4 (0xF0066A68u)
+#define MTU_MC90_RDBFL5 (0xF0066A6Au)
+#define MTU_MC90_RDBFL6 (0xF0066A6Cu)
+#define MTU_MC90_RDBFL7 (0xF0066A6Eu)
+#define MTU_MC90_RDBFL8 (0xF0066A70u)
+#define MTU_MC90_RDBFL9 (0xF0066A72u)
+#define MTU_MC90_RDBFL10 (0xF0066A74u)
+#define MTU_MC90_RDBFL11 (0xF0066A76u)
+#define MTU_MC8_RDBFL38 (0xF00618ACu)
+#define MTU_MC90_RDBFL12 (0xF0066A78u)
+#define MTU_MC90_RDBFL13 (0xF0066A7Au)
+#define MTU_MC90_RDBFL14 (0xF0066A7Cu)
+#define MTU_MC90_RDBFL15 (0xF0066A7Eu)
+#define MTU_MC90_RDBFL16 (0xF0066A80u)
+#define MTU_MC90_RDBFL17 (0xF0066A82u)
+#define MTU_MC90_RDBFL18 (0xF0066A84u)
+#define MTU_MC90_RDBFL19 (0xF0066A86u)
+#define MTU_MC90_RDBFL20 (0xF0066A88u)
+#define MTU_MC90_RDBFL21 (0xF0066A8Au)
+#define MTU_MC8_RDBFL39 (0xF00618AEu)
+#define MTU_MC90_RDBFL22 (0xF0066A8Cu)
+#define MTU_MC90_RDBFL23 (0xF0066A8Eu)
+#define MTU_MC90_RDBFL24 (0xF0066A90u)
+#define MTU_MC90_RDBFL25 (0xF0066A92u)
+#define MTU_MC90_RDBFL26 (0xF0066A94u)
+#define MTU_MC90_RDBFL27 (0xF0066A96u)
+#define MTU_MC90_RDBFL28 (0xF0066A98u)
+#define MTU_MC90_RDBFL29 (0xF0066A9Au)
+#define MTU_MC90_RDBFL30 (0xF0066A9Cu)
+#define MTU_MC90_RDBFL31 (0xF0066A9Eu)
+#define MTU_MC8_RDBFL40 (0xF00618B0u)
+#define MTU_MC90_RDBFL32 (0xF0066AA0u)
+#define MTU_MC90_RDBFL33 (0xF0066AA2u)
+#define MTU_MC90_RDBFL34 (0xF0066AA4u)
+#define MTU_MC90_RDBFL35 (0xF0066AA6u)
+#define MTU_MC90_RDBFL36 (0xF0066AA8u)
+#define MTU_MC90_RDBFL37 (0xF0066AAAu)
+#define MTU_MC90_RDBFL38 (0xF0066AACu)
+#define MTU_MC90_RDBFL39 (0xF0066AAEu)
+#define MTU_MC90_RDBFL40 (0xF0066AB0u)
+#define MTU_MC90_RDBFL41 (0xF0066AB2u)
+#define MTU_MC8_RDBFL41 (0xF00618B2u)
+#define MTU_MC90_RDBFL42 (0xF0066AB4u)
+#define MTU_MC90_RDBFL43 (0xF0066AB6u)
+#define MTU_MC90_RDBFL44 (0xF0066AB8u)
+#define MTU_MC90_RDBFL45 (0xF0066ABAu)
+#define MTU_MC90_RDBFL46 (0xF0066ABCu)
+#define MTU_MC90_RDBFL47 (0xF0066ABEu)
+#define MTU_MC90_RDBFL48 (0xF0066AC0u)
+#define MTU_MC90_RDBFL49 (0xF0066AC2u)
+#define MTU_MC90_RDBFL50 (0xF0066AC4u)
+#define MTU_MC90_RDBFL51 (0xF0066AC6u)
+#define MTU_MC8_RDBFL42 (0xF00618B4u)
+#define MTU_MC90_RDBFL52 (0xF0066AC8u)
+#define MTU_MC90_RDBFL53 (0xF0066ACAu)
+#define MTU_MC90_RDBFL54 (0xF0066ACCu)
+#define MTU_MC90_RDBFL55 (0xF0066ACEu)
+#define MTU_MC90_RDBFL56 (0xF0066AD0u)
+#define MTU_MC90_RDBFL57 (0xF0066AD2u)
+#define MTU_MC90_RDBFL58 (0xF0066AD4u)
+#define MTU_MC90_RDBFL59 (0xF0066AD6u)
+#define MTU_MC90_RDBFL60 (0xF0066AD8u)
+#define MTU_MC90_RDBFL61 (0xF0066ADAu)
+#define MTU_MC8_RDBFL43 (0xF00618B6u)
+#define MTU_MC90_RDBFL62 (0xF0066ADCu)
+#define MTU_MC90_RDBFL63 (0xF0066ADEu)
+#define MTU_MC90_RDBFL64 (0xF0066AE0u)
+#define MTU_MC90_RDBFL65 (0xF0066AE2u)
+#define MTU_MC90_RDBFL66 (0xF0066AE4u)
+#define MTU_MC90_ALMSRCS (0xF0066AEEu)
+#define MTU_MC90_FAULTSTS (0xF0066AF0u)
+#define MTU_MC90_ERRINFO0 (0xF0066AF2u)
+#define MTU_MC90_ERRINFO1 (0xF0066AF4u)
+#define MTU_MC90_ERRINFO2 (0xF0066AF6u)
+#define MTU_MC8_RDBFL44 (0xF00618B8u)
+#define MTU_MC90_ERRINFO3 (0xF0066AF8u)
+#define MTU_MC90_ERRINFO4 (0xF0066AFAu)
+#define MTU_MC91_CONFIG0 (0xF0066B00u)
+#define MTU_MC91_CONFIG1 (0xF0066B02u)
+#define MTU_MC91_MCONTROL (0xF0066B04u)
+#define MTU_MC91_MSTATUS (0xF0066B06u)
+#define MTU_MC91_RANGE (0xF0066B08u)
+#define MTU_MC91_REVID (0xF0066B0Cu)
+#define MTU_MC91_ECCS (0xF0066B0Eu)
+#define MTU_MC91_ECCD (0xF0066B10u)
+#define MTU_MC8_RDBFL45 (0xF00618BAu)
+#define MTU_MC0_RDBFL24 (0xF0061090u)
+#define MTU_MC91_ETRR0 (0xF0066B12u)
+#define MTU_MC91_ETRR1 (0xF0066B14u)
+#define MTU_MC91_ETRR2 (0xF0066B16u)
+#define MTU_MC91_ETRR3 (0xF0066B18u)
+#define MTU_MC91_ETRR4 (0xF0066B1Au)
+#define MTU_MC91_RDBFL0 (0xF0066B60u)
+#define MTU_MC91_RDBFL1 (0xF0066B62u)
+#define MTU_MC91_RDBFL2 (0xF0066B64u)
+#define MTU_MC91_RDBFL3 (0xF0066B66u)
+#define MTU_MC91_RDBFL4 (0xF0066B68u)
+#define MTU_MC8_RDBFL46 (0xF00618BCu)
+#define MTU_MC91_RDBFL5 (0xF0066B6Au)
+#define MTU_MC91_RDBFL6 (0xF0066B6Cu)
+#define MTU_MC91_RDBFL7 (0xF0066B6Eu)
+#define MTU_MC91_RDBFL8 (0xF0066B70u)
+#define MTU_MC91_RDBFL9 (0xF0066B72u)
+#define MTU_MC91_RDBFL10 (0xF0066B74u)
+#define MTU_MC91_RDBFL11 (0xF0066B76u)
+#define MTU_MC91_RDBFL12 (0xF0066B78u)
+#define MTU_MC91_RDBFL13 (0xF0066B7Au)
+#define MTU_MC91_RDBFL14 (0xF0066B7Cu)
+#define MTU_MC8_RDBFL47 (0xF00618BEu)
+#define MTU_MC91_RDBFL15 (0xF0066B7Eu)
+#define MTU_MC91_RDBFL16 (0xF0066B80u)
+#define MTU_MC91_RDBFL17 (0xF0066B82u)
+#define MTU_MC91_RDBFL18 (0xF0066B84u)
+#define MTU_MC91_RDBFL19 (0xF0066B86u)
+#define MTU_MC91_RDBFL20 (0xF0066B88u)
+#define MTU_MC91_RDBFL21 (0xF0066B8Au)
+#define MTU_MC91_RDBFL22 (0xF0066B8Cu)
+#define MTU_MC91_RDBFL23 (0xF0066B8Eu)
+#define MTU_MC91_RDBFL24 (0xF0066B90u)
+#define MTU_MC8_RDBFL48 (0xF00618C0u)
+#define MTU_MC91_RDBFL25 (0xF0066B92u)
+#define MTU_MC91_RDBFL26 (0xF0066B94u)
+#define MTU_MC91_RDBFL27 (0xF0066B96u)
+#define MTU_MC91_RDBFL28 (0xF0066B98u)
+#define MTU_MC91_RDBFL29 (0xF0066B9Au)
+#define MTU_MC91_RDBFL30 (0xF0066B9Cu)
+#define MTU_MC91_RDBFL31 (0xF0066B9Eu)
+#define MTU_MC91_RDBFL32 (0xF0066BA0u)
+#define MTU_MC91_RDBFL33 (0xF0066BA2u)
+#define MTU_MC91_RDBFL34 (0xF0066BA4u)
+#define MTU_MC8_RDBFL49 (0xF00618C2u)
+#define MTU_MC91_RDBFL35 (0xF0066BA6u)
+#define MTU_MC91_RDBFL36 (0xF0066BA8u)
+#define MTU_MC91_RDBFL37 (0xF0066BAAu)
+#define MTU_MC91_RDBFL38 (0xF0066BACu)
+#define MTU_MC91_RDBFL39 (0xF0066BAEu)
+#define MTU_MC91_RDBFL40 (0xF0066BB0u)
+#define MTU_MC91_RDBFL41 (0xF0066BB2u)
+#define MTU_MC91_RDBFL42 (0xF0066BB4u)
+#define MTU_MC91_RDBFL43 (0xF0066BB6u)
+#define MTU_MC91_RDBFL44 (0xF0066BB8u)
+#define MTU_MC8_RDBFL50 (0xF00618C4u)
+#define MTU_MC91_RDBFL45 (0xF0066BBAu)
+#define MTU_MC91_RDBFL46 (0xF0066BBCu)
+#define MTU_MC91_RDBFL47 (0xF0066BBEu)
+#define MTU_MC91_RDBFL48 (0xF0066BC0u)
+#define MTU_MC91_RDBFL49 (0xF0066BC2u)
+#define MTU_MC91_RDBFL50 (0xF0066BC4u)
+#define MTU_MC91_RDBFL51 (0xF0066BC6u)
+#define MTU_MC91_RDBFL52 (0xF0066BC8u)
+#define MTU_MC91_RDBFL53 (0xF0066BCAu)
+#define MTU_MC91_RDBFL54 (0xF0066BCCu)
+#define MTU_MC8_RDBFL51 (0xF00618C6u)
+#define MTU_MC91_RDBFL55 (0xF0066BCEu)
+#define MTU_MC91_RDBFL56 (0xF0066BD0u)
+#define MTU_MC91_RDBFL57 (0xF0066BD2u)
+#define MTU_MC91_RDBFL58 (0xF0066BD4u)
+#define MTU_MC91_RDBFL59 (0xF0066BD6u)
+#define MTU_MC91_RDBFL60 (0xF0066BD8u)
+#define MTU_MC91_RDBFL61 (0xF0066BDAu)
+#define MTU_MC91_RDBFL62 (0xF0066BDCu)
+#define MTU_MC91_RDBFL63 (0xF0066BDEu)
+#define MTU_MC91_RDBFL64 (0xF0066BE0u)
+#define MTU_MC8_RDBFL52 (0xF00618C8u)
+#define MTU_MC91_RDBFL65 (0xF0066BE2u)
+#define MTU_MC91_RDBFL66 (0xF0066BE4u)
+#define MTU_MC91_ALMSRCS (0xF0066BEEu)
+#define MTU_MC91_FAULTSTS (0xF0066BF0u)
+#define MTU_MC91_ERRINFO0 (0xF0066BF2u)
+#define MTU_MC91_ERRINFO1 (0xF0066BF4u)
+#define MTU_MC91_ERRINFO2 (0xF0066BF6u)
+#define MTU_MC91_ERRINFO3 (0xF0066BF8u)
+#define MTU_MC91_ERRINFO4 (0xF0066BFAu)
+#define MTU_MC92_CONFIG0 (0xF0066C00u)
+#define MTU_MC8_RDBFL53 (0xF00618CAu)
+#define MTU_MC92_CONFIG1 (0xF0066C02u)
+#define MTU_MC92_MCONTROL (0xF0066C04u)
+#define MTU_MC92_MSTATUS (0xF0066C06u)
+#define MTU_MC92_RANGE (0xF0066C08u)
+#define MTU_MC92_REVID (0xF0066C0Cu)
+#define MTU_MC92_ECCS (0xF0066C0Eu)
+#define MTU_MC92_ECCD (0xF0066C10u)
+#define MTU_MC92_ETRR0 (0xF0066C12u)
+#define MTU_MC92_ETRR1 (0xF0066C14u)
+#define MTU_MC92_ETRR2 (0xF0066C16u)
+#define MTU_MC8_RDBFL54 (0xF00618CCu)
+#define MTU_MC92_ETRR3 (0xF0066C18u)
+#define MTU_MC92_ETRR4 (0xF0066C1Au)
+#define MTU_MC92_RDBFL0 (0xF0066C60u)
+#define MTU_MC92_RDBFL1 (0xF0066C62u)
+#define MTU_MC92_RDBFL2 (0xF0066C64u)
+#define MTU_MC92_RDBFL3 (0xF0066C66u)
+#define MTU_MC92_RDBFL4 (0xF0066C68u)
+#define MTU_MC92_RDBFL5 (0xF0066C6Au)
+#define MTU_MC92_RDBFL6 (0xF0066C6Cu)
+#define MTU_MC92_RDBFL7 (0xF0066C6Eu)
+#define MTU_MC8_RDBFL55 (0xF00618CEu)
+#define MTU_MC0_RDBFL25 (0xF0061092u)
+#define MTU_MC92_RDBFL8 (0xF0066C70u)
+#define MTU_MC92_RDBFL9 (0xF0066C72u)
+#define MTU_MC92_RDBFL10 (0xF0066C74u)
+#define MTU_MC92_RDBFL11 (0xF0066C76u)
+#define MTU_MC92_RDBFL12 (0xF0066C78u)
+#define MTU_MC92_RDBFL13 (0xF0066C7Au)
+#define MTU_MC92_RDBFL14 (0xF0066C7Cu)
+#define MTU_MC92_RDBFL15 (0xF0066C7Eu)
+#define MTU_MC92_RDBFL16 (0xF0066C80u)
+#define MTU_MC92_RDBFL17 (0xF0066C82u)
+#define MTU_MC8_RDBFL56 (0xF00618D0u)
+#define MTU_MC92_RDBFL18 (0xF0066C84u)
+#define MTU_MC92_RDBFL19 (0xF0066C86u)
+#define MTU_MC92_RDBFL20 (0xF0066C88u)
+#define MTU_MC92_RDBFL21 (0xF0066C8Au)
+#define MTU_MC92_RDBFL22 (0xF0066C8Cu)
+#define MTU_MC92_RDBFL23 (0xF0066C8Eu)
+#define MTU_MC92_RDBFL24 (0xF0066C90u)
+#define MTU_MC92_RDBFL25 (0xF0066C92u)
+#define MTU_MC92_RDBFL26 (0xF0066C94u)
+#define MTU_MC92_RDBFL27 (0xF0066C96u)
+#define MTU_MC8_RDBFL57 (0xF00618D2u)
+#define MTU_MC92_RDBFL28 (0xF0066C98u)
+#define MTU_MC92_RDBFL29 (0xF0066C9Au)
+#define MTU_MC92_RDBFL30 (0xF0066C9Cu)
+#define MTU_MC92_RDBFL31 (0xF0066C9Eu)
+#define MTU_MC92_RDBFL32 (0xF0066CA0u)
+#define MTU_MC92_RDBFL33 (0xF0066CA2u)
+#define MTU_MC92_RDBFL34 (0xF0066CA4u)
+#define MTU_MC92_RDBFL35 (0xF0066CA6u)
+#define MTU_MC92_RDBFL36 (0xF0066CA8u)
+#define MTU_MC92_RDBFL37 (0xF0066CAAu)
+#define MTU_MC8_RDBFL58 (0xF00618D4u)
+#define MTU_MC92_RDBFL38 (0xF0066CACu)
+#define MTU_MC92_RDBFL39 (0xF0066CAEu)
+#define MTU_MC92_RDBFL40 (0xF0066CB0u)
+#define MTU_MC92_RDBFL41 (0xF0066CB2u)
+#define MTU_MC92_RDBFL42 (0xF0066CB4u)
+#define MTU_MC92_RDBFL43 (0xF0066CB6u)
+#define MTU_MC92_RDBFL44 (0xF0066CB8u)
+#define MTU_MC92_RDBFL45 (0xF0066CBAu)
+#define MTU_MC92_RDBFL46 (0xF0066CBCu)
+#define MTU_MC92_RDBFL47 (0xF0066CBEu)
+#define MTU_MC8_RDBFL59 (0xF00618D6u)
+#define MTU_MC92_RDBFL48 (0xF0066CC0u)
+#define MTU_MC92_RDBFL49 (0xF0066CC2u)
+#define MTU_MC92_RDBFL50 (0xF0066CC4u)
+#define MTU_MC92_RDBFL51 (0xF0066CC6u)
+#define MTU_MC92_RDBFL52 (0xF0066CC8u)
+#define MTU_MC92_RDBFL53 (0xF0066CCAu)
+#define MTU_MC92_RDBFL54 (0xF0066CCCu)
+#define MTU_MC92_RDBFL55 (0xF0066CCEu)
+#define MTU_MC92_RDBFL56 (0xF0066CD0u)
+#define MTU_MC92_RDBFL57 (0xF0066CD2u)
+#define MTU_MC8_RDBFL60 (0xF00618D8u)
+#define MTU_MC92_RDBFL58 (0xF0066CD4u)
+#define MTU_MC92_RDBFL59 (0xF0066CD6u)
+#define MTU_MC92_RDBFL60 (0xF0066CD8u)
+#define MTU_MC92_RDBFL61 (0xF0066CDAu)
+#define MTU_MC92_RDBFL62 (0xF0066CDCu)
+#define MTU_MC92_RDBFL63 (0xF0066CDEu)
+#define MTU_MC92_RDBFL64 (0xF0066CE0u)
+#define MTU_MC92_RDBFL65 (0xF0066CE2u)
+#define MTU_MC92_RDBFL66 (0xF0066CE4u)
+#define MTU_MC92_ALMSRCS (0xF0066CEEu)
+#define MTU_MC8_RDBFL61 (0xF00618DAu)
+#define MTU_MC92_FAULTSTS (0xF0066CF0u)
+#define MTU_MC92_ERRINFO0 (0xF0066CF2u)
+#define MTU_MC92_ERRINFO1 (0xF0066CF4u)
+#define MTU_MC92_ERRINFO2 (0xF0066CF6u)
+#define MTU_MC92_ERRINFO3 (0xF0066CF8u)
+#define MTU_MC92_ERRINFO4 (0xF0066CFAu)
+#define MTU_MC93_CONFIG0 (0xF0066D00u)
+#define MTU_MC93_CONFIG1 (0xF0066D02u)
+#define MTU_MC93_MCONTROL (0xF0066D04u)
+#define MTU_MC93_MSTATUS (0xF0066D06u)
+#define MTU_MC8_RDBFL62 (0xF00618DCu)
+#define MTU_MC93_RANGE (0xF0066D08u)
+#define MTU_MC93_REVID (0xF0066D0Cu)
+#define MTU_MC93_ECCS (0xF0066D0Eu)
+#define MTU_MC93_ECCD (0xF0066D10u)
+#define MTU_MC93_ETRR0 (0xF0066D12u)
+#define MTU_MC93_ETRR1 (0xF0066D14u)
+#define MTU_MC93_ETRR2 (0xF0066D16u)
+#define MTU_MC93_ETRR3 (0xF0066D18u)
+#define MTU_MC93_ETRR4 (0xF0066D1Au)
+#define MTU_MC93_RDBFL0 (0xF0066D60u)
+#define MTU_MC8_RDBFL63 (0xF00618DEu)
+#define MTU_MC93_RDBFL1 (0xF0066D62u)
+#define MTU_MC93_RDBFL2 (0xF0066D64u)
+#define MTU_MC93_RDBFL3 (0xF0066D66u)
+#define MTU_MC93_RDBFL4 (0xF0066D68u)
+#define MTU_MC93_RDBFL5 (0xF0066D6Au)
+#define MTU_MC93_RDBFL6 (0xF0066D6Cu)
+#define MTU_MC93_RDBFL7 (0xF0066D6Eu)
+#define MTU_MC93_RDBFL8 (0xF0066D70u)
+#define MTU_MC93_RDBFL9 (0xF0066D72u)
+#define MTU_MC93_RDBFL10 (0xF0066D74u)
+#define MTU_MC8_RDBFL64 (0xF00618E0u)
+#define MTU_MC93_RDBFL11 (0xF0066D76u)
+#define MTU_MC93_RDBFL12 (0xF0066D78u)
+#define MTU_MC93_RDBFL13 (0xF0066D7Au)
+#define MTU_MC93_RDBFL14 (0xF0066D7Cu)
+#define MTU_MC93_RDBFL15 (0xF0066D7Eu)
+#define MTU_MC93_RDBFL16 (0xF0066D80u)
+#define MTU_MC93_RDBFL17 (0xF0066D82u)
+#define MTU_MC93_RDBFL18 (0xF0066D84u)
+#define MTU_MC93_RDBFL19 (0xF0066D86u)
+#define MTU_MC93_RDBFL20 (0xF0066D88u)
+#define MTU_MC8_RDBFL65 (0xF00618E2u)
+#define MTU_MC0_RDBFL26 (0xF0061094u)
+#define MTU_MC93_RDBFL21 (0xF0066D8Au)
+#define MTU_MC93_RDBFL22 (0xF0066D8Cu)
+#define MTU_MC93_RDBFL23 (0xF0066D8Eu)
+#define MTU_MC93_RDBFL24 (0xF0066D90u)
+#define MTU_MC93_RDBFL25 (0xF0066D92u)
+#define MTU_MC93_RDBFL26 (0xF0066D94u)
+#define MTU_MC93_RDBFL27 (0xF0066D96u)
+#define MTU_MC93_RDBFL28 (0xF0066D98u)
+#define MTU_MC93_RDBFL29 (0xF0066D9Au)
+#define MTU_MC93_RDBFL30 (0xF0066D9Cu)
+#define MTU_MC8_RDBFL66 (0xF00618E4u)
+#define MTU_MC93_RDBFL31 (0xF0066D9Eu)
+#define MTU_MC93_RDBFL32 (0xF0066DA0u)
+#define MTU_MC93_RDBFL33 (0xF0066DA2u)
+#define MTU_MC93_RDBFL34 (0xF0066DA4u)
+#define MTU_MC93_RDBFL35 (0xF0066DA6u)
+#define MTU_MC93_RDBFL36 (0xF0066DA8u)
+#define MTU_MC93_RDBFL37 (0xF0066DAAu)
+#define MTU_MC93_RDBFL38 (0xF0066DACu)
+#define MTU_MC93_RDBFL39 (0xF0066DAEu)
+#define MTU_MC93_RDBFL40 (0xF0066DB0u)
+#define MTU_MC8_ALMSRCS (0xF00618EEu)
+#define MTU_MC93_RDBFL41 (0xF0066DB2u)
+#define MTU_MC93_RDBFL42 (0xF0066DB4u)
+#define MTU_MC93_RDBFL43 (0xF0066DB6u)
+#define MTU_MC93_RDBFL44 (0xF0066DB8u)
+#define MTU_MC93_RDBFL45 (0xF0066DBAu)
+#define MTU_MC93_RDBFL46 (0xF0066DBCu)
+#define MTU_MC93_RDBFL47 (0xF0066DBEu)
+#define MTU_MC93_RDBFL48 (0xF0066DC0u)
+#define MTU_MC93_RDBFL49 (0xF0066DC2u)
+#define MTU_MC93_RDBFL50 (0xF0066DC4u)
+#define MTU_MC8_FAULTSTS (0xF00618F0u)
+#define MTU_MC93_RDBFL51 (0xF0066DC6u)
+#define MTU_MC93_RDBFL52 (0xF0066DC8u)
+#define MTU_MC93_RDBFL53 (0xF0066DCAu)
+#define MTU_MC93_RDBFL54 (0xF0066DCCu)
+#define MTU_MC93_RDBFL55 (0xF0066DCEu)
+#define MTU_MC93_RDBFL56 (0xF0066DD0u)
+#define MTU_MC93_RDBFL57 (0xF0066DD2u)
+#define MTU_MC93_RDBFL58 (0xF0066DD4u)
+#define MTU_MC93_RDBFL59 (0xF0066DD6u)
+#define MTU_MC93_RDBFL60 (0xF0066DD8u)
+#define MTU_MC8_ERRINFO0 (0xF00618F2u)
+#define MTU_MC93_RDBFL61 (0xF0066DDAu)
+#define MTU_MC93_RDBFL62 (0xF0066DDCu)
+#define MTU_MC93_RDBFL63 (0xF0066DDEu)
+#define MTU_MC93_RDBFL64 (0xF0066DE0u)
+#define MTU_MC93_RDBFL65 (0xF0066DE2u)
+#define MTU_MC93_RDBFL66 (0xF0066DE4u)
+#define MTU_MC93_ALMSRCS (0xF0066DEEu)
+#define MTU_MC93_FAULTSTS (0xF0066DF0u)
+#define MTU_MC93_ERRINFO0 (0xF0066DF2u)
+#define MTU_MC93_ERRINFO1 (0xF0066DF4u)
+#define MTU_MC8_ERRINFO1 (0xF00618F4u)
+#define MTU_MC93_ERRINFO2 (0xF0066DF6u)
+#define MTU_MC93_ERRINFO3 (0xF0066DF8u)
+#define MTU_MC93_ERRINFO4 (0xF0066DFAu)
+#define MTU_MC94_CONFIG0 (0xF0066E00u)
+#define MTU_MC94_CONFIG1 (0xF0066E02u)
+#define MTU_MC94_MCONTROL (0xF0066E04u)
+#define MTU_MC94_MSTATUS (0xF0066E06u)
+#define MTU_MC94_RANGE (0xF0066E08u)
+#define MTU_MC94_REVID (0xF0066E0Cu)
+#define MTU_MC94_ECCS (0xF0066E0Eu)
+#define MTU_MC8_ERRINFO2 (0xF00618F6u)
+#define MTU_MC94_ECCD (0xF0066E10u)
+#define MTU_MC94_ETRR0 (0xF0066E12u)
+#define MTU_MC94_ETRR1 (0xF0066E14u)
+#define MTU_MC94_ETRR2 (0xF0066E16u)
+#define MTU_MC94_ETRR3 (0xF0066E18u)
+#define MTU_MC94_ETRR4 (0xF0066E1Au)
+#define MTU_MC94_RDBFL0 (0xF0066E60u)
+#define MTU_MC94_RDBFL1 (0xF0066E62u)
+#define MTU_MC94_RDBFL2 (0xF0066E64u)
+#define MTU_MC94_RDBFL3 (0xF0066E66u)
+#define MTU_MC8_ERRINFO3 (0xF00618F8u)
+#define MTU_MC94_RDBFL4 (0xF0066E68u)
+#define MTU_MC94_RDBFL5 (0xF0066E6Au)
+#define MTU_MC94_RDBFL6 (0xF0066E6Cu)
+#define MTU_MC94_RDBFL7 (0xF0066E6Eu)
+#define MTU_MC94_RDBFL8 (0xF0066E70u)
+#define MTU_MC94_RDBFL9 (0xF0066E72u)
+#define MTU_MC94_RDBFL10 (0xF0066E74u)
+#define MTU_MC94_RDBFL11 (0xF0066E76u)
+#define MTU_MC94_RDBFL12 (0xF0066E78u)
+#define MTU_MC94_RDBFL13 (0xF0066E7Au)
+#define MTU_MC8_ERRINFO4 (0xF00618FAu)
+#define MTU_MC94_RDBFL14 (0xF0066E7Cu)
+#define MTU_MC94_RDBFL15 (0xF0066E7Eu)
+#define MTU_MC94_RDBFL16 (0xF0066E80u)
+#define MTU_MC94_RDBFL17 (0xF0066E82u)
+#define MTU_MC94_RDBFL18 (0xF0066E84u)
+#define MTU_MC94_RDBFL19 (0xF0066E86u)
+#define MTU_MC94_RDBFL20 (0xF0066E88u)
+#define MTU_MC94_RDBFL21 (0xF0066E8Au)
+#define MTU_MC94_RDBFL22 (0xF0066E8Cu)
+#define MTU_MC94_RDBFL23 (0xF0066E8Eu)
+#define MTU_MC9_CONFIG0 (0xF0061900u)
+#define MTU_MC94_RDBFL24 (0xF0066E90u)
+#define MTU_MC94_RDBFL25 (0xF0066E92u)
+#define MTU_MC94_RDBFL26 (0xF0066E94u)
+#define MTU_MC94_RDBFL27 (0xF0066E96u)
+#define MTU_MC94_RDBFL28 (0xF0066E98u)
+#define MTU_MC94_RDBFL29 (0xF0066E9Au)
+#define MTU_MC94_RDBFL30 (0xF0066E9Cu)
+#define MTU_MC94_RDBFL31 (0xF0066E9Eu)
+#define MTU_MC94_RDBFL32 (0xF0066EA0u)
+#define MTU_MC94_RDBFL33 (0xF0066EA2u)
+#define MTU_MC9_CONFIG1 (0xF0061902u)
+#define MTU_MC0_RDBFL27 (0xF0061096u)
+#define MTU_MC94_RDBFL34 (0xF0066EA4u)
+#define MTU_MC94_RDBFL35 (0xF0066EA6u)
+#define MTU_MC94_RDBFL36 (0xF0066EA8u)
+#define MTU_MC94_RDBFL37 (0xF0066EAAu)
+#define MTU_MC94_RDBFL38 (0xF0066EACu)
+#define MTU_MC94_RDBFL39 (0xF0066EAEu)
+#define MTU_MC94_RDBFL40 (0xF0066EB0u)
+#define MTU_MC94_RDBFL41 (0xF0066EB2u)
+#define MTU_MC94_RDBFL42 (0xF0066EB4u)
+#define MTU_MC94_RDBFL43 (0xF0066EB6u)
+#define MTU_MC9_MCONTROL (0xF0061904u)
+#define MTU_MC94_RDBFL44 (0xF0066EB8u)
+#define MTU_MC94_RDBFL45 (0xF0066EBAu)
+#define MTU_MC94_RDBFL46 (0xF0066EBCu)
+#define MTU_MC94_RDBFL47 (0xF0066EBEu)
+#define MTU_MC94_RDBFL48 (0xF0066EC0u)
+#define MTU_MC94_RDBFL49 (0xF0066EC2u)
+#define MTU_MC94_RDBFL50 (0xF0066EC4u)
+#define MTU_MC94_RDBFL51 (0xF0066EC6u)
+#define MTU_MC94_RDBFL52 (0xF0066EC8u)
+#define MTU_MC94_RDBFL53 (0xF0066ECAu)
+#define MTU_MC9_MSTATUS (0xF0061906u)
+#define MTU_MC94_RDBFL54 (0xF0066ECCu)
+#define MTU_MC94_RDBFL55 (0xF0066ECEu)
+#define MTU_MC94_RDBFL56 (0xF0066ED0u)
+#define MTU_MC94_RDBFL57 (0xF0066ED2u)
+#define MTU_MC94_RDBFL58 (0xF0066ED4u)
+#define MTU_MC94_RDBFL59 (0xF0066ED6u)
+#define MTU_MC94_RDBFL60 (0xF0066ED8u)
+#define MTU_MC94_RDBFL61 (0xF0066EDAu)
+#define MTU_MC94_RDBFL62 (0xF0066EDCu)
+#define MTU_MC94_RDBFL63 (0xF0066EDEu)
+#define MTU_MC9_RANGE (0xF0061908u)
+#define MTU_MC94_RDBFL64 (0xF0066EE0u)
+#define MTU_MC94_RDBFL65 (0xF0066EE2u)
+#define MTU_MC94_RDBFL66 (0xF0066EE4u)
+#define MTU_MC94_ALMSRCS (0xF0066EEEu)
+#define MTU_MC94_FAULTSTS (0xF0066EF0u)
+#define MTU_MC94_ERRINFO0 (0xF0066EF2u)
+#define MTU_MC94_ERRINFO1 (0xF0066EF4u)
+#define MTU_MC94_ERRINFO2 (0xF0066EF6u)
+#define MTU_MC94_ERRINFO3 (0xF0066EF8u)
+#define MTU_MC94_ERRINFO4 (0xF0066EFAu)
+#define MTU_MC9_REVID (0xF006190Cu)
+#define MTU_MC95_CONFIG0 (0xF0066F00u)
+#define MTU_MC95_CONFIG1 (0xF0066F02u)
+#define MTU_MC95_MCONTROL (0xF0066F04u)
+#define MTU_MC95_MSTATUS (0xF0066F06u)
+#define MTU_MC95_RANGE (0xF0066F08u)
+#define MTU_MC95_REVID (0xF0066F0Cu)
+#define MTU_MC95_ECCS (0xF0066F0Eu)
+#define MTU_MC95_ECCD (0xF0066F10u)
+#define MTU_MC95_ETRR0 (0xF0066F12u)
+#define MTU_MC95_ETRR1 (0xF0066F14u)
+#define MTU_MC9_ECCS (0xF006190Eu)
+#define MTU_MC95_ETRR2 (0xF0066F16u)
+#define MTU_MC95_ETRR3 (0xF0066F18u)
+#define MTU_MC95_ETRR4 (0xF0066F1Au)
+#define MTU_MC95_RDBFL0 (0xF0066F60u)
+#define MTU_MC95_RDBFL1 (0xF0066F62u)
+#define MTU_MC95_RDBFL2 (0xF0066F64u)
+#define MTU_MC95_RDBFL3 (0xF0066F66u)
+#define MTU_MC95_RDBFL4 (0xF0066F68u)
+#define MTU_MC95_RDBFL5 (0xF0066F6Au)
+#define MTU_MC95_RDBFL6 (0xF0066F6Cu)
+#define MTU_MC9_ECCD (0xF0061910u)
+#define MTU_MC95_RDBFL7 (0xF0066F6Eu)
+#define MTU_MC95_RDBFL8 (0xF0066F70u)
+#define MTU_MC95_RDBFL9 (0xF0066F72u)
+#define MTU_MC95_RDBFL10 (0xF0066F74u)
+#define MTU_MC95_RDBFL11 (0xF0066F76u)
+#define MTU_MC95_RDBFL12 (0xF0066F78u)
+#define MTU_MC95_RDBFL13 (0xF0066F7Au)
+#define MTU_MC95_RDBFL14 (0xF0066F7Cu)
+#define MTU_MC95_RDBFL15 (0xF0066F7Eu)
+#define MTU_MC95_RDBFL16 (0xF0066F80u)
+#define MTU_MC9_ETRR0 (0xF0061912u)
+#define MTU_MC95_RDBFL17 (0xF0066F82u)
+#define MTU_MC95_RDBFL18 (0xF0066F84u)
+#define MTU_MC95_RDBFL19 (0xF0066F86u)
+#define MTU_MC95_RDBFL20 (0xF0066F88u)
+#define MTU_MC95_RDBFL21 (0xF0066F8Au)
+#define MTU_MC95_RDBFL22 (0xF0066F8Cu)
+#define MTU_MC95_RDBFL23 (0xF0066F8Eu)
+#define MTU_MC95_RDBFL24 (0xF0066F90u)
+#define MTU_MC95_RDBFL25 (0xF0066F92u)
+#define MTU_MC95_RDBFL26 (0xF0066F94u)
+#define MTU_MC9_ETRR1 (0xF0061914u)
+#define MTU_MC95_RDBFL27 (0xF0066F96u)
+#define MTU_MC95_RDBFL28 (0xF0066F98u)
+#define MTU_MC95_RDBFL29 (0xF0066F9Au)
+#define MTU_MC95_RDBFL30 (0xF0066F9Cu)
+#define MTU_MC95_RDBFL31 (0xF0066F9Eu)
+#define MTU_MC95_RDBFL32 (0xF0066FA0u)
+#define MTU_MC95_RDBFL33 (0xF0066FA2u)
+#define MTU_MC95_RDBFL34 (0xF0066FA4u)
+#define MTU_MC95_RDBFL35 (0xF0066FA6u)
+#define MTU_MC95_RDBFL36 (0xF0066FA8u)
+#define MTU_MC9_ETRR2 (0xF0061916u)
+#define MTU_MC95_RDBFL37 (0xF0066FAAu)
+#define MTU_MC95_RDBFL38 (0xF0066FACu)
+#define MTU_MC95_RDBFL39 (0xF0066FAEu)
+#define MTU_MC95_RDBFL40 (0xF0066FB0u)
+#define MTU_MC95_RDBFL41 (0xF0066FB2u)
+#define MTU_MC95_RDBFL42 (0xF0066FB4u)
+#define MTU_MC95_RDBFL43 (0xF0066FB6u)
+#define MTU_MC95_RDBFL44 (0xF0066FB8u)
+#define MTU_MC95_RDBFL45 (0xF0066FBAu)
+#define MTU_MC95_RDBFL46 (0xF0066FBCu)
+#define MTU_MC9_ETRR3 (0xF0061918u)
+#define MTU_MC0_RDBFL28 (0xF0061098u)
+#define MTU_MC95_RDBFL47 (0xF0066FBEu)
+#define MTU_MC95_RDBFL48 (0xF0066FC0u)
+#define MTU_MC95_RDBFL49 (0xF0066FC2u)
+#define MTU_MC95_RDBFL50 (0xF0066FC4u)
+#define MTU_MC95_RDBFL51 (0xF0066FC6u)
+#define MTU_MC95_RDBFL52 (0xF0066FC8u)
+#define MTU_MC95_RDBFL53 (0xF0066FCAu)
+#define MTU_MC95_RDBFL54 (0xF0066FCCu)
+#define MTU_MC95_RDBFL55 (0xF0066FCEu)
+#define MTU_MC95_RDBFL56 (0xF0066FD0u)
+#define MTU_MC9_ETRR4 (0xF006191Au)
+#define MTU_MC95_RDBFL57 (0xF0066FD2u)
+#define MTU_MC95_RDBFL58 (0xF0066FD4u)
+#define MTU_MC95_RDBFL59 (0xF0066FD6u)
+#define MTU_MC95_RDBFL60 (0xF0066FD8u)
+#define MTU_MC95_RDBFL61 (0xF0066FDAu)
+#define MTU_MC95_RDBFL62 (0xF0066FDCu)
+#define MTU_MC95_RDBFL63 (0xF0066FDEu)
+#define MTU_MC95_RDBFL64 (0xF0066FE0u)
+#define MTU_MC95_RDBFL65 (0xF0066FE2u)
+#define MTU_MC95_RDBFL66 (0xF0066FE4u)
+#define MTU_MC9_RDBFL0 (0xF0061960u)
+#define MTU_MC95_ALMSRCS (0xF0066FEEu)
+#define MTU_MC95_FAULTSTS (0xF0066FF0u)
+#define MTU_MC95_ERRINFO0 (0xF0066FF2u)
+#define MTU_MC95_ERRINFO1 (0xF0066FF4u)
+#define MTU_MC95_ERRINFO2 (0xF0066FF6u)
+#define MTU_MC95_ERRINFO3 (0xF0066FF8u)
+#define MTU_MC95_ERRINFO4 (0xF0066FFAu)
+#define MTU_MC9_RDBFL1 (0xF0061962u)
+#define MTU_MC9_RDBFL2 (0xF0061964u)
+#define MTU_MC9_RDBFL3 (0xF0061966u)
+#define MTU_MC9_RDBFL4 (0xF0061968u)
+#define MTU_MC9_RDBFL5 (0xF006196Au)
+#define MTU_MC9_RDBFL6 (0xF006196Cu)
+#define MTU_MC9_RDBFL7 (0xF006196Eu)
+#define MTU_MC9_RDBFL8 (0xF0061970u)
+#define MTU_MC0_RDBFL29 (0xF006109Au)
+#define MTU_MC9_RDBFL9 (0xF0061972u)
+#define MTU_MC9_RDBFL10 (0xF0061974u)
+#define MTU_MC9_RDBFL11 (0xF0061976u)
+#define MTU_MC9_RDBFL12 (0xF0061978u)
+#define MTU_MC9_RDBFL13 (0xF006197Au)
+#define MTU_MC9_RDBFL14 (0xF006197Cu)
+#define MTU_MC9_RDBFL15 (0xF006197Eu)
+#define MTU_MC9_RDBFL16 (0xF0061980u)
+#define MTU_MC9_RDBFL17 (0xF0061982u)
+#define MTU_MC9_RDBFL18 (0xF0061984u)
+#define MTU_MC0_RDBFL30 (0xF006109Cu)
+#define MTU_MC9_RDBFL19 (0xF0061986u)
+#define MTU_MC9_RDBFL20 (0xF0061988u)
+#define MTU_MC9_RDBFL21 (0xF006198Au)
+#define MTU_MC9_RDBFL22 (0xF006198Cu)
+#define MTU_MC9_RDBFL23 (0xF006198Eu)
+#define MTU_MC9_RDBFL24 (0xF0061990u)
+#define MTU_MC9_RDBFL25 (0xF0061992u)
+#define MTU_MC9_RDBFL26 (0xF0061994u)
+#define MTU_MC9_RDBFL27 (0xF0061996u)
+#define MTU_MC9_RDBFL28 (0xF0061998u)
+#define MTU_MC0_RDBFL31 (0xF006109Eu)
+#define MTU_MC9_RDBFL29 (0xF006199Au)
+#define MTU_MC9_RDBFL30 (0xF006199Cu)
+#define MTU_MC9_RDBFL31 (0xF006199Eu)
+#define MTU_MC9_RDBFL32 (0xF00619A0u)
+#define MTU_MC9_RDBFL33 (0xF00619A2u)
+#define MTU_MC9_RDBFL34 (0xF00619A4u)
+#define MTU_MC9_RDBFL35 (0xF00619A6u)
+#define MTU_MC9_RDBFL36 (0xF00619A8u)
+#define MTU_MC9_RDBFL37 (0xF00619AAu)
+#define MTU_MC9_RDBFL38 (0xF00619ACu)
+#define MTU_MC0_RDBFL32 (0xF00610A0u)
+#define MTU_MC9_RDBFL39 (0xF00619AEu)
+#define MTU_MC9_RDBFL40 (0xF00619B0u)
+#define MTU_MC9_RDBFL41 (0xF00619B2u)
+#define MTU_MC9_RDBFL42 (0xF00619B4u)
+#define MTU_MC9_RDBFL43 (0xF00619B6u)
+#define MTU_MC9_RDBFL44 (0xF00619B8u)
+#define MTU_MC9_RDBFL45 (0xF00619BAu)
+#define MTU_MC9_RDBFL46 (0xF00619BCu)
+#define MTU_MC9_RDBFL47 (0xF00619BEu)
+#define MTU_MC9_RDBFL48 (0xF00619C0u)
+#define MTU_MC0_RDBFL33 (0xF00610A2u)
+#define MTU_MC9_RDBFL49 (0xF00619C2u)
+#define MTU_MC9_RDBFL50 (0xF00619C4u)
+#define MTU_MC9_RDBFL51 (0xF00619C6u)
+#define MTU_MC9_RDBFL52 (0xF00619C8u)
+#define MTU_MC9_RDBFL53 (0xF00619CAu)
+#define MTU_MC9_RDBFL54 (0xF00619CCu)
+#define MTU_MC9_RDBFL55 (0xF00619CEu)
+#define MTU_MC9_RDBFL56 (0xF00619D0u)
+#define MTU_MC9_RDBFL57 (0xF00619D2u)
+#define MTU_MC9_RDBFL58 (0xF00619D4u)
+#define MTU_MC0_RDBFL34 (0xF00610A4u)
+#define MTU_MC9_RDBFL59 (0xF00619D6u)
+#define MTU_MC9_RDBFL60 (0xF00619D8u)
+#define MTU_MC9_RDBFL61 (0xF00619DAu)
+#define MTU_MC9_RDBFL62 (0xF00619DCu)
+#define MTU_MC9_RDBFL63 (0xF00619DEu)
+#define MTU_MC9_RDBFL64 (0xF00619E0u)
+#define MTU_MC9_RDBFL65 (0xF00619E2u)
+#define MTU_MC9_RDBFL66 (0xF00619E4u)
+#define MTU_MC9_ALMSRCS (0xF00619EEu)
+#define MTU_MC9_FAULTSTS (0xF00619F0u)
+#define MTU_MC0_RDBFL35 (0xF00610A6u)
+#define MTU_MC9_ERRINFO0 (0xF00619F2u)
+#define MTU_MC9_ERRINFO1 (0xF00619F4u)
+#define MTU_MC9_ERRINFO2 (0xF00619F6u)
+#define MTU_MC9_ERRINFO3 (0xF00619F8u)
+#define MTU_MC9_ERRINFO4 (0xF00619FAu)
+#define MTU_MC10_CONFIG0 (0xF0061A00u)
+#define MTU_MC10_CONFIG1 (0xF0061A02u)
+#define MTU_MC10_MCONTROL (0xF0061A04u)
+#define MTU_MC10_MSTATUS (0xF0061A06u)
+#define MTU_MC10_RANGE (0xF0061A08u)
+#define MTU_MC0_RDBFL36 (0xF00610A8u)
+#define MTU_MC10_REVID (0xF0061A0Cu)
+#define MTU_MC10_ECCS (0xF0061A0Eu)
+#define MTU_MC10_ECCD (0xF0061A10u)
+#define MTU_MC10_ETRR0 (0xF0061A12u)
+#define MTU_MC10_ETRR1 (0xF0061A14u)
+#define MTU_MC10_ETRR2 (0xF0061A16u)
+#define MTU_MC10_ETRR3 (0xF0061A18u)
+#define MTU_MC10_ETRR4 (0xF0061A1Au)
+#define MTU_MC10_RDBFL0 (0xF0061A60u)
+#define MTU_MC10_RDBFL1 (0xF0061A62u)
+#define MTU_MC0_RDBFL37 (0xF00610AAu)
+#define MTU_MC10_RDBFL2 (0xF0061A64u)
+#define MTU_MC10_RDBFL3 (0xF0061A66u)
+#define MTU_MC10_RDBFL4 (0xF0061A68u)
+#define MTU_MC10_RDBFL5 (0xF0061A6Au)
+#define MTU_MC10_RDBFL6 (0xF0061A6Cu)
+#define MTU_MC10_RDBFL7 (0xF0061A6Eu)
+#define MTU_MC10_RDBFL8 (0xF0061A70u)
+#define MTU_MC10_RDBFL9 (0xF0061A72u)
+#define MTU_MC10_RDBFL10 (0xF0061A74u)
+#define MTU_MC10_RDBFL11 (0xF0061A76u)
+#define MTU_MC0_RDBFL38 (0xF00610ACu)
+#define MTU_MC10_RDBFL12 (0xF0061A78u)
+#define MTU_MC10_RDBFL13 (0xF0061A7Au)
+#define MTU_MC10_RDBFL14 (0xF0061A7Cu)
+#define MTU_MC10_RDBFL15 (0xF0061A7Eu)
+#define MTU_MC10_RDBFL16 (0xF0061A80u)
+#define MTU_MC10_RDBFL17 (0xF0061A82u)
+#define MTU_MC10_RDBFL18 (0xF0061A84u)
+#define MTU_MC10_RDBFL19 (0xF0061A86u)
+#define MTU_MC10_RDBFL20 (0xF0061A88u)
+#define MTU_MC10_RDBFL21 (0xF0061A8Au)
+#define MTU_MC0_RDBFL39 (0xF00610AEu)
+#define MTU_MC10_RDBFL22 (0xF0061A8Cu)
+#define MTU_MC10_RDBFL23 (0xF0061A8Eu)
+#define MTU_MC10_RDBFL24 (0xF0061A90u)
+#define MTU_MC10_RDBFL25 (0xF0061A92u)
+#define MTU_MC10_RDBFL26 (0xF0061A94u)
+#define MTU_MC10_RDBFL27 (0xF0061A96u)
+#define MTU_MC10_RDBFL28 (0xF0061A98u)
+#define MTU_MC10_RDBFL29 (0xF0061A9Au)
+#define MTU_MC10_RDBFL30 (0xF0061A9Cu)
+#define MTU_MC10_RDBFL31 (0xF0061A9Eu)
+#define MTU_MC0_RDBFL40 (0xF00610B0u)
+#define MTU_MC10_RDBFL32 (0xF0061AA0u)
+#define MTU_MC10_RDBFL33 (0xF0061AA2u)
+#define MTU_MC10_RDBFL34 (0xF0061AA4u)
+#define MTU_MC10_RDBFL35 (0xF0061AA6u)
+#define MTU_MC10_RDBFL36 (0xF0061AA8u)
+#define MTU_MC10_RDBFL37 (0xF0061AAAu)
+#define MTU_MC10_RDBFL38 (0xF0061AACu)
+#define MTU_MC10_RDBFL39 (0xF0061AAEu)
+#define MTU_MC10_RDBFL40 (0xF0061AB0u)
+#define MTU_MC10_RDBFL41 (0xF0061AB2u)
+#define MTU_MC0_RDBFL41 (0xF00610B2u)
+#define MTU_MC10_RDBFL42 (0xF0061AB4u)
+#define MTU_MC10_RDBFL43 (0xF0061AB6u)
+#define MTU_MC10_RDBFL44 (0xF0061AB8u)
+#define MTU_MC10_RDBFL45 (0xF0061ABAu)
+#define MTU_MC10_RDBFL46 (0xF0061ABCu)
+#define MTU_MC10_RDBFL47 (0xF0061ABEu)
+#define MTU_MC10_RDBFL48 (0xF0061AC0u)
+#define MTU_MC10_RDBFL49 (0xF0061AC2u)
+#define MTU_MC10_RDBFL50 (0xF0061AC4u)
+#define MTU_MC10_RDBFL51 (0xF0061AC6u)
+#define MTU_MC0_RDBFL42 (0xF00610B4u)
+#define MTU_MC10_RDBFL52 (0xF0061AC8u)
+#define MTU_MC10_RDBFL53 (0xF0061ACAu)
+#define MTU_MC10_RDBFL54 (0xF0061ACCu)
+#define MTU_MC10_RDBFL55 (0xF0061ACEu)
+#define MTU_MC10_RDBFL56 (0xF0061AD0u)
+#define MTU_MC10_RDBFL57 (0xF0061AD2u)
+#define MTU_MC10_RDBFL58 (0xF0061AD4u)
+#define MTU_MC10_RDBFL59 (0xF0061AD6u)
+#define MTU_MC10_RDBFL60 (0xF0061AD8u)
+#define MTU_MC10_RDBFL61 (0xF0061ADAu)
+#define MTU_MC0_RDBFL43 (0xF00610B6u)
+#define MTU_MC10_RDBFL62 (0xF0061ADCu)
+#define MTU_MC10_RDBFL63 (0xF0061ADEu)
+#define MTU_MC10_RDBFL64 (0xF0061AE0u)
+#define MTU_MC10_RDBFL65 (0xF0061AE2u)
+#define MTU_MC10_RDBFL66 (0xF0061AE4u)
+#define MTU_MC10_ALMSRCS (0xF0061AEEu)
+#define MTU_MC10_FAULTSTS (0xF0061AF0u)
+#define MTU_MC10_ERRINFO0 (0xF0061AF2u)
+#define MTU_MC10_ERRINFO1 (0xF0061AF4u)
+#define MTU_MC10_ERRINFO2 (0xF0061AF6u)
+#define MTU_MC0_RDBFL44 (0xF00610B8u)
+#define MTU_MC10_ERRINFO3 (0xF0061AF8u)
+#define MTU_MC10_ERRINFO4 (0xF0061AFAu)
+#define MTU_MC11_CONFIG0 (0xF0061B00u)
+#define MTU_MC11_CONFIG1 (0xF0061B02u)
+#define MTU_MC11_MCONTROL (0xF0061B04u)
+#define MTU_MC11_MSTATUS (0xF0061B06u)
+#define MTU_MC11_RANGE (0xF0061B08u)
+#define MTU_MC11_REVID (0xF0061B0Cu)
+#define MTU_MC11_ECCS (0xF0061B0Eu)
+#define MTU_MC11_ECCD (0xF0061B10u)
+#define MTU_MC0_RDBFL45 (0xF00610BAu)
+#define MTU_MC11_ETRR0 (0xF0061B12u)
+#define MTU_MC11_ETRR1 (0xF0061B14u)
+#define MTU_MC11_ETRR2 (0xF0061B16u)
+#define MTU_MC11_ETRR3 (0xF0061B18u)
+#define MTU_MC11_ETRR4 (0xF0061B1Au)
+#define MTU_MC11_RDBFL0 (0xF0061B60u)
+#define MTU_MC11_RDBFL1 (0xF0061B62u)
+#define MTU_MC11_RDBFL2 (0xF0061B64u)
+#define MTU_MC11_RDBFL3 (0xF0061B66u)
+#define MTU_MC11_RDBFL4 (0xF0061B68u)
+#define MTU_MC0_RDBFL46 (0xF00610BCu)
+#define MTU_MC11_RDBFL5 (0xF0061B6Au)
+#define MTU_MC11_RDBFL6 (0xF0061B6Cu)
+#define MTU_MC11_RDBFL7 (0xF0061B6Eu)
+#define MTU_MC11_RDBFL8 (0xF0061B70u)
+#define MTU_MC11_RDBFL9 (0xF0061B72u)
+#define MTU_MC11_RDBFL10 (0xF0061B74u)
+#define MTU_MC11_RDBFL11 (0xF0061B76u)
+#define MTU_MC11_RDBFL12 (0xF0061B78u)
+#define MTU_MC11_RDBFL13 (0xF0061B7Au)
+#define MTU_MC11_RDBFL14 (0xF0061B7Cu)
+#define MTU_MC0_RDBFL47 (0xF00610BEu)
+#define MTU_MC11_RDBFL15 (0xF0061B7Eu)
+#define MTU_MC11_RDBFL16 (0xF0061B80u)
+#define MTU_MC11_RDBFL17 (0xF0061B82u)
+#define MTU_MC11_RDBFL18 (0xF0061B84u)
+#define MTU_MC11_RDBFL19 (0xF0061B86u)
+#define MTU_MC11_RDBFL20 (0xF0061B88u)
+#define MTU_MC11_RDBFL21 (0xF0061B8Au)
+#define MTU_MC11_RDBFL22 (0xF0061B8Cu)
+#define MTU_MC11_RDBFL23 (0xF0061B8Eu)
+#define MTU_MC11_RDBFL24 (0xF0061B90u)
+#define MTU_MC0_RDBFL48 (0xF00610C0u)
+#define MTU_MC11_RDBFL25 (0xF0061B92u)
+#define MTU_MC11_RDBFL26 (0xF0061B94u)
+#define MTU_MC11_RDBFL27 (0xF0061B96u)
+#define MTU_MC11_RDBFL28 (0xF0061B98u)
+#define MTU_MC11_RDBFL29 (0xF0061B9Au)
+#define MTU_MC11_RDBFL30 (0xF0061B9Cu)
+#define MTU_MC11_RDBFL31 (0xF0061B9Eu)
+#define MTU_MC11_RDBFL32 (0xF0061BA0u)
+#define MTU_MC11_RDBFL33 (0xF0061BA2u)
+#define MTU_MC11_RDBFL34 (0xF0061BA4u)
+#define MTU_MC0_RDBFL49 (0xF00610C2u)
+#define MTU_MC11_RDBFL35 (0xF0061BA6u)
+#define MTU_MC11_RDBFL36 (0xF0061BA8u)
+#define MTU_MC11_RDBFL37 (0xF0061BAAu)
+#define MTU_MC11_RDBFL38 (0xF0061BACu)
+#define MTU_MC11_RDBFL39 (0xF0061BAEu)
+#define MTU_MC11_RDBFL40 (0xF0061BB0u)
+#define MTU_MC11_RDBFL41 (0xF0061BB2u)
+#define MTU_MC11_RDBFL42 (0xF0061BB4u)
+#define MTU_MC11_RDBFL43 (0xF0061BB6u)
+#define MTU_MC11_RDBFL44 (0xF0061BB8u)
+#define MTU_MC0_RDBFL50 (0xF00610C4u)
+#define MTU_MC11_RDBFL45 (0xF0061BBAu)
+#define MTU_MC11_RDBFL46 (0xF0061BBCu)
+#define MTU_MC11_RDBFL47 (0xF0061BBEu)
+#define MTU_MC11_RDBFL48 (0xF0061BC0u)
+#define MTU_MC11_RDBFL49 (0xF0061BC2u)
+#define MTU_MC11_RDBFL50 (0xF0061BC4u)
+#define MTU_MC11_RDBFL51 (0xF0061BC6u)
+#define MTU_MC11_RDBFL52 (0xF0061BC8u)
+#define MTU_MC11_RDBFL53 (0xF0061BCAu)
+#define MTU_MC11_RDBFL54 (0xF0061BCCu)
+#define MTU_MC0_RDBFL51 (0xF00610C6u)
+#define MTU_MC11_RDBFL55 (0xF0061BCEu)
+#define MTU_MC11_RDBFL56 (0xF0061BD0u)
+#define MTU_MC11_RDBFL57 (0xF0061BD2u)
+#define MTU_MC11_RDBFL58 (0xF0061BD4u)
+#define MTU_MC11_RDBFL59 (0xF0061BD6u)
+#define MTU_MC11_RDBFL60 (0xF0061BD8u)
+#define MTU_MC11_RDBFL61 (0xF0061BDAu)
+#define MTU_MC11_RDBFL62 (0xF0061BDCu)
+#define MTU_MC11_RDBFL63 (0xF0061BDEu)
+#define MTU_MC11_RDBFL64 (0xF0061BE0u)
+#define MTU_MC0_RDBFL52 (0xF00610C8u)
+#define MTU_MC11_RDBFL65 (0xF0061BE2u)
+#define MTU_MC11_RDBFL66 (0xF0061BE4u)
+#define MTU_MC11_ALMSRCS (0xF0061BEEu)
+#define MTU_MC11_FAULTSTS (0xF0061BF0u)
+#define MTU_MC11_ERRINFO0 (0xF0061BF2u)
+#define MTU_MC11_ERRINFO1 (0xF0061BF4u)
+#define MTU_MC11_ERRINFO2 (0xF0061BF6u)
+#define MTU_MC11_ERRINFO3 (0xF0061BF8u)
+#define MTU_MC11_ERRINFO4 (0xF0061BFAu)
+#define MTU_MC12_CONFIG0 (0xF0061C00u)
+#define MTU_MC0_RDBFL53 (0xF00610CAu)
+#define MTU_MC12_CONFIG1 (0xF0061C02u)
+#define MTU_MC12_MCONTROL (0xF0061C04u)
+#define MTU_MC12_MSTATUS (0xF0061C06u)
+#define MTU_MC12_RANGE (0xF0061C08u)
+#define MTU_MC12_REVID (0xF0061C0Cu)
+#define MTU_MC12_ECCS (0xF0061C0Eu)
+#define MTU_MC12_ECCD (0xF0061C10u)
+#define MTU_MC12_ETRR0 (0xF0061C12u)
+#define MTU_MC12_ETRR1 (0xF0061C14u)
+#define MTU_MC12_ETRR2 (0xF0061C16u)
+#define MTU_MC0_RDBFL54 (0xF00610CCu)
+#define MTU_MC12_ETRR3 (0xF0061C18u)
+#define MTU_MC12_ETRR4 (0xF0061C1Au)
+#define MTU_MC12_RDBFL0 (0xF0061C60u)
+#define MTU_MC12_RDBFL1 (0xF0061C62u)
+#define MTU_MC12_RDBFL2 (0xF0061C64u)
+#define MTU_MC12_RDBFL3 (0xF0061C66u)
+#define MTU_MC12_RDBFL4 (0xF0061C68u)
+#define MTU_MC12_RDBFL5 (0xF0061C6Au)
+#define MTU_MC12_RDBFL6 (0xF0061C6Cu)
+#define MTU_MC12_RDBFL7 (0xF0061C6Eu)
+#define MTU_MC0_RDBFL55 (0xF00610CEu)
+#define MTU_MC12_RDBFL8 (0xF0061C70u)
+#define MTU_MC12_RDBFL9 (0xF0061C72u)
+#define MTU_MC12_RDBFL10 (0xF0061C74u)
+#define MTU_MC12_RDBFL11 (0xF0061C76u)
+#define MTU_MC12_RDBFL12 (0xF0061C78u)
+#define MTU_MC12_RDBFL13 (0xF0061C7Au)
+#define MTU_MC12_RDBFL14 (0xF0061C7Cu)
+#define MTU_MC12_RDBFL15 (0xF0061C7Eu)
+#define MTU_MC12_RDBFL16 (0xF0061C80u)
+#define MTU_MC12_RDBFL17 (0xF0061C82u)
+#define MTU_MC0_RDBFL56 (0xF00610D0u)
+#define MTU_MC12_RDBFL18 (0xF0061C84u)
+#define MTU_MC12_RDBFL19 (0xF0061C86u)
+#define MTU_MC12_RDBFL20 (0xF0061C88u)
+#define MTU_MC12_RDBFL21 (0xF0061C8Au)
+#define MTU_MC12_RDBFL22 (0xF0061C8Cu)
+#define MTU_MC12_RDBFL23 (0xF0061C8Eu)
+#define MTU_MC12_RDBFL24 (0xF0061C90u)
+#define MTU_MC12_RDBFL25 (0xF0061C92u)
+#define MTU_MC12_RDBFL26 (0xF0061C94u)
+#define MTU_MC12_RDBFL27 (0xF0061C96u)
+#define MTU_MC0_RDBFL57 (0xF00610D2u)
+#define MTU_MC12_RDBFL28 (0xF0061C98u)
+#define MTU_MC12_RDBFL29 (0xF0061C9Au)
+#define MTU_MC12_RDBFL30 (0xF0061C9Cu)
+#define MTU_MC12_RDBFL31 (0xF0061C9Eu)
+#define MTU_MC12_RDBFL32 (0xF0061CA0u)
+#define MTU_MC12_RDBFL33 (0xF0061CA2u)
+#define MTU_MC12_RDBFL34 (0xF0061CA4u)
+#define MTU_MC12_RDBFL35 (0xF0061CA6u)
+#define MTU_MC12_RDBFL36 (0xF0061CA8u)
+#define MTU_MC12_RDBFL37 (0xF0061CAAu)
+#define MTU_MC0_RDBFL58 (0xF00610D4u)
+#define MTU_MC12_RDBFL38 (0xF0061CACu)
+#define MTU_MC12_RDBFL39 (0xF0061CAEu)
+#define MTU_MC12_RDBFL40 (0xF0061CB0u)
+#define MTU_MC12_RDBFL41 (0xF0061CB2u)
+#define MTU_MC12_RDBFL42 (0xF0061CB4u)
+#define MTU_MC12_RDBFL43 (0xF0061CB6u)
+#define MTU_MC12_RDBFL44 (0xF0061CB8u)
+#define MTU_MC12_RDBFL45 (0xF0061CBAu)
+#define MTU_MC12_RDBFL46 (0xF0061CBCu)
+#define MTU_MC12_RDBFL47 (0xF0061CBEu)
+#define MTU_MC0_RDBFL59 (0xF00610D6u)
+#define MTU_MC12_RDBFL48 (0xF0061CC0u)
+#define MTU_MC12_RDBFL49 (0xF0061CC2u)
+#define MTU_MC12_RDBFL50 (0xF0061CC4u)
+#define MTU_MC12_RDBFL51 (0xF0061CC6u)
+#define MTU_MC12_RDBFL52 (0xF0061CC8u)
+#define MTU_MC12_RDBFL53 (0xF0061CCAu)
+#define MTU_MC12_RDBFL54 (0xF0061CCCu)
+#define MTU_MC12_RDBFL55 (0xF0061CCEu)
+#define MTU_MC12_RDBFL56 (0xF0061CD0u)
+#define MTU_MC12_RDBFL57 (0xF0061CD2u)
+#define MTU_MC0_RDBFL60 (0xF00610D8u)
+#define MTU_MC12_RDBFL58 (0xF0061CD4u)
+#define MTU_MC12_RDBFL59 (0xF0061CD6u)
+#define MTU_MC12_RDBFL60 (0xF0061CD8u)
+#define MTU_MC12_RDBFL61 (0xF0061CDAu)
+#define MTU_MC12_RDBFL62 (0xF0061CDCu)
+#define MTU_MC12_RDBFL63 (0xF0061CDEu)
+#define MTU_MC12_RDBFL64 (0xF0061CE0u)
+#define MTU_MC12_RDBFL65 (0xF0061CE2u)
+#define MTU_MC12_RDBFL66 (0xF0061CE4u)
+#define MTU_MC12_ALMSRCS (0xF0061CEEu)
+#define MTU_MC0_RDBFL61 (0xF00610DAu)
+#define MTU_MC12_FAULTSTS (0xF0061CF0u)
+#define MTU_MC12_ERRINFO0 (0xF0061CF2u)
+#define MTU_MC12_ERRINFO1 (0xF0061CF4u)
+#define MTU_MC12_ERRINFO2 (0xF0061CF6u)
+#define MTU_MC12_ERRINFO3 (0xF0061CF8u)
+#define MTU_MC12_ERRINFO4 (0xF0061CFAu)
+#define MTU_MC13_CONFIG0 (0xF0061D00u)
+#define MTU_MC13_CONFIG1 (0xF0061D02u)
+#define MTU_MC13_MCONTROL (0xF0061D04u)
+#define MTU_MC13_MSTATUS (0xF0061D06u)
+#define MTU_MC0_RDBFL62 (0xF00610DCu)
+#define MTU_MC13_RANGE (0xF0061D08u)
+#define MTU_MC13_REVID (0xF0061D0Cu)
+#define MTU_MC13_ECCS (0xF0061D0Eu)
+#define MTU_MC13_ECCD (0xF0061D10u)
+#define MTU_MC13_ETRR0 (0xF0061D12u)
+#define MTU_MC13_ETRR1 (0xF0061D14u)
+#define MTU_MC13_ETRR2 (0xF0061D16u)
+#define MTU_MC13_ETRR3 (0xF0061D18u)
+#define MTU_MC13_ETRR4 (0xF0061D1Au)
+#define MTU_MC13_RDBFL0 (0xF0061D60u)
+#define MTU_MC0_RDBFL63 (0xF00610DEu)
+#define MTU_MC13_RDBFL1 (0xF0061D62u)
+#define MTU_MC13_RDBFL2 (0xF0061D64u)
+#define MTU_MC13_RDBFL3 (0xF0061D66u)
+#define MTU_MC13_RDBFL4 (0xF0061D68u)
+#define MTU_MC13_RDBFL5 (0xF0061D6Au)
+#define MTU_MC13_RDBFL6 (0xF0061D6Cu)
+#define MTU_MC13_RDBFL7 (0xF0061D6Eu)
+#define MTU_MC13_RDBFL8 (0xF0061D70u)
+#define MTU_MC13_RDBFL9 (0xF0061D72u)
+#define MTU_MC13_RDBFL10 (0xF0061D74u)
+#define MTU_MC0_RDBFL64 (0xF00610E0u)
+#define MTU_MC13_RDBFL11 (0xF0061D76u)
+#define MTU_MC13_RDBFL12 (0xF0061D78u)
+#define MTU_MC13_RDBFL13 (0xF0061D7Au)
+#define MTU_MC13_RDBFL14 (0xF0061D7Cu)
+#define MTU_MC13_RDBFL15 (0xF0061D7Eu)
+#define MTU_MC13_RDBFL16 (0xF0061D80u)
+#define MTU_MC13_RDBFL17 (0xF0061D82u)
+#define MTU_MC13_RDBFL18 (0xF0061D84u)
+#define MTU_MC13_RDBFL19 (0xF0061D86u)
+#define MTU_MC13_RDBFL20 (0xF0061D88u)
+#define MTU_MC0_RDBFL65 (0xF00610E2u)
+#define MTU_MC13_RDBFL21 (0xF0061D8Au)
+#define MTU_MC13_RDBFL22 (0xF0061D8Cu)
+#define MTU_MC13_RDBFL23 (0xF0061D8Eu)
+#define MTU_MC13_RDBFL24 (0xF0061D90u)
+#define MTU_MC13_RDBFL25 (0xF0061D92u)
+#define MTU_MC13_RDBFL26 (0xF0061D94u)
+#define MTU_MC13_RDBFL27 (0xF0061D96u)
+#define MTU_MC13_RDBFL28 (0xF0061D98u)
+#define MTU_MC13_RDBFL29 (0xF0061D9Au)
+#define MTU_MC13_RDBFL30 (0xF0061D9Cu)
+#define MTU_MC0_RDBFL66 (0xF00610E4u)
+#define MTU_MC13_RDBFL31 (0xF0061D9Eu)
+#define MTU_MC13_RDBFL32 (0xF0061DA0u)
+#define MTU_MC13_RDBFL33 (0xF0061DA2u)
+#define MTU_MC13_RDBFL34 (0xF0061DA4u)
+#define MTU_MC13_RDBFL35 (0xF0061DA6u)
+#define MTU_MC13_RDBFL36 (0xF0061DA8u)
+#define MTU_MC13_RDBFL37 (0xF0061DAAu)
+#define MTU_MC13_RDBFL38 (0xF0061DACu)
+#define MTU_MC13_RDBFL39 (0xF0061DAEu)
+#define MTU_MC13_RDBFL40 (0xF0061DB0u)
+#define MTU_MC0_ALMSRCS (0xF00610EEu)
+#define MTU_MC13_RDBFL41 (0xF0061DB2u)
+#define MTU_MC13_RDBFL42 (0xF0061DB4u)
+#define MTU_MC13_RDBFL43 (0xF0061DB6u)
+#define MTU_MC13_RDBFL44 (0xF0061DB8u)
+#define MTU_MC13_RDBFL45 (0xF0061DBAu)
+#define MTU_MC13_RDBFL46 (0xF0061DBCu)
+#define MTU_MC13_RDBFL47 (0xF0061DBEu)
+#define MTU_MC13_RDBFL48 (0xF0061DC0u)
+#define MTU_MC13_RDBFL49 (0xF0061DC2u)
+#define MTU_MC13_RDBFL50 (0xF0061DC4u)
+#define MTU_MC0_FAULTSTS (0xF00610F0u)
+#define MTU_MC13_RDBFL51 (0xF0061DC6u)
+#define MTU_MC13_RDBFL52 (0xF0061DC8u)
+#define MTU_MC13_RDBFL53 (0xF0061DCAu)
+#define MTU_MC13_RDBFL54 (0xF0061DCCu)
+#define MTU_MC13_RDBFL55 (0xF0061DCEu)
+#define MTU_MC13_RDBFL56 (0xF0061DD0u)
+#define MTU_MC13_RDBFL57 (0xF0061DD2u)
+#define MTU_MC13_RDBFL58 (0xF0061DD4u)
+#define MTU_MC13_RDBFL59 (0xF0061DD6u)
+#define MTU_MC13_RDBFL60 (0xF0061DD8u)
+#define MTU_MC0_ERRINFO0 (0xF00610F2u)
+#define MTU_MC13_RDBFL61 (0xF0061DDAu)
+#define MTU_MC13_RDBFL62 (0xF0061DDCu)
+#define MTU_MC13_RDBFL63 (0xF0061DDEu)
+#define MTU_MC13_RDBFL64 (0xF0061DE0u)
+#define MTU_MC13_RDBFL65 (0xF0061DE2u)
+#define MTU_MC13_RDBFL66 (0xF0061DE4u)
+#define MTU_MC13_ALMSRCS (0xF0061DEEu)
+#define MTU_MC13_FAULTSTS (0xF0061DF0u)
+#define MTU_MC13_ERRINFO0 (0xF0061DF2u)
+#define MTU_MC13_ERRINFO1 (0xF0061DF4u)
+#define MTU_MC0_ERRINFO1 (0xF00610F4u)
+#define MTU_MC13_ERRINFO2 (0xF0061DF6u)
+#define MTU_MC13_ERRINFO3 (0xF0061DF8u)
+#define MTU_MC13_ERRINFO4 (0xF0061DFAu)
+#define MTU_MC14_CONFIG0 (0xF0061E00u)
+#define MTU_MC14_CONFIG1 (0xF0061E02u)
+#define MTU_MC14_MCONTROL (0xF0061E04u)
+#define MTU_MC14_MSTATUS (0xF0061E06u)
+#define MTU_MC14_RANGE (0xF0061E08u)
+#define MTU_MC14_REVID (0xF0061E0Cu)
+#define MTU_MC14_ECCS (0xF0061E0Eu)
+#define MTU_MC0_ERRINFO2 (0xF00610F6u)
+#define MTU_MC14_ECCD (0xF0061E10u)
+#define MTU_MC14_ETRR0 (0xF0061E12u)
+#define MTU_MC14_ETRR1 (0xF0061E14u)
+#define MTU_MC14_ETRR2 (0xF0061E16u)
+#define MTU_MC14_ETRR3 (0xF0061E18u)
+#define MTU_MC14_ETRR4 (0xF0061E1Au)
+#define MTU_MC14_RDBFL0 (0xF0061E60u)
+#define MTU_MC14_RDBFL1 (0xF0061E62u)
+#define MTU_MC14_RDBFL2 (0xF0061E64u)
+#define MTU_MC14_RDBFL3 (0xF0061E66u)
+#define MTU_MC0_ERRINFO3 (0xF00610F8u)
+#define MTU_MC14_RDBFL4 (0xF0061E68u)
+#define MTU_MC14_RDBFL5 (0xF0061E6Au)
+#define MTU_MC14_RDBFL6 (0xF0061E6Cu)
+#define MTU_MC14_RDBFL7 (0xF0061E6Eu)
+#define MTU_MC14_RDBFL8 (0xF0061E70u)
+#define MTU_MC14_RDBFL9 (0xF0061E72u)
+#define MTU_MC14_RDBFL10 (0xF0061E74u)
+#define MTU_MC14_RDBFL11 (0xF0061E76u)
+#define MTU_MC14_RDBFL12 (0xF0061E78u)
+#define MTU_MC14_RDBFL13 (0xF0061E7Au)
+#define MTU_MC0_ERRINFO4 (0xF00610FAu)
+#define MTU_MC14_RDBFL14 (0xF0061E7Cu)
+#define MTU_MC14_RDBFL15 (0xF0061E7Eu)
+#define MTU_MC14_RDBFL16 (0xF0061E80u)
+#define MTU_MC14_RDBFL17 (0xF0061E82u)
+#define MTU_MC14_RDBFL18 (0xF0061E84u)
+#define MTU_MC14_RDBFL19 (0xF0061E86u)
+#define MTU_MC14_RDBFL20 (0xF0061E88u)
+#define MTU_MC14_RDBFL21 (0xF0061E8Au)
+#define MTU_MC14_RDBFL22 (0xF0061E8Cu)
+#define MTU_MC14_RDBFL23 (0xF0061E8Eu)
+#define MTU_MC1_CONFIG0 (0xF0061100u)
+#define MTU_MC14_RDBFL24 (0xF0061E90u)
+#define MTU_MC14_RDBFL25 (0xF0061E92u)
+#define MTU_MC14_RDBFL26 (0xF0061E94u)
+#define MTU_MC14_RDBFL27 (0xF0061E96u)
+#define MTU_MC14_RDBFL28 (0xF0061E98u)
+#define MTU_MC14_RDBFL29 (0xF0061E9Au)
+#define MTU_MC14_RDBFL30 (0xF0061E9Cu)
+#define MTU_MC14_RDBFL31 (0xF0061E9Eu)
+#define MTU_MC14_RDBFL32 (0xF0061EA0u)
+#define MTU_MC14_RDBFL33 (0xF0061EA2u)
+#define MTU_MC1_CONFIG1 (0xF0061102u)
+#define MTU_MC14_RDBFL34 (0xF0061EA4u)
+#define MTU_MC14_RDBFL35 (0xF0061EA6u)
+#define MTU_MC14_RDBFL36 (0xF0061EA8u)
+#define MTU_MC14_RDBFL37 (0xF0061EAAu)
+#define MTU_MC14_RDBFL38 (0xF0061EACu)
+#define MTU_MC14_RDBFL39 (0xF0061EAEu)
+#define MTU_MC14_RDBFL40 (0xF0061EB0u)
+#define MTU_MC14_RDBFL41 (0xF0061EB2u)
+#define MTU_MC14_RDBFL42 (0xF0061EB4u)
+#define MTU_MC14_RDBFL43 (0xF0061EB6u)
+#define MTU_MC1_MCONTROL (0xF0061104u)
+#define MTU_MC14_RDBFL44 (0xF0061EB8u)
+#define MTU_MC14_RDBFL45 (0xF0061EBAu)
+#define MTU_MC14_RDBFL46 (0xF0061EBCu)
+#define MTU_MC14_RDBFL47 (0xF0061EBEu)
+#define MTU_MC14_RDBFL48 (0xF0061EC0u)
+#define MTU_MC14_RDBFL49 (0xF0061EC2u)
+#define MTU_MC14_RDBFL50 (0xF0061EC4u)
+#define MTU_MC14_RDBFL51 (0xF0061EC6u)
+#define MTU_MC14_RDBFL52 (0xF0061EC8u)
+#define MTU_MC14_RDBFL53 (0xF0061ECAu)
+#define MTU_MC1_MSTATUS (0xF0061106u)
+#define MTU_MC14_RDBFL54 (0xF0061ECCu)
+#define MTU_MC14_RDBFL55 (0xF0061ECEu)
+#define MTU_MC14_RDBFL56 (0xF0061ED0u)
+#define MTU_MC14_RDBFL57 (0xF0061ED2u)
+#define MTU_MC14_RDBFL58 (0xF0061ED4u)
+#define MTU_MC14_RDBFL59 (0xF0061ED6u)
+#define MTU_MC14_RDBFL60 (0xF0061ED8u)
+#define MTU_MC14_RDBFL61 (0xF0061EDAu)
+#define MTU_MC14_RDBFL62 (0xF0061EDCu)
+#define MTU_MC14_RDBFL63 (0xF0061EDEu)
+#define MTU_MC1_RANGE (0xF0061108u)
+#define MTU_MC14_RDBFL64 (0xF0061EE0u)
+#define MTU_MC14_RDBFL65 (0xF0061EE2u)
+#define MTU_MC14_RDBFL66 (0xF0061EE4u)
+#define MTU_MC14_ALMSRCS (0xF0061EEEu)
+#define MTU_MC14_FAULTSTS (0xF0061EF0u)
+#define MTU_MC14_ERRINFO0 (0xF0061EF2u)
+#define MTU_MC14_ERRINFO1 (0xF0061EF4u)
+#define MTU_MC14_ERRINFO2 (0xF0061EF6u)
+#define MTU_MC14_ERRINFO3 (0xF0061EF8u)
+#define MTU_MC14_ERRINFO4 (0xF0061EFAu)
+#define MTU_MC1_REVID (0xF006110Cu)
+#define MTU_MC15_CONFIG0 (0xF0061F00u)
+#define MTU_MC15_CONFIG1 (0xF0061F02u)
+#define MTU_MC15_MCONTROL (0xF0061F04u)
+#define MTU_MC15_MSTATUS (0xF0061F06u)
+#define MTU_MC15_RANGE (0xF0061F08u)
+#define MTU_MC15_REVID (0xF0061F0Cu)
+#define MTU_MC15_ECCS (0xF0061F0Eu)
+#define MTU_MC15_ECCD (0xF0061F10u)
+#define MTU_MC15_ETRR0 (0xF0061F12u)
+#define MTU_MC15_ETRR1 (0xF0061F14u)
+#define MTU_MC1_ECCS (0xF006110Eu)
+#define MTU_MC15_ETRR2 (0xF0061F16u)
+#define MTU_MC15_ETRR3 (0xF0061F18u)
+#define MTU_MC15_ETRR4 (0xF0061F1Au)
+#define MTU_MC15_RDBFL0 (0xF0061F60u)
+#define MTU_MC15_RDBFL1 (0xF0061F62u)
+#define MTU_MC15_RDBFL2 (0xF0061F64u)
+#define MTU_MC15_RDBFL3 (0xF0061F66u)
+#define MTU_MC15_RDBFL4 (0xF0061F68u)
+#define MTU_MC15_RDBFL5 (0xF0061F6Au)
+#define MTU_MC15_RDBFL6 (0xF0061F6Cu)
+#define MTU_MC1_ECCD (0xF0061110u)
+#define MTU_MC15_RDBFL7 (0xF0061F6Eu)
+#define MTU_MC15_RDBFL8 (0xF0061F70u)
+#define MTU_MC15_RDBFL9 (0xF0061F72u)
+#define MTU_MC15_RDBFL10 (0xF0061F74u)
+#define MTU_MC15_RDBFL11 (0xF0061F76u)
+#define MTU_MC15_RDBFL12 (0xF0061F78u)
+#define MTU_MC15_RDBFL13 (0xF0061F7Au)
+#define MTU_MC15_RDBFL14 (0xF0061F7Cu)
+#define MTU_MC15_RDBFL15 (0xF0061F7Eu)
+#define MTU_MC15_RDBFL16 (0xF0061F80u)
+#define MTU_MC1_ETRR0 (0xF0061112u)
+#define MTU_MC15_RDBFL17 (0xF0061F82u)
+#define MTU_MC15_RDBFL18 (0xF0061F84u)
+#define MTU_MC15_RDBFL19 (0xF0061F86u)
+#define MTU_MC15_RDBFL20 (0xF0061F88u)
+#define MTU_MC15_RDBFL21 (0xF0061F8Au)
+#define MTU_MC15_RDBFL22 (0xF0061F8Cu)
+#define MTU_MC15_RDBFL23 (0xF0061F8Eu)
+#define MTU_MC15_RDBFL24 (0xF0061F90u)
+#define MTU_MC15_RDBFL25 (0xF0061F92u)
+#define MTU_MC15_RDBFL26 (0xF0061F94u)
+#define MTU_MC1_ETRR1 (0xF0061114u)
+#define MTU_MC15_RDBFL27 (0xF0061F96u)
+#define MTU_MC15_RDBFL28 (0xF0061F98u)
+#define MTU_MC15_RDBFL29 (0xF0061F9Au)
+#define MTU_MC15_RDBFL30 (0xF0061F9Cu)
+#define MTU_MC15_RDBFL31 (0xF0061F9Eu)
+#define MTU_MC15_RDBFL32 (0xF0061FA0u)
+#define MTU_MC15_RDBFL33 (0xF0061FA2u)
+#define MTU_MC15_RDBFL34 (0xF0061FA4u)
+#define MTU_MC15_RDBFL35 (0xF0061FA6u)
+#define MTU_MC15_RDBFL36 (0xF0061FA8u)
+#define MTU_MC1_ETRR2 (0xF0061116u)
+#define MTU_MC15_RDBFL37 (0xF0061FAAu)
+#define MTU_MC15_RDBFL38 (0xF0061FACu)
+#define MTU_MC15_RDBFL39 (0xF0061FAEu)
+#define MTU_MC15_RDBFL40 (0xF0061FB0u)
+#define MTU_MC15_RDBFL41 (0xF0061FB2u)
+#define MTU_MC15_RDBFL42 (0xF0061FB4u)
+#define MTU_MC15_RDBFL43 (0xF0061FB6u)
+#define MTU_MC15_RDBFL44 (0xF0061FB8u)
+#define MTU_MC15_RDBFL45 (0xF0061FBAu)
+#define MTU_MC15_RDBFL46 (0xF0061FBCu)
+#define MTU_MC1_ETRR3 (0xF0061118u)
+#define MTU_MC15_RDBFL47 (0xF0061FBEu)
+#define MTU_MC15_RDBFL48 (0xF0061FC0u)
+#define MTU_MC15_RDBFL49 (0xF0061FC2u)
+#define MTU_MC15_RDBFL50 (0xF0061FC4u)
+#define MTU_MC15_RDBFL51 (0xF0061FC6u)
+#define MTU_MC15_RDBFL52 (0xF0061FC8u)
+#define MTU_MC15_RDBFL53 (0xF0061FCAu)
+#define MTU_MC15_RDBFL54 (0xF0061FCCu)
+#define MTU_MC15_RDBFL55 (0xF0061FCEu)
+#define MTU_MC15_RDBFL56 (0xF0061FD0u)
+#define MTU_MC1_ETRR4 (0xF006111Au)
+#define MTU_MC15_RDBFL57 (0xF0061FD2u)
+#define MTU_MC15_RDBFL58 (0xF0061FD4u)
+#define MTU_MC15_RDBFL59 (0xF0061FD6u)
+#define MTU_MC15_RDBFL60 (0xF0061FD8u)
+#define MTU_MC15_RDBFL61 (0xF0061FDAu)
+#define MTU_MC15_RDBFL62 (0xF0061FDCu)
+#define MTU_MC15_RDBFL63 (0xF0061FDEu)
+#define MTU_MC15_RDBFL64 (0xF0061FE0u)
+#define MTU_MC15_RDBFL65 (0xF0061FE2u)
+#define MTU_MC15_RDBFL66 (0xF0061FE4u)
+#define MTU_MC1_RDBFL0 (0xF0061160u)
+#define MTU_MC15_ALMSRCS (0xF0061FEEu)
+#define MTU_MC15_FAULTSTS (0xF0061FF0u)
+#define MTU_MC15_ERRINFO0 (0xF0061FF2u)
+#define MTU_MC15_ERRINFO1 (0xF0061FF4u)
+#define MTU_MC15_ERRINFO2 (0xF0061FF6u)
+#define MTU_MC15_ERRINFO3 (0xF0061FF8u)
+#define MTU_MC15_ERRINFO4 (0xF0061FFAu)
+#define MTU_MC16_CONFIG0 (0xF0062000u)
+#define MTU_MC16_CONFIG1 (0xF0062002u)
+#define MTU_MC16_MCONTROL (0xF0062004u)
+#define MTU_MC1_RDBFL1 (0xF0061162u)
+#define MTU_MC16_MSTATUS (0xF0062006u)
+#define MTU_MC16_RANGE (0xF0062008u)
+#define MTU_MC16_REVID (0xF006200Cu)
+#define MTU_MC16_ECCS (0xF006200Eu)
+#define MTU_MC16_ECCD (0xF0062010u)
+#define MTU_MC16_ETRR0 (0xF0062012u)
+#define MTU_MC16_ETRR1 (0xF0062014u)
+#define MTU_MC16_ETRR2 (0xF0062016u)
+#define MTU_MC16_ETRR3 (0xF0062018u)
+#define MTU_MC16_ETRR4 (0xF006201Au)
+#define MTU_MC1_RDBFL2 (0xF0061164u)
+#define MTU_MC16_RDBFL0 (0xF0062060u)
+#define MTU_MC16_RDBFL1 (0xF0062062u)
+#define MTU_MC16_RDBFL2 (0xF0062064u)
+#define MTU_MC16_RDBFL3 (0xF0062066u)
+#define MTU_MC16_RDBFL4 (0xF0062068u)
+#define MTU_MC16_RDBFL5 (0xF006206Au)
+#define MTU_MC16_RDBFL6 (0xF006206Cu)
+#define MTU_MC16_RDBFL7 (0xF006206Eu)
+#define MTU_MC16_RDBFL8 (0xF0062070u)
+#define MTU_MC16_RDBFL9 (0xF0062072u)
+#define MTU_MC1_RDBFL3 (0xF0061166u)
+#define MTU_MC16_RDBFL10 (0xF0062074u)
+#define MTU_MC16_RDBFL11 (0xF0062076u)
+#define MTU_MC16_RDBFL12 (0xF0062078u)
+#define MTU_MC16_RDBFL13 (0xF006207Au)
+#define MTU_MC16_RDBFL14 (0xF006207Cu)
+#define MTU_MC16_RDBFL15 (0xF006207Eu)
+#define MTU_MC16_RDBFL16 (0xF0062080u)
+#define MTU_MC16_RDBFL17 (0xF0062082u)
+#define MTU_MC16_RDBFL18 (0xF0062084u)
+#define MTU_MC16_RDBFL19 (0xF0062086u)
+#define MTU_MC1_RDBFL4 (0xF0061168u)
+#define MTU_MC16_RDBFL20 (0xF0062088u)
+#define MTU_MC16_RDBFL21 (0xF006208Au)
+#define MTU_MC16_RDBFL22 (0xF006208Cu)
+#define MTU_MC16_RDBFL23 (0xF006208Eu)
+#define MTU_MC16_RDBFL24 (0xF0062090u)
+#define MTU_MC16_RDBFL25 (0xF0062092u)
+#define MTU_MC16_RDBFL26 (0xF0062094u)
+#define MTU_MC16_RDBFL27 (0xF0062096u)
+#define MTU_MC16_RDBFL28 (0xF0062098u)
+#define MTU_MC16_RDBFL29 (0xF006209Au)
+#define MTU_MC1_RDBFL5 (0xF006116Au)
+#define MTU_MC16_RDBFL30 (0xF006209Cu)
+#define MTU_MC16_RDBFL31 (0xF006209Eu)
+#define MTU_MC16_RDBFL32 (0xF00620A0u)
+#define MTU_MC16_RDBFL33 (0xF00620A2u)
+#define MTU_MC16_RDBFL34 (0xF00620A4u)
+#define MTU_MC16_RDBFL35 (0xF00620A6u)
+#define MTU_MC16_RDBFL36 (0xF00620A8u)
+#define MTU_MC16_RDBFL37 (0xF00620AAu)
+#define MTU_MC16_RDBFL38 (0xF00620ACu)
+#define MTU_MC16_RDBFL39 (0xF00620AEu)
+#define MTU_MC1_RDBFL6 (0xF006116Cu)
+#define MTU_MC16_RDBFL40 (0xF00620B0u)
+#define MTU_MC16_RDBFL41 (0xF00620B2u)
+#define MTU_MC16_RDBFL42 (0xF00620B4u)
+#define MTU_MC16_RDBFL43 (0xF00620B6u)
+#define MTU_MC16_RDBFL44 (0xF00620B8u)
+#define MTU_MC16_RDBFL45 (0xF00620BAu)
+#define MTU_MC16_RDBFL46 (0xF00620BCu)
+#define MTU_MC16_RDBFL47 (0xF00620BEu)
+#define MTU_MC16_RDBFL48 (0xF00620C0u)
+#define MTU_MC16_RDBFL49 (0xF00620C2u)
+#define MTU_MC1_RDBFL7 (0xF006116Eu)
+#define MTU_MC16_RDBFL50 (0xF00620C4u)
+#define MTU_MC16_RDBFL51 (0xF00620C6u)
+#define MTU_MC16_RDBFL52 (0xF00620C8u)
+#define MTU_MC16_RDBFL53 (0xF00620CAu)
+#define MTU_MC16_RDBFL54 (0xF00620CCu)
+#define MTU_MC16_RDBFL55 (0xF00620CEu)
+#define MTU_MC16_RDBFL56 (0xF00620D0u)
+#define MTU_MC16_RDBFL57 (0xF00620D2u)
+#define MTU_MC16_RDBFL58 (0xF00620D4u)
+#define MTU_MC16_RDBFL59 (0xF00620D6u)
+#define MTU_MC1_RDBFL8 (0xF0061170u)
+#define MTU_MC16_RDBFL60 (0xF00620D8u)
+#define MTU_MC16_RDBFL61 (0xF00620DAu)
+#define MTU_MC16_RDBFL62 (0xF00620DCu)
+#define MTU_MC16_RDBFL63 (0xF00620DEu)
+#define MTU_MC16_RDBFL64 (0xF00620E0u)
+#define MTU_MC16_RDBFL65 (0xF00620E2u)
+#define MTU_MC16_RDBFL66 (0xF00620E4u)
+#define MTU_MC16_ALMSRCS (0xF00620EEu)
+#define MTU_MC16_FAULTSTS (0xF00620F0u)
+#define MTU_MC16_ERRINFO0 (0xF00620F2u)
+#define MTU_MC1_RDBFL9 (0xF0061172u)
+#define MTU_MC16_ERRINFO1 (0xF00620F4u)
+#define MTU_MC16_ERRINFO2 (0xF00620F6u)
+#define MTU_MC16_ERRINFO3 (0xF00620F8u)
+#define MTU_MC16_ERRINFO4 (0xF00620FAu)
+#define MTU_MC17_CONFIG0 (0xF0062100u)
+#define MTU_MC17_CONFIG1 (0xF0062102u)
+#define MTU_MC17_MCONTROL (0xF0062104u)
+#define MTU_MC17_MSTATUS (0xF0062106u)
+#define MTU_MC17_RANGE (0xF0062108u)
+#define MTU_MC17_REVID (0xF006210Cu)
+#define MTU_MC1_RDBFL10 (0xF0061174u)
+#define MTU_MC17_ECCS (0xF006210Eu)
+#define MTU_MC17_ECCD (0xF0062110u)
+#define MTU_MC17_ETRR0 (0xF0062112u)
+#define MTU_MC17_ETRR1 (0xF0062114u)
+#define MTU_MC17_ETRR2 (0xF0062116u)
+#define MTU_MC17_ETRR3 (0xF0062118u)
+#define MTU_MC17_ETRR4 (0xF006211Au)
+#define MTU_MC17_RDBFL0 (0xF0062160u)
+#define MTU_MC17_RDBFL1 (0xF0062162u)
+#define MTU_MC17_RDBFL2 (0xF0062164u)
+#define MTU_MC1_RDBFL11 (0xF0061176u)
+#define MTU_MC17_RDBFL3 (0xF0062166u)
+#define MTU_MC17_RDBFL4 (0xF0062168u)
+#define MTU_MC17_RDBFL5 (0xF006216Au)
+#define MTU_MC17_RDBFL6 (0xF006216Cu)
+#define MTU_MC17_RDBFL7 (0xF006216Eu)
+#define MTU_MC17_RDBFL8 (0xF0062170u)
+#define MTU_MC17_RDBFL9 (0xF0062172u)
+#define MTU_MC17_RDBFL10 (0xF0062174u)
+#define MTU_MC17_RDBFL11 (0xF0062176u)
+#define MTU_MC17_RDBFL12 (0xF0062178u)
+#define MTU_MC1_RDBFL12 (0xF0061178u)
+#define MTU_MC17_RDBFL13 (0xF006217Au)
+#define MTU_MC17_RDBFL14 (0xF006217Cu)
+#define MTU_MC17_RDBFL15 (0xF006217Eu)
+#define MTU_MC17_RDBFL16 (0xF0062180u)
+#define MTU_MC17_RDBFL17 (0xF0062182u)
+#define MTU_MC17_RDBFL18 (0xF0062184u)
+#define MTU_MC17_RDBFL19 (0xF0062186u)
+#define MTU_MC17_RDBFL20 (0xF0062188u)
+#define MTU_MC17_RDBFL21 (0xF006218Au)
+#define MTU_MC17_RDBFL22 (0xF006218Cu)
+#define MTU_MC1_RDBFL13 (0xF006117Au)
+#define MTU_MC17_RDBFL23 (0xF006218Eu)
+#define MTU_MC17_RDBFL24 (0xF0062190u)
+#define MTU_MC17_RDBFL25 (0xF0062192u)
+#define MTU_MC17_RDBFL26 (0xF0062194u)
+#define MTU_MC17_RDBFL27 (0xF0062196u)
+#define MTU_MC17_RDBFL28 (0xF0062198u)
+#define MTU_MC17_RDBFL29 (0xF006219Au)
+#define MTU_MC17_RDBFL30 (0xF006219Cu)
+#define MTU_MC17_RDBFL31 (0xF006219Eu)
+#define MTU_MC17_RDBFL32 (0xF00621A0u)
+#define MTU_MC1_RDBFL14 (0xF006117Cu)
+#define MTU_MC17_RDBFL33 (0xF00621A2u)
+#define MTU_MC17_RDBFL34 (0xF00621A4u)
+#define MTU_MC17_RDBFL35 (0xF00621A6u)
+#define MTU_MC17_RDBFL36 (0xF00621A8u)
+#define MTU_MC17_RDBFL37 (0xF00621AAu)
+#define MTU_MC17_RDBFL38 (0xF00621ACu)
+#define MTU_MC17_RDBFL39 (0xF00621AEu)
+#define MTU_MC17_RDBFL40 (0xF00621B0u)
+#define MTU_MC17_RDBFL41 (0xF00621B2u)
+#define MTU_MC17_RDBFL42 (0xF00621B4u)
+#define MTU_MC1_RDBFL15 (0xF006117Eu)
+#define MTU_MC17_RDBFL43 (0xF00621B6u)
+#define MTU_MC17_RDBFL44 (0xF00621B8u)
+#define MTU_MC17_RDBFL45 (0xF00621BAu)
+#define MTU_MC17_RDBFL46 (0xF00621BCu)
+#define MTU_MC17_RDBFL47 (0xF00621BEu)
+#define MTU_MC17_RDBFL48 (0xF00621C0u)
+#define MTU_MC17_RDBFL49 (0xF00621C2u)
+#define MTU_MC17_RDBFL50 (0xF00621C4u)
+#define MTU_MC17_RDBFL51 (0xF00621C6u)
+#define MTU_MC17_RDBFL52 (0xF00621C8u)
+#define MTU_MC1_RDBFL16 (0xF0061180u)
+#define MTU_MC17_RDBFL53 (0xF00621CAu)
+#define MTU_MC17_RDBFL54 (0xF00621CCu)
+#define MTU_MC17_RDBFL55 (0xF00621CEu)
+#define MTU_MC17_RDBFL56 (0xF00621D0u)
+#define MTU_MC17_RDBFL57 (0xF00621D2u)
+#define MTU_MC17_RDBFL58 (0xF00621D4u)
+#define MTU_MC17_RDBFL59 (0xF00621D6u)
+#define MTU_MC17_RDBFL60 (0xF00621D8u)
+#define MTU_MC17_RDBFL61 (0xF00621DAu)
+#define MTU_MC17_RDBFL62 (0xF00621DCu)
+#define MTU_MC1_RDBFL17 (0xF0061182u)
+#define MTU_MC17_RDBFL63 (0xF00621DEu)
+#define MTU_MC17_RDBFL64 (0xF00621E0u)
+#define MTU_MC17_RDBFL65 (0xF00621E2u)
+#define MTU_MC17_RDBFL66 (0xF00621E4u)
+#define MTU_MC17_ALMSRCS (0xF00621EEu)
+#define MTU_MC17_FAULTSTS (0xF00621F0u)
+#define MTU_MC17_ERRINFO0 (0xF00621F2u)
+#define MTU_MC17_ERRINFO1 (0xF00621F4u)
+#define MTU_MC17_ERRINFO2 (0xF00621F6u)
+#define MTU_MC17_ERRINFO3 (0xF00621F8u)
+#define MTU_MC1_RDBFL18 (0xF0061184u)
+#define MTU_MC17_ERRINFO4 (0xF00621FAu)
+#define MTU_MC18_CONFIG0 (0xF0062200u)
+#define MTU_MC18_CONFIG1 (0xF0062202u)
+#define MTU_MC18_MCONTROL (0xF0062204u)
+#define MTU_MC18_MSTATUS (0xF0062206u)
+#define MTU_MC18_RANGE (0xF0062208u)
+#define MTU_MC18_REVID (0xF006220Cu)
+#define MTU_MC18_ECCS (0xF006220Eu)
+#define MTU_MC18_ECCD (0xF0062210u)
+#define MTU_MC18_ETRR0 (0xF0062212u)
+#define MTU_MC1_RDBFL19 (0xF0061186u)
+#define MTU_MC18_ETRR1 (0xF0062214u)
+#define MTU_MC18_ETRR2 (0xF0062216u)
+#define MTU_MC18_ETRR3 (0xF0062218u)
+#define MTU_MC18_ETRR4 (0xF006221Au)
+#define MTU_MC18_RDBFL0 (0xF0062260u)
+#define MTU_MC18_RDBFL1 (0xF0062262u)
+#define MTU_MC18_RDBFL2 (0xF0062264u)
+#define MTU_MC18_RDBFL3 (0xF0062266u)
+#define MTU_MC18_RDBFL4 (0xF0062268u)
+#define MTU_MC18_RDBFL5 (0xF006226Au)
+#define MTU_MC1_RDBFL20 (0xF0061188u)
+#define MTU_MC18_RDBFL6 (0xF006226Cu)
+#define MTU_MC18_RDBFL7 (0xF006226Eu)
+#define MTU_MC18_RDBFL8 (0xF0062270u)
+#define MTU_MC18_RDBFL9 (0xF0062272u)
+#define MTU_MC18_RDBFL10 (0xF0062274u)
+#define MTU_MC18_RDBFL11 (0xF0062276u)
+#define MTU_MC18_RDBFL12 (0xF0062278u)
+#define MTU_MC18_RDBFL13 (0xF006227Au)
+#define MTU_MC18_RDBFL14 (0xF006227Cu)
+#define MTU_MC18_RDBFL15 (0xF006227Eu)
+#define MTU_MC1_RDBFL21 (0xF006118Au)
+#define MTU_MC18_RDBFL16 (0xF0062280u)
+#define MTU_MC18_RDBFL17 (0xF0062282u)
+#define MTU_MC18_RDBFL18 (0xF0062284u)
+#define MTU_MC18_RDBFL19 (0xF0062286u)
+#define MTU_MC18_RDBFL20 (0xF0062288u)
+#define MTU_MC18_RDBFL21 (0xF006228Au)
+#define MTU_MC18_RDBFL22 (0xF006228Cu)
+#define MTU_MC18_RDBFL23 (0xF006228Eu)
+#define MTU_MC18_RDBFL24 (0xF0062290u)
+#define MTU_MC18_RDBFL25 (0xF0062292u)
+#define MTU_MC1_RDBFL22 (0xF006118Cu)
+#define MTU_MC18_RDBFL26 (0xF0062294u)
+#define MTU_MC18_RDBFL27 (0xF0062296u)
+#define MTU_MC18_RDBFL28 (0xF0062298u)
+#define MTU_MC18_RDBFL29 (0xF006229Au)
+#define MTU_MC18_RDBFL30 (0xF006229Cu)
+#define MTU_MC18_RDBFL31 (0xF006229Eu)
+#define MTU_MC18_RDBFL32 (0xF00622A0u)
+#define MTU_MC18_RDBFL33 (0xF00622A2u)
+#define MTU_MC18_RDBFL34 (0xF00622A4u)
+#define MTU_MC18_RDBFL35 (0xF00622A6u)
+#define MTU_MC1_RDBFL23 (0xF006118Eu)
+#define MTU_MC18_RDBFL36 (0xF00622A8u)
+#define MTU_MC18_RDBFL37 (0xF00622AAu)
+#define MTU_MC18_RDBFL38 (0xF00622ACu)
+#define MTU_MC18_RDBFL39 (0xF00622AEu)
+#define MTU_MC18_RDBFL40 (0xF00622B0u)
+#define MTU_MC18_RDBFL41 (0xF00622B2u)
+#define MTU_MC18_RDBFL42 (0xF00622B4u)
+#define MTU_MC18_RDBFL43 (0xF00622B6u)
+#define MTU_MC18_RDBFL44 (0xF00622B8u)
+#define MTU_MC18_RDBFL45 (0xF00622BAu)
+#define MTU_MC1_RDBFL24 (0xF0061190u)
+#define MTU_MC18_RDBFL46 (0xF00622BCu)
+#define MTU_MC18_RDBFL47 (0xF00622BEu)
+#define MTU_MC18_RDBFL48 (0xF00622C0u)
+#define MTU_MC18_RDBFL49 (0xF00622C2u)
+#define MTU_MC18_RDBFL50 (0xF00622C4u)
+#define MTU_MC18_RDBFL51 (0xF00622C6u)
+#define MTU_MC18_RDBFL52 (0xF00622C8u)
+#define MTU_MC18_RDBFL53 (0xF00622CAu)
+#define MTU_MC18_RDBFL54 (0xF00622CCu)
+#define MTU_MC18_RDBFL55 (0xF00622CEu)
+#define MTU_MC1_RDBFL25 (0xF0061192u)
+#define MTU_MC18_RDBFL56 (0xF00622D0u)
+#define MTU_MC18_RDBFL57 (0xF00622D2u)
+#define MTU_MC18_RDBFL58 (0xF00622D4u)
+#define MTU_MC18_RDBFL59 (0xF00622D6u)
+#define MTU_MC18_RDBFL60 (0xF00622D8u)
+#define MTU_MC18_RDBFL61 (0xF00622DAu)
+#define MTU_MC18_RDBFL62 (0xF00622DCu)
+#define MTU_MC18_RDBFL63 (0xF00622DEu)
+#define MTU_MC18_RDBFL64 (0xF00622E0u)
+#define MTU_MC18_RDBFL65 (0xF00622E2u)
+#define MTU_MC1_RDBFL26 (0xF0061194u)
+#define MTU_MC18_RDBFL66 (0xF00622E4u)
+#define MTU_MC18_ALMSRCS (0xF00622EEu)
+#define MTU_MC18_FAULTSTS (0xF00622F0u)
+#define MTU_MC18_ERRINFO0 (0xF00622F2u)
+#define MTU_MC18_ERRINFO1 (0xF00622F4u)
+#define MTU_MC18_ERRINFO2 (0xF00622F6u)
+#define MTU_MC18_ERRINFO3 (0xF00622F8u)
+#define MTU_MC18_ERRINFO4 (0xF00622FAu)
+#define MTU_MC19_CONFIG0 (0xF0062300u)
+#define MTU_MC19_CONFIG1 (0xF0062302u)
+#define MTU_MC1_RDBFL27 (0xF0061196u)
+#define MTU_MC19_MCONTROL (0xF0062304u)
+#define MTU_MC19_MSTATUS (0xF0062306u)
+#define MTU_MC19_RANGE (0xF0062308u)
+#define MTU_MC19_REVID (0xF006230Cu)
+#define MTU_MC19_ECCS (0xF006230Eu)
+#define MTU_MC19_ECCD (0xF0062310u)
+#define MTU_MC19_ETRR0 (0xF0062312u)
+#define MTU_MC19_ETRR1 (0xF0062314u)
+#define MTU_MC19_ETRR2 (0xF0062316u)
+#define MTU_MC19_ETRR3 (0xF0062318u)
+#define MTU_MC1_RDBFL28 (0xF0061198u)
+#define MTU_MC19_ETRR4 (0xF006231Au)
+#define MTU_MC19_RDBFL0 (0xF0062360u)
+#define MTU_MC19_RDBFL1 (0xF0062362u)
+#define MTU_MC19_RDBFL2 (0xF0062364u)
+#define MTU_MC19_RDBFL3 (0xF0062366u)
+#define MTU_MC19_RDBFL4 (0xF0062368u)
+#define MTU_MC19_RDBFL5 (0xF006236Au)
+#define MTU_MC19_RDBFL6 (0xF006236Cu)
+#define MTU_MC19_RDBFL7 (0xF006236Eu)
+#define MTU_MC19_RDBFL8 (0xF0062370u)
+#define MTU_MC1_RDBFL29 (0xF006119Au)
+#define MTU_MC19_RDBFL9 (0xF0062372u)
+#define MTU_MC19_RDBFL10 (0xF0062374u)
+#define MTU_MC19_RDBFL11 (0xF0062376u)
+#define MTU_MC19_RDBFL12 (0xF0062378u)
+#define MTU_MC19_RDBFL13 (0xF006237Au)
+#define MTU_MC19_RDBFL14 (0xF006237Cu)
+#define MTU_MC19_RDBFL15 (0xF006237Eu)
+#define MTU_MC19_RDBFL16 (0xF0062380u)
+#define MTU_MC19_RDBFL17 (0xF0062382u)
+#define MTU_MC19_RDBFL18 (0xF0062384u)
+#define MTU_MC1_RDBFL30 (0xF006119Cu)
+#define MTU_MC19_RDBFL19 (0xF0062386u)
+#define MTU_MC19_RDBFL20 (0xF0062388u)
+#define MTU_MC19_RDBFL21 (0xF006238Au)
+#define MTU_MC19_RDBFL22 (0xF006238Cu)
+#define MTU_MC19_RDBFL23 (0xF006238Eu)
+#define MTU_MC19_RDBFL24 (0xF0062390u)
+#define MTU_MC19_RDBFL25 (0xF0062392u)
+#define MTU_MC19_RDBFL26 (0xF0062394u)
+#define MTU_MC19_RDBFL27 (0xF0062396u)
+#define MTU_MC19_RDBFL28 (0xF0062398u)
+#define MTU_MC1_RDBFL31 (0xF006119Eu)
+#define MTU_MC19_RDBFL29 (0xF006239Au)
+#define MTU_MC19_RDBFL30 (0xF006239Cu)
+#define MTU_MC19_RDBFL31 (0xF006239Eu)
+#define MTU_MC19_RDBFL32 (0xF00623A0u)
+#define MTU_MC19_RDBFL33 (0xF00623A2u)
+#define MTU_MC19_RDBFL34 (0xF00623A4u)
+#define MTU_MC19_RDBFL35 (0xF00623A6u)
+#define MTU_MC19_RDBFL36 (0xF00623A8u)
+#define MTU_MC19_RDBFL37 (0xF00623AAu)
+#define MTU_MC19_RDBFL38 (0xF00623ACu)
+#define MTU_MC1_RDBFL32 (0xF00611A0u)
+#define MTU_MC19_RDBFL39 (0xF00623AEu)
+#define MTU_MC19_RDBFL40 (0xF00623B0u)
+#define MTU_MC19_RDBFL41 (0xF00623B2u)
+#define MTU_MC19_RDBFL42 (0xF00623B4u)
+#define MTU_MC19_RDBFL43 (0xF00623B6u)
+#define MTU_MC19_RDBFL44 (0xF00623B8u)
+#define MTU_MC19_RDBFL45 (0xF00623BAu)
+#define MTU_MC19_RDBFL46 (0xF00623BCu)
+#define MTU_MC19_RDBFL47 (0xF00623BEu)
+#define MTU_MC19_RDBFL48 (0xF00623C0u)
+#define MTU_MC1_RDBFL33 (0xF00611A2u)
+#define MTU_MC19_RDBFL49 (0xF00623C2u)
+#define MTU_MC19_RDBFL50 (0xF00623C4u)
+#define MTU_MC19_RDBFL51 (0xF00623C6u)
+#define MTU_MC19_RDBFL52 (0xF00623C8u)
+#define MTU_MC19_RDBFL53 (0xF00623CAu)
+#define MTU_MC19_RDBFL54 (0xF00623CCu)
+#define MTU_MC19_RDBFL55 (0xF00623CEu)
+#define MTU_MC19_RDBFL56 (0xF00623D0u)
+#define MTU_MC19_RDBFL57 (0xF00623D2u)
+#define MTU_MC19_RDBFL58 (0xF00623D4u)
+#define MTU_MC1_RDBFL34 (0xF00611A4u)
+#define MTU_MC19_RDBFL59 (0xF00623D6u)
+#define MTU_MC19_RDBFL60 (0xF00623D8u)
+#define MTU_MC19_RDBFL61 (0xF00623DAu)
+#define MTU_MC19_RDBFL62 (0xF00623DCu)
+#define MTU_MC19_RDBFL63 (0xF00623DEu)
+#define MTU_MC19_RDBFL64 (0xF00623E0u)
+#define MTU_MC19_RDBFL65 (0xF00623E2u)
+#define MTU_MC19_RDBFL66 (0xF00623E4u)
+#define MTU_MC19_ALMSRCS (0xF00623EEu)
+#define MTU_MC19_FAULTSTS (0xF00623F0u)
+#define MTU_MC1_RDBFL35 (0xF00611A6u)
+#define MTU_MC19_ERRINFO0 (0xF00623F2u)
+#define MTU_MC19_ERRINFO1 (0xF00623F4u)
+#define MTU_MC19_ERRINFO2 (0xF00623F6u)
+#define MTU_MC19_ERRINFO3 (0xF00623F8u)
+#define MTU_MC19_ERRINFO4 (0xF00623FAu)
+#define MTU_MC20_CONFIG0 (0xF0062400u)
+#define MTU_MC20_CONFIG1 (0xF0062402u)
+#define MTU_MC20_MCONTROL (0xF0062404u)
+#define MTU_MC20_MSTATUS (0xF0062406u)
+#define MTU_MC20_RANGE (0xF0062408u)
+#define MTU_MC1_RDBFL36 (0xF00611A8u)
+#define MTU_MC20_REVID (0xF006240Cu)
+#define MTU_MC20_ECCS (0xF006240Eu)
+#define MTU_MC20_ECCD (0xF0062410u)
+#define MTU_MC20_ETRR0 (0xF0062412u)
+#define MTU_MC20_ETRR1 (0xF0062414u)
+#define MTU_MC20_ETRR2 (0xF0062416u)
+#define MTU_MC20_ETRR3 (0xF0062418u)
+#define MTU_MC20_ETRR4 (0xF006241Au)
+#define MTU_MC20_RDBFL0 (0xF0062460u)
+#define MTU_MC20_RDBFL1 (0xF0062462u)
+#define MTU_MC1_RDBFL37 (0xF00611AAu)
+#define MTU_MC20_RDBFL2 (0xF0062464u)
+#define MTU_MC20_RDBFL3 (0xF0062466u)
+#define MTU_MC20_RDBFL4 (0xF0062468u)
+#define MTU_MC20_RDBFL5 (0xF006246Au)
+#define MTU_MC20_RDBFL6 (0xF006246Cu)
+#define MTU_MC20_RDBFL7 (0xF006246Eu)
+#define MTU_MC20_RDBFL8 (0xF0062470u)
+#define MTU_MC20_RDBFL9 (0xF0062472u)
+#define MTU_MC20_RDBFL10 (0xF0062474u)
+#define MTU_MC20_RDBFL11 (0xF0062476u)
+#define MTU_MC1_RDBFL38 (0xF00611ACu)
+#define MTU_MC20_RDBFL12 (0xF0062478u)
+#define MTU_MC20_RDBFL13 (0xF006247Au)
+#define MTU_MC20_RDBFL14 (0xF006247Cu)
+#define MTU_MC20_RDBFL15 (0xF006247Eu)
+#define MTU_MC20_RDBFL16 (0xF0062480u)
+#define MTU_MC20_RDBFL17 (0xF0062482u)
+#define MTU_MC20_RDBFL18 (0xF0062484u)
+#define MTU_MC20_RDBFL19 (0xF0062486u)
+#define MTU_MC20_RDBFL20 (0xF0062488u)
+#define MTU_MC20_RDBFL21 (0xF006248Au)
+#define MTU_MC1_RDBFL39 (0xF00611AEu)
+#define MTU_MC20_RDBFL22 (0xF006248Cu)
+#define MTU_MC20_RDBFL23 (0xF006248Eu)
+#define MTU_MC20_RDBFL24 (0xF0062490u)
+#define MTU_MC20_RDBFL25 (0xF0062492u)
+#define MTU_MC20_RDBFL26 (0xF0062494u)
+#define MTU_MC20_RDBFL27 (0xF0062496u)
+#define MTU_MC20_RDBFL28 (0xF0062498u)
+#define MTU_MC20_RDBFL29 (0xF006249Au)
+#define MTU_MC20_RDBFL30 (0xF006249Cu)
+#define MTU_MC20_RDBFL31 (0xF006249Eu)
+#define MTU_MC1_RDBFL40 (0xF00611B0u)
+#define MTU_MC20_RDBFL32 (0xF00624A0u)
+#define MTU_MC20_RDBFL33 (0xF00624A2u)
+#define MTU_MC20_RDBFL34 (0xF00624A4u)
+#define MTU_MC20_RDBFL35 (0xF00624A6u)
+#define MTU_MC20_RDBFL36 (0xF00624A8u)
+#define MTU_MC20_RDBFL37 (0xF00624AAu)
+#define MTU_MC20_RDBFL38 (0xF00624ACu)
+#define MTU_MC20_RDBFL39 (0xF00624AEu)
+#define MTU_MC20_RDBFL40 (0xF00624B0u)
+#define MTU_MC20_RDBFL41 (0xF00624B2u)
+#define MTU_MC1_RDBFL41 (0xF00611B2u)
+#define MTU_MC20_RDBFL42 (0xF00624B4u)
+#define MTU_MC20_RDBFL43 (0xF00624B6u)
+#define MTU_MC20_RDBFL44 (0xF00624B8u)
+#define MTU_MC20_RDBFL45 (0xF00624BAu)
+#define MTU_MC20_RDBFL46 (0xF00624BCu)
+#define MTU_MC20_RDBFL47 (0xF00624BEu)
+#define MTU_MC20_RDBFL48 (0xF00624C0u)
+#define MTU_MC20_RDBFL49 (0xF00624C2u)
+#define MTU_MC20_RDBFL50 (0xF00624C4u)
+#define MTU_MC20_RDBFL51 (0xF00624C6u)
+#define MTU_MC1_RDBFL42 (0xF00611B4u)
+#define MTU_MC20_RDBFL52 (0xF00624C8u)
+#define MTU_MC20_RDBFL53 (0xF00624CAu)
+#define MTU_MC20_RDBFL54 (0xF00624CCu)
+#define MTU_MC20_RDBFL55 (0xF00624CEu)
+#define MTU_MC20_RDBFL56 (0xF00624D0u)
+#define MTU_MC20_RDBFL57 (0xF00624D2u)
+#define MTU_MC20_RDBFL58 (0xF00624D4u)
+#define MTU_MC20_RDBFL59 (0xF00624D6u)
+#define MTU_MC20_RDBFL60 (0xF00624D8u)
+#define MTU_MC20_RDBFL61 (0xF00624DAu)
+#define MTU_MC1_RDBFL43 (0xF00611B6u)
+#define MTU_MC20_RDBFL62 (0xF00624DCu)
+#define MTU_MC20_RDBFL63 (0xF00624DEu)
+#define MTU_MC20_RDBFL64 (0xF00624E0u)
+#define MTU_MC20_RDBFL65 (0xF00624E2u)
+#define MTU_MC20_RDBFL66 (0xF00624E4u)
+#define MTU_MC20_ALMSRCS (0xF00624EEu)
+#define MTU_MC20_FAULTSTS (0xF00624F0u)
+#define MTU_MC20_ERRINFO0 (0xF00624F2u)
+#define MTU_MC20_ERRINFO1 (0xF00624F4u)
+#define MTU_MC20_ERRINFO2 (0xF00624F6u)
+#define MTU_MC1_RDBFL44 (0xF00611B8u)
+#define MTU_MC20_ERRINFO3 (0xF00624F8u)
+#define MTU_MC20_ERRINFO4 (0xF00624FAu)
+#define MTU_MC21_CONFIG0 (0xF0062500u)
+#define MTU_MC21_CONFIG1 (0xF0062502u)
+#define MTU_MC21_MCONTROL (0xF0062504u)
+#define MTU_MC21_MSTATUS (0xF0062506u)
+#define MTU_MC21_RANGE (0xF0062508u)
+#define MTU_MC21_REVID (0xF006250Cu)
+#define MTU_MC21_ECCS (0xF006250Eu)
+#define MTU_MC21_ECCD (0xF0062510u)
+#define MTU_MC1_RDBFL45 (0xF00611BAu)
+#define MTU_MC21_ETRR0 (0xF0062512u)
+#define MTU_MC21_ETRR1 (0xF0062514u)
+#define MTU_MC21_ETRR2 (0xF0062516u)
+#define MTU_MC21_ETRR3 (0xF0062518u)
+#define MTU_MC21_ETRR4 (0xF006251Au)
+#define MTU_MC21_RDBFL0 (0xF0062560u)
+#define MTU_MC21_RDBFL1 (0xF0062562u)
+#define MTU_MC21_RDBFL2 (0xF0062564u)
+#define MTU_MC21_RDBFL3 (0xF0062566u)
+#define MTU_MC21_RDBFL4 (0xF0062568u)
+#define MTU_MC1_RDBFL46 (0xF00611BCu)
+#define MTU_MC21_RDBFL5 (0xF006256Au)
+#define MTU_MC21_RDBFL6 (0xF006256Cu)
+#define MTU_MC21_RDBFL7 (0xF006256Eu)
+#define MTU_MC21_RDBFL8 (0xF0062570u)
+#define MTU_MC21_RDBFL9 (0xF0062572u)
+#define MTU_MC21_RDBFL10 (0xF0062574u)
+#define MTU_MC21_RDBFL11 (0xF0062576u)
+#define MTU_MC21_RDBFL12 (0xF0062578u)
+#define MTU_MC21_RDBFL13 (0xF006257Au)
+#define MTU_MC21_RDBFL14 (0xF006257Cu)
+#define MTU_MC1_RDBFL47 (0xF00611BEu)
+#define MTU_MC21_RDBFL15 (0xF006257Eu)
+#define MTU_MC21_RDBFL16 (0xF0062580u)
+#define MTU_MC21_RDBFL17 (0xF0062582u)
+#define MTU_MC21_RDBFL18 (0xF0062584u)
+#define MTU_MC21_RDBFL19 (0xF0062586u)
+#define MTU_MC21_RDBFL20 (0xF0062588u)
+#define MTU_MC21_RDBFL21 (0xF006258Au)
+#define MTU_MC21_RDBFL22 (0xF006258Cu)
+#define MTU_MC21_RDBFL23 (0xF006258Eu)
+#define MTU_MC21_RDBFL24 (0xF0062590u)
+#define MTU_MC1_RDBFL48 (0xF00611C0u)
+#define MTU_MC21_RDBFL25 (0xF0062592u)
+#define MTU_MC21_RDBFL26 (0xF0062594u)
+#define MTU_MC21_RDBFL27 (0xF0062596u)
+#define MTU_MC21_RDBFL28 (0xF0062598u)
+#define MTU_MC21_RDBFL29 (0xF006259Au)
+#define MTU_MC21_RDBFL30 (0xF006259Cu)
+#define MTU_MC21_RDBFL31 (0xF006259Eu)
+#define MTU_MC21_RDBFL32 (0xF00625A0u)
+#define MTU_MC21_RDBFL33 (0xF00625A2u)
+#define MTU_MC21_RDBFL34 (0xF00625A4u)
+#define MTU_MC1_RDBFL49 (0xF00611C2u)
+#define MTU_MC21_RDBFL35 (0xF00625A6u)
+#define MTU_MC21_RDBFL36 (0xF00625A8u)
+#define MTU_MC21_RDBFL37 (0xF00625AAu)
+#define MTU_MC21_RDBFL38 (0xF00625ACu)
+#define MTU_MC21_RDBFL39 (0xF00625AEu)
+#define MTU_MC21_RDBFL40 (0xF00625B0u)
+#define MTU_MC21_RDBFL41 (0xF00625B2u)
+#define MTU_MC21_RDBFL42 (0xF00625B4u)
+#define MTU_MC21_RDBFL43 (0xF00625B6u)
+#define MTU_MC21_RDBFL44 (0xF00625B8u)
+#define MTU_MC1_RDBFL50 (0xF00611C4u)
+#define MTU_MC21_RDBFL45 (0xF00625BAu)
+#define MTU_MC21_RDBFL46 (0xF00625BCu)
+#define MTU_MC21_RDBFL47 (0xF00625BEu)
+#define MTU_MC21_RDBFL48 (0xF00625C0u)
+#define MTU_MC21_RDBFL49 (0xF00625C2u)
+#define MTU_MC21_RDBFL50 (0xF00625C4u)
+#define MTU_MC21_RDBFL51 (0xF00625C6u)
+#define MTU_MC21_RDBFL52 (0xF00625C8u)
+#define MTU_MC21_RDBFL53 (0xF00625CAu)
+#define MTU_MC21_RDBFL54 (0xF00625CCu)
+#define MTU_MC1_RDBFL51 (0xF00611C6u)
+#define MTU_MC21_RDBFL55 (0xF00625CEu)
+#define MTU_MC21_RDBFL56 (0xF00625D0u)
+#define MTU_MC21_RDBFL57 (0xF00625D2u)
+#define MTU_MC21_RDBFL58 (0xF00625D4u)
+#define MTU_MC21_RDBFL59 (0xF00625D6u)
+#define MTU_MC21_RDBFL60 (0xF00625D8u)
+#define MTU_MC21_RDBFL61 (0xF00625DAu)
+#define MTU_MC21_RDBFL62 (0xF00625DCu)
+#define MTU_MC21_RDBFL63 (0xF00625DEu)
+#define MTU_MC21_RDBFL64 (0xF00625E0u)
+#define MTU_MC1_RDBFL52 (0xF00611C8u)
+#define MTU_MC21_RDBFL65 (0xF00625E2u)
+#define MTU_MC21_RDBFL66 (0xF00625E4u)
+#define MTU_MC21_ALMSRCS (0xF00625EEu)
+#define MTU_MC21_FAULTSTS (0xF00625F0u)
+#define MTU_MC21_ERRINFO0 (0xF00625F2u)
+#define MTU_MC21_ERRINFO1 (0xF00625F4u)
+#define MTU_MC21_ERRINFO2 (0xF00625F6u)
+#define MTU_MC21_ERRINFO3 (0xF00625F8u)
+#define MTU_MC21_ERRINFO4 (0xF00625FAu)
+#define MTU_MC22_CONFIG0 (0xF0062600u)
+#define MTU_MC1_RDBFL53 (0xF00611CAu)
+#define MTU_MC22_CONFIG1 (0xF0062602u)
+#define MTU_MC22_MCONTROL (0xF0062604u)
+#define MTU_MC22_MSTATUS (0xF0062606u)
+#define MTU_MC22_RANGE (0xF0062608u)
+#define MTU_MC22_REVID (0xF006260Cu)
+#define MTU_MC22_ECCS (0xF006260Eu)
+#define MTU_MC22_ECCD (0xF0062610u)
+#define MTU_MC22_ETRR0 (0xF0062612u)
+#define MTU_MC22_ETRR1 (0xF0062614u)
+#define MTU_MC22_ETRR2 (0xF0062616u)
+#define MTU_MC1_RDBFL54 (0xF00611CCu)
+#define MTU_MC22_ETRR3 (0xF0062618u)
+#define MTU_MC22_ETRR4 (0xF006261Au)
+#define MTU_MC22_RDBFL0 (0xF0062660u)
+#define MTU_MC22_RDBFL1 (0xF0062662u)
+#define MTU_MC22_RDBFL2 (0xF0062664u)
+#define MTU_MC22_RDBFL3 (0xF0062666u)
+#define MTU_MC22_RDBFL4 (0xF0062668u)
+#define MTU_MC22_RDBFL5 (0xF006266Au)
+#define MTU_MC22_RDBFL6 (0xF006266Cu)
+#define MTU_MC22_RDBFL7 (0xF006266Eu)
+#define MTU_MC1_RDBFL55 (0xF00611CEu)
+#define MTU_MC22_RDBFL8 (0xF0062670u)
+#define MTU_MC22_RDBFL9 (0xF0062672u)
+#define MTU_MC22_RDBFL10 (0xF0062674u)
+#define MTU_MC22_RDBFL11 (0xF0062676u)
+#define MTU_MC22_RDBFL12 (0xF0062678u)
+#define MTU_MC22_RDBFL13 (0xF006267Au)
+#define MTU_MC22_RDBFL14 (0xF006267Cu)
+#define MTU_MC22_RDBFL15 (0xF006267Eu)
+#define MTU_MC22_RDBFL16 (0xF0062680u)
+#define MTU_MC22_RDBFL17 (0xF0062682u)
+#define MTU_MC1_RDBFL56 (0xF00611D0u)
+#define MTU_MC22_RDBFL18 (0xF0062684u)
+#define MTU_MC22_RDBFL19 (0xF0062686u)
+#define MTU_MC22_RDBFL20 (0xF0062688u)
+#define MTU_MC22_RDBFL21 (0xF006268Au)
+#define MTU_MC22_RDBFL22 (0xF006268Cu)
+#define MTU_MC22_RDBFL23 (0xF006268Eu)
+#define MTU_MC22_RDBFL24 (0xF0062690u)
+#define MTU_MC22_RDBFL25 (0xF0062692u)
+#define MTU_MC22_RDBFL26 (0xF0062694u)
+#define MTU_MC22_RDBFL27 (0xF0062696u)
+#define MTU_MC1_RDBFL57 (0xF00611D2u)
+#define MTU_MC22_RDBFL28 (0xF0062698u)
+#define MTU_MC22_RDBFL29 (0xF006269Au)
+#define MTU_MC22_RDBFL30 (0xF006269Cu)
+#define MTU_MC22_RDBFL31 (0xF006269Eu)
+#define MTU_MC22_RDBFL32 (0xF00626A0u)
+#define MTU_MC22_RDBFL33 (0xF00626A2u)
+#define MTU_MC22_RDBFL34 (0xF00626A4u)
+#define MTU_MC22_RDBFL35 (0xF00626A6u)
+#define MTU_MC22_RDBFL36 (0xF00626A8u)
+#define MTU_MC22_RDBFL37 (0xF00626AAu)
+#define MTU_MC1_RDBFL58 (0xF00611D4u)
+#define MTU_MC22_RDBFL38 (0xF00626ACu)
+#define MTU_MC22_RDBFL39 (0xF00626AEu)
+#define MTU_MC22_RDBFL40 (0xF00626B0u)
+#define MTU_MC22_RDBFL41 (0xF00626B2u)
+#define MTU_MC22_RDBFL42 (0xF00626B4u)
+#define MTU_MC22_RDBFL43 (0xF00626B6u)
+#define MTU_MC22_RDBFL44 (0xF00626B8u)
+#define MTU_MC22_RDBFL45 (0xF00626BAu)
+#define MTU_MC22_RDBFL46 (0xF00626BCu)
+#define MTU_MC22_RDBFL47 (0xF00626BEu)
+#define MTU_MC1_RDBFL59 (0xF00611D6u)
+#define MTU_MC22_RDBFL48 (0xF00626C0u)
+#define MTU_MC22_RDBFL49 (0xF00626C2u)
+#define MTU_MC22_RDBFL50 (0xF00626C4u)
+#define MTU_MC22_RDBFL51 (0xF00626C6u)
+#define MTU_MC22_RDBFL52 (0xF00626C8u)
+#define MTU_MC22_RDBFL53 (0xF00626CAu)
+#define MTU_MC22_RDBFL54 (0xF00626CCu)
+#define MTU_MC22_RDBFL55 (0xF00626CEu)
+#define MTU_MC22_RDBFL56 (0xF00626D0u)
+#define MTU_MC22_RDBFL57 (0xF00626D2u)
+#define MTU_MC1_RDBFL60 (0xF00611D8u)
+#define MTU_MC22_RDBFL58 (0xF00626D4u)
+#define MTU_MC22_RDBFL59 (0xF00626D6u)
+#define MTU_MC22_RDBFL60 (0xF00626D8u)
+#define MTU_MC22_RDBFL61 (0xF00626DAu)
+#define MTU_MC22_RDBFL62 (0xF00626DCu)
+#define MTU_MC22_RDBFL63 (0xF00626DEu)
+#define MTU_MC22_RDBFL64 (0xF00626E0u)
+#define MTU_MC22_RDBFL65 (0xF00626E2u)
+#define MTU_MC22_RDBFL66 (0xF00626E4u)
+#define MTU_MC22_ALMSRCS (0xF00626EEu)
+#define MTU_MC1_RDBFL61 (0xF00611DAu)
+#define MTU_MC22_FAULTSTS (0xF00626F0u)
+#define MTU_MC22_ERRINFO0 (0xF00626F2u)
+#define MTU_MC22_ERRINFO1 (0xF00626F4u)
+#define MTU_MC22_ERRINFO2 (0xF00626F6u)
+#define MTU_MC22_ERRINFO3 (0xF00626F8u)
+#define MTU_MC22_ERRINFO4 (0xF00626FAu)
+#define MTU_MC23_CONFIG0 (0xF0062700u)
+#define MTU_MC23_CONFIG1 (0xF0062702u)
+#define MTU_MC23_MCONTROL (0xF0062704u)
+#define MTU_MC23_MSTATUS (0xF0062706u)
+#define MTU_MC1_RDBFL62 (0xF00611DCu)
+#define MTU_MC23_RANGE (0xF0062708u)
+#define MTU_MC23_REVID (0xF006270Cu)
+#define MTU_MC23_ECCS (0xF006270Eu)
+#define MTU_MC23_ECCD (0xF0062710u)
+#define MTU_MC23_ETRR0 (0xF0062712u)
+#define MTU_MC23_ETRR1 (0xF0062714u)
+#define MTU_MC23_ETRR2 (0xF0062716u)
+#define MTU_MC23_ETRR3 (0xF0062718u)
+#define MTU_MC23_ETRR4 (0xF006271Au)
+#define MTU_MC23_RDBFL0 (0xF0062760u)
+#define MTU_MC1_RDBFL63 (0xF00611DEu)
+#define MTU_MC23_RDBFL1 (0xF0062762u)
+#define MTU_MC23_RDBFL2 (0xF0062764u)
+#define MTU_MC23_RDBFL3 (0xF0062766u)
+#define MTU_MC23_RDBFL4 (0xF0062768u)
+#define MTU_MC23_RDBFL5 (0xF006276Au)
+#define MTU_MC23_RDBFL6 (0xF006276Cu)
+#define MTU_MC23_RDBFL7 (0xF006276Eu)
+#define MTU_MC23_RDBFL8 (0xF0062770u)
+#define MTU_MC23_RDBFL9 (0xF0062772u)
+#define MTU_MC23_RDBFL10 (0xF0062774u)
+#define MTU_MC1_RDBFL64 (0xF00611E0u)
+#define MTU_MC23_RDBFL11 (0xF0062776u)
+#define MTU_MC23_RDBFL12 (0xF0062778u)
+#define MTU_MC23_RDBFL13 (0xF006277Au)
+#define MTU_MC23_RDBFL14 (0xF006277Cu)
+#define MTU_MC23_RDBFL15 (0xF006277Eu)
+#define MTU_MC23_RDBFL16 (0xF0062780u)
+#define MTU_MC23_RDBFL17 (0xF0062782u)
+#define MTU_MC23_RDBFL18 (0xF0062784u)
+#define MTU_MC23_RDBFL19 (0xF0062786u)
+#define MTU_MC23_RDBFL20 (0xF0062788u)
+#define MTU_MC1_RDBFL65 (0xF00611E2u)
+#define MTU_MC23_RDBFL21 (0xF006278Au)
+#define MTU_MC23_RDBFL22 (0xF006278Cu)
+#define MTU_MC23_RDBFL23 (0xF006278Eu)
+#define MTU_MC23_RDBFL24 (0xF0062790u)
+#define MTU_MC23_RDBFL25 (0xF0062792u)
+#define MTU_MC23_RDBFL26 (0xF0062794u)
+#define MTU_MC23_RDBFL27 (0xF0062796u)
+#define MTU_MC23_RDBFL28 (0xF0062798u)
+#define MTU_MC23_RDBFL29 (0xF006279Au)
+#define MTU_MC23_RDBFL30 (0xF006279Cu)
+#define MTU_MC1_RDBFL66 (0xF00611E4u)
+#define MTU_MC23_RDBFL31 (0xF006279Eu)
+#define MTU_MC23_RDBFL32 (0xF00627A0u)
+#define MTU_MC23_RDBFL33 (0xF00627A2u)
+#define MTU_MC23_RDBFL34 (0xF00627A4u)
+#define MTU_MC23_RDBFL35 (0xF00627A6u)
+#define MTU_MC23_RDBFL36 (0xF00627A8u)
+#define MTU_MC23_RDBFL37 (0xF00627AAu)
+#define MTU_MC23_RDBFL38 (0xF00627ACu)
+#define MTU_MC23_RDBFL39 (0xF00627AEu)
+#define MTU_MC23_RDBFL40 (0xF00627B0u)
+#define MTU_MC1_ALMSRCS (0xF00611EEu)
+#define MTU_MC23_RDBFL41 (0xF00627B2u)
+#define MTU_MC23_RDBFL42 (0xF00627B4u)
+#define MTU_MC23_RDBFL43 (0xF00627B6u)
+#define MTU_MC23_RDBFL44 (0xF00627B8u)
+#define MTU_MC23_RDBFL45 (0xF00627BAu)
+#define MTU_MC23_RDBFL46 (0xF00627BCu)
+#define MTU_MC23_RDBFL47 (0xF00627BEu)
+#define MTU_MC23_RDBFL48 (0xF00627C0u)
+#define MTU_MC23_RDBFL49 (0xF00627C2u)
+#define MTU_MC23_RDBFL50 (0xF00627C4u)
+#define MTU_MC1_FAULTSTS (0xF00611F0u)
+#define MTU_MC23_RDBFL51 (0xF00627C6u)
+#define MTU_MC23_RDBFL52 (0xF00627C8u)
+#define MTU_MC23_RDBFL53 (0xF00627CAu)
+#define MTU_MC23_RDBFL54 (0xF00627CCu)
+#define MTU_MC23_RDBFL55 (0xF00627CEu)
+#define MTU_MC23_RDBFL56 (0xF00627D0u)
+#define MTU_MC23_RDBFL57 (0xF00627D2u)
+#define MTU_MC23_RDBFL58 (0xF00627D4u)
+#define MTU_MC23_RDBFL59 (0xF00627D6u)
+#define MTU_MC23_RDBFL60 (0xF00627D8u)
+#define MTU_MC1_ERRINFO0 (0xF00611F2u)
+#define MTU_MC23_RDBFL61 (0xF00627DAu)
+#define MTU_MC23_RDBFL62 (0xF00627DCu)
+#define MTU_MC23_RDBFL63 (0xF00627DEu)
+#define MTU_MC23_RDBFL64 (0xF00627E0u)
+#define MTU_MC23_RDBFL65 (0xF00627E2u)
+#define MTU_MC23_RDBFL66 (0xF00627E4u)
+#define MTU_MC23_ALMSRCS (0xF00627EEu)
+#define MTU_MC23_FAULTSTS (0xF00627F0u)
+#define MTU_MC23_ERRINFO0 (0xF00627F2u)
+#define MTU_MC23_ERRINFO1 (0xF00627F4u)
+#define MTU_MC1_ERRINFO1 (0xF00611F4u)
+#define MTU_MC23_ERRINFO2 (0xF00627F6u)
+#define MTU_MC23_ERRINFO3 (0xF00627F8u)
+#define MTU_MC23_ERRINFO4 (0xF00627FAu)
+#define MTU_MC24_CONFIG0 (0xF0062800u)
+#define MTU_MC24_CONFIG1 (0xF0062802u)
+#define MTU_MC24_MCONTROL (0xF0062804u)
+#define MTU_MC24_MSTATUS (0xF0062806u)
+#define MTU_MC24_RANGE (0xF0062808u)
+#define MTU_MC24_REVID (0xF006280Cu)
+#define MTU_MC24_ECCS (0xF006280Eu)
+#define MTU_MC1_ERRINFO2 (0xF00611F6u)
+#define MTU_MC24_ECCD (0xF0062810u)
+#define MTU_MC24_ETRR0 (0xF0062812u)
+#define MTU_MC24_ETRR1 (0xF0062814u)
+#define MTU_MC24_ETRR2 (0xF0062816u)
+#define MTU_MC24_ETRR3 (0xF0062818u)
+#define MTU_MC24_ETRR4 (0xF006281Au)
+#define MTU_MC24_RDBFL0 (0xF0062860u)
+#define MTU_MC24_RDBFL1 (0xF0062862u)
+#define MTU_MC24_RDBFL2 (0xF0062864u)
+#define MTU_MC24_RDBFL3 (0xF0062866u)
+#define MTU_MC1_ERRINFO3 (0xF00611F8u)
+#define MTU_MC24_RDBFL4 (0xF0062868u)
+#define MTU_MC24_RDBFL5 (0xF006286Au)
+#define MTU_MC24_RDBFL6 (0xF006286Cu)
+#define MTU_MC24_RDBFL7 (0xF006286Eu)
+#define MTU_MC24_RDBFL8 (0xF0062870u)
+#define MTU_MC24_RDBFL9 (0xF0062872u)
+#define MTU_MC24_RDBFL10 (0xF0062874u)
+#define MTU_MC24_RDBFL11 (0xF0062876u)
+#define MTU_MC24_RDBFL12 (0xF0062878u)
+#define MTU_MC24_RDBFL13 (0xF006287Au)
+#define MTU_MC1_ERRINFO4 (0xF00611FAu)
+#define MTU_MC24_RDBFL14 (0xF006287Cu)
+#define MTU_MC24_RDBFL15 (0xF006287Eu)
+#define MTU_MC24_RDBFL16 (0xF0062880u)
+#define MTU_MC24_RDBFL17 (0xF0062882u)
+#define MTU_MC24_RDBFL18 (0xF0062884u)
+#define MTU_MC24_RDBFL19 (0xF0062886u)
+#define MTU_MC24_RDBFL20 (0xF0062888u)
+#define MTU_MC24_RDBFL21 (0xF006288Au)
+#define MTU_MC24_RDBFL22 (0xF006288Cu)
+#define MTU_MC24_RDBFL23 (0xF006288Eu)
+#define MTU_MC2_CONFIG0 (0xF0061200u)
+#define MTU_MC24_RDBFL24 (0xF0062890u)
+#define MTU_MC24_RDBFL25 (0xF0062892u)
+#define MTU_MC24_RDBFL26 (0xF0062894u)
+#define MTU_MC24_RDBFL27 (0xF0062896u)
+#define MTU_MC24_RDBFL28 (0xF0062898u)
+#define MTU_MC24_RDBFL29 (0xF006289Au)
+#define MTU_MC24_RDBFL30 (0xF006289Cu)
+#define MTU_MC24_RDBFL31 (0xF006289Eu)
+#define MTU_MC24_RDBFL32 (0xF00628A0u)
+#define MTU_MC24_RDBFL33 (0xF00628A2u)
+#define MTU_MC2_CONFIG1 (0xF0061202u)
+#define MTU_MC24_RDBFL34 (0xF00628A4u)
+#define MTU_MC24_RDBFL35 (0xF00628A6u)
+#define MTU_MC24_RDBFL36 (0xF00628A8u)
+#define MTU_MC24_RDBFL37 (0xF00628AAu)
+#define MTU_MC24_RDBFL38 (0xF00628ACu)
+#define MTU_MC24_RDBFL39 (0xF00628AEu)
+#define MTU_MC24_RDBFL40 (0xF00628B0u)
+#define MTU_MC24_RDBFL41 (0xF00628B2u)
+#define MTU_MC24_RDBFL42 (0xF00628B4u)
+#define MTU_MC24_RDBFL43 (0xF00628B6u)
+#define MTU_MC2_MCONTROL (0xF0061204u)
+#define MTU_MC24_RDBFL44 (0xF00628B8u)
+#define MTU_MC24_RDBFL45 (0xF00628BAu)
+#define MTU_MC24_RDBFL46 (0xF00628BCu)
+#define MTU_MC24_RDBFL47 (0xF00628BEu)
+#define MTU_MC24_RDBFL48 (0xF00628C0u)
+#define MTU_MC24_RDBFL49 (0xF00628C2u)
+#define MTU_MC24_RDBFL50 (0xF00628C4u)
+#define MTU_MC24_RDBFL51 (0xF00628C6u)
+#define MTU_MC24_RDBFL52 (0xF00628C8u)
+#define MTU_MC24_RDBFL53 (0xF00628CAu)
+#define MTU_MC2_MSTATUS (0xF0061206u)
+#define MTU_MC24_RDBFL54 (0xF00628CCu)
+#define MTU_MC24_RDBFL55 (0xF00628CEu)
+#define MTU_MC24_RDBFL56 (0xF00628D0u)
+#define MTU_MC24_RDBFL57 (0xF00628D2u)
+#define MTU_MC24_RDBFL58 (0xF00628D4u)
+#define MTU_MC24_RDBFL59 (0xF00628D6u)
+#define MTU_MC24_RDBFL60 (0xF00628D8u)
+#define MTU_MC24_RDBFL61 (0xF00628DAu)
+#define MTU_MC24_RDBFL62 (0xF00628DCu)
+#define MTU_MC24_RDBFL63 (0xF00628DEu)
+#define MTU_MC2_RANGE (0xF0061208u)
+#define MTU_MC24_RDBFL64 (0xF00628E0u)
+#define MTU_MC24_RDBFL65 (0xF00628E2u)
+#define MTU_MC24_RDBFL66 (0xF00628E4u)
+#define MTU_MC24_ALMSRCS (0xF00628EEu)
+#define MTU_MC24_FAULTSTS (0xF00628F0u)
+#define MTU_MC24_ERRINFO0 (0xF00628F2u)
+#define MTU_MC24_ERRINFO1 (0xF00628F4u)
+#define MTU_MC24_ERRINFO2 (0xF00628F6u)
+#define MTU_MC24_ERRINFO3 (0xF00628F8u)
+#define MTU_MC24_ERRINFO4 (0xF00628FAu)
+#define MTU_MC2_REVID (0xF006120Cu)
+#define MTU_MC25_CONFIG0 (0xF0062900u)
+#define MTU_MC25_CONFIG1 (0xF0062902u)
+#define MTU_MC25_MCONTROL (0xF0062904u)
+#define MTU_MC25_MSTATUS (0xF0062906u)
+#define MTU_MC25_RANGE (0xF0062908u)
+#define MTU_MC25_REVID (0xF006290Cu)
+#define MTU_MC25_ECCS (0xF006290Eu)
+#define MTU_MC25_ECCD (0xF0062910u)
+#define MTU_MC25_ETRR0 (0xF0062912u)
+#define MTU_MC25_ETRR1 (0xF0062914u)
+#define MTU_MC2_ECCS (0xF006120Eu)
+#define MTU_MC25_ETRR2 (0xF0062916u)
+#define MTU_MC25_ETRR3 (0xF0062918u)
+#define MTU_MC25_ETRR4 (0xF006291Au)
+#define MTU_MC25_RDBFL0 (0xF0062960u)
+#define MTU_MC25_RDBFL1 (0xF0062962u)
+#define MTU_MC25_RDBFL2 (0xF0062964u)
+#define MTU_MC25_RDBFL3 (0xF0062966u)
+#define MTU_MC25_RDBFL4 (0xF0062968u)
+#define MTU_MC25_RDBFL5 (0xF006296Au)
+#define MTU_MC25_RDBFL6 (0xF006296Cu)
+#define MTU_MC2_ECCD (0xF0061210u)
+#define MTU_MC25_RDBFL7 (0xF006296Eu)
+#define MTU_MC25_RDBFL8 (0xF0062970u)
+#define MTU_MC25_RDBFL9 (0xF0062972u)
+#define MTU_MC25_RDBFL10 (0xF0062974u)
+#define MTU_MC25_RDBFL11 (0xF0062976u)
+#define MTU_MC25_RDBFL12 (0xF0062978u)
+#define MTU_MC25_RDBFL13 (0xF006297Au)
+#define MTU_MC25_RDBFL14 (0xF006297Cu)
+#define MTU_MC25_RDBFL15 (0xF006297Eu)
+#define MTU_MC25_RDBFL16 (0xF0062980u)
+#define MTU_MC2_ETRR0 (0xF0061212u)
+#define MTU_MC25_RDBFL17 (0xF0062982u)
+#define MTU_MC25_RDBFL18 (0xF0062984u)
+#define MTU_MC25_RDBFL19 (0xF0062986u)
+#define MTU_MC25_RDBFL20 (0xF0062988u)
+#define MTU_MC25_RDBFL21 (0xF006298Au)
+#define MTU_MC25_RDBFL22 (0xF006298Cu)
+#define MTU_MC25_RDBFL23 (0xF006298Eu)
+#define MTU_MC25_RDBFL24 (0xF0062990u)
+#define MTU_MC25_RDBFL25 (0xF0062992u)
+#define MTU_MC25_RDBFL26 (0xF0062994u)
+#define MTU_MC2_ETRR1 (0xF0061214u)
+#define MTU_MC25_RDBFL27 (0xF0062996u)
+#define MTU_MC25_RDBFL28 (0xF0062998u)
+#define MTU_MC25_RDBFL29 (0xF006299Au)
+#define MTU_MC25_RDBFL30 (0xF006299Cu)
+#define MTU_MC25_RDBFL31 (0xF006299Eu)
+#define MTU_MC25_RDBFL32 (0xF00629A0u)
+#define MTU_MC25_RDBFL33 (0xF00629A2u)
+#define MTU_MC25_RDBFL34 (0xF00629A4u)
+#define MTU_MC25_RDBFL35 (0xF00629A6u)
+#define MTU_MC25_RDBFL36 (0xF00629A8u)
+#define MTU_MC2_ETRR2 (0xF0061216u)
+#define MODULE_MTU (0xF0060000u)
+#define MTU_MC25_RDBFL37 (0xF00629AAu)
+#define MTU_MC25_RDBFL38 (0xF00629ACu)
+#define MTU_MC25_RDBFL39 (0xF00629AEu)
+#define MTU_MC25_RDBFL40 (0xF00629B0u)
+#define MTU_MC25_RDBFL41 (0xF00629B2u)
+#define MTU_MC25_RDBFL42 (0xF00629B4u)
+#define MTU_MC25_RDBFL43 (0xF00629B6u)
+#define MTU_MC25_RDBFL44 (0xF00629B8u)
+#define MTU_MC25_RDBFL45 (0xF00629BAu)
+#define MTU_MC25_RDBFL46 (0xF00629BCu)
+#define MTU_MC2_ETRR3 (0xF0061218u)
+#define MTU_MC25_RDBFL47 (0xF00629BEu)
+#define MTU_MC25_RDBFL48 (0xF00629C0u)
+#define MTU_MC25_RDBFL49 (0xF00629C2u)
+#define MTU_MC25_RDBFL50 (0xF00629C4u)
+#define MTU_MC25_RDBFL51 (0xF00629C6u)
+#define MTU_MC25_RDBFL52 (0xF00629C8u)
+#define MTU_MC25_RDBFL53 (0xF00629CAu)
+#define MTU_MC25_RDBFL54 (0xF00629CCu)
+#define MTU_MC25_RDBFL55 (0xF00629CEu)
+#define MTU_MC25_RDBFL56 (0xF00629D0u)
+#define MTU_MC2_ETRR4 (0xF006121Au)
+#define MTU_MC25_RDBFL57 (0xF00629D2u)
+#define MTU_MC25_RDBFL58 (0xF00629D4u)
+#define MTU_MC25_RDBFL59 (0xF00629D6u)
+#define MTU_MC25_RDBFL60 (0xF00629D8u)
+#define MTU_MC25_RDBFL61 (0xF00629DAu)
+#define MTU_MC25_RDBFL62 (0xF00629DCu)
+#define MTU_MC25_RDBFL63 (0xF00629DEu)
+#define MTU_MC25_RDBFL64 (0xF00629E0u)
+#define MTU_MC25_RDBFL65 (0xF00629E2u)
+#define MTU_MC25_RDBFL66 (0xF00629E4u)
+#define MTU_MC2_RDBFL0 (0xF0061260u)
+#define MTU_MC25_ALMSRCS (0xF00629EEu)
+#define MTU_MC25_FAULTSTS (0xF00629F0u)
+#define MTU_MC25_ERRINFO0 (0xF00629F2u)
+#define MTU_MC25_ERRINFO1 (0xF00629F4u)
+#define MTU_MC25_ERRINFO2 (0xF00629F6u)
+#define MTU_MC25_ERRINFO3 (0xF00629F8u)
+#define MTU_MC25_ERRINFO4 (0xF00629FAu)
+#define MTU_MC26_CONFIG0 (0xF0062A00u)
+#define MTU_MC26_CONFIG1 (0xF0062A02u)
+#define MTU_MC26_MCONTROL (0xF0062A04u)
+#define MTU_MC2_RDBFL1 (0xF0061262u)
+#define MTU_MC26_MSTATUS (0xF0062A06u)
+#define MTU_MC26_RANGE (0xF0062A08u)
+#define MTU_MC26_REVID (0xF0062A0Cu)
+#define MTU_MC26_ECCS (0xF0062A0Eu)
+#define MTU_MC26_ECCD (0xF0062A10u)
+#define MTU_MC26_ETRR0 (0xF0062A12u)
+#define MTU_MC26_ETRR1 (0xF0062A14u)
+#define MTU_MC26_ETRR2 (0xF0062A16u)
+#define MTU_MC26_ETRR3 (0xF0062A18u)
+#define MTU_MC26_ETRR4 (0xF0062A1Au)
+#define MTU_MC2_RDBFL2 (0xF0061264u)
+#define MTU_MC26_RDBFL0 (0xF0062A60u)
+#define MTU_MC26_RDBFL1 (0xF0062A62u)
+#define MTU_MC26_RDBFL2 (0xF0062A64u)
+#define MTU_MC26_RDBFL3 (0xF0062A66u)
+#define MTU_MC26_RDBFL4 (0xF0062A68u)
+#define MTU_MC26_RDBFL5 (0xF0062A6Au)
+#define MTU_MC26_RDBFL6 (0xF0062A6Cu)
+#define MTU_MC26_RDBFL7 (0xF0062A6Eu)
+#define MTU_MC26_RDBFL8 (0xF0062A70u)
+#define MTU_MC26_RDBFL9 (0xF0062A72u)
+#define MTU_MC2_RDBFL3 (0xF0061266u)
+#define MTU_MC26_RDBFL10 (0xF0062A74u)
+#define MTU_MC26_RDBFL11 (0xF0062A76u)
+#define MTU_MC26_RDBFL12 (0xF0062A78u)
+#define MTU_MC26_RDBFL13 (0xF0062A7Au)
+#define MTU_MC26_RDBFL14 (0xF0062A7Cu)
+#define MTU_MC26_RDBFL15 (0xF0062A7Eu)
+#define MTU_MC26_RDBFL16 (0xF0062A80u)
+#define MTU_MC26_RDBFL17 (0xF0062A82u)
+#define MTU_MC26_RDBFL18 (0xF0062A84u)
+#define MTU_MC26_RDBFL19 (0xF0062A86u)
+#define MTU_MC2_RDBFL4 (0xF0061268u)
+#define MTU_MC26_RDBFL20 (0xF0062A88u)
+#define MTU_MC26_RDBFL21 (0xF0062A8Au)
+#define MTU_MC26_RDBFL22 (0xF0062A8Cu)
+#define MTU_MC26_RDBFL23 (0xF0062A8Eu)
+#define MTU_MC26_RDBFL24 (0xF0062A90u)
+#define MTU_MC26_RDBFL25 (0xF0062A92u)
+#define MTU_MC26_RDBFL26 (0xF0062A94u)
+#define MTU_MC26_RDBFL27 (0xF0062A96u)
+#define MTU_MC26_RDBFL28 (0xF0062A98u)
+#define MTU_MC26_RDBFL29 (0xF0062A9Au)
+#define MTU_MC2_RDBFL5 (0xF006126Au)
+#define MTU_MC26_RDBFL30 (0xF0062A9Cu)
+#define MTU_MC26_RDBFL31 (0xF0062A9Eu)
+#define MTU_MC26_RDBFL32 (0xF0062AA0u)
+#define MTU_MC26_RDBFL33 (0xF0062AA2u)
+#define MTU_MC26_RDBFL34 (0xF0062AA4u)
+#define MTU_MC26_RDBFL35 (0xF0062AA6u)
+#define MTU_MC26_RDBFL36 (0xF0062AA8u)
+#define MTU_MC26_RDBFL37 (0xF0062AAAu)
+#define MTU_MC26_RDBFL38 (0xF0062AACu)
+#define MTU_MC26_RDBFL39 (0xF0062AAEu)
+#define MTU_MC2_RDBFL6 (0xF006126Cu)
+#define MTU_MC26_RDBFL40 (0xF0062AB0u)
+#define MTU_MC26_RDBFL41 (0xF0062AB2u)
+#define MTU_MC26_RDBFL42 (0xF0062AB4u)
+#define MTU_MC26_RDBFL43 (0xF0062AB6u)
+#define MTU_MC26_RDBFL44 (0xF0062AB8u)
+#define MTU_MC26_RDBFL45 (0xF0062ABAu)
+#define MTU_MC26_RDBFL46 (0xF0062ABCu)
+#define MTU_MC26_RDBFL47 (0xF0062ABEu)
+#define MTU_MC26_RDBFL48 (0xF0062AC0u)
+#define MTU_MC26_RDBFL49 (0xF0062AC2u)
+#define MTU_MC2_RDBFL7 (0xF006126Eu)
+#define MTU_MC26_RDBFL50 (0xF0062AC4u)
+#define MTU_MC26_RDBFL51 (0xF0062AC6u)
+#define MTU_MC26_RDBFL52 (0xF0062AC8u)
+#define MTU_MC26_RDBFL53 (0xF0062ACAu)
+#define MTU_MC26_RDBFL54 (0xF0062ACCu)
+#define MTU_MC26_RDBFL55 (0xF0062ACEu)
+#define MTU_MC26_RDBFL56 (0xF0062AD0u)
+#define MTU_MC26_RDBFL57 (0xF0062AD2u)
+#define MTU_MC26_RDBFL58 (0xF0062AD4u)
+#define MTU_MC26_RDBFL59 (0xF0062AD6u)
+#define MTU_MC2_RDBFL8 (0xF0061270u)
+#define MTU_MC26_RDBFL60 (0xF0062AD8u)
+#define MTU_MC26_RDBFL61 (0xF0062ADAu)
+#define MTU_MC26_RDBFL62 (0xF0062ADCu)
+#define MTU_MC26_RDBFL63 (0xF0062ADEu)
+#define MTU_MC26_RDBFL64 (0xF0062AE0u)
+#define MTU_MC26_RDBFL65 (0xF0062AE2u)
+#define MTU_MC26_RDBFL66 (0xF0062AE4u)
+#define MTU_MC26_ALMSRCS (0xF0062AEEu)
+#define MTU_MC26_FAULTSTS (0xF0062AF0u)
+#define MTU_MC26_ERRINFO0 (0xF0062AF2u)
+#define MTU_MC2_RDBFL9 (0xF0061272u)
+#define MTU_MC26_ERRINFO1 (0xF0062AF4u)
+#define MTU_MC26_ERRINFO2 (0xF0062AF6u)
+#define MTU_MC26_ERRINFO3 (0xF0062AF8u)
+#define MTU_MC26_ERRINFO4 (0xF0062AFAu)
+#define MTU_MC27_CONFIG0 (0xF0062B00u)
+#define MTU_MC27_CONFIG1 (0xF0062B02u)
+#define MTU_MC27_MCONTROL (0xF0062B04u)
+#define MTU_MC27_MSTATUS (0xF0062B06u)
+#define MTU_MC27_RANGE (0xF0062B08u)
+#define MTU_MC27_REVID (0xF0062B0Cu)
+#define MTU_MC2_RDBFL10 (0xF0061274u)
+#define MTU_MC27_ECCS (0xF0062B0Eu)
+#define MTU_MC27_ECCD (0xF0062B10u)
+#define MTU_MC27_ETRR0 (0xF0062B12u)
+#define MTU_MC27_ETRR1 (0xF0062B14u)
+#define MTU_MC27_ETRR2 (0xF0062B16u)
+#define MTU_MC27_ETRR3 (0xF0062B18u)
+#define MTU_MC27_ETRR4 (0xF0062B1Au)
+#define MTU_MC27_RDBFL0 (0xF0062B60u)
+#define MTU_MC27_RDBFL1 (0xF0062B62u)
+#define MTU_MC27_RDBFL2 (0xF0062B64u)
+#define MTU_MC2_RDBFL11 (0xF0061276u)
+#define MTU_MC27_RDBFL3 (0xF0062B66u)
+#define MTU_MC27_RDBFL4 (0xF0062B68u)
+#define MTU_MC27_RDBFL5 (0xF0062B6Au)
+#define MTU_MC27_RDBFL6 (0xF0062B6Cu)
+#define MTU_MC27_RDBFL7 (0xF0062B6Eu)
+#define MTU_MC27_RDBFL8 (0xF0062B70u)
+#define MTU_MC27_RDBFL9 (0xF0062B72u)
+#define MTU_MC27_RDBFL10 (0xF0062B74u)
+#define MTU_MC27_RDBFL11 (0xF0062B76u)
+#define MTU_MC27_RDBFL12 (0xF0062B78u)
+#define MTU_MC2_RDBFL12 (0xF0061278u)
+#define MTU_MC27_RDBFL13 (0xF0062B7Au)
+#define MTU_MC27_RDBFL14 (0xF0062B7Cu)
+#define MTU_MC27_RDBFL15 (0xF0062B7Eu)
+#define MTU_MC27_RDBFL16 (0xF0062B80u)
+#define MTU_MC27_RDBFL17 (0xF0062B82u)
+#define MTU_MC27_RDBFL18 (0xF0062B84u)
+#define MTU_MC27_RDBFL19 (0xF0062B86u)
+#define MTU_MC27_RDBFL20 (0xF0062B88u)
+#define MTU_MC27_RDBFL21 (0xF0062B8Au)
+#define MTU_MC27_RDBFL22 (0xF0062B8Cu)
+#define MTU_MC2_RDBFL13 (0xF006127Au)
+#define MTU_MC27_RDBFL23 (0xF0062B8Eu)
+#define MTU_MC27_RDBFL24 (0xF0062B90u)
+#define MTU_MC27_RDBFL25 (0xF0062B92u)
+#define MTU_MC27_RDBFL26 (0xF0062B94u)
+#define MTU_MC27_RDBFL27 (0xF0062B96u)
+#define MTU_MC27_RDBFL28 (0xF0062B98u)
+#define MTU_MC27_RDBFL29 (0xF0062B9Au)
+#define MTU_MC27_RDBFL30 (0xF0062B9Cu)
+#define MTU_MC27_RDBFL31 (0xF0062B9Eu)
+#define MTU_MC27_RDBFL32 (0xF0062BA0u)
+#define MTU_MC2_RDBFL14 (0xF006127Cu)
+#define MTU_MC27_RDBFL33 (0xF0062BA2u)
+#define MTU_MC27_RDBFL34 (0xF0062BA4u)
+#define MTU_MC27_RDBFL35 (0xF0062BA6u)
+#define MTU_MC27_RDBFL36 (0xF0062BA8u)
+#define MTU_MC27_RDBFL37 (0xF0062BAAu)
+#define MTU_MC27_RDBFL38 (0xF0062BACu)
+#define MTU_MC27_RDBFL39 (0xF0062BAEu)
+#define MTU_MC27_RDBFL40 (0xF0062BB0u)
+#define MTU_MC27_RDBFL41 (0xF0062BB2u)
+#define MTU_MC27_RDBFL42 (0xF0062BB4u)
+#define MTU_MC2_RDBFL15 (0xF006127Eu)
+#define MTU_MC27_RDBFL43 (0xF0062BB6u)
+#define MTU_MC27_RDBFL44 (0xF0062BB8u)
+#define MTU_MC27_RDBFL45 (0xF0062BBAu)
+#define MTU_MC27_RDBFL46 (0xF0062BBCu)
+#define MTU_MC27_RDBFL47 (0xF0062BBEu)
+#define MTU_MC27_RDBFL48 (0xF0062BC0u)
+#define MTU_MC27_RDBFL49 (0xF0062BC2u)
+#define MTU_MC27_RDBFL50 (0xF0062BC4u)
+#define MTU_MC27_RDBFL51 (0xF0062BC6u)
+#define MTU_MC27_RDBFL52 (0xF0062BC8u)
+#define MTU_MC2_RDBFL16 (0xF0061280u)
+#define MTU_MC27_RDBFL53 (0xF0062BCAu)
+#define MTU_MC27_RDBFL54 (0xF0062BCCu)
+#define MTU_MC27_RDBFL55 (0xF0062BCEu)
+#define MTU_MC27_RDBFL56 (0xF0062BD0u)
+#define MTU_MC27_RDBFL57 (0xF0062BD2u)
+#define MTU_MC27_RDBFL58 (0xF0062BD4u)
+#define MTU_MC27_RDBFL59 (0xF0062BD6u)
+#define MTU_MC27_RDBFL60 (0xF0062BD8u)
+#define MTU_MC27_RDBFL61 (0xF0062BDAu)
+#define MTU_MC27_RDBFL62 (0xF0062BDCu)
+#define MTU_MC2_RDBFL17 (0xF0061282u)
+#define MTU_MC27_RDBFL63 (0xF0062BDEu)
+#define MTU_MC27_RDBFL64 (0xF0062BE0u)
+#define MTU_MC27_RDBFL65 (0xF0062BE2u)
+#define MTU_MC27_RDBFL66 (0xF0062BE4u)
+#define MTU_MC27_ALMSRCS (0xF0062BEEu)
+#define MTU_MC27_FAULTSTS (0xF0062BF0u)
+#define MTU_MC27_ERRINFO0 (0xF0062BF2u)
+#define MTU_MC27_ERRINFO1 (0xF0062BF4u)
+#define MTU_MC27_ERRINFO2 (0xF0062BF6u)
+#define MTU_MC27_ERRINFO3 (0xF0062BF8u)
+#define MTU_MC2_RDBFL18 (0xF0061284u)
+#define MTU_MC27_ERRINFO4 (0xF0062BFAu)
+#define MTU_MC28_CONFIG0 (0xF0062C00u)
+#define MTU_MC28_CONFIG1 (0xF0062C02u)
+#define MTU_MC28_MCONTROL (0xF0062C04u)
+#define MTU_MC28_MSTATUS (0xF0062C06u)
+#define MTU_MC28_RANGE (0xF0062C08u)
+#define MTU_MC28_REVID (0xF0062C0Cu)
+#define MTU_MC28_ECCS (0xF0062C0Eu)
+#define MTU_MC28_ECCD (0xF0062C10u)
+#define MTU_MC28_ETRR0 (0xF0062C12u)
+#define MTU_MC2_RDBFL19 (0xF0061286u)
+#define MTU_MC28_ETRR1 (0xF0062C14u)
+#define MTU_MC28_ETRR2 (0xF0062C16u)
+#define MTU_MC28_ETRR3 (0xF0062C18u)
+#define MTU_MC28_ETRR4 (0xF0062C1Au)
+#define MTU_MC28_RDBFL0 (0xF0062C60u)
+#define MTU_MC28_RDBFL1 (0xF0062C62u)
+#define MTU_MC28_RDBFL2 (0xF0062C64u)
+#define MTU_MC28_RDBFL3 (0xF0062C66u)
+#define MTU_MC28_RDBFL4 (0xF0062C68u)
+#define MTU_MC28_RDBFL5 (0xF0062C6Au)
+#define MTU_MC2_RDBFL20 (0xF0061288u)
+#define MTU_MC28_RDBFL6 (0xF0062C6Cu)
+#define MTU_MC28_RDBFL7 (0xF0062C6Eu)
+#define MTU_MC28_RDBFL8 (0xF0062C70u)
+#define MTU_MC28_RDBFL9 (0xF0062C72u)
+#define MTU_MC28_RDBFL10 (0xF0062C74u)
+#define MTU_MC28_RDBFL11 (0xF0062C76u)
+#define MTU_MC28_RDBFL12 (0xF0062C78u)
+#define MTU_MC28_RDBFL13 (0xF0062C7Au)
+#define MTU_MC28_RDBFL14 (0xF0062C7Cu)
+#define MTU_MC28_RDBFL15 (0xF0062C7Eu)
+#define MTU_MC2_RDBFL21 (0xF006128Au)
+#define MTU_MC28_RDBFL16 (0xF0062C80u)
+#define MTU_MC28_RDBFL17 (0xF0062C82u)
+#define MTU_MC28_RDBFL18 (0xF0062C84u)
+#define MTU_MC28_RDBFL19 (0xF0062C86u)
+#define MTU_MC28_RDBFL20 (0xF0062C88u)
+#define MTU_MC28_RDBFL21 (0xF0062C8Au)
+#define MTU_MC28_RDBFL22 (0xF0062C8Cu)
+#define MTU_MC28_RDBFL23 (0xF0062C8Eu)
+#define MTU_MC28_RDBFL24 (0xF0062C90u)
+#define MTU_MC28_RDBFL25 (0xF0062C92u)
+#define MTU_MC2_RDBFL22 (0xF006128Cu)
+#define MTU_MC28_RDBFL26 (0xF0062C94u)
+#define MTU_MC28_RDBFL27 (0xF0062C96u)
+#define MTU_MC28_RDBFL28 (0xF0062C98u)
+#define MTU_MC28_RDBFL29 (0xF0062C9Au)
+#define MTU_MC28_RDBFL30 (0xF0062C9Cu)
+#define MTU_MC28_RDBFL31 (0xF0062C9Eu)
+#define MTU_MC28_RDBFL32 (0xF0062CA0u)
+#define MTU_MC28_RDBFL33 (0xF0062CA2u)
+#define MTU_MC28_RDBFL34 (0xF0062CA4u)
+#define MTU_MC28_RDBFL35 (0xF0062CA6u)
+#define MTU_MC2_RDBFL23 (0xF006128Eu)
+#define MTU_MC28_RDBFL36 (0xF0062CA8u)
+#define MTU_MC28_RDBFL37 (0xF0062CAAu)
+#define MTU_MC28_RDBFL38 (0xF0062CACu)
+#define MTU_MC28_RDBFL39 (0xF0062CAEu)
+#define MTU_MC28_RDBFL40 (0xF0062CB0u)
+#define MTU_MC28_RDBFL41 (0xF0062CB2u)
+#define MTU_MC28_RDBFL42 (0xF0062CB4u)
+#define MTU_MC28_RDBFL43 (0xF0062CB6u)
+#define MTU_MC28_RDBFL44 (0xF0062CB8u)
+#define MTU_MC28_RDBFL45 (0xF0062CBAu)
+#define MTU_MC2_RDBFL24 (0xF0061290u)
+#define MTU_MC28_RDBFL46 (0xF0062CBCu)
+#define MTU_MC28_RDBFL47 (0xF0062CBEu)
+#define MTU_MC28_RDBFL48 (0xF0062CC0u)
+#define MTU_MC28_RDBFL49 (0xF0062CC2u)
+#define MTU_MC28_RDBFL50 (0xF0062CC4u)
+#define MTU_MC28_RDBFL51 (0xF0062CC6u)
+#define MTU_MC28_RDBFL52 (0xF0062CC8u)
+#define MTU_MC28_RDBFL53 (0xF0062CCAu)
+#define MTU_MC28_RDBFL54 (0xF0062CCCu)
+#define MTU_MC28_RDBFL55 (0xF0062CCEu)
+#define MTU_MC2_RDBFL25 (0xF0061292u)
+#define MTU_MC28_RDBFL56 (0xF0062CD0u)
+#define MTU_MC28_RDBFL57 (0xF0062CD2u)
+#define MTU_MC28_RDBFL58 (0xF0062CD4u)
+#define MTU_MC28_RDBFL59 (0xF0062CD6u)
+#define MTU_MC28_RDBFL60 (0xF0062CD8u)
+#define MTU_MC28_RDBFL61 (0xF0062CDAu)
+#define MTU_MC28_RDBFL62 (0xF0062CDCu)
+#define MTU_MC28_RDBFL63 (0xF0062CDEu)
+#define MTU_MC28_RDBFL64 (0xF0062CE0u)
+#define MTU_MC28_RDBFL65 (0xF0062CE2u)
+#define MTU_MC2_RDBFL26 (0xF0061294u)
+#define MTU_MC28_RDBFL66 (0xF0062CE4u)
+#define MTU_MC28_ALMSRCS (0xF0062CEEu)
+#define MTU_MC28_FAULTSTS (0xF0062CF0u)
+#define MTU_MC28_ERRINFO0 (0xF0062CF2u)
+#define MTU_MC28_ERRINFO1 (0xF0062CF4u)
+#define MTU_MC28_ERRINFO2 (0xF0062CF6u)
+#define MTU_MC28_ERRINFO3 (0xF0062CF8u)
+#define MTU_MC28_ERRINFO4 (0xF0062CFAu)
+#define MTU_MC29_CONFIG0 (0xF0062D00u)
+#define MTU_MC29_CONFIG1 (0xF0062D02u)
+#define MTU_MC2_RDBFL27 (0xF0061296u)
+#define MTU_CLC (0xF0060000u)
+#define MTU_MC29_MCONTROL (0xF0062D04u)
+#define MTU_MC29_MSTATUS (0xF0062D06u)
+#define MTU_MC29_RANGE (0xF0062D08u)
+#define MTU_MC29_REVID (0xF0062D0Cu)
+#define MTU_MC29_ECCS (0xF0062D0Eu)
+#define MTU_MC29_ECCD (0xF0062D10u)
+#define MTU_MC29_ETRR0 (0xF0062D12u)
+#define MTU_MC29_ETRR1 (0xF0062D14u)
+#define MTU_MC29_ETRR2 (0xF0062D16u)
+#define MTU_MC29_ETRR3 (0xF0062D18u)
+#define MTU_MC2_RDBFL28 (0xF0061298u)
+#define MTU_MC29_ETRR4 (0xF0062D1Au)
+#define MTU_MC29_RDBFL0 (0xF0062D60u)
+#define MTU_MC29_RDBFL1 (0xF0062D62u)
+#define MTU_MC29_RDBFL2 (0xF0062D64u)
+#define MTU_MC29_RDBFL3 (0xF0062D66u)
+#define MTU_MC29_RDBFL4 (0xF0062D68u)
+#define MTU_MC29_RDBFL5 (0xF0062D6Au)
+#define MTU_MC29_RDBFL6 (0xF0062D6Cu)
+#define MTU_MC29_RDBFL7 (0xF0062D6Eu)
+#define MTU_MC29_RDBFL8 (0xF0062D70u)
+#define MTU_MC2_RDBFL29 (0xF006129Au)
+#define MTU_MC29_RDBFL9 (0xF0062D72u)
+#define MTU_MC29_RDBFL10 (0xF0062D74u)
+#define MTU_MC29_RDBFL11 (0xF0062D76u)
+#define MTU_MC29_RDBFL12 (0xF0062D78u)
+#define MTU_MC29_RDBFL13 (0xF0062D7Au)
+#define MTU_MC29_RDBFL14 (0xF0062D7Cu)
+#define MTU_MC29_RDBFL15 (0xF0062D7Eu)
+#define MTU_MC29_RDBFL16 (0xF0062D80u)
+#define MTU_MC29_RDBFL17 (0xF0062D82u)
+#define MTU_MC29_RDBFL18 (0xF0062D84u)
+#define MTU_MC2_RDBFL30 (0xF006129Cu)
+#define MTU_MC29_RDBFL19 (0xF0062D86u)
+#define MTU_MC29_RDBFL20 (0xF0062D88u)
+#define MTU_MC29_RDBFL21 (0xF0062D8Au)
+#define MTU_MC29_RDBFL22 (0xF0062D8Cu)
+#define MTU_MC29_RDBFL23 (0xF0062D8Eu)
+#define MTU_MC29_RDBFL24 (0xF0062D90u)
+#define MTU_MC29_RDBFL25 (0xF0062D92u)
+#define MTU_MC29_RDBFL26 (0xF0062D94u)
+#define MTU_MC29_RDBFL27 (0xF0062D96u)
+#define MTU_MC29_RDBFL28 (0xF0062D98u)
+#define MTU_MC2_RDBFL31 (0xF006129Eu)
+#define MTU_MC29_RDBFL29 (0xF0062D9Au)
+#define MTU_MC29_RDBFL30 (0xF0062D9Cu)
+#define MTU_MC29_RDBFL31 (0xF0062D9Eu)
+#define MTU_MC29_RDBFL32 (0xF0062DA0u)
+#define MTU_MC29_RDBFL33 (0xF0062DA2u)
+#define MTU_MC29_RDBFL34 (0xF0062DA4u)
+#define MTU_MC29_RDBFL35 (0xF0062DA6u)
+#define MTU_MC29_RDBFL36 (0xF0062DA8u)
+#define MTU_MC29_RDBFL37 (0xF0062DAAu)
+#define MTU_MC29_RDBFL38 (0xF0062DACu)
+#define MTU_MC2_RDBFL32 (0xF00612A0u)
+#define MTU_MC29_RDBFL39 (0xF0062DAEu)
+#define MTU_MC29_RDBFL40 (0xF0062DB0u)
+#define MTU_MC29_RDBFL41 (0xF0062DB2u)
+#define MTU_MC29_RDBFL42 (0xF0062DB4u)
+#define MTU_MC29_RDBFL43 (0xF0062DB6u)
+#define MTU_MC29_RDBFL44 (0xF0062DB8u)
+#define MTU_MC29_RDBFL45 (0xF0062DBAu)
+#define MTU_MC29_RDBFL46 (0xF0062DBCu)
+#define MTU_MC29_RDBFL47 (0xF0062DBEu)
+#define MTU_MC29_RDBFL48 (0xF0062DC0u)
+#define MTU_MC2_RDBFL33 (0xF00612A2u)
+#define MTU_MC29_RDBFL49 (0xF0062DC2u)
+#define MTU_MC29_RDBFL50 (0xF0062DC4u)
+#define MTU_MC29_RDBFL51 (0xF0062DC6u)
+#define MTU_MC29_RDBFL52 (0xF0062DC8u)
+#define MTU_MC29_RDBFL53 (0xF0062DCAu)
+#define MTU_MC29_RDBFL54 (0xF0062DCCu)
+#define MTU_MC29_RDBFL55 (0xF0062DCEu)
+#define MTU_MC29_RDBFL56 (0xF0062DD0u)
+#define MTU_MC29_RDBFL57 (0xF0062DD2u)
+#define MTU_MC29_RDBFL58 (0xF0062DD4u)
+#define MTU_MC2_RDBFL34 (0xF00612A4u)
+#define MTU_MC29_RDBFL59 (0xF0062DD6u)
+#define MTU_MC29_RDBFL60 (0xF0062DD8u)
+#define MTU_MC29_RDBFL61 (0xF0062DDAu)
+#define MTU_MC29_RDBFL62 (0xF0062DDCu)
+#define MTU_MC29_RDBFL63 (0xF0062DDEu)
+#define MTU_MC29_RDBFL64 (0xF0062DE0u)
+#define MTU_MC29_RDBFL65 (0xF0062DE2u)
+#define MTU_MC29_RDBFL66 (0xF0062DE4u)
+#define MTU_MC29_ALMSRCS (0xF0062DEEu)
+#define MTU_MC29_FAULTSTS (0xF0062DF0u)
+#define MTU_MC2_RDBFL35 (0xF00612A6u)
+#define MTU_MC29_ERRINFO0 (0xF0062DF2u)
+#define MTU_MC29_ERRINFO1 (0xF0062DF4u)
+#define MTU_MC29_ERRINFO2 (0xF0062DF6u)
+#define MTU_MC29_ERRINFO3 (0xF0062DF8u)
+#define MTU_MC29_ERRINFO4 (0xF0062DFAu)
+#define MTU_MC30_CONFIG0 (0xF0062E00u)
+#define MTU_MC30_CONFIG1 (0xF0062E02u)
+#define MTU_MC30_MCONTROL (0xF0062E04u)
+#define MTU_MC30_MSTATUS (0xF0062E06u)
+#define MTU_MC30_RANGE (0xF0062E08u)
+#define MTU_MC2_RDBFL36 (0xF00612A8u)
+#define MTU_MC30_REVID (0xF0062E0Cu)
+#define MTU_MC30_ECCS (0xF0062E0Eu)
+#define MTU_MC30_ECCD (0xF0062E10u)
+#define MTU_MC30_ETRR0 (0xF0062E12u)
+#define MTU_MC30_ETRR1 (0xF0062E14u)
+#define MTU_MC30_ETRR2 (0xF0062E16u)
+#define MTU_MC30_ETRR3 (0xF0062E18u)
+#define MTU_MC30_ETRR4 (0xF0062E1Au)
+#define MTU_MC30_RDBFL0 (0xF0062E60u)
+#define MTU_MC30_RDBFL1 (0xF0062E62u)
+#define MTU_MC2_RDBFL37 (0xF00612AAu)
+#define MTU_ID (0xF0060008u)
+#define MTU_MC30_RDBFL2 (0xF0062E64u)
+#define MTU_MC30_RDBFL3 (0xF0062E66u)
+#define MTU_MC30_RDBFL4 (0xF0062E68u)
+#define MTU_MC30_RDBFL5 (0xF0062E6Au)
+#define MTU_MC30_RDBFL6 (0xF0062E6Cu)
+#define MTU_MC30_RDBFL7 (0xF0062E6Eu)
+#define MTU_MC30_RDBFL8 (0xF0062E70u)
+#define MTU_MC30_RDBFL9 (0xF0062E72u)
+#define MTU_MC30_RDBFL10 (0xF0062E74u)
+#define MTU_MC30_RDBFL11 (0xF0062E76u)
+#define MTU_MC2_RDBFL38 (0xF00612ACu)
+#define MTU_MC30_RDBFL12 (0xF0062E78u)
+#define MTU_MC30_RDBFL13 (0xF0062E7Au)
+#define MTU_MC30_RDBFL14 (0xF0062E7Cu)
+#define MTU_MC30_RDBFL15 (0xF0062E7Eu)
+#define MTU_MC30_RDBFL16 (0xF0062E80u)
+#define MTU_MC30_RDBFL17 (0xF0062E82u)
+#define MTU_MC30_RDBFL18 (0xF0062E84u)
+#define MTU_MC30_RDBFL19 (0xF0062E86u)
+#define MTU_MC30_RDBFL20 (0xF0062E88u)
+#define MTU_MC30_RDBFL21 (0xF0062E8Au)
+#define MTU_MC2_RDBFL39 (0xF00612AEu)
+#define MTU_MC30_RDBFL22 (0xF0062E8Cu)
+#define MTU_MC30_RDBFL23 (0xF0062E8Eu)
+#define MTU_MC30_RDBFL24 (0xF0062E90u)
+#define MTU_MC30_RDBFL25 (0xF0062E92u)
+#define MTU_MC30_RDBFL26 (0xF0062E94u)
+#define MTU_MC30_RDBFL27 (0xF0062E96u)
+#define MTU_MC30_RDBFL28 (0xF0062E98u)
+#define MTU_MC30_RDBFL29 (0xF0062E9Au)
+#define MTU_MC30_RDBFL30 (0xF0062E9Cu)
+#define MTU_MC30_RDBFL31 (0xF0062E9Eu)
+#define MTU_MC2_RDBFL40 (0xF00612B0u)
+#define MTU_MC30_RDBFL32 (0xF0062EA0u)
+#define MTU_MC30_RDBFL33 (0xF0062EA2u)
+#define MTU_MC30_RDBFL34 (0xF0062EA4u)
+#define MTU_MC30_RDBFL35 (0xF0062EA6u)
+#define MTU_MC30_RDBFL36 (0xF0062EA8u)
+#define MTU_MC30_RDBFL37 (0xF0062EAAu)
+#define MTU_MC30_RDBFL38 (0xF0062EACu)
+#define MTU_MC30_RDBFL39 (0xF0062EAEu)
+#define MTU_MC30_RDBFL40 (0xF0062EB0u)
+#define MTU_MC30_RDBFL41 (0xF0062EB2u)
+#define MTU_MC2_RDBFL41 (0xF00612B2u)
+#define MTU_MC30_RDBFL42 (0xF0062EB4u)
+#define MTU_MC30_RDBFL43 (0xF0062EB6u)
+#define MTU_MC30_RDBFL44 (0xF0062EB8u)
+#define MTU_MC30_RDBFL45 (0xF0062EBAu)
+#define MTU_MC30_RDBFL46 (0xF0062EBCu)
+#define MTU_MC30_RDBFL47 (0xF0062EBEu)
+#define MTU_MC30_RDBFL48 (0xF0062EC0u)
+#define MTU_MC30_RDBFL49 (0xF0062EC2u)
+#define MTU_MC30_RDBFL50 (0xF0062EC4u)
+#define MTU_MC30_RDBFL51 (0xF0062EC6u)
+#define MTU_MC2_RDBFL42 (0xF00612B4u)
+#define MTU_MC30_RDBFL52 (0xF0062EC8u)
+#define MTU_MC30_RDBFL53 (0xF0062ECAu)
+#define MTU_MC30_RDBFL54 (0xF0062ECCu)
+#define MTU_MC30_RDBFL55 (0xF0062ECEu)
+#define MTU_MC30_RDBFL56 (0xF0062ED0u)
+#define MTU_MC30_RDBFL57 (0xF0062ED2u)
+#define MTU_MC30_RDBFL58 (0xF0062ED4u)
+#define MTU_MC30_RDBFL59 (0xF0062ED6u)
+#define MTU_MC30_RDBFL60 (0xF0062ED8u)
+#define MTU_MC30_RDBFL61 (0xF0062EDAu)
+#define MTU_MC2_RDBFL43 (0xF00612B6u)
+#define MTU_MC30_RDBFL62 (0xF0062EDCu)
+#define MTU_MC30_RDBFL63 (0xF0062EDEu)
+#define MTU_MC30_RDBFL64 (0xF0062EE0u)
+#define MTU_MC30_RDBFL65 (0xF0062EE2u)
+#define MTU_MC30_RDBFL66 (0xF0062EE4u)
+#define MTU_MC30_ALMSRCS (0xF0062EEEu)
+#define MTU_MC30_FAULTSTS (0xF0062EF0u)
+#define MTU_MC30_ERRINFO0 (0xF0062EF2u)
+#define MTU_MC30_ERRINFO1 (0xF0062EF4u)
+#define MTU_MC30_ERRINFO2 (0xF0062EF6u)
+#define MTU_MC2_RDBFL44 (0xF00612B8u)
+#define MTU_MC30_ERRINFO3 (0xF0062EF8u)
+#define MTU_MC30_ERRINFO4 (0xF0062EFAu)
+#define MTU_MC31_CONFIG0 (0xF0062F00u)
+#define MTU_MC31_CONFIG1 (0xF0062F02u)
+#define MTU_MC31_MCONTROL (0xF0062F04u)
+#define MTU_MC31_MSTATUS (0xF0062F06u)
+#define MTU_MC31_RANGE (0xF0062F08u)
+#define MTU_MC31_REVID (0xF0062F0Cu)
+#define MTU_MC31_ECCS (0xF0062F0Eu)
+#define MTU_MC31_ECCD (0xF0062F10u)
+#define MTU_MC2_RDBFL45 (0xF00612BAu)
+#define MTU_MC31_ETRR0 (0xF0062F12u)
+#define MTU_MC31_ETRR1 (0xF0062F14u)
+#define MTU_MC31_ETRR2 (0xF0062F16u)
+#define MTU_MC31_ETRR3 (0xF0062F18u)
+#define MTU_MC31_ETRR4 (0xF0062F1Au)
+#define MTU_MC31_RDBFL0 (0xF0062F60u)
+#define MTU_MC31_RDBFL1 (0xF0062F62u)
+#define MTU_MC31_RDBFL2 (0xF0062F64u)
+#define MTU_MC31_RDBFL3 (0xF0062F66u)
+#define MTU_MC31_RDBFL4 (0xF0062F68u)
+#define MTU_MC2_RDBFL46 (0xF00612BCu)
+#define MTU_MC31_RDBFL5 (0xF0062F6Au)
+#define MTU_MC31_RDBFL6 (0xF0062F6Cu)
+#define MTU_MC31_RDBFL7 (0xF0062F6Eu)
+#define MTU_MC31_RDBFL8 (0xF0062F70u)
+#define MTU_MC31_RDBFL9 (0xF0062F72u)
+#define MTU_MC31_RDBFL10 (0xF0062F74u)
+#define MTU_MC31_RDBFL11 (0xF0062F76u)
+#define MTU_MC31_RDBFL12 (0xF0062F78u)
+#define MTU_MC31_RDBFL13 (0xF0062F7Au)
+#define MTU_MC31_RDBFL14 (0xF0062F7Cu)
+#define MTU_MC2_RDBFL47 (0xF00612BEu)
+#define MTU_MEMTEST0 (0xF0060010u)
+#define MTU_MC31_RDBFL15 (0xF0062F7Eu)
+#define MTU_MC31_RDBFL16 (0xF0062F80u)
+#define MTU_MC31_RDBFL17 (0xF0062F82u)
+#define MTU_MC31_RDBFL18 (0xF0062F84u)
+#define MTU_MC31_RDBFL19 (0xF0062F86u)
+#define MTU_MC31_RDBFL20 (0xF0062F88u)
+#define MTU_MC31_RDBFL21 (0xF0062F8Au)
+#define MTU_MC31_RDBFL22 (0xF0062F8Cu)
+#define MTU_MC31_RDBFL23 (0xF0062F8Eu)
+#define MTU_MC31_RDBFL24 (0xF0062F90u)
+#define MTU_MC2_RDBFL48 (0xF00612C0u)
+#define MTU_MC31_RDBFL25 (0xF0062F92u)
+#define MTU_MC31_RDBFL26 (0xF0062F94u)
+#define MTU_MC31_RDBFL27 (0xF0062F96u)
+#define MTU_MC31_RDBFL28 (0xF0062F98u)
+#define MTU_MC31_RDBFL29 (0xF0062F9Au)
+#define MTU_MC31_RDBFL30 (0xF0062F9Cu)
+#define MTU_MC31_RDBFL31 (0xF0062F9Eu)
+#define MTU_MC31_RDBFL32 (0xF0062FA0u)
+#define MTU_MC31_RDBFL33 (0xF0062FA2u)
+#define MTU_MC31_RDBFL34 (0xF0062FA4u)
+#define MTU_MC2_RDBFL49 (0xF00612C2u)
+#define MTU_MC31_RDBFL35 (0xF0062FA6u)
+#define MTU_MC31_RDBFL36 (0xF0062FA8u)
+#define MTU_MC31_RDBFL37 (0xF0062FAAu)
+#define MTU_MC31_RDBFL38 (0xF0062FACu)
+#define MTU_MC31_RDBFL39 (0xF0062FAEu)
+#define MTU_MC31_RDBFL40 (0xF0062FB0u)
+#define MTU_MC31_RDBFL41 (0xF0062FB2u)
+#define MTU_MC31_RDBFL42 (0xF0062FB4u)
+#define MTU_MC31_RDBFL43 (0xF0062FB6u)
+#define MTU_MC31_RDBFL44 (0xF0062FB8u)
+#define MTU_MC2_RDBFL50 (0xF00612C4u)
+#define MTU_MC31_RDBFL45 (0xF0062FBAu)
+#define MTU_MC31_RDBFL46 (0xF0062FBCu)
+#define MTU_MC31_RDBFL47 (0xF0062FBEu)
+#define MTU_MC31_RDBFL48 (0xF0062FC0u)
+#define MTU_MC31_RDBFL49 (0xF0062FC2u)
+#define MTU_MC31_RDBFL50 (0xF0062FC4u)
+#define MTU_MC31_RDBFL51 (0xF0062FC6u)
+#define MTU_MC31_RDBFL52 (0xF0062FC8u)
+#define MTU_MC31_RDBFL53 (0xF0062FCAu)
+#define MTU_MC31_RDBFL54 (0xF0062FCCu)
+#define MTU_MC2_RDBFL51 (0xF00612C6u)
+#define MTU_MC31_RDBFL55 (0xF0062FCEu)
+#define MTU_MC31_RDBFL56 (0xF0062FD0u)
+#define MTU_MC31_RDBFL57 (0xF0062FD2u)
+#define MTU_MC31_RDBFL58 (0xF0062FD4u)
+#define MTU_MC31_RDBFL59 (0xF0062FD6u)
+#define MTU_MC31_RDBFL60 (0xF0062FD8u)
+#define MTU_MC31_RDBFL61 (0xF0062FDAu)
+#define MTU_MC31_RDBFL62 (0xF0062FDCu)
+#define MTU_MC31_RDBFL63 (0xF0062FDEu)
+#define MTU_MC31_RDBFL64 (0xF0062FE0u)
+#define MTU_MC2_RDBFL52 (0xF00612C8u)
+#define MTU_MC31_RDBFL65 (0xF0062FE2u)
+#define MTU_MC31_RDBFL66 (0xF0062FE4u)
+#define MTU_MC31_ALMSRCS (0xF0062FEEu)
+#define MTU_MC31_FAULTSTS (0xF0062FF0u)
+#define MTU_MC31_ERRINFO0 (0xF0062FF2u)
+#define MTU_MC31_ERRINFO1 (0xF0062FF4u)
+#define MTU_MC31_ERRINFO2 (0xF0062FF6u)
+#define MTU_MC31_ERRINFO3 (0xF0062FF8u)
+#define MTU_MC31_ERRINFO4 (0xF0062FFAu)
+#define MTU_MC32_CONFIG0 (0xF0063000u)
+#define MTU_MC2_RDBFL53 (0xF00612CAu)
+#define MTU_MC32_CONFIG1 (0xF0063002u)
+#define MTU_MC32_MCONTROL (0xF0063004u)
+#define MTU_MC32_MSTATUS (0xF0063006u)
+#define MTU_MC32_RANGE (0xF0063008u)
+#define MTU_MC32_REVID (0xF006300Cu)
+#define MTU_MC32_ECCS (0xF006300Eu)
+#define MTU_MC32_ECCD (0xF0063010u)
+#define MTU_MC32_ETRR0 (0xF0063012u)
+#define MTU_MC32_ETRR1 (0xF0063014u)
+#define MTU_MC32_ETRR2 (0xF0063016u)
+#define MTU_MC2_RDBFL54 (0xF00612CCu)
+#define MTU_MC32_ETRR3 (0xF0063018u)
+#define MTU_MC32_ETRR4 (0xF006301Au)
+#define MTU_MC32_RDBFL0 (0xF0063060u)
+#define MTU_MC32_RDBFL1 (0xF0063062u)
+#define MTU_MC32_RDBFL2 (0xF0063064u)
+#define MTU_MC32_RDBFL3 (0xF0063066u)
+#define MTU_MC32_RDBFL4 (0xF0063068u)
+#define MTU_MC32_RDBFL5 (0xF006306Au)
+#define MTU_MC32_RDBFL6 (0xF006306Cu)
+#define MTU_MC32_RDBFL7 (0xF006306Eu)
+#define MTU_MC2_RDBFL55 (0xF00612CEu)
+#define MTU_MC32_RDBFL8 (0xF0063070u)
+#define MTU_MC32_RDBFL9 (0xF0063072u)
+#define MTU_MC32_RDBFL10 (0xF0063074u)
+#define MTU_MC32_RDBFL11 (0xF0063076u)
+#define MTU_MC32_RDBFL12 (0xF0063078u)
+#define MTU_MC32_RDBFL13 (0xF006307Au)
+#define MTU_MC32_RDBFL14 (0xF006307Cu)
+#define MTU_MC32_RDBFL15 (0xF006307Eu)
+#define MTU_MC32_RDBFL16 (0xF0063080u)
+#define MTU_MC32_RDBFL17 (0xF0063082u)
+#define MTU_MC2_RDBFL56 (0xF00612D0u)
+#define MTU_MC32_RDBFL18 (0xF0063084u)
+#define MTU_MC32_RDBFL19 (0xF0063086u)
+#define MTU_MC32_RDBFL20 (0xF0063088u)
+#define MTU_MC32_RDBFL21 (0xF006308Au)
+#define MTU_MC32_RDBFL22 (0xF006308Cu)
+#define MTU_MC32_RDBFL23 (0xF006308Eu)
+#define MTU_MC32_RDBFL24 (0xF0063090u)
+#define MTU_MC32_RDBFL25 (0xF0063092u)
+#define MTU_MC32_RDBFL26 (0xF0063094u)
+#define MTU_MC32_RDBFL27 (0xF0063096u)
+#define MTU_MC2_RDBFL57 (0xF00612D2u)
+#define MTU_MEMTEST1 (0xF0060014u)
+#define MTU_MC32_RDBFL28 (0xF0063098u)
+#define MTU_MC32_RDBFL29 (0xF006309Au)
+#define MTU_MC32_RDBFL30 (0xF006309Cu)
+#define MTU_MC32_RDBFL31 (0xF006309Eu)
+#define MTU_MC32_RDBFL32 (0xF00630A0u)
+#define MTU_MC32_RDBFL33 (0xF00630A2u)
+#define MTU_MC32_RDBFL34 (0xF00630A4u)
+#define MTU_MC32_RDBFL35 (0xF00630A6u)
+#define MTU_MC32_RDBFL36 (0xF00630A8u)
+#define MTU_MC32_RDBFL37 (0xF00630AAu)
+#define MTU_MC2_RDBFL58 (0xF00612D4u)
+#define MTU_MC32_RDBFL38 (0xF00630ACu)
+#define MTU_MC32_RDBFL39 (0xF00630AEu)
+#define MTU_MC32_RDBFL40 (0xF00630B0u)
+#define MTU_MC32_RDBFL41 (0xF00630B2u)
+#define MTU_MC32_RDBFL42 (0xF00630B4u)
+#define MTU_MC32_RDBFL43 (0xF00630B6u)
+#define MTU_MC32_RDBFL44 (0xF00630B8u)
+#define MTU_MC32_RDBFL45 (0xF00630BAu)
+#define MTU_MC32_RDBFL46 (0xF00630BCu)
+#define MTU_MC32_RDBFL47 (0xF00630BEu)
+#define MTU_MC2_RDBFL59 (0xF00612D6u)
+#define MTU_MC32_RDBFL48 (0xF00630C0u)
+#define MTU_MC32_RDBFL49 (0xF00630C2u)
+#define MTU_MC32_RDBFL50 (0xF00630C4u)
+#define MTU_MC32_RDBFL51 (0xF00630C6u)
+#define MTU_MC32_RDBFL52 (0xF00630C8u)
+#define MTU_MC32_RDBFL53 (0xF00630CAu)
+#define MTU_MC32_RDBFL54 (0xF00630CCu)
+#define MTU_MC32_RDBFL55 (0xF00630CEu)
+#define MTU_MC32_RDBFL56 (0xF00630D0u)
+#define MTU_MC32_RDBFL57 (0xF00630D2u)
+#define MTU_MC2_RDBFL60 (0xF00612D8u)
+#define MTU_MC32_RDBFL58 (0xF00630D4u)
+#define MTU_MC32_RDBFL59 (0xF00630D6u)
+#define MTU_MC32_RDBFL60 (0xF00630D8u)
+#define MTU_MC32_RDBFL61 (0xF00630DAu)
+#define MTU_MC32_RDBFL62 (0xF00630DCu)
+#define MTU_MC32_RDBFL63 (0xF00630DEu)
+#define MTU_MC32_RDBFL64 (0xF00630E0u)
+#define MTU_MC32_RDBFL65 (0xF00630E2u)
+#define MTU_MC32_RDBFL66 (0xF00630E4u)
+#define MTU_MC32_ALMSRCS (0xF00630EEu)
+#define MTU_MC2_RDBFL61 (0xF00612DAu)
+#define MTU_MC32_FAULTSTS (0xF00630F0u)
+#define MTU_MC32_ERRINFO0 (0xF00630F2u)
+#define MTU_MC32_ERRINFO1 (0xF00630F4u)
+#define MTU_MC32_ERRINFO2 (0xF00630F6u)
+#define MTU_MC32_ERRINFO3 (0xF00630F8u)
+#define MTU_MC32_ERRINFO4 (0xF00630FAu)
+#define MTU_MC33_CONFIG0 (0xF0063100u)
+#define MTU_MC33_CONFIG1 (0xF0063102u)
+#define MTU_MC33_MCONTROL (0xF0063104u)
+#define MTU_MC33_MSTATUS (0xF0063106u)
+#define MTU_MC2_RDBFL62 (0xF00612DCu)
+#define MTU_MC33_RANGE (0xF0063108u)
+#define MTU_MC33_REVID (0xF006310Cu)
+#define MTU_MC33_ECCS (0xF006310Eu)
+#define MTU_MC33_ECCD (0xF0063110u)
+#define MTU_MC33_ETRR0 (0xF0063112u)
+#define MTU_MC33_ETRR1 (0xF0063114u)
+#define MTU_MC33_ETRR2 (0xF0063116u)
+#define MTU_MC33_ETRR3 (0xF0063118u)
+#define MTU_MC33_ETRR4 (0xF006311Au)
+#define MTU_MC33_RDBFL0 (0xF0063160u)
+#define MTU_MC2_RDBFL63 (0xF00612DEu)
+#define MTU_MC33_RDBFL1 (0xF0063162u)
+#define MTU_MC33_RDBFL2 (0xF0063164u)
+#define MTU_MC33_RDBFL3 (0xF0063166u)
+#define MTU_MC33_RDBFL4 (0xF0063168u)
+#define MTU_MC33_RDBFL5 (0xF006316Au)
+#define MTU_MC33_RDBFL6 (0xF006316Cu)
+#define MTU_MC33_RDBFL7 (0xF006316Eu)
+#define MTU_MC33_RDBFL8 (0xF0063170u)
+#define MTU_MC33_RDBFL9 (0xF0063172u)
+#define MTU_MC33_RDBFL10 (0xF0063174u)
+#define MTU_MC2_RDBFL64 (0xF00612E0u)
+#define MTU_MC33_RDBFL11 (0xF0063176u)
+#define MTU_MC33_RDBFL12 (0xF0063178u)
+#define MTU_MC33_RDBFL13 (0xF006317Au)
+#define MTU_MC33_RDBFL14 (0xF006317Cu)
+#define MTU_MC33_RDBFL15 (0xF006317Eu)
+#define MTU_MC33_RDBFL16 (0xF0063180u)
+#define MTU_MC33_RDBFL17 (0xF0063182u)
+#define MTU_MC33_RDBFL18 (0xF0063184u)
+#define MTU_MC33_RDBFL19 (0xF0063186u)
+#define MTU_MC33_RDBFL20 (0xF0063188u)
+#define MTU_MC2_RDBFL65 (0xF00612E2u)
+#define MTU_MC33_RDBFL21 (0xF006318Au)
+#define MTU_MC33_RDBFL22 (0xF006318Cu)
+#define MTU_MC33_RDBFL23 (0xF006318Eu)
+#define MTU_MC33_RDBFL24 (0xF0063190u)
+#define MTU_MC33_RDBFL25 (0xF0063192u)
+#define MTU_MC33_RDBFL26 (0xF0063194u)
+#define MTU_MC33_RDBFL27 (0xF0063196u)
+#define MTU_MC33_RDBFL28 (0xF0063198u)
+#define MTU_MC33_RDBFL29 (0xF006319Au)
+#define MTU_MC33_RDBFL30 (0xF006319Cu)
+#define MTU_MC2_RDBFL66 (0xF00612E4u)
+#define MTU_MC33_RDBFL31 (0xF006319Eu)
+#define MTU_MC33_RDBFL32 (0xF00631A0u)
+#define MTU_MC33_RDBFL33 (0xF00631A2u)
+#define MTU_MC33_RDBFL34 (0xF00631A4u)
+#define MTU_MC33_RDBFL35 (0xF00631A6u)
+#define MTU_MC33_RDBFL36 (0xF00631A8u)
+#define MTU_MC33_RDBFL37 (0xF00631AAu)
+#define MTU_MC33_RDBFL38 (0xF00631ACu)
+#define MTU_MC33_RDBFL39 (0xF00631AEu)
+#define MTU_MC33_RDBFL40 (0xF00631B0u)
+#define MTU_MC2_ALMSRCS (0xF00612EEu)
+#define MTU_MEMTEST2 (0xF0060018u)
+#define MTU_MC33_RDBFL41 (0xF00631B2u)
+#define MTU_MC33_RDBFL42 (0xF00631B4u)
+#define MTU_MC33_RDBFL43 (0xF00631B6u)
+#define MTU_MC33_RDBFL44 (0xF00631B8u)
+#define MTU_MC33_RDBFL45 (0xF00631BAu)
+#define MTU_MC33_RDBFL46 (0xF00631BCu)
+#define MTU_MC33_RDBFL47 (0xF00631BEu)
+#define MTU_MC33_RDBFL48 (0xF00631C0u)
+#define MTU_MC33_RDBFL49 (0xF00631C2u)
+#define MTU_MC33_RDBFL50 (0xF00631C4u)
+#define MTU_MC2_FAULTSTS (0xF00612F0u)
+#define MTU_MC33_RDBFL51 (0xF00631C6u)
+#define MTU_MC33_RDBFL52 (0xF00631C8u)
+#define MTU_MC33_RDBFL53 (0xF00631CAu)
+#define MTU_MC33_RDBFL54 (0xF00631CCu)
+#define MTU_MC33_RDBFL55 (0xF00631CEu)
+#define MTU_MC33_RDBFL56 (0xF00631D0u)
+#define MTU_MC33_RDBFL57 (0xF00631D2u)
+#define MTU_MC33_RDBFL58 (0xF00631D4u)
+#define MTU_MC33_RDBFL59 (0xF00631D6u)
+#define MTU_MC33_RDBFL60 (0xF00631D8u)
+#define MTU_MC2_ERRINFO0 (0xF00612F2u)
+#define MTU_MC33_RDBFL61 (0xF00631DAu)
+#define MTU_MC33_RDBFL62 (0xF00631DCu)
+#define MTU_MC33_RDBFL63 (0xF00631DEu)
+#define MTU_MC33_RDBFL64 (0xF00631E0u)
+#define MTU_MC33_RDBFL65 (0xF00631E2u)
+#define MTU_MC33_RDBFL66 (0xF00631E4u)
+#define MTU_MC33_ALMSRCS (0xF00631EEu)
+#define MTU_MC33_FAULTSTS (0xF00631F0u)
+#define MTU_MC33_ERRINFO0 (0xF00631F2u)
+#define MTU_MC33_ERRINFO1 (0xF00631F4u)
+#define MTU_MC2_ERRINFO1 (0xF00612F4u)
+#define MTU_MC33_ERRINFO2 (0xF00631F6u)
+#define MTU_MC33_ERRINFO3 (0xF00631F8u)
+#define MTU_MC33_ERRINFO4 (0xF00631FAu)
+#define MTU_MC34_CONFIG0 (0xF0063200u)
+#define MTU_MC34_CONFIG1 (0xF0063202u)
+#define MTU_MC34_MCONTROL (0xF0063204u)
+#define MTU_MC34_MSTATUS (0xF0063206u)
+#define MTU_MC34_RANGE (0xF0063208u)
+#define MTU_MC34_REVID (0xF006320Cu)
+#define MTU_MC34_ECCS (0xF006320Eu)
+#define MTU_MC2_ERRINFO2 (0xF00612F6u)
+#define MTU_MC34_ECCD (0xF0063210u)
+#define MTU_MC34_ETRR0 (0xF0063212u)
+#define MTU_MC34_ETRR1 (0xF0063214u)
+#define MTU_MC34_ETRR2 (0xF0063216u)
+#define MTU_MC34_ETRR3 (0xF0063218u)
+#define MTU_MC34_ETRR4 (0xF006321Au)
+#define MTU_MC34_RDBFL0 (0xF0063260u)
+#define MTU_MC34_RDBFL1 (0xF0063262u)
+#define MTU_MC34_RDBFL2 (0xF0063264u)
+#define MTU_MC34_RDBFL3 (0xF0063266u)
+#define MTU_MC2_ERRINFO3 (0xF00612F8u)
+#define MTU_MC34_RDBFL4 (0xF0063268u)
+#define MTU_MC34_RDBFL5 (0xF006326Au)
+#define MTU_MC34_RDBFL6 (0xF006326Cu)
+#define MTU_MC34_RDBFL7 (0xF006326Eu)
+#define MTU_MC34_RDBFL8 (0xF0063270u)
+#define MTU_MC34_RDBFL9 (0xF0063272u)
+#define MTU_MC34_RDBFL10 (0xF0063274u)
+#define MTU_MC34_RDBFL11 (0xF0063276u)
+#define MTU_MC34_RDBFL12 (0xF0063278u)
+#define MTU_MC34_RDBFL13 (0xF006327Au)
+#define MTU_MC2_ERRINFO4 (0xF00612FAu)
+#define MTU_MC34_RDBFL14 (0xF006327Cu)
+#define MTU_MC34_RDBFL15 (0xF006327Eu)
+#define MTU_MC34_RDBFL16 (0xF0063280u)
+#define MTU_MC34_RDBFL17 (0xF0063282u)
+#define MTU_MC34_RDBFL18 (0xF0063284u)
+#define MTU_MC34_RDBFL19 (0xF0063286u)
+#define MTU_MC34_RDBFL20 (0xF0063288u)
+#define MTU_MC34_RDBFL21 (0xF006328Au)
+#define MTU_MC34_RDBFL22 (0xF006328Cu)
+#define MTU_MC34_RDBFL23 (0xF006328Eu)
+#define MTU_MC3_CONFIG0 (0xF0061300u)
+#define MTU_MC34_RDBFL24 (0xF0063290u)
+#define MTU_MC34_RDBFL25 (0xF0063292u)
+#define MTU_MC34_RDBFL26 (0xF0063294u)
+#define MTU_MC34_RDBFL27 (0xF0063296u)
+#define MTU_MC34_RDBFL28 (0xF0063298u)
+#define MTU_MC34_RDBFL29 (0xF006329Au)
+#define MTU_MC34_RDBFL30 (0xF006329Cu)
+#define MTU_MC34_RDBFL31 (0xF006329Eu)
+#define MTU_MC34_RDBFL32 (0xF00632A0u)
+#define MTU_MC34_RDBFL33 (0xF00632A2u)
+#define MTU_MC3_CONFIG1 (0xF0061302u)
+#define MTU_MC34_RDBFL34 (0xF00632A4u)
+#define MTU_MC34_RDBFL35 (0xF00632A6u)
+#define MTU_MC34_RDBFL36 (0xF00632A8u)
+#define MTU_MC34_RDBFL37 (0xF00632AAu)
+#define MTU_MC34_RDBFL38 (0xF00632ACu)
+#define MTU_MC34_RDBFL39 (0xF00632AEu)
+#define MTU_MC34_RDBFL40 (0xF00632B0u)
+#define MTU_MC34_RDBFL41 (0xF00632B2u)
+#define MTU_MC34_RDBFL42 (0xF00632B4u)
+#define MTU_MC34_RDBFL43 (0xF00632B6u)
+#define MTU_MC3_MCONTROL (0xF0061304u)
+#define MTU_MC34_RDBFL44 (0xF00632B8u)
+#define MTU_MC34_RDBFL45 (0xF00632BAu)
+#define MTU_MC34_RDBFL46 (0xF00632BCu)
+#define MTU_MC34_RDBFL47 (0xF00632BEu)
+#define MTU_MC34_RDBFL48 (0xF00632C0u)
+#define MTU_MC34_RDBFL49 (0xF00632C2u)
+#define MTU_MC34_RDBFL50 (0xF00632C4u)
+#define MTU_MC34_RDBFL51 (0xF00632C6u)
+#define MTU_MC34_RDBFL52 (0xF00632C8u)
+#define MTU_MC34_RDBFL53 (0xF00632CAu)
+#define MTU_MC3_MSTATUS (0xF0061306u)
+#define MTU_MEMMAP (0xF006001Cu)
+#define MTU_MC34_RDBFL54 (0xF00632CCu)
+#define MTU_MC34_RDBFL55 (0xF00632CEu)
+#define MTU_MC34_RDBFL56 (0xF00632D0u)
+#define MTU_MC34_RDBFL57 (0xF00632D2u)
+#define MTU_MC34_RDBFL58 (0xF00632D4u)
+#define MTU_MC34_RDBFL59 (0xF00632D6u)
+#define MTU_MC34_RDBFL60 (0xF00632D8u)
+#define MTU_MC34_RDBFL61 (0xF00632DAu)
+#define MTU_MC34_RDBFL62 (0xF00632DCu)
+#define MTU_MC34_RDBFL63 (0xF00632DEu)
+#define MTU_MC3_RANGE (0xF0061308u)
+#define MTU_MC34_RDBFL64 (0xF00632E0u)
+#define MTU_MC34_RDBFL65 (0xF00632E2u)
+#define MTU_MC34_RDBFL66 (0xF00632E4u)
+#define MTU_MC34_ALMSRCS (0xF00632EEu)
+#define MTU_MC34_FAULTSTS (0xF00632F0u)
+#define MTU_MC34_ERRINFO0 (0xF00632F2u)
+#define MTU_MC34_ERRINFO1 (0xF00632F4u)
+#define MTU_MC34_ERRINFO2 (0xF00632F6u)
+#define MTU_MC34_ERRINFO3 (0xF00632F8u)
+#define MTU_MC34_ERRINFO4 (0xF00632FAu)
+#define MTU_MC3_REVID (0xF006130Cu)
+#define MTU_MC35_CONFIG0 (0xF0063300u)
+#define MTU_MC35_CONFIG1 (0xF0063302u)
+#define MTU_MC35_MCONTROL (0xF0063304u)
+#define MTU_MC35_MSTATUS (0xF0063306u)
+#define MTU_MC35_RANGE (0xF0063308u)
+#define MTU_MC35_REVID (0xF006330Cu)
+#define MTU_MC35_ECCS (0xF006330Eu)
+#define MTU_MC35_ECCD (0xF0063310u)
+#define MTU_MC35_ETRR0 (0xF0063312u)
+#define MTU_MC35_ETRR1 (0xF0063314u)
+#define MTU_MC3_ECCS (0xF006130Eu)
+#define MTU_MC35_ETRR2 (0xF0063316u)
+#define MTU_MC35_ETRR3 (0xF0063318u)
+#define MTU_MC35_ETRR4 (0xF006331Au)
+#define MTU_MC35_RDBFL0 (0xF0063360u)
+#define MTU_MC35_RDBFL1 (0xF0063362u)
+#define MTU_MC35_RDBFL2 (0xF0063364u)
+#define MTU_MC35_RDBFL3 (0xF0063366u)
+#define MTU_MC35_RDBFL4 (0xF0063368u)
+#define MTU_MC35_RDBFL5 (0xF006336Au)
+#define MTU_MC35_RDBFL6 (0xF006336Cu)
+#define MTU_MC3_ECCD (0xF0061310u)
+#define MTU_MC35_RDBFL7 (0xF006336Eu)
+#define MTU_MC35_RDBFL8 (0xF0063370u)
+#define MTU_MC35_RDBFL9 (0xF0063372u)
+#define MTU_MC35_RDBFL10 (0xF0063374u)
+#define MTU_MC35_RDBFL11 (0xF0063376u)
+#define MTU_MC35_RDBFL12 (0xF0063378u)
+#define MTU_MC35_RDBFL13 (0xF006337Au)
+#define MTU_MC35_RDBFL14 (0xF006337Cu)
+#define MTU_MC35_RDBFL15 (0xF006337Eu)
+#define MTU_MC35_RDBFL16 (0xF0063380u)
+#define MTU_MC3_ETRR0 (0xF0061312u)
+#define MTU_MC35_RDBFL17 (0xF0063382u)
+#define MTU_MC35_RDBFL18 (0xF0063384u)
+#define MTU_MC35_RDBFL19 (0xF0063386u)
+#define MTU_MC35_RDBFL20 (0xF0063388u)
+#define MTU_MC35_RDBFL21 (0xF006338Au)
+#define MTU_MC35_RDBFL22 (0xF006338Cu)
+#define MTU_MC35_RDBFL23 (0xF006338Eu)
+#define MTU_MC35_RDBFL24 (0xF0063390u)
+#define MTU_MC35_RDBFL25 (0xF0063392u)
+#define MTU_MC35_RDBFL26 (0xF0063394u)
+#define MTU_MC3_ETRR1 (0xF0061314u)
+#define MTU_MC35_RDBFL27 (0xF0063396u)
+#define MTU_MC35_RDBFL28 (0xF0063398u)
+#define MTU_MC35_RDBFL29 (0xF006339Au)
+#define MTU_MC35_RDBFL30 (0xF006339Cu)
+#define MTU_MC35_RDBFL31 (0xF006339Eu)
+#define MTU_MC35_RDBFL32 (0xF00633A0u)
+#define MTU_MC35_RDBFL33 (0xF00633A2u)
+#define MTU_MC35_RDBFL34 (0xF00633A4u)
+#define MTU_MC35_RDBFL35 (0xF00633A6u)
+#define MTU_MC35_RDBFL36 (0xF00633A8u)
+#define MTU_MC3_ETRR2 (0xF0061316u)
+#define MTU_MC35_RDBFL37 (0xF00633AAu)
+#define MTU_MC35_RDBFL38 (0xF00633ACu)
+#define MTU_MC35_RDBFL39 (0xF00633AEu)
+#define MTU_MC35_RDBFL40 (0xF00633B0u)
+#define MTU_MC35_RDBFL41 (0xF00633B2u)
+#define MTU_MC35_RDBFL42 (0xF00633B4u)
+#define MTU_MC35_RDBFL43 (0xF00633B6u)
+#define MTU_MC35_RDBFL44 (0xF00633B8u)
+#define MTU_MC35_RDBFL45 (0xF00633BAu)
+#define MTU_MC35_RDBFL46 (0xF00633BCu)
+#define MTU_MC3_ETRR3 (0xF0061318u)
+#define MTU_MC35_RDBFL47 (0xF00633BEu)
+#define MTU_MC35_RDBFL48 (0xF00633C0u)
+#define MTU_MC35_RDBFL49 (0xF00633C2u)
+#define MTU_MC35_RDBFL50 (0xF00633C4u)
+#define MTU_MC35_RDBFL51 (0xF00633C6u)
+#define MTU_MC35_RDBFL52 (0xF00633C8u)
+#define MTU_MC35_RDBFL53 (0xF00633CAu)
+#define MTU_MC35_RDBFL54 (0xF00633CCu)
+#define MTU_MC35_RDBFL55 (0xF00633CEu)
+#define MTU_MC35_RDBFL56 (0xF00633D0u)
+#define MTU_MC3_ETRR4 (0xF006131Au)
+#define MTU_MC35_RDBFL57 (0xF00633D2u)
+#define MTU_MC35_RDBFL58 (0xF00633D4u)
+#define MTU_MC35_RDBFL59 (0xF00633D6u)
+#define MTU_MC35_RDBFL60 (0xF00633D8u)
+#define MTU_MC35_RDBFL61 (0xF00633DAu)
+#define MTU_MC35_RDBFL62 (0xF00633DCu)
+#define MTU_MC35_RDBFL63 (0xF00633DEu)
+#define MTU_MC35_RDBFL64 (0xF00633E0u)
+#define MTU_MC35_RDBFL65 (0xF00633E2u)
+#define MTU_MC35_RDBFL66 (0xF00633E4u)
+#define MTU_MC3_RDBFL0 (0xF0061360u)
+#define MTU_MEMSTAT0 (0xF0060038u)
+#define MTU_MC35_ALMSRCS (0xF00633EEu)
+#define MTU_MC35_FAULTSTS (0xF00633F0u)
+#define MTU_MC35_ERRINFO0 (0xF00633F2u)
+#define MTU_MC35_ERRINFO1 (0xF00633F4u)
+#define MTU_MC35_ERRINFO2 (0xF00633F6u)
+#define MTU_MC35_ERRINFO3 (0xF00633F8u)
+#define MTU_MC35_ERRINFO4 (0xF00633FAu)
+#define MTU_MC36_CONFIG0 (0xF0063400u)
+#define MTU_MC36_CONFIG1 (0xF0063402u)
+#define MTU_MC36_MCONTROL (0xF0063404u)
+#define MTU_MC3_RDBFL1 (0xF0061362u)
+#define MTU_MC36_MSTATUS (0xF0063406u)
+#define MTU_MC36_RANGE (0xF0063408u)
+#define MTU_MC36_REVID (0xF006340Cu)
+#define MTU_MC36_ECCS (0xF006340Eu)
+#define MTU_MC36_ECCD (0xF0063410u)
+#define MTU_MC36_ETRR0 (0xF0063412u)
+#define MTU_MC36_ETRR1 (0xF0063414u)
+#define MTU_MC36_ETRR2 (0xF0063416u)
+#define MTU_MC36_ETRR3 (0xF0063418u)
+#define MTU_MC36_ETRR4 (0xF006341Au)
+#define MTU_MC3_RDBFL2 (0xF0061364u)
+#define MTU_MC36_RDBFL0 (0xF0063460u)
+#define MTU_MC36_RDBFL1 (0xF0063462u)
+#define MTU_MC36_RDBFL2 (0xF0063464u)
+#define MTU_MC36_RDBFL3 (0xF0063466u)
+#define MTU_MC36_RDBFL4 (0xF0063468u)
+#define MTU_MC36_RDBFL5 (0xF006346Au)
+#define MTU_MC36_RDBFL6 (0xF006346Cu)
+#define MTU_MC36_RDBFL7 (0xF006346Eu)
+#define MTU_MC36_RDBFL8 (0xF0063470u)
+#define MTU_MC36_RDBFL9 (0xF0063472u)
+#define MTU_MC3_RDBFL3 (0xF0061366u)
+#define MTU_MC36_RDBFL10 (0xF0063474u)
+#define MTU_MC36_RDBFL11 (0xF0063476u)
+#define MTU_MC36_RDBFL12 (0xF0063478u)
+#define MTU_MC36_RDBFL13 (0xF006347Au)
+#define MTU_MC36_RDBFL14 (0xF006347Cu)
+#define MTU_MC36_RDBFL15 (0xF006347Eu)
+#define MTU_MC36_RDBFL16 (0xF0063480u)
+#define MTU_MC36_RDBFL17 (0xF0063482u)
+#define MTU_MC36_RDBFL18 (0xF0063484u)
+#define MTU_MC36_RDBFL19 (0xF0063486u)
+#define MTU_MC3_RDBFL4 (0xF0061368u)
+#define MTU_MC36_RDBFL20 (0xF0063488u)
+#define MTU_MC36_RDBFL21 (0xF006348Au)
+#define MTU_MC36_RDBFL22 (0xF006348Cu)
+#define MTU_MC36_RDBFL23 (0xF006348Eu)
+#define MTU_MC36_RDBFL24 (0xF0063490u)
+#define MTU_MC36_RDBFL25 (0xF0063492u)
+#define MTU_MC36_RDBFL26 (0xF0063494u)
+#define MTU_MC36_RDBFL27 (0xF0063496u)
+#define MTU_MC36_RDBFL28 (0xF0063498u)
+#define MTU_MC36_RDBFL29 (0xF006349Au)
+#define MTU_MC3_RDBFL5 (0xF006136Au)
+#define MTU_MC36_RDBFL30 (0xF006349Cu)
+#define MTU_MC36_RDBFL31 (0xF006349Eu)
+#define MTU_MC36_RDBFL32 (0xF00634A0u)
+#define MTU_MC36_RDBFL33 (0xF00634A2u)
+#define MTU_MC36_RDBFL34 (0xF00634A4u)
+#define MTU_MC36_RDBFL35 (0xF00634A6u)
+#define MTU_MC36_RDBFL36 (0xF00634A8u)
+#define MTU_MC36_RDBFL37 (0xF00634AAu)
+#define MTU_MC36_RDBFL38 (0xF00634ACu)
+#define MTU_MC36_RDBFL39 (0xF00634AEu)
+#define MTU_MC3_RDBFL6 (0xF006136Cu)
+#define MTU_MC36_RDBFL40 (0xF00634B0u)
+#define MTU_MC36_RDBFL41 (0xF00634B2u)
+#define MTU_MC36_RDBFL42 (0xF00634B4u)
+#define MTU_MC36_RDBFL43 (0xF00634B6u)
+#define MTU_MC36_RDBFL44 (0xF00634B8u)
+#define MTU_MC36_RDBFL45 (0xF00634BAu)
+#define MTU_MC36_RDBFL46 (0xF00634BCu)
+#define MTU_MC36_RDBFL47 (0xF00634BEu)
+#define MTU_MC36_RDBFL48 (0xF00634C0u)
+#define MTU_MC36_RDBFL49 (0xF00634C2u)
+#define MTU_MC3_RDBFL7 (0xF006136Eu)
+#define MTU_MC36_RDBFL50 (0xF00634C4u)
+#define MTU_MC36_RDBFL51 (0xF00634C6u)
+#define MTU_MC36_RDBFL52 (0xF00634C8u)
+#define MTU_MC36_RDBFL53 (0xF00634CAu)
+#define MTU_MC36_RDBFL54 (0xF00634CCu)
+#define MTU_MC36_RDBFL55 (0xF00634CEu)
+#define MTU_MC36_RDBFL56 (0xF00634D0u)
+#define MTU_MC36_RDBFL57 (0xF00634D2u)
+#define MTU_MC36_RDBFL58 (0xF00634D4u)
+#define MTU_MC36_RDBFL59 (0xF00634D6u)
+#define MTU_MC3_RDBFL8 (0xF0061370u)
+#define MTU_MC36_RDBFL60 (0xF00634D8u)
+#define MTU_MC36_RDBFL61 (0xF00634DAu)
+#define MTU_MC36_RDBFL62 (0xF00634DCu)
+#define MTU_MC36_RDBFL63 (0xF00634DEu)
+#define MTU_MC36_RDBFL64 (0xF00634E0u)
+#define MTU_MC36_RDBFL65 (0xF00634E2u)
+#define MTU_MC36_RDBFL66 (0xF00634E4u)
+#define MTU_MC36_ALMSRCS (0xF00634EEu)
+#define MTU_MC36_FAULTSTS (0xF00634F0u)
+#define MTU_MC36_ERRINFO0 (0xF00634F2u)
+#define MTU_MC3_RDBFL9 (0xF0061372u)
+#define MTU_MC36_ERRINFO1 (0xF00634F4u)
+#define MTU_MC36_ERRINFO2 (0xF00634F6u)
+#define MTU_MC36_ERRINFO3 (0xF00634F8u)
+#define MTU_MC36_ERRINFO4 (0xF00634FAu)
+#define MTU_MC37_CONFIG0 (0xF0063500u)
+#define MTU_MC37_CONFIG1 (0xF0063502u)
+#define MTU_MC37_MCONTROL (0xF0063504u)
+#define MTU_MC37_MSTATUS (0xF0063506u)
+#define MTU_MC37_RANGE (0xF0063508u)
+#define MTU_MC37_REVID (0xF006350Cu)
+#define MTU_MC3_RDBFL10 (0xF0061374u)
+#define MTU_MEMSTAT1 (0xF006003Cu)
+#define MTU_MC37_ECCS (0xF006350Eu)
+#define MTU_MC37_ECCD (0xF0063510u)
+#define MTU_MC37_ETRR0 (0xF0063512u)
+#define MTU_MC37_ETRR1 (0xF0063514u)
+#define MTU_MC37_ETRR2 (0xF0063516u)
+#define MTU_MC37_ETRR3 (0xF0063518u)
+#define MTU_MC37_ETRR4 (0xF006351Au)
+#define MTU_MC37_RDBFL0 (0xF0063560u)
+#define MTU_MC37_RDBFL1 (0xF0063562u)
+#define MTU_MC37_RDBFL2 (0xF0063564u)
+#define MTU_MC3_RDBFL11 (0xF0061376u)
+#define MTU_MC37_RDBFL3 (0xF0063566u)
+#define MTU_MC37_RDBFL4 (0xF0063568u)
+#define MTU_MC37_RDBFL5 (0xF006356Au)
+#define MTU_MC37_RDBFL6 (0xF006356Cu)
+#define MTU_MC37_RDBFL7 (0xF006356Eu)
+#define MTU_MC37_RDBFL8 (0xF0063570u)
+#define MTU_MC37_RDBFL9 (0xF0063572u)
+#define MTU_MC37_RDBFL10 (0xF0063574u)
+#define MTU_MC37_RDBFL11 (0xF0063576u)
+#define MTU_MC37_RDBFL12 (0xF0063578u)
+#define MTU_MC3_RDBFL12 (0xF0061378u)
+#define MTU_MC37_RDBFL13 (0xF006357Au)
+#define MTU_MC37_RDBFL14 (0xF006357Cu)
+#define MTU_MC37_RDBFL15 (0xF006357Eu)
+#define MTU_MC37_RDBFL16 (0xF0063580u)
+#define MTU_MC37_RDBFL17 (0xF0063582u)
+#define MTU_MC37_RDBFL18 (0xF0063584u)
+#define MTU_MC37_RDBFL19 (0xF0063586u)
+#define MTU_MC37_RDBFL20 (0xF0063588u)
+#define MTU_MC37_RDBFL21 (0xF006358Au)
+#define MTU_MC37_RDBFL22 (0xF006358Cu)
+#define MTU_MC3_RDBFL13 (0xF006137Au)
+#define MTU_MC37_RDBFL23 (0xF006358Eu)
+#define MTU_MC37_RDBFL24 (0xF0063590u)
+#define MTU_MC37_RDBFL25 (0xF0063592u)
+#define MTU_MC37_RDBFL26 (0xF0063594u)
+#define MTU_MC37_RDBFL27 (0xF0063596u)
+#define MTU_MC37_RDBFL28 (0xF0063598u)
+#define MTU_MC37_RDBFL29 (0xF006359Au)
+#define MTU_MC37_RDBFL30 (0xF006359Cu)
+#define MTU_MC37_RDBFL31 (0xF006359Eu)
+#define MTU_MC37_RDBFL32 (0xF00635A0u)
+#define MTU_MC3_RDBFL14 (0xF006137Cu)
+#define MTU_MC37_RDBFL33 (0xF00635A2u)
+#define MTU_MC37_RDBFL34 (0xF00635A4u)
+#define MTU_MC37_RDBFL35 (0xF00635A6u)
+#define MTU_MC37_RDBFL36 (0xF00635A8u)
+#define MTU_MC37_RDBFL37 (0xF00635AAu)
+#define MTU_MC37_RDBFL38 (0xF00635ACu)
+#define MTU_MC37_RDBFL39 (0xF00635AEu)
+#define MTU_MC37_RDBFL40 (0xF00635B0u)
+#define MTU_MC37_RDBFL41 (0xF00635B2u)
+#define MTU_MC37_RDBFL42 (0xF00635B4u)
+#define MTU_MC3_RDBFL15 (0xF006137Eu)
+#define MTU_MC37_RDBFL43 (0xF00635B6u)
+#define MTU_MC37_RDBFL44 (0xF00635B8u)
+#define MTU_MC37_RDBFL45 (0xF00635BAu)
+#define MTU_MC37_RDBFL46 (0xF00635BCu)
+#define MTU_MC37_RDBFL47 (0xF00635BEu)
+#define MTU_MC37_RDBFL48 (0xF00635C0u)
+#define MTU_MC37_RDBFL49 (0xF00635C2u)
+#define MTU_MC37_RDBFL50 (0xF00635C4u)
+#define MTU_MC37_RDBFL51 (0xF00635C6u)
+#define MTU_MC37_RDBFL52 (0xF00635C8u)
+#define MTU_MC3_RDBFL16 (0xF0061380u)
+#define MTU_MC37_RDBFL53 (0xF00635CAu)
+#define MTU_MC37_RDBFL54 (0xF00635CCu)
+#define MTU_MC37_RDBFL55 (0xF00635CEu)
+#define MTU_MC37_RDBFL56 (0xF00635D0u)
+#define MTU_MC37_RDBFL57 (0xF00635D2u)
+#define MTU_MC37_RDBFL58 (0xF00635D4u)
+#define PFI0_SBAB2_SBABRECORD (0xA8082040u)
+#define PFI0_SBAB3_SBABRECORD (0xA8082060u)
+#define PFI0_SBAB4_SBABRECORD (0xA8082080u)
+#define PFI0_SBAB5_SBABRECORD (0xA80820A0u)
+#define PFI0_SBAB6_SBABRECORD (0xA80820C0u)
+#define PFI0_SBAB7_SBABRECORD (0xA80820E0u)
+#define PFI0_SBAB8_SBABRECORD (0xA8082100u)
+#define PFI0_SBAB9_SBABRECORD (0xA8082120u)
+#define PFI0_SBAB10_SBABRECORD (0xA8082140u)
+#define PFI0_SBAB11_SBABRECORD (0xA8082160u)
+#define PFI0_SBAB12_SBABRECORD (0xA8082180u)
+#define PFI0_SBAB13_SBABRECORD (0xA80821A0u)
+#define PFI0_SBAB14_SBABRECORD (0xA80821C0u)
+#define PFI0_SBAB15_SBABRECORD (0xA80821E0u)
+#define PFI0_SBAB16_SBABRECORD (0xA8082200u)
+#define PFI0_DBAB0_DBABRECORD (0xA8084000u)
+#define PFI0_DBAB1_DBABRECORD (0xA8084020u)
+#define PFI0_MBAB0_MBABRECORD (0xA8088000u)
+#define PFI0_ZBAB0_ZBABRECORD (0xA808C000u)
+#define PFI0_ZBAB1_ZBABRECORD (0xA808C020u)
+#define PFI0_ZBAB2_ZBABRECORD (0xA808C040u)
+#define PFI0_ZBAB3_ZBABRECORD (0xA808C060u)
+#define PFI1_ECCR (0xA8380000u)
+#define PFI1_ECCS (0xA8380020u)
+#define PFI1_SBAB0_SBABRECORD (0xA8382000u)
+#define PFI1_SBAB1_SBABRECORD (0xA8382020u)
+#define PFI1_SBAB2_SBABRECORD (0xA8382040u)
+#define PFI1_SBAB3_SBABRECORD (0xA8382060u)
+#define PFI1_SBAB4_SBABRECORD (0xA8382080u)
+#define PFI1_SBAB5_SBABRECORD (0xA83820A0u)
+#define PFI1_SBAB6_SBABRECORD (0xA83820C0u)
+#define PFI1_SBAB7_SBABRECORD (0xA83820E0u)
+#define PFI1_SBAB8_SBABRECORD (0xA8382100u)
+#define PFI1_SBAB9_SBABRECORD (0xA8382120u)
+#define PFI1_SBAB10_SBABRECORD (0xA8382140u)
+#define PFI1_SBAB11_SBABRECORD (0xA8382160u)
+#define PFI1_SBAB12_SBABRECORD (0xA8382180u)
+#define PFI1_SBAB13_SBABRECORD (0xA83821A0u)
+#define PFI1_SBAB14_SBABRECORD (0xA83821C0u)
+#define PFI1_SBAB15_SBABRECORD (0xA83821E0u)
+#define PFI1_SBAB16_SBABRECORD (0xA8382200u)
+#define PFI1_DBAB0_DBABRECORD (0xA8384000u)
+#define PFI1_DBAB1_DBABRECORD (0xA8384020u)
+#define PFI1_MBAB0_MBABRECORD (0xA8388000u)
+#define PFI1_ZBAB0_ZBABRECORD (0xA838C000u)
+#define PFI1_ZBAB1_ZBABRECORD (0xA838C020u)
+#define PFI1_ZBAB2_ZBABRECORD (0xA838C040u)
+#define PFI1_ZBAB3_ZBABRECORD (0xA838C060u)
+#define MODULE_PFI0 (0xA8080000u)
+#define MODULE_PFI1 (0xA8380000u)
+#define PFI0_ECCR (0xA8080000u)
+#define PFI0_ECCS (0xA8080020u)
+#define PFI0_SBAB0_SBABRECORD (0xA8082000u)
+#define PFI0_SBAB1_SBABRECORD (0xA8082020u)
+#define PMS_MONSTAT1 (0xF0248060u)
+#define PMS_MONSTAT2 (0xF0248064u)
+#define PMS_MONCTRL (0xF0248068u)
+#define PMS_MONFILT (0xF0248070u)
+#define PMS_PMSIEN (0xF0248074u)
+#define PMS_UVMON (0xF0248078u)
+#define PMS_OVMON (0xF024807Cu)
+#define PMS_UVMON2 (0xF0248080u)
+#define PMS_OVMON2 (0xF0248084u)
+#define PMS_HSMUVMON (0xF0248088u)
+#define PMS_HSMOVMON (0xF024808Cu)
+#define PMS_EVR33CON (0xF0248090u)
+#define PMS_EVROSCCTRL (0xF02480A0u)
+#define PMS_PMSWCR0 (0xF02480B4u)
+#define PMS_PMSWCR2 (0xF02480B8u)
+#define PMS_PMSWCR3 (0xF02480C0u)
+#define PMS_PMSWCR4 (0xF02480C4u)
+#define PMS_PMSWCR5 (0xF02480C8u)
+#define PMS_PMSWSTAT (0xF02480D4u)
+#define PMS_PMSWSTAT2 (0xF02480D8u)
+#define PMS_PMSWUTCNT (0xF02480DCu)
+#define PMS_PMSWSTATCLR (0xF02480E8u)
+#define PMS_EVRSDSTAT0 (0xF02480FCu)
+#define PMS_EVRSDCTRL0 (0xF0248108u)
+#define PMS_EVRSDCTRL1 (0xF024810Cu)
+#define PMS_EVRSDCTRL2 (0xF0248110u)
+#define PMS_EVRSDCTRL3 (0xF0248114u)
+#define PMS_EVRSDCTRL4 (0xF0248118u)
+#define PMS_EVRSDCTRL5 (0xF024811Cu)
+#define PMS_EVRSDCTRL6 (0xF0248120u)
+#define PMS_EVRSDCTRL7 (0xF0248124u)
+#define PMS_EVRSDCTRL8 (0xF0248128u)
+#define PMS_EVRSDCTRL9 (0xF024812Cu)
+#define PMS_EVRSDCTRL10 (0xF0248130u)
+#define PMS_EVRSDCTRL11 (0xF0248134u)
+#define PMS_EVRSDCOEFF0 (0xF0248148u)
+#define PMS_EVRSDCOEFF1 (0xF024814Cu)
+#define PMS_EVRSDCOEFF2 (0xF0248150u)
+#define PMS_EVRSDCOEFF3 (0xF0248154u)
+#define PMS_EVRSDCOEFF4 (0xF0248158u)
+#define PMS_EVRSDCOEFF5 (0xF024815Cu)
+#define PMS_EVRSDCOEFF6 (0xF0248160u)
+#define PMS_EVRSDCOEFF7 (0xF0248164u)
+#define PMS_EVRSDCOEFF8 (0xF0248168u)
+#define PMS_EVRSDCOEFF9 (0xF024816Cu)
+#define PMS_AG_STDBY0 (0xF0248188u)
+#define PMS_AG_STDBY1 (0xF024818Cu)
+#define PMS_MONBISTSTAT (0xF0248190u)
+#define PMS_MONBISTCTRL (0xF0248198u)
+#define PMS_CMD_STDBY (0xF024819Cu)
+#define PMS_AGFSP_STDBY0 (0xF02481A4u)
+#define PMS_AGFSP_STDBY1 (0xF02481A8u)
+#define PMS_DTSSTAT (0xF02481C0u)
+#define PMS_DTSLIM (0xF02481C8u)
+#define PMS_OTSS (0xF02481E0u)
+#define PMS_OTSC0 (0xF02481E4u)
+#define PMS_OTSC1 (0xF02481E8u)
+#define PMS_ACCEN1 (0xF02481F8u)
+#define PMS_ACCEN0 (0xF02481FCu)
+#define MODULE_PMS (0xF0248000u)
+#define PMS_XRAM (0xF0240000u)
+#define PMS_ID (0xF0248008u)
+#define PMS_EVRSTAT (0xF024802Cu)
+#define PMS_EVRADCSTAT (0xF0248034u)
+#define PMS_EVRRSTCON (0xF024803Cu)
+#define PMS_EVRRSTSTAT (0xF0248044u)
+#define PMS_EVRTRIM (0xF024804Cu)
+#define PMS_EVRTRIMSTAT (0xF0248050u)
+#define MODULE_PMU (0xF8038000u)
+#define PMU_ID (0xF8038508u)
+#define P23_OMCR0 (0xF003B780u)
+#define P23_OMCR4 (0xF003B784u)
+#define P23_OMSR (0xF003B790u)
+#define P23_OMCR (0xF003B794u)
+#define P23_ACCEN1 (0xF003B7F8u)
+#define P23_ACCEN0 (0xF003B7FCu)
+#define P32_OUT (0xF003C000u)
+#define P32_OMR (0xF003C004u)
+#define P32_ID (0xF003C008u)
+#define P32_IOCR0 (0xF003C010u)
+#define P32_IOCR4 (0xF003C014u)
+#define P32_IN (0xF003C024u)
+#define P32_PDR0 (0xF003C040u)
+#define P32_ESR (0xF003C050u)
+#define P32_PDISC (0xF003C060u)
+#define P32_PCSR (0xF003C064u)
+#define P32_OMSR0 (0xF003C070u)
+#define P32_OMSR4 (0xF003C074u)
+#define P32_OMCR0 (0xF003C080u)
+#define P32_OMCR4 (0xF003C084u)
+#define P32_OMSR (0xF003C090u)
+#define P32_OMCR (0xF003C094u)
+#define P32_ACCEN1 (0xF003C0F8u)
+#define P32_ACCEN0 (0xF003C0FCu)
+#define P33_OUT (0xF003C100u)
+#define P33_OMR (0xF003C104u)
+#define P33_ID (0xF003C108u)
+#define P33_IOCR0 (0xF003C110u)
+#define P33_IOCR4 (0xF003C114u)
+#define P33_IOCR8 (0xF003C118u)
+#define P33_IOCR12 (0xF003C11Cu)
+#define P33_IN (0xF003C124u)
+#define P33_PDR0 (0xF003C140u)
+#define P33_PDR1 (0xF003C144u)
+#define P33_ESR (0xF003C150u)
+#define P33_PDISC (0xF003C160u)
+#define P33_PCSR (0xF003C164u)
+#define P33_OMSR0 (0xF003C170u)
+#define P33_OMSR4 (0xF003C174u)
+#define P33_OMSR8 (0xF003C178u)
+#define P33_OMSR12 (0xF003C17Cu)
+#define P33_OMCR0 (0xF003C180u)
+#define P33_OMCR4 (0xF003C184u)
+#define P33_OMCR8 (0xF003C188u)
+#define P33_OMCR12 (0xF003C18Cu)
+#define P33_OMSR (0xF003C190u)
+#define P33_OMCR (0xF003C194u)
+#define P33_ACCEN1 (0xF003C1F8u)
+#define P33_ACCEN0 (0xF003C1FCu)
+#define MODULE_P00 (0xF003A000u)
+#define P34_OUT (0xF003C200u)
+#define P34_OMR (0xF003C204u)
+#define P34_ID (0xF003C208u)
+#define P34_IOCR0 (0xF003C210u)
+#define MODULE_P01 (0xF003A100u)
+#define P34_IOCR4 (0xF003C214u)
+#define P34_IN (0xF003C224u)
+#define P34_PDR0 (0xF003C240u)
+#define MODULE_P02 (0xF003A200u)
+#define P34_ESR (0xF003C250u)
+#define P34_PDISC (0xF003C260u)
+#define P34_PCSR (0xF003C264u)
+#define MODULE_P10 (0xF003AA00u)
+#define P34_OMSR0 (0xF003C270u)
+#define P34_OMSR4 (0xF003C274u)
+#define P34_OMCR0 (0xF003C280u)
+#define P34_OMCR4 (0xF003C284u)
+#define MODULE_P11 (0xF003AB00u)
+#define P34_OMSR (0xF003C290u)
+#define P34_OMCR (0xF003C294u)
+#define P34_ACCEN1 (0xF003C2F8u)
+#define MODULE_P12 (0xF003AC00u)
+#define P34_ACCEN0 (0xF003C2FCu)
+#define P40_OUT (0xF003C800u)
+#define MODULE_P13 (0xF003AD00u)
+#define P40_OMR (0xF003C804u)
+#define P40_ID (0xF003C808u)
+#define P40_IOCR0 (0xF003C810u)
+#define MODULE_P14 (0xF003AE00u)
+#define P40_IOCR4 (0xF003C814u)
+#define P40_IOCR8 (0xF003C818u)
+#define P40_IOCR12 (0xF003C81Cu)
+#define P40_IN (0xF003C824u)
+#define MODULE_P15 (0xF003AF00u)
+#define P40_PDR0 (0xF003C840u)
+#define P40_PDR1 (0xF003C844u)
+#define P40_ESR (0xF003C850u)
+#define MODULE_P20 (0xF003B400u)
+#define P40_PDISC (0xF003C860u)
+#define P40_PCSR (0xF003C864u)
+#define P40_OMSR0 (0xF003C870u)
+#define MODULE_P21 (0xF003B500u)
+#define P40_OMSR4 (0xF003C874u)
+#define P40_OMSR8 (0xF003C878u)
+#define P40_OMSR12 (0xF003C87Cu)
+#define P40_OMCR0 (0xF003C880u)
+#define MODULE_P22 (0xF003B600u)
+#define P40_OMCR4 (0xF003C884u)
+#define P40_OMCR8 (0xF003C888u)
+#define P40_OMCR12 (0xF003C88Cu)
+#define MODULE_P23 (0xF003B700u)
+#define P40_OMSR (0xF003C890u)
+#define P40_OMCR (0xF003C894u)
+#define P40_ACCEN1 (0xF003C8F8u)
+#define MODULE_P32 (0xF003C000u)
+#define P40_ACCEN0 (0xF003C8FCu)
+#define MODULE_P33 (0xF003C100u)
+#define MODULE_P34 (0xF003C200u)
+#define MODULE_P40 (0xF003C800u)
+#define P00_OUT (0xF003A000u)
+#define P00_OMR (0xF003A004u)
+#define P00_ID (0xF003A008u)
+#define P00_IOCR0 (0xF003A010u)
+#define P00_IOCR4 (0xF003A014u)
+#define P00_IOCR8 (0xF003A018u)
+#define P00_IOCR12 (0xF003A01Cu)
+#define P00_IN (0xF003A024u)
+#define P00_PDR0 (0xF003A040u)
+#define P00_PDR1 (0xF003A044u)
+#define P00_ESR (0xF003A050u)
+#define P00_PDISC (0xF003A060u)
+#define P00_PCSR (0xF003A064u)
+#define P00_OMSR0 (0xF003A070u)
+#define P00_OMSR4 (0xF003A074u)
+#define P00_OMSR8 (0xF003A078u)
+#define P00_OMSR12 (0xF003A07Cu)
+#define P00_OMCR0 (0xF003A080u)
+#define P00_OMCR4 (0xF003A084u)
+#define P00_OMCR8 (0xF003A088u)
+#define P00_OMCR12 (0xF003A08Cu)
+#define P00_OMSR (0xF003A090u)
+#define P00_OMCR (0xF003A094u)
+#define P00_ACCEN1 (0xF003A0F8u)
+#define P00_ACCEN0 (0xF003A0FCu)
+#define P01_OUT (0xF003A100u)
+#define P01_OMR (0xF003A104u)
+#define P01_ID (0xF003A108u)
+#define P01_IOCR0 (0xF003A110u)
+#define P01_IOCR4 (0xF003A114u)
+#define P01_IN (0xF003A124u)
+#define P01_PDR0 (0xF003A140u)
+#define P01_ESR (0xF003A150u)
+#define P01_PDISC (0xF003A160u)
+#define P01_PCSR (0xF003A164u)
+#define P01_OMSR0 (0xF003A170u)
+#define P01_OMSR4 (0xF003A174u)
+#define P01_OMCR0 (0xF003A180u)
+#define P01_OMCR4 (0xF003A184u)
+#define P01_OMSR (0xF003A190u)
+#define P01_OMCR (0xF003A194u)
+#define P01_ACCEN1 (0xF003A1F8u)
+#define P01_ACCEN0 (0xF003A1FCu)
+#define P02_OUT (0xF003A200u)
+#define P02_OMR (0xF003A204u)
+#define P02_ID (0xF003A208u)
+#define P02_IOCR0 (0xF003A210u)
+#define P02_IOCR4 (0xF003A214u)
+#define P02_IOCR8 (0xF003A218u)
+#define P02_IN (0xF003A224u)
+#define P02_PDR0 (0xF003A240u)
+#define P02_PDR1 (0xF003A244u)
+#define P02_ESR (0xF003A250u)
+#define P02_PDISC (0xF003A260u)
+#define P02_PCSR (0xF003A264u)
+#define P02_OMSR0 (0xF003A270u)
+#define P02_OMSR4 (0xF003A274u)
+#define P02_OMSR8 (0xF003A278u)
+#define P02_OMCR0 (0xF003A280u)
+#define P02_OMCR4 (0xF003A284u)
+#define P02_OMCR8 (0xF003A288u)
+#define P02_OMSR (0xF003A290u)
+#define P02_OMCR (0xF003A294u)
+#define P02_ACCEN1 (0xF003A2F8u)
+#define P02_ACCEN0 (0xF003A2FCu)
+#define P10_OUT (0xF003AA00u)
+#define P10_OMR (0xF003AA04u)
+#define P10_ID (0xF003AA08u)
+#define P10_IOCR0 (0xF003AA10u)
+#define P10_IOCR4 (0xF003AA14u)
+#define P10_IOCR8 (0xF003AA18u)
+#define P10_IN (0xF003AA24u)
+#define P10_PDR0 (0xF003AA40u)
+#define P10_PDR1 (0xF003AA44u)
+#define P10_ESR (0xF003AA50u)
+#define P10_PDISC (0xF003AA60u)
+#define P10_PCSR (0xF003AA64u)
+#define P10_OMSR0 (0xF003AA70u)
+#define P10_OMSR4 (0xF003AA74u)
+#define P10_OMSR8 (0xF003AA78u)
+#define P10_OMCR0 (0xF003AA80u)
+#define P10_OMCR4 (0xF003AA84u)
+#define P10_OMCR8 (0xF003AA88u)
+#define P10_OMSR (0xF003AA90u)
+#define P10_OMCR (0xF003AA94u)
+#define P10_ACCEN1 (0xF003AAF8u)
+#define P10_ACCEN0 (0xF003AAFCu)
+#define P11_OUT (0xF003AB00u)
+#define P11_OMR (0xF003AB04u)
+#define P11_ID (0xF003AB08u)
+#define P11_IOCR0 (0xF003AB10u)
+#define P11_IOCR4 (0xF003AB14u)
+#define P11_IOCR8 (0xF003AB18u)
+#define P11_IOCR12 (0xF003AB1Cu)
+#define P11_IN (0xF003AB24u)
+#define P11_PDR0 (0xF003AB40u)
+#define P11_PDR1 (0xF003AB44u)
+#define P11_ESR (0xF003AB50u)
+#define P11_PDISC (0xF003AB60u)
+#define P11_PCSR (0xF003AB64u)
+#define P11_OMSR0 (0xF003AB70u)
+#define P11_OMSR4 (0xF003AB74u)
+#define P11_OMSR8 (0xF003AB78u)
+#define P11_OMSR12 (0xF003AB7Cu)
+#define P11_OMCR0 (0xF003AB80u)
+#define P11_OMCR4 (0xF003AB84u)
+#define P11_OMCR8 (0xF003AB88u)
+#define P11_OMCR12 (0xF003AB8Cu)
+#define P11_OMSR (0xF003AB90u)
+#define P11_OMCR (0xF003AB94u)
+#define P11_ACCEN1 (0xF003ABF8u)
+#define P11_ACCEN0 (0xF003ABFCu)
+#define P12_OUT (0xF003AC00u)
+#define P12_OMR (0xF003AC04u)
+#define P12_ID (0xF003AC08u)
+#define P12_IOCR0 (0xF003AC10u)
+#define P12_IN (0xF003AC24u)
+#define P12_PDR0 (0xF003AC40u)
+#define P12_ESR (0xF003AC50u)
+#define P12_PDISC (0xF003AC60u)
+#define P12_PCSR (0xF003AC64u)
+#define P12_OMSR0 (0xF003AC70u)
+#define P12_OMCR0 (0xF003AC80u)
+#define P12_OMSR (0xF003AC90u)
+#define P12_OMCR (0xF003AC94u)
+#define P12_ACCEN1 (0xF003ACF8u)
+#define P12_ACCEN0 (0xF003ACFCu)
+#define P13_OUT (0xF003AD00u)
+#define P13_OMR (0xF003AD04u)
+#define P13_ID (0xF003AD08u)
+#define P13_IOCR0 (0xF003AD10u)
+#define P13_IN (0xF003AD24u)
+#define P13_PDR0 (0xF003AD40u)
+#define P13_ESR (0xF003AD50u)
+#define P13_PDISC (0xF003AD60u)
+#define P13_PCSR (0xF003AD64u)
+#define P13_OMSR0 (0xF003AD70u)
+#define P13_OMCR0 (0xF003AD80u)
+#define P13_OMSR (0xF003AD90u)
+#define P13_OMCR (0xF003AD94u)
+#define P13_LPCR0 (0xF003ADA0u)
+#define P13_LPCR1 (0xF003ADA4u)
+#define P13_ACCEN1 (0xF003ADF8u)
+#define P13_ACCEN0 (0xF003ADFCu)
+#define P14_OUT (0xF003AE00u)
+#define P14_OMR (0xF003AE04u)
+#define P14_ID (0xF003AE08u)
+#define P14_IOCR0 (0xF003AE10u)
+#define P14_IOCR4 (0xF003AE14u)
+#define P14_IOCR8 (0xF003AE18u)
+#define P14_IN (0xF003AE24u)
+#define P14_PDR0 (0xF003AE40u)
+#define P14_PDR1 (0xF003AE44u)
+#define P14_ESR (0xF003AE50u)
+#define P14_PDISC (0xF003AE60u)
+#define P14_PCSR (0xF003AE64u)
+#define P14_OMSR0 (0xF003AE70u)
+#define P14_OMSR4 (0xF003AE74u)
+#define P14_OMSR8 (0xF003AE78u)
+#define P14_OMCR0 (0xF003AE80u)
+#define P14_OMCR4 (0xF003AE84u)
+#define P14_OMCR8 (0xF003AE88u)
+#define P14_OMSR (0xF003AE90u)
+#define P14_OMCR (0xF003AE94u)
+#define P14_LPCR5 (0xF003AEB4u)
+#define P14_ACCEN1 (0xF003AEF8u)
+#define P14_ACCEN0 (0xF003AEFCu)
+#define P15_OUT (0xF003AF00u)
+#define P15_OMR (0xF003AF04u)
+#define P15_ID (0xF003AF08u)
+#define P15_IOCR0 (0xF003AF10u)
+#define P15_IOCR4 (0xF003AF14u)
+#define P15_IOCR8 (0xF003AF18u)
+#define P15_IN (0xF003AF24u)
+#define P15_PDR0 (0xF003AF40u)
+#define P15_PDR1 (0xF003AF44u)
+#define P15_ESR (0xF003AF50u)
+#define P15_PDISC (0xF003AF60u)
+#define P15_PCSR (0xF003AF64u)
+#define P15_OMSR0 (0xF003AF70u)
+#define P15_OMSR4 (0xF003AF74u)
+#define P15_OMSR8 (0xF003AF78u)
+#define P15_OMCR0 (0xF003AF80u)
+#define P15_OMCR4 (0xF003AF84u)
+#define P15_OMCR8 (0xF003AF88u)
+#define P15_OMSR (0xF003AF90u)
+#define P15_OMCR (0xF003AF94u)
+#define P15_ACCEN1 (0xF003AFF8u)
+#define P15_ACCEN0 (0xF003AFFCu)
+#define P20_OUT (0xF003B400u)
+#define P20_OMR (0xF003B404u)
+#define P20_ID (0xF003B408u)
+#define P20_IOCR0 (0xF003B410u)
+#define P20_IOCR4 (0xF003B414u)
+#define P20_IOCR8 (0xF003B418u)
+#define P20_IOCR12 (0xF003B41Cu)
+#define P20_IN (0xF003B424u)
+#define P20_PDR0 (0xF003B440u)
+#define P20_PDR1 (0xF003B444u)
+#define P20_ESR (0xF003B450u)
+#define P20_PDISC (0xF003B460u)
+#define P20_PCSR (0xF003B464u)
+#define P20_OMSR0 (0xF003B470u)
+#define P20_OMSR4 (0xF003B474u)
+#define P20_OMSR8 (0xF003B478u)
+#define P20_OMSR12 (0xF003B47Cu)
+#define P20_OMCR0 (0xF003B480u)
+#define P20_OMCR4 (0xF003B484u)
+#define P20_OMCR8 (0xF003B488u)
+#define P20_OMCR12 (0xF003B48Cu)
+#define P20_OMSR (0xF003B490u)
+#define P20_OMCR (0xF003B494u)
+#define P20_ACCEN1 (0xF003B4F8u)
+#define P20_ACCEN0 (0xF003B4FCu)
+#define P21_OUT (0xF003B500u)
+#define P21_OMR (0xF003B504u)
+#define P21_ID (0xF003B508u)
+#define P21_IOCR0 (0xF003B510u)
+#define P21_IOCR4 (0xF003B514u)
+#define P21_IN (0xF003B524u)
+#define P21_PDR0 (0xF003B540u)
+#define P21_ESR (0xF003B550u)
+#define P21_PDISC (0xF003B560u)
+#define P21_PCSR (0xF003B564u)
+#define P21_OMSR0 (0xF003B570u)
+#define P21_OMSR4 (0xF003B574u)
+#define P21_OMCR0 (0xF003B580u)
+#define P21_OMCR4 (0xF003B584u)
+#define P21_OMSR (0xF003B590u)
+#define P21_OMCR (0xF003B594u)
+#define P21_LPCR0 (0xF003B5A0u)
+#define P21_LPCR1 (0xF003B5A4u)
+#define P21_LPCR2 (0xF003B5A8u)
+#define P21_ACCEN1 (0xF003B5F8u)
+#define P21_ACCEN0 (0xF003B5FCu)
+#define P22_OUT (0xF003B600u)
+#define P22_OMR (0xF003B604u)
+#define P22_ID (0xF003B608u)
+#define P22_IOCR0 (0xF003B610u)
+#define P22_IOCR4 (0xF003B614u)
+#define P22_IOCR8 (0xF003B618u)
+#define P22_IN (0xF003B624u)
+#define P22_PDR0 (0xF003B640u)
+#define P22_PDR1 (0xF003B644u)
+#define P22_ESR (0xF003B650u)
+#define P22_PDISC (0xF003B660u)
+#define P22_PCSR (0xF003B664u)
+#define P22_OMSR0 (0xF003B670u)
+#define P22_OMSR4 (0xF003B674u)
+#define P22_OMSR8 (0xF003B678u)
+#define P22_OMCR0 (0xF003B680u)
+#define P22_OMCR4 (0xF003B684u)
+#define P22_OMCR8 (0xF003B688u)
+#define P22_OMSR (0xF003B690u)
+#define P22_OMCR (0xF003B694u)
+#define P22_LPCR0 (0xF003B6A0u)
+#define P22_LPCR1 (0xF003B6A4u)
+#define P22_ACCEN1 (0xF003B6F8u)
+#define P22_ACCEN0 (0xF003B6FCu)
+#define P23_OUT (0xF003B700u)
+#define P23_OMR (0xF003B704u)
+#define P23_ID (0xF003B708u)
+#define P23_IOCR0 (0xF003B710u)
+#define P23_IOCR4 (0xF003B714u)
+#define P23_IN (0xF003B724u)
+#define P23_PDR0 (0xF003B740u)
+#define P23_ESR (0xF003B750u)
+#define P23_PDISC (0xF003B760u)
+#define P23_PCSR (0xF003B764u)
+#define P23_OMSR0 (0xF003B770u)
+#define P23_OMSR4 (0xF003B774u)
+#define PSI5_RDM0_16_H (0xF0005684u)
+#define PSI5_RDM0_17_L (0xF0005688u)
+#define PSI5_TSRC (0xF0005024u)
+#define PSI5_RDM0_17_H (0xF000568Cu)
+#define PSI5_RDM0_18_L (0xF0005690u)
+#define PSI5_RDM0_18_H (0xF0005694u)
+#define PSI5_RDM0_19_L (0xF0005698u)
+#define PSI5_GCR (0xF000502Cu)
+#define PSI5_RDM0_19_H (0xF000569Cu)
+#define PSI5_RDM0_20_L (0xF00056A0u)
+#define PSI5_RDM0_20_H (0xF00056A4u)
+#define PSI5_RDM0_21_L (0xF00056A8u)
+#define PSI5_CH0_IOCR (0xF0005030u)
+#define PSI5_RDM0_21_H (0xF00056ACu)
+#define PSI5_RDM0_22_L (0xF00056B0u)
+#define PSI5_RDM0_22_H (0xF00056B4u)
+#define PSI5_RDM0_23_L (0xF00056B8u)
+#define PSI5_RDM0_23_H (0xF00056BCu)
+#define PSI5_RDM0_24_L (0xF00056C0u)
+#define PSI5_RDM0_24_H (0xF00056C4u)
+#define PSI5_RDM0_25_L (0xF00056C8u)
+#define PSI5_RDM0_25_H (0xF00056CCu)
+#define PSI5_RDM0_26_L (0xF00056D0u)
+#define PSI5_CH0_RCRA (0xF0005034u)
+#define PSI5_RDM0_26_H (0xF00056D4u)
+#define PSI5_RDM0_27_L (0xF00056D8u)
+#define PSI5_RDM0_27_H (0xF00056DCu)
+#define PSI5_RDM0_28_L (0xF00056E0u)
+#define PSI5_RDM0_28_H (0xF00056E4u)
+#define PSI5_RDM0_29_L (0xF00056E8u)
+#define PSI5_RDM0_29_H (0xF00056ECu)
+#define PSI5_RDM0_30_L (0xF00056F0u)
+#define PSI5_RDM0_30_H (0xF00056F4u)
+#define PSI5_RDM0_31_L (0xF00056F8u)
+#define PSI5_CH0_RCRB (0xF0005038u)
+#define PSI5_RDM0_31_H (0xF00056FCu)
+#define PSI5_RDM1_0_L (0xF0005700u)
+#define PSI5_RDM1_0_H (0xF0005704u)
+#define PSI5_RDM1_1_L (0xF0005708u)
+#define PSI5_RDM1_1_H (0xF000570Cu)
+#define PSI5_RDM1_2_L (0xF0005710u)
+#define PSI5_RDM1_2_H (0xF0005714u)
+#define PSI5_RDM1_3_L (0xF0005718u)
+#define PSI5_RDM1_3_H (0xF000571Cu)
+#define PSI5_RDM1_4_L (0xF0005720u)
+#define PSI5_CH0_RCRC (0xF000503Cu)
+#define PSI5_RDM1_4_H (0xF0005724u)
+#define PSI5_RDM1_5_L (0xF0005728u)
+#define PSI5_RDM1_5_H (0xF000572Cu)
+#define PSI5_RDM1_6_L (0xF0005730u)
+#define PSI5_RDM1_6_H (0xF0005734u)
+#define PSI5_RDM1_7_L (0xF0005738u)
+#define PSI5_RDM1_7_H (0xF000573Cu)
+#define PSI5_RDM1_8_L (0xF0005740u)
+#define PSI5_RDM1_8_H (0xF0005744u)
+#define PSI5_RDM1_9_L (0xF0005748u)
+#define PSI5_CH0_WDT0 (0xF0005040u)
+#define PSI5_RDM1_9_H (0xF000574Cu)
+#define PSI5_RDM1_10_L (0xF0005750u)
+#define PSI5_RDM1_10_H (0xF0005754u)
+#define PSI5_RDM1_11_L (0xF0005758u)
+#define PSI5_RDM1_11_H (0xF000575Cu)
+#define PSI5_RDM1_12_L (0xF0005760u)
+#define PSI5_RDM1_12_H (0xF0005764u)
+#define PSI5_RDM1_13_L (0xF0005768u)
+#define PSI5_RDM1_13_H (0xF000576Cu)
+#define PSI5_RDM1_14_L (0xF0005770u)
+#define PSI5_CH0_WDT1 (0xF0005044u)
+#define PSI5_RDM1_14_H (0xF0005774u)
+#define PSI5_RDM1_15_L (0xF0005778u)
+#define PSI5_RDM1_15_H (0xF000577Cu)
+#define PSI5_RDM1_16_L (0xF0005780u)
+#define PSI5_RDM1_16_H (0xF0005784u)
+#define PSI5_RDM1_17_L (0xF0005788u)
+#define PSI5_RDM1_17_H (0xF000578Cu)
+#define PSI5_RDM1_18_L (0xF0005790u)
+#define PSI5_RDM1_18_H (0xF0005794u)
+#define PSI5_RDM1_19_L (0xF0005798u)
+#define PSI5_CH0_WDT2 (0xF0005048u)
+#define PSI5_RDM1_19_H (0xF000579Cu)
+#define PSI5_RDM1_20_L (0xF00057A0u)
+#define PSI5_RDM1_20_H (0xF00057A4u)
+#define PSI5_RDM1_21_L (0xF00057A8u)
+#define PSI5_RDM1_21_H (0xF00057ACu)
+#define PSI5_RDM1_22_L (0xF00057B0u)
+#define PSI5_RDM1_22_H (0xF00057B4u)
+#define PSI5_RDM1_23_L (0xF00057B8u)
+#define PSI5_RDM1_23_H (0xF00057BCu)
+#define PSI5_RDM1_24_L (0xF00057C0u)
+#define PSI5_CH0_WDT3 (0xF000504Cu)
+#define PSI5_RDM1_24_H (0xF00057C4u)
+#define PSI5_RDM1_25_L (0xF00057C8u)
+#define PSI5_RDM1_25_H (0xF00057CCu)
+#define PSI5_RDM1_26_L (0xF00057D0u)
+#define PSI5_RDM1_26_H (0xF00057D4u)
+#define PSI5_RDM1_27_L (0xF00057D8u)
+#define PSI5_RDM1_27_H (0xF00057DCu)
+#define PSI5_RDM1_28_L (0xF00057E0u)
+#define PSI5_RDM1_28_H (0xF00057E4u)
+#define PSI5_RDM1_29_L (0xF00057E8u)
+#define PSI5_CH0_WDT4 (0xF0005050u)
+#define PSI5_RDM1_29_H (0xF00057ECu)
+#define PSI5_RDM1_30_L (0xF00057F0u)
+#define PSI5_RDM1_30_H (0xF00057F4u)
+#define PSI5_RDM1_31_L (0xF00057F8u)
+#define PSI5_RDM1_31_H (0xF00057FCu)
+#define PSI5_RDM2_0_L (0xF0005800u)
+#define PSI5_RDM2_0_H (0xF0005804u)
+#define PSI5_RDM2_1_L (0xF0005808u)
+#define PSI5_RDM2_1_H (0xF000580Cu)
+#define PSI5_RDM2_2_L (0xF0005810u)
+#define PSI5_CH0_WDT5 (0xF0005054u)
+#define PSI5_RDM2_2_H (0xF0005814u)
+#define PSI5_RDM2_3_L (0xF0005818u)
+#define PSI5_RDM2_3_H (0xF000581Cu)
+#define PSI5_RDM2_4_L (0xF0005820u)
+#define PSI5_RDM2_4_H (0xF0005824u)
+#define PSI5_RDM2_5_L (0xF0005828u)
+#define PSI5_RDM2_5_H (0xF000582Cu)
+#define PSI5_RDM2_6_L (0xF0005830u)
+#define PSI5_RDM2_6_H (0xF0005834u)
+#define PSI5_RDM2_7_L (0xF0005838u)
+#define PSI5_CH0_WDT6 (0xF0005058u)
+#define PSI5_RDM2_7_H (0xF000583Cu)
+#define PSI5_RDM2_8_L (0xF0005840u)
+#define PSI5_RDM2_8_H (0xF0005844u)
+#define PSI5_RDM2_9_L (0xF0005848u)
+#define PSI5_RDM2_9_H (0xF000584Cu)
+#define PSI5_RDM2_10_L (0xF0005850u)
+#define PSI5_RDM2_10_H (0xF0005854u)
+#define PSI5_RDM2_11_L (0xF0005858u)
+#define PSI5_RDM2_11_H (0xF000585Cu)
+#define PSI5_RDM2_12_L (0xF0005860u)
+#define PSI5_CH0_RSR (0xF000505Cu)
+#define PSI5_RDM2_12_H (0xF0005864u)
+#define PSI5_RDM2_13_L (0xF0005868u)
+#define PSI5_RDM2_13_H (0xF000586Cu)
+#define PSI5_RDM2_14_L (0xF0005870u)
+#define PSI5_RDM2_14_H (0xF0005874u)
+#define PSI5_RDM2_15_L (0xF0005878u)
+#define PSI5_RDM2_15_H (0xF000587Cu)
+#define PSI5_RDM2_16_L (0xF0005880u)
+#define PSI5_RDM2_16_H (0xF0005884u)
+#define PSI5_RDM2_17_L (0xF0005888u)
+#define PSI5_CH0_SDS0 (0xF0005060u)
+#define PSI5_RDM2_17_H (0xF000588Cu)
+#define PSI5_RDM2_18_L (0xF0005890u)
+#define PSI5_RDM2_18_H (0xF0005894u)
+#define PSI5_RDM2_19_L (0xF0005898u)
+#define PSI5_RDM2_19_H (0xF000589Cu)
+#define PSI5_RDM2_20_L (0xF00058A0u)
+#define PSI5_RDM2_20_H (0xF00058A4u)
+#define PSI5_RDM2_21_L (0xF00058A8u)
+#define PSI5_RDM2_21_H (0xF00058ACu)
+#define PSI5_RDM2_22_L (0xF00058B0u)
+#define PSI5_CH0_SDS1 (0xF0005064u)
+#define PSI5_RDM2_22_H (0xF00058B4u)
+#define PSI5_RDM2_23_L (0xF00058B8u)
+#define PSI5_RDM2_23_H (0xF00058BCu)
+#define PSI5_RDM2_24_L (0xF00058C0u)
+#define PSI5_RDM2_24_H (0xF00058C4u)
+#define PSI5_RDM2_25_L (0xF00058C8u)
+#define PSI5_RDM2_25_H (0xF00058CCu)
+#define PSI5_RDM2_26_L (0xF00058D0u)
+#define PSI5_RDM2_26_H (0xF00058D4u)
+#define PSI5_RDM2_27_L (0xF00058D8u)
+#define PSI5_CH0_SDS2 (0xF0005068u)
+#define PSI5_RDM2_27_H (0xF00058DCu)
+#define PSI5_RDM2_28_L (0xF00058E0u)
+#define PSI5_RDM2_28_H (0xF00058E4u)
+#define PSI5_RDM2_29_L (0xF00058E8u)
+#define PSI5_RDM2_29_H (0xF00058ECu)
+#define PSI5_RDM2_30_L (0xF00058F0u)
+#define PSI5_RDM2_30_H (0xF00058F4u)
+#define PSI5_RDM2_31_L (0xF00058F8u)
+#define PSI5_RDM2_31_H (0xF00058FCu)
+#define PSI5_RDM3_0_L (0xF0005900u)
+#define PSI5_CH0_SDS3 (0xF000506Cu)
+#define PSI5_RDM3_0_H (0xF0005904u)
+#define PSI5_RDM3_1_L (0xF0005908u)
+#define PSI5_RDM3_1_H (0xF000590Cu)
+#define PSI5_RDM3_2_L (0xF0005910u)
+#define PSI5_RDM3_2_H (0xF0005914u)
+#define PSI5_RDM3_3_L (0xF0005918u)
+#define PSI5_RDM3_3_H (0xF000591Cu)
+#define PSI5_RDM3_4_L (0xF0005920u)
+#define PSI5_RDM3_4_H (0xF0005924u)
+#define PSI5_RDM3_5_L (0xF0005928u)
+#define PSI5_CH0_SDS4 (0xF0005070u)
+#define PSI5_RDM3_5_H (0xF000592Cu)
+#define PSI5_RDM3_6_L (0xF0005930u)
+#define PSI5_RDM3_6_H (0xF0005934u)
+#define PSI5_RDM3_7_L (0xF0005938u)
+#define PSI5_RDM3_7_H (0xF000593Cu)
+#define PSI5_RDM3_8_L (0xF0005940u)
+#define PSI5_RDM3_8_H (0xF0005944u)
+#define PSI5_RDM3_9_L (0xF0005948u)
+#define PSI5_RDM3_9_H (0xF000594Cu)
+#define PSI5_RDM3_10_L (0xF0005950u)
+#define PSI5_CH0_SDS5 (0xF0005074u)
+#define PSI5_RDM3_10_H (0xF0005954u)
+#define PSI5_RDM3_11_L (0xF0005958u)
+#define PSI5_RDM3_11_H (0xF000595Cu)
+#define PSI5_RDM3_12_L (0xF0005960u)
+#define PSI5_RDM3_12_H (0xF0005964u)
+#define PSI5_RDM3_13_L (0xF0005968u)
+#define PSI5_RDM3_13_H (0xF000596Cu)
+#define PSI5_RDM3_14_L (0xF0005970u)
+#define PSI5_RDM3_14_H (0xF0005974u)
+#define PSI5_RDM3_15_L (0xF0005978u)
+#define PSI5_CH0_SPTSC (0xF0005078u)
+#define PSI5_RDM3_15_H (0xF000597Cu)
+#define PSI5_RDM3_16_L (0xF0005980u)
+#define PSI5_RDM3_16_H (0xF0005984u)
+#define PSI5_RDM3_17_L (0xF0005988u)
+#define PSI5_RDM3_17_H (0xF000598Cu)
+#define PSI5_RDM3_18_L (0xF0005990u)
+#define PSI5_RDM3_18_H (0xF0005994u)
+#define PSI5_RDM3_19_L (0xF0005998u)
+#define PSI5_RDM3_19_H (0xF000599Cu)
+#define PSI5_RDM3_20_L (0xF00059A0u)
+#define PSI5_CH0_SFTSC (0xF000507Cu)
+#define PSI5_RDM3_20_H (0xF00059A4u)
+#define PSI5_RDM3_21_L (0xF00059A8u)
+#define PSI5_RDM3_21_H (0xF00059ACu)
+#define PSI5_RDM3_22_L (0xF00059B0u)
+#define PSI5_RDM3_22_H (0xF00059B4u)
+#define PSI5_RDM3_23_L (0xF00059B8u)
+#define PSI5_RDM3_23_H (0xF00059BCu)
+#define PSI5_RDM3_24_L (0xF00059C0u)
+#define PSI5_RDM3_24_H (0xF00059C4u)
+#define PSI5_RDM3_25_L (0xF00059C8u)
+#define PSI5_CH0_RDRL (0xF0005080u)
+#define PSI5_RDM3_25_H (0xF00059CCu)
+#define PSI5_RDM3_26_L (0xF00059D0u)
+#define PSI5_RDM3_26_H (0xF00059D4u)
+#define PSI5_RDM3_27_L (0xF00059D8u)
+#define PSI5_RDM3_27_H (0xF00059DCu)
+#define PSI5_RDM3_28_L (0xF00059E0u)
+#define PSI5_RDM3_28_H (0xF00059E4u)
+#define PSI5_RDM3_29_L (0xF00059E8u)
+#define PSI5_RDM3_29_H (0xF00059ECu)
+#define PSI5_RDM3_30_L (0xF00059F0u)
+#define PSI5_CH0_RDRH (0xF0005084u)
+#define PSI5_RDM3_30_H (0xF00059F4u)
+#define PSI5_RDM3_31_L (0xF00059F8u)
+#define PSI5_RDM3_31_H (0xF00059FCu)
+#define PSI5_CH0_PGC (0xF0005088u)
+#define PSI5_CH0_CTV (0xF000508Cu)
+#define PSI5_CH0_SCR (0xF0005090u)
+#define PSI5_CH0_SDRL (0xF0005094u)
+#define PSI5_CH0_SDRH (0xF0005098u)
+#define PSI5_CH0_SSRL (0xF000509Cu)
+#define PSI5_CH0_SSRH (0xF00050A0u)
+#define PSI5_CH0_SORL (0xF00050A4u)
+#define PSI5_CH0_SORH (0xF00050A8u)
+#define PSI5_CH1_IOCR (0xF00050C0u)
+#define PSI5_CH1_RCRA (0xF00050C4u)
+#define PSI5_CH1_RCRB (0xF00050C8u)
+#define PSI5_CH1_RCRC (0xF00050CCu)
+#define PSI5_CH1_WDT0 (0xF00050D0u)
+#define PSI5_CH1_WDT1 (0xF00050D4u)
+#define PSI5_CH1_WDT2 (0xF00050D8u)
+#define PSI5_CH1_WDT3 (0xF00050DCu)
+#define PSI5_CH1_WDT4 (0xF00050E0u)
+#define PSI5_CH1_WDT5 (0xF00050E4u)
+#define PSI5_CH1_WDT6 (0xF00050E8u)
+#define PSI5_CH1_RSR (0xF00050ECu)
+#define PSI5_CH1_SDS0 (0xF00050F0u)
+#define PSI5_CH1_SDS1 (0xF00050F4u)
+#define PSI5_CH1_SDS2 (0xF00050F8u)
+#define PSI5_CH1_SDS3 (0xF00050FCu)
+#define PSI5_CH1_SDS4 (0xF0005100u)
+#define PSI5_CH1_SDS5 (0xF0005104u)
+#define PSI5_CH1_SPTSC (0xF0005108u)
+#define PSI5_CH1_SFTSC (0xF000510Cu)
+#define PSI5_CH1_RDRL (0xF0005110u)
+#define PSI5_CH1_RDRH (0xF0005114u)
+#define PSI5_CH1_PGC (0xF0005118u)
+#define PSI5_CH1_CTV (0xF000511Cu)
+#define PSI5_CH1_SCR (0xF0005120u)
+#define PSI5_CH1_SDRL (0xF0005124u)
+#define PSI5_CH1_SDRH (0xF0005128u)
+#define PSI5_CH1_SSRL (0xF000512Cu)
+#define PSI5_CH1_SSRH (0xF0005130u)
+#define PSI5_CH1_SORL (0xF0005134u)
+#define PSI5_CH1_SORH (0xF0005138u)
+#define PSI5_INTOV (0xF00052F8u)
+#define PSI5_INP0 (0xF00052FCu)
+#define PSI5_INP1 (0xF0005300u)
+#define PSI5_INTSTATA0 (0xF0005310u)
+#define PSI5_INTSTATA1 (0xF0005314u)
+#define PSI5_INTSTATB0 (0xF0005324u)
+#define PSI5_INTSTATB1 (0xF0005328u)
+#define PSI5_INTSETA0 (0xF0005338u)
+#define PSI5_INTSETA1 (0xF000533Cu)
+#define PSI5_INTSETB0 (0xF000534Cu)
+#define PSI5_INTSETB1 (0xF0005350u)
+#define PSI5_INTCLRA0 (0xF0005360u)
+#define PSI5_INTCLRA1 (0xF0005364u)
+#define PSI5_INTCLRB0 (0xF0005374u)
+#define PSI5_INTCLRB1 (0xF0005378u)
+#define PSI5_INTENA0 (0xF0005388u)
+#define PSI5_INTENA1 (0xF000538Cu)
+#define PSI5_INTENB0 (0xF000539Cu)
+#define PSI5_INTENB1 (0xF00053A0u)
+#define PSI5_OCS (0xF00053CCu)
+#define PSI5_ACCEN0 (0xF00053D0u)
+#define PSI5_ACCEN1 (0xF00053D4u)
+#define PSI5_KRST0 (0xF00053D8u)
+#define PSI5_KRST1 (0xF00053DCu)
+#define PSI5_KRSTCLR (0xF00053E0u)
+#define PSI5_RFC0 (0xF00053E4u)
+#define PSI5_RFC1 (0xF00053E8u)
+#define PSI5_RDF0 (0xF00053F8u)
+#define PSI5_RDF1 (0xF00053FCu)
+#define PSI5_RSIOV0 (0xF000540Cu)
+#define PSI5_RSIOV1 (0xF0005410u)
+#define PSI5_RMIOV0 (0xF0005420u)
+#define PSI5_RMIOV1 (0xF0005424u)
+#define PSI5_NBIOV0 (0xF0005434u)
+#define PSI5_NBIOV1 (0xF0005438u)
+#define PSI5_TEIOV0 (0xF0005448u)
+#define PSI5_TEIOV1 (0xF000544Cu)
+#define PSI5_CRCIOV0 (0xF000545Cu)
+#define PSI5_CRCIOV1 (0xF0005460u)
+#define PSI5_RDIOV0 (0xF0005470u)
+#define PSI5_RDIOV1 (0xF0005474u)
+#define PSI5_NFIOV0 (0xF0005484u)
+#define PSI5_NFIOV1 (0xF0005488u)
+#define PSI5_MEIOV0 (0xF0005498u)
+#define PSI5_MEIOV1 (0xF000549Cu)
+#define PSI5_RSISET0 (0xF00054ACu)
+#define PSI5_RSISET1 (0xF00054B0u)
+#define MODULE_PSI5 (0xF0005000u)
+#define PSI5_RMISET0 (0xF00054C0u)
+#define PSI5_RMISET1 (0xF00054C4u)
+#define PSI5_NBISET0 (0xF00054D4u)
+#define PSI5_NBISET1 (0xF00054D8u)
+#define PSI5_TEISET0 (0xF00054E8u)
+#define PSI5_TEISET1 (0xF00054ECu)
+#define PSI5_CRCISET0 (0xF00054FCu)
+#define PSI5_CRCISET1 (0xF0005500u)
+#define PSI5_RDISET0 (0xF0005510u)
+#define PSI5_RDISET1 (0xF0005514u)
+#define PSI5_NFISET0 (0xF0005524u)
+#define PSI5_NFISET1 (0xF0005528u)
+#define PSI5_MEISET0 (0xF0005538u)
+#define PSI5_MEISET1 (0xF000553Cu)
+#define PSI5_RSICLR0 (0xF000554Cu)
+#define PSI5_RSICLR1 (0xF0005550u)
+#define PSI5_RMICLR0 (0xF0005560u)
+#define PSI5_RMICLR1 (0xF0005564u)
+#define PSI5_NBICLR0 (0xF0005574u)
+#define PSI5_NBICLR1 (0xF0005578u)
+#define PSI5_TEICLR0 (0xF0005588u)
+#define PSI5_TEICLR1 (0xF000558Cu)
+#define PSI5_CRCICLR0 (0xF000559Cu)
+#define PSI5_CRCICLR1 (0xF00055A0u)
+#define PSI5_RDICLR0 (0xF00055B0u)
+#define PSI5_RDICLR1 (0xF00055B4u)
+#define PSI5_NFICLR0 (0xF00055C4u)
+#define PSI5_NFICLR1 (0xF00055C8u)
+#define PSI5_MEICLR0 (0xF00055D8u)
+#define PSI5_MEICLR1 (0xF00055DCu)
+#define PSI5_CLC (0xF0005000u)
+#define PSI5_RDM0_0_L (0xF0005600u)
+#define PSI5_RDM0_0_H (0xF0005604u)
+#define PSI5_RDM0_1_L (0xF0005608u)
+#define PSI5_RDM0_1_H (0xF000560Cu)
+#define PSI5_RDM0_2_L (0xF0005610u)
+#define PSI5_ID (0xF0005008u)
+#define PSI5_RDM0_2_H (0xF0005614u)
+#define PSI5_RDM0_3_L (0xF0005618u)
+#define PSI5_RDM0_3_H (0xF000561Cu)
+#define PSI5_RDM0_4_L (0xF0005620u)
+#define PSI5_FDR (0xF000500Cu)
+#define PSI5_RDM0_4_H (0xF0005624u)
+#define PSI5_RDM0_5_L (0xF0005628u)
+#define PSI5_RDM0_5_H (0xF000562Cu)
+#define PSI5_RDM0_6_L (0xF0005630u)
+#define PSI5_FDRL (0xF0005010u)
+#define PSI5_RDM0_6_H (0xF0005634u)
+#define PSI5_RDM0_7_L (0xF0005638u)
+#define PSI5_RDM0_7_H (0xF000563Cu)
+#define PSI5_RDM0_8_L (0xF0005640u)
+#define PSI5_FDRH (0xF0005014u)
+#define PSI5_RDM0_8_H (0xF0005644u)
+#define PSI5_RDM0_9_L (0xF0005648u)
+#define PSI5_RDM0_9_H (0xF000564Cu)
+#define PSI5_RDM0_10_L (0xF0005650u)
+#define PSI5_RDM0_10_H (0xF0005654u)
+#define PSI5_FDRT (0xF0005018u)
+#define PSI5_RDM0_11_L (0xF0005658u)
+#define PSI5_RDM0_11_H (0xF000565Cu)
+#define PSI5_RDM0_12_L (0xF0005660u)
+#define PSI5_RDM0_12_H (0xF0005664u)
+#define PSI5_TSRA (0xF000501Cu)
+#define PSI5_RDM0_13_L (0xF0005668u)
+#define PSI5_RDM0_13_H (0xF000566Cu)
+#define PSI5_RDM0_14_L (0xF0005670u)
+#define PSI5_RDM0_14_H (0xF0005674u)
+#define PSI5_TSRB (0xF0005020u)
+#define PSI5_RDM0_15_L (0xF0005678u)
+#define PSI5_RDM0_15_H (0xF000567Cu)
+#define PSI5_RDM0_16_L (0xF0005680u)
+#define PSI5S_FCNT (0xF0007024u)
+#define PSI5S_IOCR (0xF0007028u)
+#define PSI5S_RCRA0 (0xF0007030u)
+#define PSI5S_RCRA1 (0xF0007034u)
+#define PSI5S_RCRA2 (0xF0007038u)
+#define PSI5S_RCRA3 (0xF000703Cu)
+#define PSI5S_RCRA4 (0xF0007040u)
+#define PSI5S_RCRA5 (0xF0007044u)
+#define PSI5S_RCRA6 (0xF0007048u)
+#define PSI5S_RCRA7 (0xF000704Cu)
+#define PSI5S_RCRB0 (0xF0007050u)
+#define PSI5S_RCRB1 (0xF0007054u)
+#define PSI5S_RCRB2 (0xF0007058u)
+#define PSI5S_RCRB3 (0xF000705Cu)
+#define PSI5S_RCRB4 (0xF0007060u)
+#define PSI5S_RCRB5 (0xF0007064u)
+#define PSI5S_RCRB6 (0xF0007068u)
+#define PSI5S_RCRB7 (0xF000706Cu)
+#define PSI5S_WDT0 (0xF0007070u)
+#define PSI5S_WDT1 (0xF0007074u)
+#define PSI5S_WDT2 (0xF0007078u)
+#define PSI5S_WDT3 (0xF000707Cu)
+#define PSI5S_WDT4 (0xF0007080u)
+#define PSI5S_WDT5 (0xF0007084u)
+#define PSI5S_WDT6 (0xF0007088u)
+#define PSI5S_WDT7 (0xF000708Cu)
+#define PSI5S_TSCR0 (0xF0007090u)
+#define PSI5S_TSCR1 (0xF0007094u)
+#define PSI5S_TSCR2 (0xF0007098u)
+#define PSI5S_TSCR3 (0xF000709Cu)
+#define PSI5S_TSCR4 (0xF00070A0u)
+#define PSI5S_TSCR5 (0xF00070A4u)
+#define PSI5S_TSCR6 (0xF00070A8u)
+#define PSI5S_TSCR7 (0xF00070ACu)
+#define PSI5S_RDS (0xF00070B0u)
+#define PSI5S_RDR (0xF00070B4u)
+#define PSI5S_TSM (0xF00070B8u)
+#define PSI5S_TAR (0xF00070D0u)
+#define PSI5S_BAR (0xF00070D4u)
+#define PSI5S_PGC0 (0xF00070F0u)
+#define PSI5S_PGC1 (0xF00070F4u)
+#define PSI5S_PGC2 (0xF00070F8u)
+#define PSI5S_PGC3 (0xF00070FCu)
+#define PSI5S_PGC4 (0xF0007100u)
+#define PSI5S_PGC5 (0xF0007104u)
+#define PSI5S_PGC6 (0xF0007108u)
+#define PSI5S_PGC7 (0xF000710Cu)
+#define PSI5S_CTV0 (0xF0007110u)
+#define PSI5S_CTV1 (0xF0007114u)
+#define PSI5S_CTV2 (0xF0007118u)
+#define PSI5S_CTV3 (0xF000711Cu)
+#define PSI5S_CTV4 (0xF0007120u)
+#define PSI5S_CTV5 (0xF0007124u)
+#define PSI5S_CTV6 (0xF0007128u)
+#define PSI5S_CTV7 (0xF000712Cu)
+#define PSI5S_SCR0 (0xF0007130u)
+#define PSI5S_SCR1 (0xF0007134u)
+#define PSI5S_SCR2 (0xF0007138u)
+#define PSI5S_SCR3 (0xF000713Cu)
+#define PSI5S_SCR4 (0xF0007140u)
+#define PSI5S_SCR5 (0xF0007144u)
+#define PSI5S_SCR6 (0xF0007148u)
+#define PSI5S_SCR7 (0xF000714Cu)
+#define PSI5S_SDR0 (0xF0007150u)
+#define PSI5S_SDR1 (0xF0007154u)
+#define PSI5S_SDR2 (0xF0007158u)
+#define PSI5S_SDR3 (0xF000715Cu)
+#define PSI5S_SDR4 (0xF0007160u)
+#define PSI5S_SDR5 (0xF0007164u)
+#define PSI5S_SDR6 (0xF0007168u)
+#define PSI5S_SDR7 (0xF000716Cu)
+#define PSI5S_CDW (0xF0007170u)
+#define PSI5S_CON (0xF0007210u)
+#define PSI5S_BG (0xF0007214u)
+#define PSI5S_FDV (0xF0007218u)
+#define PSI5S_FDO (0xF000721Cu)
+#define PSI5S_TBUF (0xF0007220u)
+#define PSI5S_RBUF (0xF0007224u)
+#define PSI5S_WHBCON (0xF0007250u)
+#define PSI5S_INTSTAT0 (0xF0007260u)
+#define PSI5S_INTSTAT1 (0xF0007264u)
+#define PSI5S_INTSTAT2 (0xF0007268u)
+#define PSI5S_INTSTAT3 (0xF000726Cu)
+#define PSI5S_INTSTAT4 (0xF0007270u)
+#define PSI5S_INTSTAT5 (0xF0007274u)
+#define PSI5S_INTSTAT6 (0xF0007278u)
+#define PSI5S_INTSTAT7 (0xF000727Cu)
+#define PSI5S_INTSET0 (0xF0007280u)
+#define PSI5S_INTSET1 (0xF0007284u)
+#define PSI5S_INTSET2 (0xF0007288u)
+#define PSI5S_INTSET3 (0xF000728Cu)
+#define PSI5S_INTSET4 (0xF0007290u)
+#define PSI5S_INTSET5 (0xF0007294u)
+#define PSI5S_INTSET6 (0xF0007298u)
+#define PSI5S_INTSET7 (0xF000729Cu)
+#define PSI5S_INTCLR0 (0xF00072A0u)
+#define PSI5S_INTCLR1 (0xF00072A4u)
+#define PSI5S_INTCLR2 (0xF00072A8u)
+#define PSI5S_INTCLR3 (0xF00072ACu)
+#define PSI5S_INTCLR4 (0xF00072B0u)
+#define PSI5S_INTCLR5 (0xF00072B4u)
+#define PSI5S_INTCLR6 (0xF00072B8u)
+#define PSI5S_INTCLR7 (0xF00072BCu)
+#define PSI5S_INTEN0 (0xF00072C0u)
+#define PSI5S_INTEN1 (0xF00072C4u)
+#define PSI5S_INTEN2 (0xF00072C8u)
+#define PSI5S_INTEN3 (0xF00072CCu)
+#define PSI5S_INTEN4 (0xF00072D0u)
+#define PSI5S_INTEN5 (0xF00072D4u)
+#define PSI5S_INTEN6 (0xF00072D8u)
+#define PSI5S_INTEN7 (0xF00072DCu)
+#define PSI5S_INP0 (0xF00072E0u)
+#define PSI5S_INP1 (0xF00072E4u)
+#define PSI5S_INP2 (0xF00072E8u)
+#define PSI5S_INP3 (0xF00072ECu)
+#define PSI5S_INP4 (0xF00072F0u)
+#define PSI5S_INP5 (0xF00072F4u)
+#define PSI5S_INP6 (0xF00072F8u)
+#define PSI5S_INP7 (0xF00072FCu)
+#define PSI5S_INTOV (0xF0007300u)
+#define PSI5S_INTSTATG (0xF0007304u)
+#define PSI5S_INTSETG (0xF0007308u)
+#define PSI5S_INTCLRG (0xF000730Cu)
+#define PSI5S_INTENG (0xF0007310u)
+#define PSI5S_INPG (0xF0007314u)
+#define PSI5S_OCS (0xF00073CCu)
+#define PSI5S_ACCEN0 (0xF00073D0u)
+#define PSI5S_ACCEN1 (0xF00073D4u)
+#define PSI5S_KRST0 (0xF00073D8u)
+#define PSI5S_KRST1 (0xF00073DCu)
+#define PSI5S_KRSTCLR (0xF00073E0u)
+#define MODULE_PSI5S (0xF0007000u)
+#define PSI5S_CLC (0xF0007000u)
+#define PSI5S_ID (0xF0007008u)
+#define PSI5S_FDR (0xF000700Cu)
+#define PSI5S_FDRT (0xF0007010u)
+#define PSI5S_TSCNTA (0xF0007014u)
+#define PSI5S_TSCNTB (0xF0007018u)
+#define PSI5S_GCR (0xF000701Cu)
+#define PSI5S_NFC (0xF0007020u)
+#define QSPI0_GLOBALCON (0xF0001C10u)
+#define QSPI0_GLOBALCON1 (0xF0001C14u)
+#define QSPI0_BACON (0xF0001C18u)
+#define QSPI0_ECON0 (0xF0001C20u)
+#define QSPI0_ECON1 (0xF0001C24u)
+#define QSPI0_ECON2 (0xF0001C28u)
+#define QSPI0_ECON3 (0xF0001C2Cu)
+#define QSPI0_ECON4 (0xF0001C30u)
+#define QSPI0_ECON5 (0xF0001C34u)
+#define QSPI0_ECON6 (0xF0001C38u)
+#define QSPI0_ECON7 (0xF0001C3Cu)
+#define QSPI0_STATUS (0xF0001C40u)
+#define QSPI0_STATUS1 (0xF0001C44u)
+#define QSPI0_SSOC (0xF0001C48u)
+#define QSPI0_FLAGSCLEAR (0xF0001C54u)
+#define QSPI0_XXLCON (0xF0001C58u)
+#define QSPI0_MIXENTRY (0xF0001C5Cu)
+#define QSPI0_BACONENTRY (0xF0001C60u)
+#define QSPI0_DATAENTRY0 (0xF0001C64u)
+#define QSPI0_DATAENTRY1 (0xF0001C68u)
+#define QSPI0_DATAENTRY2 (0xF0001C6Cu)
+#define QSPI0_DATAENTRY3 (0xF0001C70u)
+#define QSPI0_DATAENTRY4 (0xF0001C74u)
+#define QSPI0_DATAENTRY5 (0xF0001C78u)
+#define QSPI0_DATAENTRY6 (0xF0001C7Cu)
+#define QSPI0_DATAENTRY7 (0xF0001C80u)
+#define QSPI0_RXEXIT (0xF0001C90u)
+#define QSPI0_RXEXITD (0xF0001C94u)
+#define QSPI0_MC (0xF0001CA4u)
+#define QSPI0_MCCON (0xF0001CA8u)
+#define QSPI0_OCS (0xF0001CE8u)
+#define QSPI0_KRSTCLR (0xF0001CECu)
+#define QSPI0_KRST1 (0xF0001CF0u)
+#define QSPI0_KRST0 (0xF0001CF4u)
+#define QSPI0_ACCEN1 (0xF0001CF8u)
+#define QSPI0_ACCEN0 (0xF0001CFCu)
+#define QSPI1_CLC (0xF0001D00u)
+#define QSPI1_PISEL (0xF0001D04u)
+#define QSPI1_ID (0xF0001D08u)
+#define QSPI1_GLOBALCON (0xF0001D10u)
+#define QSPI1_GLOBALCON1 (0xF0001D14u)
+#define QSPI1_BACON (0xF0001D18u)
+#define QSPI1_ECON0 (0xF0001D20u)
+#define QSPI1_ECON1 (0xF0001D24u)
+#define QSPI1_ECON2 (0xF0001D28u)
+#define QSPI1_ECON3 (0xF0001D2Cu)
+#define QSPI1_ECON4 (0xF0001D30u)
+#define QSPI1_ECON5 (0xF0001D34u)
+#define QSPI1_ECON6 (0xF0001D38u)
+#define QSPI1_ECON7 (0xF0001D3Cu)
+#define QSPI1_STATUS (0xF0001D40u)
+#define QSPI1_STATUS1 (0xF0001D44u)
+#define QSPI1_SSOC (0xF0001D48u)
+#define QSPI1_FLAGSCLEAR (0xF0001D54u)
+#define QSPI1_XXLCON (0xF0001D58u)
+#define QSPI1_MIXENTRY (0xF0001D5Cu)
+#define QSPI1_BACONENTRY (0xF0001D60u)
+#define QSPI1_DATAENTRY0 (0xF0001D64u)
+#define QSPI1_DATAENTRY1 (0xF0001D68u)
+#define QSPI1_DATAENTRY2 (0xF0001D6Cu)
+#define QSPI1_DATAENTRY3 (0xF0001D70u)
+#define QSPI1_DATAENTRY4 (0xF0001D74u)
+#define QSPI1_DATAENTRY5 (0xF0001D78u)
+#define QSPI1_DATAENTRY6 (0xF0001D7Cu)
+#define QSPI1_DATAENTRY7 (0xF0001D80u)
+#define QSPI1_RXEXIT (0xF0001D90u)
+#define QSPI1_RXEXITD (0xF0001D94u)
+#define QSPI1_MC (0xF0001DA4u)
+#define QSPI1_MCCON (0xF0001DA8u)
+#define QSPI1_OCS (0xF0001DE8u)
+#define QSPI1_KRSTCLR (0xF0001DECu)
+#define QSPI1_KRST1 (0xF0001DF0u)
+#define QSPI1_KRST0 (0xF0001DF4u)
+#define QSPI1_ACCEN1 (0xF0001DF8u)
+#define QSPI1_ACCEN0 (0xF0001DFCu)
+#define QSPI2_CLC (0xF0001E00u)
+#define QSPI2_PISEL (0xF0001E04u)
+#define QSPI2_ID (0xF0001E08u)
+#define QSPI2_GLOBALCON (0xF0001E10u)
+#define QSPI2_GLOBALCON1 (0xF0001E14u)
+#define QSPI2_BACON (0xF0001E18u)
+#define QSPI2_ECON0 (0xF0001E20u)
+#define QSPI2_ECON1 (0xF0001E24u)
+#define QSPI2_ECON2 (0xF0001E28u)
+#define QSPI2_ECON3 (0xF0001E2Cu)
+#define QSPI2_ECON4 (0xF0001E30u)
+#define QSPI2_ECON5 (0xF0001E34u)
+#define QSPI2_ECON6 (0xF0001E38u)
+#define QSPI2_ECON7 (0xF0001E3Cu)
+#define QSPI2_STATUS (0xF0001E40u)
+#define QSPI2_STATUS1 (0xF0001E44u)
+#define QSPI2_SSOC (0xF0001E48u)
+#define QSPI2_FLAGSCLEAR (0xF0001E54u)
+#define QSPI2_XXLCON (0xF0001E58u)
+#define QSPI2_MIXENTRY (0xF0001E5Cu)
+#define QSPI2_BACONENTRY (0xF0001E60u)
+#define QSPI2_DATAENTRY0 (0xF0001E64u)
+#define QSPI2_DATAENTRY1 (0xF0001E68u)
+#define QSPI2_DATAENTRY2 (0xF0001E6Cu)
+#define QSPI2_DATAENTRY3 (0xF0001E70u)
+#define QSPI2_DATAENTRY4 (0xF0001E74u)
+#define QSPI2_DATAENTRY5 (0xF0001E78u)
+#define QSPI2_DATAENTRY6 (0xF0001E7Cu)
+#define QSPI2_DATAENTRY7 (0xF0001E80u)
+#define QSPI2_RXEXIT (0xF0001E90u)
+#define QSPI2_RXEXITD (0xF0001E94u)
+#define QSPI2_MC (0xF0001EA4u)
+#define QSPI2_MCCON (0xF0001EA8u)
+#define QSPI2_OCS (0xF0001EE8u)
+#define QSPI2_KRSTCLR (0xF0001EECu)
+#define QSPI2_KRST1 (0xF0001EF0u)
+#define QSPI2_KRST0 (0xF0001EF4u)
+#define QSPI2_ACCEN1 (0xF0001EF8u)
+#define QSPI2_ACCEN0 (0xF0001EFCu)
+#define QSPI3_CLC (0xF0001F00u)
+#define QSPI3_PISEL (0xF0001F04u)
+#define QSPI3_ID (0xF0001F08u)
+#define QSPI3_GLOBALCON (0xF0001F10u)
+#define QSPI3_GLOBALCON1 (0xF0001F14u)
+#define QSPI3_BACON (0xF0001F18u)
+#define QSPI3_ECON0 (0xF0001F20u)
+#define QSPI3_ECON1 (0xF0001F24u)
+#define QSPI3_ECON2 (0xF0001F28u)
+#define QSPI3_ECON3 (0xF0001F2Cu)
+#define QSPI3_ECON4 (0xF0001F30u)
+#define QSPI3_ECON5 (0xF0001F34u)
+#define QSPI3_ECON6 (0xF0001F38u)
+#define QSPI3_ECON7 (0xF0001F3Cu)
+#define QSPI3_STATUS (0xF0001F40u)
+#define QSPI3_STATUS1 (0xF0001F44u)
+#define QSPI3_SSOC (0xF0001F48u)
+#define QSPI3_FLAGSCLEAR (0xF0001F54u)
+#define QSPI3_XXLCON (0xF0001F58u)
+#define QSPI3_MIXENTRY (0xF0001F5Cu)
+#define QSPI3_BACONENTRY (0xF0001F60u)
+#define QSPI3_DATAENTRY0 (0xF0001F64u)
+#define QSPI3_DATAENTRY1 (0xF0001F68u)
+#define QSPI3_DATAENTRY2 (0xF0001F6Cu)
+#define QSPI3_DATAENTRY3 (0xF0001F70u)
+#define QSPI3_DATAENTRY4 (0xF0001F74u)
+#define QSPI3_DATAENTRY5 (0xF0001F78u)
+#define QSPI3_DATAENTRY6 (0xF0001F7Cu)
+#define QSPI3_DATAENTRY7 (0xF0001F80u)
+#define QSPI3_RXEXIT (0xF0001F90u)
+#define QSPI3_RXEXITD (0xF0001F94u)
+#define QSPI3_MC (0xF0001FA4u)
+#define QSPI3_MCCON (0xF0001FA8u)
+#define QSPI3_OCS (0xF0001FE8u)
+#define QSPI3_KRSTCLR (0xF0001FECu)
+#define QSPI3_KRST1 (0xF0001FF0u)
+#define QSPI3_KRST0 (0xF0001FF4u)
+#define QSPI3_ACCEN1 (0xF0001FF8u)
+#define QSPI3_ACCEN0 (0xF0001FFCu)
+#define QSPI4_CLC (0xF0002000u)
+#define QSPI4_PISEL (0xF0002004u)
+#define QSPI4_ID (0xF0002008u)
+#define QSPI4_GLOBALCON (0xF0002010u)
+#define QSPI4_GLOBALCON1 (0xF0002014u)
+#define QSPI4_BACON (0xF0002018u)
+#define QSPI4_ECON0 (0xF0002020u)
+#define QSPI4_ECON1 (0xF0002024u)
+#define QSPI4_ECON2 (0xF0002028u)
+#define QSPI4_ECON3 (0xF000202Cu)
+#define QSPI4_ECON4 (0xF0002030u)
+#define QSPI4_ECON5 (0xF0002034u)
+#define QSPI4_ECON6 (0xF0002038u)
+#define QSPI4_ECON7 (0xF000203Cu)
+#define QSPI4_STATUS (0xF0002040u)
+#define QSPI4_STATUS1 (0xF0002044u)
+#define QSPI4_SSOC (0xF0002048u)
+#define QSPI4_FLAGSCLEAR (0xF0002054u)
+#define QSPI4_XXLCON (0xF0002058u)
+#define QSPI4_MIXENTRY (0xF000205Cu)
+#define QSPI4_BACONENTRY (0xF0002060u)
+#define QSPI4_DATAENTRY0 (0xF0002064u)
+#define QSPI4_DATAENTRY1 (0xF0002068u)
+#define QSPI4_DATAENTRY2 (0xF000206Cu)
+#define QSPI4_DATAENTRY3 (0xF0002070u)
+#define QSPI4_DATAENTRY4 (0xF0002074u)
+#define QSPI4_DATAENTRY5 (0xF0002078u)
+#define QSPI4_DATAENTRY6 (0xF000207Cu)
+#define QSPI4_DATAENTRY7 (0xF0002080u)
+#define QSPI4_RXEXIT (0xF0002090u)
+#define QSPI4_RXEXITD (0xF0002094u)
+#define QSPI4_MC (0xF00020A4u)
+#define QSPI4_MCCON (0xF00020A8u)
+#define QSPI4_OCS (0xF00020E8u)
+#define QSPI4_KRSTCLR (0xF00020ECu)
+#define QSPI4_KRST1 (0xF00020F0u)
+#define QSPI4_KRST0 (0xF00020F4u)
+#define QSPI4_ACCEN1 (0xF00020F8u)
+#define QSPI4_ACCEN0 (0xF00020FCu)
+#define MODULE_QSPI0 (0xF0001C00u)
+#define MODULE_QSPI1 (0xF0001D00u)
+#define MODULE_QSPI2 (0xF0001E00u)
+#define MODULE_QSPI3 (0xF0001F00u)
+#define MODULE_QSPI4 (0xF0002000u)
+#define QSPI0_CLC (0xF0001C00u)
+#define QSPI0_PISEL (0xF0001C04u)
+#define QSPI0_ID (0xF0001C08u)
+#define SBCU_DBGRNT (0xF0030034u)
+#define SBCU_DBADR1 (0xF0030038u)
+#define SBCU_DBADR2 (0xF003003Cu)
+#define SBCU_DBBOS (0xF0030040u)
+#define SBCU_DBGNTT (0xF0030044u)
+#define SBCU_DBADRT (0xF0030048u)
+#define SBCU_DBBOST (0xF003004Cu)
+#define SBCU_DBDAT (0xF0030050u)
+#define SBCU_ALSTAT0 (0xF0030060u)
+#define SBCU_ALSTAT1 (0xF0030064u)
+#define SBCU_ALSTAT2 (0xF0030068u)
+#define SBCU_ALSTAT3 (0xF003006Cu)
+#define SBCU_ALCLR0 (0xF0030070u)
+#define SBCU_ALCLR1 (0xF0030074u)
+#define SBCU_ALCLR2 (0xF0030078u)
+#define SBCU_ALCLR3 (0xF003007Cu)
+#define SBCU_ALCTRL (0xF0030080u)
+#define SBCU_FEGEN (0xF0030084u)
+#define SBCU_ACCEN1 (0xF00300F8u)
+#define SBCU_ACCEN0 (0xF00300FCu)
+#define MODULE_SBCU (0xF0030000u)
+#define SBCU_ID (0xF0030008u)
+#define SBCU_CON (0xF0030010u)
+#define SBCU_PRIOH (0xF0030014u)
+#define SBCU_PRIOL (0xF0030018u)
+#define SBCU_ECON (0xF0030020u)
+#define SBCU_EADD (0xF0030024u)
+#define SBCU_EDAT (0xF0030028u)
+#define SBCU_DBCNTL (0xF0030030u)
+#define SCU_PERPLLCON1 (0xF003602Cu)
+#define SCU_CCUCON0 (0xF0036030u)
+#define SCU_CCUCON1 (0xF0036034u)
+#define SCU_FDR (0xF0036038u)
+#define SCU_EXTCON (0xF003603Cu)
+#define SCU_CCUCON2 (0xF0036040u)
+#define SCU_CCUCON3 (0xF0036044u)
+#define SCU_CCUCON4 (0xF0036048u)
+#define SCU_CCUCON5 (0xF003604Cu)
+#define SCU_RSTSTAT (0xF0036050u)
+#define SCU_RSTCON (0xF0036058u)
+#define SCU_ARSTDIS (0xF003605Cu)
+#define SCU_SWRSTCON (0xF0036060u)
+#define SCU_RSTCON2 (0xF0036064u)
+#define SCU_RSTCON3 (0xF0036068u)
+#define SCU_ESRCFGX0_ESRCFGX (0xF0036070u)
+#define SCU_ESRCFGX1_ESRCFGX (0xF0036074u)
+#define SCU_ESROCFG (0xF0036078u)
+#define SCU_SYSCON (0xF003607Cu)
+#define SCU_CCUCON6 (0xF0036080u)
+#define SCU_CCUCON7 (0xF0036084u)
+#define SCU_CCUCON8 (0xF0036088u)
+#define SCU_PDR (0xF003609Cu)
+#define SCU_IOCR (0xF00360A0u)
+#define SCU_OUT (0xF00360A4u)
+#define SCU_OMR (0xF00360A8u)
+#define SCU_IN (0xF00360ACu)
+#define SCU_STSTAT (0xF00360C0u)
+#define SCU_STCON (0xF00360C4u)
+#define SCU_PMCSR0 (0xF00360C8u)
+#define SCU_PMCSR1 (0xF00360CCu)
+#define SCU_PMCSR2 (0xF00360D0u)
+#define SCU_PMCSR3 (0xF00360D4u)
+#define SCU_PMCSR4 (0xF00360D8u)
+#define SCU_PMCSR5 (0xF00360DCu)
+#define SCU_PMSTAT0 (0xF00360E4u)
+#define SCU_PMSWCR1 (0xF00360E8u)
+#define SCU_EMSR (0xF00360FCu)
+#define SCU_EMSSW (0xF0036100u)
+#define SCU_DTSCSTAT (0xF0036104u)
+#define SCU_DTSCLIM (0xF0036108u)
+#define SCU_TRAPDIS1 (0xF0036120u)
+#define SCU_TRAPSTAT (0xF0036124u)
+#define SCU_TRAPSET (0xF0036128u)
+#define SCU_TRAPCLR (0xF003612Cu)
+#define SCU_TRAPDIS0 (0xF0036130u)
+#define SCU_LCLCON0 (0xF0036134u)
+#define SCU_LCLCON1 (0xF0036138u)
+#define SCU_LCLTEST (0xF003613Cu)
+#define SCU_CHIPID (0xF0036140u)
+#define SCU_MANID (0xF0036144u)
+#define SCU_SWAPCTRL (0xF003614Cu)
+#define SCU_LBISTCTRL0 (0xF0036164u)
+#define SCU_LBISTCTRL1 (0xF0036168u)
+#define SCU_LBISTCTRL2 (0xF003616Cu)
+#define SCU_LBISTCTRL3 (0xF0036170u)
+#define SCU_STMEM1 (0xF0036184u)
+#define SCU_STMEM2 (0xF0036188u)
+#define SCU_PDISC (0xF003618Cu)
+#define SCU_PMTRCSR0 (0xF0036198u)
+#define SCU_PMTRCSR1 (0xF003619Cu)
+#define SCU_PMTRCSR2 (0xF00361A0u)
+#define SCU_PMTRCSR3 (0xF00361A4u)
+#define SCU_STMEM3 (0xF00361C0u)
+#define SCU_STMEM4 (0xF00361C4u)
+#define SCU_STMEM5 (0xF00361C8u)
+#define SCU_STMEM6 (0xF00361CCu)
+#define SCU_OVCENABLE (0xF00361E0u)
+#define SCU_OVCCON (0xF00361E4u)
+#define SCU_EIFILT (0xF003620Cu)
+#define SCU_EICR0 (0xF0036210u)
+#define SCU_EICR1 (0xF0036214u)
+#define SCU_EICR2 (0xF0036218u)
+#define SCU_EICR3 (0xF003621Cu)
+#define SCU_EIFR (0xF0036220u)
+#define SCU_FMR (0xF0036224u)
+#define SCU_PDRR (0xF0036228u)
+#define SCU_IGCR0 (0xF003622Cu)
+#define SCU_IGCR1 (0xF0036230u)
+#define SCU_IGCR2 (0xF0036234u)
+#define SCU_IGCR3 (0xF0036238u)
+#define SCU_WDTCPU0_CON0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SCU_WDTCPU_CON0*)0xF003624Cu)
+#define SCU_WDTCPU0_CON1 (0xF0036250u)
+#define SCU_WDTCPU0_SR (0xF0036254u)
+#define SCU_WDTCPU1_CON0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SCU_WDTCPU_CON0*)0xF0036258u)
+#define SCU_WDTCPU1_CON1 (0xF003625Cu)
+#define SCU_WDTCPU1_SR (0xF0036260u)
+#define SCU_WDTCPU2_CON0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SCU_WDTCPU_CON0*)0xF0036264u)
+#define SCU_WDTCPU2_CON1 (0xF0036268u)
+#define SCU_WDTCPU2_SR (0xF003626Cu)
+#define SCU_EICON0 (0xF003629Cu)
+#define SCU_EICON1 (0xF00362A0u)
+#define SCU_EISR (0xF00362A4u)
+#define SCU_WDTS_CON0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SCU_WDTS_CON0*)0xF00362A8u)
+#define SCU_WDTS_CON1 (0xF00362ACu)
+#define SCU_WDTS_SR (0xF00362B0u)
+#define SCU_SEICON0 (0xF00362B4u)
+#define SCU_SEICON1 (0xF00362B8u)
+#define SCU_SEISR (0xF00362BCu)
+#define SCU_ACCEN11 (0xF00363F0u)
+#define SCU_ACCEN10 (0xF00363F4u)
+#define SCU_ACCEN01 (0xF00363F8u)
+#define SCU_ACCEN00 (0xF00363FCu)
+#define MODULE_SCU (0xF0036000u)
+#define SCU_ID (0xF0036008u)
+#define SCU_OSCCON (0xF0036010u)
+#define SCU_SYSPLLSTAT (0xF0036014u)
+#define SCU_SYSPLLCON0 (0xF0036018u)
+#define SCU_SYSPLLCON1 (0xF003601Cu)
+#define SCU_SYSPLLCON2 (0xF0036020u)
+#define SCU_PERPLLSTAT (0xF0036024u)
+#define SCU_PERPLLCON0 (0xF0036028u)
+#define SENT_CH8_INTSET (0xF0003324u)
+#define SENT_RDR2 (0xF0003088u)
+#define SENT_CH8_INTCLR (0xF0003328u)
+#define SENT_CH8_INTEN (0xF000332Cu)
+#define SENT_CH8_INP (0xF0003330u)
+#define SENT_CH8_WDT (0xF0003334u)
+#define SENT_CH9_CPDR (0xF0003340u)
+#define SENT_RDR3 (0xF000308Cu)
+#define SENT_CH9_CFDR (0xF0003344u)
+#define SENT_CH9_RCR (0xF0003348u)
+#define SENT_CH9_RSR (0xF000334Cu)
+#define SENT_CH9_SDS (0xF0003350u)
+#define SENT_RDR4 (0xF0003090u)
+#define SENT_CH9_IOCR (0xF0003354u)
+#define SENT_CH9_SCR (0xF0003358u)
+#define SENT_CH9_VIEW (0xF000335Cu)
+#define SENT_CH9_INTSTAT (0xF0003360u)
+#define SENT_RDR5 (0xF0003094u)
+#define SENT_CH9_INTSET (0xF0003364u)
+#define SENT_CH9_INTCLR (0xF0003368u)
+#define SENT_CH9_INTEN (0xF000336Cu)
+#define SENT_CH9_INP (0xF0003370u)
+#define SENT_RDR6 (0xF0003098u)
+#define SENT_CH9_WDT (0xF0003374u)
+#define SENT_CH10_CPDR (0xF0003380u)
+#define SENT_CH10_CFDR (0xF0003384u)
+#define SENT_CH10_RCR (0xF0003388u)
+#define SENT_CH10_RSR (0xF000338Cu)
+#define SENT_RDR7 (0xF000309Cu)
+#define SENT_CH10_SDS (0xF0003390u)
+#define SENT_CH10_IOCR (0xF0003394u)
+#define SENT_CH10_SCR (0xF0003398u)
+#define SENT_CH10_VIEW (0xF000339Cu)
+#define SENT_RDR8 (0xF00030A0u)
+#define SENT_CH10_INTSTAT (0xF00033A0u)
+#define SENT_CH10_INTSET (0xF00033A4u)
+#define SENT_CH10_INTCLR (0xF00033A8u)
+#define SENT_CH10_INTEN (0xF00033ACu)
+#define SENT_RDR9 (0xF00030A4u)
+#define SENT_CH10_INP (0xF00033B0u)
+#define SENT_CH10_WDT (0xF00033B4u)
+#define SENT_CH11_CPDR (0xF00033C0u)
+#define SENT_CH11_CFDR (0xF00033C4u)
+#define SENT_CH11_RCR (0xF00033C8u)
+#define SENT_RDR10 (0xF00030A8u)
+#define SENT_CH11_RSR (0xF00033CCu)
+#define SENT_CH11_SDS (0xF00033D0u)
+#define SENT_CH11_IOCR (0xF00033D4u)
+#define SENT_CH11_SCR (0xF00033D8u)
+#define SENT_RDR11 (0xF00030ACu)
+#define SENT_CH11_VIEW (0xF00033DCu)
+#define SENT_CH11_INTSTAT (0xF00033E0u)
+#define SENT_CH11_INTSET (0xF00033E4u)
+#define SENT_CH11_INTCLR (0xF00033E8u)
+#define SENT_RDR12 (0xF00030B0u)
+#define SENT_CH11_INTEN (0xF00033ECu)
+#define SENT_CH11_INP (0xF00033F0u)
+#define SENT_CH11_WDT (0xF00033F4u)
+#define SENT_CH12_CPDR (0xF0003400u)
+#define SENT_RDR13 (0xF00030B4u)
+#define SENT_CH12_CFDR (0xF0003404u)
+#define SENT_CH12_RCR (0xF0003408u)
+#define SENT_CH12_RSR (0xF000340Cu)
+#define SENT_CH12_SDS (0xF0003410u)
+#define SENT_CH12_IOCR (0xF0003414u)
+#define SENT_RDR14 (0xF00030B8u)
+#define SENT_CH12_SCR (0xF0003418u)
+#define SENT_CH12_VIEW (0xF000341Cu)
+#define SENT_CH12_INTSTAT (0xF0003420u)
+#define SENT_CH12_INTSET (0xF0003424u)
+#define SENT_OCS (0xF00030E8u)
+#define SENT_CH12_INTCLR (0xF0003428u)
+#define SENT_CH12_INTEN (0xF000342Cu)
+#define SENT_CH12_INP (0xF0003430u)
+#define SENT_CH12_WDT (0xF0003434u)
+#define SENT_KRSTCLR (0xF00030ECu)
+#define SENT_CH13_CPDR (0xF0003440u)
+#define SENT_CH13_CFDR (0xF0003444u)
+#define SENT_CH13_RCR (0xF0003448u)
+#define SENT_CH13_RSR (0xF000344Cu)
+#define SENT_CH13_SDS (0xF0003450u)
+#define SENT_KRST1 (0xF00030F0u)
+#define SENT_CH13_IOCR (0xF0003454u)
+#define SENT_CH13_SCR (0xF0003458u)
+#define SENT_CH13_VIEW (0xF000345Cu)
+#define SENT_CH13_INTSTAT (0xF0003460u)
+#define SENT_KRST0 (0xF00030F4u)
+#define SENT_CH13_INTSET (0xF0003464u)
+#define SENT_CH13_INTCLR (0xF0003468u)
+#define SENT_CH13_INTEN (0xF000346Cu)
+#define SENT_CH13_INP (0xF0003470u)
+#define SENT_ACCEN1 (0xF00030F8u)
+#define SENT_CH13_WDT (0xF0003474u)
+#define SENT_CH14_CPDR (0xF0003480u)
+#define SENT_CH14_CFDR (0xF0003484u)
+#define SENT_CH14_RCR (0xF0003488u)
+#define SENT_ACCEN0 (0xF00030FCu)
+#define SENT_CH14_RSR (0xF000348Cu)
+#define SENT_CH14_SDS (0xF0003490u)
+#define SENT_CH14_IOCR (0xF0003494u)
+#define SENT_CH14_SCR (0xF0003498u)
+#define SENT_CH14_VIEW (0xF000349Cu)
+#define SENT_CH0_CPDR (0xF0003100u)
+#define SENT_CH14_INTSTAT (0xF00034A0u)
+#define SENT_CH14_INTSET (0xF00034A4u)
+#define SENT_CH14_INTCLR (0xF00034A8u)
+#define SENT_CH14_INTEN (0xF00034ACu)
+#define SENT_CH14_INP (0xF00034B0u)
+#define SENT_CH14_WDT (0xF00034B4u)
+#define SENT_RTS0 (0xF0003A80u)
+#define SENT_RTS1 (0xF0003A84u)
+#define SENT_RTS2 (0xF0003A88u)
+#define SENT_RTS3 (0xF0003A8Cu)
+#define SENT_RTS4 (0xF0003A90u)
+#define SENT_RTS5 (0xF0003A94u)
+#define SENT_RTS6 (0xF0003A98u)
+#define SENT_RTS7 (0xF0003A9Cu)
+#define SENT_CH0_CFDR (0xF0003104u)
+#define SENT_RTS8 (0xF0003AA0u)
+#define SENT_RTS9 (0xF0003AA4u)
+#define SENT_RTS10 (0xF0003AA8u)
+#define SENT_RTS11 (0xF0003AACu)
+#define SENT_RTS12 (0xF0003AB0u)
+#define SENT_RTS13 (0xF0003AB4u)
+#define SENT_RTS14 (0xF0003AB8u)
+#define SENT_CH0_RCR (0xF0003108u)
+#define SENT_CH0_RSR (0xF000310Cu)
+#define SENT_CH0_SDS (0xF0003110u)
+#define SENT_CH0_IOCR (0xF0003114u)
+#define SENT_CH0_SCR (0xF0003118u)
+#define SENT_CH0_VIEW (0xF000311Cu)
+#define SENT_CH0_INTSTAT (0xF0003120u)
+#define SENT_CH0_INTSET (0xF0003124u)
+#define SENT_CH0_INTCLR (0xF0003128u)
+#define SENT_CH0_INTEN (0xF000312Cu)
+#define SENT_CH0_INP (0xF0003130u)
+#define SENT_CH0_WDT (0xF0003134u)
+#define SENT_CH1_CPDR (0xF0003140u)
+#define SENT_CH1_CFDR (0xF0003144u)
+#define SENT_CH1_RCR (0xF0003148u)
+#define SENT_CH1_RSR (0xF000314Cu)
+#define SENT_CH1_SDS (0xF0003150u)
+#define SENT_CH1_IOCR (0xF0003154u)
+#define SENT_CH1_SCR (0xF0003158u)
+#define SENT_CH1_VIEW (0xF000315Cu)
+#define SENT_CH1_INTSTAT (0xF0003160u)
+#define SENT_CH1_INTSET (0xF0003164u)
+#define SENT_CH1_INTCLR (0xF0003168u)
+#define SENT_CH1_INTEN (0xF000316Cu)
+#define SENT_CH1_INP (0xF0003170u)
+#define SENT_CH1_WDT (0xF0003174u)
+#define SENT_CH2_CPDR (0xF0003180u)
+#define SENT_CH2_CFDR (0xF0003184u)
+#define SENT_CH2_RCR (0xF0003188u)
+#define SENT_CH2_RSR (0xF000318Cu)
+#define SENT_CH2_SDS (0xF0003190u)
+#define SENT_CH2_IOCR (0xF0003194u)
+#define SENT_CH2_SCR (0xF0003198u)
+#define SENT_CH2_VIEW (0xF000319Cu)
+#define SENT_CH2_INTSTAT (0xF00031A0u)
+#define SENT_CH2_INTSET (0xF00031A4u)
+#define SENT_CH2_INTCLR (0xF00031A8u)
+#define SENT_CH2_INTEN (0xF00031ACu)
+#define SENT_CH2_INP (0xF00031B0u)
+#define SENT_CH2_WDT (0xF00031B4u)
+#define SENT_CH3_CPDR (0xF00031C0u)
+#define SENT_CH3_CFDR (0xF00031C4u)
+#define SENT_CH3_RCR (0xF00031C8u)
+#define SENT_CH3_RSR (0xF00031CCu)
+#define SENT_CH3_SDS (0xF00031D0u)
+#define SENT_CH3_IOCR (0xF00031D4u)
+#define SENT_CH3_SCR (0xF00031D8u)
+#define SENT_CH3_VIEW (0xF00031DCu)
+#define SENT_CH3_INTSTAT (0xF00031E0u)
+#define SENT_CH3_INTSET (0xF00031E4u)
+#define SENT_CH3_INTCLR (0xF00031E8u)
+#define SENT_CH3_INTEN (0xF00031ECu)
+#define SENT_CH3_INP (0xF00031F0u)
+#define SENT_CH3_WDT (0xF00031F4u)
+#define SENT_CH4_CPDR (0xF0003200u)
+#define SENT_CH4_CFDR (0xF0003204u)
+#define SENT_CH4_RCR (0xF0003208u)
+#define SENT_CH4_RSR (0xF000320Cu)
+#define SENT_CH4_SDS (0xF0003210u)
+#define SENT_CH4_IOCR (0xF0003214u)
+#define SENT_CH4_SCR (0xF0003218u)
+#define SENT_CH4_VIEW (0xF000321Cu)
+#define SENT_CH4_INTSTAT (0xF0003220u)
+#define SENT_CH4_INTSET (0xF0003224u)
+#define SENT_CH4_INTCLR (0xF0003228u)
+#define SENT_CH4_INTEN (0xF000322Cu)
+#define SENT_CH4_INP (0xF0003230u)
+#define SENT_CH4_WDT (0xF0003234u)
+#define SENT_CH5_CPDR (0xF0003240u)
+#define SENT_CH5_CFDR (0xF0003244u)
+#define SENT_CH5_RCR (0xF0003248u)
+#define SENT_CH5_RSR (0xF000324Cu)
+#define SENT_CH5_SDS (0xF0003250u)
+#define MODULE_SENT (0xF0003000u)
+#define SENT_CH5_IOCR (0xF0003254u)
+#define SENT_CH5_SCR (0xF0003258u)
+#define SENT_CH5_VIEW (0xF000325Cu)
+#define SENT_CH5_INTSTAT (0xF0003260u)
+#define SENT_CH5_INTSET (0xF0003264u)
+#define SENT_CH5_INTCLR (0xF0003268u)
+#define SENT_CH5_INTEN (0xF000326Cu)
+#define SENT_CH5_INP (0xF0003270u)
+#define SENT_CH5_WDT (0xF0003274u)
+#define SENT_CH6_CPDR (0xF0003280u)
+#define SENT_CH6_CFDR (0xF0003284u)
+#define SENT_CH6_RCR (0xF0003288u)
+#define SENT_CH6_RSR (0xF000328Cu)
+#define SENT_CLC (0xF0003000u)
+#define SENT_CH6_SDS (0xF0003290u)
+#define SENT_CH6_IOCR (0xF0003294u)
+#define SENT_CH6_SCR (0xF0003298u)
+#define SENT_CH6_VIEW (0xF000329Cu)
+#define SENT_ID (0xF0003008u)
+#define SENT_CH6_INTSTAT (0xF00032A0u)
+#define SENT_CH6_INTSET (0xF00032A4u)
+#define SENT_CH6_INTCLR (0xF00032A8u)
+#define SENT_CH6_INTEN (0xF00032ACu)
+#define SENT_CH6_INP (0xF00032B0u)
+#define SENT_FDR (0xF000300Cu)
+#define SENT_CH6_WDT (0xF00032B4u)
+#define SENT_CH7_CPDR (0xF00032C0u)
+#define SENT_CH7_CFDR (0xF00032C4u)
+#define SENT_CH7_RCR (0xF00032C8u)
+#define SENT_INTOV (0xF0003014u)
+#define SENT_CH7_RSR (0xF00032CCu)
+#define SENT_CH7_SDS (0xF00032D0u)
+#define SENT_CH7_IOCR (0xF00032D4u)
+#define SENT_CH7_SCR (0xF00032D8u)
+#define SENT_TSR (0xF0003018u)
+#define SENT_CH7_VIEW (0xF00032DCu)
+#define SENT_CH7_INTSTAT (0xF00032E0u)
+#define SENT_CH7_INTSET (0xF00032E4u)
+#define SENT_CH7_INTCLR (0xF00032E8u)
+#define SENT_TPD (0xF000301Cu)
+#define SENT_CH7_INTEN (0xF00032ECu)
+#define SENT_CH7_INP (0xF00032F0u)
+#define SENT_CH7_WDT (0xF00032F4u)
+#define SENT_CH8_CPDR (0xF0003300u)
+#define SENT_CH8_CFDR (0xF0003304u)
+#define SENT_RDR0 (0xF0003080u)
+#define SENT_CH8_RCR (0xF0003308u)
+#define SENT_CH8_RSR (0xF000330Cu)
+#define SENT_CH8_SDS (0xF0003310u)
+#define SENT_CH8_IOCR (0xF0003314u)
+#define SENT_RDR1 (0xF0003084u)
+#define SENT_CH8_SCR (0xF0003318u)
+#define SENT_CH8_VIEW (0xF000331Cu)
+#define SENT_CH8_INTSTAT (0xF0003320u)
+#define SMU_DBG (0xF0036838u)
+#define SMU_PCTL (0xF003683Cu)
+#define SMU_AFCNT (0xF0036840u)
+#define SMU_RTAC00 (0xF0036860u)
+#define SMU_RTAC01 (0xF0036864u)
+#define SMU_RTAC10 (0xF0036868u)
+#define SMU_RTAC11 (0xF003686Cu)
+#define SMU_AEX (0xF0036870u)
+#define SMU_AEXCLR (0xF0036874u)
+#define SMU_AGCF0_0 (0xF0036900u)
+#define SMU_AGCF0_1 (0xF0036904u)
+#define SMU_AGCF0_2 (0xF0036908u)
+#define SMU_AGCF1_0 (0xF003690Cu)
+#define SMU_AGCF1_1 (0xF0036910u)
+#define SMU_AGCF1_2 (0xF0036914u)
+#define SMU_AGCF2_0 (0xF0036918u)
+#define SMU_AGCF2_1 (0xF003691Cu)
+#define SMU_AGCF2_2 (0xF0036920u)
+#define SMU_AGCF3_0 (0xF0036924u)
+#define SMU_AGCF3_1 (0xF0036928u)
+#define SMU_AGCF3_2 (0xF003692Cu)
+#define SMU_AGCF4_0 (0xF0036930u)
+#define SMU_AGCF4_1 (0xF0036934u)
+#define SMU_AGCF4_2 (0xF0036938u)
+#define SMU_AGCF5_0 (0xF003693Cu)
+#define SMU_AGCF5_1 (0xF0036940u)
+#define SMU_AGCF5_2 (0xF0036944u)
+#define SMU_AGCF6_0 (0xF0036948u)
+#define SMU_AGCF6_1 (0xF003694Cu)
+#define SMU_AGCF6_2 (0xF0036950u)
+#define SMU_AGCF7_0 (0xF0036954u)
+#define SMU_AGCF7_1 (0xF0036958u)
+#define SMU_AGCF7_2 (0xF003695Cu)
+#define SMU_AGCF8_0 (0xF0036960u)
+#define SMU_AGCF8_1 (0xF0036964u)
+#define SMU_AGCF8_2 (0xF0036968u)
+#define SMU_AGCF9_0 (0xF003696Cu)
+#define SMU_AGCF9_1 (0xF0036970u)
+#define SMU_AGCF9_2 (0xF0036974u)
+#define SMU_AGCF10_0 (0xF0036978u)
+#define SMU_AGCF10_1 (0xF003697Cu)
+#define SMU_AGCF10_2 (0xF0036980u)
+#define SMU_AGCF11_0 (0xF0036984u)
+#define SMU_AGCF11_1 (0xF0036988u)
+#define SMU_AGCF11_2 (0xF003698Cu)
+#define SMU_AGFSP0 (0xF0036990u)
+#define SMU_AGFSP1 (0xF0036994u)
+#define SMU_AGFSP2 (0xF0036998u)
+#define SMU_AGFSP3 (0xF003699Cu)
+#define SMU_AGFSP4 (0xF00369A0u)
+#define SMU_AGFSP5 (0xF00369A4u)
+#define SMU_AGFSP6 (0xF00369A8u)
+#define SMU_AGFSP7 (0xF00369ACu)
+#define SMU_AGFSP8 (0xF00369B0u)
+#define SMU_AGFSP9 (0xF00369B4u)
+#define SMU_AGFSP10 (0xF00369B8u)
+#define SMU_AGFSP11 (0xF00369BCu)
+#define SMU_AG0 (0xF00369C0u)
+#define SMU_AG1 (0xF00369C4u)
+#define SMU_AG2 (0xF00369C8u)
+#define SMU_AG3 (0xF00369CCu)
+#define SMU_AG4 (0xF00369D0u)
+#define SMU_AG5 (0xF00369D4u)
+#define SMU_AG6 (0xF00369D8u)
+#define SMU_AG7 (0xF00369DCu)
+#define SMU_AG8 (0xF00369E0u)
+#define SMU_AG9 (0xF00369E4u)
+#define SMU_AG10 (0xF00369E8u)
+#define SMU_AG11 (0xF00369ECu)
+#define SMU_AD0 (0xF0036A00u)
+#define SMU_AD1 (0xF0036A04u)
+#define SMU_AD2 (0xF0036A08u)
+#define SMU_AD3 (0xF0036A0Cu)
+#define SMU_AD4 (0xF0036A10u)
+#define SMU_AD5 (0xF0036A14u)
+#define SMU_AD6 (0xF0036A18u)
+#define SMU_AD7 (0xF0036A1Cu)
+#define SMU_AD8 (0xF0036A20u)
+#define SMU_AD9 (0xF0036A24u)
+#define SMU_AD10 (0xF0036A28u)
+#define SMU_AD11 (0xF0036A2Cu)
+#define SMU_RMCTL (0xF0036B00u)
+#define SMU_RMEF (0xF0036B04u)
+#define SMU_RMSTS (0xF0036B08u)
+#define SMU_OCS (0xF0036FE8u)
+#define SMU_ACCEN1 (0xF0036FF8u)
+#define SMU_ACCEN0 (0xF0036FFCu)
+#define MODULE_SMU (0xF0036800u)
+#define SMU_CLC (0xF0036800u)
+#define SMU_ID (0xF0036808u)
+#define SMU_CMD (0xF0036820u)
+#define SMU_STS (0xF0036824u)
+#define SMU_FSP (0xF0036828u)
+#define SMU_AGC (0xF003682Cu)
+#define SMU_RTC (0xF0036830u)
+#define SMU_KEYS (0xF0036834u)
+#define SRC_DMA_DMA0_ERR3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003834Cu)
+#define SRC_DMA_DMA0_CH0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038370u)
+#define SRC_DMA_DMA0_CH1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038374u)
+#define SRC_DMA_DMA0_CH2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038378u)
+#define SRC_DMA_DMA0_CH3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003837Cu)
+#define SRC_DMA_DMA0_CH4 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038380u)
+#define SRC_DMA_DMA0_CH5 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038384u)
+#define SRC_XBAR0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038030u)
+#define SRC_DMA_DMA0_CH6 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038388u)
+#define SRC_DMA_DMA0_CH7 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003838Cu)
+#define SRC_DMA_DMA0_CH8 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038390u)
+#define SRC_DMA_DMA0_CH9 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038394u)
+#define SRC_CERBERUS_CERBERUS_SR0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038040u)
+#define SRC_DMA_DMA0_CH10 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038398u)
+#define SRC_DMA_DMA0_CH11 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003839Cu)
+#define SRC_DMA_DMA0_CH12 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00383A0u)
+#define SRC_DMA_DMA0_CH13 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00383A4u)
+#define SRC_DMA_DMA0_CH14 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00383A8u)
+#define SRC_DMA_DMA0_CH15 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00383ACu)
+#define SRC_DMA_DMA0_CH16 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00383B0u)
+#define SRC_DMA_DMA0_CH17 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00383B4u)
+#define SRC_DMA_DMA0_CH18 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00383B8u)
+#define SRC_DMA_DMA0_CH19 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00383BCu)
+#define SRC_CERBERUS_CERBERUS_SR1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038044u)
+#define SRC_DMA_DMA0_CH20 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00383C0u)
+#define SRC_DMA_DMA0_CH21 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00383C4u)
+#define SRC_DMA_DMA0_CH22 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00383C8u)
+#define SRC_DMA_DMA0_CH23 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00383CCu)
+#define SRC_DMA_DMA0_CH24 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00383D0u)
+#define SRC_DMA_DMA0_CH25 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00383D4u)
+#define SRC_DMA_DMA0_CH26 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00383D8u)
+#define SRC_DMA_DMA0_CH27 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00383DCu)
+#define SRC_DMA_DMA0_CH28 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00383E0u)
+#define SRC_DMA_DMA0_CH29 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00383E4u)
+#define SRC_ASCLIN_ASCLIN0_TX /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038050u)
+#define SRC_DMA_DMA0_CH30 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00383E8u)
+#define SRC_DMA_DMA0_CH31 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00383ECu)
+#define SRC_DMA_DMA0_CH32 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00383F0u)
+#define SRC_DMA_DMA0_CH33 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00383F4u)
+#define SRC_DMA_DMA0_CH34 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00383F8u)
+#define SRC_DMA_DMA0_CH35 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00383FCu)
+#define SRC_DMA_DMA0_CH36 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038400u)
+#define SRC_DMA_DMA0_CH37 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038404u)
+#define SRC_DMA_DMA0_CH38 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038408u)
+#define SRC_DMA_DMA0_CH39 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003840Cu)
+#define SRC_ASCLIN_ASCLIN0_RX /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038054u)
+#define SRC_DMA_DMA0_CH40 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038410u)
+#define SRC_DMA_DMA0_CH41 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038414u)
+#define SRC_DMA_DMA0_CH42 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038418u)
+#define SRC_DMA_DMA0_CH43 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003841Cu)
+#define SRC_DMA_DMA0_CH44 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038420u)
+#define SRC_DMA_DMA0_CH45 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038424u)
+#define SRC_DMA_DMA0_CH46 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038428u)
+#define SRC_DMA_DMA0_CH47 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003842Cu)
+#define SRC_DMA_DMA0_CH48 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038430u)
+#define SRC_DMA_DMA0_CH49 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038434u)
+#define SRC_ASCLIN_ASCLIN0_ERR /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038058u)
+#define SRC_DMA_DMA0_CH50 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038438u)
+#define SRC_DMA_DMA0_CH51 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003843Cu)
+#define SRC_DMA_DMA0_CH52 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038440u)
+#define SRC_DMA_DMA0_CH53 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038444u)
+#define SRC_DMA_DMA0_CH54 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038448u)
+#define SRC_DMA_DMA0_CH55 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003844Cu)
+#define SRC_DMA_DMA0_CH56 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038450u)
+#define SRC_DMA_DMA0_CH57 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038454u)
+#define SRC_DMA_DMA0_CH58 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038458u)
+#define SRC_DMA_DMA0_CH59 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003845Cu)
+#define SRC_ASCLIN_ASCLIN1_TX /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003805Cu)
+#define SRC_DMA_DMA0_CH60 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038460u)
+#define SRC_DMA_DMA0_CH61 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038464u)
+#define SRC_DMA_DMA0_CH62 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038468u)
+#define SRC_DMA_DMA0_CH63 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003846Cu)
+#define SRC_DMA_DMA0_CH64 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038470u)
+#define SRC_DMA_DMA0_CH65 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038474u)
+#define SRC_DMA_DMA0_CH66 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038478u)
+#define SRC_DMA_DMA0_CH67 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003847Cu)
+#define SRC_DMA_DMA0_CH68 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038480u)
+#define SRC_DMA_DMA0_CH69 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038484u)
+#define SRC_ASCLIN_ASCLIN1_RX /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038060u)
+#define SRC_DMA_DMA0_CH70 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038488u)
+#define SRC_DMA_DMA0_CH71 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003848Cu)
+#define SRC_DMA_DMA0_CH72 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038490u)
+#define SRC_DMA_DMA0_CH73 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038494u)
+#define SRC_DMA_DMA0_CH74 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038498u)
+#define SRC_DMA_DMA0_CH75 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003849Cu)
+#define SRC_DMA_DMA0_CH76 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00384A0u)
+#define SRC_DMA_DMA0_CH77 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00384A4u)
+#define SRC_DMA_DMA0_CH78 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00384A8u)
+#define SRC_DMA_DMA0_CH79 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00384ACu)
+#define SRC_ASCLIN_ASCLIN1_ERR /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038064u)
+#define SRC_DMA_DMA0_CH80 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00384B0u)
+#define SRC_DMA_DMA0_CH81 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00384B4u)
+#define SRC_DMA_DMA0_CH82 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00384B8u)
+#define SRC_DMA_DMA0_CH83 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00384BCu)
+#define SRC_DMA_DMA0_CH84 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00384C0u)
+#define SRC_DMA_DMA0_CH85 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00384C4u)
+#define SRC_DMA_DMA0_CH86 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00384C8u)
+#define SRC_DMA_DMA0_CH87 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00384CCu)
+#define SRC_DMA_DMA0_CH88 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00384D0u)
+#define SRC_DMA_DMA0_CH89 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00384D4u)
+#define SRC_ASCLIN_ASCLIN2_TX /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038068u)
+#define SRC_DMA_DMA0_CH90 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00384D8u)
+#define SRC_DMA_DMA0_CH91 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00384DCu)
+#define SRC_DMA_DMA0_CH92 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00384E0u)
+#define SRC_DMA_DMA0_CH93 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00384E4u)
+#define SRC_DMA_DMA0_CH94 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00384E8u)
+#define SRC_DMA_DMA0_CH95 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00384ECu)
+#define SRC_DMA_DMA0_CH96 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00384F0u)
+#define SRC_DMA_DMA0_CH97 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00384F4u)
+#define SRC_DMA_DMA0_CH98 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00384F8u)
+#define SRC_DMA_DMA0_CH99 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00384FCu)
+#define SRC_ASCLIN_ASCLIN2_RX /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003806Cu)
+#define SRC_DMA_DMA0_CH100 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038500u)
+#define SRC_DMA_DMA0_CH101 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038504u)
+#define SRC_DMA_DMA0_CH102 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038508u)
+#define SRC_DMA_DMA0_CH103 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003850Cu)
+#define SRC_DMA_DMA0_CH104 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038510u)
+#define SRC_DMA_DMA0_CH105 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038514u)
+#define SRC_DMA_DMA0_CH106 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038518u)
+#define SRC_DMA_DMA0_CH107 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003851Cu)
+#define SRC_DMA_DMA0_CH108 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038520u)
+#define SRC_DMA_DMA0_CH109 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038524u)
+#define SRC_ASCLIN_ASCLIN2_ERR /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038070u)
+#define SRC_DMA_DMA0_CH110 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038528u)
+#define SRC_DMA_DMA0_CH111 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003852Cu)
+#define SRC_DMA_DMA0_CH112 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038530u)
+#define SRC_DMA_DMA0_CH113 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038534u)
+#define SRC_DMA_DMA0_CH114 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038538u)
+#define SRC_DMA_DMA0_CH115 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003853Cu)
+#define SRC_DMA_DMA0_CH116 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038540u)
+#define SRC_DMA_DMA0_CH117 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038544u)
+#define SRC_DMA_DMA0_CH118 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038548u)
+#define SRC_DMA_DMA0_CH119 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003854Cu)
+#define SRC_ASCLIN_ASCLIN3_TX /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038074u)
+#define SRC_DMA_DMA0_CH120 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038550u)
+#define SRC_DMA_DMA0_CH121 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038554u)
+#define SRC_DMA_DMA0_CH122 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038558u)
+#define SRC_DMA_DMA0_CH123 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003855Cu)
+#define SRC_DMA_DMA0_CH124 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038560u)
+#define SRC_DMA_DMA0_CH125 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038564u)
+#define SRC_DMA_DMA0_CH126 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038568u)
+#define SRC_DMA_DMA0_CH127 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003856Cu)
+#define SRC_GETH_GETH0_SR0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038580u)
+#define SRC_GETH_GETH0_SR1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038584u)
+#define SRC_ASCLIN_ASCLIN3_RX /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038078u)
+#define SRC_GETH_GETH0_SR2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038588u)
+#define SRC_GETH_GETH0_SR3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003858Cu)
+#define SRC_GETH_GETH0_SR4 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038590u)
+#define SRC_GETH_GETH0_SR5 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038594u)
+#define SRC_GETH_GETH0_SR6 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038598u)
+#define SRC_GETH_GETH0_SR7 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003859Cu)
+#define SRC_GETH_GETH0_SR8 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00385A0u)
+#define SRC_GETH_GETH0_SR9 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00385A4u)
+#define SRC_CAN_CAN0_INT0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00385B0u)
+#define SRC_CAN_CAN0_INT1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00385B4u)
+#define SRC_ASCLIN_ASCLIN3_ERR /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003807Cu)
+#define SRC_CAN_CAN0_INT2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00385B8u)
+#define SRC_CAN_CAN0_INT3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00385BCu)
+#define SRC_CAN_CAN0_INT4 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00385C0u)
+#define SRC_CAN_CAN0_INT5 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00385C4u)
+#define SRC_CAN_CAN0_INT6 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00385C8u)
+#define SRC_CAN_CAN0_INT7 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00385CCu)
+#define SRC_CAN_CAN0_INT8 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00385D0u)
+#define SRC_CAN_CAN0_INT9 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00385D4u)
+#define SRC_CAN_CAN0_INT10 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00385D8u)
+#define SRC_CAN_CAN0_INT11 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00385DCu)
+#define SRC_ASCLIN_ASCLIN4_TX /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038080u)
+#define SRC_CAN_CAN0_INT12 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00385E0u)
+#define SRC_CAN_CAN0_INT13 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00385E4u)
+#define SRC_CAN_CAN0_INT14 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00385E8u)
+#define SRC_CAN_CAN0_INT15 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00385ECu)
+#define SRC_CAN_CAN1_INT0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00385F0u)
+#define SRC_CAN_CAN1_INT1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00385F4u)
+#define SRC_CAN_CAN1_INT2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00385F8u)
+#define SRC_CAN_CAN1_INT3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00385FCu)
+#define SRC_CAN_CAN1_INT4 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038600u)
+#define SRC_CAN_CAN1_INT5 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038604u)
+#define SRC_ASCLIN_ASCLIN4_RX /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038084u)
+#define SRC_CAN_CAN1_INT6 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038608u)
+#define SRC_CAN_CAN1_INT7 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003860Cu)
+#define SRC_CAN_CAN1_INT8 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038610u)
+#define SRC_CAN_CAN1_INT9 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038614u)
+#define SRC_CAN_CAN1_INT10 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038618u)
+#define SRC_CAN_CAN1_INT11 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003861Cu)
+#define SRC_CAN_CAN1_INT12 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038620u)
+#define SRC_CAN_CAN1_INT13 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038624u)
+#define SRC_CAN_CAN1_INT14 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038628u)
+#define SRC_CAN_CAN1_INT15 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003862Cu)
+#define SRC_ASCLIN_ASCLIN4_ERR /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038088u)
+#define SRC_VADC_G0_SR0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038670u)
+#define SRC_VADC_G0_SR1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038674u)
+#define SRC_VADC_G0_SR2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038678u)
+#define SRC_VADC_G0_SR3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003867Cu)
+#define SRC_VADC_G1_SR0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038680u)
+#define SRC_VADC_G1_SR1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038684u)
+#define SRC_VADC_G1_SR2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038688u)
+#define SRC_VADC_G1_SR3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003868Cu)
+#define SRC_VADC_G2_SR0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038690u)
+#define SRC_VADC_G2_SR1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038694u)
+#define SRC_ASCLIN_ASCLIN5_TX /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003808Cu)
+#define SRC_VADC_G2_SR2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038698u)
+#define SRC_VADC_G2_SR3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003869Cu)
+#define SRC_VADC_G3_SR0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00386A0u)
+#define SRC_VADC_G3_SR1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00386A4u)
+#define SRC_VADC_G3_SR2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00386A8u)
+#define SRC_VADC_G3_SR3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00386ACu)
+#define SRC_VADC_G8_SR0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00386F0u)
+#define SRC_VADC_G8_SR1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00386F4u)
+#define SRC_VADC_G8_SR2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00386F8u)
+#define SRC_VADC_G8_SR3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00386FCu)
+#define SRC_ASCLIN_ASCLIN5_RX /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038090u)
+#define SRC_VADC_G9_SR0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038700u)
+#define SRC_VADC_G9_SR1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038704u)
+#define SRC_VADC_G9_SR2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038708u)
+#define SRC_VADC_G9_SR3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003870Cu)
+#define SRC_VADC_G10_SR0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038710u)
+#define SRC_VADC_G10_SR1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038714u)
+#define SRC_VADC_G10_SR2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038718u)
+#define SRC_VADC_G10_SR3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003871Cu)
+#define SRC_VADC_G11_SR0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038720u)
+#define SRC_VADC_G11_SR1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038724u)
+#define SRC_ASCLIN_ASCLIN5_ERR /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038094u)
+#define SRC_VADC_G11_SR2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038728u)
+#define SRC_VADC_G11_SR3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003872Cu)
+#define SRC_VADC_FC0_SR0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038730u)
+#define SRC_VADC_FC1_SR0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038734u)
+#define SRC_VADC_FC2_SR0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038738u)
+#define SRC_VADC_FC3_SR0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003873Cu)
+#define SRC_VADC_CG0_SR0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038750u)
+#define SRC_VADC_CG0_SR1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038754u)
+#define SRC_VADC_CG0_SR2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038758u)
+#define SRC_VADC_CG0_SR3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003875Cu)
+#define SRC_ASCLIN_ASCLIN6_TX /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038098u)
+#define SRC_VADC_CG1_SR0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038760u)
+#define SRC_VADC_CG1_SR1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038764u)
+#define SRC_VADC_CG1_SR2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038768u)
+#define SRC_VADC_CG1_SR3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003876Cu)
+#define SRC_DSADC_DSADC0_SRM /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038770u)
+#define SRC_DSADC_DSADC0_SRA /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038774u)
+#define SRC_DSADC_DSADC1_SRM /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038778u)
+#define SRC_DSADC_DSADC1_SRA /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003877Cu)
+#define SRC_DSADC_DSADC2_SRM /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038780u)
+#define SRC_DSADC_DSADC2_SRA /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038784u)
+#define SRC_ASCLIN_ASCLIN6_RX /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003809Cu)
+#define SRC_DSADC_DSADC3_SRM /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038788u)
+#define SRC_DSADC_DSADC3_SRA /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003878Cu)
+#define SRC_DSADC_DSADC4_SRM /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038790u)
+#define SRC_DSADC_DSADC4_SRA /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038794u)
+#define SRC_DSADC_DSADC5_SRM /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038798u)
+#define SRC_DSADC_DSADC5_SRA /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003879Cu)
+#define SRC_ERAY_ERAY0_INT0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038800u)
+#define SRC_ERAY_ERAY0_INT1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038804u)
+#define SRC_ERAY_ERAY0_TINT0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038808u)
+#define SRC_ERAY_ERAY0_TINT1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003880Cu)
+#define SRC_ASCLIN_ASCLIN6_ERR /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00380A0u)
+#define SRC_ERAY_ERAY0_NDAT0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038810u)
+#define SRC_ERAY_ERAY0_NDAT1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038814u)
+#define SRC_ERAY_ERAY0_MBSC0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038818u)
+#define SRC_ERAY_ERAY0_MBSC1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003881Cu)
+#define SRC_ERAY_ERAY0_OBUSY /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038820u)
+#define SRC_ERAY_ERAY0_IBUSY /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038824u)
+#define SRC_DMUHOST /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038860u)
+#define SRC_DMUFSI /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038864u)
+#define SRC_HSM_HSM0_HSM0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038870u)
+#define SRC_HSM_HSM0_HSM1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038874u)
+#define SRC_SCU_SCUERU0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038880u)
+#define SRC_ASCLIN_ASCLIN7_TX /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00380A4u)
+#define SRC_SCU_SCUERU1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038884u)
+#define SRC_SCU_SCUERU2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038888u)
+#define SRC_SCU_SCUERU3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003888Cu)
+#define SRC_PMSDTS /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00388ACu)
+#define SRC_PMS_PMS0_SR /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00388B0u)
+#define SRC_PMS_PMS1_SR /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00388B4u)
+#define SRC_PMS_PMS2_SR /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00388B8u)
+#define SRC_PMS_PMS3_SR /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00388BCu)
+#define SRC_SCR /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00388C0u)
+#define SRC_SMU_SMU0_SR0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00388D0u)
+#define SRC_SMU_SMU0_SR1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00388D4u)
+#define SRC_ASCLIN_ASCLIN7_RX /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00380A8u)
+#define SRC_SMU_SMU0_SR2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00388D8u)
+#define SRC_PSI5_PSI50_SR0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00388E0u)
+#define SRC_PSI5_PSI50_SR1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00388E4u)
+#define SRC_PSI5_PSI50_SR2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00388E8u)
+#define SRC_PSI5_PSI50_SR3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00388ECu)
+#define SRC_PSI5_PSI50_SR4 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00388F0u)
+#define SRC_PSI5_PSI50_SR5 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00388F4u)
+#define SRC_PSI5_PSI50_SR6 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00388F8u)
+#define SRC_PSI5_PSI50_SR7 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00388FCu)
+#define SRC_DAM_DAM0_LI0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038910u)
+#define SRC_ASCLIN_ASCLIN7_ERR /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00380ACu)
+#define SRC_DAM_DAM0_RI0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038914u)
+#define SRC_DAM_DAM0_LI1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038918u)
+#define SRC_DAM_DAM0_RI1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003891Cu)
+#define SRC_DAM_DAM0_DR /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038920u)
+#define SRC_DAM_DAM0_ERR /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038924u)
+#define SRC_PSI5S_PSI5S0_SR0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038950u)
+#define SRC_PSI5S_PSI5S0_SR1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038954u)
+#define SRC_PSI5S_PSI5S0_SR2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038958u)
+#define SRC_PSI5S_PSI5S0_SR3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003895Cu)
+#define SRC_PSI5S_PSI5S0_SR4 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038960u)
+#define SRC_ASCLIN_ASCLIN8_TX /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00380B0u)
+#define SRC_PSI5S_PSI5S0_SR5 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038964u)
+#define SRC_PSI5S_PSI5S0_SR6 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038968u)
+#define SRC_PSI5S_PSI5S0_SR7 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003896Cu)
+#define SRC_GPSR_GPSR0_SR0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038990u)
+#define SRC_GPSR_GPSR0_SR1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038994u)
+#define SRC_GPSR_GPSR0_SR2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038998u)
+#define SRC_GPSR_GPSR0_SR3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003899Cu)
+#define SRC_GPSR_GPSR0_SR4 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00389A0u)
+#define SRC_GPSR_GPSR0_SR5 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00389A4u)
+#define SRC_GPSR_GPSR0_SR6 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00389A8u)
+#define SRC_ASCLIN_ASCLIN8_RX /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00380B4u)
+#define SRC_GPSR_GPSR0_SR7 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00389ACu)
+#define SRC_GPSR_GPSR1_SR0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00389B0u)
+#define SRC_GPSR_GPSR1_SR1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00389B4u)
+#define SRC_GPSR_GPSR1_SR2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00389B8u)
+#define SRC_GPSR_GPSR1_SR3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00389BCu)
+#define SRC_GPSR_GPSR1_SR4 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00389C0u)
+#define SRC_GPSR_GPSR1_SR5 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00389C4u)
+#define SRC_GPSR_GPSR1_SR6 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00389C8u)
+#define SRC_GPSR_GPSR1_SR7 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00389CCu)
+#define SRC_GPSR_GPSR2_SR0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00389D0u)
+#define SRC_ASCLIN_ASCLIN8_ERR /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00380B8u)
+#define SRC_GPSR_GPSR2_SR1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00389D4u)
+#define SRC_GPSR_GPSR2_SR2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00389D8u)
+#define SRC_GPSR_GPSR2_SR3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00389DCu)
+#define SRC_GPSR_GPSR2_SR4 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00389E0u)
+#define SRC_GPSR_GPSR2_SR5 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00389E4u)
+#define SRC_GPSR_GPSR2_SR6 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00389E8u)
+#define SRC_GPSR_GPSR2_SR7 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00389ECu)
+#define SRC_GTM_AEIIRQ /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038A70u)
+#define SRC_GTM_ARUIRQ0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038A74u)
+#define SRC_GTM_ARUIRQ1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038A78u)
+#define SRC_ASCLIN_ASCLIN9_TX /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00380BCu)
+#define SRC_GTM_ARUIRQ2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038A7Cu)
+#define SRC_GTM_BRCIRQ /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038A80u)
+#define SRC_GTM_CMBIRQ /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038A84u)
+#define SRC_GTM_SPEIRQ0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038A88u)
+#define SRC_GTM_SPEIRQ1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038A8Cu)
+#define SRC_GTM_PSM0_0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038AA0u)
+#define SRC_GTM_PSM0_1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038AA4u)
+#define SRC_GTM_PSM0_2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038AA8u)
+#define SRC_GTM_PSM0_3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038AACu)
+#define SRC_GTM_PSM0_4 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038AB0u)
+#define SRC_ASCLIN_ASCLIN9_RX /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00380C0u)
+#define SRC_GTM_PSM0_5 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038AB4u)
+#define SRC_GTM_PSM0_6 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038AB8u)
+#define SRC_GTM_PSM0_7 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038ABCu)
+#define SRC_GTM_DPLL0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038B00u)
+#define SRC_GTM_DPLL1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038B04u)
+#define SRC_GTM_DPLL2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038B08u)
+#define SRC_GTM_DPLL3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038B0Cu)
+#define SRC_GTM_DPLL4 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038B10u)
+#define SRC_GTM_DPLL5 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038B14u)
+#define SRC_GTM_DPLL6 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038B18u)
+#define SRC_ASCLIN_ASCLIN9_ERR /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00380C4u)
+#define SRC_GTM_DPLL7 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038B1Cu)
+#define SRC_GTM_DPLL8 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038B20u)
+#define SRC_GTM_DPLL9 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038B24u)
+#define SRC_GTM_DPLL10 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038B28u)
+#define SRC_GTM_DPLL11 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038B2Cu)
+#define SRC_GTM_DPLL12 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038B30u)
+#define SRC_GTM_DPLL13 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038B34u)
+#define SRC_GTM_DPLL14 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038B38u)
+#define SRC_GTM_DPLL15 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038B3Cu)
+#define SRC_GTM_DPLL16 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038B40u)
+#define SRC_ASCLIN_ASCLIN10_TX /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00380C8u)
+#define SRC_GTM_DPLL17 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038B44u)
+#define SRC_GTM_DPLL18 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038B48u)
+#define SRC_GTM_DPLL19 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038B4Cu)
+#define SRC_GTM_DPLL20 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038B50u)
+#define SRC_GTM_DPLL21 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038B54u)
+#define SRC_GTM_DPLL22 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038B58u)
+#define SRC_GTM_DPLL23 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038B5Cu)
+#define SRC_GTM_DPLL24 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038B60u)
+#define SRC_GTM_DPLL25 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038B64u)
+#define SRC_GTM_DPLL26 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038B68u)
+#define SRC_ASCLIN_ASCLIN10_RX /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00380CCu)
+#define SRC_GTM_ERR /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038B70u)
+#define SRC_GTM_TIM0_0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038B90u)
+#define SRC_GTM_TIM0_1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038B94u)
+#define SRC_GTM_TIM0_2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038B98u)
+#define SRC_GTM_TIM0_3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038B9Cu)
+#define SRC_GTM_TIM0_4 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038BA0u)
+#define SRC_GTM_TIM0_5 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038BA4u)
+#define SRC_GTM_TIM0_6 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038BA8u)
+#define SRC_GTM_TIM0_7 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038BACu)
+#define SRC_GTM_TIM1_0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038BB0u)
+#define SRC_ASCLIN_ASCLIN10_ERR /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00380D0u)
+#define SRC_GTM_TIM1_1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038BB4u)
+#define SRC_GTM_TIM1_2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038BB8u)
+#define SRC_GTM_TIM1_3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038BBCu)
+#define SRC_GTM_TIM1_4 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038BC0u)
+#define SRC_GTM_TIM1_5 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038BC4u)
+#define SRC_GTM_TIM1_6 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038BC8u)
+#define SRC_GTM_TIM1_7 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038BCCu)
+#define SRC_GTM_TIM2_0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038BD0u)
+#define SRC_GTM_TIM2_1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038BD4u)
+#define SRC_GTM_TIM2_2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038BD8u)
+#define SRC_ASCLIN_ASCLIN11_TX /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00380D4u)
+#define SRC_GTM_TIM2_3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038BDCu)
+#define SRC_GTM_TIM2_4 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038BE0u)
+#define SRC_GTM_TIM2_5 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038BE4u)
+#define SRC_GTM_TIM2_6 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038BE8u)
+#define SRC_GTM_TIM2_7 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038BECu)
+#define SRC_GTM_TIM3_0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038BF0u)
+#define SRC_GTM_TIM3_1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038BF4u)
+#define SRC_GTM_TIM3_2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038BF8u)
+#define SRC_GTM_TIM3_3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038BFCu)
+#define SRC_GTM_TIM3_4 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038C00u)
+#define SRC_ASCLIN_ASCLIN11_RX /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00380D8u)
+#define SRC_GTM_TIM3_5 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038C04u)
+#define SRC_GTM_TIM3_6 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038C08u)
+#define SRC_GTM_TIM3_7 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038C0Cu)
+#define SRC_GTM_TIM4_0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038C10u)
+#define SRC_GTM_TIM4_1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038C14u)
+#define SRC_GTM_TIM4_2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038C18u)
+#define SRC_GTM_TIM4_3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038C1Cu)
+#define SRC_GTM_TIM4_4 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038C20u)
+#define SRC_GTM_TIM4_5 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038C24u)
+#define SRC_GTM_TIM4_6 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038C28u)
+#define SRC_ASCLIN_ASCLIN11_ERR /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00380DCu)
+#define SRC_GTM_TIM4_7 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038C2Cu)
+#define SRC_GTM_TIM5_0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038C30u)
+#define SRC_GTM_TIM5_1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038C34u)
+#define SRC_GTM_TIM5_2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038C38u)
+#define SRC_GTM_TIM5_3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038C3Cu)
+#define SRC_GTM_TIM5_4 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038C40u)
+#define SRC_GTM_TIM5_5 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038C44u)
+#define SRC_GTM_TIM5_6 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038C48u)
+#define SRC_GTM_TIM5_7 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038C4Cu)
+#define SRC_GTM_MCS0_0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038CB0u)
+#define SRC_MTUDONE /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00380ECu)
+#define SRC_GTM_MCS0_1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038CB4u)
+#define SRC_GTM_MCS0_2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038CB8u)
+#define SRC_GTM_MCS0_3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038CBCu)
+#define SRC_GTM_MCS0_4 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038CC0u)
+#define SRC_GTM_MCS0_5 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038CC4u)
+#define SRC_QSPI_QSPI0_TX /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00380F0u)
+#define SRC_GTM_MCS0_6 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038CC8u)
+#define SRC_GTM_MCS0_7 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038CCCu)
+#define SRC_GTM_MCS1_0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038CD0u)
+#define SRC_GTM_MCS1_1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038CD4u)
+#define SRC_GTM_MCS1_2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038CD8u)
+#define SRC_GTM_MCS1_3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038CDCu)
+#define SRC_GTM_MCS1_4 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038CE0u)
+#define SRC_GTM_MCS1_5 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038CE4u)
+#define SRC_GTM_MCS1_6 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038CE8u)
+#define SRC_GTM_MCS1_7 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038CECu)
+#define SRC_QSPI_QSPI0_RX /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00380F4u)
+#define SRC_GTM_MCS2_0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038CF0u)
+#define SRC_GTM_MCS2_1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038CF4u)
+#define SRC_GTM_MCS2_2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038CF8u)
+#define SRC_GTM_MCS2_3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038CFCu)
+#define SRC_GTM_MCS2_4 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038D00u)
+#define SRC_GTM_MCS2_5 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038D04u)
+#define SRC_GTM_MCS2_6 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038D08u)
+#define SRC_GTM_MCS2_7 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038D0Cu)
+#define SRC_GTM_MCS3_0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038D10u)
+#define SRC_GTM_MCS3_1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038D14u)
+#define SRC_QSPI_QSPI0_ERR /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00380F8u)
+#define SRC_GTM_MCS3_2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038D18u)
+#define SRC_GTM_MCS3_3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038D1Cu)
+#define SRC_GTM_MCS3_4 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038D20u)
+#define SRC_GTM_MCS3_5 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038D24u)
+#define SRC_GTM_MCS3_6 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038D28u)
+#define SRC_GTM_MCS3_7 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038D2Cu)
+#define SRC_GTM_MCS4_0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038D30u)
+#define SRC_GTM_MCS4_1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038D34u)
+#define SRC_GTM_MCS4_2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038D38u)
+#define SRC_GTM_MCS4_3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038D3Cu)
+#define SRC_QSPI_QSPI0_PT /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00380FCu)
+#define SRC_GTM_MCS4_4 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038D40u)
+#define SRC_GTM_MCS4_5 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038D44u)
+#define SRC_GTM_MCS4_6 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038D48u)
+#define SRC_GTM_MCS4_7 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038D4Cu)
+#define SRC_GTM_TOM0_0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038E10u)
+#define SRC_GTM_TOM0_1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038E14u)
+#define SRC_GTM_TOM0_2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038E18u)
+#define SRC_GTM_TOM0_3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038E1Cu)
+#define SRC_GTM_TOM0_4 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038E20u)
+#define SRC_GTM_TOM0_5 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038E24u)
+#define SRC_QSPI_QSPI0_U /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038100u)
+#define SRC_GTM_TOM0_6 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038E28u)
+#define SRC_GTM_TOM0_7 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038E2Cu)
+#define SRC_GTM_TOM1_0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038E30u)
+#define SRC_GTM_TOM1_1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038E34u)
+#define SRC_GTM_TOM1_2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038E38u)
+#define SRC_GTM_TOM1_3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038E3Cu)
+#define SRC_GTM_TOM1_4 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038E40u)
+#define SRC_GTM_TOM1_5 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038E44u)
+#define SRC_GTM_TOM1_6 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038E48u)
+#define SRC_GTM_TOM1_7 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038E4Cu)
+#define SRC_QSPI_QSPI1_TX /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038104u)
+#define SRC_GTM_TOM2_0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038E50u)
+#define SRC_GTM_TOM2_1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038E54u)
+#define SRC_GTM_TOM2_2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038E58u)
+#define SRC_GTM_TOM2_3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038E5Cu)
+#define SRC_GTM_TOM2_4 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038E60u)
+#define SRC_GTM_TOM2_5 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038E64u)
+#define SRC_GTM_TOM2_6 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038E68u)
+#define SRC_GTM_TOM2_7 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038E6Cu)
+#define SRC_GTM_ATOM0_0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038EF0u)
+#define SRC_GTM_ATOM0_1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038EF4u)
+#define SRC_QSPI_QSPI1_RX /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038108u)
+#define SRC_GTM_ATOM0_2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038EF8u)
+#define SRC_GTM_ATOM0_3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038EFCu)
+#define SRC_GTM_ATOM1_0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038F00u)
+#define SRC_GTM_ATOM1_1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038F04u)
+#define SRC_GTM_ATOM1_2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038F08u)
+#define SRC_GTM_ATOM1_3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038F0Cu)
+#define SRC_GTM_ATOM2_0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038F10u)
+#define SRC_GTM_ATOM2_1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038F14u)
+#define SRC_GTM_ATOM2_2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038F18u)
+#define SRC_GTM_ATOM2_3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038F1Cu)
+#define SRC_QSPI_QSPI1_ERR /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003810Cu)
+#define SRC_GTM_ATOM3_0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038F20u)
+#define SRC_GTM_ATOM3_1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038F24u)
+#define SRC_GTM_ATOM3_2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038F28u)
+#define SRC_GTM_ATOM3_3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038F2Cu)
+#define SRC_GTM_ATOM4_0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038F30u)
+#define SRC_GTM_ATOM4_1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038F34u)
+#define SRC_GTM_ATOM4_2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038F38u)
+#define SRC_GTM_ATOM4_3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038F3Cu)
+#define SRC_GTM_ATOM5_0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038F40u)
+#define SRC_GTM_ATOM5_1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038F44u)
+#define SRC_QSPI_QSPI1_PT /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038110u)
+#define SRC_GTM_ATOM5_2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038F48u)
+#define SRC_GTM_ATOM5_3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038F4Cu)
+#define SRC_GTM_MCSW0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038FD0u)
+#define SRC_GTM_MCSW1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038FD4u)
+#define SRC_GTM_MCSW2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038FD8u)
+#define SRC_GTM_MCSW3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038FDCu)
+#define SRC_GTM_MCSW4 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038FE0u)
+#define SRC_GTM_MCSW5 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038FE4u)
+#define SRC_GTM_MCSW6 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038FE8u)
+#define SRC_GTM_MCSW7 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038FECu)
+#define SRC_QSPI_QSPI1_U /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038114u)
+#define SRC_GTM_MCSW8 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038FF0u)
+#define SRC_GTM_MCSW9 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038FF4u)
+#define SRC_QSPI_QSPI2_TX /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038118u)
+#define SRC_QSPI_QSPI2_RX /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003811Cu)
+#define SRC_QSPI_QSPI2_ERR /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038120u)
+#define SRC_QSPI_QSPI2_PT /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038124u)
+#define SRC_QSPI_QSPI2_U /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038128u)
+#define SRC_QSPI_QSPI3_TX /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003812Cu)
+#define SRC_QSPI_QSPI3_RX /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038130u)
+#define SRC_QSPI_QSPI3_ERR /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038134u)
+#define SRC_QSPI_QSPI3_PT /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038138u)
+#define SRC_QSPI_QSPI3_U /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003813Cu)
+#define SRC_QSPI_QSPI4_TX /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038140u)
+#define SRC_QSPI_QSPI4_RX /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038144u)
+#define SRC_QSPI_QSPI4_ERR /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038148u)
+#define SRC_QSPI_QSPI4_PT /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003814Cu)
+#define SRC_QSPI_QSPI4_U /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038150u)
+#define SRC_HSCT_HSCT0_SR /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038180u)
+#define SRC_HSSL_HSSL0_CH0_COK /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038190u)
+#define SRC_HSSL_HSSL0_CH0_RDI /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038194u)
+#define SRC_HSSL_HSSL0_CH0_ERR /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038198u)
+#define SRC_HSSL_HSSL0_CH0_TRG /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003819Cu)
+#define SRC_HSSL_HSSL0_CH1_COK /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00381A0u)
+#define SRC_HSSL_HSSL0_CH1_RDI /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00381A4u)
+#define SRC_HSSL_HSSL0_CH1_ERR /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00381A8u)
+#define SRC_HSSL_HSSL0_CH1_TRG /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00381ACu)
+#define SRC_HSSL_HSSL0_CH2_COK /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00381B0u)
+#define SRC_HSSL_HSSL0_CH2_RDI /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00381B4u)
+#define SRC_HSSL_HSSL0_CH2_ERR /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00381B8u)
+#define SRC_HSSL_HSSL0_CH2_TRG /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00381BCu)
+#define SRC_HSSL_HSSL0_CH3_COK /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00381C0u)
+#define SRC_HSSL_HSSL0_CH3_RDI /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00381C4u)
+#define SRC_HSSL_HSSL0_CH3_ERR /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00381C8u)
+#define SRC_HSSL_HSSL0_CH3_TRG /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00381CCu)
+#define SRC_HSSL_HSSL0_EXI /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00381D0u)
+#define SRC_I2C_I2C0_DTR /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038220u)
+#define MODULE_SRC (0xF0038000u)
+#define SRC_I2C_I2C0_ERR /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038224u)
+#define SRC_I2C_I2C0_P /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038228u)
+#define SRC_SENT_SENT0_SR /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038240u)
+#define SRC_SENT_SENT1_SR /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038244u)
+#define SRC_SENT_SENT2_SR /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038248u)
+#define SRC_SENT_SENT3_SR /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003824Cu)
+#define SRC_SENT_SENT4_SR /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038250u)
+#define SRC_SENT_SENT5_SR /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038254u)
+#define SRC_SENT_SENT6_SR /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038258u)
+#define SRC_SENT_SENT7_SR /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003825Cu)
+#define SRC_SENT_SENT8_SR /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038260u)
+#define SRC_SENT_SENT9_SR /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038264u)
+#define SRC_MSC_MSC0_SR0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038270u)
+#define SRC_CPU_CPU0_SB /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038000u)
+#define SRC_MSC_MSC0_SR1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038274u)
+#define SRC_MSC_MSC0_SR2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038278u)
+#define SRC_MSC_MSC0_SR3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003827Cu)
+#define SRC_MSC_MSC0_SR4 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038280u)
+#define SRC_MSC_MSC1_SR0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038284u)
+#define SRC_MSC_MSC1_SR1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038288u)
+#define SRC_MSC_MSC1_SR2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003828Cu)
+#define SRC_MSC_MSC1_SR3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038290u)
+#define SRC_MSC_MSC1_SR4 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038294u)
+#define SRC_CCU6_CCU0_SR0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00382C0u)
+#define SRC_CPU_CPU1_SB /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038004u)
+#define SRC_CCU6_CCU0_SR1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00382C4u)
+#define SRC_CCU6_CCU0_SR2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00382C8u)
+#define SRC_CCU6_CCU0_SR3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00382CCu)
+#define SRC_CCU6_CCU1_SR0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00382D0u)
+#define SRC_CCU6_CCU1_SR1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00382D4u)
+#define SRC_CCU6_CCU1_SR2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00382D8u)
+#define SRC_CCU6_CCU1_SR3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00382DCu)
+#define SRC_GPT12_GPT120_CIRQ /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00382E0u)
+#define SRC_GPT12_GPT120_T2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00382E4u)
+#define SRC_GPT12_GPT120_T3 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00382E8u)
+#define SRC_CPU_CPU2_SB /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038008u)
+#define SRC_GPT12_GPT120_T4 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00382ECu)
+#define SRC_GPT12_GPT120_T5 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00382F0u)
+#define SRC_GPT12_GPT120_T6 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF00382F4u)
+#define SRC_STM_STM0_SR0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038300u)
+#define SRC_STM_STM0_SR1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038304u)
+#define SRC_STM_STM1_SR0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038308u)
+#define SRC_STM_STM1_SR1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF003830Cu)
+#define SRC_STM_STM2_SR0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038310u)
+#define SRC_STM_STM2_SR1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038314u)
+#define SRC_FCE_FCE0_SR /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038330u)
+#define SRC_SBCU /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038020u)
+#define SRC_DMA_DMA0_ERR0 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038340u)
+#define SRC_DMA_DMA0_ERR1 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038344u)
+#define SRC_DMA_DMA0_ERR2 /*lint --e(923, 9078)*/ (*(volatile Ifx_SRC_SRCR*)0xF0038348u)
+#define STM0_TIM4 /*lint --e(923, 9078)*/ (*(volatile Ifx_STM_TIM4*)0xF0001020u)
+#define STM0_TIM5 /*lint --e(923, 9078)*/ (*(volatile Ifx_STM_TIM5*)0xF0001024u)
+#define STM0_TIM6 /*lint --e(923, 9078)*/ (*(volatile Ifx_STM_TIM6*)0xF0001028u)
+#define STM0_CAP /*lint --e(923, 9078)*/ (*(volatile Ifx_STM_CAP*)0xF000102Cu)
+#define STM0_CMP0 /*lint --e(923, 9078)*/ (*(volatile Ifx_STM_CMP*)0xF0001030u)
+#define STM0_CMP1 /*lint --e(923, 9078)*/ (*(volatile Ifx_STM_CMP*)0xF0001034u)
+#define STM0_CMCON /*lint --e(923, 9078)*/ (*(volatile Ifx_STM_CMCON*)0xF0001038u)
+#define STM0_ICR /*lint --e(923, 9078)*/ (*(volatile Ifx_STM_ICR*)0xF000103Cu)
+#define STM0_ISCR /*lint --e(923, 9078)*/ (*(volatile Ifx_STM_ISCR*)0xF0001040u)
+#define STM0_TIM0SV /*lint --e(923, 9078)*/ (*(volatile Ifx_STM_TIM0SV*)0xF0001050u)
+#define STM0_CAPSV /*lint --e(923, 9078)*/ (*(volatile Ifx_STM_CAPSV*)0xF0001054u)
+#define STM0_OCS (0xF00010E8u)
+#define STM0_KRSTCLR (0xF00010ECu)
+#define STM0_KRST1 (0xF00010F0u)
+#define STM0_KRST0 (0xF00010F4u)
+#define STM0_ACCEN1 (0xF00010F8u)
+#define STM0_ACCEN0 (0xF00010FCu)
+#define STM1_CLC /*lint --e(923, 9078)*/ (*(volatile Ifx_STM_CLC*)0xF0001100u)
+#define STM1_ID (0xF0001108u)
+#define STM1_TIM0 /*lint --e(923, 9078)*/ (*(volatile Ifx_STM_TIM0*)0xF0001110u)
+#define STM1_TIM1 /*lint --e(923, 9078)*/ (*(volatile Ifx_STM_TIM1*)0xF0001114u)
+#define STM1_TIM2 /*lint --e(923, 9078)*/ (*(volatile Ifx_STM_TIM2*)0xF0001118u)
+#define STM1_TIM3 /*lint --e(923, 9078)*/ (*(volatile Ifx_STM_TIM3*)0xF000111Cu)
+#define STM1_TIM4 /*lint --e(923, 9078)*/ (*(volatile Ifx_STM_TIM4*)0xF0001120u)
+#define STM1_TIM5 /*lint --e(923, 9078)*/ (*(volatile Ifx_STM_TIM5*)0xF0001124u)
+#define STM1_TIM6 /*lint --e(923, 9078)*/ (*(volatile Ifx_STM_TIM6*)0xF0001128u)
+#define STM1_CAP /*lint --e(923, 9078)*/ (*(volatile Ifx_STM_CAP*)0xF000112Cu)
+#define STM1_CMP0 /*lint --e(923, 9078)*/ (*(volatile Ifx_STM_CMP*)0xF0001130u)
+#define STM1_CMP1 /*lint --e(923, 9078)*/ (*(volatile Ifx_STM_CMP*)0xF0001134u)
+#define STM1_CMCON /*lint --e(923, 9078)*/ (*(volatile Ifx_STM_CMCON*)0xF0001138u)
+#define STM1_ICR /*lint --e(923, 9078)*/ (*(volatile Ifx_STM_ICR*)0xF000113Cu)
+#define STM1_ISCR /*lint --e(923, 9078)*/ (*(volatile Ifx_STM_ISCR*)0xF0001140u)
+#define STM1_TIM0SV /*lint --e(923, 9078)*/ (*(volatile Ifx_STM_TIM0SV*)0xF0001150u)
+#define STM1_CAPSV /*lint --e(923, 9078)*/ (*(volatile Ifx_STM_CAPSV*)0xF0001154u)
+#define STM1_OCS (0xF00011E8u)
+#define STM1_KRSTCLR (0xF00011ECu)
+#define STM1_KRST1 (0xF00011F0u)
+#define STM1_KRST0 (0xF00011F4u)
+#define STM1_ACCEN1 (0xF00011F8u)
+#define STM1_ACCEN0 (0xF00011FCu)
+#define STM2_CLC /*lint --e(923, 9078)*/ (*(volatile Ifx_STM_CLC*)0xF0001200u)
+#define STM2_ID (0xF0001208u)
+#define STM2_TIM0 /*lint --e(923, 9078)*/ (*(volatile Ifx_STM_TIM0*)0xF0001210u)
+#define STM2_TIM1 /*lint --e(923, 9078)*/ (*(volatile Ifx_STM_TIM1*)0xF0001214u)
+#define STM2_TIM2 /*lint --e(923, 9078)*/ (*(volatile Ifx_STM_TIM2*)0xF0001218u)
+#define STM2_TIM3 /*lint --e(923, 9078)*/ (*(volatile Ifx_STM_TIM3*)0xF000121Cu)
+#define STM2_TIM4 /*lint --e(923, 9078)*/ (*(volatile Ifx_STM_TIM4*)0xF0001220u)
+#define STM2_TIM5 /*lint --e(923, 9078)*/ (*(volatile Ifx_STM_TIM5*)0xF0001224u)
+#define STM2_TIM6 /*lint --e(923, 9078)*/ (*(volatile Ifx_STM_TIM6*)0xF0001228u)
+#define STM2_CAP /*lint --e(923, 9078)*/ (*(volatile Ifx_STM_CAP*)0xF000122Cu)
+#define STM2_CMP0 /*lint --e(923, 9078)*/ (*(volatile Ifx_STM_CMP*)0xF0001230u)
+#define STM2_CMP1 /*lint --e(923, 9078)*/ (*(volatile Ifx_STM_CMP*)0xF0001234u)
+#define STM2_CMCON /*lint --e(923, 9078)*/ (*(volatile Ifx_STM_CMCON*)0xF0001238u)
+#define STM2_ICR /*lint --e(923, 9078)*/ (*(volatile Ifx_STM_ICR*)0xF000123Cu)
+#define STM2_ISCR /*lint --e(923, 9078)*/ (*(volatile Ifx_STM_ISCR*)0xF0001240u)
+#define STM2_TIM0SV /*lint --e(923, 9078)*/ (*(volatile Ifx_STM_TIM0SV*)0xF0001250u)
+#define STM2_CAPSV /*lint --e(923, 9078)*/ (*(volatile Ifx_STM_CAPSV*)0xF0001254u)
+#define STM2_OCS (0xF00012E8u)
+#define STM2_KRSTCLR (0xF00012ECu)
+#define STM2_KRST1 (0xF00012F0u)
+#define STM2_KRST0 (0xF00012F4u)
+#define STM2_ACCEN1 (0xF00012F8u)
+#define STM2_ACCEN0 (0xF00012FCu)
+#define MODULE_STM0 (0xF0001000u)
+#define MODULE_STM1 (0xF0001100u)
+#define MODULE_STM2 (0xF0001200u)
+#define STM0_CLC /*lint --e(923, 9078)*/ (*(volatile Ifx_STM_CLC*)0xF0001000u)
+#define STM0_ID (0xF0001008u)
+#define STM0_TIM0 /*lint --e(923, 9078)*/ (*(volatile Ifx_STM_TIM0*)0xF0001010u)
+#define STM0_TIM1 /*lint --e(923, 9078)*/ (*(volatile Ifx_STM_TIM1*)0xF0001014u)
+#define STM0_TIM2 /*lint --e(923, 9078)*/ (*(volatile Ifx_STM_TIM2*)0xF0001018u)
+#define STM0_TIM3 /*lint --e(923, 9078)*/ (*(volatile Ifx_STM_TIM3*)0xF000101Cu)
+#define IFX_CFG_SSW_PSW_DEFAULT (0x00000980u)
+#define IFXHSSL_JTAG_ID_ADDRESS (0xF0000464u)
+#define IFXMTU_MC_ADDRESS_BASE (0xF0061000u)
@@ -0,0 +1,661 @@
+/* TC375 bitfields of the modules emulated by QECU (ASCLIN, CPU, SCU watchdogs,
+ * SRC and STM), iLLD IfxXxx_regdef.h format. The complete set is generated
+ * from an iLLD release with scripts/illd2regmap.py. */
+
+typedef struct _Ifx_ASCLIN_CLC_Bits
+{
+    Ifx_UReg_32Bit DISR:1;              /* [0:0] */
+    Ifx_UReg_32Bit DISS:1;              /* [1:1] */
+    Ifx_UReg_32Bit reserved_2:1;        /* [2:2] */
+    Ifx_UReg_32Bit EDIS:1;              /* [3:3] */
+    Ifx_UReg_32Bit reserved_4:28;       /* [31:4] */
+} Ifx_ASCLIN_CLC_Bits;
+
+typedef struct _Ifx_ASCLIN_TXFIFOCON_Bits
+{
+    Ifx_UReg_32Bit FLUSH:1;             /* [0:0] */
+    Ifx_UReg_32Bit ENO:1;               /* [1:1] */
+    Ifx_UReg_32Bit reserved_2:4;        /* [5:2] */
+    Ifx_UReg_32Bit INW:2;               /* [7:6] */
+    Ifx_UReg_32Bit INTLEVEL:4;          /* [11:8] */
+    Ifx_UReg_32Bit reserved_12:4;       /* [15:12] */
+    Ifx_UReg_32Bit FILL:5;              /* [20:16] */
+    Ifx_UReg_32Bit reserved_21:11;      /* [31:21] */
+} Ifx_ASCLIN_TXFIFOCON_Bits;
+
+typedef struct _Ifx_ASCLIN_RXFIFOCON_Bits
+{
+    Ifx_UReg_32Bit FLUSH:1;             /* [0:0] */
+    Ifx_UReg_32Bit ENI:1;               /* [1:1] */
+    Ifx_UReg_32Bit reserved_2:4;        /* [5:2] */
+    Ifx_UReg_32Bit OUTW:2;              /* [7:6] */
+    Ifx_UReg_32Bit INTLEVEL:4;          /* [11:8] */
+    Ifx_UReg_32Bit reserved_12:4;       /* [15:12] */
+    Ifx_UReg_32Bit FILL:5;              /* [20:16] */
+    Ifx_UReg_32Bit reserved_21:10;      /* [30:21] */
+    Ifx_UReg_32Bit BUF:1;               /* [31:31] */
+} Ifx_ASCLIN_RXFIFOCON_Bits;
+
+typedef struct _Ifx_ASCLIN_BITCON_Bits
+{
+    Ifx_UReg_32Bit PRESCALER:12;        /* [11:0] */
+    Ifx_UReg_32Bit reserved_12:4;       /* [15:12] */
+    Ifx_UReg_32Bit OVERSAMPLING:4;      /* [19:16] */
+    Ifx_UReg_32Bit reserved_20:4;       /* [23:20] */
+    Ifx_UReg_32Bit SAMPLEPOINT:4;       /* [27:24] */
+    Ifx_UReg_32Bit reserved_28:3;       /* [30:28] */
+    Ifx_UReg_32Bit SM:1;                /* [31:31] */
+} Ifx_ASCLIN_BITCON_Bits;
+
+typedef struct _Ifx_ASCLIN_FRAMECON_Bits
+{
+    Ifx_UReg_32Bit reserved_0:6;        /* [5:0] */
+    Ifx_UReg_32Bit IDLE:3;              /* [8:6] */
+    Ifx_UReg_32Bit STOP:3;              /* [11:9] */
+    Ifx_UReg_32Bit LEAD:3;              /* [14:12] */
+    Ifx_UReg_32Bit reserved_15:1;       /* [15:15] */
+    Ifx_UReg_32Bit MODE:2;              /* [17:16] */
+    Ifx_UReg_32Bit reserved_18:10;      /* [27:18] */
+    Ifx_UReg_32Bit MSB:1;               /* [28:28] */
+    Ifx_UReg_32Bit CEN:1;               /* [29:29] */
+    Ifx_UReg_32Bit PEN:1;               /* [30:30] */
+    Ifx_UReg_32Bit ODD:1;               /* [31:31] */
+} Ifx_ASCLIN_FRAMECON_Bits;
+
+typedef struct _Ifx_ASCLIN_DATCON_Bits
+{
+    Ifx_UReg_32Bit DATLEN:4;            /* [3:0] */
+    Ifx_UReg_32Bit reserved_4:9;        /* [12:4] */
+    Ifx_UReg_32Bit HO:1;                /* [13:13] */
+    Ifx_UReg_32Bit RM:1;                /* [14:14] */
+    Ifx_UReg_32Bit CSM:1;               /* [15:15] */
+    Ifx_UReg_32Bit RESPONSE:8;          /* [23:16] */
+    Ifx_UReg_32Bit reserved_24:8;       /* [31:24] */
+} Ifx_ASCLIN_DATCON_Bits;
+
+typedef struct _Ifx_ASCLIN_BRG_Bits
+{
+    Ifx_UReg_32Bit DENOMINATOR:12;      /* [11:0] */
+    Ifx_UReg_32Bit reserved_12:4;       /* [15:12] */
+    Ifx_UReg_32Bit NUMERATOR:12;        /* [27:16] */
+    Ifx_UReg_32Bit reserved_28:4;       /* [31:28] */
+} Ifx_ASCLIN_BRG_Bits;
+
+typedef struct _Ifx_ASCLIN_FLAGS_Bits
+{
+    Ifx_UReg_32Bit TH:1;                /* [0:0] */
+    Ifx_UReg_32Bit TR:1;                /* [1:1] */
+    Ifx_UReg_32Bit RH:1;                /* [2:2] */
+    Ifx_UReg_32Bit RR:1;                /* [3:3] */
+    Ifx_UReg_32Bit reserved_4:1;        /* [4:4] */
+    Ifx_UReg_32Bit FED:1;               /* [5:5] */
+    Ifx_UReg_32Bit RED:1;               /* [6:6] */
+    Ifx_UReg_32Bit reserved_7:6;        /* [12:7] */
+    Ifx_UReg_32Bit TWRQ:1;              /* [13:13] */
+    Ifx_UReg_32Bit THRQ:1;              /* [14:14] */
+    Ifx_UReg_32Bit TRRQ:1;              /* [15:15] */
+    Ifx_UReg_32Bit PE:1;                /* [16:16] */
+    Ifx_UReg_32Bit TC:1;                /* [17:17] */
+    Ifx_UReg_32Bit FE:1;                /* [18:18] */
+    Ifx_UReg_32Bit HT:1;                /* [19:19] */
+    Ifx_UReg_32Bit RT:1;                /* [20:20] */
+    Ifx_UReg_32Bit BD:1;                /* [21:21] */
+    Ifx_UReg_32Bit LP:1;                /* [22:22] */
+    Ifx_UReg_32Bit LA:1;                /* [23:23] */
+    Ifx_UReg_32Bit LC:1;                /* [24:24] */
+    Ifx_UReg_32Bit CE:1;                /* [25:25] */
+    Ifx_UReg_32Bit RFO:1;               /* [26:26] */
+    Ifx_UReg_32Bit RFU:1;               /* [27:27] */
+    Ifx_UReg_32Bit RFL:1;               /* [28:28] */
+    Ifx_UReg_32Bit reserved_29:1;       /* [29:29] */
+    Ifx_UReg_32Bit TFO:1;               /* [30:30] */
+    Ifx_UReg_32Bit TFL:1;               /* [31:31] */
+} Ifx_ASCLIN_FLAGS_Bits;
+
+typedef struct _Ifx_ASCLIN_FLAGSSET_Bits
+{
+    Ifx_UReg_32Bit THS:1;               /* [0:0] */
+    Ifx_UReg_32Bit TRS:1;               /* [1:1] */
+    Ifx_UReg_32Bit RHS:1;               /* [2:2] */
+    Ifx_UReg_32Bit RRS:1;               /* [3:3] */
+    Ifx_UReg_32Bit reserved_4:1;        /* [4:4] */
+    Ifx_UReg_32Bit FEDS:1;              /* [5:5] */
+    Ifx_UReg_32Bit REDS:1;              /* [6:6] */
+    Ifx_UReg_32Bit reserved_7:6;        /* [12:7] */
+    Ifx_UReg_32Bit TWRQS:1;             /* [13:13] */
+    Ifx_UReg_32Bit THRQS:1;             /* [14:14] */
+    Ifx_UReg_32Bit TRRQS:1;             /* [15:15] */
+    Ifx_UReg_32Bit PES:1;               /* [16:16] */
+    Ifx_UReg_32Bit TCS:1;               /* [17:17] */
+    Ifx_UReg_32Bit FES:1;               /* [18:18] */
+    Ifx_UReg_32Bit HTS:1;               /* [19:19] */
+    Ifx_UReg_32Bit RTS:1;               /* [20:20] */
+    Ifx_UReg_32Bit BDS:1;               /* [21:21] */
+    Ifx_UReg_32Bit LPS:1;               /* [22:22] */
+    Ifx_UReg_32Bit LAS:1;               /* [23:23] */
+    Ifx_UReg_32Bit LCS:1;               /* [24:24] */
+    Ifx_UReg_32Bit CES:1;               /* [25:25] */
+    Ifx_UReg_32Bit RFOS:1;              /* [26:26] */
+    Ifx_UReg_32Bit RFUS:1;              /* [27:27] */
+    Ifx_UReg_32Bit RFLS:1;              /* [28:28] */
+    Ifx_UReg_32Bit reserved_29:1;       /* [29:29] */
+    Ifx_UReg_32Bit TFOS:1;              /* [30:30] */
+    Ifx_UReg_32Bit TFLS:1;              /* [31:31] */
+} Ifx_ASCLIN_FLAGSSET_Bits;
+
+typedef struct _Ifx_ASCLIN_FLAGSCLEAR_Bits
+{
+    Ifx_UReg_32Bit THC:1;               /* [0:0] */
+    Ifx_UReg_32Bit TRC:1;               /* [1:1] */
+    Ifx_UReg_32Bit RHC:1;               /* [2:2] */
+    Ifx_UReg_32Bit RRC:1;               /* [3:3] */
+    Ifx_UReg_32Bit reserved_4:1;        /* [4:4] */
+    Ifx_UReg_32Bit FEDC:1;              /* [5:5] */
+    Ifx_UReg_32Bit REDC:1;              /* [6:6] */
+    Ifx_UReg_32Bit reserved_7:6;        /* [12:7] */
+    Ifx_UReg_32Bit TWRQC:1;             /* [13:13] */
+    Ifx_UReg_32Bit THRQC:1;             /* [14:14] */
+    Ifx_UReg_32Bit TRRQC:1;             /* [15:15] */
+    Ifx_UReg_32Bit PEC:1;               /* [16:16] */
+    Ifx_UReg_32Bit TCC:1;               /* [17:17] */
+    Ifx_UReg_32Bit FEC:1;               /* [18:18] */
+    Ifx_UReg_32Bit HTC:1;               /* [19:19] */
+    Ifx_UReg_32Bit RTC:1;               /* [20:20] */
+    Ifx_UReg_32Bit BDC:1;               /* [21:21] */
+    Ifx_UReg_32Bit LPC:1;               /* [22:22] */
+    Ifx_UReg_32Bit LAC:1;               /* [23:23] */
+    Ifx_UReg_32Bit LCC:1;               /* [24:24] */
+    Ifx_UReg_32Bit CEC:1;               /* [25:25] */
+    Ifx_UReg_32Bit RFOC:1;              /* [26:26] */
+    Ifx_UReg_32Bit RFUC:1;              /* [27:27] */
+    Ifx_UReg_32Bit RFLC:1;              /* [28:28] */
+    Ifx_UReg_32Bit reserved_29:1;       /* [29:29] */
+    Ifx_UReg_32Bit TFOC:1;              /* [30:30] */
+    Ifx_UReg_32Bit TFLC:1;              /* [31:31] */
+} Ifx_ASCLIN_FLAGSCLEAR_Bits;
+
+typedef struct _Ifx_ASCLIN_FLAGSENABLE_Bits
+{
+    Ifx_UReg_32Bit THE:1;               /* [0:0] */
+    Ifx_UReg_32Bit TRE:1;               /* [1:1] */
+    Ifx_UReg_32Bit reserved_2:1;        /* [2:2] */
+    Ifx_UReg_32Bit reserved_3:1;        /* [3:3] */
+    Ifx_UReg_32Bit reserved_4:1;        /* [4:4] */
+    Ifx_UReg_32Bit FEDE:1;              /* [5:5] */
+    Ifx_UReg_32Bit REDE:1;              /* [6:6] */
+    Ifx_UReg_32Bit reserved_7:6;        /* [12:7] */
+    Ifx_UReg_32Bit reserved_13:1;       /* [13:13] */
+    Ifx_UReg_32Bit reserved_14:1;       /* [14:14] */
+    Ifx_UReg_32Bit reserved_15:1;       /* [15:15] */
+    Ifx_UReg_32Bit PEE:1;               /* [16:16] */
+    Ifx_UReg_32Bit TCE:1;               /* [17:17] */
+    Ifx_UReg_32Bit FEE:1;               /* [18:18] */
+    Ifx_UReg_32Bit HTE:1;               /* [19:19] */
+    Ifx_UReg_32Bit RTE:1;               /* [20:20] */
+    Ifx_UReg_32Bit BDE:1;               /* [21:21] */
+    Ifx_UReg_32Bit LPE:1;               /* [22:22] */
+    Ifx_UReg_32Bit LAE:1;               /* [23:23] */
+    Ifx_UReg_32Bit LCE:1;               /* [24:24] */
+    Ifx_UReg_32Bit CEE:1;               /* [25:25] */
+    Ifx_UReg_32Bit RFOE:1;              /* [26:26] */
+    Ifx_UReg_32Bit RFUE:1;              /* [27:27] */
+    Ifx_UReg_32Bit RFLE:1;              /* [28:28] */
+    Ifx_UReg_32Bit reserved_29:1;       /* [29:29] */
+    Ifx_UReg_32Bit TFOE:1;              /* [30:30] */
+    Ifx_UReg_32Bit TFLE:1;              /* [31:31] */
+} Ifx_ASCLIN_FLAGSENABLE_Bits;
+
+typedef struct _Ifx_ASCLIN_TXDATA_Bits
+{
+    Ifx_UReg_32Bit DATA:32;             /* [31:0] */
+} Ifx_ASCLIN_TXDATA_Bits;
+
+typedef struct _Ifx_ASCLIN_RXDATA_Bits
+{
+    Ifx_UReg_32Bit DATA:32;             /* [31:0] */
+} Ifx_ASCLIN_RXDATA_Bits;
+
+typedef struct _Ifx_ASCLIN_CSR_Bits
+{
+    Ifx_UReg_32Bit CLKSEL:5;            /* [4:0] */
+    Ifx_UReg_32Bit reserved_5:26;       /* [30:5] */
+    Ifx_UReg_32Bit CON:1;               /* [31:31] */
+} Ifx_ASCLIN_CSR_Bits;
+
+typedef struct _Ifx_ASCLIN_RXDATAD_Bits
+{
+    Ifx_UReg_32Bit DATA:32;             /* [31:0] */
+} Ifx_ASCLIN_RXDATAD_Bits;
+
+typedef struct _Ifx_CPU_DBGSR_Bits
+{
+    Ifx_UReg_32Bit DE:1;                /* [0:0] */
+    Ifx_UReg_32Bit HALT:2;              /* [2:1] */
+    Ifx_UReg_32Bit SIH:1;               /* [3:3] */
+    Ifx_UReg_32Bit SUSP:1;              /* [4:4] */
+    Ifx_UReg_32Bit reserved_5:1;        /* [5:5] */
+    Ifx_UReg_32Bit PREVSUSP:1;          /* [6:6] */
+    Ifx_UReg_32Bit PEVT:1;              /* [7:7] */
+    Ifx_UReg_32Bit EVTSRC:5;            /* [12:8] */
+    Ifx_UReg_32Bit reserved_13:19;      /* [31:13] */
+} Ifx_CPU_DBGSR_Bits;
+
+typedef struct _Ifx_CPU_PC_Bits
+{
+    Ifx_UReg_32Bit reserved_0:1;        /* [0:0] */
+    Ifx_UReg_32Bit PC:31;               /* [31:1] */
+} Ifx_CPU_PC_Bits;
+
+typedef struct _Ifx_CPU_SYSCON_Bits
+{
+    Ifx_UReg_32Bit FCDSF:1;             /* [0:0] */
+    Ifx_UReg_32Bit PROTEN:1;            /* [1:1] */
+    Ifx_UReg_32Bit TPROTEN:1;           /* [2:2] */
+    Ifx_UReg_32Bit IS:1;                /* [3:3] */
+    Ifx_UReg_32Bit TS:1;                /* [4:4] */
+    Ifx_UReg_32Bit reserved_5:3;        /* [7:5] */
+    Ifx_UReg_32Bit ESDIS:1;             /* [8:8] */
+    Ifx_UReg_32Bit reserved_9:7;        /* [15:9] */
+    Ifx_UReg_32Bit U1_IED:1;            /* [16:16] */
+    Ifx_UReg_32Bit U1_IOS:1;            /* [17:17] */
+    Ifx_UReg_32Bit reserved_18:6;       /* [23:18] */
+    Ifx_UReg_32Bit BHALT:1;             /* [24:24] */
+    Ifx_UReg_32Bit reserved_25:7;       /* [31:25] */
+} Ifx_CPU_SYSCON_Bits;
+
+typedef struct _Ifx_CPU_CORE_ID_Bits
+{
+    Ifx_UReg_32Bit CORE_ID:3;           /* [2:0] */
+    Ifx_UReg_32Bit reserved_3:29;       /* [31:3] */
+} Ifx_CPU_CORE_ID_Bits;
+
+typedef struct _Ifx_SCU_WDTCPU_CON0_Bits
+{
+    Ifx_UReg_32Bit ENDINIT:1;           /* [0:0] */
+    Ifx_UReg_32Bit LCK:1;               /* [1:1] */
+    Ifx_UReg_32Bit PW:14;               /* [15:2] */
+    Ifx_UReg_32Bit REL:16;              /* [31:16] */
+} Ifx_SCU_WDTCPU_CON0_Bits;
+
+typedef struct _Ifx_SCU_WDTS_CON0_Bits
+{
+    Ifx_UReg_32Bit ENDINIT:1;           /* [0:0] */
+    Ifx_UReg_32Bit LCK:1;               /* [1:1] */
+    Ifx_UReg_32Bit PW:14;               /* [15:2] */
+    Ifx_UReg_32Bit REL:16;              /* [31:16] */
+} Ifx_SCU_WDTS_CON0_Bits;
+
+typedef struct _Ifx_SRC_SRCR_Bits
+{
+    Ifx_UReg_32Bit SRPN:8;              /* [7:0] */
+    Ifx_UReg_32Bit reserved_8:2;        /* [9:8] */
+    Ifx_UReg_32Bit SRE:1;               /* [10:10] */
+    Ifx_UReg_32Bit TOS:3;               /* [13:11] */
+    Ifx_UReg_32Bit reserved_14:2;       /* [15:14] */
+    Ifx_UReg_32Bit ECC:5;               /* [20:16] */
+    Ifx_UReg_32Bit reserved_21:3;       /* [23:21] */
+    Ifx_UReg_32Bit SRR:1;               /* [24:24] */
+    Ifx_UReg_32Bit CLRR:1;              /* [25:25] */
+    Ifx_UReg_32Bit SETR:1;              /* [26:26] */
+    Ifx_UReg_32Bit IOV:1;               /* [27:27] */
+    Ifx_UReg_32Bit IOVCLR:1;            /* [28:28] */
+    Ifx_UReg_32Bit SWS:1;               /* [29:29] */
+    Ifx_UReg_32Bit SWSCLR:1;            /* [30:30] */
+    Ifx_UReg_32Bit reserved_31:1;       /* [31:31] */
+} Ifx_SRC_SRCR_Bits;
+
+typedef struct _Ifx_STM_CLC_Bits
+{
+    Ifx_UReg_32Bit DISR:1;              /* [0:0] */
+    Ifx_UReg_32Bit DISS:1;              /* [1:1] */
+    Ifx_UReg_32Bit reserved_2:1;        /* [2:2] */
+    Ifx_UReg_32Bit EDIS:1;              /* [3:3] */
+    Ifx_UReg_32Bit reserved_4:28;       /* [31:4] */
+} Ifx_STM_CLC_Bits;
+
+typedef struct _Ifx_STM_TIM0_Bits
+{
+    Ifx_UReg_32Bit STM31_0:32;          /* [31:0] */
+} Ifx_STM_TIM0_Bits;
+
+typedef struct _Ifx_STM_TIM1_Bits
+{
+    Ifx_UReg_32Bit STM35_4:32;          /* [31:0] */
+} Ifx_STM_TIM1_Bits;
+
+typedef struct _Ifx_STM_TIM2_Bits
+{
+    Ifx_UReg_32Bit STM39_8:32;          /* [31:0] */
+} Ifx_STM_TIM2_Bits;
+
+typedef struct _Ifx_STM_TIM3_Bits
+{
+    Ifx_UReg_32Bit STM43_12:32;         /* [31:0] */
+} Ifx_STM_TIM3_Bits;
+
+typedef struct _Ifx_STM_TIM4_Bits
+{
+    Ifx_UReg_32Bit STM47_16:32;         /* [31:0] */
+} Ifx_STM_TIM4_Bits;
+
+typedef struct _Ifx_STM_TIM5_Bits
+{
+    Ifx_UReg_32Bit STM51_20:32;         /* [31:0] */
+} Ifx_STM_TIM5_Bits;
+
+typedef struct _Ifx_STM_TIM6_Bits
+{
+    Ifx_UReg_32Bit STM63_32:32;         /* [31:0] */
+} Ifx_STM_TIM6_Bits;
+
+typedef struct _Ifx_STM_CAP_Bits
+{
+    Ifx_UReg_32Bit STMCAP63_32:32;      /* [31:0] */
+} Ifx_STM_CAP_Bits;
+
+typedef struct _Ifx_STM_CMP_Bits
+{
+    Ifx_UReg_32Bit CMPVAL:32;           /* [31:0] */
+} Ifx_STM_CMP_Bits;
+
+typedef struct _Ifx_STM_CMCON_Bits
+{
+    Ifx_UReg_32Bit MSIZE0:5;            /* [4:0] */
+    Ifx_UReg_32Bit reserved_5:3;        /* [7:5] */
+    Ifx_UReg_32Bit MSTART0:5;           /* [12:8] */
+    Ifx_UReg_32Bit reserved_13:3;       /* [15:13] */
+    Ifx_UReg_32Bit MSIZE1:5;            /* [20:16] */
+    Ifx_UReg_32Bit reserved_21:3;       /* [23:21] */
+    Ifx_UReg_32Bit MSTART1:5;           /* [28:24] */
+    Ifx_UReg_32Bit reserved_29:3;       /* [31:29] */
+} Ifx_STM_CMCON_Bits;
+
+typedef struct _Ifx_STM_ICR_Bits
+{
+    Ifx_UReg_32Bit CMP0EN:1;            /* [0:0] */
+    Ifx_UReg_32Bit CMP0IR:1;            /* [1:1] */
+    Ifx_UReg_32Bit CMP0OS:1;            /* [2:2] */
+    Ifx_UReg_32Bit reserved_3:1;        /* [3:3] */
+    Ifx_UReg_32Bit CMP1EN:1;            /* [4:4] */
+    Ifx_UReg_32Bit CMP1IR:1;            /* [5:5] */
+    Ifx_UReg_32Bit CMP1OS:1;            /* [6:6] */
+    Ifx_UReg_32Bit reserved_7:25;       /* [31:7] */
+} Ifx_STM_ICR_Bits;
+
+typedef struct _Ifx_STM_ISCR_Bits
+{
+    Ifx_UReg_32Bit CMP0IRR:1;           /* [0:0] */
+    Ifx_UReg_32Bit CMP0IRS:1;           /* [1:1] */
+    Ifx_UReg_32Bit CMP1IRR:1;           /* [2:2] */
+    Ifx_UReg_32Bit CMP1IRS:1;           /* [3:3] */
+    Ifx_UReg_32Bit reserved_4:28;       /* [31:4] */
+} Ifx_STM_ISCR_Bits;
+
+typedef struct _Ifx_STM_TIM0SV_Bits
+{
+    Ifx_UReg_32Bit STM31_0:32;          /* [31:0] */
+} Ifx_STM_TIM0SV_Bits;
+
+typedef struct _Ifx_STM_CAPSV_Bits
+{
+    Ifx_UReg_32Bit STMCAP63_32:32;      /* [31:0] */
+} Ifx_STM_CAPSV_Bits;
+
+typedef union
+{
+    Ifx_UReg_32Bit U;
+    Ifx_SReg_32Bit I;
+    Ifx_ASCLIN_CLC_Bits B;
+} Ifx_ASCLIN_CLC;
+
+typedef union
+{
+    Ifx_UReg_32Bit U;
+    Ifx_SReg_32Bit I;
+    Ifx_ASCLIN_TXFIFOCON_Bits B;
+} Ifx_ASCLIN_TXFIFOCON;
+
+typedef union
+{
+    Ifx_UReg_32Bit U;
+    Ifx_SReg_32Bit I;
+    Ifx_ASCLIN_RXFIFOCON_Bits B;
+} Ifx_ASCLIN_RXFIFOCON;
+
+typedef union
+{
+    Ifx_UReg_32Bit U;
+    Ifx_SReg_32Bit I;
+    Ifx_ASCLIN_BITCON_Bits B;
+} Ifx_ASCLIN_BITCON;
+
+typedef union
+{
+    Ifx_UReg_32Bit U;
+    Ifx_SReg_32Bit I;
+    Ifx_ASCLIN_FRAMECON_Bits B;
+} Ifx_ASCLIN_FRAMECON;
+
+typedef union
+{
+    Ifx_UReg_32Bit U;
+    Ifx_SReg_32Bit I;
+    Ifx_ASCLIN_DATCON_Bits B;
+} Ifx_ASCLIN_DATCON;
+
+typedef union
+{
+    Ifx_UReg_32Bit U;
+    Ifx_SReg_32Bit I;
+    Ifx_ASCLIN_BRG_Bits B;
+} Ifx_ASCLIN_BRG;
+
+typedef union
+{
+    Ifx_UReg_32Bit U;
+    Ifx_SReg_32Bit I;
+    Ifx_ASCLIN_FLAGS_Bits B;
+} Ifx_ASCLIN_FLAGS;
+
+typedef union
+{
+    Ifx_UReg_32Bit U;
+    Ifx_SReg_32Bit I;
+    Ifx_ASCLIN_FLAGSSET_Bits B;
+} Ifx_ASCLIN_FLAGSSET;
+
+typedef union
+{
+    Ifx_UReg_32Bit U;
+    Ifx_SReg_32Bit I;
+    Ifx_ASCLIN_FLAGSCLEAR_Bits B;
+} Ifx_ASCLIN_FLAGSCLEAR;
+
+typedef union
+{
+    Ifx_UReg_32Bit U;
+    Ifx_SReg_32Bit I;
+    Ifx_ASCLIN_FLAGSENABLE_Bits B;
+} Ifx_ASCLIN_FLAGSENABLE;
+
+typedef union
+{
+    Ifx_UReg_32Bit U;
+    Ifx_SReg_32Bit I;
+    Ifx_ASCLIN_TXDATA_Bits B;
+} Ifx_ASCLIN_TXDATA;
+
+typedef union
+{
+    Ifx_UReg_32Bit U;
+    Ifx_SReg_32Bit I;
+    Ifx_ASCLIN_RXDATA_Bits B;
+} Ifx_ASCLIN_RXDATA;
+
+typedef union
+{
+    Ifx_UReg_32Bit U;
+    Ifx_SReg_32Bit I;
+    Ifx_ASCLIN_CSR_Bits B;
+} Ifx_ASCLIN_CSR;
+
+typedef union
+{
+    Ifx_UReg_32Bit U;
+    Ifx_SReg_32Bit I;
+    Ifx_ASCLIN_RXDATAD_Bits B;
+} Ifx_ASCLIN_RXDATAD;
+
+typedef union
+{
+    Ifx_UReg_32Bit U;
+    Ifx_SReg_32Bit I;
+    Ifx_CPU_DBGSR_Bits B;
+} Ifx_CPU_DBGSR;
+
+typedef union
+{
+    Ifx_UReg_32Bit U;
+    Ifx_SReg_32Bit I;
+    Ifx_CPU_PC_Bits B;
+} Ifx_CPU_PC;
+
+typedef union
+{
+    Ifx_UReg_32Bit U;
+    Ifx_SReg_32Bit I;
+    Ifx_CPU_SYSCON_Bits B;
+} Ifx_CPU_SYSCON;
+
+typedef union
+{
+    Ifx_UReg_32Bit U;
+    Ifx_SReg_32Bit I;
+    Ifx_CPU_CORE_ID_Bits B;
+} Ifx_CPU_CORE_ID;
+
+typedef union
+{
+    Ifx_UReg_32Bit U;
+    Ifx_SReg_32Bit I;
+    Ifx_SCU_WDTCPU_CON0_Bits B;
+} Ifx_SCU_WDTCPU_CON0;
+
+typedef union
+{
+    Ifx_UReg_32Bit U;
+    Ifx_SReg_32Bit I;
+    Ifx_SCU_WDTS_CON0_Bits B;
+} Ifx_SCU_WDTS_CON0;
+
+typedef union
+{
+    Ifx_UReg_32Bit U;
+    Ifx_SReg_32Bit I;
+    Ifx_SRC_SRCR_Bits B;
+} Ifx_SRC_SRCR;
+
+typedef union
+{
+    Ifx_UReg_32Bit U;
+    Ifx_SReg_32Bit I;
+    Ifx_STM_CLC_Bits B;
+} Ifx_STM_CLC;
+
+typedef union
+{
+    Ifx_UReg_32Bit U;
+    Ifx_SReg_32Bit I;
+    Ifx_STM_TIM0_Bits B;
+} Ifx_STM_TIM0;
+
+typedef union
+{
+    Ifx_UReg_32Bit U;
+    Ifx_SReg_32Bit I;
+    Ifx_STM_TIM1_Bits B;
+} Ifx_STM_TIM1;
+
+typedef union
+{
+    Ifx_UReg_32Bit U;
+    Ifx_SReg_32Bit I;
+    Ifx_STM_TIM2_Bits B;
+} Ifx_STM_TIM2;
+
+typedef union
+{
+    Ifx_UReg_32Bit U;
+    Ifx_SReg_32Bit I;
+    Ifx_STM_TIM3_Bits B;
+} Ifx_STM_TIM3;
+
+typedef union
+{
+    Ifx_UReg_32Bit U;
+    Ifx_SReg_32Bit I;
+    Ifx_STM_TIM4_Bits B;
+} Ifx_STM_TIM4;
+
+typedef union
+{
+    Ifx_UReg_32Bit U;
+    Ifx_SReg_32Bit I;
+    Ifx_STM_TIM5_Bits B;
+} Ifx_STM_TIM5;
+
+typedef union
+{
+    Ifx_UReg_32Bit U;
+    Ifx_SReg_32Bit I;
+    Ifx_STM_TIM6_Bits B;
+} Ifx_STM_TIM6;
+
+typedef union
+{
+    Ifx_UReg_32Bit U;
+    Ifx_SReg_32Bit I;
+    Ifx_STM_CAP_Bits B;
+} Ifx_STM_CAP;
+
+typedef union
+{
+    Ifx_UReg_32Bit U;
+    Ifx_SReg_32Bit I;
+    Ifx_STM_CMP_Bits B;
+} Ifx_STM_CMP;
+
+typedef union
+{
+    Ifx_UReg_32Bit U;
+    Ifx_SReg_32Bit I;
+    Ifx_STM_CMCON_Bits B;
+} Ifx_STM_CMCON;
+
+typedef union
+{
+    Ifx_UReg_32Bit U;
+    Ifx_SReg_32Bit I;
+    Ifx_STM_ICR_Bits B;
+} Ifx_STM_ICR;
+
+typedef union
+{
+    Ifx_UReg_32Bit U;
+    Ifx_SReg_32Bit I;
+    Ifx_STM_ISCR_Bits B;
+} Ifx_STM_ISCR;
+
+typedef union
+{
+    Ifx_UReg_32Bit U;
+    Ifx_SReg_32Bit I;
+    Ifx_STM_TIM0SV_Bits B;
+} Ifx_STM_TIM0SV;
+
+typedef union
+{
+    Ifx_UReg_32Bit U;
+    Ifx_SReg_32Bit I;
+    Ifx_STM_CAPSV_Bits B;
+} Ifx_STM_CAPSV;
@@ -0,0 +1,191 @@
+use std::collections::HashMap;
+
+use crate::qecu::peripheral::{BusContext, Peripheral, PeripheralState};
+use crate::utils::workflow::WorkflowStm;
+
+// STM register offsets (TC3xx).
+const STM_TIM0: u64 = 0x10;
+const STM_TIM6: u64 = 0x28;
+const STM_CAP: u64 = 0x2C;
+const STM_CMP0: u64 = 0x30;
+const STM_CMP1: u64 = 0x34;
+const STM_CMCON: u64 = 0x38;
+const STM_ICR: u64 = 0x3C;
+const STM_ISCR: u64 = 0x40;
+const STM_TIM0SV: u64 = 0x50;
+const STM_CAPSV: u64 = 0x54;
+const STM_SIZE: u64 = 0x100;
+
+// ICR fields, CMP1 fields are the CMP0 ones shifted by 4.
+const ICR_CMPEN: u32 = 1 << 0;
+const ICR_CMPIR: u32 = 1 << 1;
+const ICR_CMPOS: u32 = 1 << 2;
+
+/// System Timer: a free running 64 bit counter with two compare channels.
+#[derive(Clone)]
+pub struct Stm {
+    name: String,
+    base: u64,
+    src: [u64; 2],
+    frequency: u64,
+    time: u64,
+    counter: u64,
+    cap: u32,
+    capsv: u32,
+    cmp: [u32; 2],
+    cmcon: u32,
+    icr: u32,
+    regs: HashMap<u64, u32>
+}
+
+impl Stm {
+    /// `src` are the SRC registers of the SR0 and SR1 service requests.
+    pub fn new(name: &str, base: u64, src: [u64; 2], config: &WorkflowStm) -> Stm {
+        return Stm {
+            name: name.to_string(),
+            base: base,
+            src: src,
+            frequency: config.frequency,
+            time: 0,
+            counter: 0,
+            cap: 0,
+            capsv: 0,
+            cmp: [0; 2],
+            cmcon: 0,
+            icr: 0,
+            regs: HashMap::new()
+        };
+    }
+
+    fn now(&self) -> u64 {
+        (self.time as u128 * self.frequency as u128 / 1_000_000_000) as u64
+    }
+
+    /// True if the compared bits of the counter went through CMPx in (old, new].
+    fn matched(&self, channel: usize, old: u64, new: u64) -> bool {
+        let fields = self.cmcon >> (channel * 16);
+        let msize = fields & 0x1F;
+        let mstart = (fields >> 8) & 0x1F;
+        let mask = (1u64 << (msize + 1)) - 1;
+        let (old, new) = (old >> mstart, new >> mstart);
+        if old == new {
+            return false;
+        }
+        let mut distance = (self.cmp[channel] as u64).wrapping_sub(old) & mask;
+        if distance == 0 {
+            distance = mask + 1;
+        }
+        return distance <= new - old;
+    }
+
+    fn compare(&mut self, old: u64, new: u64, ctx: &mut BusContext) {
+        for channel in 0..2 {
+            if !self.matched(channel, old, new) {
+                continue;
+            }
+            let shift = channel * 4;
+            self.icr |= ICR_CMPIR << shift;
+            if self.icr & (ICR_CMPEN << shift) != 0 {
+                let sr = ((self.icr >> shift) & ICR_CMPOS != 0) as usize;
+                ctx.request(self.src[sr]);
+            }
+        }
+    }
+}
+
+impl Peripheral for Stm {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn base(&self) -> u64 {
+        self.base
+    }
+
+    fn size(&self) -> u64 {
+        STM_SIZE
+    }
+
+    fn read(&mut self, offset: u64, _size: usize, _ctx: &mut BusContext) -> u64 {
+        let register = offset & !0x3;
+        let value = match register {
+            // TIM0..TIM5 are 32 bit windows of the counter starting at bit 4 * n,
+            // reading one of them latches the upper half in CAP.
+            STM_TIM0..=STM_TIM6 => {
+                let counter = self.now();
+                let index = (register - STM_TIM0) / 4;
+                let shift = if register == STM_TIM6 { 32 } else { index * 4 };
+                if register != STM_TIM6 {
+                    self.cap = (counter >> 32) as u32;
+                }
+                (counter >> shift) as u32
+            }
+            STM_CAP => self.cap,
+            STM_CMP0 => self.cmp[0],
+            STM_CMP1 => self.cmp[1],
+            STM_CMCON => self.cmcon,
+            STM_ICR => self.icr,
+            STM_ISCR => 0,
+            STM_TIM0SV => {
+                let counter = self.now();
+                self.capsv = (counter >> 32) as u32;
+                counter as u32
+            }
+            STM_CAPSV => self.capsv,
+            _ => self.regs.get(&register).copied().unwrap_or(0)
+        };
+        return (value >> ((offset & 0x3) * 8)) as u64;
+    }
+
+    fn write(&mut self, offset: u64, size: usize, value: u64, _ctx: &mut BusContext) {
+        let register = offset & !0x3;
+        let shift = (offset & 0x3) * 8;
+        let mask = if size >= 4 { 0xFFFFFFFF } else { ((1u32 << (size * 8)) - 1) << shift };
+        let value = (value as u32) << shift;
+        let merge = |current: u32| (current & !mask) | (value & mask);
+        match register {
+            STM_CMP0 => self.cmp[0] = merge(self.cmp[0]),
+            STM_CMP1 => self.cmp[1] = merge(self.cmp[1]),
+            STM_CMCON => self.cmcon = merge(self.cmcon) & 0x1F1F1F1F,
+            // CMPxIR are only changed by hardware and ISCR.
+            STM_ICR => self.icr = (merge(self.icr) & 0x55) | (self.icr & 0x22),
+            STM_ISCR => {
+                let value = value & mask;
+                for channel in 0..2 {
+                    let ir = ICR_CMPIR << (channel * 4);
+                    if value & (1 << (channel * 2)) != 0 {
+                        self.icr &= !ir;
+                    }
+                    if value & (2 << (channel * 2)) != 0 {
+                        self.icr |= ir;
+                    }
+                }
+            }
+            STM_TIM0..=STM_TIM6 | STM_CAP | STM_TIM0SV | STM_CAPSV => {}
+            _ => {
+                let current = self.regs.get(&register).copied().unwrap_or(0);
+                self.regs.insert(register, merge(current));
+            }
+        }
+    }
+
+    fn tick(&mut self, now: u64, ctx: &mut BusContext) {
+        self.time = now;
+        let counter = self.now();
+        // The clock goes back when a snapshot is restored.
+        if counter > self.counter {
+            self.compare(self.counter, counter, ctx);
+        }
+        self.counter = counter;
+    }
+
+    fn save(&self) -> PeripheralState {
+        Box::new(self.clone())
+    }
+
+    fn restore(&mut self, state: &PeripheralState) {
+        if let Some(state) = state.downcast_ref::<Stm>() {
+            *self = state.clone();
+        }
+    }
+}
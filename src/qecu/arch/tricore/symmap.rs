@@ -1,4 +1,10 @@
+use crate::qecu::regmap::FieldValue;
 
 pub trait SymMap {
-    fn get_symbol(&self, symbol: String) -> u32;
+    /// Address of `symbol`, an error for unknown symbols.
+    fn get_symbol(&self, symbol: &str) -> Result<u64, String>;
+    /// `MODULE_REG` name of the register at `address`.
+    fn get_name(&self, address: u64) -> Option<String>;
+    /// Bitfields of `value` read from the register `symbol`.
+    fn decode(&self, symbol: &str, value: u64) -> Result<Vec<FieldValue>, String>;
 }
\ No newline at end of file
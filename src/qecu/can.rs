@@ -0,0 +1,132 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::VecDeque;
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+
+const FD_LENGTHS: [usize; 16] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 12, 16, 20, 24, 32, 48, 64];
+
+pub fn dlc_to_len(dlc: u8) -> usize {
+    FD_LENGTHS[(dlc & 0xF) as usize]
+}
+
+/// Smallest DLC able to carry `len` bytes.
+pub fn len_to_dlc(len: usize) -> u8 {
+    FD_LENGTHS.iter().position(|max| *max >= len).unwrap_or(15) as u8
+}
+
+pub fn to_hex<S: Serializer>(data: &Vec<u8>, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&data.iter().map(|b| format!("{:02x}", b)).collect::<String>())
+}
+
+pub fn from_hex<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+    let hex = String::deserialize(deserializer)?;
+    return parse_hex(&hex).ok_or_else(|| serde::de::Error::custom("invalid hex data"));
+}
+
+pub fn parse_hex(hex: &str) -> Option<Vec<u8>> {
+    let hex: String = hex.chars().filter(|c| !c.is_whitespace()).collect();
+    if hex.len() % 2 != 0 {
+        return None;
+    }
+    (0..hex.len() / 2).map(|i| u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).ok()).collect()
+}
+
+/// A classic or FD frame, `data` is hex encoded in JSON.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct CanFrame {
+    pub id: u32,
+    #[serde(default)]
+    pub extended: bool,
+    #[serde(default)]
+    pub fd: bool,
+    #[serde(default)]
+    pub brs: bool,
+    #[serde(default)]
+    pub rtr: bool,
+    #[serde(default, serialize_with = "to_hex", deserialize_with = "from_hex")]
+    pub data: Vec<u8>
+}
+
+impl CanFrame {
+    pub fn new(id: u32, data: Vec<u8>) -> CanFrame {
+        return CanFrame {
+            id: id,
+            extended: id > 0x7FF,
+            fd: data.len() > 8,
+            data: data,
+            ..CanFrame::default()
+        };
+    }
+}
+
+/// Frames exchanged between an emulated CAN node and the outside: injected
+/// frames wait in the inbox until the controller receives them, transmitted
+/// ones are sent to every subscriber. Taps see the injected frames too, for
+/// host-side nodes sharing the bus with the firmware.
+#[derive(Clone, Debug)]
+pub struct CanPort {
+    name: String,
+    inbox: Arc<Mutex<VecDeque<CanFrame>>>,
+    subscribers: Arc<Mutex<Vec<Sender<CanFrame>>>>,
+    taps: Arc<Mutex<Vec<Sender<CanFrame>>>>
+}
+
+impl CanPort {
+    pub fn new(name: &str) -> CanPort {
+        return CanPort {
+            name: name.to_string(),
+            inbox: Arc::new(Mutex::new(VecDeque::new())),
+            subscribers: Arc::new(Mutex::new(Vec::new())),
+            taps: Arc::new(Mutex::new(Vec::new()))
+        };
+    }
+
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    /// Queues a frame for reception by the firmware.
+    pub fn inject(&self, frame: CanFrame) {
+        self.taps.lock().unwrap().retain(|tx| tx.send(frame.clone()).is_ok());
+        self.inbox.lock().unwrap().push_back(frame);
+    }
+
+    /// Returns a channel receiving every frame injected in the node.
+    pub fn tap(&self) -> Receiver<CanFrame> {
+        let (tx, rx) = channel();
+        self.taps.lock().unwrap().push(tx);
+        return rx;
+    }
+
+    pub fn pop_received(&self) -> Option<CanFrame> {
+        self.inbox.lock().unwrap().pop_front()
+    }
+
+    /// Frames injected and not received yet.
+    pub fn pending(&self) -> VecDeque<CanFrame> {
+        self.inbox.lock().unwrap().clone()
+    }
+
+    pub fn set_pending(&self, frames: VecDeque<CanFrame>) {
+        *self.inbox.lock().unwrap() = frames;
+    }
+
+    /// Returns a channel receiving every frame transmitted by the firmware.
+    pub fn subscribe(&self) -> Receiver<CanFrame> {
+        let (tx, rx) = channel();
+        self.subscribers.lock().unwrap().push(tx);
+        return rx;
+    }
+
+    pub fn publish(&self, frame: &CanFrame) {
+        self.subscribers.lock().unwrap().retain(|tx| tx.send(frame.clone()).is_ok());
+    }
+}
+
+/// Payload of the `can-tx` event.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CanEvent {
+    pub node: String,
+    #[serde(flatten)]
+    pub frame: CanFrame
+}
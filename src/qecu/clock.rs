@@ -0,0 +1,104 @@
+use std::collections::BTreeMap;
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+use crate::utils::workflow::WorkflowClock;
+
+/// Instructions between two real-time pacing checks.
+const PACE_INTERVAL: u64 = 0x10000;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum TimerAction {
+    /// Rhai callback, `code_type` as in the Interceptor hooks.
+    Script { code_type: u8, callback: String },
+    Event { event_type: String, msg: String }
+}
+
+/// Virtual time base: the emulated time only depends on the number of executed
+/// instructions (and explicit skips), so the same inputs give the same run.
+#[derive(Clone, Debug)]
+pub struct Clock {
+    cpu_frequency: u64,
+    realtime: bool,
+    /// Host instant and emulated time the real-time pacing started at.
+    started: Option<(Instant, u64)>,
+    instructions: u64,
+    skipped: u64,
+    next_id: u64,
+    timers: BTreeMap<(u64, u64), TimerAction>
+}
+
+impl Clock {
+    pub fn new(config: &WorkflowClock) -> Clock {
+        return Clock {
+            cpu_frequency: config.cpu_frequency.max(1),
+            realtime: config.realtime,
+            started: None,
+            instructions: 0,
+            skipped: 0,
+            next_id: 0,
+            timers: BTreeMap::new()
+        };
+    }
+
+    /// Emulated time in nanoseconds.
+    pub fn now(&self) -> u64 {
+        let executed = self.instructions as u128 * 1_000_000_000 / self.cpu_frequency as u128;
+        return executed as u64 + self.skipped;
+    }
+
+    pub fn get_instructions(&self) -> u64 {
+        self.instructions
+    }
+
+    /// Accounts for one executed instruction, returns true if a timer expired.
+    pub fn tick(&mut self) -> bool {
+        self.instructions += 1;
+        if self.realtime && self.instructions % PACE_INTERVAL == 0 {
+            self.pace();
+        }
+        return match self.timers.keys().next() {
+            Some((deadline, _)) => *deadline <= self.now(),
+            None => false
+        };
+    }
+
+    /// Real-time mode: waits until the host time catches up with the emulated one.
+    fn pace(&mut self) {
+        let now = self.now();
+        let (instant, origin) = *self.started.get_or_insert_with(|| (Instant::now(), now));
+        let elapsed = origin + instant.elapsed().as_nanos() as u64;
+        if now > elapsed {
+            sleep(Duration::from_nanos(now - elapsed));
+        }
+    }
+
+    /// Skips `nanos` of emulated time, e.g. a busy wait replaced by a script.
+    pub fn advance(&mut self, nanos: u64) {
+        self.skipped += nanos;
+    }
+
+    pub fn schedule(&mut self, delay: u64, action: TimerAction) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.timers.insert((self.now() + delay, id), action);
+        return id;
+    }
+
+    pub fn cancel(&mut self, id: u64) {
+        self.timers.retain(|(_, timer), _| *timer != id);
+    }
+
+    /// Removes and returns the expired timers, earliest first.
+    pub fn pop_due(&mut self) -> Vec<TimerAction> {
+        let now = self.now();
+        let mut ret = Vec::new();
+        while let Some(entry) = self.timers.first_entry() {
+            if entry.key().0 > now {
+                break;
+            }
+            ret.push(entry.remove());
+        }
+        return ret;
+    }
+}
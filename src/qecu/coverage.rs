@@ -0,0 +1,107 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+use crate::utils::loader::LineInfo;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CoverageBlock {
+    pub address: u64,
+    pub size: u32,
+    pub hits: u64
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Coverage {
+    enabled: bool,
+    blocks: BTreeMap<u64, CoverageBlock>
+}
+
+impl Coverage {
+    pub fn new(enabled: bool) -> Coverage {
+        return Coverage {
+            enabled: enabled,
+            blocks: BTreeMap::new()
+        };
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn on_block(&mut self, address: u64, size: u32) {
+        if !self.enabled {
+            return;
+        }
+        let block = self.blocks.entry(address).or_insert(CoverageBlock {
+            address: address,
+            size: size,
+            hits: 0
+        });
+        block.hits += 1;
+    }
+
+    pub fn reset(&mut self) {
+        self.blocks.clear();
+    }
+
+    pub fn get_blocks(&self) -> Vec<CoverageBlock> {
+        self.blocks.values().cloned().collect()
+    }
+
+    fn hits_at(&self, address: u64) -> u64 {
+        match self.blocks.range(..=address).next_back() {
+            Some((start, block)) if address < start + block.size as u64 => block.hits,
+            _ => 0
+        }
+    }
+
+    /// Exports the covered blocks as a drcov v2 log with a single module mapped at 0,
+    /// so block offsets are the firmware addresses (load the ELF at its link address
+    /// in Lighthouse/Cartographer).
+    pub fn to_drcov(&self, module_path: &str) -> Vec<u8> {
+        let mut out: Vec<u8> = Vec::new();
+        let header = format!(
+            "DRCOV VERSION: 2\n\
+             DRCOV FLAVOR: qecu\n\
+             Module Table: version 2, count 1\n\
+             Columns: id, base, end, entry, checksum, timestamp, path\n\
+             \x20 0, 0x0000000000000000, 0x00000000ffffffff, 0x0000000000000000, 0x00000000, 0x00000000, {}\n\
+             BB Table: {} bbs\n",
+            module_path, self.blocks.len());
+        out.extend_from_slice(header.as_bytes());
+        for block in self.blocks.values() {
+            out.extend_from_slice(&(block.address as u32).to_le_bytes());
+            out.extend_from_slice(&(block.size.min(0xFFFF) as u16).to_le_bytes());
+            out.extend_from_slice(&0u16.to_le_bytes());
+        }
+        return out;
+    }
+
+    /// Exports line coverage in lcov tracefile format, a line is hit when one of
+    /// its addresses lies inside a covered block.
+    pub fn to_lcov(&self, lines: &[LineInfo]) -> String {
+        let mut files: BTreeMap<&str, HashMap<u64, u64>> = BTreeMap::new();
+        for line in lines {
+            let hits = self.hits_at(line.address);
+            let entry = files.entry(line.file.as_str()).or_default().entry(line.line).or_insert(0);
+            *entry = (*entry).max(hits);
+        }
+
+        let mut out = String::from("TN:qecu\n");
+        for (file, file_lines) in files {
+            let mut file_lines: Vec<(u64, u64)> = file_lines.into_iter().collect();
+            file_lines.sort();
+            out.push_str(format!("SF:{}\n", file).as_str());
+            for (line, hits) in &file_lines {
+                out.push_str(format!("DA:{},{}\n", line, hits).as_str());
+            }
+            let hit = file_lines.iter().filter(|(_, hits)| *hits > 0).count();
+            out.push_str(format!("LH:{}\nLF:{}\nend_of_record\n", hit, file_lines.len()).as_str());
+        }
+        return out;
+    }
+}
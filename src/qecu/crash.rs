@@ -0,0 +1,218 @@
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use unicorn_engine::unicorn_const::MemType;
+
+use crate::qecu::arch::tricore::get_cpu_symbol;
+use crate::qecu::arch::tricore::derivative::Derivative;
+use crate::utils::workflow::WorkflowCrash;
+use super::symbols::Symbols;
+
+/// SMU_CMD.CMD value of IfxSmu_Command_triggerAlarm.
+const SMU_CMD_TRIGGER_ALARM: u64 = 0x5;
+const MAX_BACKTRACE: usize = 16;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum CrashKind {
+    UnmappedAccess { address: u64, access: String },
+    ProtectedAccess { address: u64, access: String },
+    ExecOutsideCode { address: u64 },
+    Trap { class: u8, tin: u32, name: String },
+    Alarm { source: String },
+    Hang { budget: u64 },
+    Assertion { message: String },
+    EmulatorError { error: String }
+}
+
+impl CrashKind {
+    fn tag(&self) -> &str {
+        match self {
+            CrashKind::UnmappedAccess { .. } => "unmapped",
+            CrashKind::ProtectedAccess { .. } => "protected",
+            CrashKind::ExecOutsideCode { .. } => "exec-outside-code",
+            CrashKind::Trap { .. } => "trap",
+            CrashKind::Alarm { .. } => "alarm",
+            CrashKind::Hang { .. } => "hang",
+            CrashKind::Assertion { .. } => "assertion",
+            CrashKind::EmulatorError { .. } => "error"
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Crash {
+    pub kind: CrashKind,
+    pub pc: u64,
+    pub backtrace: Vec<u64>,
+    pub symbolized: Vec<String>,
+    pub bucket: String
+}
+
+pub fn trap_name(class: u8) -> &'static str {
+    match class {
+        0 => "MMU",
+        1 => "Internal Protection",
+        2 => "Instruction Error",
+        3 => "Context Management",
+        4 => "System Bus and Peripheral Error",
+        5 => "Assertion",
+        6 => "System Call",
+        _ => "Non-Maskable Interrupt"
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct CrashMonitor {
+    code_regions: Vec<(u64, u64)>,
+    check_code_regions: bool,
+    watchdog_budget: Option<u64>,
+    since_watchdog: u64,
+    wdt_addresses: Vec<u64>,
+    smu_cmd: Option<u64>,
+    expected_trap: Option<u64>,
+    pending: Option<CrashKind>,
+    crashes: Vec<Crash>
+}
+
+impl CrashMonitor {
+    pub fn new(wf_crash: &Option<WorkflowCrash>, mut code_regions: Vec<(u64, u64)>, derivative: &Derivative) -> CrashMonitor {
+        let mut monitor = CrashMonitor::default();
+        if let Some(wf_crash) = wf_crash {
+            for region in &wf_crash.code_regions {
+                code_regions.push((region.from, region.from + region.size as u64));
+            }
+            monitor.check_code_regions = wf_crash.check_code_regions;
+            monitor.watchdog_budget = wf_crash.watchdog_budget;
+        }
+        monitor.code_regions = code_regions;
+        let cpu = String::from(derivative.name);
+        // Watchdog service registers, a write to any of them resets the watchdog budget.
+        let mut wdt_con0: Vec<String> = (0..derivative.cores).map(|core| format!("SCU_WDTCPU{}_CON0", core)).collect();
+        wdt_con0.push(String::from("SCU_WDTS_CON0"));
+        monitor.wdt_addresses = wdt_con0.into_iter().filter_map(|name| get_cpu_symbol(cpu.clone(), name).ok()).map(|address| address as u64).collect();
+        monitor.smu_cmd = get_cpu_symbol(cpu, String::from("SMU_CMD")).ok().map(|address| address as u64);
+        return monitor;
+    }
+
+    pub fn has_pending(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// Declares a crash detected by a hook; the emulator stops and triages it.
+    pub fn raise(&mut self, kind: CrashKind) {
+        if self.pending.is_none() {
+            self.pending = Some(kind);
+        }
+    }
+
+    /// Traps raised on purpose (scripts, API) must not be reported when entering `vector`.
+    pub fn expect_trap(&mut self, vector: u64) {
+        self.expected_trap = Some(vector);
+    }
+
+    /// Returns true if execution must stop before `address`. `btv` is the trap
+    /// vector base, entering one of its handlers is a trap of class (pc - BTV) / 32.
+    pub fn on_instruction(&mut self, address: u64, btv: u64, d15: u64) -> bool {
+        if self.check_code_regions && !self.code_regions.iter().any(|(from, to)| address >= *from && address < *to) {
+            self.raise(CrashKind::ExecOutsideCode { address: address });
+        }
+        if self.expected_trap == Some(address) {
+            self.expected_trap = None;
+        } else if btv != 0 && address >= btv && address < btv + 8 * 32 && (address - btv) % 32 == 0 {
+            let class = ((address - btv) / 32) as u8;
+            if class != 6 {
+                self.raise(CrashKind::Trap { class: class, tin: d15 as u32, name: trap_name(class).to_string() });
+            }
+        }
+        if let Some(budget) = self.watchdog_budget {
+            self.since_watchdog += 1;
+            if self.since_watchdog > budget {
+                self.raise(CrashKind::Alarm { source: String::from("watchdog timeout") });
+            }
+        }
+        return self.pending.is_some();
+    }
+
+    pub fn on_write(&mut self, address: u64, value: u64) -> bool {
+        if self.wdt_addresses.contains(&address) {
+            self.since_watchdog = 0;
+        }
+        if Some(address) == self.smu_cmd && value & 0xF == SMU_CMD_TRIGGER_ALARM {
+            self.raise(CrashKind::Alarm { source: format!("SMU alarm {:#x}", (value >> 4) & 0xFFFF) });
+        }
+        return self.pending.is_some();
+    }
+
+    pub fn on_invalid_memory(&mut self, mem_type: MemType, address: u64) {
+        let (protected, access) = match mem_type {
+            MemType::READ_UNMAPPED => (false, "read"),
+            MemType::WRITE_UNMAPPED => (false, "write"),
+            MemType::FETCH_UNMAPPED => (false, "fetch"),
+            MemType::READ_PROT => (true, "read"),
+            MemType::WRITE_PROT => (true, "write"),
+            MemType::FETCH_PROT => (true, "fetch"),
+            _ => (false, "unknown")
+        };
+        let access = access.to_string();
+        if protected {
+            self.raise(CrashKind::ProtectedAccess { address: address, access: access });
+        } else {
+            self.raise(CrashKind::UnmappedAccess { address: address, access: access });
+        }
+    }
+
+    /// Turns the pending crash (or `fallback`) into a deduplicated crash record.
+    pub fn triage(&mut self, fallback: Option<CrashKind>, pc: u64, backtrace: Vec<u64>, symbols: &Symbols) -> Option<Crash> {
+        let kind = self.pending.take().or(fallback)?;
+        let mut hasher = DefaultHasher::new();
+        kind.tag().hash(&mut hasher);
+        pc.hash(&mut hasher);
+        backtrace.hash(&mut hasher);
+        let crash = Crash {
+            bucket: format!("{}-{:08x}-{:016x}", kind.tag(), pc, hasher.finish()),
+            kind: kind,
+            pc: pc,
+            symbolized: backtrace.iter().map(|addr| symbols.symbolize(*addr)).collect(),
+            backtrace: backtrace
+        };
+        print!("[qecu::crash] {:?} at {:#010x} ({}), bucket {}\n", crash.kind, crash.pc, symbols.symbolize(crash.pc), crash.bucket);
+        self.since_watchdog = 0;
+        if !self.crashes.iter().any(|known| known.bucket == crash.bucket) {
+            self.crashes.push(crash.clone());
+        }
+        return Some(crash);
+    }
+
+    pub fn reset(&mut self) {
+        self.pending = None;
+        self.since_watchdog = 0;
+    }
+
+    pub fn get_crashes(&self) -> Vec<Crash> {
+        self.crashes.clone()
+    }
+}
+
+/// Walks the CSA chain: every upper context saved by a call holds the caller's A11.
+/// `pcxi_ul` is the PCXI.UL bit of the core family.
+pub fn backtrace<F>(pc: u64, a11: u64, pcxi: u64, pcxi_ul: u64, read_word: F) -> Vec<u64>
+    where F: Fn(u64) -> Option<u64> {
+    let mut ret = vec![pc, a11];
+    let mut pcxi = pcxi;
+    while ret.len() < MAX_BACKTRACE && pcxi & 0xFFFFF != 0 {
+        let csa = ((pcxi & 0xF0000) << 12) | ((pcxi & 0xFFFF) << 6);
+        let upper = pcxi & pcxi_ul != 0;
+        let next = match read_word(csa) {
+            Some(next) => next,
+            None => break
+        };
+        if upper {
+            match read_word(csa + 12) {
+                Some(ra) => ret.push(ra),
+                None => break
+            }
+        }
+        pcxi = next;
+    }
+    return ret;
+}
@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+
+use super::symbols::Symbols;
+
+/// Longest TriCore instruction, used to size reads when disassembling by count.
+pub const MAX_INSTRUCTION_SIZE: u32 = 4;
+
+/// Length of the TriCore instruction whose first byte is `first`: bit 0 of
+/// the opcode selects the 32-bit formats.
+pub fn instruction_length(first: u8) -> usize {
+    if first & 1 == 1 { 4 } else { 2 }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Instruction {
+    pub address: u64,
+    pub length: u32,
+    pub bytes: Vec<u8>,
+    pub mnemonic: String,
+    pub operands: String
+}
+
+impl Instruction {
+    /// Instruction at `address` encoded by `bytes`, as disassembled by Sleigh.
+    pub fn from_sleigh(ins: &rust_sleigh::ffi::SleighInstruction, address: u64, bytes: &[u8]) -> Instruction {
+        return Instruction {
+            address: address,
+            length: bytes.len() as u32,
+            bytes: bytes.to_vec(),
+            mnemonic: ins.mnemonic.to_string(),
+            operands: ins.body.to_string()
+        };
+    }
+
+    pub fn to_map(&self) -> rhai::Map {
+        let mut map = rhai::Map::new();
+        let bytes: rhai::Array = self.bytes.iter().map(|b| rhai::Dynamic::from(*b as i64)).collect();
+        map.insert("address".into(), (self.address as i64).into());
+        map.insert("length".into(), (self.length as i64).into());
+        map.insert("bytes".into(), bytes.into());
+        map.insert("mnemonic".into(), self.mnemonic.clone().into());
+        map.insert("operands".into(), self.operands.clone().into());
+        return map;
+    }
+}
+
+/// Renders instructions as a listing, with a label line at every function entry.
+pub fn to_listing(instructions: &[Instruction], symbols: &Symbols) -> String {
+    let mut out = String::new();
+    for ins in instructions {
+        if let Some((name, 0)) = symbols.lookup(ins.address) {
+            out.push_str(format!("{}:\n", name).as_str());
+        }
+        let bytes: Vec<String> = ins.bytes.iter().map(|b| format!("{:02x}", b)).collect();
+        out.push_str(format!("  {:#010x}  {:<24} {:<12} {:<8} {}\n",
+            ins.address, symbols.symbolize(ins.address), bytes.join(" "), ins.mnemonic, ins.operands).as_str());
+    }
+    return out;
+}
@@ -0,0 +1,295 @@
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream, UdpSocket};
+use std::sync::mpsc::{channel, Receiver, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use crate::utils::workflow::WorkflowDoip;
+use super::emulator::Emulator;
+use super::isotp::{self, IsoTpConfig};
+
+const DOIP_PORT: u16 = 13400;
+const HEADER_SIZE: usize = 8;
+const MAX_PAYLOAD: usize = 0x10000;
+const ANNOUNCE_COUNT: usize = 3;
+const ANNOUNCE_INTERVAL: Duration = Duration::from_millis(500);
+const DIAG_TIMEOUT: Duration = Duration::from_millis(1000);
+/// P2* server timeout, used after a response pending answer.
+const DIAG_PENDING_TIMEOUT: Duration = Duration::from_millis(5000);
+
+// Payload types.
+const GENERIC_NACK: u16 = 0x0000;
+const VEHICLE_ID_REQUEST: u16 = 0x0001;
+const VEHICLE_ID_REQUEST_EID: u16 = 0x0002;
+const VEHICLE_ID_REQUEST_VIN: u16 = 0x0003;
+const VEHICLE_ANNOUNCEMENT: u16 = 0x0004;
+const ROUTING_ACTIVATION_REQUEST: u16 = 0x0005;
+const ROUTING_ACTIVATION_RESPONSE: u16 = 0x0006;
+const ALIVE_CHECK_REQUEST: u16 = 0x0007;
+const ALIVE_CHECK_RESPONSE: u16 = 0x0008;
+const ENTITY_STATUS_REQUEST: u16 = 0x4001;
+const ENTITY_STATUS_RESPONSE: u16 = 0x4002;
+const POWER_MODE_REQUEST: u16 = 0x4003;
+const POWER_MODE_RESPONSE: u16 = 0x4004;
+const DIAGNOSTIC_MESSAGE: u16 = 0x8001;
+const DIAGNOSTIC_ACK: u16 = 0x8002;
+const DIAGNOSTIC_NACK: u16 = 0x8003;
+
+// Generic header NACK codes.
+const NACK_INCORRECT_PATTERN: u8 = 0x00;
+const NACK_UNKNOWN_PAYLOAD_TYPE: u8 = 0x01;
+const NACK_MESSAGE_TOO_LARGE: u8 = 0x02;
+const NACK_INVALID_PAYLOAD_LENGTH: u8 = 0x04;
+
+// Diagnostic message NACK codes.
+const DIAG_NACK_INVALID_SOURCE: u8 = 0x02;
+const DIAG_NACK_UNKNOWN_TARGET: u8 = 0x03;
+const DIAG_NACK_TARGET_UNREACHABLE: u8 = 0x06;
+
+const ROUTING_ACTIVATION_SUCCESS: u8 = 0x10;
+
+/// Answers of the script target to diagnostic messages, sent back to every
+/// DoIP connection waiting for one.
+#[derive(Clone, Debug, Default)]
+pub struct DiagResponses {
+    subscribers: Arc<Mutex<Vec<Sender<Vec<u8>>>>>
+}
+
+impl DiagResponses {
+    pub fn subscribe(&self) -> Receiver<Vec<u8>> {
+        let (tx, rx) = channel();
+        self.subscribers.lock().unwrap().push(tx);
+        return rx;
+    }
+
+    pub fn publish(&self, data: &[u8]) {
+        self.subscribers.lock().unwrap().retain(|tx| tx.send(data.to_vec()).is_ok());
+    }
+}
+
+fn message(version: u8, payload_type: u16, payload: &[u8]) -> Vec<u8> {
+    let mut data = vec![version, !version];
+    data.extend_from_slice(&payload_type.to_be_bytes());
+    data.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+    data.extend_from_slice(payload);
+    return data;
+}
+
+/// Parses a generic header, returns (version, payload type, payload length)
+/// or the NACK code.
+fn parse_header(header: &[u8]) -> Result<(u8, u16, usize), u8> {
+    if header.len() < HEADER_SIZE || header[0] != !header[1] || !matches!(header[0], 0x01..=0x03 | 0xFF) {
+        return Err(NACK_INCORRECT_PATTERN);
+    }
+    let payload_type = u16::from_be_bytes([header[2], header[3]]);
+    let length = u32::from_be_bytes([header[4], header[5], header[6], header[7]]) as usize;
+    if length > MAX_PAYLOAD {
+        return Err(NACK_MESSAGE_TOO_LARGE);
+    }
+    return Ok((header[0], payload_type, length));
+}
+
+fn announcement(config: &WorkflowDoip) -> Vec<u8> {
+    let mut payload = config.vin.as_bytes().to_vec();
+    payload.resize(17, 0);
+    payload.extend_from_slice(&config.logical_address.to_be_bytes());
+    payload.extend_from_slice(&config.eid.to_be_bytes()[2..]);
+    payload.extend_from_slice(&config.gid.to_be_bytes()[2..]);
+    // No further action, VIN/GID synchronized.
+    payload.extend_from_slice(&[0x00, 0x00]);
+    return payload;
+}
+
+/// Vehicle identification on UDP, plus the announcements sent at startup.
+fn serve_udp(config: WorkflowDoip) {
+    let socket = match UdpSocket::bind(&config.bind) {
+        Ok(socket) => socket,
+        Err(err) => {
+            print!("[qecu::doip] Cannot bind UDP {}: {}\n", config.bind, err);
+            return;
+        }
+    };
+    if socket.set_broadcast(true).is_ok() {
+        for _ in 0..ANNOUNCE_COUNT {
+            if socket.send_to(&message(0x02, VEHICLE_ANNOUNCEMENT, &announcement(&config)), ("255.255.255.255", DOIP_PORT)).is_err() {
+                break;
+            }
+            thread::sleep(ANNOUNCE_INTERVAL);
+        }
+    }
+    let mut buffer = [0u8; 1500];
+    loop {
+        let (size, peer) = match socket.recv_from(&mut buffer) {
+            Ok(received) => received,
+            Err(_) => continue
+        };
+        let (version, payload_type, length) = match parse_header(&buffer[..size]) {
+            Ok(header) => header,
+            Err(code) => {
+                let _ = socket.send_to(&message(0x02, GENERIC_NACK, &[code]), peer);
+                continue;
+            }
+        };
+        let payload = &buffer[HEADER_SIZE..size.min(HEADER_SIZE + length)];
+        let matches = match payload_type {
+            VEHICLE_ID_REQUEST => true,
+            VEHICLE_ID_REQUEST_EID => payload == &config.eid.to_be_bytes()[2..],
+            VEHICLE_ID_REQUEST_VIN => payload == config.vin.as_bytes(),
+            _ => {
+                let _ = socket.send_to(&message(version, GENERIC_NACK, &[NACK_UNKNOWN_PAYLOAD_TYPE]), peer);
+                continue;
+            }
+        };
+        if matches {
+            let version = if version == 0xFF { 0x02 } else { version };
+            let _ = socket.send_to(&message(version, VEHICLE_ANNOUNCEMENT, &announcement(&config)), peer);
+        }
+    }
+}
+
+/// Forwards a diagnostic request to the firmware, calling `respond` with
+/// every answer, response pending ones included.
+fn tunnel(emulator: &Emulator<'static>, config: &WorkflowDoip, data: &[u8], respond: &mut dyn FnMut(Vec<u8>)) -> Result<(), u8> {
+    let pending = |response: &Vec<u8>| response.len() >= 3 && response[0] == 0x7F && response[2] == 0x78;
+    if let Some(event) = &config.event {
+        let responses = emulator.doip.subscribe();
+        let msg = format!("{{\"data\":\"{}\"}}", data.iter().map(|b| format!("{:02x}", b)).collect::<String>());
+        emulator.emit(event.clone(), msg);
+        let mut timeout = DIAG_TIMEOUT;
+        loop {
+            match responses.recv_timeout(timeout) {
+                Ok(response) => {
+                    let more = pending(&response);
+                    respond(response);
+                    if !more {
+                        return Ok(());
+                    }
+                    timeout = DIAG_PENDING_TIMEOUT;
+                }
+                Err(RecvTimeoutError::Timeout) => return Ok(()),
+                Err(RecvTimeoutError::Disconnected) => return Err(DIAG_NACK_TARGET_UNREACHABLE)
+            }
+        }
+    }
+    let link = emulator.frame_link(&config.node).ok_or(DIAG_NACK_TARGET_UNREACHABLE)?;
+    let mut isotp_config = IsoTpConfig::new(config.tx_id, config.rx_id);
+    isotp::send(&link, &isotp_config, data).map_err(|_| DIAG_NACK_TARGET_UNREACHABLE)?;
+    loop {
+        // No answer is fine, e.g. with the suppress positive response bit.
+        let response = match isotp::receive(&link, &isotp_config) {
+            Ok(response) => response,
+            Err(_) => return Ok(())
+        };
+        let more = pending(&response);
+        respond(response);
+        if !more {
+            return Ok(());
+        }
+        isotp_config.timeout_ms = DIAG_PENDING_TIMEOUT.as_millis() as u64;
+    }
+}
+
+fn serve_connection(emulator: Emulator<'static>, config: WorkflowDoip, mut stream: TcpStream) {
+    let entity = config.logical_address.to_be_bytes();
+    let mut tester: Option<[u8; 2]> = None;
+    let mut header = [0u8; HEADER_SIZE];
+    loop {
+        if stream.read_exact(&mut header).is_err() {
+            break;
+        }
+        let (version, payload_type, length) = match parse_header(&header) {
+            Ok(parsed) => parsed,
+            Err(code) => {
+                let _ = stream.write_all(&message(0x02, GENERIC_NACK, &[code]));
+                if code == NACK_INCORRECT_PATTERN {
+                    break;
+                }
+                // Skips the payload of the rejected message.
+                let length = u32::from_be_bytes([header[4], header[5], header[6], header[7]]) as u64;
+                let _ = std::io::copy(&mut (&stream).take(length), &mut std::io::sink());
+                continue;
+            }
+        };
+        let mut payload = vec![0u8; length];
+        if stream.read_exact(&mut payload).is_err() {
+            break;
+        }
+        let reply = |payload_type: u16, payload: &[u8]| message(version, payload_type, payload);
+        let response = match payload_type {
+            ROUTING_ACTIVATION_REQUEST if length == 7 || length == 11 => {
+                let source = [payload[0], payload[1]];
+                tester = Some(source);
+                print!("[qecu::doip] Routing activated for tester 0x{:04x}\n", u16::from_be_bytes(source));
+                let mut response = vec![source[0], source[1], entity[0], entity[1], ROUTING_ACTIVATION_SUCCESS, 0, 0, 0, 0];
+                if length == 11 {
+                    response.extend_from_slice(&payload[7..11]);
+                }
+                reply(ROUTING_ACTIVATION_RESPONSE, &response)
+            }
+            ALIVE_CHECK_REQUEST => reply(ALIVE_CHECK_RESPONSE, &tester.unwrap_or([0, 0])),
+            ENTITY_STATUS_REQUEST => reply(ENTITY_STATUS_RESPONSE, &[0x01, 0x01, 0x01, 0x00, 0x01, 0x00, 0x00]),
+            POWER_MODE_REQUEST => reply(POWER_MODE_RESPONSE, &[0x01]),
+            DIAGNOSTIC_MESSAGE if length > 4 => {
+                let (source, target) = ([payload[0], payload[1]], [payload[2], payload[3]]);
+                let nack = match (tester, target) {
+                    (Some(tester), _) if tester != source => Some(DIAG_NACK_INVALID_SOURCE),
+                    (None, _) => Some(DIAG_NACK_INVALID_SOURCE),
+                    (_, target) if target != entity => Some(DIAG_NACK_UNKNOWN_TARGET),
+                    _ => None
+                };
+                if let Some(code) = nack {
+                    let _ = stream.write_all(&reply(DIAGNOSTIC_NACK, &[entity[0], entity[1], source[0], source[1], code]));
+                    continue;
+                }
+                if stream.write_all(&reply(DIAGNOSTIC_ACK, &[entity[0], entity[1], source[0], source[1], 0x00])).is_err() {
+                    break;
+                }
+                let mut writer = match stream.try_clone() {
+                    Ok(writer) => writer,
+                    Err(_) => break
+                };
+                let mut respond = |data: Vec<u8>| {
+                    let mut response = vec![entity[0], entity[1], source[0], source[1]];
+                    response.extend_from_slice(&data);
+                    let _ = writer.write_all(&reply(DIAGNOSTIC_MESSAGE, &response));
+                };
+                if let Err(code) = tunnel(&emulator, &config, &payload[4..], &mut respond) {
+                    let _ = stream.write_all(&reply(DIAGNOSTIC_NACK, &[entity[0], entity[1], source[0], source[1], code]));
+                }
+                continue;
+            }
+            ROUTING_ACTIVATION_REQUEST | DIAGNOSTIC_MESSAGE => reply(GENERIC_NACK, &[NACK_INVALID_PAYLOAD_LENGTH]),
+            _ => reply(GENERIC_NACK, &[NACK_UNKNOWN_PAYLOAD_TYPE])
+        };
+        if stream.write_all(&response).is_err() {
+            break;
+        }
+    }
+}
+
+/// Hosts the DoIP entity of the `doip` workflow section, if any.
+pub fn serve(emulator: Emulator<'static>) {
+    let config = match emulator.wf.doip.clone() {
+        Some(config) => config,
+        None => return
+    };
+    let listener = match TcpListener::bind(&config.bind) {
+        Ok(listener) => listener,
+        Err(err) => {
+            print!("[qecu::doip] Cannot bind TCP {}: {}\n", config.bind, err);
+            return;
+        }
+    };
+    print!("[qecu::doip] Entity 0x{:04x} listening on {}\n", config.logical_address, config.bind);
+    let udp_config = config.clone();
+    thread::spawn(move || serve_udp(udp_config));
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            if let Ok(stream) = stream {
+                let (emulator, config) = (emulator.clone(), config.clone());
+                thread::spawn(move || serve_connection(emulator, config, stream));
+            }
+        }
+    });
+}
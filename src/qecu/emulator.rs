@@ -1,11 +1,38 @@
 use rust_sleigh::SleighDecompiler;
 use unicorn_engine::{RegisterTRICORE, Unicorn};
-use unicorn_engine::unicorn_const::{Arch, Mode, Permission};
+use unicorn_engine::unicorn_const::{uc_error, Arch, HookType, MemType, Mode};
 use crate::utils::{self, workflow::Workflow};
 use std::os::raw::c_void;
+use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::fmt;
 use super::interceptor::Interceptor;
+use super::coverage::{Coverage, CoverageBlock};
+use super::disas::{self, Instruction, MAX_INSTRUCTION_SIZE};
+use super::pcode::{self, PcodeOp};
+use super::symbols::Symbols;
+use super::taint::{Taint, TaintReport};
+use super::snapshot::Snapshot;
+use super::memory::Memory;
+use super::scheduler::{CoreState, Scheduler};
+use super::fuzz::FuzzRuntime;
+use super::crash::{self, Crash, CrashKind, CrashMonitor};
+use super::arch::tricore::interrupt::{self, InterruptController};
+use super::arch::tricore::{self as tricore, asclin::Asclin, cpu::CpuControl, derivative::{self, Derivative}, ir::InterruptRouter, mcmcan::Mcmcan, stm::Stm};
+use super::peripheral::{BusContext, PeripheralBus};
+use super::clock::{Clock, TimerAction};
+use super::serial::SerialHost;
+use super::can::{CanFrame, CanPort};
+use super::isotp::{self, FrameLink, IsoTpConfig};
+use super::doip::DiagResponses;
+use super::xcp::XcpSlave;
+use super::a2l::{A2l, LabelInfo};
+use super::regmap::{RegMap, SfrValue};
+use super::arch::tricore::symmap::SymMap;
+use super::uds::{ScanConfig, ScanReport, UdsClient, UdsCommand, UdsResponse};
+use super::socketcan;
+use super::trace::{self, TraceConfig, Tracer, TRACE_REGISTERS};
 
 struct UcWrapper <'a>{
     uc: Unicorn<'a, ()>
@@ -29,39 +56,70 @@ impl fmt::Debug for SleighDecompilerWrapper {
 #[derive(Debug, Clone)]
 pub struct Emulator <'a>{
     pub wf: Arc<Workflow>,
-    uc: Arc<Mutex<UcWrapper<'static>>>,
+    /// One Unicorn instance per core, all of them mapping the same memory.
+    cores: Arc<Vec<Mutex<UcWrapper<'static>>>>,
+    pub scheduler: Arc<Mutex<Scheduler>>,
+    pub derivative: &'static Derivative,
+    /// Address CPU0 starts from: the entry point of the image or the boot
+    /// address of the derivative.
+    pub entry: u64,
     disas: Arc<Mutex<SleighDecompilerWrapper>>,
-    pub interceptor: Arc<Mutex<Option<Interceptor<'a>>>>
+    pub interceptor: Arc<Mutex<Option<Interceptor<'a>>>>,
+    /// Set when the init script registered memory hooks, checked before
+    /// locking the interceptor on every memory access.
+    mem_hooks: Arc<AtomicBool>,
+    pub symbols: Arc<Symbols>,
+    tracer: Arc<Mutex<Tracer>>,
+    coverage: Arc<Mutex<Coverage>>,
+    pub taint: Arc<Mutex<Taint>>,
+    snapshot: Arc<Mutex<Snapshot>>,
+    /// Host memory of the mapped regions, must outlive the Unicorn instance.
+    memory: Arc<Mutex<Memory>>,
+    pub fuzz: Arc<Mutex<FuzzRuntime>>,
+    pub crash: Arc<Mutex<CrashMonitor>>,
+    pub irq: Arc<Mutex<InterruptController>>,
+    pub peripherals: Arc<Mutex<PeripheralBus>>,
+    pub clock: Arc<Mutex<Clock>>,
+    pub can: Arc<HashMap<String, CanPort>>,
+    /// Frames transmitted by the firmware as reported by scripts.
+    pub script_can: CanPort,
+    /// Answers of scripts to DoIP diagnostic messages.
+    pub doip: DiagResponses,
+    pub xcp: Option<Arc<Mutex<XcpSlave>>>,
+    pub a2l: Arc<A2l>,
+    /// Register map of the emulated CPU, annotates peripheral accesses.
+    pub regmap: Arc<RegMap>,
+    events: Arc<Mutex<Vec<(String, String)>>>
 }
 
 impl<'a> Emulator <'static>{
 
     pub fn new(workflow: Workflow) -> Emulator<'static> {
-        let mut unicorn: Unicorn<'_, ()> = Unicorn::new(Arch::TRICORE, Mode::LITTLE_ENDIAN).expect("failed to initialize Unicorn instance");
+        let mut code_regions: Vec<(u64, u64)> = Vec::new();
+        let derivative = derivative::get_derivative(&workflow.cpu)
+            .expect(format!("[qecu::emulator] Unknown cpu {}\n", workflow.cpu).as_str());
+        print!("[qecu::emulator] cpu: {} family: {:?} cores: {}\n", derivative.name, derivative.family, derivative.cores);
+        let mut memory = Memory::new(&derivative::merge_memory_map(derivative.memory_map(0), &workflow.mem_map));
+        let mut cores: Vec<Unicorn<'_, ()>> = Vec::new();
+        for core in 0..derivative.cores {
+            let mut unicorn: Unicorn<'_, ()> = Unicorn::new(Arch::TRICORE, Mode::LITTLE_ENDIAN).expect("failed to initialize Unicorn instance");
+            let mem_maps = derivative::merge_memory_map(derivative.memory_map(core), &workflow.mem_map);
+            memory.map(&mut unicorn, &mem_maps)
+                .unwrap_or_else(|err| panic!("[qecu::emulator] CPU{}: {}\n", core, err));
+            cores.push(unicorn);
+        }
         {
             let workflow = workflow.clone();
             let input = workflow.input;
             let registers = &workflow.registers;
             let code_sections = utils::loader::Loader::load_code_sections(&input.format, &input.path);
-            let uc = &mut unicorn;
-            for mem_map in workflow.mem_map {
-                let mut perms = Permission::NONE;
-                for chr in mem_map.flags.to_uppercase().chars() {
-                    let new_permission_feature = match chr {
-                        'R' => Permission::READ,
-                        'W' => Permission::WRITE,
-                        'X' => Permission::EXEC,
-                        '*' => Permission::ALL,
-                        _ => Permission::NONE
-                    };
-                    perms = perms | new_permission_feature;
-                }
-                uc.mem_map(mem_map.from, mem_map.size, perms)
-                    .expect(format!("[unicorn::mem_map] Failed to write data at {:#01x} of size {}\n", mem_map.from, mem_map.size).as_str());
-                println!("[unicorn::mem_map] address: {:#01x} size: {}", mem_map.from, mem_map.size);
-            }
-        
+            // Memory is shared, the boot registers are the ones of CPU0.
+            let uc = &mut cores[0];
+
             for code_section in code_sections {
+                if code_section.executable && code_section.size > 0 {
+                    code_regions.push((code_section.address, code_section.address + code_section.size as u64));
+                }
                 uc.mem_write(code_section.address, &code_section.data)
                     .expect(format!("[unicorn::mem_wirte] Failed to write data at {:#01x} of size {}\n", code_section.address, code_section.size).as_str());
                 println!("[unicorn::mem_write] address: {:#01x} size: {}", code_section.address, code_section.size);
@@ -73,16 +131,108 @@ impl<'a> Emulator <'static>{
                 println!("[unicorn::reg_write] register: {} value: {:#01x}", register.name, register.value);
             }
         }
+        let entry = utils::loader::Loader::load_entry(&workflow.input.format, &workflow.input.path)
+            .unwrap_or(derivative.boot_address());
+        print!("[qecu::emulator] entry: {:#x}\n", entry);
         let sleigh_path = {
             workflow.sleigh_path.clone()
         };
         let init_script = {
             workflow.init_script.clone()
         };
+        for map in &workflow.register_maps {
+            tricore::set_cpu_regmap(&map.cpu, RegMap::load(&map.paths));
+        }
+        let symbols = Symbols::new(
+            utils::loader::Loader::load_functions(&workflow.input.format, &workflow.input.path),
+            utils::loader::Loader::load_function_ends(&workflow.input.format, &workflow.input.path)
+        );
+        let tracer = Tracer::new(&workflow.trace, &symbols);
+        let coverage = Coverage::new(workflow.coverage);
+        let taint = Taint::new(&workflow.taint, &symbols, derivative.cores);
+        let crash = CrashMonitor::new(&workflow.crash, code_regions, derivative);
+        let clock = Clock::new(&workflow.clock);
+        let a2l = A2l::new(&workflow.a2l);
+        let xcp = workflow.xcp.as_ref().map(|xcp| {
+            let mut xcp = xcp.clone();
+            xcp.a2l = xcp.a2l.or(workflow.a2l.clone());
+            XcpSlave::new(&xcp, &symbols)
+        });
+        let mut peripherals = PeripheralBus::new();
+        // Without a register map none of the peripherals can be placed.
+        let regmap = tricore::get_cpu_regmap(&workflow.cpu)
+            .filter(|regmap| !regmap.get_registers().is_empty())
+            .expect(format!("[qecu::emulator] No register map for {}, add one to register_maps\n", derivative.name).as_str());
+        let symbol = |name: String| regmap.get_symbol(&name).ok();
+        match symbol(String::from("SRC_CPU_CPU0_SB")) {
+            Some(base) => peripherals.set_router(Box::new(InterruptRouter::new(base, 0x2000))),
+            None => print!("[qecu::emulator] No interrupt router for {}\n", derivative.name)
+        }
+        for core in 0..derivative.cores {
+            match symbol(format!("CPU{}_DBGSR", core)) {
+                Some(base) => peripherals.add(Box::new(CpuControl::new(core, base))),
+                None => print!("[qecu::emulator] CPU{} cannot be started without its DBGSR\n", core)
+            }
+        }
+        for index in 0..derivative.stm {
+            let name = format!("STM{}", index);
+            let src = [symbol(format!("SRC_STM_STM{}_SR0", index)), symbol(format!("SRC_STM_STM{}_SR1", index))];
+            match (symbol(format!("{}_CLC", name)), src) {
+                (Some(base), [Some(sr0), Some(sr1)]) => peripherals.add(Box::new(Stm::new(&name, base, [sr0, sr1], &workflow.stm))),
+                _ => print!("[qecu::emulator] Skipping {}\n", name)
+            }
+        }
+        let mut can = HashMap::new();
+        for &module in derivative.can {
+            let src: Option<Vec<u64>> = (0..16).map(|line| symbol(format!("SRC_CAN_{}_INT{}", module, line))).collect();
+            match (symbol(format!("{}_RAM", module)), src) {
+                (Some(base), Some(src)) => {
+                    let ports: Vec<CanPort> = (0..4).map(|node| CanPort::new(&format!("{}_N{}", module, node))).collect();
+                    for port in &ports {
+                        can.insert(port.name(), port.clone());
+                    }
+                    peripherals.add(Box::new(Mcmcan::new(module, base, ports, src)));
+                },
+                _ => print!("[qecu::emulator] Skipping {}\n", module)
+            }
+        }
+        for link in &workflow.socketcan {
+            match can.get(&link.node.to_uppercase()) {
+                Some(port) => socketcan::bridge(port, &link.interface),
+                None => print!("[qecu::socketcan] Unknown CAN node {}\n", link.node)
+            }
+        }
+        for uart in &workflow.uart {
+            let name = uart.module.to_uppercase();
+            let index = name.strip_prefix("ASCLIN").and_then(|index| index.parse::<usize>().ok());
+            if index.map_or(true, |index| index >= derivative.asclin) {
+                print!("[qecu::emulator] {} has no {}\n", derivative.name, name);
+                continue;
+            }
+            let src = ["TX", "RX", "ERR"].map(|sr| symbol(format!("SRC_ASCLIN_{}_{}", name, sr)));
+            match (symbol(format!("{}_CLC", name)), src) {
+                (Some(base), [Some(tx), Some(rx), Some(err)]) => {
+                    let host = SerialHost::open(&name, &uart.host);
+                    peripherals.add(Box::new(Asclin::new(&name, base, [tx, rx, err], host)));
+                },
+                _ => print!("[qecu::emulator] Skipping {}\n", name)
+            }
+        }
+        // SFRs start at their reset value, reads of the modelled ones are refreshed from their peripheral.
+        for register in regmap.get_registers() {
+            if register.reset != 0 {
+                cores[0].mem_write(register.address, &register.reset_bytes()).unwrap_or(());
+            }
+        }
+
+        let scheduler = Scheduler::new(derivative.cores, &workflow.scheduler);
 
         Emulator {
             wf: Arc::new(workflow), 
-            uc: Arc::new(Mutex::new(UcWrapper { uc: unicorn })),
+            cores: Arc::new(cores.into_iter().map(|uc| Mutex::new(UcWrapper { uc: uc })).collect()),
+            scheduler: Arc::new(Mutex::new(scheduler)),
+            derivative: derivative,
+            entry: entry,
             disas: 
                 Arc::new(
                     Mutex::new(SleighDecompilerWrapper { 
@@ -92,7 +242,26 @@ impl<'a> Emulator <'static>{
                                                     }
                                                 )
                                             ),
-            interceptor: Arc::new(Mutex::new(Some(Interceptor::new(init_script))))
+            interceptor: Arc::new(Mutex::new(Some(Interceptor::new(init_script)))),
+            mem_hooks: Arc::new(AtomicBool::new(false)),
+            symbols: Arc::new(symbols),
+            tracer: Arc::new(Mutex::new(tracer)),
+            coverage: Arc::new(Mutex::new(coverage)),
+            taint: Arc::new(Mutex::new(taint)),
+            snapshot: Arc::new(Mutex::new(Snapshot::default())),
+            memory: Arc::new(Mutex::new(memory)),
+            fuzz: Arc::new(Mutex::new(FuzzRuntime::new())),
+            crash: Arc::new(Mutex::new(crash)),
+            irq: Arc::new(Mutex::new(InterruptController::default())),
+            peripherals: Arc::new(Mutex::new(peripherals)),
+            clock: Arc::new(Mutex::new(clock)),
+            can: Arc::new(can),
+            script_can: CanPort::new("SCRIPT"),
+            doip: DiagResponses::default(),
+            xcp: xcp.map(|xcp| Arc::new(Mutex::new(xcp))),
+            a2l: Arc::new(a2l),
+            regmap: regmap,
+            events: Arc::new(Mutex::new(Vec::new()))
         }
     }
 
@@ -102,6 +271,59 @@ impl<'a> Emulator <'static>{
             return Unicorn::from_handle(uc_handle).unwrap();
         }
     }
+
+    /// Unicorn instance of `core`, `mut_uc` being the one of the current core.
+    pub fn core_uc(&self, core: usize) -> Unicorn<'_, ()> {
+        unsafe {
+            let uc_handle = self.cores[core].lock().unwrap().uc.get_handle();
+            return Unicorn::from_handle(uc_handle).unwrap();
+        }
+    }
+
+    /// Core executing instructions, the one hooks and scripts act on.
+    pub fn current_core(&self) -> usize {
+        self.scheduler.lock().unwrap().current()
+    }
+
+    pub fn get_cores(&self) -> Vec<CoreState> {
+        let scheduler = self.scheduler.lock().unwrap().clone();
+        return (0..scheduler.cores()).map(|core| CoreState {
+            core: core,
+            running: scheduler.is_running(core),
+            pc: self.core_uc(core).pc_read().unwrap_or(0)
+        }).collect();
+    }
+
+    /// Starts `core` at `pc`, or halts it if `pc` is None, as requested by
+    /// the firmware through the DBGSR of the core.
+    fn set_core_mode(&self, core: usize, pc: Option<u64>) {
+        match pc {
+            Some(pc) => {
+                self.core_uc(core).set_pc(pc).expect("[emulator::set_core_mode] Cannot set pc");
+                self.scheduler.lock().unwrap().start(core);
+                let msg = serde_json::json!({ "core": core, "pc": pc }).to_string();
+                self.events.lock().unwrap().push((String::from("core-start"), msg));
+            }
+            None => {
+                let current = {
+                    let mut scheduler = self.scheduler.lock().unwrap();
+                    scheduler.halt(core);
+                    scheduler.current()
+                };
+                if current == core {
+                    self.core_uc(core).emu_stop().expect("[emulator::set_core_mode] Cannot stop emulation");
+                }
+                let msg = serde_json::json!({ "core": core }).to_string();
+                self.events.lock().unwrap().push((String::from("core-halt"), msg));
+            }
+        }
+    }
+
+    /// Ends the run: the current slice stops and no other core is scheduled.
+    fn stop(&self, uc: &mut Unicorn<'_, ()>) {
+        self.scheduler.lock().unwrap().stop();
+        uc.emu_stop().expect("[emulator::stop] Cannot stop emulation");
+    }
     
     pub fn read_register(&self, reg_name: String) -> u64 {
         let ret = self.mut_uc().reg_read(Emulator::get_register(&reg_name))
@@ -112,35 +334,546 @@ impl<'a> Emulator <'static>{
     pub fn write_register(&self, reg_name: String, value: u64) -> u64 {
         self.mut_uc().reg_write(Emulator::get_register(&reg_name), value.try_into().unwrap())
             .expect("[emulator::write_register] Cannot write register\n");
+        let core = self.current_core();
+        self.taint.lock().unwrap().untaint_register(core, &reg_name, &self.symbols);
         return 0;
     }
 
     pub fn read_memory(&self, address: u64, size: usize) -> Vec<u8>{
+        self.sync_peripherals(address, size);
         let ret = self.mut_uc().mem_read_as_vec(address, size)
-            .expect("[emulator::read_memory] Cannot write register\n");
+            .expect("[emulator::read_memory] Cannot read memory\n");
         return ret;
     }
 
     pub fn write_memory(&self, address: u64, data: Vec<u8>) -> u64{
         let data = &data; // b: &Vec<u8>
         let data: &[u8] = &data; // c: &[u8]
+        self.save_pages(address, data.len());
         self.mut_uc().mem_write(address, data)
             .expect("[emulator::write_memory] Cannot write register\n");
+        self.write_peripherals(address, data);
         return 0;
     }
 
+    /// `read_memory` returning None on unmapped memory, for host-side clients.
+    pub fn try_read_memory(&self, address: u64, size: usize) -> Option<Vec<u8>> {
+        self.sync_peripherals(address, size);
+        return self.mut_uc().mem_read_as_vec(address, size).ok();
+    }
+
+    /// Reads `address..address + size` up to the first unmapped page.
+    pub fn read_mapped(&self, address: u64, size: usize) -> Vec<u8> {
+        let end = address.saturating_add(size as u64);
+        let mut data = Vec::with_capacity(size);
+        let mut chunk = address;
+        while chunk < end {
+            let next = ((chunk & !0xFFF) + 0x1000).min(end);
+            match self.try_read_memory(chunk, (next - chunk) as usize) {
+                Some(bytes) => data.extend(bytes),
+                None => break
+            }
+            chunk = next;
+        }
+        return data;
+    }
+
+    pub fn try_write_memory(&self, address: u64, data: &[u8]) -> bool {
+        self.save_pages(address, data.len());
+        if self.mut_uc().mem_write(address, data).is_err() {
+            return false;
+        }
+        self.write_peripherals(address, data);
+        return true;
+    }
+
+    /// Current value of the register `symbol` (a name or a 0x address)
+    /// decoded to its bitfields.
+    pub fn read_sfr(&self, symbol: &str) -> Result<SfrValue, String> {
+        let address = match symbol.strip_prefix("0x").or(symbol.strip_prefix("0X")) {
+            Some(hex) => u64::from_str_radix(hex, 16).map_err(|_| format!("invalid address {}", symbol))?,
+            None => self.regmap.get_symbol(symbol)?
+        };
+        let (register, _) = self.regmap.lookup(address).ok_or(format!("no register at {:#x}", address))?;
+        let data = self.try_read_memory(register.address, (register.width as usize / 8).max(1))
+            .ok_or(format!("cannot read {}", register.name))?;
+        let mut value = [0u8; 8];
+        value[..data.len().min(8)].copy_from_slice(&data[..data.len().min(8)]);
+        return Ok(self.regmap.decode_access(register.address, u64::from_le_bytes(value)).unwrap_or_default());
+    }
+
+    pub fn get_labels(&self) -> Vec<LabelInfo> {
+        return self.a2l.get_labels();
+    }
+
+    /// Physical value of an A2L measurement or characteristic.
+    pub fn read_label(&self, name: &str) -> Result<serde_json::Value, String> {
+        return self.a2l.read(name, &|address, size| self.try_read_memory(address, size));
+    }
+
+    pub fn write_label(&self, name: &str, value: &serde_json::Value) -> Result<(), String> {
+        return self.a2l.write(name, value,
+            &|address, size| self.try_read_memory(address, size),
+            &|address, data| self.try_write_memory(address, data));
+    }
+
+    /// Saves the pages a host write is about to modify in the snapshot, as
+    /// Unicorn only hooks the writes of the guest.
+    fn save_pages(&self, address: u64, size: usize) {
+        if size == 0 {
+            return;
+        }
+        let address = self.memory.lock().unwrap().resolve(self.current_core(), address);
+        self.snapshot.lock().unwrap().on_write(&self.mut_uc(), address, size);
+    }
+
+    /// Refreshes the memory behind peripheral registers in `address..address + size`.
+    fn sync_peripherals(&self, address: u64, size: usize) {
+        let mut bus = self.peripherals.lock().unwrap();
+        if size == 0 || (!bus.contains(address) && !bus.contains(address + size as u64 - 1)) {
+            return;
+        }
+        let mut ctx = BusContext::default();
+        let mut word = address & !0x3;
+        while word < address + size as u64 {
+            if let Some(value) = bus.read(word, 4, &mut ctx) {
+                self.mut_uc().mem_write(word, &(value as u32).to_le_bytes()).unwrap_or(());
+            }
+            word += 4;
+        }
+        self.queue_events(ctx);
+    }
+
+    /// Forwards a script write to the peripheral models, one register at a time.
+    fn write_peripherals(&self, address: u64, data: &[u8]) {
+        let mut bus = self.peripherals.lock().unwrap();
+        if data.is_empty() || (!bus.contains(address) && !bus.contains(address + data.len() as u64 - 1)) {
+            return;
+        }
+        let mut ctx = BusContext::default();
+        let mut start = address;
+        let end = address + data.len() as u64;
+        while start < end {
+            let chunk_end = ((start & !0x3) + 4).min(end);
+            let mut value = [0u8; 8];
+            let bytes = &data[(start - address) as usize..(chunk_end - address) as usize];
+            value[..bytes.len()].copy_from_slice(bytes);
+            bus.write(start, bytes.len(), u64::from_le_bytes(value), &mut ctx);
+            start = chunk_end;
+        }
+        self.queue_events(ctx);
+    }
+
+    fn queue_events(&self, ctx: BusContext) {
+        for (core, pc) in ctx.core_requests {
+            self.set_core_mode(core, pc);
+        }
+        if !ctx.events.is_empty() {
+            self.events.lock().unwrap().extend(ctx.events);
+        }
+    }
+
+    /// Publishes the events raised by peripherals, outside of any hook lock.
+    fn flush_events(&self) {
+        let events: Vec<(String, String)> = self.events.lock().unwrap().drain(..).collect();
+        for (event_type, msg) in events {
+            self.emit(event_type, msg);
+        }
+    }
+
     pub fn set_pc(&self, addr: u64) {
         self.mut_uc().set_pc(addr).expect("[emulator::set_pc] Cannot set pc");
+        let core = self.current_core();
+        self.taint.lock().unwrap().untaint_register(core, "PC", &self.symbols);
     }
 
-    pub fn on_code_hook(&self, _addr: u64, _size: u32) {
+    /// Returns true when emulation has to stop before this instruction.
+    pub fn on_code_hook(&self, uc: &mut Unicorn<'_, ()>, _addr: u64, _size: u32) -> bool {
+        if self.fuzz.lock().unwrap().on_instruction(_addr) {
+            return true;
+        }
+        {
+            let btv = uc.reg_read(RegisterTRICORE::BTV).unwrap_or(0);
+            let d15 = match _addr >= btv && _addr < btv + 0x100 {
+                true => uc.reg_read(RegisterTRICORE::D15).unwrap_or(0),
+                false => 0
+            };
+            if self.crash.lock().unwrap().on_instruction(_addr, btv, d15) {
+                return true;
+            }
+        }
+        let timebase = {
+            let mut scheduler = self.scheduler.lock().unwrap();
+            scheduler.on_instruction();
+            scheduler.is_timebase(scheduler.current())
+        };
+        // Emulated time follows one core, the other ones run within its cycles.
+        let (now, timers) = {
+            let mut clock = self.clock.lock().unwrap();
+            let expired = timebase && clock.tick();
+            let now = clock.now();
+            (now, if expired { clock.pop_due() } else { Vec::new() })
+        };
+        {
+            let mut ctx = BusContext::default();
+            self.peripherals.lock().unwrap().tick(now, &mut ctx);
+            self.queue_events(ctx);
+        }
+        if let Some(xcp) = &self.xcp {
+            xcp.lock().unwrap().on_instruction(self, _addr, now);
+        }
+        self.flush_events();
+        {
+            let mut tracer = self.tracer.lock().unwrap();
+            if tracer.is_enabled() {
+                tracer.on_instruction(_addr, _size);
+                if tracer.wants_registers() {
+                    tracer.on_registers(self.read_trace_registers());
+                }
+            }
+        }
+        let core = self.current_core();
+        self.taint.lock().unwrap().on_instruction(core, _addr, &self.symbols, || self.lift(_addr, _size));
         let mut lock = self.interceptor.lock();
         let intercept = lock.as_mut().unwrap().as_mut().unwrap();
         intercept.on_code_hook(_addr, _size);
+        for timer in &timers {
+            if let TimerAction::Script { code_type, callback } = timer {
+                intercept.on_timer(*code_type, callback);
+            }
+        }
+        drop(lock);
+        for timer in timers {
+            if let TimerAction::Event { event_type, msg } = timer {
+                self.emit(event_type, msg);
+            }
+        }
+        interrupt::deliver(self);
+        self.taint.lock().unwrap().end_hook(core);
+        return self.crash.lock().unwrap().has_pending();
+    }
+
+    /// Returns true when emulation has to stop at this block.
+    pub fn on_block_hook(&self, _addr: u64, _size: u32) -> bool {
+        self.coverage.lock().unwrap().on_block(_addr, _size);
+        {
+            let mut tracer = self.tracer.lock().unwrap();
+            if tracer.is_enabled() {
+                tracer.on_block(_addr, _size);
+                if tracer.wants_registers() {
+                    tracer.on_registers(self.read_trace_registers());
+                }
+            }
+        }
+        self.fuzz.lock().unwrap().on_block(_addr);
+        return false;
+    }
+
+    pub fn on_mem_hook(&self, uc: &mut Unicorn<'_, ()>, mem_type: MemType, address: u64, size: usize, value: i64) {
+        {
+            let mut bus = self.peripherals.lock().unwrap();
+            if bus.contains(address) {
+                let mut ctx = BusContext::default();
+                match mem_type {
+                    MemType::READ => {
+                        if let Some(value) = bus.read(address, size, &mut ctx) {
+                            uc.mem_write(address, &value.to_le_bytes()[..size.min(8)]).unwrap_or(());
+                        }
+                    }
+                    MemType::WRITE => {
+                        bus.write(address, size, value as u64, &mut ctx);
+                    }
+                    _ => {}
+                }
+                drop(bus);
+                self.queue_events(ctx);
+            }
+        }
+        let core = self.current_core();
+        let resolved = self.memory.lock().unwrap().resolve(core, address);
+        self.taint.lock().unwrap().on_memory(core, mem_type == MemType::WRITE, resolved, size);
+        if mem_type == MemType::WRITE {
+            self.snapshot.lock().unwrap().on_write(uc, resolved, size);
+            if self.crash.lock().unwrap().on_write(resolved, value as u64) {
+                self.stop(uc);
+            }
+        }
+        {
+            let mut tracer = self.tracer.lock().unwrap();
+            if tracer.is_enabled() {
+                match mem_type {
+                    MemType::WRITE => tracer.on_memory(true, address, size, value as u64),
+                    MemType::READ => {
+                        let mut data = [0u8; 8];
+                        let size = size.min(8);
+                        uc.mem_read(address, &mut data[..size]).unwrap_or(());
+                        tracer.on_memory(false, address, size, u64::from_le_bytes(data));
+                    }
+                    _ => {}
+                }
+            }
+        }
+        if !self.mem_hooks.load(Ordering::Relaxed) {
+            return;
+        }
+        let write = mem_type == MemType::WRITE;
+        let mut lock = self.interceptor.lock().unwrap();
+        if let Some(intercept) = lock.as_mut() {
+            if intercept.wants_memory(core, write, address, size) {
+                let value = match write {
+                    true => value as u64,
+                    false => {
+                        let mut data = [0u8; 8];
+                        uc.mem_read(address, &mut data[..size.min(8)]).unwrap_or(());
+                        u64::from_le_bytes(data)
+                    }
+                };
+                intercept.on_mem_hook(core, write, address, size, value, self.regmap.decode_access(address, value));
+            }
+        }
+    }
+
+    fn read_trace_registers(&self) -> [u32; 32] {
+        let mut ret = [0u32; 32];
+        for (index, name) in TRACE_REGISTERS.iter().enumerate() {
+            ret[index] = self.read_register(name.to_string()) as u32;
+        }
+        return ret;
+    }
+
+    pub fn start_trace(&self, config: TraceConfig) {
+        self.tracer.lock().unwrap().start(config);
+    }
+
+    pub fn stop_trace(&self) {
+        self.tracer.lock().unwrap().stop();
+    }
+
+    pub fn get_trace(&self) -> Vec<u8> {
+        self.tracer.lock().unwrap().get_data()
+    }
+
+    pub fn get_trace_text(&self) -> Result<String, String> {
+        let data = self.get_trace();
+        return trace::to_text(&data, &self.symbols, &self.regmap);
+    }
+
+    pub fn set_coverage(&self, enabled: bool) {
+        self.coverage.lock().unwrap().set_enabled(enabled);
+    }
+
+    pub fn reset_coverage(&self) {
+        self.coverage.lock().unwrap().reset();
+    }
+
+    pub fn get_coverage(&self) -> Vec<CoverageBlock> {
+        self.coverage.lock().unwrap().get_blocks()
+    }
+
+    pub fn get_coverage_drcov(&self) -> Vec<u8> {
+        self.coverage.lock().unwrap().to_drcov(&self.wf.input.path)
+    }
+
+    pub fn get_coverage_lcov(&self) -> String {
+        let lines = utils::loader::Loader::load_line_info(&self.wf.input.format, &self.wf.input.path);
+        if lines.is_empty() {
+            print!("[qecu::coverage] No DWARF line info in {}, lcov report will be empty.\n", self.wf.input.path);
+        }
+        return self.coverage.lock().unwrap().to_lcov(&lines);
+    }
+
+    pub fn take_snapshot(&self) {
+        let mut snapshot = self.snapshot.lock().unwrap();
+        let cores: Vec<Unicorn<'_, ()>> = (0..self.cores.len()).map(|core| self.core_uc(core)).collect();
+        snapshot.take(&cores)
+            .expect("[emulator::take_snapshot] Cannot save context\n");
+        snapshot.clock = Some(self.clock.lock().unwrap().clone());
+        snapshot.peripherals = Some(self.peripherals.lock().unwrap().save());
+        snapshot.irq = Some(self.irq.lock().unwrap().clone());
+        snapshot.scheduler = Some(self.scheduler.lock().unwrap().clone());
+    }
+
+    pub fn snapshot_pc(&self) -> u64 {
+        self.snapshot.lock().unwrap().pc
+    }
+
+    pub fn restore_snapshot(&self) {
+        let mut snapshot = self.snapshot.lock().unwrap();
+        let mut cores: Vec<Unicorn<'_, ()>> = (0..self.cores.len()).map(|core| self.core_uc(core)).collect();
+        snapshot.restore(&mut cores)
+            .expect("[emulator::restore_snapshot] Cannot restore snapshot\n");
+        if let Some(clock) = &snapshot.clock {
+            *self.clock.lock().unwrap() = clock.clone();
+        }
+        if let Some(peripherals) = &snapshot.peripherals {
+            self.peripherals.lock().unwrap().restore(peripherals);
+        }
+        if let Some(irq) = &snapshot.irq {
+            *self.irq.lock().unwrap() = irq.clone();
+        }
+        if let Some(scheduler) = &snapshot.scheduler {
+            *self.scheduler.lock().unwrap() = scheduler.clone();
+        }
+    }
+
+    /// Classifies why the last run stopped, `fallback` is used when no hook
+    /// detected a crash (emulator error or exhausted budget).
+    pub fn triage(&self, fallback: Option<CrashKind>) -> Option<Crash> {
+        let uc = self.mut_uc();
+        let pc = uc.reg_read(RegisterTRICORE::PC).unwrap_or(0);
+        let a11 = uc.reg_read(RegisterTRICORE::A11).unwrap_or(0);
+        let pcxi = uc.reg_read(RegisterTRICORE::PCXI).unwrap_or(0);
+        let pcxi_ul = self.derivative.family.context_layout().pcxi_ul;
+        let backtrace = crash::backtrace(pc, a11, pcxi, pcxi_ul, |address| {
+            uc.mem_read_as_vec(address, 4).ok().map(|word| u32::from_le_bytes(word[..4].try_into().unwrap()) as u64)
+        });
+        return self.crash.lock().unwrap().triage(fallback, pc, backtrace, &self.symbols);
+    }
+
+    pub fn raise_trap(&self, core: usize, class: u8, tin: u32) -> Result<(), String> {
+        if core >= self.derivative.cores {
+            return Err(format!("{} has no CPU{}", self.derivative.name, core));
+        }
+        self.irq.lock().unwrap().raise_trap(core, class, tin);
+        return Ok(());
+    }
+
+    /// Raises `priority` for the core a service request with this TOS goes to.
+    pub fn raise_interrupt(&self, tos: u8, priority: u8) -> Result<(), String> {
+        if self.derivative.tos_core(tos).is_none() {
+            return Err(format!("TOS {} routes to no core of {}", tos, self.derivative.name));
+        }
+        self.irq.lock().unwrap().raise_interrupt(tos, priority);
+        return Ok(());
+    }
+
+    pub fn raise_crash(&self, kind: CrashKind) {
+        self.crash.lock().unwrap().raise(kind);
+    }
+
+    pub fn get_crashes(&self) -> Vec<Crash> {
+        self.crash.lock().unwrap().get_crashes()
+    }
+
+    /// Queues `frame` for reception on the CAN node `node` (e.g. `CAN0_N0`).
+    pub fn can_inject(&self, node: &str, frame: CanFrame) -> bool {
+        match self.can.get(&node.to_uppercase()) {
+            Some(port) => {
+                port.inject(frame);
+                true
+            }
+            None => {
+                print!("[qecu::can] Unknown CAN node {}\n", node);
+                false
+            }
+        }
+    }
+
+    /// Frame link to the firmware through the CAN node `node`, or through
+    /// script-level events for the `SCRIPT` node: frames to the firmware are
+    /// emitted as `can-recv` events and its frames come from `can_transmit`.
+    pub fn frame_link(&self, node: &str) -> Option<FrameLink> {
+        if node.to_uppercase() == self.script_can.name() {
+            let emu = self.clone();
+            return Some(FrameLink {
+                send: Box::new(move |frame: CanFrame| {
+                    emu.emit(String::from("can-recv"), serde_json::to_string(&frame).unwrap());
+                }),
+                rx: self.script_can.subscribe(),
+                now: self.clock_fn()
+            });
+        }
+        let port = match self.can.get(&node.to_uppercase()) {
+            Some(port) => port.clone(),
+            None => {
+                print!("[qecu::isotp] Unknown CAN node {}\n", node);
+                return None;
+            }
+        };
+        let rx = port.subscribe();
+        return Some(FrameLink {
+            send: Box::new(move |frame: CanFrame| port.inject(frame)),
+            rx: rx,
+            now: self.clock_fn()
+        });
+    }
+
+    /// Emulated time in nanoseconds, for the threads talking to the firmware.
+    fn clock_fn(&self) -> Box<dyn Fn() -> u64 + Send> {
+        let clock = self.clock.clone();
+        return Box::new(move || clock.lock().unwrap().now());
+    }
+
+    /// Sends an ISO-TP payload on `node` and waits for the answer of the firmware.
+    pub fn isotp_request(&self, node: &str, config: &IsoTpConfig, payload: &[u8]) -> Result<Vec<u8>, String> {
+        let link = self.frame_link(node).ok_or(format!("unknown CAN node {}", node))?;
+        return isotp::request(&link, config, payload);
+    }
+
+    /// Sends an ISO-TP payload on `node` without waiting for an answer.
+    pub fn isotp_send(&self, node: &str, config: &IsoTpConfig, payload: &[u8]) -> Result<(), String> {
+        let link = self.frame_link(node).ok_or(format!("unknown CAN node {}", node))?;
+        return isotp::send(&link, config, payload);
+    }
+
+    /// Key of the UDS security access, computed by a function of the init script.
+    fn security_key(&self, function: &Option<String>, level: u8, seed: Vec<u8>) -> Result<Vec<u8>, String> {
+        let function = function.as_ref().ok_or(String::from("no key_function"))?;
+        return self.interceptor.lock().unwrap().as_ref().unwrap().security_key(function, level, seed);
+    }
+
+    pub fn uds_command(&self, node: &str, config: IsoTpConfig, command: &UdsCommand) -> UdsResponse {
+        let link = match self.frame_link(node) {
+            Some(link) => link,
+            None => return UdsResponse { error: Some(format!("unknown CAN node {}", node)), ..UdsResponse::default() }
+        };
+        let key_function = match command {
+            UdsCommand::SecurityAccess { key_function, .. } => Some(key_function.clone()),
+            _ => None
+        };
+        let client = UdsClient::new(link, config);
+        return UdsResponse::new(client.execute(command, &|level, seed| self.security_key(&key_function, level, seed)));
+    }
+
+    pub fn uds_scan(&self, node: &str, mut config: IsoTpConfig, scan: &ScanConfig) -> ScanReport {
+        let link = match self.frame_link(node) {
+            Some(link) => link,
+            None => return ScanReport { error: Some(format!("unknown CAN node {}", node)), ..ScanReport::default() }
+        };
+        config.timeout_ms = scan.timeout_ms;
+        let client = UdsClient::new(link, config);
+        return client.scan(scan, &|level, seed| self.security_key(&scan.key_function, level, seed));
+    }
+
+    /// Addresses behind the aliases of `address..address + size` for the current core.
+    fn resolve_bytes(&self, address: u64, size: u64) -> Vec<u64> {
+        let core = self.current_core();
+        let memory = self.memory.lock().unwrap();
+        return (address..address.saturating_add(size)).map(|byte| memory.resolve(core, byte)).collect();
+    }
+
+    pub fn taint_memory(&self, address: u64, size: u64, label: &str) {
+        let bytes = self.resolve_bytes(address, size);
+        self.taint.lock().unwrap().taint_memory(&bytes, label);
+    }
+
+    pub fn taint_register(&self, name: &str, label: &str) {
+        let core = self.current_core();
+        self.taint.lock().unwrap().taint_register(core, name, label);
+    }
+
+    pub fn get_memory_taint(&self, address: u64, size: u64) -> Vec<String> {
+        let bytes = self.resolve_bytes(address, size);
+        return self.taint.lock().unwrap().get_memory_taint(&bytes);
+    }
+
+    pub fn get_taint_reports(&self) -> Vec<TaintReport> {
+        self.taint.lock().unwrap().get_reports()
     }
 
     pub fn get_uc_handle(&self) -> *mut c_void {
-        self.uc.lock().unwrap().uc.get_handle()
+        let core = self.current_core();
+        self.cores[core].lock().unwrap().uc.get_handle()
     }
 
     pub fn emit(&self, event_type: String, msg: String) {
@@ -149,20 +882,98 @@ impl<'a> Emulator <'static>{
         self.interceptor.lock().unwrap().as_ref().unwrap().emit(event_type, msg);
     }
 
-    pub fn run(&self) {
+    /// Runs the init script and installs the default hooks.
+    pub fn setup(&self) {
         {
             let mut mutex_guard = self.interceptor.lock().unwrap();
             let intercept = mutex_guard.as_mut().unwrap();
             intercept.set_emulator(self.clone());
             intercept.init();
+            self.mem_hooks.store(intercept.has_mem_hooks(), Ordering::Relaxed);
         }
 
-        let mut uc = self.mut_uc();   
-        let callback = move |_uc: &mut Unicorn<'_, ()>, addr: u64, size: u32| {
-            self.on_code_hook(addr, size);
-        };
-        uc.add_code_hook(0, 0xFFFFFFFF, callback).expect("[emulator::run] Cannot install default code_hook");
-        uc.emu_start(0x80003d10, 0xFFFFFFFF, 0x00, 0x00).unwrap();
+        // Hooks are owned by the Unicorn instance registering them, so they are
+        // installed on the ones held by the emulator to outlive this call.
+        for wrapper in self.cores.iter() {
+            let mut wrapper = wrapper.lock().unwrap();
+            let uc = &mut wrapper.uc;
+            let emu = self.clone();
+            let callback = move |_uc: &mut Unicorn<'_, ()>, addr: u64, size: u32| {
+                if emu.on_code_hook(_uc, addr, size) {
+                    emu.stop(_uc);
+                }
+            };
+            uc.add_code_hook(0, 0xFFFFFFFF, callback).expect("[emulator::setup] Cannot install default code_hook");
+            let emu = self.clone();
+            let block_callback = move |_uc: &mut Unicorn<'_, ()>, addr: u64, size: u32| {
+                if emu.on_block_hook(addr, size) {
+                    emu.stop(_uc);
+                }
+            };
+            uc.add_block_hook(0, 0xFFFFFFFF, block_callback).expect("[emulator::setup] Cannot install default block_hook");
+            let emu = self.clone();
+            let mem_callback = move |_uc: &mut Unicorn<'_, ()>, mem_type: MemType, address: u64, size: usize, value: i64| {
+                emu.on_mem_hook(_uc, mem_type, address, size, value);
+                true
+            };
+            uc.add_mem_hook(HookType::MEM_READ | HookType::MEM_WRITE, 0, 0xFFFFFFFF, mem_callback)
+                .expect("[emulator::setup] Cannot install default mem_hook");
+            let emu = self.clone();
+            let invalid_callback = move |_uc: &mut Unicorn<'_, ()>, mem_type: MemType, address: u64, _size: usize, _value: i64| {
+                emu.crash.lock().unwrap().on_invalid_memory(mem_type, address);
+                false
+            };
+            uc.add_mem_hook(HookType::MEM_INVALID, 0, 0xFFFFFFFF, invalid_callback)
+                .expect("[emulator::setup] Cannot install invalid mem_hook");
+        }
+    }
+
+    /// Runs CPU0 from `begin` and the cores it starts, round-robin, until a
+    /// core reaches `until`, `count` instructions were executed (0 for no
+    /// limit), a hook stops the emulation or no core is left running.
+    pub fn start(&self, begin: u64, until: u64, count: usize) -> Result<(), uc_error> {
+        self.core_uc(0).set_pc(begin)?;
+        self.scheduler.lock().unwrap().begin();
+        let mut first = true;
+        loop {
+            let (core, slice, executed) = {
+                let mut scheduler = self.scheduler.lock().unwrap();
+                // CPU0 runs the first slice from `begin`, whichever core ran last.
+                let next = match first {
+                    true => Some(scheduler.select(0)),
+                    false => scheduler.next()
+                };
+                match next {
+                    Some(core) => (core, scheduler.slice(), scheduler.executed()),
+                    None => {
+                        print!("[qecu::scheduler] No core left running\n");
+                        return Ok(());
+                    }
+                }
+            };
+            first = false;
+            let slice = match count {
+                0 => slice,
+                count => slice.min(count as u64 - executed)
+            };
+            let mut uc = self.core_uc(core);
+            let pc = uc.pc_read()?;
+            if pc == until {
+                return Ok(());
+            }
+            uc.emu_start(pc, until, 0x00, slice as usize)?;
+            let scheduler = self.scheduler.lock().unwrap();
+            if scheduler.is_stopped() || (count > 0 && scheduler.executed() >= count as u64) || uc.pc_read()? == until {
+                return Ok(());
+            }
+        }
+    }
+
+    pub fn run(&self) {
+        self.setup();
+        let result = self.start(self.entry, 0xFFFFFFFF, 0x00);
+        self.triage(result.err().map(|err| CrashKind::EmulatorError { error: format!("{:?}", err) }));
+        self.stop_trace();
     }
 
     pub fn disas(&self, code: Vec<u8>, addr: u64, size: u32) -> Vec<rust_sleigh::ffi::SleighInstruction> {
@@ -172,6 +983,49 @@ impl<'a> Emulator <'static>{
         return disas.disas(code, addr, size);
     }
 
+    /// Disassembles `addr..addr + size` one instruction at a time, the length
+    /// of each one coming from its opcode. Stops at the first invalid one.
+    pub fn disassemble(&self, addr: u64, size: u32) -> Vec<Instruction> {
+        let code = self.read_mapped(addr, size as usize);
+        let mut ret = Vec::new();
+        let mut offset = 0;
+        while offset < code.len() {
+            let length = disas::instruction_length(code[offset]);
+            let bytes = match code.get(offset..offset + length) {
+                Some(bytes) => bytes,
+                None => break
+            };
+            let address = addr + offset as u64;
+            match self.disas(bytes.to_vec(), address, length as u32).first() {
+                Some(ins) => ret.push(Instruction::from_sleigh(ins, address, bytes)),
+                None => break
+            }
+            offset += length;
+        }
+        return ret;
+    }
+
+    pub fn disassemble_count(&self, addr: u64, count: u32) -> Vec<Instruction> {
+        let mut ret = self.disassemble(addr, count.saturating_mul(MAX_INSTRUCTION_SIZE));
+        ret.truncate(count as usize);
+        return ret;
+    }
+
+    /// P-code of `addr..addr + size`, lifted from its disassembly.
+    pub fn lift(&self, addr: u64, size: u32) -> Vec<PcodeOp> {
+        return self.disassemble(addr, size).iter().flat_map(tricore::lift::lift).collect();
+    }
+
+    /// Lifts the basic block starting at `addr`, up to its first branch.
+    pub fn lift_block(&self, addr: u64) -> Vec<PcodeOp> {
+        return pcode::cut_block(self.lift(addr, 0x100));
+    }
+
+    pub fn listing(&self, addr: u64, size: u32) -> String {
+        let instructions = self.disassemble(addr, size);
+        return disas::to_listing(&instructions, &self.symbols);
+    }
+
     pub fn get_register(reg_name: &String) -> RegisterTRICORE {
         match reg_name.to_uppercase().as_str() {
             "A0" => RegisterTRICORE::A0,
@@ -208,8 +1062,16 @@ impl<'a> Emulator <'static>{
             "D14" => RegisterTRICORE::D14,
             "D15" => RegisterTRICORE::D15,
             
+            "PC" => RegisterTRICORE::PC,
+            "PSW" => RegisterTRICORE::PSW,
+            "PCXI" => RegisterTRICORE::PCXI,
+            "ICR" => RegisterTRICORE::ICR,
+            "ISP" => RegisterTRICORE::ISP,
+            "BTV" => RegisterTRICORE::BTV,
             "BIV" => RegisterTRICORE::BIV,
             "FCX" => RegisterTRICORE::FCX,
+            "LCX" => RegisterTRICORE::LCX,
+            "SYSCON" => RegisterTRICORE::SYSCON,
             _ => RegisterTRICORE::A0
         }
     }
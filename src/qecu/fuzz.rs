@@ -0,0 +1,323 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::DefaultHasher;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::Path;
+use std::time::Instant;
+
+use crate::utils::workflow::WorkflowFuzz;
+use super::crash::{Crash, CrashKind};
+use super::emulator::Emulator;
+
+pub const MAP_SIZE: usize = 1 << 16;
+
+const INTERESTING_8: [u8; 9] = [0x00, 0x01, 0x10, 0x20, 0x40, 0x64, 0x7F, 0x80, 0xFF];
+
+/// Edge coverage collected from the block hook, AFL style.
+#[derive(Clone, Debug)]
+pub struct FuzzRuntime {
+    pub map: Vec<u8>,
+    prev: u64,
+    enabled: bool,
+    ends: Vec<u64>,
+    pub reached_end: bool
+}
+
+impl FuzzRuntime {
+    pub fn new() -> FuzzRuntime {
+        return FuzzRuntime {
+            map: vec![0; MAP_SIZE],
+            prev: 0,
+            enabled: false,
+            ends: Vec::new(),
+            reached_end: false
+        };
+    }
+
+    pub fn arm(&mut self, ends: &[u64]) {
+        self.map.iter_mut().for_each(|b| *b = 0);
+        self.prev = 0;
+        self.enabled = true;
+        self.ends = ends.to_vec();
+        self.reached_end = false;
+    }
+
+    pub fn on_block(&mut self, address: u64) {
+        if !self.enabled {
+            return;
+        }
+        let cur = (address >> 1) ^ (address << 7);
+        let index = ((cur ^ self.prev) as usize) & (MAP_SIZE - 1);
+        self.map[index] = self.map[index].wrapping_add(1);
+        self.prev = cur >> 1;
+    }
+
+    /// Returns true when `address` is an end condition and emulation must
+    /// stop, checked per instruction as an end may be inside a block.
+    pub fn on_instruction(&mut self, address: u64) -> bool {
+        if !self.enabled || !self.ends.contains(&address) {
+            return false;
+        }
+        self.reached_end = true;
+        return true;
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ExecResult {
+    Ok,
+    Crash(Crash)
+}
+
+fn bucket(hits: u8) -> u8 {
+    match hits {
+        0 => 0,
+        1 => 1,
+        2 => 2,
+        3 => 4,
+        4..=7 => 8,
+        8..=15 => 16,
+        16..=31 => 32,
+        32..=127 => 64,
+        _ => 128
+    }
+}
+
+pub fn input_hash(input: &[u8]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    input.hash(&mut hasher);
+    return hasher.finish();
+}
+
+/// Restores the snapshot, injects `input` and runs until an end address,
+/// a crash or the instruction budget, which is reported as a hang.
+pub fn exec_input(emulator: &Emulator<'static>, config: &WorkflowFuzz, input: &[u8]) -> ExecResult {
+    emulator.restore_snapshot();
+    emulator.crash.lock().unwrap().reset();
+    emulator.fuzz.lock().unwrap().arm(&config.end);
+
+    if let Some(address) = config.inject.address {
+        emulator.write_memory(address, input.to_vec());
+        if let Some(size_register) = &config.inject.size_register {
+            emulator.write_register(size_register.clone(), input.len() as u64);
+        }
+    }
+    if let Some(event) = &config.inject.event {
+        let msg: String = input.iter().map(|b| format!("{:02x}", b)).collect();
+        emulator.emit(event.clone(), msg);
+    }
+
+    let pc = emulator.snapshot_pc();
+    let result = emulator.start(pc, 0xFFFFFFFF, config.budget as usize);
+    let reached_end = {
+        let mut runtime = emulator.fuzz.lock().unwrap();
+        runtime.enabled = false;
+        runtime.reached_end
+    };
+    let fallback = match result {
+        Err(err) => Some(CrashKind::EmulatorError { error: format!("{:?}", err) }),
+        Ok(_) if reached_end => None,
+        Ok(_) => Some(CrashKind::Hang { budget: config.budget })
+    };
+    return match emulator.triage(fallback) {
+        Some(crash) => ExecResult::Crash(crash),
+        None => ExecResult::Ok
+    };
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ReplayFile {
+    pub crash: Crash,
+    pub input: String,
+    pub snapshot: u64,
+    pub firmware: String,
+    pub project: String
+}
+
+pub struct Fuzzer {
+    config: WorkflowFuzz,
+    corpus: Vec<Vec<u8>>,
+    virgin: Vec<u8>,
+    rng: StdRng,
+    execs: u64,
+    crashes: u64
+}
+
+impl Fuzzer {
+    pub fn new(config: WorkflowFuzz) -> Fuzzer {
+        let seed = config.seed.unwrap_or(0x1337);
+        fs::create_dir_all(&config.corpus)
+            .expect(format!("[qecu::fuzz] Cannot create corpus directory {}\n", config.corpus).as_str());
+        fs::create_dir_all(&config.crashes)
+            .expect(format!("[qecu::fuzz] Cannot create crashes directory {}\n", config.crashes).as_str());
+        let mut corpus: Vec<Vec<u8>> = Vec::new();
+        let mut entries: Vec<_> = fs::read_dir(&config.corpus).unwrap().filter_map(|e| e.ok()).map(|e| e.path()).collect();
+        entries.sort();
+        for entry in entries {
+            if let Ok(data) = fs::read(&entry) {
+                corpus.push(data);
+            }
+        }
+        if corpus.is_empty() {
+            corpus.push(vec![0; config.inject.max_size.min(16)]);
+        }
+        print!("[qecu::fuzz] Loaded {} corpus entries.\n", corpus.len());
+        return Fuzzer {
+            config: config,
+            corpus: corpus,
+            virgin: vec![0; MAP_SIZE],
+            rng: StdRng::seed_from_u64(seed),
+            execs: 0,
+            crashes: 0
+        };
+    }
+
+    fn has_new_bits(&mut self, map: &[u8]) -> bool {
+        let mut ret = false;
+        for (index, hits) in map.iter().enumerate() {
+            let b = bucket(*hits);
+            if b != 0 && self.virgin[index] & b == 0 {
+                self.virgin[index] |= b;
+                ret = true;
+            }
+        }
+        return ret;
+    }
+
+    fn mutate(&mut self, input: &[u8]) -> Vec<u8> {
+        let mut out = input.to_vec();
+        let max_size = self.config.inject.max_size.max(1);
+        let rounds = 1 << self.rng.random_range(0..4);
+        for _ in 0..rounds {
+            if out.is_empty() {
+                out.push(self.rng.random());
+            }
+            let pos = self.rng.random_range(0..out.len());
+            match self.rng.random_range(0..7) {
+                0 => out[pos] ^= 1 << self.rng.random_range(0..8),
+                1 => out[pos] = self.rng.random(),
+                2 => out[pos] = INTERESTING_8[self.rng.random_range(0..INTERESTING_8.len())],
+                3 => out[pos] = out[pos].wrapping_add(self.rng.random_range(1..=35)),
+                4 if out.len() < max_size => out.insert(pos, self.rng.random()),
+                5 if out.len() > 1 => { out.remove(pos); }
+                _ => {
+                    let other = &self.corpus[self.rng.random_range(0..self.corpus.len())];
+                    if !other.is_empty() {
+                        let from = self.rng.random_range(0..other.len());
+                        let len = self.rng.random_range(1..=other.len() - from).min(out.len() - pos);
+                        out[pos..pos + len].copy_from_slice(&other[from..from + len]);
+                    }
+                }
+            }
+        }
+        out.truncate(max_size);
+        return out;
+    }
+
+    /// Saves the first input of every crash bucket with its replay file.
+    fn save_crash(&mut self, emulator: &Emulator<'static>, crash: &Crash, input: &[u8]) {
+        let dir = Path::new(&self.config.crashes).join(&crash.bucket);
+        if dir.exists() {
+            return;
+        }
+        self.crashes += 1;
+        fs::create_dir_all(&dir).expect("[qecu::fuzz] Cannot create crash bucket\n");
+        fs::write(dir.join("input"), input).expect("[qecu::fuzz] Cannot write crash input\n");
+        let replay = ReplayFile {
+            crash: crash.clone(),
+            input: input.iter().map(|b| format!("{:02x}", b)).collect(),
+            snapshot: emulator.snapshot_pc(),
+            firmware: emulator.wf.input.path.clone(),
+            project: emulator.wf.project.clone()
+        };
+        fs::write(dir.join("crash.json"), serde_json::to_string_pretty(&replay).unwrap())
+            .expect("[qecu::fuzz] Cannot write replay file\n");
+        print!("[qecu::fuzz] New crash bucket {}\n", dir.display());
+    }
+
+    fn save_corpus(&mut self, input: &[u8]) {
+        let name = format!("id-{:06}-{:016x}", self.corpus.len(), input_hash(input));
+        fs::write(Path::new(&self.config.corpus).join(name), input).expect("[qecu::fuzz] Cannot write corpus file\n");
+        self.corpus.push(input.to_vec());
+    }
+
+    pub fn run(&mut self, emulator: &Emulator<'static>) {
+        let started = Instant::now();
+        // Seed the coverage with the initial corpus.
+        for index in 0..self.corpus.len() {
+            let input = self.corpus[index].clone();
+            exec_input(emulator, &self.config, &input);
+            let map = emulator.fuzz.lock().unwrap().map.clone();
+            self.has_new_bits(&map);
+        }
+        loop {
+            if let Some(iterations) = self.config.iterations {
+                if self.execs >= iterations {
+                    break;
+                }
+            }
+            let parent = self.corpus[self.rng.random_range(0..self.corpus.len())].clone();
+            let input = self.mutate(&parent);
+            let result = exec_input(emulator, &self.config, &input);
+            self.execs += 1;
+            let map = emulator.fuzz.lock().unwrap().map.clone();
+            match result {
+                ExecResult::Crash(crash) => {
+                    self.has_new_bits(&map);
+                    self.save_crash(emulator, &crash, &input);
+                }
+                ExecResult::Ok => {
+                    if self.has_new_bits(&map) {
+                        self.save_corpus(&input);
+                    }
+                }
+            }
+            if self.execs % 1000 == 0 {
+                let secs = started.elapsed().as_secs_f64().max(0.001);
+                print!("[qecu::fuzz] execs: {} ({:.0}/s) corpus: {} crashes: {}\n",
+                    self.execs, self.execs as f64 / secs, self.corpus.len(), self.crashes);
+            }
+        }
+    }
+}
+
+/// Boots the firmware up to the snapshot point.
+fn prepare(emulator: &Emulator<'static>, config: &WorkflowFuzz) {
+    emulator.setup();
+    emulator.start(emulator.entry, config.snapshot, 0)
+        .expect("[qecu::fuzz] Firmware faulted before reaching the snapshot point\n");
+    // A hook stopping the run returns Ok as well.
+    let pc = emulator.core_uc(0).pc_read().unwrap_or(0);
+    if pc != config.snapshot {
+        panic!("[qecu::fuzz] Firmware stopped at {:#x} before reaching the snapshot point {:#x}\n", pc, config.snapshot);
+    }
+    emulator.take_snapshot();
+}
+
+pub fn fuzz(emulator: &Emulator<'static>) {
+    let config = emulator.wf.fuzz.clone().expect("[qecu::fuzz] Missing fuzz section in workflow\n");
+    prepare(emulator, &config);
+    Fuzzer::new(config).run(emulator);
+}
+
+/// Runs a single input from the snapshot point, either a raw input or the
+/// `crash.json` replay file of a crash bucket.
+pub fn replay(emulator: &Emulator<'static>, input_path: &str) -> ExecResult {
+    let config = emulator.wf.fuzz.clone().expect("[qecu::fuzz] Missing fuzz section in workflow\n");
+    let data = fs::read(input_path).expect("[qecu::fuzz] Cannot read input file\n");
+    let input = match input_path.ends_with(".json") {
+        true => {
+            let replay: ReplayFile = serde_json::from_slice(&data).expect("[qecu::fuzz] Invalid replay file\n");
+            (0..replay.input.len() / 2)
+                .map(|i| u8::from_str_radix(&replay.input[i * 2..i * 2 + 2], 16).unwrap())
+                .collect()
+        }
+        false => data
+    };
+    prepare(emulator, &config);
+    let result = exec_input(emulator, &config, &input);
+    print!("[qecu::fuzz] Replay of {}: {:?}\n", input_path, result);
+    return result;
+}
@@ -1,12 +1,18 @@
 use rhai::{Engine, Scope, AST};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::{fs, thread::sleep, time};
+use std::fs;
 use std::fmt;
+use std::thread;
 use rand::RngCore;
 
 
 use super::emulator::Emulator;
+use super::crash::CrashKind;
+use super::clock::TimerAction;
+use super::can::{self, CanFrame};
+use super::isotp::IsoTpConfig;
+use super::regmap::{FieldValue, SfrValue};
 
 #[derive(Clone, Deserialize, Serialize)]
 pub struct CodeHook {
@@ -14,10 +20,24 @@ pub struct CodeHook {
     begin: u64,
     end: u64,
     code_type: u8,
-    content: String
+    content: String,
+    /// Core the hook is restricted to, any core if None.
+    core: Option<usize>
 }
 
 
+/// `MEM_READ`, `MEM_WRITE` or `MEM` hook on an address range.
+#[derive(Clone, Deserialize, Serialize)]
+pub struct MemHook {
+    id: u64,
+    hook_type: String,
+    begin: u64,
+    end: u64,
+    code_type: u8,
+    content: String,
+    core: Option<usize>
+}
+
 #[derive(Clone, Deserialize, Serialize)]
 pub struct EventCallback {
     event_type: String,
@@ -31,6 +51,7 @@ pub struct Interceptor <'a>{
     ast: AST,
     scope: Scope<'a>,
     code_hooks: Vec<CodeHook>,
+    mem_hooks: Vec<MemHook>,
     hook_datas: HashMap<u64, rhai::Map>,
     on_events: Vec<EventCallback>
 }
@@ -51,6 +72,7 @@ impl <'a> Interceptor <'static> {
             ast: ast,
             scope: Scope::new(),
             code_hooks: Vec::new(),
+            mem_hooks: Vec::new(),
             hook_datas: HashMap::new(),
             on_events: Vec::new()
         };
@@ -67,22 +89,38 @@ impl <'a> Interceptor <'static> {
         _engine.run_ast_with_scope(&mut scope, &self.ast).expect("[engine::run_with_scope] Error running init script.\n");
         let intercept = scope.get_value::<Interceptor>("Interceptor").unwrap();
         self.code_hooks = intercept.code_hooks;
+        self.mem_hooks = intercept.mem_hooks;
         self.on_events = intercept.on_events;
         self.hook_datas = intercept.hook_datas;
         self.scope = scope.clone();
     }
 
-    pub fn disas(&mut self, address: i64, size: i64) -> String {
-        let code = self.read_memory(address, size);
+    pub fn disas(&mut self, address: i64, size: i64) -> rhai::Array {
         let address: u64 = address.try_into().unwrap();
         let size: u32 = size.try_into().unwrap();
-        let disas = self.emulator.as_ref().unwrap().disas(code, address, size);
-        let mut out = String::new();
-        for ins in disas {
-            let line = format!("{} {}", ins.mnemonic, ins.body);
-            out.push_str(line.as_str());
-        }
-        return out;
+        let disas = self.emulator.as_ref().unwrap().disassemble(address, size);
+        return disas.iter().map(|ins| rhai::Dynamic::from_map(ins.to_map())).collect();
+    }
+
+    pub fn disas_pc(&mut self, count: i64) -> rhai::Array {
+        let emulator = self.emulator.as_ref().unwrap();
+        let pc = emulator.read_register(String::from("PC"));
+        let disas = emulator.disassemble_count(pc, count.try_into().unwrap());
+        return disas.iter().map(|ins| rhai::Dynamic::from_map(ins.to_map())).collect();
+    }
+
+    pub fn pcode(&mut self, address: i64, size: i64) -> rhai::Array {
+        let ops = self.emulator.as_ref().unwrap().lift(address.try_into().unwrap(), size.try_into().unwrap());
+        return ops.iter().map(|op| rhai::Dynamic::from_map(op.to_map())).collect();
+    }
+
+    pub fn pcode_block(&mut self, address: i64) -> rhai::Array {
+        let ops = self.emulator.as_ref().unwrap().lift_block(address.try_into().unwrap());
+        return ops.iter().map(|op| rhai::Dynamic::from_map(op.to_map())).collect();
+    }
+
+    pub fn listing(&mut self, address: i64, size: i64) -> String {
+        self.emulator.as_ref().unwrap().listing(address.try_into().unwrap(), size.try_into().unwrap())
     }
     
     pub fn read_register(&mut self, reg_name: String) -> i64{
@@ -101,7 +139,7 @@ impl <'a> Interceptor <'static> {
         self.emulator.as_ref().unwrap().write_memory(address.try_into().unwrap(), data).try_into().unwrap()
     }
 
-    pub fn add_cb_hook(&mut self, hook_type: String, address: i64, size: i64, callback: rhai::FnPtr) {
+    pub fn add_cb_hook(&mut self, hook_type: String, address: i64, size: i64, callback: rhai::FnPtr) -> Result<(), Box<rhai::EvalAltResult>> {
         let fn_name = callback.fn_name().to_string();
         match hook_type.as_str() {
             "CODE" => {
@@ -112,17 +150,21 @@ impl <'a> Interceptor <'static> {
                     begin: begin, 
                     end: end, 
                     code_type: 1, 
-                    content: fn_name.clone()
+                    content: fn_name.clone(),
+                    core: None
                 };
                 self.code_hooks.push(code_hook);
             }
+            "MEM_READ" | "MEM_WRITE" | "MEM" => {
+                self.add_mem_hook(hook_type, address, size, 1, fn_name)?;
+            }
             _ => {
                 panic!("[interceptor::add_hook] Unknown hook type {} ", hook_type);
             }
         }
-
+        return Ok(());
     }
-    pub fn add_hook(&mut self, hook_type: String, address: i64, size: i64, function_name: String) {
+    pub fn add_hook(&mut self, hook_type: String, address: i64, size: i64, function_name: String) -> Result<(), Box<rhai::EvalAltResult>> {
         match hook_type.as_str() {
             "CODE" => {
                 let begin: u64 = address.try_into().unwrap();
@@ -132,18 +174,22 @@ impl <'a> Interceptor <'static> {
                     begin: begin, 
                     end: end, 
                     code_type: 0, 
-                    content: function_name.clone()
+                    content: function_name.clone(),
+                    core: None
                 };
                 self.code_hooks.push(code_hook);
             }
+            "MEM_READ" | "MEM_WRITE" | "MEM" => {
+                self.add_mem_hook(hook_type, address, size, 0, function_name)?;
+            }
             _ => {
                 panic!("[interceptor::add_hook] Unknown hook type {} ", hook_type);
             }
         }
-
+        return Ok(());
     }
 
-    pub fn add_hook_with_data(&mut self, hook_type: String, address: i64, size: i64, function_name: String, data: rhai::Map) {
+    pub fn add_hook_with_data(&mut self, hook_type: String, address: i64, size: i64, function_name: String, data: rhai::Map) -> Result<(), Box<rhai::EvalAltResult>> {
         match hook_type.as_str() {
             "CODE" => {
                 let begin: u64 = address.try_into().unwrap();
@@ -154,18 +200,24 @@ impl <'a> Interceptor <'static> {
                     begin: begin, 
                     end: end, 
                     code_type: 0, 
-                    content: function_name.clone()
+                    content: function_name.clone(),
+                    core: None
                 };
                 self.code_hooks.push(code_hook);
                 self.hook_datas.insert(id, data);
             }
+            "MEM_READ" | "MEM_WRITE" | "MEM" => {
+                let id = self.add_mem_hook(hook_type, address, size, 0, function_name)?;
+                self.hook_datas.insert(id, data);
+            }
             _ => {
                 panic!("[interceptor::add_hook] Unknown hook type {} ", hook_type);
             }
         }
+        return Ok(());
     }
 
-    pub fn add_cb_hook_with_data(&mut self, hook_type: String, address: i64, size: i64, callback: rhai::FnPtr, data: rhai::Map) {
+    pub fn add_cb_hook_with_data(&mut self, hook_type: String, address: i64, size: i64, callback: rhai::FnPtr, data: rhai::Map) -> Result<(), Box<rhai::EvalAltResult>> {
         let fn_name = callback.fn_name().to_string();
         match hook_type.as_str() {
             "CODE" => {
@@ -177,17 +229,74 @@ impl <'a> Interceptor <'static> {
                     begin: begin, 
                     end: end, 
                     code_type: 1, 
-                    content: fn_name.clone()
+                    content: fn_name.clone(),
+                    core: None
                 };
                 self.code_hooks.push(code_hook);
                 self.hook_datas.insert(id, data);
             }
+            "MEM_READ" | "MEM_WRITE" | "MEM" => {
+                let id = self.add_mem_hook(hook_type, address, size, 1, fn_name)?;
+                self.hook_datas.insert(id, data);
+            }
             _ => {
                 panic!("[interceptor::add_hook] Unknown hook type {} ", hook_type);
             }
         }
+        return Ok(());
+    }
+    fn add_mem_hook(&mut self, hook_type: String, address: i64, size: i64, code_type: u8, content: String) -> Result<u64, Box<rhai::EvalAltResult>> {
+        let end = address.checked_add(size).filter(|_| address >= 0 && size >= 0);
+        let end = match end {
+            Some(end) => end as u64,
+            None => return Err(format!("invalid memory hook range {:#x}, size {:#x}", address, size).into())
+        };
+        let id = rand::rng().next_u64();
+        let mem_hook = MemHook {
+            id: id,
+            hook_type: hook_type,
+            begin: address as u64,
+            end: end,
+            code_type: code_type,
+            content: content,
+            core: None
+        };
+        self.mem_hooks.push(mem_hook);
+        return Ok(id);
+    }
+
+    /// Restricts the hooks added after the first `code_hooks` code hooks and
+    /// `mem_hooks` memory hooks to `core`.
+    fn restrict_hooks(&mut self, code_hooks: usize, mem_hooks: usize, core: i64) {
+        let core: usize = core.try_into().unwrap();
+        for code_hook in &mut self.code_hooks[code_hooks..] {
+            code_hook.core = Some(core);
+        }
+        for mem_hook in &mut self.mem_hooks[mem_hooks..] {
+            mem_hook.core = Some(core);
+        }
+    }
+
+    /// `add_hook` for the instructions or accesses of one core only.
+    pub fn add_core_hook(&mut self, core: i64, hook_type: String, address: i64, size: i64, function_name: String) -> Result<(), Box<rhai::EvalAltResult>> {
+        let (code_hooks, mem_hooks) = (self.code_hooks.len(), self.mem_hooks.len());
+        self.add_hook(hook_type, address, size, function_name)?;
+        self.restrict_hooks(code_hooks, mem_hooks, core);
+        return Ok(());
+    }
 
+    pub fn add_core_cb_hook(&mut self, core: i64, hook_type: String, address: i64, size: i64, callback: rhai::FnPtr) -> Result<(), Box<rhai::EvalAltResult>> {
+        let (code_hooks, mem_hooks) = (self.code_hooks.len(), self.mem_hooks.len());
+        self.add_cb_hook(hook_type, address, size, callback)?;
+        self.restrict_hooks(code_hooks, mem_hooks, core);
+        return Ok(());
     }
+
+    /// Core executing the hook, 0 for CPU0.
+    pub fn core(&mut self) -> i64 {
+        self.emulator.as_ref().unwrap().current_core().try_into().unwrap()
+    }
+
     pub fn on_cb_event(&mut self, event_type: String, callback: rhai::FnPtr) {
         let fn_name = callback.fn_name().to_string();
         let evt = EventCallback {
@@ -208,9 +317,179 @@ impl <'a> Interceptor <'static> {
         self.on_events.push(evt);
     }
 
-    pub fn sleep(&mut self, millis: i64) {
-        let millis = time::Duration::from_millis(millis.try_into().unwrap());
-        sleep(millis);
+    pub fn taint_memory(&mut self, address: i64, size: i64, label: String) {
+        let emulator = self.emulator.as_ref().unwrap();
+        emulator.taint_memory(address.try_into().unwrap(), size.try_into().unwrap(), &label);
+    }
+
+    pub fn taint_register(&mut self, reg_name: String, label: String) {
+        let emulator = self.emulator.as_ref().unwrap();
+        emulator.taint_register(&reg_name, &label);
+    }
+
+    pub fn get_taint(&mut self, address: i64, size: i64) -> rhai::Array {
+        let emulator = self.emulator.as_ref().unwrap();
+        let labels = emulator.get_memory_taint(address.try_into().unwrap(), size.try_into().unwrap());
+        return labels.into_iter().map(rhai::Dynamic::from).collect();
+    }
+
+    pub fn clear_taint(&mut self) {
+        self.emulator.as_ref().unwrap().taint.lock().unwrap().clear();
+    }
+
+    pub fn add_taint_sink(&mut self, name: String, address: i64, reg_name: String) {
+        let emulator = self.emulator.as_ref().unwrap();
+        emulator.taint.lock().unwrap().add_sink(name, address.try_into().unwrap(), reg_name);
+    }
+
+    /// Raises an interrupt for CPU0, the TOS of a service request at reset.
+    pub fn raise_interrupt(&mut self, priority: i64) -> Result<(), Box<rhai::EvalAltResult>> {
+        return self.raise_interrupt_tos(priority, 0);
+    }
+
+    pub fn raise_interrupt_tos(&mut self, priority: i64, tos: i64) -> Result<(), Box<rhai::EvalAltResult>> {
+        return self.emulator.as_ref().unwrap().raise_interrupt(tos.try_into().unwrap(), priority.try_into().unwrap())
+            .map_err(|err| err.into());
+    }
+
+    /// Injects a frame on a CAN node, `frame` is a map with `id`, `data` (blob
+    /// or hex string) and the optional `extended`, `fd`, `brs` and `rtr` flags.
+    pub fn can_inject(&mut self, node: String, frame: rhai::Map) -> bool {
+        return self.emulator.as_ref().unwrap().can_inject(&node, frame_from_map(&frame));
+    }
+
+    /// Reports a frame transmitted by the firmware to the `SCRIPT` CAN node,
+    /// for firmware whose CAN driver is hooked instead of emulated.
+    pub fn can_transmit(&mut self, frame: rhai::Map) {
+        self.emulator.as_ref().unwrap().script_can.publish(&frame_from_map(&frame));
+    }
+
+    /// Answers the DoIP diagnostic message of the current `doip.event` event.
+    pub fn doip_respond(&mut self, data: rhai::Blob) {
+        self.emulator.as_ref().unwrap().doip.publish(&data);
+    }
+
+    /// Sends an ISO-TP payload in the background, failures are emitted as
+    /// `isotp-error` events.
+    pub fn isotp_send(&mut self, node: String, tx_id: i64, rx_id: i64, data: rhai::Blob) -> Result<(), Box<rhai::EvalAltResult>> {
+        let emulator = self.emulator.as_ref().unwrap().clone();
+        let config = isotp_config(tx_id, rx_id)?;
+        thread::spawn(move || {
+            if let Err(err) = emulator.isotp_send(&node, &config, &data) {
+                emulator.emit(String::from("isotp-error"), err);
+            }
+        });
+        return Ok(());
+    }
+
+    /// Sends an ISO-TP payload in the background, the answer of the firmware
+    /// is emitted as an `isotp-recv` event (`isotp-error` on failure).
+    pub fn isotp_request(&mut self, node: String, tx_id: i64, rx_id: i64, data: rhai::Blob) -> Result<(), Box<rhai::EvalAltResult>> {
+        let emulator = self.emulator.as_ref().unwrap().clone();
+        let config = isotp_config(tx_id, rx_id)?;
+        thread::spawn(move || {
+            match emulator.isotp_request(&node, &config, &data) {
+                Ok(response) => {
+                    let msg = format!("{{\"tx_id\":{},\"rx_id\":{},\"data\":\"{}\"}}", config.tx_id, config.rx_id,
+                        response.iter().map(|b| format!("{:02x}", b)).collect::<String>());
+                    emulator.emit(String::from("isotp-recv"), msg);
+                }
+                Err(err) => emulator.emit(String::from("isotp-error"), err)
+            }
+        });
+        return Ok(());
+    }
+
+    /// Physical value of an A2L label, `()` if it cannot be read.
+    pub fn read_label(&mut self, name: String) -> rhai::Dynamic {
+        match self.emulator.as_ref().unwrap().read_label(&name) {
+            Ok(value) => dynamic_from_json(&value),
+            Err(err) => {
+                print!("[qecu::interceptor] read_label: {}\n", err);
+                rhai::Dynamic::UNIT
+            }
+        }
+    }
+
+    pub fn write_label(&mut self, name: String, value: rhai::Dynamic) -> bool {
+        match self.emulator.as_ref().unwrap().write_label(&name, &json_from_dynamic(&value)) {
+            Ok(()) => true,
+            Err(err) => {
+                print!("[qecu::interceptor] write_label: {}\n", err);
+                false
+            }
+        }
+    }
+
+    /// Raises a trap on the core running the script.
+    pub fn raise_trap(&mut self, class: i64, tin: i64) -> Result<(), Box<rhai::EvalAltResult>> {
+        let core = self.emulator.as_ref().unwrap().current_core();
+        return self.raise_trap_core(class, tin, core as i64);
+    }
+
+    pub fn raise_trap_core(&mut self, class: i64, tin: i64, core: i64) -> Result<(), Box<rhai::EvalAltResult>> {
+        return self.emulator.as_ref().unwrap().raise_trap(core.try_into().unwrap(), class.try_into().unwrap(), tin.try_into().unwrap())
+            .map_err(|err| err.into());
+    }
+
+    pub fn assert(&mut self, condition: bool, message: String) {
+        if !condition {
+            self.crash(message);
+        }
+    }
+
+    pub fn crash(&mut self, message: String) {
+        print!("[interceptor::crash] {}\n", message);
+        self.emulator.as_ref().unwrap().raise_crash(CrashKind::Assertion { message: message });
+    }
+
+    /// Skips `millis` of emulated time instead of blocking the host thread.
+    pub fn sleep(&mut self, millis: i64) -> Result<(), Box<rhai::EvalAltResult>> {
+        let nanos = millis_to_nanos(millis)?;
+        self.emulator.as_ref().unwrap().clock.lock().unwrap().advance(nanos);
+        return Ok(());
+    }
+
+    /// Emulated time in microseconds.
+    pub fn now(&mut self) -> i64 {
+        (self.emulator.as_ref().unwrap().clock.lock().unwrap().now() / 1000).try_into().unwrap()
+    }
+
+    fn schedule(&mut self, millis: i64, code_type: u8, callback: String) -> Result<i64, Box<rhai::EvalAltResult>> {
+        let nanos = millis_to_nanos(millis)?;
+        let action = TimerAction::Script { code_type: code_type, callback: callback };
+        let id = self.emulator.as_ref().unwrap().clock.lock().unwrap().schedule(nanos, action);
+        return Ok(id.try_into().unwrap());
+    }
+
+    /// Calls `callback` once `millis` of emulated time elapsed, returns the timer id.
+    pub fn after(&mut self, millis: i64, function_name: String) -> Result<i64, Box<rhai::EvalAltResult>> {
+        self.schedule(millis, 0, function_name)
+    }
+
+    pub fn after_cb(&mut self, millis: i64, callback: rhai::FnPtr) -> Result<i64, Box<rhai::EvalAltResult>> {
+        self.schedule(millis, 1, callback.fn_name().to_string())
+    }
+
+    pub fn cancel_timer(&mut self, id: i64) {
+        self.emulator.as_ref().unwrap().clock.lock().unwrap().cancel(id.try_into().unwrap());
+    }
+
+    pub fn on_timer(&mut self, code_type: u8, callback: &String) {
+        let mut _engine = make_engine();
+        let mut _scope = self.scope.clone();
+        let ast = self.ast.clone_functions_only();
+        match code_type {
+            0 => {
+                _engine.call_fn::<i64>(&mut _scope, &ast, callback, ())
+                    .expect(format!("[interceptor::on_timer] Cannot call function {} ", callback).as_str());
+            }
+            1 => {
+                _engine.call_fn::<i64>(&mut _scope, &ast, callback, (self.clone(),))
+                    .expect(format!("[interceptor::on_timer] Cannot call function {} ", callback).as_str());
+            }
+            _ => {}
+        }
     }
 
     pub fn set_pc(&mut self, addr: i64) {
@@ -222,8 +501,9 @@ impl <'a> Interceptor <'static> {
         let size_rhai: i64 = size.try_into().unwrap();
         let code_hooks = &self.code_hooks;
         let size: u64 = size.try_into().unwrap();
+        let core = self.emulator.as_ref().unwrap().current_core();
         for code_hook in code_hooks {
-            if addr >= code_hook.begin && (addr + size) <= code_hook.end {
+            if addr >= code_hook.begin && (addr + size) <= code_hook.end && code_hook.core.map_or(true, |hook_core| hook_core == core) {
                 let mut _engine = make_engine();
                 let mut _scope = self.scope.clone();
                 let ast = self.ast.clone_functions_only();
@@ -256,6 +536,43 @@ impl <'a> Interceptor <'static> {
         }
     }
 
+    pub fn wants_memory(&self, core: usize, write: bool, address: u64, size: usize) -> bool {
+        self.mem_hooks.iter().any(|hook| hook.matches(core, write, address, size))
+    }
+
+    /// Calls the memory hooks of the access of `core` with a map holding
+    /// `core`, `write`, `address`, `size`, `value` and, for SFRs, `register`,
+    /// `offset` and `fields`.
+    pub fn on_mem_hook(&mut self, core: usize, write: bool, address: u64, size: usize, value: u64, sfr: Option<SfrValue>) {
+        let mut access = rhai::Map::new();
+        access.insert("core".into(), rhai::Dynamic::from(core as i64));
+        access.insert("write".into(), rhai::Dynamic::from(write));
+        access.insert("address".into(), rhai::Dynamic::from(address as i64));
+        access.insert("size".into(), rhai::Dynamic::from(size as i64));
+        access.insert("value".into(), rhai::Dynamic::from(value as i64));
+        if let Some(sfr) = sfr {
+            access.insert("register".into(), rhai::Dynamic::from(sfr.register.clone()));
+            access.insert("offset".into(), rhai::Dynamic::from(sfr.offset as i64));
+            access.insert("fields".into(), rhai::Dynamic::from_map(fields_to_map(&sfr.fields)));
+        }
+        for mem_hook in &self.mem_hooks {
+            if !mem_hook.matches(core, write, address, size) {
+                continue;
+            }
+            let mut _engine = make_engine();
+            let mut _scope = self.scope.clone();
+            let ast = self.ast.clone_functions_only();
+            let data = self.hook_datas.get(&mem_hook.id);
+            let result = match (mem_hook.code_type, data) {
+                (0, None) => _engine.call_fn::<i64>(&mut _scope, &ast, &mem_hook.content, (access.clone(),)),
+                (0, Some(data)) => _engine.call_fn::<i64>(&mut _scope, &ast, &mem_hook.content, (access.clone(), data.clone())),
+                (_, None) => _engine.call_fn::<i64>(&mut _scope, &ast, &mem_hook.content, (self.clone(), access.clone())),
+                (_, Some(data)) => _engine.call_fn::<i64>(&mut _scope, &ast, &mem_hook.content, (self.clone(), access.clone(), data.clone()))
+            };
+            result.expect(format!("[interceptor::on_mem_hook] Cannot call function {} ", mem_hook.content).as_str());
+        }
+    }
+
     pub fn emit(&self, event_type: String, msg: String) {
         for event in &self.on_events {
             if event.event_type == event_type {
@@ -277,6 +594,15 @@ impl <'a> Interceptor <'static> {
         }
     }
 
+    /// Calls the UDS key algorithm `function(level, seed)` of the init script.
+    pub fn security_key(&self, function: &str, level: u8, seed: Vec<u8>) -> Result<Vec<u8>, String> {
+        let _engine = make_engine();
+        let mut _scope = self.scope.clone();
+        let ast = self.ast.clone_functions_only();
+        return _engine.call_fn::<rhai::Blob>(&mut _scope, &ast, function, (level as i64, seed))
+            .map_err(|err| format!("key function {}: {}", function, err));
+    }
+
     pub fn get_code_hooks(&self) -> Vec<CodeHook> {
         self.code_hooks.clone()
     }
@@ -284,10 +610,112 @@ impl <'a> Interceptor <'static> {
     pub fn get_event_hooks(&self) -> Vec<EventCallback> {
         self.on_events.clone()
     }
+
+    pub fn has_mem_hooks(&self) -> bool {
+        !self.mem_hooks.is_empty()
+    }
+
+    pub fn get_mem_hooks(&self) -> Vec<MemHook> {
+        self.mem_hooks.clone()
+    }
+}
+
+impl MemHook {
+    fn matches(&self, core: usize, write: bool, address: u64, size: usize) -> bool {
+        let kind = match self.hook_type.as_str() {
+            "MEM_READ" => !write,
+            "MEM_WRITE" => write,
+            _ => true
+        };
+        return kind && self.core.map_or(true, |hook_core| hook_core == core) && address < self.end && address + size as u64 > self.begin;
+    }
+}
+
+fn fields_to_map(fields: &[FieldValue]) -> rhai::Map {
+    fields.iter().map(|field| (field.name.as_str().into(), rhai::Dynamic::from(field.value as i64))).collect()
+}
+
+fn frame_from_map(frame: &rhai::Map) -> CanFrame {
+    let flag = |name: &str| frame.get(name).and_then(|value| value.as_bool().ok()).unwrap_or(false);
+    let id = frame.get("id").and_then(|value| value.as_int().ok()).unwrap_or(0) as u32;
+    let data = match frame.get("data") {
+        Some(value) if value.is_blob() => value.clone().cast::<rhai::Blob>(),
+        Some(value) if value.is_string() => can::parse_hex(&value.clone().cast::<String>()).unwrap_or_default(),
+        _ => Vec::new()
+    };
+    let mut frame_data = CanFrame::new(id, data);
+    frame_data.extended |= flag("extended");
+    frame_data.fd |= flag("fd");
+    frame_data.brs = flag("brs");
+    frame_data.rtr = flag("rtr");
+    return frame_data;
+}
+
+fn dynamic_from_json(value: &serde_json::Value) -> rhai::Dynamic {
+    match value {
+        serde_json::Value::Bool(value) => rhai::Dynamic::from(*value),
+        serde_json::Value::Number(number) => match number.as_i64() {
+            Some(value) => rhai::Dynamic::from(value),
+            None => rhai::Dynamic::from(number.as_f64().unwrap_or(0.0))
+        },
+        serde_json::Value::String(value) => rhai::Dynamic::from(value.clone()),
+        serde_json::Value::Array(values) => rhai::Dynamic::from_array(values.iter().map(dynamic_from_json).collect()),
+        serde_json::Value::Object(map) => rhai::Dynamic::from_map(map.iter().map(|(key, value)| (key.into(), dynamic_from_json(value))).collect()),
+        serde_json::Value::Null => rhai::Dynamic::UNIT
+    }
+}
+
+fn json_from_dynamic(value: &rhai::Dynamic) -> serde_json::Value {
+    if let Ok(value) = value.as_int() {
+        return serde_json::json!(value);
+    }
+    if let Ok(value) = value.as_float() {
+        return serde_json::json!(value);
+    }
+    if value.is_string() {
+        return serde_json::json!(value.clone().cast::<String>());
+    }
+    if value.is_array() {
+        return serde_json::Value::Array(value.clone().cast::<rhai::Array>().iter().map(json_from_dynamic).collect());
+    }
+    if value.is_map() {
+        return serde_json::Value::Object(value.clone().cast::<rhai::Map>().iter()
+            .map(|(key, value)| (key.to_string(), json_from_dynamic(value))).collect());
+    }
+    return serde_json::Value::Null;
+}
+
+/// Script delay in nanoseconds, an error if it is negative or overflows.
+fn millis_to_nanos(millis: i64) -> Result<u64, Box<rhai::EvalAltResult>> {
+    return u64::try_from(millis).ok()
+        .and_then(|millis| millis.checked_mul(1_000_000))
+        .ok_or(format!("invalid delay of {} ms", millis).into());
+}
+
+/// ISO-TP channel of a script, an error if an id is not a CAN id.
+fn isotp_config(tx_id: i64, rx_id: i64) -> Result<IsoTpConfig, Box<rhai::EvalAltResult>> {
+    return match (u32::try_from(tx_id), u32::try_from(rx_id)) {
+        (Ok(tx), Ok(rx)) if tx <= 0x1FFFFFFF && rx <= 0x1FFFFFFF => Ok(IsoTpConfig::new(tx, rx)),
+        _ => Err(format!("invalid ISO-TP ids {:#x}/{:#x}", tx_id, rx_id).into())
+    };
+}
+
+pub fn get_cpu_symbol(cpu: String, symbol: String) -> Result<u32, Box<rhai::EvalAltResult>> {
+    crate::qecu::arch::tricore::get_cpu_symbol(cpu, symbol).map_err(|err| err.into())
+}
+
+/// `MODULE_REG` name of the register at `address`, `()` if there is none.
+pub fn get_cpu_register(cpu: String, address: i64) -> rhai::Dynamic {
+    match crate::qecu::arch::tricore::get_cpu_register_name(&cpu, address as u64) {
+        Some(name) => rhai::Dynamic::from(name),
+        None => rhai::Dynamic::UNIT
+    }
 }
 
-pub fn get_cpu_symbol(cpu: String, symbol: String) -> u32 {
-    crate::qecu::arch::tricore::get_cpu_symbol(cpu, symbol)
+pub fn decode_cpu_register(cpu: String, symbol: String, value: i64) -> Result<rhai::Map, Box<rhai::EvalAltResult>> {
+    crate::qecu::arch::tricore::decode_cpu_register(&cpu, &symbol, value as u64)
+        .map(|fields| fields_to_map(&fields))
+        .map_err(|err| err.into())
 }
 
 pub fn make_engine() -> Engine{
@@ -300,15 +728,46 @@ pub fn make_engine() -> Engine{
         register_fn("write_memory", Interceptor::write_memory).
         register_fn("add_hook", Interceptor::add_hook).
         register_fn("add_hook", Interceptor::add_cb_hook).
+        register_fn("add_core_hook", Interceptor::add_core_hook).
+        register_fn("add_core_hook", Interceptor::add_core_cb_hook).
+        register_fn("core", Interceptor::core).
         register_fn("add_hook_with_data", Interceptor::add_hook_with_data).
         register_fn("add_hook_with_data", Interceptor::add_cb_hook_with_data).
         register_fn("on_event", Interceptor::on_event).
         register_fn("on_event", Interceptor::on_cb_event).
         register_fn("disas", Interceptor::disas).
+        register_fn("disas_pc", Interceptor::disas_pc).
+        register_fn("listing", Interceptor::listing).
+        register_fn("pcode", Interceptor::pcode).
+        register_fn("pcode_block", Interceptor::pcode_block).
+        register_fn("taint_memory", Interceptor::taint_memory).
+        register_fn("taint_register", Interceptor::taint_register).
+        register_fn("get_taint", Interceptor::get_taint).
+        register_fn("clear_taint", Interceptor::clear_taint).
+        register_fn("add_taint_sink", Interceptor::add_taint_sink).
+        register_fn("raise_interrupt", Interceptor::raise_interrupt).
+        register_fn("raise_interrupt", Interceptor::raise_interrupt_tos).
+        register_fn("raise_trap", Interceptor::raise_trap).
+        register_fn("raise_trap", Interceptor::raise_trap_core).
+        register_fn("can_inject", Interceptor::can_inject).
+        register_fn("can_transmit", Interceptor::can_transmit).
+        register_fn("isotp_send", Interceptor::isotp_send).
+        register_fn("isotp_request", Interceptor::isotp_request).
+        register_fn("doip_respond", Interceptor::doip_respond).
+        register_fn("read_label", Interceptor::read_label).
+        register_fn("write_label", Interceptor::write_label).
+        register_fn("assert", Interceptor::assert).
+        register_fn("crash", Interceptor::crash).
         register_fn("sleep", Interceptor::sleep).
+        register_fn("now", Interceptor::now).
+        register_fn("after", Interceptor::after).
+        register_fn("after", Interceptor::after_cb).
+        register_fn("cancel_timer", Interceptor::cancel_timer).
         register_fn("get_code_hooks", Interceptor::get_code_hooks).
         register_fn("set_pc", Interceptor::set_pc);
     engine.register_fn("get_cpu_symbol", get_cpu_symbol);
+    engine.register_fn("get_cpu_register", get_cpu_register);
+    engine.register_fn("decode_cpu_register", decode_cpu_register);
     return engine;
 }
 
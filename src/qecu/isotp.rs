@@ -0,0 +1,250 @@
+use serde::{Deserialize, Serialize};
+use std::sync::mpsc::{Receiver, RecvTimeoutError};
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+use super::can::CanFrame;
+
+/// Maximum number of consecutive FC.WAIT frames accepted from the firmware.
+const MAX_WFT: u32 = 16;
+
+const PCI_SF: u8 = 0x0;
+const PCI_FF: u8 = 0x1;
+const PCI_CF: u8 = 0x2;
+const PCI_FC: u8 = 0x3;
+
+const FC_CTS: u8 = 0x0;
+const FC_WAIT: u8 = 0x1;
+const FC_OVFLW: u8 = 0x2;
+
+/// Filler of CAN FD frames rounded up to a valid length without padding.
+const FD_FILLER: u8 = 0xCC;
+
+/// Addressing and timing of an ISO-TP channel, `tx_id` being the tester side.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct IsoTpConfig {
+    pub tx_id: u32,
+    pub rx_id: u32,
+    #[serde(default)]
+    pub extended: bool,
+    #[serde(default)]
+    pub fd: bool,
+    #[serde(default)]
+    pub padding: Option<u8>,
+    /// BS and STmin sent in our flow control frames.
+    #[serde(default)]
+    pub block_size: u8,
+    #[serde(default)]
+    pub st_min: u8,
+    #[serde(default = "IsoTpConfig::default_timeout")]
+    pub timeout_ms: u64
+}
+
+impl IsoTpConfig {
+    fn default_timeout() -> u64 {
+        1000
+    }
+
+    pub fn new(tx_id: u32, rx_id: u32) -> IsoTpConfig {
+        return IsoTpConfig {
+            tx_id: tx_id,
+            rx_id: rx_id,
+            extended: tx_id > 0x7FF || rx_id > 0x7FF,
+            fd: false,
+            padding: Some(0xCC),
+            block_size: 0,
+            st_min: 0,
+            timeout_ms: IsoTpConfig::default_timeout()
+        };
+    }
+
+    fn frame_size(&self) -> usize {
+        if self.fd { 64 } else { 8 }
+    }
+}
+
+/// Where the frames of a channel go: a closure sending to the firmware, the
+/// frames transmitted by it and the emulated time STmin is measured on.
+pub struct FrameLink {
+    pub send: Box<dyn Fn(CanFrame) + Send>,
+    pub rx: Receiver<CanFrame>,
+    /// Emulated time in nanoseconds.
+    pub now: Box<dyn Fn() -> u64 + Send>
+}
+
+/// STmin in nanoseconds.
+fn st_min_nanos(st_min: u8) -> u64 {
+    match st_min {
+        0x00..=0x7F => st_min as u64 * 1_000_000,
+        0xF1..=0xF9 => (st_min - 0xF0) as u64 * 100_000,
+        _ => 0x7F * 1_000_000
+    }
+}
+
+/// Waits until `nanos` of emulated time elapsed since `start`. The emulated
+/// time only advances while the firmware runs, so the wait is bounded by the
+/// timeout of the channel.
+fn wait_emulated(link: &FrameLink, config: &IsoTpConfig, start: u64, nanos: u64) -> Result<(), String> {
+    let deadline = Instant::now() + Duration::from_millis(config.timeout_ms);
+    while (link.now)().saturating_sub(start) < nanos {
+        if Instant::now() >= deadline {
+            return Err(String::from("STmin: emulated clock stalled"));
+        }
+        sleep(Duration::from_micros(100));
+    }
+    return Ok(());
+}
+
+fn send_frame(link: &FrameLink, config: &IsoTpConfig, mut data: Vec<u8>) {
+    // CAN FD frames longer than 8 bytes only come in the DLC lengths.
+    let size = match config.fd {
+        true => crate::qecu::can::dlc_to_len(crate::qecu::can::len_to_dlc(data.len())),
+        false => 8
+    };
+    match config.padding {
+        Some(padding) => data.resize(size, padding),
+        None if data.len() > 8 => data.resize(size, FD_FILLER),
+        None => {}
+    }
+    let frame = CanFrame {
+        id: config.tx_id,
+        extended: config.extended,
+        fd: config.fd,
+        brs: config.fd,
+        rtr: false,
+        data: data
+    };
+    (link.send)(frame);
+}
+
+/// Next frame of the firmware on `rx_id`.
+fn recv_frame(link: &FrameLink, config: &IsoTpConfig, deadline: Instant) -> Result<Vec<u8>, String> {
+    loop {
+        let timeout = deadline.saturating_duration_since(Instant::now());
+        match link.rx.recv_timeout(timeout) {
+            Ok(frame) if frame.id == config.rx_id && !frame.data.is_empty() => return Ok(frame.data),
+            Ok(_) => {}
+            Err(RecvTimeoutError::Timeout) => return Err(String::from("timeout")),
+            Err(RecvTimeoutError::Disconnected) => return Err(String::from("link closed"))
+        }
+    }
+}
+
+/// Waits for a flow control frame, returns (BS, STmin).
+fn recv_flow_control(link: &FrameLink, config: &IsoTpConfig) -> Result<(u8, u8), String> {
+    let mut waits = 0;
+    loop {
+        let deadline = Instant::now() + Duration::from_millis(config.timeout_ms);
+        let data = recv_frame(link, config, deadline).map_err(|err| format!("flow control {}", err))?;
+        if data[0] >> 4 != PCI_FC {
+            continue;
+        }
+        match data[0] & 0xF {
+            FC_CTS => return Ok((*data.get(1).unwrap_or(&0), *data.get(2).unwrap_or(&0))),
+            FC_WAIT => {
+                waits += 1;
+                if waits > MAX_WFT {
+                    return Err(String::from("too many FC.WAIT"));
+                }
+            }
+            FC_OVFLW => return Err(String::from("receiver overflow")),
+            status => return Err(format!("invalid flow status {}", status))
+        }
+    }
+}
+
+/// Segments and sends `payload` to the firmware.
+pub fn send(link: &FrameLink, config: &IsoTpConfig, payload: &[u8]) -> Result<(), String> {
+    let frame_size = config.frame_size();
+    // Single frame, with the escape sequence for CAN FD.
+    if payload.len() <= 7 || (config.fd && payload.len() <= frame_size - 2) {
+        let mut data = match payload.len() <= 7 {
+            true => vec![(PCI_SF << 4) | payload.len() as u8],
+            false => vec![PCI_SF << 4, payload.len() as u8]
+        };
+        data.extend_from_slice(payload);
+        send_frame(link, config, data);
+        return Ok(());
+    }
+    let mut data = match payload.len() <= 0xFFF {
+        true => vec![(PCI_FF << 4) | (payload.len() >> 8) as u8, payload.len() as u8],
+        false => {
+            let mut header = vec![PCI_FF << 4, 0];
+            header.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+            header
+        }
+    };
+    let mut offset = frame_size - data.len();
+    data.extend_from_slice(&payload[..offset]);
+    send_frame(link, config, data);
+
+    let mut sn: u8 = 1;
+    while offset < payload.len() {
+        let (block_size, st_min) = recv_flow_control(link, config)?;
+        let mut sent = 0;
+        while offset < payload.len() && (block_size == 0 || sent < block_size) {
+            let end = (offset + frame_size - 1).min(payload.len());
+            let mut data = vec![(PCI_CF << 4) | (sn & 0xF)];
+            data.extend_from_slice(&payload[offset..end]);
+            send_frame(link, config, data);
+            let sent_at = (link.now)();
+            offset = end;
+            sn = sn.wrapping_add(1);
+            sent += 1;
+            if offset < payload.len() {
+                wait_emulated(link, config, sent_at, st_min_nanos(st_min))?;
+            }
+        }
+    }
+    return Ok(());
+}
+
+/// Reassembles the next payload sent by the firmware.
+pub fn receive(link: &FrameLink, config: &IsoTpConfig) -> Result<Vec<u8>, String> {
+    let timeout = Duration::from_millis(config.timeout_ms);
+    let data = loop {
+        let data = recv_frame(link, config, Instant::now() + timeout)?;
+        if data[0] >> 4 == PCI_SF || data[0] >> 4 == PCI_FF {
+            break data;
+        }
+    };
+    if data[0] >> 4 == PCI_SF {
+        let (len, start) = match data[0] & 0xF {
+            0 => (*data.get(1).unwrap_or(&0) as usize, 2),
+            len => (len as usize, 1)
+        };
+        return data.get(start..start + len).map(|payload| payload.to_vec()).ok_or(String::from("truncated single frame"));
+    }
+    let (len, start) = match ((data[0] & 0xF) as usize) << 8 | *data.get(1).unwrap_or(&0) as usize {
+        0 if data.len() >= 6 => (u32::from_be_bytes(data[2..6].try_into().unwrap()) as usize, 6),
+        len => (len, 2)
+    };
+    let mut payload = data[start.min(data.len())..].to_vec();
+    let mut sn: u8 = 1;
+    let mut received = 0;
+    send_frame(link, config, vec![(PCI_FC << 4) | FC_CTS, config.block_size, config.st_min]);
+    while payload.len() < len {
+        let data = recv_frame(link, config, Instant::now() + timeout).map_err(|err| format!("consecutive frame {}", err))?;
+        if data[0] >> 4 != PCI_CF {
+            continue;
+        }
+        if data[0] & 0xF != sn & 0xF {
+            return Err(format!("wrong sequence number {}, expected {}", data[0] & 0xF, sn & 0xF));
+        }
+        payload.extend_from_slice(&data[1..]);
+        sn = sn.wrapping_add(1);
+        received += 1;
+        if config.block_size != 0 && received == config.block_size && payload.len() < len {
+            received = 0;
+            send_frame(link, config, vec![(PCI_FC << 4) | FC_CTS, config.block_size, config.st_min]);
+        }
+    }
+    payload.truncate(len);
+    return Ok(payload);
+}
+
+/// Sends `payload` and waits for the answer.
+pub fn request(link: &FrameLink, config: &IsoTpConfig, payload: &[u8]) -> Result<Vec<u8>, String> {
+    send(link, config, payload)?;
+    return receive(link, config);
+}
@@ -0,0 +1,150 @@
+use std::fmt;
+use std::os::raw::c_void;
+use unicorn_engine::Unicorn;
+use unicorn_engine::unicorn_const::Permission;
+
+use crate::utils::workflow::WorkflowMemMap;
+
+/// Zeroed anonymous mapping: the host only commits the pages the firmware
+/// touches, so the large SFR and reserved regions cost nothing.
+struct HostBuffer {
+    ptr: *mut c_void,
+    len: usize
+}
+
+// The mapping is owned by the buffer and only accessed through Unicorn.
+unsafe impl Send for HostBuffer {}
+
+impl HostBuffer {
+    fn new(len: usize) -> HostBuffer {
+        let ptr = unsafe {
+            libc::mmap(std::ptr::null_mut(), len.max(1), libc::PROT_READ | libc::PROT_WRITE,
+                libc::MAP_PRIVATE | libc::MAP_ANONYMOUS | libc::MAP_NORESERVE, -1, 0)
+        };
+        if ptr == libc::MAP_FAILED {
+            panic!("[qecu::memory] Cannot allocate {:#x} bytes\n", len);
+        }
+        return HostBuffer { ptr: ptr, len: len };
+    }
+}
+
+impl Drop for HostBuffer {
+    fn drop(&mut self) {
+        unsafe { libc::munmap(self.ptr, self.len.max(1)); }
+    }
+}
+
+/// Host memory of the mapped regions. An alias region is mapped onto the
+/// memory of the region it aliases, so a write through one address is seen
+/// through the other.
+#[derive(Default)]
+pub struct Memory {
+    /// Global address and content of every region which is not an alias.
+    buffers: Vec<(u64, HostBuffer)>,
+    /// Address, size and target of the alias regions, per Unicorn instance.
+    aliases: Vec<Vec<(u64, usize, u64)>>
+}
+
+impl fmt::Debug for Memory {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Memory")
+    }
+}
+
+pub fn permissions(flags: &str) -> Permission {
+    let mut perms = Permission::NONE;
+    for chr in flags.to_uppercase().chars() {
+        let new_permission_feature = match chr {
+            'R' => Permission::READ,
+            'W' => Permission::WRITE,
+            'X' => Permission::EXEC,
+            '*' => Permission::ALL,
+            _ => Permission::NONE
+        };
+        perms = perms | new_permission_feature;
+    }
+    return perms;
+}
+
+impl Memory {
+    /// Allocates the memory of `regions`, aliases are resolved when mapped.
+    pub fn new(regions: &[WorkflowMemMap]) -> Memory {
+        let mut memory = Memory::default();
+        for region in regions.iter().filter(|region| region.alias.is_none()) {
+            memory.buffers.push((region.from, HostBuffer::new(region.size)));
+        }
+        return memory;
+    }
+
+    /// Host memory at `address` and the number of bytes left in its buffer.
+    fn host(&mut self, address: u64) -> Option<(*mut c_void, u64)> {
+        self.buffers.iter_mut()
+            .find(|(from, data)| address >= *from && address < *from + data.len as u64)
+            .map(|(from, data)| {
+                let offset = (address - *from) as usize;
+                (unsafe { (data.ptr as *mut u8).add(offset) } as *mut c_void, (data.len - offset) as u64)
+            })
+    }
+
+    /// Maps `regions` in `uc` on the host memory, which lives as long as
+    /// `self`. An alias spanning several regions is mapped piece by piece,
+    /// and an alias of an alias is mapped onto the memory behind the latter.
+    /// Each core is mapped in turn, with its own aliases.
+    pub fn map(&mut self, uc: &mut Unicorn<'_, ()>, regions: &[WorkflowMemMap]) -> Result<(), String> {
+        let mut aliases = Vec::new();
+        for region in regions {
+            let end = region.from + region.size as u64;
+            let mut from = region.from;
+            while from < end {
+                let (address, aliased) = unalias(regions, from)?;
+                let (ptr, available) = self.host(address)
+                    .ok_or(format!("{:#010x} is not mapped, cannot alias it at {:#010x}", address, from))?;
+                let size = available.min(aliased).min(end - from);
+                unsafe { uc.mem_map_ptr(from, size as usize, permissions(&region.flags), ptr) }
+                    .map_err(|err| format!("Failed to map {:#010x} of size {:#x}: {:?}", from, size, err))?;
+                if region.alias.is_some() {
+                    aliases.push((from, size as usize, address));
+                }
+                from += size;
+            }
+            match region.alias {
+                Some(target) => {
+                    print!("[qecu::memory] address: {:#010x} size: {:#x} alias of {:#010x}\n", region.from, region.size, target);
+                }
+                None => print!("[qecu::memory] address: {:#010x} size: {:#x}\n", region.from, region.size)
+            }
+        }
+        self.aliases.push(aliases);
+        return Ok(());
+    }
+
+    /// Address of the memory behind `address` as seen by `core`, resolving aliases.
+    pub fn resolve(&self, core: usize, address: u64) -> u64 {
+        for (from, size, target) in self.aliases.get(core).into_iter().flatten() {
+            if address >= *from && address < *from + *size as u64 {
+                return target + (address - from);
+            }
+        }
+        return address;
+    }
+}
+
+/// Follows the aliases of `regions` from `address` to the memory behind it,
+/// with the number of bytes from `address` the translation holds for. A
+/// chain of aliases leading back to itself is an error.
+fn unalias(regions: &[WorkflowMemMap], address: u64) -> Result<(u64, u64), String> {
+    let mut target = address;
+    let mut available = u64::MAX;
+    for _ in 0..=regions.len() {
+        let region = regions.iter()
+            .find(|region| region.alias.is_some() && target >= region.from && target < region.from + region.size as u64);
+        match region {
+            Some(region) => {
+                available = available.min(region.from + region.size as u64 - target);
+                target = region.alias.unwrap() + (target - region.from);
+            }
+            None => return Ok((target, available))
+        }
+    }
+    return Err(format!("{:#010x} is an alias loop", address));
+}
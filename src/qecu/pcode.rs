@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+
+/// P-code opcodes ending a basic block.
+pub const BLOCK_TERMINATORS: [&str; 6] = ["BRANCH", "CBRANCH", "BRANCHIND", "CALL", "CALLIND", "RETURN"];
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Varnode {
+    pub space: String,
+    pub offset: u64,
+    pub size: u32
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PcodeOp {
+    pub address: u64,
+    pub seq: u32,
+    pub opcode: String,
+    pub output: Option<Varnode>,
+    pub inputs: Vec<Varnode>
+}
+
+impl Varnode {
+    pub fn is_constant(&self) -> bool {
+        self.space == "const"
+    }
+
+    pub fn to_map(&self) -> rhai::Map {
+        let mut map = rhai::Map::new();
+        map.insert("space".into(), self.space.clone().into());
+        map.insert("offset".into(), (self.offset as i64).into());
+        map.insert("size".into(), (self.size as i64).into());
+        return map;
+    }
+}
+
+impl PcodeOp {
+    pub fn is_terminator(&self) -> bool {
+        BLOCK_TERMINATORS.contains(&self.opcode.as_str())
+    }
+
+    pub fn to_map(&self) -> rhai::Map {
+        let mut map = rhai::Map::new();
+        let inputs: rhai::Array = self.inputs.iter().map(|v| rhai::Dynamic::from_map(v.to_map())).collect();
+        map.insert("address".into(), (self.address as i64).into());
+        map.insert("seq".into(), (self.seq as i64).into());
+        map.insert("opcode".into(), self.opcode.clone().into());
+        match &self.output {
+            Some(output) => map.insert("output".into(), rhai::Dynamic::from_map(output.to_map())),
+            None => map.insert("output".into(), rhai::Dynamic::UNIT)
+        };
+        map.insert("inputs".into(), inputs.into());
+        return map;
+    }
+}
+
+/// Truncates lifted ops after the instruction holding the first block terminator.
+pub fn cut_block(ops: Vec<PcodeOp>) -> Vec<PcodeOp> {
+    let end = match ops.iter().find(|op| op.is_terminator()) {
+        Some(op) => op.address,
+        None => return ops
+    };
+    return ops.into_iter().take_while(|op| op.address <= end).collect();
+}
@@ -0,0 +1,189 @@
+use std::any::Any;
+use std::fmt;
+
+/// Side effects of a peripheral access, applied by the bus once the access is done.
+#[derive(Clone, Debug, Default)]
+pub struct BusContext {
+    pub src_requests: Vec<u64>,
+    pub events: Vec<(String, String)>,
+    /// Cores started at a PC (Some) or halted (None) by the access.
+    pub core_requests: Vec<(usize, Option<u64>)>
+}
+
+impl BusContext {
+    /// Hardware service request on the SRC register at `src`.
+    pub fn request(&mut self, src: u64) {
+        self.src_requests.push(src);
+    }
+
+    pub fn start_core(&mut self, core: usize, pc: u64) {
+        self.core_requests.push((core, Some(pc)));
+    }
+
+    pub fn halt_core(&mut self, core: usize) {
+        self.core_requests.push((core, None));
+    }
+
+    /// Publishes an event to the Interceptor `on_event` handlers.
+    pub fn emit(&mut self, event_type: &str, msg: String) {
+        self.events.push((event_type.to_string(), msg));
+    }
+}
+
+/// Copy of the state of a model, restored with the emulator snapshot.
+pub type PeripheralState = Box<dyn Any + Send>;
+
+/// A memory mapped device model. Offsets are relative to `base()`.
+pub trait Peripheral: Send {
+    fn name(&self) -> String;
+    fn base(&self) -> u64;
+    fn size(&self) -> u64;
+    fn read(&mut self, offset: u64, size: usize, ctx: &mut BusContext) -> u64;
+    fn write(&mut self, offset: u64, size: usize, value: u64, ctx: &mut BusContext);
+    /// Called once per executed instruction with the emulated time in nanoseconds.
+    fn tick(&mut self, _now: u64, _ctx: &mut BusContext) {}
+    /// State of the model, without its host side (sockets, ports).
+    fn save(&self) -> PeripheralState {
+        Box::new(())
+    }
+    /// Restores a state returned by `save`.
+    fn restore(&mut self, _state: &PeripheralState) {}
+}
+
+/// Peripheral side of the interrupt router, fed with the SRC requests of the bus.
+pub trait ServiceRequestSink: Peripheral {
+    fn set_request(&mut self, src: u64);
+    /// Highest priority pending request routed to `tos`, as (src, priority).
+    fn arbitrate(&self, tos: u8) -> Option<(u64, u8)>;
+    fn acknowledge(&mut self, src: u64);
+}
+
+/// State of the router and of every peripheral, in bus order.
+pub struct BusState {
+    router: Option<PeripheralState>,
+    peripherals: Vec<PeripheralState>
+}
+
+impl fmt::Debug for BusState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "BusState")
+    }
+}
+
+pub struct PeripheralBus {
+    router: Option<Box<dyn ServiceRequestSink>>,
+    peripherals: Vec<Box<dyn Peripheral>>,
+    low: u64,
+    high: u64
+}
+
+impl fmt::Debug for PeripheralBus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PeripheralBus")
+    }
+}
+
+impl PeripheralBus {
+    pub fn new() -> PeripheralBus {
+        return PeripheralBus {
+            router: None,
+            peripherals: Vec::new(),
+            low: u64::MAX,
+            high: 0
+        };
+    }
+
+    fn extend_range(&mut self, base: u64, size: u64) {
+        self.low = self.low.min(base);
+        self.high = self.high.max(base + size);
+    }
+
+    pub fn set_router(&mut self, router: Box<dyn ServiceRequestSink>) {
+        print!("[qecu::peripheral] {} at {:#010x}\n", router.name(), router.base());
+        self.extend_range(router.base(), router.size());
+        self.router = Some(router);
+    }
+
+    pub fn add(&mut self, peripheral: Box<dyn Peripheral>) {
+        print!("[qecu::peripheral] {} at {:#010x}\n", peripheral.name(), peripheral.base());
+        self.extend_range(peripheral.base(), peripheral.size());
+        self.peripherals.push(peripheral);
+    }
+
+    pub fn contains(&self, address: u64) -> bool {
+        address >= self.low && address < self.high
+    }
+
+    fn find(&mut self, address: u64) -> Option<&mut dyn Peripheral> {
+        if let Some(router) = self.router.as_mut() {
+            if address >= router.base() && address < router.base() + router.size() {
+                return Some(router.as_mut());
+            }
+        }
+        for peripheral in self.peripherals.iter_mut() {
+            if address >= peripheral.base() && address < peripheral.base() + peripheral.size() {
+                return Some(peripheral.as_mut());
+            }
+        }
+        return None;
+    }
+
+    /// Returns the value of the register at `address`, None if no model maps it.
+    pub fn read(&mut self, address: u64, size: usize, ctx: &mut BusContext) -> Option<u64> {
+        let peripheral = self.find(address)?;
+        let value = peripheral.read(address - peripheral.base(), size, ctx);
+        self.apply(ctx);
+        return Some(value);
+    }
+
+    pub fn write(&mut self, address: u64, size: usize, value: u64, ctx: &mut BusContext) -> bool {
+        let peripheral = match self.find(address) {
+            Some(peripheral) => peripheral,
+            None => return false
+        };
+        peripheral.write(address - peripheral.base(), size, value, ctx);
+        self.apply(ctx);
+        return true;
+    }
+
+    fn apply(&mut self, ctx: &mut BusContext) {
+        if let Some(router) = self.router.as_mut() {
+            for src in ctx.src_requests.drain(..) {
+                router.set_request(src);
+            }
+        }
+    }
+
+    pub fn tick(&mut self, now: u64, ctx: &mut BusContext) {
+        for peripheral in self.peripherals.iter_mut() {
+            peripheral.tick(now, ctx);
+        }
+        self.apply(ctx);
+    }
+
+    pub fn save(&self) -> BusState {
+        return BusState {
+            router: self.router.as_ref().map(|router| router.save()),
+            peripherals: self.peripherals.iter().map(|peripheral| peripheral.save()).collect()
+        };
+    }
+
+    pub fn restore(&mut self, state: &BusState) {
+        if let (Some(router), Some(saved)) = (self.router.as_mut(), &state.router) {
+            router.restore(saved);
+        }
+        for (peripheral, saved) in self.peripherals.iter_mut().zip(&state.peripherals) {
+            peripheral.restore(saved);
+        }
+    }
+
+    pub fn arbitrate(&self, tos: u8) -> Option<(u64, u8)> {
+        self.router.as_ref()?.arbitrate(tos)
+    }
+
+    pub fn acknowledge(&mut self, src: u64) {
+        if let Some(router) = self.router.as_mut() {
+            router.acknowledge(src);
+        }
+    }
+}
@@ -0,0 +1,514 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::Path;
+use crate::qecu::arch::tricore::symmap::SymMap;
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Field {
+    pub name: String,
+    pub offset: u8,
+    pub width: u8
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Register {
+    pub name: String,
+    pub address: u64,
+    /// Width in bits.
+    pub width: u8,
+    pub reset: u64,
+    pub fields: Vec<Field>
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct FieldValue {
+    pub name: String,
+    pub offset: u8,
+    pub width: u8,
+    pub value: u64
+}
+
+/// A register access or value, decoded to its bitfields.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct SfrValue {
+    pub register: String,
+    pub address: u64,
+    /// Offset of the access in the register.
+    pub offset: u64,
+    pub width: u8,
+    pub value: u64,
+    pub fields: Vec<FieldValue>
+}
+
+impl Field {
+    /// None for the fields of a malformed file lying outside of a 64-bit register.
+    fn new(name: &str, offset: u64, width: u64) -> Option<Field> {
+        if width == 0 || offset.checked_add(width)? > 64 {
+            print!("[qecu::regmap] Ignoring field {} at bit {} of width {}\n", name, offset, width);
+            return None;
+        }
+        return Some(Field { name: name.to_string(), offset: offset as u8, width: width as u8 });
+    }
+}
+
+impl Register {
+    pub fn decode(&self, value: u64) -> Vec<FieldValue> {
+        return self.fields.iter().map(|field| FieldValue {
+            name: field.name.clone(),
+            offset: field.offset,
+            width: field.width,
+            value: value.checked_shr(field.offset as u32).unwrap_or(0) & (u64::MAX >> (64 - field.width.clamp(1, 64) as u32))
+        }).collect();
+    }
+
+    /// Reset value as the little-endian bytes of the register.
+    pub fn reset_bytes(&self) -> Vec<u8> {
+        let size = (self.width as usize / 8).clamp(1, 8);
+        return self.reset.to_le_bytes()[..size].to_vec();
+    }
+}
+
+impl SfrValue {
+    /// `ASCLIN0_FLAGS {TH: 1, TR: 0}`, `+0x1` for accesses inside a register.
+    pub fn to_text(&self) -> String {
+        let mut text = self.register.clone();
+        if self.offset != 0 {
+            text.push_str(format!("+{:#x}", self.offset).as_str());
+        }
+        if !self.fields.is_empty() {
+            let fields: Vec<String> = self.fields.iter().map(|field| format!("{}: {:#x}", field.name, field.value)).collect();
+            text.push_str(format!(" {{{}}}", fields.join(", ")).as_str());
+        }
+        return text;
+    }
+}
+
+/// Registers and symbols of a device, loaded at runtime from CMSIS-SVD files
+/// or iLLD `*_reg.h`, `*_regdef.h` and `*_bf.h` headers.
+#[derive(Clone, Debug, Default)]
+pub struct RegMap {
+    registers: Vec<Register>,
+    names: HashMap<String, usize>,
+    addresses: BTreeMap<u64, usize>,
+    symbols: HashMap<String, u64>
+}
+
+/// Definitions collected from iLLD headers, linked once every file is read.
+#[derive(Default)]
+struct HeaderDefs {
+    /// Register name, address and `Ifx_` type.
+    registers: Vec<(String, u64, Option<String>)>,
+    /// `_Ifx_<type>_Bits` structures and their width in bits.
+    structs: HashMap<String, (Vec<Field>, u32)>,
+    /// `IFX_<type>_<field>_OFF/_LEN` values.
+    bitfields: HashMap<String, u64>
+}
+
+fn parse_int(token: &str) -> Option<u64> {
+    let token = token.trim().trim_start_matches('(').trim_end_matches(')')
+        .trim_end_matches(['u', 'U', 'l', 'L']);
+    match token.strip_prefix("0x").or(token.strip_prefix("0X")) {
+        Some(hex) => u64::from_str_radix(hex, 16).ok(),
+        None => token.parse::<u64>().ok()
+    }
+}
+
+fn strip_comments(content: &str) -> String {
+    let mut output = String::with_capacity(content.len());
+    let mut rest = content;
+    while let Some(index) = rest.find('/') {
+        output.push_str(&rest[..index]);
+        rest = &rest[index..];
+        if rest.starts_with("/*") {
+            rest = rest.find("*/").map_or("", |end| &rest[end + 2..]);
+            output.push(' ');
+        } else if rest.starts_with("//") {
+            rest = rest.find('\n').map_or("", |end| &rest[end..]);
+        } else {
+            output.push('/');
+            rest = &rest[1..];
+        }
+    }
+    output.push_str(rest);
+    return output;
+}
+
+impl HeaderDefs {
+    fn parse(&mut self, content: &str) {
+        let content = strip_comments(content);
+        for line in content.lines() {
+            let mut tokens = line.split_whitespace();
+            if tokens.next() != Some("#define") {
+                continue;
+            }
+            let name = match tokens.next() {
+                Some(name) if !name.contains('(') => name.to_string(),
+                _ => continue
+            };
+            let value: String = tokens.collect::<Vec<&str>>().join(" ");
+            if name.starts_with("IFX_") && (name.ends_with("_OFF") || name.ends_with("_LEN")) {
+                if let Some(value) = parse_int(&value) {
+                    self.bitfields.insert(name, value);
+                }
+                continue;
+            }
+            // (*(volatile Ifx_ASCLIN_FLAGS*)0xF0000634u) or a bare 0xF0000634u
+            let address = match value.find("0x").or(value.find("0X")) {
+                Some(index) => value[index..].split(|c: char| !c.is_ascii_alphanumeric()).next().and_then(parse_int),
+                None => None
+            };
+            let address = match address {
+                Some(address) => address,
+                None => continue
+            };
+            let register_type = value.find("Ifx_").map(|index| value[index + 4..]
+                .split(|c: char| !(c.is_ascii_alphanumeric() || c == '_')).next().unwrap_or("").to_string());
+            self.registers.push((name, address, register_type));
+        }
+        let mut rest = content.as_str();
+        while let Some(index) = rest.find("struct _Ifx_") {
+            rest = &rest[index + 12..];
+            let type_name = rest.split(|c: char| !(c.is_ascii_alphanumeric() || c == '_')).next().unwrap_or("");
+            let (body_start, body_end) = match (rest.find('{'), rest.find('}')) {
+                (Some(start), Some(end)) if start < end => (start, end),
+                _ => continue
+            };
+            let type_name = match type_name.strip_suffix("_Bits") {
+                Some(type_name) if rest[..body_start].trim() == format!("{}_Bits", type_name) => type_name.to_string(),
+                _ => continue
+            };
+            let mut fields = Vec::new();
+            let mut offset = 0u32;
+            for member in rest[body_start + 1..body_end].split(';') {
+                let (declaration, bits) = match member.split_once(':') {
+                    Some(member) => member,
+                    None => continue
+                };
+                let width = parse_int(bits).unwrap_or(0) as u32;
+                let name = declaration.split_whitespace().last().unwrap_or("");
+                if !name.starts_with("reserved") && width > 0 {
+                    fields.extend(Field::new(name, offset as u64, width as u64));
+                }
+                offset += width;
+            }
+            self.structs.insert(type_name, (fields, offset));
+            rest = &rest[body_end..];
+        }
+    }
+
+    fn fields(&self, register_type: &str) -> (Vec<Field>, u8) {
+        if let Some((fields, bits)) = self.structs.get(register_type) {
+            let width = [8u8, 16, 32, 64].into_iter().find(|width| *bits <= *width as u32).unwrap_or(64);
+            return (fields.clone(), width);
+        }
+        let prefix = format!("IFX_{}_", register_type);
+        let mut fields: Vec<Field> = self.bitfields.iter()
+            .filter_map(|(name, offset)| {
+                let field = name.strip_prefix(&prefix)?.strip_suffix("_OFF")?;
+                let width = *self.bitfields.get(&format!("{}{}_LEN", prefix, field))?;
+                Field::new(field, *offset, width)
+            }).collect();
+        fields.sort_by_key(|field| field.offset);
+        return (fields, 32);
+    }
+}
+
+/// Minimal XML element tree, enough for CMSIS-SVD.
+#[derive(Default)]
+struct Element {
+    name: String,
+    attributes: HashMap<String, String>,
+    text: String,
+    children: Vec<Element>
+}
+
+impl Element {
+    fn parse(content: &str) -> Element {
+        let mut stack = vec![Element::default()];
+        let mut rest = content;
+        while let Some(index) = rest.find('<') {
+            let text = rest[..index].trim();
+            if !text.is_empty() {
+                stack.last_mut().unwrap().text.push_str(&unescape(text));
+            }
+            rest = &rest[index..];
+            let end = if rest.starts_with("<!--") { rest.find("-->").map(|end| end + 3) } else { rest.find('>').map(|end| end + 1) };
+            let end = end.unwrap_or(rest.len());
+            let tag = &rest[1..end.saturating_sub(1).max(1)];
+            rest = &rest[end..];
+            if tag.starts_with('?') || tag.starts_with('!') {
+                continue;
+            }
+            if tag.starts_with('/') {
+                if stack.len() > 1 {
+                    let element = stack.pop().unwrap();
+                    stack.last_mut().unwrap().children.push(element);
+                }
+                continue;
+            }
+            let closed = tag.ends_with('/');
+            let tag = tag.trim_end_matches('/');
+            let name = tag.split_whitespace().next().unwrap_or("").to_string();
+            let mut element = Element { name: name.clone(), ..Element::default() };
+            for attribute in tag[name.len()..].split('"').collect::<Vec<&str>>().chunks(2) {
+                if let [key, value] = attribute {
+                    element.attributes.insert(key.trim().trim_end_matches('=').trim().to_string(), unescape(value));
+                }
+            }
+            match closed {
+                true => stack.last_mut().unwrap().children.push(element),
+                false => stack.push(element)
+            }
+        }
+        while stack.len() > 1 {
+            let element = stack.pop().unwrap();
+            stack.last_mut().unwrap().children.push(element);
+        }
+        return stack.pop().unwrap();
+    }
+
+    fn child(&self, name: &str) -> Option<&Element> {
+        self.children.iter().find(|child| child.name == name)
+    }
+
+    fn children<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Element> + 'a {
+        self.children.iter().filter(move |child| child.name == name)
+    }
+
+    fn value(&self, name: &str) -> Option<u64> {
+        self.child(name).and_then(|child| parse_int(&child.text))
+    }
+
+    fn find(&self, name: &str) -> Option<&Element> {
+        if self.name == name {
+            return Some(self);
+        }
+        return self.children.iter().find_map(|child| child.find(name));
+    }
+}
+
+fn unescape(text: &str) -> String {
+    text.replace("&lt;", "<").replace("&gt;", ">").replace("&quot;", "\"").replace("&apos;", "'").replace("&amp;", "&")
+}
+
+/// Names and address increments of a `dim` element, a single entry otherwise.
+fn expand_dim(element: &Element, name: &str) -> Vec<(String, u64)> {
+    let dim = match element.value("dim") {
+        Some(dim) => dim,
+        None => return vec![(name.to_string(), 0)]
+    };
+    let increment = element.value("dimIncrement").unwrap_or(0);
+    let indexes: Vec<String> = match element.child("dimIndex").map(|index| index.text.clone()) {
+        Some(index) if index.contains('-') && !index.contains(',') => {
+            let (first, last) = index.split_once('-').unwrap();
+            match (first.trim().parse::<u64>(), last.trim().parse::<u64>()) {
+                (Ok(first), Ok(last)) => (first..=last).map(|index| index.to_string()).collect(),
+                _ => (0..dim).map(|index| index.to_string()).collect()
+            }
+        }
+        Some(index) => index.split(',').map(|index| index.trim().to_string()).collect(),
+        None => (0..dim).map(|index| index.to_string()).collect()
+    };
+    return indexes.iter().take(dim as usize).enumerate()
+        .map(|(count, index)| (name.replace("[%s]", index).replace("%s", index), increment * count as u64))
+        .collect();
+}
+
+impl RegMap {
+    /// Loads `paths`, files or directories of `.svd`/`.xml` and `.h` files.
+    pub fn load(paths: &[String]) -> RegMap {
+        let mut files = Vec::new();
+        for path in paths {
+            match Path::new(path).is_dir() {
+                true => {
+                    let mut entries: Vec<_> = fs::read_dir(path).expect(format!("[qecu::regmap] Cannot open {}\n", path).as_str())
+                        .filter_map(|entry| entry.ok()).map(|entry| entry.path()).collect();
+                    entries.sort();
+                    files.extend(entries);
+                }
+                false => files.push(Path::new(path).to_path_buf())
+            }
+        }
+        let mut map = RegMap::default();
+        let mut defs = HeaderDefs::default();
+        for file in files {
+            let extension = file.extension().map(|extension| extension.to_string_lossy().to_lowercase()).unwrap_or_default();
+            if !matches!(extension.as_str(), "svd" | "xml" | "h") {
+                continue;
+            }
+            let content = fs::read(&file).expect(format!("[qecu::regmap] Cannot open {}\n", file.display()).as_str());
+            let content = String::from_utf8_lossy(&content);
+            match extension.as_str() {
+                "h" => defs.parse(&content),
+                _ => map.parse_svd(&content)
+            }
+        }
+        map.link_headers(defs);
+        print!("[qecu::regmap] {} registers loaded from {}\n", map.registers.len(), paths.join(", "));
+        return map;
+    }
+
+    /// Register map of iLLD header contents, bitfields being linked across them.
+    pub fn from_headers(contents: &[&str]) -> RegMap {
+        let mut defs = HeaderDefs::default();
+        for content in contents {
+            defs.parse(content);
+        }
+        let mut map = RegMap::default();
+        map.link_headers(defs);
+        return map;
+    }
+
+    fn link_headers(&mut self, defs: HeaderDefs) {
+        for (name, address, register_type) in &defs.registers {
+            self.symbols.insert(name.clone(), *address);
+            // MODULE_ASCLIN0 and IFX_ constants are symbols, not registers
+            if name.starts_with("MODULE_") || name.starts_with("IFX") {
+                continue;
+            }
+            let (fields, width) = match register_type {
+                Some(register_type) => defs.fields(register_type),
+                None => (Vec::new(), 32)
+            };
+            self.add_register(Register { name: name.clone(), address: *address, width: width, reset: 0, fields: fields });
+        }
+    }
+
+    fn add_register(&mut self, register: Register) {
+        self.symbols.insert(register.name.clone(), register.address);
+        let index = match self.names.get(&register.name) {
+            Some(index) => {
+                let index = *index;
+                self.addresses.retain(|_, other| *other != index);
+                self.registers[index] = register.clone();
+                index
+            }
+            None => {
+                self.names.insert(register.name.clone(), self.registers.len());
+                self.registers.push(register.clone());
+                self.registers.len() - 1
+            }
+        };
+        // aliases keep the name registered first
+        self.addresses.entry(register.address).or_insert(index);
+    }
+
+    pub fn parse_svd(&mut self, content: &str) {
+        let root = Element::parse(content);
+        let device = match root.find("device") {
+            Some(device) => device,
+            None => return
+        };
+        let defaults = (device.value("size").unwrap_or(32) as u8, device.value("resetValue").unwrap_or(0));
+        let peripherals: Vec<&Element> = device.child("peripherals").map(|peripherals| peripherals.children("peripheral").collect()).unwrap_or_default();
+        let by_name: HashMap<String, &Element> = peripherals.iter()
+            .filter_map(|peripheral| peripheral.child("name").map(|name| (name.text.clone(), *peripheral)))
+            .collect();
+        for peripheral in &peripherals {
+            let name = peripheral.child("name").map(|name| name.text.clone()).unwrap_or_default();
+            let base = peripheral.value("baseAddress").unwrap_or(0);
+            // derivedFrom peripherals reuse the registers of their parent
+            let source = match (peripheral.child("registers"), peripheral.attributes.get("derivedFrom").and_then(|parent| by_name.get(parent))) {
+                (None, Some(parent)) => *parent,
+                _ => *peripheral
+            };
+            let defaults = (source.value("size").map_or(defaults.0, |size| size as u8), source.value("resetValue").unwrap_or(defaults.1));
+            for (name, increment) in expand_dim(peripheral, &name) {
+                self.symbols.insert(format!("MODULE_{}", name), base + increment);
+                if let Some(registers) = source.child("registers") {
+                    self.parse_svd_registers(registers, &name, base + increment, defaults);
+                }
+            }
+        }
+    }
+
+    fn parse_svd_registers(&mut self, parent: &Element, prefix: &str, base: u64, defaults: (u8, u64)) {
+        for element in &parent.children {
+            let name = element.child("name").map(|name| name.text.clone()).unwrap_or_default();
+            let offset = element.value("addressOffset").unwrap_or(0);
+            let defaults = (element.value("size").map_or(defaults.0, |size| size as u8), element.value("resetValue").unwrap_or(defaults.1));
+            for (name, increment) in expand_dim(element, &name) {
+                let name = format!("{}_{}", prefix, name);
+                let address = base + offset + increment;
+                match element.name.as_str() {
+                    "cluster" => self.parse_svd_registers(element, &name, address, defaults),
+                    "register" => {
+                        let fields = element.child("fields").map(|fields| fields.children("field").filter_map(|field| {
+                            let (offset, width) = match (field.value("bitOffset"), field.value("bitWidth"), field.value("lsb"), field.value("msb"), field.child("bitRange")) {
+                                (Some(offset), Some(width), _, _, _) => (offset, width),
+                                (_, _, Some(lsb), Some(msb), _) => (lsb, msb.checked_add(1)?.checked_sub(lsb)?),
+                                (_, _, _, _, Some(range)) => {
+                                    let (msb, lsb) = range.text.trim_matches(|c| c == '[' || c == ']').split_once(':')?;
+                                    let (msb, lsb) = (parse_int(msb)?, parse_int(lsb)?);
+                                    (lsb, msb.checked_add(1)?.checked_sub(lsb)?)
+                                }
+                                _ => return None
+                            };
+                            Field::new(&field.child("name")?.text, offset, width)
+                        }).collect()).unwrap_or_default();
+                        self.add_register(Register { name: name, address: address, width: defaults.0, reset: defaults.1, fields: fields });
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    pub fn get_register(&self, name: &str) -> Option<&Register> {
+        self.names.get(name).map(|index| &self.registers[*index])
+    }
+
+    /// Address of a register or of a module/constant symbol.
+    pub fn get_address(&self, name: &str) -> Option<u64> {
+        self.symbols.get(name).copied()
+    }
+
+    pub fn get_registers(&self) -> &[Register] {
+        &self.registers
+    }
+
+    /// Register containing `address` and the offset of `address` in it.
+    pub fn lookup(&self, address: u64) -> Option<(&Register, u64)> {
+        let (start, index) = self.addresses.range(..=address).next_back()?;
+        let register = &self.registers[*index];
+        match address - start < (register.width as u64 / 8).max(1) {
+            true => Some((register, address - start)),
+            false => None
+        }
+    }
+
+    /// Decodes `value` accessed at `address`, None outside of the registers.
+    pub fn decode_access(&self, address: u64, value: u64) -> Option<SfrValue> {
+        let (register, offset) = self.lookup(address)?;
+        return Some(SfrValue {
+            register: register.name.clone(),
+            address: register.address,
+            offset: offset,
+            width: register.width,
+            value: value,
+            fields: register.decode(value << (offset * 8))
+                .into_iter().filter(|field| field.offset as u64 >= offset * 8).collect()
+        });
+    }
+}
+
+impl SymMap for RegMap {
+    fn get_symbol(&self, symbol: &str) -> Result<u64, String> {
+        self.get_address(symbol).ok_or(format!("unknown symbol {}", symbol))
+    }
+
+    fn get_name(&self, address: u64) -> Option<String> {
+        self.lookup(address).map(|(register, offset)| match offset {
+            0 => register.name.clone(),
+            _ => format!("{}+{:#x}", register.name, offset)
+        })
+    }
+
+    fn decode(&self, symbol: &str, value: u64) -> Result<Vec<FieldValue>, String> {
+        match self.get_register(symbol) {
+            Some(register) => Ok(register.decode(value)),
+            None => Err(format!("unknown register {}", symbol))
+        }
+    }
+}
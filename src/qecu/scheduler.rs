@@ -0,0 +1,114 @@
+use serde::{Deserialize, Serialize};
+
+use crate::utils::workflow::WorkflowScheduler;
+
+/// State of a core as reported by the API.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CoreState {
+    pub core: usize,
+    pub running: bool,
+    pub pc: u64
+}
+
+/// Round-robin scheduling of the cores: the running ones execute `slice`
+/// instructions in turn, CPU0 being the only one running at reset.
+#[derive(Clone, Debug)]
+pub struct Scheduler {
+    running: Vec<bool>,
+    current: usize,
+    slice: u64,
+    executed: u64,
+    stopped: bool
+}
+
+impl Scheduler {
+    pub fn new(cores: usize, config: &WorkflowScheduler) -> Scheduler {
+        let mut running = vec![false; cores];
+        running[0] = true;
+        return Scheduler {
+            running: running,
+            current: 0,
+            slice: config.slice.max(1),
+            executed: 0,
+            stopped: false
+        };
+    }
+
+    pub fn cores(&self) -> usize {
+        self.running.len()
+    }
+
+    /// Core executing instructions, or the last one that did.
+    pub fn current(&self) -> usize {
+        self.current
+    }
+
+    pub fn is_running(&self, core: usize) -> bool {
+        self.running.get(core).copied().unwrap_or(false)
+    }
+
+    pub fn start(&mut self, core: usize) {
+        if core < self.running.len() && !self.running[core] {
+            print!("[qecu::scheduler] CPU{} started\n", core);
+            self.running[core] = true;
+        }
+    }
+
+    pub fn halt(&mut self, core: usize) {
+        if core < self.running.len() && self.running[core] {
+            print!("[qecu::scheduler] CPU{} halted\n", core);
+            self.running[core] = false;
+        }
+    }
+
+    /// Makes `core` the current one and runs it, e.g. CPU0 entering a run at
+    /// its start address.
+    pub fn select(&mut self, core: usize) -> usize {
+        self.start(core);
+        self.current = core;
+        return core;
+    }
+
+    /// Core whose instructions make the emulated time advance: CPU0, or the
+    /// first running core once it is halted.
+    pub fn is_timebase(&self, core: usize) -> bool {
+        self.running.iter().position(|running| *running) == Some(core)
+    }
+
+    /// Switches to the next running core after the current one, the current
+    /// one keeps running if it is alone.
+    pub fn next(&mut self) -> Option<usize> {
+        let cores = self.running.len();
+        let core = (1..=cores).map(|step| (self.current + step) % cores).find(|core| self.running[*core])?;
+        self.current = core;
+        return Some(core);
+    }
+
+    pub fn slice(&self) -> u64 {
+        self.slice
+    }
+
+    /// Resets the instruction count and the stop request of a new run.
+    pub fn begin(&mut self) {
+        self.executed = 0;
+        self.stopped = false;
+    }
+
+    pub fn on_instruction(&mut self) {
+        self.executed += 1;
+    }
+
+    /// Instructions executed by all the cores since `begin`.
+    pub fn executed(&self) -> u64 {
+        self.executed
+    }
+
+    /// Ends the run once the current slice returns.
+    pub fn stop(&mut self) {
+        self.stopped = true;
+    }
+
+    pub fn is_stopped(&self) -> bool {
+        self.stopped
+    }
+}
@@ -0,0 +1,135 @@
+use std::ffi::CStr;
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::os::unix::io::FromRawFd;
+use std::sync::mpsc::{channel, Receiver, Sender, TryRecvError};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// Host side of an emulated serial line: bytes sent by the firmware go to
+/// `tx`, bytes typed on the host side are read from `rx`.
+pub struct SerialHost {
+    tx: Sender<Vec<u8>>,
+    rx: Receiver<u8>,
+    /// Slave side of a pty, kept open to avoid EIO on the master between two
+    /// clients and closed with the host.
+    _pty_slave: Option<File>
+}
+
+impl SerialHost {
+    /// `spec` is `tcp:<address>`, `pty` or `file:<path>`.
+    pub fn open(name: &str, spec: &str) -> SerialHost {
+        let (tx, tx_rx) = channel::<Vec<u8>>();
+        let (rx_tx, rx) = channel::<u8>();
+        let pty_slave = match spec.split_once(':').unwrap_or((spec, "")) {
+            ("tcp", address) => {
+                open_tcp(name, address, tx_rx, rx_tx);
+                None
+            }
+            ("pty", _) => Some(open_pty(name, tx_rx, rx_tx)),
+            ("file", path) => {
+                let file = OpenOptions::new().create(true).append(true).open(path)
+                    .expect(format!("[qecu::serial] Cannot open {}\n", path).as_str());
+                print!("[qecu::serial] {} logged to {}\n", name, path);
+                spawn_writer(file, tx_rx);
+                None
+            }
+            _ => panic!("[qecu::serial] Unknown host {} for {}, expected tcp:<address>, pty or file:<path>", spec, name)
+        };
+        return SerialHost { tx: tx, rx: rx, _pty_slave: pty_slave };
+    }
+
+    pub fn send(&self, data: Vec<u8>) {
+        self.tx.send(data).unwrap_or(());
+    }
+
+    /// Next byte received from the host, if any.
+    pub fn try_recv(&self) -> Option<u8> {
+        match self.rx.try_recv() {
+            Ok(byte) => Some(byte),
+            Err(TryRecvError::Empty) | Err(TryRecvError::Disconnected) => None
+        }
+    }
+}
+
+fn spawn_writer<W: Write + Send + 'static>(mut writer: W, tx_rx: Receiver<Vec<u8>>) {
+    thread::spawn(move || {
+        for data in tx_rx {
+            if writer.write_all(&data).and_then(|_| writer.flush()).is_err() {
+                break;
+            }
+        }
+    });
+}
+
+fn spawn_reader<R: Read + Send + 'static>(mut reader: R, rx_tx: Sender<u8>) {
+    thread::spawn(move || {
+        let mut buffer = [0u8; 256];
+        loop {
+            match reader.read(&mut buffer) {
+                Ok(0) | Err(_) => break,
+                Ok(len) => {
+                    for byte in &buffer[..len] {
+                        if rx_tx.send(*byte).is_err() {
+                            return;
+                        }
+                    }
+                }
+            }
+        }
+    });
+}
+
+/// One client at a time, output is dropped while nobody is connected.
+fn open_tcp(name: &str, address: &str, tx_rx: Receiver<Vec<u8>>, rx_tx: Sender<u8>) {
+    let listener = TcpListener::bind(address)
+        .expect(format!("[qecu::serial] Cannot listen on {}\n", address).as_str());
+    print!("[qecu::serial] {} listening on tcp:{}\n", name, address);
+    let client: Arc<Mutex<Option<TcpStream>>> = Arc::new(Mutex::new(None));
+    let accepted = client.clone();
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            if let Ok(stream) = stream {
+                if let Ok(reader) = stream.try_clone() {
+                    spawn_reader(reader, rx_tx.clone());
+                }
+                *accepted.lock().unwrap() = Some(stream);
+            }
+        }
+    });
+    thread::spawn(move || {
+        for data in tx_rx {
+            let mut client = client.lock().unwrap();
+            if let Some(stream) = client.as_mut() {
+                if stream.write_all(&data).is_err() {
+                    *client = None;
+                }
+            }
+        }
+    });
+}
+
+/// Returns the slave side, which has to stay open.
+fn open_pty(name: &str, tx_rx: Receiver<Vec<u8>>, rx_tx: Sender<u8>) -> File {
+    let (master, path) = unsafe {
+        let fd = libc::posix_openpt(libc::O_RDWR | libc::O_NOCTTY);
+        if fd < 0 || libc::grantpt(fd) != 0 || libc::unlockpt(fd) != 0 {
+            panic!("[qecu::serial] Cannot allocate a pty for {}", name);
+        }
+        let path = CStr::from_ptr(libc::ptsname(fd)).to_string_lossy().into_owned();
+        let mut termios: libc::termios = std::mem::zeroed();
+        if libc::tcgetattr(fd, &mut termios) == 0 {
+            libc::cfmakeraw(&mut termios);
+            libc::tcsetattr(fd, libc::TCSANOW, &termios);
+        }
+        (File::from_raw_fd(fd), path)
+    };
+    print!("[qecu::serial] {} available on {}\n", name, path);
+    let slave = OpenOptions::new().read(true).write(true).open(&path)
+        .expect(format!("[qecu::serial] Cannot open {}\n", path).as_str());
+    let reader = master.try_clone().expect("[qecu::serial] Cannot clone pty\n");
+    spawn_reader(reader, rx_tx);
+    spawn_writer(master, tx_rx);
+    return slave;
+}
@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+use std::fmt;
+use unicorn_engine::Unicorn;
+use unicorn_engine::unicorn_const::uc_error;
+
+use super::arch::tricore::interrupt::InterruptController;
+use super::clock::Clock;
+use super::peripheral::BusState;
+use super::scheduler::Scheduler;
+
+const PAGE_SIZE: u64 = 0x1000;
+
+struct ContextWrapper {
+    context: unicorn_engine::Context
+}
+unsafe impl Send for ContextWrapper {}
+impl fmt::Debug for ContextWrapper {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ContextWrapper")
+    }
+}
+
+/// CPU contexts plus copy-on-write memory: the original content of a page is
+/// saved on its first write after the snapshot, restoring writes them back.
+#[derive(Debug, Default)]
+pub struct Snapshot {
+    contexts: Vec<ContextWrapper>,
+    /// PC of CPU0.
+    pub pc: u64,
+    /// Emulated time and pending timers at the snapshot point.
+    pub clock: Option<Clock>,
+    /// Peripheral models and pending traps and interrupts.
+    pub peripherals: Option<BusState>,
+    pub irq: Option<InterruptController>,
+    /// Running cores at the snapshot point.
+    pub scheduler: Option<Scheduler>,
+    pages: HashMap<u64, Vec<u8>>
+}
+
+impl Snapshot {
+    pub fn is_taken(&self) -> bool {
+        !self.contexts.is_empty()
+    }
+
+    /// Saves the context of every core, `cores` being indexed by core.
+    pub fn take(&mut self, cores: &[Unicorn<'_, ()>]) -> Result<(), uc_error> {
+        self.contexts.clear();
+        for uc in cores {
+            let mut context = uc.context_init()?;
+            uc.context_save(&mut context)?;
+            self.contexts.push(ContextWrapper { context: context });
+        }
+        self.pc = cores[0].pc_read()?;
+        self.pages.clear();
+        print!("[qecu::snapshot] Snapshot taken at {:#010x}\n", self.pc);
+        return Ok(());
+    }
+
+    pub fn on_write(&mut self, uc: &Unicorn<'_, ()>, address: u64, size: usize) {
+        if self.contexts.is_empty() {
+            return;
+        }
+        let first = address & !(PAGE_SIZE - 1);
+        let last = (address + size as u64 - 1) & !(PAGE_SIZE - 1);
+        let mut page = first;
+        while page <= last {
+            if !self.pages.contains_key(&page) {
+                if let Ok(data) = uc.mem_read_as_vec(page, PAGE_SIZE as usize) {
+                    self.pages.insert(page, data);
+                }
+            }
+            page += PAGE_SIZE;
+        }
+    }
+
+    pub fn restore(&mut self, cores: &mut [Unicorn<'_, ()>]) -> Result<(), uc_error> {
+        if self.contexts.is_empty() {
+            return Ok(());
+        }
+        for (page, data) in self.pages.drain() {
+            cores[0].mem_write(page, &data)?;
+        }
+        for (uc, context) in cores.iter_mut().zip(&self.contexts) {
+            uc.context_restore(&context.context)?;
+        }
+        return Ok(());
+    }
+}
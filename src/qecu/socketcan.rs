@@ -0,0 +1,140 @@
+use std::ffi::CString;
+use std::fs::File;
+use std::io::{Read, Write};
+use std::mem::size_of;
+use std::os::unix::io::FromRawFd;
+use std::thread;
+
+use super::can::{CanFrame, CanPort};
+
+const CAN_EFF_FLAG: u32 = 0x80000000;
+const CAN_RTR_FLAG: u32 = 0x40000000;
+const CAN_EFF_MASK: u32 = 0x1FFFFFFF;
+const CAN_SFF_MASK: u32 = 0x7FF;
+const CANFD_BRS: u8 = 0x01;
+const CAN_MTU: usize = 16;
+const CANFD_MTU: usize = 72;
+
+/// struct sockaddr_can, without the protocol specific addresses.
+#[repr(C)]
+struct SockaddrCan {
+    can_family: libc::sa_family_t,
+    can_ifindex: libc::c_int
+}
+
+/// struct canfd_frame, struct can_frame is its first CAN_MTU bytes.
+#[repr(C)]
+struct CanFdFrame {
+    can_id: u32,
+    len: u8,
+    flags: u8,
+    res0: u8,
+    res1: u8,
+    data: [u8; 64]
+}
+
+impl CanFdFrame {
+    fn as_bytes(&self) -> &[u8] {
+        unsafe { std::slice::from_raw_parts(self as *const CanFdFrame as *const u8, CANFD_MTU) }
+    }
+
+    fn from_bytes(bytes: &[u8; CANFD_MTU]) -> CanFdFrame {
+        unsafe { std::ptr::read_unaligned(bytes.as_ptr() as *const CanFdFrame) }
+    }
+}
+
+fn encode(frame: &CanFrame) -> Vec<u8> {
+    let mut raw = CanFdFrame { can_id: 0, len: 0, flags: 0, res0: 0, res1: 0, data: [0; 64] };
+    raw.can_id = match frame.extended {
+        true => (frame.id & CAN_EFF_MASK) | CAN_EFF_FLAG,
+        false => frame.id & CAN_SFF_MASK
+    };
+    if frame.rtr {
+        raw.can_id |= CAN_RTR_FLAG;
+    }
+    let len = frame.data.len().min(if frame.fd { 64 } else { 8 });
+    raw.len = len as u8;
+    raw.data[..len].copy_from_slice(&frame.data[..len]);
+    if frame.fd && frame.brs {
+        raw.flags |= CANFD_BRS;
+    }
+    let mtu = if frame.fd { CANFD_MTU } else { CAN_MTU };
+    return raw.as_bytes()[..mtu].to_vec();
+}
+
+fn decode(bytes: &[u8; CANFD_MTU], size: usize) -> CanFrame {
+    let raw = CanFdFrame::from_bytes(bytes);
+    let fd = size == CANFD_MTU;
+    let extended = raw.can_id & CAN_EFF_FLAG != 0;
+    let len = (raw.len as usize).min(if fd { 64 } else { 8 });
+    return CanFrame {
+        id: raw.can_id & if extended { CAN_EFF_MASK } else { CAN_SFF_MASK },
+        extended: extended,
+        fd: fd,
+        brs: fd && raw.flags & CANFD_BRS != 0,
+        rtr: raw.can_id & CAN_RTR_FLAG != 0,
+        data: raw.data[..len].to_vec()
+    };
+}
+
+/// Opens a raw CAN socket with CAN FD frames enabled, bound to `interface`.
+fn open(interface: &str) -> Result<File, String> {
+    let name = CString::new(interface).map_err(|_| format!("invalid interface name {}", interface))?;
+    unsafe {
+        let ifindex = libc::if_nametoindex(name.as_ptr());
+        if ifindex == 0 {
+            return Err(format!("no such interface {}", interface));
+        }
+        let fd = libc::socket(libc::PF_CAN, libc::SOCK_RAW, libc::CAN_RAW);
+        if fd < 0 {
+            return Err(String::from("cannot open a CAN_RAW socket"));
+        }
+        let enable: libc::c_int = 1;
+        libc::setsockopt(fd, libc::SOL_CAN_RAW, libc::CAN_RAW_FD_FRAMES,
+            &enable as *const libc::c_int as *const libc::c_void, size_of::<libc::c_int>() as libc::socklen_t);
+        let address = SockaddrCan { can_family: libc::AF_CAN as libc::sa_family_t, can_ifindex: ifindex as libc::c_int };
+        if libc::bind(fd, &address as *const SockaddrCan as *const libc::sockaddr, size_of::<SockaddrCan>() as libc::socklen_t) != 0 {
+            libc::close(fd);
+            return Err(format!("cannot bind to {}", interface));
+        }
+        return Ok(File::from_raw_fd(fd));
+    }
+}
+
+/// Connects an emulated CAN node to a SocketCAN interface (e.g. `vcan0`):
+/// frames sent on the bus are injected in the node, frames transmitted by the
+/// firmware are written on the bus.
+pub fn bridge(port: &CanPort, interface: &str) {
+    let socket = match open(interface) {
+        Ok(socket) => socket,
+        Err(err) => {
+            print!("[qecu::socketcan] Cannot bridge {} to {}: {}\n", port.name(), interface, err);
+            return;
+        }
+    };
+    print!("[qecu::socketcan] {} bridged to {}\n", port.name(), interface);
+
+    let mut reader = socket.try_clone().expect("[qecu::socketcan] Cannot clone socket\n");
+    let inbox = port.clone();
+    thread::spawn(move || {
+        let mut buffer = [0u8; CANFD_MTU];
+        loop {
+            match reader.read(&mut buffer) {
+                Ok(size) if size == CAN_MTU || size == CANFD_MTU => inbox.inject(decode(&buffer, size)),
+                Ok(_) => {}
+                Err(_) => break
+            }
+        }
+    });
+
+    let mut writer = socket;
+    let transmitted = port.subscribe();
+    let name = port.name();
+    thread::spawn(move || {
+        for frame in transmitted {
+            if let Err(err) = writer.write_all(&encode(&frame)) {
+                print!("[qecu::socketcan] {} write error: {}\n", name, err);
+            }
+        }
+    });
+}
@@ -0,0 +1,55 @@
+use std::collections::{BTreeMap, HashMap};
+
+#[derive(Clone, Debug, Default)]
+pub struct Symbols {
+    by_name: HashMap<String, u64>,
+    by_addr: BTreeMap<u64, String>,
+    /// End address of the functions, from their size or section.
+    ends: HashMap<u64, u64>
+}
+
+impl Symbols {
+    pub fn new(functions: HashMap<String, u64>, ends: HashMap<u64, u64>) -> Symbols {
+        let mut by_addr: BTreeMap<u64, String> = BTreeMap::new();
+        for (name, address) in &functions {
+            by_addr.insert(*address, name.clone());
+        }
+        return Symbols {
+            by_name: functions,
+            by_addr: by_addr,
+            ends: ends
+        };
+    }
+
+    pub fn get_address(&self, name: &str) -> Option<u64> {
+        self.by_name.get(name).copied()
+    }
+
+    /// Returns the function containing `address` with the offset inside it,
+    /// None past the end of the closest function.
+    pub fn lookup(&self, address: u64) -> Option<(String, u64)> {
+        let (base, name) = self.by_addr.range(..=address).next_back()?;
+        let end = self.get_end(*base).unwrap_or(*base + 1);
+        if address >= end.max(*base + 1) {
+            return None;
+        }
+        return Some((name.clone(), address - base));
+    }
+
+    /// End of the function starting at `address`: its size or section, else
+    /// the next known symbol.
+    pub fn get_end(&self, address: u64) -> Option<u64> {
+        if let Some(end) = self.ends.get(&address) {
+            return Some(*end);
+        }
+        self.by_addr.range(address + 1..).next().map(|(addr, _)| *addr)
+    }
+
+    pub fn symbolize(&self, address: u64) -> String {
+        match self.lookup(address) {
+            Some((name, 0)) => name,
+            Some((name, offset)) => format!("{}+{:#x}", name, offset),
+            None => format!("{:#010x}", address)
+        }
+    }
+}
@@ -0,0 +1,305 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+
+use crate::qecu::arch::tricore::get_sleigh_register;
+use crate::utils::workflow::WorkflowTaint;
+use super::pcode::{PcodeOp, Varnode};
+use super::symbols::Symbols;
+
+/// Taint of a byte: bit `n` set means the byte derives from label `n`.
+type TaintMask = u64;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TaintSink {
+    pub name: String,
+    pub address: u64,
+    pub register: String
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TaintReport {
+    pub kind: String,
+    pub pc: u64,
+    pub location: String,
+    pub labels: Vec<String>
+}
+
+struct MemAccess {
+    write: bool,
+    address: u64,
+    size: usize
+}
+
+/// Registers and instruction in flight of one core.
+#[derive(Default)]
+struct CoreTaint {
+    registers: HashMap<u64, TaintMask>,
+    unique: HashMap<u64, TaintMask>,
+    pending: Option<u64>,
+    /// The code hook of the pending instruction is running, it has not
+    /// executed yet.
+    in_hook: bool,
+    accesses: VecDeque<MemAccess>
+}
+
+/// Memory is keyed by resolved addresses, the aliases of a byte sharing its
+/// taint.
+#[derive(Default)]
+pub struct Taint {
+    labels: Vec<String>,
+    memory: HashMap<u64, TaintMask>,
+    cores: Vec<CoreTaint>,
+    cache: HashMap<u64, Vec<PcodeOp>>,
+    sinks: Vec<TaintSink>,
+    reports: Vec<TaintReport>
+}
+
+impl Taint {
+    pub fn new(wf_taint: &Option<WorkflowTaint>, symbols: &Symbols, cores: usize) -> Taint {
+        let mut taint = Taint::default();
+        taint.cores = (0..cores).map(|_| CoreTaint::default()).collect();
+        if let Some(wf_taint) = wf_taint {
+            for sink in &wf_taint.sinks {
+                let address = match &sink.symbol {
+                    Some(symbol) => symbols.get_address(symbol)
+                        .expect(format!("[qecu::taint] Unknown symbol {}\n", symbol).as_str()),
+                    None => sink.address
+                };
+                let name = sink.symbol.clone().unwrap_or(format!("{:#010x}", address));
+                taint.add_sink(name, address, sink.register.clone());
+            }
+        }
+        return taint;
+    }
+
+    /// True while something is tainted, nothing needs to be lifted otherwise.
+    pub fn is_active(&self) -> bool {
+        !self.memory.is_empty() || self.cores.iter().any(|core| !core.registers.is_empty())
+    }
+
+    fn label_mask(&mut self, label: &str) -> TaintMask {
+        let index = match self.labels.iter().position(|l| l == label) {
+            Some(index) => index,
+            None => {
+                if self.labels.len() == 64 {
+                    print!("[qecu::taint] Too many labels, merging {} into {}\n", label, self.labels[63]);
+                    63
+                } else {
+                    self.labels.push(label.to_string());
+                    self.labels.len() - 1
+                }
+            }
+        };
+        return 1 << index;
+    }
+
+    fn mask_labels(&self, mask: TaintMask) -> Vec<String> {
+        self.labels.iter().enumerate()
+            .filter(|(index, _)| mask & (1 << index) != 0)
+            .map(|(_, label)| label.clone())
+            .collect()
+    }
+
+    /// Taints the resolved addresses `bytes`.
+    pub fn taint_memory(&mut self, bytes: &[u64], label: &str) {
+        let mask = self.label_mask(label);
+        for byte in bytes {
+            *self.memory.entry(*byte).or_insert(0) |= mask;
+        }
+    }
+
+    pub fn taint_register(&mut self, core: usize, name: &str, label: &str) {
+        let offset = get_sleigh_register(name)
+            .expect(format!("[qecu::taint] Unknown register {}\n", name).as_str());
+        let mask = self.label_mask(label);
+        for byte in offset..offset + 4 {
+            *self.cores[core].registers.entry(byte).or_insert(0) |= mask;
+        }
+    }
+
+    /// Clears the taint of a register written by the host or a script, whose
+    /// value no longer comes from the firmware. An executed pending instruction
+    /// is propagated first so it does not taint the register again. Registers
+    /// outside the core register space, like PC, are not tracked.
+    pub fn untaint_register(&mut self, core: usize, name: &str, symbols: &Symbols) {
+        if !self.cores[core].in_hook {
+            if let Some(pc) = self.cores[core].pending.take() {
+                let ops = self.cache.get(&pc).cloned().unwrap_or_default();
+                self.propagate(core, pc, &ops, symbols);
+                self.cores[core].accesses.clear();
+            }
+        }
+        if let Some(offset) = get_sleigh_register(name) {
+            Taint::write_space(&mut self.cores[core].registers, offset, 4, 0);
+        }
+    }
+
+    /// Labels of the resolved addresses `bytes`.
+    pub fn get_memory_taint(&self, bytes: &[u64]) -> Vec<String> {
+        let mask = bytes.iter().fold(0, |mask, byte| mask | self.memory.get(byte).copied().unwrap_or(0));
+        return self.mask_labels(mask);
+    }
+
+    pub fn clear(&mut self) {
+        self.memory.clear();
+        for core in self.cores.iter_mut() {
+            *core = CoreTaint::default();
+        }
+        self.reports.clear();
+    }
+
+    pub fn add_sink(&mut self, name: String, address: u64, register: String) {
+        self.sinks.push(TaintSink { name: name, address: address, register: register });
+    }
+
+    pub fn get_reports(&self) -> Vec<TaintReport> {
+        self.reports.clone()
+    }
+
+    /// Called before `core` executes `address`: propagates taint through its
+    /// previous instruction, checks sinks and queues the current one.
+    pub fn on_instruction<F>(&mut self, core: usize, address: u64, symbols: &Symbols, lift: F)
+        where F: FnOnce() -> Vec<PcodeOp> {
+        if let Some(pc) = self.cores[core].pending.take() {
+            let ops = self.cache.get(&pc).cloned().unwrap_or_default();
+            self.propagate(core, pc, &ops, symbols);
+        }
+        self.cores[core].accesses.clear();
+        if !self.is_active() {
+            return;
+        }
+        self.check_sinks(core, address, symbols);
+        if !self.cache.contains_key(&address) {
+            let ops = lift();
+            self.cache.insert(address, ops);
+        }
+        self.cores[core].pending = Some(address);
+        self.cores[core].in_hook = true;
+    }
+
+    /// Called when the code hook of `core` returns, its pending instruction
+    /// executes next.
+    pub fn end_hook(&mut self, core: usize) {
+        self.cores[core].in_hook = false;
+    }
+
+    /// Access of the pending instruction of `core` to the resolved `address`.
+    pub fn on_memory(&mut self, core: usize, write: bool, address: u64, size: usize) {
+        if self.cores[core].pending.is_some() {
+            self.cores[core].accesses.push_back(MemAccess { write: write, address: address, size: size });
+        }
+    }
+
+    fn check_sinks(&mut self, core: usize, address: u64, symbols: &Symbols) {
+        let mut hits: Vec<(String, TaintMask)> = Vec::new();
+        for sink in self.sinks.iter().filter(|sink| sink.address == address) {
+            if let Some(offset) = get_sleigh_register(&sink.register) {
+                let mask = self.read_space(&self.cores[core].registers, offset, 4);
+                if mask != 0 {
+                    hits.push((format!("length:{}:{}", sink.name, sink.register), mask));
+                }
+            }
+        }
+        for (kind, mask) in hits {
+            self.report(&kind, address, mask, symbols);
+        }
+    }
+
+    fn read_space(&self, space: &HashMap<u64, TaintMask>, offset: u64, size: u32) -> TaintMask {
+        (offset..offset + size as u64).fold(0, |mask, byte| mask | space.get(&byte).copied().unwrap_or(0))
+    }
+
+    /// Taint of a register or unique varnode of `core`. Memory is only reached
+    /// through the resolved addresses of the LOAD and STORE accesses.
+    fn read(&self, core: usize, varnode: &Varnode) -> TaintMask {
+        match varnode.space.as_str() {
+            "register" => self.read_space(&self.cores[core].registers, varnode.offset, varnode.size),
+            "unique" => self.read_space(&self.cores[core].unique, varnode.offset, varnode.size),
+            _ => 0
+        }
+    }
+
+    fn write_space(space: &mut HashMap<u64, TaintMask>, offset: u64, size: u64, mask: TaintMask) {
+        for byte in offset..offset + size {
+            if mask == 0 {
+                space.remove(&byte);
+            } else {
+                space.insert(byte, mask);
+            }
+        }
+    }
+
+    fn write(&mut self, core: usize, varnode: &Varnode, mask: TaintMask) {
+        let size = varnode.size as u64;
+        match varnode.space.as_str() {
+            "register" => Taint::write_space(&mut self.cores[core].registers, varnode.offset, size, mask),
+            "unique" => Taint::write_space(&mut self.cores[core].unique, varnode.offset, size, mask),
+            _ => {}
+        }
+    }
+
+    fn next_access(&mut self, core: usize, write: bool) -> Option<MemAccess> {
+        let accesses = &mut self.cores[core].accesses;
+        let index = accesses.iter().position(|access| access.write == write)?;
+        return accesses.remove(index);
+    }
+
+    fn propagate(&mut self, core: usize, pc: u64, ops: &[PcodeOp], symbols: &Symbols) {
+        for op in ops {
+            match op.opcode.as_str() {
+                "LOAD" => {
+                    let mask = match self.next_access(core, false) {
+                        Some(access) => self.read_space(&self.memory, access.address, access.size as u32),
+                        None => 0
+                    };
+                    if let Some(output) = &op.output {
+                        self.write(core, output, mask);
+                    }
+                }
+                "STORE" => {
+                    let pointer = self.read(core, &op.inputs[1]);
+                    if pointer != 0 {
+                        self.report("store-address", pc, pointer, symbols);
+                    }
+                    let mask = self.read(core, &op.inputs[2]);
+                    if let Some(access) = self.next_access(core, true) {
+                        Taint::write_space(&mut self.memory, access.address, access.size as u64, mask);
+                    }
+                }
+                "CBRANCH" => {
+                    let mask = self.read(core, &op.inputs[1]);
+                    if mask != 0 {
+                        self.report("branch", pc, mask, symbols);
+                    }
+                }
+                "BRANCHIND" | "CALLIND" | "RETURN" => {
+                    let mask = self.read(core, &op.inputs[0]);
+                    if mask != 0 {
+                        self.report("indirect-jump", pc, mask, symbols);
+                    }
+                }
+                _ => {
+                    if let Some(output) = &op.output {
+                        let mask = op.inputs.iter().fold(0, |mask, input| mask | self.read(core, input));
+                        self.write(core, output, mask);
+                    }
+                }
+            }
+        }
+        self.cores[core].unique.clear();
+    }
+
+    fn report(&mut self, kind: &str, pc: u64, mask: TaintMask, symbols: &Symbols) {
+        if self.reports.iter().any(|report| report.pc == pc && report.kind == kind) {
+            return;
+        }
+        let report = TaintReport {
+            kind: kind.to_string(),
+            pc: pc,
+            location: symbols.symbolize(pc),
+            labels: self.mask_labels(mask)
+        };
+        print!("[qecu::taint] {} at {:#010x} ({}) from {:?}\n", report.kind, report.pc, report.location, report.labels);
+        self.reports.push(report);
+    }
+}
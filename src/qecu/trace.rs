@@ -0,0 +1,264 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+
+use crate::utils::workflow::WorkflowTrace;
+use super::symbols::Symbols;
+use super::regmap::RegMap;
+
+const TRACE_MAGIC: &[u8; 4] = b"QTRC";
+const TRACE_VERSION: u8 = 1;
+
+const TAG_BLOCK: u8 = 0x01;
+const TAG_INSTRUCTION: u8 = 0x02;
+const TAG_REGISTER: u8 = 0x03;
+const TAG_MEM_READ: u8 = 0x04;
+const TAG_MEM_WRITE: u8 = 0x05;
+
+/// Registers compared for deltas, the index is the one stored in the trace.
+pub const TRACE_REGISTERS: [&str; 32] = [
+    "D0", "D1", "D2", "D3", "D4", "D5", "D6", "D7",
+    "D8", "D9", "D10", "D11", "D12", "D13", "D14", "D15",
+    "A0", "A1", "A2", "A3", "A4", "A5", "A6", "A7",
+    "A8", "A9", "A10", "A11", "A12", "A13", "A14", "A15"
+];
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TraceWindow {
+    pub from: u64,
+    pub to: u64
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TraceConfig {
+    pub windows: Vec<TraceWindow>,
+    #[serde(default = "default_mode")]
+    pub mode: String,
+    #[serde(default)]
+    pub registers: bool,
+    #[serde(default)]
+    pub memory: bool,
+    #[serde(default)]
+    pub output: Option<String>
+}
+
+fn default_mode() -> String {
+    String::from("block")
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum TraceRecord {
+    Block { address: u64, size: u32 },
+    Instruction { address: u64, size: u32 },
+    Register { index: u8, value: u32 },
+    MemRead { address: u64, size: u8, value: u64 },
+    MemWrite { address: u64, size: u8, value: u64 }
+}
+
+#[derive(Clone, Debug)]
+pub struct Tracer {
+    config: Option<TraceConfig>,
+    buffer: Vec<u8>,
+    last_regs: [u32; 32],
+    active: bool
+}
+
+impl Tracer {
+    pub fn new(wf_trace: &Option<WorkflowTrace>, symbols: &Symbols) -> Tracer {
+        let mut tracer = Tracer {
+            config: None,
+            buffer: Vec::new(),
+            last_regs: [0; 32],
+            active: false
+        };
+        if let Some(wf_trace) = wf_trace {
+            let mut windows: Vec<TraceWindow> = Vec::new();
+            for window in &wf_trace.windows {
+                match &window.symbol {
+                    Some(symbol) => {
+                        let from = symbols.get_address(symbol)
+                            .expect(format!("[qecu::trace] Unknown symbol {}\n", symbol).as_str());
+                        let to = symbols.get_end(from).unwrap_or(from + 1);
+                        windows.push(TraceWindow { from: from, to: to });
+                    }
+                    None => windows.push(TraceWindow { from: window.from, to: window.to })
+                }
+            }
+            tracer.start(TraceConfig {
+                windows: windows,
+                mode: wf_trace.mode.clone(),
+                registers: wf_trace.registers,
+                memory: wf_trace.memory,
+                output: wf_trace.output.clone()
+            });
+        }
+        return tracer;
+    }
+
+    pub fn start(&mut self, config: TraceConfig) {
+        print!("[qecu::trace] Tracing {} window(s) in {} mode.\n", config.windows.len(), config.mode);
+        self.buffer.clear();
+        self.buffer.extend_from_slice(TRACE_MAGIC);
+        self.buffer.push(TRACE_VERSION);
+        self.last_regs = [0; 32];
+        self.active = false;
+        self.config = Some(config);
+    }
+
+    /// Stops tracing, flushing the trace to the configured output file.
+    pub fn stop(&mut self) {
+        if let Some(config) = self.config.take() {
+            if let Some(output) = config.output {
+                fs::write(&output, &self.buffer)
+                    .expect(format!("[qecu::trace] Cannot write trace to {}\n", output).as_str());
+                print!("[qecu::trace] Trace written to {}\n", output);
+            }
+        }
+        self.active = false;
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.config.is_some()
+    }
+
+    /// Register deltas are recorded per instruction, so only in instruction mode.
+    pub fn wants_registers(&self) -> bool {
+        self.active && self.config.as_ref().map_or(false, |c| c.registers && c.mode == "instruction")
+    }
+
+    fn in_window(&self, address: u64) -> bool {
+        match &self.config {
+            Some(config) => config.windows.iter().any(|w| address >= w.from && address < w.to),
+            None => false
+        }
+    }
+
+    fn trace_mode(&self) -> &str {
+        self.config.as_ref().map_or("", |c| c.mode.as_str())
+    }
+
+    pub fn on_block(&mut self, address: u64, size: u32) {
+        if self.trace_mode() != "block" {
+            return;
+        }
+        self.active = self.in_window(address);
+        if self.active {
+            self.push_location(TAG_BLOCK, address, size);
+        }
+    }
+
+    pub fn on_instruction(&mut self, address: u64, size: u32) {
+        if self.trace_mode() != "instruction" {
+            return;
+        }
+        self.active = self.in_window(address);
+        if self.active {
+            self.push_location(TAG_INSTRUCTION, address, size);
+        }
+    }
+
+    /// Records the registers which changed since the last recorded location.
+    pub fn on_registers(&mut self, values: [u32; 32]) {
+        for (index, value) in values.iter().enumerate() {
+            if self.last_regs[index] != *value {
+                self.buffer.push(TAG_REGISTER);
+                self.buffer.push(index as u8);
+                self.buffer.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+        self.last_regs = values;
+    }
+
+    pub fn on_memory(&mut self, write: bool, address: u64, size: usize, value: u64) {
+        if !self.active || !self.config.as_ref().map_or(false, |c| c.memory) {
+            return;
+        }
+        self.buffer.push(if write { TAG_MEM_WRITE } else { TAG_MEM_READ });
+        self.buffer.extend_from_slice(&(address as u32).to_le_bytes());
+        self.buffer.push(size as u8);
+        self.buffer.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn push_location(&mut self, tag: u8, address: u64, size: u32) {
+        self.buffer.push(tag);
+        self.buffer.extend_from_slice(&(address as u32).to_le_bytes());
+        self.buffer.extend_from_slice(&(size as u16).to_le_bytes());
+    }
+
+    pub fn get_data(&self) -> Vec<u8> {
+        self.buffer.clone()
+    }
+}
+
+/// Records of a binary trace, an error if it is malformed or truncated.
+pub fn decode(data: &[u8]) -> Result<Vec<TraceRecord>, String> {
+    let mut ret: Vec<TraceRecord> = Vec::new();
+    if data.len() < 5 || &data[0..4] != TRACE_MAGIC || data[4] != TRACE_VERSION {
+        return Err(String::from("invalid trace header"));
+    }
+    let bytes = |i: usize, size: usize| data.get(i..i + size)
+        .ok_or(format!("truncated record at offset {}", i - 1));
+    let mut i = 5;
+    while i < data.len() {
+        let tag = data[i];
+        i += 1;
+        match tag {
+            TAG_BLOCK | TAG_INSTRUCTION => {
+                let record = bytes(i, 6)?;
+                let address = u32::from_le_bytes(record[0..4].try_into().unwrap()) as u64;
+                let size = u16::from_le_bytes(record[4..6].try_into().unwrap()) as u32;
+                i += 6;
+                if tag == TAG_BLOCK {
+                    ret.push(TraceRecord::Block { address: address, size: size });
+                } else {
+                    ret.push(TraceRecord::Instruction { address: address, size: size });
+                }
+            }
+            TAG_REGISTER => {
+                let record = bytes(i, 5)?;
+                ret.push(TraceRecord::Register { index: record[0], value: u32::from_le_bytes(record[1..5].try_into().unwrap()) });
+                i += 5;
+            }
+            TAG_MEM_READ | TAG_MEM_WRITE => {
+                let record = bytes(i, 13)?;
+                let address = u32::from_le_bytes(record[0..4].try_into().unwrap()) as u64;
+                let size = record[4];
+                let value = u64::from_le_bytes(record[5..13].try_into().unwrap());
+                i += 13;
+                if tag == TAG_MEM_WRITE {
+                    ret.push(TraceRecord::MemWrite { address: address, size: size, value: value });
+                } else {
+                    ret.push(TraceRecord::MemRead { address: address, size: size, value: value });
+                }
+            }
+            _ => return Err(format!("unknown record tag {:#x} at offset {}", tag, i - 1))
+        }
+    }
+    return Ok(ret);
+}
+
+/// Text form of a trace, one record per line with symbolized locations.
+/// Peripheral accesses are annotated with the register and its bitfields
+/// from `regmap`.
+pub fn to_text(data: &[u8], symbols: &Symbols, regmap: &RegMap) -> Result<String, String> {
+    let sfr = |address: u64, value: u64| match regmap.decode_access(address, value) {
+        Some(sfr) => format!(" {}", sfr.to_text()),
+        None => String::new()
+    };
+    let mut out = String::new();
+    for record in decode(data)? {
+        let line = match record {
+            TraceRecord::Block { address, size } =>
+                format!("B {:#010x} {} [{}]\n", address, symbols.symbolize(address), size),
+            TraceRecord::Instruction { address, size } =>
+                format!("I {:#010x} {} [{}]\n", address, symbols.symbolize(address), size),
+            TraceRecord::Register { index, value } =>
+                format!("    {} = {:#010x}\n", TRACE_REGISTERS[index as usize % 32], value),
+            TraceRecord::MemRead { address, size, value } =>
+                format!("    R {:#010x} [{}] = {:#x}{}\n", address, size, value, sfr(address, value)),
+            TraceRecord::MemWrite { address, size, value } =>
+                format!("    W {:#010x} [{}] = {:#x}{}\n", address, size, value, sfr(address, value))
+        };
+        out.push_str(line.as_str());
+    }
+    return Ok(out);
+}
@@ -0,0 +1,497 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use super::can::{from_hex, to_hex};
+use super::isotp::{self, FrameLink, IsoTpConfig};
+
+const SID_SESSION_CONTROL: u8 = 0x10;
+const SID_ECU_RESET: u8 = 0x11;
+const SID_SECURITY_ACCESS: u8 = 0x27;
+const SID_READ_DID: u8 = 0x22;
+const SID_WRITE_DID: u8 = 0x2E;
+const SID_ROUTINE_CONTROL: u8 = 0x31;
+const SID_REQUEST_DOWNLOAD: u8 = 0x34;
+const SID_REQUEST_UPLOAD: u8 = 0x35;
+const SID_TRANSFER_DATA: u8 = 0x36;
+const SID_TRANSFER_EXIT: u8 = 0x37;
+const SID_TESTER_PRESENT: u8 = 0x3E;
+const NEGATIVE_RESPONSE: u8 = 0x7F;
+
+const NRC_SERVICE_NOT_SUPPORTED: u8 = 0x11;
+const NRC_SUBFUNCTION_NOT_SUPPORTED: u8 = 0x12;
+const NRC_REQUEST_OUT_OF_RANGE: u8 = 0x31;
+const NRC_RESPONSE_PENDING: u8 = 0x78;
+const NRC_SUBFUNCTION_NOT_SUPPORTED_IN_SESSION: u8 = 0x7E;
+const NRC_SERVICE_NOT_SUPPORTED_IN_SESSION: u8 = 0x7F;
+
+/// P2* server timeout, used after a response pending answer.
+const P2_EXTENDED_MS: u64 = 5000;
+/// addressAndLengthFormatIdentifier of download/upload requests: 4 byte size and address.
+const ADDRESS_AND_LENGTH_FORMAT: u8 = 0x44;
+
+pub fn service_name(sid: u8) -> &'static str {
+    match sid {
+        0x10 => "DiagnosticSessionControl",
+        0x11 => "ECUReset",
+        0x14 => "ClearDiagnosticInformation",
+        0x19 => "ReadDTCInformation",
+        0x22 => "ReadDataByIdentifier",
+        0x23 => "ReadMemoryByAddress",
+        0x24 => "ReadScalingDataByIdentifier",
+        0x27 => "SecurityAccess",
+        0x28 => "CommunicationControl",
+        0x29 => "Authentication",
+        0x2A => "ReadDataByPeriodicIdentifier",
+        0x2C => "DynamicallyDefineDataIdentifier",
+        0x2E => "WriteDataByIdentifier",
+        0x2F => "InputOutputControlByIdentifier",
+        0x31 => "RoutineControl",
+        0x34 => "RequestDownload",
+        0x35 => "RequestUpload",
+        0x36 => "TransferData",
+        0x37 => "RequestTransferExit",
+        0x38 => "RequestFileTransfer",
+        0x3D => "WriteMemoryByAddress",
+        0x3E => "TesterPresent",
+        0x83 => "AccessTimingParameter",
+        0x84 => "SecuredDataTransmission",
+        0x85 => "ControlDTCSetting",
+        0x86 => "ResponseOnEvent",
+        0x87 => "LinkControl",
+        _ => "Unknown"
+    }
+}
+
+pub fn nrc_name(nrc: u8) -> &'static str {
+    match nrc {
+        0x10 => "generalReject",
+        0x11 => "serviceNotSupported",
+        0x12 => "subFunctionNotSupported",
+        0x13 => "incorrectMessageLengthOrInvalidFormat",
+        0x14 => "responseTooLong",
+        0x21 => "busyRepeatRequest",
+        0x22 => "conditionsNotCorrect",
+        0x24 => "requestSequenceError",
+        0x25 => "noResponseFromSubnetComponent",
+        0x26 => "failurePreventsExecutionOfRequestedAction",
+        0x31 => "requestOutOfRange",
+        0x33 => "securityAccessDenied",
+        0x35 => "invalidKey",
+        0x36 => "exceedNumberOfAttempts",
+        0x37 => "requiredTimeDelayNotExpired",
+        0x70 => "uploadDownloadNotAccepted",
+        0x71 => "transferDataSuspended",
+        0x72 => "generalProgrammingFailure",
+        0x73 => "wrongBlockSequenceCounter",
+        0x78 => "requestCorrectlyReceived-ResponsePending",
+        0x7E => "subFunctionNotSupportedInActiveSession",
+        0x7F => "serviceNotSupportedInActiveSession",
+        _ => "unknown"
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum UdsError {
+    Negative { sid: u8, nrc: u8 },
+    Transport(String),
+    Invalid(String),
+    Request(String)
+}
+
+impl fmt::Display for UdsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            UdsError::Negative { sid, nrc } => write!(f, "{} rejected: {} (0x{:02x})", service_name(*sid), nrc_name(*nrc), nrc),
+            UdsError::Transport(err) => write!(f, "transport: {}", err),
+            UdsError::Invalid(err) => write!(f, "invalid response: {}", err),
+            UdsError::Request(err) => write!(f, "invalid request: {}", err)
+        }
+    }
+}
+
+/// A UDS request of the API.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "service", rename_all = "snake_case")]
+pub enum UdsCommand {
+    SessionControl { session: u8 },
+    EcuReset { reset_type: u8 },
+    TesterPresent,
+    /// Unlocks `level` (odd), the key is computed by the Rhai function
+    /// `key_function(level, seed)` of the init script.
+    SecurityAccess { level: u8, key_function: String },
+    ReadDid { did: u16 },
+    WriteDid {
+        did: u16,
+        #[serde(serialize_with = "to_hex", deserialize_with = "from_hex")]
+        data: Vec<u8>
+    },
+    RoutineControl {
+        control: u8,
+        routine: u16,
+        #[serde(default, serialize_with = "to_hex", deserialize_with = "from_hex")]
+        data: Vec<u8>
+    },
+    /// RequestDownload followed by the TransferData of `data` and RequestTransferExit.
+    Download {
+        address: u32,
+        #[serde(default)]
+        format: u8,
+        #[serde(serialize_with = "to_hex", deserialize_with = "from_hex")]
+        data: Vec<u8>
+    },
+    Upload {
+        address: u32,
+        size: u32,
+        #[serde(default)]
+        format: u8
+    },
+    Raw {
+        #[serde(serialize_with = "to_hex", deserialize_with = "from_hex")]
+        data: Vec<u8>
+    }
+}
+
+/// Outcome of a `UdsCommand`: the positive response data or the error.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct UdsResponse {
+    pub data: Option<String>,
+    pub nrc: Option<u8>,
+    pub error: Option<String>
+}
+
+impl UdsResponse {
+    pub fn new(result: Result<Vec<u8>, UdsError>) -> UdsResponse {
+        match result {
+            Ok(data) => UdsResponse { data: Some(data.iter().map(|b| format!("{:02x}", b)).collect()), ..UdsResponse::default() },
+            Err(err) => UdsResponse {
+                nrc: match err {
+                    UdsError::Negative { nrc, .. } => Some(nrc),
+                    _ => None
+                },
+                error: Some(err.to_string()),
+                ..UdsResponse::default()
+            }
+        }
+    }
+}
+
+pub struct UdsClient {
+    link: FrameLink,
+    config: IsoTpConfig
+}
+
+impl UdsClient {
+    pub fn new(link: FrameLink, config: IsoTpConfig) -> UdsClient {
+        return UdsClient { link: link, config: config };
+    }
+
+    /// Sends `payload` and returns the positive response, waiting again on
+    /// response pending answers.
+    pub fn request(&self, payload: &[u8]) -> Result<Vec<u8>, UdsError> {
+        if payload.is_empty() {
+            return Err(UdsError::Invalid(String::from("empty request")));
+        }
+        let sid = payload[0];
+        // Drops late answers of previous requests.
+        while self.link.rx.try_recv().is_ok() {}
+        isotp::send(&self.link, &self.config, payload).map_err(UdsError::Transport)?;
+        let mut config = self.config.clone();
+        loop {
+            let response = isotp::receive(&self.link, &config).map_err(UdsError::Transport)?;
+            match response.as_slice() {
+                [NEGATIVE_RESPONSE, rsid, NRC_RESPONSE_PENDING, ..] if *rsid == sid => config.timeout_ms = P2_EXTENDED_MS,
+                [NEGATIVE_RESPONSE, rsid, nrc, ..] if *rsid == sid => return Err(UdsError::Negative { sid: sid, nrc: *nrc }),
+                [rsid, ..] if *rsid == sid.wrapping_add(0x40) => return Ok(response),
+                _ => {}
+            }
+        }
+    }
+
+    pub fn session_control(&self, session: u8) -> Result<Vec<u8>, UdsError> {
+        return self.request(&[SID_SESSION_CONTROL, session]);
+    }
+
+    pub fn ecu_reset(&self, reset_type: u8) -> Result<Vec<u8>, UdsError> {
+        return self.request(&[SID_ECU_RESET, reset_type]);
+    }
+
+    pub fn tester_present(&self) -> Result<Vec<u8>, UdsError> {
+        return self.request(&[SID_TESTER_PRESENT, 0x00]);
+    }
+
+    /// Seed/key exchange of `level`, `key` computes the key from the seed.
+    /// `level` is the odd requestSeed sub-function, sendKey is `level + 1`.
+    pub fn security_access(&self, level: u8, key: &dyn Fn(u8, Vec<u8>) -> Result<Vec<u8>, String>) -> Result<Vec<u8>, UdsError> {
+        check_security_level(level)?;
+        let send_key = level + 1;
+        let response = self.request(&[SID_SECURITY_ACCESS, level])?;
+        let seed = response.get(2..).unwrap_or(&[]).to_vec();
+        // A zero seed means the level is already unlocked.
+        if seed.iter().all(|byte| *byte == 0) {
+            return Ok(response);
+        }
+        let key = key(level, seed).map_err(UdsError::Invalid)?;
+        let mut payload = vec![SID_SECURITY_ACCESS, send_key];
+        payload.extend_from_slice(&key);
+        return self.request(&payload);
+    }
+
+    pub fn read_did(&self, did: u16) -> Result<Vec<u8>, UdsError> {
+        let response = self.request(&[SID_READ_DID, (did >> 8) as u8, did as u8])?;
+        return Ok(response.get(3..).unwrap_or(&[]).to_vec());
+    }
+
+    pub fn write_did(&self, did: u16, data: &[u8]) -> Result<Vec<u8>, UdsError> {
+        let mut payload = vec![SID_WRITE_DID, (did >> 8) as u8, did as u8];
+        payload.extend_from_slice(data);
+        return self.request(&payload);
+    }
+
+    pub fn routine_control(&self, control: u8, routine: u16, data: &[u8]) -> Result<Vec<u8>, UdsError> {
+        let mut payload = vec![SID_ROUTINE_CONTROL, control, (routine >> 8) as u8, routine as u8];
+        payload.extend_from_slice(data);
+        return self.request(&payload);
+    }
+
+    /// RequestDownload/RequestUpload, returns the maxNumberOfBlockLength.
+    fn request_transfer(&self, sid: u8, address: u32, size: u32, format: u8) -> Result<usize, UdsError> {
+        let mut payload = vec![sid, format, ADDRESS_AND_LENGTH_FORMAT];
+        payload.extend_from_slice(&address.to_be_bytes());
+        payload.extend_from_slice(&size.to_be_bytes());
+        let response = self.request(&payload)?;
+        let length = (*response.get(1).unwrap_or(&0) >> 4) as usize;
+        let max_block = response.get(2..2 + length)
+            .ok_or(UdsError::Invalid(String::from("truncated maxNumberOfBlockLength")))?
+            .iter().fold(0usize, |value, byte| (value << 8) | *byte as usize);
+        if max_block <= 2 {
+            return Err(UdsError::Invalid(format!("maxNumberOfBlockLength {}", max_block)));
+        }
+        return Ok(max_block);
+    }
+
+    pub fn download(&self, address: u32, format: u8, data: &[u8]) -> Result<Vec<u8>, UdsError> {
+        let max_block = self.request_transfer(SID_REQUEST_DOWNLOAD, address, data.len() as u32, format)?;
+        let mut counter: u8 = 1;
+        for chunk in data.chunks(max_block - 2) {
+            let mut payload = vec![SID_TRANSFER_DATA, counter];
+            payload.extend_from_slice(chunk);
+            self.request(&payload)?;
+            counter = counter.wrapping_add(1);
+        }
+        return self.request(&[SID_TRANSFER_EXIT]);
+    }
+
+    pub fn upload(&self, address: u32, size: u32, format: u8) -> Result<Vec<u8>, UdsError> {
+        self.request_transfer(SID_REQUEST_UPLOAD, address, size, format)?;
+        let mut data = Vec::new();
+        let mut counter: u8 = 1;
+        while data.len() < size as usize {
+            let response = self.request(&[SID_TRANSFER_DATA, counter])?;
+            if response.len() <= 2 {
+                return Err(UdsError::Invalid(String::from("empty TransferData block")));
+            }
+            data.extend_from_slice(&response[2..]);
+            counter = counter.wrapping_add(1);
+        }
+        self.request(&[SID_TRANSFER_EXIT])?;
+        data.truncate(size as usize);
+        return Ok(data);
+    }
+
+    pub fn execute(&self, command: &UdsCommand, key: &dyn Fn(u8, Vec<u8>) -> Result<Vec<u8>, String>) -> Result<Vec<u8>, UdsError> {
+        match command {
+            UdsCommand::SessionControl { session } => self.session_control(*session),
+            UdsCommand::EcuReset { reset_type } => self.ecu_reset(*reset_type),
+            UdsCommand::TesterPresent => self.tester_present(),
+            UdsCommand::SecurityAccess { level, .. } => self.security_access(*level, key),
+            UdsCommand::ReadDid { did } => self.read_did(*did),
+            UdsCommand::WriteDid { did, data } => self.write_did(*did, data),
+            UdsCommand::RoutineControl { control, routine, data } => self.routine_control(*control, *routine, data),
+            UdsCommand::Download { address, format, data } => self.download(*address, *format, data),
+            UdsCommand::Upload { address, size, format } => self.upload(*address, *size, *format),
+            UdsCommand::Raw { data } => self.request(data)
+        }
+    }
+}
+
+/// What the scanner enumerates, in every session of `sessions`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScanConfig {
+    pub sessions: Vec<u8>,
+    /// Services probed, all of them when empty.
+    pub services: Vec<u8>,
+    /// Services whose sub-functions are enumerated. ECUReset is left out by
+    /// default as it restarts the firmware.
+    pub subfunctions: Vec<u8>,
+    /// Inclusive DID ranges.
+    pub dids: Vec<(u16, u16)>,
+    /// Unlocks this level in each session before scanning, with `key_function`.
+    pub security_level: Option<u8>,
+    pub key_function: Option<String>,
+    pub timeout_ms: u64
+}
+
+impl Default for ScanConfig {
+    fn default() -> Self {
+        ScanConfig {
+            sessions: vec![0x01, 0x02, 0x03],
+            services: Vec::new(),
+            subfunctions: vec![0x10, 0x19, 0x27, 0x28, 0x3E, 0x85],
+            dids: vec![(0xF180, 0xF1FF)],
+            security_level: None,
+            key_function: None,
+            timeout_ms: 200
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ServiceReport {
+    pub sid: u8,
+    pub name: String,
+    /// Negative answer to the bare service request, if any.
+    pub nrc: Option<String>,
+    pub subfunctions: Vec<u8>
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct DidReport {
+    pub did: u16,
+    pub data: Option<String>,
+    pub nrc: Option<String>
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct SessionReport {
+    pub session: u8,
+    pub error: Option<String>,
+    pub unlocked: Option<bool>,
+    pub services: Vec<ServiceReport>,
+    pub dids: Vec<DidReport>
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ScanReport {
+    pub error: Option<String>,
+    pub sessions: Vec<SessionReport>
+}
+
+/// Whether an answer to a probe means the service (or sub-function) exists.
+fn supported(result: &Result<Vec<u8>, UdsError>, not_supported: &[u8]) -> bool {
+    match result {
+        Ok(_) => true,
+        Err(UdsError::Negative { nrc, .. }) => !not_supported.contains(nrc),
+        Err(_) => false
+    }
+}
+
+/// requestSeed sub-functions are odd and below 0x7F, sendKey is the next one.
+fn check_security_level(level: u8) -> Result<(), UdsError> {
+    if level % 2 == 0 || level >= 0x7F {
+        return Err(UdsError::Request(format!("security level 0x{:02x} is not a requestSeed sub-function", level)));
+    }
+    return Ok(());
+}
+
+fn nrc_string(err: &UdsError) -> Option<String> {
+    match err {
+        UdsError::Negative { nrc, .. } => Some(format!("0x{:02x} {}", nrc, nrc_name(*nrc))),
+        _ => None
+    }
+}
+
+impl UdsClient {
+    /// Enters `session` from the default session.
+    fn enter_session(&self, session: u8) -> Result<Vec<u8>, UdsError> {
+        let response = self.session_control(0x01)?;
+        if session == 0x01 {
+            return Ok(response);
+        }
+        return self.session_control(session);
+    }
+
+    fn scan_services(&self, session: u8, config: &ScanConfig) -> Vec<ServiceReport> {
+        let services: Vec<u8> = match config.services.is_empty() {
+            true => (0x00..=0xFF).filter(|sid| sid & 0x40 == 0 && *sid != NEGATIVE_RESPONSE).collect(),
+            false => config.services.clone()
+        };
+        let mut reports = Vec::new();
+        for sid in services {
+            // Sub-function services reject a bare SID with a length error,
+            // which still tells they exist.
+            let result = self.request(&[sid]);
+            if !supported(&result, &[NRC_SERVICE_NOT_SUPPORTED, NRC_SERVICE_NOT_SUPPORTED_IN_SESSION]) {
+                continue;
+            }
+            let mut report = ServiceReport {
+                sid: sid,
+                name: service_name(sid).to_string(),
+                nrc: result.as_ref().err().and_then(nrc_string),
+                subfunctions: Vec::new()
+            };
+            if config.subfunctions.contains(&sid) {
+                for subfunction in 0x00..0x80u8 {
+                    let result = self.request(&[sid, subfunction]);
+                    if supported(&result, &[NRC_SUBFUNCTION_NOT_SUPPORTED, NRC_SUBFUNCTION_NOT_SUPPORTED_IN_SESSION, NRC_SERVICE_NOT_SUPPORTED_IN_SESSION]) {
+                        report.subfunctions.push(subfunction);
+                    }
+                    // Session changes are undone to keep probing the scanned session.
+                    if sid == SID_SESSION_CONTROL && result.is_ok() {
+                        let _ = self.enter_session(session);
+                    }
+                }
+            }
+            reports.push(report);
+        }
+        return reports;
+    }
+
+    fn scan_dids(&self, config: &ScanConfig) -> Vec<DidReport> {
+        let mut reports = Vec::new();
+        for (first, last) in &config.dids {
+            for did in *first..=*last {
+                match self.read_did(did) {
+                    Ok(data) => reports.push(DidReport {
+                        did: did,
+                        data: Some(data.iter().map(|b| format!("{:02x}", b)).collect()),
+                        nrc: None
+                    }),
+                    Err(UdsError::Negative { nrc, .. }) if nrc == NRC_REQUEST_OUT_OF_RANGE => {}
+                    Err(err) => if let Some(nrc) = nrc_string(&err) {
+                        reports.push(DidReport { did: did, data: None, nrc: Some(nrc) });
+                    }
+                }
+            }
+        }
+        return reports;
+    }
+
+    /// Enumerates services, sub-functions and DIDs in every configured session.
+    pub fn scan(&self, config: &ScanConfig, key: &dyn Fn(u8, Vec<u8>) -> Result<Vec<u8>, String>) -> ScanReport {
+        let mut report = ScanReport::default();
+        if let Some(level) = config.security_level {
+            if let Err(err) = check_security_level(level) {
+                report.error = Some(err.to_string());
+                return report;
+            }
+        }
+        for session in &config.sessions {
+            let mut session_report = SessionReport { session: *session, ..SessionReport::default() };
+            print!("[qecu::uds] Scanning session 0x{:02x}\n", session);
+            if let Err(err) = self.enter_session(*session) {
+                session_report.error = Some(err.to_string());
+                report.sessions.push(session_report);
+                continue;
+            }
+            if let Some(level) = config.security_level {
+                session_report.unlocked = Some(self.security_access(level, key).is_ok());
+            }
+            session_report.services = self.scan_services(*session, config);
+            session_report.dids = self.scan_dids(config);
+            report.sessions.push(session_report);
+        }
+        let _ = self.session_control(0x01);
+        return report;
+    }
+}
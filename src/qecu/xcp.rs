@@ -0,0 +1,656 @@
+use std::fs;
+use std::io::{Read, Write};
+use std::net::{TcpListener, UdpSocket};
+use std::sync::mpsc::{channel, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use crate::utils::workflow::WorkflowXcp;
+use super::can::CanFrame;
+use super::emulator::Emulator;
+use super::symbols::Symbols;
+
+// Commands.
+const CMD_CONNECT: u8 = 0xFF;
+const CMD_DISCONNECT: u8 = 0xFE;
+const CMD_GET_STATUS: u8 = 0xFD;
+const CMD_SYNCH: u8 = 0xFC;
+const CMD_GET_COMM_MODE_INFO: u8 = 0xFB;
+const CMD_GET_ID: u8 = 0xFA;
+const CMD_SET_MTA: u8 = 0xF6;
+const CMD_UPLOAD: u8 = 0xF5;
+const CMD_SHORT_UPLOAD: u8 = 0xF4;
+const CMD_BUILD_CHECKSUM: u8 = 0xF3;
+const CMD_DOWNLOAD: u8 = 0xF0;
+const CMD_SHORT_DOWNLOAD: u8 = 0xED;
+const CMD_SET_CAL_PAGE: u8 = 0xEB;
+const CMD_GET_CAL_PAGE: u8 = 0xEA;
+const CMD_CLEAR_DAQ_LIST: u8 = 0xE3;
+const CMD_SET_DAQ_PTR: u8 = 0xE2;
+const CMD_WRITE_DAQ: u8 = 0xE1;
+const CMD_SET_DAQ_LIST_MODE: u8 = 0xE0;
+const CMD_GET_DAQ_LIST_MODE: u8 = 0xDF;
+const CMD_START_STOP_DAQ_LIST: u8 = 0xDE;
+const CMD_START_STOP_SYNCH: u8 = 0xDD;
+const CMD_GET_DAQ_CLOCK: u8 = 0xDC;
+const CMD_GET_DAQ_PROCESSOR_INFO: u8 = 0xDA;
+const CMD_GET_DAQ_RESOLUTION_INFO: u8 = 0xD9;
+const CMD_GET_DAQ_EVENT_INFO: u8 = 0xD7;
+const CMD_FREE_DAQ: u8 = 0xD6;
+const CMD_ALLOC_DAQ: u8 = 0xD5;
+const CMD_ALLOC_ODT: u8 = 0xD4;
+const CMD_ALLOC_ODT_ENTRY: u8 = 0xD3;
+
+const PID_RES: u8 = 0xFF;
+const PID_ERR: u8 = 0xFE;
+
+// Error codes.
+const ERR_CMD_SYNCH: u8 = 0x00;
+const ERR_DAQ_ACTIVE: u8 = 0x11;
+const ERR_CMD_UNKNOWN: u8 = 0x20;
+const ERR_CMD_SYNTAX: u8 = 0x21;
+const ERR_OUT_OF_RANGE: u8 = 0x22;
+const ERR_ACCESS_DENIED: u8 = 0x24;
+const ERR_MODE_NOT_VALID: u8 = 0x27;
+const ERR_SEQUENCE: u8 = 0x29;
+const ERR_DAQ_CONFIG: u8 = 0x2A;
+const ERR_MEMORY_OVERFLOW: u8 = 0x30;
+
+// CONNECT resources and GET_STATUS session status.
+const RESOURCE_CAL_PAG: u8 = 0x01;
+const RESOURCE_DAQ: u8 = 0x04;
+const COMM_MODE_OPTIONAL: u8 = 0x80;
+const STATUS_DAQ_RUNNING: u8 = 0x40;
+
+// DAQ list mode bits.
+const DAQ_MODE_TIMESTAMP: u8 = 0x10;
+const DAQ_MODE_SELECTED: u8 = 0x01;
+const DAQ_MODE_RUNNING: u8 = 0x40;
+
+/// Dynamic DAQ, prescaler and timestamps supported.
+const DAQ_PROPERTIES: u8 = 0x01 | 0x02 | 0x10;
+/// 4 byte timestamps in 1 us units.
+const TIMESTAMP_MODE: u8 = 0x04 | (0x3 << 4);
+const MAX_ODT_ENTRY_SIZE: u8 = 0xFF;
+const MAX_DAQ: usize = 16;
+/// PIDs 0xFC..0xFF are responses, errors, events and service requests.
+const MAX_ODT: usize = 0xFC;
+/// Largest BUILD_CHECKSUM block, the size comes from the master.
+const MAX_CHECKSUM_SIZE: usize = 0x100000;
+
+#[derive(Clone, Debug, Default)]
+struct OdtEntry {
+    address: u64,
+    size: usize
+}
+
+#[derive(Clone, Debug, Default)]
+struct DaqList {
+    odts: Vec<Vec<OdtEntry>>,
+    mode: u8,
+    event: u16,
+    prescaler: u8,
+    priority: u8,
+    counter: u8
+}
+
+#[derive(Clone, Debug)]
+struct EventChannel {
+    name: String,
+    period: Option<u64>,
+    address: Option<u64>,
+    next: u64
+}
+
+/// XCP slave over the emulated memory: calibration through the memory
+/// transfer commands and measurement with dynamic DAQ lists sampled on
+/// event channels tied to code addresses or to the virtual clock.
+#[derive(Debug)]
+pub struct XcpSlave {
+    max_cto: usize,
+    max_dto: usize,
+    connected: bool,
+    mta: u64,
+    /// Data of GET_ID/GET_DAQ_EVENT_INFO uploaded from MTA instead of memory.
+    upload_buffer: Option<Vec<u8>>,
+    cal_page: u8,
+    daq: Vec<DaqList>,
+    daq_ptr: Option<(usize, usize, usize)>,
+    events: Vec<EventChannel>,
+    running: bool,
+    a2l: Option<String>,
+    output: Option<Sender<Vec<u8>>>
+}
+
+fn u16_at(packet: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes([packet[offset], packet[offset + 1]])
+}
+
+fn u32_at(packet: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(packet[offset..offset + 4].try_into().unwrap())
+}
+
+fn error(code: u8) -> Vec<u8> {
+    vec![PID_ERR, code]
+}
+
+impl XcpSlave {
+    pub fn new(config: &WorkflowXcp, symbols: &Symbols) -> XcpSlave {
+        let events = config.events.iter().map(|event| EventChannel {
+            name: event.name.clone(),
+            period: event.period_us.map(|period| period.max(1) * 1000),
+            address: match &event.symbol {
+                Some(symbol) => Some(symbols.get_address(symbol)
+                    .expect(format!("[qecu::xcp] Unknown symbol {}\n", symbol).as_str())),
+                None => event.address
+            },
+            next: 0
+        }).collect();
+        let can = config.transport.starts_with("can:");
+        return XcpSlave {
+            max_cto: if can { 8 } else { 255 },
+            max_dto: if can { 8 } else { 1024 },
+            connected: false,
+            mta: 0,
+            upload_buffer: None,
+            cal_page: 0,
+            daq: Vec::new(),
+            daq_ptr: None,
+            events: events,
+            running: false,
+            a2l: config.a2l.clone(),
+            output: None
+        };
+    }
+
+    /// Where DAQ packets go, set by the transport of the connected master.
+    pub fn set_output(&mut self, output: Option<Sender<Vec<u8>>>) {
+        self.output = output;
+    }
+
+    fn first_pid(&self, daq: usize) -> usize {
+        self.daq[..daq].iter().map(|list| list.odts.len()).sum()
+    }
+
+    fn stop_all(&mut self) {
+        for list in self.daq.iter_mut() {
+            list.mode &= !(DAQ_MODE_RUNNING | DAQ_MODE_SELECTED);
+        }
+        self.running = false;
+    }
+
+    fn upload(&mut self, emulator: &Emulator<'static>, size: usize) -> Vec<u8> {
+        if size == 0 || size > self.max_cto - 1 {
+            return error(ERR_OUT_OF_RANGE);
+        }
+        let data = match &self.upload_buffer {
+            Some(buffer) => {
+                let start = (self.mta as usize).min(buffer.len());
+                let mut data = buffer[start..(start + size).min(buffer.len())].to_vec();
+                data.resize(size, 0);
+                data
+            }
+            None => match emulator.try_read_memory(self.mta, size) {
+                Some(data) => data,
+                None => return error(ERR_ACCESS_DENIED)
+            }
+        };
+        self.mta += size as u64;
+        let mut response = vec![PID_RES];
+        response.extend_from_slice(&data);
+        return response;
+    }
+
+    fn download(&mut self, emulator: &Emulator<'static>, data: &[u8]) -> Vec<u8> {
+        if self.upload_buffer.is_some() || !emulator.try_write_memory(self.mta, data) {
+            return error(ERR_ACCESS_DENIED);
+        }
+        self.mta += data.len() as u64;
+        return vec![PID_RES];
+    }
+
+    fn get_id(&mut self, emulator: &Emulator<'static>, id_type: u8) -> Vec<u8> {
+        // 0: ASCII text, 1: A2L name without path and extension, 2: with its
+        // path, 3: URL (none), 4: A2L file to upload.
+        let id = match (id_type, &self.a2l) {
+            (0, _) => emulator.wf.project.as_bytes().to_vec(),
+            (1, Some(a2l)) => std::path::Path::new(a2l).file_stem().map(|stem| stem.to_string_lossy().as_bytes().to_vec()).unwrap_or_default(),
+            (2, Some(a2l)) => a2l.as_bytes().to_vec(),
+            (4, Some(a2l)) => fs::read(a2l).unwrap_or_default(),
+            _ => Vec::new()
+        };
+        let mut response = vec![PID_RES, 0, 0, 0];
+        response.extend_from_slice(&(id.len() as u32).to_le_bytes());
+        self.upload_buffer = Some(id);
+        self.mta = 0;
+        return response;
+    }
+
+    fn daq_command(&mut self, packet: &[u8]) -> Vec<u8> {
+        match packet[0] {
+            CMD_FREE_DAQ => {
+                self.stop_all();
+                self.daq.clear();
+                self.daq_ptr = None;
+            }
+            CMD_ALLOC_DAQ => {
+                if self.running {
+                    return error(ERR_DAQ_ACTIVE);
+                }
+                if !self.daq.is_empty() {
+                    return error(ERR_SEQUENCE);
+                }
+                let count = u16_at(packet, 2) as usize;
+                if count > MAX_DAQ {
+                    return error(ERR_MEMORY_OVERFLOW);
+                }
+                self.daq = vec![DaqList { prescaler: 1, ..DaqList::default() }; count];
+            }
+            CMD_ALLOC_ODT => {
+                let daq = u16_at(packet, 2) as usize;
+                if self.first_pid(self.daq.len()) + packet[4] as usize > MAX_ODT {
+                    return error(ERR_MEMORY_OVERFLOW);
+                }
+                match self.daq.get_mut(daq) {
+                    Some(list) if list.odts.is_empty() => list.odts = vec![Vec::new(); packet[4] as usize],
+                    Some(_) => return error(ERR_SEQUENCE),
+                    None => return error(ERR_OUT_OF_RANGE)
+                }
+            }
+            CMD_ALLOC_ODT_ENTRY => {
+                let (daq, odt) = (u16_at(packet, 2) as usize, packet[4] as usize);
+                match self.daq.get_mut(daq).and_then(|list| list.odts.get_mut(odt)) {
+                    Some(entries) if entries.is_empty() => *entries = vec![OdtEntry::default(); packet[5] as usize],
+                    Some(_) => return error(ERR_SEQUENCE),
+                    None => return error(ERR_OUT_OF_RANGE)
+                }
+            }
+            CMD_CLEAR_DAQ_LIST => match self.daq.get_mut(u16_at(packet, 2) as usize) {
+                Some(list) => {
+                    for entries in list.odts.iter_mut() {
+                        entries.iter_mut().for_each(|entry| *entry = OdtEntry::default());
+                    }
+                    list.mode &= !(DAQ_MODE_RUNNING | DAQ_MODE_SELECTED);
+                }
+                None => return error(ERR_OUT_OF_RANGE)
+            },
+            CMD_SET_DAQ_PTR => {
+                let (daq, odt, entry) = (u16_at(packet, 2) as usize, packet[4] as usize, packet[5] as usize);
+                if self.daq.get(daq).and_then(|list| list.odts.get(odt)).map_or(true, |entries| entry >= entries.len()) {
+                    return error(ERR_OUT_OF_RANGE);
+                }
+                self.daq_ptr = Some((daq, odt, entry));
+            }
+            CMD_WRITE_DAQ => {
+                let (daq, odt, entry) = match self.daq_ptr {
+                    Some(ptr) => ptr,
+                    None => return error(ERR_SEQUENCE)
+                };
+                let size = packet[2] as usize;
+                let entries = &mut self.daq[daq].odts[odt];
+                if entry >= entries.len() {
+                    return error(ERR_OUT_OF_RANGE);
+                }
+                let used: usize = entries.iter().enumerate().filter(|(index, _)| *index != entry).map(|(_, entry)| entry.size).sum();
+                let timestamp = if odt == 0 { 4 } else { 0 };
+                if used + size + 1 + timestamp > self.max_dto {
+                    return error(ERR_DAQ_CONFIG);
+                }
+                entries[entry] = OdtEntry { address: u32_at(packet, 4) as u64, size: size };
+                self.daq_ptr = Some((daq, odt, entry + 1));
+            }
+            CMD_SET_DAQ_LIST_MODE => match self.daq.get_mut(u16_at(packet, 2) as usize) {
+                Some(list) => {
+                    list.mode = (list.mode & (DAQ_MODE_RUNNING | DAQ_MODE_SELECTED)) | (packet[1] & !(DAQ_MODE_RUNNING | DAQ_MODE_SELECTED));
+                    list.event = u16_at(packet, 4);
+                    list.prescaler = packet[6].max(1);
+                    list.priority = packet[7];
+                    if list.event as usize >= self.events.len() {
+                        return error(ERR_OUT_OF_RANGE);
+                    }
+                }
+                None => return error(ERR_OUT_OF_RANGE)
+            },
+            CMD_GET_DAQ_LIST_MODE => {
+                let list = match self.daq.get(u16_at(packet, 2) as usize) {
+                    Some(list) => list,
+                    None => return error(ERR_OUT_OF_RANGE)
+                };
+                let event = list.event.to_le_bytes();
+                return vec![PID_RES, list.mode, 0, 0, event[0], event[1], list.prescaler, list.priority];
+            }
+            CMD_START_STOP_DAQ_LIST => {
+                let daq = u16_at(packet, 2) as usize;
+                if daq >= self.daq.len() {
+                    return error(ERR_OUT_OF_RANGE);
+                }
+                let first_pid = self.first_pid(daq) as u8;
+                let list = &mut self.daq[daq];
+                match packet[1] {
+                    0 => list.mode &= !DAQ_MODE_RUNNING,
+                    1 => list.mode |= DAQ_MODE_RUNNING,
+                    2 => list.mode |= DAQ_MODE_SELECTED,
+                    _ => return error(ERR_MODE_NOT_VALID)
+                }
+                self.running = self.daq.iter().any(|list| list.mode & DAQ_MODE_RUNNING != 0);
+                return vec![PID_RES, first_pid];
+            }
+            CMD_START_STOP_SYNCH => {
+                match packet[1] {
+                    0 => self.stop_all(),
+                    1 | 2 => for list in self.daq.iter_mut().filter(|list| list.mode & DAQ_MODE_SELECTED != 0) {
+                        list.mode &= !DAQ_MODE_SELECTED;
+                        if packet[1] == 1 {
+                            list.mode |= DAQ_MODE_RUNNING;
+                        } else {
+                            list.mode &= !DAQ_MODE_RUNNING;
+                        }
+                    },
+                    _ => return error(ERR_MODE_NOT_VALID)
+                }
+                self.running = self.daq.iter().any(|list| list.mode & DAQ_MODE_RUNNING != 0);
+            }
+            _ => return error(ERR_CMD_UNKNOWN)
+        }
+        return vec![PID_RES];
+    }
+
+    /// Handles a command packet (CTO) and returns the response.
+    pub fn command(&mut self, emulator: &Emulator<'static>, packet: &[u8]) -> Vec<u8> {
+        let length = match packet.first() {
+            Some(&CMD_CONNECT) | Some(&CMD_UPLOAD) | Some(&CMD_SET_CAL_PAGE) | Some(&CMD_GET_ID) => 2,
+            Some(&CMD_SHORT_UPLOAD) | Some(&CMD_SET_MTA) | Some(&CMD_WRITE_DAQ) | Some(&CMD_BUILD_CHECKSUM) | Some(&CMD_SHORT_DOWNLOAD) | Some(&CMD_SET_DAQ_LIST_MODE) => 8,
+            Some(&CMD_GET_CAL_PAGE) | Some(&CMD_DOWNLOAD) => 3,
+            Some(&CMD_ALLOC_DAQ) | Some(&CMD_CLEAR_DAQ_LIST) | Some(&CMD_GET_DAQ_LIST_MODE) | Some(&CMD_START_STOP_DAQ_LIST) | Some(&CMD_GET_DAQ_EVENT_INFO) => 4,
+            Some(&CMD_ALLOC_ODT) => 5,
+            Some(&CMD_ALLOC_ODT_ENTRY) | Some(&CMD_SET_DAQ_PTR) => 6,
+            Some(_) => 1,
+            None => return error(ERR_CMD_SYNTAX)
+        };
+        if packet.len() < length {
+            return error(ERR_CMD_SYNTAX);
+        }
+        if packet[0] == CMD_CONNECT {
+            self.connected = true;
+            self.upload_buffer = None;
+            let max_dto = (self.max_dto as u16).to_le_bytes();
+            return vec![PID_RES, RESOURCE_CAL_PAG | RESOURCE_DAQ, COMM_MODE_OPTIONAL, self.max_cto as u8, max_dto[0], max_dto[1], 0x01, 0x01];
+        }
+        if !self.connected {
+            return Vec::new();
+        }
+        match packet[0] {
+            CMD_DISCONNECT => {
+                self.stop_all();
+                self.connected = false;
+                vec![PID_RES]
+            }
+            CMD_GET_STATUS => {
+                let status = if self.running { STATUS_DAQ_RUNNING } else { 0 };
+                vec![PID_RES, status, 0, 0, 0, 0]
+            }
+            CMD_SYNCH => error(ERR_CMD_SYNCH),
+            CMD_GET_COMM_MODE_INFO => vec![PID_RES, 0, 0, 0, 0, 0, 0, 0x10],
+            CMD_GET_ID => self.get_id(emulator, packet[1]),
+            CMD_SET_MTA => {
+                self.upload_buffer = None;
+                self.mta = u32_at(packet, 4) as u64;
+                vec![PID_RES]
+            }
+            CMD_UPLOAD => self.upload(emulator, packet[1] as usize),
+            CMD_SHORT_UPLOAD => {
+                self.upload_buffer = None;
+                self.mta = u32_at(packet, 4) as u64;
+                self.upload(emulator, packet[1] as usize)
+            }
+            CMD_DOWNLOAD => {
+                let size = packet[1] as usize;
+                match packet.get(2..2 + size) {
+                    Some(data) if size <= self.max_cto - 2 => self.download(emulator, data),
+                    _ => error(ERR_OUT_OF_RANGE)
+                }
+            }
+            CMD_SHORT_DOWNLOAD => {
+                let size = packet[1] as usize;
+                self.upload_buffer = None;
+                self.mta = u32_at(packet, 4) as u64;
+                match packet.get(8..8 + size) {
+                    Some(data) => self.download(emulator, data),
+                    None => error(ERR_OUT_OF_RANGE)
+                }
+            }
+            CMD_BUILD_CHECKSUM => {
+                let size = u32_at(packet, 4) as usize;
+                if size > MAX_CHECKSUM_SIZE {
+                    return error(ERR_OUT_OF_RANGE);
+                }
+                match emulator.try_read_memory(self.mta, size) {
+                    Some(data) => {
+                        // XCP_ADD_14: sum of the bytes into a dword.
+                        let sum = data.iter().fold(0u32, |sum, byte| sum.wrapping_add(*byte as u32));
+                        self.mta += size as u64;
+                        let mut response = vec![PID_RES, 0x03, 0, 0];
+                        response.extend_from_slice(&sum.to_le_bytes());
+                        response
+                    }
+                    None => error(ERR_ACCESS_DENIED)
+                }
+            }
+            // A single page, RAM and flash alike.
+            CMD_SET_CAL_PAGE => match packet.get(3) {
+                Some(0) | None => vec![PID_RES],
+                Some(_) => error(ERR_OUT_OF_RANGE)
+            },
+            CMD_GET_CAL_PAGE => vec![PID_RES, 0, 0, self.cal_page],
+            CMD_GET_DAQ_CLOCK => {
+                let mut response = vec![PID_RES, 0, 0, 0];
+                let now = (emulator.clock.lock().unwrap().now() / 1000) as u32;
+                response.extend_from_slice(&now.to_le_bytes());
+                response
+            }
+            CMD_GET_DAQ_PROCESSOR_INFO => {
+                let max_daq = (MAX_DAQ as u16).to_le_bytes();
+                let events = (self.events.len() as u16).to_le_bytes();
+                vec![PID_RES, DAQ_PROPERTIES, max_daq[0], max_daq[1], events[0], events[1], 0, 0]
+            }
+            CMD_GET_DAQ_RESOLUTION_INFO => vec![PID_RES, 1, MAX_ODT_ENTRY_SIZE, 1, MAX_ODT_ENTRY_SIZE, TIMESTAMP_MODE, 1, 0],
+            CMD_GET_DAQ_EVENT_INFO => {
+                let event = match self.events.get(u16_at(packet, 2) as usize) {
+                    Some(event) => event.clone(),
+                    None => return error(ERR_OUT_OF_RANGE)
+                };
+                // Cycle in 1 ms units (unit 6), 0 for non cyclic events.
+                let cycle = event.period.map(|period| (period / 1_000_000).min(0xFF) as u8).unwrap_or(0);
+                self.upload_buffer = Some(event.name.as_bytes().to_vec());
+                self.mta = 0;
+                vec![PID_RES, 0x04, 0xFF, event.name.len() as u8, cycle, 6, 0]
+            }
+            _ => self.daq_command(packet)
+        }
+    }
+
+    /// Samples the DAQ lists of the events triggered by this instruction.
+    pub fn on_instruction(&mut self, emulator: &Emulator<'static>, address: u64, now: u64) {
+        if !self.running || self.output.is_none() {
+            return;
+        }
+        let mut triggered = Vec::new();
+        for (index, event) in self.events.iter_mut().enumerate() {
+            let fired = match (event.period, event.address) {
+                (_, Some(event_address)) => event_address == address,
+                (Some(period), None) => {
+                    // The clock goes back when a snapshot is restored.
+                    if event.next > now + period {
+                        event.next = now;
+                    }
+                    if now >= event.next {
+                        event.next = now + period;
+                        true
+                    } else {
+                        false
+                    }
+                }
+                (None, None) => false
+            };
+            if fired {
+                triggered.push(index as u16);
+            }
+        }
+        if triggered.is_empty() {
+            return;
+        }
+        let timestamp = ((now / 1000) as u32).to_le_bytes();
+        let mut pid = 0;
+        let mut packets = Vec::new();
+        for list in self.daq.iter_mut() {
+            let first_pid = pid;
+            pid += list.odts.len();
+            if list.mode & DAQ_MODE_RUNNING == 0 || !triggered.contains(&list.event) {
+                continue;
+            }
+            list.counter = list.counter.wrapping_add(1);
+            if list.counter % list.prescaler != 0 {
+                continue;
+            }
+            list.counter = 0;
+            for (odt, entries) in list.odts.iter().enumerate() {
+                let mut packet = vec![(first_pid + odt) as u8];
+                if odt == 0 && list.mode & DAQ_MODE_TIMESTAMP != 0 {
+                    packet.extend_from_slice(&timestamp);
+                }
+                for entry in entries.iter().filter(|entry| entry.size > 0) {
+                    let data = emulator.try_read_memory(entry.address, entry.size).unwrap_or(vec![0; entry.size]);
+                    packet.extend_from_slice(&data);
+                }
+                packets.push(packet);
+            }
+        }
+        let output = self.output.as_ref().unwrap();
+        for packet in packets {
+            let _ = output.send(packet);
+        }
+    }
+}
+
+/// Adds the XCP on Ethernet header (LEN, CTR) to every packet of `packets`.
+fn frame_packets(packets: std::sync::mpsc::Receiver<Vec<u8>>, mut write: impl FnMut(Vec<u8>) -> bool) {
+    let mut counter: u16 = 0;
+    for packet in packets {
+        let mut data = (packet.len() as u16).to_le_bytes().to_vec();
+        data.extend_from_slice(&counter.to_le_bytes());
+        data.extend_from_slice(&packet);
+        counter = counter.wrapping_add(1);
+        if !write(data) {
+            break;
+        }
+    }
+}
+
+fn serve_tcp(emulator: Emulator<'static>, xcp: Arc<Mutex<XcpSlave>>, address: &str) {
+    let listener = TcpListener::bind(address).expect(format!("[qecu::xcp] Cannot bind {}\n", address).as_str());
+    for stream in listener.incoming() {
+        let mut stream = match stream {
+            Ok(stream) => stream,
+            Err(_) => continue
+        };
+        print!("[qecu::xcp] Master connected from {:?}\n", stream.peer_addr());
+        let (tx, rx) = channel();
+        xcp.lock().unwrap().set_output(Some(tx.clone()));
+        let mut writer = stream.try_clone().expect("[qecu::xcp] Cannot clone stream\n");
+        thread::spawn(move || frame_packets(rx, |data| writer.write_all(&data).is_ok()));
+        let mut header = [0u8; 4];
+        while stream.read_exact(&mut header).is_ok() {
+            let mut packet = vec![0u8; u16::from_le_bytes([header[0], header[1]]) as usize];
+            if stream.read_exact(&mut packet).is_err() {
+                break;
+            }
+            let response = xcp.lock().unwrap().command(&emulator, &packet);
+            if !response.is_empty() {
+                let _ = tx.send(response);
+            }
+        }
+        let mut slave = xcp.lock().unwrap();
+        slave.stop_all();
+        slave.connected = false;
+        slave.set_output(None);
+    }
+}
+
+fn serve_udp(emulator: Emulator<'static>, xcp: Arc<Mutex<XcpSlave>>, address: &str) {
+    let socket = UdpSocket::bind(address).expect(format!("[qecu::xcp] Cannot bind {}\n", address).as_str());
+    let master = Arc::new(Mutex::new(None));
+    let (tx, rx) = channel();
+    xcp.lock().unwrap().set_output(Some(tx.clone()));
+    let writer = socket.try_clone().expect("[qecu::xcp] Cannot clone socket\n");
+    let peer = master.clone();
+    thread::spawn(move || frame_packets(rx, |data| {
+        if let Some(peer) = *peer.lock().unwrap() {
+            let _ = writer.send_to(&data, peer);
+        }
+        true
+    }));
+    let mut buffer = [0u8; 1500];
+    loop {
+        let (size, from) = match socket.recv_from(&mut buffer) {
+            Ok(received) => received,
+            Err(_) => continue
+        };
+        *master.lock().unwrap() = Some(from);
+        // A datagram may carry several messages.
+        let mut offset = 0;
+        while offset + 4 <= size {
+            let length = u16::from_le_bytes([buffer[offset], buffer[offset + 1]]) as usize;
+            let packet = &buffer[offset + 4..(offset + 4 + length).min(size)];
+            let response = xcp.lock().unwrap().command(&emulator, packet);
+            if !response.is_empty() {
+                let _ = tx.send(response);
+            }
+            offset += 4 + length;
+        }
+    }
+}
+
+fn serve_can(emulator: Emulator<'static>, xcp: Arc<Mutex<XcpSlave>>, node: &str, cmd_id: u32, res_id: u32) {
+    let port = match emulator.can.get(&node.to_uppercase()) {
+        Some(port) => port.clone(),
+        None => {
+            print!("[qecu::xcp] Unknown CAN node {}\n", node);
+            return;
+        }
+    };
+    let commands = port.tap();
+    let (tx, rx) = channel::<Vec<u8>>();
+    xcp.lock().unwrap().set_output(Some(tx.clone()));
+    let writer = port.clone();
+    thread::spawn(move || {
+        for packet in rx {
+            writer.publish(&CanFrame::new(res_id, packet));
+        }
+    });
+    for frame in commands {
+        if frame.id != cmd_id {
+            continue;
+        }
+        let response = xcp.lock().unwrap().command(&emulator, &frame.data);
+        if !response.is_empty() {
+            let _ = tx.send(response);
+        }
+    }
+}
+
+/// Starts the transport of the `xcp` workflow section, if any.
+pub fn serve(emulator: Emulator<'static>) {
+    let (config, xcp) = match (emulator.wf.xcp.clone(), emulator.xcp.clone()) {
+        (Some(config), Some(xcp)) => (config, xcp),
+        _ => return
+    };
+    print!("[qecu::xcp] Slave on {}\n", config.transport);
+    thread::spawn(move || {
+        match config.transport.split_once(':') {
+            Some(("tcp", address)) => serve_tcp(emulator, xcp, address),
+            Some(("udp", address)) => serve_udp(emulator, xcp, address),
+            Some(("can", node)) => serve_can(emulator, xcp, node, config.cmd_id, config.res_id),
+            _ => print!("[qecu::xcp] Unknown transport {}\n", config.transport)
+        }
+    });
+}
@@ -7,10 +7,27 @@ pub struct CodeSection {
     pub address: u64,
     pub size: usize,
     pub data: Vec<u8>,
+    pub executable: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct LineInfo {
+    pub address: u64,
+    pub file: String,
+    pub line: u64,
 }
 
 pub struct Loader {}
 impl Loader {
+    fn load_elf_entry(path: &String) -> Option<u64> {
+        let file_data = std::fs::read(path).expect("[qecu::loader] Could not read file.\n");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(file_data.as_slice()).ok()?;
+        return match file.ehdr.e_entry {
+            0 => None,
+            entry => Some(entry)
+        };
+    }
+
     fn load_elf_cs(path: &String) -> Vec<CodeSection> {
         let path = std::path::PathBuf::from(path);
         let file_data = std::fs::read(path).expect("[Could not read file.");
@@ -25,7 +42,8 @@ impl Loader {
             let code_section: CodeSection = CodeSection {
                 address: _segment.p_vaddr,
                 size: _data.len(),
-                data: _data
+                data: _data,
+                executable: _segment.p_flags & elf::abi::PF_X != 0
             };
             ret.push(code_section);
         }
@@ -37,7 +55,8 @@ impl Loader {
             let code_section: CodeSection = CodeSection {
                 address: _section.sh_addr,
                 size: _data.len(),
-                data: _data.to_vec()
+                data: _data.to_vec(),
+                executable: _section.sh_flags & elf::abi::SHF_EXECINSTR as u64 != 0
             };
             ret.push(code_section);
         }
@@ -45,16 +64,121 @@ impl Loader {
     }
 
     fn load_elf_functions(path: &String) -> HashMap<String, u64> {
-        let ret: HashMap<String, u64> = HashMap::new();
+        let mut ret: HashMap<String, u64> = HashMap::new();
+
+        let path = std::path::PathBuf::from(path);
+        let file_data = std::fs::read(path).expect("[Could not read file.");
+        let slice = file_data.as_slice();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).unwrap();
+
+        print!("[qecu::loader] Loading symbols.\n");
+        let sym_table = file.symbol_table().unwrap();
+        for (_symbols, _strings) in sym_table.iter() {
+            for _symbol in _symbols.iter() {
+                if _symbol.st_symtype() != elf::abi::STT_FUNC || _symbol.st_value == 0 {
+                    continue;
+                }
+                let name = _strings.get(_symbol.st_name as usize).unwrap_or("");
+                if !name.is_empty() {
+                    ret.insert(name.to_string(), _symbol.st_value);
+                }
+            }
+        }
+
+        return ret;
+    }
+
+    /// End address of each function: its symbol size, or the end of its
+    /// section for the symbols without one.
+    fn load_elf_function_ends(path: &String) -> HashMap<u64, u64> {
+        let mut ret: HashMap<u64, u64> = HashMap::new();
 
         let path = std::path::PathBuf::from(path);
         let file_data = std::fs::read(path).expect("[Could not read file.");
         let slice = file_data.as_slice();
         let file = ElfBytes::<AnyEndian>::minimal_parse(slice).unwrap();
 
+        let sections = file.section_headers();
         let sym_table = file.symbol_table().unwrap();
-        for _symbol in sym_table.iter() {}
+        for (_symbols, _strings) in sym_table.iter() {
+            for _symbol in _symbols.iter() {
+                if _symbol.st_symtype() != elf::abi::STT_FUNC || _symbol.st_value == 0 {
+                    continue;
+                }
+                let section = sections.as_ref().and_then(|sections| sections.get(_symbol.st_shndx as usize).ok());
+                let end = match (_symbol.st_size, section) {
+                    (0, Some(section)) if section.sh_addr <= _symbol.st_value => section.sh_addr + section.sh_size,
+                    (0, _) => continue,
+                    (size, _) => _symbol.st_value + size
+                };
+                ret.insert(_symbol.st_value, end);
+            }
+        }
+
+        return ret;
+    }
+
+    fn load_elf_line_info(path: &String) -> Vec<LineInfo> {
+        let mut ret: Vec<LineInfo> = Vec::new();
+
+        let path = std::path::PathBuf::from(path);
+        let file_data = std::fs::read(path).expect("[Could not read file.");
+        let slice = file_data.as_slice();
+        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).unwrap();
 
+        print!("[qecu::loader] Loading DWARF line info.\n");
+        let load_section = |id: gimli::SectionId| -> Result<gimli::EndianSlice<'_, gimli::LittleEndian>, gimli::Error> {
+            let data = match file.section_header_by_name(id.name()) {
+                Ok(Some(header)) => file.section_data(&header).map(|(data, _)| data).unwrap_or(&[]),
+                _ => &[]
+            };
+            Ok(gimli::EndianSlice::new(data, gimli::LittleEndian))
+        };
+        let dwarf = match gimli::Dwarf::load(load_section) {
+            Ok(dwarf) => dwarf,
+            Err(_) => return ret
+        };
+
+        let mut units = dwarf.units();
+        while let Ok(Some(header)) = units.next() {
+            let unit = match dwarf.unit(header) {
+                Ok(unit) => unit,
+                Err(_) => continue
+            };
+            let program = match unit.line_program.clone() {
+                Some(program) => program,
+                None => continue
+            };
+            let mut rows = program.rows();
+            while let Ok(Some((header, row))) = rows.next_row() {
+                if row.end_sequence() {
+                    continue;
+                }
+                let line = match row.line() {
+                    Some(line) => line.get(),
+                    None => continue
+                };
+                let file_entry = match row.file(header) {
+                    Some(file_entry) => file_entry,
+                    None => continue
+                };
+                let mut file_name = String::new();
+                if let Some(dir) = file_entry.directory(header) {
+                    if let Ok(dir) = dwarf.attr_string(&unit, dir) {
+                        file_name.push_str(&dir.to_string_lossy());
+                        file_name.push('/');
+                    }
+                }
+                if let Ok(name) = dwarf.attr_string(&unit, file_entry.path_name()) {
+                    file_name.push_str(&name.to_string_lossy());
+                }
+                ret.push(LineInfo {
+                    address: row.address(),
+                    file: file_name,
+                    line: line
+                });
+            }
+        }
         return ret;
     }
 
@@ -68,8 +192,36 @@ impl Loader {
         return ret;
     }
 
+    /// Entry point of the image, None if it has none.
+    pub fn load_entry(format: &str, path: &String) -> Option<u64> {
+        let ret = match format {
+            "elf"   => Loader::load_elf_entry(path),
+            _       => None
+        };
+        return ret;
+    }
+
     pub fn load_functions(format: &str, path: &String) -> HashMap<String, u64> {
-        let ret: HashMap<String, u64> = HashMap::new();
+        let ret = match format {
+            "elf"   => Loader::load_elf_functions(path),
+            _       => HashMap::new()
+        };
+        return ret;
+    }
+
+    pub fn load_function_ends(format: &str, path: &String) -> HashMap<u64, u64> {
+        let ret = match format {
+            "elf"   => Loader::load_elf_function_ends(path),
+            _       => HashMap::new()
+        };
+        return ret;
+    }
+
+    pub fn load_line_info(format: &str, path: &String) -> Vec<LineInfo> {
+        let ret = match format {
+            "elf"   => Loader::load_elf_line_info(path),
+            _       => Vec::new()
+        };
         return ret;
     }
 }
\ No newline at end of file
@@ -10,7 +10,10 @@ pub struct WorkflowInput {
 pub struct WorkflowMemMap {
     pub from: u64,
     pub size: usize,
-    pub flags: String
+    pub flags: String,
+    /// Address of the memory this region is a view of, both share their content.
+    #[serde(default)]
+    pub alias: Option<u64>
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -19,6 +22,14 @@ pub struct WorkflowRegister {
     pub value: u64
 }
 
+/// Register map of `cpu` loaded from SVD files or iLLD headers (files or
+/// directories), replacing the built-in one.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WorkflowRegisterMap {
+    pub cpu: String,
+    pub paths: Vec<String>
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct WorkflowBoot {
     pub begin: u64,
@@ -27,15 +38,314 @@ pub struct WorkflowBoot {
     pub count: u64
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WorkflowTraceWindow {
+    #[serde(default)]
+    pub from: u64,
+    #[serde(default)]
+    pub to: u64,
+    #[serde(default)]
+    pub symbol: Option<String>
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WorkflowTrace {
+    pub windows: Vec<WorkflowTraceWindow>,
+    #[serde(default = "WorkflowTrace::default_mode")]
+    pub mode: String,
+    #[serde(default)]
+    pub registers: bool,
+    #[serde(default)]
+    pub memory: bool,
+    #[serde(default)]
+    pub output: Option<String>
+}
+
+impl WorkflowTrace {
+    fn default_mode() -> String {
+        String::from("block")
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WorkflowTaintSink {
+    #[serde(default)]
+    pub symbol: Option<String>,
+    #[serde(default)]
+    pub address: u64,
+    pub register: String
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WorkflowTaint {
+    #[serde(default)]
+    pub sinks: Vec<WorkflowTaintSink>
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WorkflowFuzzInject {
+    #[serde(default)]
+    pub address: Option<u64>,
+    #[serde(default)]
+    pub size_register: Option<String>,
+    #[serde(default)]
+    pub event: Option<String>,
+    #[serde(default = "WorkflowFuzzInject::default_max_size")]
+    pub max_size: usize
+}
+
+impl WorkflowFuzzInject {
+    fn default_max_size() -> usize {
+        256
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WorkflowFuzz {
+    pub snapshot: u64,
+    pub inject: WorkflowFuzzInject,
+    pub end: Vec<u64>,
+    #[serde(default = "WorkflowFuzz::default_budget")]
+    pub budget: u64,
+    pub corpus: String,
+    pub crashes: String,
+    #[serde(default)]
+    pub iterations: Option<u64>,
+    #[serde(default)]
+    pub seed: Option<u64>
+}
+
+impl WorkflowFuzz {
+    fn default_budget() -> u64 {
+        1000000
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WorkflowCrash {
+    #[serde(default)]
+    pub check_code_regions: bool,
+    #[serde(default)]
+    pub code_regions: Vec<WorkflowMemMap>,
+    #[serde(default)]
+    pub watchdog_budget: Option<u64>
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WorkflowClock {
+    #[serde(default = "WorkflowClock::default_cpu_frequency")]
+    pub cpu_frequency: u64,
+    #[serde(default)]
+    pub realtime: bool
+}
+
+impl WorkflowClock {
+    fn default_cpu_frequency() -> u64 {
+        300000000
+    }
+}
+
+impl Default for WorkflowClock {
+    fn default() -> WorkflowClock {
+        WorkflowClock {
+            cpu_frequency: WorkflowClock::default_cpu_frequency(),
+            realtime: false
+        }
+    }
+}
+
+/// Round-robin scheduling of the cores, `slice` instructions each.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WorkflowScheduler {
+    #[serde(default = "WorkflowScheduler::default_slice")]
+    pub slice: u64
+}
+
+impl WorkflowScheduler {
+    fn default_slice() -> u64 {
+        1000
+    }
+}
+
+impl Default for WorkflowScheduler {
+    fn default() -> WorkflowScheduler {
+        WorkflowScheduler {
+            slice: WorkflowScheduler::default_slice()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WorkflowStm {
+    #[serde(default = "WorkflowStm::default_frequency")]
+    pub frequency: u64
+}
+
+impl WorkflowStm {
+    fn default_frequency() -> u64 {
+        100000000
+    }
+}
+
+impl Default for WorkflowStm {
+    fn default() -> WorkflowStm {
+        WorkflowStm {
+            frequency: WorkflowStm::default_frequency()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WorkflowUart {
+    pub module: String,
+    pub host: String
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WorkflowSocketCan {
+    pub node: String,
+    pub interface: String
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WorkflowDoip {
+    #[serde(default = "WorkflowDoip::default_bind")]
+    pub bind: String,
+    #[serde(default = "WorkflowDoip::default_logical_address")]
+    pub logical_address: u16,
+    #[serde(default = "WorkflowDoip::default_vin")]
+    pub vin: String,
+    #[serde(default)]
+    pub eid: u64,
+    #[serde(default)]
+    pub gid: u64,
+    /// Diagnostic messages go to this script event instead of ISO-TP.
+    #[serde(default)]
+    pub event: Option<String>,
+    #[serde(default = "WorkflowDoip::default_node")]
+    pub node: String,
+    #[serde(default = "WorkflowDoip::default_tx_id")]
+    pub tx_id: u32,
+    #[serde(default = "WorkflowDoip::default_rx_id")]
+    pub rx_id: u32
+}
+
+impl WorkflowDoip {
+    fn default_bind() -> String {
+        String::from("127.0.0.1:13400")
+    }
+
+    fn default_logical_address() -> u16 {
+        0x1001
+    }
+
+    fn default_vin() -> String {
+        String::from("QECU0000000000000")
+    }
+
+    fn default_node() -> String {
+        String::from("CAN0_N0")
+    }
+
+    fn default_tx_id() -> u32 {
+        0x7E0
+    }
+
+    fn default_rx_id() -> u32 {
+        0x7E8
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WorkflowXcpEvent {
+    pub name: String,
+    /// Cyclic event on the virtual clock.
+    #[serde(default)]
+    pub period_us: Option<u64>,
+    /// Event raised when the instruction at `address` (or `symbol`) executes.
+    #[serde(default)]
+    pub address: Option<u64>,
+    #[serde(default)]
+    pub symbol: Option<String>
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WorkflowXcp {
+    /// `tcp:addr`, `udp:addr` or `can:NODE`.
+    #[serde(default = "WorkflowXcp::default_transport")]
+    pub transport: String,
+    #[serde(default = "WorkflowXcp::default_cmd_id")]
+    pub cmd_id: u32,
+    #[serde(default = "WorkflowXcp::default_res_id")]
+    pub res_id: u32,
+    #[serde(default = "WorkflowXcp::default_events")]
+    pub events: Vec<WorkflowXcpEvent>,
+    /// A2L file served by GET_ID.
+    #[serde(default)]
+    pub a2l: Option<String>
+}
+
+impl WorkflowXcp {
+    fn default_transport() -> String {
+        String::from("tcp:127.0.0.1:5555")
+    }
+
+    fn default_cmd_id() -> u32 {
+        0x7F0
+    }
+
+    fn default_res_id() -> u32 {
+        0x7F1
+    }
+
+    fn default_events() -> Vec<WorkflowXcpEvent> {
+        vec![WorkflowXcpEvent { name: String::from("10ms"), period_us: Some(10000), address: None, symbol: None }]
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Workflow {
     pub project: String,
+    /// Emulated AURIX derivative (tc375, tc387, tc397, tc277 or tc234).
+    #[serde(default = "Workflow::default_cpu")]
+    pub cpu: String,
     pub input: WorkflowInput,
+    /// Added to the memory map of the derivative, replacing what they overlap.
+    #[serde(default)]
     pub mem_map: Vec<WorkflowMemMap>,
     pub registers: Vec<WorkflowRegister>,
     // pub boot: WorkflowBoot,
     pub init_script: String,
-    pub sleigh_path: String
+    pub sleigh_path: String,
+    #[serde(default)]
+    pub trace: Option<WorkflowTrace>,
+    #[serde(default)]
+    pub coverage: bool,
+    #[serde(default)]
+    pub taint: Option<WorkflowTaint>,
+    #[serde(default)]
+    pub fuzz: Option<WorkflowFuzz>,
+    #[serde(default)]
+    pub crash: Option<WorkflowCrash>,
+    #[serde(default)]
+    pub clock: WorkflowClock,
+    #[serde(default)]
+    pub scheduler: WorkflowScheduler,
+    #[serde(default)]
+    pub stm: WorkflowStm,
+    #[serde(default)]
+    pub uart: Vec<WorkflowUart>,
+    #[serde(default)]
+    pub socketcan: Vec<WorkflowSocketCan>,
+    #[serde(default)]
+    pub doip: Option<WorkflowDoip>,
+    #[serde(default)]
+    pub xcp: Option<WorkflowXcp>,
+    /// ASAM MCD-2 MC description of the firmware's labels.
+    #[serde(default)]
+    pub a2l: Option<String>,
+    #[serde(default)]
+    pub register_maps: Vec<WorkflowRegisterMap>
 }
 
 impl Workflow {
@@ -44,4 +354,8 @@ impl Workflow {
         let schema = serde_yaml::from_str::<Workflow>(content_str).unwrap();
         return schema;
     }
+
+    fn default_cpu() -> String {
+        String::from("tc375")
+    }
 }
\ No newline at end of file